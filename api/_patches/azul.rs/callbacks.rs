@@ -64,7 +64,7 @@
                     // copy the struct from the heap to the stack and
                     // call mem::drop on U to run the destructor
                     let mut stack_mem = mem::MaybeUninit::<U>::uninit();
-                    ptr::copy_nonoverlapping((ptr as *mut c_void) as *const U, stack_mem.as_mut_ptr(), mem::size_of::<U>());
+                    ptr::copy_nonoverlapping((ptr as *mut c_void) as *const U, stack_mem.as_mut_ptr(), 1);
                     let stack_mem = stack_mem.assume_init();
                     mem::drop(stack_mem);
                 }
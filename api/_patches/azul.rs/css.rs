@@ -1,12 +1,7 @@
 
     use crate::vec::{
-        StyleBackgroundPositionVec,
-        StyleBackgroundContentVec,
-        StyleBackgroundSizeVec,
-        StyleBackgroundRepeatVec,
-        StyleTransformVec,
-        StyleFontFamilyVec,
-        StyleFilterVec,
+        GridTrackVec,
+        CssPropertyTypeVec,
     };
 
     macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => ({
@@ -15,6 +10,8 @@
             CssPropertyType::FontSize => CssProperty::FontSize(StyleFontSizeValue::$content_type),
             CssPropertyType::FontFamily => CssProperty::FontFamily(StyleFontFamilyVecValue::$content_type),
             CssPropertyType::TextAlign => CssProperty::TextAlign(StyleTextAlignValue::$content_type),
+            CssPropertyType::VerticalAlign => CssProperty::VerticalAlign(StyleVerticalAlignValue::$content_type),
+            CssPropertyType::Direction => CssProperty::Direction(StyleDirectionValue::$content_type),
             CssPropertyType::LetterSpacing => CssProperty::LetterSpacing(StyleLetterSpacingValue::$content_type),
             CssPropertyType::LineHeight => CssProperty::LineHeight(StyleLineHeightValue::$content_type),
             CssPropertyType::WordSpacing => CssProperty::WordSpacing(StyleWordSpacingValue::$content_type),
@@ -41,6 +38,10 @@
             CssPropertyType::JustifyContent => CssProperty::JustifyContent(LayoutJustifyContentValue::$content_type),
             CssPropertyType::AlignItems => CssProperty::AlignItems(LayoutAlignItemsValue::$content_type),
             CssPropertyType::AlignContent => CssProperty::AlignContent(LayoutAlignContentValue::$content_type),
+            CssPropertyType::GridTemplateColumns => CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::$content_type),
+            CssPropertyType::GridTemplateRows => CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::$content_type),
+            CssPropertyType::GridColumn => CssProperty::GridColumn(LayoutGridColumnValue::$content_type),
+            CssPropertyType::GridRow => CssProperty::GridRow(LayoutGridRowValue::$content_type),
             CssPropertyType::BackgroundContent => CssProperty::BackgroundContent(StyleBackgroundContentVecValue::$content_type),
             CssPropertyType::BackgroundPosition => CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::$content_type),
             CssPropertyType::BackgroundSize => CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::$content_type),
@@ -82,12 +83,48 @@
             CssPropertyType::TransformOrigin => CssProperty::TransformOrigin(StyleTransformOriginValue::$content_type),
             CssPropertyType::BackfaceVisibility => CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::$content_type),
             CssPropertyType::MixBlendMode => CssProperty::MixBlendMode(StyleMixBlendModeValue::$content_type),
+            CssPropertyType::Isolation => CssProperty::Isolation(StyleIsolationValue::$content_type),
+            CssPropertyType::ImageRendering => CssProperty::ImageRendering(StyleImageRenderingValue::$content_type),
             CssPropertyType::Filter => CssProperty::Filter(StyleFilterVecValue::$content_type),
             CssPropertyType::BackdropFilter => CssProperty::BackdropFilter(StyleFilterVecValue::$content_type),
             CssPropertyType::TextShadow => CssProperty::TextShadow(StyleBoxShadowValue::$content_type),
+            CssPropertyType::TransitionProperty => CssProperty::TransitionProperty(StyleTransitionPropertyValue::$content_type),
+            CssPropertyType::TransitionDuration => CssProperty::TransitionDuration(StyleTransitionDurationValue::$content_type),
+            CssPropertyType::TransitionTimingFunction => CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionValue::$content_type),
+            CssPropertyType::TransitionDelay => CssProperty::TransitionDelay(StyleTransitionDelayValue::$content_type),
+            CssPropertyType::CaretColor => CssProperty::CaretColor(StyleCaretColorValue::$content_type),
+            CssPropertyType::SelectionColor => CssProperty::SelectionColor(StyleSelectionColorValue::$content_type),
+            CssPropertyType::SelectionBackground => CssProperty::SelectionBackground(StyleSelectionBackgroundValue::$content_type),
         }
     })}
 
+    macro_rules! impl_boxed_new_static {($($boxed:ident: $value:ident),*) => ($(
+        impl $boxed {
+            /// Value for a `static` that can be used in the `const` constructors of the boxed
+            /// `Exact` variants (i.e. `CssProperty::const_font_family`), the library never frees it
+            pub const fn new_static(value: $value) -> Self { Self { run_destructor: false, value } }
+        }
+    )*)}
+
+    impl_boxed_new_static!(
+        CalcLengthBoxed: CalcLength,
+        LayoutGridTemplateColumnsBoxed: LayoutGridTemplateColumns,
+        LayoutGridTemplateRowsBoxed: LayoutGridTemplateRows,
+        StyleBackgroundContentVecBoxed: StyleBackgroundContentVec,
+        StyleBackgroundPositionVecBoxed: StyleBackgroundPositionVec,
+        StyleBackgroundRepeatVecBoxed: StyleBackgroundRepeatVec,
+        StyleBackgroundSizeVecBoxed: StyleBackgroundSizeVec,
+        StyleBoxShadowBoxed: StyleBoxShadow,
+        StyleFilterVecBoxed: StyleFilterVec,
+        StyleFontFamilyVecBoxed: StyleFontFamilyVec,
+        StylePerspectiveOriginBoxed: StylePerspectiveOrigin,
+        StyleTransformOriginBoxed: StyleTransformOrigin,
+        StyleTransformVecBoxed: StyleTransformVec,
+        StyleTransitionPropertyBoxed: StyleTransitionProperty,
+        StyleTransitionTimingFunctionBoxed: StyleTransitionTimingFunction,
+        StyleVerticalAlignBoxed: StyleVerticalAlign
+    );
+
     impl CssProperty {
 
         /// Return the type (key) of this property as a statically typed enum
@@ -97,6 +134,8 @@
                 CssProperty::FontSize(_) => CssPropertyType::FontSize,
                 CssProperty::FontFamily(_) => CssPropertyType::FontFamily,
                 CssProperty::TextAlign(_) => CssPropertyType::TextAlign,
+                CssProperty::VerticalAlign(_) => CssPropertyType::VerticalAlign,
+                CssProperty::Direction(_) => CssPropertyType::Direction,
                 CssProperty::LetterSpacing(_) => CssPropertyType::LetterSpacing,
                 CssProperty::LineHeight(_) => CssPropertyType::LineHeight,
                 CssProperty::WordSpacing(_) => CssPropertyType::WordSpacing,
@@ -123,6 +162,10 @@
                 CssProperty::JustifyContent(_) => CssPropertyType::JustifyContent,
                 CssProperty::AlignItems(_) => CssPropertyType::AlignItems,
                 CssProperty::AlignContent(_) => CssPropertyType::AlignContent,
+                CssProperty::GridTemplateColumns(_) => CssPropertyType::GridTemplateColumns,
+                CssProperty::GridTemplateRows(_) => CssPropertyType::GridTemplateRows,
+                CssProperty::GridColumn(_) => CssPropertyType::GridColumn,
+                CssProperty::GridRow(_) => CssPropertyType::GridRow,
                 CssProperty::BackgroundContent(_) => CssPropertyType::BackgroundContent,
                 CssProperty::BackgroundPosition(_) => CssPropertyType::BackgroundPosition,
                 CssProperty::BackgroundSize(_) => CssPropertyType::BackgroundSize,
@@ -164,9 +207,18 @@
                 CssProperty::TransformOrigin(_) => CssPropertyType::TransformOrigin,
                 CssProperty::BackfaceVisibility(_) => CssPropertyType::BackfaceVisibility,
                 CssProperty::MixBlendMode(_) => CssPropertyType::MixBlendMode,
+                CssProperty::Isolation(_) => CssPropertyType::Isolation,
+                CssProperty::ImageRendering(_) => CssPropertyType::ImageRendering,
                 CssProperty::Filter(_) => CssPropertyType::Filter,
                 CssProperty::BackdropFilter(_) => CssPropertyType::BackdropFilter,
                 CssProperty::TextShadow(_) => CssPropertyType::TextShadow,
+                CssProperty::TransitionProperty(_) => CssPropertyType::TransitionProperty,
+                CssProperty::TransitionDuration(_) => CssPropertyType::TransitionDuration,
+                CssProperty::TransitionTimingFunction(_) => CssPropertyType::TransitionTimingFunction,
+                CssProperty::TransitionDelay(_) => CssPropertyType::TransitionDelay,
+                CssProperty::CaretColor(_) => CssPropertyType::CaretColor,
+                CssProperty::SelectionColor(_) => CssPropertyType::SelectionColor,
+                CssProperty::SelectionBackground(_) => CssPropertyType::SelectionBackground,
             }
         }
 
@@ -179,8 +231,12 @@
 
         pub const fn text_color(input: StyleTextColor) -> Self { CssProperty::TextColor(StyleTextColorValue::Exact(input)) }
         pub const fn font_size(input: StyleFontSize) -> Self { CssProperty::FontSize(StyleFontSizeValue::Exact(input)) }
-        pub const fn font_family(input: StyleFontFamilyVec) -> Self { CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(input)) }
+        pub const fn const_font_family(input: &'static StyleFontFamilyVecBoxed) -> Self { CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(input)) }
+        pub fn font_family(input: StyleFontFamilyVec) -> Self { CssProperty::FontFamily(StyleFontFamilyVecValue::exact(input)) }
         pub const fn text_align(input: StyleTextAlign) -> Self { CssProperty::TextAlign(StyleTextAlignValue::Exact(input)) }
+        pub const fn const_vertical_align(input: &'static StyleVerticalAlignBoxed) -> Self { CssProperty::VerticalAlign(StyleVerticalAlignValue::Exact(input)) }
+        pub fn vertical_align(input: StyleVerticalAlign) -> Self { CssProperty::VerticalAlign(StyleVerticalAlignValue::exact(input)) }
+        pub const fn direction(input: StyleDirection) -> Self { CssProperty::Direction(StyleDirectionValue::Exact(input)) }
         pub const fn letter_spacing(input: StyleLetterSpacing) -> Self { CssProperty::LetterSpacing(StyleLetterSpacingValue::Exact(input)) }
         pub const fn line_height(input: StyleLineHeight) -> Self { CssProperty::LineHeight(StyleLineHeightValue::Exact(input)) }
         pub const fn word_spacing(input: StyleWordSpacing) -> Self { CssProperty::WordSpacing(StyleWordSpacingValue::Exact(input)) }
@@ -207,10 +263,20 @@
         pub const fn justify_content(input: LayoutJustifyContent) -> Self { CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(input)) }
         pub const fn align_items(input: LayoutAlignItems) -> Self { CssProperty::AlignItems(LayoutAlignItemsValue::Exact(input)) }
         pub const fn align_content(input: LayoutAlignContent) -> Self { CssProperty::AlignContent(LayoutAlignContentValue::Exact(input)) }
-        pub const fn background_content(input: StyleBackgroundContentVec) -> Self { CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(input)) }
-        pub const fn background_position(input: StyleBackgroundPositionVec) -> Self { CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::Exact(input)) }
-        pub const fn background_size(input: StyleBackgroundSizeVec) -> Self { CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::Exact(input)) }
-        pub const fn background_repeat(input: StyleBackgroundRepeatVec) -> Self { CssProperty::BackgroundRepeat(StyleBackgroundRepeatVecValue::Exact(input)) }
+        pub const fn const_grid_template_columns(input: &'static LayoutGridTemplateColumnsBoxed) -> Self { CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::Exact(input)) }
+        pub fn grid_template_columns(input: LayoutGridTemplateColumns) -> Self { CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::exact(input)) }
+        pub const fn const_grid_template_rows(input: &'static LayoutGridTemplateRowsBoxed) -> Self { CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::Exact(input)) }
+        pub fn grid_template_rows(input: LayoutGridTemplateRows) -> Self { CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::exact(input)) }
+        pub const fn grid_column(input: LayoutGridColumn) -> Self { CssProperty::GridColumn(LayoutGridColumnValue::Exact(input)) }
+        pub const fn grid_row(input: LayoutGridRow) -> Self { CssProperty::GridRow(LayoutGridRowValue::Exact(input)) }
+        pub const fn const_background_content(input: &'static StyleBackgroundContentVecBoxed) -> Self { CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(input)) }
+        pub fn background_content(input: StyleBackgroundContentVec) -> Self { CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact(input)) }
+        pub const fn const_background_position(input: &'static StyleBackgroundPositionVecBoxed) -> Self { CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::Exact(input)) }
+        pub fn background_position(input: StyleBackgroundPositionVec) -> Self { CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::exact(input)) }
+        pub const fn const_background_size(input: &'static StyleBackgroundSizeVecBoxed) -> Self { CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::Exact(input)) }
+        pub fn background_size(input: StyleBackgroundSizeVec) -> Self { CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::exact(input)) }
+        pub const fn const_background_repeat(input: &'static StyleBackgroundRepeatVecBoxed) -> Self { CssProperty::BackgroundRepeat(StyleBackgroundRepeatVecValue::Exact(input)) }
+        pub fn background_repeat(input: StyleBackgroundRepeatVec) -> Self { CssProperty::BackgroundRepeat(StyleBackgroundRepeatVecValue::exact(input)) }
         pub const fn overflow_x(input: LayoutOverflow) -> Self { CssProperty::OverflowX(LayoutOverflowValue::Exact(input)) }
         pub const fn overflow_y(input: LayoutOverflow) -> Self { CssProperty::OverflowY(LayoutOverflowValue::Exact(input)) }
         pub const fn padding_top(input: LayoutPaddingTop) -> Self { CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(input)) }
@@ -237,19 +303,40 @@
         pub const fn border_right_width(input: LayoutBorderRightWidth) -> Self { CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(input)) }
         pub const fn border_left_width(input: LayoutBorderLeftWidth) -> Self { CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(input)) }
         pub const fn border_bottom_width(input: LayoutBorderBottomWidth) -> Self { CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(input)) }
-        pub const fn box_shadow_left(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(input)) }
-        pub const fn box_shadow_right(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(input)) }
-        pub const fn box_shadow_top(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(input)) }
-        pub const fn box_shadow_bottom(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(input)) }
+        pub const fn const_box_shadow_left(input: &'static StyleBoxShadowBoxed) -> Self { CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(input)) }
+        pub fn box_shadow_left(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowLeft(StyleBoxShadowValue::exact(input)) }
+        pub const fn const_box_shadow_right(input: &'static StyleBoxShadowBoxed) -> Self { CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(input)) }
+        pub fn box_shadow_right(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowRight(StyleBoxShadowValue::exact(input)) }
+        pub const fn const_box_shadow_top(input: &'static StyleBoxShadowBoxed) -> Self { CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(input)) }
+        pub fn box_shadow_top(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowTop(StyleBoxShadowValue::exact(input)) }
+        pub const fn const_box_shadow_bottom(input: &'static StyleBoxShadowBoxed) -> Self { CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(input)) }
+        pub fn box_shadow_bottom(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowBottom(StyleBoxShadowValue::exact(input)) }
         pub const fn opacity(input: StyleOpacity) -> Self { CssProperty::Opacity(StyleOpacityValue::Exact(input)) }
-        pub const fn transform(input: StyleTransformVec) -> Self { CssProperty::Transform(StyleTransformVecValue::Exact(input)) }
-        pub const fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
-        pub const fn perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input)) }
+        pub const fn const_transform(input: &'static StyleTransformVecBoxed) -> Self { CssProperty::Transform(StyleTransformVecValue::Exact(input)) }
+        pub fn transform(input: StyleTransformVec) -> Self { CssProperty::Transform(StyleTransformVecValue::exact(input)) }
+        pub const fn const_transform_origin(input: &'static StyleTransformOriginBoxed) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
+        pub fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::exact(input)) }
+        pub const fn const_perspective_origin(input: &'static StylePerspectiveOriginBoxed) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input)) }
+        pub fn perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::exact(input)) }
         pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self { CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input)) }
         pub const fn mix_blend_mode(input: StyleMixBlendMode) -> Self { CssProperty::MixBlendMode(StyleMixBlendModeValue::Exact(input)) }
-        pub const fn filter(input: StyleFilterVec) -> Self { CssProperty::Filter(StyleFilterVecValue::Exact(input)) }
-        pub const fn backdrop_filter(input: StyleFilterVec) -> Self { CssProperty::BackdropFilter(StyleFilterVecValue::Exact(input)) }
-        pub const fn text_shadow(input: StyleBoxShadow) -> Self { CssProperty::TextShadow(StyleBoxShadowValue::Exact(input)) }
+        pub const fn isolation(input: StyleIsolation) -> Self { CssProperty::Isolation(StyleIsolationValue::Exact(input)) }
+        pub const fn image_rendering(input: StyleImageRendering) -> Self { CssProperty::ImageRendering(StyleImageRenderingValue::Exact(input)) }
+        pub const fn const_filter(input: &'static StyleFilterVecBoxed) -> Self { CssProperty::Filter(StyleFilterVecValue::Exact(input)) }
+        pub fn filter(input: StyleFilterVec) -> Self { CssProperty::Filter(StyleFilterVecValue::exact(input)) }
+        pub const fn const_backdrop_filter(input: &'static StyleFilterVecBoxed) -> Self { CssProperty::BackdropFilter(StyleFilterVecValue::Exact(input)) }
+        pub fn backdrop_filter(input: StyleFilterVec) -> Self { CssProperty::BackdropFilter(StyleFilterVecValue::exact(input)) }
+        pub const fn const_text_shadow(input: &'static StyleBoxShadowBoxed) -> Self { CssProperty::TextShadow(StyleBoxShadowValue::Exact(input)) }
+        pub fn text_shadow(input: StyleBoxShadow) -> Self { CssProperty::TextShadow(StyleBoxShadowValue::exact(input)) }
+        pub const fn const_transition_property(input: &'static StyleTransitionPropertyBoxed) -> Self { CssProperty::TransitionProperty(StyleTransitionPropertyValue::Exact(input)) }
+        pub fn transition_property(input: StyleTransitionProperty) -> Self { CssProperty::TransitionProperty(StyleTransitionPropertyValue::exact(input)) }
+        pub const fn transition_duration(input: StyleTransitionDuration) -> Self { CssProperty::TransitionDuration(StyleTransitionDurationValue::Exact(input)) }
+        pub const fn const_transition_timing_function(input: &'static StyleTransitionTimingFunctionBoxed) -> Self { CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionValue::Exact(input)) }
+        pub fn transition_timing_function(input: StyleTransitionTimingFunction) -> Self { CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionValue::exact(input)) }
+        pub const fn transition_delay(input: StyleTransitionDelay) -> Self { CssProperty::TransitionDelay(StyleTransitionDelayValue::Exact(input)) }
+        pub const fn caret_color(input: StyleCaretColor) -> Self { CssProperty::CaretColor(StyleCaretColorValue::Exact(input)) }
+        pub const fn selection_color(input: StyleSelectionColor) -> Self { CssProperty::SelectionColor(StyleSelectionColorValue::Exact(input)) }
+        pub const fn selection_background(input: StyleSelectionBackground) -> Self { CssProperty::SelectionBackground(StyleSelectionBackgroundValue::Exact(input)) }
     }
 
     const FP_PRECISION_MULTIPLIER: f32 = 1000.0;
@@ -285,6 +285,16 @@
     impl_vec_clone!(AzIdOrClass, AzIdOrClassVec, IdOrClassVecDestructor);
     impl_vec!(AzStyleTransform, AzStyleTransformVec, AzStyleTransformVecDestructor, az_style_transform_vec_destructor, AzStyleTransformVec_delete);
     impl_vec_clone!(AzStyleTransform, AzStyleTransformVec, AzStyleTransformVecDestructor);
+    impl_vec!(AzGridTrack, AzGridTrackVec, AzGridTrackVecDestructor, az_grid_track_vec_destructor, AzGridTrackVec_delete);
+    impl_vec_clone!(AzGridTrack, AzGridTrackVec, AzGridTrackVecDestructor);
+    impl_vec!(AzCssPropertyType, AzCssPropertyTypeVec, AzCssPropertyTypeVecDestructor, az_css_property_type_vec_destructor, AzCssPropertyTypeVec_delete);
+    impl_vec_clone!(AzCssPropertyType, AzCssPropertyTypeVec, AzCssPropertyTypeVecDestructor);
+    impl_vec!(AzCssVariable, AzCssVariableVec, AzCssVariableVecDestructor, az_css_variable_vec_destructor, AzCssVariableVec_delete);
+    impl_vec_clone!(AzCssVariable, AzCssVariableVec, AzCssVariableVecDestructor);
+    impl_vec!(AzMediaFeature, AzMediaFeatureVec, AzMediaFeatureVecDestructor, az_media_feature_vec_destructor, AzMediaFeatureVec_delete);
+    impl_vec_clone!(AzMediaFeature, AzMediaFeatureVec, AzMediaFeatureVecDestructor);
+    impl_vec!(AzMediaCondition, AzMediaConditionVec, AzMediaConditionVecDestructor, az_media_condition_vec_destructor, AzMediaConditionVec_delete);
+    impl_vec_clone!(AzMediaCondition, AzMediaConditionVec, AzMediaConditionVecDestructor);
     impl_vec!(AzCssProperty, AzCssPropertyVec, AzCssPropertyVecDestructor, az_css_property_vec_destructor, AzCssPropertyVec_delete);
     impl_vec_clone!(AzCssProperty, AzCssPropertyVec, AzCssPropertyVecDestructor);
     impl_vec!(AzSvgMultiPolygon, AzSvgMultiPolygonVec, AzSvgMultiPolygonVecDestructor, az_svg_multi_polygon_vec_destructor, AzSvgMultiPolygonVec_delete);
@@ -347,6 +357,8 @@
     impl_vec_clone!(AzStyleBackgroundContent, AzStyleBackgroundContentVec, AzStyleBackgroundContentVecDestructor);
     impl_vec!(AzVideoMode, AzVideoModeVec, AzVideoModeVecDestructor, az_video_mode_vec_destructor, AzVideoModeVec_delete);
     impl_vec_clone!(AzVideoMode, AzVideoModeVec, AzVideoModeVecDestructor);
+    impl_vec!(AzAcceleratorCallback, AzAcceleratorCallbackVec, AzAcceleratorCallbackVecDestructor, az_accelerator_callback_vec_destructor, AzAcceleratorCallbackVec_delete);
+    impl_vec_clone!(AzAcceleratorCallback, AzAcceleratorCallbackVec, AzAcceleratorCallbackVecDestructor);
     impl_vec!(AzMonitor, AzMonitorVec, AzMonitorVecDestructor, az_monitor_vec_destructor, AzMonitorVec_delete);
     impl_vec_clone!(AzMonitor, AzMonitorVec, AzMonitorVecDestructor);
     impl_vec!(AzStyleFontFamily, AzStyleFontFamilyVec, AzStyleFontFamilyVecDestructor, az_style_font_family_vec_destructor, AzStyleFontFamilyVec_delete);
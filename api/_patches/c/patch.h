@@ -49,6 +49,7 @@
     .enable_logging_on_panic = true, \
     .enable_tab_navigation = true, \
     .system_callbacks = AzSystemCallbacks_libraryInternal(), \
+    .redraw_behavior = AzRedrawBehavior_OnEventOnly, \
 }
 
 /* Macro to generate reflection metadata for a given struct - for a "structName" of "foo", generates:
@@ -78,6 +78,8 @@ struct AzThreadReceiver;
 typedef struct AzThreadReceiver AzThreadReceiver;
 typedef void (*AzThreadCallbackType)(AzRefAny A, AzThreadSender B, AzThreadReceiver C);
 
+typedef void (*AzThreadFnType)(AzRefAny* restrict A, AzThreadSender* restrict B, AzThreadReceiver* restrict C);
+
 typedef void (*AzRefAnyDestructorType)(void* restrict A);
 
 typedef AzUpdate (*AzRibbonOnTabClickedCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, int32_t C);
@@ -294,6 +296,10 @@ struct AzVideoModeVec;
 typedef struct AzVideoModeVec AzVideoModeVec;
 typedef void (*AzVideoModeVecDestructorType)(AzVideoModeVec* restrict A);
 
+struct AzAcceleratorCallbackVec;
+typedef struct AzAcceleratorCallbackVec AzAcceleratorCallbackVec;
+typedef void (*AzAcceleratorCallbackVecDestructorType)(AzAcceleratorCallbackVec* restrict A);
+
 struct AzDomVec;
 typedef struct AzDomVec AzDomVec;
 typedef void (*AzDomVecDestructorType)(AzDomVec* restrict A);
@@ -326,6 +332,26 @@ struct AzStyleTransformVec;
 typedef struct AzStyleTransformVec AzStyleTransformVec;
 typedef void (*AzStyleTransformVecDestructorType)(AzStyleTransformVec* restrict A);
 
+struct AzGridTrackVec;
+typedef struct AzGridTrackVec AzGridTrackVec;
+typedef void (*AzGridTrackVecDestructorType)(AzGridTrackVec* restrict A);
+
+struct AzCssPropertyTypeVec;
+typedef struct AzCssPropertyTypeVec AzCssPropertyTypeVec;
+typedef void (*AzCssPropertyTypeVecDestructorType)(AzCssPropertyTypeVec* restrict A);
+
+struct AzCssVariableVec;
+typedef struct AzCssVariableVec AzCssVariableVec;
+typedef void (*AzCssVariableVecDestructorType)(AzCssVariableVec* restrict A);
+
+struct AzMediaFeatureVec;
+typedef struct AzMediaFeatureVec AzMediaFeatureVec;
+typedef void (*AzMediaFeatureVecDestructorType)(AzMediaFeatureVec* restrict A);
+
+struct AzMediaConditionVec;
+typedef struct AzMediaConditionVec AzMediaConditionVec;
+typedef void (*AzMediaConditionVecDestructorType)(AzMediaConditionVec* restrict A);
+
 struct AzCssPropertyVec;
 typedef struct AzCssPropertyVec AzCssPropertyVec;
 typedef void (*AzCssPropertyVecDestructorType)(AzCssPropertyVec* restrict A);
@@ -479,11 +505,36 @@ enum AzAppLogLevel {
 };
 typedef enum AzAppLogLevel AzAppLogLevel;
 
+enum AzRedrawBehaviorTag {
+   AzRedrawBehaviorTag_OnEventOnly,
+   AzRedrawBehaviorTag_MaxFps,
+   AzRedrawBehaviorTag_Unlimited,
+};
+typedef enum AzRedrawBehaviorTag AzRedrawBehaviorTag;
+
+struct AzRedrawBehaviorVariant_OnEventOnly { AzRedrawBehaviorTag tag; };
+typedef struct AzRedrawBehaviorVariant_OnEventOnly AzRedrawBehaviorVariant_OnEventOnly;
+struct AzRedrawBehaviorVariant_MaxFps { AzRedrawBehaviorTag tag; uint16_t payload; };
+typedef struct AzRedrawBehaviorVariant_MaxFps AzRedrawBehaviorVariant_MaxFps;
+struct AzRedrawBehaviorVariant_Unlimited { AzRedrawBehaviorTag tag; };
+typedef struct AzRedrawBehaviorVariant_Unlimited AzRedrawBehaviorVariant_Unlimited;
+union AzRedrawBehavior {
+    AzRedrawBehaviorVariant_OnEventOnly OnEventOnly;
+    AzRedrawBehaviorVariant_MaxFps MaxFps;
+    AzRedrawBehaviorVariant_Unlimited Unlimited;
+};
+typedef union AzRedrawBehavior AzRedrawBehavior;
+
 enum AzLayoutSolver {
    AzLayoutSolver_Default,
 };
 typedef enum AzLayoutSolver AzLayoutSolver;
 
+struct AzApi {
+    size_t _reserved;
+};
+typedef struct AzApi AzApi;
+
 enum AzVsync {
    AzVsync_Enabled,
    AzVsync_Disabled,
@@ -778,6 +829,19 @@ enum AzVirtualKeyCode {
 };
 typedef enum AzVirtualKeyCode AzVirtualKeyCode;
 
+struct AzModifiers {
+    bool  shift;
+    bool  ctrl;
+    bool  alt;
+    bool  super_key;
+};
+typedef struct AzModifiers AzModifiers;
+
+struct AzAcceleratorId {
+    size_t id;
+};
+typedef struct AzAcceleratorId AzAcceleratorId;
+
 enum AzWindowFrame {
    AzWindowFrame_Normal,
    AzWindowFrame_Minimized,
@@ -817,6 +881,12 @@ struct AzDebugState {
 };
 typedef struct AzDebugState AzDebugState;
 
+struct AzImeCursorRange {
+    size_t start;
+    size_t end;
+};
+typedef struct AzImeCursorRange AzImeCursorRange;
+
 enum AzMouseCursorType {
    AzMouseCursorType_Default,
    AzMouseCursorType_Crosshair,
@@ -1040,6 +1110,9 @@ enum AzHoverEventFilter {
    AzHoverEventFilter_TouchMove,
    AzHoverEventFilter_TouchEnd,
    AzHoverEventFilter_TouchCancel,
+   AzHoverEventFilter_ImeStateChanged,
+   AzHoverEventFilter_ImePreedit,
+   AzHoverEventFilter_ImeCommit,
 };
 typedef enum AzHoverEventFilter AzHoverEventFilter;
 
@@ -1063,6 +1136,9 @@ enum AzFocusEventFilter {
    AzFocusEventFilter_VirtualKeyUp,
    AzFocusEventFilter_FocusReceived,
    AzFocusEventFilter_FocusLost,
+   AzFocusEventFilter_ImeStateChanged,
+   AzFocusEventFilter_ImePreedit,
+   AzFocusEventFilter_ImeCommit,
 };
 typedef enum AzFocusEventFilter AzFocusEventFilter;
 
@@ -1097,6 +1173,11 @@ enum AzWindowEventFilter {
    AzWindowEventFilter_FocusLost,
    AzWindowEventFilter_CloseRequested,
    AzWindowEventFilter_ThemeChanged,
+   AzWindowEventFilter_WindowFocusReceived,
+   AzWindowEventFilter_WindowFocusLost,
+   AzWindowEventFilter_ImeStateChanged,
+   AzWindowEventFilter_ImePreedit,
+   AzWindowEventFilter_ImeCommit,
 };
 typedef enum AzWindowEventFilter AzWindowEventFilter;
 
@@ -1267,11 +1348,25 @@ struct AzCssNthChildPattern {
 };
 typedef struct AzCssNthChildPattern AzCssNthChildPattern;
 
+enum AzMediaOrientation {
+   AzMediaOrientation_Portrait,
+   AzMediaOrientation_Landscape,
+};
+typedef enum AzMediaOrientation AzMediaOrientation;
+
+enum AzMediaColorScheme {
+   AzMediaColorScheme_Light,
+   AzMediaColorScheme_Dark,
+};
+typedef enum AzMediaColorScheme AzMediaColorScheme;
+
 enum AzCssPropertyType {
    AzCssPropertyType_TextColor,
    AzCssPropertyType_FontSize,
    AzCssPropertyType_FontFamily,
    AzCssPropertyType_TextAlign,
+   AzCssPropertyType_VerticalAlign,
+   AzCssPropertyType_Direction,
    AzCssPropertyType_LetterSpacing,
    AzCssPropertyType_LineHeight,
    AzCssPropertyType_WordSpacing,
@@ -1298,6 +1393,10 @@ enum AzCssPropertyType {
    AzCssPropertyType_JustifyContent,
    AzCssPropertyType_AlignItems,
    AzCssPropertyType_AlignContent,
+   AzCssPropertyType_GridTemplateColumns,
+   AzCssPropertyType_GridTemplateRows,
+   AzCssPropertyType_GridColumn,
+   AzCssPropertyType_GridRow,
    AzCssPropertyType_BackgroundContent,
    AzCssPropertyType_BackgroundPosition,
    AzCssPropertyType_BackgroundSize,
@@ -1339,9 +1438,18 @@ enum AzCssPropertyType {
    AzCssPropertyType_PerspectiveOrigin,
    AzCssPropertyType_BackfaceVisibility,
    AzCssPropertyType_MixBlendMode,
+   AzCssPropertyType_Isolation,
+   AzCssPropertyType_ImageRendering,
    AzCssPropertyType_Filter,
    AzCssPropertyType_BackdropFilter,
    AzCssPropertyType_TextShadow,
+   AzCssPropertyType_TransitionProperty,
+   AzCssPropertyType_TransitionDuration,
+   AzCssPropertyType_TransitionTimingFunction,
+   AzCssPropertyType_TransitionDelay,
+   AzCssPropertyType_CaretColor,
+   AzCssPropertyType_SelectionColor,
+   AzCssPropertyType_SelectionBackground,
 };
 typedef enum AzCssPropertyType AzCssPropertyType;
 
@@ -1392,6 +1500,19 @@ enum AzStyleMixBlendMode {
 };
 typedef enum AzStyleMixBlendMode AzStyleMixBlendMode;
 
+enum AzStyleIsolation {
+   AzStyleIsolation_Auto,
+   AzStyleIsolation_Isolate,
+};
+typedef enum AzStyleIsolation AzStyleIsolation;
+
+enum AzStyleImageRendering {
+   AzStyleImageRendering_Auto,
+   AzStyleImageRendering_CrispEdges,
+   AzStyleImageRendering_Pixelated,
+};
+typedef enum AzStyleImageRendering AzStyleImageRendering;
+
 enum AzLayoutAlignContent {
    AzLayoutAlignContent_Stretch,
    AzLayoutAlignContent_Center,
@@ -1429,12 +1550,35 @@ enum AzLayoutDisplay {
    AzLayoutDisplay_Flex,
    AzLayoutDisplay_Block,
    AzLayoutDisplay_InlineBlock,
+   AzLayoutDisplay_Grid,
+   AzLayoutDisplay_Inline,
 };
 typedef enum AzLayoutDisplay AzLayoutDisplay;
 
+enum AzGridLineTag {
+   AzGridLineTag_Auto,
+   AzGridLineTag_Line,
+   AzGridLineTag_Span,
+};
+typedef enum AzGridLineTag AzGridLineTag;
+
+struct AzGridLineVariant_Auto { AzGridLineTag tag; };
+typedef struct AzGridLineVariant_Auto AzGridLineVariant_Auto;
+struct AzGridLineVariant_Line { AzGridLineTag tag; int16_t payload; };
+typedef struct AzGridLineVariant_Line AzGridLineVariant_Line;
+struct AzGridLineVariant_Span { AzGridLineTag tag; uint16_t payload; };
+typedef struct AzGridLineVariant_Span AzGridLineVariant_Span;
+union AzGridLine {
+    AzGridLineVariant_Auto Auto;
+    AzGridLineVariant_Line Line;
+    AzGridLineVariant_Span Span;
+};
+typedef union AzGridLine AzGridLine;
+
 enum AzLayoutFloat {
    AzLayoutFloat_Left,
    AzLayoutFloat_Right,
+   AzLayoutFloat_None,
 };
 typedef enum AzLayoutFloat AzLayoutFloat;
 
@@ -1459,6 +1603,7 @@ typedef enum AzLayoutPosition AzLayoutPosition;
 enum AzLayoutFlexWrap {
    AzLayoutFlexWrap_Wrap,
    AzLayoutFlexWrap_NoWrap,
+   AzLayoutFlexWrap_WrapReverse,
 };
 typedef enum AzLayoutFlexWrap AzLayoutFlexWrap;
 
@@ -1467,6 +1612,7 @@ enum AzLayoutOverflow {
    AzLayoutOverflow_Auto,
    AzLayoutOverflow_Hidden,
    AzLayoutOverflow_Visible,
+   AzLayoutOverflow_Clip,
 };
 typedef enum AzLayoutOverflow AzLayoutOverflow;
 
@@ -1516,6 +1662,8 @@ enum AzStyleBackgroundRepeat {
    AzStyleBackgroundRepeat_Repeat,
    AzStyleBackgroundRepeat_RepeatX,
    AzStyleBackgroundRepeat_RepeatY,
+   AzStyleBackgroundRepeat_Space,
+   AzStyleBackgroundRepeat_Round,
 };
 typedef enum AzStyleBackgroundRepeat AzStyleBackgroundRepeat;
 
@@ -1577,9 +1725,25 @@ enum AzStyleTextAlign {
    AzStyleTextAlign_Left,
    AzStyleTextAlign_Center,
    AzStyleTextAlign_Right,
+   AzStyleTextAlign_Start,
+   AzStyleTextAlign_End,
 };
 typedef enum AzStyleTextAlign AzStyleTextAlign;
 
+enum AzStyleDirection {
+   AzStyleDirection_Ltr,
+   AzStyleDirection_Rtl,
+};
+typedef enum AzStyleDirection AzStyleDirection;
+
+enum AzStepPosition {
+   AzStepPosition_JumpStart,
+   AzStepPosition_JumpEnd,
+   AzStepPosition_JumpNone,
+   AzStepPosition_JumpBoth,
+};
+typedef enum AzStepPosition AzStepPosition;
+
 struct AzRibbon {
     int32_t tab_active;
 };
@@ -2301,6 +2465,12 @@ struct AzThreadSenderDestructorFn {
 };
 typedef struct AzThreadSenderDestructorFn AzThreadSenderDestructorFn;
 
+struct AzTextCursor {
+    size_t byte_offset;
+    size_t grapheme_offset;
+};
+typedef struct AzTextCursor AzTextCursor;
+
 enum AzStyleFontFamilyVecDestructorTag {
    AzStyleFontFamilyVecDestructorTag_DefaultRust,
    AzStyleFontFamilyVecDestructorTag_NoDestructor,
@@ -2801,6 +2971,26 @@ union AzVideoModeVecDestructor {
 };
 typedef union AzVideoModeVecDestructor AzVideoModeVecDestructor;
 
+enum AzAcceleratorCallbackVecDestructorTag {
+   AzAcceleratorCallbackVecDestructorTag_DefaultRust,
+   AzAcceleratorCallbackVecDestructorTag_NoDestructor,
+   AzAcceleratorCallbackVecDestructorTag_External,
+};
+typedef enum AzAcceleratorCallbackVecDestructorTag AzAcceleratorCallbackVecDestructorTag;
+
+struct AzAcceleratorCallbackVecDestructorVariant_DefaultRust { AzAcceleratorCallbackVecDestructorTag tag; };
+typedef struct AzAcceleratorCallbackVecDestructorVariant_DefaultRust AzAcceleratorCallbackVecDestructorVariant_DefaultRust;
+struct AzAcceleratorCallbackVecDestructorVariant_NoDestructor { AzAcceleratorCallbackVecDestructorTag tag; };
+typedef struct AzAcceleratorCallbackVecDestructorVariant_NoDestructor AzAcceleratorCallbackVecDestructorVariant_NoDestructor;
+struct AzAcceleratorCallbackVecDestructorVariant_External { AzAcceleratorCallbackVecDestructorTag tag; AzAcceleratorCallbackVecDestructorType payload; };
+typedef struct AzAcceleratorCallbackVecDestructorVariant_External AzAcceleratorCallbackVecDestructorVariant_External;
+union AzAcceleratorCallbackVecDestructor {
+    AzAcceleratorCallbackVecDestructorVariant_DefaultRust DefaultRust;
+    AzAcceleratorCallbackVecDestructorVariant_NoDestructor NoDestructor;
+    AzAcceleratorCallbackVecDestructorVariant_External External;
+};
+typedef union AzAcceleratorCallbackVecDestructor AzAcceleratorCallbackVecDestructor;
+
 enum AzDomVecDestructorTag {
    AzDomVecDestructorTag_DefaultRust,
    AzDomVecDestructorTag_NoDestructor,
@@ -2961,6 +3151,106 @@ union AzStyleTransformVecDestructor {
 };
 typedef union AzStyleTransformVecDestructor AzStyleTransformVecDestructor;
 
+enum AzGridTrackVecDestructorTag {
+   AzGridTrackVecDestructorTag_DefaultRust,
+   AzGridTrackVecDestructorTag_NoDestructor,
+   AzGridTrackVecDestructorTag_External,
+};
+typedef enum AzGridTrackVecDestructorTag AzGridTrackVecDestructorTag;
+
+struct AzGridTrackVecDestructorVariant_DefaultRust { AzGridTrackVecDestructorTag tag; };
+typedef struct AzGridTrackVecDestructorVariant_DefaultRust AzGridTrackVecDestructorVariant_DefaultRust;
+struct AzGridTrackVecDestructorVariant_NoDestructor { AzGridTrackVecDestructorTag tag; };
+typedef struct AzGridTrackVecDestructorVariant_NoDestructor AzGridTrackVecDestructorVariant_NoDestructor;
+struct AzGridTrackVecDestructorVariant_External { AzGridTrackVecDestructorTag tag; AzGridTrackVecDestructorType payload; };
+typedef struct AzGridTrackVecDestructorVariant_External AzGridTrackVecDestructorVariant_External;
+union AzGridTrackVecDestructor {
+    AzGridTrackVecDestructorVariant_DefaultRust DefaultRust;
+    AzGridTrackVecDestructorVariant_NoDestructor NoDestructor;
+    AzGridTrackVecDestructorVariant_External External;
+};
+typedef union AzGridTrackVecDestructor AzGridTrackVecDestructor;
+
+enum AzCssPropertyTypeVecDestructorTag {
+   AzCssPropertyTypeVecDestructorTag_DefaultRust,
+   AzCssPropertyTypeVecDestructorTag_NoDestructor,
+   AzCssPropertyTypeVecDestructorTag_External,
+};
+typedef enum AzCssPropertyTypeVecDestructorTag AzCssPropertyTypeVecDestructorTag;
+
+struct AzCssPropertyTypeVecDestructorVariant_DefaultRust { AzCssPropertyTypeVecDestructorTag tag; };
+typedef struct AzCssPropertyTypeVecDestructorVariant_DefaultRust AzCssPropertyTypeVecDestructorVariant_DefaultRust;
+struct AzCssPropertyTypeVecDestructorVariant_NoDestructor { AzCssPropertyTypeVecDestructorTag tag; };
+typedef struct AzCssPropertyTypeVecDestructorVariant_NoDestructor AzCssPropertyTypeVecDestructorVariant_NoDestructor;
+struct AzCssPropertyTypeVecDestructorVariant_External { AzCssPropertyTypeVecDestructorTag tag; AzCssPropertyTypeVecDestructorType payload; };
+typedef struct AzCssPropertyTypeVecDestructorVariant_External AzCssPropertyTypeVecDestructorVariant_External;
+union AzCssPropertyTypeVecDestructor {
+    AzCssPropertyTypeVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssPropertyTypeVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssPropertyTypeVecDestructorVariant_External External;
+};
+typedef union AzCssPropertyTypeVecDestructor AzCssPropertyTypeVecDestructor;
+
+enum AzCssVariableVecDestructorTag {
+   AzCssVariableVecDestructorTag_DefaultRust,
+   AzCssVariableVecDestructorTag_NoDestructor,
+   AzCssVariableVecDestructorTag_External,
+};
+typedef enum AzCssVariableVecDestructorTag AzCssVariableVecDestructorTag;
+
+struct AzCssVariableVecDestructorVariant_DefaultRust { AzCssVariableVecDestructorTag tag; };
+typedef struct AzCssVariableVecDestructorVariant_DefaultRust AzCssVariableVecDestructorVariant_DefaultRust;
+struct AzCssVariableVecDestructorVariant_NoDestructor { AzCssVariableVecDestructorTag tag; };
+typedef struct AzCssVariableVecDestructorVariant_NoDestructor AzCssVariableVecDestructorVariant_NoDestructor;
+struct AzCssVariableVecDestructorVariant_External { AzCssVariableVecDestructorTag tag; AzCssVariableVecDestructorType payload; };
+typedef struct AzCssVariableVecDestructorVariant_External AzCssVariableVecDestructorVariant_External;
+union AzCssVariableVecDestructor {
+    AzCssVariableVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssVariableVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssVariableVecDestructorVariant_External External;
+};
+typedef union AzCssVariableVecDestructor AzCssVariableVecDestructor;
+
+enum AzMediaFeatureVecDestructorTag {
+   AzMediaFeatureVecDestructorTag_DefaultRust,
+   AzMediaFeatureVecDestructorTag_NoDestructor,
+   AzMediaFeatureVecDestructorTag_External,
+};
+typedef enum AzMediaFeatureVecDestructorTag AzMediaFeatureVecDestructorTag;
+
+struct AzMediaFeatureVecDestructorVariant_DefaultRust { AzMediaFeatureVecDestructorTag tag; };
+typedef struct AzMediaFeatureVecDestructorVariant_DefaultRust AzMediaFeatureVecDestructorVariant_DefaultRust;
+struct AzMediaFeatureVecDestructorVariant_NoDestructor { AzMediaFeatureVecDestructorTag tag; };
+typedef struct AzMediaFeatureVecDestructorVariant_NoDestructor AzMediaFeatureVecDestructorVariant_NoDestructor;
+struct AzMediaFeatureVecDestructorVariant_External { AzMediaFeatureVecDestructorTag tag; AzMediaFeatureVecDestructorType payload; };
+typedef struct AzMediaFeatureVecDestructorVariant_External AzMediaFeatureVecDestructorVariant_External;
+union AzMediaFeatureVecDestructor {
+    AzMediaFeatureVecDestructorVariant_DefaultRust DefaultRust;
+    AzMediaFeatureVecDestructorVariant_NoDestructor NoDestructor;
+    AzMediaFeatureVecDestructorVariant_External External;
+};
+typedef union AzMediaFeatureVecDestructor AzMediaFeatureVecDestructor;
+
+enum AzMediaConditionVecDestructorTag {
+   AzMediaConditionVecDestructorTag_DefaultRust,
+   AzMediaConditionVecDestructorTag_NoDestructor,
+   AzMediaConditionVecDestructorTag_External,
+};
+typedef enum AzMediaConditionVecDestructorTag AzMediaConditionVecDestructorTag;
+
+struct AzMediaConditionVecDestructorVariant_DefaultRust { AzMediaConditionVecDestructorTag tag; };
+typedef struct AzMediaConditionVecDestructorVariant_DefaultRust AzMediaConditionVecDestructorVariant_DefaultRust;
+struct AzMediaConditionVecDestructorVariant_NoDestructor { AzMediaConditionVecDestructorTag tag; };
+typedef struct AzMediaConditionVecDestructorVariant_NoDestructor AzMediaConditionVecDestructorVariant_NoDestructor;
+struct AzMediaConditionVecDestructorVariant_External { AzMediaConditionVecDestructorTag tag; AzMediaConditionVecDestructorType payload; };
+typedef struct AzMediaConditionVecDestructorVariant_External AzMediaConditionVecDestructorVariant_External;
+union AzMediaConditionVecDestructor {
+    AzMediaConditionVecDestructorVariant_DefaultRust DefaultRust;
+    AzMediaConditionVecDestructorVariant_NoDestructor NoDestructor;
+    AzMediaConditionVecDestructorVariant_External External;
+};
+typedef union AzMediaConditionVecDestructor AzMediaConditionVecDestructor;
+
 enum AzCssPropertyVecDestructorTag {
    AzCssPropertyVecDestructorTag_DefaultRust,
    AzCssPropertyVecDestructorTag_NoDestructor,
@@ -3884,6 +4174,12 @@ union AzAcceleratorKey {
 };
 typedef union AzAcceleratorKey AzAcceleratorKey;
 
+struct AzAccelerator {
+    AzModifiers modifiers;
+    AzVirtualKeyCode key;
+};
+typedef struct AzAccelerator AzAccelerator;
+
 struct AzWindowFlags {
     AzWindowFrame frame;
     bool  is_about_to_close;
@@ -4123,6 +4419,20 @@ struct AzPixelValue {
 };
 typedef struct AzPixelValue AzPixelValue;
 
+struct AzCalcLength {
+    AzFloatValue px;
+    AzFloatValue pt;
+    AzFloatValue em;
+    AzFloatValue percent;
+};
+typedef struct AzCalcLength AzCalcLength;
+
+struct AzCalcLengthBoxed {
+    bool  run_destructor;
+    AzCalcLength value;
+};
+typedef struct AzCalcLengthBoxed AzCalcLengthBoxed;
+
 struct AzPixelValueNoPercent {
     AzPixelValue inner;
 };
@@ -4195,6 +4505,22 @@ struct AzLayoutBottom {
 };
 typedef struct AzLayoutBottom AzLayoutBottom;
 
+struct AzGridPlacement {
+    AzGridLine start;
+    AzGridLine end;
+};
+typedef struct AzGridPlacement AzGridPlacement;
+
+struct AzLayoutGridColumn {
+    AzGridPlacement inner;
+};
+typedef struct AzLayoutGridColumn AzLayoutGridColumn;
+
+struct AzLayoutGridRow {
+    AzGridPlacement inner;
+};
+typedef struct AzLayoutGridRow AzLayoutGridRow;
+
 struct AzLayoutFlexGrow {
     AzFloatValue inner;
 };
@@ -4596,16 +4922,141 @@ struct AzStyleTransformSkew2D {
 };
 typedef struct AzStyleTransformSkew2D AzStyleTransformSkew2D;
 
+struct AzStyleTransitionDuration {
+    AzFloatValue inner;
+};
+typedef struct AzStyleTransitionDuration AzStyleTransitionDuration;
+
+struct AzStyleTransitionDelay {
+    AzFloatValue inner;
+};
+typedef struct AzStyleTransitionDelay AzStyleTransitionDelay;
+
+struct AzStyleCubicBezier {
+    AzFloatValue x1;
+    AzFloatValue y1;
+    AzFloatValue x2;
+    AzFloatValue y2;
+};
+typedef struct AzStyleCubicBezier AzStyleCubicBezier;
+
+struct AzStyleSteps {
+    uint32_t count;
+    AzStepPosition position;
+};
+typedef struct AzStyleSteps AzStyleSteps;
+
+enum AzStyleTransitionTimingFunctionTag {
+   AzStyleTransitionTimingFunctionTag_Linear,
+   AzStyleTransitionTimingFunctionTag_Ease,
+   AzStyleTransitionTimingFunctionTag_EaseIn,
+   AzStyleTransitionTimingFunctionTag_EaseOut,
+   AzStyleTransitionTimingFunctionTag_EaseInOut,
+   AzStyleTransitionTimingFunctionTag_CubicBezier,
+   AzStyleTransitionTimingFunctionTag_Steps,
+};
+typedef enum AzStyleTransitionTimingFunctionTag AzStyleTransitionTimingFunctionTag;
+
+struct AzStyleTransitionTimingFunctionVariant_Linear { AzStyleTransitionTimingFunctionTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVariant_Linear AzStyleTransitionTimingFunctionVariant_Linear;
+struct AzStyleTransitionTimingFunctionVariant_Ease { AzStyleTransitionTimingFunctionTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVariant_Ease AzStyleTransitionTimingFunctionVariant_Ease;
+struct AzStyleTransitionTimingFunctionVariant_EaseIn { AzStyleTransitionTimingFunctionTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVariant_EaseIn AzStyleTransitionTimingFunctionVariant_EaseIn;
+struct AzStyleTransitionTimingFunctionVariant_EaseOut { AzStyleTransitionTimingFunctionTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVariant_EaseOut AzStyleTransitionTimingFunctionVariant_EaseOut;
+struct AzStyleTransitionTimingFunctionVariant_EaseInOut { AzStyleTransitionTimingFunctionTag tag; };
+typedef struct AzStyleTransitionTimingFunctionVariant_EaseInOut AzStyleTransitionTimingFunctionVariant_EaseInOut;
+struct AzStyleTransitionTimingFunctionVariant_CubicBezier { AzStyleTransitionTimingFunctionTag tag; AzStyleCubicBezier payload; };
+typedef struct AzStyleTransitionTimingFunctionVariant_CubicBezier AzStyleTransitionTimingFunctionVariant_CubicBezier;
+struct AzStyleTransitionTimingFunctionVariant_Steps { AzStyleTransitionTimingFunctionTag tag; AzStyleSteps payload; };
+typedef struct AzStyleTransitionTimingFunctionVariant_Steps AzStyleTransitionTimingFunctionVariant_Steps;
+union AzStyleTransitionTimingFunction {
+    AzStyleTransitionTimingFunctionVariant_Linear Linear;
+    AzStyleTransitionTimingFunctionVariant_Ease Ease;
+    AzStyleTransitionTimingFunctionVariant_EaseIn EaseIn;
+    AzStyleTransitionTimingFunctionVariant_EaseOut EaseOut;
+    AzStyleTransitionTimingFunctionVariant_EaseInOut EaseInOut;
+    AzStyleTransitionTimingFunctionVariant_CubicBezier CubicBezier;
+    AzStyleTransitionTimingFunctionVariant_Steps Steps;
+};
+typedef union AzStyleTransitionTimingFunction AzStyleTransitionTimingFunction;
+
+enum AzStyleVerticalAlignTag {
+   AzStyleVerticalAlignTag_Baseline,
+   AzStyleVerticalAlignTag_Top,
+   AzStyleVerticalAlignTag_Middle,
+   AzStyleVerticalAlignTag_Bottom,
+   AzStyleVerticalAlignTag_TextTop,
+   AzStyleVerticalAlignTag_TextBottom,
+   AzStyleVerticalAlignTag_Sub,
+   AzStyleVerticalAlignTag_Super,
+   AzStyleVerticalAlignTag_Exact,
+};
+typedef enum AzStyleVerticalAlignTag AzStyleVerticalAlignTag;
+
+struct AzStyleVerticalAlignVariant_Baseline { AzStyleVerticalAlignTag tag; };
+typedef struct AzStyleVerticalAlignVariant_Baseline AzStyleVerticalAlignVariant_Baseline;
+struct AzStyleVerticalAlignVariant_Top { AzStyleVerticalAlignTag tag; };
+typedef struct AzStyleVerticalAlignVariant_Top AzStyleVerticalAlignVariant_Top;
+struct AzStyleVerticalAlignVariant_Middle { AzStyleVerticalAlignTag tag; };
+typedef struct AzStyleVerticalAlignVariant_Middle AzStyleVerticalAlignVariant_Middle;
+struct AzStyleVerticalAlignVariant_Bottom { AzStyleVerticalAlignTag tag; };
+typedef struct AzStyleVerticalAlignVariant_Bottom AzStyleVerticalAlignVariant_Bottom;
+struct AzStyleVerticalAlignVariant_TextTop { AzStyleVerticalAlignTag tag; };
+typedef struct AzStyleVerticalAlignVariant_TextTop AzStyleVerticalAlignVariant_TextTop;
+struct AzStyleVerticalAlignVariant_TextBottom { AzStyleVerticalAlignTag tag; };
+typedef struct AzStyleVerticalAlignVariant_TextBottom AzStyleVerticalAlignVariant_TextBottom;
+struct AzStyleVerticalAlignVariant_Sub { AzStyleVerticalAlignTag tag; };
+typedef struct AzStyleVerticalAlignVariant_Sub AzStyleVerticalAlignVariant_Sub;
+struct AzStyleVerticalAlignVariant_Super { AzStyleVerticalAlignTag tag; };
+typedef struct AzStyleVerticalAlignVariant_Super AzStyleVerticalAlignVariant_Super;
+struct AzStyleVerticalAlignVariant_Exact { AzStyleVerticalAlignTag tag; AzPixelValue payload; };
+typedef struct AzStyleVerticalAlignVariant_Exact AzStyleVerticalAlignVariant_Exact;
+union AzStyleVerticalAlign {
+    AzStyleVerticalAlignVariant_Baseline Baseline;
+    AzStyleVerticalAlignVariant_Top Top;
+    AzStyleVerticalAlignVariant_Middle Middle;
+    AzStyleVerticalAlignVariant_Bottom Bottom;
+    AzStyleVerticalAlignVariant_TextTop TextTop;
+    AzStyleVerticalAlignVariant_TextBottom TextBottom;
+    AzStyleVerticalAlignVariant_Sub Sub;
+    AzStyleVerticalAlignVariant_Super Super;
+    AzStyleVerticalAlignVariant_Exact Exact;
+};
+typedef union AzStyleVerticalAlign AzStyleVerticalAlign;
+
 struct AzStyleTextColor {
     AzColorU inner;
 };
 typedef struct AzStyleTextColor AzStyleTextColor;
 
+struct AzStyleCaretColor {
+    AzColorU inner;
+};
+typedef struct AzStyleCaretColor AzStyleCaretColor;
+
+struct AzStyleSelectionColor {
+    AzColorU inner;
+};
+typedef struct AzStyleSelectionColor AzStyleSelectionColor;
+
+struct AzStyleSelectionBackground {
+    AzColorU inner;
+};
+typedef struct AzStyleSelectionBackground AzStyleSelectionBackground;
+
 struct AzStyleWordSpacing {
     AzPixelValue inner;
 };
 typedef struct AzStyleWordSpacing AzStyleWordSpacing;
 
+struct AzStyleBoxShadowBoxed {
+    bool  run_destructor;
+    AzStyleBoxShadow value;
+};
+typedef struct AzStyleBoxShadowBoxed AzStyleBoxShadowBoxed;
+
 enum AzStyleBoxShadowValueTag {
    AzStyleBoxShadowValueTag_Auto,
    AzStyleBoxShadowValueTag_None,
@@ -4623,7 +5074,7 @@ struct AzStyleBoxShadowValueVariant_Inherit { AzStyleBoxShadowValueTag tag; };
 typedef struct AzStyleBoxShadowValueVariant_Inherit AzStyleBoxShadowValueVariant_Inherit;
 struct AzStyleBoxShadowValueVariant_Initial { AzStyleBoxShadowValueTag tag; };
 typedef struct AzStyleBoxShadowValueVariant_Initial AzStyleBoxShadowValueVariant_Initial;
-struct AzStyleBoxShadowValueVariant_Exact { AzStyleBoxShadowValueTag tag; AzStyleBoxShadow payload; };
+struct AzStyleBoxShadowValueVariant_Exact { AzStyleBoxShadowValueTag tag; AzStyleBoxShadowBoxed* payload; };
 typedef struct AzStyleBoxShadowValueVariant_Exact AzStyleBoxShadowValueVariant_Exact;
 union AzStyleBoxShadowValue {
     AzStyleBoxShadowValueVariant_Auto Auto;
@@ -4662,6 +5113,62 @@ union AzLayoutAlignContentValue {
 };
 typedef union AzLayoutAlignContentValue AzLayoutAlignContentValue;
 
+enum AzLayoutGridColumnValueTag {
+   AzLayoutGridColumnValueTag_Auto,
+   AzLayoutGridColumnValueTag_None,
+   AzLayoutGridColumnValueTag_Inherit,
+   AzLayoutGridColumnValueTag_Initial,
+   AzLayoutGridColumnValueTag_Exact,
+};
+typedef enum AzLayoutGridColumnValueTag AzLayoutGridColumnValueTag;
+
+struct AzLayoutGridColumnValueVariant_Auto { AzLayoutGridColumnValueTag tag; };
+typedef struct AzLayoutGridColumnValueVariant_Auto AzLayoutGridColumnValueVariant_Auto;
+struct AzLayoutGridColumnValueVariant_None { AzLayoutGridColumnValueTag tag; };
+typedef struct AzLayoutGridColumnValueVariant_None AzLayoutGridColumnValueVariant_None;
+struct AzLayoutGridColumnValueVariant_Inherit { AzLayoutGridColumnValueTag tag; };
+typedef struct AzLayoutGridColumnValueVariant_Inherit AzLayoutGridColumnValueVariant_Inherit;
+struct AzLayoutGridColumnValueVariant_Initial { AzLayoutGridColumnValueTag tag; };
+typedef struct AzLayoutGridColumnValueVariant_Initial AzLayoutGridColumnValueVariant_Initial;
+struct AzLayoutGridColumnValueVariant_Exact { AzLayoutGridColumnValueTag tag; AzLayoutGridColumn payload; };
+typedef struct AzLayoutGridColumnValueVariant_Exact AzLayoutGridColumnValueVariant_Exact;
+union AzLayoutGridColumnValue {
+    AzLayoutGridColumnValueVariant_Auto Auto;
+    AzLayoutGridColumnValueVariant_None None;
+    AzLayoutGridColumnValueVariant_Inherit Inherit;
+    AzLayoutGridColumnValueVariant_Initial Initial;
+    AzLayoutGridColumnValueVariant_Exact Exact;
+};
+typedef union AzLayoutGridColumnValue AzLayoutGridColumnValue;
+
+enum AzLayoutGridRowValueTag {
+   AzLayoutGridRowValueTag_Auto,
+   AzLayoutGridRowValueTag_None,
+   AzLayoutGridRowValueTag_Inherit,
+   AzLayoutGridRowValueTag_Initial,
+   AzLayoutGridRowValueTag_Exact,
+};
+typedef enum AzLayoutGridRowValueTag AzLayoutGridRowValueTag;
+
+struct AzLayoutGridRowValueVariant_Auto { AzLayoutGridRowValueTag tag; };
+typedef struct AzLayoutGridRowValueVariant_Auto AzLayoutGridRowValueVariant_Auto;
+struct AzLayoutGridRowValueVariant_None { AzLayoutGridRowValueTag tag; };
+typedef struct AzLayoutGridRowValueVariant_None AzLayoutGridRowValueVariant_None;
+struct AzLayoutGridRowValueVariant_Inherit { AzLayoutGridRowValueTag tag; };
+typedef struct AzLayoutGridRowValueVariant_Inherit AzLayoutGridRowValueVariant_Inherit;
+struct AzLayoutGridRowValueVariant_Initial { AzLayoutGridRowValueTag tag; };
+typedef struct AzLayoutGridRowValueVariant_Initial AzLayoutGridRowValueVariant_Initial;
+struct AzLayoutGridRowValueVariant_Exact { AzLayoutGridRowValueTag tag; AzLayoutGridRow payload; };
+typedef struct AzLayoutGridRowValueVariant_Exact AzLayoutGridRowValueVariant_Exact;
+union AzLayoutGridRowValue {
+    AzLayoutGridRowValueVariant_Auto Auto;
+    AzLayoutGridRowValueVariant_None None;
+    AzLayoutGridRowValueVariant_Inherit Inherit;
+    AzLayoutGridRowValueVariant_Initial Initial;
+    AzLayoutGridRowValueVariant_Exact Exact;
+};
+typedef union AzLayoutGridRowValue AzLayoutGridRowValue;
+
 enum AzLayoutAlignItemsValueTag {
    AzLayoutAlignItemsValueTag_Auto,
    AzLayoutAlignItemsValueTag_None,
@@ -4696,6 +5203,7 @@ enum AzLayoutBottomValueTag {
    AzLayoutBottomValueTag_Inherit,
    AzLayoutBottomValueTag_Initial,
    AzLayoutBottomValueTag_Exact,
+   AzLayoutBottomValueTag_Calc,
 };
 typedef enum AzLayoutBottomValueTag AzLayoutBottomValueTag;
 
@@ -4709,12 +5217,15 @@ struct AzLayoutBottomValueVariant_Initial { AzLayoutBottomValueTag tag; };
 typedef struct AzLayoutBottomValueVariant_Initial AzLayoutBottomValueVariant_Initial;
 struct AzLayoutBottomValueVariant_Exact { AzLayoutBottomValueTag tag; AzLayoutBottom payload; };
 typedef struct AzLayoutBottomValueVariant_Exact AzLayoutBottomValueVariant_Exact;
+struct AzLayoutBottomValueVariant_Calc { AzLayoutBottomValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutBottomValueVariant_Calc AzLayoutBottomValueVariant_Calc;
 union AzLayoutBottomValue {
     AzLayoutBottomValueVariant_Auto Auto;
     AzLayoutBottomValueVariant_None None;
     AzLayoutBottomValueVariant_Inherit Inherit;
     AzLayoutBottomValueVariant_Initial Initial;
     AzLayoutBottomValueVariant_Exact Exact;
+    AzLayoutBottomValueVariant_Calc Calc;
 };
 typedef union AzLayoutBottomValue AzLayoutBottomValue;
 
@@ -4892,6 +5403,7 @@ enum AzLayoutHeightValueTag {
    AzLayoutHeightValueTag_Inherit,
    AzLayoutHeightValueTag_Initial,
    AzLayoutHeightValueTag_Exact,
+   AzLayoutHeightValueTag_Calc,
 };
 typedef enum AzLayoutHeightValueTag AzLayoutHeightValueTag;
 
@@ -4905,12 +5417,15 @@ struct AzLayoutHeightValueVariant_Initial { AzLayoutHeightValueTag tag; };
 typedef struct AzLayoutHeightValueVariant_Initial AzLayoutHeightValueVariant_Initial;
 struct AzLayoutHeightValueVariant_Exact { AzLayoutHeightValueTag tag; AzLayoutHeight payload; };
 typedef struct AzLayoutHeightValueVariant_Exact AzLayoutHeightValueVariant_Exact;
+struct AzLayoutHeightValueVariant_Calc { AzLayoutHeightValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutHeightValueVariant_Calc AzLayoutHeightValueVariant_Calc;
 union AzLayoutHeightValue {
     AzLayoutHeightValueVariant_Auto Auto;
     AzLayoutHeightValueVariant_None None;
     AzLayoutHeightValueVariant_Inherit Inherit;
     AzLayoutHeightValueVariant_Initial Initial;
     AzLayoutHeightValueVariant_Exact Exact;
+    AzLayoutHeightValueVariant_Calc Calc;
 };
 typedef union AzLayoutHeightValue AzLayoutHeightValue;
 
@@ -4948,6 +5463,7 @@ enum AzLayoutLeftValueTag {
    AzLayoutLeftValueTag_Inherit,
    AzLayoutLeftValueTag_Initial,
    AzLayoutLeftValueTag_Exact,
+   AzLayoutLeftValueTag_Calc,
 };
 typedef enum AzLayoutLeftValueTag AzLayoutLeftValueTag;
 
@@ -4961,12 +5477,15 @@ struct AzLayoutLeftValueVariant_Initial { AzLayoutLeftValueTag tag; };
 typedef struct AzLayoutLeftValueVariant_Initial AzLayoutLeftValueVariant_Initial;
 struct AzLayoutLeftValueVariant_Exact { AzLayoutLeftValueTag tag; AzLayoutLeft payload; };
 typedef struct AzLayoutLeftValueVariant_Exact AzLayoutLeftValueVariant_Exact;
+struct AzLayoutLeftValueVariant_Calc { AzLayoutLeftValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutLeftValueVariant_Calc AzLayoutLeftValueVariant_Calc;
 union AzLayoutLeftValue {
     AzLayoutLeftValueVariant_Auto Auto;
     AzLayoutLeftValueVariant_None None;
     AzLayoutLeftValueVariant_Inherit Inherit;
     AzLayoutLeftValueVariant_Initial Initial;
     AzLayoutLeftValueVariant_Exact Exact;
+    AzLayoutLeftValueVariant_Calc Calc;
 };
 typedef union AzLayoutLeftValue AzLayoutLeftValue;
 
@@ -4976,6 +5495,7 @@ enum AzLayoutMarginBottomValueTag {
    AzLayoutMarginBottomValueTag_Inherit,
    AzLayoutMarginBottomValueTag_Initial,
    AzLayoutMarginBottomValueTag_Exact,
+   AzLayoutMarginBottomValueTag_Calc,
 };
 typedef enum AzLayoutMarginBottomValueTag AzLayoutMarginBottomValueTag;
 
@@ -4989,12 +5509,15 @@ struct AzLayoutMarginBottomValueVariant_Initial { AzLayoutMarginBottomValueTag t
 typedef struct AzLayoutMarginBottomValueVariant_Initial AzLayoutMarginBottomValueVariant_Initial;
 struct AzLayoutMarginBottomValueVariant_Exact { AzLayoutMarginBottomValueTag tag; AzLayoutMarginBottom payload; };
 typedef struct AzLayoutMarginBottomValueVariant_Exact AzLayoutMarginBottomValueVariant_Exact;
+struct AzLayoutMarginBottomValueVariant_Calc { AzLayoutMarginBottomValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutMarginBottomValueVariant_Calc AzLayoutMarginBottomValueVariant_Calc;
 union AzLayoutMarginBottomValue {
     AzLayoutMarginBottomValueVariant_Auto Auto;
     AzLayoutMarginBottomValueVariant_None None;
     AzLayoutMarginBottomValueVariant_Inherit Inherit;
     AzLayoutMarginBottomValueVariant_Initial Initial;
     AzLayoutMarginBottomValueVariant_Exact Exact;
+    AzLayoutMarginBottomValueVariant_Calc Calc;
 };
 typedef union AzLayoutMarginBottomValue AzLayoutMarginBottomValue;
 
@@ -5004,6 +5527,7 @@ enum AzLayoutMarginLeftValueTag {
    AzLayoutMarginLeftValueTag_Inherit,
    AzLayoutMarginLeftValueTag_Initial,
    AzLayoutMarginLeftValueTag_Exact,
+   AzLayoutMarginLeftValueTag_Calc,
 };
 typedef enum AzLayoutMarginLeftValueTag AzLayoutMarginLeftValueTag;
 
@@ -5017,12 +5541,15 @@ struct AzLayoutMarginLeftValueVariant_Initial { AzLayoutMarginLeftValueTag tag;
 typedef struct AzLayoutMarginLeftValueVariant_Initial AzLayoutMarginLeftValueVariant_Initial;
 struct AzLayoutMarginLeftValueVariant_Exact { AzLayoutMarginLeftValueTag tag; AzLayoutMarginLeft payload; };
 typedef struct AzLayoutMarginLeftValueVariant_Exact AzLayoutMarginLeftValueVariant_Exact;
+struct AzLayoutMarginLeftValueVariant_Calc { AzLayoutMarginLeftValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutMarginLeftValueVariant_Calc AzLayoutMarginLeftValueVariant_Calc;
 union AzLayoutMarginLeftValue {
     AzLayoutMarginLeftValueVariant_Auto Auto;
     AzLayoutMarginLeftValueVariant_None None;
     AzLayoutMarginLeftValueVariant_Inherit Inherit;
     AzLayoutMarginLeftValueVariant_Initial Initial;
     AzLayoutMarginLeftValueVariant_Exact Exact;
+    AzLayoutMarginLeftValueVariant_Calc Calc;
 };
 typedef union AzLayoutMarginLeftValue AzLayoutMarginLeftValue;
 
@@ -5032,6 +5559,7 @@ enum AzLayoutMarginRightValueTag {
    AzLayoutMarginRightValueTag_Inherit,
    AzLayoutMarginRightValueTag_Initial,
    AzLayoutMarginRightValueTag_Exact,
+   AzLayoutMarginRightValueTag_Calc,
 };
 typedef enum AzLayoutMarginRightValueTag AzLayoutMarginRightValueTag;
 
@@ -5045,12 +5573,15 @@ struct AzLayoutMarginRightValueVariant_Initial { AzLayoutMarginRightValueTag tag
 typedef struct AzLayoutMarginRightValueVariant_Initial AzLayoutMarginRightValueVariant_Initial;
 struct AzLayoutMarginRightValueVariant_Exact { AzLayoutMarginRightValueTag tag; AzLayoutMarginRight payload; };
 typedef struct AzLayoutMarginRightValueVariant_Exact AzLayoutMarginRightValueVariant_Exact;
+struct AzLayoutMarginRightValueVariant_Calc { AzLayoutMarginRightValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutMarginRightValueVariant_Calc AzLayoutMarginRightValueVariant_Calc;
 union AzLayoutMarginRightValue {
     AzLayoutMarginRightValueVariant_Auto Auto;
     AzLayoutMarginRightValueVariant_None None;
     AzLayoutMarginRightValueVariant_Inherit Inherit;
     AzLayoutMarginRightValueVariant_Initial Initial;
     AzLayoutMarginRightValueVariant_Exact Exact;
+    AzLayoutMarginRightValueVariant_Calc Calc;
 };
 typedef union AzLayoutMarginRightValue AzLayoutMarginRightValue;
 
@@ -5060,6 +5591,7 @@ enum AzLayoutMarginTopValueTag {
    AzLayoutMarginTopValueTag_Inherit,
    AzLayoutMarginTopValueTag_Initial,
    AzLayoutMarginTopValueTag_Exact,
+   AzLayoutMarginTopValueTag_Calc,
 };
 typedef enum AzLayoutMarginTopValueTag AzLayoutMarginTopValueTag;
 
@@ -5073,12 +5605,15 @@ struct AzLayoutMarginTopValueVariant_Initial { AzLayoutMarginTopValueTag tag; };
 typedef struct AzLayoutMarginTopValueVariant_Initial AzLayoutMarginTopValueVariant_Initial;
 struct AzLayoutMarginTopValueVariant_Exact { AzLayoutMarginTopValueTag tag; AzLayoutMarginTop payload; };
 typedef struct AzLayoutMarginTopValueVariant_Exact AzLayoutMarginTopValueVariant_Exact;
+struct AzLayoutMarginTopValueVariant_Calc { AzLayoutMarginTopValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutMarginTopValueVariant_Calc AzLayoutMarginTopValueVariant_Calc;
 union AzLayoutMarginTopValue {
     AzLayoutMarginTopValueVariant_Auto Auto;
     AzLayoutMarginTopValueVariant_None None;
     AzLayoutMarginTopValueVariant_Inherit Inherit;
     AzLayoutMarginTopValueVariant_Initial Initial;
     AzLayoutMarginTopValueVariant_Exact Exact;
+    AzLayoutMarginTopValueVariant_Calc Calc;
 };
 typedef union AzLayoutMarginTopValue AzLayoutMarginTopValue;
 
@@ -5088,6 +5623,7 @@ enum AzLayoutMaxHeightValueTag {
    AzLayoutMaxHeightValueTag_Inherit,
    AzLayoutMaxHeightValueTag_Initial,
    AzLayoutMaxHeightValueTag_Exact,
+   AzLayoutMaxHeightValueTag_Calc,
 };
 typedef enum AzLayoutMaxHeightValueTag AzLayoutMaxHeightValueTag;
 
@@ -5101,12 +5637,15 @@ struct AzLayoutMaxHeightValueVariant_Initial { AzLayoutMaxHeightValueTag tag; };
 typedef struct AzLayoutMaxHeightValueVariant_Initial AzLayoutMaxHeightValueVariant_Initial;
 struct AzLayoutMaxHeightValueVariant_Exact { AzLayoutMaxHeightValueTag tag; AzLayoutMaxHeight payload; };
 typedef struct AzLayoutMaxHeightValueVariant_Exact AzLayoutMaxHeightValueVariant_Exact;
+struct AzLayoutMaxHeightValueVariant_Calc { AzLayoutMaxHeightValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutMaxHeightValueVariant_Calc AzLayoutMaxHeightValueVariant_Calc;
 union AzLayoutMaxHeightValue {
     AzLayoutMaxHeightValueVariant_Auto Auto;
     AzLayoutMaxHeightValueVariant_None None;
     AzLayoutMaxHeightValueVariant_Inherit Inherit;
     AzLayoutMaxHeightValueVariant_Initial Initial;
     AzLayoutMaxHeightValueVariant_Exact Exact;
+    AzLayoutMaxHeightValueVariant_Calc Calc;
 };
 typedef union AzLayoutMaxHeightValue AzLayoutMaxHeightValue;
 
@@ -5116,6 +5655,7 @@ enum AzLayoutMaxWidthValueTag {
    AzLayoutMaxWidthValueTag_Inherit,
    AzLayoutMaxWidthValueTag_Initial,
    AzLayoutMaxWidthValueTag_Exact,
+   AzLayoutMaxWidthValueTag_Calc,
 };
 typedef enum AzLayoutMaxWidthValueTag AzLayoutMaxWidthValueTag;
 
@@ -5129,12 +5669,15 @@ struct AzLayoutMaxWidthValueVariant_Initial { AzLayoutMaxWidthValueTag tag; };
 typedef struct AzLayoutMaxWidthValueVariant_Initial AzLayoutMaxWidthValueVariant_Initial;
 struct AzLayoutMaxWidthValueVariant_Exact { AzLayoutMaxWidthValueTag tag; AzLayoutMaxWidth payload; };
 typedef struct AzLayoutMaxWidthValueVariant_Exact AzLayoutMaxWidthValueVariant_Exact;
+struct AzLayoutMaxWidthValueVariant_Calc { AzLayoutMaxWidthValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutMaxWidthValueVariant_Calc AzLayoutMaxWidthValueVariant_Calc;
 union AzLayoutMaxWidthValue {
     AzLayoutMaxWidthValueVariant_Auto Auto;
     AzLayoutMaxWidthValueVariant_None None;
     AzLayoutMaxWidthValueVariant_Inherit Inherit;
     AzLayoutMaxWidthValueVariant_Initial Initial;
     AzLayoutMaxWidthValueVariant_Exact Exact;
+    AzLayoutMaxWidthValueVariant_Calc Calc;
 };
 typedef union AzLayoutMaxWidthValue AzLayoutMaxWidthValue;
 
@@ -5144,6 +5687,7 @@ enum AzLayoutMinHeightValueTag {
    AzLayoutMinHeightValueTag_Inherit,
    AzLayoutMinHeightValueTag_Initial,
    AzLayoutMinHeightValueTag_Exact,
+   AzLayoutMinHeightValueTag_Calc,
 };
 typedef enum AzLayoutMinHeightValueTag AzLayoutMinHeightValueTag;
 
@@ -5157,12 +5701,15 @@ struct AzLayoutMinHeightValueVariant_Initial { AzLayoutMinHeightValueTag tag; };
 typedef struct AzLayoutMinHeightValueVariant_Initial AzLayoutMinHeightValueVariant_Initial;
 struct AzLayoutMinHeightValueVariant_Exact { AzLayoutMinHeightValueTag tag; AzLayoutMinHeight payload; };
 typedef struct AzLayoutMinHeightValueVariant_Exact AzLayoutMinHeightValueVariant_Exact;
+struct AzLayoutMinHeightValueVariant_Calc { AzLayoutMinHeightValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutMinHeightValueVariant_Calc AzLayoutMinHeightValueVariant_Calc;
 union AzLayoutMinHeightValue {
     AzLayoutMinHeightValueVariant_Auto Auto;
     AzLayoutMinHeightValueVariant_None None;
     AzLayoutMinHeightValueVariant_Inherit Inherit;
     AzLayoutMinHeightValueVariant_Initial Initial;
     AzLayoutMinHeightValueVariant_Exact Exact;
+    AzLayoutMinHeightValueVariant_Calc Calc;
 };
 typedef union AzLayoutMinHeightValue AzLayoutMinHeightValue;
 
@@ -5172,6 +5719,7 @@ enum AzLayoutMinWidthValueTag {
    AzLayoutMinWidthValueTag_Inherit,
    AzLayoutMinWidthValueTag_Initial,
    AzLayoutMinWidthValueTag_Exact,
+   AzLayoutMinWidthValueTag_Calc,
 };
 typedef enum AzLayoutMinWidthValueTag AzLayoutMinWidthValueTag;
 
@@ -5185,12 +5733,15 @@ struct AzLayoutMinWidthValueVariant_Initial { AzLayoutMinWidthValueTag tag; };
 typedef struct AzLayoutMinWidthValueVariant_Initial AzLayoutMinWidthValueVariant_Initial;
 struct AzLayoutMinWidthValueVariant_Exact { AzLayoutMinWidthValueTag tag; AzLayoutMinWidth payload; };
 typedef struct AzLayoutMinWidthValueVariant_Exact AzLayoutMinWidthValueVariant_Exact;
+struct AzLayoutMinWidthValueVariant_Calc { AzLayoutMinWidthValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutMinWidthValueVariant_Calc AzLayoutMinWidthValueVariant_Calc;
 union AzLayoutMinWidthValue {
     AzLayoutMinWidthValueVariant_Auto Auto;
     AzLayoutMinWidthValueVariant_None None;
     AzLayoutMinWidthValueVariant_Inherit Inherit;
     AzLayoutMinWidthValueVariant_Initial Initial;
     AzLayoutMinWidthValueVariant_Exact Exact;
+    AzLayoutMinWidthValueVariant_Calc Calc;
 };
 typedef union AzLayoutMinWidthValue AzLayoutMinWidthValue;
 
@@ -5200,6 +5751,7 @@ enum AzLayoutPaddingBottomValueTag {
    AzLayoutPaddingBottomValueTag_Inherit,
    AzLayoutPaddingBottomValueTag_Initial,
    AzLayoutPaddingBottomValueTag_Exact,
+   AzLayoutPaddingBottomValueTag_Calc,
 };
 typedef enum AzLayoutPaddingBottomValueTag AzLayoutPaddingBottomValueTag;
 
@@ -5213,12 +5765,15 @@ struct AzLayoutPaddingBottomValueVariant_Initial { AzLayoutPaddingBottomValueTag
 typedef struct AzLayoutPaddingBottomValueVariant_Initial AzLayoutPaddingBottomValueVariant_Initial;
 struct AzLayoutPaddingBottomValueVariant_Exact { AzLayoutPaddingBottomValueTag tag; AzLayoutPaddingBottom payload; };
 typedef struct AzLayoutPaddingBottomValueVariant_Exact AzLayoutPaddingBottomValueVariant_Exact;
+struct AzLayoutPaddingBottomValueVariant_Calc { AzLayoutPaddingBottomValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutPaddingBottomValueVariant_Calc AzLayoutPaddingBottomValueVariant_Calc;
 union AzLayoutPaddingBottomValue {
     AzLayoutPaddingBottomValueVariant_Auto Auto;
     AzLayoutPaddingBottomValueVariant_None None;
     AzLayoutPaddingBottomValueVariant_Inherit Inherit;
     AzLayoutPaddingBottomValueVariant_Initial Initial;
     AzLayoutPaddingBottomValueVariant_Exact Exact;
+    AzLayoutPaddingBottomValueVariant_Calc Calc;
 };
 typedef union AzLayoutPaddingBottomValue AzLayoutPaddingBottomValue;
 
@@ -5228,6 +5783,7 @@ enum AzLayoutPaddingLeftValueTag {
    AzLayoutPaddingLeftValueTag_Inherit,
    AzLayoutPaddingLeftValueTag_Initial,
    AzLayoutPaddingLeftValueTag_Exact,
+   AzLayoutPaddingLeftValueTag_Calc,
 };
 typedef enum AzLayoutPaddingLeftValueTag AzLayoutPaddingLeftValueTag;
 
@@ -5241,12 +5797,15 @@ struct AzLayoutPaddingLeftValueVariant_Initial { AzLayoutPaddingLeftValueTag tag
 typedef struct AzLayoutPaddingLeftValueVariant_Initial AzLayoutPaddingLeftValueVariant_Initial;
 struct AzLayoutPaddingLeftValueVariant_Exact { AzLayoutPaddingLeftValueTag tag; AzLayoutPaddingLeft payload; };
 typedef struct AzLayoutPaddingLeftValueVariant_Exact AzLayoutPaddingLeftValueVariant_Exact;
+struct AzLayoutPaddingLeftValueVariant_Calc { AzLayoutPaddingLeftValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutPaddingLeftValueVariant_Calc AzLayoutPaddingLeftValueVariant_Calc;
 union AzLayoutPaddingLeftValue {
     AzLayoutPaddingLeftValueVariant_Auto Auto;
     AzLayoutPaddingLeftValueVariant_None None;
     AzLayoutPaddingLeftValueVariant_Inherit Inherit;
     AzLayoutPaddingLeftValueVariant_Initial Initial;
     AzLayoutPaddingLeftValueVariant_Exact Exact;
+    AzLayoutPaddingLeftValueVariant_Calc Calc;
 };
 typedef union AzLayoutPaddingLeftValue AzLayoutPaddingLeftValue;
 
@@ -5256,6 +5815,7 @@ enum AzLayoutPaddingRightValueTag {
    AzLayoutPaddingRightValueTag_Inherit,
    AzLayoutPaddingRightValueTag_Initial,
    AzLayoutPaddingRightValueTag_Exact,
+   AzLayoutPaddingRightValueTag_Calc,
 };
 typedef enum AzLayoutPaddingRightValueTag AzLayoutPaddingRightValueTag;
 
@@ -5269,12 +5829,15 @@ struct AzLayoutPaddingRightValueVariant_Initial { AzLayoutPaddingRightValueTag t
 typedef struct AzLayoutPaddingRightValueVariant_Initial AzLayoutPaddingRightValueVariant_Initial;
 struct AzLayoutPaddingRightValueVariant_Exact { AzLayoutPaddingRightValueTag tag; AzLayoutPaddingRight payload; };
 typedef struct AzLayoutPaddingRightValueVariant_Exact AzLayoutPaddingRightValueVariant_Exact;
+struct AzLayoutPaddingRightValueVariant_Calc { AzLayoutPaddingRightValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutPaddingRightValueVariant_Calc AzLayoutPaddingRightValueVariant_Calc;
 union AzLayoutPaddingRightValue {
     AzLayoutPaddingRightValueVariant_Auto Auto;
     AzLayoutPaddingRightValueVariant_None None;
     AzLayoutPaddingRightValueVariant_Inherit Inherit;
     AzLayoutPaddingRightValueVariant_Initial Initial;
     AzLayoutPaddingRightValueVariant_Exact Exact;
+    AzLayoutPaddingRightValueVariant_Calc Calc;
 };
 typedef union AzLayoutPaddingRightValue AzLayoutPaddingRightValue;
 
@@ -5284,6 +5847,7 @@ enum AzLayoutPaddingTopValueTag {
    AzLayoutPaddingTopValueTag_Inherit,
    AzLayoutPaddingTopValueTag_Initial,
    AzLayoutPaddingTopValueTag_Exact,
+   AzLayoutPaddingTopValueTag_Calc,
 };
 typedef enum AzLayoutPaddingTopValueTag AzLayoutPaddingTopValueTag;
 
@@ -5297,12 +5861,15 @@ struct AzLayoutPaddingTopValueVariant_Initial { AzLayoutPaddingTopValueTag tag;
 typedef struct AzLayoutPaddingTopValueVariant_Initial AzLayoutPaddingTopValueVariant_Initial;
 struct AzLayoutPaddingTopValueVariant_Exact { AzLayoutPaddingTopValueTag tag; AzLayoutPaddingTop payload; };
 typedef struct AzLayoutPaddingTopValueVariant_Exact AzLayoutPaddingTopValueVariant_Exact;
+struct AzLayoutPaddingTopValueVariant_Calc { AzLayoutPaddingTopValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutPaddingTopValueVariant_Calc AzLayoutPaddingTopValueVariant_Calc;
 union AzLayoutPaddingTopValue {
     AzLayoutPaddingTopValueVariant_Auto Auto;
     AzLayoutPaddingTopValueVariant_None None;
     AzLayoutPaddingTopValueVariant_Inherit Inherit;
     AzLayoutPaddingTopValueVariant_Initial Initial;
     AzLayoutPaddingTopValueVariant_Exact Exact;
+    AzLayoutPaddingTopValueVariant_Calc Calc;
 };
 typedef union AzLayoutPaddingTopValue AzLayoutPaddingTopValue;
 
@@ -5340,6 +5907,7 @@ enum AzLayoutRightValueTag {
    AzLayoutRightValueTag_Inherit,
    AzLayoutRightValueTag_Initial,
    AzLayoutRightValueTag_Exact,
+   AzLayoutRightValueTag_Calc,
 };
 typedef enum AzLayoutRightValueTag AzLayoutRightValueTag;
 
@@ -5353,12 +5921,15 @@ struct AzLayoutRightValueVariant_Initial { AzLayoutRightValueTag tag; };
 typedef struct AzLayoutRightValueVariant_Initial AzLayoutRightValueVariant_Initial;
 struct AzLayoutRightValueVariant_Exact { AzLayoutRightValueTag tag; AzLayoutRight payload; };
 typedef struct AzLayoutRightValueVariant_Exact AzLayoutRightValueVariant_Exact;
+struct AzLayoutRightValueVariant_Calc { AzLayoutRightValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutRightValueVariant_Calc AzLayoutRightValueVariant_Calc;
 union AzLayoutRightValue {
     AzLayoutRightValueVariant_Auto Auto;
     AzLayoutRightValueVariant_None None;
     AzLayoutRightValueVariant_Inherit Inherit;
     AzLayoutRightValueVariant_Initial Initial;
     AzLayoutRightValueVariant_Exact Exact;
+    AzLayoutRightValueVariant_Calc Calc;
 };
 typedef union AzLayoutRightValue AzLayoutRightValue;
 
@@ -5368,6 +5939,7 @@ enum AzLayoutTopValueTag {
    AzLayoutTopValueTag_Inherit,
    AzLayoutTopValueTag_Initial,
    AzLayoutTopValueTag_Exact,
+   AzLayoutTopValueTag_Calc,
 };
 typedef enum AzLayoutTopValueTag AzLayoutTopValueTag;
 
@@ -5381,12 +5953,15 @@ struct AzLayoutTopValueVariant_Initial { AzLayoutTopValueTag tag; };
 typedef struct AzLayoutTopValueVariant_Initial AzLayoutTopValueVariant_Initial;
 struct AzLayoutTopValueVariant_Exact { AzLayoutTopValueTag tag; AzLayoutTop payload; };
 typedef struct AzLayoutTopValueVariant_Exact AzLayoutTopValueVariant_Exact;
+struct AzLayoutTopValueVariant_Calc { AzLayoutTopValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutTopValueVariant_Calc AzLayoutTopValueVariant_Calc;
 union AzLayoutTopValue {
     AzLayoutTopValueVariant_Auto Auto;
     AzLayoutTopValueVariant_None None;
     AzLayoutTopValueVariant_Inherit Inherit;
     AzLayoutTopValueVariant_Initial Initial;
     AzLayoutTopValueVariant_Exact Exact;
+    AzLayoutTopValueVariant_Calc Calc;
 };
 typedef union AzLayoutTopValue AzLayoutTopValue;
 
@@ -5396,6 +5971,7 @@ enum AzLayoutWidthValueTag {
    AzLayoutWidthValueTag_Inherit,
    AzLayoutWidthValueTag_Initial,
    AzLayoutWidthValueTag_Exact,
+   AzLayoutWidthValueTag_Calc,
 };
 typedef enum AzLayoutWidthValueTag AzLayoutWidthValueTag;
 
@@ -5409,12 +5985,15 @@ struct AzLayoutWidthValueVariant_Initial { AzLayoutWidthValueTag tag; };
 typedef struct AzLayoutWidthValueVariant_Initial AzLayoutWidthValueVariant_Initial;
 struct AzLayoutWidthValueVariant_Exact { AzLayoutWidthValueTag tag; AzLayoutWidth payload; };
 typedef struct AzLayoutWidthValueVariant_Exact AzLayoutWidthValueVariant_Exact;
+struct AzLayoutWidthValueVariant_Calc { AzLayoutWidthValueTag tag; AzCalcLengthBoxed* payload; };
+typedef struct AzLayoutWidthValueVariant_Calc AzLayoutWidthValueVariant_Calc;
 union AzLayoutWidthValue {
     AzLayoutWidthValueVariant_Auto Auto;
     AzLayoutWidthValueVariant_None None;
     AzLayoutWidthValueVariant_Inherit Inherit;
     AzLayoutWidthValueVariant_Initial Initial;
     AzLayoutWidthValueVariant_Exact Exact;
+    AzLayoutWidthValueVariant_Calc Calc;
 };
 typedef union AzLayoutWidthValue AzLayoutWidthValue;
 
@@ -6090,6 +6669,242 @@ union AzStyleTextAlignValue {
 };
 typedef union AzStyleTextAlignValue AzStyleTextAlignValue;
 
+enum AzStyleDirectionValueTag {
+   AzStyleDirectionValueTag_Auto,
+   AzStyleDirectionValueTag_None,
+   AzStyleDirectionValueTag_Inherit,
+   AzStyleDirectionValueTag_Initial,
+   AzStyleDirectionValueTag_Exact,
+};
+typedef enum AzStyleDirectionValueTag AzStyleDirectionValueTag;
+
+struct AzStyleDirectionValueVariant_Auto { AzStyleDirectionValueTag tag; };
+typedef struct AzStyleDirectionValueVariant_Auto AzStyleDirectionValueVariant_Auto;
+struct AzStyleDirectionValueVariant_None { AzStyleDirectionValueTag tag; };
+typedef struct AzStyleDirectionValueVariant_None AzStyleDirectionValueVariant_None;
+struct AzStyleDirectionValueVariant_Inherit { AzStyleDirectionValueTag tag; };
+typedef struct AzStyleDirectionValueVariant_Inherit AzStyleDirectionValueVariant_Inherit;
+struct AzStyleDirectionValueVariant_Initial { AzStyleDirectionValueTag tag; };
+typedef struct AzStyleDirectionValueVariant_Initial AzStyleDirectionValueVariant_Initial;
+struct AzStyleDirectionValueVariant_Exact { AzStyleDirectionValueTag tag; AzStyleDirection payload; };
+typedef struct AzStyleDirectionValueVariant_Exact AzStyleDirectionValueVariant_Exact;
+union AzStyleDirectionValue {
+    AzStyleDirectionValueVariant_Auto Auto;
+    AzStyleDirectionValueVariant_None None;
+    AzStyleDirectionValueVariant_Inherit Inherit;
+    AzStyleDirectionValueVariant_Initial Initial;
+    AzStyleDirectionValueVariant_Exact Exact;
+};
+typedef union AzStyleDirectionValue AzStyleDirectionValue;
+
+enum AzStyleTransitionDurationValueTag {
+   AzStyleTransitionDurationValueTag_Auto,
+   AzStyleTransitionDurationValueTag_None,
+   AzStyleTransitionDurationValueTag_Inherit,
+   AzStyleTransitionDurationValueTag_Initial,
+   AzStyleTransitionDurationValueTag_Exact,
+};
+typedef enum AzStyleTransitionDurationValueTag AzStyleTransitionDurationValueTag;
+
+struct AzStyleTransitionDurationValueVariant_Auto { AzStyleTransitionDurationValueTag tag; };
+typedef struct AzStyleTransitionDurationValueVariant_Auto AzStyleTransitionDurationValueVariant_Auto;
+struct AzStyleTransitionDurationValueVariant_None { AzStyleTransitionDurationValueTag tag; };
+typedef struct AzStyleTransitionDurationValueVariant_None AzStyleTransitionDurationValueVariant_None;
+struct AzStyleTransitionDurationValueVariant_Inherit { AzStyleTransitionDurationValueTag tag; };
+typedef struct AzStyleTransitionDurationValueVariant_Inherit AzStyleTransitionDurationValueVariant_Inherit;
+struct AzStyleTransitionDurationValueVariant_Initial { AzStyleTransitionDurationValueTag tag; };
+typedef struct AzStyleTransitionDurationValueVariant_Initial AzStyleTransitionDurationValueVariant_Initial;
+struct AzStyleTransitionDurationValueVariant_Exact { AzStyleTransitionDurationValueTag tag; AzStyleTransitionDuration payload; };
+typedef struct AzStyleTransitionDurationValueVariant_Exact AzStyleTransitionDurationValueVariant_Exact;
+union AzStyleTransitionDurationValue {
+    AzStyleTransitionDurationValueVariant_Auto Auto;
+    AzStyleTransitionDurationValueVariant_None None;
+    AzStyleTransitionDurationValueVariant_Inherit Inherit;
+    AzStyleTransitionDurationValueVariant_Initial Initial;
+    AzStyleTransitionDurationValueVariant_Exact Exact;
+};
+typedef union AzStyleTransitionDurationValue AzStyleTransitionDurationValue;
+
+struct AzStyleTransitionTimingFunctionBoxed {
+    bool  run_destructor;
+    AzStyleTransitionTimingFunction value;
+};
+typedef struct AzStyleTransitionTimingFunctionBoxed AzStyleTransitionTimingFunctionBoxed;
+
+enum AzStyleTransitionTimingFunctionValueTag {
+   AzStyleTransitionTimingFunctionValueTag_Auto,
+   AzStyleTransitionTimingFunctionValueTag_None,
+   AzStyleTransitionTimingFunctionValueTag_Inherit,
+   AzStyleTransitionTimingFunctionValueTag_Initial,
+   AzStyleTransitionTimingFunctionValueTag_Exact,
+};
+typedef enum AzStyleTransitionTimingFunctionValueTag AzStyleTransitionTimingFunctionValueTag;
+
+struct AzStyleTransitionTimingFunctionValueVariant_Auto { AzStyleTransitionTimingFunctionValueTag tag; };
+typedef struct AzStyleTransitionTimingFunctionValueVariant_Auto AzStyleTransitionTimingFunctionValueVariant_Auto;
+struct AzStyleTransitionTimingFunctionValueVariant_None { AzStyleTransitionTimingFunctionValueTag tag; };
+typedef struct AzStyleTransitionTimingFunctionValueVariant_None AzStyleTransitionTimingFunctionValueVariant_None;
+struct AzStyleTransitionTimingFunctionValueVariant_Inherit { AzStyleTransitionTimingFunctionValueTag tag; };
+typedef struct AzStyleTransitionTimingFunctionValueVariant_Inherit AzStyleTransitionTimingFunctionValueVariant_Inherit;
+struct AzStyleTransitionTimingFunctionValueVariant_Initial { AzStyleTransitionTimingFunctionValueTag tag; };
+typedef struct AzStyleTransitionTimingFunctionValueVariant_Initial AzStyleTransitionTimingFunctionValueVariant_Initial;
+struct AzStyleTransitionTimingFunctionValueVariant_Exact { AzStyleTransitionTimingFunctionValueTag tag; AzStyleTransitionTimingFunctionBoxed* payload; };
+typedef struct AzStyleTransitionTimingFunctionValueVariant_Exact AzStyleTransitionTimingFunctionValueVariant_Exact;
+union AzStyleTransitionTimingFunctionValue {
+    AzStyleTransitionTimingFunctionValueVariant_Auto Auto;
+    AzStyleTransitionTimingFunctionValueVariant_None None;
+    AzStyleTransitionTimingFunctionValueVariant_Inherit Inherit;
+    AzStyleTransitionTimingFunctionValueVariant_Initial Initial;
+    AzStyleTransitionTimingFunctionValueVariant_Exact Exact;
+};
+typedef union AzStyleTransitionTimingFunctionValue AzStyleTransitionTimingFunctionValue;
+
+enum AzStyleTransitionDelayValueTag {
+   AzStyleTransitionDelayValueTag_Auto,
+   AzStyleTransitionDelayValueTag_None,
+   AzStyleTransitionDelayValueTag_Inherit,
+   AzStyleTransitionDelayValueTag_Initial,
+   AzStyleTransitionDelayValueTag_Exact,
+};
+typedef enum AzStyleTransitionDelayValueTag AzStyleTransitionDelayValueTag;
+
+struct AzStyleTransitionDelayValueVariant_Auto { AzStyleTransitionDelayValueTag tag; };
+typedef struct AzStyleTransitionDelayValueVariant_Auto AzStyleTransitionDelayValueVariant_Auto;
+struct AzStyleTransitionDelayValueVariant_None { AzStyleTransitionDelayValueTag tag; };
+typedef struct AzStyleTransitionDelayValueVariant_None AzStyleTransitionDelayValueVariant_None;
+struct AzStyleTransitionDelayValueVariant_Inherit { AzStyleTransitionDelayValueTag tag; };
+typedef struct AzStyleTransitionDelayValueVariant_Inherit AzStyleTransitionDelayValueVariant_Inherit;
+struct AzStyleTransitionDelayValueVariant_Initial { AzStyleTransitionDelayValueTag tag; };
+typedef struct AzStyleTransitionDelayValueVariant_Initial AzStyleTransitionDelayValueVariant_Initial;
+struct AzStyleTransitionDelayValueVariant_Exact { AzStyleTransitionDelayValueTag tag; AzStyleTransitionDelay payload; };
+typedef struct AzStyleTransitionDelayValueVariant_Exact AzStyleTransitionDelayValueVariant_Exact;
+union AzStyleTransitionDelayValue {
+    AzStyleTransitionDelayValueVariant_Auto Auto;
+    AzStyleTransitionDelayValueVariant_None None;
+    AzStyleTransitionDelayValueVariant_Inherit Inherit;
+    AzStyleTransitionDelayValueVariant_Initial Initial;
+    AzStyleTransitionDelayValueVariant_Exact Exact;
+};
+typedef union AzStyleTransitionDelayValue AzStyleTransitionDelayValue;
+
+enum AzStyleCaretColorValueTag {
+   AzStyleCaretColorValueTag_Auto,
+   AzStyleCaretColorValueTag_None,
+   AzStyleCaretColorValueTag_Inherit,
+   AzStyleCaretColorValueTag_Initial,
+   AzStyleCaretColorValueTag_Exact,
+};
+typedef enum AzStyleCaretColorValueTag AzStyleCaretColorValueTag;
+
+struct AzStyleCaretColorValueVariant_Auto { AzStyleCaretColorValueTag tag; };
+typedef struct AzStyleCaretColorValueVariant_Auto AzStyleCaretColorValueVariant_Auto;
+struct AzStyleCaretColorValueVariant_None { AzStyleCaretColorValueTag tag; };
+typedef struct AzStyleCaretColorValueVariant_None AzStyleCaretColorValueVariant_None;
+struct AzStyleCaretColorValueVariant_Inherit { AzStyleCaretColorValueTag tag; };
+typedef struct AzStyleCaretColorValueVariant_Inherit AzStyleCaretColorValueVariant_Inherit;
+struct AzStyleCaretColorValueVariant_Initial { AzStyleCaretColorValueTag tag; };
+typedef struct AzStyleCaretColorValueVariant_Initial AzStyleCaretColorValueVariant_Initial;
+struct AzStyleCaretColorValueVariant_Exact { AzStyleCaretColorValueTag tag; AzStyleCaretColor payload; };
+typedef struct AzStyleCaretColorValueVariant_Exact AzStyleCaretColorValueVariant_Exact;
+union AzStyleCaretColorValue {
+    AzStyleCaretColorValueVariant_Auto Auto;
+    AzStyleCaretColorValueVariant_None None;
+    AzStyleCaretColorValueVariant_Inherit Inherit;
+    AzStyleCaretColorValueVariant_Initial Initial;
+    AzStyleCaretColorValueVariant_Exact Exact;
+};
+typedef union AzStyleCaretColorValue AzStyleCaretColorValue;
+
+enum AzStyleSelectionColorValueTag {
+   AzStyleSelectionColorValueTag_Auto,
+   AzStyleSelectionColorValueTag_None,
+   AzStyleSelectionColorValueTag_Inherit,
+   AzStyleSelectionColorValueTag_Initial,
+   AzStyleSelectionColorValueTag_Exact,
+};
+typedef enum AzStyleSelectionColorValueTag AzStyleSelectionColorValueTag;
+
+struct AzStyleSelectionColorValueVariant_Auto { AzStyleSelectionColorValueTag tag; };
+typedef struct AzStyleSelectionColorValueVariant_Auto AzStyleSelectionColorValueVariant_Auto;
+struct AzStyleSelectionColorValueVariant_None { AzStyleSelectionColorValueTag tag; };
+typedef struct AzStyleSelectionColorValueVariant_None AzStyleSelectionColorValueVariant_None;
+struct AzStyleSelectionColorValueVariant_Inherit { AzStyleSelectionColorValueTag tag; };
+typedef struct AzStyleSelectionColorValueVariant_Inherit AzStyleSelectionColorValueVariant_Inherit;
+struct AzStyleSelectionColorValueVariant_Initial { AzStyleSelectionColorValueTag tag; };
+typedef struct AzStyleSelectionColorValueVariant_Initial AzStyleSelectionColorValueVariant_Initial;
+struct AzStyleSelectionColorValueVariant_Exact { AzStyleSelectionColorValueTag tag; AzStyleSelectionColor payload; };
+typedef struct AzStyleSelectionColorValueVariant_Exact AzStyleSelectionColorValueVariant_Exact;
+union AzStyleSelectionColorValue {
+    AzStyleSelectionColorValueVariant_Auto Auto;
+    AzStyleSelectionColorValueVariant_None None;
+    AzStyleSelectionColorValueVariant_Inherit Inherit;
+    AzStyleSelectionColorValueVariant_Initial Initial;
+    AzStyleSelectionColorValueVariant_Exact Exact;
+};
+typedef union AzStyleSelectionColorValue AzStyleSelectionColorValue;
+
+enum AzStyleSelectionBackgroundValueTag {
+   AzStyleSelectionBackgroundValueTag_Auto,
+   AzStyleSelectionBackgroundValueTag_None,
+   AzStyleSelectionBackgroundValueTag_Inherit,
+   AzStyleSelectionBackgroundValueTag_Initial,
+   AzStyleSelectionBackgroundValueTag_Exact,
+};
+typedef enum AzStyleSelectionBackgroundValueTag AzStyleSelectionBackgroundValueTag;
+
+struct AzStyleSelectionBackgroundValueVariant_Auto { AzStyleSelectionBackgroundValueTag tag; };
+typedef struct AzStyleSelectionBackgroundValueVariant_Auto AzStyleSelectionBackgroundValueVariant_Auto;
+struct AzStyleSelectionBackgroundValueVariant_None { AzStyleSelectionBackgroundValueTag tag; };
+typedef struct AzStyleSelectionBackgroundValueVariant_None AzStyleSelectionBackgroundValueVariant_None;
+struct AzStyleSelectionBackgroundValueVariant_Inherit { AzStyleSelectionBackgroundValueTag tag; };
+typedef struct AzStyleSelectionBackgroundValueVariant_Inherit AzStyleSelectionBackgroundValueVariant_Inherit;
+struct AzStyleSelectionBackgroundValueVariant_Initial { AzStyleSelectionBackgroundValueTag tag; };
+typedef struct AzStyleSelectionBackgroundValueVariant_Initial AzStyleSelectionBackgroundValueVariant_Initial;
+struct AzStyleSelectionBackgroundValueVariant_Exact { AzStyleSelectionBackgroundValueTag tag; AzStyleSelectionBackground payload; };
+typedef struct AzStyleSelectionBackgroundValueVariant_Exact AzStyleSelectionBackgroundValueVariant_Exact;
+union AzStyleSelectionBackgroundValue {
+    AzStyleSelectionBackgroundValueVariant_Auto Auto;
+    AzStyleSelectionBackgroundValueVariant_None None;
+    AzStyleSelectionBackgroundValueVariant_Inherit Inherit;
+    AzStyleSelectionBackgroundValueVariant_Initial Initial;
+    AzStyleSelectionBackgroundValueVariant_Exact Exact;
+};
+typedef union AzStyleSelectionBackgroundValue AzStyleSelectionBackgroundValue;
+
+struct AzStyleVerticalAlignBoxed {
+    bool  run_destructor;
+    AzStyleVerticalAlign value;
+};
+typedef struct AzStyleVerticalAlignBoxed AzStyleVerticalAlignBoxed;
+
+enum AzStyleVerticalAlignValueTag {
+   AzStyleVerticalAlignValueTag_Auto,
+   AzStyleVerticalAlignValueTag_None,
+   AzStyleVerticalAlignValueTag_Inherit,
+   AzStyleVerticalAlignValueTag_Initial,
+   AzStyleVerticalAlignValueTag_Exact,
+};
+typedef enum AzStyleVerticalAlignValueTag AzStyleVerticalAlignValueTag;
+
+struct AzStyleVerticalAlignValueVariant_Auto { AzStyleVerticalAlignValueTag tag; };
+typedef struct AzStyleVerticalAlignValueVariant_Auto AzStyleVerticalAlignValueVariant_Auto;
+struct AzStyleVerticalAlignValueVariant_None { AzStyleVerticalAlignValueTag tag; };
+typedef struct AzStyleVerticalAlignValueVariant_None AzStyleVerticalAlignValueVariant_None;
+struct AzStyleVerticalAlignValueVariant_Inherit { AzStyleVerticalAlignValueTag tag; };
+typedef struct AzStyleVerticalAlignValueVariant_Inherit AzStyleVerticalAlignValueVariant_Inherit;
+struct AzStyleVerticalAlignValueVariant_Initial { AzStyleVerticalAlignValueTag tag; };
+typedef struct AzStyleVerticalAlignValueVariant_Initial AzStyleVerticalAlignValueVariant_Initial;
+struct AzStyleVerticalAlignValueVariant_Exact { AzStyleVerticalAlignValueTag tag; AzStyleVerticalAlignBoxed* payload; };
+typedef struct AzStyleVerticalAlignValueVariant_Exact AzStyleVerticalAlignValueVariant_Exact;
+union AzStyleVerticalAlignValue {
+    AzStyleVerticalAlignValueVariant_Auto Auto;
+    AzStyleVerticalAlignValueVariant_None None;
+    AzStyleVerticalAlignValueVariant_Inherit Inherit;
+    AzStyleVerticalAlignValueVariant_Initial Initial;
+    AzStyleVerticalAlignValueVariant_Exact Exact;
+};
+typedef union AzStyleVerticalAlignValue AzStyleVerticalAlignValue;
+
 enum AzStyleTextColorValueTag {
    AzStyleTextColorValueTag_Auto,
    AzStyleTextColorValueTag_None,
@@ -6174,6 +6989,12 @@ union AzStyleOpacityValue {
 };
 typedef union AzStyleOpacityValue AzStyleOpacityValue;
 
+struct AzStyleTransformOriginBoxed {
+    bool  run_destructor;
+    AzStyleTransformOrigin value;
+};
+typedef struct AzStyleTransformOriginBoxed AzStyleTransformOriginBoxed;
+
 enum AzStyleTransformOriginValueTag {
    AzStyleTransformOriginValueTag_Auto,
    AzStyleTransformOriginValueTag_None,
@@ -6191,7 +7012,7 @@ struct AzStyleTransformOriginValueVariant_Inherit { AzStyleTransformOriginValueT
 typedef struct AzStyleTransformOriginValueVariant_Inherit AzStyleTransformOriginValueVariant_Inherit;
 struct AzStyleTransformOriginValueVariant_Initial { AzStyleTransformOriginValueTag tag; };
 typedef struct AzStyleTransformOriginValueVariant_Initial AzStyleTransformOriginValueVariant_Initial;
-struct AzStyleTransformOriginValueVariant_Exact { AzStyleTransformOriginValueTag tag; AzStyleTransformOrigin payload; };
+struct AzStyleTransformOriginValueVariant_Exact { AzStyleTransformOriginValueTag tag; AzStyleTransformOriginBoxed* payload; };
 typedef struct AzStyleTransformOriginValueVariant_Exact AzStyleTransformOriginValueVariant_Exact;
 union AzStyleTransformOriginValue {
     AzStyleTransformOriginValueVariant_Auto Auto;
@@ -6202,6 +7023,12 @@ union AzStyleTransformOriginValue {
 };
 typedef union AzStyleTransformOriginValue AzStyleTransformOriginValue;
 
+struct AzStylePerspectiveOriginBoxed {
+    bool  run_destructor;
+    AzStylePerspectiveOrigin value;
+};
+typedef struct AzStylePerspectiveOriginBoxed AzStylePerspectiveOriginBoxed;
+
 enum AzStylePerspectiveOriginValueTag {
    AzStylePerspectiveOriginValueTag_Auto,
    AzStylePerspectiveOriginValueTag_None,
@@ -6219,7 +7046,7 @@ struct AzStylePerspectiveOriginValueVariant_Inherit { AzStylePerspectiveOriginVa
 typedef struct AzStylePerspectiveOriginValueVariant_Inherit AzStylePerspectiveOriginValueVariant_Inherit;
 struct AzStylePerspectiveOriginValueVariant_Initial { AzStylePerspectiveOriginValueTag tag; };
 typedef struct AzStylePerspectiveOriginValueVariant_Initial AzStylePerspectiveOriginValueVariant_Initial;
-struct AzStylePerspectiveOriginValueVariant_Exact { AzStylePerspectiveOriginValueTag tag; AzStylePerspectiveOrigin payload; };
+struct AzStylePerspectiveOriginValueVariant_Exact { AzStylePerspectiveOriginValueTag tag; AzStylePerspectiveOriginBoxed* payload; };
 typedef struct AzStylePerspectiveOriginValueVariant_Exact AzStylePerspectiveOriginValueVariant_Exact;
 union AzStylePerspectiveOriginValue {
     AzStylePerspectiveOriginValueVariant_Auto Auto;
@@ -6286,6 +7113,62 @@ union AzStyleMixBlendModeValue {
 };
 typedef union AzStyleMixBlendModeValue AzStyleMixBlendModeValue;
 
+enum AzStyleIsolationValueTag {
+   AzStyleIsolationValueTag_Auto,
+   AzStyleIsolationValueTag_None,
+   AzStyleIsolationValueTag_Inherit,
+   AzStyleIsolationValueTag_Initial,
+   AzStyleIsolationValueTag_Exact,
+};
+typedef enum AzStyleIsolationValueTag AzStyleIsolationValueTag;
+
+struct AzStyleIsolationValueVariant_Auto { AzStyleIsolationValueTag tag; };
+typedef struct AzStyleIsolationValueVariant_Auto AzStyleIsolationValueVariant_Auto;
+struct AzStyleIsolationValueVariant_None { AzStyleIsolationValueTag tag; };
+typedef struct AzStyleIsolationValueVariant_None AzStyleIsolationValueVariant_None;
+struct AzStyleIsolationValueVariant_Inherit { AzStyleIsolationValueTag tag; };
+typedef struct AzStyleIsolationValueVariant_Inherit AzStyleIsolationValueVariant_Inherit;
+struct AzStyleIsolationValueVariant_Initial { AzStyleIsolationValueTag tag; };
+typedef struct AzStyleIsolationValueVariant_Initial AzStyleIsolationValueVariant_Initial;
+struct AzStyleIsolationValueVariant_Exact { AzStyleIsolationValueTag tag; AzStyleIsolation payload; };
+typedef struct AzStyleIsolationValueVariant_Exact AzStyleIsolationValueVariant_Exact;
+union AzStyleIsolationValue {
+    AzStyleIsolationValueVariant_Auto Auto;
+    AzStyleIsolationValueVariant_None None;
+    AzStyleIsolationValueVariant_Inherit Inherit;
+    AzStyleIsolationValueVariant_Initial Initial;
+    AzStyleIsolationValueVariant_Exact Exact;
+};
+typedef union AzStyleIsolationValue AzStyleIsolationValue;
+
+enum AzStyleImageRenderingValueTag {
+   AzStyleImageRenderingValueTag_Auto,
+   AzStyleImageRenderingValueTag_None,
+   AzStyleImageRenderingValueTag_Inherit,
+   AzStyleImageRenderingValueTag_Initial,
+   AzStyleImageRenderingValueTag_Exact,
+};
+typedef enum AzStyleImageRenderingValueTag AzStyleImageRenderingValueTag;
+
+struct AzStyleImageRenderingValueVariant_Auto { AzStyleImageRenderingValueTag tag; };
+typedef struct AzStyleImageRenderingValueVariant_Auto AzStyleImageRenderingValueVariant_Auto;
+struct AzStyleImageRenderingValueVariant_None { AzStyleImageRenderingValueTag tag; };
+typedef struct AzStyleImageRenderingValueVariant_None AzStyleImageRenderingValueVariant_None;
+struct AzStyleImageRenderingValueVariant_Inherit { AzStyleImageRenderingValueTag tag; };
+typedef struct AzStyleImageRenderingValueVariant_Inherit AzStyleImageRenderingValueVariant_Inherit;
+struct AzStyleImageRenderingValueVariant_Initial { AzStyleImageRenderingValueTag tag; };
+typedef struct AzStyleImageRenderingValueVariant_Initial AzStyleImageRenderingValueVariant_Initial;
+struct AzStyleImageRenderingValueVariant_Exact { AzStyleImageRenderingValueTag tag; AzStyleImageRendering payload; };
+typedef struct AzStyleImageRenderingValueVariant_Exact AzStyleImageRenderingValueVariant_Exact;
+union AzStyleImageRenderingValue {
+    AzStyleImageRenderingValueVariant_Auto Auto;
+    AzStyleImageRenderingValueVariant_None None;
+    AzStyleImageRenderingValueVariant_Inherit Inherit;
+    AzStyleImageRenderingValueVariant_Initial Initial;
+    AzStyleImageRenderingValueVariant_Exact Exact;
+};
+typedef union AzStyleImageRenderingValue AzStyleImageRenderingValue;
+
 struct AzButtonOnClick {
     AzRefAny data;
     AzCallback callback;
@@ -6605,6 +7488,12 @@ union AzDuration {
 };
 typedef union AzDuration AzDuration;
 
+struct AzPendingThread {
+    AzRefAny data;
+    AzThreadFnType thread_fn;
+};
+typedef struct AzPendingThread AzPendingThread;
+
 enum AzThreadSendMsgTag {
    AzThreadSendMsgTag_TerminateThread,
    AzThreadSendMsgTag_Tick,
@@ -6631,6 +7520,12 @@ struct AzThreadWriteBackMsg {
 };
 typedef struct AzThreadWriteBackMsg AzThreadWriteBackMsg;
 
+struct AzTextSelection {
+    AzTextCursor start;
+    AzTextCursor end;
+};
+typedef struct AzTextSelection AzTextSelection;
+
 struct AzLogicalRectVec {
     AzLogicalRect* ptr;
     size_t len;
@@ -6749,6 +7644,24 @@ struct AzStyleBackgroundSizeVec {
 };
 typedef struct AzStyleBackgroundSizeVec AzStyleBackgroundSizeVec;
 
+union AzGridTrack;
+typedef union AzGridTrack AzGridTrack;
+struct AzGridTrackVec {
+    AzGridTrack* ptr;
+    size_t len;
+    size_t cap;
+    AzGridTrackVecDestructor destructor;
+};
+typedef struct AzGridTrackVec AzGridTrackVec;
+
+struct AzCssPropertyTypeVec {
+    AzCssPropertyType* ptr;
+    size_t len;
+    size_t cap;
+    AzCssPropertyTypeVecDestructor destructor;
+};
+typedef struct AzCssPropertyTypeVec AzCssPropertyTypeVec;
+
 struct AzSvgVertexVec {
     AzSvgVertex* ptr;
     size_t len;
@@ -7317,6 +8230,22 @@ union AzOptionMenuCallback {
 };
 typedef union AzOptionMenuCallback AzOptionMenuCallback;
 
+enum AzOptionNormalizedLinearColorStopTag {
+   AzOptionNormalizedLinearColorStopTag_None,
+   AzOptionNormalizedLinearColorStopTag_Some,
+};
+typedef enum AzOptionNormalizedLinearColorStopTag AzOptionNormalizedLinearColorStopTag;
+
+struct AzOptionNormalizedLinearColorStopVariant_None { AzOptionNormalizedLinearColorStopTag tag; };
+typedef struct AzOptionNormalizedLinearColorStopVariant_None AzOptionNormalizedLinearColorStopVariant_None;
+struct AzOptionNormalizedLinearColorStopVariant_Some { AzOptionNormalizedLinearColorStopTag tag; AzNormalizedLinearColorStop payload; };
+typedef struct AzOptionNormalizedLinearColorStopVariant_Some AzOptionNormalizedLinearColorStopVariant_Some;
+union AzOptionNormalizedLinearColorStop {
+    AzOptionNormalizedLinearColorStopVariant_None None;
+    AzOptionNormalizedLinearColorStopVariant_Some Some;
+};
+typedef union AzOptionNormalizedLinearColorStop AzOptionNormalizedLinearColorStop;
+
 enum AzOptionPositionInfoTag {
    AzOptionPositionInfoTag_None,
    AzOptionPositionInfoTag_Some,
@@ -7669,6 +8598,22 @@ union AzOptionLogicalPosition {
 };
 typedef union AzOptionLogicalPosition AzOptionLogicalPosition;
 
+enum AzOptionLogicalRectTag {
+   AzOptionLogicalRectTag_None,
+   AzOptionLogicalRectTag_Some,
+};
+typedef enum AzOptionLogicalRectTag AzOptionLogicalRectTag;
+
+struct AzOptionLogicalRectVariant_None { AzOptionLogicalRectTag tag; };
+typedef struct AzOptionLogicalRectVariant_None AzOptionLogicalRectVariant_None;
+struct AzOptionLogicalRectVariant_Some { AzOptionLogicalRectTag tag; AzLogicalRect payload; };
+typedef struct AzOptionLogicalRectVariant_Some AzOptionLogicalRectVariant_Some;
+union AzOptionLogicalRect {
+    AzOptionLogicalRectVariant_None None;
+    AzOptionLogicalRectVariant_Some Some;
+};
+typedef union AzOptionLogicalRect AzOptionLogicalRect;
+
 enum AzOptionPhysicalPositionI32Tag {
    AzOptionPhysicalPositionI32Tag_None,
    AzOptionPhysicalPositionI32Tag_Some,
@@ -7717,6 +8662,22 @@ union AzOptionLogicalSize {
 };
 typedef union AzOptionLogicalSize AzOptionLogicalSize;
 
+enum AzOptionImeCursorRangeTag {
+   AzOptionImeCursorRangeTag_None,
+   AzOptionImeCursorRangeTag_Some,
+};
+typedef enum AzOptionImeCursorRangeTag AzOptionImeCursorRangeTag;
+
+struct AzOptionImeCursorRangeVariant_None { AzOptionImeCursorRangeTag tag; };
+typedef struct AzOptionImeCursorRangeVariant_None AzOptionImeCursorRangeVariant_None;
+struct AzOptionImeCursorRangeVariant_Some { AzOptionImeCursorRangeTag tag; AzImeCursorRange payload; };
+typedef struct AzOptionImeCursorRangeVariant_Some AzOptionImeCursorRangeVariant_Some;
+union AzOptionImeCursorRange {
+    AzOptionImeCursorRangeVariant_None None;
+    AzOptionImeCursorRangeVariant_Some Some;
+};
+typedef union AzOptionImeCursorRange AzOptionImeCursorRange;
+
 enum AzOptionVirtualKeyCodeTag {
    AzOptionVirtualKeyCodeTag_None,
    AzOptionVirtualKeyCodeTag_Some,
@@ -7884,6 +8845,7 @@ struct AzAppConfig {
     bool  enable_logging_on_panic;
     bool  enable_tab_navigation;
     AzSystemCallbacks system_callbacks;
+    AzRedrawBehavior redraw_behavior;
 };
 typedef struct AzAppConfig AzAppConfig;
 
@@ -7921,6 +8883,14 @@ struct AzTaskBarIcon {
 };
 typedef struct AzTaskBarIcon AzTaskBarIcon;
 
+struct AzAcceleratorCallback {
+    AzAcceleratorId id;
+    AzAccelerator accelerator;
+    AzRefAny data;
+    AzCallback callback;
+};
+typedef struct AzAcceleratorCallback AzAcceleratorCallback;
+
 struct AzWindowSize {
     AzLogicalSize dimensions;
     uint32_t dpi;
@@ -7929,14 +8899,6 @@ struct AzWindowSize {
 };
 typedef struct AzWindowSize AzWindowSize;
 
-struct AzKeyboardState {
-    AzOptionChar current_char;
-    AzOptionVirtualKeyCode current_virtual_keycode;
-    AzVirtualKeyCodeVec pressed_virtual_keycodes;
-    AzScanCodeVec pressed_scancodes;
-};
-typedef struct AzKeyboardState AzKeyboardState;
-
 struct AzMouseState {
     AzOptionMouseCursorType mouse_cursor_type;
     AzCursorPosition cursor_position;
@@ -8108,6 +9070,74 @@ union AzCssPathPseudoSelector {
 };
 typedef union AzCssPathPseudoSelector AzCssPathPseudoSelector;
 
+enum AzMediaFeatureTag {
+   AzMediaFeatureTag_MinWidth,
+   AzMediaFeatureTag_MaxWidth,
+   AzMediaFeatureTag_MinHeight,
+   AzMediaFeatureTag_MaxHeight,
+   AzMediaFeatureTag_WidthLessThan,
+   AzMediaFeatureTag_WidthGreaterThan,
+   AzMediaFeatureTag_HeightLessThan,
+   AzMediaFeatureTag_HeightGreaterThan,
+   AzMediaFeatureTag_Orientation,
+   AzMediaFeatureTag_PrefersColorScheme,
+   AzMediaFeatureTag_Resolution,
+   AzMediaFeatureTag_MinResolution,
+   AzMediaFeatureTag_MaxResolution,
+   AzMediaFeatureTag_ResolutionLessThan,
+   AzMediaFeatureTag_ResolutionGreaterThan,
+};
+typedef enum AzMediaFeatureTag AzMediaFeatureTag;
+
+struct AzMediaFeatureVariant_MinWidth { AzMediaFeatureTag tag; AzPixelValue payload; };
+typedef struct AzMediaFeatureVariant_MinWidth AzMediaFeatureVariant_MinWidth;
+struct AzMediaFeatureVariant_MaxWidth { AzMediaFeatureTag tag; AzPixelValue payload; };
+typedef struct AzMediaFeatureVariant_MaxWidth AzMediaFeatureVariant_MaxWidth;
+struct AzMediaFeatureVariant_MinHeight { AzMediaFeatureTag tag; AzPixelValue payload; };
+typedef struct AzMediaFeatureVariant_MinHeight AzMediaFeatureVariant_MinHeight;
+struct AzMediaFeatureVariant_MaxHeight { AzMediaFeatureTag tag; AzPixelValue payload; };
+typedef struct AzMediaFeatureVariant_MaxHeight AzMediaFeatureVariant_MaxHeight;
+struct AzMediaFeatureVariant_WidthLessThan { AzMediaFeatureTag tag; AzPixelValue payload; };
+typedef struct AzMediaFeatureVariant_WidthLessThan AzMediaFeatureVariant_WidthLessThan;
+struct AzMediaFeatureVariant_WidthGreaterThan { AzMediaFeatureTag tag; AzPixelValue payload; };
+typedef struct AzMediaFeatureVariant_WidthGreaterThan AzMediaFeatureVariant_WidthGreaterThan;
+struct AzMediaFeatureVariant_HeightLessThan { AzMediaFeatureTag tag; AzPixelValue payload; };
+typedef struct AzMediaFeatureVariant_HeightLessThan AzMediaFeatureVariant_HeightLessThan;
+struct AzMediaFeatureVariant_HeightGreaterThan { AzMediaFeatureTag tag; AzPixelValue payload; };
+typedef struct AzMediaFeatureVariant_HeightGreaterThan AzMediaFeatureVariant_HeightGreaterThan;
+struct AzMediaFeatureVariant_Orientation { AzMediaFeatureTag tag; AzMediaOrientation payload; };
+typedef struct AzMediaFeatureVariant_Orientation AzMediaFeatureVariant_Orientation;
+struct AzMediaFeatureVariant_PrefersColorScheme { AzMediaFeatureTag tag; AzMediaColorScheme payload; };
+typedef struct AzMediaFeatureVariant_PrefersColorScheme AzMediaFeatureVariant_PrefersColorScheme;
+struct AzMediaFeatureVariant_Resolution { AzMediaFeatureTag tag; AzFloatValue payload; };
+typedef struct AzMediaFeatureVariant_Resolution AzMediaFeatureVariant_Resolution;
+struct AzMediaFeatureVariant_MinResolution { AzMediaFeatureTag tag; AzFloatValue payload; };
+typedef struct AzMediaFeatureVariant_MinResolution AzMediaFeatureVariant_MinResolution;
+struct AzMediaFeatureVariant_MaxResolution { AzMediaFeatureTag tag; AzFloatValue payload; };
+typedef struct AzMediaFeatureVariant_MaxResolution AzMediaFeatureVariant_MaxResolution;
+struct AzMediaFeatureVariant_ResolutionLessThan { AzMediaFeatureTag tag; AzFloatValue payload; };
+typedef struct AzMediaFeatureVariant_ResolutionLessThan AzMediaFeatureVariant_ResolutionLessThan;
+struct AzMediaFeatureVariant_ResolutionGreaterThan { AzMediaFeatureTag tag; AzFloatValue payload; };
+typedef struct AzMediaFeatureVariant_ResolutionGreaterThan AzMediaFeatureVariant_ResolutionGreaterThan;
+union AzMediaFeature {
+    AzMediaFeatureVariant_MinWidth MinWidth;
+    AzMediaFeatureVariant_MaxWidth MaxWidth;
+    AzMediaFeatureVariant_MinHeight MinHeight;
+    AzMediaFeatureVariant_MaxHeight MaxHeight;
+    AzMediaFeatureVariant_WidthLessThan WidthLessThan;
+    AzMediaFeatureVariant_WidthGreaterThan WidthGreaterThan;
+    AzMediaFeatureVariant_HeightLessThan HeightLessThan;
+    AzMediaFeatureVariant_HeightGreaterThan HeightGreaterThan;
+    AzMediaFeatureVariant_Orientation Orientation;
+    AzMediaFeatureVariant_PrefersColorScheme PrefersColorScheme;
+    AzMediaFeatureVariant_Resolution Resolution;
+    AzMediaFeatureVariant_MinResolution MinResolution;
+    AzMediaFeatureVariant_MaxResolution MaxResolution;
+    AzMediaFeatureVariant_ResolutionLessThan ResolutionLessThan;
+    AzMediaFeatureVariant_ResolutionGreaterThan ResolutionGreaterThan;
+};
+typedef union AzMediaFeature AzMediaFeature;
+
 enum AzAnimationInterpolationFunctionTag {
    AzAnimationInterpolationFunctionTag_Ease,
    AzAnimationInterpolationFunctionTag_Linear,
@@ -8146,6 +9176,7 @@ struct AzInterpolateContext {
     float parent_rect_height;
     float current_rect_width;
     float current_rect_height;
+    float em_size;
 };
 typedef struct AzInterpolateContext AzInterpolateContext;
 
@@ -8193,6 +9224,22 @@ union AzStyleFilter {
 };
 typedef union AzStyleFilter AzStyleFilter;
 
+struct AzGridTrackRepeat {
+    uint32_t count;
+    AzGridTrackVec tracks;
+};
+typedef struct AzGridTrackRepeat AzGridTrackRepeat;
+
+struct AzLayoutGridTemplateColumns {
+    AzGridTrackVec inner;
+};
+typedef struct AzLayoutGridTemplateColumns AzLayoutGridTemplateColumns;
+
+struct AzLayoutGridTemplateRows {
+    AzGridTrackVec inner;
+};
+typedef struct AzLayoutGridTemplateRows AzLayoutGridTemplateRows;
+
 struct AzLinearGradient {
     AzDirection direction;
     AzExtendMode extend_mode;
@@ -8309,6 +9356,100 @@ union AzStyleTransform {
 };
 typedef union AzStyleTransform AzStyleTransform;
 
+enum AzStyleTransitionPropertyTag {
+   AzStyleTransitionPropertyTag_All,
+   AzStyleTransitionPropertyTag_None,
+   AzStyleTransitionPropertyTag_List,
+};
+typedef enum AzStyleTransitionPropertyTag AzStyleTransitionPropertyTag;
+
+struct AzStyleTransitionPropertyVariant_All { AzStyleTransitionPropertyTag tag; };
+typedef struct AzStyleTransitionPropertyVariant_All AzStyleTransitionPropertyVariant_All;
+struct AzStyleTransitionPropertyVariant_None { AzStyleTransitionPropertyTag tag; };
+typedef struct AzStyleTransitionPropertyVariant_None AzStyleTransitionPropertyVariant_None;
+struct AzStyleTransitionPropertyVariant_List { AzStyleTransitionPropertyTag tag; AzCssPropertyTypeVec payload; };
+typedef struct AzStyleTransitionPropertyVariant_List AzStyleTransitionPropertyVariant_List;
+union AzStyleTransitionProperty {
+    AzStyleTransitionPropertyVariant_All All;
+    AzStyleTransitionPropertyVariant_None None;
+    AzStyleTransitionPropertyVariant_List List;
+};
+typedef union AzStyleTransitionProperty AzStyleTransitionProperty;
+
+struct AzLayoutGridTemplateColumnsBoxed {
+    bool  run_destructor;
+    AzLayoutGridTemplateColumns value;
+};
+typedef struct AzLayoutGridTemplateColumnsBoxed AzLayoutGridTemplateColumnsBoxed;
+
+enum AzLayoutGridTemplateColumnsValueTag {
+   AzLayoutGridTemplateColumnsValueTag_Auto,
+   AzLayoutGridTemplateColumnsValueTag_None,
+   AzLayoutGridTemplateColumnsValueTag_Inherit,
+   AzLayoutGridTemplateColumnsValueTag_Initial,
+   AzLayoutGridTemplateColumnsValueTag_Exact,
+};
+typedef enum AzLayoutGridTemplateColumnsValueTag AzLayoutGridTemplateColumnsValueTag;
+
+struct AzLayoutGridTemplateColumnsValueVariant_Auto { AzLayoutGridTemplateColumnsValueTag tag; };
+typedef struct AzLayoutGridTemplateColumnsValueVariant_Auto AzLayoutGridTemplateColumnsValueVariant_Auto;
+struct AzLayoutGridTemplateColumnsValueVariant_None { AzLayoutGridTemplateColumnsValueTag tag; };
+typedef struct AzLayoutGridTemplateColumnsValueVariant_None AzLayoutGridTemplateColumnsValueVariant_None;
+struct AzLayoutGridTemplateColumnsValueVariant_Inherit { AzLayoutGridTemplateColumnsValueTag tag; };
+typedef struct AzLayoutGridTemplateColumnsValueVariant_Inherit AzLayoutGridTemplateColumnsValueVariant_Inherit;
+struct AzLayoutGridTemplateColumnsValueVariant_Initial { AzLayoutGridTemplateColumnsValueTag tag; };
+typedef struct AzLayoutGridTemplateColumnsValueVariant_Initial AzLayoutGridTemplateColumnsValueVariant_Initial;
+struct AzLayoutGridTemplateColumnsValueVariant_Exact { AzLayoutGridTemplateColumnsValueTag tag; AzLayoutGridTemplateColumnsBoxed* payload; };
+typedef struct AzLayoutGridTemplateColumnsValueVariant_Exact AzLayoutGridTemplateColumnsValueVariant_Exact;
+union AzLayoutGridTemplateColumnsValue {
+    AzLayoutGridTemplateColumnsValueVariant_Auto Auto;
+    AzLayoutGridTemplateColumnsValueVariant_None None;
+    AzLayoutGridTemplateColumnsValueVariant_Inherit Inherit;
+    AzLayoutGridTemplateColumnsValueVariant_Initial Initial;
+    AzLayoutGridTemplateColumnsValueVariant_Exact Exact;
+};
+typedef union AzLayoutGridTemplateColumnsValue AzLayoutGridTemplateColumnsValue;
+
+struct AzLayoutGridTemplateRowsBoxed {
+    bool  run_destructor;
+    AzLayoutGridTemplateRows value;
+};
+typedef struct AzLayoutGridTemplateRowsBoxed AzLayoutGridTemplateRowsBoxed;
+
+enum AzLayoutGridTemplateRowsValueTag {
+   AzLayoutGridTemplateRowsValueTag_Auto,
+   AzLayoutGridTemplateRowsValueTag_None,
+   AzLayoutGridTemplateRowsValueTag_Inherit,
+   AzLayoutGridTemplateRowsValueTag_Initial,
+   AzLayoutGridTemplateRowsValueTag_Exact,
+};
+typedef enum AzLayoutGridTemplateRowsValueTag AzLayoutGridTemplateRowsValueTag;
+
+struct AzLayoutGridTemplateRowsValueVariant_Auto { AzLayoutGridTemplateRowsValueTag tag; };
+typedef struct AzLayoutGridTemplateRowsValueVariant_Auto AzLayoutGridTemplateRowsValueVariant_Auto;
+struct AzLayoutGridTemplateRowsValueVariant_None { AzLayoutGridTemplateRowsValueTag tag; };
+typedef struct AzLayoutGridTemplateRowsValueVariant_None AzLayoutGridTemplateRowsValueVariant_None;
+struct AzLayoutGridTemplateRowsValueVariant_Inherit { AzLayoutGridTemplateRowsValueTag tag; };
+typedef struct AzLayoutGridTemplateRowsValueVariant_Inherit AzLayoutGridTemplateRowsValueVariant_Inherit;
+struct AzLayoutGridTemplateRowsValueVariant_Initial { AzLayoutGridTemplateRowsValueTag tag; };
+typedef struct AzLayoutGridTemplateRowsValueVariant_Initial AzLayoutGridTemplateRowsValueVariant_Initial;
+struct AzLayoutGridTemplateRowsValueVariant_Exact { AzLayoutGridTemplateRowsValueTag tag; AzLayoutGridTemplateRowsBoxed* payload; };
+typedef struct AzLayoutGridTemplateRowsValueVariant_Exact AzLayoutGridTemplateRowsValueVariant_Exact;
+union AzLayoutGridTemplateRowsValue {
+    AzLayoutGridTemplateRowsValueVariant_Auto Auto;
+    AzLayoutGridTemplateRowsValueVariant_None None;
+    AzLayoutGridTemplateRowsValueVariant_Inherit Inherit;
+    AzLayoutGridTemplateRowsValueVariant_Initial Initial;
+    AzLayoutGridTemplateRowsValueVariant_Exact Exact;
+};
+typedef union AzLayoutGridTemplateRowsValue AzLayoutGridTemplateRowsValue;
+
+struct AzStyleBackgroundPositionVecBoxed {
+    bool  run_destructor;
+    AzStyleBackgroundPositionVec value;
+};
+typedef struct AzStyleBackgroundPositionVecBoxed AzStyleBackgroundPositionVecBoxed;
+
 enum AzStyleBackgroundPositionVecValueTag {
    AzStyleBackgroundPositionVecValueTag_Auto,
    AzStyleBackgroundPositionVecValueTag_None,
@@ -8326,7 +9467,7 @@ struct AzStyleBackgroundPositionVecValueVariant_Inherit { AzStyleBackgroundPosit
 typedef struct AzStyleBackgroundPositionVecValueVariant_Inherit AzStyleBackgroundPositionVecValueVariant_Inherit;
 struct AzStyleBackgroundPositionVecValueVariant_Initial { AzStyleBackgroundPositionVecValueTag tag; };
 typedef struct AzStyleBackgroundPositionVecValueVariant_Initial AzStyleBackgroundPositionVecValueVariant_Initial;
-struct AzStyleBackgroundPositionVecValueVariant_Exact { AzStyleBackgroundPositionVecValueTag tag; AzStyleBackgroundPositionVec payload; };
+struct AzStyleBackgroundPositionVecValueVariant_Exact { AzStyleBackgroundPositionVecValueTag tag; AzStyleBackgroundPositionVecBoxed* payload; };
 typedef struct AzStyleBackgroundPositionVecValueVariant_Exact AzStyleBackgroundPositionVecValueVariant_Exact;
 union AzStyleBackgroundPositionVecValue {
     AzStyleBackgroundPositionVecValueVariant_Auto Auto;
@@ -8337,6 +9478,12 @@ union AzStyleBackgroundPositionVecValue {
 };
 typedef union AzStyleBackgroundPositionVecValue AzStyleBackgroundPositionVecValue;
 
+struct AzStyleBackgroundRepeatVecBoxed {
+    bool  run_destructor;
+    AzStyleBackgroundRepeatVec value;
+};
+typedef struct AzStyleBackgroundRepeatVecBoxed AzStyleBackgroundRepeatVecBoxed;
+
 enum AzStyleBackgroundRepeatVecValueTag {
    AzStyleBackgroundRepeatVecValueTag_Auto,
    AzStyleBackgroundRepeatVecValueTag_None,
@@ -8354,7 +9501,7 @@ struct AzStyleBackgroundRepeatVecValueVariant_Inherit { AzStyleBackgroundRepeatV
 typedef struct AzStyleBackgroundRepeatVecValueVariant_Inherit AzStyleBackgroundRepeatVecValueVariant_Inherit;
 struct AzStyleBackgroundRepeatVecValueVariant_Initial { AzStyleBackgroundRepeatVecValueTag tag; };
 typedef struct AzStyleBackgroundRepeatVecValueVariant_Initial AzStyleBackgroundRepeatVecValueVariant_Initial;
-struct AzStyleBackgroundRepeatVecValueVariant_Exact { AzStyleBackgroundRepeatVecValueTag tag; AzStyleBackgroundRepeatVec payload; };
+struct AzStyleBackgroundRepeatVecValueVariant_Exact { AzStyleBackgroundRepeatVecValueTag tag; AzStyleBackgroundRepeatVecBoxed* payload; };
 typedef struct AzStyleBackgroundRepeatVecValueVariant_Exact AzStyleBackgroundRepeatVecValueVariant_Exact;
 union AzStyleBackgroundRepeatVecValue {
     AzStyleBackgroundRepeatVecValueVariant_Auto Auto;
@@ -8365,6 +9512,12 @@ union AzStyleBackgroundRepeatVecValue {
 };
 typedef union AzStyleBackgroundRepeatVecValue AzStyleBackgroundRepeatVecValue;
 
+struct AzStyleBackgroundSizeVecBoxed {
+    bool  run_destructor;
+    AzStyleBackgroundSizeVec value;
+};
+typedef struct AzStyleBackgroundSizeVecBoxed AzStyleBackgroundSizeVecBoxed;
+
 enum AzStyleBackgroundSizeVecValueTag {
    AzStyleBackgroundSizeVecValueTag_Auto,
    AzStyleBackgroundSizeVecValueTag_None,
@@ -8382,7 +9535,7 @@ struct AzStyleBackgroundSizeVecValueVariant_Inherit { AzStyleBackgroundSizeVecVa
 typedef struct AzStyleBackgroundSizeVecValueVariant_Inherit AzStyleBackgroundSizeVecValueVariant_Inherit;
 struct AzStyleBackgroundSizeVecValueVariant_Initial { AzStyleBackgroundSizeVecValueTag tag; };
 typedef struct AzStyleBackgroundSizeVecValueVariant_Initial AzStyleBackgroundSizeVecValueVariant_Initial;
-struct AzStyleBackgroundSizeVecValueVariant_Exact { AzStyleBackgroundSizeVecValueTag tag; AzStyleBackgroundSizeVec payload; };
+struct AzStyleBackgroundSizeVecValueVariant_Exact { AzStyleBackgroundSizeVecValueTag tag; AzStyleBackgroundSizeVecBoxed* payload; };
 typedef struct AzStyleBackgroundSizeVecValueVariant_Exact AzStyleBackgroundSizeVecValueVariant_Exact;
 union AzStyleBackgroundSizeVecValue {
     AzStyleBackgroundSizeVecValueVariant_Auto Auto;
@@ -8393,6 +9546,40 @@ union AzStyleBackgroundSizeVecValue {
 };
 typedef union AzStyleBackgroundSizeVecValue AzStyleBackgroundSizeVecValue;
 
+struct AzStyleTransitionPropertyBoxed {
+    bool  run_destructor;
+    AzStyleTransitionProperty value;
+};
+typedef struct AzStyleTransitionPropertyBoxed AzStyleTransitionPropertyBoxed;
+
+enum AzStyleTransitionPropertyValueTag {
+   AzStyleTransitionPropertyValueTag_Auto,
+   AzStyleTransitionPropertyValueTag_None,
+   AzStyleTransitionPropertyValueTag_Inherit,
+   AzStyleTransitionPropertyValueTag_Initial,
+   AzStyleTransitionPropertyValueTag_Exact,
+};
+typedef enum AzStyleTransitionPropertyValueTag AzStyleTransitionPropertyValueTag;
+
+struct AzStyleTransitionPropertyValueVariant_Auto { AzStyleTransitionPropertyValueTag tag; };
+typedef struct AzStyleTransitionPropertyValueVariant_Auto AzStyleTransitionPropertyValueVariant_Auto;
+struct AzStyleTransitionPropertyValueVariant_None { AzStyleTransitionPropertyValueTag tag; };
+typedef struct AzStyleTransitionPropertyValueVariant_None AzStyleTransitionPropertyValueVariant_None;
+struct AzStyleTransitionPropertyValueVariant_Inherit { AzStyleTransitionPropertyValueTag tag; };
+typedef struct AzStyleTransitionPropertyValueVariant_Inherit AzStyleTransitionPropertyValueVariant_Inherit;
+struct AzStyleTransitionPropertyValueVariant_Initial { AzStyleTransitionPropertyValueTag tag; };
+typedef struct AzStyleTransitionPropertyValueVariant_Initial AzStyleTransitionPropertyValueVariant_Initial;
+struct AzStyleTransitionPropertyValueVariant_Exact { AzStyleTransitionPropertyValueTag tag; AzStyleTransitionPropertyBoxed* payload; };
+typedef struct AzStyleTransitionPropertyValueVariant_Exact AzStyleTransitionPropertyValueVariant_Exact;
+union AzStyleTransitionPropertyValue {
+    AzStyleTransitionPropertyValueVariant_Auto Auto;
+    AzStyleTransitionPropertyValueVariant_None None;
+    AzStyleTransitionPropertyValueVariant_Inherit Inherit;
+    AzStyleTransitionPropertyValueVariant_Initial Initial;
+    AzStyleTransitionPropertyValueVariant_Exact Exact;
+};
+typedef union AzStyleTransitionPropertyValue AzStyleTransitionPropertyValue;
+
 struct AzCheckBoxStateWrapper {
     AzCheckBoxState inner;
     AzOptionCheckBoxOnToggle on_toggle;
@@ -8652,6 +9839,14 @@ struct AzTessellatedColoredSvgNodeVec {
 };
 typedef struct AzTessellatedColoredSvgNodeVec AzTessellatedColoredSvgNodeVec;
 
+struct AzAcceleratorCallbackVec {
+    AzAcceleratorCallback* ptr;
+    size_t len;
+    size_t cap;
+    AzAcceleratorCallbackVecDestructor destructor;
+};
+typedef struct AzAcceleratorCallbackVec AzAcceleratorCallbackVec;
+
 struct AzStyleTransformVec {
     AzStyleTransform* ptr;
     size_t len;
@@ -8660,6 +9855,14 @@ struct AzStyleTransformVec {
 };
 typedef struct AzStyleTransformVec AzStyleTransformVec;
 
+struct AzMediaFeatureVec {
+    AzMediaFeature* ptr;
+    size_t len;
+    size_t cap;
+    AzMediaFeatureVecDestructor destructor;
+};
+typedef struct AzMediaFeatureVec AzMediaFeatureVec;
+
 struct AzSvgPathElementVec {
     AzSvgPathElement* ptr;
     size_t len;
@@ -8756,22 +9959,6 @@ union AzOptionMouseState {
 };
 typedef union AzOptionMouseState AzOptionMouseState;
 
-enum AzOptionKeyboardStateTag {
-   AzOptionKeyboardStateTag_None,
-   AzOptionKeyboardStateTag_Some,
-};
-typedef enum AzOptionKeyboardStateTag AzOptionKeyboardStateTag;
-
-struct AzOptionKeyboardStateVariant_None { AzOptionKeyboardStateTag tag; };
-typedef struct AzOptionKeyboardStateVariant_None AzOptionKeyboardStateVariant_None;
-struct AzOptionKeyboardStateVariant_Some { AzOptionKeyboardStateTag tag; AzKeyboardState payload; };
-typedef struct AzOptionKeyboardStateVariant_Some AzOptionKeyboardStateVariant_Some;
-union AzOptionKeyboardState {
-    AzOptionKeyboardStateVariant_None None;
-    AzOptionKeyboardStateVariant_Some Some;
-};
-typedef union AzOptionKeyboardState AzOptionKeyboardState;
-
 enum AzOptionStringVecTag {
    AzOptionStringVecTag_None,
    AzOptionStringVecTag_Some,
@@ -8921,6 +10108,12 @@ struct AzInvalidStringError {
 };
 typedef struct AzInvalidStringError AzInvalidStringError;
 
+struct AzImePreedit {
+    AzString text;
+    AzOptionImeCursorRange cursor_range;
+};
+typedef struct AzImePreedit AzImePreedit;
+
 struct AzWindowsWindowOptions {
     bool  allow_drag_drop;
     bool  no_redirection_bitmap;
@@ -9146,6 +10339,43 @@ union AzCssPathSelector {
 };
 typedef union AzCssPathSelector AzCssPathSelector;
 
+struct AzMediaCondition {
+    AzMediaFeatureVec features;
+};
+typedef struct AzMediaCondition AzMediaCondition;
+
+enum AzGridTrackTag {
+   AzGridTrackTag_Px,
+   AzGridTrackTag_Fraction,
+   AzGridTrackTag_MinContent,
+   AzGridTrackTag_MaxContent,
+   AzGridTrackTag_Auto,
+   AzGridTrackTag_Repeat,
+};
+typedef enum AzGridTrackTag AzGridTrackTag;
+
+struct AzGridTrackVariant_Px { AzGridTrackTag tag; AzPixelValue payload; };
+typedef struct AzGridTrackVariant_Px AzGridTrackVariant_Px;
+struct AzGridTrackVariant_Fraction { AzGridTrackTag tag; AzFloatValue payload; };
+typedef struct AzGridTrackVariant_Fraction AzGridTrackVariant_Fraction;
+struct AzGridTrackVariant_MinContent { AzGridTrackTag tag; };
+typedef struct AzGridTrackVariant_MinContent AzGridTrackVariant_MinContent;
+struct AzGridTrackVariant_MaxContent { AzGridTrackTag tag; };
+typedef struct AzGridTrackVariant_MaxContent AzGridTrackVariant_MaxContent;
+struct AzGridTrackVariant_Auto { AzGridTrackTag tag; };
+typedef struct AzGridTrackVariant_Auto AzGridTrackVariant_Auto;
+struct AzGridTrackVariant_Repeat { AzGridTrackTag tag; AzGridTrackRepeat payload; };
+typedef struct AzGridTrackVariant_Repeat AzGridTrackVariant_Repeat;
+union AzGridTrack {
+    AzGridTrackVariant_Px Px;
+    AzGridTrackVariant_Fraction Fraction;
+    AzGridTrackVariant_MinContent MinContent;
+    AzGridTrackVariant_MaxContent MaxContent;
+    AzGridTrackVariant_Auto Auto;
+    AzGridTrackVariant_Repeat Repeat;
+};
+typedef union AzGridTrack AzGridTrack;
+
 enum AzStyleBackgroundContentTag {
    AzStyleBackgroundContentTag_LinearGradient,
    AzStyleBackgroundContentTag_RadialGradient,
@@ -9212,6 +10442,12 @@ union AzStyleFontFamily {
 };
 typedef union AzStyleFontFamily AzStyleFontFamily;
 
+struct AzScrollbarStyleBoxed {
+    bool  run_destructor;
+    AzScrollbarStyle value;
+};
+typedef struct AzScrollbarStyleBoxed AzScrollbarStyleBoxed;
+
 enum AzScrollbarStyleValueTag {
    AzScrollbarStyleValueTag_Auto,
    AzScrollbarStyleValueTag_None,
@@ -9229,7 +10465,7 @@ struct AzScrollbarStyleValueVariant_Inherit { AzScrollbarStyleValueTag tag; };
 typedef struct AzScrollbarStyleValueVariant_Inherit AzScrollbarStyleValueVariant_Inherit;
 struct AzScrollbarStyleValueVariant_Initial { AzScrollbarStyleValueTag tag; };
 typedef struct AzScrollbarStyleValueVariant_Initial AzScrollbarStyleValueVariant_Initial;
-struct AzScrollbarStyleValueVariant_Exact { AzScrollbarStyleValueTag tag; AzScrollbarStyle payload; };
+struct AzScrollbarStyleValueVariant_Exact { AzScrollbarStyleValueTag tag; AzScrollbarStyleBoxed* payload; };
 typedef struct AzScrollbarStyleValueVariant_Exact AzScrollbarStyleValueVariant_Exact;
 union AzScrollbarStyleValue {
     AzScrollbarStyleValueVariant_Auto Auto;
@@ -9240,6 +10476,12 @@ union AzScrollbarStyleValue {
 };
 typedef union AzScrollbarStyleValue AzScrollbarStyleValue;
 
+struct AzStyleTransformVecBoxed {
+    bool  run_destructor;
+    AzStyleTransformVec value;
+};
+typedef struct AzStyleTransformVecBoxed AzStyleTransformVecBoxed;
+
 enum AzStyleTransformVecValueTag {
    AzStyleTransformVecValueTag_Auto,
    AzStyleTransformVecValueTag_None,
@@ -9257,7 +10499,7 @@ struct AzStyleTransformVecValueVariant_Inherit { AzStyleTransformVecValueTag tag
 typedef struct AzStyleTransformVecValueVariant_Inherit AzStyleTransformVecValueVariant_Inherit;
 struct AzStyleTransformVecValueVariant_Initial { AzStyleTransformVecValueTag tag; };
 typedef struct AzStyleTransformVecValueVariant_Initial AzStyleTransformVecValueVariant_Initial;
-struct AzStyleTransformVecValueVariant_Exact { AzStyleTransformVecValueTag tag; AzStyleTransformVec payload; };
+struct AzStyleTransformVecValueVariant_Exact { AzStyleTransformVecValueTag tag; AzStyleTransformVecBoxed* payload; };
 typedef struct AzStyleTransformVecValueVariant_Exact AzStyleTransformVecValueVariant_Exact;
 union AzStyleTransformVecValue {
     AzStyleTransformVecValueVariant_Auto Auto;
@@ -9268,6 +10510,12 @@ union AzStyleTransformVecValue {
 };
 typedef union AzStyleTransformVecValue AzStyleTransformVecValue;
 
+struct AzStyleFilterVecBoxed {
+    bool  run_destructor;
+    AzStyleFilterVec value;
+};
+typedef struct AzStyleFilterVecBoxed AzStyleFilterVecBoxed;
+
 enum AzStyleFilterVecValueTag {
    AzStyleFilterVecValueTag_Auto,
    AzStyleFilterVecValueTag_None,
@@ -9285,7 +10533,7 @@ struct AzStyleFilterVecValueVariant_Inherit { AzStyleFilterVecValueTag tag; };
 typedef struct AzStyleFilterVecValueVariant_Inherit AzStyleFilterVecValueVariant_Inherit;
 struct AzStyleFilterVecValueVariant_Initial { AzStyleFilterVecValueTag tag; };
 typedef struct AzStyleFilterVecValueVariant_Initial AzStyleFilterVecValueVariant_Initial;
-struct AzStyleFilterVecValueVariant_Exact { AzStyleFilterVecValueTag tag; AzStyleFilterVec payload; };
+struct AzStyleFilterVecValueVariant_Exact { AzStyleFilterVecValueTag tag; AzStyleFilterVecBoxed* payload; };
 typedef struct AzStyleFilterVecValueVariant_Exact AzStyleFilterVecValueVariant_Exact;
 union AzStyleFilterVecValue {
     AzStyleFilterVecValueVariant_Auto Auto;
@@ -9624,6 +10872,14 @@ struct AzStyleBackgroundContentVec {
 };
 typedef struct AzStyleBackgroundContentVec AzStyleBackgroundContentVec;
 
+struct AzMediaConditionVec {
+    AzMediaCondition* ptr;
+    size_t len;
+    size_t cap;
+    AzMediaConditionVecDestructor destructor;
+};
+typedef struct AzMediaConditionVec AzMediaConditionVec;
+
 struct AzSvgPathVec {
     AzSvgPath* ptr;
     size_t len;
@@ -9812,6 +11068,30 @@ union AzXmlStreamError {
 };
 typedef union AzXmlStreamError AzXmlStreamError;
 
+enum AzImeEventTag {
+   AzImeEventTag_Enabled,
+   AzImeEventTag_Disabled,
+   AzImeEventTag_Preedit,
+   AzImeEventTag_Commit,
+};
+typedef enum AzImeEventTag AzImeEventTag;
+
+struct AzImeEventVariant_Enabled { AzImeEventTag tag; };
+typedef struct AzImeEventVariant_Enabled AzImeEventVariant_Enabled;
+struct AzImeEventVariant_Disabled { AzImeEventTag tag; };
+typedef struct AzImeEventVariant_Disabled AzImeEventVariant_Disabled;
+struct AzImeEventVariant_Preedit { AzImeEventTag tag; AzImePreedit payload; };
+typedef struct AzImeEventVariant_Preedit AzImeEventVariant_Preedit;
+struct AzImeEventVariant_Commit { AzImeEventTag tag; AzString payload; };
+typedef struct AzImeEventVariant_Commit AzImeEventVariant_Commit;
+union AzImeEvent {
+    AzImeEventVariant_Enabled Enabled;
+    AzImeEventVariant_Disabled Disabled;
+    AzImeEventVariant_Preedit Preedit;
+    AzImeEventVariant_Commit Commit;
+};
+typedef union AzImeEvent AzImeEvent;
+
 struct AzLinuxWindowOptions {
     AzOptionX11Visual x11_visual;
     AzOptionI32 x11_screen;
@@ -9859,6 +11139,17 @@ struct AzCssPath {
 };
 typedef struct AzCssPath AzCssPath;
 
+struct AzMediaQuery {
+    AzMediaConditionVec conditions;
+};
+typedef struct AzMediaQuery AzMediaQuery;
+
+struct AzStyleBackgroundContentVecBoxed {
+    bool  run_destructor;
+    AzStyleBackgroundContentVec value;
+};
+typedef struct AzStyleBackgroundContentVecBoxed AzStyleBackgroundContentVecBoxed;
+
 enum AzStyleBackgroundContentVecValueTag {
    AzStyleBackgroundContentVecValueTag_Auto,
    AzStyleBackgroundContentVecValueTag_None,
@@ -9876,7 +11167,7 @@ struct AzStyleBackgroundContentVecValueVariant_Inherit { AzStyleBackgroundConten
 typedef struct AzStyleBackgroundContentVecValueVariant_Inherit AzStyleBackgroundContentVecValueVariant_Inherit;
 struct AzStyleBackgroundContentVecValueVariant_Initial { AzStyleBackgroundContentVecValueTag tag; };
 typedef struct AzStyleBackgroundContentVecValueVariant_Initial AzStyleBackgroundContentVecValueVariant_Initial;
-struct AzStyleBackgroundContentVecValueVariant_Exact { AzStyleBackgroundContentVecValueTag tag; AzStyleBackgroundContentVec payload; };
+struct AzStyleBackgroundContentVecValueVariant_Exact { AzStyleBackgroundContentVecValueTag tag; AzStyleBackgroundContentVecBoxed* payload; };
 typedef struct AzStyleBackgroundContentVecValueVariant_Exact AzStyleBackgroundContentVecValueVariant_Exact;
 union AzStyleBackgroundContentVecValue {
     AzStyleBackgroundContentVecValueVariant_Auto Auto;
@@ -9887,6 +11178,12 @@ union AzStyleBackgroundContentVecValue {
 };
 typedef union AzStyleBackgroundContentVecValue AzStyleBackgroundContentVecValue;
 
+struct AzStyleFontFamilyVecBoxed {
+    bool  run_destructor;
+    AzStyleFontFamilyVec value;
+};
+typedef struct AzStyleFontFamilyVecBoxed AzStyleFontFamilyVecBoxed;
+
 enum AzStyleFontFamilyVecValueTag {
    AzStyleFontFamilyVecValueTag_Auto,
    AzStyleFontFamilyVecValueTag_None,
@@ -9904,7 +11201,7 @@ struct AzStyleFontFamilyVecValueVariant_Inherit { AzStyleFontFamilyVecValueTag t
 typedef struct AzStyleFontFamilyVecValueVariant_Inherit AzStyleFontFamilyVecValueVariant_Inherit;
 struct AzStyleFontFamilyVecValueVariant_Initial { AzStyleFontFamilyVecValueTag tag; };
 typedef struct AzStyleFontFamilyVecValueVariant_Initial AzStyleFontFamilyVecValueVariant_Initial;
-struct AzStyleFontFamilyVecValueVariant_Exact { AzStyleFontFamilyVecValueTag tag; AzStyleFontFamilyVec payload; };
+struct AzStyleFontFamilyVecValueVariant_Exact { AzStyleFontFamilyVecValueTag tag; AzStyleFontFamilyVecBoxed* payload; };
 typedef struct AzStyleFontFamilyVecValueVariant_Exact AzStyleFontFamilyVecValueVariant_Exact;
 union AzStyleFontFamilyVecValue {
     AzStyleFontFamilyVecValueVariant_Auto Auto;
@@ -9920,6 +11217,8 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_FontSize,
    AzCssPropertyTag_FontFamily,
    AzCssPropertyTag_TextAlign,
+   AzCssPropertyTag_VerticalAlign,
+   AzCssPropertyTag_Direction,
    AzCssPropertyTag_LetterSpacing,
    AzCssPropertyTag_LineHeight,
    AzCssPropertyTag_WordSpacing,
@@ -9946,6 +11245,10 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_JustifyContent,
    AzCssPropertyTag_AlignItems,
    AzCssPropertyTag_AlignContent,
+   AzCssPropertyTag_GridTemplateColumns,
+   AzCssPropertyTag_GridTemplateRows,
+   AzCssPropertyTag_GridColumn,
+   AzCssPropertyTag_GridRow,
    AzCssPropertyTag_BackgroundContent,
    AzCssPropertyTag_BackgroundPosition,
    AzCssPropertyTag_BackgroundSize,
@@ -9987,9 +11290,18 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_PerspectiveOrigin,
    AzCssPropertyTag_BackfaceVisibility,
    AzCssPropertyTag_MixBlendMode,
+   AzCssPropertyTag_Isolation,
+   AzCssPropertyTag_ImageRendering,
    AzCssPropertyTag_Filter,
    AzCssPropertyTag_BackdropFilter,
    AzCssPropertyTag_TextShadow,
+   AzCssPropertyTag_TransitionProperty,
+   AzCssPropertyTag_TransitionDuration,
+   AzCssPropertyTag_TransitionTimingFunction,
+   AzCssPropertyTag_TransitionDelay,
+   AzCssPropertyTag_CaretColor,
+   AzCssPropertyTag_SelectionColor,
+   AzCssPropertyTag_SelectionBackground,
 };
 typedef enum AzCssPropertyTag AzCssPropertyTag;
 
@@ -10001,6 +11313,10 @@ struct AzCssPropertyVariant_FontFamily { AzCssPropertyTag tag; AzStyleFontFamily
 typedef struct AzCssPropertyVariant_FontFamily AzCssPropertyVariant_FontFamily;
 struct AzCssPropertyVariant_TextAlign { AzCssPropertyTag tag; AzStyleTextAlignValue payload; };
 typedef struct AzCssPropertyVariant_TextAlign AzCssPropertyVariant_TextAlign;
+struct AzCssPropertyVariant_VerticalAlign { AzCssPropertyTag tag; AzStyleVerticalAlignValue payload; };
+typedef struct AzCssPropertyVariant_VerticalAlign AzCssPropertyVariant_VerticalAlign;
+struct AzCssPropertyVariant_Direction { AzCssPropertyTag tag; AzStyleDirectionValue payload; };
+typedef struct AzCssPropertyVariant_Direction AzCssPropertyVariant_Direction;
 struct AzCssPropertyVariant_LetterSpacing { AzCssPropertyTag tag; AzStyleLetterSpacingValue payload; };
 typedef struct AzCssPropertyVariant_LetterSpacing AzCssPropertyVariant_LetterSpacing;
 struct AzCssPropertyVariant_LineHeight { AzCssPropertyTag tag; AzStyleLineHeightValue payload; };
@@ -10053,6 +11369,14 @@ struct AzCssPropertyVariant_AlignItems { AzCssPropertyTag tag; AzLayoutAlignItem
 typedef struct AzCssPropertyVariant_AlignItems AzCssPropertyVariant_AlignItems;
 struct AzCssPropertyVariant_AlignContent { AzCssPropertyTag tag; AzLayoutAlignContentValue payload; };
 typedef struct AzCssPropertyVariant_AlignContent AzCssPropertyVariant_AlignContent;
+struct AzCssPropertyVariant_GridTemplateColumns { AzCssPropertyTag tag; AzLayoutGridTemplateColumnsValue payload; };
+typedef struct AzCssPropertyVariant_GridTemplateColumns AzCssPropertyVariant_GridTemplateColumns;
+struct AzCssPropertyVariant_GridTemplateRows { AzCssPropertyTag tag; AzLayoutGridTemplateRowsValue payload; };
+typedef struct AzCssPropertyVariant_GridTemplateRows AzCssPropertyVariant_GridTemplateRows;
+struct AzCssPropertyVariant_GridColumn { AzCssPropertyTag tag; AzLayoutGridColumnValue payload; };
+typedef struct AzCssPropertyVariant_GridColumn AzCssPropertyVariant_GridColumn;
+struct AzCssPropertyVariant_GridRow { AzCssPropertyTag tag; AzLayoutGridRowValue payload; };
+typedef struct AzCssPropertyVariant_GridRow AzCssPropertyVariant_GridRow;
 struct AzCssPropertyVariant_BackgroundContent { AzCssPropertyTag tag; AzStyleBackgroundContentVecValue payload; };
 typedef struct AzCssPropertyVariant_BackgroundContent AzCssPropertyVariant_BackgroundContent;
 struct AzCssPropertyVariant_BackgroundPosition { AzCssPropertyTag tag; AzStyleBackgroundPositionVecValue payload; };
@@ -10135,17 +11459,37 @@ struct AzCssPropertyVariant_BackfaceVisibility { AzCssPropertyTag tag; AzStyleBa
 typedef struct AzCssPropertyVariant_BackfaceVisibility AzCssPropertyVariant_BackfaceVisibility;
 struct AzCssPropertyVariant_MixBlendMode { AzCssPropertyTag tag; AzStyleMixBlendModeValue payload; };
 typedef struct AzCssPropertyVariant_MixBlendMode AzCssPropertyVariant_MixBlendMode;
+struct AzCssPropertyVariant_Isolation { AzCssPropertyTag tag; AzStyleIsolationValue payload; };
+typedef struct AzCssPropertyVariant_Isolation AzCssPropertyVariant_Isolation;
+struct AzCssPropertyVariant_ImageRendering { AzCssPropertyTag tag; AzStyleImageRenderingValue payload; };
+typedef struct AzCssPropertyVariant_ImageRendering AzCssPropertyVariant_ImageRendering;
 struct AzCssPropertyVariant_Filter { AzCssPropertyTag tag; AzStyleFilterVecValue payload; };
 typedef struct AzCssPropertyVariant_Filter AzCssPropertyVariant_Filter;
 struct AzCssPropertyVariant_BackdropFilter { AzCssPropertyTag tag; AzStyleFilterVecValue payload; };
 typedef struct AzCssPropertyVariant_BackdropFilter AzCssPropertyVariant_BackdropFilter;
 struct AzCssPropertyVariant_TextShadow { AzCssPropertyTag tag; AzStyleBoxShadowValue payload; };
 typedef struct AzCssPropertyVariant_TextShadow AzCssPropertyVariant_TextShadow;
+struct AzCssPropertyVariant_TransitionProperty { AzCssPropertyTag tag; AzStyleTransitionPropertyValue payload; };
+typedef struct AzCssPropertyVariant_TransitionProperty AzCssPropertyVariant_TransitionProperty;
+struct AzCssPropertyVariant_TransitionDuration { AzCssPropertyTag tag; AzStyleTransitionDurationValue payload; };
+typedef struct AzCssPropertyVariant_TransitionDuration AzCssPropertyVariant_TransitionDuration;
+struct AzCssPropertyVariant_TransitionTimingFunction { AzCssPropertyTag tag; AzStyleTransitionTimingFunctionValue payload; };
+typedef struct AzCssPropertyVariant_TransitionTimingFunction AzCssPropertyVariant_TransitionTimingFunction;
+struct AzCssPropertyVariant_TransitionDelay { AzCssPropertyTag tag; AzStyleTransitionDelayValue payload; };
+typedef struct AzCssPropertyVariant_TransitionDelay AzCssPropertyVariant_TransitionDelay;
+struct AzCssPropertyVariant_CaretColor { AzCssPropertyTag tag; AzStyleCaretColorValue payload; };
+typedef struct AzCssPropertyVariant_CaretColor AzCssPropertyVariant_CaretColor;
+struct AzCssPropertyVariant_SelectionColor { AzCssPropertyTag tag; AzStyleSelectionColorValue payload; };
+typedef struct AzCssPropertyVariant_SelectionColor AzCssPropertyVariant_SelectionColor;
+struct AzCssPropertyVariant_SelectionBackground { AzCssPropertyTag tag; AzStyleSelectionBackgroundValue payload; };
+typedef struct AzCssPropertyVariant_SelectionBackground AzCssPropertyVariant_SelectionBackground;
 union AzCssProperty {
     AzCssPropertyVariant_TextColor TextColor;
     AzCssPropertyVariant_FontSize FontSize;
     AzCssPropertyVariant_FontFamily FontFamily;
     AzCssPropertyVariant_TextAlign TextAlign;
+    AzCssPropertyVariant_VerticalAlign VerticalAlign;
+    AzCssPropertyVariant_Direction Direction;
     AzCssPropertyVariant_LetterSpacing LetterSpacing;
     AzCssPropertyVariant_LineHeight LineHeight;
     AzCssPropertyVariant_WordSpacing WordSpacing;
@@ -10172,6 +11516,10 @@ union AzCssProperty {
     AzCssPropertyVariant_JustifyContent JustifyContent;
     AzCssPropertyVariant_AlignItems AlignItems;
     AzCssPropertyVariant_AlignContent AlignContent;
+    AzCssPropertyVariant_GridTemplateColumns GridTemplateColumns;
+    AzCssPropertyVariant_GridTemplateRows GridTemplateRows;
+    AzCssPropertyVariant_GridColumn GridColumn;
+    AzCssPropertyVariant_GridRow GridRow;
     AzCssPropertyVariant_BackgroundContent BackgroundContent;
     AzCssPropertyVariant_BackgroundPosition BackgroundPosition;
     AzCssPropertyVariant_BackgroundSize BackgroundSize;
@@ -10213,9 +11561,18 @@ union AzCssProperty {
     AzCssPropertyVariant_PerspectiveOrigin PerspectiveOrigin;
     AzCssPropertyVariant_BackfaceVisibility BackfaceVisibility;
     AzCssPropertyVariant_MixBlendMode MixBlendMode;
+    AzCssPropertyVariant_Isolation Isolation;
+    AzCssPropertyVariant_ImageRendering ImageRendering;
     AzCssPropertyVariant_Filter Filter;
     AzCssPropertyVariant_BackdropFilter BackdropFilter;
     AzCssPropertyVariant_TextShadow TextShadow;
+    AzCssPropertyVariant_TransitionProperty TransitionProperty;
+    AzCssPropertyVariant_TransitionDuration TransitionDuration;
+    AzCssPropertyVariant_TransitionTimingFunction TransitionTimingFunction;
+    AzCssPropertyVariant_TransitionDelay TransitionDelay;
+    AzCssPropertyVariant_CaretColor CaretColor;
+    AzCssPropertyVariant_SelectionColor SelectionColor;
+    AzCssPropertyVariant_SelectionBackground SelectionBackground;
 };
 typedef union AzCssProperty AzCssProperty;
 
@@ -10414,6 +11771,22 @@ struct AzSvgSimpleNodeVec {
 };
 typedef struct AzSvgSimpleNodeVec AzSvgSimpleNodeVec;
 
+enum AzOptionMediaQueryTag {
+   AzOptionMediaQueryTag_None,
+   AzOptionMediaQueryTag_Some,
+};
+typedef enum AzOptionMediaQueryTag AzOptionMediaQueryTag;
+
+struct AzOptionMediaQueryVariant_None { AzOptionMediaQueryTag tag; };
+typedef struct AzOptionMediaQueryVariant_None AzOptionMediaQueryVariant_None;
+struct AzOptionMediaQueryVariant_Some { AzOptionMediaQueryTag tag; AzMediaQuery payload; };
+typedef struct AzOptionMediaQueryVariant_Some AzOptionMediaQueryVariant_Some;
+union AzOptionMediaQuery {
+    AzOptionMediaQueryVariant_None None;
+    AzOptionMediaQueryVariant_Some Some;
+};
+typedef union AzOptionMediaQuery AzOptionMediaQuery;
+
 enum AzOptionCssPropertyTag {
    AzOptionCssPropertyTag_None,
    AzOptionCssPropertyTag_Some,
@@ -10430,12 +11803,37 @@ union AzOptionCssProperty {
 };
 typedef union AzOptionCssProperty AzOptionCssProperty;
 
+enum AzOptionImeEventTag {
+   AzOptionImeEventTag_None,
+   AzOptionImeEventTag_Some,
+};
+typedef enum AzOptionImeEventTag AzOptionImeEventTag;
+
+struct AzOptionImeEventVariant_None { AzOptionImeEventTag tag; };
+typedef struct AzOptionImeEventVariant_None AzOptionImeEventVariant_None;
+struct AzOptionImeEventVariant_Some { AzOptionImeEventTag tag; AzImeEvent payload; };
+typedef struct AzOptionImeEventVariant_Some AzOptionImeEventVariant_Some;
+union AzOptionImeEvent {
+    AzOptionImeEventVariant_None None;
+    AzOptionImeEventVariant_Some Some;
+};
+typedef union AzOptionImeEvent AzOptionImeEvent;
+
 struct AzXmlTextError {
     AzXmlStreamError stream_error;
     AzSvgParseErrorPosition pos;
 };
 typedef struct AzXmlTextError AzXmlTextError;
 
+struct AzKeyboardState {
+    AzOptionChar current_char;
+    AzOptionVirtualKeyCode current_virtual_keycode;
+    AzVirtualKeyCodeVec pressed_virtual_keycodes;
+    AzScanCodeVec pressed_scancodes;
+    AzOptionImeEvent current_ime_event;
+};
+typedef struct AzKeyboardState AzKeyboardState;
+
 struct AzPlatformSpecificOptions {
     AzWindowsWindowOptions windows_options;
     AzLinuxWindowOptions linux_options;
@@ -10461,6 +11859,7 @@ struct AzWindowState {
     AzColorU background_color;
     AzLayoutCallback layout_callback;
     AzOptionCallback close_callback;
+    AzAcceleratorCallbackVec accelerators;
 };
 typedef struct AzWindowState AzWindowState;
 
@@ -10478,6 +11877,7 @@ struct AzCallbackInfo {
     void* restrict threads;
     void* restrict timers_removed;
     void* restrict threads_removed;
+    void* restrict thread_msgs_sent;
     AzRawWindowHandle* current_window_handle;
     void* restrict new_windows;
     AzSystemCallbacks* system_callbacks;
@@ -10487,6 +11887,8 @@ struct AzCallbackInfo {
     void* restrict images_changed_in_callbacks;
     void* restrict image_masks_changed_in_callbacks;
     void* restrict css_properties_changed_in_callbacks;
+    void* restrict stylesheet_changed_in_callbacks;
+    void* restrict context_menu_changed_in_callbacks;
     void* current_scroll_states;
     void* restrict nodes_scrolled_in_callback;
     AzDomNodeId hit_dom_node;
@@ -10564,6 +11966,12 @@ struct AzDynamicCssProperty {
 };
 typedef struct AzDynamicCssProperty AzDynamicCssProperty;
 
+struct AzCssVariable {
+    AzString name;
+    AzCssProperty value;
+};
+typedef struct AzCssVariable AzCssVariable;
+
 struct AzNode {
     AzNodeTypeId node_type;
     AzNodePosition position;
@@ -10619,6 +12027,14 @@ struct AzNodeDataInlineCssPropertyVec {
 };
 typedef struct AzNodeDataInlineCssPropertyVec AzNodeDataInlineCssPropertyVec;
 
+struct AzCssVariableVec {
+    AzCssVariable* ptr;
+    size_t len;
+    size_t cap;
+    AzCssVariableVecDestructor destructor;
+};
+typedef struct AzCssVariableVec AzCssVariableVec;
+
 enum AzOptionWindowStateTag {
    AzOptionWindowStateTag_None,
    AzOptionWindowStateTag_Some,
@@ -10635,6 +12051,22 @@ union AzOptionWindowState {
 };
 typedef union AzOptionWindowState AzOptionWindowState;
 
+enum AzOptionKeyboardStateTag {
+   AzOptionKeyboardStateTag_None,
+   AzOptionKeyboardStateTag_Some,
+};
+typedef enum AzOptionKeyboardStateTag AzOptionKeyboardStateTag;
+
+struct AzOptionKeyboardStateVariant_None { AzOptionKeyboardStateTag tag; };
+typedef struct AzOptionKeyboardStateVariant_None AzOptionKeyboardStateVariant_None;
+struct AzOptionKeyboardStateVariant_Some { AzOptionKeyboardStateTag tag; AzKeyboardState payload; };
+typedef struct AzOptionKeyboardStateVariant_Some AzOptionKeyboardStateVariant_Some;
+union AzOptionKeyboardState {
+    AzOptionKeyboardStateVariant_None None;
+    AzOptionKeyboardStateVariant_Some Some;
+};
+typedef union AzOptionKeyboardState AzOptionKeyboardState;
+
 enum AzOptionInlineTextTag {
    AzOptionInlineTextTag_None,
    AzOptionInlineTextTag_Some,
@@ -10941,6 +12373,7 @@ typedef struct AzDom AzDom;
 struct AzCssRuleBlock {
     AzCssPath path;
     AzCssDeclarationVec declarations;
+    AzOptionMediaQuery media;
 };
 typedef struct AzCssRuleBlock AzCssRuleBlock;
 
@@ -11113,14 +12546,21 @@ typedef union AzResultSvgSvgParseError AzResultSvgSvgParseError;
 
 struct AzCss {
     AzStylesheetVec stylesheets;
+    AzCssVariableVec variables;
 };
 typedef struct AzCss AzCss;
 
+#define AzRedrawBehavior_OnEventOnly { .OnEventOnly = { .tag = AzRedrawBehaviorTag_OnEventOnly } }
+#define AzRedrawBehavior_MaxFps(v) { .MaxFps = { .tag = AzRedrawBehaviorTag_MaxFps, .payload = v } }
+#define AzRedrawBehavior_Unlimited { .Unlimited = { .tag = AzRedrawBehaviorTag_Unlimited } }
 #define AzAnimationRepeatCount_Times(v) { .Times = { .tag = AzAnimationRepeatCountTag_Times, .payload = v } }
 #define AzAnimationRepeatCount_Infinite { .Infinite = { .tag = AzAnimationRepeatCountTag_Infinite } }
 #define AzTabIndex_Auto { .Auto = { .tag = AzTabIndexTag_Auto } }
 #define AzTabIndex_OverrideInParent(v) { .OverrideInParent = { .tag = AzTabIndexTag_OverrideInParent, .payload = v } }
 #define AzTabIndex_NoKeyboardFocus { .NoKeyboardFocus = { .tag = AzTabIndexTag_NoKeyboardFocus } }
+#define AzGridLine_Auto { .Auto = { .tag = AzGridLineTag_Auto } }
+#define AzGridLine_Line(v) { .Line = { .tag = AzGridLineTag_Line, .payload = v } }
+#define AzGridLine_Span(v) { .Span = { .tag = AzGridLineTag_Span, .payload = v } }
 #define AzIndent_None { .None = { .tag = AzIndentTag_None } }
 #define AzIndent_Spaces(v) { .Spaces = { .tag = AzIndentTag_Spaces, .payload = v } }
 #define AzIndent_Tabs { .Tabs = { .tag = AzIndentTag_Tabs } }
@@ -11203,6 +12643,9 @@ typedef struct AzCss AzCss;
 #define AzVideoModeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzVideoModeVecDestructorTag_DefaultRust } }
 #define AzVideoModeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzVideoModeVecDestructorTag_NoDestructor } }
 #define AzVideoModeVecDestructor_External(v) { .External = { .tag = AzVideoModeVecDestructorTag_External, .payload = v } }
+#define AzAcceleratorCallbackVecDestructor_DefaultRust { .DefaultRust = { .tag = AzAcceleratorCallbackVecDestructorTag_DefaultRust } }
+#define AzAcceleratorCallbackVecDestructor_NoDestructor { .NoDestructor = { .tag = AzAcceleratorCallbackVecDestructorTag_NoDestructor } }
+#define AzAcceleratorCallbackVecDestructor_External(v) { .External = { .tag = AzAcceleratorCallbackVecDestructorTag_External, .payload = v } }
 #define AzDomVecDestructor_DefaultRust { .DefaultRust = { .tag = AzDomVecDestructorTag_DefaultRust } }
 #define AzDomVecDestructor_NoDestructor { .NoDestructor = { .tag = AzDomVecDestructorTag_NoDestructor } }
 #define AzDomVecDestructor_External(v) { .External = { .tag = AzDomVecDestructorTag_External, .payload = v } }
@@ -11227,6 +12670,21 @@ typedef struct AzCss AzCss;
 #define AzStyleTransformVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleTransformVecDestructorTag_DefaultRust } }
 #define AzStyleTransformVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleTransformVecDestructorTag_NoDestructor } }
 #define AzStyleTransformVecDestructor_External(v) { .External = { .tag = AzStyleTransformVecDestructorTag_External, .payload = v } }
+#define AzGridTrackVecDestructor_DefaultRust { .DefaultRust = { .tag = AzGridTrackVecDestructorTag_DefaultRust } }
+#define AzGridTrackVecDestructor_NoDestructor { .NoDestructor = { .tag = AzGridTrackVecDestructorTag_NoDestructor } }
+#define AzGridTrackVecDestructor_External(v) { .External = { .tag = AzGridTrackVecDestructorTag_External, .payload = v } }
+#define AzCssPropertyTypeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssPropertyTypeVecDestructorTag_DefaultRust } }
+#define AzCssPropertyTypeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssPropertyTypeVecDestructorTag_NoDestructor } }
+#define AzCssPropertyTypeVecDestructor_External(v) { .External = { .tag = AzCssPropertyTypeVecDestructorTag_External, .payload = v } }
+#define AzCssVariableVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssVariableVecDestructorTag_DefaultRust } }
+#define AzCssVariableVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssVariableVecDestructorTag_NoDestructor } }
+#define AzCssVariableVecDestructor_External(v) { .External = { .tag = AzCssVariableVecDestructorTag_External, .payload = v } }
+#define AzMediaFeatureVecDestructor_DefaultRust { .DefaultRust = { .tag = AzMediaFeatureVecDestructorTag_DefaultRust } }
+#define AzMediaFeatureVecDestructor_NoDestructor { .NoDestructor = { .tag = AzMediaFeatureVecDestructorTag_NoDestructor } }
+#define AzMediaFeatureVecDestructor_External(v) { .External = { .tag = AzMediaFeatureVecDestructorTag_External, .payload = v } }
+#define AzMediaConditionVecDestructor_DefaultRust { .DefaultRust = { .tag = AzMediaConditionVecDestructorTag_DefaultRust } }
+#define AzMediaConditionVecDestructor_NoDestructor { .NoDestructor = { .tag = AzMediaConditionVecDestructorTag_NoDestructor } }
+#define AzMediaConditionVecDestructor_External(v) { .External = { .tag = AzMediaConditionVecDestructorTag_External, .payload = v } }
 #define AzCssPropertyVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssPropertyVecDestructorTag_DefaultRust } }
 #define AzCssPropertyVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssPropertyVecDestructorTag_NoDestructor } }
 #define AzCssPropertyVecDestructor_External(v) { .External = { .tag = AzCssPropertyVecDestructorTag_External, .payload = v } }
@@ -11399,6 +12857,22 @@ typedef struct AzCss AzCss;
 #define AzStyleBackgroundSize_ExactSize(v) { .ExactSize = { .tag = AzStyleBackgroundSizeTag_ExactSize, .payload = v } }
 #define AzStyleBackgroundSize_Contain { .Contain = { .tag = AzStyleBackgroundSizeTag_Contain } }
 #define AzStyleBackgroundSize_Cover { .Cover = { .tag = AzStyleBackgroundSizeTag_Cover } }
+#define AzStyleTransitionTimingFunction_Linear { .Linear = { .tag = AzStyleTransitionTimingFunctionTag_Linear } }
+#define AzStyleTransitionTimingFunction_Ease { .Ease = { .tag = AzStyleTransitionTimingFunctionTag_Ease } }
+#define AzStyleTransitionTimingFunction_EaseIn { .EaseIn = { .tag = AzStyleTransitionTimingFunctionTag_EaseIn } }
+#define AzStyleTransitionTimingFunction_EaseOut { .EaseOut = { .tag = AzStyleTransitionTimingFunctionTag_EaseOut } }
+#define AzStyleTransitionTimingFunction_EaseInOut { .EaseInOut = { .tag = AzStyleTransitionTimingFunctionTag_EaseInOut } }
+#define AzStyleTransitionTimingFunction_CubicBezier(v) { .CubicBezier = { .tag = AzStyleTransitionTimingFunctionTag_CubicBezier, .payload = v } }
+#define AzStyleTransitionTimingFunction_Steps(v) { .Steps = { .tag = AzStyleTransitionTimingFunctionTag_Steps, .payload = v } }
+#define AzStyleVerticalAlign_Baseline { .Baseline = { .tag = AzStyleVerticalAlignTag_Baseline } }
+#define AzStyleVerticalAlign_Top { .Top = { .tag = AzStyleVerticalAlignTag_Top } }
+#define AzStyleVerticalAlign_Middle { .Middle = { .tag = AzStyleVerticalAlignTag_Middle } }
+#define AzStyleVerticalAlign_Bottom { .Bottom = { .tag = AzStyleVerticalAlignTag_Bottom } }
+#define AzStyleVerticalAlign_TextTop { .TextTop = { .tag = AzStyleVerticalAlignTag_TextTop } }
+#define AzStyleVerticalAlign_TextBottom { .TextBottom = { .tag = AzStyleVerticalAlignTag_TextBottom } }
+#define AzStyleVerticalAlign_Sub { .Sub = { .tag = AzStyleVerticalAlignTag_Sub } }
+#define AzStyleVerticalAlign_Super { .Super = { .tag = AzStyleVerticalAlignTag_Super } }
+#define AzStyleVerticalAlign_Exact(v) { .Exact = { .tag = AzStyleVerticalAlignTag_Exact, .payload = v } }
 #define AzStyleBoxShadowValue_Auto { .Auto = { .tag = AzStyleBoxShadowValueTag_Auto } }
 #define AzStyleBoxShadowValue_None { .None = { .tag = AzStyleBoxShadowValueTag_None } }
 #define AzStyleBoxShadowValue_Inherit { .Inherit = { .tag = AzStyleBoxShadowValueTag_Inherit } }
@@ -11409,6 +12883,16 @@ typedef struct AzCss AzCss;
 #define AzLayoutAlignContentValue_Inherit { .Inherit = { .tag = AzLayoutAlignContentValueTag_Inherit } }
 #define AzLayoutAlignContentValue_Initial { .Initial = { .tag = AzLayoutAlignContentValueTag_Initial } }
 #define AzLayoutAlignContentValue_Exact(v) { .Exact = { .tag = AzLayoutAlignContentValueTag_Exact, .payload = v } }
+#define AzLayoutGridColumnValue_Auto { .Auto = { .tag = AzLayoutGridColumnValueTag_Auto } }
+#define AzLayoutGridColumnValue_None { .None = { .tag = AzLayoutGridColumnValueTag_None } }
+#define AzLayoutGridColumnValue_Inherit { .Inherit = { .tag = AzLayoutGridColumnValueTag_Inherit } }
+#define AzLayoutGridColumnValue_Initial { .Initial = { .tag = AzLayoutGridColumnValueTag_Initial } }
+#define AzLayoutGridColumnValue_Exact(v) { .Exact = { .tag = AzLayoutGridColumnValueTag_Exact, .payload = v } }
+#define AzLayoutGridRowValue_Auto { .Auto = { .tag = AzLayoutGridRowValueTag_Auto } }
+#define AzLayoutGridRowValue_None { .None = { .tag = AzLayoutGridRowValueTag_None } }
+#define AzLayoutGridRowValue_Inherit { .Inherit = { .tag = AzLayoutGridRowValueTag_Inherit } }
+#define AzLayoutGridRowValue_Initial { .Initial = { .tag = AzLayoutGridRowValueTag_Initial } }
+#define AzLayoutGridRowValue_Exact(v) { .Exact = { .tag = AzLayoutGridRowValueTag_Exact, .payload = v } }
 #define AzLayoutAlignItemsValue_Auto { .Auto = { .tag = AzLayoutAlignItemsValueTag_Auto } }
 #define AzLayoutAlignItemsValue_None { .None = { .tag = AzLayoutAlignItemsValueTag_None } }
 #define AzLayoutAlignItemsValue_Inherit { .Inherit = { .tag = AzLayoutAlignItemsValueTag_Inherit } }
@@ -11419,6 +12903,7 @@ typedef struct AzCss AzCss;
 #define AzLayoutBottomValue_Inherit { .Inherit = { .tag = AzLayoutBottomValueTag_Inherit } }
 #define AzLayoutBottomValue_Initial { .Initial = { .tag = AzLayoutBottomValueTag_Initial } }
 #define AzLayoutBottomValue_Exact(v) { .Exact = { .tag = AzLayoutBottomValueTag_Exact, .payload = v } }
+#define AzLayoutBottomValue_Calc(v) { .Calc = { .tag = AzLayoutBottomValueTag_Calc, .payload = v } }
 #define AzLayoutBoxSizingValue_Auto { .Auto = { .tag = AzLayoutBoxSizingValueTag_Auto } }
 #define AzLayoutBoxSizingValue_None { .None = { .tag = AzLayoutBoxSizingValueTag_None } }
 #define AzLayoutBoxSizingValue_Inherit { .Inherit = { .tag = AzLayoutBoxSizingValueTag_Inherit } }
@@ -11454,6 +12939,7 @@ typedef struct AzCss AzCss;
 #define AzLayoutHeightValue_Inherit { .Inherit = { .tag = AzLayoutHeightValueTag_Inherit } }
 #define AzLayoutHeightValue_Initial { .Initial = { .tag = AzLayoutHeightValueTag_Initial } }
 #define AzLayoutHeightValue_Exact(v) { .Exact = { .tag = AzLayoutHeightValueTag_Exact, .payload = v } }
+#define AzLayoutHeightValue_Calc(v) { .Calc = { .tag = AzLayoutHeightValueTag_Calc, .payload = v } }
 #define AzLayoutJustifyContentValue_Auto { .Auto = { .tag = AzLayoutJustifyContentValueTag_Auto } }
 #define AzLayoutJustifyContentValue_None { .None = { .tag = AzLayoutJustifyContentValueTag_None } }
 #define AzLayoutJustifyContentValue_Inherit { .Inherit = { .tag = AzLayoutJustifyContentValueTag_Inherit } }
@@ -11464,66 +12950,79 @@ typedef struct AzCss AzCss;
 #define AzLayoutLeftValue_Inherit { .Inherit = { .tag = AzLayoutLeftValueTag_Inherit } }
 #define AzLayoutLeftValue_Initial { .Initial = { .tag = AzLayoutLeftValueTag_Initial } }
 #define AzLayoutLeftValue_Exact(v) { .Exact = { .tag = AzLayoutLeftValueTag_Exact, .payload = v } }
+#define AzLayoutLeftValue_Calc(v) { .Calc = { .tag = AzLayoutLeftValueTag_Calc, .payload = v } }
 #define AzLayoutMarginBottomValue_Auto { .Auto = { .tag = AzLayoutMarginBottomValueTag_Auto } }
 #define AzLayoutMarginBottomValue_None { .None = { .tag = AzLayoutMarginBottomValueTag_None } }
 #define AzLayoutMarginBottomValue_Inherit { .Inherit = { .tag = AzLayoutMarginBottomValueTag_Inherit } }
 #define AzLayoutMarginBottomValue_Initial { .Initial = { .tag = AzLayoutMarginBottomValueTag_Initial } }
 #define AzLayoutMarginBottomValue_Exact(v) { .Exact = { .tag = AzLayoutMarginBottomValueTag_Exact, .payload = v } }
+#define AzLayoutMarginBottomValue_Calc(v) { .Calc = { .tag = AzLayoutMarginBottomValueTag_Calc, .payload = v } }
 #define AzLayoutMarginLeftValue_Auto { .Auto = { .tag = AzLayoutMarginLeftValueTag_Auto } }
 #define AzLayoutMarginLeftValue_None { .None = { .tag = AzLayoutMarginLeftValueTag_None } }
 #define AzLayoutMarginLeftValue_Inherit { .Inherit = { .tag = AzLayoutMarginLeftValueTag_Inherit } }
 #define AzLayoutMarginLeftValue_Initial { .Initial = { .tag = AzLayoutMarginLeftValueTag_Initial } }
 #define AzLayoutMarginLeftValue_Exact(v) { .Exact = { .tag = AzLayoutMarginLeftValueTag_Exact, .payload = v } }
+#define AzLayoutMarginLeftValue_Calc(v) { .Calc = { .tag = AzLayoutMarginLeftValueTag_Calc, .payload = v } }
 #define AzLayoutMarginRightValue_Auto { .Auto = { .tag = AzLayoutMarginRightValueTag_Auto } }
 #define AzLayoutMarginRightValue_None { .None = { .tag = AzLayoutMarginRightValueTag_None } }
 #define AzLayoutMarginRightValue_Inherit { .Inherit = { .tag = AzLayoutMarginRightValueTag_Inherit } }
 #define AzLayoutMarginRightValue_Initial { .Initial = { .tag = AzLayoutMarginRightValueTag_Initial } }
 #define AzLayoutMarginRightValue_Exact(v) { .Exact = { .tag = AzLayoutMarginRightValueTag_Exact, .payload = v } }
+#define AzLayoutMarginRightValue_Calc(v) { .Calc = { .tag = AzLayoutMarginRightValueTag_Calc, .payload = v } }
 #define AzLayoutMarginTopValue_Auto { .Auto = { .tag = AzLayoutMarginTopValueTag_Auto } }
 #define AzLayoutMarginTopValue_None { .None = { .tag = AzLayoutMarginTopValueTag_None } }
 #define AzLayoutMarginTopValue_Inherit { .Inherit = { .tag = AzLayoutMarginTopValueTag_Inherit } }
 #define AzLayoutMarginTopValue_Initial { .Initial = { .tag = AzLayoutMarginTopValueTag_Initial } }
 #define AzLayoutMarginTopValue_Exact(v) { .Exact = { .tag = AzLayoutMarginTopValueTag_Exact, .payload = v } }
+#define AzLayoutMarginTopValue_Calc(v) { .Calc = { .tag = AzLayoutMarginTopValueTag_Calc, .payload = v } }
 #define AzLayoutMaxHeightValue_Auto { .Auto = { .tag = AzLayoutMaxHeightValueTag_Auto } }
 #define AzLayoutMaxHeightValue_None { .None = { .tag = AzLayoutMaxHeightValueTag_None } }
 #define AzLayoutMaxHeightValue_Inherit { .Inherit = { .tag = AzLayoutMaxHeightValueTag_Inherit } }
 #define AzLayoutMaxHeightValue_Initial { .Initial = { .tag = AzLayoutMaxHeightValueTag_Initial } }
 #define AzLayoutMaxHeightValue_Exact(v) { .Exact = { .tag = AzLayoutMaxHeightValueTag_Exact, .payload = v } }
+#define AzLayoutMaxHeightValue_Calc(v) { .Calc = { .tag = AzLayoutMaxHeightValueTag_Calc, .payload = v } }
 #define AzLayoutMaxWidthValue_Auto { .Auto = { .tag = AzLayoutMaxWidthValueTag_Auto } }
 #define AzLayoutMaxWidthValue_None { .None = { .tag = AzLayoutMaxWidthValueTag_None } }
 #define AzLayoutMaxWidthValue_Inherit { .Inherit = { .tag = AzLayoutMaxWidthValueTag_Inherit } }
 #define AzLayoutMaxWidthValue_Initial { .Initial = { .tag = AzLayoutMaxWidthValueTag_Initial } }
 #define AzLayoutMaxWidthValue_Exact(v) { .Exact = { .tag = AzLayoutMaxWidthValueTag_Exact, .payload = v } }
+#define AzLayoutMaxWidthValue_Calc(v) { .Calc = { .tag = AzLayoutMaxWidthValueTag_Calc, .payload = v } }
 #define AzLayoutMinHeightValue_Auto { .Auto = { .tag = AzLayoutMinHeightValueTag_Auto } }
 #define AzLayoutMinHeightValue_None { .None = { .tag = AzLayoutMinHeightValueTag_None } }
 #define AzLayoutMinHeightValue_Inherit { .Inherit = { .tag = AzLayoutMinHeightValueTag_Inherit } }
 #define AzLayoutMinHeightValue_Initial { .Initial = { .tag = AzLayoutMinHeightValueTag_Initial } }
 #define AzLayoutMinHeightValue_Exact(v) { .Exact = { .tag = AzLayoutMinHeightValueTag_Exact, .payload = v } }
+#define AzLayoutMinHeightValue_Calc(v) { .Calc = { .tag = AzLayoutMinHeightValueTag_Calc, .payload = v } }
 #define AzLayoutMinWidthValue_Auto { .Auto = { .tag = AzLayoutMinWidthValueTag_Auto } }
 #define AzLayoutMinWidthValue_None { .None = { .tag = AzLayoutMinWidthValueTag_None } }
 #define AzLayoutMinWidthValue_Inherit { .Inherit = { .tag = AzLayoutMinWidthValueTag_Inherit } }
 #define AzLayoutMinWidthValue_Initial { .Initial = { .tag = AzLayoutMinWidthValueTag_Initial } }
 #define AzLayoutMinWidthValue_Exact(v) { .Exact = { .tag = AzLayoutMinWidthValueTag_Exact, .payload = v } }
+#define AzLayoutMinWidthValue_Calc(v) { .Calc = { .tag = AzLayoutMinWidthValueTag_Calc, .payload = v } }
 #define AzLayoutPaddingBottomValue_Auto { .Auto = { .tag = AzLayoutPaddingBottomValueTag_Auto } }
 #define AzLayoutPaddingBottomValue_None { .None = { .tag = AzLayoutPaddingBottomValueTag_None } }
 #define AzLayoutPaddingBottomValue_Inherit { .Inherit = { .tag = AzLayoutPaddingBottomValueTag_Inherit } }
 #define AzLayoutPaddingBottomValue_Initial { .Initial = { .tag = AzLayoutPaddingBottomValueTag_Initial } }
 #define AzLayoutPaddingBottomValue_Exact(v) { .Exact = { .tag = AzLayoutPaddingBottomValueTag_Exact, .payload = v } }
+#define AzLayoutPaddingBottomValue_Calc(v) { .Calc = { .tag = AzLayoutPaddingBottomValueTag_Calc, .payload = v } }
 #define AzLayoutPaddingLeftValue_Auto { .Auto = { .tag = AzLayoutPaddingLeftValueTag_Auto } }
 #define AzLayoutPaddingLeftValue_None { .None = { .tag = AzLayoutPaddingLeftValueTag_None } }
 #define AzLayoutPaddingLeftValue_Inherit { .Inherit = { .tag = AzLayoutPaddingLeftValueTag_Inherit } }
 #define AzLayoutPaddingLeftValue_Initial { .Initial = { .tag = AzLayoutPaddingLeftValueTag_Initial } }
 #define AzLayoutPaddingLeftValue_Exact(v) { .Exact = { .tag = AzLayoutPaddingLeftValueTag_Exact, .payload = v } }
+#define AzLayoutPaddingLeftValue_Calc(v) { .Calc = { .tag = AzLayoutPaddingLeftValueTag_Calc, .payload = v } }
 #define AzLayoutPaddingRightValue_Auto { .Auto = { .tag = AzLayoutPaddingRightValueTag_Auto } }
 #define AzLayoutPaddingRightValue_None { .None = { .tag = AzLayoutPaddingRightValueTag_None } }
 #define AzLayoutPaddingRightValue_Inherit { .Inherit = { .tag = AzLayoutPaddingRightValueTag_Inherit } }
 #define AzLayoutPaddingRightValue_Initial { .Initial = { .tag = AzLayoutPaddingRightValueTag_Initial } }
 #define AzLayoutPaddingRightValue_Exact(v) { .Exact = { .tag = AzLayoutPaddingRightValueTag_Exact, .payload = v } }
+#define AzLayoutPaddingRightValue_Calc(v) { .Calc = { .tag = AzLayoutPaddingRightValueTag_Calc, .payload = v } }
 #define AzLayoutPaddingTopValue_Auto { .Auto = { .tag = AzLayoutPaddingTopValueTag_Auto } }
 #define AzLayoutPaddingTopValue_None { .None = { .tag = AzLayoutPaddingTopValueTag_None } }
 #define AzLayoutPaddingTopValue_Inherit { .Inherit = { .tag = AzLayoutPaddingTopValueTag_Inherit } }
 #define AzLayoutPaddingTopValue_Initial { .Initial = { .tag = AzLayoutPaddingTopValueTag_Initial } }
 #define AzLayoutPaddingTopValue_Exact(v) { .Exact = { .tag = AzLayoutPaddingTopValueTag_Exact, .payload = v } }
+#define AzLayoutPaddingTopValue_Calc(v) { .Calc = { .tag = AzLayoutPaddingTopValueTag_Calc, .payload = v } }
 #define AzLayoutPositionValue_Auto { .Auto = { .tag = AzLayoutPositionValueTag_Auto } }
 #define AzLayoutPositionValue_None { .None = { .tag = AzLayoutPositionValueTag_None } }
 #define AzLayoutPositionValue_Inherit { .Inherit = { .tag = AzLayoutPositionValueTag_Inherit } }
@@ -11534,16 +13033,19 @@ typedef struct AzCss AzCss;
 #define AzLayoutRightValue_Inherit { .Inherit = { .tag = AzLayoutRightValueTag_Inherit } }
 #define AzLayoutRightValue_Initial { .Initial = { .tag = AzLayoutRightValueTag_Initial } }
 #define AzLayoutRightValue_Exact(v) { .Exact = { .tag = AzLayoutRightValueTag_Exact, .payload = v } }
+#define AzLayoutRightValue_Calc(v) { .Calc = { .tag = AzLayoutRightValueTag_Calc, .payload = v } }
 #define AzLayoutTopValue_Auto { .Auto = { .tag = AzLayoutTopValueTag_Auto } }
 #define AzLayoutTopValue_None { .None = { .tag = AzLayoutTopValueTag_None } }
 #define AzLayoutTopValue_Inherit { .Inherit = { .tag = AzLayoutTopValueTag_Inherit } }
 #define AzLayoutTopValue_Initial { .Initial = { .tag = AzLayoutTopValueTag_Initial } }
 #define AzLayoutTopValue_Exact(v) { .Exact = { .tag = AzLayoutTopValueTag_Exact, .payload = v } }
+#define AzLayoutTopValue_Calc(v) { .Calc = { .tag = AzLayoutTopValueTag_Calc, .payload = v } }
 #define AzLayoutWidthValue_Auto { .Auto = { .tag = AzLayoutWidthValueTag_Auto } }
 #define AzLayoutWidthValue_None { .None = { .tag = AzLayoutWidthValueTag_None } }
 #define AzLayoutWidthValue_Inherit { .Inherit = { .tag = AzLayoutWidthValueTag_Inherit } }
 #define AzLayoutWidthValue_Initial { .Initial = { .tag = AzLayoutWidthValueTag_Initial } }
 #define AzLayoutWidthValue_Exact(v) { .Exact = { .tag = AzLayoutWidthValueTag_Exact, .payload = v } }
+#define AzLayoutWidthValue_Calc(v) { .Calc = { .tag = AzLayoutWidthValueTag_Calc, .payload = v } }
 #define AzLayoutFlexWrapValue_Auto { .Auto = { .tag = AzLayoutFlexWrapValueTag_Auto } }
 #define AzLayoutFlexWrapValue_None { .None = { .tag = AzLayoutFlexWrapValueTag_None } }
 #define AzLayoutFlexWrapValue_Inherit { .Inherit = { .tag = AzLayoutFlexWrapValueTag_Inherit } }
@@ -11664,6 +13166,46 @@ typedef struct AzCss AzCss;
 #define AzStyleTextAlignValue_Inherit { .Inherit = { .tag = AzStyleTextAlignValueTag_Inherit } }
 #define AzStyleTextAlignValue_Initial { .Initial = { .tag = AzStyleTextAlignValueTag_Initial } }
 #define AzStyleTextAlignValue_Exact(v) { .Exact = { .tag = AzStyleTextAlignValueTag_Exact, .payload = v } }
+#define AzStyleDirectionValue_Auto { .Auto = { .tag = AzStyleDirectionValueTag_Auto } }
+#define AzStyleDirectionValue_None { .None = { .tag = AzStyleDirectionValueTag_None } }
+#define AzStyleDirectionValue_Inherit { .Inherit = { .tag = AzStyleDirectionValueTag_Inherit } }
+#define AzStyleDirectionValue_Initial { .Initial = { .tag = AzStyleDirectionValueTag_Initial } }
+#define AzStyleDirectionValue_Exact(v) { .Exact = { .tag = AzStyleDirectionValueTag_Exact, .payload = v } }
+#define AzStyleTransitionDurationValue_Auto { .Auto = { .tag = AzStyleTransitionDurationValueTag_Auto } }
+#define AzStyleTransitionDurationValue_None { .None = { .tag = AzStyleTransitionDurationValueTag_None } }
+#define AzStyleTransitionDurationValue_Inherit { .Inherit = { .tag = AzStyleTransitionDurationValueTag_Inherit } }
+#define AzStyleTransitionDurationValue_Initial { .Initial = { .tag = AzStyleTransitionDurationValueTag_Initial } }
+#define AzStyleTransitionDurationValue_Exact(v) { .Exact = { .tag = AzStyleTransitionDurationValueTag_Exact, .payload = v } }
+#define AzStyleTransitionTimingFunctionValue_Auto { .Auto = { .tag = AzStyleTransitionTimingFunctionValueTag_Auto } }
+#define AzStyleTransitionTimingFunctionValue_None { .None = { .tag = AzStyleTransitionTimingFunctionValueTag_None } }
+#define AzStyleTransitionTimingFunctionValue_Inherit { .Inherit = { .tag = AzStyleTransitionTimingFunctionValueTag_Inherit } }
+#define AzStyleTransitionTimingFunctionValue_Initial { .Initial = { .tag = AzStyleTransitionTimingFunctionValueTag_Initial } }
+#define AzStyleTransitionTimingFunctionValue_Exact(v) { .Exact = { .tag = AzStyleTransitionTimingFunctionValueTag_Exact, .payload = v } }
+#define AzStyleTransitionDelayValue_Auto { .Auto = { .tag = AzStyleTransitionDelayValueTag_Auto } }
+#define AzStyleTransitionDelayValue_None { .None = { .tag = AzStyleTransitionDelayValueTag_None } }
+#define AzStyleTransitionDelayValue_Inherit { .Inherit = { .tag = AzStyleTransitionDelayValueTag_Inherit } }
+#define AzStyleTransitionDelayValue_Initial { .Initial = { .tag = AzStyleTransitionDelayValueTag_Initial } }
+#define AzStyleTransitionDelayValue_Exact(v) { .Exact = { .tag = AzStyleTransitionDelayValueTag_Exact, .payload = v } }
+#define AzStyleCaretColorValue_Auto { .Auto = { .tag = AzStyleCaretColorValueTag_Auto } }
+#define AzStyleCaretColorValue_None { .None = { .tag = AzStyleCaretColorValueTag_None } }
+#define AzStyleCaretColorValue_Inherit { .Inherit = { .tag = AzStyleCaretColorValueTag_Inherit } }
+#define AzStyleCaretColorValue_Initial { .Initial = { .tag = AzStyleCaretColorValueTag_Initial } }
+#define AzStyleCaretColorValue_Exact(v) { .Exact = { .tag = AzStyleCaretColorValueTag_Exact, .payload = v } }
+#define AzStyleSelectionColorValue_Auto { .Auto = { .tag = AzStyleSelectionColorValueTag_Auto } }
+#define AzStyleSelectionColorValue_None { .None = { .tag = AzStyleSelectionColorValueTag_None } }
+#define AzStyleSelectionColorValue_Inherit { .Inherit = { .tag = AzStyleSelectionColorValueTag_Inherit } }
+#define AzStyleSelectionColorValue_Initial { .Initial = { .tag = AzStyleSelectionColorValueTag_Initial } }
+#define AzStyleSelectionColorValue_Exact(v) { .Exact = { .tag = AzStyleSelectionColorValueTag_Exact, .payload = v } }
+#define AzStyleSelectionBackgroundValue_Auto { .Auto = { .tag = AzStyleSelectionBackgroundValueTag_Auto } }
+#define AzStyleSelectionBackgroundValue_None { .None = { .tag = AzStyleSelectionBackgroundValueTag_None } }
+#define AzStyleSelectionBackgroundValue_Inherit { .Inherit = { .tag = AzStyleSelectionBackgroundValueTag_Inherit } }
+#define AzStyleSelectionBackgroundValue_Initial { .Initial = { .tag = AzStyleSelectionBackgroundValueTag_Initial } }
+#define AzStyleSelectionBackgroundValue_Exact(v) { .Exact = { .tag = AzStyleSelectionBackgroundValueTag_Exact, .payload = v } }
+#define AzStyleVerticalAlignValue_Auto { .Auto = { .tag = AzStyleVerticalAlignValueTag_Auto } }
+#define AzStyleVerticalAlignValue_None { .None = { .tag = AzStyleVerticalAlignValueTag_None } }
+#define AzStyleVerticalAlignValue_Inherit { .Inherit = { .tag = AzStyleVerticalAlignValueTag_Inherit } }
+#define AzStyleVerticalAlignValue_Initial { .Initial = { .tag = AzStyleVerticalAlignValueTag_Initial } }
+#define AzStyleVerticalAlignValue_Exact(v) { .Exact = { .tag = AzStyleVerticalAlignValueTag_Exact, .payload = v } }
 #define AzStyleTextColorValue_Auto { .Auto = { .tag = AzStyleTextColorValueTag_Auto } }
 #define AzStyleTextColorValue_None { .None = { .tag = AzStyleTextColorValueTag_None } }
 #define AzStyleTextColorValue_Inherit { .Inherit = { .tag = AzStyleTextColorValueTag_Inherit } }
@@ -11699,6 +13241,16 @@ typedef struct AzCss AzCss;
 #define AzStyleMixBlendModeValue_Inherit { .Inherit = { .tag = AzStyleMixBlendModeValueTag_Inherit } }
 #define AzStyleMixBlendModeValue_Initial { .Initial = { .tag = AzStyleMixBlendModeValueTag_Initial } }
 #define AzStyleMixBlendModeValue_Exact(v) { .Exact = { .tag = AzStyleMixBlendModeValueTag_Exact, .payload = v } }
+#define AzStyleIsolationValue_Auto { .Auto = { .tag = AzStyleIsolationValueTag_Auto } }
+#define AzStyleIsolationValue_None { .None = { .tag = AzStyleIsolationValueTag_None } }
+#define AzStyleIsolationValue_Inherit { .Inherit = { .tag = AzStyleIsolationValueTag_Inherit } }
+#define AzStyleIsolationValue_Initial { .Initial = { .tag = AzStyleIsolationValueTag_Initial } }
+#define AzStyleIsolationValue_Exact(v) { .Exact = { .tag = AzStyleIsolationValueTag_Exact, .payload = v } }
+#define AzStyleImageRenderingValue_Auto { .Auto = { .tag = AzStyleImageRenderingValueTag_Auto } }
+#define AzStyleImageRenderingValue_None { .None = { .tag = AzStyleImageRenderingValueTag_None } }
+#define AzStyleImageRenderingValue_Inherit { .Inherit = { .tag = AzStyleImageRenderingValueTag_Inherit } }
+#define AzStyleImageRenderingValue_Initial { .Initial = { .tag = AzStyleImageRenderingValueTag_Initial } }
+#define AzStyleImageRenderingValue_Exact(v) { .Exact = { .tag = AzStyleImageRenderingValueTag_Exact, .payload = v } }
 #define AzTextInputSelection_All { .All = { .tag = AzTextInputSelectionTag_All } }
 #define AzTextInputSelection_FromTo(v) { .FromTo = { .tag = AzTextInputSelectionTag_FromTo, .payload = v } }
 #define AzDuration_System(v) { .System = { .tag = AzDurationTag_System, .payload = v } }
@@ -11760,6 +13312,8 @@ typedef struct AzCss AzCss;
 #define AzOptionMenuItemIcon_Some(v) { .Some = { .tag = AzOptionMenuItemIconTag_Some, .payload = v } }
 #define AzOptionMenuCallback_None { .None = { .tag = AzOptionMenuCallbackTag_None } }
 #define AzOptionMenuCallback_Some(v) { .Some = { .tag = AzOptionMenuCallbackTag_Some, .payload = v } }
+#define AzOptionNormalizedLinearColorStop_None { .None = { .tag = AzOptionNormalizedLinearColorStopTag_None } }
+#define AzOptionNormalizedLinearColorStop_Some(v) { .Some = { .tag = AzOptionNormalizedLinearColorStopTag_Some, .payload = v } }
 #define AzOptionPositionInfo_None { .None = { .tag = AzOptionPositionInfoTag_None } }
 #define AzOptionPositionInfo_Some(v) { .Some = { .tag = AzOptionPositionInfoTag_Some, .payload = v } }
 #define AzOptionTimerId_None { .None = { .tag = AzOptionTimerIdTag_None } }
@@ -11804,12 +13358,16 @@ typedef struct AzCss AzCss;
 #define AzOptionSvgDashPattern_Some(v) { .Some = { .tag = AzOptionSvgDashPatternTag_Some, .payload = v } }
 #define AzOptionLogicalPosition_None { .None = { .tag = AzOptionLogicalPositionTag_None } }
 #define AzOptionLogicalPosition_Some(v) { .Some = { .tag = AzOptionLogicalPositionTag_Some, .payload = v } }
+#define AzOptionLogicalRect_None { .None = { .tag = AzOptionLogicalRectTag_None } }
+#define AzOptionLogicalRect_Some(v) { .Some = { .tag = AzOptionLogicalRectTag_Some, .payload = v } }
 #define AzOptionPhysicalPositionI32_None { .None = { .tag = AzOptionPhysicalPositionI32Tag_None } }
 #define AzOptionPhysicalPositionI32_Some(v) { .Some = { .tag = AzOptionPhysicalPositionI32Tag_Some, .payload = v } }
 #define AzOptionMouseCursorType_None { .None = { .tag = AzOptionMouseCursorTypeTag_None } }
 #define AzOptionMouseCursorType_Some(v) { .Some = { .tag = AzOptionMouseCursorTypeTag_Some, .payload = v } }
 #define AzOptionLogicalSize_None { .None = { .tag = AzOptionLogicalSizeTag_None } }
 #define AzOptionLogicalSize_Some(v) { .Some = { .tag = AzOptionLogicalSizeTag_Some, .payload = v } }
+#define AzOptionImeCursorRange_None { .None = { .tag = AzOptionImeCursorRangeTag_None } }
+#define AzOptionImeCursorRange_Some(v) { .Some = { .tag = AzOptionImeCursorRangeTag_Some, .payload = v } }
 #define AzOptionVirtualKeyCode_None { .None = { .tag = AzOptionVirtualKeyCodeTag_None } }
 #define AzOptionVirtualKeyCode_Some(v) { .Some = { .tag = AzOptionVirtualKeyCodeTag_Some, .payload = v } }
 #define AzOptionImageMask_None { .None = { .tag = AzOptionImageMaskTag_None } }
@@ -11846,6 +13404,21 @@ typedef struct AzCss AzCss;
 #define AzCssPathPseudoSelector_Hover { .Hover = { .tag = AzCssPathPseudoSelectorTag_Hover } }
 #define AzCssPathPseudoSelector_Active { .Active = { .tag = AzCssPathPseudoSelectorTag_Active } }
 #define AzCssPathPseudoSelector_Focus { .Focus = { .tag = AzCssPathPseudoSelectorTag_Focus } }
+#define AzMediaFeature_MinWidth(v) { .MinWidth = { .tag = AzMediaFeatureTag_MinWidth, .payload = v } }
+#define AzMediaFeature_MaxWidth(v) { .MaxWidth = { .tag = AzMediaFeatureTag_MaxWidth, .payload = v } }
+#define AzMediaFeature_MinHeight(v) { .MinHeight = { .tag = AzMediaFeatureTag_MinHeight, .payload = v } }
+#define AzMediaFeature_MaxHeight(v) { .MaxHeight = { .tag = AzMediaFeatureTag_MaxHeight, .payload = v } }
+#define AzMediaFeature_WidthLessThan(v) { .WidthLessThan = { .tag = AzMediaFeatureTag_WidthLessThan, .payload = v } }
+#define AzMediaFeature_WidthGreaterThan(v) { .WidthGreaterThan = { .tag = AzMediaFeatureTag_WidthGreaterThan, .payload = v } }
+#define AzMediaFeature_HeightLessThan(v) { .HeightLessThan = { .tag = AzMediaFeatureTag_HeightLessThan, .payload = v } }
+#define AzMediaFeature_HeightGreaterThan(v) { .HeightGreaterThan = { .tag = AzMediaFeatureTag_HeightGreaterThan, .payload = v } }
+#define AzMediaFeature_Orientation(v) { .Orientation = { .tag = AzMediaFeatureTag_Orientation, .payload = v } }
+#define AzMediaFeature_PrefersColorScheme(v) { .PrefersColorScheme = { .tag = AzMediaFeatureTag_PrefersColorScheme, .payload = v } }
+#define AzMediaFeature_Resolution(v) { .Resolution = { .tag = AzMediaFeatureTag_Resolution, .payload = v } }
+#define AzMediaFeature_MinResolution(v) { .MinResolution = { .tag = AzMediaFeatureTag_MinResolution, .payload = v } }
+#define AzMediaFeature_MaxResolution(v) { .MaxResolution = { .tag = AzMediaFeatureTag_MaxResolution, .payload = v } }
+#define AzMediaFeature_ResolutionLessThan(v) { .ResolutionLessThan = { .tag = AzMediaFeatureTag_ResolutionLessThan, .payload = v } }
+#define AzMediaFeature_ResolutionGreaterThan(v) { .ResolutionGreaterThan = { .tag = AzMediaFeatureTag_ResolutionGreaterThan, .payload = v } }
 #define AzAnimationInterpolationFunction_Ease { .Ease = { .tag = AzAnimationInterpolationFunctionTag_Ease } }
 #define AzAnimationInterpolationFunction_Linear { .Linear = { .tag = AzAnimationInterpolationFunctionTag_Linear } }
 #define AzAnimationInterpolationFunction_EaseIn { .EaseIn = { .tag = AzAnimationInterpolationFunctionTag_EaseIn } }
@@ -11882,6 +13455,19 @@ typedef struct AzCss AzCss;
 #define AzStyleTransform_SkewX(v) { .SkewX = { .tag = AzStyleTransformTag_SkewX, .payload = v } }
 #define AzStyleTransform_SkewY(v) { .SkewY = { .tag = AzStyleTransformTag_SkewY, .payload = v } }
 #define AzStyleTransform_Perspective(v) { .Perspective = { .tag = AzStyleTransformTag_Perspective, .payload = v } }
+#define AzStyleTransitionProperty_All { .All = { .tag = AzStyleTransitionPropertyTag_All } }
+#define AzStyleTransitionProperty_None { .None = { .tag = AzStyleTransitionPropertyTag_None } }
+#define AzStyleTransitionProperty_List(v) { .List = { .tag = AzStyleTransitionPropertyTag_List, .payload = v } }
+#define AzLayoutGridTemplateColumnsValue_Auto { .Auto = { .tag = AzLayoutGridTemplateColumnsValueTag_Auto } }
+#define AzLayoutGridTemplateColumnsValue_None { .None = { .tag = AzLayoutGridTemplateColumnsValueTag_None } }
+#define AzLayoutGridTemplateColumnsValue_Inherit { .Inherit = { .tag = AzLayoutGridTemplateColumnsValueTag_Inherit } }
+#define AzLayoutGridTemplateColumnsValue_Initial { .Initial = { .tag = AzLayoutGridTemplateColumnsValueTag_Initial } }
+#define AzLayoutGridTemplateColumnsValue_Exact(v) { .Exact = { .tag = AzLayoutGridTemplateColumnsValueTag_Exact, .payload = v } }
+#define AzLayoutGridTemplateRowsValue_Auto { .Auto = { .tag = AzLayoutGridTemplateRowsValueTag_Auto } }
+#define AzLayoutGridTemplateRowsValue_None { .None = { .tag = AzLayoutGridTemplateRowsValueTag_None } }
+#define AzLayoutGridTemplateRowsValue_Inherit { .Inherit = { .tag = AzLayoutGridTemplateRowsValueTag_Inherit } }
+#define AzLayoutGridTemplateRowsValue_Initial { .Initial = { .tag = AzLayoutGridTemplateRowsValueTag_Initial } }
+#define AzLayoutGridTemplateRowsValue_Exact(v) { .Exact = { .tag = AzLayoutGridTemplateRowsValueTag_Exact, .payload = v } }
 #define AzStyleBackgroundPositionVecValue_Auto { .Auto = { .tag = AzStyleBackgroundPositionVecValueTag_Auto } }
 #define AzStyleBackgroundPositionVecValue_None { .None = { .tag = AzStyleBackgroundPositionVecValueTag_None } }
 #define AzStyleBackgroundPositionVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundPositionVecValueTag_Inherit } }
@@ -11897,6 +13483,11 @@ typedef struct AzCss AzCss;
 #define AzStyleBackgroundSizeVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundSizeVecValueTag_Inherit } }
 #define AzStyleBackgroundSizeVecValue_Initial { .Initial = { .tag = AzStyleBackgroundSizeVecValueTag_Initial } }
 #define AzStyleBackgroundSizeVecValue_Exact(v) { .Exact = { .tag = AzStyleBackgroundSizeVecValueTag_Exact, .payload = v } }
+#define AzStyleTransitionPropertyValue_Auto { .Auto = { .tag = AzStyleTransitionPropertyValueTag_Auto } }
+#define AzStyleTransitionPropertyValue_None { .None = { .tag = AzStyleTransitionPropertyValueTag_None } }
+#define AzStyleTransitionPropertyValue_Inherit { .Inherit = { .tag = AzStyleTransitionPropertyValueTag_Inherit } }
+#define AzStyleTransitionPropertyValue_Initial { .Initial = { .tag = AzStyleTransitionPropertyValueTag_Initial } }
+#define AzStyleTransitionPropertyValue_Exact(v) { .Exact = { .tag = AzStyleTransitionPropertyValueTag_Exact, .payload = v } }
 #define AzRawImageData_U8(v) { .U8 = { .tag = AzRawImageDataTag_U8, .payload = v } }
 #define AzRawImageData_U16(v) { .U16 = { .tag = AzRawImageDataTag_U16, .payload = v } }
 #define AzRawImageData_F32(v) { .F32 = { .tag = AzRawImageDataTag_F32, .payload = v } }
@@ -11915,8 +13506,6 @@ typedef struct AzCss AzCss;
 #define AzOptionVirtualKeyCodeCombo_Some(v) { .Some = { .tag = AzOptionVirtualKeyCodeComboTag_Some, .payload = v } }
 #define AzOptionMouseState_None { .None = { .tag = AzOptionMouseStateTag_None } }
 #define AzOptionMouseState_Some(v) { .Some = { .tag = AzOptionMouseStateTag_Some, .payload = v } }
-#define AzOptionKeyboardState_None { .None = { .tag = AzOptionKeyboardStateTag_None } }
-#define AzOptionKeyboardState_Some(v) { .Some = { .tag = AzOptionKeyboardStateTag_Some, .payload = v } }
 #define AzOptionStringVec_None { .None = { .tag = AzOptionStringVecTag_None } }
 #define AzOptionStringVec_Some(v) { .Some = { .tag = AzOptionStringVecTag_Some, .payload = v } }
 #define AzOptionThreadReceiveMsg_None { .None = { .tag = AzOptionThreadReceiveMsgTag_None } }
@@ -11952,6 +13541,12 @@ typedef struct AzCss AzCss;
 #define AzCssPathSelector_PseudoSelector(v) { .PseudoSelector = { .tag = AzCssPathSelectorTag_PseudoSelector, .payload = v } }
 #define AzCssPathSelector_DirectChildren { .DirectChildren = { .tag = AzCssPathSelectorTag_DirectChildren } }
 #define AzCssPathSelector_Children { .Children = { .tag = AzCssPathSelectorTag_Children } }
+#define AzGridTrack_Px(v) { .Px = { .tag = AzGridTrackTag_Px, .payload = v } }
+#define AzGridTrack_Fraction(v) { .Fraction = { .tag = AzGridTrackTag_Fraction, .payload = v } }
+#define AzGridTrack_MinContent { .MinContent = { .tag = AzGridTrackTag_MinContent } }
+#define AzGridTrack_MaxContent { .MaxContent = { .tag = AzGridTrackTag_MaxContent } }
+#define AzGridTrack_Auto { .Auto = { .tag = AzGridTrackTag_Auto } }
+#define AzGridTrack_Repeat(v) { .Repeat = { .tag = AzGridTrackTag_Repeat, .payload = v } }
 #define AzStyleBackgroundContent_LinearGradient(v) { .LinearGradient = { .tag = AzStyleBackgroundContentTag_LinearGradient, .payload = v } }
 #define AzStyleBackgroundContent_RadialGradient(v) { .RadialGradient = { .tag = AzStyleBackgroundContentTag_RadialGradient, .payload = v } }
 #define AzStyleBackgroundContent_ConicGradient(v) { .ConicGradient = { .tag = AzStyleBackgroundContentTag_ConicGradient, .payload = v } }
@@ -12020,6 +13615,10 @@ typedef struct AzCss AzCss;
 #define AzXmlStreamError_InvalidCommentData { .InvalidCommentData = { .tag = AzXmlStreamErrorTag_InvalidCommentData } }
 #define AzXmlStreamError_InvalidCommentEnd { .InvalidCommentEnd = { .tag = AzXmlStreamErrorTag_InvalidCommentEnd } }
 #define AzXmlStreamError_InvalidCharacterData { .InvalidCharacterData = { .tag = AzXmlStreamErrorTag_InvalidCharacterData } }
+#define AzImeEvent_Enabled { .Enabled = { .tag = AzImeEventTag_Enabled } }
+#define AzImeEvent_Disabled { .Disabled = { .tag = AzImeEventTag_Disabled } }
+#define AzImeEvent_Preedit(v) { .Preedit = { .tag = AzImeEventTag_Preedit, .payload = v } }
+#define AzImeEvent_Commit(v) { .Commit = { .tag = AzImeEventTag_Commit, .payload = v } }
 #define AzMenuItem_String(v) { .String = { .tag = AzMenuItemTag_String, .payload = v } }
 #define AzMenuItem_Separator { .Separator = { .tag = AzMenuItemTag_Separator } }
 #define AzMenuItem_BreakLine { .BreakLine = { .tag = AzMenuItemTag_BreakLine } }
@@ -12037,6 +13636,8 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_FontSize(v) { .FontSize = { .tag = AzCssPropertyTag_FontSize, .payload = v } }
 #define AzCssProperty_FontFamily(v) { .FontFamily = { .tag = AzCssPropertyTag_FontFamily, .payload = v } }
 #define AzCssProperty_TextAlign(v) { .TextAlign = { .tag = AzCssPropertyTag_TextAlign, .payload = v } }
+#define AzCssProperty_VerticalAlign(v) { .VerticalAlign = { .tag = AzCssPropertyTag_VerticalAlign, .payload = v } }
+#define AzCssProperty_Direction(v) { .Direction = { .tag = AzCssPropertyTag_Direction, .payload = v } }
 #define AzCssProperty_LetterSpacing(v) { .LetterSpacing = { .tag = AzCssPropertyTag_LetterSpacing, .payload = v } }
 #define AzCssProperty_LineHeight(v) { .LineHeight = { .tag = AzCssPropertyTag_LineHeight, .payload = v } }
 #define AzCssProperty_WordSpacing(v) { .WordSpacing = { .tag = AzCssPropertyTag_WordSpacing, .payload = v } }
@@ -12063,6 +13664,10 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_JustifyContent(v) { .JustifyContent = { .tag = AzCssPropertyTag_JustifyContent, .payload = v } }
 #define AzCssProperty_AlignItems(v) { .AlignItems = { .tag = AzCssPropertyTag_AlignItems, .payload = v } }
 #define AzCssProperty_AlignContent(v) { .AlignContent = { .tag = AzCssPropertyTag_AlignContent, .payload = v } }
+#define AzCssProperty_GridTemplateColumns(v) { .GridTemplateColumns = { .tag = AzCssPropertyTag_GridTemplateColumns, .payload = v } }
+#define AzCssProperty_GridTemplateRows(v) { .GridTemplateRows = { .tag = AzCssPropertyTag_GridTemplateRows, .payload = v } }
+#define AzCssProperty_GridColumn(v) { .GridColumn = { .tag = AzCssPropertyTag_GridColumn, .payload = v } }
+#define AzCssProperty_GridRow(v) { .GridRow = { .tag = AzCssPropertyTag_GridRow, .payload = v } }
 #define AzCssProperty_BackgroundContent(v) { .BackgroundContent = { .tag = AzCssPropertyTag_BackgroundContent, .payload = v } }
 #define AzCssProperty_BackgroundPosition(v) { .BackgroundPosition = { .tag = AzCssPropertyTag_BackgroundPosition, .payload = v } }
 #define AzCssProperty_BackgroundSize(v) { .BackgroundSize = { .tag = AzCssPropertyTag_BackgroundSize, .payload = v } }
@@ -12104,9 +13709,18 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_PerspectiveOrigin(v) { .PerspectiveOrigin = { .tag = AzCssPropertyTag_PerspectiveOrigin, .payload = v } }
 #define AzCssProperty_BackfaceVisibility(v) { .BackfaceVisibility = { .tag = AzCssPropertyTag_BackfaceVisibility, .payload = v } }
 #define AzCssProperty_MixBlendMode(v) { .MixBlendMode = { .tag = AzCssPropertyTag_MixBlendMode, .payload = v } }
+#define AzCssProperty_Isolation(v) { .Isolation = { .tag = AzCssPropertyTag_Isolation, .payload = v } }
+#define AzCssProperty_ImageRendering(v) { .ImageRendering = { .tag = AzCssPropertyTag_ImageRendering, .payload = v } }
 #define AzCssProperty_Filter(v) { .Filter = { .tag = AzCssPropertyTag_Filter, .payload = v } }
 #define AzCssProperty_BackdropFilter(v) { .BackdropFilter = { .tag = AzCssPropertyTag_BackdropFilter, .payload = v } }
 #define AzCssProperty_TextShadow(v) { .TextShadow = { .tag = AzCssPropertyTag_TextShadow, .payload = v } }
+#define AzCssProperty_TransitionProperty(v) { .TransitionProperty = { .tag = AzCssPropertyTag_TransitionProperty, .payload = v } }
+#define AzCssProperty_TransitionDuration(v) { .TransitionDuration = { .tag = AzCssPropertyTag_TransitionDuration, .payload = v } }
+#define AzCssProperty_TransitionTimingFunction(v) { .TransitionTimingFunction = { .tag = AzCssPropertyTag_TransitionTimingFunction, .payload = v } }
+#define AzCssProperty_TransitionDelay(v) { .TransitionDelay = { .tag = AzCssPropertyTag_TransitionDelay, .payload = v } }
+#define AzCssProperty_CaretColor(v) { .CaretColor = { .tag = AzCssPropertyTag_CaretColor, .payload = v } }
+#define AzCssProperty_SelectionColor(v) { .SelectionColor = { .tag = AzCssPropertyTag_SelectionColor, .payload = v } }
+#define AzCssProperty_SelectionBackground(v) { .SelectionBackground = { .tag = AzCssPropertyTag_SelectionBackground, .payload = v } }
 #define AzCssPropertySource_Css(v) { .Css = { .tag = AzCssPropertySourceTag_Css, .payload = v } }
 #define AzCssPropertySource_Inline { .Inline = { .tag = AzCssPropertySourceTag_Inline } }
 #define AzSvgSimpleNode_Path(v) { .Path = { .tag = AzSvgSimpleNodeTag_Path, .payload = v } }
@@ -12114,8 +13728,12 @@ typedef struct AzCss AzCss;
 #define AzSvgSimpleNode_Rect(v) { .Rect = { .tag = AzSvgSimpleNodeTag_Rect, .payload = v } }
 #define AzSvgSimpleNode_CircleHole(v) { .CircleHole = { .tag = AzSvgSimpleNodeTag_CircleHole, .payload = v } }
 #define AzSvgSimpleNode_RectHole(v) { .RectHole = { .tag = AzSvgSimpleNodeTag_RectHole, .payload = v } }
+#define AzOptionMediaQuery_None { .None = { .tag = AzOptionMediaQueryTag_None } }
+#define AzOptionMediaQuery_Some(v) { .Some = { .tag = AzOptionMediaQueryTag_Some, .payload = v } }
 #define AzOptionCssProperty_None { .None = { .tag = AzOptionCssPropertyTag_None } }
 #define AzOptionCssProperty_Some(v) { .Some = { .tag = AzOptionCssPropertyTag_Some, .payload = v } }
+#define AzOptionImeEvent_None { .None = { .tag = AzOptionImeEventTag_None } }
+#define AzOptionImeEvent_Some(v) { .Some = { .tag = AzOptionImeEventTag_Some, .payload = v } }
 #define AzNodeDataInlineCssProperty_Normal(v) { .Normal = { .tag = AzNodeDataInlineCssPropertyTag_Normal, .payload = v } }
 #define AzNodeDataInlineCssProperty_Active(v) { .Active = { .tag = AzNodeDataInlineCssPropertyTag_Active, .payload = v } }
 #define AzNodeDataInlineCssProperty_Focus(v) { .Focus = { .tag = AzNodeDataInlineCssPropertyTag_Focus, .payload = v } }
@@ -12128,6 +13746,8 @@ typedef struct AzCss AzCss;
 #define AzSvgNode_Rect(v) { .Rect = { .tag = AzSvgNodeTag_Rect, .payload = v } }
 #define AzOptionWindowState_None { .None = { .tag = AzOptionWindowStateTag_None } }
 #define AzOptionWindowState_Some(v) { .Some = { .tag = AzOptionWindowStateTag_Some, .payload = v } }
+#define AzOptionKeyboardState_None { .None = { .tag = AzOptionKeyboardStateTag_None } }
+#define AzOptionKeyboardState_Some(v) { .Some = { .tag = AzOptionKeyboardStateTag_Some, .payload = v } }
 #define AzOptionInlineText_None { .None = { .tag = AzOptionInlineTextTag_None } }
 #define AzOptionInlineText_Some(v) { .Some = { .tag = AzOptionInlineTextTag_Some, .payload = v } }
 #define AzXmlParseError_InvalidDeclaration(v) { .InvalidDeclaration = { .tag = AzXmlParseErrorTag_InvalidDeclaration, .payload = v } }
@@ -12280,6 +13900,10 @@ AzVideoMode AzVideoModeVecArray[] = {};
 #define AzVideoModeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzVideoMode), .cap = sizeof(v) / sizeof(AzVideoMode), .destructor = { .NoDestructor = { .tag = AzVideoModeVecDestructorTag_NoDestructor, }, }, }
 #define AzVideoModeVec_empty { .ptr = &AzVideoModeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzVideoModeVecDestructorTag_NoDestructor, }, }, }
 
+AzAcceleratorCallback AzAcceleratorCallbackVecArray[] = {};
+#define AzAcceleratorCallbackVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzAcceleratorCallback), .cap = sizeof(v) / sizeof(AzAcceleratorCallback), .destructor = { .NoDestructor = { .tag = AzAcceleratorCallbackVecDestructorTag_NoDestructor, }, }, }
+#define AzAcceleratorCallbackVec_empty { .ptr = &AzAcceleratorCallbackVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzAcceleratorCallbackVecDestructorTag_NoDestructor, }, }, }
+
 AzDom AzDomVecArray[] = {};
 #define AzDomVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzDom), .cap = sizeof(v) / sizeof(AzDom), .destructor = { .NoDestructor = { .tag = AzDomVecDestructorTag_NoDestructor, }, }, }
 #define AzDomVec_empty { .ptr = &AzDomVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzDomVecDestructorTag_NoDestructor, }, }, }
@@ -12312,6 +13936,26 @@ AzStyleTransform AzStyleTransformVecArray[] = {};
 #define AzStyleTransformVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleTransform), .cap = sizeof(v) / sizeof(AzStyleTransform), .destructor = { .NoDestructor = { .tag = AzStyleTransformVecDestructorTag_NoDestructor, }, }, }
 #define AzStyleTransformVec_empty { .ptr = &AzStyleTransformVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleTransformVecDestructorTag_NoDestructor, }, }, }
 
+AzGridTrack AzGridTrackVecArray[] = {};
+#define AzGridTrackVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzGridTrack), .cap = sizeof(v) / sizeof(AzGridTrack), .destructor = { .NoDestructor = { .tag = AzGridTrackVecDestructorTag_NoDestructor, }, }, }
+#define AzGridTrackVec_empty { .ptr = &AzGridTrackVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzGridTrackVecDestructorTag_NoDestructor, }, }, }
+
+AzCssPropertyType AzCssPropertyTypeVecArray[] = {};
+#define AzCssPropertyTypeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssPropertyType), .cap = sizeof(v) / sizeof(AzCssPropertyType), .destructor = { .NoDestructor = { .tag = AzCssPropertyTypeVecDestructorTag_NoDestructor, }, }, }
+#define AzCssPropertyTypeVec_empty { .ptr = &AzCssPropertyTypeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssPropertyTypeVecDestructorTag_NoDestructor, }, }, }
+
+AzCssVariable AzCssVariableVecArray[] = {};
+#define AzCssVariableVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssVariable), .cap = sizeof(v) / sizeof(AzCssVariable), .destructor = { .NoDestructor = { .tag = AzCssVariableVecDestructorTag_NoDestructor, }, }, }
+#define AzCssVariableVec_empty { .ptr = &AzCssVariableVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssVariableVecDestructorTag_NoDestructor, }, }, }
+
+AzMediaFeature AzMediaFeatureVecArray[] = {};
+#define AzMediaFeatureVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzMediaFeature), .cap = sizeof(v) / sizeof(AzMediaFeature), .destructor = { .NoDestructor = { .tag = AzMediaFeatureVecDestructorTag_NoDestructor, }, }, }
+#define AzMediaFeatureVec_empty { .ptr = &AzMediaFeatureVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzMediaFeatureVecDestructorTag_NoDestructor, }, }, }
+
+AzMediaCondition AzMediaConditionVecArray[] = {};
+#define AzMediaConditionVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzMediaCondition), .cap = sizeof(v) / sizeof(AzMediaCondition), .destructor = { .NoDestructor = { .tag = AzMediaConditionVecDestructorTag_NoDestructor, }, }, }
+#define AzMediaConditionVec_empty { .ptr = &AzMediaConditionVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzMediaConditionVecDestructorTag_NoDestructor, }, }, }
+
 AzCssProperty AzCssPropertyVecArray[] = {};
 #define AzCssPropertyVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssProperty), .cap = sizeof(v) / sizeof(AzCssProperty), .destructor = { .NoDestructor = { .tag = AzCssPropertyVecDestructorTag_NoDestructor, }, }, }
 #define AzCssPropertyVec_empty { .ptr = &AzCssPropertyVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssPropertyVecDestructorTag_NoDestructor, }, }, }
@@ -12449,17 +14093,55 @@ AzNodeData AzNodeDataVecArray[] = {};
 #define AzNodeDataVec_empty { .ptr = &AzNodeDataVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzNodeDataVecDestructorTag_NoDestructor, }, }, }
 
 
+/* HANDLES to rust-allocated objects, only accessible via the functions */
+typedef struct AzTestHarness AzTestHarness;
+typedef AzTestHarness* AzTestHarnessPtr;
+
 /* FUNCTIONS from azul.dll / libazul.so */
 extern DLLIMPORT AzApp AzApp_new(AzRefAny  data, AzAppConfig  config);
 extern DLLIMPORT void AzApp_addWindow(AzApp* restrict app, AzWindowCreateOptions  window);
 extern DLLIMPORT void AzApp_addImage(AzApp* restrict app, AzString  id, AzImageRef  image);
+extern DLLIMPORT bool  AzApp_addImageRaw(AzApp* restrict app, AzString  id, const uint8_t* data, size_t len, uint32_t width, uint32_t height, AzRawImageFormat  format);
+extern DLLIMPORT bool  AzApp_updateImageRaw(AzApp* restrict app, AzString  id, const uint8_t* data, size_t len, uint32_t width, uint32_t height, AzRawImageFormat  format);
+extern DLLIMPORT void AzApp_deleteImage(AzApp* restrict app, AzString  id);
 extern DLLIMPORT AzMonitorVec AzApp_getMonitors(const AzApp* app);
+extern DLLIMPORT AzOptionWindowTheme AzApp_getSystemTheme(const AzApp* app);
 extern DLLIMPORT void AzApp_run(const AzApp* app, AzWindowCreateOptions  window);
+extern DLLIMPORT int32_t AzApp_runReturnCode(const AzApp* app, AzWindowCreateOptions  window);
+extern DLLIMPORT AzTestHarnessPtr AzApp_createTestHarness(const AzApp* app, AzWindowCreateOptions  window);
 extern DLLIMPORT void AzApp_delete(AzApp* restrict instance);
 extern DLLIMPORT AzApp AzApp_deepCopy(AzApp* const instance);
 extern DLLIMPORT AzAppConfig AzAppConfig_new(AzLayoutSolver  layout_solver);
+extern DLLIMPORT void AzAppConfig_setRedrawBehavior(AzAppConfig* restrict appconfig, AzRedrawBehavior  behavior);
 extern DLLIMPORT AzSystemCallbacks AzSystemCallbacks_libraryInternal();
+extern DLLIMPORT uint32_t AzApi_versionMajor();
+extern DLLIMPORT uint32_t AzApi_versionMinor();
+extern DLLIMPORT uint32_t AzApi_versionPatch();
+extern DLLIMPORT const uint8_t* AzApi_versionString();
+extern DLLIMPORT uint64_t AzApi_abiHash();
+extern DLLIMPORT bool  AzApi_hasFeature(const uint8_t* name_ptr, size_t name_len);
+extern DLLIMPORT AzOptionString AzApi_lastError();
+extern DLLIMPORT bool  AzApi_clearLastError();
+extern DLLIMPORT AzOptionString AzTestHarness_diffSnapshots(AzString  expected, AzString  actual);
+extern DLLIMPORT void AzTestHarness_resize(AzTestHarnessPtr testharness, float width, float height);
+extern DLLIMPORT void AzTestHarness_mouseMove(AzTestHarnessPtr testharness, float x, float y);
+extern DLLIMPORT void AzTestHarness_mouseDown(AzTestHarnessPtr testharness, AzContextMenuMouseButton  button);
+extern DLLIMPORT void AzTestHarness_mouseUp(AzTestHarnessPtr testharness, AzContextMenuMouseButton  button);
+extern DLLIMPORT void AzTestHarness_keyChar(AzTestHarnessPtr testharness, uint32_t c);
+extern DLLIMPORT void AzTestHarness_keyDown(AzTestHarnessPtr testharness, AzVirtualKeyCode  key);
+extern DLLIMPORT void AzTestHarness_keyUp(AzTestHarnessPtr testharness, AzVirtualKeyCode  key);
+extern DLLIMPORT size_t AzTestHarness_getHitNodeCount(AzTestHarnessPtr testharness);
+extern DLLIMPORT AzOptionDomNodeId AzTestHarness_getHitNode(AzTestHarnessPtr testharness, size_t index);
+extern DLLIMPORT AzOptionLogicalRect AzTestHarness_getNodeRect(AzTestHarnessPtr testharness, AzDomNodeId  node);
+extern DLLIMPORT AzOptionString AzTestHarness_getNodeText(AzTestHarnessPtr testharness, AzDomNodeId  node);
+extern DLLIMPORT void AzTestHarness_advanceTime(AzTestHarnessPtr testharness, uint64_t millis);
+extern DLLIMPORT size_t AzTestHarness_getFramesRendered(AzTestHarnessPtr testharness);
+extern DLLIMPORT AzRefAny AzTestHarness_getData(AzTestHarnessPtr testharness);
+extern DLLIMPORT AzOptionString AzTestHarness_serializeLayout(AzTestHarnessPtr testharness, AzDomId  dom, AzCssPropertyTypeVec  properties);
+extern DLLIMPORT void AzTestHarness_delete(AzTestHarnessPtr ptr);
 extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_new(AzLayoutCallbackType  layout_callback);
+extern DLLIMPORT void AzWindowCreateOptions_setLayoutCallback(AzWindowCreateOptions* restrict windowcreateoptions, AzLayoutCallbackType  layout_callback);
+extern DLLIMPORT AzAcceleratorId AzWindowCreateOptions_addAccelerator(AzWindowCreateOptions* restrict windowcreateoptions, AzAccelerator  accelerator, AzRefAny  data, AzCallbackType  callback);
 extern DLLIMPORT void AzWindowCreateOptions_delete(AzWindowCreateOptions* restrict instance);
 extern DLLIMPORT AzLogicalPosition AzLogicalPosition_new(float x, float y);
 extern DLLIMPORT AzLogicalPosition AzLogicalPosition_zero();
@@ -12468,6 +14150,8 @@ extern DLLIMPORT void AzSmallWindowIconBytes_delete(AzSmallWindowIconBytes* rest
 extern DLLIMPORT void AzLargeWindowIconBytes_delete(AzLargeWindowIconBytes* restrict instance);
 extern DLLIMPORT void AzWindowIcon_delete(AzWindowIcon* restrict instance);
 extern DLLIMPORT void AzTaskBarIcon_delete(AzTaskBarIcon* restrict instance);
+extern DLLIMPORT AzAccelerator AzAccelerator_new(AzModifiers  modifiers, AzVirtualKeyCode  key);
+extern DLLIMPORT void AzAcceleratorCallback_delete(AzAcceleratorCallback* restrict instance);
 extern DLLIMPORT float AzWindowSize_getHidpiFactor(const AzWindowSize* windowsize);
 extern DLLIMPORT bool  AzKeyboardState_shiftDown(const AzKeyboardState* keyboardstate);
 extern DLLIMPORT bool  AzKeyboardState_ctrlDown(const AzKeyboardState* keyboardstate);
@@ -12475,6 +14159,8 @@ extern DLLIMPORT bool  AzKeyboardState_altDown(const AzKeyboardState* keyboardst
 extern DLLIMPORT bool  AzKeyboardState_superDown(const AzKeyboardState* keyboardstate);
 extern DLLIMPORT bool  AzKeyboardState_isKeyDown(const AzKeyboardState* keyboardstate, AzVirtualKeyCode  key);
 extern DLLIMPORT void AzKeyboardState_delete(AzKeyboardState* restrict instance);
+extern DLLIMPORT void AzImeEvent_delete(AzImeEvent* restrict instance);
+extern DLLIMPORT void AzImePreedit_delete(AzImePreedit* restrict instance);
 extern DLLIMPORT AzOptionLogicalPosition AzCursorPosition_getPosition(const AzCursorPosition* cursorposition);
 extern DLLIMPORT void AzPlatformSpecificOptions_delete(AzPlatformSpecificOptions* restrict instance);
 extern DLLIMPORT void AzWindowsWindowOptions_delete(AzWindowsWindowOptions* restrict instance);
@@ -12494,12 +14180,18 @@ extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getCursorRelativeToNode(
 extern DLLIMPORT AzWindowState AzCallbackInfo_getCurrentWindowState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzKeyboardState AzCallbackInfo_getCurrentKeyboardState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzMouseState AzCallbackInfo_getCurrentMouseState(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionImeEvent AzCallbackInfo_getImeEvent(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzStringVec AzCallbackInfo_getHoveredFiles(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzStringVec AzCallbackInfo_getDroppedFiles(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getDropPosition(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionDomNodeId AzCallbackInfo_getDropTarget(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionWindowState AzCallbackInfo_getPreviousWindowState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionKeyboardState AzCallbackInfo_getPreviousKeyboardState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionMouseState AzCallbackInfo_getPreviousMouseState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzRawWindowHandle AzCallbackInfo_getCurrentWindowHandle(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionGl AzCallbackInfo_getGlContext(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getScrollPosition(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
+extern DLLIMPORT AzOptionLogicalSize AzCallbackInfo_getScrollExtent(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionRefAny AzCallbackInfo_getDataset(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionDomNodeId AzCallbackInfo_getNodeIdOfRootDataset(AzCallbackInfo* restrict callbackinfo, AzRefAny  dataset);
 extern DLLIMPORT AzOptionString AzCallbackInfo_getStringContents(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
@@ -12516,16 +14208,26 @@ extern DLLIMPORT AzOptionDomNodeId AzCallbackInfo_getLastChild(AzCallbackInfo* r
 extern DLLIMPORT AzOptionPositionInfo AzCallbackInfo_getNodePosition(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionLogicalSize AzCallbackInfo_getNodeSize(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT AzOptionCssProperty AzCallbackInfo_getComputedCssProperty(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssPropertyType  property_type);
+extern DLLIMPORT AzCssPropertyVec AzCallbackInfo_getComputedCssProperties(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
 extern DLLIMPORT void AzCallbackInfo_setWindowState(AzCallbackInfo* restrict callbackinfo, AzWindowState  new_state);
+extern DLLIMPORT void AzCallbackInfo_setImePosition(AzCallbackInfo* restrict callbackinfo, AzLogicalPosition  position);
 extern DLLIMPORT void AzCallbackInfo_setFocus(AzCallbackInfo* restrict callbackinfo, AzFocusTarget  target);
 extern DLLIMPORT void AzCallbackInfo_setCssProperty(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssProperty  new_property);
-extern DLLIMPORT void AzCallbackInfo_setScrollPosition(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
+extern DLLIMPORT void AzCallbackInfo_setCss(AzCallbackInfo* restrict callbackinfo, AzCss  css);
+extern DLLIMPORT bool  AzCallbackInfo_setScrollPosition(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
 extern DLLIMPORT void AzCallbackInfo_setStringContents(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  string);
 extern DLLIMPORT void AzCallbackInfo_addImage(AzCallbackInfo* restrict callbackinfo, AzString  id, AzImageRef  image);
+extern DLLIMPORT bool  AzCallbackInfo_addImageRaw(AzCallbackInfo* restrict callbackinfo, AzString  id, const uint8_t* data, size_t len, uint32_t width, uint32_t height, AzRawImageFormat  format);
+extern DLLIMPORT bool  AzCallbackInfo_updateImageRaw(AzCallbackInfo* restrict callbackinfo, AzString  id, const uint8_t* data, size_t len, uint32_t width, uint32_t height, AzRawImageFormat  format);
 extern DLLIMPORT bool  AzCallbackInfo_hasImage(const AzCallbackInfo* callbackinfo, AzString  id);
 extern DLLIMPORT AzOptionImageRef AzCallbackInfo_getImage(const AzCallbackInfo* callbackinfo, AzString  id);
 extern DLLIMPORT void AzCallbackInfo_updateImage(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageRef  new_image, AzUpdateImageType  image_type);
 extern DLLIMPORT void AzCallbackInfo_deleteImage(AzCallbackInfo* restrict callbackinfo, AzString  id);
+extern DLLIMPORT AzAcceleratorId AzCallbackInfo_addAccelerator(AzCallbackInfo* restrict callbackinfo, AzAccelerator  accelerator, AzRefAny  data, AzCallbackType  callback);
+extern DLLIMPORT bool  AzCallbackInfo_replaceAccelerator(AzCallbackInfo* restrict callbackinfo, AzAcceleratorId  id, AzAccelerator  accelerator, AzRefAny  data, AzCallbackType  callback);
+extern DLLIMPORT bool  AzCallbackInfo_removeAccelerator(AzCallbackInfo* restrict callbackinfo, AzAcceleratorId  id);
+extern DLLIMPORT void AzCallbackInfo_openContextMenu(AzCallbackInfo* restrict callbackinfo, AzMenu  menu, AzLogicalPosition  position);
+extern DLLIMPORT void AzCallbackInfo_closeContextMenu(AzCallbackInfo* restrict callbackinfo);
 extern DLLIMPORT void AzCallbackInfo_updateImageMask(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageMask  new_mask);
 extern DLLIMPORT void AzCallbackInfo_stopPropagation(AzCallbackInfo* restrict callbackinfo);
 extern DLLIMPORT void AzCallbackInfo_createWindow(AzCallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
@@ -12533,6 +14235,8 @@ extern DLLIMPORT AzTimerId AzCallbackInfo_startTimer(AzCallbackInfo* restrict ca
 extern DLLIMPORT AzOptionTimerId AzCallbackInfo_startAnimation(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzAnimation  animation);
 extern DLLIMPORT bool  AzCallbackInfo_stopTimer(AzCallbackInfo* restrict callbackinfo, AzTimerId  timer_id);
 extern DLLIMPORT AzOptionThreadId AzCallbackInfo_startThread(AzCallbackInfo* restrict callbackinfo, AzRefAny  thread_initialize_data, AzRefAny  writeback_data, AzThreadCallbackType  callback);
+extern DLLIMPORT AzOptionThreadId AzCallbackInfo_startPendingThread(AzCallbackInfo* restrict callbackinfo, AzPendingThread  thread, AzCallbackType  on_finish);
+extern DLLIMPORT bool  AzCallbackInfo_requestThreadStop(AzCallbackInfo* restrict callbackinfo, AzThreadId  thread_id);
 extern DLLIMPORT bool  AzCallbackInfo_sendThreadMsg(AzCallbackInfo* restrict callbackinfo, AzThreadId  thread_id, AzThreadSendMsg  msg);
 extern DLLIMPORT bool  AzCallbackInfo_stopThread(AzCallbackInfo* restrict callbackinfo, AzThreadId  thread_id);
 extern DLLIMPORT void AzCallbackInfo_delete(AzCallbackInfo* restrict instance);
@@ -12573,9 +14277,10 @@ extern DLLIMPORT void AzRefCount_increaseRefmut(AzRefCount* restrict refcount);
 extern DLLIMPORT void AzRefCount_decreaseRefmut(AzRefCount* restrict refcount);
 extern DLLIMPORT void AzRefCount_delete(AzRefCount* restrict instance);
 extern DLLIMPORT AzRefCount AzRefCount_deepCopy(AzRefCount* const instance);
-extern DLLIMPORT AzRefAny AzRefAny_newC(void ptr, size_t len, uint64_t type_id, AzString  type_name, AzRefAnyDestructorType  destructor);
+extern DLLIMPORT AzRefAny AzRefAny_newC(const void* ptr, size_t len, uint64_t type_id, AzString  type_name, AzRefAnyDestructorType  destructor);
 extern DLLIMPORT uint64_t AzRefAny_getTypeId(const AzRefAny* refany);
 extern DLLIMPORT AzString AzRefAny_getTypeName(const AzRefAny* refany);
+extern DLLIMPORT size_t AzRefAny_strongCount(const AzRefAny* refany);
 extern DLLIMPORT void AzRefAny_delete(AzRefAny* restrict instance);
 extern DLLIMPORT AzRefAny AzRefAny_deepCopy(AzRefAny* const instance);
 extern DLLIMPORT AzOptionGl AzLayoutCallbackInfo_getGlContext(const AzLayoutCallbackInfo* layoutcallbackinfo);
@@ -12631,12 +14336,20 @@ extern DLLIMPORT void AzDom_setTabIndex(AzDom* restrict dom, AzTabIndex  tab_ind
 extern DLLIMPORT AzDom AzDom_withTabIndex(AzDom* restrict dom, AzTabIndex  tab_index);
 extern DLLIMPORT void AzDom_setAccessibilityInfo(AzDom* restrict dom, AzAccessibilityInfo  accessibility_info);
 extern DLLIMPORT AzDom AzDom_withAccessibilityInfo(AzDom* restrict dom, AzAccessibilityInfo  accessibility_info);
+extern DLLIMPORT void AzDom_setAccessibilityRole(AzDom* restrict dom, AzAccessibilityRole  role);
+extern DLLIMPORT AzDom AzDom_withAccessibilityRole(AzDom* restrict dom, AzAccessibilityRole  role);
+extern DLLIMPORT void AzDom_setAccessibilityLabel(AzDom* restrict dom, AzString  label);
+extern DLLIMPORT AzDom AzDom_withAccessibilityLabel(AzDom* restrict dom, AzString  label);
 extern DLLIMPORT void AzDom_setMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
 extern DLLIMPORT AzDom AzDom_withMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
 extern DLLIMPORT void AzDom_setContextMenu(AzDom* restrict dom, AzMenu  context_menu);
 extern DLLIMPORT AzDom AzDom_withContextMenu(AzDom* restrict dom, AzMenu  context_menu);
 extern DLLIMPORT uint64_t AzDom_hash(const AzDom* dom);
 extern DLLIMPORT size_t AzDom_nodeCount(const AzDom* dom);
+extern DLLIMPORT AzNodeType AzDom_getNodeType(const AzDom* dom);
+extern DLLIMPORT size_t AzDom_childrenCount(const AzDom* dom);
+extern DLLIMPORT AzOptionNodeId AzDom_findFirstById(const AzDom* dom, AzString  id);
+extern DLLIMPORT AzNodeIdVec AzDom_findAllByClass(const AzDom* dom, AzString  class_name);
 extern DLLIMPORT AzString AzDom_getHtmlString(AzDom* restrict dom);
 extern DLLIMPORT AzString AzDom_getHtmlStringTest(AzDom* restrict dom);
 extern DLLIMPORT AzStyledDom AzDom_style(AzDom* restrict dom, AzCss  css);
@@ -12673,6 +14386,8 @@ extern DLLIMPORT AzNodeData AzNodeData_withInlineFocusStyle(AzNodeData* restrict
 extern DLLIMPORT void AzNodeData_setClipMask(AzNodeData* restrict nodedata, AzImageMask  image_mask);
 extern DLLIMPORT void AzNodeData_setTabIndex(AzNodeData* restrict nodedata, AzTabIndex  tab_index);
 extern DLLIMPORT void AzNodeData_setAccessibilityInfo(AzNodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
+extern DLLIMPORT void AzNodeData_setAccessibilityRole(AzNodeData* restrict nodedata, AzAccessibilityRole  role);
+extern DLLIMPORT void AzNodeData_setAccessibilityLabel(AzNodeData* restrict nodedata, AzString  label);
 extern DLLIMPORT void AzNodeData_setMenuBar(AzNodeData* restrict nodedata, AzMenu  menu_bar);
 extern DLLIMPORT void AzNodeData_setContextMenu(AzNodeData* restrict nodedata, AzMenu  context_menu);
 extern DLLIMPORT uint64_t AzNodeData_hash(const AzNodeData* nodedata);
@@ -12690,6 +14405,10 @@ extern DLLIMPORT void AzMenuItem_delete(AzMenuItem* restrict instance);
 extern DLLIMPORT AzStringMenuItem AzStringMenuItem_new(AzString  label);
 extern DLLIMPORT void AzStringMenuItem_setCallback(AzStringMenuItem* restrict stringmenuitem, AzRefAny  data, AzCallbackType  callback);
 extern DLLIMPORT AzStringMenuItem AzStringMenuItem_withCallback(AzStringMenuItem* restrict stringmenuitem, AzRefAny  data, AzCallbackType  callback);
+extern DLLIMPORT void AzStringMenuItem_setAccelerator(AzStringMenuItem* restrict stringmenuitem, AzVirtualKeyCodeCombo  accelerator);
+extern DLLIMPORT AzStringMenuItem AzStringMenuItem_withAccelerator(AzStringMenuItem* restrict stringmenuitem, AzVirtualKeyCodeCombo  accelerator);
+extern DLLIMPORT void AzStringMenuItem_setState(AzStringMenuItem* restrict stringmenuitem, AzMenuItemState  state);
+extern DLLIMPORT AzStringMenuItem AzStringMenuItem_withState(AzStringMenuItem* restrict stringmenuitem, AzMenuItemState  state);
 extern DLLIMPORT void AzStringMenuItem_addChild(AzStringMenuItem* restrict stringmenuitem, AzMenuItem  child);
 extern DLLIMPORT AzStringMenuItem AzStringMenuItem_withChild(AzStringMenuItem* restrict stringmenuitem, AzMenuItem  child);
 extern DLLIMPORT void AzStringMenuItem_setChildren(AzStringMenuItem* restrict stringmenuitem, AzMenuItemVec  children);
@@ -12707,12 +14426,29 @@ extern DLLIMPORT void AzCssPathSelector_delete(AzCssPathSelector* restrict insta
 extern DLLIMPORT void AzStylesheet_delete(AzStylesheet* restrict instance);
 extern DLLIMPORT AzCss AzCss_empty();
 extern DLLIMPORT AzCss AzCss_fromString(AzString  s);
+extern DLLIMPORT void AzCss_setVariable(AzCss* restrict css, AzString  name, AzCssProperty  value);
+extern DLLIMPORT void AzCss_resolveVariables(AzCss* restrict css);
 extern DLLIMPORT void AzCss_delete(AzCss* restrict instance);
+extern DLLIMPORT void AzCssVariable_delete(AzCssVariable* restrict instance);
+extern DLLIMPORT void AzMediaQuery_delete(AzMediaQuery* restrict instance);
+extern DLLIMPORT void AzMediaCondition_delete(AzMediaCondition* restrict instance);
 extern DLLIMPORT AzColorU AzColorU_fromStr(AzString  string);
 extern DLLIMPORT AzColorU AzColorU_transparent();
 extern DLLIMPORT AzColorU AzColorU_white();
 extern DLLIMPORT AzColorU AzColorU_black();
 extern DLLIMPORT AzString AzColorU_toHash(const AzColorU* coloru);
+extern DLLIMPORT AzColorU AzColorU_multiply(const AzColorU* coloru, AzColorU  other);
+extern DLLIMPORT AzColorU AzColorU_addSaturating(const AzColorU* coloru, AzColorU  other);
+extern DLLIMPORT AzColorU AzColorU_hueRotate(const AzColorU* coloru, float degrees);
+extern DLLIMPORT AzColorU AzColorU_withR(const AzColorU* coloru, uint8_t r);
+extern DLLIMPORT AzColorU AzColorU_withG(const AzColorU* coloru, uint8_t g);
+extern DLLIMPORT AzColorU AzColorU_withB(const AzColorU* coloru, uint8_t b);
+extern DLLIMPORT AzColorU AzColorU_withA(const AzColorU* coloru, uint8_t a);
+extern DLLIMPORT AzColorU AzColorU_withAlphaF32(const AzColorU* coloru, float a);
+extern DLLIMPORT void AzGridTrack_delete(AzGridTrack* restrict instance);
+extern DLLIMPORT void AzGridTrackRepeat_delete(AzGridTrackRepeat* restrict instance);
+extern DLLIMPORT void AzLayoutGridTemplateColumns_delete(AzLayoutGridTemplateColumns* restrict instance);
+extern DLLIMPORT void AzLayoutGridTemplateRows_delete(AzLayoutGridTemplateRows* restrict instance);
 extern DLLIMPORT float AzAngleValue_getDegrees(const AzAngleValue* anglevalue);
 extern DLLIMPORT void AzLinearGradient_delete(AzLinearGradient* restrict instance);
 extern DLLIMPORT void AzRadialGradient_delete(AzRadialGradient* restrict instance);
@@ -12721,13 +14457,62 @@ extern DLLIMPORT void AzStyleBackgroundContent_delete(AzStyleBackgroundContent*
 extern DLLIMPORT void AzScrollbarInfo_delete(AzScrollbarInfo* restrict instance);
 extern DLLIMPORT void AzScrollbarStyle_delete(AzScrollbarStyle* restrict instance);
 extern DLLIMPORT void AzStyleFontFamily_delete(AzStyleFontFamily* restrict instance);
+extern DLLIMPORT void AzStyleTransitionProperty_delete(AzStyleTransitionProperty* restrict instance);
+extern DLLIMPORT AzStyleBoxShadowValue AzStyleBoxShadowValue_exact(AzStyleBoxShadow  shadow);
+extern DLLIMPORT void AzLayoutGridTemplateColumnsBoxed_delete(AzLayoutGridTemplateColumnsBoxed* restrict instance);
+extern DLLIMPORT AzLayoutGridTemplateColumnsValue AzLayoutGridTemplateColumnsValue_exact(AzLayoutGridTemplateColumns  value);
+extern DLLIMPORT void AzLayoutGridTemplateColumnsValue_delete(AzLayoutGridTemplateColumnsValue* restrict instance);
+extern DLLIMPORT void AzLayoutGridTemplateRowsBoxed_delete(AzLayoutGridTemplateRowsBoxed* restrict instance);
+extern DLLIMPORT AzLayoutGridTemplateRowsValue AzLayoutGridTemplateRowsValue_exact(AzLayoutGridTemplateRows  value);
+extern DLLIMPORT void AzLayoutGridTemplateRowsValue_delete(AzLayoutGridTemplateRowsValue* restrict instance);
+extern DLLIMPORT AzLayoutBottomValue AzLayoutBottomValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutHeightValue AzLayoutHeightValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutLeftValue AzLayoutLeftValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutMarginBottomValue AzLayoutMarginBottomValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutMarginLeftValue AzLayoutMarginLeftValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutMarginRightValue AzLayoutMarginRightValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutMarginTopValue AzLayoutMarginTopValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutMaxHeightValue AzLayoutMaxHeightValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutMaxWidthValue AzLayoutMaxWidthValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutMinHeightValue AzLayoutMinHeightValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutMinWidthValue AzLayoutMinWidthValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutPaddingBottomValue AzLayoutPaddingBottomValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutPaddingLeftValue AzLayoutPaddingLeftValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutPaddingRightValue AzLayoutPaddingRightValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutPaddingTopValue AzLayoutPaddingTopValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutRightValue AzLayoutRightValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutTopValue AzLayoutTopValue_calc(AzCalcLength  calc);
+extern DLLIMPORT AzLayoutWidthValue AzLayoutWidthValue_calc(AzCalcLength  calc);
+extern DLLIMPORT void AzScrollbarStyleBoxed_delete(AzScrollbarStyleBoxed* restrict instance);
+extern DLLIMPORT AzScrollbarStyleValue AzScrollbarStyleValue_exact(AzScrollbarStyle  style);
 extern DLLIMPORT void AzScrollbarStyleValue_delete(AzScrollbarStyleValue* restrict instance);
+extern DLLIMPORT void AzStyleBackgroundContentVecBoxed_delete(AzStyleBackgroundContentVecBoxed* restrict instance);
+extern DLLIMPORT AzStyleBackgroundContentVecValue AzStyleBackgroundContentVecValue_exact(AzStyleBackgroundContentVec  value);
 extern DLLIMPORT void AzStyleBackgroundContentVecValue_delete(AzStyleBackgroundContentVecValue* restrict instance);
+extern DLLIMPORT void AzStyleBackgroundPositionVecBoxed_delete(AzStyleBackgroundPositionVecBoxed* restrict instance);
+extern DLLIMPORT AzStyleBackgroundPositionVecValue AzStyleBackgroundPositionVecValue_exact(AzStyleBackgroundPositionVec  value);
 extern DLLIMPORT void AzStyleBackgroundPositionVecValue_delete(AzStyleBackgroundPositionVecValue* restrict instance);
+extern DLLIMPORT void AzStyleBackgroundRepeatVecBoxed_delete(AzStyleBackgroundRepeatVecBoxed* restrict instance);
+extern DLLIMPORT AzStyleBackgroundRepeatVecValue AzStyleBackgroundRepeatVecValue_exact(AzStyleBackgroundRepeatVec  value);
 extern DLLIMPORT void AzStyleBackgroundRepeatVecValue_delete(AzStyleBackgroundRepeatVecValue* restrict instance);
+extern DLLIMPORT void AzStyleBackgroundSizeVecBoxed_delete(AzStyleBackgroundSizeVecBoxed* restrict instance);
+extern DLLIMPORT AzStyleBackgroundSizeVecValue AzStyleBackgroundSizeVecValue_exact(AzStyleBackgroundSizeVec  value);
 extern DLLIMPORT void AzStyleBackgroundSizeVecValue_delete(AzStyleBackgroundSizeVecValue* restrict instance);
+extern DLLIMPORT void AzStyleFontFamilyVecBoxed_delete(AzStyleFontFamilyVecBoxed* restrict instance);
+extern DLLIMPORT AzStyleFontFamilyVecValue AzStyleFontFamilyVecValue_exact(AzStyleFontFamilyVec  value);
 extern DLLIMPORT void AzStyleFontFamilyVecValue_delete(AzStyleFontFamilyVecValue* restrict instance);
+extern DLLIMPORT void AzStyleTransitionPropertyBoxed_delete(AzStyleTransitionPropertyBoxed* restrict instance);
+extern DLLIMPORT AzStyleTransitionPropertyValue AzStyleTransitionPropertyValue_exact(AzStyleTransitionProperty  value);
+extern DLLIMPORT void AzStyleTransitionPropertyValue_delete(AzStyleTransitionPropertyValue* restrict instance);
+extern DLLIMPORT AzStyleTransitionTimingFunctionValue AzStyleTransitionTimingFunctionValue_exact(AzStyleTransitionTimingFunction  value);
+extern DLLIMPORT AzStyleVerticalAlignValue AzStyleVerticalAlignValue_exact(AzStyleVerticalAlign  value);
+extern DLLIMPORT void AzStyleTransformVecBoxed_delete(AzStyleTransformVecBoxed* restrict instance);
+extern DLLIMPORT AzStyleTransformVecValue AzStyleTransformVecValue_exact(AzStyleTransformVec  value);
 extern DLLIMPORT void AzStyleTransformVecValue_delete(AzStyleTransformVecValue* restrict instance);
+extern DLLIMPORT AzStyleTransformOriginValue AzStyleTransformOriginValue_exact(AzStyleTransformOrigin  value);
+extern DLLIMPORT AzStylePerspectiveOriginValue AzStylePerspectiveOriginValue_exact(AzStylePerspectiveOrigin  value);
+extern DLLIMPORT void AzStyleFilterVecBoxed_delete(AzStyleFilterVecBoxed* restrict instance);
+extern DLLIMPORT AzStyleFilterVecValue AzStyleFilterVecValue_exact(AzStyleFilterVec  value);
 extern DLLIMPORT void AzStyleFilterVecValue_delete(AzStyleFilterVecValue* restrict instance);
 extern DLLIMPORT AzString AzCssProperty_getKeyString(const AzCssProperty* cssproperty);
 extern DLLIMPORT AzString AzCssProperty_getValueString(const AzCssProperty* cssproperty);
@@ -13147,7 +14932,7 @@ extern DLLIMPORT AzGLsyncPtr AzGLsyncPtr_deepCopy(AzGLsyncPtr* const instance);
 extern DLLIMPORT void AzGetActiveUniformReturn_delete(AzGetActiveUniformReturn* restrict instance);
 extern DLLIMPORT AzTextureFlags AzTextureFlags_default();
 extern DLLIMPORT AzImageRef AzImageRef_invalid(size_t width, size_t height, AzRawImageFormat  format);
-extern DLLIMPORT AzImageRef AzImageRef_rawImage(AzRawImage  data);
+extern DLLIMPORT AzOptionImageRef AzImageRef_rawImage(AzRawImage  data);
 extern DLLIMPORT AzImageRef AzImageRef_glTexture(AzTexture  texture);
 extern DLLIMPORT AzImageRef AzImageRef_callback(AzRefAny  data, AzRenderImageCallbackType  callback);
 extern DLLIMPORT AzImageRef AzImageRef_cloneBytes(const AzImageRef* imageref);
@@ -13161,7 +14946,7 @@ extern DLLIMPORT void AzImageRef_delete(AzImageRef* restrict instance);
 extern DLLIMPORT AzImageRef AzImageRef_deepCopy(AzImageRef* const instance);
 extern DLLIMPORT AzRawImage AzRawImage_empty();
 extern DLLIMPORT AzRawImage AzRawImage_allocateClipMask(AzLayoutSize  size);
-extern DLLIMPORT AzRawImage AzRawImage_decodeImageBytesAny(AzU8VecRef  bytes);
+extern DLLIMPORT AzResultRawImageDecodeImageError AzRawImage_decodeImageBytesAny(AzU8VecRef  bytes);
 extern DLLIMPORT bool  AzRawImage_drawClipMask(AzRawImage* restrict rawimage, AzSvgNode  node, AzSvgStyle  style);
 extern DLLIMPORT AzResultU8VecEncodeImageError AzRawImage_encodeBmp(const AzRawImage* rawimage);
 extern DLLIMPORT AzResultU8VecEncodeImageError AzRawImage_encodePng(const AzRawImage* rawimage);
@@ -13198,21 +14983,21 @@ extern DLLIMPORT float AzFontMetrics_getYSuperscriptYOffset(const AzFontMetrics*
 extern DLLIMPORT float AzFontMetrics_getYStrikeoutSize(const AzFontMetrics* fontmetrics, float target_font_size);
 extern DLLIMPORT float AzFontMetrics_getYStrikeoutPosition(const AzFontMetrics* fontmetrics, float target_font_size);
 extern DLLIMPORT void AzFontSource_delete(AzFontSource* restrict instance);
-extern DLLIMPORT AzFontRef AzFontRef_parse(AzFontSource  source);
+extern DLLIMPORT AzOptionFontRef AzFontRef_parse(AzFontSource  source);
 extern DLLIMPORT AzU8Vec AzFontRef_getBytes(const AzFontRef* fontref);
 extern DLLIMPORT AzFontMetrics AzFontRef_getFontMetrics(const AzFontRef* fontref);
 extern DLLIMPORT AzInlineText AzFontRef_shapeText(const AzFontRef* fontref, AzRefstr  text, AzResolvedTextLayoutOptions  options);
 extern DLLIMPORT uint64_t AzFontRef_getHash(const AzFontRef* fontref);
 extern DLLIMPORT void AzFontRef_delete(AzFontRef* restrict instance);
 extern DLLIMPORT AzFontRef AzFontRef_deepCopy(AzFontRef* const instance);
-extern DLLIMPORT AzSvg AzSvg_fromString(AzString  svg_string, AzSvgParseOptions  parse_options);
-extern DLLIMPORT AzSvg AzSvg_fromBytes(AzU8VecRef  svg_bytes, AzSvgParseOptions  parse_options);
+extern DLLIMPORT AzResultSvgSvgParseError AzSvg_fromString(AzString  svg_string, AzSvgParseOptions  parse_options);
+extern DLLIMPORT AzResultSvgSvgParseError AzSvg_fromBytes(AzU8VecRef  svg_bytes, AzSvgParseOptions  parse_options);
 extern DLLIMPORT AzSvgXmlNode AzSvg_getRoot(const AzSvg* svg);
 extern DLLIMPORT AzOptionRawImage AzSvg_render(const AzSvg* svg, AzSvgRenderOptions  options);
 extern DLLIMPORT AzString AzSvg_toString(const AzSvg* svg, AzSvgStringFormatOptions  options);
 extern DLLIMPORT void AzSvg_delete(AzSvg* restrict instance);
 extern DLLIMPORT AzSvg AzSvg_deepCopy(AzSvg* const instance);
-extern DLLIMPORT AzSvgXmlNode AzSvgXmlNode_parseFrom(AzU8VecRef  svg_bytes, AzSvgParseOptions  parse_options);
+extern DLLIMPORT AzResultSvgXmlNodeSvgParseError AzSvgXmlNode_parseFrom(AzU8VecRef  svg_bytes, AzSvgParseOptions  parse_options);
 extern DLLIMPORT void AzSvgXmlNode_delete(AzSvgXmlNode* restrict instance);
 extern DLLIMPORT AzSvgXmlNode AzSvgXmlNode_deepCopy(AzSvgXmlNode* const instance);
 extern DLLIMPORT AzSvgRect AzSvgMultiPolygon_getBounds(const AzSvgMultiPolygon* svgmultipolygon);
@@ -13314,11 +15099,11 @@ extern DLLIMPORT void AzSvgParseOptions_delete(AzSvgParseOptions* restrict insta
 extern DLLIMPORT AzSvgRenderOptions AzSvgRenderOptions_default();
 extern DLLIMPORT AzSvgFillStyle AzSvgFillStyle_default();
 extern DLLIMPORT AzSvgStrokeStyle AzSvgStrokeStyle_default();
-extern DLLIMPORT AzXml AzXml_fromStr(AzRefstr  xml_string);
+extern DLLIMPORT AzResultXmlXmlError AzXml_fromStr(AzRefstr  xml_string);
 extern DLLIMPORT void AzXml_delete(AzXml* restrict instance);
 extern DLLIMPORT void AzXmlNode_delete(AzXmlNode* restrict instance);
-extern DLLIMPORT AzFile AzFile_open(AzString  path);
-extern DLLIMPORT AzFile AzFile_create(AzString  path);
+extern DLLIMPORT AzOptionFile AzFile_open(AzString  path);
+extern DLLIMPORT AzOptionFile AzFile_create(AzString  path);
 extern DLLIMPORT AzOptionString AzFile_readToString(AzFile* restrict file);
 extern DLLIMPORT AzOptionU8Vec AzFile_readToBytes(AzFile* restrict file);
 extern DLLIMPORT bool  AzFile_writeString(AzFile* restrict file, AzRefstr  bytes);
@@ -13326,20 +15111,20 @@ extern DLLIMPORT bool  AzFile_writeBytes(AzFile* restrict file, AzU8VecRef  byte
 extern DLLIMPORT void AzFile_close(AzFile* restrict file);
 extern DLLIMPORT void AzFile_delete(AzFile* restrict instance);
 extern DLLIMPORT AzFile AzFile_deepCopy(AzFile* const instance);
-extern DLLIMPORT AzMsgBox AzMsgBox_ok(AzMsgBoxIcon  icon, AzString  title, AzString  message);
-extern DLLIMPORT AzMsgBox AzMsgBox_info(AzString  message);
-extern DLLIMPORT AzMsgBox AzMsgBox_warning(AzString  message);
-extern DLLIMPORT AzMsgBox AzMsgBox_error(AzString  message);
-extern DLLIMPORT AzMsgBox AzMsgBox_question(AzString  message);
-extern DLLIMPORT AzMsgBox AzMsgBox_okCancel(AzMsgBoxIcon  icon, AzString  title, AzString  message, AzMsgBoxOkCancel  default_value);
-extern DLLIMPORT AzMsgBox AzMsgBox_yesNo(AzMsgBoxIcon  icon, AzString  title, AzString  message, AzMsgBoxYesNo  default_value);
-extern DLLIMPORT AzFileDialog AzFileDialog_selectFile(AzString  title, AzOptionString  default_path, AzOptionFileTypeList  filter_list);
-extern DLLIMPORT AzFileDialog AzFileDialog_selectMultipleFiles(AzString  title, AzOptionString  default_path, AzOptionFileTypeList  filter_list);
-extern DLLIMPORT AzFileDialog AzFileDialog_selectFolder(AzString  title, AzOptionString  default_path);
-extern DLLIMPORT AzFileDialog AzFileDialog_saveFile(AzString  title, AzOptionString  default_path);
+extern DLLIMPORT bool  AzMsgBox_ok(AzMsgBoxIcon  icon, AzString  title, AzString  message);
+extern DLLIMPORT bool  AzMsgBox_info(AzString  message);
+extern DLLIMPORT bool  AzMsgBox_warning(AzString  message);
+extern DLLIMPORT bool  AzMsgBox_error(AzString  message);
+extern DLLIMPORT bool  AzMsgBox_question(AzString  message);
+extern DLLIMPORT AzMsgBoxOkCancel AzMsgBox_okCancel(AzMsgBoxIcon  icon, AzString  title, AzString  message, AzMsgBoxOkCancel  default_value);
+extern DLLIMPORT AzMsgBoxYesNo AzMsgBox_yesNo(AzMsgBoxIcon  icon, AzString  title, AzString  message, AzMsgBoxYesNo  default_value);
+extern DLLIMPORT AzOptionString AzFileDialog_selectFile(AzString  title, AzOptionString  default_path, AzOptionFileTypeList  filter_list);
+extern DLLIMPORT AzOptionStringVec AzFileDialog_selectMultipleFiles(AzString  title, AzOptionString  default_path, AzOptionFileTypeList  filter_list);
+extern DLLIMPORT AzOptionString AzFileDialog_selectFolder(AzString  title, AzOptionString  default_path);
+extern DLLIMPORT AzOptionString AzFileDialog_saveFile(AzString  title, AzOptionString  default_path);
 extern DLLIMPORT void AzFileTypeList_delete(AzFileTypeList* restrict instance);
-extern DLLIMPORT AzColorPickerDialog AzColorPickerDialog_open(AzString  title, AzOptionColorU  default_color);
-extern DLLIMPORT AzSystemClipboard AzSystemClipboard_new();
+extern DLLIMPORT AzOptionColorU AzColorPickerDialog_open(AzString  title, AzOptionColorU  default_color);
+extern DLLIMPORT AzOptionSystemClipboard AzSystemClipboard_new();
 extern DLLIMPORT AzOptionString AzSystemClipboard_getStringContents(const AzSystemClipboard* systemclipboard);
 extern DLLIMPORT bool  AzSystemClipboard_setStringContents(AzSystemClipboard* restrict systemclipboard, AzString  contents);
 extern DLLIMPORT void AzSystemClipboard_delete(AzSystemClipboard* restrict instance);
@@ -13357,6 +15142,8 @@ extern DLLIMPORT AzTimer AzTimer_withTimeout(const AzTimer* timer, AzDuration  t
 extern DLLIMPORT void AzTimer_delete(AzTimer* restrict instance);
 extern DLLIMPORT void AzThread_delete(AzThread* restrict instance);
 extern DLLIMPORT AzThread AzThread_deepCopy(AzThread* const instance);
+extern DLLIMPORT AzPendingThread AzPendingThread_new(AzRefAny  data, AzThreadFnType  thread_fn);
+extern DLLIMPORT void AzPendingThread_delete(AzPendingThread* restrict instance);
 extern DLLIMPORT bool  AzThreadSender_send(AzThreadSender* restrict threadsender, AzThreadReceiveMsg  msg);
 extern DLLIMPORT void AzThreadSender_delete(AzThreadSender* restrict instance);
 extern DLLIMPORT AzThreadSender AzThreadSender_deepCopy(AzThreadSender* const instance);
@@ -13369,10 +15156,20 @@ extern DLLIMPORT void AzThreadWriteBackMsg_delete(AzThreadWriteBackMsg* restrict
 extern DLLIMPORT void AzFmtValue_delete(AzFmtValue* restrict instance);
 extern DLLIMPORT void AzFmtArg_delete(AzFmtArg* restrict instance);
 extern DLLIMPORT AzString AzString_format(AzString  format, AzFmtArgVec  args);
-extern DLLIMPORT AzString AzString_copyFromBytes(uint8_t ptr, size_t start, size_t len);
+extern DLLIMPORT AzString AzString_copyFromBytes(const uint8_t* ptr, size_t start, size_t len);
 extern DLLIMPORT AzString AzString_trim(const AzString* string);
 extern DLLIMPORT AzRefstr AzString_asRefstr(const AzString* string);
 extern DLLIMPORT void AzString_delete(AzString* restrict instance);
+extern DLLIMPORT AzTextCursor AzTextCursor_start();
+extern DLLIMPORT AzTextCursor AzTextCursor_end(AzString  text);
+extern DLLIMPORT AzTextCursor AzTextCursor_fromByteOffset(AzString  text, size_t byte_offset);
+extern DLLIMPORT AzTextCursor AzTextCursor_nextGrapheme(const AzTextCursor* textcursor, AzString  text);
+extern DLLIMPORT AzTextCursor AzTextCursor_prevGrapheme(const AzTextCursor* textcursor, AzString  text);
+extern DLLIMPORT AzTextCursor AzTextCursor_nextWord(const AzTextCursor* textcursor, AzString  text);
+extern DLLIMPORT AzTextCursor AzTextCursor_prevWord(const AzTextCursor* textcursor, AzString  text);
+extern DLLIMPORT AzTextSelection AzTextSelection_collapsed(AzTextCursor  cursor);
+extern DLLIMPORT bool  AzTextSelection_isCollapsed(const AzTextSelection* textselection);
+extern DLLIMPORT AzTextSelection AzTextSelection_normalized(const AzTextSelection* textselection);
 extern DLLIMPORT void AzListViewRowVec_delete(AzListViewRowVec* restrict instance);
 extern DLLIMPORT void AzStyleFilterVec_delete(AzStyleFilterVec* restrict instance);
 extern DLLIMPORT void AzLogicalRectVec_delete(AzLogicalRectVec* restrict instance);
@@ -13400,6 +15197,7 @@ extern DLLIMPORT void AzInlineGlyphVec_delete(AzInlineGlyphVec* restrict instanc
 extern DLLIMPORT void AzInlineTextHitVec_delete(AzInlineTextHitVec* restrict instance);
 extern DLLIMPORT void AzMonitorVec_delete(AzMonitorVec* restrict instance);
 extern DLLIMPORT void AzVideoModeVec_delete(AzVideoModeVec* restrict instance);
+extern DLLIMPORT void AzAcceleratorCallbackVec_delete(AzAcceleratorCallbackVec* restrict instance);
 extern DLLIMPORT void AzDomVec_delete(AzDomVec* restrict instance);
 extern DLLIMPORT void AzIdOrClassVec_delete(AzIdOrClassVec* restrict instance);
 extern DLLIMPORT void AzNodeDataInlineCssPropertyVec_delete(AzNodeDataInlineCssPropertyVec* restrict instance);
@@ -13408,6 +15206,13 @@ extern DLLIMPORT void AzStyleBackgroundPositionVec_delete(AzStyleBackgroundPosit
 extern DLLIMPORT void AzStyleBackgroundRepeatVec_delete(AzStyleBackgroundRepeatVec* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundSizeVec_delete(AzStyleBackgroundSizeVec* restrict instance);
 extern DLLIMPORT void AzStyleTransformVec_delete(AzStyleTransformVec* restrict instance);
+extern DLLIMPORT void AzGridTrackVec_delete(AzGridTrackVec* restrict instance);
+extern DLLIMPORT void AzCssPropertyTypeVec_delete(AzCssPropertyTypeVec* restrict instance);
+extern DLLIMPORT void AzCssVariableVec_delete(AzCssVariableVec* restrict instance);
+extern DLLIMPORT void AzMediaFeatureVec_delete(AzMediaFeatureVec* restrict instance);
+extern DLLIMPORT void AzMediaConditionVec_delete(AzMediaConditionVec* restrict instance);
+extern DLLIMPORT size_t AzCssPropertyVec_len(const AzCssPropertyVec* csspropertyvec);
+extern DLLIMPORT AzOptionCssProperty AzCssPropertyVec_get(const AzCssPropertyVec* csspropertyvec, size_t index);
 extern DLLIMPORT void AzCssPropertyVec_delete(AzCssPropertyVec* restrict instance);
 extern DLLIMPORT void AzSvgMultiPolygonVec_delete(AzSvgMultiPolygonVec* restrict instance);
 extern DLLIMPORT void AzSvgSimpleNodeVec_delete(AzSvgSimpleNodeVec* restrict instance);
@@ -13427,15 +15232,20 @@ extern DLLIMPORT void AzStylesheetVec_delete(AzStylesheetVec* restrict instance)
 extern DLLIMPORT void AzCssRuleBlockVec_delete(AzCssRuleBlockVec* restrict instance);
 extern DLLIMPORT void AzU16Vec_delete(AzU16Vec* restrict instance);
 extern DLLIMPORT void AzF32Vec_delete(AzF32Vec* restrict instance);
-extern DLLIMPORT AzU8Vec AzU8Vec_copyFromBytes(uint8_t ptr, size_t start, size_t len);
+extern DLLIMPORT AzU8Vec AzU8Vec_copyFromBytes(const uint8_t* ptr, size_t start, size_t len);
 extern DLLIMPORT AzU8VecRef AzU8Vec_asRefVec(const AzU8Vec* u8vec);
 extern DLLIMPORT void AzU8Vec_delete(AzU8Vec* restrict instance);
 extern DLLIMPORT void AzCallbackDataVec_delete(AzCallbackDataVec* restrict instance);
 extern DLLIMPORT void AzDebugMessageVec_delete(AzDebugMessageVec* restrict instance);
 extern DLLIMPORT void AzGLuintVec_delete(AzGLuintVec* restrict instance);
 extern DLLIMPORT void AzGLintVec_delete(AzGLintVec* restrict instance);
+extern DLLIMPORT AzStringVec AzStringVec_new();
+extern DLLIMPORT size_t AzStringVec_len(const AzStringVec* stringvec);
+extern DLLIMPORT AzOptionString AzStringVec_get(const AzStringVec* stringvec, size_t index);
 extern DLLIMPORT void AzStringVec_delete(AzStringVec* restrict instance);
 extern DLLIMPORT void AzStringPairVec_delete(AzStringPairVec* restrict instance);
+extern DLLIMPORT size_t AzNormalizedLinearColorStopVec_len(const AzNormalizedLinearColorStopVec* normalizedlinearcolorstopvec);
+extern DLLIMPORT AzOptionNormalizedLinearColorStop AzNormalizedLinearColorStopVec_get(const AzNormalizedLinearColorStopVec* normalizedlinearcolorstopvec, size_t index);
 extern DLLIMPORT void AzNormalizedLinearColorStopVec_delete(AzNormalizedLinearColorStopVec* restrict instance);
 extern DLLIMPORT void AzNormalizedRadialColorStopVec_delete(AzNormalizedRadialColorStopVec* restrict instance);
 extern DLLIMPORT void AzNodeIdVec_delete(AzNodeIdVec* restrict instance);
@@ -13444,6 +15254,7 @@ extern DLLIMPORT void AzStyledNodeVec_delete(AzStyledNodeVec* restrict instance)
 extern DLLIMPORT void AzTagIdToNodeIdMappingVec_delete(AzTagIdToNodeIdMappingVec* restrict instance);
 extern DLLIMPORT void AzParentWithNodeDepthVec_delete(AzParentWithNodeDepthVec* restrict instance);
 extern DLLIMPORT void AzNodeDataVec_delete(AzNodeDataVec* restrict instance);
+extern DLLIMPORT void AzOptionMediaQuery_delete(AzOptionMediaQuery* restrict instance);
 extern DLLIMPORT void AzOptionListViewOnRowClick_delete(AzOptionListViewOnRowClick* restrict instance);
 extern DLLIMPORT void AzOptionListViewOnColumnClick_delete(AzOptionListViewOnColumnClick* restrict instance);
 extern DLLIMPORT void AzOptionListViewOnLazyLoadScroll_delete(AzOptionListViewOnLazyLoadScroll* restrict instance);
@@ -13490,6 +15301,7 @@ extern DLLIMPORT void AzOptionWaylandTheme_delete(AzOptionWaylandTheme* restrict
 extern DLLIMPORT void AzOptionTaskBarIcon_delete(AzOptionTaskBarIcon* restrict instance);
 extern DLLIMPORT void AzOptionWindowIcon_delete(AzOptionWindowIcon* restrict instance);
 extern DLLIMPORT void AzOptionString_delete(AzOptionString* restrict instance);
+extern DLLIMPORT void AzOptionImeEvent_delete(AzOptionImeEvent* restrict instance);
 extern DLLIMPORT void AzOptionDom_delete(AzOptionDom* restrict instance);
 extern DLLIMPORT void AzOptionTexture_delete(AzOptionTexture* restrict instance);
 extern DLLIMPORT void AzOptionImageMask_delete(AzOptionImageMask* restrict instance);
@@ -13513,6 +15325,14 @@ extern DLLIMPORT void AzXmlStreamError_delete(AzXmlStreamError* restrict instanc
 extern DLLIMPORT void AzInvalidCharMultipleError_delete(AzInvalidCharMultipleError* restrict instance);
 extern DLLIMPORT void AzInvalidStringError_delete(AzInvalidStringError* restrict instance);
 
+/* VERSION of the API this header was generated for, compare with AzApi_versionMajor() etc. */
+
+#define AZ_API_VERSION_MAJOR 0
+#define AZ_API_VERSION_MINOR 0
+#define AZ_API_VERSION_PATCH 1
+#define AZ_API_ABI_HASH 0x37827d2f72e8e9dfULL
+
+
 /* CONSTANTS */
 
 #define AzGl_ACCUM 0x0100
@@ -14952,16 +16772,30 @@ extern DLLIMPORT void AzInvalidStringError_delete(AzInvalidStringError* restrict
 #define AzGl_ZOOM_X 0x0D16
 #define AzGl_ZOOM_Y 0x0D17
 
-bool AzRawWindowHandle_matchRefIOS(const AzRawWindowHandle* value, const AzIOSHandle** restrict out) {
-    const AzRawWindowHandleVariant_IOS* casted = (const AzRawWindowHandleVariant_IOS*)value;
-    bool valid = casted->tag == AzRawWindowHandleTag_IOS;
+bool AzRedrawBehavior_matchRefMaxFps(const AzRedrawBehavior* value, const uint16_t** restrict out) {
+    const AzRedrawBehaviorVariant_MaxFps* casted = (const AzRedrawBehaviorVariant_MaxFps*)value;
+    bool valid = casted->tag == AzRedrawBehaviorTag_MaxFps;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzRawWindowHandle_matchMutIOS(AzRawWindowHandle* restrict value, AzIOSHandle* restrict * restrict out) {
-    AzRawWindowHandleVariant_IOS* restrict casted = (AzRawWindowHandleVariant_IOS* restrict)value;
-    bool valid = casted->tag == AzRawWindowHandleTag_IOS;
+bool AzRedrawBehavior_matchMutMaxFps(AzRedrawBehavior* restrict value, uint16_t* restrict * restrict out) {
+    AzRedrawBehaviorVariant_MaxFps* restrict casted = (AzRedrawBehaviorVariant_MaxFps* restrict)value;
+    bool valid = casted->tag == AzRedrawBehaviorTag_MaxFps;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzRawWindowHandle_matchRefIOS(const AzRawWindowHandle* value, const AzIOSHandle** restrict out) {
+    const AzRawWindowHandleVariant_IOS* casted = (const AzRawWindowHandleVariant_IOS*)value;
+    bool valid = casted->tag == AzRawWindowHandleTag_IOS;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzRawWindowHandle_matchMutIOS(AzRawWindowHandle* restrict value, AzIOSHandle* restrict * restrict out) {
+    AzRawWindowHandleVariant_IOS* restrict casted = (AzRawWindowHandleVariant_IOS* restrict)value;
+    bool valid = casted->tag == AzRawWindowHandleTag_IOS;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
@@ -15106,6 +16940,34 @@ bool AzAcceleratorKey_matchMutKey(AzAcceleratorKey* restrict value, AzVirtualKey
     return valid;
 }
 
+bool AzImeEvent_matchRefPreedit(const AzImeEvent* value, const AzImePreedit** restrict out) {
+    const AzImeEventVariant_Preedit* casted = (const AzImeEventVariant_Preedit*)value;
+    bool valid = casted->tag == AzImeEventTag_Preedit;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzImeEvent_matchMutPreedit(AzImeEvent* restrict value, AzImePreedit* restrict * restrict out) {
+    AzImeEventVariant_Preedit* restrict casted = (AzImeEventVariant_Preedit* restrict)value;
+    bool valid = casted->tag == AzImeEventTag_Preedit;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzImeEvent_matchRefCommit(const AzImeEvent* value, const AzString** restrict out) {
+    const AzImeEventVariant_Commit* casted = (const AzImeEventVariant_Commit*)value;
+    bool valid = casted->tag == AzImeEventTag_Commit;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzImeEvent_matchMutCommit(AzImeEvent* restrict value, AzString* restrict * restrict out) {
+    AzImeEventVariant_Commit* restrict casted = (AzImeEventVariant_Commit* restrict)value;
+    bool valid = casted->tag == AzImeEventTag_Commit;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCursorPosition_matchRefOutOfWindow(const AzCursorPosition* value, const AzLogicalPosition** restrict out) {
     const AzCursorPositionVariant_OutOfWindow* casted = (const AzCursorPositionVariant_OutOfWindow*)value;
     bool valid = casted->tag == AzCursorPositionTag_OutOfWindow;
@@ -15288,14 +17150,14 @@ bool AzFocusTarget_matchMutPath(AzFocusTarget* restrict value, AzFocusTargetPath
     return valid;
 }
 
-bool AzAnimationRepeatCount_matchRefTimes(const AzAnimationRepeatCount* value, const Azusize** restrict out) {
+bool AzAnimationRepeatCount_matchRefTimes(const AzAnimationRepeatCount* value, const size_t** restrict out) {
     const AzAnimationRepeatCountVariant_Times* casted = (const AzAnimationRepeatCountVariant_Times*)value;
     bool valid = casted->tag == AzAnimationRepeatCountTag_Times;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzAnimationRepeatCount_matchMutTimes(AzAnimationRepeatCount* restrict value, Azusize* restrict * restrict out) {
+bool AzAnimationRepeatCount_matchMutTimes(AzAnimationRepeatCount* restrict value, size_t* restrict * restrict out) {
     AzAnimationRepeatCountVariant_Times* restrict casted = (AzAnimationRepeatCountVariant_Times* restrict)value;
     bool valid = casted->tag == AzAnimationRepeatCountTag_Times;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -15470,14 +17332,14 @@ bool AzNotEventFilter_matchMutFocus(AzNotEventFilter* restrict value, AzFocusEve
     return valid;
 }
 
-bool AzTabIndex_matchRefOverrideInParent(const AzTabIndex* value, const Azu32** restrict out) {
+bool AzTabIndex_matchRefOverrideInParent(const AzTabIndex* value, const uint32_t** restrict out) {
     const AzTabIndexVariant_OverrideInParent* casted = (const AzTabIndexVariant_OverrideInParent*)value;
     bool valid = casted->tag == AzTabIndexTag_OverrideInParent;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzTabIndex_matchMutOverrideInParent(AzTabIndex* restrict value, Azu32* restrict * restrict out) {
+bool AzTabIndex_matchMutOverrideInParent(AzTabIndex* restrict value, uint32_t* restrict * restrict out) {
     AzTabIndexVariant_OverrideInParent* restrict casted = (AzTabIndexVariant_OverrideInParent* restrict)value;
     bool valid = casted->tag == AzTabIndexTag_OverrideInParent;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -15582,14 +17444,14 @@ bool AzMenuItem_matchMutString(AzMenuItem* restrict value, AzStringMenuItem* res
     return valid;
 }
 
-bool AzMenuItemIcon_matchRefCheckbox(const AzMenuItemIcon* value, const Azbool** restrict out) {
+bool AzMenuItemIcon_matchRefCheckbox(const AzMenuItemIcon* value, const bool** restrict out) {
     const AzMenuItemIconVariant_Checkbox* casted = (const AzMenuItemIconVariant_Checkbox*)value;
     bool valid = casted->tag == AzMenuItemIconTag_Checkbox;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzMenuItemIcon_matchMutCheckbox(AzMenuItemIcon* restrict value, Azbool* restrict * restrict out) {
+bool AzMenuItemIcon_matchMutCheckbox(AzMenuItemIcon* restrict value, bool* restrict * restrict out) {
     AzMenuItemIconVariant_Checkbox* restrict casted = (AzMenuItemIconVariant_Checkbox* restrict)value;
     bool valid = casted->tag == AzMenuItemIconTag_Checkbox;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -15708,14 +17570,14 @@ bool AzCssPathPseudoSelector_matchMutNthChild(AzCssPathPseudoSelector* restrict
     return valid;
 }
 
-bool AzCssNthChildSelector_matchRefNumber(const AzCssNthChildSelector* value, const Azu32** restrict out) {
+bool AzCssNthChildSelector_matchRefNumber(const AzCssNthChildSelector* value, const uint32_t** restrict out) {
     const AzCssNthChildSelectorVariant_Number* casted = (const AzCssNthChildSelectorVariant_Number*)value;
     bool valid = casted->tag == AzCssNthChildSelectorTag_Number;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssNthChildSelector_matchMutNumber(AzCssNthChildSelector* restrict value, Azu32* restrict * restrict out) {
+bool AzCssNthChildSelector_matchMutNumber(AzCssNthChildSelector* restrict value, uint32_t* restrict * restrict out) {
     AzCssNthChildSelectorVariant_Number* restrict casted = (AzCssNthChildSelectorVariant_Number* restrict)value;
     bool valid = casted->tag == AzCssNthChildSelectorTag_Number;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -15736,6 +17598,216 @@ bool AzCssNthChildSelector_matchMutPattern(AzCssNthChildSelector* restrict value
     return valid;
 }
 
+bool AzMediaFeature_matchRefMinWidth(const AzMediaFeature* value, const AzPixelValue** restrict out) {
+    const AzMediaFeatureVariant_MinWidth* casted = (const AzMediaFeatureVariant_MinWidth*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MinWidth;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutMinWidth(AzMediaFeature* restrict value, AzPixelValue* restrict * restrict out) {
+    AzMediaFeatureVariant_MinWidth* restrict casted = (AzMediaFeatureVariant_MinWidth* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MinWidth;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefMaxWidth(const AzMediaFeature* value, const AzPixelValue** restrict out) {
+    const AzMediaFeatureVariant_MaxWidth* casted = (const AzMediaFeatureVariant_MaxWidth*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MaxWidth;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutMaxWidth(AzMediaFeature* restrict value, AzPixelValue* restrict * restrict out) {
+    AzMediaFeatureVariant_MaxWidth* restrict casted = (AzMediaFeatureVariant_MaxWidth* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MaxWidth;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefMinHeight(const AzMediaFeature* value, const AzPixelValue** restrict out) {
+    const AzMediaFeatureVariant_MinHeight* casted = (const AzMediaFeatureVariant_MinHeight*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MinHeight;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutMinHeight(AzMediaFeature* restrict value, AzPixelValue* restrict * restrict out) {
+    AzMediaFeatureVariant_MinHeight* restrict casted = (AzMediaFeatureVariant_MinHeight* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MinHeight;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefMaxHeight(const AzMediaFeature* value, const AzPixelValue** restrict out) {
+    const AzMediaFeatureVariant_MaxHeight* casted = (const AzMediaFeatureVariant_MaxHeight*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MaxHeight;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutMaxHeight(AzMediaFeature* restrict value, AzPixelValue* restrict * restrict out) {
+    AzMediaFeatureVariant_MaxHeight* restrict casted = (AzMediaFeatureVariant_MaxHeight* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MaxHeight;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefWidthLessThan(const AzMediaFeature* value, const AzPixelValue** restrict out) {
+    const AzMediaFeatureVariant_WidthLessThan* casted = (const AzMediaFeatureVariant_WidthLessThan*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_WidthLessThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutWidthLessThan(AzMediaFeature* restrict value, AzPixelValue* restrict * restrict out) {
+    AzMediaFeatureVariant_WidthLessThan* restrict casted = (AzMediaFeatureVariant_WidthLessThan* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_WidthLessThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefWidthGreaterThan(const AzMediaFeature* value, const AzPixelValue** restrict out) {
+    const AzMediaFeatureVariant_WidthGreaterThan* casted = (const AzMediaFeatureVariant_WidthGreaterThan*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_WidthGreaterThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutWidthGreaterThan(AzMediaFeature* restrict value, AzPixelValue* restrict * restrict out) {
+    AzMediaFeatureVariant_WidthGreaterThan* restrict casted = (AzMediaFeatureVariant_WidthGreaterThan* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_WidthGreaterThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefHeightLessThan(const AzMediaFeature* value, const AzPixelValue** restrict out) {
+    const AzMediaFeatureVariant_HeightLessThan* casted = (const AzMediaFeatureVariant_HeightLessThan*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_HeightLessThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutHeightLessThan(AzMediaFeature* restrict value, AzPixelValue* restrict * restrict out) {
+    AzMediaFeatureVariant_HeightLessThan* restrict casted = (AzMediaFeatureVariant_HeightLessThan* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_HeightLessThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefHeightGreaterThan(const AzMediaFeature* value, const AzPixelValue** restrict out) {
+    const AzMediaFeatureVariant_HeightGreaterThan* casted = (const AzMediaFeatureVariant_HeightGreaterThan*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_HeightGreaterThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutHeightGreaterThan(AzMediaFeature* restrict value, AzPixelValue* restrict * restrict out) {
+    AzMediaFeatureVariant_HeightGreaterThan* restrict casted = (AzMediaFeatureVariant_HeightGreaterThan* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_HeightGreaterThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefOrientation(const AzMediaFeature* value, const AzMediaOrientation** restrict out) {
+    const AzMediaFeatureVariant_Orientation* casted = (const AzMediaFeatureVariant_Orientation*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_Orientation;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutOrientation(AzMediaFeature* restrict value, AzMediaOrientation* restrict * restrict out) {
+    AzMediaFeatureVariant_Orientation* restrict casted = (AzMediaFeatureVariant_Orientation* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_Orientation;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefPrefersColorScheme(const AzMediaFeature* value, const AzMediaColorScheme** restrict out) {
+    const AzMediaFeatureVariant_PrefersColorScheme* casted = (const AzMediaFeatureVariant_PrefersColorScheme*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_PrefersColorScheme;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutPrefersColorScheme(AzMediaFeature* restrict value, AzMediaColorScheme* restrict * restrict out) {
+    AzMediaFeatureVariant_PrefersColorScheme* restrict casted = (AzMediaFeatureVariant_PrefersColorScheme* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_PrefersColorScheme;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefResolution(const AzMediaFeature* value, const AzFloatValue** restrict out) {
+    const AzMediaFeatureVariant_Resolution* casted = (const AzMediaFeatureVariant_Resolution*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_Resolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutResolution(AzMediaFeature* restrict value, AzFloatValue* restrict * restrict out) {
+    AzMediaFeatureVariant_Resolution* restrict casted = (AzMediaFeatureVariant_Resolution* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_Resolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefMinResolution(const AzMediaFeature* value, const AzFloatValue** restrict out) {
+    const AzMediaFeatureVariant_MinResolution* casted = (const AzMediaFeatureVariant_MinResolution*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MinResolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutMinResolution(AzMediaFeature* restrict value, AzFloatValue* restrict * restrict out) {
+    AzMediaFeatureVariant_MinResolution* restrict casted = (AzMediaFeatureVariant_MinResolution* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MinResolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefMaxResolution(const AzMediaFeature* value, const AzFloatValue** restrict out) {
+    const AzMediaFeatureVariant_MaxResolution* casted = (const AzMediaFeatureVariant_MaxResolution*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MaxResolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutMaxResolution(AzMediaFeature* restrict value, AzFloatValue* restrict * restrict out) {
+    AzMediaFeatureVariant_MaxResolution* restrict casted = (AzMediaFeatureVariant_MaxResolution* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_MaxResolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefResolutionLessThan(const AzMediaFeature* value, const AzFloatValue** restrict out) {
+    const AzMediaFeatureVariant_ResolutionLessThan* casted = (const AzMediaFeatureVariant_ResolutionLessThan*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_ResolutionLessThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutResolutionLessThan(AzMediaFeature* restrict value, AzFloatValue* restrict * restrict out) {
+    AzMediaFeatureVariant_ResolutionLessThan* restrict casted = (AzMediaFeatureVariant_ResolutionLessThan* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_ResolutionLessThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchRefResolutionGreaterThan(const AzMediaFeature* value, const AzFloatValue** restrict out) {
+    const AzMediaFeatureVariant_ResolutionGreaterThan* casted = (const AzMediaFeatureVariant_ResolutionGreaterThan*)value;
+    bool valid = casted->tag == AzMediaFeatureTag_ResolutionGreaterThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeature_matchMutResolutionGreaterThan(AzMediaFeature* restrict value, AzFloatValue* restrict * restrict out) {
+    AzMediaFeatureVariant_ResolutionGreaterThan* restrict casted = (AzMediaFeatureVariant_ResolutionGreaterThan* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureTag_ResolutionGreaterThan;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzAnimationInterpolationFunction_matchRefCubicBezier(const AzAnimationInterpolationFunction* value, const AzSvgCubicCurve** restrict out) {
     const AzAnimationInterpolationFunctionVariant_CubicBezier* casted = (const AzAnimationInterpolationFunctionVariant_CubicBezier*)value;
     bool valid = casted->tag == AzAnimationInterpolationFunctionTag_CubicBezier;
@@ -15862,20 +17934,90 @@ bool AzStyleFilter_matchMutComposite(AzStyleFilter* restrict value, AzStyleCompo
     return valid;
 }
 
-bool AzStyleCompositeFilter_matchRefArithmetic(const AzStyleCompositeFilter* value, const Az[FloatValue;4]** restrict out) {
+bool AzStyleCompositeFilter_matchRefArithmetic(const AzStyleCompositeFilter* value, const AzFloatValue (** restrict out)[4]) {
     const AzStyleCompositeFilterVariant_Arithmetic* casted = (const AzStyleCompositeFilterVariant_Arithmetic*)value;
     bool valid = casted->tag == AzStyleCompositeFilterTag_Arithmetic;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleCompositeFilter_matchMutArithmetic(AzStyleCompositeFilter* restrict value, Az[FloatValue;4]* restrict * restrict out) {
+bool AzStyleCompositeFilter_matchMutArithmetic(AzStyleCompositeFilter* restrict value, AzFloatValue (* restrict * restrict out)[4]) {
     AzStyleCompositeFilterVariant_Arithmetic* restrict casted = (AzStyleCompositeFilterVariant_Arithmetic* restrict)value;
     bool valid = casted->tag == AzStyleCompositeFilterTag_Arithmetic;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
+bool AzGridTrack_matchRefPx(const AzGridTrack* value, const AzPixelValue** restrict out) {
+    const AzGridTrackVariant_Px* casted = (const AzGridTrackVariant_Px*)value;
+    bool valid = casted->tag == AzGridTrackTag_Px;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrack_matchMutPx(AzGridTrack* restrict value, AzPixelValue* restrict * restrict out) {
+    AzGridTrackVariant_Px* restrict casted = (AzGridTrackVariant_Px* restrict)value;
+    bool valid = casted->tag == AzGridTrackTag_Px;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrack_matchRefFraction(const AzGridTrack* value, const AzFloatValue** restrict out) {
+    const AzGridTrackVariant_Fraction* casted = (const AzGridTrackVariant_Fraction*)value;
+    bool valid = casted->tag == AzGridTrackTag_Fraction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrack_matchMutFraction(AzGridTrack* restrict value, AzFloatValue* restrict * restrict out) {
+    AzGridTrackVariant_Fraction* restrict casted = (AzGridTrackVariant_Fraction* restrict)value;
+    bool valid = casted->tag == AzGridTrackTag_Fraction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrack_matchRefRepeat(const AzGridTrack* value, const AzGridTrackRepeat** restrict out) {
+    const AzGridTrackVariant_Repeat* casted = (const AzGridTrackVariant_Repeat*)value;
+    bool valid = casted->tag == AzGridTrackTag_Repeat;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrack_matchMutRepeat(AzGridTrack* restrict value, AzGridTrackRepeat* restrict * restrict out) {
+    AzGridTrackVariant_Repeat* restrict casted = (AzGridTrackVariant_Repeat* restrict)value;
+    bool valid = casted->tag == AzGridTrackTag_Repeat;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchRefLine(const AzGridLine* value, const int16_t** restrict out) {
+    const AzGridLineVariant_Line* casted = (const AzGridLineVariant_Line*)value;
+    bool valid = casted->tag == AzGridLineTag_Line;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchMutLine(AzGridLine* restrict value, int16_t* restrict * restrict out) {
+    AzGridLineVariant_Line* restrict casted = (AzGridLineVariant_Line* restrict)value;
+    bool valid = casted->tag == AzGridLineTag_Line;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchRefSpan(const AzGridLine* value, const uint16_t** restrict out) {
+    const AzGridLineVariant_Span* casted = (const AzGridLineVariant_Span*)value;
+    bool valid = casted->tag == AzGridLineTag_Span;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchMutSpan(AzGridLine* restrict value, uint16_t* restrict * restrict out) {
+    AzGridLineVariant_Span* restrict casted = (AzGridLineVariant_Span* restrict)value;
+    bool valid = casted->tag == AzGridLineTag_Span;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzDirection_matchRefAngle(const AzDirection* value, const AzAngleValue** restrict out) {
     const AzDirectionVariant_Angle* casted = (const AzDirectionVariant_Angle*)value;
     bool valid = casted->tag == AzDirectionTag_Angle;
@@ -16002,14 +18144,14 @@ bool AzBackgroundPositionVertical_matchMutExact(AzBackgroundPositionVertical* re
     return valid;
 }
 
-bool AzStyleBackgroundSize_matchRefExactSize(const AzStyleBackgroundSize* value, const Az[PixelValue;2]** restrict out) {
+bool AzStyleBackgroundSize_matchRefExactSize(const AzStyleBackgroundSize* value, const AzPixelValue (** restrict out)[2]) {
     const AzStyleBackgroundSizeVariant_ExactSize* casted = (const AzStyleBackgroundSizeVariant_ExactSize*)value;
     bool valid = casted->tag == AzStyleBackgroundSizeTag_ExactSize;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundSize_matchMutExactSize(AzStyleBackgroundSize* restrict value, Az[PixelValue;2]* restrict * restrict out) {
+bool AzStyleBackgroundSize_matchMutExactSize(AzStyleBackgroundSize* restrict value, AzPixelValue (* restrict * restrict out)[2]) {
     AzStyleBackgroundSizeVariant_ExactSize* restrict casted = (AzStyleBackgroundSizeVariant_ExactSize* restrict)value;
     bool valid = casted->tag == AzStyleBackgroundSizeTag_ExactSize;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -16352,14 +18494,70 @@ bool AzStyleTransform_matchMutPerspective(AzStyleTransform* restrict value, AzPi
     return valid;
 }
 
-bool AzStyleBoxShadowValue_matchRefExact(const AzStyleBoxShadowValue* value, const AzStyleBoxShadow** restrict out) {
+bool AzStyleTransitionProperty_matchRefList(const AzStyleTransitionProperty* value, const AzCssPropertyTypeVec** restrict out) {
+    const AzStyleTransitionPropertyVariant_List* casted = (const AzStyleTransitionPropertyVariant_List*)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyTag_List;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionProperty_matchMutList(AzStyleTransitionProperty* restrict value, AzCssPropertyTypeVec* restrict * restrict out) {
+    AzStyleTransitionPropertyVariant_List* restrict casted = (AzStyleTransitionPropertyVariant_List* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyTag_List;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunction_matchRefCubicBezier(const AzStyleTransitionTimingFunction* value, const AzStyleCubicBezier** restrict out) {
+    const AzStyleTransitionTimingFunctionVariant_CubicBezier* casted = (const AzStyleTransitionTimingFunctionVariant_CubicBezier*)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionTag_CubicBezier;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunction_matchMutCubicBezier(AzStyleTransitionTimingFunction* restrict value, AzStyleCubicBezier* restrict * restrict out) {
+    AzStyleTransitionTimingFunctionVariant_CubicBezier* restrict casted = (AzStyleTransitionTimingFunctionVariant_CubicBezier* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionTag_CubicBezier;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunction_matchRefSteps(const AzStyleTransitionTimingFunction* value, const AzStyleSteps** restrict out) {
+    const AzStyleTransitionTimingFunctionVariant_Steps* casted = (const AzStyleTransitionTimingFunctionVariant_Steps*)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionTag_Steps;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunction_matchMutSteps(AzStyleTransitionTimingFunction* restrict value, AzStyleSteps* restrict * restrict out) {
+    AzStyleTransitionTimingFunctionVariant_Steps* restrict casted = (AzStyleTransitionTimingFunctionVariant_Steps* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionTag_Steps;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleVerticalAlign_matchRefExact(const AzStyleVerticalAlign* value, const AzPixelValue** restrict out) {
+    const AzStyleVerticalAlignVariant_Exact* casted = (const AzStyleVerticalAlignVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleVerticalAlignTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleVerticalAlign_matchMutExact(AzStyleVerticalAlign* restrict value, AzPixelValue* restrict * restrict out) {
+    AzStyleVerticalAlignVariant_Exact* restrict casted = (AzStyleVerticalAlignVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleVerticalAlignTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBoxShadowValue_matchRefExact(const AzStyleBoxShadowValue* value, const AzStyleBoxShadowBoxed*** restrict out) {
     const AzStyleBoxShadowValueVariant_Exact* casted = (const AzStyleBoxShadowValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleBoxShadowValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBoxShadowValue_matchMutExact(AzStyleBoxShadowValue* restrict value, AzStyleBoxShadow* restrict * restrict out) {
+bool AzStyleBoxShadowValue_matchMutExact(AzStyleBoxShadowValue* restrict value, AzStyleBoxShadowBoxed** restrict * restrict out) {
     AzStyleBoxShadowValueVariant_Exact* restrict casted = (AzStyleBoxShadowValueVariant_Exact* restrict)value;
     bool valid = casted->tag == AzStyleBoxShadowValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -16380,6 +18578,62 @@ bool AzLayoutAlignContentValue_matchMutExact(AzLayoutAlignContentValue* restrict
     return valid;
 }
 
+bool AzLayoutGridTemplateColumnsValue_matchRefExact(const AzLayoutGridTemplateColumnsValue* value, const AzLayoutGridTemplateColumnsBoxed*** restrict out) {
+    const AzLayoutGridTemplateColumnsValueVariant_Exact* casted = (const AzLayoutGridTemplateColumnsValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutGridTemplateColumnsValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridTemplateColumnsValue_matchMutExact(AzLayoutGridTemplateColumnsValue* restrict value, AzLayoutGridTemplateColumnsBoxed** restrict * restrict out) {
+    AzLayoutGridTemplateColumnsValueVariant_Exact* restrict casted = (AzLayoutGridTemplateColumnsValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutGridTemplateColumnsValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridTemplateRowsValue_matchRefExact(const AzLayoutGridTemplateRowsValue* value, const AzLayoutGridTemplateRowsBoxed*** restrict out) {
+    const AzLayoutGridTemplateRowsValueVariant_Exact* casted = (const AzLayoutGridTemplateRowsValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutGridTemplateRowsValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridTemplateRowsValue_matchMutExact(AzLayoutGridTemplateRowsValue* restrict value, AzLayoutGridTemplateRowsBoxed** restrict * restrict out) {
+    AzLayoutGridTemplateRowsValueVariant_Exact* restrict casted = (AzLayoutGridTemplateRowsValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutGridTemplateRowsValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridColumnValue_matchRefExact(const AzLayoutGridColumnValue* value, const AzLayoutGridColumn** restrict out) {
+    const AzLayoutGridColumnValueVariant_Exact* casted = (const AzLayoutGridColumnValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutGridColumnValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridColumnValue_matchMutExact(AzLayoutGridColumnValue* restrict value, AzLayoutGridColumn* restrict * restrict out) {
+    AzLayoutGridColumnValueVariant_Exact* restrict casted = (AzLayoutGridColumnValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutGridColumnValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridRowValue_matchRefExact(const AzLayoutGridRowValue* value, const AzLayoutGridRow** restrict out) {
+    const AzLayoutGridRowValueVariant_Exact* casted = (const AzLayoutGridRowValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutGridRowValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutGridRowValue_matchMutExact(AzLayoutGridRowValue* restrict value, AzLayoutGridRow* restrict * restrict out) {
+    AzLayoutGridRowValueVariant_Exact* restrict casted = (AzLayoutGridRowValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutGridRowValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutAlignItemsValue_matchRefExact(const AzLayoutAlignItemsValue* value, const AzLayoutAlignItems** restrict out) {
     const AzLayoutAlignItemsValueVariant_Exact* casted = (const AzLayoutAlignItemsValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutAlignItemsValueTag_Exact;
@@ -16408,6 +18662,20 @@ bool AzLayoutBottomValue_matchMutExact(AzLayoutBottomValue* restrict value, AzLa
     return valid;
 }
 
+bool AzLayoutBottomValue_matchRefCalc(const AzLayoutBottomValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutBottomValueVariant_Calc* casted = (const AzLayoutBottomValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutBottomValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutBottomValue_matchMutCalc(AzLayoutBottomValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutBottomValueVariant_Calc* restrict casted = (AzLayoutBottomValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutBottomValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutBoxSizingValue_matchRefExact(const AzLayoutBoxSizingValue* value, const AzLayoutBoxSizing** restrict out) {
     const AzLayoutBoxSizingValueVariant_Exact* casted = (const AzLayoutBoxSizingValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutBoxSizingValueTag_Exact;
@@ -16506,6 +18774,20 @@ bool AzLayoutHeightValue_matchMutExact(AzLayoutHeightValue* restrict value, AzLa
     return valid;
 }
 
+bool AzLayoutHeightValue_matchRefCalc(const AzLayoutHeightValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutHeightValueVariant_Calc* casted = (const AzLayoutHeightValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutHeightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutHeightValue_matchMutCalc(AzLayoutHeightValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutHeightValueVariant_Calc* restrict casted = (AzLayoutHeightValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutHeightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutJustifyContentValue_matchRefExact(const AzLayoutJustifyContentValue* value, const AzLayoutJustifyContent** restrict out) {
     const AzLayoutJustifyContentValueVariant_Exact* casted = (const AzLayoutJustifyContentValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutJustifyContentValueTag_Exact;
@@ -16534,6 +18816,20 @@ bool AzLayoutLeftValue_matchMutExact(AzLayoutLeftValue* restrict value, AzLayout
     return valid;
 }
 
+bool AzLayoutLeftValue_matchRefCalc(const AzLayoutLeftValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutLeftValueVariant_Calc* casted = (const AzLayoutLeftValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutLeftValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutLeftValue_matchMutCalc(AzLayoutLeftValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutLeftValueVariant_Calc* restrict casted = (AzLayoutLeftValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutLeftValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutMarginBottomValue_matchRefExact(const AzLayoutMarginBottomValue* value, const AzLayoutMarginBottom** restrict out) {
     const AzLayoutMarginBottomValueVariant_Exact* casted = (const AzLayoutMarginBottomValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutMarginBottomValueTag_Exact;
@@ -16548,6 +18844,20 @@ bool AzLayoutMarginBottomValue_matchMutExact(AzLayoutMarginBottomValue* restrict
     return valid;
 }
 
+bool AzLayoutMarginBottomValue_matchRefCalc(const AzLayoutMarginBottomValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutMarginBottomValueVariant_Calc* casted = (const AzLayoutMarginBottomValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutMarginBottomValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutMarginBottomValue_matchMutCalc(AzLayoutMarginBottomValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutMarginBottomValueVariant_Calc* restrict casted = (AzLayoutMarginBottomValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutMarginBottomValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutMarginLeftValue_matchRefExact(const AzLayoutMarginLeftValue* value, const AzLayoutMarginLeft** restrict out) {
     const AzLayoutMarginLeftValueVariant_Exact* casted = (const AzLayoutMarginLeftValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutMarginLeftValueTag_Exact;
@@ -16562,6 +18872,20 @@ bool AzLayoutMarginLeftValue_matchMutExact(AzLayoutMarginLeftValue* restrict val
     return valid;
 }
 
+bool AzLayoutMarginLeftValue_matchRefCalc(const AzLayoutMarginLeftValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutMarginLeftValueVariant_Calc* casted = (const AzLayoutMarginLeftValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutMarginLeftValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutMarginLeftValue_matchMutCalc(AzLayoutMarginLeftValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutMarginLeftValueVariant_Calc* restrict casted = (AzLayoutMarginLeftValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutMarginLeftValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutMarginRightValue_matchRefExact(const AzLayoutMarginRightValue* value, const AzLayoutMarginRight** restrict out) {
     const AzLayoutMarginRightValueVariant_Exact* casted = (const AzLayoutMarginRightValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutMarginRightValueTag_Exact;
@@ -16576,6 +18900,20 @@ bool AzLayoutMarginRightValue_matchMutExact(AzLayoutMarginRightValue* restrict v
     return valid;
 }
 
+bool AzLayoutMarginRightValue_matchRefCalc(const AzLayoutMarginRightValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutMarginRightValueVariant_Calc* casted = (const AzLayoutMarginRightValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutMarginRightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutMarginRightValue_matchMutCalc(AzLayoutMarginRightValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutMarginRightValueVariant_Calc* restrict casted = (AzLayoutMarginRightValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutMarginRightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutMarginTopValue_matchRefExact(const AzLayoutMarginTopValue* value, const AzLayoutMarginTop** restrict out) {
     const AzLayoutMarginTopValueVariant_Exact* casted = (const AzLayoutMarginTopValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutMarginTopValueTag_Exact;
@@ -16590,6 +18928,20 @@ bool AzLayoutMarginTopValue_matchMutExact(AzLayoutMarginTopValue* restrict value
     return valid;
 }
 
+bool AzLayoutMarginTopValue_matchRefCalc(const AzLayoutMarginTopValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutMarginTopValueVariant_Calc* casted = (const AzLayoutMarginTopValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutMarginTopValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutMarginTopValue_matchMutCalc(AzLayoutMarginTopValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutMarginTopValueVariant_Calc* restrict casted = (AzLayoutMarginTopValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutMarginTopValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutMaxHeightValue_matchRefExact(const AzLayoutMaxHeightValue* value, const AzLayoutMaxHeight** restrict out) {
     const AzLayoutMaxHeightValueVariant_Exact* casted = (const AzLayoutMaxHeightValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutMaxHeightValueTag_Exact;
@@ -16604,6 +18956,20 @@ bool AzLayoutMaxHeightValue_matchMutExact(AzLayoutMaxHeightValue* restrict value
     return valid;
 }
 
+bool AzLayoutMaxHeightValue_matchRefCalc(const AzLayoutMaxHeightValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutMaxHeightValueVariant_Calc* casted = (const AzLayoutMaxHeightValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutMaxHeightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutMaxHeightValue_matchMutCalc(AzLayoutMaxHeightValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutMaxHeightValueVariant_Calc* restrict casted = (AzLayoutMaxHeightValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutMaxHeightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutMaxWidthValue_matchRefExact(const AzLayoutMaxWidthValue* value, const AzLayoutMaxWidth** restrict out) {
     const AzLayoutMaxWidthValueVariant_Exact* casted = (const AzLayoutMaxWidthValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutMaxWidthValueTag_Exact;
@@ -16618,6 +18984,20 @@ bool AzLayoutMaxWidthValue_matchMutExact(AzLayoutMaxWidthValue* restrict value,
     return valid;
 }
 
+bool AzLayoutMaxWidthValue_matchRefCalc(const AzLayoutMaxWidthValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutMaxWidthValueVariant_Calc* casted = (const AzLayoutMaxWidthValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutMaxWidthValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutMaxWidthValue_matchMutCalc(AzLayoutMaxWidthValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutMaxWidthValueVariant_Calc* restrict casted = (AzLayoutMaxWidthValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutMaxWidthValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutMinHeightValue_matchRefExact(const AzLayoutMinHeightValue* value, const AzLayoutMinHeight** restrict out) {
     const AzLayoutMinHeightValueVariant_Exact* casted = (const AzLayoutMinHeightValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutMinHeightValueTag_Exact;
@@ -16632,6 +19012,20 @@ bool AzLayoutMinHeightValue_matchMutExact(AzLayoutMinHeightValue* restrict value
     return valid;
 }
 
+bool AzLayoutMinHeightValue_matchRefCalc(const AzLayoutMinHeightValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutMinHeightValueVariant_Calc* casted = (const AzLayoutMinHeightValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutMinHeightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutMinHeightValue_matchMutCalc(AzLayoutMinHeightValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutMinHeightValueVariant_Calc* restrict casted = (AzLayoutMinHeightValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutMinHeightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutMinWidthValue_matchRefExact(const AzLayoutMinWidthValue* value, const AzLayoutMinWidth** restrict out) {
     const AzLayoutMinWidthValueVariant_Exact* casted = (const AzLayoutMinWidthValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutMinWidthValueTag_Exact;
@@ -16646,6 +19040,20 @@ bool AzLayoutMinWidthValue_matchMutExact(AzLayoutMinWidthValue* restrict value,
     return valid;
 }
 
+bool AzLayoutMinWidthValue_matchRefCalc(const AzLayoutMinWidthValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutMinWidthValueVariant_Calc* casted = (const AzLayoutMinWidthValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutMinWidthValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutMinWidthValue_matchMutCalc(AzLayoutMinWidthValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutMinWidthValueVariant_Calc* restrict casted = (AzLayoutMinWidthValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutMinWidthValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutPaddingBottomValue_matchRefExact(const AzLayoutPaddingBottomValue* value, const AzLayoutPaddingBottom** restrict out) {
     const AzLayoutPaddingBottomValueVariant_Exact* casted = (const AzLayoutPaddingBottomValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutPaddingBottomValueTag_Exact;
@@ -16660,6 +19068,20 @@ bool AzLayoutPaddingBottomValue_matchMutExact(AzLayoutPaddingBottomValue* restri
     return valid;
 }
 
+bool AzLayoutPaddingBottomValue_matchRefCalc(const AzLayoutPaddingBottomValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutPaddingBottomValueVariant_Calc* casted = (const AzLayoutPaddingBottomValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutPaddingBottomValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutPaddingBottomValue_matchMutCalc(AzLayoutPaddingBottomValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutPaddingBottomValueVariant_Calc* restrict casted = (AzLayoutPaddingBottomValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutPaddingBottomValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutPaddingLeftValue_matchRefExact(const AzLayoutPaddingLeftValue* value, const AzLayoutPaddingLeft** restrict out) {
     const AzLayoutPaddingLeftValueVariant_Exact* casted = (const AzLayoutPaddingLeftValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutPaddingLeftValueTag_Exact;
@@ -16674,6 +19096,20 @@ bool AzLayoutPaddingLeftValue_matchMutExact(AzLayoutPaddingLeftValue* restrict v
     return valid;
 }
 
+bool AzLayoutPaddingLeftValue_matchRefCalc(const AzLayoutPaddingLeftValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutPaddingLeftValueVariant_Calc* casted = (const AzLayoutPaddingLeftValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutPaddingLeftValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutPaddingLeftValue_matchMutCalc(AzLayoutPaddingLeftValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutPaddingLeftValueVariant_Calc* restrict casted = (AzLayoutPaddingLeftValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutPaddingLeftValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutPaddingRightValue_matchRefExact(const AzLayoutPaddingRightValue* value, const AzLayoutPaddingRight** restrict out) {
     const AzLayoutPaddingRightValueVariant_Exact* casted = (const AzLayoutPaddingRightValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutPaddingRightValueTag_Exact;
@@ -16688,6 +19124,20 @@ bool AzLayoutPaddingRightValue_matchMutExact(AzLayoutPaddingRightValue* restrict
     return valid;
 }
 
+bool AzLayoutPaddingRightValue_matchRefCalc(const AzLayoutPaddingRightValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutPaddingRightValueVariant_Calc* casted = (const AzLayoutPaddingRightValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutPaddingRightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutPaddingRightValue_matchMutCalc(AzLayoutPaddingRightValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutPaddingRightValueVariant_Calc* restrict casted = (AzLayoutPaddingRightValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutPaddingRightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutPaddingTopValue_matchRefExact(const AzLayoutPaddingTopValue* value, const AzLayoutPaddingTop** restrict out) {
     const AzLayoutPaddingTopValueVariant_Exact* casted = (const AzLayoutPaddingTopValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutPaddingTopValueTag_Exact;
@@ -16702,6 +19152,20 @@ bool AzLayoutPaddingTopValue_matchMutExact(AzLayoutPaddingTopValue* restrict val
     return valid;
 }
 
+bool AzLayoutPaddingTopValue_matchRefCalc(const AzLayoutPaddingTopValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutPaddingTopValueVariant_Calc* casted = (const AzLayoutPaddingTopValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutPaddingTopValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutPaddingTopValue_matchMutCalc(AzLayoutPaddingTopValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutPaddingTopValueVariant_Calc* restrict casted = (AzLayoutPaddingTopValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutPaddingTopValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutPositionValue_matchRefExact(const AzLayoutPositionValue* value, const AzLayoutPosition** restrict out) {
     const AzLayoutPositionValueVariant_Exact* casted = (const AzLayoutPositionValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutPositionValueTag_Exact;
@@ -16730,6 +19194,20 @@ bool AzLayoutRightValue_matchMutExact(AzLayoutRightValue* restrict value, AzLayo
     return valid;
 }
 
+bool AzLayoutRightValue_matchRefCalc(const AzLayoutRightValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutRightValueVariant_Calc* casted = (const AzLayoutRightValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutRightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutRightValue_matchMutCalc(AzLayoutRightValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutRightValueVariant_Calc* restrict casted = (AzLayoutRightValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutRightValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutTopValue_matchRefExact(const AzLayoutTopValue* value, const AzLayoutTop** restrict out) {
     const AzLayoutTopValueVariant_Exact* casted = (const AzLayoutTopValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutTopValueTag_Exact;
@@ -16744,6 +19222,20 @@ bool AzLayoutTopValue_matchMutExact(AzLayoutTopValue* restrict value, AzLayoutTo
     return valid;
 }
 
+bool AzLayoutTopValue_matchRefCalc(const AzLayoutTopValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutTopValueVariant_Calc* casted = (const AzLayoutTopValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutTopValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutTopValue_matchMutCalc(AzLayoutTopValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutTopValueVariant_Calc* restrict casted = (AzLayoutTopValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutTopValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutWidthValue_matchRefExact(const AzLayoutWidthValue* value, const AzLayoutWidth** restrict out) {
     const AzLayoutWidthValueVariant_Exact* casted = (const AzLayoutWidthValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutWidthValueTag_Exact;
@@ -16758,6 +19250,20 @@ bool AzLayoutWidthValue_matchMutExact(AzLayoutWidthValue* restrict value, AzLayo
     return valid;
 }
 
+bool AzLayoutWidthValue_matchRefCalc(const AzLayoutWidthValue* value, const AzCalcLengthBoxed*** restrict out) {
+    const AzLayoutWidthValueVariant_Calc* casted = (const AzLayoutWidthValueVariant_Calc*)value;
+    bool valid = casted->tag == AzLayoutWidthValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutWidthValue_matchMutCalc(AzLayoutWidthValue* restrict value, AzCalcLengthBoxed** restrict * restrict out) {
+    AzLayoutWidthValueVariant_Calc* restrict casted = (AzLayoutWidthValueVariant_Calc* restrict)value;
+    bool valid = casted->tag == AzLayoutWidthValueTag_Calc;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzLayoutFlexWrapValue_matchRefExact(const AzLayoutFlexWrapValue* value, const AzLayoutFlexWrap** restrict out) {
     const AzLayoutFlexWrapValueVariant_Exact* casted = (const AzLayoutFlexWrapValueVariant_Exact*)value;
     bool valid = casted->tag == AzLayoutFlexWrapValueTag_Exact;
@@ -16786,70 +19292,70 @@ bool AzLayoutOverflowValue_matchMutExact(AzLayoutOverflowValue* restrict value,
     return valid;
 }
 
-bool AzScrollbarStyleValue_matchRefExact(const AzScrollbarStyleValue* value, const AzScrollbarStyle** restrict out) {
+bool AzScrollbarStyleValue_matchRefExact(const AzScrollbarStyleValue* value, const AzScrollbarStyleBoxed*** restrict out) {
     const AzScrollbarStyleValueVariant_Exact* casted = (const AzScrollbarStyleValueVariant_Exact*)value;
     bool valid = casted->tag == AzScrollbarStyleValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzScrollbarStyleValue_matchMutExact(AzScrollbarStyleValue* restrict value, AzScrollbarStyle* restrict * restrict out) {
+bool AzScrollbarStyleValue_matchMutExact(AzScrollbarStyleValue* restrict value, AzScrollbarStyleBoxed** restrict * restrict out) {
     AzScrollbarStyleValueVariant_Exact* restrict casted = (AzScrollbarStyleValueVariant_Exact* restrict)value;
     bool valid = casted->tag == AzScrollbarStyleValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundContentVecValue_matchRefExact(const AzStyleBackgroundContentVecValue* value, const AzStyleBackgroundContentVec** restrict out) {
+bool AzStyleBackgroundContentVecValue_matchRefExact(const AzStyleBackgroundContentVecValue* value, const AzStyleBackgroundContentVecBoxed*** restrict out) {
     const AzStyleBackgroundContentVecValueVariant_Exact* casted = (const AzStyleBackgroundContentVecValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleBackgroundContentVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundContentVecValue_matchMutExact(AzStyleBackgroundContentVecValue* restrict value, AzStyleBackgroundContentVec* restrict * restrict out) {
+bool AzStyleBackgroundContentVecValue_matchMutExact(AzStyleBackgroundContentVecValue* restrict value, AzStyleBackgroundContentVecBoxed** restrict * restrict out) {
     AzStyleBackgroundContentVecValueVariant_Exact* restrict casted = (AzStyleBackgroundContentVecValueVariant_Exact* restrict)value;
     bool valid = casted->tag == AzStyleBackgroundContentVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundPositionVecValue_matchRefExact(const AzStyleBackgroundPositionVecValue* value, const AzStyleBackgroundPositionVec** restrict out) {
+bool AzStyleBackgroundPositionVecValue_matchRefExact(const AzStyleBackgroundPositionVecValue* value, const AzStyleBackgroundPositionVecBoxed*** restrict out) {
     const AzStyleBackgroundPositionVecValueVariant_Exact* casted = (const AzStyleBackgroundPositionVecValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleBackgroundPositionVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundPositionVecValue_matchMutExact(AzStyleBackgroundPositionVecValue* restrict value, AzStyleBackgroundPositionVec* restrict * restrict out) {
+bool AzStyleBackgroundPositionVecValue_matchMutExact(AzStyleBackgroundPositionVecValue* restrict value, AzStyleBackgroundPositionVecBoxed** restrict * restrict out) {
     AzStyleBackgroundPositionVecValueVariant_Exact* restrict casted = (AzStyleBackgroundPositionVecValueVariant_Exact* restrict)value;
     bool valid = casted->tag == AzStyleBackgroundPositionVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundRepeatVecValue_matchRefExact(const AzStyleBackgroundRepeatVecValue* value, const AzStyleBackgroundRepeatVec** restrict out) {
+bool AzStyleBackgroundRepeatVecValue_matchRefExact(const AzStyleBackgroundRepeatVecValue* value, const AzStyleBackgroundRepeatVecBoxed*** restrict out) {
     const AzStyleBackgroundRepeatVecValueVariant_Exact* casted = (const AzStyleBackgroundRepeatVecValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleBackgroundRepeatVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundRepeatVecValue_matchMutExact(AzStyleBackgroundRepeatVecValue* restrict value, AzStyleBackgroundRepeatVec* restrict * restrict out) {
+bool AzStyleBackgroundRepeatVecValue_matchMutExact(AzStyleBackgroundRepeatVecValue* restrict value, AzStyleBackgroundRepeatVecBoxed** restrict * restrict out) {
     AzStyleBackgroundRepeatVecValueVariant_Exact* restrict casted = (AzStyleBackgroundRepeatVecValueVariant_Exact* restrict)value;
     bool valid = casted->tag == AzStyleBackgroundRepeatVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundSizeVecValue_matchRefExact(const AzStyleBackgroundSizeVecValue* value, const AzStyleBackgroundSizeVec** restrict out) {
+bool AzStyleBackgroundSizeVecValue_matchRefExact(const AzStyleBackgroundSizeVecValue* value, const AzStyleBackgroundSizeVecBoxed*** restrict out) {
     const AzStyleBackgroundSizeVecValueVariant_Exact* casted = (const AzStyleBackgroundSizeVecValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleBackgroundSizeVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundSizeVecValue_matchMutExact(AzStyleBackgroundSizeVecValue* restrict value, AzStyleBackgroundSizeVec* restrict * restrict out) {
+bool AzStyleBackgroundSizeVecValue_matchMutExact(AzStyleBackgroundSizeVecValue* restrict value, AzStyleBackgroundSizeVecBoxed** restrict * restrict out) {
     AzStyleBackgroundSizeVecValueVariant_Exact* restrict casted = (AzStyleBackgroundSizeVecValueVariant_Exact* restrict)value;
     bool valid = casted->tag == AzStyleBackgroundSizeVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -17094,86 +19600,212 @@ bool AzStyleCursorValue_matchMutExact(AzStyleCursorValue* restrict value, AzStyl
     return valid;
 }
 
-bool AzStyleFontFamilyVecValue_matchRefExact(const AzStyleFontFamilyVecValue* value, const AzStyleFontFamilyVec** restrict out) {
+bool AzStyleFontFamilyVecValue_matchRefExact(const AzStyleFontFamilyVecValue* value, const AzStyleFontFamilyVecBoxed*** restrict out) {
     const AzStyleFontFamilyVecValueVariant_Exact* casted = (const AzStyleFontFamilyVecValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleFontFamilyVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleFontFamilyVecValue_matchMutExact(AzStyleFontFamilyVecValue* restrict value, AzStyleFontFamilyVec* restrict * restrict out) {
-    AzStyleFontFamilyVecValueVariant_Exact* restrict casted = (AzStyleFontFamilyVecValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzStyleFontFamilyVecValueTag_Exact;
+bool AzStyleFontFamilyVecValue_matchMutExact(AzStyleFontFamilyVecValue* restrict value, AzStyleFontFamilyVecBoxed** restrict * restrict out) {
+    AzStyleFontFamilyVecValueVariant_Exact* restrict casted = (AzStyleFontFamilyVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleFontFamilyVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleFontSizeValue_matchRefExact(const AzStyleFontSizeValue* value, const AzStyleFontSize** restrict out) {
+    const AzStyleFontSizeValueVariant_Exact* casted = (const AzStyleFontSizeValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleFontSizeValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleFontSizeValue_matchMutExact(AzStyleFontSizeValue* restrict value, AzStyleFontSize* restrict * restrict out) {
+    AzStyleFontSizeValueVariant_Exact* restrict casted = (AzStyleFontSizeValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleFontSizeValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleLetterSpacingValue_matchRefExact(const AzStyleLetterSpacingValue* value, const AzStyleLetterSpacing** restrict out) {
+    const AzStyleLetterSpacingValueVariant_Exact* casted = (const AzStyleLetterSpacingValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleLetterSpacingValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleLetterSpacingValue_matchMutExact(AzStyleLetterSpacingValue* restrict value, AzStyleLetterSpacing* restrict * restrict out) {
+    AzStyleLetterSpacingValueVariant_Exact* restrict casted = (AzStyleLetterSpacingValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleLetterSpacingValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleLineHeightValue_matchRefExact(const AzStyleLineHeightValue* value, const AzStyleLineHeight** restrict out) {
+    const AzStyleLineHeightValueVariant_Exact* casted = (const AzStyleLineHeightValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleLineHeightValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleLineHeightValue_matchMutExact(AzStyleLineHeightValue* restrict value, AzStyleLineHeight* restrict * restrict out) {
+    AzStyleLineHeightValueVariant_Exact* restrict casted = (AzStyleLineHeightValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleLineHeightValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTabWidthValue_matchRefExact(const AzStyleTabWidthValue* value, const AzStyleTabWidth** restrict out) {
+    const AzStyleTabWidthValueVariant_Exact* casted = (const AzStyleTabWidthValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTabWidthValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTabWidthValue_matchMutExact(AzStyleTabWidthValue* restrict value, AzStyleTabWidth* restrict * restrict out) {
+    AzStyleTabWidthValueVariant_Exact* restrict casted = (AzStyleTabWidthValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTabWidthValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTextAlignValue_matchRefExact(const AzStyleTextAlignValue* value, const AzStyleTextAlign** restrict out) {
+    const AzStyleTextAlignValueVariant_Exact* casted = (const AzStyleTextAlignValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTextAlignValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTextAlignValue_matchMutExact(AzStyleTextAlignValue* restrict value, AzStyleTextAlign* restrict * restrict out) {
+    AzStyleTextAlignValueVariant_Exact* restrict casted = (AzStyleTextAlignValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTextAlignValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleDirectionValue_matchRefExact(const AzStyleDirectionValue* value, const AzStyleDirection** restrict out) {
+    const AzStyleDirectionValueVariant_Exact* casted = (const AzStyleDirectionValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleDirectionValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleDirectionValue_matchMutExact(AzStyleDirectionValue* restrict value, AzStyleDirection* restrict * restrict out) {
+    AzStyleDirectionValueVariant_Exact* restrict casted = (AzStyleDirectionValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleDirectionValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionPropertyValue_matchRefExact(const AzStyleTransitionPropertyValue* value, const AzStyleTransitionPropertyBoxed*** restrict out) {
+    const AzStyleTransitionPropertyValueVariant_Exact* casted = (const AzStyleTransitionPropertyValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionPropertyValue_matchMutExact(AzStyleTransitionPropertyValue* restrict value, AzStyleTransitionPropertyBoxed** restrict * restrict out) {
+    AzStyleTransitionPropertyValueVariant_Exact* restrict casted = (AzStyleTransitionPropertyValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionDurationValue_matchRefExact(const AzStyleTransitionDurationValue* value, const AzStyleTransitionDuration** restrict out) {
+    const AzStyleTransitionDurationValueVariant_Exact* casted = (const AzStyleTransitionDurationValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTransitionDurationValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionDurationValue_matchMutExact(AzStyleTransitionDurationValue* restrict value, AzStyleTransitionDuration* restrict * restrict out) {
+    AzStyleTransitionDurationValueVariant_Exact* restrict casted = (AzStyleTransitionDurationValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionDurationValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunctionValue_matchRefExact(const AzStyleTransitionTimingFunctionValue* value, const AzStyleTransitionTimingFunctionBoxed*** restrict out) {
+    const AzStyleTransitionTimingFunctionValueVariant_Exact* casted = (const AzStyleTransitionTimingFunctionValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionTimingFunctionValue_matchMutExact(AzStyleTransitionTimingFunctionValue* restrict value, AzStyleTransitionTimingFunctionBoxed** restrict * restrict out) {
+    AzStyleTransitionTimingFunctionValueVariant_Exact* restrict casted = (AzStyleTransitionTimingFunctionValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionTimingFunctionValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleFontSizeValue_matchRefExact(const AzStyleFontSizeValue* value, const AzStyleFontSize** restrict out) {
-    const AzStyleFontSizeValueVariant_Exact* casted = (const AzStyleFontSizeValueVariant_Exact*)value;
-    bool valid = casted->tag == AzStyleFontSizeValueTag_Exact;
+bool AzStyleTransitionDelayValue_matchRefExact(const AzStyleTransitionDelayValue* value, const AzStyleTransitionDelay** restrict out) {
+    const AzStyleTransitionDelayValueVariant_Exact* casted = (const AzStyleTransitionDelayValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTransitionDelayValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleFontSizeValue_matchMutExact(AzStyleFontSizeValue* restrict value, AzStyleFontSize* restrict * restrict out) {
-    AzStyleFontSizeValueVariant_Exact* restrict casted = (AzStyleFontSizeValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzStyleFontSizeValueTag_Exact;
+bool AzStyleTransitionDelayValue_matchMutExact(AzStyleTransitionDelayValue* restrict value, AzStyleTransitionDelay* restrict * restrict out) {
+    AzStyleTransitionDelayValueVariant_Exact* restrict casted = (AzStyleTransitionDelayValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionDelayValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleLetterSpacingValue_matchRefExact(const AzStyleLetterSpacingValue* value, const AzStyleLetterSpacing** restrict out) {
-    const AzStyleLetterSpacingValueVariant_Exact* casted = (const AzStyleLetterSpacingValueVariant_Exact*)value;
-    bool valid = casted->tag == AzStyleLetterSpacingValueTag_Exact;
+bool AzStyleCaretColorValue_matchRefExact(const AzStyleCaretColorValue* value, const AzStyleCaretColor** restrict out) {
+    const AzStyleCaretColorValueVariant_Exact* casted = (const AzStyleCaretColorValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleCaretColorValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleLetterSpacingValue_matchMutExact(AzStyleLetterSpacingValue* restrict value, AzStyleLetterSpacing* restrict * restrict out) {
-    AzStyleLetterSpacingValueVariant_Exact* restrict casted = (AzStyleLetterSpacingValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzStyleLetterSpacingValueTag_Exact;
+bool AzStyleCaretColorValue_matchMutExact(AzStyleCaretColorValue* restrict value, AzStyleCaretColor* restrict * restrict out) {
+    AzStyleCaretColorValueVariant_Exact* restrict casted = (AzStyleCaretColorValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleCaretColorValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleLineHeightValue_matchRefExact(const AzStyleLineHeightValue* value, const AzStyleLineHeight** restrict out) {
-    const AzStyleLineHeightValueVariant_Exact* casted = (const AzStyleLineHeightValueVariant_Exact*)value;
-    bool valid = casted->tag == AzStyleLineHeightValueTag_Exact;
+bool AzStyleSelectionColorValue_matchRefExact(const AzStyleSelectionColorValue* value, const AzStyleSelectionColor** restrict out) {
+    const AzStyleSelectionColorValueVariant_Exact* casted = (const AzStyleSelectionColorValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleSelectionColorValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleLineHeightValue_matchMutExact(AzStyleLineHeightValue* restrict value, AzStyleLineHeight* restrict * restrict out) {
-    AzStyleLineHeightValueVariant_Exact* restrict casted = (AzStyleLineHeightValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzStyleLineHeightValueTag_Exact;
+bool AzStyleSelectionColorValue_matchMutExact(AzStyleSelectionColorValue* restrict value, AzStyleSelectionColor* restrict * restrict out) {
+    AzStyleSelectionColorValueVariant_Exact* restrict casted = (AzStyleSelectionColorValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleSelectionColorValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleTabWidthValue_matchRefExact(const AzStyleTabWidthValue* value, const AzStyleTabWidth** restrict out) {
-    const AzStyleTabWidthValueVariant_Exact* casted = (const AzStyleTabWidthValueVariant_Exact*)value;
-    bool valid = casted->tag == AzStyleTabWidthValueTag_Exact;
+bool AzStyleSelectionBackgroundValue_matchRefExact(const AzStyleSelectionBackgroundValue* value, const AzStyleSelectionBackground** restrict out) {
+    const AzStyleSelectionBackgroundValueVariant_Exact* casted = (const AzStyleSelectionBackgroundValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleSelectionBackgroundValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleTabWidthValue_matchMutExact(AzStyleTabWidthValue* restrict value, AzStyleTabWidth* restrict * restrict out) {
-    AzStyleTabWidthValueVariant_Exact* restrict casted = (AzStyleTabWidthValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzStyleTabWidthValueTag_Exact;
+bool AzStyleSelectionBackgroundValue_matchMutExact(AzStyleSelectionBackgroundValue* restrict value, AzStyleSelectionBackground* restrict * restrict out) {
+    AzStyleSelectionBackgroundValueVariant_Exact* restrict casted = (AzStyleSelectionBackgroundValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleSelectionBackgroundValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleTextAlignValue_matchRefExact(const AzStyleTextAlignValue* value, const AzStyleTextAlign** restrict out) {
-    const AzStyleTextAlignValueVariant_Exact* casted = (const AzStyleTextAlignValueVariant_Exact*)value;
-    bool valid = casted->tag == AzStyleTextAlignValueTag_Exact;
+bool AzStyleVerticalAlignValue_matchRefExact(const AzStyleVerticalAlignValue* value, const AzStyleVerticalAlignBoxed*** restrict out) {
+    const AzStyleVerticalAlignValueVariant_Exact* casted = (const AzStyleVerticalAlignValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleVerticalAlignValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleTextAlignValue_matchMutExact(AzStyleTextAlignValue* restrict value, AzStyleTextAlign* restrict * restrict out) {
-    AzStyleTextAlignValueVariant_Exact* restrict casted = (AzStyleTextAlignValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzStyleTextAlignValueTag_Exact;
+bool AzStyleVerticalAlignValue_matchMutExact(AzStyleVerticalAlignValue* restrict value, AzStyleVerticalAlignBoxed** restrict * restrict out) {
+    AzStyleVerticalAlignValueVariant_Exact* restrict casted = (AzStyleVerticalAlignValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleVerticalAlignValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
@@ -17220,42 +19852,42 @@ bool AzStyleOpacityValue_matchMutExact(AzStyleOpacityValue* restrict value, AzSt
     return valid;
 }
 
-bool AzStyleTransformVecValue_matchRefExact(const AzStyleTransformVecValue* value, const AzStyleTransformVec** restrict out) {
+bool AzStyleTransformVecValue_matchRefExact(const AzStyleTransformVecValue* value, const AzStyleTransformVecBoxed*** restrict out) {
     const AzStyleTransformVecValueVariant_Exact* casted = (const AzStyleTransformVecValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleTransformVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleTransformVecValue_matchMutExact(AzStyleTransformVecValue* restrict value, AzStyleTransformVec* restrict * restrict out) {
+bool AzStyleTransformVecValue_matchMutExact(AzStyleTransformVecValue* restrict value, AzStyleTransformVecBoxed** restrict * restrict out) {
     AzStyleTransformVecValueVariant_Exact* restrict casted = (AzStyleTransformVecValueVariant_Exact* restrict)value;
     bool valid = casted->tag == AzStyleTransformVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleTransformOriginValue_matchRefExact(const AzStyleTransformOriginValue* value, const AzStyleTransformOrigin** restrict out) {
+bool AzStyleTransformOriginValue_matchRefExact(const AzStyleTransformOriginValue* value, const AzStyleTransformOriginBoxed*** restrict out) {
     const AzStyleTransformOriginValueVariant_Exact* casted = (const AzStyleTransformOriginValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleTransformOriginValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleTransformOriginValue_matchMutExact(AzStyleTransformOriginValue* restrict value, AzStyleTransformOrigin* restrict * restrict out) {
+bool AzStyleTransformOriginValue_matchMutExact(AzStyleTransformOriginValue* restrict value, AzStyleTransformOriginBoxed** restrict * restrict out) {
     AzStyleTransformOriginValueVariant_Exact* restrict casted = (AzStyleTransformOriginValueVariant_Exact* restrict)value;
     bool valid = casted->tag == AzStyleTransformOriginValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStylePerspectiveOriginValue_matchRefExact(const AzStylePerspectiveOriginValue* value, const AzStylePerspectiveOrigin** restrict out) {
+bool AzStylePerspectiveOriginValue_matchRefExact(const AzStylePerspectiveOriginValue* value, const AzStylePerspectiveOriginBoxed*** restrict out) {
     const AzStylePerspectiveOriginValueVariant_Exact* casted = (const AzStylePerspectiveOriginValueVariant_Exact*)value;
     bool valid = casted->tag == AzStylePerspectiveOriginValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStylePerspectiveOriginValue_matchMutExact(AzStylePerspectiveOriginValue* restrict value, AzStylePerspectiveOrigin* restrict * restrict out) {
+bool AzStylePerspectiveOriginValue_matchMutExact(AzStylePerspectiveOriginValue* restrict value, AzStylePerspectiveOriginBoxed** restrict * restrict out) {
     AzStylePerspectiveOriginValueVariant_Exact* restrict casted = (AzStylePerspectiveOriginValueVariant_Exact* restrict)value;
     bool valid = casted->tag == AzStylePerspectiveOriginValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -17290,14 +19922,42 @@ bool AzStyleMixBlendModeValue_matchMutExact(AzStyleMixBlendModeValue* restrict v
     return valid;
 }
 
-bool AzStyleFilterVecValue_matchRefExact(const AzStyleFilterVecValue* value, const AzStyleFilterVec** restrict out) {
+bool AzStyleIsolationValue_matchRefExact(const AzStyleIsolationValue* value, const AzStyleIsolation** restrict out) {
+    const AzStyleIsolationValueVariant_Exact* casted = (const AzStyleIsolationValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleIsolationValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleIsolationValue_matchMutExact(AzStyleIsolationValue* restrict value, AzStyleIsolation* restrict * restrict out) {
+    AzStyleIsolationValueVariant_Exact* restrict casted = (AzStyleIsolationValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleIsolationValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleImageRenderingValue_matchRefExact(const AzStyleImageRenderingValue* value, const AzStyleImageRendering** restrict out) {
+    const AzStyleImageRenderingValueVariant_Exact* casted = (const AzStyleImageRenderingValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleImageRenderingValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleImageRenderingValue_matchMutExact(AzStyleImageRenderingValue* restrict value, AzStyleImageRendering* restrict * restrict out) {
+    AzStyleImageRenderingValueVariant_Exact* restrict casted = (AzStyleImageRenderingValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleImageRenderingValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleFilterVecValue_matchRefExact(const AzStyleFilterVecValue* value, const AzStyleFilterVecBoxed*** restrict out) {
     const AzStyleFilterVecValueVariant_Exact* casted = (const AzStyleFilterVecValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleFilterVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleFilterVecValue_matchMutExact(AzStyleFilterVecValue* restrict value, AzStyleFilterVec* restrict * restrict out) {
+bool AzStyleFilterVecValue_matchMutExact(AzStyleFilterVecValue* restrict value, AzStyleFilterVecBoxed** restrict * restrict out) {
     AzStyleFilterVecValueVariant_Exact* restrict casted = (AzStyleFilterVecValueVariant_Exact* restrict)value;
     bool valid = casted->tag == AzStyleFilterVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -17360,6 +20020,34 @@ bool AzCssProperty_matchMutTextAlign(AzCssProperty* restrict value, AzStyleTextA
     return valid;
 }
 
+bool AzCssProperty_matchRefVerticalAlign(const AzCssProperty* value, const AzStyleVerticalAlignValue** restrict out) {
+    const AzCssPropertyVariant_VerticalAlign* casted = (const AzCssPropertyVariant_VerticalAlign*)value;
+    bool valid = casted->tag == AzCssPropertyTag_VerticalAlign;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutVerticalAlign(AzCssProperty* restrict value, AzStyleVerticalAlignValue* restrict * restrict out) {
+    AzCssPropertyVariant_VerticalAlign* restrict casted = (AzCssPropertyVariant_VerticalAlign* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_VerticalAlign;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefDirection(const AzCssProperty* value, const AzStyleDirectionValue** restrict out) {
+    const AzCssPropertyVariant_Direction* casted = (const AzCssPropertyVariant_Direction*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Direction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutDirection(AzCssProperty* restrict value, AzStyleDirectionValue* restrict * restrict out) {
+    AzCssPropertyVariant_Direction* restrict casted = (AzCssPropertyVariant_Direction* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Direction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssProperty_matchRefLetterSpacing(const AzCssProperty* value, const AzStyleLetterSpacingValue** restrict out) {
     const AzCssPropertyVariant_LetterSpacing* casted = (const AzCssPropertyVariant_LetterSpacing*)value;
     bool valid = casted->tag == AzCssPropertyTag_LetterSpacing;
@@ -17724,6 +20412,62 @@ bool AzCssProperty_matchMutAlignContent(AzCssProperty* restrict value, AzLayoutA
     return valid;
 }
 
+bool AzCssProperty_matchRefGridTemplateColumns(const AzCssProperty* value, const AzLayoutGridTemplateColumnsValue** restrict out) {
+    const AzCssPropertyVariant_GridTemplateColumns* casted = (const AzCssPropertyVariant_GridTemplateColumns*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateColumns;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridTemplateColumns(AzCssProperty* restrict value, AzLayoutGridTemplateColumnsValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridTemplateColumns* restrict casted = (AzCssPropertyVariant_GridTemplateColumns* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateColumns;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefGridTemplateRows(const AzCssProperty* value, const AzLayoutGridTemplateRowsValue** restrict out) {
+    const AzCssPropertyVariant_GridTemplateRows* casted = (const AzCssPropertyVariant_GridTemplateRows*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateRows;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridTemplateRows(AzCssProperty* restrict value, AzLayoutGridTemplateRowsValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridTemplateRows* restrict casted = (AzCssPropertyVariant_GridTemplateRows* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateRows;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefGridColumn(const AzCssProperty* value, const AzLayoutGridColumnValue** restrict out) {
+    const AzCssPropertyVariant_GridColumn* casted = (const AzCssPropertyVariant_GridColumn*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridColumn;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridColumn(AzCssProperty* restrict value, AzLayoutGridColumnValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridColumn* restrict casted = (AzCssPropertyVariant_GridColumn* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridColumn;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefGridRow(const AzCssProperty* value, const AzLayoutGridRowValue** restrict out) {
+    const AzCssPropertyVariant_GridRow* casted = (const AzCssPropertyVariant_GridRow*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridRow;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridRow(AzCssProperty* restrict value, AzLayoutGridRowValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridRow* restrict casted = (AzCssPropertyVariant_GridRow* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridRow;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssProperty_matchRefBackgroundContent(const AzCssProperty* value, const AzStyleBackgroundContentVecValue** restrict out) {
     const AzCssPropertyVariant_BackgroundContent* casted = (const AzCssPropertyVariant_BackgroundContent*)value;
     bool valid = casted->tag == AzCssPropertyTag_BackgroundContent;
@@ -18298,6 +21042,34 @@ bool AzCssProperty_matchMutMixBlendMode(AzCssProperty* restrict value, AzStyleMi
     return valid;
 }
 
+bool AzCssProperty_matchRefIsolation(const AzCssProperty* value, const AzStyleIsolationValue** restrict out) {
+    const AzCssPropertyVariant_Isolation* casted = (const AzCssPropertyVariant_Isolation*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Isolation;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutIsolation(AzCssProperty* restrict value, AzStyleIsolationValue* restrict * restrict out) {
+    AzCssPropertyVariant_Isolation* restrict casted = (AzCssPropertyVariant_Isolation* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Isolation;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefImageRendering(const AzCssProperty* value, const AzStyleImageRenderingValue** restrict out) {
+    const AzCssPropertyVariant_ImageRendering* casted = (const AzCssPropertyVariant_ImageRendering*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ImageRendering;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutImageRendering(AzCssProperty* restrict value, AzStyleImageRenderingValue* restrict * restrict out) {
+    AzCssPropertyVariant_ImageRendering* restrict casted = (AzCssPropertyVariant_ImageRendering* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ImageRendering;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssProperty_matchRefFilter(const AzCssProperty* value, const AzStyleFilterVecValue** restrict out) {
     const AzCssPropertyVariant_Filter* casted = (const AzCssPropertyVariant_Filter*)value;
     bool valid = casted->tag == AzCssPropertyTag_Filter;
@@ -18340,6 +21112,104 @@ bool AzCssProperty_matchMutTextShadow(AzCssProperty* restrict value, AzStyleBoxS
     return valid;
 }
 
+bool AzCssProperty_matchRefTransitionProperty(const AzCssProperty* value, const AzStyleTransitionPropertyValue** restrict out) {
+    const AzCssPropertyVariant_TransitionProperty* casted = (const AzCssPropertyVariant_TransitionProperty*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionProperty;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTransitionProperty(AzCssProperty* restrict value, AzStyleTransitionPropertyValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionProperty* restrict casted = (AzCssPropertyVariant_TransitionProperty* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionProperty;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTransitionDuration(const AzCssProperty* value, const AzStyleTransitionDurationValue** restrict out) {
+    const AzCssPropertyVariant_TransitionDuration* casted = (const AzCssPropertyVariant_TransitionDuration*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDuration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTransitionDuration(AzCssProperty* restrict value, AzStyleTransitionDurationValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionDuration* restrict casted = (AzCssPropertyVariant_TransitionDuration* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDuration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTransitionTimingFunction(const AzCssProperty* value, const AzStyleTransitionTimingFunctionValue** restrict out) {
+    const AzCssPropertyVariant_TransitionTimingFunction* casted = (const AzCssPropertyVariant_TransitionTimingFunction*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionTimingFunction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTransitionTimingFunction(AzCssProperty* restrict value, AzStyleTransitionTimingFunctionValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionTimingFunction* restrict casted = (AzCssPropertyVariant_TransitionTimingFunction* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionTimingFunction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTransitionDelay(const AzCssProperty* value, const AzStyleTransitionDelayValue** restrict out) {
+    const AzCssPropertyVariant_TransitionDelay* casted = (const AzCssPropertyVariant_TransitionDelay*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDelay;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTransitionDelay(AzCssProperty* restrict value, AzStyleTransitionDelayValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionDelay* restrict casted = (AzCssPropertyVariant_TransitionDelay* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDelay;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefCaretColor(const AzCssProperty* value, const AzStyleCaretColorValue** restrict out) {
+    const AzCssPropertyVariant_CaretColor* casted = (const AzCssPropertyVariant_CaretColor*)value;
+    bool valid = casted->tag == AzCssPropertyTag_CaretColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutCaretColor(AzCssProperty* restrict value, AzStyleCaretColorValue* restrict * restrict out) {
+    AzCssPropertyVariant_CaretColor* restrict casted = (AzCssPropertyVariant_CaretColor* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_CaretColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefSelectionColor(const AzCssProperty* value, const AzStyleSelectionColorValue** restrict out) {
+    const AzCssPropertyVariant_SelectionColor* casted = (const AzCssPropertyVariant_SelectionColor*)value;
+    bool valid = casted->tag == AzCssPropertyTag_SelectionColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutSelectionColor(AzCssProperty* restrict value, AzStyleSelectionColorValue* restrict * restrict out) {
+    AzCssPropertyVariant_SelectionColor* restrict casted = (AzCssPropertyVariant_SelectionColor* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_SelectionColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefSelectionBackground(const AzCssProperty* value, const AzStyleSelectionBackgroundValue** restrict out) {
+    const AzCssPropertyVariant_SelectionBackground* casted = (const AzCssPropertyVariant_SelectionBackground*)value;
+    bool valid = casted->tag == AzCssPropertyTag_SelectionBackground;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutSelectionBackground(AzCssProperty* restrict value, AzStyleSelectionBackgroundValue* restrict * restrict out) {
+    AzCssPropertyVariant_SelectionBackground* restrict casted = (AzCssPropertyVariant_SelectionBackground* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_SelectionBackground;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzTextInputSelection_matchRefFromTo(const AzTextInputSelection* value, const AzTextInputSelectionRange** restrict out) {
     const AzTextInputSelectionVariant_FromTo* casted = (const AzTextInputSelectionVariant_FromTo*)value;
     bool valid = casted->tag == AzTextInputSelectionTag_FromTo;
@@ -18368,28 +21238,28 @@ bool AzNodeTypeFieldValue_matchMutTextInput(AzNodeTypeFieldValue* restrict value
     return valid;
 }
 
-bool AzNodeTypeFieldValue_matchRefNumberInput(const AzNodeTypeFieldValue* value, const Azf32** restrict out) {
+bool AzNodeTypeFieldValue_matchRefNumberInput(const AzNodeTypeFieldValue* value, const float** restrict out) {
     const AzNodeTypeFieldValueVariant_NumberInput* casted = (const AzNodeTypeFieldValueVariant_NumberInput*)value;
     bool valid = casted->tag == AzNodeTypeFieldValueTag_NumberInput;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzNodeTypeFieldValue_matchMutNumberInput(AzNodeTypeFieldValue* restrict value, Azf32* restrict * restrict out) {
+bool AzNodeTypeFieldValue_matchMutNumberInput(AzNodeTypeFieldValue* restrict value, float* restrict * restrict out) {
     AzNodeTypeFieldValueVariant_NumberInput* restrict casted = (AzNodeTypeFieldValueVariant_NumberInput* restrict)value;
     bool valid = casted->tag == AzNodeTypeFieldValueTag_NumberInput;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzNodeTypeFieldValue_matchRefCheckBox(const AzNodeTypeFieldValue* value, const Azbool** restrict out) {
+bool AzNodeTypeFieldValue_matchRefCheckBox(const AzNodeTypeFieldValue* value, const bool** restrict out) {
     const AzNodeTypeFieldValueVariant_CheckBox* casted = (const AzNodeTypeFieldValueVariant_CheckBox*)value;
     bool valid = casted->tag == AzNodeTypeFieldValueTag_CheckBox;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzNodeTypeFieldValue_matchMutCheckBox(AzNodeTypeFieldValue* restrict value, Azbool* restrict * restrict out) {
+bool AzNodeTypeFieldValue_matchMutCheckBox(AzNodeTypeFieldValue* restrict value, bool* restrict * restrict out) {
     AzNodeTypeFieldValueVariant_CheckBox* restrict casted = (AzNodeTypeFieldValueVariant_CheckBox* restrict)value;
     bool valid = casted->tag == AzNodeTypeFieldValueTag_CheckBox;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -18676,56 +21546,56 @@ bool AzSvgPathElement_matchMutCubicCurve(AzSvgPathElement* restrict value, AzSvg
     return valid;
 }
 
-bool AzIndent_matchRefSpaces(const AzIndent* value, const Azu8** restrict out) {
+bool AzIndent_matchRefSpaces(const AzIndent* value, const uint8_t** restrict out) {
     const AzIndentVariant_Spaces* casted = (const AzIndentVariant_Spaces*)value;
     bool valid = casted->tag == AzIndentTag_Spaces;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzIndent_matchMutSpaces(AzIndent* restrict value, Azu8* restrict * restrict out) {
+bool AzIndent_matchMutSpaces(AzIndent* restrict value, uint8_t* restrict * restrict out) {
     AzIndentVariant_Spaces* restrict casted = (AzIndentVariant_Spaces* restrict)value;
     bool valid = casted->tag == AzIndentTag_Spaces;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzSvgFitTo_matchRefWidth(const AzSvgFitTo* value, const Azu32** restrict out) {
+bool AzSvgFitTo_matchRefWidth(const AzSvgFitTo* value, const uint32_t** restrict out) {
     const AzSvgFitToVariant_Width* casted = (const AzSvgFitToVariant_Width*)value;
     bool valid = casted->tag == AzSvgFitToTag_Width;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzSvgFitTo_matchMutWidth(AzSvgFitTo* restrict value, Azu32* restrict * restrict out) {
+bool AzSvgFitTo_matchMutWidth(AzSvgFitTo* restrict value, uint32_t* restrict * restrict out) {
     AzSvgFitToVariant_Width* restrict casted = (AzSvgFitToVariant_Width* restrict)value;
     bool valid = casted->tag == AzSvgFitToTag_Width;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzSvgFitTo_matchRefHeight(const AzSvgFitTo* value, const Azu32** restrict out) {
+bool AzSvgFitTo_matchRefHeight(const AzSvgFitTo* value, const uint32_t** restrict out) {
     const AzSvgFitToVariant_Height* casted = (const AzSvgFitToVariant_Height*)value;
     bool valid = casted->tag == AzSvgFitToTag_Height;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzSvgFitTo_matchMutHeight(AzSvgFitTo* restrict value, Azu32* restrict * restrict out) {
+bool AzSvgFitTo_matchMutHeight(AzSvgFitTo* restrict value, uint32_t* restrict * restrict out) {
     AzSvgFitToVariant_Height* restrict casted = (AzSvgFitToVariant_Height* restrict)value;
     bool valid = casted->tag == AzSvgFitToTag_Height;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzSvgFitTo_matchRefZoom(const AzSvgFitTo* value, const Azf32** restrict out) {
+bool AzSvgFitTo_matchRefZoom(const AzSvgFitTo* value, const float** restrict out) {
     const AzSvgFitToVariant_Zoom* casted = (const AzSvgFitToVariant_Zoom*)value;
     bool valid = casted->tag == AzSvgFitToTag_Zoom;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzSvgFitTo_matchMutZoom(AzSvgFitTo* restrict value, Azf32* restrict * restrict out) {
+bool AzSvgFitTo_matchMutZoom(AzSvgFitTo* restrict value, float* restrict * restrict out) {
     AzSvgFitToVariant_Zoom* restrict casted = (AzSvgFitToVariant_Zoom* restrict)value;
     bool valid = casted->tag == AzSvgFitToTag_Zoom;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -18858,182 +21728,182 @@ bool AzThreadReceiveMsg_matchMutUpdate(AzThreadReceiveMsg* restrict value, AzUpd
     return valid;
 }
 
-bool AzFmtValue_matchRefBool(const AzFmtValue* value, const Azbool** restrict out) {
+bool AzFmtValue_matchRefBool(const AzFmtValue* value, const bool** restrict out) {
     const AzFmtValueVariant_Bool* casted = (const AzFmtValueVariant_Bool*)value;
     bool valid = casted->tag == AzFmtValueTag_Bool;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutBool(AzFmtValue* restrict value, Azbool* restrict * restrict out) {
+bool AzFmtValue_matchMutBool(AzFmtValue* restrict value, bool* restrict * restrict out) {
     AzFmtValueVariant_Bool* restrict casted = (AzFmtValueVariant_Bool* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Bool;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefUchar(const AzFmtValue* value, const Azu8** restrict out) {
+bool AzFmtValue_matchRefUchar(const AzFmtValue* value, const uint8_t** restrict out) {
     const AzFmtValueVariant_Uchar* casted = (const AzFmtValueVariant_Uchar*)value;
     bool valid = casted->tag == AzFmtValueTag_Uchar;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutUchar(AzFmtValue* restrict value, Azu8* restrict * restrict out) {
+bool AzFmtValue_matchMutUchar(AzFmtValue* restrict value, uint8_t* restrict * restrict out) {
     AzFmtValueVariant_Uchar* restrict casted = (AzFmtValueVariant_Uchar* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Uchar;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefSchar(const AzFmtValue* value, const Azi8** restrict out) {
+bool AzFmtValue_matchRefSchar(const AzFmtValue* value, const int8_t** restrict out) {
     const AzFmtValueVariant_Schar* casted = (const AzFmtValueVariant_Schar*)value;
     bool valid = casted->tag == AzFmtValueTag_Schar;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutSchar(AzFmtValue* restrict value, Azi8* restrict * restrict out) {
+bool AzFmtValue_matchMutSchar(AzFmtValue* restrict value, int8_t* restrict * restrict out) {
     AzFmtValueVariant_Schar* restrict casted = (AzFmtValueVariant_Schar* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Schar;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefUshort(const AzFmtValue* value, const Azu16** restrict out) {
+bool AzFmtValue_matchRefUshort(const AzFmtValue* value, const uint16_t** restrict out) {
     const AzFmtValueVariant_Ushort* casted = (const AzFmtValueVariant_Ushort*)value;
     bool valid = casted->tag == AzFmtValueTag_Ushort;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutUshort(AzFmtValue* restrict value, Azu16* restrict * restrict out) {
+bool AzFmtValue_matchMutUshort(AzFmtValue* restrict value, uint16_t* restrict * restrict out) {
     AzFmtValueVariant_Ushort* restrict casted = (AzFmtValueVariant_Ushort* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Ushort;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefSshort(const AzFmtValue* value, const Azi16** restrict out) {
+bool AzFmtValue_matchRefSshort(const AzFmtValue* value, const int16_t** restrict out) {
     const AzFmtValueVariant_Sshort* casted = (const AzFmtValueVariant_Sshort*)value;
     bool valid = casted->tag == AzFmtValueTag_Sshort;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutSshort(AzFmtValue* restrict value, Azi16* restrict * restrict out) {
+bool AzFmtValue_matchMutSshort(AzFmtValue* restrict value, int16_t* restrict * restrict out) {
     AzFmtValueVariant_Sshort* restrict casted = (AzFmtValueVariant_Sshort* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Sshort;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefUint(const AzFmtValue* value, const Azu32** restrict out) {
+bool AzFmtValue_matchRefUint(const AzFmtValue* value, const uint32_t** restrict out) {
     const AzFmtValueVariant_Uint* casted = (const AzFmtValueVariant_Uint*)value;
     bool valid = casted->tag == AzFmtValueTag_Uint;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutUint(AzFmtValue* restrict value, Azu32* restrict * restrict out) {
+bool AzFmtValue_matchMutUint(AzFmtValue* restrict value, uint32_t* restrict * restrict out) {
     AzFmtValueVariant_Uint* restrict casted = (AzFmtValueVariant_Uint* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Uint;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefSint(const AzFmtValue* value, const Azi32** restrict out) {
+bool AzFmtValue_matchRefSint(const AzFmtValue* value, const int32_t** restrict out) {
     const AzFmtValueVariant_Sint* casted = (const AzFmtValueVariant_Sint*)value;
     bool valid = casted->tag == AzFmtValueTag_Sint;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutSint(AzFmtValue* restrict value, Azi32* restrict * restrict out) {
+bool AzFmtValue_matchMutSint(AzFmtValue* restrict value, int32_t* restrict * restrict out) {
     AzFmtValueVariant_Sint* restrict casted = (AzFmtValueVariant_Sint* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Sint;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefUlong(const AzFmtValue* value, const Azu64** restrict out) {
+bool AzFmtValue_matchRefUlong(const AzFmtValue* value, const uint64_t** restrict out) {
     const AzFmtValueVariant_Ulong* casted = (const AzFmtValueVariant_Ulong*)value;
     bool valid = casted->tag == AzFmtValueTag_Ulong;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutUlong(AzFmtValue* restrict value, Azu64* restrict * restrict out) {
+bool AzFmtValue_matchMutUlong(AzFmtValue* restrict value, uint64_t* restrict * restrict out) {
     AzFmtValueVariant_Ulong* restrict casted = (AzFmtValueVariant_Ulong* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Ulong;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefSlong(const AzFmtValue* value, const Azi64** restrict out) {
+bool AzFmtValue_matchRefSlong(const AzFmtValue* value, const int64_t** restrict out) {
     const AzFmtValueVariant_Slong* casted = (const AzFmtValueVariant_Slong*)value;
     bool valid = casted->tag == AzFmtValueTag_Slong;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutSlong(AzFmtValue* restrict value, Azi64* restrict * restrict out) {
+bool AzFmtValue_matchMutSlong(AzFmtValue* restrict value, int64_t* restrict * restrict out) {
     AzFmtValueVariant_Slong* restrict casted = (AzFmtValueVariant_Slong* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Slong;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefIsize(const AzFmtValue* value, const Azisize** restrict out) {
+bool AzFmtValue_matchRefIsize(const AzFmtValue* value, const ssize_t** restrict out) {
     const AzFmtValueVariant_Isize* casted = (const AzFmtValueVariant_Isize*)value;
     bool valid = casted->tag == AzFmtValueTag_Isize;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutIsize(AzFmtValue* restrict value, Azisize* restrict * restrict out) {
+bool AzFmtValue_matchMutIsize(AzFmtValue* restrict value, ssize_t* restrict * restrict out) {
     AzFmtValueVariant_Isize* restrict casted = (AzFmtValueVariant_Isize* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Isize;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefUsize(const AzFmtValue* value, const Azusize** restrict out) {
+bool AzFmtValue_matchRefUsize(const AzFmtValue* value, const size_t** restrict out) {
     const AzFmtValueVariant_Usize* casted = (const AzFmtValueVariant_Usize*)value;
     bool valid = casted->tag == AzFmtValueTag_Usize;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutUsize(AzFmtValue* restrict value, Azusize* restrict * restrict out) {
+bool AzFmtValue_matchMutUsize(AzFmtValue* restrict value, size_t* restrict * restrict out) {
     AzFmtValueVariant_Usize* restrict casted = (AzFmtValueVariant_Usize* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Usize;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefFloat(const AzFmtValue* value, const Azf32** restrict out) {
+bool AzFmtValue_matchRefFloat(const AzFmtValue* value, const float** restrict out) {
     const AzFmtValueVariant_Float* casted = (const AzFmtValueVariant_Float*)value;
     bool valid = casted->tag == AzFmtValueTag_Float;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutFloat(AzFmtValue* restrict value, Azf32* restrict * restrict out) {
+bool AzFmtValue_matchMutFloat(AzFmtValue* restrict value, float* restrict * restrict out) {
     AzFmtValueVariant_Float* restrict casted = (AzFmtValueVariant_Float* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Float;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchRefDouble(const AzFmtValue* value, const Azf64** restrict out) {
+bool AzFmtValue_matchRefDouble(const AzFmtValue* value, const double** restrict out) {
     const AzFmtValueVariant_Double* casted = (const AzFmtValueVariant_Double*)value;
     bool valid = casted->tag == AzFmtValueTag_Double;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzFmtValue_matchMutDouble(AzFmtValue* restrict value, Azf64* restrict * restrict out) {
+bool AzFmtValue_matchMutDouble(AzFmtValue* restrict value, double* restrict * restrict out) {
     AzFmtValueVariant_Double* restrict casted = (AzFmtValueVariant_Double* restrict)value;
     bool valid = casted->tag == AzFmtValueTag_Double;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -19418,6 +22288,20 @@ bool AzVideoModeVecDestructor_matchMutExternal(AzVideoModeVecDestructor* restric
     return valid;
 }
 
+bool AzAcceleratorCallbackVecDestructor_matchRefExternal(const AzAcceleratorCallbackVecDestructor* value, const AzAcceleratorCallbackVecDestructorType** restrict out) {
+    const AzAcceleratorCallbackVecDestructorVariant_External* casted = (const AzAcceleratorCallbackVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzAcceleratorCallbackVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzAcceleratorCallbackVecDestructor_matchMutExternal(AzAcceleratorCallbackVecDestructor* restrict value, AzAcceleratorCallbackVecDestructorType* restrict * restrict out) {
+    AzAcceleratorCallbackVecDestructorVariant_External* restrict casted = (AzAcceleratorCallbackVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzAcceleratorCallbackVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzDomVecDestructor_matchRefExternal(const AzDomVecDestructor* value, const AzDomVecDestructorType** restrict out) {
     const AzDomVecDestructorVariant_External* casted = (const AzDomVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzDomVecDestructorTag_External;
@@ -19530,6 +22414,76 @@ bool AzStyleTransformVecDestructor_matchMutExternal(AzStyleTransformVecDestructo
     return valid;
 }
 
+bool AzGridTrackVecDestructor_matchRefExternal(const AzGridTrackVecDestructor* value, const AzGridTrackVecDestructorType** restrict out) {
+    const AzGridTrackVecDestructorVariant_External* casted = (const AzGridTrackVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzGridTrackVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrackVecDestructor_matchMutExternal(AzGridTrackVecDestructor* restrict value, AzGridTrackVecDestructorType* restrict * restrict out) {
+    AzGridTrackVecDestructorVariant_External* restrict casted = (AzGridTrackVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzGridTrackVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssPropertyTypeVecDestructor_matchRefExternal(const AzCssPropertyTypeVecDestructor* value, const AzCssPropertyTypeVecDestructorType** restrict out) {
+    const AzCssPropertyTypeVecDestructorVariant_External* casted = (const AzCssPropertyTypeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssPropertyTypeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssPropertyTypeVecDestructor_matchMutExternal(AzCssPropertyTypeVecDestructor* restrict value, AzCssPropertyTypeVecDestructorType* restrict * restrict out) {
+    AzCssPropertyTypeVecDestructorVariant_External* restrict casted = (AzCssPropertyTypeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTypeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssVariableVecDestructor_matchRefExternal(const AzCssVariableVecDestructor* value, const AzCssVariableVecDestructorType** restrict out) {
+    const AzCssVariableVecDestructorVariant_External* casted = (const AzCssVariableVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssVariableVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssVariableVecDestructor_matchMutExternal(AzCssVariableVecDestructor* restrict value, AzCssVariableVecDestructorType* restrict * restrict out) {
+    AzCssVariableVecDestructorVariant_External* restrict casted = (AzCssVariableVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssVariableVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeatureVecDestructor_matchRefExternal(const AzMediaFeatureVecDestructor* value, const AzMediaFeatureVecDestructorType** restrict out) {
+    const AzMediaFeatureVecDestructorVariant_External* casted = (const AzMediaFeatureVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzMediaFeatureVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaFeatureVecDestructor_matchMutExternal(AzMediaFeatureVecDestructor* restrict value, AzMediaFeatureVecDestructorType* restrict * restrict out) {
+    AzMediaFeatureVecDestructorVariant_External* restrict casted = (AzMediaFeatureVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzMediaFeatureVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaConditionVecDestructor_matchRefExternal(const AzMediaConditionVecDestructor* value, const AzMediaConditionVecDestructorType** restrict out) {
+    const AzMediaConditionVecDestructorVariant_External* casted = (const AzMediaConditionVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzMediaConditionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzMediaConditionVecDestructor_matchMutExternal(AzMediaConditionVecDestructor* restrict value, AzMediaConditionVecDestructorType* restrict * restrict out) {
+    AzMediaConditionVecDestructorVariant_External* restrict casted = (AzMediaConditionVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzMediaConditionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssPropertyVecDestructor_matchRefExternal(const AzCssPropertyVecDestructor* value, const AzCssPropertyVecDestructorType** restrict out) {
     const AzCssPropertyVecDestructorVariant_External* casted = (const AzCssPropertyVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzCssPropertyVecDestructorTag_External;
@@ -20020,6 +22974,20 @@ bool AzOptionSvgPoint_matchMutSome(AzOptionSvgPoint* restrict value, AzSvgPoint*
     return valid;
 }
 
+bool AzOptionMediaQuery_matchRefSome(const AzOptionMediaQuery* value, const AzMediaQuery** restrict out) {
+    const AzOptionMediaQueryVariant_Some* casted = (const AzOptionMediaQueryVariant_Some*)value;
+    bool valid = casted->tag == AzOptionMediaQueryTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionMediaQuery_matchMutSome(AzOptionMediaQuery* restrict value, AzMediaQuery* restrict * restrict out) {
+    AzOptionMediaQueryVariant_Some* restrict casted = (AzOptionMediaQueryVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionMediaQueryTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionListViewOnRowClick_matchRefSome(const AzOptionListViewOnRowClick* value, const AzListViewOnRowClick** restrict out) {
     const AzOptionListViewOnRowClickVariant_Some* casted = (const AzOptionListViewOnRowClickVariant_Some*)value;
     bool valid = casted->tag == AzOptionListViewOnRowClickTag_Some;
@@ -20440,6 +23408,20 @@ bool AzOptionCssProperty_matchMutSome(AzOptionCssProperty* restrict value, AzCss
     return valid;
 }
 
+bool AzOptionNormalizedLinearColorStop_matchRefSome(const AzOptionNormalizedLinearColorStop* value, const AzNormalizedLinearColorStop** restrict out) {
+    const AzOptionNormalizedLinearColorStopVariant_Some* casted = (const AzOptionNormalizedLinearColorStopVariant_Some*)value;
+    bool valid = casted->tag == AzOptionNormalizedLinearColorStopTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionNormalizedLinearColorStop_matchMutSome(AzOptionNormalizedLinearColorStop* restrict value, AzNormalizedLinearColorStop* restrict * restrict out) {
+    AzOptionNormalizedLinearColorStopVariant_Some* restrict casted = (AzOptionNormalizedLinearColorStopVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionNormalizedLinearColorStopTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionPositionInfo_matchRefSome(const AzOptionPositionInfo* value, const AzPositionInfo** restrict out) {
     const AzOptionPositionInfoVariant_Some* casted = (const AzOptionPositionInfoVariant_Some*)value;
     bool valid = casted->tag == AzOptionPositionInfoTag_Some;
@@ -20482,42 +23464,42 @@ bool AzOptionThreadId_matchMutSome(AzOptionThreadId* restrict value, AzThreadId*
     return valid;
 }
 
-bool AzOptionI16_matchRefSome(const AzOptionI16* value, const Azi16** restrict out) {
+bool AzOptionI16_matchRefSome(const AzOptionI16* value, const int16_t** restrict out) {
     const AzOptionI16Variant_Some* casted = (const AzOptionI16Variant_Some*)value;
     bool valid = casted->tag == AzOptionI16Tag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionI16_matchMutSome(AzOptionI16* restrict value, Azi16* restrict * restrict out) {
+bool AzOptionI16_matchMutSome(AzOptionI16* restrict value, int16_t* restrict * restrict out) {
     AzOptionI16Variant_Some* restrict casted = (AzOptionI16Variant_Some* restrict)value;
     bool valid = casted->tag == AzOptionI16Tag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionU16_matchRefSome(const AzOptionU16* value, const Azu16** restrict out) {
+bool AzOptionU16_matchRefSome(const AzOptionU16* value, const uint16_t** restrict out) {
     const AzOptionU16Variant_Some* casted = (const AzOptionU16Variant_Some*)value;
     bool valid = casted->tag == AzOptionU16Tag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionU16_matchMutSome(AzOptionU16* restrict value, Azu16* restrict * restrict out) {
+bool AzOptionU16_matchMutSome(AzOptionU16* restrict value, uint16_t* restrict * restrict out) {
     AzOptionU16Variant_Some* restrict casted = (AzOptionU16Variant_Some* restrict)value;
     bool valid = casted->tag == AzOptionU16Tag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionU32_matchRefSome(const AzOptionU32* value, const Azu32** restrict out) {
+bool AzOptionU32_matchRefSome(const AzOptionU32* value, const uint32_t** restrict out) {
     const AzOptionU32Variant_Some* casted = (const AzOptionU32Variant_Some*)value;
     bool valid = casted->tag == AzOptionU32Tag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionU32_matchMutSome(AzOptionU32* restrict value, Azu32* restrict * restrict out) {
+bool AzOptionU32_matchMutSome(AzOptionU32* restrict value, uint32_t* restrict * restrict out) {
     AzOptionU32Variant_Some* restrict casted = (AzOptionU32Variant_Some* restrict)value;
     bool valid = casted->tag == AzOptionU32Tag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -20930,14 +23912,14 @@ bool AzOptionTaskBarIcon_matchMutSome(AzOptionTaskBarIcon* restrict value, AzTas
     return valid;
 }
 
-bool AzOptionHwndHandle_matchRefSome(const AzOptionHwndHandle* value, const Az*mut c_void** restrict out) {
+bool AzOptionHwndHandle_matchRefSome(const AzOptionHwndHandle* value, const void* restrict** restrict out) {
     const AzOptionHwndHandleVariant_Some* casted = (const AzOptionHwndHandleVariant_Some*)value;
     bool valid = casted->tag == AzOptionHwndHandleTag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionHwndHandle_matchMutSome(AzOptionHwndHandle* restrict value, Az*mut c_void* restrict * restrict out) {
+bool AzOptionHwndHandle_matchMutSome(AzOptionHwndHandle* restrict value, void* restrict* restrict * restrict out) {
     AzOptionHwndHandleVariant_Some* restrict casted = (AzOptionHwndHandleVariant_Some* restrict)value;
     bool valid = casted->tag == AzOptionHwndHandleTag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -20958,6 +23940,20 @@ bool AzOptionLogicalPosition_matchMutSome(AzOptionLogicalPosition* restrict valu
     return valid;
 }
 
+bool AzOptionLogicalRect_matchRefSome(const AzOptionLogicalRect* value, const AzLogicalRect** restrict out) {
+    const AzOptionLogicalRectVariant_Some* casted = (const AzOptionLogicalRectVariant_Some*)value;
+    bool valid = casted->tag == AzOptionLogicalRectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionLogicalRect_matchMutSome(AzOptionLogicalRect* restrict value, AzLogicalRect* restrict * restrict out) {
+    AzOptionLogicalRectVariant_Some* restrict casted = (AzOptionLogicalRectVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionLogicalRectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionPhysicalPositionI32_matchRefSome(const AzOptionPhysicalPositionI32* value, const AzPhysicalPositionI32** restrict out) {
     const AzOptionPhysicalPositionI32Variant_Some* casted = (const AzOptionPhysicalPositionI32Variant_Some*)value;
     bool valid = casted->tag == AzOptionPhysicalPositionI32Tag_Some;
@@ -21000,42 +23996,42 @@ bool AzOptionString_matchMutSome(AzOptionString* restrict value, AzString* restr
     return valid;
 }
 
-bool AzOptionX11Visual_matchRefSome(const AzOptionX11Visual* value, const Az*const c_void** restrict out) {
+bool AzOptionX11Visual_matchRefSome(const AzOptionX11Visual* value, const void*** restrict out) {
     const AzOptionX11VisualVariant_Some* casted = (const AzOptionX11VisualVariant_Some*)value;
     bool valid = casted->tag == AzOptionX11VisualTag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionX11Visual_matchMutSome(AzOptionX11Visual* restrict value, Az*const c_void* restrict * restrict out) {
+bool AzOptionX11Visual_matchMutSome(AzOptionX11Visual* restrict value, void** restrict * restrict out) {
     AzOptionX11VisualVariant_Some* restrict casted = (AzOptionX11VisualVariant_Some* restrict)value;
     bool valid = casted->tag == AzOptionX11VisualTag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionI32_matchRefSome(const AzOptionI32* value, const Azi32** restrict out) {
+bool AzOptionI32_matchRefSome(const AzOptionI32* value, const int32_t** restrict out) {
     const AzOptionI32Variant_Some* casted = (const AzOptionI32Variant_Some*)value;
     bool valid = casted->tag == AzOptionI32Tag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionI32_matchMutSome(AzOptionI32* restrict value, Azi32* restrict * restrict out) {
+bool AzOptionI32_matchMutSome(AzOptionI32* restrict value, int32_t* restrict * restrict out) {
     AzOptionI32Variant_Some* restrict casted = (AzOptionI32Variant_Some* restrict)value;
     bool valid = casted->tag == AzOptionI32Tag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionF32_matchRefSome(const AzOptionF32* value, const Azf32** restrict out) {
+bool AzOptionF32_matchRefSome(const AzOptionF32* value, const float** restrict out) {
     const AzOptionF32Variant_Some* casted = (const AzOptionF32Variant_Some*)value;
     bool valid = casted->tag == AzOptionF32Tag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionF32_matchMutSome(AzOptionF32* restrict value, Azf32* restrict * restrict out) {
+bool AzOptionF32_matchMutSome(AzOptionF32* restrict value, float* restrict * restrict out) {
     AzOptionF32Variant_Some* restrict casted = (AzOptionF32Variant_Some* restrict)value;
     bool valid = casted->tag == AzOptionF32Tag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -21070,14 +24066,42 @@ bool AzOptionLogicalSize_matchMutSome(AzOptionLogicalSize* restrict value, AzLog
     return valid;
 }
 
-bool AzOptionChar_matchRefSome(const AzOptionChar* value, const Azu32** restrict out) {
+bool AzOptionImeEvent_matchRefSome(const AzOptionImeEvent* value, const AzImeEvent** restrict out) {
+    const AzOptionImeEventVariant_Some* casted = (const AzOptionImeEventVariant_Some*)value;
+    bool valid = casted->tag == AzOptionImeEventTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionImeEvent_matchMutSome(AzOptionImeEvent* restrict value, AzImeEvent* restrict * restrict out) {
+    AzOptionImeEventVariant_Some* restrict casted = (AzOptionImeEventVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionImeEventTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionImeCursorRange_matchRefSome(const AzOptionImeCursorRange* value, const AzImeCursorRange** restrict out) {
+    const AzOptionImeCursorRangeVariant_Some* casted = (const AzOptionImeCursorRangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionImeCursorRangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionImeCursorRange_matchMutSome(AzOptionImeCursorRange* restrict value, AzImeCursorRange* restrict * restrict out) {
+    AzOptionImeCursorRangeVariant_Some* restrict casted = (AzOptionImeCursorRangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionImeCursorRangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionChar_matchRefSome(const AzOptionChar* value, const uint32_t** restrict out) {
     const AzOptionCharVariant_Some* casted = (const AzOptionCharVariant_Some*)value;
     bool valid = casted->tag == AzOptionCharTag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionChar_matchMutSome(AzOptionChar* restrict value, Azu32* restrict * restrict out) {
+bool AzOptionChar_matchMutSome(AzOptionChar* restrict value, uint32_t* restrict * restrict out) {
     AzOptionCharVariant_Some* restrict casted = (AzOptionCharVariant_Some* restrict)value;
     bool valid = casted->tag == AzOptionCharTag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -21196,14 +24220,14 @@ bool AzOptionInstant_matchMutSome(AzOptionInstant* restrict value, AzInstant* re
     return valid;
 }
 
-bool AzOptionUsize_matchRefSome(const AzOptionUsize* value, const Azusize** restrict out) {
+bool AzOptionUsize_matchRefSome(const AzOptionUsize* value, const size_t** restrict out) {
     const AzOptionUsizeVariant_Some* casted = (const AzOptionUsizeVariant_Some*)value;
     bool valid = casted->tag == AzOptionUsizeTag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzOptionUsize_matchMutSome(AzOptionUsize* restrict value, Azusize* restrict * restrict out) {
+bool AzOptionUsize_matchMutSome(AzOptionUsize* restrict value, size_t* restrict * restrict out) {
     AzOptionUsizeVariant_Some* restrict casted = (AzOptionUsizeVariant_Some* restrict)value;
     bool valid = casted->tag == AzOptionUsizeTag_Some;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
@@ -21864,6 +24888,7 @@ bool AzXmlStreamError_matchMutInvalidString(AzXmlStreamError* restrict value, Az
     .enable_logging_on_panic = true, \
     .enable_tab_navigation = true, \
     .system_callbacks = AzSystemCallbacks_libraryInternal(), \
+    .redraw_behavior = AzRedrawBehavior_OnEventOnly, \
 }
 
 /* Macro to generate reflection metadata for a given struct - for a "structName" of "foo", generates:
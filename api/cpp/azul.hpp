@@ -35,6 +35,8 @@ namespace dll {
     struct ThreadReceiver;
     using ThreadCallbackType = void(*)(RefAny, ThreadSender, ThreadReceiver);
     
+    using ThreadFnType = void(*)(RefAny* restrict, ThreadSender* restrict, ThreadReceiver* restrict);
+    
     using RefAnyDestructorType = void(*)(void* restrict);
     
     using RibbonOnTabClickedCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, int32_t);
@@ -204,6 +206,9 @@ namespace dll {
     struct VideoModeVec;
     using VideoModeVecDestructorType = void(*)(VideoModeVec* restrict);
     
+    struct AcceleratorCallbackVec;
+    using AcceleratorCallbackVecDestructorType = void(*)(AcceleratorCallbackVec* restrict);
+    
     struct DomVec;
     using DomVecDestructorType = void(*)(DomVec* restrict);
     
@@ -228,6 +233,21 @@ namespace dll {
     struct StyleTransformVec;
     using StyleTransformVecDestructorType = void(*)(StyleTransformVec* restrict);
     
+    struct GridTrackVec;
+    using GridTrackVecDestructorType = void(*)(GridTrackVec* restrict);
+    
+    struct CssPropertyTypeVec;
+    using CssPropertyTypeVecDestructorType = void(*)(CssPropertyTypeVec* restrict);
+    
+    struct CssVariableVec;
+    using CssVariableVecDestructorType = void(*)(CssVariableVec* restrict);
+    
+    struct MediaFeatureVec;
+    using MediaFeatureVecDestructorType = void(*)(MediaFeatureVec* restrict);
+    
+    struct MediaConditionVec;
+    using MediaConditionVecDestructorType = void(*)(MediaConditionVec* restrict);
+    
     struct CssPropertyVec;
     using CssPropertyVecDestructorType = void(*)(CssPropertyVec* restrict);
     
@@ -348,10 +368,33 @@ namespace dll {
        Trace,
     };
     
+    enum class RedrawBehaviorTag {
+       OnEventOnly,
+       MaxFps,
+       Unlimited,
+    };
+    
+    struct RedrawBehaviorVariant_OnEventOnly { RedrawBehaviorTag tag; };
+    struct RedrawBehaviorVariant_MaxFps { RedrawBehaviorTag tag; uint16_t payload; };
+    struct RedrawBehaviorVariant_Unlimited { RedrawBehaviorTag tag; };
+    union RedrawBehavior {
+        RedrawBehaviorVariant_OnEventOnly OnEventOnly;
+        RedrawBehaviorVariant_MaxFps MaxFps;
+        RedrawBehaviorVariant_Unlimited Unlimited;
+    };
+    
+    
     enum class LayoutSolver {
        Default,
     };
     
+    struct Api {
+        size_t _reserved;
+        Api& operator=(const Api&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Api(const Api&) = delete; /* disable copy constructor, use explicit .clone() */
+        Api() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class Vsync {
        Enabled,
        Disabled,
@@ -656,6 +699,21 @@ namespace dll {
        Cut,
     };
     
+    struct Modifiers {
+        bool  shift;
+        bool  ctrl;
+        bool  alt;
+        bool  super_key;
+        Modifiers& operator=(const Modifiers&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Modifiers() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct AcceleratorId {
+        size_t id;
+        AcceleratorId& operator=(const AcceleratorId&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AcceleratorId() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class WindowFrame {
        Normal,
        Minimized,
@@ -695,6 +753,13 @@ namespace dll {
         DebugState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ImeCursorRange {
+        size_t start;
+        size_t end;
+        ImeCursorRange& operator=(const ImeCursorRange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ImeCursorRange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class MouseCursorType {
        Default,
        Crosshair,
@@ -926,6 +991,9 @@ namespace dll {
        TouchMove,
        TouchEnd,
        TouchCancel,
+       ImeStateChanged,
+       ImePreedit,
+       ImeCommit,
     };
     
     enum class FocusEventFilter {
@@ -948,6 +1016,9 @@ namespace dll {
        VirtualKeyUp,
        FocusReceived,
        FocusLost,
+       ImeStateChanged,
+       ImePreedit,
+       ImeCommit,
     };
     
     enum class WindowEventFilter {
@@ -981,6 +1052,11 @@ namespace dll {
        FocusLost,
        CloseRequested,
        ThemeChanged,
+       WindowFocusReceived,
+       WindowFocusLost,
+       ImeStateChanged,
+       ImePreedit,
+       ImeCommit,
     };
     
     enum class ComponentEventFilter {
@@ -1139,11 +1215,23 @@ namespace dll {
         CssNthChildPattern() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class MediaOrientation {
+       Portrait,
+       Landscape,
+    };
+    
+    enum class MediaColorScheme {
+       Light,
+       Dark,
+    };
+    
     enum class CssPropertyType {
        TextColor,
        FontSize,
        FontFamily,
        TextAlign,
+       VerticalAlign,
+       Direction,
        LetterSpacing,
        LineHeight,
        WordSpacing,
@@ -1170,6 +1258,10 @@ namespace dll {
        JustifyContent,
        AlignItems,
        AlignContent,
+       GridTemplateColumns,
+       GridTemplateRows,
+       GridColumn,
+       GridRow,
        BackgroundContent,
        BackgroundPosition,
        BackgroundSize,
@@ -1211,9 +1303,18 @@ namespace dll {
        PerspectiveOrigin,
        BackfaceVisibility,
        MixBlendMode,
+       Isolation,
+       ImageRendering,
        Filter,
        BackdropFilter,
        TextShadow,
+       TransitionProperty,
+       TransitionDuration,
+       TransitionTimingFunction,
+       TransitionDelay,
+       CaretColor,
+       SelectionColor,
+       SelectionBackground,
     };
     
     struct ColorU {
@@ -1262,6 +1363,17 @@ namespace dll {
        Luminosity,
     };
     
+    enum class StyleIsolation {
+       Auto,
+       Isolate,
+    };
+    
+    enum class StyleImageRendering {
+       Auto,
+       CrispEdges,
+       Pixelated,
+    };
+    
     enum class LayoutAlignContent {
        Stretch,
        Center,
@@ -1295,11 +1407,30 @@ namespace dll {
        Flex,
        Block,
        InlineBlock,
+       Grid,
+       Inline,
+    };
+    
+    enum class GridLineTag {
+       Auto,
+       Line,
+       Span,
+    };
+    
+    struct GridLineVariant_Auto { GridLineTag tag; };
+    struct GridLineVariant_Line { GridLineTag tag; int16_t payload; };
+    struct GridLineVariant_Span { GridLineTag tag; uint16_t payload; };
+    union GridLine {
+        GridLineVariant_Auto Auto;
+        GridLineVariant_Line Line;
+        GridLineVariant_Span Span;
     };
     
+    
     enum class LayoutFloat {
        Left,
        Right,
+       None,
     };
     
     enum class LayoutJustifyContent {
@@ -1321,6 +1452,7 @@ namespace dll {
     enum class LayoutFlexWrap {
        Wrap,
        NoWrap,
+       WrapReverse,
     };
     
     enum class LayoutOverflow {
@@ -1328,6 +1460,7 @@ namespace dll {
        Auto,
        Hidden,
        Visible,
+       Clip,
     };
     
     enum class AngleMetric {
@@ -1371,6 +1504,8 @@ namespace dll {
        Repeat,
        RepeatX,
        RepeatY,
+       Space,
+       Round,
     };
     
     enum class BorderStyle {
@@ -1428,6 +1563,20 @@ namespace dll {
        Left,
        Center,
        Right,
+       Start,
+       End,
+    };
+    
+    enum class StyleDirection {
+       Ltr,
+       Rtl,
+    };
+    
+    enum class StepPosition {
+       JumpStart,
+       JumpEnd,
+       JumpNone,
+       JumpBoth,
     };
     
     struct Ribbon {
@@ -2278,6 +2427,13 @@ namespace dll {
         ThreadSenderDestructorFn() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextCursor {
+        size_t byte_offset;
+        size_t grapheme_offset;
+        TextCursor& operator=(const TextCursor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextCursor() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleFontFamilyVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2678,6 +2834,22 @@ namespace dll {
     };
     
     
+    enum class AcceleratorCallbackVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct AcceleratorCallbackVecDestructorVariant_DefaultRust { AcceleratorCallbackVecDestructorTag tag; };
+    struct AcceleratorCallbackVecDestructorVariant_NoDestructor { AcceleratorCallbackVecDestructorTag tag; };
+    struct AcceleratorCallbackVecDestructorVariant_External { AcceleratorCallbackVecDestructorTag tag; AcceleratorCallbackVecDestructorType payload; };
+    union AcceleratorCallbackVecDestructor {
+        AcceleratorCallbackVecDestructorVariant_DefaultRust DefaultRust;
+        AcceleratorCallbackVecDestructorVariant_NoDestructor NoDestructor;
+        AcceleratorCallbackVecDestructorVariant_External External;
+    };
+    
+    
     enum class DomVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2806,6 +2978,86 @@ namespace dll {
     };
     
     
+    enum class GridTrackVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct GridTrackVecDestructorVariant_DefaultRust { GridTrackVecDestructorTag tag; };
+    struct GridTrackVecDestructorVariant_NoDestructor { GridTrackVecDestructorTag tag; };
+    struct GridTrackVecDestructorVariant_External { GridTrackVecDestructorTag tag; GridTrackVecDestructorType payload; };
+    union GridTrackVecDestructor {
+        GridTrackVecDestructorVariant_DefaultRust DefaultRust;
+        GridTrackVecDestructorVariant_NoDestructor NoDestructor;
+        GridTrackVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssPropertyTypeVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssPropertyTypeVecDestructorVariant_DefaultRust { CssPropertyTypeVecDestructorTag tag; };
+    struct CssPropertyTypeVecDestructorVariant_NoDestructor { CssPropertyTypeVecDestructorTag tag; };
+    struct CssPropertyTypeVecDestructorVariant_External { CssPropertyTypeVecDestructorTag tag; CssPropertyTypeVecDestructorType payload; };
+    union CssPropertyTypeVecDestructor {
+        CssPropertyTypeVecDestructorVariant_DefaultRust DefaultRust;
+        CssPropertyTypeVecDestructorVariant_NoDestructor NoDestructor;
+        CssPropertyTypeVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssVariableVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssVariableVecDestructorVariant_DefaultRust { CssVariableVecDestructorTag tag; };
+    struct CssVariableVecDestructorVariant_NoDestructor { CssVariableVecDestructorTag tag; };
+    struct CssVariableVecDestructorVariant_External { CssVariableVecDestructorTag tag; CssVariableVecDestructorType payload; };
+    union CssVariableVecDestructor {
+        CssVariableVecDestructorVariant_DefaultRust DefaultRust;
+        CssVariableVecDestructorVariant_NoDestructor NoDestructor;
+        CssVariableVecDestructorVariant_External External;
+    };
+    
+    
+    enum class MediaFeatureVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct MediaFeatureVecDestructorVariant_DefaultRust { MediaFeatureVecDestructorTag tag; };
+    struct MediaFeatureVecDestructorVariant_NoDestructor { MediaFeatureVecDestructorTag tag; };
+    struct MediaFeatureVecDestructorVariant_External { MediaFeatureVecDestructorTag tag; MediaFeatureVecDestructorType payload; };
+    union MediaFeatureVecDestructor {
+        MediaFeatureVecDestructorVariant_DefaultRust DefaultRust;
+        MediaFeatureVecDestructorVariant_NoDestructor NoDestructor;
+        MediaFeatureVecDestructorVariant_External External;
+    };
+    
+    
+    enum class MediaConditionVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct MediaConditionVecDestructorVariant_DefaultRust { MediaConditionVecDestructorTag tag; };
+    struct MediaConditionVecDestructorVariant_NoDestructor { MediaConditionVecDestructorTag tag; };
+    struct MediaConditionVecDestructorVariant_External { MediaConditionVecDestructorTag tag; MediaConditionVecDestructorType payload; };
+    union MediaConditionVecDestructor {
+        MediaConditionVecDestructorVariant_DefaultRust DefaultRust;
+        MediaConditionVecDestructorVariant_NoDestructor NoDestructor;
+        MediaConditionVecDestructorVariant_External External;
+    };
+    
+    
     enum class CssPropertyVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -3557,6 +3809,13 @@ namespace dll {
     };
     
     
+    struct Accelerator {
+        Modifiers modifiers;
+        VirtualKeyCode key;
+        Accelerator& operator=(const Accelerator&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Accelerator() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct WindowFlags {
         WindowFrame frame;
         bool  is_about_to_close;
@@ -3789,6 +4048,23 @@ namespace dll {
         PixelValue() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CalcLength {
+        FloatValue px;
+        FloatValue pt;
+        FloatValue em;
+        FloatValue percent;
+        CalcLength& operator=(const CalcLength&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CalcLength() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct CalcLengthBoxed {
+        bool  run_destructor;
+        CalcLength value;
+        CalcLengthBoxed& operator=(const CalcLengthBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CalcLengthBoxed(const CalcLengthBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        CalcLengthBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct PixelValueNoPercent {
         PixelValue inner;
         PixelValueNoPercent& operator=(const PixelValueNoPercent&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -3859,6 +4135,25 @@ namespace dll {
         LayoutBottom() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct GridPlacement {
+        GridLine start;
+        GridLine end;
+        GridPlacement& operator=(const GridPlacement&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GridPlacement() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct LayoutGridColumn {
+        GridPlacement inner;
+        LayoutGridColumn& operator=(const LayoutGridColumn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutGridColumn() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct LayoutGridRow {
+        GridPlacement inner;
+        LayoutGridRow& operator=(const LayoutGridRow&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutGridRow() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LayoutFlexGrow {
         FloatValue inner;
         LayoutFlexGrow& operator=(const LayoutFlexGrow&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -4299,18 +4594,134 @@ namespace dll {
         StyleTransformSkew2D() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct StyleTransitionDuration {
+        FloatValue inner;
+        StyleTransitionDuration& operator=(const StyleTransitionDuration&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionDuration() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleTransitionDelay {
+        FloatValue inner;
+        StyleTransitionDelay& operator=(const StyleTransitionDelay&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionDelay() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleCubicBezier {
+        FloatValue x1;
+        FloatValue y1;
+        FloatValue x2;
+        FloatValue y2;
+        StyleCubicBezier& operator=(const StyleCubicBezier&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleCubicBezier() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleSteps {
+        uint32_t count;
+        StepPosition position;
+        StyleSteps& operator=(const StyleSteps&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleSteps() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class StyleTransitionTimingFunctionTag {
+       Linear,
+       Ease,
+       EaseIn,
+       EaseOut,
+       EaseInOut,
+       CubicBezier,
+       Steps,
+    };
+    
+    struct StyleTransitionTimingFunctionVariant_Linear { StyleTransitionTimingFunctionTag tag; };
+    struct StyleTransitionTimingFunctionVariant_Ease { StyleTransitionTimingFunctionTag tag; };
+    struct StyleTransitionTimingFunctionVariant_EaseIn { StyleTransitionTimingFunctionTag tag; };
+    struct StyleTransitionTimingFunctionVariant_EaseOut { StyleTransitionTimingFunctionTag tag; };
+    struct StyleTransitionTimingFunctionVariant_EaseInOut { StyleTransitionTimingFunctionTag tag; };
+    struct StyleTransitionTimingFunctionVariant_CubicBezier { StyleTransitionTimingFunctionTag tag; StyleCubicBezier payload; };
+    struct StyleTransitionTimingFunctionVariant_Steps { StyleTransitionTimingFunctionTag tag; StyleSteps payload; };
+    union StyleTransitionTimingFunction {
+        StyleTransitionTimingFunctionVariant_Linear Linear;
+        StyleTransitionTimingFunctionVariant_Ease Ease;
+        StyleTransitionTimingFunctionVariant_EaseIn EaseIn;
+        StyleTransitionTimingFunctionVariant_EaseOut EaseOut;
+        StyleTransitionTimingFunctionVariant_EaseInOut EaseInOut;
+        StyleTransitionTimingFunctionVariant_CubicBezier CubicBezier;
+        StyleTransitionTimingFunctionVariant_Steps Steps;
+    };
+    
+    
+    enum class StyleVerticalAlignTag {
+       Baseline,
+       Top,
+       Middle,
+       Bottom,
+       TextTop,
+       TextBottom,
+       Sub,
+       Super,
+       Exact,
+    };
+    
+    struct StyleVerticalAlignVariant_Baseline { StyleVerticalAlignTag tag; };
+    struct StyleVerticalAlignVariant_Top { StyleVerticalAlignTag tag; };
+    struct StyleVerticalAlignVariant_Middle { StyleVerticalAlignTag tag; };
+    struct StyleVerticalAlignVariant_Bottom { StyleVerticalAlignTag tag; };
+    struct StyleVerticalAlignVariant_TextTop { StyleVerticalAlignTag tag; };
+    struct StyleVerticalAlignVariant_TextBottom { StyleVerticalAlignTag tag; };
+    struct StyleVerticalAlignVariant_Sub { StyleVerticalAlignTag tag; };
+    struct StyleVerticalAlignVariant_Super { StyleVerticalAlignTag tag; };
+    struct StyleVerticalAlignVariant_Exact { StyleVerticalAlignTag tag; PixelValue payload; };
+    union StyleVerticalAlign {
+        StyleVerticalAlignVariant_Baseline Baseline;
+        StyleVerticalAlignVariant_Top Top;
+        StyleVerticalAlignVariant_Middle Middle;
+        StyleVerticalAlignVariant_Bottom Bottom;
+        StyleVerticalAlignVariant_TextTop TextTop;
+        StyleVerticalAlignVariant_TextBottom TextBottom;
+        StyleVerticalAlignVariant_Sub Sub;
+        StyleVerticalAlignVariant_Super Super;
+        StyleVerticalAlignVariant_Exact Exact;
+    };
+    
+    
     struct StyleTextColor {
         ColorU inner;
         StyleTextColor& operator=(const StyleTextColor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         StyleTextColor() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct StyleCaretColor {
+        ColorU inner;
+        StyleCaretColor& operator=(const StyleCaretColor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleCaretColor() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleSelectionColor {
+        ColorU inner;
+        StyleSelectionColor& operator=(const StyleSelectionColor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleSelectionColor() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleSelectionBackground {
+        ColorU inner;
+        StyleSelectionBackground& operator=(const StyleSelectionBackground&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleSelectionBackground() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyleWordSpacing {
         PixelValue inner;
         StyleWordSpacing& operator=(const StyleWordSpacing&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         StyleWordSpacing() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct StyleBoxShadowBoxed {
+        bool  run_destructor;
+        StyleBoxShadow value;
+        StyleBoxShadowBoxed& operator=(const StyleBoxShadowBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleBoxShadowBoxed(const StyleBoxShadowBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleBoxShadowBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleBoxShadowValueTag {
        Auto,
        None,
@@ -4323,7 +4734,7 @@ namespace dll {
     struct StyleBoxShadowValueVariant_None { StyleBoxShadowValueTag tag; };
     struct StyleBoxShadowValueVariant_Inherit { StyleBoxShadowValueTag tag; };
     struct StyleBoxShadowValueVariant_Initial { StyleBoxShadowValueTag tag; };
-    struct StyleBoxShadowValueVariant_Exact { StyleBoxShadowValueTag tag; StyleBoxShadow payload; };
+    struct StyleBoxShadowValueVariant_Exact { StyleBoxShadowValueTag tag; StyleBoxShadowBoxed* payload; };
     union StyleBoxShadowValue {
         StyleBoxShadowValueVariant_Auto Auto;
         StyleBoxShadowValueVariant_None None;
@@ -4355,6 +4766,50 @@ namespace dll {
     };
     
     
+    enum class LayoutGridColumnValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutGridColumnValueVariant_Auto { LayoutGridColumnValueTag tag; };
+    struct LayoutGridColumnValueVariant_None { LayoutGridColumnValueTag tag; };
+    struct LayoutGridColumnValueVariant_Inherit { LayoutGridColumnValueTag tag; };
+    struct LayoutGridColumnValueVariant_Initial { LayoutGridColumnValueTag tag; };
+    struct LayoutGridColumnValueVariant_Exact { LayoutGridColumnValueTag tag; LayoutGridColumn payload; };
+    union LayoutGridColumnValue {
+        LayoutGridColumnValueVariant_Auto Auto;
+        LayoutGridColumnValueVariant_None None;
+        LayoutGridColumnValueVariant_Inherit Inherit;
+        LayoutGridColumnValueVariant_Initial Initial;
+        LayoutGridColumnValueVariant_Exact Exact;
+    };
+    
+    
+    enum class LayoutGridRowValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutGridRowValueVariant_Auto { LayoutGridRowValueTag tag; };
+    struct LayoutGridRowValueVariant_None { LayoutGridRowValueTag tag; };
+    struct LayoutGridRowValueVariant_Inherit { LayoutGridRowValueTag tag; };
+    struct LayoutGridRowValueVariant_Initial { LayoutGridRowValueTag tag; };
+    struct LayoutGridRowValueVariant_Exact { LayoutGridRowValueTag tag; LayoutGridRow payload; };
+    union LayoutGridRowValue {
+        LayoutGridRowValueVariant_Auto Auto;
+        LayoutGridRowValueVariant_None None;
+        LayoutGridRowValueVariant_Inherit Inherit;
+        LayoutGridRowValueVariant_Initial Initial;
+        LayoutGridRowValueVariant_Exact Exact;
+    };
+    
+    
     enum class LayoutAlignItemsValueTag {
        Auto,
        None,
@@ -4383,6 +4838,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutBottomValueVariant_Auto { LayoutBottomValueTag tag; };
@@ -4390,12 +4846,14 @@ namespace dll {
     struct LayoutBottomValueVariant_Inherit { LayoutBottomValueTag tag; };
     struct LayoutBottomValueVariant_Initial { LayoutBottomValueTag tag; };
     struct LayoutBottomValueVariant_Exact { LayoutBottomValueTag tag; LayoutBottom payload; };
+    struct LayoutBottomValueVariant_Calc { LayoutBottomValueTag tag; CalcLengthBoxed* payload; };
     union LayoutBottomValue {
         LayoutBottomValueVariant_Auto Auto;
         LayoutBottomValueVariant_None None;
         LayoutBottomValueVariant_Inherit Inherit;
         LayoutBottomValueVariant_Initial Initial;
         LayoutBottomValueVariant_Exact Exact;
+        LayoutBottomValueVariant_Calc Calc;
     };
     
     
@@ -4537,6 +4995,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutHeightValueVariant_Auto { LayoutHeightValueTag tag; };
@@ -4544,12 +5003,14 @@ namespace dll {
     struct LayoutHeightValueVariant_Inherit { LayoutHeightValueTag tag; };
     struct LayoutHeightValueVariant_Initial { LayoutHeightValueTag tag; };
     struct LayoutHeightValueVariant_Exact { LayoutHeightValueTag tag; LayoutHeight payload; };
+    struct LayoutHeightValueVariant_Calc { LayoutHeightValueTag tag; CalcLengthBoxed* payload; };
     union LayoutHeightValue {
         LayoutHeightValueVariant_Auto Auto;
         LayoutHeightValueVariant_None None;
         LayoutHeightValueVariant_Inherit Inherit;
         LayoutHeightValueVariant_Initial Initial;
         LayoutHeightValueVariant_Exact Exact;
+        LayoutHeightValueVariant_Calc Calc;
     };
     
     
@@ -4581,6 +5042,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutLeftValueVariant_Auto { LayoutLeftValueTag tag; };
@@ -4588,12 +5050,14 @@ namespace dll {
     struct LayoutLeftValueVariant_Inherit { LayoutLeftValueTag tag; };
     struct LayoutLeftValueVariant_Initial { LayoutLeftValueTag tag; };
     struct LayoutLeftValueVariant_Exact { LayoutLeftValueTag tag; LayoutLeft payload; };
+    struct LayoutLeftValueVariant_Calc { LayoutLeftValueTag tag; CalcLengthBoxed* payload; };
     union LayoutLeftValue {
         LayoutLeftValueVariant_Auto Auto;
         LayoutLeftValueVariant_None None;
         LayoutLeftValueVariant_Inherit Inherit;
         LayoutLeftValueVariant_Initial Initial;
         LayoutLeftValueVariant_Exact Exact;
+        LayoutLeftValueVariant_Calc Calc;
     };
     
     
@@ -4603,6 +5067,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutMarginBottomValueVariant_Auto { LayoutMarginBottomValueTag tag; };
@@ -4610,12 +5075,14 @@ namespace dll {
     struct LayoutMarginBottomValueVariant_Inherit { LayoutMarginBottomValueTag tag; };
     struct LayoutMarginBottomValueVariant_Initial { LayoutMarginBottomValueTag tag; };
     struct LayoutMarginBottomValueVariant_Exact { LayoutMarginBottomValueTag tag; LayoutMarginBottom payload; };
+    struct LayoutMarginBottomValueVariant_Calc { LayoutMarginBottomValueTag tag; CalcLengthBoxed* payload; };
     union LayoutMarginBottomValue {
         LayoutMarginBottomValueVariant_Auto Auto;
         LayoutMarginBottomValueVariant_None None;
         LayoutMarginBottomValueVariant_Inherit Inherit;
         LayoutMarginBottomValueVariant_Initial Initial;
         LayoutMarginBottomValueVariant_Exact Exact;
+        LayoutMarginBottomValueVariant_Calc Calc;
     };
     
     
@@ -4625,6 +5092,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutMarginLeftValueVariant_Auto { LayoutMarginLeftValueTag tag; };
@@ -4632,12 +5100,14 @@ namespace dll {
     struct LayoutMarginLeftValueVariant_Inherit { LayoutMarginLeftValueTag tag; };
     struct LayoutMarginLeftValueVariant_Initial { LayoutMarginLeftValueTag tag; };
     struct LayoutMarginLeftValueVariant_Exact { LayoutMarginLeftValueTag tag; LayoutMarginLeft payload; };
+    struct LayoutMarginLeftValueVariant_Calc { LayoutMarginLeftValueTag tag; CalcLengthBoxed* payload; };
     union LayoutMarginLeftValue {
         LayoutMarginLeftValueVariant_Auto Auto;
         LayoutMarginLeftValueVariant_None None;
         LayoutMarginLeftValueVariant_Inherit Inherit;
         LayoutMarginLeftValueVariant_Initial Initial;
         LayoutMarginLeftValueVariant_Exact Exact;
+        LayoutMarginLeftValueVariant_Calc Calc;
     };
     
     
@@ -4647,6 +5117,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutMarginRightValueVariant_Auto { LayoutMarginRightValueTag tag; };
@@ -4654,12 +5125,14 @@ namespace dll {
     struct LayoutMarginRightValueVariant_Inherit { LayoutMarginRightValueTag tag; };
     struct LayoutMarginRightValueVariant_Initial { LayoutMarginRightValueTag tag; };
     struct LayoutMarginRightValueVariant_Exact { LayoutMarginRightValueTag tag; LayoutMarginRight payload; };
+    struct LayoutMarginRightValueVariant_Calc { LayoutMarginRightValueTag tag; CalcLengthBoxed* payload; };
     union LayoutMarginRightValue {
         LayoutMarginRightValueVariant_Auto Auto;
         LayoutMarginRightValueVariant_None None;
         LayoutMarginRightValueVariant_Inherit Inherit;
         LayoutMarginRightValueVariant_Initial Initial;
         LayoutMarginRightValueVariant_Exact Exact;
+        LayoutMarginRightValueVariant_Calc Calc;
     };
     
     
@@ -4669,6 +5142,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutMarginTopValueVariant_Auto { LayoutMarginTopValueTag tag; };
@@ -4676,12 +5150,14 @@ namespace dll {
     struct LayoutMarginTopValueVariant_Inherit { LayoutMarginTopValueTag tag; };
     struct LayoutMarginTopValueVariant_Initial { LayoutMarginTopValueTag tag; };
     struct LayoutMarginTopValueVariant_Exact { LayoutMarginTopValueTag tag; LayoutMarginTop payload; };
+    struct LayoutMarginTopValueVariant_Calc { LayoutMarginTopValueTag tag; CalcLengthBoxed* payload; };
     union LayoutMarginTopValue {
         LayoutMarginTopValueVariant_Auto Auto;
         LayoutMarginTopValueVariant_None None;
         LayoutMarginTopValueVariant_Inherit Inherit;
         LayoutMarginTopValueVariant_Initial Initial;
         LayoutMarginTopValueVariant_Exact Exact;
+        LayoutMarginTopValueVariant_Calc Calc;
     };
     
     
@@ -4691,6 +5167,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutMaxHeightValueVariant_Auto { LayoutMaxHeightValueTag tag; };
@@ -4698,12 +5175,14 @@ namespace dll {
     struct LayoutMaxHeightValueVariant_Inherit { LayoutMaxHeightValueTag tag; };
     struct LayoutMaxHeightValueVariant_Initial { LayoutMaxHeightValueTag tag; };
     struct LayoutMaxHeightValueVariant_Exact { LayoutMaxHeightValueTag tag; LayoutMaxHeight payload; };
+    struct LayoutMaxHeightValueVariant_Calc { LayoutMaxHeightValueTag tag; CalcLengthBoxed* payload; };
     union LayoutMaxHeightValue {
         LayoutMaxHeightValueVariant_Auto Auto;
         LayoutMaxHeightValueVariant_None None;
         LayoutMaxHeightValueVariant_Inherit Inherit;
         LayoutMaxHeightValueVariant_Initial Initial;
         LayoutMaxHeightValueVariant_Exact Exact;
+        LayoutMaxHeightValueVariant_Calc Calc;
     };
     
     
@@ -4713,6 +5192,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutMaxWidthValueVariant_Auto { LayoutMaxWidthValueTag tag; };
@@ -4720,12 +5200,14 @@ namespace dll {
     struct LayoutMaxWidthValueVariant_Inherit { LayoutMaxWidthValueTag tag; };
     struct LayoutMaxWidthValueVariant_Initial { LayoutMaxWidthValueTag tag; };
     struct LayoutMaxWidthValueVariant_Exact { LayoutMaxWidthValueTag tag; LayoutMaxWidth payload; };
+    struct LayoutMaxWidthValueVariant_Calc { LayoutMaxWidthValueTag tag; CalcLengthBoxed* payload; };
     union LayoutMaxWidthValue {
         LayoutMaxWidthValueVariant_Auto Auto;
         LayoutMaxWidthValueVariant_None None;
         LayoutMaxWidthValueVariant_Inherit Inherit;
         LayoutMaxWidthValueVariant_Initial Initial;
         LayoutMaxWidthValueVariant_Exact Exact;
+        LayoutMaxWidthValueVariant_Calc Calc;
     };
     
     
@@ -4735,6 +5217,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutMinHeightValueVariant_Auto { LayoutMinHeightValueTag tag; };
@@ -4742,12 +5225,14 @@ namespace dll {
     struct LayoutMinHeightValueVariant_Inherit { LayoutMinHeightValueTag tag; };
     struct LayoutMinHeightValueVariant_Initial { LayoutMinHeightValueTag tag; };
     struct LayoutMinHeightValueVariant_Exact { LayoutMinHeightValueTag tag; LayoutMinHeight payload; };
+    struct LayoutMinHeightValueVariant_Calc { LayoutMinHeightValueTag tag; CalcLengthBoxed* payload; };
     union LayoutMinHeightValue {
         LayoutMinHeightValueVariant_Auto Auto;
         LayoutMinHeightValueVariant_None None;
         LayoutMinHeightValueVariant_Inherit Inherit;
         LayoutMinHeightValueVariant_Initial Initial;
         LayoutMinHeightValueVariant_Exact Exact;
+        LayoutMinHeightValueVariant_Calc Calc;
     };
     
     
@@ -4757,6 +5242,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutMinWidthValueVariant_Auto { LayoutMinWidthValueTag tag; };
@@ -4764,12 +5250,14 @@ namespace dll {
     struct LayoutMinWidthValueVariant_Inherit { LayoutMinWidthValueTag tag; };
     struct LayoutMinWidthValueVariant_Initial { LayoutMinWidthValueTag tag; };
     struct LayoutMinWidthValueVariant_Exact { LayoutMinWidthValueTag tag; LayoutMinWidth payload; };
+    struct LayoutMinWidthValueVariant_Calc { LayoutMinWidthValueTag tag; CalcLengthBoxed* payload; };
     union LayoutMinWidthValue {
         LayoutMinWidthValueVariant_Auto Auto;
         LayoutMinWidthValueVariant_None None;
         LayoutMinWidthValueVariant_Inherit Inherit;
         LayoutMinWidthValueVariant_Initial Initial;
         LayoutMinWidthValueVariant_Exact Exact;
+        LayoutMinWidthValueVariant_Calc Calc;
     };
     
     
@@ -4779,6 +5267,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutPaddingBottomValueVariant_Auto { LayoutPaddingBottomValueTag tag; };
@@ -4786,12 +5275,14 @@ namespace dll {
     struct LayoutPaddingBottomValueVariant_Inherit { LayoutPaddingBottomValueTag tag; };
     struct LayoutPaddingBottomValueVariant_Initial { LayoutPaddingBottomValueTag tag; };
     struct LayoutPaddingBottomValueVariant_Exact { LayoutPaddingBottomValueTag tag; LayoutPaddingBottom payload; };
+    struct LayoutPaddingBottomValueVariant_Calc { LayoutPaddingBottomValueTag tag; CalcLengthBoxed* payload; };
     union LayoutPaddingBottomValue {
         LayoutPaddingBottomValueVariant_Auto Auto;
         LayoutPaddingBottomValueVariant_None None;
         LayoutPaddingBottomValueVariant_Inherit Inherit;
         LayoutPaddingBottomValueVariant_Initial Initial;
         LayoutPaddingBottomValueVariant_Exact Exact;
+        LayoutPaddingBottomValueVariant_Calc Calc;
     };
     
     
@@ -4801,6 +5292,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutPaddingLeftValueVariant_Auto { LayoutPaddingLeftValueTag tag; };
@@ -4808,12 +5300,14 @@ namespace dll {
     struct LayoutPaddingLeftValueVariant_Inherit { LayoutPaddingLeftValueTag tag; };
     struct LayoutPaddingLeftValueVariant_Initial { LayoutPaddingLeftValueTag tag; };
     struct LayoutPaddingLeftValueVariant_Exact { LayoutPaddingLeftValueTag tag; LayoutPaddingLeft payload; };
+    struct LayoutPaddingLeftValueVariant_Calc { LayoutPaddingLeftValueTag tag; CalcLengthBoxed* payload; };
     union LayoutPaddingLeftValue {
         LayoutPaddingLeftValueVariant_Auto Auto;
         LayoutPaddingLeftValueVariant_None None;
         LayoutPaddingLeftValueVariant_Inherit Inherit;
         LayoutPaddingLeftValueVariant_Initial Initial;
         LayoutPaddingLeftValueVariant_Exact Exact;
+        LayoutPaddingLeftValueVariant_Calc Calc;
     };
     
     
@@ -4823,6 +5317,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutPaddingRightValueVariant_Auto { LayoutPaddingRightValueTag tag; };
@@ -4830,12 +5325,14 @@ namespace dll {
     struct LayoutPaddingRightValueVariant_Inherit { LayoutPaddingRightValueTag tag; };
     struct LayoutPaddingRightValueVariant_Initial { LayoutPaddingRightValueTag tag; };
     struct LayoutPaddingRightValueVariant_Exact { LayoutPaddingRightValueTag tag; LayoutPaddingRight payload; };
+    struct LayoutPaddingRightValueVariant_Calc { LayoutPaddingRightValueTag tag; CalcLengthBoxed* payload; };
     union LayoutPaddingRightValue {
         LayoutPaddingRightValueVariant_Auto Auto;
         LayoutPaddingRightValueVariant_None None;
         LayoutPaddingRightValueVariant_Inherit Inherit;
         LayoutPaddingRightValueVariant_Initial Initial;
         LayoutPaddingRightValueVariant_Exact Exact;
+        LayoutPaddingRightValueVariant_Calc Calc;
     };
     
     
@@ -4845,6 +5342,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutPaddingTopValueVariant_Auto { LayoutPaddingTopValueTag tag; };
@@ -4852,12 +5350,14 @@ namespace dll {
     struct LayoutPaddingTopValueVariant_Inherit { LayoutPaddingTopValueTag tag; };
     struct LayoutPaddingTopValueVariant_Initial { LayoutPaddingTopValueTag tag; };
     struct LayoutPaddingTopValueVariant_Exact { LayoutPaddingTopValueTag tag; LayoutPaddingTop payload; };
+    struct LayoutPaddingTopValueVariant_Calc { LayoutPaddingTopValueTag tag; CalcLengthBoxed* payload; };
     union LayoutPaddingTopValue {
         LayoutPaddingTopValueVariant_Auto Auto;
         LayoutPaddingTopValueVariant_None None;
         LayoutPaddingTopValueVariant_Inherit Inherit;
         LayoutPaddingTopValueVariant_Initial Initial;
         LayoutPaddingTopValueVariant_Exact Exact;
+        LayoutPaddingTopValueVariant_Calc Calc;
     };
     
     
@@ -4889,6 +5389,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutRightValueVariant_Auto { LayoutRightValueTag tag; };
@@ -4896,12 +5397,14 @@ namespace dll {
     struct LayoutRightValueVariant_Inherit { LayoutRightValueTag tag; };
     struct LayoutRightValueVariant_Initial { LayoutRightValueTag tag; };
     struct LayoutRightValueVariant_Exact { LayoutRightValueTag tag; LayoutRight payload; };
+    struct LayoutRightValueVariant_Calc { LayoutRightValueTag tag; CalcLengthBoxed* payload; };
     union LayoutRightValue {
         LayoutRightValueVariant_Auto Auto;
         LayoutRightValueVariant_None None;
         LayoutRightValueVariant_Inherit Inherit;
         LayoutRightValueVariant_Initial Initial;
         LayoutRightValueVariant_Exact Exact;
+        LayoutRightValueVariant_Calc Calc;
     };
     
     
@@ -4911,6 +5414,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutTopValueVariant_Auto { LayoutTopValueTag tag; };
@@ -4918,12 +5422,14 @@ namespace dll {
     struct LayoutTopValueVariant_Inherit { LayoutTopValueTag tag; };
     struct LayoutTopValueVariant_Initial { LayoutTopValueTag tag; };
     struct LayoutTopValueVariant_Exact { LayoutTopValueTag tag; LayoutTop payload; };
+    struct LayoutTopValueVariant_Calc { LayoutTopValueTag tag; CalcLengthBoxed* payload; };
     union LayoutTopValue {
         LayoutTopValueVariant_Auto Auto;
         LayoutTopValueVariant_None None;
         LayoutTopValueVariant_Inherit Inherit;
         LayoutTopValueVariant_Initial Initial;
         LayoutTopValueVariant_Exact Exact;
+        LayoutTopValueVariant_Calc Calc;
     };
     
     
@@ -4933,6 +5439,7 @@ namespace dll {
        Inherit,
        Initial,
        Exact,
+       Calc,
     };
     
     struct LayoutWidthValueVariant_Auto { LayoutWidthValueTag tag; };
@@ -4940,12 +5447,14 @@ namespace dll {
     struct LayoutWidthValueVariant_Inherit { LayoutWidthValueTag tag; };
     struct LayoutWidthValueVariant_Initial { LayoutWidthValueTag tag; };
     struct LayoutWidthValueVariant_Exact { LayoutWidthValueTag tag; LayoutWidth payload; };
+    struct LayoutWidthValueVariant_Calc { LayoutWidthValueTag tag; CalcLengthBoxed* payload; };
     union LayoutWidthValue {
         LayoutWidthValueVariant_Auto Auto;
         LayoutWidthValueVariant_None None;
         LayoutWidthValueVariant_Inherit Inherit;
         LayoutWidthValueVariant_Initial Initial;
         LayoutWidthValueVariant_Exact Exact;
+        LayoutWidthValueVariant_Calc Calc;
     };
     
     
@@ -5477,6 +5986,198 @@ namespace dll {
     };
     
     
+    enum class StyleDirectionValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleDirectionValueVariant_Auto { StyleDirectionValueTag tag; };
+    struct StyleDirectionValueVariant_None { StyleDirectionValueTag tag; };
+    struct StyleDirectionValueVariant_Inherit { StyleDirectionValueTag tag; };
+    struct StyleDirectionValueVariant_Initial { StyleDirectionValueTag tag; };
+    struct StyleDirectionValueVariant_Exact { StyleDirectionValueTag tag; StyleDirection payload; };
+    union StyleDirectionValue {
+        StyleDirectionValueVariant_Auto Auto;
+        StyleDirectionValueVariant_None None;
+        StyleDirectionValueVariant_Inherit Inherit;
+        StyleDirectionValueVariant_Initial Initial;
+        StyleDirectionValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleTransitionDurationValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTransitionDurationValueVariant_Auto { StyleTransitionDurationValueTag tag; };
+    struct StyleTransitionDurationValueVariant_None { StyleTransitionDurationValueTag tag; };
+    struct StyleTransitionDurationValueVariant_Inherit { StyleTransitionDurationValueTag tag; };
+    struct StyleTransitionDurationValueVariant_Initial { StyleTransitionDurationValueTag tag; };
+    struct StyleTransitionDurationValueVariant_Exact { StyleTransitionDurationValueTag tag; StyleTransitionDuration payload; };
+    union StyleTransitionDurationValue {
+        StyleTransitionDurationValueVariant_Auto Auto;
+        StyleTransitionDurationValueVariant_None None;
+        StyleTransitionDurationValueVariant_Inherit Inherit;
+        StyleTransitionDurationValueVariant_Initial Initial;
+        StyleTransitionDurationValueVariant_Exact Exact;
+    };
+    
+    
+    struct StyleTransitionTimingFunctionBoxed {
+        bool  run_destructor;
+        StyleTransitionTimingFunction value;
+        StyleTransitionTimingFunctionBoxed& operator=(const StyleTransitionTimingFunctionBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionTimingFunctionBoxed(const StyleTransitionTimingFunctionBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTransitionTimingFunctionBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class StyleTransitionTimingFunctionValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTransitionTimingFunctionValueVariant_Auto { StyleTransitionTimingFunctionValueTag tag; };
+    struct StyleTransitionTimingFunctionValueVariant_None { StyleTransitionTimingFunctionValueTag tag; };
+    struct StyleTransitionTimingFunctionValueVariant_Inherit { StyleTransitionTimingFunctionValueTag tag; };
+    struct StyleTransitionTimingFunctionValueVariant_Initial { StyleTransitionTimingFunctionValueTag tag; };
+    struct StyleTransitionTimingFunctionValueVariant_Exact { StyleTransitionTimingFunctionValueTag tag; StyleTransitionTimingFunctionBoxed* payload; };
+    union StyleTransitionTimingFunctionValue {
+        StyleTransitionTimingFunctionValueVariant_Auto Auto;
+        StyleTransitionTimingFunctionValueVariant_None None;
+        StyleTransitionTimingFunctionValueVariant_Inherit Inherit;
+        StyleTransitionTimingFunctionValueVariant_Initial Initial;
+        StyleTransitionTimingFunctionValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleTransitionDelayValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTransitionDelayValueVariant_Auto { StyleTransitionDelayValueTag tag; };
+    struct StyleTransitionDelayValueVariant_None { StyleTransitionDelayValueTag tag; };
+    struct StyleTransitionDelayValueVariant_Inherit { StyleTransitionDelayValueTag tag; };
+    struct StyleTransitionDelayValueVariant_Initial { StyleTransitionDelayValueTag tag; };
+    struct StyleTransitionDelayValueVariant_Exact { StyleTransitionDelayValueTag tag; StyleTransitionDelay payload; };
+    union StyleTransitionDelayValue {
+        StyleTransitionDelayValueVariant_Auto Auto;
+        StyleTransitionDelayValueVariant_None None;
+        StyleTransitionDelayValueVariant_Inherit Inherit;
+        StyleTransitionDelayValueVariant_Initial Initial;
+        StyleTransitionDelayValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleCaretColorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleCaretColorValueVariant_Auto { StyleCaretColorValueTag tag; };
+    struct StyleCaretColorValueVariant_None { StyleCaretColorValueTag tag; };
+    struct StyleCaretColorValueVariant_Inherit { StyleCaretColorValueTag tag; };
+    struct StyleCaretColorValueVariant_Initial { StyleCaretColorValueTag tag; };
+    struct StyleCaretColorValueVariant_Exact { StyleCaretColorValueTag tag; StyleCaretColor payload; };
+    union StyleCaretColorValue {
+        StyleCaretColorValueVariant_Auto Auto;
+        StyleCaretColorValueVariant_None None;
+        StyleCaretColorValueVariant_Inherit Inherit;
+        StyleCaretColorValueVariant_Initial Initial;
+        StyleCaretColorValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleSelectionColorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleSelectionColorValueVariant_Auto { StyleSelectionColorValueTag tag; };
+    struct StyleSelectionColorValueVariant_None { StyleSelectionColorValueTag tag; };
+    struct StyleSelectionColorValueVariant_Inherit { StyleSelectionColorValueTag tag; };
+    struct StyleSelectionColorValueVariant_Initial { StyleSelectionColorValueTag tag; };
+    struct StyleSelectionColorValueVariant_Exact { StyleSelectionColorValueTag tag; StyleSelectionColor payload; };
+    union StyleSelectionColorValue {
+        StyleSelectionColorValueVariant_Auto Auto;
+        StyleSelectionColorValueVariant_None None;
+        StyleSelectionColorValueVariant_Inherit Inherit;
+        StyleSelectionColorValueVariant_Initial Initial;
+        StyleSelectionColorValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleSelectionBackgroundValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleSelectionBackgroundValueVariant_Auto { StyleSelectionBackgroundValueTag tag; };
+    struct StyleSelectionBackgroundValueVariant_None { StyleSelectionBackgroundValueTag tag; };
+    struct StyleSelectionBackgroundValueVariant_Inherit { StyleSelectionBackgroundValueTag tag; };
+    struct StyleSelectionBackgroundValueVariant_Initial { StyleSelectionBackgroundValueTag tag; };
+    struct StyleSelectionBackgroundValueVariant_Exact { StyleSelectionBackgroundValueTag tag; StyleSelectionBackground payload; };
+    union StyleSelectionBackgroundValue {
+        StyleSelectionBackgroundValueVariant_Auto Auto;
+        StyleSelectionBackgroundValueVariant_None None;
+        StyleSelectionBackgroundValueVariant_Inherit Inherit;
+        StyleSelectionBackgroundValueVariant_Initial Initial;
+        StyleSelectionBackgroundValueVariant_Exact Exact;
+    };
+    
+    
+    struct StyleVerticalAlignBoxed {
+        bool  run_destructor;
+        StyleVerticalAlign value;
+        StyleVerticalAlignBoxed& operator=(const StyleVerticalAlignBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleVerticalAlignBoxed(const StyleVerticalAlignBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleVerticalAlignBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class StyleVerticalAlignValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleVerticalAlignValueVariant_Auto { StyleVerticalAlignValueTag tag; };
+    struct StyleVerticalAlignValueVariant_None { StyleVerticalAlignValueTag tag; };
+    struct StyleVerticalAlignValueVariant_Inherit { StyleVerticalAlignValueTag tag; };
+    struct StyleVerticalAlignValueVariant_Initial { StyleVerticalAlignValueTag tag; };
+    struct StyleVerticalAlignValueVariant_Exact { StyleVerticalAlignValueTag tag; StyleVerticalAlignBoxed* payload; };
+    union StyleVerticalAlignValue {
+        StyleVerticalAlignValueVariant_Auto Auto;
+        StyleVerticalAlignValueVariant_None None;
+        StyleVerticalAlignValueVariant_Inherit Inherit;
+        StyleVerticalAlignValueVariant_Initial Initial;
+        StyleVerticalAlignValueVariant_Exact Exact;
+    };
+    
+    
     enum class StyleTextColorValueTag {
        Auto,
        None,
@@ -5543,6 +6244,14 @@ namespace dll {
     };
     
     
+    struct StyleTransformOriginBoxed {
+        bool  run_destructor;
+        StyleTransformOrigin value;
+        StyleTransformOriginBoxed& operator=(const StyleTransformOriginBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransformOriginBoxed(const StyleTransformOriginBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTransformOriginBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleTransformOriginValueTag {
        Auto,
        None,
@@ -5555,7 +6264,7 @@ namespace dll {
     struct StyleTransformOriginValueVariant_None { StyleTransformOriginValueTag tag; };
     struct StyleTransformOriginValueVariant_Inherit { StyleTransformOriginValueTag tag; };
     struct StyleTransformOriginValueVariant_Initial { StyleTransformOriginValueTag tag; };
-    struct StyleTransformOriginValueVariant_Exact { StyleTransformOriginValueTag tag; StyleTransformOrigin payload; };
+    struct StyleTransformOriginValueVariant_Exact { StyleTransformOriginValueTag tag; StyleTransformOriginBoxed* payload; };
     union StyleTransformOriginValue {
         StyleTransformOriginValueVariant_Auto Auto;
         StyleTransformOriginValueVariant_None None;
@@ -5565,6 +6274,14 @@ namespace dll {
     };
     
     
+    struct StylePerspectiveOriginBoxed {
+        bool  run_destructor;
+        StylePerspectiveOrigin value;
+        StylePerspectiveOriginBoxed& operator=(const StylePerspectiveOriginBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StylePerspectiveOriginBoxed(const StylePerspectiveOriginBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StylePerspectiveOriginBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StylePerspectiveOriginValueTag {
        Auto,
        None,
@@ -5577,7 +6294,7 @@ namespace dll {
     struct StylePerspectiveOriginValueVariant_None { StylePerspectiveOriginValueTag tag; };
     struct StylePerspectiveOriginValueVariant_Inherit { StylePerspectiveOriginValueTag tag; };
     struct StylePerspectiveOriginValueVariant_Initial { StylePerspectiveOriginValueTag tag; };
-    struct StylePerspectiveOriginValueVariant_Exact { StylePerspectiveOriginValueTag tag; StylePerspectiveOrigin payload; };
+    struct StylePerspectiveOriginValueVariant_Exact { StylePerspectiveOriginValueTag tag; StylePerspectiveOriginBoxed* payload; };
     union StylePerspectiveOriginValue {
         StylePerspectiveOriginValueVariant_Auto Auto;
         StylePerspectiveOriginValueVariant_None None;
@@ -5631,6 +6348,50 @@ namespace dll {
     };
     
     
+    enum class StyleIsolationValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleIsolationValueVariant_Auto { StyleIsolationValueTag tag; };
+    struct StyleIsolationValueVariant_None { StyleIsolationValueTag tag; };
+    struct StyleIsolationValueVariant_Inherit { StyleIsolationValueTag tag; };
+    struct StyleIsolationValueVariant_Initial { StyleIsolationValueTag tag; };
+    struct StyleIsolationValueVariant_Exact { StyleIsolationValueTag tag; StyleIsolation payload; };
+    union StyleIsolationValue {
+        StyleIsolationValueVariant_Auto Auto;
+        StyleIsolationValueVariant_None None;
+        StyleIsolationValueVariant_Inherit Inherit;
+        StyleIsolationValueVariant_Initial Initial;
+        StyleIsolationValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleImageRenderingValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleImageRenderingValueVariant_Auto { StyleImageRenderingValueTag tag; };
+    struct StyleImageRenderingValueVariant_None { StyleImageRenderingValueTag tag; };
+    struct StyleImageRenderingValueVariant_Inherit { StyleImageRenderingValueTag tag; };
+    struct StyleImageRenderingValueVariant_Initial { StyleImageRenderingValueTag tag; };
+    struct StyleImageRenderingValueVariant_Exact { StyleImageRenderingValueTag tag; StyleImageRendering payload; };
+    union StyleImageRenderingValue {
+        StyleImageRenderingValueVariant_Auto Auto;
+        StyleImageRenderingValueVariant_None None;
+        StyleImageRenderingValueVariant_Inherit Inherit;
+        StyleImageRenderingValueVariant_Initial Initial;
+        StyleImageRenderingValueVariant_Exact Exact;
+    };
+    
+    
     struct ButtonOnClick {
         RefAny data;
         Callback callback;
@@ -6013,6 +6774,14 @@ namespace dll {
     };
     
     
+    struct PendingThread {
+        RefAny data;
+        ThreadFnType thread_fn;
+        PendingThread& operator=(const PendingThread&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        PendingThread(const PendingThread&) = delete; /* disable copy constructor, use explicit .clone() */
+        PendingThread() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class ThreadSendMsgTag {
        TerminateThread,
        Tick,
@@ -6037,6 +6806,13 @@ namespace dll {
         ThreadWriteBackMsg() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextSelection {
+        TextCursor start;
+        TextCursor end;
+        TextSelection& operator=(const TextSelection&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextSelection() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LogicalRectVec {
         LogicalRect* ptr;
         size_t len;
@@ -6180,6 +6956,27 @@ namespace dll {
         StyleBackgroundSizeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    union AzGridTrack;
+    struct GridTrackVec {
+        GridTrack* ptr;
+        size_t len;
+        size_t cap;
+        GridTrackVecDestructor destructor;
+        GridTrackVec& operator=(const GridTrackVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GridTrackVec(const GridTrackVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        GridTrackVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct CssPropertyTypeVec {
+        CssPropertyType* ptr;
+        size_t len;
+        size_t cap;
+        CssPropertyTypeVecDestructor destructor;
+        CssPropertyTypeVec& operator=(const CssPropertyTypeVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssPropertyTypeVec(const CssPropertyTypeVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssPropertyTypeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct SvgVertexVec {
         SvgVertex* ptr;
         size_t len;
@@ -6701,6 +7498,19 @@ namespace dll {
     };
     
     
+    enum class OptionNormalizedLinearColorStopTag {
+       None,
+       Some,
+    };
+    
+    struct OptionNormalizedLinearColorStopVariant_None { OptionNormalizedLinearColorStopTag tag; };
+    struct OptionNormalizedLinearColorStopVariant_Some { OptionNormalizedLinearColorStopTag tag; NormalizedLinearColorStop payload; };
+    union OptionNormalizedLinearColorStop {
+        OptionNormalizedLinearColorStopVariant_None None;
+        OptionNormalizedLinearColorStopVariant_Some Some;
+    };
+    
+    
     enum class OptionPositionInfoTag {
        None,
        Some,
@@ -6987,6 +7797,19 @@ namespace dll {
     };
     
     
+    enum class OptionLogicalRectTag {
+       None,
+       Some,
+    };
+    
+    struct OptionLogicalRectVariant_None { OptionLogicalRectTag tag; };
+    struct OptionLogicalRectVariant_Some { OptionLogicalRectTag tag; LogicalRect payload; };
+    union OptionLogicalRect {
+        OptionLogicalRectVariant_None None;
+        OptionLogicalRectVariant_Some Some;
+    };
+    
+    
     enum class OptionPhysicalPositionI32Tag {
        None,
        Some,
@@ -7026,6 +7849,19 @@ namespace dll {
     };
     
     
+    enum class OptionImeCursorRangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionImeCursorRangeVariant_None { OptionImeCursorRangeTag tag; };
+    struct OptionImeCursorRangeVariant_Some { OptionImeCursorRangeTag tag; ImeCursorRange payload; };
+    union OptionImeCursorRange {
+        OptionImeCursorRangeVariant_None None;
+        OptionImeCursorRangeVariant_Some Some;
+    };
+    
+    
     enum class OptionVirtualKeyCodeTag {
        None,
        Some,
@@ -7175,6 +8011,7 @@ namespace dll {
         bool  enable_logging_on_panic;
         bool  enable_tab_navigation;
         SystemCallbacks system_callbacks;
+        RedrawBehavior redraw_behavior;
         AppConfig& operator=(const AppConfig&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         AppConfig(const AppConfig&) = delete; /* disable copy constructor, use explicit .clone() */
         AppConfig() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -7217,6 +8054,16 @@ namespace dll {
         TaskBarIcon() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct AcceleratorCallback {
+        AcceleratorId id;
+        Accelerator accelerator;
+        RefAny data;
+        Callback callback;
+        AcceleratorCallback& operator=(const AcceleratorCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AcceleratorCallback(const AcceleratorCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        AcceleratorCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct WindowSize {
         LogicalSize dimensions;
         uint32_t dpi;
@@ -7226,16 +8073,6 @@ namespace dll {
         WindowSize() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct KeyboardState {
-        OptionChar current_char;
-        OptionVirtualKeyCode current_virtual_keycode;
-        VirtualKeyCodeVec pressed_virtual_keycodes;
-        ScanCodeVec pressed_scancodes;
-        KeyboardState& operator=(const KeyboardState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        KeyboardState(const KeyboardState&) = delete; /* disable copy constructor, use explicit .clone() */
-        KeyboardState() = delete; /* disable default constructor, use C++20 designated initializer instead */
-    };
-    
     struct MouseState {
         OptionMouseCursorType mouse_cursor_type;
         CursorPosition cursor_position;
@@ -7402,6 +8239,58 @@ namespace dll {
     };
     
     
+    enum class MediaFeatureTag {
+       MinWidth,
+       MaxWidth,
+       MinHeight,
+       MaxHeight,
+       WidthLessThan,
+       WidthGreaterThan,
+       HeightLessThan,
+       HeightGreaterThan,
+       Orientation,
+       PrefersColorScheme,
+       Resolution,
+       MinResolution,
+       MaxResolution,
+       ResolutionLessThan,
+       ResolutionGreaterThan,
+    };
+    
+    struct MediaFeatureVariant_MinWidth { MediaFeatureTag tag; PixelValue payload; };
+    struct MediaFeatureVariant_MaxWidth { MediaFeatureTag tag; PixelValue payload; };
+    struct MediaFeatureVariant_MinHeight { MediaFeatureTag tag; PixelValue payload; };
+    struct MediaFeatureVariant_MaxHeight { MediaFeatureTag tag; PixelValue payload; };
+    struct MediaFeatureVariant_WidthLessThan { MediaFeatureTag tag; PixelValue payload; };
+    struct MediaFeatureVariant_WidthGreaterThan { MediaFeatureTag tag; PixelValue payload; };
+    struct MediaFeatureVariant_HeightLessThan { MediaFeatureTag tag; PixelValue payload; };
+    struct MediaFeatureVariant_HeightGreaterThan { MediaFeatureTag tag; PixelValue payload; };
+    struct MediaFeatureVariant_Orientation { MediaFeatureTag tag; MediaOrientation payload; };
+    struct MediaFeatureVariant_PrefersColorScheme { MediaFeatureTag tag; MediaColorScheme payload; };
+    struct MediaFeatureVariant_Resolution { MediaFeatureTag tag; FloatValue payload; };
+    struct MediaFeatureVariant_MinResolution { MediaFeatureTag tag; FloatValue payload; };
+    struct MediaFeatureVariant_MaxResolution { MediaFeatureTag tag; FloatValue payload; };
+    struct MediaFeatureVariant_ResolutionLessThan { MediaFeatureTag tag; FloatValue payload; };
+    struct MediaFeatureVariant_ResolutionGreaterThan { MediaFeatureTag tag; FloatValue payload; };
+    union MediaFeature {
+        MediaFeatureVariant_MinWidth MinWidth;
+        MediaFeatureVariant_MaxWidth MaxWidth;
+        MediaFeatureVariant_MinHeight MinHeight;
+        MediaFeatureVariant_MaxHeight MaxHeight;
+        MediaFeatureVariant_WidthLessThan WidthLessThan;
+        MediaFeatureVariant_WidthGreaterThan WidthGreaterThan;
+        MediaFeatureVariant_HeightLessThan HeightLessThan;
+        MediaFeatureVariant_HeightGreaterThan HeightGreaterThan;
+        MediaFeatureVariant_Orientation Orientation;
+        MediaFeatureVariant_PrefersColorScheme PrefersColorScheme;
+        MediaFeatureVariant_Resolution Resolution;
+        MediaFeatureVariant_MinResolution MinResolution;
+        MediaFeatureVariant_MaxResolution MaxResolution;
+        MediaFeatureVariant_ResolutionLessThan ResolutionLessThan;
+        MediaFeatureVariant_ResolutionGreaterThan ResolutionGreaterThan;
+    };
+    
+    
     enum class AnimationInterpolationFunctionTag {
        Ease,
        Linear,
@@ -7433,6 +8322,7 @@ namespace dll {
         float parent_rect_height;
         float current_rect_width;
         float current_rect_height;
+        float em_size;
         InterpolateContext& operator=(const InterpolateContext&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         InterpolateContext() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
@@ -7471,6 +8361,28 @@ namespace dll {
     };
     
     
+    struct GridTrackRepeat {
+        uint32_t count;
+        GridTrackVec tracks;
+        GridTrackRepeat& operator=(const GridTrackRepeat&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GridTrackRepeat(const GridTrackRepeat&) = delete; /* disable copy constructor, use explicit .clone() */
+        GridTrackRepeat() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct LayoutGridTemplateColumns {
+        GridTrackVec inner;
+        LayoutGridTemplateColumns& operator=(const LayoutGridTemplateColumns&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutGridTemplateColumns(const LayoutGridTemplateColumns&) = delete; /* disable copy constructor, use explicit .clone() */
+        LayoutGridTemplateColumns() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct LayoutGridTemplateRows {
+        GridTrackVec inner;
+        LayoutGridTemplateRows& operator=(const LayoutGridTemplateRows&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutGridTemplateRows(const LayoutGridTemplateRows&) = delete; /* disable copy constructor, use explicit .clone() */
+        LayoutGridTemplateRows() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LinearGradient {
         Direction direction;
         ExtendMode extend_mode;
@@ -7571,6 +8483,90 @@ namespace dll {
     };
     
     
+    enum class StyleTransitionPropertyTag {
+       All,
+       None,
+       List,
+    };
+    
+    struct StyleTransitionPropertyVariant_All { StyleTransitionPropertyTag tag; };
+    struct StyleTransitionPropertyVariant_None { StyleTransitionPropertyTag tag; };
+    struct StyleTransitionPropertyVariant_List { StyleTransitionPropertyTag tag; CssPropertyTypeVec payload; };
+    union StyleTransitionProperty {
+        StyleTransitionPropertyVariant_All All;
+        StyleTransitionPropertyVariant_None None;
+        StyleTransitionPropertyVariant_List List;
+    };
+    
+    
+    struct LayoutGridTemplateColumnsBoxed {
+        bool  run_destructor;
+        LayoutGridTemplateColumns value;
+        LayoutGridTemplateColumnsBoxed& operator=(const LayoutGridTemplateColumnsBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutGridTemplateColumnsBoxed(const LayoutGridTemplateColumnsBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        LayoutGridTemplateColumnsBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class LayoutGridTemplateColumnsValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutGridTemplateColumnsValueVariant_Auto { LayoutGridTemplateColumnsValueTag tag; };
+    struct LayoutGridTemplateColumnsValueVariant_None { LayoutGridTemplateColumnsValueTag tag; };
+    struct LayoutGridTemplateColumnsValueVariant_Inherit { LayoutGridTemplateColumnsValueTag tag; };
+    struct LayoutGridTemplateColumnsValueVariant_Initial { LayoutGridTemplateColumnsValueTag tag; };
+    struct LayoutGridTemplateColumnsValueVariant_Exact { LayoutGridTemplateColumnsValueTag tag; LayoutGridTemplateColumnsBoxed* payload; };
+    union LayoutGridTemplateColumnsValue {
+        LayoutGridTemplateColumnsValueVariant_Auto Auto;
+        LayoutGridTemplateColumnsValueVariant_None None;
+        LayoutGridTemplateColumnsValueVariant_Inherit Inherit;
+        LayoutGridTemplateColumnsValueVariant_Initial Initial;
+        LayoutGridTemplateColumnsValueVariant_Exact Exact;
+    };
+    
+    
+    struct LayoutGridTemplateRowsBoxed {
+        bool  run_destructor;
+        LayoutGridTemplateRows value;
+        LayoutGridTemplateRowsBoxed& operator=(const LayoutGridTemplateRowsBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutGridTemplateRowsBoxed(const LayoutGridTemplateRowsBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        LayoutGridTemplateRowsBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class LayoutGridTemplateRowsValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutGridTemplateRowsValueVariant_Auto { LayoutGridTemplateRowsValueTag tag; };
+    struct LayoutGridTemplateRowsValueVariant_None { LayoutGridTemplateRowsValueTag tag; };
+    struct LayoutGridTemplateRowsValueVariant_Inherit { LayoutGridTemplateRowsValueTag tag; };
+    struct LayoutGridTemplateRowsValueVariant_Initial { LayoutGridTemplateRowsValueTag tag; };
+    struct LayoutGridTemplateRowsValueVariant_Exact { LayoutGridTemplateRowsValueTag tag; LayoutGridTemplateRowsBoxed* payload; };
+    union LayoutGridTemplateRowsValue {
+        LayoutGridTemplateRowsValueVariant_Auto Auto;
+        LayoutGridTemplateRowsValueVariant_None None;
+        LayoutGridTemplateRowsValueVariant_Inherit Inherit;
+        LayoutGridTemplateRowsValueVariant_Initial Initial;
+        LayoutGridTemplateRowsValueVariant_Exact Exact;
+    };
+    
+    
+    struct StyleBackgroundPositionVecBoxed {
+        bool  run_destructor;
+        StyleBackgroundPositionVec value;
+        StyleBackgroundPositionVecBoxed& operator=(const StyleBackgroundPositionVecBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleBackgroundPositionVecBoxed(const StyleBackgroundPositionVecBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleBackgroundPositionVecBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleBackgroundPositionVecValueTag {
        Auto,
        None,
@@ -7583,7 +8579,7 @@ namespace dll {
     struct StyleBackgroundPositionVecValueVariant_None { StyleBackgroundPositionVecValueTag tag; };
     struct StyleBackgroundPositionVecValueVariant_Inherit { StyleBackgroundPositionVecValueTag tag; };
     struct StyleBackgroundPositionVecValueVariant_Initial { StyleBackgroundPositionVecValueTag tag; };
-    struct StyleBackgroundPositionVecValueVariant_Exact { StyleBackgroundPositionVecValueTag tag; StyleBackgroundPositionVec payload; };
+    struct StyleBackgroundPositionVecValueVariant_Exact { StyleBackgroundPositionVecValueTag tag; StyleBackgroundPositionVecBoxed* payload; };
     union StyleBackgroundPositionVecValue {
         StyleBackgroundPositionVecValueVariant_Auto Auto;
         StyleBackgroundPositionVecValueVariant_None None;
@@ -7593,6 +8589,14 @@ namespace dll {
     };
     
     
+    struct StyleBackgroundRepeatVecBoxed {
+        bool  run_destructor;
+        StyleBackgroundRepeatVec value;
+        StyleBackgroundRepeatVecBoxed& operator=(const StyleBackgroundRepeatVecBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleBackgroundRepeatVecBoxed(const StyleBackgroundRepeatVecBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleBackgroundRepeatVecBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleBackgroundRepeatVecValueTag {
        Auto,
        None,
@@ -7605,7 +8609,7 @@ namespace dll {
     struct StyleBackgroundRepeatVecValueVariant_None { StyleBackgroundRepeatVecValueTag tag; };
     struct StyleBackgroundRepeatVecValueVariant_Inherit { StyleBackgroundRepeatVecValueTag tag; };
     struct StyleBackgroundRepeatVecValueVariant_Initial { StyleBackgroundRepeatVecValueTag tag; };
-    struct StyleBackgroundRepeatVecValueVariant_Exact { StyleBackgroundRepeatVecValueTag tag; StyleBackgroundRepeatVec payload; };
+    struct StyleBackgroundRepeatVecValueVariant_Exact { StyleBackgroundRepeatVecValueTag tag; StyleBackgroundRepeatVecBoxed* payload; };
     union StyleBackgroundRepeatVecValue {
         StyleBackgroundRepeatVecValueVariant_Auto Auto;
         StyleBackgroundRepeatVecValueVariant_None None;
@@ -7615,6 +8619,14 @@ namespace dll {
     };
     
     
+    struct StyleBackgroundSizeVecBoxed {
+        bool  run_destructor;
+        StyleBackgroundSizeVec value;
+        StyleBackgroundSizeVecBoxed& operator=(const StyleBackgroundSizeVecBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleBackgroundSizeVecBoxed(const StyleBackgroundSizeVecBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleBackgroundSizeVecBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleBackgroundSizeVecValueTag {
        Auto,
        None,
@@ -7627,7 +8639,7 @@ namespace dll {
     struct StyleBackgroundSizeVecValueVariant_None { StyleBackgroundSizeVecValueTag tag; };
     struct StyleBackgroundSizeVecValueVariant_Inherit { StyleBackgroundSizeVecValueTag tag; };
     struct StyleBackgroundSizeVecValueVariant_Initial { StyleBackgroundSizeVecValueTag tag; };
-    struct StyleBackgroundSizeVecValueVariant_Exact { StyleBackgroundSizeVecValueTag tag; StyleBackgroundSizeVec payload; };
+    struct StyleBackgroundSizeVecValueVariant_Exact { StyleBackgroundSizeVecValueTag tag; StyleBackgroundSizeVecBoxed* payload; };
     union StyleBackgroundSizeVecValue {
         StyleBackgroundSizeVecValueVariant_Auto Auto;
         StyleBackgroundSizeVecValueVariant_None None;
@@ -7637,6 +8649,36 @@ namespace dll {
     };
     
     
+    struct StyleTransitionPropertyBoxed {
+        bool  run_destructor;
+        StyleTransitionProperty value;
+        StyleTransitionPropertyBoxed& operator=(const StyleTransitionPropertyBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionPropertyBoxed(const StyleTransitionPropertyBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTransitionPropertyBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class StyleTransitionPropertyValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTransitionPropertyValueVariant_Auto { StyleTransitionPropertyValueTag tag; };
+    struct StyleTransitionPropertyValueVariant_None { StyleTransitionPropertyValueTag tag; };
+    struct StyleTransitionPropertyValueVariant_Inherit { StyleTransitionPropertyValueTag tag; };
+    struct StyleTransitionPropertyValueVariant_Initial { StyleTransitionPropertyValueTag tag; };
+    struct StyleTransitionPropertyValueVariant_Exact { StyleTransitionPropertyValueTag tag; StyleTransitionPropertyBoxed* payload; };
+    union StyleTransitionPropertyValue {
+        StyleTransitionPropertyValueVariant_Auto Auto;
+        StyleTransitionPropertyValueVariant_None None;
+        StyleTransitionPropertyValueVariant_Inherit Inherit;
+        StyleTransitionPropertyValueVariant_Initial Initial;
+        StyleTransitionPropertyValueVariant_Exact Exact;
+    };
+    
+    
     struct CheckBoxStateWrapper {
         CheckBoxState inner;
         OptionCheckBoxOnToggle on_toggle;
@@ -7931,6 +8973,16 @@ namespace dll {
         TessellatedColoredSvgNodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct AcceleratorCallbackVec {
+        AcceleratorCallback* ptr;
+        size_t len;
+        size_t cap;
+        AcceleratorCallbackVecDestructor destructor;
+        AcceleratorCallbackVec& operator=(const AcceleratorCallbackVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AcceleratorCallbackVec(const AcceleratorCallbackVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        AcceleratorCallbackVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyleTransformVec {
         StyleTransform* ptr;
         size_t len;
@@ -7941,6 +8993,16 @@ namespace dll {
         StyleTransformVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct MediaFeatureVec {
+        MediaFeature* ptr;
+        size_t len;
+        size_t cap;
+        MediaFeatureVecDestructor destructor;
+        MediaFeatureVec& operator=(const MediaFeatureVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        MediaFeatureVec(const MediaFeatureVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        MediaFeatureVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct SvgPathElementVec {
         SvgPathElement* ptr;
         size_t len;
@@ -8033,19 +9095,6 @@ namespace dll {
     };
     
     
-    enum class OptionKeyboardStateTag {
-       None,
-       Some,
-    };
-    
-    struct OptionKeyboardStateVariant_None { OptionKeyboardStateTag tag; };
-    struct OptionKeyboardStateVariant_Some { OptionKeyboardStateTag tag; KeyboardState payload; };
-    union OptionKeyboardState {
-        OptionKeyboardStateVariant_None None;
-        OptionKeyboardStateVariant_Some Some;
-    };
-    
-    
     enum class OptionStringVecTag {
        None,
        Some,
@@ -8186,6 +9235,14 @@ namespace dll {
         InvalidStringError() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ImePreedit {
+        String text;
+        OptionImeCursorRange cursor_range;
+        ImePreedit& operator=(const ImePreedit&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ImePreedit(const ImePreedit&) = delete; /* disable copy constructor, use explicit .clone() */
+        ImePreedit() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct WindowsWindowOptions {
         bool  allow_drag_drop;
         bool  no_redirection_bitmap;
@@ -8399,6 +9456,38 @@ namespace dll {
     };
     
     
+    struct MediaCondition {
+        MediaFeatureVec features;
+        MediaCondition& operator=(const MediaCondition&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        MediaCondition(const MediaCondition&) = delete; /* disable copy constructor, use explicit .clone() */
+        MediaCondition() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class GridTrackTag {
+       Px,
+       Fraction,
+       MinContent,
+       MaxContent,
+       Auto,
+       Repeat,
+    };
+    
+    struct GridTrackVariant_Px { GridTrackTag tag; PixelValue payload; };
+    struct GridTrackVariant_Fraction { GridTrackTag tag; FloatValue payload; };
+    struct GridTrackVariant_MinContent { GridTrackTag tag; };
+    struct GridTrackVariant_MaxContent { GridTrackTag tag; };
+    struct GridTrackVariant_Auto { GridTrackTag tag; };
+    struct GridTrackVariant_Repeat { GridTrackTag tag; GridTrackRepeat payload; };
+    union GridTrack {
+        GridTrackVariant_Px Px;
+        GridTrackVariant_Fraction Fraction;
+        GridTrackVariant_MinContent MinContent;
+        GridTrackVariant_MaxContent MaxContent;
+        GridTrackVariant_Auto Auto;
+        GridTrackVariant_Repeat Repeat;
+    };
+    
+    
     enum class StyleBackgroundContentTag {
        LinearGradient,
        RadialGradient,
@@ -8459,6 +9548,14 @@ namespace dll {
     };
     
     
+    struct ScrollbarStyleBoxed {
+        bool  run_destructor;
+        ScrollbarStyle value;
+        ScrollbarStyleBoxed& operator=(const ScrollbarStyleBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ScrollbarStyleBoxed(const ScrollbarStyleBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        ScrollbarStyleBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class ScrollbarStyleValueTag {
        Auto,
        None,
@@ -8471,7 +9568,7 @@ namespace dll {
     struct ScrollbarStyleValueVariant_None { ScrollbarStyleValueTag tag; };
     struct ScrollbarStyleValueVariant_Inherit { ScrollbarStyleValueTag tag; };
     struct ScrollbarStyleValueVariant_Initial { ScrollbarStyleValueTag tag; };
-    struct ScrollbarStyleValueVariant_Exact { ScrollbarStyleValueTag tag; ScrollbarStyle payload; };
+    struct ScrollbarStyleValueVariant_Exact { ScrollbarStyleValueTag tag; ScrollbarStyleBoxed* payload; };
     union ScrollbarStyleValue {
         ScrollbarStyleValueVariant_Auto Auto;
         ScrollbarStyleValueVariant_None None;
@@ -8481,6 +9578,14 @@ namespace dll {
     };
     
     
+    struct StyleTransformVecBoxed {
+        bool  run_destructor;
+        StyleTransformVec value;
+        StyleTransformVecBoxed& operator=(const StyleTransformVecBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransformVecBoxed(const StyleTransformVecBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTransformVecBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleTransformVecValueTag {
        Auto,
        None,
@@ -8493,7 +9598,7 @@ namespace dll {
     struct StyleTransformVecValueVariant_None { StyleTransformVecValueTag tag; };
     struct StyleTransformVecValueVariant_Inherit { StyleTransformVecValueTag tag; };
     struct StyleTransformVecValueVariant_Initial { StyleTransformVecValueTag tag; };
-    struct StyleTransformVecValueVariant_Exact { StyleTransformVecValueTag tag; StyleTransformVec payload; };
+    struct StyleTransformVecValueVariant_Exact { StyleTransformVecValueTag tag; StyleTransformVecBoxed* payload; };
     union StyleTransformVecValue {
         StyleTransformVecValueVariant_Auto Auto;
         StyleTransformVecValueVariant_None None;
@@ -8503,6 +9608,14 @@ namespace dll {
     };
     
     
+    struct StyleFilterVecBoxed {
+        bool  run_destructor;
+        StyleFilterVec value;
+        StyleFilterVecBoxed& operator=(const StyleFilterVecBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleFilterVecBoxed(const StyleFilterVecBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleFilterVecBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleFilterVecValueTag {
        Auto,
        None,
@@ -8515,7 +9628,7 @@ namespace dll {
     struct StyleFilterVecValueVariant_None { StyleFilterVecValueTag tag; };
     struct StyleFilterVecValueVariant_Inherit { StyleFilterVecValueTag tag; };
     struct StyleFilterVecValueVariant_Initial { StyleFilterVecValueTag tag; };
-    struct StyleFilterVecValueVariant_Exact { StyleFilterVecValueTag tag; StyleFilterVec payload; };
+    struct StyleFilterVecValueVariant_Exact { StyleFilterVecValueTag tag; StyleFilterVecBoxed* payload; };
     union StyleFilterVecValue {
         StyleFilterVecValueVariant_Auto Auto;
         StyleFilterVecValueVariant_None None;
@@ -8882,6 +9995,16 @@ namespace dll {
         StyleBackgroundContentVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct MediaConditionVec {
+        MediaCondition* ptr;
+        size_t len;
+        size_t cap;
+        MediaConditionVecDestructor destructor;
+        MediaConditionVec& operator=(const MediaConditionVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        MediaConditionVec(const MediaConditionVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        MediaConditionVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct SvgPathVec {
         SvgPath* ptr;
         size_t len;
@@ -9053,6 +10176,25 @@ namespace dll {
     };
     
     
+    enum class ImeEventTag {
+       Enabled,
+       Disabled,
+       Preedit,
+       Commit,
+    };
+    
+    struct ImeEventVariant_Enabled { ImeEventTag tag; };
+    struct ImeEventVariant_Disabled { ImeEventTag tag; };
+    struct ImeEventVariant_Preedit { ImeEventTag tag; ImePreedit payload; };
+    struct ImeEventVariant_Commit { ImeEventTag tag; String payload; };
+    union ImeEvent {
+        ImeEventVariant_Enabled Enabled;
+        ImeEventVariant_Disabled Disabled;
+        ImeEventVariant_Preedit Preedit;
+        ImeEventVariant_Commit Commit;
+    };
+    
+    
     struct LinuxWindowOptions {
         OptionX11Visual x11_visual;
         OptionI32 x11_screen;
@@ -9102,6 +10244,21 @@ namespace dll {
         CssPath() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct MediaQuery {
+        MediaConditionVec conditions;
+        MediaQuery& operator=(const MediaQuery&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        MediaQuery(const MediaQuery&) = delete; /* disable copy constructor, use explicit .clone() */
+        MediaQuery() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleBackgroundContentVecBoxed {
+        bool  run_destructor;
+        StyleBackgroundContentVec value;
+        StyleBackgroundContentVecBoxed& operator=(const StyleBackgroundContentVecBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleBackgroundContentVecBoxed(const StyleBackgroundContentVecBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleBackgroundContentVecBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleBackgroundContentVecValueTag {
        Auto,
        None,
@@ -9114,7 +10271,7 @@ namespace dll {
     struct StyleBackgroundContentVecValueVariant_None { StyleBackgroundContentVecValueTag tag; };
     struct StyleBackgroundContentVecValueVariant_Inherit { StyleBackgroundContentVecValueTag tag; };
     struct StyleBackgroundContentVecValueVariant_Initial { StyleBackgroundContentVecValueTag tag; };
-    struct StyleBackgroundContentVecValueVariant_Exact { StyleBackgroundContentVecValueTag tag; StyleBackgroundContentVec payload; };
+    struct StyleBackgroundContentVecValueVariant_Exact { StyleBackgroundContentVecValueTag tag; StyleBackgroundContentVecBoxed* payload; };
     union StyleBackgroundContentVecValue {
         StyleBackgroundContentVecValueVariant_Auto Auto;
         StyleBackgroundContentVecValueVariant_None None;
@@ -9124,6 +10281,14 @@ namespace dll {
     };
     
     
+    struct StyleFontFamilyVecBoxed {
+        bool  run_destructor;
+        StyleFontFamilyVec value;
+        StyleFontFamilyVecBoxed& operator=(const StyleFontFamilyVecBoxed&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleFontFamilyVecBoxed(const StyleFontFamilyVecBoxed&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleFontFamilyVecBoxed() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleFontFamilyVecValueTag {
        Auto,
        None,
@@ -9136,7 +10301,7 @@ namespace dll {
     struct StyleFontFamilyVecValueVariant_None { StyleFontFamilyVecValueTag tag; };
     struct StyleFontFamilyVecValueVariant_Inherit { StyleFontFamilyVecValueTag tag; };
     struct StyleFontFamilyVecValueVariant_Initial { StyleFontFamilyVecValueTag tag; };
-    struct StyleFontFamilyVecValueVariant_Exact { StyleFontFamilyVecValueTag tag; StyleFontFamilyVec payload; };
+    struct StyleFontFamilyVecValueVariant_Exact { StyleFontFamilyVecValueTag tag; StyleFontFamilyVecBoxed* payload; };
     union StyleFontFamilyVecValue {
         StyleFontFamilyVecValueVariant_Auto Auto;
         StyleFontFamilyVecValueVariant_None None;
@@ -9151,6 +10316,8 @@ namespace dll {
        FontSize,
        FontFamily,
        TextAlign,
+       VerticalAlign,
+       Direction,
        LetterSpacing,
        LineHeight,
        WordSpacing,
@@ -9177,6 +10344,10 @@ namespace dll {
        JustifyContent,
        AlignItems,
        AlignContent,
+       GridTemplateColumns,
+       GridTemplateRows,
+       GridColumn,
+       GridRow,
        BackgroundContent,
        BackgroundPosition,
        BackgroundSize,
@@ -9218,15 +10389,26 @@ namespace dll {
        PerspectiveOrigin,
        BackfaceVisibility,
        MixBlendMode,
+       Isolation,
+       ImageRendering,
        Filter,
        BackdropFilter,
        TextShadow,
+       TransitionProperty,
+       TransitionDuration,
+       TransitionTimingFunction,
+       TransitionDelay,
+       CaretColor,
+       SelectionColor,
+       SelectionBackground,
     };
     
     struct CssPropertyVariant_TextColor { CssPropertyTag tag; StyleTextColorValue payload; };
     struct CssPropertyVariant_FontSize { CssPropertyTag tag; StyleFontSizeValue payload; };
     struct CssPropertyVariant_FontFamily { CssPropertyTag tag; StyleFontFamilyVecValue payload; };
     struct CssPropertyVariant_TextAlign { CssPropertyTag tag; StyleTextAlignValue payload; };
+    struct CssPropertyVariant_VerticalAlign { CssPropertyTag tag; StyleVerticalAlignValue payload; };
+    struct CssPropertyVariant_Direction { CssPropertyTag tag; StyleDirectionValue payload; };
     struct CssPropertyVariant_LetterSpacing { CssPropertyTag tag; StyleLetterSpacingValue payload; };
     struct CssPropertyVariant_LineHeight { CssPropertyTag tag; StyleLineHeightValue payload; };
     struct CssPropertyVariant_WordSpacing { CssPropertyTag tag; StyleWordSpacingValue payload; };
@@ -9253,6 +10435,10 @@ namespace dll {
     struct CssPropertyVariant_JustifyContent { CssPropertyTag tag; LayoutJustifyContentValue payload; };
     struct CssPropertyVariant_AlignItems { CssPropertyTag tag; LayoutAlignItemsValue payload; };
     struct CssPropertyVariant_AlignContent { CssPropertyTag tag; LayoutAlignContentValue payload; };
+    struct CssPropertyVariant_GridTemplateColumns { CssPropertyTag tag; LayoutGridTemplateColumnsValue payload; };
+    struct CssPropertyVariant_GridTemplateRows { CssPropertyTag tag; LayoutGridTemplateRowsValue payload; };
+    struct CssPropertyVariant_GridColumn { CssPropertyTag tag; LayoutGridColumnValue payload; };
+    struct CssPropertyVariant_GridRow { CssPropertyTag tag; LayoutGridRowValue payload; };
     struct CssPropertyVariant_BackgroundContent { CssPropertyTag tag; StyleBackgroundContentVecValue payload; };
     struct CssPropertyVariant_BackgroundPosition { CssPropertyTag tag; StyleBackgroundPositionVecValue payload; };
     struct CssPropertyVariant_BackgroundSize { CssPropertyTag tag; StyleBackgroundSizeVecValue payload; };
@@ -9294,14 +10480,25 @@ namespace dll {
     struct CssPropertyVariant_PerspectiveOrigin { CssPropertyTag tag; StylePerspectiveOriginValue payload; };
     struct CssPropertyVariant_BackfaceVisibility { CssPropertyTag tag; StyleBackfaceVisibilityValue payload; };
     struct CssPropertyVariant_MixBlendMode { CssPropertyTag tag; StyleMixBlendModeValue payload; };
+    struct CssPropertyVariant_Isolation { CssPropertyTag tag; StyleIsolationValue payload; };
+    struct CssPropertyVariant_ImageRendering { CssPropertyTag tag; StyleImageRenderingValue payload; };
     struct CssPropertyVariant_Filter { CssPropertyTag tag; StyleFilterVecValue payload; };
     struct CssPropertyVariant_BackdropFilter { CssPropertyTag tag; StyleFilterVecValue payload; };
     struct CssPropertyVariant_TextShadow { CssPropertyTag tag; StyleBoxShadowValue payload; };
+    struct CssPropertyVariant_TransitionProperty { CssPropertyTag tag; StyleTransitionPropertyValue payload; };
+    struct CssPropertyVariant_TransitionDuration { CssPropertyTag tag; StyleTransitionDurationValue payload; };
+    struct CssPropertyVariant_TransitionTimingFunction { CssPropertyTag tag; StyleTransitionTimingFunctionValue payload; };
+    struct CssPropertyVariant_TransitionDelay { CssPropertyTag tag; StyleTransitionDelayValue payload; };
+    struct CssPropertyVariant_CaretColor { CssPropertyTag tag; StyleCaretColorValue payload; };
+    struct CssPropertyVariant_SelectionColor { CssPropertyTag tag; StyleSelectionColorValue payload; };
+    struct CssPropertyVariant_SelectionBackground { CssPropertyTag tag; StyleSelectionBackgroundValue payload; };
     union CssProperty {
         CssPropertyVariant_TextColor TextColor;
         CssPropertyVariant_FontSize FontSize;
         CssPropertyVariant_FontFamily FontFamily;
         CssPropertyVariant_TextAlign TextAlign;
+        CssPropertyVariant_VerticalAlign VerticalAlign;
+        CssPropertyVariant_Direction Direction;
         CssPropertyVariant_LetterSpacing LetterSpacing;
         CssPropertyVariant_LineHeight LineHeight;
         CssPropertyVariant_WordSpacing WordSpacing;
@@ -9328,6 +10525,10 @@ namespace dll {
         CssPropertyVariant_JustifyContent JustifyContent;
         CssPropertyVariant_AlignItems AlignItems;
         CssPropertyVariant_AlignContent AlignContent;
+        CssPropertyVariant_GridTemplateColumns GridTemplateColumns;
+        CssPropertyVariant_GridTemplateRows GridTemplateRows;
+        CssPropertyVariant_GridColumn GridColumn;
+        CssPropertyVariant_GridRow GridRow;
         CssPropertyVariant_BackgroundContent BackgroundContent;
         CssPropertyVariant_BackgroundPosition BackgroundPosition;
         CssPropertyVariant_BackgroundSize BackgroundSize;
@@ -9369,9 +10570,18 @@ namespace dll {
         CssPropertyVariant_PerspectiveOrigin PerspectiveOrigin;
         CssPropertyVariant_BackfaceVisibility BackfaceVisibility;
         CssPropertyVariant_MixBlendMode MixBlendMode;
+        CssPropertyVariant_Isolation Isolation;
+        CssPropertyVariant_ImageRendering ImageRendering;
         CssPropertyVariant_Filter Filter;
         CssPropertyVariant_BackdropFilter BackdropFilter;
         CssPropertyVariant_TextShadow TextShadow;
+        CssPropertyVariant_TransitionProperty TransitionProperty;
+        CssPropertyVariant_TransitionDuration TransitionDuration;
+        CssPropertyVariant_TransitionTimingFunction TransitionTimingFunction;
+        CssPropertyVariant_TransitionDelay TransitionDelay;
+        CssPropertyVariant_CaretColor CaretColor;
+        CssPropertyVariant_SelectionColor SelectionColor;
+        CssPropertyVariant_SelectionBackground SelectionBackground;
     };
     
     
@@ -9601,6 +10811,19 @@ namespace dll {
         SvgSimpleNodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class OptionMediaQueryTag {
+       None,
+       Some,
+    };
+    
+    struct OptionMediaQueryVariant_None { OptionMediaQueryTag tag; };
+    struct OptionMediaQueryVariant_Some { OptionMediaQueryTag tag; MediaQuery payload; };
+    union OptionMediaQuery {
+        OptionMediaQueryVariant_None None;
+        OptionMediaQueryVariant_Some Some;
+    };
+    
+    
     enum class OptionCssPropertyTag {
        None,
        Some,
@@ -9614,6 +10837,19 @@ namespace dll {
     };
     
     
+    enum class OptionImeEventTag {
+       None,
+       Some,
+    };
+    
+    struct OptionImeEventVariant_None { OptionImeEventTag tag; };
+    struct OptionImeEventVariant_Some { OptionImeEventTag tag; ImeEvent payload; };
+    union OptionImeEvent {
+        OptionImeEventVariant_None None;
+        OptionImeEventVariant_Some Some;
+    };
+    
+    
     struct XmlTextError {
         XmlStreamError stream_error;
         SvgParseErrorPosition pos;
@@ -9622,6 +10858,17 @@ namespace dll {
         XmlTextError() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct KeyboardState {
+        OptionChar current_char;
+        OptionVirtualKeyCode current_virtual_keycode;
+        VirtualKeyCodeVec pressed_virtual_keycodes;
+        ScanCodeVec pressed_scancodes;
+        OptionImeEvent current_ime_event;
+        KeyboardState& operator=(const KeyboardState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        KeyboardState(const KeyboardState&) = delete; /* disable copy constructor, use explicit .clone() */
+        KeyboardState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct PlatformSpecificOptions {
         WindowsWindowOptions windows_options;
         LinuxWindowOptions linux_options;
@@ -9649,6 +10896,7 @@ namespace dll {
         ColorU background_color;
         LayoutCallback layout_callback;
         OptionCallback close_callback;
+        AcceleratorCallbackVec accelerators;
         WindowState& operator=(const WindowState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         WindowState(const WindowState&) = delete; /* disable copy constructor, use explicit .clone() */
         WindowState() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -9668,6 +10916,7 @@ namespace dll {
         void* restrict threads;
         void* restrict timers_removed;
         void* restrict threads_removed;
+        void* restrict thread_msgs_sent;
         RawWindowHandle* current_window_handle;
         void* restrict new_windows;
         SystemCallbacks* system_callbacks;
@@ -9677,6 +10926,8 @@ namespace dll {
         void* restrict images_changed_in_callbacks;
         void* restrict image_masks_changed_in_callbacks;
         void* restrict css_properties_changed_in_callbacks;
+        void* restrict stylesheet_changed_in_callbacks;
+        void* restrict context_menu_changed_in_callbacks;
         void* current_scroll_states;
         void* restrict nodes_scrolled_in_callback;
         DomNodeId hit_dom_node;
@@ -9761,6 +11012,14 @@ namespace dll {
         DynamicCssProperty() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssVariable {
+        String name;
+        CssProperty value;
+        CssVariable& operator=(const CssVariable&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssVariable(const CssVariable&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssVariable() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Node {
         NodeTypeId node_type;
         NodePosition position;
@@ -9815,6 +11074,16 @@ namespace dll {
         NodeDataInlineCssPropertyVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssVariableVec {
+        CssVariable* ptr;
+        size_t len;
+        size_t cap;
+        CssVariableVecDestructor destructor;
+        CssVariableVec& operator=(const CssVariableVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssVariableVec(const CssVariableVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssVariableVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class OptionWindowStateTag {
        None,
        Some,
@@ -9828,6 +11097,19 @@ namespace dll {
     };
     
     
+    enum class OptionKeyboardStateTag {
+       None,
+       Some,
+    };
+    
+    struct OptionKeyboardStateVariant_None { OptionKeyboardStateTag tag; };
+    struct OptionKeyboardStateVariant_Some { OptionKeyboardStateTag tag; KeyboardState payload; };
+    union OptionKeyboardState {
+        OptionKeyboardStateVariant_None None;
+        OptionKeyboardStateVariant_Some Some;
+    };
+    
+    
     enum class OptionInlineTextTag {
        None,
        Some,
@@ -10119,6 +11401,7 @@ namespace dll {
     struct CssRuleBlock {
         CssPath path;
         CssDeclarationVec declarations;
+        OptionMediaQuery media;
         CssRuleBlock& operator=(const CssRuleBlock&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         CssRuleBlock(const CssRuleBlock&) = delete; /* disable copy constructor, use explicit .clone() */
         CssRuleBlock() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -10290,6 +11573,7 @@ namespace dll {
     
     struct Css {
         StylesheetVec stylesheets;
+        CssVariableVec variables;
         Css& operator=(const Css&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         Css(const Css&) = delete; /* disable copy constructor, use explicit .clone() */
         Css() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -10297,17 +11581,55 @@ namespace dll {
 
     extern "C" {        
         
+        /* HANDLES to rust-allocated objects, only accessible via the functions */
+        typedef struct TestHarness TestHarness;
+        typedef TestHarness* TestHarnessPtr;
+        
         /* FUNCTIONS from azul.dll / libazul.so */
         App App_new(AzRefAny  data, AzAppConfig  config);
         void App_addWindow(App* restrict app, AzWindowCreateOptions  window);
         void App_addImage(App* restrict app, AzString  id, AzImageRef  image);
+        bool  App_addImageRaw(App* restrict app, AzString  id, const uint8_t* data, size_t len, uint32_t width, uint32_t height, AzRawImageFormat  format);
+        bool  App_updateImageRaw(App* restrict app, AzString  id, const uint8_t* data, size_t len, uint32_t width, uint32_t height, AzRawImageFormat  format);
+        void App_deleteImage(App* restrict app, AzString  id);
         MonitorVec App_getMonitors(const App* app);
+        OptionWindowTheme App_getSystemTheme(const App* app);
         void App_run(const App* app, AzWindowCreateOptions  window);
+        int32_t App_runReturnCode(const App* app, AzWindowCreateOptions  window);
+        TestHarnessPtr App_createTestHarness(const App* app, AzWindowCreateOptions  window);
         void App_delete(App* restrict instance);
         App App_deepCopy(App* const instance);
         AppConfig AppConfig_new(AzLayoutSolver  layout_solver);
+        void AppConfig_setRedrawBehavior(AppConfig* restrict appconfig, AzRedrawBehavior  behavior);
         SystemCallbacks SystemCallbacks_libraryInternal();
+        uint32_t Api_versionMajor();
+        uint32_t Api_versionMinor();
+        uint32_t Api_versionPatch();
+        const uint8_t* Api_versionString();
+        uint64_t Api_abiHash();
+        bool  Api_hasFeature(const uint8_t* name_ptr, size_t name_len);
+        OptionString Api_lastError();
+        bool  Api_clearLastError();
+        OptionString TestHarness_diffSnapshots(AzString  expected, AzString  actual);
+        void TestHarness_resize(TestHarnessPtr testharness, float width, float height);
+        void TestHarness_mouseMove(TestHarnessPtr testharness, float x, float y);
+        void TestHarness_mouseDown(TestHarnessPtr testharness, AzContextMenuMouseButton  button);
+        void TestHarness_mouseUp(TestHarnessPtr testharness, AzContextMenuMouseButton  button);
+        void TestHarness_keyChar(TestHarnessPtr testharness, uint32_t c);
+        void TestHarness_keyDown(TestHarnessPtr testharness, AzVirtualKeyCode  key);
+        void TestHarness_keyUp(TestHarnessPtr testharness, AzVirtualKeyCode  key);
+        size_t TestHarness_getHitNodeCount(TestHarnessPtr testharness);
+        OptionDomNodeId TestHarness_getHitNode(TestHarnessPtr testharness, size_t index);
+        OptionLogicalRect TestHarness_getNodeRect(TestHarnessPtr testharness, AzDomNodeId  node);
+        OptionString TestHarness_getNodeText(TestHarnessPtr testharness, AzDomNodeId  node);
+        void TestHarness_advanceTime(TestHarnessPtr testharness, uint64_t millis);
+        size_t TestHarness_getFramesRendered(TestHarnessPtr testharness);
+        RefAny TestHarness_getData(TestHarnessPtr testharness);
+        OptionString TestHarness_serializeLayout(TestHarnessPtr testharness, AzDomId  dom, AzCssPropertyTypeVec  properties);
+        void TestHarness_delete(TestHarnessPtr ptr);
         WindowCreateOptions WindowCreateOptions_new(AzLayoutCallbackType  layout_callback);
+        void WindowCreateOptions_setLayoutCallback(WindowCreateOptions* restrict windowcreateoptions, AzLayoutCallbackType  layout_callback);
+        AcceleratorId WindowCreateOptions_addAccelerator(WindowCreateOptions* restrict windowcreateoptions, AzAccelerator  accelerator, AzRefAny  data, AzCallbackType  callback);
         void WindowCreateOptions_delete(WindowCreateOptions* restrict instance);
         LogicalPosition LogicalPosition_new(float x, float y);
         LogicalPosition LogicalPosition_zero();
@@ -10316,6 +11638,8 @@ namespace dll {
         void LargeWindowIconBytes_delete(LargeWindowIconBytes* restrict instance);
         void WindowIcon_delete(WindowIcon* restrict instance);
         void TaskBarIcon_delete(TaskBarIcon* restrict instance);
+        Accelerator Accelerator_new(AzModifiers  modifiers, AzVirtualKeyCode  key);
+        void AcceleratorCallback_delete(AcceleratorCallback* restrict instance);
         float WindowSize_getHidpiFactor(const WindowSize* windowsize);
         bool  KeyboardState_shiftDown(const KeyboardState* keyboardstate);
         bool  KeyboardState_ctrlDown(const KeyboardState* keyboardstate);
@@ -10323,6 +11647,8 @@ namespace dll {
         bool  KeyboardState_superDown(const KeyboardState* keyboardstate);
         bool  KeyboardState_isKeyDown(const KeyboardState* keyboardstate, AzVirtualKeyCode  key);
         void KeyboardState_delete(KeyboardState* restrict instance);
+        void ImeEvent_delete(ImeEvent* restrict instance);
+        void ImePreedit_delete(ImePreedit* restrict instance);
         OptionLogicalPosition CursorPosition_getPosition(const CursorPosition* cursorposition);
         void PlatformSpecificOptions_delete(PlatformSpecificOptions* restrict instance);
         void WindowsWindowOptions_delete(WindowsWindowOptions* restrict instance);
@@ -10342,12 +11668,18 @@ namespace dll {
         WindowState CallbackInfo_getCurrentWindowState(const CallbackInfo* callbackinfo);
         KeyboardState CallbackInfo_getCurrentKeyboardState(const CallbackInfo* callbackinfo);
         MouseState CallbackInfo_getCurrentMouseState(const CallbackInfo* callbackinfo);
+        OptionImeEvent CallbackInfo_getImeEvent(const CallbackInfo* callbackinfo);
+        StringVec CallbackInfo_getHoveredFiles(const CallbackInfo* callbackinfo);
+        StringVec CallbackInfo_getDroppedFiles(const CallbackInfo* callbackinfo);
+        OptionLogicalPosition CallbackInfo_getDropPosition(const CallbackInfo* callbackinfo);
+        OptionDomNodeId CallbackInfo_getDropTarget(const CallbackInfo* callbackinfo);
         OptionWindowState CallbackInfo_getPreviousWindowState(const CallbackInfo* callbackinfo);
         OptionKeyboardState CallbackInfo_getPreviousKeyboardState(const CallbackInfo* callbackinfo);
         OptionMouseState CallbackInfo_getPreviousMouseState(const CallbackInfo* callbackinfo);
         RawWindowHandle CallbackInfo_getCurrentWindowHandle(const CallbackInfo* callbackinfo);
         OptionGl CallbackInfo_getGlContext(const CallbackInfo* callbackinfo);
         OptionLogicalPosition CallbackInfo_getScrollPosition(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
+        OptionLogicalSize CallbackInfo_getScrollExtent(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
         OptionRefAny CallbackInfo_getDataset(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
         OptionDomNodeId CallbackInfo_getNodeIdOfRootDataset(CallbackInfo* restrict callbackinfo, AzRefAny  dataset);
         OptionString CallbackInfo_getStringContents(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
@@ -10364,16 +11696,26 @@ namespace dll {
         OptionPositionInfo CallbackInfo_getNodePosition(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
         OptionLogicalSize CallbackInfo_getNodeSize(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id);
         OptionCssProperty CallbackInfo_getComputedCssProperty(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssPropertyType  property_type);
+        CssPropertyVec CallbackInfo_getComputedCssProperties(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
         void CallbackInfo_setWindowState(CallbackInfo* restrict callbackinfo, AzWindowState  new_state);
+        void CallbackInfo_setImePosition(CallbackInfo* restrict callbackinfo, AzLogicalPosition  position);
         void CallbackInfo_setFocus(CallbackInfo* restrict callbackinfo, AzFocusTarget  target);
         void CallbackInfo_setCssProperty(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzCssProperty  new_property);
-        void CallbackInfo_setScrollPosition(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
+        void CallbackInfo_setCss(CallbackInfo* restrict callbackinfo, AzCss  css);
+        bool  CallbackInfo_setScrollPosition(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzLogicalPosition  scroll_position);
         void CallbackInfo_setStringContents(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzString  string);
         void CallbackInfo_addImage(CallbackInfo* restrict callbackinfo, AzString  id, AzImageRef  image);
+        bool  CallbackInfo_addImageRaw(CallbackInfo* restrict callbackinfo, AzString  id, const uint8_t* data, size_t len, uint32_t width, uint32_t height, AzRawImageFormat  format);
+        bool  CallbackInfo_updateImageRaw(CallbackInfo* restrict callbackinfo, AzString  id, const uint8_t* data, size_t len, uint32_t width, uint32_t height, AzRawImageFormat  format);
         bool  CallbackInfo_hasImage(const CallbackInfo* callbackinfo, AzString  id);
         OptionImageRef CallbackInfo_getImage(const CallbackInfo* callbackinfo, AzString  id);
         void CallbackInfo_updateImage(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageRef  new_image, AzUpdateImageType  image_type);
         void CallbackInfo_deleteImage(CallbackInfo* restrict callbackinfo, AzString  id);
+        AcceleratorId CallbackInfo_addAccelerator(CallbackInfo* restrict callbackinfo, AzAccelerator  accelerator, AzRefAny  data, AzCallbackType  callback);
+        bool  CallbackInfo_replaceAccelerator(CallbackInfo* restrict callbackinfo, AzAcceleratorId  id, AzAccelerator  accelerator, AzRefAny  data, AzCallbackType  callback);
+        bool  CallbackInfo_removeAccelerator(CallbackInfo* restrict callbackinfo, AzAcceleratorId  id);
+        void CallbackInfo_openContextMenu(CallbackInfo* restrict callbackinfo, AzMenu  menu, AzLogicalPosition  position);
+        void CallbackInfo_closeContextMenu(CallbackInfo* restrict callbackinfo);
         void CallbackInfo_updateImageMask(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageMask  new_mask);
         void CallbackInfo_stopPropagation(CallbackInfo* restrict callbackinfo);
         void CallbackInfo_createWindow(CallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
@@ -10381,6 +11723,8 @@ namespace dll {
         OptionTimerId CallbackInfo_startAnimation(CallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzAnimation  animation);
         bool  CallbackInfo_stopTimer(CallbackInfo* restrict callbackinfo, AzTimerId  timer_id);
         OptionThreadId CallbackInfo_startThread(CallbackInfo* restrict callbackinfo, AzRefAny  thread_initialize_data, AzRefAny  writeback_data, AzThreadCallbackType  callback);
+        OptionThreadId CallbackInfo_startPendingThread(CallbackInfo* restrict callbackinfo, AzPendingThread  thread, AzCallbackType  on_finish);
+        bool  CallbackInfo_requestThreadStop(CallbackInfo* restrict callbackinfo, AzThreadId  thread_id);
         bool  CallbackInfo_sendThreadMsg(CallbackInfo* restrict callbackinfo, AzThreadId  thread_id, AzThreadSendMsg  msg);
         bool  CallbackInfo_stopThread(CallbackInfo* restrict callbackinfo, AzThreadId  thread_id);
         void CallbackInfo_delete(CallbackInfo* restrict instance);
@@ -10421,9 +11765,10 @@ namespace dll {
         void RefCount_decreaseRefmut(RefCount* restrict refcount);
         void RefCount_delete(RefCount* restrict instance);
         RefCount RefCount_deepCopy(RefCount* const instance);
-        RefAny RefAny_newC(void ptr, size_t len, uint64_t type_id, AzString  type_name, AzRefAnyDestructorType  destructor);
+        RefAny RefAny_newC(const void* ptr, size_t len, uint64_t type_id, AzString  type_name, AzRefAnyDestructorType  destructor);
         uint64_t RefAny_getTypeId(const RefAny* refany);
         String RefAny_getTypeName(const RefAny* refany);
+        size_t RefAny_strongCount(const RefAny* refany);
         void RefAny_delete(RefAny* restrict instance);
         RefAny RefAny_deepCopy(RefAny* const instance);
         OptionGl LayoutCallbackInfo_getGlContext(const LayoutCallbackInfo* layoutcallbackinfo);
@@ -10479,12 +11824,20 @@ namespace dll {
         Dom Dom_withTabIndex(Dom* restrict dom, AzTabIndex  tab_index);
         void Dom_setAccessibilityInfo(Dom* restrict dom, AzAccessibilityInfo  accessibility_info);
         Dom Dom_withAccessibilityInfo(Dom* restrict dom, AzAccessibilityInfo  accessibility_info);
+        void Dom_setAccessibilityRole(Dom* restrict dom, AzAccessibilityRole  role);
+        Dom Dom_withAccessibilityRole(Dom* restrict dom, AzAccessibilityRole  role);
+        void Dom_setAccessibilityLabel(Dom* restrict dom, AzString  label);
+        Dom Dom_withAccessibilityLabel(Dom* restrict dom, AzString  label);
         void Dom_setMenuBar(Dom* restrict dom, AzMenu  menu_bar);
         Dom Dom_withMenuBar(Dom* restrict dom, AzMenu  menu_bar);
         void Dom_setContextMenu(Dom* restrict dom, AzMenu  context_menu);
         Dom Dom_withContextMenu(Dom* restrict dom, AzMenu  context_menu);
         uint64_t Dom_hash(const Dom* dom);
         size_t Dom_nodeCount(const Dom* dom);
+        NodeType Dom_getNodeType(const Dom* dom);
+        size_t Dom_childrenCount(const Dom* dom);
+        OptionNodeId Dom_findFirstById(const Dom* dom, AzString  id);
+        NodeIdVec Dom_findAllByClass(const Dom* dom, AzString  class_name);
         String Dom_getHtmlString(Dom* restrict dom);
         String Dom_getHtmlStringTest(Dom* restrict dom);
         StyledDom Dom_style(Dom* restrict dom, AzCss  css);
@@ -10521,6 +11874,8 @@ namespace dll {
         void NodeData_setClipMask(NodeData* restrict nodedata, AzImageMask  image_mask);
         void NodeData_setTabIndex(NodeData* restrict nodedata, AzTabIndex  tab_index);
         void NodeData_setAccessibilityInfo(NodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
+        void NodeData_setAccessibilityRole(NodeData* restrict nodedata, AzAccessibilityRole  role);
+        void NodeData_setAccessibilityLabel(NodeData* restrict nodedata, AzString  label);
         void NodeData_setMenuBar(NodeData* restrict nodedata, AzMenu  menu_bar);
         void NodeData_setContextMenu(NodeData* restrict nodedata, AzMenu  context_menu);
         uint64_t NodeData_hash(const NodeData* nodedata);
@@ -10538,6 +11893,10 @@ namespace dll {
         StringMenuItem StringMenuItem_new(AzString  label);
         void StringMenuItem_setCallback(StringMenuItem* restrict stringmenuitem, AzRefAny  data, AzCallbackType  callback);
         StringMenuItem StringMenuItem_withCallback(StringMenuItem* restrict stringmenuitem, AzRefAny  data, AzCallbackType  callback);
+        void StringMenuItem_setAccelerator(StringMenuItem* restrict stringmenuitem, AzVirtualKeyCodeCombo  accelerator);
+        StringMenuItem StringMenuItem_withAccelerator(StringMenuItem* restrict stringmenuitem, AzVirtualKeyCodeCombo  accelerator);
+        void StringMenuItem_setState(StringMenuItem* restrict stringmenuitem, AzMenuItemState  state);
+        StringMenuItem StringMenuItem_withState(StringMenuItem* restrict stringmenuitem, AzMenuItemState  state);
         void StringMenuItem_addChild(StringMenuItem* restrict stringmenuitem, AzMenuItem  child);
         StringMenuItem StringMenuItem_withChild(StringMenuItem* restrict stringmenuitem, AzMenuItem  child);
         void StringMenuItem_setChildren(StringMenuItem* restrict stringmenuitem, AzMenuItemVec  children);
@@ -10555,12 +11914,29 @@ namespace dll {
         void Stylesheet_delete(Stylesheet* restrict instance);
         Css Css_empty();
         Css Css_fromString(AzString  s);
+        void Css_setVariable(Css* restrict css, AzString  name, AzCssProperty  value);
+        void Css_resolveVariables(Css* restrict css);
         void Css_delete(Css* restrict instance);
+        void CssVariable_delete(CssVariable* restrict instance);
+        void MediaQuery_delete(MediaQuery* restrict instance);
+        void MediaCondition_delete(MediaCondition* restrict instance);
         ColorU ColorU_fromStr(AzString  string);
         ColorU ColorU_transparent();
         ColorU ColorU_white();
         ColorU ColorU_black();
         String ColorU_toHash(const ColorU* coloru);
+        ColorU ColorU_multiply(const ColorU* coloru, AzColorU  other);
+        ColorU ColorU_addSaturating(const ColorU* coloru, AzColorU  other);
+        ColorU ColorU_hueRotate(const ColorU* coloru, float degrees);
+        ColorU ColorU_withR(const ColorU* coloru, uint8_t r);
+        ColorU ColorU_withG(const ColorU* coloru, uint8_t g);
+        ColorU ColorU_withB(const ColorU* coloru, uint8_t b);
+        ColorU ColorU_withA(const ColorU* coloru, uint8_t a);
+        ColorU ColorU_withAlphaF32(const ColorU* coloru, float a);
+        void GridTrack_delete(GridTrack* restrict instance);
+        void GridTrackRepeat_delete(GridTrackRepeat* restrict instance);
+        void LayoutGridTemplateColumns_delete(LayoutGridTemplateColumns* restrict instance);
+        void LayoutGridTemplateRows_delete(LayoutGridTemplateRows* restrict instance);
         float AngleValue_getDegrees(const AngleValue* anglevalue);
         void LinearGradient_delete(LinearGradient* restrict instance);
         void RadialGradient_delete(RadialGradient* restrict instance);
@@ -10569,13 +11945,62 @@ namespace dll {
         void ScrollbarInfo_delete(ScrollbarInfo* restrict instance);
         void ScrollbarStyle_delete(ScrollbarStyle* restrict instance);
         void StyleFontFamily_delete(StyleFontFamily* restrict instance);
+        void StyleTransitionProperty_delete(StyleTransitionProperty* restrict instance);
+        StyleBoxShadowValue StyleBoxShadowValue_exact(AzStyleBoxShadow  shadow);
+        void LayoutGridTemplateColumnsBoxed_delete(LayoutGridTemplateColumnsBoxed* restrict instance);
+        LayoutGridTemplateColumnsValue LayoutGridTemplateColumnsValue_exact(AzLayoutGridTemplateColumns  value);
+        void LayoutGridTemplateColumnsValue_delete(LayoutGridTemplateColumnsValue* restrict instance);
+        void LayoutGridTemplateRowsBoxed_delete(LayoutGridTemplateRowsBoxed* restrict instance);
+        LayoutGridTemplateRowsValue LayoutGridTemplateRowsValue_exact(AzLayoutGridTemplateRows  value);
+        void LayoutGridTemplateRowsValue_delete(LayoutGridTemplateRowsValue* restrict instance);
+        LayoutBottomValue LayoutBottomValue_calc(AzCalcLength  calc);
+        LayoutHeightValue LayoutHeightValue_calc(AzCalcLength  calc);
+        LayoutLeftValue LayoutLeftValue_calc(AzCalcLength  calc);
+        LayoutMarginBottomValue LayoutMarginBottomValue_calc(AzCalcLength  calc);
+        LayoutMarginLeftValue LayoutMarginLeftValue_calc(AzCalcLength  calc);
+        LayoutMarginRightValue LayoutMarginRightValue_calc(AzCalcLength  calc);
+        LayoutMarginTopValue LayoutMarginTopValue_calc(AzCalcLength  calc);
+        LayoutMaxHeightValue LayoutMaxHeightValue_calc(AzCalcLength  calc);
+        LayoutMaxWidthValue LayoutMaxWidthValue_calc(AzCalcLength  calc);
+        LayoutMinHeightValue LayoutMinHeightValue_calc(AzCalcLength  calc);
+        LayoutMinWidthValue LayoutMinWidthValue_calc(AzCalcLength  calc);
+        LayoutPaddingBottomValue LayoutPaddingBottomValue_calc(AzCalcLength  calc);
+        LayoutPaddingLeftValue LayoutPaddingLeftValue_calc(AzCalcLength  calc);
+        LayoutPaddingRightValue LayoutPaddingRightValue_calc(AzCalcLength  calc);
+        LayoutPaddingTopValue LayoutPaddingTopValue_calc(AzCalcLength  calc);
+        LayoutRightValue LayoutRightValue_calc(AzCalcLength  calc);
+        LayoutTopValue LayoutTopValue_calc(AzCalcLength  calc);
+        LayoutWidthValue LayoutWidthValue_calc(AzCalcLength  calc);
+        void ScrollbarStyleBoxed_delete(ScrollbarStyleBoxed* restrict instance);
+        ScrollbarStyleValue ScrollbarStyleValue_exact(AzScrollbarStyle  style);
         void ScrollbarStyleValue_delete(ScrollbarStyleValue* restrict instance);
+        void StyleBackgroundContentVecBoxed_delete(StyleBackgroundContentVecBoxed* restrict instance);
+        StyleBackgroundContentVecValue StyleBackgroundContentVecValue_exact(AzStyleBackgroundContentVec  value);
         void StyleBackgroundContentVecValue_delete(StyleBackgroundContentVecValue* restrict instance);
+        void StyleBackgroundPositionVecBoxed_delete(StyleBackgroundPositionVecBoxed* restrict instance);
+        StyleBackgroundPositionVecValue StyleBackgroundPositionVecValue_exact(AzStyleBackgroundPositionVec  value);
         void StyleBackgroundPositionVecValue_delete(StyleBackgroundPositionVecValue* restrict instance);
+        void StyleBackgroundRepeatVecBoxed_delete(StyleBackgroundRepeatVecBoxed* restrict instance);
+        StyleBackgroundRepeatVecValue StyleBackgroundRepeatVecValue_exact(AzStyleBackgroundRepeatVec  value);
         void StyleBackgroundRepeatVecValue_delete(StyleBackgroundRepeatVecValue* restrict instance);
+        void StyleBackgroundSizeVecBoxed_delete(StyleBackgroundSizeVecBoxed* restrict instance);
+        StyleBackgroundSizeVecValue StyleBackgroundSizeVecValue_exact(AzStyleBackgroundSizeVec  value);
         void StyleBackgroundSizeVecValue_delete(StyleBackgroundSizeVecValue* restrict instance);
+        void StyleFontFamilyVecBoxed_delete(StyleFontFamilyVecBoxed* restrict instance);
+        StyleFontFamilyVecValue StyleFontFamilyVecValue_exact(AzStyleFontFamilyVec  value);
         void StyleFontFamilyVecValue_delete(StyleFontFamilyVecValue* restrict instance);
+        void StyleTransitionPropertyBoxed_delete(StyleTransitionPropertyBoxed* restrict instance);
+        StyleTransitionPropertyValue StyleTransitionPropertyValue_exact(AzStyleTransitionProperty  value);
+        void StyleTransitionPropertyValue_delete(StyleTransitionPropertyValue* restrict instance);
+        StyleTransitionTimingFunctionValue StyleTransitionTimingFunctionValue_exact(AzStyleTransitionTimingFunction  value);
+        StyleVerticalAlignValue StyleVerticalAlignValue_exact(AzStyleVerticalAlign  value);
+        void StyleTransformVecBoxed_delete(StyleTransformVecBoxed* restrict instance);
+        StyleTransformVecValue StyleTransformVecValue_exact(AzStyleTransformVec  value);
         void StyleTransformVecValue_delete(StyleTransformVecValue* restrict instance);
+        StyleTransformOriginValue StyleTransformOriginValue_exact(AzStyleTransformOrigin  value);
+        StylePerspectiveOriginValue StylePerspectiveOriginValue_exact(AzStylePerspectiveOrigin  value);
+        void StyleFilterVecBoxed_delete(StyleFilterVecBoxed* restrict instance);
+        StyleFilterVecValue StyleFilterVecValue_exact(AzStyleFilterVec  value);
         void StyleFilterVecValue_delete(StyleFilterVecValue* restrict instance);
         String CssProperty_getKeyString(const CssProperty* cssproperty);
         String CssProperty_getValueString(const CssProperty* cssproperty);
@@ -10995,7 +12420,7 @@ namespace dll {
         void GetActiveUniformReturn_delete(GetActiveUniformReturn* restrict instance);
         TextureFlags TextureFlags_default();
         ImageRef ImageRef_invalid(size_t width, size_t height, AzRawImageFormat  format);
-        ImageRef ImageRef_rawImage(AzRawImage  data);
+        OptionImageRef ImageRef_rawImage(AzRawImage  data);
         ImageRef ImageRef_glTexture(AzTexture  texture);
         ImageRef ImageRef_callback(AzRefAny  data, AzRenderImageCallbackType  callback);
         ImageRef ImageRef_cloneBytes(const ImageRef* imageref);
@@ -11009,7 +12434,7 @@ namespace dll {
         ImageRef ImageRef_deepCopy(ImageRef* const instance);
         RawImage RawImage_empty();
         RawImage RawImage_allocateClipMask(AzLayoutSize  size);
-        RawImage RawImage_decodeImageBytesAny(AzU8VecRef  bytes);
+        ResultRawImageDecodeImageError RawImage_decodeImageBytesAny(AzU8VecRef  bytes);
         bool  RawImage_drawClipMask(RawImage* restrict rawimage, AzSvgNode  node, AzSvgStyle  style);
         ResultU8VecEncodeImageError RawImage_encodeBmp(const RawImage* rawimage);
         ResultU8VecEncodeImageError RawImage_encodePng(const RawImage* rawimage);
@@ -11046,21 +12471,21 @@ namespace dll {
         float FontMetrics_getYStrikeoutSize(const FontMetrics* fontmetrics, float target_font_size);
         float FontMetrics_getYStrikeoutPosition(const FontMetrics* fontmetrics, float target_font_size);
         void FontSource_delete(FontSource* restrict instance);
-        FontRef FontRef_parse(AzFontSource  source);
+        OptionFontRef FontRef_parse(AzFontSource  source);
         U8Vec FontRef_getBytes(const FontRef* fontref);
         FontMetrics FontRef_getFontMetrics(const FontRef* fontref);
         InlineText FontRef_shapeText(const FontRef* fontref, AzRefstr  text, AzResolvedTextLayoutOptions  options);
         uint64_t FontRef_getHash(const FontRef* fontref);
         void FontRef_delete(FontRef* restrict instance);
         FontRef FontRef_deepCopy(FontRef* const instance);
-        Svg Svg_fromString(AzString  svg_string, AzSvgParseOptions  parse_options);
-        Svg Svg_fromBytes(AzU8VecRef  svg_bytes, AzSvgParseOptions  parse_options);
+        ResultSvgSvgParseError Svg_fromString(AzString  svg_string, AzSvgParseOptions  parse_options);
+        ResultSvgSvgParseError Svg_fromBytes(AzU8VecRef  svg_bytes, AzSvgParseOptions  parse_options);
         SvgXmlNode Svg_getRoot(const Svg* svg);
         OptionRawImage Svg_render(const Svg* svg, AzSvgRenderOptions  options);
         String Svg_toString(const Svg* svg, AzSvgStringFormatOptions  options);
         void Svg_delete(Svg* restrict instance);
         Svg Svg_deepCopy(Svg* const instance);
-        SvgXmlNode SvgXmlNode_parseFrom(AzU8VecRef  svg_bytes, AzSvgParseOptions  parse_options);
+        ResultSvgXmlNodeSvgParseError SvgXmlNode_parseFrom(AzU8VecRef  svg_bytes, AzSvgParseOptions  parse_options);
         void SvgXmlNode_delete(SvgXmlNode* restrict instance);
         SvgXmlNode SvgXmlNode_deepCopy(SvgXmlNode* const instance);
         SvgRect SvgMultiPolygon_getBounds(const SvgMultiPolygon* svgmultipolygon);
@@ -11162,11 +12587,11 @@ namespace dll {
         SvgRenderOptions SvgRenderOptions_default();
         SvgFillStyle SvgFillStyle_default();
         SvgStrokeStyle SvgStrokeStyle_default();
-        Xml Xml_fromStr(AzRefstr  xml_string);
+        ResultXmlXmlError Xml_fromStr(AzRefstr  xml_string);
         void Xml_delete(Xml* restrict instance);
         void XmlNode_delete(XmlNode* restrict instance);
-        File File_open(AzString  path);
-        File File_create(AzString  path);
+        OptionFile File_open(AzString  path);
+        OptionFile File_create(AzString  path);
         OptionString File_readToString(File* restrict file);
         OptionU8Vec File_readToBytes(File* restrict file);
         bool  File_writeString(File* restrict file, AzRefstr  bytes);
@@ -11174,20 +12599,20 @@ namespace dll {
         void File_close(File* restrict file);
         void File_delete(File* restrict instance);
         File File_deepCopy(File* const instance);
-        MsgBox MsgBox_ok(AzMsgBoxIcon  icon, AzString  title, AzString  message);
-        MsgBox MsgBox_info(AzString  message);
-        MsgBox MsgBox_warning(AzString  message);
-        MsgBox MsgBox_error(AzString  message);
-        MsgBox MsgBox_question(AzString  message);
-        MsgBox MsgBox_okCancel(AzMsgBoxIcon  icon, AzString  title, AzString  message, AzMsgBoxOkCancel  default_value);
-        MsgBox MsgBox_yesNo(AzMsgBoxIcon  icon, AzString  title, AzString  message, AzMsgBoxYesNo  default_value);
-        FileDialog FileDialog_selectFile(AzString  title, AzOptionString  default_path, AzOptionFileTypeList  filter_list);
-        FileDialog FileDialog_selectMultipleFiles(AzString  title, AzOptionString  default_path, AzOptionFileTypeList  filter_list);
-        FileDialog FileDialog_selectFolder(AzString  title, AzOptionString  default_path);
-        FileDialog FileDialog_saveFile(AzString  title, AzOptionString  default_path);
+        bool  MsgBox_ok(AzMsgBoxIcon  icon, AzString  title, AzString  message);
+        bool  MsgBox_info(AzString  message);
+        bool  MsgBox_warning(AzString  message);
+        bool  MsgBox_error(AzString  message);
+        bool  MsgBox_question(AzString  message);
+        MsgBoxOkCancel MsgBox_okCancel(AzMsgBoxIcon  icon, AzString  title, AzString  message, AzMsgBoxOkCancel  default_value);
+        MsgBoxYesNo MsgBox_yesNo(AzMsgBoxIcon  icon, AzString  title, AzString  message, AzMsgBoxYesNo  default_value);
+        OptionString FileDialog_selectFile(AzString  title, AzOptionString  default_path, AzOptionFileTypeList  filter_list);
+        OptionStringVec FileDialog_selectMultipleFiles(AzString  title, AzOptionString  default_path, AzOptionFileTypeList  filter_list);
+        OptionString FileDialog_selectFolder(AzString  title, AzOptionString  default_path);
+        OptionString FileDialog_saveFile(AzString  title, AzOptionString  default_path);
         void FileTypeList_delete(FileTypeList* restrict instance);
-        ColorPickerDialog ColorPickerDialog_open(AzString  title, AzOptionColorU  default_color);
-        SystemClipboard SystemClipboard_new();
+        OptionColorU ColorPickerDialog_open(AzString  title, AzOptionColorU  default_color);
+        OptionSystemClipboard SystemClipboard_new();
         OptionString SystemClipboard_getStringContents(const SystemClipboard* systemclipboard);
         bool  SystemClipboard_setStringContents(SystemClipboard* restrict systemclipboard, AzString  contents);
         void SystemClipboard_delete(SystemClipboard* restrict instance);
@@ -11205,6 +12630,8 @@ namespace dll {
         void Timer_delete(Timer* restrict instance);
         void Thread_delete(Thread* restrict instance);
         Thread Thread_deepCopy(Thread* const instance);
+        PendingThread PendingThread_new(AzRefAny  data, AzThreadFnType  thread_fn);
+        void PendingThread_delete(PendingThread* restrict instance);
         bool  ThreadSender_send(ThreadSender* restrict threadsender, AzThreadReceiveMsg  msg);
         void ThreadSender_delete(ThreadSender* restrict instance);
         ThreadSender ThreadSender_deepCopy(ThreadSender* const instance);
@@ -11217,10 +12644,20 @@ namespace dll {
         void FmtValue_delete(FmtValue* restrict instance);
         void FmtArg_delete(FmtArg* restrict instance);
         String String_format(AzString  format, AzFmtArgVec  args);
-        String String_copyFromBytes(uint8_t ptr, size_t start, size_t len);
+        String String_copyFromBytes(const uint8_t* ptr, size_t start, size_t len);
         String String_trim(const String* string);
         Refstr String_asRefstr(const String* string);
         void String_delete(String* restrict instance);
+        TextCursor TextCursor_start();
+        TextCursor TextCursor_end(AzString  text);
+        TextCursor TextCursor_fromByteOffset(AzString  text, size_t byte_offset);
+        TextCursor TextCursor_nextGrapheme(const TextCursor* textcursor, AzString  text);
+        TextCursor TextCursor_prevGrapheme(const TextCursor* textcursor, AzString  text);
+        TextCursor TextCursor_nextWord(const TextCursor* textcursor, AzString  text);
+        TextCursor TextCursor_prevWord(const TextCursor* textcursor, AzString  text);
+        TextSelection TextSelection_collapsed(AzTextCursor  cursor);
+        bool  TextSelection_isCollapsed(const TextSelection* textselection);
+        TextSelection TextSelection_normalized(const TextSelection* textselection);
         void ListViewRowVec_delete(ListViewRowVec* restrict instance);
         void StyleFilterVec_delete(StyleFilterVec* restrict instance);
         void LogicalRectVec_delete(LogicalRectVec* restrict instance);
@@ -11248,6 +12685,7 @@ namespace dll {
         void InlineTextHitVec_delete(InlineTextHitVec* restrict instance);
         void MonitorVec_delete(MonitorVec* restrict instance);
         void VideoModeVec_delete(VideoModeVec* restrict instance);
+        void AcceleratorCallbackVec_delete(AcceleratorCallbackVec* restrict instance);
         void DomVec_delete(DomVec* restrict instance);
         void IdOrClassVec_delete(IdOrClassVec* restrict instance);
         void NodeDataInlineCssPropertyVec_delete(NodeDataInlineCssPropertyVec* restrict instance);
@@ -11256,6 +12694,13 @@ namespace dll {
         void StyleBackgroundRepeatVec_delete(StyleBackgroundRepeatVec* restrict instance);
         void StyleBackgroundSizeVec_delete(StyleBackgroundSizeVec* restrict instance);
         void StyleTransformVec_delete(StyleTransformVec* restrict instance);
+        void GridTrackVec_delete(GridTrackVec* restrict instance);
+        void CssPropertyTypeVec_delete(CssPropertyTypeVec* restrict instance);
+        void CssVariableVec_delete(CssVariableVec* restrict instance);
+        void MediaFeatureVec_delete(MediaFeatureVec* restrict instance);
+        void MediaConditionVec_delete(MediaConditionVec* restrict instance);
+        size_t CssPropertyVec_len(const CssPropertyVec* csspropertyvec);
+        OptionCssProperty CssPropertyVec_get(const CssPropertyVec* csspropertyvec, size_t index);
         void CssPropertyVec_delete(CssPropertyVec* restrict instance);
         void SvgMultiPolygonVec_delete(SvgMultiPolygonVec* restrict instance);
         void SvgSimpleNodeVec_delete(SvgSimpleNodeVec* restrict instance);
@@ -11275,15 +12720,20 @@ namespace dll {
         void CssRuleBlockVec_delete(CssRuleBlockVec* restrict instance);
         void U16Vec_delete(U16Vec* restrict instance);
         void F32Vec_delete(F32Vec* restrict instance);
-        U8Vec U8Vec_copyFromBytes(uint8_t ptr, size_t start, size_t len);
+        U8Vec U8Vec_copyFromBytes(const uint8_t* ptr, size_t start, size_t len);
         U8VecRef U8Vec_asRefVec(const U8Vec* u8vec);
         void U8Vec_delete(U8Vec* restrict instance);
         void CallbackDataVec_delete(CallbackDataVec* restrict instance);
         void DebugMessageVec_delete(DebugMessageVec* restrict instance);
         void GLuintVec_delete(GLuintVec* restrict instance);
         void GLintVec_delete(GLintVec* restrict instance);
+        StringVec StringVec_new();
+        size_t StringVec_len(const StringVec* stringvec);
+        OptionString StringVec_get(const StringVec* stringvec, size_t index);
         void StringVec_delete(StringVec* restrict instance);
         void StringPairVec_delete(StringPairVec* restrict instance);
+        size_t NormalizedLinearColorStopVec_len(const NormalizedLinearColorStopVec* normalizedlinearcolorstopvec);
+        OptionNormalizedLinearColorStop NormalizedLinearColorStopVec_get(const NormalizedLinearColorStopVec* normalizedlinearcolorstopvec, size_t index);
         void NormalizedLinearColorStopVec_delete(NormalizedLinearColorStopVec* restrict instance);
         void NormalizedRadialColorStopVec_delete(NormalizedRadialColorStopVec* restrict instance);
         void NodeIdVec_delete(NodeIdVec* restrict instance);
@@ -11292,6 +12742,7 @@ namespace dll {
         void TagIdToNodeIdMappingVec_delete(TagIdToNodeIdMappingVec* restrict instance);
         void ParentWithNodeDepthVec_delete(ParentWithNodeDepthVec* restrict instance);
         void NodeDataVec_delete(NodeDataVec* restrict instance);
+        void OptionMediaQuery_delete(OptionMediaQuery* restrict instance);
         void OptionListViewOnRowClick_delete(OptionListViewOnRowClick* restrict instance);
         void OptionListViewOnColumnClick_delete(OptionListViewOnColumnClick* restrict instance);
         void OptionListViewOnLazyLoadScroll_delete(OptionListViewOnLazyLoadScroll* restrict instance);
@@ -11338,6 +12789,7 @@ namespace dll {
         void OptionTaskBarIcon_delete(OptionTaskBarIcon* restrict instance);
         void OptionWindowIcon_delete(OptionWindowIcon* restrict instance);
         void OptionString_delete(OptionString* restrict instance);
+        void OptionImeEvent_delete(OptionImeEvent* restrict instance);
         void OptionDom_delete(OptionDom* restrict instance);
         void OptionTexture_delete(OptionTexture* restrict instance);
         void OptionImageMask_delete(OptionImageMask* restrict instance);
@@ -707,6 +707,18 @@ mod dll {
             Trace,
         }
 
+        /// Controls how often a window renders a new frame
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzRedrawBehavior {
+            OnEventOnly,
+            MaxFps(u16),
+            Unlimited,
+        }
+
         /// Version of the layout solver to use - future binary versions of azul may have more fields here, necessary so that old compiled applications don't break with newer releases of azul. Newer layout versions are opt-in only.
         #[repr(C)]
         #[derive(Debug)]
@@ -718,6 +730,15 @@ mod dll {
             Default,
         }
 
+        /// Version and feature detection of the loaded library. Bindings that load the library dynamically should call these functions before anything else and compare the results with the `AZ_API_VERSION_*` and `AZ_API_ABI_HASH` defines of the header they were generated from. The functions only use primitive types, so they can be called even if the rest of the API does not match.
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzApi {
+            pub _reserved: usize,
+        }
+
         /// Whether the renderer has VSync enabled
         #[repr(C)]
         #[derive(Debug)]
@@ -1123,6 +1144,29 @@ mod dll {
             Cut,
         }
 
+        /// Which modifier keys are held down (left and right keys are not distinguished)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzModifiers {
+            pub shift: bool,
+            pub ctrl: bool,
+            pub alt: bool,
+            pub super_key: bool,
+        }
+
+        /// ID of a registered accelerator, used to replace or remove it
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzAcceleratorId {
+            pub id: usize,
+        }
+
         /// State of the window frame (minimized, maximized, fullscreen or normal window)
         #[repr(C)]
         #[derive(Debug)]
@@ -1174,6 +1218,17 @@ mod dll {
             pub force_picture_invalidation: bool,
         }
 
+        /// Byte range in the `ImePreedit` text
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzImeCursorRange {
+            pub start: usize,
+            pub end: usize,
+        }
+
         /// Current icon of the mouse cursor
         #[repr(C)]
         #[derive(Debug)]
@@ -1454,6 +1509,9 @@ mod dll {
         /// `AzThreadCallbackType` struct
         pub type AzThreadCallbackType = extern "C" fn(AzRefAny, AzThreadSender, AzThreadReceiver);
 
+        /// `AzThreadFnType` struct
+        pub type AzThreadFnType = extern "C" fn(&mut AzRefAny, &mut AzThreadSender, &mut AzThreadReceiver);
+
         /// `AzRefAnyDestructorType` struct
         pub type AzRefAnyDestructorType = extern "C" fn(&mut c_void);
 
@@ -1526,6 +1584,9 @@ mod dll {
             TouchMove,
             TouchEnd,
             TouchCancel,
+            ImeStateChanged,
+            ImePreedit,
+            ImeCommit,
         }
 
         /// Re-export of rust-allocated (stack based) `FocusEventFilter` struct
@@ -1554,6 +1615,9 @@ mod dll {
             VirtualKeyUp,
             FocusReceived,
             FocusLost,
+            ImeStateChanged,
+            ImePreedit,
+            ImeCommit,
         }
 
         /// Re-export of rust-allocated (stack based) `WindowEventFilter` struct
@@ -1593,6 +1657,11 @@ mod dll {
             FocusLost,
             CloseRequested,
             ThemeChanged,
+            WindowFocusReceived,
+            WindowFocusLost,
+            ImeStateChanged,
+            ImePreedit,
+            ImeCommit,
         }
 
         /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -1764,6 +1833,7 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
         pub enum AzMenuItemState {
             Normal,
             Greyed,
@@ -1796,6 +1866,28 @@ mod dll {
             pub offset: u32,
         }
 
+        /// Value of the `orientation` media feature
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzMediaOrientation {
+            Portrait,
+            Landscape,
+        }
+
+        /// Value of the `prefers-color-scheme` media feature
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzMediaColorScheme {
+            Light,
+            Dark,
+        }
+
         /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -1807,6 +1899,8 @@ mod dll {
             FontSize,
             FontFamily,
             TextAlign,
+            VerticalAlign,
+            Direction,
             LetterSpacing,
             LineHeight,
             WordSpacing,
@@ -1833,6 +1927,10 @@ mod dll {
             JustifyContent,
             AlignItems,
             AlignContent,
+            GridTemplateColumns,
+            GridTemplateRows,
+            GridColumn,
+            GridRow,
             BackgroundContent,
             BackgroundPosition,
             BackgroundSize,
@@ -1874,9 +1972,18 @@ mod dll {
             PerspectiveOrigin,
             BackfaceVisibility,
             MixBlendMode,
+            Isolation,
+            ImageRendering,
             Filter,
             BackdropFilter,
             TextShadow,
+            TransitionProperty,
+            TransitionDuration,
+            TransitionTimingFunction,
+            TransitionDelay,
+            CaretColor,
+            SelectionColor,
+            SelectionBackground,
         }
 
         /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -1955,6 +2062,29 @@ mod dll {
             Luminosity,
         }
 
+        /// Represents an `isolation` attribute: whether the element creates a new stacking context for `mix-blend-mode`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleIsolation {
+            Auto,
+            Isolate,
+        }
+
+        /// Represents an `image-rendering` attribute: which algorithm is used to scale images and background images
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleImageRendering {
+            Auto,
+            CrispEdges,
+            Pixelated,
+        }
+
         /// Re-export of rust-allocated (stack based) `LayoutAlignContent` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -2018,6 +2148,20 @@ mod dll {
             Flex,
             Block,
             InlineBlock,
+            Grid,
+            Inline,
+        }
+
+        /// Start or end line of a `grid-column` or `grid-row` attribute
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzGridLine {
+            Auto,
+            Line(i16),
+            Span(u16),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -2029,6 +2173,7 @@ mod dll {
         pub enum AzLayoutFloat {
             Left,
             Right,
+            None,
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutJustifyContent` struct
@@ -2068,6 +2213,7 @@ mod dll {
         pub enum AzLayoutFlexWrap {
             Wrap,
             NoWrap,
+            WrapReverse,
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutOverflow` struct
@@ -2081,6 +2227,7 @@ mod dll {
             Auto,
             Hidden,
             Visible,
+            Clip,
         }
 
         /// Re-export of rust-allocated (stack based) `AngleMetric` struct
@@ -2145,6 +2292,7 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
         pub enum AzRadialGradientSize {
             ClosestSide,
             ClosestCorner,
@@ -2164,6 +2312,8 @@ mod dll {
             Repeat,
             RepeatX,
             RepeatY,
+            Space,
+            Round,
         }
 
         /// Re-export of rust-allocated (stack based) `BorderStyle` struct
@@ -2246,6 +2396,32 @@ mod dll {
             Left,
             Center,
             Right,
+            Start,
+            End,
+        }
+
+        /// Represents a `direction` attribute: the inline base direction of text and of `flex-direction: row` - default: `Ltr`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleDirection {
+            Ltr,
+            Rtl,
+        }
+
+        /// Position of the jumps of a `steps()` timing function - default: `JumpEnd`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStepPosition {
+            JumpStart,
+            JumpEnd,
+            JumpNone,
+            JumpBoth,
         }
 
         /// Re-export of rust-allocated (stack based) `Ribbon` struct
@@ -3358,7 +3534,7 @@ mod dll {
             pub id: usize,
         }
 
-        /// Re-export of rust-allocated (stack based) `Thread` struct
+        /// Background thread, started via `CallbackInfo::start_thread`. The thread function receives the `thread_initialize_data`, a `ThreadSender` and a `ThreadReceiver` by value: in C, the thread function has to delete all three before it returns. Progress and results are sent back with `ThreadSender::send(ThreadReceiveMsg::WriteBack(..))`: the `WriteBackCallback` then runs on the main thread with the `writeback_data` and the sent data (which is deleted after the callback returns). Once the thread function has returned and all of its messages have been processed, the thread is removed and the `writeback_data` is deleted. `CallbackInfo::stop_thread` sends a `ThreadSendMsg::TerminateThread` and joins the thread, so the thread function should check `ThreadReceiver::receive` regularly and return early.
         #[repr(C)]
         #[derive(Debug)]
         #[derive(PartialEq, PartialOrd)]
@@ -3485,6 +3661,18 @@ mod dll {
             pub cb: AzThreadSenderDestructorFnType,
         }
 
+        /// Position of the text cursor (caret) in a UTF-8 string. `byte_offset` is always on a grapheme boundary, `grapheme_offset` is the number of graphemes before the cursor.
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        #[derive(Default)]
+        pub struct AzTextCursor {
+            pub byte_offset: usize,
+            pub grapheme_offset: usize,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -3810,6 +3998,19 @@ mod dll {
         /// `AzVideoModeVecDestructorType` struct
         pub type AzVideoModeVecDestructorType = extern "C" fn(&mut AzVideoModeVec);
 
+        /// Re-export of rust-allocated (stack based) `AcceleratorCallbackVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzAcceleratorCallbackVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzAcceleratorCallbackVecDestructorType),
+        }
+
+        /// `AzAcceleratorCallbackVecDestructorType` struct
+        pub type AzAcceleratorCallbackVecDestructorType = extern "C" fn(&mut AzAcceleratorCallbackVec);
+
         /// Re-export of rust-allocated (stack based) `DomVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -3914,6 +4115,71 @@ mod dll {
         /// `AzStyleTransformVecDestructorType` struct
         pub type AzStyleTransformVecDestructorType = extern "C" fn(&mut AzStyleTransformVec);
 
+        /// Re-export of rust-allocated (stack based) `GridTrackVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzGridTrackVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzGridTrackVecDestructorType),
+        }
+
+        /// `AzGridTrackVecDestructorType` struct
+        pub type AzGridTrackVecDestructorType = extern "C" fn(&mut AzGridTrackVec);
+
+        /// Re-export of rust-allocated (stack based) `CssPropertyTypeVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzCssPropertyTypeVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzCssPropertyTypeVecDestructorType),
+        }
+
+        /// `AzCssPropertyTypeVecDestructorType` struct
+        pub type AzCssPropertyTypeVecDestructorType = extern "C" fn(&mut AzCssPropertyTypeVec);
+
+        /// Re-export of rust-allocated (stack based) `CssVariableVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzCssVariableVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzCssVariableVecDestructorType),
+        }
+
+        /// `AzCssVariableVecDestructorType` struct
+        pub type AzCssVariableVecDestructorType = extern "C" fn(&mut AzCssVariableVec);
+
+        /// Re-export of rust-allocated (stack based) `MediaFeatureVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzMediaFeatureVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzMediaFeatureVecDestructorType),
+        }
+
+        /// `AzMediaFeatureVecDestructorType` struct
+        pub type AzMediaFeatureVecDestructorType = extern "C" fn(&mut AzMediaFeatureVec);
+
+        /// Re-export of rust-allocated (stack based) `MediaConditionVecDestructor` struct
+        #[repr(C, u8)]
+        #[derive(Clone)]
+        #[derive(Copy)]
+        pub enum AzMediaConditionVecDestructor {
+            DefaultRust,
+            NoDestructor,
+            External(AzMediaConditionVecDestructorType),
+        }
+
+        /// `AzMediaConditionVecDestructorType` struct
+        pub type AzMediaConditionVecDestructorType = extern "C" fn(&mut AzMediaConditionVec);
+
         /// Re-export of rust-allocated (stack based) `CssPropertyVecDestructor` struct
         #[repr(C, u8)]
         #[derive(Clone)]
@@ -4542,6 +4808,17 @@ mod dll {
             Key(AzVirtualKeyCode),
         }
 
+        /// Window-wide keyboard shortcut (i.e. `Ctrl+S`) that fires regardless of which node is focused
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzAccelerator {
+            pub modifiers: AzModifiers,
+            pub key: AzVirtualKeyCode,
+        }
+
         /// Boolean flags relating to the current window state
         #[repr(C)]
         #[derive(Debug)]
@@ -4788,6 +5065,29 @@ mod dll {
             pub number: AzFloatValue,
         }
 
+        /// Simplified `calc()` expression: sum of lengths with different metrics, i.e. `calc(100% - 32px)` is stored as `{ percent: 100, px: -32 }`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzCalcLength {
+            pub px: AzFloatValue,
+            pub pt: AzFloatValue,
+            pub em: AzFloatValue,
+            pub percent: AzFloatValue,
+        }
+
+        /// Value of a boxed `Calc` length: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzCalcLengthBoxed {
+            pub run_destructor: bool,
+            pub value: AzCalcLength,
+        }
+
         /// Re-export of rust-allocated (stack based) `PixelValueNoPercent` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -4871,6 +5171,37 @@ mod dll {
             pub inner: AzPixelValue,
         }
 
+        /// Start / end line pair of a `grid-column` or `grid-row` attribute
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzGridPlacement {
+            pub start: AzGridLine,
+            pub end: AzGridLine,
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutGridColumn` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzLayoutGridColumn {
+            pub inner: AzGridPlacement,
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutGridRow` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzLayoutGridRow {
+            pub inner: AzGridPlacement,
+        }
+
         /// Re-export of rust-allocated (stack based) `LayoutFlexGrow` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -5530,94 +5861,239 @@ mod dll {
             pub y: AzPercentageValue,
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
+        /// Represents a `transition-duration` attribute, in milliseconds
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub struct AzStyleTextColor {
-            pub inner: AzColorU,
+        pub struct AzStyleTransitionDuration {
+            pub inner: AzFloatValue,
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleWordSpacing` struct
+        /// Represents a `transition-delay` attribute, in milliseconds (can be negative)
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub struct AzStyleWordSpacing {
-            pub inner: AzPixelValue,
+        pub struct AzStyleTransitionDelay {
+            pub inner: AzFloatValue,
         }
 
-        /// Re-export of rust-allocated (stack based) `StyleBoxShadowValue` struct
-        #[repr(C, u8)]
+        /// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+        #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzStyleBoxShadowValue {
-            Auto,
-            None,
-            Inherit,
-            Initial,
-            Exact(AzStyleBoxShadow),
+        pub struct AzStyleCubicBezier {
+            pub x1: AzFloatValue,
+            pub y1: AzFloatValue,
+            pub x2: AzFloatValue,
+            pub y2: AzFloatValue,
         }
 
-        /// Re-export of rust-allocated (stack based) `LayoutAlignContentValue` struct
-        #[repr(C, u8)]
+        /// A `steps(count, position)` timing function
+        #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzLayoutAlignContentValue {
-            Auto,
-            None,
-            Inherit,
-            Initial,
-            Exact(AzLayoutAlignContent),
+        pub struct AzStyleSteps {
+            pub count: u32,
+            pub position: AzStepPosition,
         }
 
-        /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
+        /// Represents a `transition-timing-function` attribute - default: `Ease`
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzLayoutAlignItemsValue {
-            Auto,
-            None,
-            Inherit,
-            Initial,
-            Exact(AzLayoutAlignItems),
+        pub enum AzStyleTransitionTimingFunction {
+            Linear,
+            Ease,
+            EaseIn,
+            EaseOut,
+            EaseInOut,
+            CubicBezier(AzStyleCubicBezier),
+            Steps(AzStyleSteps),
         }
 
-        /// Re-export of rust-allocated (stack based) `LayoutBottomValue` struct
+        /// Represents a `vertical-align` attribute, aligns an inline item (i.e. an icon next to text) inside of its line - default: `Baseline`
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzLayoutBottomValue {
-            Auto,
-            None,
-            Inherit,
-            Initial,
-            Exact(AzLayoutBottom),
+        pub enum AzStyleVerticalAlign {
+            Baseline,
+            Top,
+            Middle,
+            Bottom,
+            TextTop,
+            TextBottom,
+            Sub,
+            Super,
+            Exact(AzPixelValue),
         }
 
-        /// Re-export of rust-allocated (stack based) `LayoutBoxSizingValue` struct
-        #[repr(C, u8)]
+        /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
+        #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzLayoutBoxSizingValue {
-            Auto,
-            None,
-            Inherit,
-            Initial,
-            Exact(AzLayoutBoxSizing),
+        pub struct AzStyleTextColor {
+            pub inner: AzColorU,
+        }
+
+        /// Represents a `caret-color` attribute: the color of the text cursor in editable text
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyleCaretColor {
+            pub inner: AzColorU,
+        }
+
+        /// Represents a `-azul-selection-color` attribute: the text color of selected text (same as `::selection { color }`)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyleSelectionColor {
+            pub inner: AzColorU,
+        }
+
+        /// Represents a `-azul-selection-background-color` attribute: the highlight color behind selected text
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyleSelectionBackground {
+            pub inner: AzColorU,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleWordSpacing` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub struct AzStyleWordSpacing {
+            pub inner: AzPixelValue,
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleBoxShadowBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleBoxShadow,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleBoxShadowValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleBoxShadowValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(*const AzStyleBoxShadowBoxed),
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutAlignContentValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzLayoutAlignContentValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzLayoutAlignContent),
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutGridColumnValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzLayoutGridColumnValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzLayoutGridColumn),
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutGridRowValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzLayoutGridRowValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzLayoutGridRow),
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzLayoutAlignItemsValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzLayoutAlignItems),
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutBottomValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzLayoutBottomValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzLayoutBottom),
+            Calc(*const AzCalcLengthBoxed),
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutBoxSizingValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzLayoutBoxSizingValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzLayoutBoxSizing),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutFlexDirectionValue` struct
@@ -5695,13 +6171,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutHeightValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutHeight),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutJustifyContentValue` struct
@@ -5723,13 +6199,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutLeftValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutLeft),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutMarginBottomValue` struct
@@ -5737,13 +6213,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutMarginBottomValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutMarginBottom),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutMarginLeftValue` struct
@@ -5751,13 +6227,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutMarginLeftValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutMarginLeft),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutMarginRightValue` struct
@@ -5765,13 +6241,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutMarginRightValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutMarginRight),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutMarginTopValue` struct
@@ -5779,13 +6255,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutMarginTopValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutMarginTop),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutMaxHeightValue` struct
@@ -5793,13 +6269,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutMaxHeightValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutMaxHeight),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutMaxWidthValue` struct
@@ -5807,13 +6283,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutMaxWidthValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutMaxWidth),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutMinHeightValue` struct
@@ -5821,13 +6297,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutMinHeightValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutMinHeight),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutMinWidthValue` struct
@@ -5835,13 +6311,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutMinWidthValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutMinWidth),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutPaddingBottomValue` struct
@@ -5849,13 +6325,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutPaddingBottomValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutPaddingBottom),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutPaddingLeftValue` struct
@@ -5863,13 +6339,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutPaddingLeftValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutPaddingLeft),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutPaddingRightValue` struct
@@ -5877,13 +6353,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutPaddingRightValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutPaddingRight),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutPaddingTopValue` struct
@@ -5891,13 +6367,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutPaddingTopValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutPaddingTop),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutPositionValue` struct
@@ -5919,13 +6395,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutRightValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutRight),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutTopValue` struct
@@ -5933,13 +6409,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutTopValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutTop),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutWidthValue` struct
@@ -5947,13 +6423,13 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzLayoutWidthValue {
             Auto,
             None,
             Inherit,
             Initial,
             Exact(AzLayoutWidth),
+            Calc(*const AzCalcLengthBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `LayoutFlexWrapValue` struct
@@ -6292,6 +6768,136 @@ mod dll {
             Exact(AzStyleTextAlign),
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleDirectionValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleDirectionValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleDirection),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleTransitionDurationValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleTransitionDurationValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleTransitionDuration),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleTransitionTimingFunctionBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleTransitionTimingFunction,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleTransitionTimingFunctionValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(*const AzStyleTransitionTimingFunctionBoxed),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleTransitionDelayValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleTransitionDelayValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleTransitionDelay),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleCaretColorValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleCaretColorValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleCaretColor),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleSelectionColorValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleSelectionColorValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleSelectionColor),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleSelectionBackgroundValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleSelectionBackgroundValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleSelectionBackground),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleVerticalAlignBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleVerticalAlign,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleVerticalAlignValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleVerticalAlignValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(*const AzStyleVerticalAlignBoxed),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -6334,18 +6940,37 @@ mod dll {
             Exact(AzStyleOpacity),
         }
 
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleTransformOriginBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleTransformOrigin,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleTransformOriginValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzStyleTransformOriginValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStyleTransformOrigin),
+            Exact(*const AzStyleTransformOriginBoxed),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStylePerspectiveOriginBoxed {
+            pub run_destructor: bool,
+            pub value: AzStylePerspectiveOrigin,
         }
 
         /// Re-export of rust-allocated (stack based) `StylePerspectiveOriginValue` struct
@@ -6353,13 +6978,12 @@ mod dll {
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        #[derive(Copy)]
         pub enum AzStylePerspectiveOriginValue {
             Auto,
             None,
             Inherit,
             Initial,
-            Exact(AzStylePerspectiveOrigin),
+            Exact(*const AzStylePerspectiveOriginBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibilityValue` struct
@@ -6390,6 +7014,34 @@ mod dll {
             Exact(AzStyleMixBlendMode),
         }
 
+        /// Re-export of rust-allocated (stack based) `StyleIsolationValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleIsolationValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleIsolation),
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleImageRenderingValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzStyleImageRenderingValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(AzStyleImageRendering),
+        }
+
         /// Re-export of rust-allocated (stack based) `ButtonOnClick` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -6852,6 +7504,14 @@ mod dll {
             Tick(AzSystemTickDiff),
         }
 
+        /// Background thread that has been created, but not started yet. Unlike `CallbackInfo::start_thread`, the data is moved back to the main thread once the thread function returns and passed to an `on_finish` callback. Start it with `CallbackInfo::start_pending_thread`, which consumes it - a thread that is never started has to be deleted.
+        #[repr(C)]
+        #[derive(Clone)]
+        pub struct AzPendingThread {
+            pub data: AzRefAny,
+            pub thread_fn: AzThreadFnType,
+        }
+
         /// Re-export of rust-allocated (stack based) `ThreadSendMsg` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -6873,6 +7533,18 @@ mod dll {
             pub callback: AzWriteBackCallback,
         }
 
+        /// Selected range of a text. `start` is where the selection was started, `end` is where the caret is (before `start` if the text was selected backwards)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        #[derive(Default)]
+        pub struct AzTextSelection {
+            pub start: AzTextCursor,
+            pub end: AzTextCursor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<LogicalRect>`
         #[repr(C)]
         pub struct AzLogicalRectVec {
@@ -6999,6 +7671,24 @@ mod dll {
             pub destructor: AzStyleBackgroundSizeVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<GridTrack>`
+        #[repr(C)]
+        pub struct AzGridTrackVec {
+            pub(crate) ptr: *const AzGridTrack,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzGridTrackVecDestructor,
+        }
+
+        /// Wrapper over a Rust-allocated `Vec<CssPropertyType>`
+        #[repr(C)]
+        pub struct AzCssPropertyTypeVec {
+            pub(crate) ptr: *const AzCssPropertyType,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzCssPropertyTypeVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `SvgVertex`
         #[repr(C)]
         pub struct AzSvgVertexVec {
@@ -7422,6 +8112,17 @@ mod dll {
             Some(AzMenuCallback),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionNormalizedLinearColorStop` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionNormalizedLinearColorStop {
+            None,
+            Some(AzNormalizedLinearColorStop),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionPositionInfo` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7641,18 +8342,29 @@ mod dll {
         #[derive(Copy)]
         pub enum AzOptionSvgDashPattern {
             None,
-            Some(AzSvgDashPattern),
+            Some(AzSvgDashPattern),
+        }
+
+        /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionLogicalPosition {
+            None,
+            Some(AzLogicalPosition),
         }
 
-        /// Re-export of rust-allocated (stack based) `OptionLogicalPosition` struct
+        /// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
         #[repr(C, u8)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
         #[derive(Copy)]
-        pub enum AzOptionLogicalPosition {
+        pub enum AzOptionLogicalRect {
             None,
-            Some(AzLogicalPosition),
+            Some(AzLogicalRect),
         }
 
         /// Re-export of rust-allocated (stack based) `OptionPhysicalPositionI32` struct
@@ -7690,6 +8402,17 @@ mod dll {
             Some(AzLogicalSize),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionImeCursorRange` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        #[derive(Copy)]
+        pub enum AzOptionImeCursorRange {
+            None,
+            Some(AzImeCursorRange),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionVirtualKeyCode` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -7842,6 +8565,7 @@ mod dll {
             pub enable_logging_on_panic: bool,
             pub enable_tab_navigation: bool,
             pub system_callbacks: AzSystemCallbacks,
+            pub redraw_behavior: AzRedrawBehavior,
         }
 
         /// Small (16x16x4) window icon, usually shown in the window titlebar
@@ -7884,6 +8608,18 @@ mod dll {
             pub rgba_bytes: AzU8Vec,
         }
 
+        /// Accelerator + the callback to invoke when it is pressed
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzAcceleratorCallback {
+            pub id: AzAcceleratorId,
+            pub accelerator: AzAccelerator,
+            pub data: AzRefAny,
+            pub callback: AzCallback,
+        }
+
         /// Minimum / maximum / current size of the window in logical dimensions
         #[repr(C)]
         #[derive(Debug)]
@@ -7898,18 +8634,6 @@ mod dll {
             pub max_dimensions: AzOptionLogicalSize,
         }
 
-        /// Current keyboard state, stores what keys / characters have been pressed
-        #[repr(C)]
-        #[derive(Debug)]
-        #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        pub struct AzKeyboardState {
-            pub current_char: AzOptionChar,
-            pub current_virtual_keycode: AzOptionVirtualKeyCode,
-            pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
-            pub pressed_scancodes: AzScanCodeVec,
-        }
-
         /// Current mouse / cursor state
         #[repr(C)]
         #[derive(Debug)]
@@ -8063,6 +8787,29 @@ mod dll {
             Focus,
         }
 
+        /// One `(feature: value)` or `(feature < value)` test of a `MediaCondition`
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzMediaFeature {
+            MinWidth(AzPixelValue),
+            MaxWidth(AzPixelValue),
+            MinHeight(AzPixelValue),
+            MaxHeight(AzPixelValue),
+            WidthLessThan(AzPixelValue),
+            WidthGreaterThan(AzPixelValue),
+            HeightLessThan(AzPixelValue),
+            HeightGreaterThan(AzPixelValue),
+            Orientation(AzMediaOrientation),
+            PrefersColorScheme(AzMediaColorScheme),
+            Resolution(AzFloatValue),
+            MinResolution(AzFloatValue),
+            MaxResolution(AzFloatValue),
+            ResolutionLessThan(AzFloatValue),
+            ResolutionGreaterThan(AzFloatValue),
+        }
+
         /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8090,6 +8837,7 @@ mod dll {
             pub parent_rect_height: f32,
             pub current_rect_width: f32,
             pub current_rect_height: f32,
+            pub em_size: f32,
         }
 
         /// Re-export of rust-allocated (stack based) `StyleFilter` struct
@@ -8110,6 +8858,34 @@ mod dll {
             Composite(AzStyleCompositeFilter),
         }
 
+        /// Argument of a `repeat()` track
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzGridTrackRepeat {
+            pub count: u32,
+            pub tracks: AzGridTrackVec,
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumns` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzLayoutGridTemplateColumns {
+            pub inner: AzGridTrackVec,
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutGridTemplateRows` struct
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzLayoutGridTemplateRows {
+            pub inner: AzGridTrackVec,
+        }
+
         /// Re-export of rust-allocated (stack based) `LinearGradient` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -8176,6 +8952,73 @@ mod dll {
             Perspective(AzPixelValue),
         }
 
+        /// Represents a `transition-property` attribute: which properties are animated when they change - default: `All`
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleTransitionProperty {
+            All,
+            None,
+            List(AzCssPropertyTypeVec),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzLayoutGridTemplateColumnsBoxed {
+            pub run_destructor: bool,
+            pub value: AzLayoutGridTemplateColumns,
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumnsValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzLayoutGridTemplateColumnsValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(*const AzLayoutGridTemplateColumnsBoxed),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzLayoutGridTemplateRowsBoxed {
+            pub run_destructor: bool,
+            pub value: AzLayoutGridTemplateRows,
+        }
+
+        /// Re-export of rust-allocated (stack based) `LayoutGridTemplateRowsValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzLayoutGridTemplateRowsValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(*const AzLayoutGridTemplateRowsBoxed),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleBackgroundPositionVecBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleBackgroundPositionVec,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleBackgroundPositionVecValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8186,7 +9029,17 @@ mod dll {
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBackgroundPositionVec),
+            Exact(*const AzStyleBackgroundPositionVecBoxed),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleBackgroundRepeatVecBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleBackgroundRepeatVec,
         }
 
         /// Re-export of rust-allocated (stack based) `StyleBackgroundRepeatVecValue` struct
@@ -8199,7 +9052,17 @@ mod dll {
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBackgroundRepeatVec),
+            Exact(*const AzStyleBackgroundRepeatVecBoxed),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleBackgroundSizeVecBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleBackgroundSizeVec,
         }
 
         /// Re-export of rust-allocated (stack based) `StyleBackgroundSizeVecValue` struct
@@ -8212,7 +9075,30 @@ mod dll {
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBackgroundSizeVec),
+            Exact(*const AzStyleBackgroundSizeVecBoxed),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleTransitionPropertyBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleTransitionProperty,
+        }
+
+        /// Re-export of rust-allocated (stack based) `StyleTransitionPropertyValue` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzStyleTransitionPropertyValue {
+            Auto,
+            None,
+            Inherit,
+            Initial,
+            Exact(*const AzStyleTransitionPropertyBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `CheckBoxStateWrapper` struct
@@ -8527,6 +9413,15 @@ mod dll {
             pub destructor: AzTessellatedColoredSvgNodeVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<AcceleratorCallback>`
+        #[repr(C)]
+        pub struct AzAcceleratorCallbackVec {
+            pub(crate) ptr: *const AzAcceleratorCallback,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzAcceleratorCallbackVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
         #[repr(C)]
         pub struct AzStyleTransformVec {
@@ -8536,6 +9431,15 @@ mod dll {
             pub destructor: AzStyleTransformVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<MediaFeature>`
+        #[repr(C)]
+        pub struct AzMediaFeatureVec {
+            pub(crate) ptr: *const AzMediaFeature,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzMediaFeatureVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `VertexAttribute`
         #[repr(C)]
         pub struct AzSvgPathElementVec {
@@ -8612,16 +9516,6 @@ mod dll {
             Some(AzMouseState),
         }
 
-        /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
-        #[repr(C, u8)]
-        #[derive(Debug)]
-        #[derive(Clone)]
-        #[derive(PartialEq, PartialOrd)]
-        pub enum AzOptionKeyboardState {
-            None,
-            Some(AzKeyboardState),
-        }
-
         /// Re-export of rust-allocated (stack based) `OptionStringVec` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8754,6 +9648,16 @@ mod dll {
             pub pos: AzSvgParseErrorPosition,
         }
 
+        /// Text that is currently being composed by the IME (usually rendered underlined)
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzImePreedit {
+            pub text: AzString,
+            pub cursor_range: AzOptionImeCursorRange,
+        }
+
         /// Window configuration specific to Win32
         #[repr(C)]
         #[derive(Debug)]
@@ -8945,6 +9849,29 @@ mod dll {
             Children,
         }
 
+        /// Media features combined with `and`, i.e. `(min-width: 600px) and (orientation: portrait)`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzMediaCondition {
+            pub features: AzMediaFeatureVec,
+        }
+
+        /// Single track size of a `grid-template-columns` or `grid-template-rows` attribute
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzGridTrack {
+            Px(AzPixelValue),
+            Fraction(AzFloatValue),
+            MinContent,
+            MaxContent,
+            Auto,
+            Repeat(AzGridTrackRepeat),
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -8995,6 +9922,16 @@ mod dll {
             Ref(AzFontRef),
         }
 
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzScrollbarStyleBoxed {
+            pub run_destructor: bool,
+            pub value: AzScrollbarStyle,
+        }
+
         /// Re-export of rust-allocated (stack based) `ScrollbarStyleValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9005,7 +9942,17 @@ mod dll {
             None,
             Inherit,
             Initial,
-            Exact(AzScrollbarStyle),
+            Exact(*const AzScrollbarStyleBoxed),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleTransformVecBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleTransformVec,
         }
 
         /// Re-export of rust-allocated (stack based) `StyleTransformVecValue` struct
@@ -9018,7 +9965,17 @@ mod dll {
             None,
             Inherit,
             Initial,
-            Exact(AzStyleTransformVec),
+            Exact(*const AzStyleTransformVecBoxed),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleFilterVecBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleFilterVec,
         }
 
         /// Re-export of rust-allocated (stack based) `StyleFilterVecValue` struct
@@ -9031,7 +9988,7 @@ mod dll {
             None,
             Inherit,
             Initial,
-            Exact(AzStyleFilterVec),
+            Exact(*const AzStyleFilterVecBoxed),
         }
 
         /// Re-export of rust-allocated (stack based) `FileInputState` struct
@@ -9390,6 +10347,15 @@ mod dll {
             pub destructor: AzStyleBackgroundContentVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<MediaCondition>`
+        #[repr(C)]
+        pub struct AzMediaConditionVec {
+            pub(crate) ptr: *const AzMediaCondition,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzMediaConditionVecDestructor,
+        }
+
         /// Wrapper over a Rust-allocated `Vec<SvgPath>`
         #[repr(C)]
         pub struct AzSvgPathVec {
@@ -9515,6 +10481,18 @@ mod dll {
             InvalidCharacterData,
         }
 
+        /// Event of the input method editor (IME), used to compose text that can't be typed directly (CJK characters, emojis, etc.)
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzImeEvent {
+            Enabled,
+            Disabled,
+            Preedit(AzImePreedit),
+            Commit(AzString),
+        }
+
         /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -9565,6 +10543,25 @@ mod dll {
             pub selectors: AzCssPathSelectorVec,
         }
 
+        /// Condition of an `@media` group, i.e. `(max-width: 600px), (orientation: portrait)`: the group applies if any of the conditions matches
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzMediaQuery {
+            pub conditions: AzMediaConditionVec,
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleBackgroundContentVecBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleBackgroundContentVec,
+        }
+
         /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9575,7 +10572,17 @@ mod dll {
             None,
             Inherit,
             Initial,
-            Exact(AzStyleBackgroundContentVec),
+            Exact(*const AzStyleBackgroundContentVecBoxed),
+        }
+
+        /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzStyleFontFamilyVecBoxed {
+            pub run_destructor: bool,
+            pub value: AzStyleFontFamilyVec,
         }
 
         /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
@@ -9588,7 +10595,7 @@ mod dll {
             None,
             Inherit,
             Initial,
-            Exact(AzStyleFontFamilyVec),
+            Exact(*const AzStyleFontFamilyVecBoxed),
         }
 
         /// Parsed CSS key-value pair
@@ -9601,6 +10608,8 @@ mod dll {
             FontSize(AzStyleFontSizeValue),
             FontFamily(AzStyleFontFamilyVecValue),
             TextAlign(AzStyleTextAlignValue),
+            VerticalAlign(AzStyleVerticalAlignValue),
+            Direction(AzStyleDirectionValue),
             LetterSpacing(AzStyleLetterSpacingValue),
             LineHeight(AzStyleLineHeightValue),
             WordSpacing(AzStyleWordSpacingValue),
@@ -9627,6 +10636,10 @@ mod dll {
             JustifyContent(AzLayoutJustifyContentValue),
             AlignItems(AzLayoutAlignItemsValue),
             AlignContent(AzLayoutAlignContentValue),
+            GridTemplateColumns(AzLayoutGridTemplateColumnsValue),
+            GridTemplateRows(AzLayoutGridTemplateRowsValue),
+            GridColumn(AzLayoutGridColumnValue),
+            GridRow(AzLayoutGridRowValue),
             BackgroundContent(AzStyleBackgroundContentVecValue),
             BackgroundPosition(AzStyleBackgroundPositionVecValue),
             BackgroundSize(AzStyleBackgroundSizeVecValue),
@@ -9668,9 +10681,18 @@ mod dll {
             PerspectiveOrigin(AzStylePerspectiveOriginValue),
             BackfaceVisibility(AzStyleBackfaceVisibilityValue),
             MixBlendMode(AzStyleMixBlendModeValue),
+            Isolation(AzStyleIsolationValue),
+            ImageRendering(AzStyleImageRenderingValue),
             Filter(AzStyleFilterVecValue),
             BackdropFilter(AzStyleFilterVecValue),
             TextShadow(AzStyleBoxShadowValue),
+            TransitionProperty(AzStyleTransitionPropertyValue),
+            TransitionDuration(AzStyleTransitionDurationValue),
+            TransitionTimingFunction(AzStyleTransitionTimingFunctionValue),
+            TransitionDelay(AzStyleTransitionDelayValue),
+            CaretColor(AzStyleCaretColorValue),
+            SelectionColor(AzStyleSelectionColorValue),
+            SelectionBackground(AzStyleSelectionBackgroundValue),
         }
 
         /// Re-export of rust-allocated (stack based) `FileInputStateWrapper` struct
@@ -9907,6 +10929,16 @@ mod dll {
             pub destructor: AzSvgSimpleNodeVecDestructor,
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionMediaQuery` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionMediaQuery {
+            None,
+            Some(AzMediaQuery),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -9917,14 +10949,37 @@ mod dll {
             Some(AzCssProperty),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionImeEvent` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionImeEvent {
+            None,
+            Some(AzImeEvent),
+        }
+
         /// Re-export of rust-allocated (stack based) `XmlTextError` struct
         #[repr(C)]
         #[derive(Debug)]
         #[derive(Clone)]
         #[derive(PartialEq, PartialOrd)]
-        pub struct AzXmlTextError {
-            pub stream_error: AzXmlStreamError,
-            pub pos: AzSvgParseErrorPosition,
+        pub struct AzXmlTextError {
+            pub stream_error: AzXmlStreamError,
+            pub pos: AzSvgParseErrorPosition,
+        }
+
+        /// Current keyboard state, stores what keys / characters have been pressed
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzKeyboardState {
+            pub current_char: AzOptionChar,
+            pub current_virtual_keycode: AzOptionVirtualKeyCode,
+            pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
+            pub pressed_scancodes: AzScanCodeVec,
+            pub current_ime_event: AzOptionImeEvent,
         }
 
         /// Platform-specific window configuration, i.e. WM options that are not cross-platform
@@ -9961,6 +11016,7 @@ mod dll {
             pub background_color: AzColorU,
             pub layout_callback: AzLayoutCallback,
             pub close_callback: AzOptionCallback,
+            pub accelerators: AzAcceleratorCallbackVec,
         }
 
         /// Re-export of rust-allocated (stack based) `CallbackInfo` struct
@@ -9982,6 +11038,7 @@ mod dll {
             pub threads: *mut c_void,
             pub timers_removed: *mut c_void,
             pub threads_removed: *mut c_void,
+            pub thread_msgs_sent: *mut c_void,
             pub current_window_handle: *const AzRawWindowHandle,
             pub new_windows: *mut c_void,
             pub system_callbacks: *const AzSystemCallbacks,
@@ -9991,6 +11048,8 @@ mod dll {
             pub images_changed_in_callbacks: *mut c_void,
             pub image_masks_changed_in_callbacks: *mut c_void,
             pub css_properties_changed_in_callbacks: *mut c_void,
+            pub stylesheet_changed_in_callbacks: *mut c_void,
+            pub context_menu_changed_in_callbacks: *mut c_void,
             pub current_scroll_states: *const c_void,
             pub nodes_scrolled_in_callback: *mut c_void,
             pub hit_dom_node: AzDomNodeId,
@@ -10075,6 +11134,16 @@ mod dll {
             pub default_value: AzCssProperty,
         }
 
+        /// Value of a CSS variable (`--name`) that is set at runtime
+        #[repr(C)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub struct AzCssVariable {
+            pub name: AzString,
+            pub value: AzCssProperty,
+        }
+
         /// Re-export of rust-allocated (stack based) `Node` struct
         #[repr(C)]
         #[derive(Debug)]
@@ -10121,6 +11190,15 @@ mod dll {
             pub destructor: AzNodeDataInlineCssPropertyVecDestructor,
         }
 
+        /// Wrapper over a Rust-allocated `Vec<CssVariable>`
+        #[repr(C)]
+        pub struct AzCssVariableVec {
+            pub(crate) ptr: *const AzCssVariable,
+            pub len: usize,
+            pub cap: usize,
+            pub destructor: AzCssVariableVecDestructor,
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -10131,6 +11209,16 @@ mod dll {
             Some(AzWindowState),
         }
 
+        /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+        #[repr(C, u8)]
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq, PartialOrd)]
+        pub enum AzOptionKeyboardState {
+            None,
+            Some(AzKeyboardState),
+        }
+
         /// Re-export of rust-allocated (stack based) `OptionInlineText` struct
         #[repr(C, u8)]
         #[derive(Debug)]
@@ -10375,6 +11463,7 @@ mod dll {
         pub struct AzCssRuleBlock {
             pub path: AzCssPath,
             pub declarations: AzCssDeclarationVec,
+            pub media: AzOptionMediaQuery,
         }
 
         /// Re-export of rust-allocated (stack based) `TabContent` struct
@@ -10535,8 +11624,12 @@ mod dll {
         #[derive(PartialEq, PartialOrd)]
         pub struct AzCss {
             pub stylesheets: AzStylesheetVec,
+            pub variables: AzCssVariableVec,
         }
 
+        /// Rust-allocated `TestHarness`, only accessible via `AzTestHarnessPtr`
+        #[repr(C)] pub struct AzTestHarness { _opaque: [u8; 0] }
+        pub type AzTestHarnessPtr = *mut AzTestHarness;
     }
 
     #[cfg(feature = "link-static")]
@@ -10549,16 +11642,51 @@ mod dll {
         pub(crate) fn AzApp_new(data: AzRefAny, config: AzAppConfig) -> AzApp { unsafe { transmute(azul::AzApp_new(transmute(data), transmute(config))) } }
         pub(crate) fn AzApp_addWindow(app: &mut AzApp, window: AzWindowCreateOptions) { unsafe { transmute(azul::AzApp_addWindow(transmute(app), transmute(window))) } }
         pub(crate) fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { unsafe { transmute(azul::AzApp_addImage(transmute(app), transmute(id), transmute(image))) } }
+        pub(crate) fn AzApp_addImageRaw(app: &mut AzApp, id: AzString, data: *const u8, len: usize, width: u32, height: u32, format: AzRawImageFormat) -> bool { unsafe { transmute(azul::AzApp_addImageRaw(transmute(app), transmute(id), transmute(data), transmute(len), transmute(width), transmute(height), transmute(format))) } }
+        pub(crate) fn AzApp_updateImageRaw(app: &mut AzApp, id: AzString, data: *const u8, len: usize, width: u32, height: u32, format: AzRawImageFormat) -> bool { unsafe { transmute(azul::AzApp_updateImageRaw(transmute(app), transmute(id), transmute(data), transmute(len), transmute(width), transmute(height), transmute(format))) } }
+        pub(crate) fn AzApp_deleteImage(app: &mut AzApp, id: AzString) { unsafe { transmute(azul::AzApp_deleteImage(transmute(app), transmute(id))) } }
         pub(crate) fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { unsafe { transmute(azul::AzApp_getMonitors(transmute(app))) } }
+        pub(crate) fn AzApp_getSystemTheme(app: &AzApp) -> AzOptionWindowTheme { unsafe { transmute(azul::AzApp_getSystemTheme(transmute(app))) } }
         pub(crate) fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) { unsafe { transmute(azul::AzApp_run(transmute(app), transmute(window))) } }
+        pub(crate) fn AzApp_runReturnCode(app: &AzApp, window: AzWindowCreateOptions) -> i32 { unsafe { transmute(azul::AzApp_runReturnCode(transmute(app), transmute(window))) } }
+        pub(crate) fn AzApp_createTestHarness(app: &AzApp, window: AzWindowCreateOptions) -> AzTestHarnessPtr { unsafe { transmute(azul::AzApp_createTestHarness(transmute(app), transmute(window))) } }
         pub(crate) fn AzApp_delete(object: &mut AzApp) { unsafe { transmute(azul::AzApp_delete(transmute(object))) } }
         pub(crate) fn AzApp_deepCopy(object: &AzApp) -> AzApp { unsafe { transmute(azul::AzApp_deepCopy(transmute(object))) } }
         pub(crate) fn AzAppConfig_new(layout_solver: AzLayoutSolver) -> AzAppConfig { unsafe { transmute(azul::AzAppConfig_new(transmute(layout_solver))) } }
+        pub(crate) fn AzAppConfig_setRedrawBehavior(appconfig: &mut AzAppConfig, behavior: AzRedrawBehavior) { unsafe { transmute(azul::AzAppConfig_setRedrawBehavior(transmute(appconfig), transmute(behavior))) } }
         pub(crate) fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks { unsafe { transmute(azul::AzSystemCallbacks_libraryInternal()) } }
+        pub(crate) fn AzApi_versionMajor() -> u32 { unsafe { transmute(azul::AzApi_versionMajor()) } }
+        pub(crate) fn AzApi_versionMinor() -> u32 { unsafe { transmute(azul::AzApi_versionMinor()) } }
+        pub(crate) fn AzApi_versionPatch() -> u32 { unsafe { transmute(azul::AzApi_versionPatch()) } }
+        pub(crate) fn AzApi_versionString() -> *const u8 { unsafe { transmute(azul::AzApi_versionString()) } }
+        pub(crate) fn AzApi_abiHash() -> u64 { unsafe { transmute(azul::AzApi_abiHash()) } }
+        pub(crate) fn AzApi_hasFeature(name_ptr: *const u8, name_len: usize) -> bool { unsafe { transmute(azul::AzApi_hasFeature(transmute(name_ptr), transmute(name_len))) } }
+        pub(crate) fn AzApi_lastError() -> AzOptionString { unsafe { transmute(azul::AzApi_lastError()) } }
+        pub(crate) fn AzApi_clearLastError() -> bool { unsafe { transmute(azul::AzApi_clearLastError()) } }
+        pub(crate) fn AzTestHarness_delete(ptr: AzTestHarnessPtr) { unsafe { transmute(azul::AzTestHarness_delete(transmute(ptr))) } }
+        pub(crate) fn AzTestHarness_diffSnapshots(expected: AzString, actual: AzString) -> AzOptionString { unsafe { transmute(azul::AzTestHarness_diffSnapshots(transmute(expected), transmute(actual))) } }
+        pub(crate) fn AzTestHarness_resize(testharness: AzTestHarnessPtr, width: f32, height: f32) { unsafe { transmute(azul::AzTestHarness_resize(transmute(testharness), transmute(width), transmute(height))) } }
+        pub(crate) fn AzTestHarness_mouseMove(testharness: AzTestHarnessPtr, x: f32, y: f32) { unsafe { transmute(azul::AzTestHarness_mouseMove(transmute(testharness), transmute(x), transmute(y))) } }
+        pub(crate) fn AzTestHarness_mouseDown(testharness: AzTestHarnessPtr, button: AzContextMenuMouseButton) { unsafe { transmute(azul::AzTestHarness_mouseDown(transmute(testharness), transmute(button))) } }
+        pub(crate) fn AzTestHarness_mouseUp(testharness: AzTestHarnessPtr, button: AzContextMenuMouseButton) { unsafe { transmute(azul::AzTestHarness_mouseUp(transmute(testharness), transmute(button))) } }
+        pub(crate) fn AzTestHarness_keyChar(testharness: AzTestHarnessPtr, c: u32) { unsafe { transmute(azul::AzTestHarness_keyChar(transmute(testharness), transmute(c))) } }
+        pub(crate) fn AzTestHarness_keyDown(testharness: AzTestHarnessPtr, key: AzVirtualKeyCode) { unsafe { transmute(azul::AzTestHarness_keyDown(transmute(testharness), transmute(key))) } }
+        pub(crate) fn AzTestHarness_keyUp(testharness: AzTestHarnessPtr, key: AzVirtualKeyCode) { unsafe { transmute(azul::AzTestHarness_keyUp(transmute(testharness), transmute(key))) } }
+        pub(crate) fn AzTestHarness_getHitNodeCount(testharness: AzTestHarnessPtr) -> usize { unsafe { transmute(azul::AzTestHarness_getHitNodeCount(transmute(testharness))) } }
+        pub(crate) fn AzTestHarness_getHitNode(testharness: AzTestHarnessPtr, index: usize) -> AzOptionDomNodeId { unsafe { transmute(azul::AzTestHarness_getHitNode(transmute(testharness), transmute(index))) } }
+        pub(crate) fn AzTestHarness_getNodeRect(testharness: AzTestHarnessPtr, node: AzDomNodeId) -> AzOptionLogicalRect { unsafe { transmute(azul::AzTestHarness_getNodeRect(transmute(testharness), transmute(node))) } }
+        pub(crate) fn AzTestHarness_getNodeText(testharness: AzTestHarnessPtr, node: AzDomNodeId) -> AzOptionString { unsafe { transmute(azul::AzTestHarness_getNodeText(transmute(testharness), transmute(node))) } }
+        pub(crate) fn AzTestHarness_advanceTime(testharness: AzTestHarnessPtr, millis: u64) { unsafe { transmute(azul::AzTestHarness_advanceTime(transmute(testharness), transmute(millis))) } }
+        pub(crate) fn AzTestHarness_getFramesRendered(testharness: AzTestHarnessPtr) -> usize { unsafe { transmute(azul::AzTestHarness_getFramesRendered(transmute(testharness))) } }
+        pub(crate) fn AzTestHarness_getData(testharness: AzTestHarnessPtr) -> AzRefAny { unsafe { transmute(azul::AzTestHarness_getData(transmute(testharness))) } }
+        pub(crate) fn AzTestHarness_serializeLayout(testharness: AzTestHarnessPtr, dom: AzDomId, properties: AzCssPropertyTypeVec) -> AzOptionString { unsafe { transmute(azul::AzTestHarness_serializeLayout(transmute(testharness), transmute(dom), transmute(properties))) } }
         pub(crate) fn AzWindowCreateOptions_new(layout_callback: AzLayoutCallbackType) -> AzWindowCreateOptions { unsafe { transmute(azul::AzWindowCreateOptions_new(transmute(layout_callback))) } }
+        pub(crate) fn AzWindowCreateOptions_setLayoutCallback(windowcreateoptions: &mut AzWindowCreateOptions, layout_callback: AzLayoutCallbackType) { unsafe { transmute(azul::AzWindowCreateOptions_setLayoutCallback(transmute(windowcreateoptions), transmute(layout_callback))) } }
+        pub(crate) fn AzWindowCreateOptions_addAccelerator(windowcreateoptions: &mut AzWindowCreateOptions, accelerator: AzAccelerator, data: AzRefAny, callback: AzCallbackType) -> AzAcceleratorId { unsafe { transmute(azul::AzWindowCreateOptions_addAccelerator(transmute(windowcreateoptions), transmute(accelerator), transmute(data), transmute(callback))) } }
         pub(crate) fn AzLogicalPosition_new(x: f32, y: f32) -> AzLogicalPosition { unsafe { transmute(azul::AzLogicalPosition_new(transmute(x), transmute(y))) } }
         pub(crate) fn AzLogicalPosition_zero() -> AzLogicalPosition { unsafe { transmute(azul::AzLogicalPosition_zero()) } }
         pub(crate) fn AzLogicalSize_toPhysical(logicalsize: &AzLogicalSize, hidpi_factor: f32) -> AzPhysicalSizeU32 { unsafe { transmute(azul::AzLogicalSize_toPhysical(transmute(logicalsize), transmute(hidpi_factor))) } }
+        pub(crate) fn AzAccelerator_new(modifiers: AzModifiers, key: AzVirtualKeyCode) -> AzAccelerator { unsafe { transmute(azul::AzAccelerator_new(transmute(modifiers), transmute(key))) } }
         pub(crate) fn AzWindowSize_getHidpiFactor(windowsize: &AzWindowSize) -> f32 { unsafe { transmute(azul::AzWindowSize_getHidpiFactor(transmute(windowsize))) } }
         pub(crate) fn AzKeyboardState_shiftDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_shiftDown(transmute(keyboardstate))) } }
         pub(crate) fn AzKeyboardState_ctrlDown(keyboardstate: &AzKeyboardState) -> bool { unsafe { transmute(azul::AzKeyboardState_ctrlDown(transmute(keyboardstate))) } }
@@ -10575,12 +11703,18 @@ mod dll {
         pub(crate) fn AzCallbackInfo_getCurrentWindowState(callbackinfo: &AzCallbackInfo) -> AzWindowState { unsafe { transmute(azul::AzCallbackInfo_getCurrentWindowState(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCurrentKeyboardState(callbackinfo: &AzCallbackInfo) -> AzKeyboardState { unsafe { transmute(azul::AzCallbackInfo_getCurrentKeyboardState(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCurrentMouseState(callbackinfo: &AzCallbackInfo) -> AzMouseState { unsafe { transmute(azul::AzCallbackInfo_getCurrentMouseState(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_getImeEvent(callbackinfo: &AzCallbackInfo) -> AzOptionImeEvent { unsafe { transmute(azul::AzCallbackInfo_getImeEvent(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_getHoveredFiles(callbackinfo: &AzCallbackInfo) -> AzStringVec { unsafe { transmute(azul::AzCallbackInfo_getHoveredFiles(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_getDroppedFiles(callbackinfo: &AzCallbackInfo) -> AzStringVec { unsafe { transmute(azul::AzCallbackInfo_getDroppedFiles(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_getDropPosition(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { unsafe { transmute(azul::AzCallbackInfo_getDropPosition(transmute(callbackinfo))) } }
+        pub(crate) fn AzCallbackInfo_getDropTarget(callbackinfo: &AzCallbackInfo) -> AzOptionDomNodeId { unsafe { transmute(azul::AzCallbackInfo_getDropTarget(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getPreviousWindowState(callbackinfo: &AzCallbackInfo) -> AzOptionWindowState { unsafe { transmute(azul::AzCallbackInfo_getPreviousWindowState(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getPreviousKeyboardState(callbackinfo: &AzCallbackInfo) -> AzOptionKeyboardState { unsafe { transmute(azul::AzCallbackInfo_getPreviousKeyboardState(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getPreviousMouseState(callbackinfo: &AzCallbackInfo) -> AzOptionMouseState { unsafe { transmute(azul::AzCallbackInfo_getPreviousMouseState(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getCurrentWindowHandle(callbackinfo: &AzCallbackInfo) -> AzRawWindowHandle { unsafe { transmute(azul::AzCallbackInfo_getCurrentWindowHandle(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getGlContext(callbackinfo: &AzCallbackInfo) -> AzOptionGl { unsafe { transmute(azul::AzCallbackInfo_getGlContext(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_getScrollPosition(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalPosition { unsafe { transmute(azul::AzCallbackInfo_getScrollPosition(transmute(callbackinfo), transmute(node_id))) } }
+        pub(crate) fn AzCallbackInfo_getScrollExtent(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalSize { unsafe { transmute(azul::AzCallbackInfo_getScrollExtent(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_getDataset(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionRefAny { unsafe { transmute(azul::AzCallbackInfo_getDataset(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_getNodeIdOfRootDataset(callbackinfo: &mut AzCallbackInfo, dataset: AzRefAny) -> AzOptionDomNodeId { unsafe { transmute(azul::AzCallbackInfo_getNodeIdOfRootDataset(transmute(callbackinfo), transmute(dataset))) } }
         pub(crate) fn AzCallbackInfo_getStringContents(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionString { unsafe { transmute(azul::AzCallbackInfo_getStringContents(transmute(callbackinfo), transmute(node_id))) } }
@@ -10597,16 +11731,26 @@ mod dll {
         pub(crate) fn AzCallbackInfo_getNodePosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionPositionInfo { unsafe { transmute(azul::AzCallbackInfo_getNodePosition(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_getNodeSize(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalSize { unsafe { transmute(azul::AzCallbackInfo_getNodeSize(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_getComputedCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, property_type: AzCssPropertyType) -> AzOptionCssProperty { unsafe { transmute(azul::AzCallbackInfo_getComputedCssProperty(transmute(callbackinfo), transmute(node_id), transmute(property_type))) } }
+        pub(crate) fn AzCallbackInfo_getComputedCssProperties(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzCssPropertyVec { unsafe { transmute(azul::AzCallbackInfo_getComputedCssProperties(transmute(callbackinfo), transmute(node_id))) } }
         pub(crate) fn AzCallbackInfo_setWindowState(callbackinfo: &mut AzCallbackInfo, new_state: AzWindowState) { unsafe { transmute(azul::AzCallbackInfo_setWindowState(transmute(callbackinfo), transmute(new_state))) } }
+        pub(crate) fn AzCallbackInfo_setImePosition(callbackinfo: &mut AzCallbackInfo, position: AzLogicalPosition) { unsafe { transmute(azul::AzCallbackInfo_setImePosition(transmute(callbackinfo), transmute(position))) } }
         pub(crate) fn AzCallbackInfo_setFocus(callbackinfo: &mut AzCallbackInfo, target: AzFocusTarget) { unsafe { transmute(azul::AzCallbackInfo_setFocus(transmute(callbackinfo), transmute(target))) } }
         pub(crate) fn AzCallbackInfo_setCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_property: AzCssProperty) { unsafe { transmute(azul::AzCallbackInfo_setCssProperty(transmute(callbackinfo), transmute(node_id), transmute(new_property))) } }
-        pub(crate) fn AzCallbackInfo_setScrollPosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) { unsafe { transmute(azul::AzCallbackInfo_setScrollPosition(transmute(callbackinfo), transmute(node_id), transmute(scroll_position))) } }
+        pub(crate) fn AzCallbackInfo_setCss(callbackinfo: &mut AzCallbackInfo, css: AzCss) { unsafe { transmute(azul::AzCallbackInfo_setCss(transmute(callbackinfo), transmute(css))) } }
+        pub(crate) fn AzCallbackInfo_setScrollPosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) -> bool { unsafe { transmute(azul::AzCallbackInfo_setScrollPosition(transmute(callbackinfo), transmute(node_id), transmute(scroll_position))) } }
         pub(crate) fn AzCallbackInfo_setStringContents(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, string: AzString) { unsafe { transmute(azul::AzCallbackInfo_setStringContents(transmute(callbackinfo), transmute(node_id), transmute(string))) } }
         pub(crate) fn AzCallbackInfo_addImage(callbackinfo: &mut AzCallbackInfo, id: AzString, image: AzImageRef) { unsafe { transmute(azul::AzCallbackInfo_addImage(transmute(callbackinfo), transmute(id), transmute(image))) } }
+        pub(crate) fn AzCallbackInfo_addImageRaw(callbackinfo: &mut AzCallbackInfo, id: AzString, data: *const u8, len: usize, width: u32, height: u32, format: AzRawImageFormat) -> bool { unsafe { transmute(azul::AzCallbackInfo_addImageRaw(transmute(callbackinfo), transmute(id), transmute(data), transmute(len), transmute(width), transmute(height), transmute(format))) } }
+        pub(crate) fn AzCallbackInfo_updateImageRaw(callbackinfo: &mut AzCallbackInfo, id: AzString, data: *const u8, len: usize, width: u32, height: u32, format: AzRawImageFormat) -> bool { unsafe { transmute(azul::AzCallbackInfo_updateImageRaw(transmute(callbackinfo), transmute(id), transmute(data), transmute(len), transmute(width), transmute(height), transmute(format))) } }
         pub(crate) fn AzCallbackInfo_hasImage(callbackinfo: &AzCallbackInfo, id: AzString) -> bool { unsafe { transmute(azul::AzCallbackInfo_hasImage(transmute(callbackinfo), transmute(id))) } }
         pub(crate) fn AzCallbackInfo_getImage(callbackinfo: &AzCallbackInfo, id: AzString) -> AzOptionImageRef { unsafe { transmute(azul::AzCallbackInfo_getImage(transmute(callbackinfo), transmute(id))) } }
         pub(crate) fn AzCallbackInfo_updateImage(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_image: AzImageRef, image_type: AzUpdateImageType) { unsafe { transmute(azul::AzCallbackInfo_updateImage(transmute(callbackinfo), transmute(node_id), transmute(new_image), transmute(image_type))) } }
         pub(crate) fn AzCallbackInfo_deleteImage(callbackinfo: &mut AzCallbackInfo, id: AzString) { unsafe { transmute(azul::AzCallbackInfo_deleteImage(transmute(callbackinfo), transmute(id))) } }
+        pub(crate) fn AzCallbackInfo_addAccelerator(callbackinfo: &mut AzCallbackInfo, accelerator: AzAccelerator, data: AzRefAny, callback: AzCallbackType) -> AzAcceleratorId { unsafe { transmute(azul::AzCallbackInfo_addAccelerator(transmute(callbackinfo), transmute(accelerator), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzCallbackInfo_replaceAccelerator(callbackinfo: &mut AzCallbackInfo, id: AzAcceleratorId, accelerator: AzAccelerator, data: AzRefAny, callback: AzCallbackType) -> bool { unsafe { transmute(azul::AzCallbackInfo_replaceAccelerator(transmute(callbackinfo), transmute(id), transmute(accelerator), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzCallbackInfo_removeAccelerator(callbackinfo: &mut AzCallbackInfo, id: AzAcceleratorId) -> bool { unsafe { transmute(azul::AzCallbackInfo_removeAccelerator(transmute(callbackinfo), transmute(id))) } }
+        pub(crate) fn AzCallbackInfo_openContextMenu(callbackinfo: &mut AzCallbackInfo, menu: AzMenu, position: AzLogicalPosition) { unsafe { transmute(azul::AzCallbackInfo_openContextMenu(transmute(callbackinfo), transmute(menu), transmute(position))) } }
+        pub(crate) fn AzCallbackInfo_closeContextMenu(callbackinfo: &mut AzCallbackInfo) { unsafe { transmute(azul::AzCallbackInfo_closeContextMenu(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_updateImageMask(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_mask: AzImageMask) { unsafe { transmute(azul::AzCallbackInfo_updateImageMask(transmute(callbackinfo), transmute(node_id), transmute(new_mask))) } }
         pub(crate) fn AzCallbackInfo_stopPropagation(callbackinfo: &mut AzCallbackInfo) { unsafe { transmute(azul::AzCallbackInfo_stopPropagation(transmute(callbackinfo))) } }
         pub(crate) fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) { unsafe { transmute(azul::AzCallbackInfo_createWindow(transmute(callbackinfo), transmute(new_window))) } }
@@ -10614,6 +11758,8 @@ mod dll {
         pub(crate) fn AzCallbackInfo_startAnimation(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, animation: AzAnimation) -> AzOptionTimerId { unsafe { transmute(azul::AzCallbackInfo_startAnimation(transmute(callbackinfo), transmute(node), transmute(animation))) } }
         pub(crate) fn AzCallbackInfo_stopTimer(callbackinfo: &mut AzCallbackInfo, timer_id: AzTimerId) -> bool { unsafe { transmute(azul::AzCallbackInfo_stopTimer(transmute(callbackinfo), transmute(timer_id))) } }
         pub(crate) fn AzCallbackInfo_startThread(callbackinfo: &mut AzCallbackInfo, thread_initialize_data: AzRefAny, writeback_data: AzRefAny, callback: AzThreadCallbackType) -> AzOptionThreadId { unsafe { transmute(azul::AzCallbackInfo_startThread(transmute(callbackinfo), transmute(thread_initialize_data), transmute(writeback_data), transmute(callback))) } }
+        pub(crate) fn AzCallbackInfo_startPendingThread(callbackinfo: &mut AzCallbackInfo, thread: AzPendingThread, on_finish: AzCallbackType) -> AzOptionThreadId { unsafe { transmute(azul::AzCallbackInfo_startPendingThread(transmute(callbackinfo), transmute(thread), transmute(on_finish))) } }
+        pub(crate) fn AzCallbackInfo_requestThreadStop(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId) -> bool { unsafe { transmute(azul::AzCallbackInfo_requestThreadStop(transmute(callbackinfo), transmute(thread_id))) } }
         pub(crate) fn AzCallbackInfo_sendThreadMsg(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId, msg: AzThreadSendMsg) -> bool { unsafe { transmute(azul::AzCallbackInfo_sendThreadMsg(transmute(callbackinfo), transmute(thread_id), transmute(msg))) } }
         pub(crate) fn AzCallbackInfo_stopThread(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId) -> bool { unsafe { transmute(azul::AzCallbackInfo_stopThread(transmute(callbackinfo), transmute(thread_id))) } }
         pub(crate) fn AzPositionInfo_isPositioned(positioninfo: &AzPositionInfo) -> bool { unsafe { transmute(azul::AzPositionInfo_isPositioned(transmute(positioninfo))) } }
@@ -10645,6 +11791,7 @@ mod dll {
         pub(crate) fn AzRefAny_newC(ptr: *const c_void, len: usize, type_id: u64, type_name: AzString, destructor: AzRefAnyDestructorType) -> AzRefAny { unsafe { transmute(azul::AzRefAny_newC(transmute(ptr), transmute(len), transmute(type_id), transmute(type_name), transmute(destructor))) } }
         pub(crate) fn AzRefAny_getTypeId(refany: &AzRefAny) -> u64 { unsafe { transmute(azul::AzRefAny_getTypeId(transmute(refany))) } }
         pub(crate) fn AzRefAny_getTypeName(refany: &AzRefAny) -> AzString { unsafe { transmute(azul::AzRefAny_getTypeName(transmute(refany))) } }
+        pub(crate) fn AzRefAny_strongCount(refany: &AzRefAny) -> usize { unsafe { transmute(azul::AzRefAny_strongCount(transmute(refany))) } }
         pub(crate) fn AzRefAny_delete(object: &mut AzRefAny) { unsafe { transmute(azul::AzRefAny_delete(transmute(object))) } }
         pub(crate) fn AzRefAny_deepCopy(object: &AzRefAny) -> AzRefAny { unsafe { transmute(azul::AzRefAny_deepCopy(transmute(object))) } }
         pub(crate) fn AzLayoutCallbackInfo_getGlContext(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzOptionGl { unsafe { transmute(azul::AzLayoutCallbackInfo_getGlContext(transmute(layoutcallbackinfo))) } }
@@ -10699,12 +11846,20 @@ mod dll {
         pub(crate) fn AzDom_withTabIndex(dom: &mut AzDom, tab_index: AzTabIndex) -> AzDom { unsafe { transmute(azul::AzDom_withTabIndex(transmute(dom), transmute(tab_index))) } }
         pub(crate) fn AzDom_setAccessibilityInfo(dom: &mut AzDom, accessibility_info: AzAccessibilityInfo) { unsafe { transmute(azul::AzDom_setAccessibilityInfo(transmute(dom), transmute(accessibility_info))) } }
         pub(crate) fn AzDom_withAccessibilityInfo(dom: &mut AzDom, accessibility_info: AzAccessibilityInfo) -> AzDom { unsafe { transmute(azul::AzDom_withAccessibilityInfo(transmute(dom), transmute(accessibility_info))) } }
+        pub(crate) fn AzDom_setAccessibilityRole(dom: &mut AzDom, role: AzAccessibilityRole) { unsafe { transmute(azul::AzDom_setAccessibilityRole(transmute(dom), transmute(role))) } }
+        pub(crate) fn AzDom_withAccessibilityRole(dom: &mut AzDom, role: AzAccessibilityRole) -> AzDom { unsafe { transmute(azul::AzDom_withAccessibilityRole(transmute(dom), transmute(role))) } }
+        pub(crate) fn AzDom_setAccessibilityLabel(dom: &mut AzDom, label: AzString) { unsafe { transmute(azul::AzDom_setAccessibilityLabel(transmute(dom), transmute(label))) } }
+        pub(crate) fn AzDom_withAccessibilityLabel(dom: &mut AzDom, label: AzString) -> AzDom { unsafe { transmute(azul::AzDom_withAccessibilityLabel(transmute(dom), transmute(label))) } }
         pub(crate) fn AzDom_setMenuBar(dom: &mut AzDom, menu_bar: AzMenu) { unsafe { transmute(azul::AzDom_setMenuBar(transmute(dom), transmute(menu_bar))) } }
         pub(crate) fn AzDom_withMenuBar(dom: &mut AzDom, menu_bar: AzMenu) -> AzDom { unsafe { transmute(azul::AzDom_withMenuBar(transmute(dom), transmute(menu_bar))) } }
         pub(crate) fn AzDom_setContextMenu(dom: &mut AzDom, context_menu: AzMenu) { unsafe { transmute(azul::AzDom_setContextMenu(transmute(dom), transmute(context_menu))) } }
         pub(crate) fn AzDom_withContextMenu(dom: &mut AzDom, context_menu: AzMenu) -> AzDom { unsafe { transmute(azul::AzDom_withContextMenu(transmute(dom), transmute(context_menu))) } }
         pub(crate) fn AzDom_hash(dom: &AzDom) -> u64 { unsafe { transmute(azul::AzDom_hash(transmute(dom))) } }
         pub(crate) fn AzDom_nodeCount(dom: &AzDom) -> usize { unsafe { transmute(azul::AzDom_nodeCount(transmute(dom))) } }
+        pub(crate) fn AzDom_getNodeType(dom: &AzDom) -> AzNodeType { unsafe { transmute(azul::AzDom_getNodeType(transmute(dom))) } }
+        pub(crate) fn AzDom_childrenCount(dom: &AzDom) -> usize { unsafe { transmute(azul::AzDom_childrenCount(transmute(dom))) } }
+        pub(crate) fn AzDom_findFirstById(dom: &AzDom, id: AzString) -> AzOptionNodeId { unsafe { transmute(azul::AzDom_findFirstById(transmute(dom), transmute(id))) } }
+        pub(crate) fn AzDom_findAllByClass(dom: &AzDom, class_name: AzString) -> AzNodeIdVec { unsafe { transmute(azul::AzDom_findAllByClass(transmute(dom), transmute(class_name))) } }
         pub(crate) fn AzDom_getHtmlString(dom: &mut AzDom) -> AzString { unsafe { transmute(azul::AzDom_getHtmlString(transmute(dom))) } }
         pub(crate) fn AzDom_getHtmlStringTest(dom: &mut AzDom) -> AzString { unsafe { transmute(azul::AzDom_getHtmlStringTest(transmute(dom))) } }
         pub(crate) fn AzDom_style(dom: &mut AzDom, css: AzCss) -> AzStyledDom { unsafe { transmute(azul::AzDom_style(transmute(dom), transmute(css))) } }
@@ -10738,6 +11893,8 @@ mod dll {
         pub(crate) fn AzNodeData_setClipMask(nodedata: &mut AzNodeData, image_mask: AzImageMask) { unsafe { transmute(azul::AzNodeData_setClipMask(transmute(nodedata), transmute(image_mask))) } }
         pub(crate) fn AzNodeData_setTabIndex(nodedata: &mut AzNodeData, tab_index: AzTabIndex) { unsafe { transmute(azul::AzNodeData_setTabIndex(transmute(nodedata), transmute(tab_index))) } }
         pub(crate) fn AzNodeData_setAccessibilityInfo(nodedata: &mut AzNodeData, accessibility_info: AzAccessibilityInfo) { unsafe { transmute(azul::AzNodeData_setAccessibilityInfo(transmute(nodedata), transmute(accessibility_info))) } }
+        pub(crate) fn AzNodeData_setAccessibilityRole(nodedata: &mut AzNodeData, role: AzAccessibilityRole) { unsafe { transmute(azul::AzNodeData_setAccessibilityRole(transmute(nodedata), transmute(role))) } }
+        pub(crate) fn AzNodeData_setAccessibilityLabel(nodedata: &mut AzNodeData, label: AzString) { unsafe { transmute(azul::AzNodeData_setAccessibilityLabel(transmute(nodedata), transmute(label))) } }
         pub(crate) fn AzNodeData_setMenuBar(nodedata: &mut AzNodeData, menu_bar: AzMenu) { unsafe { transmute(azul::AzNodeData_setMenuBar(transmute(nodedata), transmute(menu_bar))) } }
         pub(crate) fn AzNodeData_setContextMenu(nodedata: &mut AzNodeData, context_menu: AzMenu) { unsafe { transmute(azul::AzNodeData_setContextMenu(transmute(nodedata), transmute(context_menu))) } }
         pub(crate) fn AzNodeData_hash(nodedata: &AzNodeData) -> u64 { unsafe { transmute(azul::AzNodeData_hash(transmute(nodedata))) } }
@@ -10748,6 +11905,10 @@ mod dll {
         pub(crate) fn AzStringMenuItem_new(label: AzString) -> AzStringMenuItem { unsafe { transmute(azul::AzStringMenuItem_new(transmute(label))) } }
         pub(crate) fn AzStringMenuItem_setCallback(stringmenuitem: &mut AzStringMenuItem, data: AzRefAny, callback: AzCallbackType) { unsafe { transmute(azul::AzStringMenuItem_setCallback(transmute(stringmenuitem), transmute(data), transmute(callback))) } }
         pub(crate) fn AzStringMenuItem_withCallback(stringmenuitem: &mut AzStringMenuItem, data: AzRefAny, callback: AzCallbackType) -> AzStringMenuItem { unsafe { transmute(azul::AzStringMenuItem_withCallback(transmute(stringmenuitem), transmute(data), transmute(callback))) } }
+        pub(crate) fn AzStringMenuItem_setAccelerator(stringmenuitem: &mut AzStringMenuItem, accelerator: AzVirtualKeyCodeCombo) { unsafe { transmute(azul::AzStringMenuItem_setAccelerator(transmute(stringmenuitem), transmute(accelerator))) } }
+        pub(crate) fn AzStringMenuItem_withAccelerator(stringmenuitem: &mut AzStringMenuItem, accelerator: AzVirtualKeyCodeCombo) -> AzStringMenuItem { unsafe { transmute(azul::AzStringMenuItem_withAccelerator(transmute(stringmenuitem), transmute(accelerator))) } }
+        pub(crate) fn AzStringMenuItem_setState(stringmenuitem: &mut AzStringMenuItem, state: AzMenuItemState) { unsafe { transmute(azul::AzStringMenuItem_setState(transmute(stringmenuitem), transmute(state))) } }
+        pub(crate) fn AzStringMenuItem_withState(stringmenuitem: &mut AzStringMenuItem, state: AzMenuItemState) -> AzStringMenuItem { unsafe { transmute(azul::AzStringMenuItem_withState(transmute(stringmenuitem), transmute(state))) } }
         pub(crate) fn AzStringMenuItem_addChild(stringmenuitem: &mut AzStringMenuItem, child: AzMenuItem) { unsafe { transmute(azul::AzStringMenuItem_addChild(transmute(stringmenuitem), transmute(child))) } }
         pub(crate) fn AzStringMenuItem_withChild(stringmenuitem: &mut AzStringMenuItem, child: AzMenuItem) -> AzStringMenuItem { unsafe { transmute(azul::AzStringMenuItem_withChild(transmute(stringmenuitem), transmute(child))) } }
         pub(crate) fn AzStringMenuItem_setChildren(stringmenuitem: &mut AzStringMenuItem, children: AzMenuItemVec) { unsafe { transmute(azul::AzStringMenuItem_setChildren(transmute(stringmenuitem), transmute(children))) } }
@@ -10755,12 +11916,56 @@ mod dll {
         pub(crate) fn AzMenuCallback_new(data: AzRefAny, callback: AzCallbackType) -> AzMenuCallback { unsafe { transmute(azul::AzMenuCallback_new(transmute(data), transmute(callback))) } }
         pub(crate) fn AzCss_empty() -> AzCss { unsafe { transmute(azul::AzCss_empty()) } }
         pub(crate) fn AzCss_fromString(s: AzString) -> AzCss { unsafe { transmute(azul::AzCss_fromString(transmute(s))) } }
+        pub(crate) fn AzCss_setVariable(css: &mut AzCss, name: AzString, value: AzCssProperty) { unsafe { transmute(azul::AzCss_setVariable(transmute(css), transmute(name), transmute(value))) } }
+        pub(crate) fn AzCss_resolveVariables(css: &mut AzCss) { unsafe { transmute(azul::AzCss_resolveVariables(transmute(css))) } }
         pub(crate) fn AzColorU_fromStr(string: AzString) -> AzColorU { unsafe { transmute(azul::AzColorU_fromStr(transmute(string))) } }
         pub(crate) fn AzColorU_transparent() -> AzColorU { unsafe { transmute(azul::AzColorU_transparent()) } }
         pub(crate) fn AzColorU_white() -> AzColorU { unsafe { transmute(azul::AzColorU_white()) } }
         pub(crate) fn AzColorU_black() -> AzColorU { unsafe { transmute(azul::AzColorU_black()) } }
         pub(crate) fn AzColorU_toHash(coloru: &AzColorU) -> AzString { unsafe { transmute(azul::AzColorU_toHash(transmute(coloru))) } }
+        pub(crate) fn AzColorU_multiply(coloru: &AzColorU, other: AzColorU) -> AzColorU { unsafe { transmute(azul::AzColorU_multiply(transmute(coloru), transmute(other))) } }
+        pub(crate) fn AzColorU_addSaturating(coloru: &AzColorU, other: AzColorU) -> AzColorU { unsafe { transmute(azul::AzColorU_addSaturating(transmute(coloru), transmute(other))) } }
+        pub(crate) fn AzColorU_hueRotate(coloru: &AzColorU, degrees: f32) -> AzColorU { unsafe { transmute(azul::AzColorU_hueRotate(transmute(coloru), transmute(degrees))) } }
+        pub(crate) fn AzColorU_withR(coloru: &AzColorU, r: u8) -> AzColorU { unsafe { transmute(azul::AzColorU_withR(transmute(coloru), transmute(r))) } }
+        pub(crate) fn AzColorU_withG(coloru: &AzColorU, g: u8) -> AzColorU { unsafe { transmute(azul::AzColorU_withG(transmute(coloru), transmute(g))) } }
+        pub(crate) fn AzColorU_withB(coloru: &AzColorU, b: u8) -> AzColorU { unsafe { transmute(azul::AzColorU_withB(transmute(coloru), transmute(b))) } }
+        pub(crate) fn AzColorU_withA(coloru: &AzColorU, a: u8) -> AzColorU { unsafe { transmute(azul::AzColorU_withA(transmute(coloru), transmute(a))) } }
+        pub(crate) fn AzColorU_withAlphaF32(coloru: &AzColorU, a: f32) -> AzColorU { unsafe { transmute(azul::AzColorU_withAlphaF32(transmute(coloru), transmute(a))) } }
         pub(crate) fn AzAngleValue_getDegrees(anglevalue: &AzAngleValue) -> f32 { unsafe { transmute(azul::AzAngleValue_getDegrees(transmute(anglevalue))) } }
+        pub(crate) fn AzStyleBoxShadowValue_exact(shadow: AzStyleBoxShadow) -> AzStyleBoxShadowValue { unsafe { transmute(azul::AzStyleBoxShadowValue_exact(transmute(shadow))) } }
+        pub(crate) fn AzLayoutGridTemplateColumnsValue_exact(value: AzLayoutGridTemplateColumns) -> AzLayoutGridTemplateColumnsValue { unsafe { transmute(azul::AzLayoutGridTemplateColumnsValue_exact(transmute(value))) } }
+        pub(crate) fn AzLayoutGridTemplateRowsValue_exact(value: AzLayoutGridTemplateRows) -> AzLayoutGridTemplateRowsValue { unsafe { transmute(azul::AzLayoutGridTemplateRowsValue_exact(transmute(value))) } }
+        pub(crate) fn AzLayoutBottomValue_calc(calc: AzCalcLength) -> AzLayoutBottomValue { unsafe { transmute(azul::AzLayoutBottomValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutHeightValue_calc(calc: AzCalcLength) -> AzLayoutHeightValue { unsafe { transmute(azul::AzLayoutHeightValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutLeftValue_calc(calc: AzCalcLength) -> AzLayoutLeftValue { unsafe { transmute(azul::AzLayoutLeftValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutMarginBottomValue_calc(calc: AzCalcLength) -> AzLayoutMarginBottomValue { unsafe { transmute(azul::AzLayoutMarginBottomValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutMarginLeftValue_calc(calc: AzCalcLength) -> AzLayoutMarginLeftValue { unsafe { transmute(azul::AzLayoutMarginLeftValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutMarginRightValue_calc(calc: AzCalcLength) -> AzLayoutMarginRightValue { unsafe { transmute(azul::AzLayoutMarginRightValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutMarginTopValue_calc(calc: AzCalcLength) -> AzLayoutMarginTopValue { unsafe { transmute(azul::AzLayoutMarginTopValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutMaxHeightValue_calc(calc: AzCalcLength) -> AzLayoutMaxHeightValue { unsafe { transmute(azul::AzLayoutMaxHeightValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutMaxWidthValue_calc(calc: AzCalcLength) -> AzLayoutMaxWidthValue { unsafe { transmute(azul::AzLayoutMaxWidthValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutMinHeightValue_calc(calc: AzCalcLength) -> AzLayoutMinHeightValue { unsafe { transmute(azul::AzLayoutMinHeightValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutMinWidthValue_calc(calc: AzCalcLength) -> AzLayoutMinWidthValue { unsafe { transmute(azul::AzLayoutMinWidthValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutPaddingBottomValue_calc(calc: AzCalcLength) -> AzLayoutPaddingBottomValue { unsafe { transmute(azul::AzLayoutPaddingBottomValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutPaddingLeftValue_calc(calc: AzCalcLength) -> AzLayoutPaddingLeftValue { unsafe { transmute(azul::AzLayoutPaddingLeftValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutPaddingRightValue_calc(calc: AzCalcLength) -> AzLayoutPaddingRightValue { unsafe { transmute(azul::AzLayoutPaddingRightValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutPaddingTopValue_calc(calc: AzCalcLength) -> AzLayoutPaddingTopValue { unsafe { transmute(azul::AzLayoutPaddingTopValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutRightValue_calc(calc: AzCalcLength) -> AzLayoutRightValue { unsafe { transmute(azul::AzLayoutRightValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutTopValue_calc(calc: AzCalcLength) -> AzLayoutTopValue { unsafe { transmute(azul::AzLayoutTopValue_calc(transmute(calc))) } }
+        pub(crate) fn AzLayoutWidthValue_calc(calc: AzCalcLength) -> AzLayoutWidthValue { unsafe { transmute(azul::AzLayoutWidthValue_calc(transmute(calc))) } }
+        pub(crate) fn AzScrollbarStyleValue_exact(style: AzScrollbarStyle) -> AzScrollbarStyleValue { unsafe { transmute(azul::AzScrollbarStyleValue_exact(transmute(style))) } }
+        pub(crate) fn AzStyleBackgroundContentVecValue_exact(value: AzStyleBackgroundContentVec) -> AzStyleBackgroundContentVecValue { unsafe { transmute(azul::AzStyleBackgroundContentVecValue_exact(transmute(value))) } }
+        pub(crate) fn AzStyleBackgroundPositionVecValue_exact(value: AzStyleBackgroundPositionVec) -> AzStyleBackgroundPositionVecValue { unsafe { transmute(azul::AzStyleBackgroundPositionVecValue_exact(transmute(value))) } }
+        pub(crate) fn AzStyleBackgroundRepeatVecValue_exact(value: AzStyleBackgroundRepeatVec) -> AzStyleBackgroundRepeatVecValue { unsafe { transmute(azul::AzStyleBackgroundRepeatVecValue_exact(transmute(value))) } }
+        pub(crate) fn AzStyleBackgroundSizeVecValue_exact(value: AzStyleBackgroundSizeVec) -> AzStyleBackgroundSizeVecValue { unsafe { transmute(azul::AzStyleBackgroundSizeVecValue_exact(transmute(value))) } }
+        pub(crate) fn AzStyleFontFamilyVecValue_exact(value: AzStyleFontFamilyVec) -> AzStyleFontFamilyVecValue { unsafe { transmute(azul::AzStyleFontFamilyVecValue_exact(transmute(value))) } }
+        pub(crate) fn AzStyleTransitionPropertyValue_exact(value: AzStyleTransitionProperty) -> AzStyleTransitionPropertyValue { unsafe { transmute(azul::AzStyleTransitionPropertyValue_exact(transmute(value))) } }
+        pub(crate) fn AzStyleTransitionTimingFunctionValue_exact(value: AzStyleTransitionTimingFunction) -> AzStyleTransitionTimingFunctionValue { unsafe { transmute(azul::AzStyleTransitionTimingFunctionValue_exact(transmute(value))) } }
+        pub(crate) fn AzStyleVerticalAlignValue_exact(value: AzStyleVerticalAlign) -> AzStyleVerticalAlignValue { unsafe { transmute(azul::AzStyleVerticalAlignValue_exact(transmute(value))) } }
+        pub(crate) fn AzStyleTransformVecValue_exact(value: AzStyleTransformVec) -> AzStyleTransformVecValue { unsafe { transmute(azul::AzStyleTransformVecValue_exact(transmute(value))) } }
+        pub(crate) fn AzStyleTransformOriginValue_exact(value: AzStyleTransformOrigin) -> AzStyleTransformOriginValue { unsafe { transmute(azul::AzStyleTransformOriginValue_exact(transmute(value))) } }
+        pub(crate) fn AzStylePerspectiveOriginValue_exact(value: AzStylePerspectiveOrigin) -> AzStylePerspectiveOriginValue { unsafe { transmute(azul::AzStylePerspectiveOriginValue_exact(transmute(value))) } }
+        pub(crate) fn AzStyleFilterVecValue_exact(value: AzStyleFilterVec) -> AzStyleFilterVecValue { unsafe { transmute(azul::AzStyleFilterVecValue_exact(transmute(value))) } }
         pub(crate) fn AzCssProperty_getKeyString(cssproperty: &AzCssProperty) -> AzString { unsafe { transmute(azul::AzCssProperty_getKeyString(transmute(cssproperty))) } }
         pub(crate) fn AzCssProperty_getValueString(cssproperty: &AzCssProperty) -> AzString { unsafe { transmute(azul::AzCssProperty_getValueString(transmute(cssproperty))) } }
         pub(crate) fn AzCssProperty_getKeyValueString(cssproperty: &AzCssProperty) -> AzString { unsafe { transmute(azul::AzCssProperty_getKeyValueString(transmute(cssproperty))) } }
@@ -11301,6 +12506,7 @@ mod dll {
         pub(crate) fn AzTimer_withTimeout(timer: &AzTimer, timeout: AzDuration) -> AzTimer { unsafe { transmute(azul::AzTimer_withTimeout(transmute(timer), transmute(timeout))) } }
         pub(crate) fn AzThread_delete(object: &mut AzThread) { unsafe { transmute(azul::AzThread_delete(transmute(object))) } }
         pub(crate) fn AzThread_deepCopy(object: &AzThread) -> AzThread { unsafe { transmute(azul::AzThread_deepCopy(transmute(object))) } }
+        pub(crate) fn AzPendingThread_new(data: AzRefAny, thread_fn: AzThreadFnType) -> AzPendingThread { unsafe { transmute(azul::AzPendingThread_new(transmute(data), transmute(thread_fn))) } }
         pub(crate) fn AzThreadSender_send(threadsender: &mut AzThreadSender, msg: AzThreadReceiveMsg) -> bool { unsafe { transmute(azul::AzThreadSender_send(transmute(threadsender), transmute(msg))) } }
         pub(crate) fn AzThreadSender_delete(object: &mut AzThreadSender) { unsafe { transmute(azul::AzThreadSender_delete(transmute(object))) } }
         pub(crate) fn AzThreadSender_deepCopy(object: &AzThreadSender) -> AzThreadSender { unsafe { transmute(azul::AzThreadSender_deepCopy(transmute(object))) } }
@@ -11311,6 +12517,16 @@ mod dll {
         pub(crate) fn AzString_copyFromBytes(ptr: *const u8, start: usize, len: usize) -> AzString { unsafe { transmute(azul::AzString_copyFromBytes(transmute(ptr), transmute(start), transmute(len))) } }
         pub(crate) fn AzString_trim(string: &AzString) -> AzString { unsafe { transmute(azul::AzString_trim(transmute(string))) } }
         pub(crate) fn AzString_asRefstr(string: &AzString) -> AzRefstr { unsafe { transmute(azul::AzString_asRefstr(transmute(string))) } }
+        pub(crate) fn AzTextCursor_start() -> AzTextCursor { unsafe { transmute(azul::AzTextCursor_start()) } }
+        pub(crate) fn AzTextCursor_end(text: AzString) -> AzTextCursor { unsafe { transmute(azul::AzTextCursor_end(transmute(text))) } }
+        pub(crate) fn AzTextCursor_fromByteOffset(text: AzString, byte_offset: usize) -> AzTextCursor { unsafe { transmute(azul::AzTextCursor_fromByteOffset(transmute(text), transmute(byte_offset))) } }
+        pub(crate) fn AzTextCursor_nextGrapheme(textcursor: &AzTextCursor, text: AzString) -> AzTextCursor { unsafe { transmute(azul::AzTextCursor_nextGrapheme(transmute(textcursor), transmute(text))) } }
+        pub(crate) fn AzTextCursor_prevGrapheme(textcursor: &AzTextCursor, text: AzString) -> AzTextCursor { unsafe { transmute(azul::AzTextCursor_prevGrapheme(transmute(textcursor), transmute(text))) } }
+        pub(crate) fn AzTextCursor_nextWord(textcursor: &AzTextCursor, text: AzString) -> AzTextCursor { unsafe { transmute(azul::AzTextCursor_nextWord(transmute(textcursor), transmute(text))) } }
+        pub(crate) fn AzTextCursor_prevWord(textcursor: &AzTextCursor, text: AzString) -> AzTextCursor { unsafe { transmute(azul::AzTextCursor_prevWord(transmute(textcursor), transmute(text))) } }
+        pub(crate) fn AzTextSelection_collapsed(cursor: AzTextCursor) -> AzTextSelection { unsafe { transmute(azul::AzTextSelection_collapsed(transmute(cursor))) } }
+        pub(crate) fn AzTextSelection_isCollapsed(textselection: &AzTextSelection) -> bool { unsafe { transmute(azul::AzTextSelection_isCollapsed(transmute(textselection))) } }
+        pub(crate) fn AzTextSelection_normalized(textselection: &AzTextSelection) -> AzTextSelection { unsafe { transmute(azul::AzTextSelection_normalized(transmute(textselection))) } }
         pub(crate) fn AzListViewRowVec_delete(object: &mut AzListViewRowVec) { unsafe { transmute(azul::AzListViewRowVec_delete(transmute(object))) } }
         pub(crate) fn AzStyleFilterVec_delete(object: &mut AzStyleFilterVec) { unsafe { transmute(azul::AzStyleFilterVec_delete(transmute(object))) } }
         pub(crate) fn AzLogicalRectVec_delete(object: &mut AzLogicalRectVec) { unsafe { transmute(azul::AzLogicalRectVec_delete(transmute(object))) } }
@@ -11338,6 +12554,7 @@ mod dll {
         pub(crate) fn AzInlineTextHitVec_delete(object: &mut AzInlineTextHitVec) { unsafe { transmute(azul::AzInlineTextHitVec_delete(transmute(object))) } }
         pub(crate) fn AzMonitorVec_delete(object: &mut AzMonitorVec) { unsafe { transmute(azul::AzMonitorVec_delete(transmute(object))) } }
         pub(crate) fn AzVideoModeVec_delete(object: &mut AzVideoModeVec) { unsafe { transmute(azul::AzVideoModeVec_delete(transmute(object))) } }
+        pub(crate) fn AzAcceleratorCallbackVec_delete(object: &mut AzAcceleratorCallbackVec) { unsafe { transmute(azul::AzAcceleratorCallbackVec_delete(transmute(object))) } }
         pub(crate) fn AzDomVec_delete(object: &mut AzDomVec) { unsafe { transmute(azul::AzDomVec_delete(transmute(object))) } }
         pub(crate) fn AzIdOrClassVec_delete(object: &mut AzIdOrClassVec) { unsafe { transmute(azul::AzIdOrClassVec_delete(transmute(object))) } }
         pub(crate) fn AzNodeDataInlineCssPropertyVec_delete(object: &mut AzNodeDataInlineCssPropertyVec) { unsafe { transmute(azul::AzNodeDataInlineCssPropertyVec_delete(transmute(object))) } }
@@ -11346,6 +12563,13 @@ mod dll {
         pub(crate) fn AzStyleBackgroundRepeatVec_delete(object: &mut AzStyleBackgroundRepeatVec) { unsafe { transmute(azul::AzStyleBackgroundRepeatVec_delete(transmute(object))) } }
         pub(crate) fn AzStyleBackgroundSizeVec_delete(object: &mut AzStyleBackgroundSizeVec) { unsafe { transmute(azul::AzStyleBackgroundSizeVec_delete(transmute(object))) } }
         pub(crate) fn AzStyleTransformVec_delete(object: &mut AzStyleTransformVec) { unsafe { transmute(azul::AzStyleTransformVec_delete(transmute(object))) } }
+        pub(crate) fn AzGridTrackVec_delete(object: &mut AzGridTrackVec) { unsafe { transmute(azul::AzGridTrackVec_delete(transmute(object))) } }
+        pub(crate) fn AzCssPropertyTypeVec_delete(object: &mut AzCssPropertyTypeVec) { unsafe { transmute(azul::AzCssPropertyTypeVec_delete(transmute(object))) } }
+        pub(crate) fn AzCssVariableVec_delete(object: &mut AzCssVariableVec) { unsafe { transmute(azul::AzCssVariableVec_delete(transmute(object))) } }
+        pub(crate) fn AzMediaFeatureVec_delete(object: &mut AzMediaFeatureVec) { unsafe { transmute(azul::AzMediaFeatureVec_delete(transmute(object))) } }
+        pub(crate) fn AzMediaConditionVec_delete(object: &mut AzMediaConditionVec) { unsafe { transmute(azul::AzMediaConditionVec_delete(transmute(object))) } }
+        pub(crate) fn AzCssPropertyVec_len(csspropertyvec: &AzCssPropertyVec) -> usize { unsafe { transmute(azul::AzCssPropertyVec_len(transmute(csspropertyvec))) } }
+        pub(crate) fn AzCssPropertyVec_get(csspropertyvec: &AzCssPropertyVec, index: usize) -> AzOptionCssProperty { unsafe { transmute(azul::AzCssPropertyVec_get(transmute(csspropertyvec), transmute(index))) } }
         pub(crate) fn AzCssPropertyVec_delete(object: &mut AzCssPropertyVec) { unsafe { transmute(azul::AzCssPropertyVec_delete(transmute(object))) } }
         pub(crate) fn AzSvgMultiPolygonVec_delete(object: &mut AzSvgMultiPolygonVec) { unsafe { transmute(azul::AzSvgMultiPolygonVec_delete(transmute(object))) } }
         pub(crate) fn AzSvgSimpleNodeVec_delete(object: &mut AzSvgSimpleNodeVec) { unsafe { transmute(azul::AzSvgSimpleNodeVec_delete(transmute(object))) } }
@@ -11372,8 +12596,13 @@ mod dll {
         pub(crate) fn AzDebugMessageVec_delete(object: &mut AzDebugMessageVec) { unsafe { transmute(azul::AzDebugMessageVec_delete(transmute(object))) } }
         pub(crate) fn AzGLuintVec_delete(object: &mut AzGLuintVec) { unsafe { transmute(azul::AzGLuintVec_delete(transmute(object))) } }
         pub(crate) fn AzGLintVec_delete(object: &mut AzGLintVec) { unsafe { transmute(azul::AzGLintVec_delete(transmute(object))) } }
+        pub(crate) fn AzStringVec_new() -> AzStringVec { unsafe { transmute(azul::AzStringVec_new()) } }
+        pub(crate) fn AzStringVec_len(stringvec: &AzStringVec) -> usize { unsafe { transmute(azul::AzStringVec_len(transmute(stringvec))) } }
+        pub(crate) fn AzStringVec_get(stringvec: &AzStringVec, index: usize) -> AzOptionString { unsafe { transmute(azul::AzStringVec_get(transmute(stringvec), transmute(index))) } }
         pub(crate) fn AzStringVec_delete(object: &mut AzStringVec) { unsafe { transmute(azul::AzStringVec_delete(transmute(object))) } }
         pub(crate) fn AzStringPairVec_delete(object: &mut AzStringPairVec) { unsafe { transmute(azul::AzStringPairVec_delete(transmute(object))) } }
+        pub(crate) fn AzNormalizedLinearColorStopVec_len(normalizedlinearcolorstopvec: &AzNormalizedLinearColorStopVec) -> usize { unsafe { transmute(azul::AzNormalizedLinearColorStopVec_len(transmute(normalizedlinearcolorstopvec))) } }
+        pub(crate) fn AzNormalizedLinearColorStopVec_get(normalizedlinearcolorstopvec: &AzNormalizedLinearColorStopVec, index: usize) -> AzOptionNormalizedLinearColorStop { unsafe { transmute(azul::AzNormalizedLinearColorStopVec_get(transmute(normalizedlinearcolorstopvec), transmute(index))) } }
         pub(crate) fn AzNormalizedLinearColorStopVec_delete(object: &mut AzNormalizedLinearColorStopVec) { unsafe { transmute(azul::AzNormalizedLinearColorStopVec_delete(transmute(object))) } }
         pub(crate) fn AzNormalizedRadialColorStopVec_delete(object: &mut AzNormalizedRadialColorStopVec) { unsafe { transmute(azul::AzNormalizedRadialColorStopVec_delete(transmute(object))) } }
         pub(crate) fn AzNodeIdVec_delete(object: &mut AzNodeIdVec) { unsafe { transmute(azul::AzNodeIdVec_delete(transmute(object))) } }
@@ -11396,16 +12625,51 @@ mod dll {
             pub(crate) fn AzApp_new(_:  AzRefAny, _:  AzAppConfig) -> AzApp;
             pub(crate) fn AzApp_addWindow(_:  &mut AzApp, _:  AzWindowCreateOptions);
             pub(crate) fn AzApp_addImage(_:  &mut AzApp, _:  AzString, _:  AzImageRef);
+            pub(crate) fn AzApp_addImageRaw(_:  &mut AzApp, _:  AzString, _:  *const u8, _:  usize, _:  u32, _:  u32, _:  AzRawImageFormat) -> bool;
+            pub(crate) fn AzApp_updateImageRaw(_:  &mut AzApp, _:  AzString, _:  *const u8, _:  usize, _:  u32, _:  u32, _:  AzRawImageFormat) -> bool;
+            pub(crate) fn AzApp_deleteImage(_:  &mut AzApp, _:  AzString);
             pub(crate) fn AzApp_getMonitors(_:  &AzApp) -> AzMonitorVec;
+            pub(crate) fn AzApp_getSystemTheme(_:  &AzApp) -> AzOptionWindowTheme;
             pub(crate) fn AzApp_run(_:  &AzApp, _:  AzWindowCreateOptions);
+            pub(crate) fn AzApp_runReturnCode(_:  &AzApp, _:  AzWindowCreateOptions) -> i32;
+            pub(crate) fn AzApp_createTestHarness(_:  &AzApp, _:  AzWindowCreateOptions) -> AzTestHarnessPtr;
             pub(crate) fn AzApp_delete(_:  &mut AzApp);
             pub(crate) fn AzApp_deepCopy(_:  &AzApp) -> AzApp;
             pub(crate) fn AzAppConfig_new(_:  AzLayoutSolver) -> AzAppConfig;
+            pub(crate) fn AzAppConfig_setRedrawBehavior(_:  &mut AzAppConfig, _:  AzRedrawBehavior);
             pub(crate) fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks;
+            pub(crate) fn AzApi_versionMajor() -> u32;
+            pub(crate) fn AzApi_versionMinor() -> u32;
+            pub(crate) fn AzApi_versionPatch() -> u32;
+            pub(crate) fn AzApi_versionString() -> *const u8;
+            pub(crate) fn AzApi_abiHash() -> u64;
+            pub(crate) fn AzApi_hasFeature(_:  *const u8, _:  usize) -> bool;
+            pub(crate) fn AzApi_lastError() -> AzOptionString;
+            pub(crate) fn AzApi_clearLastError() -> bool;
+            pub(crate) fn AzTestHarness_delete(_:  AzTestHarnessPtr);
+            pub(crate) fn AzTestHarness_diffSnapshots(_:  AzString, _:  AzString) -> AzOptionString;
+            pub(crate) fn AzTestHarness_resize(_:  AzTestHarnessPtr, _:  f32, _:  f32);
+            pub(crate) fn AzTestHarness_mouseMove(_:  AzTestHarnessPtr, _:  f32, _:  f32);
+            pub(crate) fn AzTestHarness_mouseDown(_:  AzTestHarnessPtr, _:  AzContextMenuMouseButton);
+            pub(crate) fn AzTestHarness_mouseUp(_:  AzTestHarnessPtr, _:  AzContextMenuMouseButton);
+            pub(crate) fn AzTestHarness_keyChar(_:  AzTestHarnessPtr, _:  u32);
+            pub(crate) fn AzTestHarness_keyDown(_:  AzTestHarnessPtr, _:  AzVirtualKeyCode);
+            pub(crate) fn AzTestHarness_keyUp(_:  AzTestHarnessPtr, _:  AzVirtualKeyCode);
+            pub(crate) fn AzTestHarness_getHitNodeCount(_:  AzTestHarnessPtr) -> usize;
+            pub(crate) fn AzTestHarness_getHitNode(_:  AzTestHarnessPtr, _:  usize) -> AzOptionDomNodeId;
+            pub(crate) fn AzTestHarness_getNodeRect(_:  AzTestHarnessPtr, _:  AzDomNodeId) -> AzOptionLogicalRect;
+            pub(crate) fn AzTestHarness_getNodeText(_:  AzTestHarnessPtr, _:  AzDomNodeId) -> AzOptionString;
+            pub(crate) fn AzTestHarness_advanceTime(_:  AzTestHarnessPtr, _:  u64);
+            pub(crate) fn AzTestHarness_getFramesRendered(_:  AzTestHarnessPtr) -> usize;
+            pub(crate) fn AzTestHarness_getData(_:  AzTestHarnessPtr) -> AzRefAny;
+            pub(crate) fn AzTestHarness_serializeLayout(_:  AzTestHarnessPtr, _:  AzDomId, _:  AzCssPropertyTypeVec) -> AzOptionString;
             pub(crate) fn AzWindowCreateOptions_new(_:  AzLayoutCallbackType) -> AzWindowCreateOptions;
+            pub(crate) fn AzWindowCreateOptions_setLayoutCallback(_:  &mut AzWindowCreateOptions, _:  AzLayoutCallbackType);
+            pub(crate) fn AzWindowCreateOptions_addAccelerator(_:  &mut AzWindowCreateOptions, _:  AzAccelerator, _:  AzRefAny, _:  AzCallbackType) -> AzAcceleratorId;
             pub(crate) fn AzLogicalPosition_new(_:  f32, _:  f32) -> AzLogicalPosition;
             pub(crate) fn AzLogicalPosition_zero() -> AzLogicalPosition;
             pub(crate) fn AzLogicalSize_toPhysical(_:  &AzLogicalSize, _:  f32) -> AzPhysicalSizeU32;
+            pub(crate) fn AzAccelerator_new(_:  AzModifiers, _:  AzVirtualKeyCode) -> AzAccelerator;
             pub(crate) fn AzWindowSize_getHidpiFactor(_:  &AzWindowSize) -> f32;
             pub(crate) fn AzKeyboardState_shiftDown(_:  &AzKeyboardState) -> bool;
             pub(crate) fn AzKeyboardState_ctrlDown(_:  &AzKeyboardState) -> bool;
@@ -11422,12 +12686,18 @@ mod dll {
             pub(crate) fn AzCallbackInfo_getCurrentWindowState(_:  &AzCallbackInfo) -> AzWindowState;
             pub(crate) fn AzCallbackInfo_getCurrentKeyboardState(_:  &AzCallbackInfo) -> AzKeyboardState;
             pub(crate) fn AzCallbackInfo_getCurrentMouseState(_:  &AzCallbackInfo) -> AzMouseState;
+            pub(crate) fn AzCallbackInfo_getImeEvent(_:  &AzCallbackInfo) -> AzOptionImeEvent;
+            pub(crate) fn AzCallbackInfo_getHoveredFiles(_:  &AzCallbackInfo) -> AzStringVec;
+            pub(crate) fn AzCallbackInfo_getDroppedFiles(_:  &AzCallbackInfo) -> AzStringVec;
+            pub(crate) fn AzCallbackInfo_getDropPosition(_:  &AzCallbackInfo) -> AzOptionLogicalPosition;
+            pub(crate) fn AzCallbackInfo_getDropTarget(_:  &AzCallbackInfo) -> AzOptionDomNodeId;
             pub(crate) fn AzCallbackInfo_getPreviousWindowState(_:  &AzCallbackInfo) -> AzOptionWindowState;
             pub(crate) fn AzCallbackInfo_getPreviousKeyboardState(_:  &AzCallbackInfo) -> AzOptionKeyboardState;
             pub(crate) fn AzCallbackInfo_getPreviousMouseState(_:  &AzCallbackInfo) -> AzOptionMouseState;
             pub(crate) fn AzCallbackInfo_getCurrentWindowHandle(_:  &AzCallbackInfo) -> AzRawWindowHandle;
             pub(crate) fn AzCallbackInfo_getGlContext(_:  &AzCallbackInfo) -> AzOptionGl;
             pub(crate) fn AzCallbackInfo_getScrollPosition(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionLogicalPosition;
+            pub(crate) fn AzCallbackInfo_getScrollExtent(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionLogicalSize;
             pub(crate) fn AzCallbackInfo_getDataset(_:  &mut AzCallbackInfo, _:  AzDomNodeId) -> AzOptionRefAny;
             pub(crate) fn AzCallbackInfo_getNodeIdOfRootDataset(_:  &mut AzCallbackInfo, _:  AzRefAny) -> AzOptionDomNodeId;
             pub(crate) fn AzCallbackInfo_getStringContents(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionString;
@@ -11444,16 +12714,26 @@ mod dll {
             pub(crate) fn AzCallbackInfo_getNodePosition(_:  &mut AzCallbackInfo, _:  AzDomNodeId) -> AzOptionPositionInfo;
             pub(crate) fn AzCallbackInfo_getNodeSize(_:  &mut AzCallbackInfo, _:  AzDomNodeId) -> AzOptionLogicalSize;
             pub(crate) fn AzCallbackInfo_getComputedCssProperty(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzCssPropertyType) -> AzOptionCssProperty;
+            pub(crate) fn AzCallbackInfo_getComputedCssProperties(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzCssPropertyVec;
             pub(crate) fn AzCallbackInfo_setWindowState(_:  &mut AzCallbackInfo, _:  AzWindowState);
+            pub(crate) fn AzCallbackInfo_setImePosition(_:  &mut AzCallbackInfo, _:  AzLogicalPosition);
             pub(crate) fn AzCallbackInfo_setFocus(_:  &mut AzCallbackInfo, _:  AzFocusTarget);
             pub(crate) fn AzCallbackInfo_setCssProperty(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzCssProperty);
-            pub(crate) fn AzCallbackInfo_setScrollPosition(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzLogicalPosition);
+            pub(crate) fn AzCallbackInfo_setCss(_:  &mut AzCallbackInfo, _:  AzCss);
+            pub(crate) fn AzCallbackInfo_setScrollPosition(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzLogicalPosition) -> bool;
             pub(crate) fn AzCallbackInfo_setStringContents(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzString);
             pub(crate) fn AzCallbackInfo_addImage(_:  &mut AzCallbackInfo, _:  AzString, _:  AzImageRef);
+            pub(crate) fn AzCallbackInfo_addImageRaw(_:  &mut AzCallbackInfo, _:  AzString, _:  *const u8, _:  usize, _:  u32, _:  u32, _:  AzRawImageFormat) -> bool;
+            pub(crate) fn AzCallbackInfo_updateImageRaw(_:  &mut AzCallbackInfo, _:  AzString, _:  *const u8, _:  usize, _:  u32, _:  u32, _:  AzRawImageFormat) -> bool;
             pub(crate) fn AzCallbackInfo_hasImage(_:  &AzCallbackInfo, _:  AzString) -> bool;
             pub(crate) fn AzCallbackInfo_getImage(_:  &AzCallbackInfo, _:  AzString) -> AzOptionImageRef;
             pub(crate) fn AzCallbackInfo_updateImage(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzImageRef, _:  AzUpdateImageType);
             pub(crate) fn AzCallbackInfo_deleteImage(_:  &mut AzCallbackInfo, _:  AzString);
+            pub(crate) fn AzCallbackInfo_addAccelerator(_:  &mut AzCallbackInfo, _:  AzAccelerator, _:  AzRefAny, _:  AzCallbackType) -> AzAcceleratorId;
+            pub(crate) fn AzCallbackInfo_replaceAccelerator(_:  &mut AzCallbackInfo, _:  AzAcceleratorId, _:  AzAccelerator, _:  AzRefAny, _:  AzCallbackType) -> bool;
+            pub(crate) fn AzCallbackInfo_removeAccelerator(_:  &mut AzCallbackInfo, _:  AzAcceleratorId) -> bool;
+            pub(crate) fn AzCallbackInfo_openContextMenu(_:  &mut AzCallbackInfo, _:  AzMenu, _:  AzLogicalPosition);
+            pub(crate) fn AzCallbackInfo_closeContextMenu(_:  &mut AzCallbackInfo);
             pub(crate) fn AzCallbackInfo_updateImageMask(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzImageMask);
             pub(crate) fn AzCallbackInfo_stopPropagation(_:  &mut AzCallbackInfo);
             pub(crate) fn AzCallbackInfo_createWindow(_:  &mut AzCallbackInfo, _:  AzWindowCreateOptions);
@@ -11461,6 +12741,8 @@ mod dll {
             pub(crate) fn AzCallbackInfo_startAnimation(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzAnimation) -> AzOptionTimerId;
             pub(crate) fn AzCallbackInfo_stopTimer(_:  &mut AzCallbackInfo, _:  AzTimerId) -> bool;
             pub(crate) fn AzCallbackInfo_startThread(_:  &mut AzCallbackInfo, _:  AzRefAny, _:  AzRefAny, _:  AzThreadCallbackType) -> AzOptionThreadId;
+            pub(crate) fn AzCallbackInfo_startPendingThread(_:  &mut AzCallbackInfo, _:  AzPendingThread, _:  AzCallbackType) -> AzOptionThreadId;
+            pub(crate) fn AzCallbackInfo_requestThreadStop(_:  &mut AzCallbackInfo, _:  AzThreadId) -> bool;
             pub(crate) fn AzCallbackInfo_sendThreadMsg(_:  &mut AzCallbackInfo, _:  AzThreadId, _:  AzThreadSendMsg) -> bool;
             pub(crate) fn AzCallbackInfo_stopThread(_:  &mut AzCallbackInfo, _:  AzThreadId) -> bool;
             pub(crate) fn AzPositionInfo_isPositioned(_:  &AzPositionInfo) -> bool;
@@ -11492,6 +12774,7 @@ mod dll {
             pub(crate) fn AzRefAny_newC(_:  *const c_void, _:  usize, _:  u64, _:  AzString, _:  AzRefAnyDestructorType) -> AzRefAny;
             pub(crate) fn AzRefAny_getTypeId(_:  &AzRefAny) -> u64;
             pub(crate) fn AzRefAny_getTypeName(_:  &AzRefAny) -> AzString;
+            pub(crate) fn AzRefAny_strongCount(_:  &AzRefAny) -> usize;
             pub(crate) fn AzRefAny_delete(_:  &mut AzRefAny);
             pub(crate) fn AzRefAny_deepCopy(_:  &AzRefAny) -> AzRefAny;
             pub(crate) fn AzLayoutCallbackInfo_getGlContext(_:  &AzLayoutCallbackInfo) -> AzOptionGl;
@@ -11546,12 +12829,20 @@ mod dll {
             pub(crate) fn AzDom_withTabIndex(_:  &mut AzDom, _:  AzTabIndex) -> AzDom;
             pub(crate) fn AzDom_setAccessibilityInfo(_:  &mut AzDom, _:  AzAccessibilityInfo);
             pub(crate) fn AzDom_withAccessibilityInfo(_:  &mut AzDom, _:  AzAccessibilityInfo) -> AzDom;
+            pub(crate) fn AzDom_setAccessibilityRole(_:  &mut AzDom, _:  AzAccessibilityRole);
+            pub(crate) fn AzDom_withAccessibilityRole(_:  &mut AzDom, _:  AzAccessibilityRole) -> AzDom;
+            pub(crate) fn AzDom_setAccessibilityLabel(_:  &mut AzDom, _:  AzString);
+            pub(crate) fn AzDom_withAccessibilityLabel(_:  &mut AzDom, _:  AzString) -> AzDom;
             pub(crate) fn AzDom_setMenuBar(_:  &mut AzDom, _:  AzMenu);
             pub(crate) fn AzDom_withMenuBar(_:  &mut AzDom, _:  AzMenu) -> AzDom;
             pub(crate) fn AzDom_setContextMenu(_:  &mut AzDom, _:  AzMenu);
             pub(crate) fn AzDom_withContextMenu(_:  &mut AzDom, _:  AzMenu) -> AzDom;
             pub(crate) fn AzDom_hash(_:  &AzDom) -> u64;
             pub(crate) fn AzDom_nodeCount(_:  &AzDom) -> usize;
+            pub(crate) fn AzDom_getNodeType(_:  &AzDom) -> AzNodeType;
+            pub(crate) fn AzDom_childrenCount(_:  &AzDom) -> usize;
+            pub(crate) fn AzDom_findFirstById(_:  &AzDom, _:  AzString) -> AzOptionNodeId;
+            pub(crate) fn AzDom_findAllByClass(_:  &AzDom, _:  AzString) -> AzNodeIdVec;
             pub(crate) fn AzDom_getHtmlString(_:  &mut AzDom) -> AzString;
             pub(crate) fn AzDom_getHtmlStringTest(_:  &mut AzDom) -> AzString;
             pub(crate) fn AzDom_style(_:  &mut AzDom, _:  AzCss) -> AzStyledDom;
@@ -11585,6 +12876,8 @@ mod dll {
             pub(crate) fn AzNodeData_setClipMask(_:  &mut AzNodeData, _:  AzImageMask);
             pub(crate) fn AzNodeData_setTabIndex(_:  &mut AzNodeData, _:  AzTabIndex);
             pub(crate) fn AzNodeData_setAccessibilityInfo(_:  &mut AzNodeData, _:  AzAccessibilityInfo);
+            pub(crate) fn AzNodeData_setAccessibilityRole(_:  &mut AzNodeData, _:  AzAccessibilityRole);
+            pub(crate) fn AzNodeData_setAccessibilityLabel(_:  &mut AzNodeData, _:  AzString);
             pub(crate) fn AzNodeData_setMenuBar(_:  &mut AzNodeData, _:  AzMenu);
             pub(crate) fn AzNodeData_setContextMenu(_:  &mut AzNodeData, _:  AzMenu);
             pub(crate) fn AzNodeData_hash(_:  &AzNodeData) -> u64;
@@ -11595,6 +12888,10 @@ mod dll {
             pub(crate) fn AzStringMenuItem_new(_:  AzString) -> AzStringMenuItem;
             pub(crate) fn AzStringMenuItem_setCallback(_:  &mut AzStringMenuItem, _:  AzRefAny, _:  AzCallbackType);
             pub(crate) fn AzStringMenuItem_withCallback(_:  &mut AzStringMenuItem, _:  AzRefAny, _:  AzCallbackType) -> AzStringMenuItem;
+            pub(crate) fn AzStringMenuItem_setAccelerator(_:  &mut AzStringMenuItem, _:  AzVirtualKeyCodeCombo);
+            pub(crate) fn AzStringMenuItem_withAccelerator(_:  &mut AzStringMenuItem, _:  AzVirtualKeyCodeCombo) -> AzStringMenuItem;
+            pub(crate) fn AzStringMenuItem_setState(_:  &mut AzStringMenuItem, _:  AzMenuItemState);
+            pub(crate) fn AzStringMenuItem_withState(_:  &mut AzStringMenuItem, _:  AzMenuItemState) -> AzStringMenuItem;
             pub(crate) fn AzStringMenuItem_addChild(_:  &mut AzStringMenuItem, _:  AzMenuItem);
             pub(crate) fn AzStringMenuItem_withChild(_:  &mut AzStringMenuItem, _:  AzMenuItem) -> AzStringMenuItem;
             pub(crate) fn AzStringMenuItem_setChildren(_:  &mut AzStringMenuItem, _:  AzMenuItemVec);
@@ -11602,12 +12899,56 @@ mod dll {
             pub(crate) fn AzMenuCallback_new(_:  AzRefAny, _:  AzCallbackType) -> AzMenuCallback;
             pub(crate) fn AzCss_empty() -> AzCss;
             pub(crate) fn AzCss_fromString(_:  AzString) -> AzCss;
+            pub(crate) fn AzCss_setVariable(_:  &mut AzCss, _:  AzString, _:  AzCssProperty);
+            pub(crate) fn AzCss_resolveVariables(_:  &mut AzCss);
             pub(crate) fn AzColorU_fromStr(_:  AzString) -> AzColorU;
             pub(crate) fn AzColorU_transparent() -> AzColorU;
             pub(crate) fn AzColorU_white() -> AzColorU;
             pub(crate) fn AzColorU_black() -> AzColorU;
             pub(crate) fn AzColorU_toHash(_:  &AzColorU) -> AzString;
+            pub(crate) fn AzColorU_multiply(_:  &AzColorU, _:  AzColorU) -> AzColorU;
+            pub(crate) fn AzColorU_addSaturating(_:  &AzColorU, _:  AzColorU) -> AzColorU;
+            pub(crate) fn AzColorU_hueRotate(_:  &AzColorU, _:  f32) -> AzColorU;
+            pub(crate) fn AzColorU_withR(_:  &AzColorU, _:  u8) -> AzColorU;
+            pub(crate) fn AzColorU_withG(_:  &AzColorU, _:  u8) -> AzColorU;
+            pub(crate) fn AzColorU_withB(_:  &AzColorU, _:  u8) -> AzColorU;
+            pub(crate) fn AzColorU_withA(_:  &AzColorU, _:  u8) -> AzColorU;
+            pub(crate) fn AzColorU_withAlphaF32(_:  &AzColorU, _:  f32) -> AzColorU;
             pub(crate) fn AzAngleValue_getDegrees(_:  &AzAngleValue) -> f32;
+            pub(crate) fn AzStyleBoxShadowValue_exact(_:  AzStyleBoxShadow) -> AzStyleBoxShadowValue;
+            pub(crate) fn AzLayoutGridTemplateColumnsValue_exact(_:  AzLayoutGridTemplateColumns) -> AzLayoutGridTemplateColumnsValue;
+            pub(crate) fn AzLayoutGridTemplateRowsValue_exact(_:  AzLayoutGridTemplateRows) -> AzLayoutGridTemplateRowsValue;
+            pub(crate) fn AzLayoutBottomValue_calc(_:  AzCalcLength) -> AzLayoutBottomValue;
+            pub(crate) fn AzLayoutHeightValue_calc(_:  AzCalcLength) -> AzLayoutHeightValue;
+            pub(crate) fn AzLayoutLeftValue_calc(_:  AzCalcLength) -> AzLayoutLeftValue;
+            pub(crate) fn AzLayoutMarginBottomValue_calc(_:  AzCalcLength) -> AzLayoutMarginBottomValue;
+            pub(crate) fn AzLayoutMarginLeftValue_calc(_:  AzCalcLength) -> AzLayoutMarginLeftValue;
+            pub(crate) fn AzLayoutMarginRightValue_calc(_:  AzCalcLength) -> AzLayoutMarginRightValue;
+            pub(crate) fn AzLayoutMarginTopValue_calc(_:  AzCalcLength) -> AzLayoutMarginTopValue;
+            pub(crate) fn AzLayoutMaxHeightValue_calc(_:  AzCalcLength) -> AzLayoutMaxHeightValue;
+            pub(crate) fn AzLayoutMaxWidthValue_calc(_:  AzCalcLength) -> AzLayoutMaxWidthValue;
+            pub(crate) fn AzLayoutMinHeightValue_calc(_:  AzCalcLength) -> AzLayoutMinHeightValue;
+            pub(crate) fn AzLayoutMinWidthValue_calc(_:  AzCalcLength) -> AzLayoutMinWidthValue;
+            pub(crate) fn AzLayoutPaddingBottomValue_calc(_:  AzCalcLength) -> AzLayoutPaddingBottomValue;
+            pub(crate) fn AzLayoutPaddingLeftValue_calc(_:  AzCalcLength) -> AzLayoutPaddingLeftValue;
+            pub(crate) fn AzLayoutPaddingRightValue_calc(_:  AzCalcLength) -> AzLayoutPaddingRightValue;
+            pub(crate) fn AzLayoutPaddingTopValue_calc(_:  AzCalcLength) -> AzLayoutPaddingTopValue;
+            pub(crate) fn AzLayoutRightValue_calc(_:  AzCalcLength) -> AzLayoutRightValue;
+            pub(crate) fn AzLayoutTopValue_calc(_:  AzCalcLength) -> AzLayoutTopValue;
+            pub(crate) fn AzLayoutWidthValue_calc(_:  AzCalcLength) -> AzLayoutWidthValue;
+            pub(crate) fn AzScrollbarStyleValue_exact(_:  AzScrollbarStyle) -> AzScrollbarStyleValue;
+            pub(crate) fn AzStyleBackgroundContentVecValue_exact(_:  AzStyleBackgroundContentVec) -> AzStyleBackgroundContentVecValue;
+            pub(crate) fn AzStyleBackgroundPositionVecValue_exact(_:  AzStyleBackgroundPositionVec) -> AzStyleBackgroundPositionVecValue;
+            pub(crate) fn AzStyleBackgroundRepeatVecValue_exact(_:  AzStyleBackgroundRepeatVec) -> AzStyleBackgroundRepeatVecValue;
+            pub(crate) fn AzStyleBackgroundSizeVecValue_exact(_:  AzStyleBackgroundSizeVec) -> AzStyleBackgroundSizeVecValue;
+            pub(crate) fn AzStyleFontFamilyVecValue_exact(_:  AzStyleFontFamilyVec) -> AzStyleFontFamilyVecValue;
+            pub(crate) fn AzStyleTransitionPropertyValue_exact(_:  AzStyleTransitionProperty) -> AzStyleTransitionPropertyValue;
+            pub(crate) fn AzStyleTransitionTimingFunctionValue_exact(_:  AzStyleTransitionTimingFunction) -> AzStyleTransitionTimingFunctionValue;
+            pub(crate) fn AzStyleVerticalAlignValue_exact(_:  AzStyleVerticalAlign) -> AzStyleVerticalAlignValue;
+            pub(crate) fn AzStyleTransformVecValue_exact(_:  AzStyleTransformVec) -> AzStyleTransformVecValue;
+            pub(crate) fn AzStyleTransformOriginValue_exact(_:  AzStyleTransformOrigin) -> AzStyleTransformOriginValue;
+            pub(crate) fn AzStylePerspectiveOriginValue_exact(_:  AzStylePerspectiveOrigin) -> AzStylePerspectiveOriginValue;
+            pub(crate) fn AzStyleFilterVecValue_exact(_:  AzStyleFilterVec) -> AzStyleFilterVecValue;
             pub(crate) fn AzCssProperty_getKeyString(_:  &AzCssProperty) -> AzString;
             pub(crate) fn AzCssProperty_getValueString(_:  &AzCssProperty) -> AzString;
             pub(crate) fn AzCssProperty_getKeyValueString(_:  &AzCssProperty) -> AzString;
@@ -12148,6 +13489,7 @@ mod dll {
             pub(crate) fn AzTimer_withTimeout(_:  &AzTimer, _:  AzDuration) -> AzTimer;
             pub(crate) fn AzThread_delete(_:  &mut AzThread);
             pub(crate) fn AzThread_deepCopy(_:  &AzThread) -> AzThread;
+            pub(crate) fn AzPendingThread_new(_:  AzRefAny, _:  AzThreadFnType) -> AzPendingThread;
             pub(crate) fn AzThreadSender_send(_:  &mut AzThreadSender, _:  AzThreadReceiveMsg) -> bool;
             pub(crate) fn AzThreadSender_delete(_:  &mut AzThreadSender);
             pub(crate) fn AzThreadSender_deepCopy(_:  &AzThreadSender) -> AzThreadSender;
@@ -12158,6 +13500,16 @@ mod dll {
             pub(crate) fn AzString_copyFromBytes(_:  *const u8, _:  usize, _:  usize) -> AzString;
             pub(crate) fn AzString_trim(_:  &AzString) -> AzString;
             pub(crate) fn AzString_asRefstr(_:  &AzString) -> AzRefstr;
+            pub(crate) fn AzTextCursor_start() -> AzTextCursor;
+            pub(crate) fn AzTextCursor_end(_:  AzString) -> AzTextCursor;
+            pub(crate) fn AzTextCursor_fromByteOffset(_:  AzString, _:  usize) -> AzTextCursor;
+            pub(crate) fn AzTextCursor_nextGrapheme(_:  &AzTextCursor, _:  AzString) -> AzTextCursor;
+            pub(crate) fn AzTextCursor_prevGrapheme(_:  &AzTextCursor, _:  AzString) -> AzTextCursor;
+            pub(crate) fn AzTextCursor_nextWord(_:  &AzTextCursor, _:  AzString) -> AzTextCursor;
+            pub(crate) fn AzTextCursor_prevWord(_:  &AzTextCursor, _:  AzString) -> AzTextCursor;
+            pub(crate) fn AzTextSelection_collapsed(_:  AzTextCursor) -> AzTextSelection;
+            pub(crate) fn AzTextSelection_isCollapsed(_:  &AzTextSelection) -> bool;
+            pub(crate) fn AzTextSelection_normalized(_:  &AzTextSelection) -> AzTextSelection;
             pub(crate) fn AzListViewRowVec_delete(_:  &mut AzListViewRowVec);
             pub(crate) fn AzStyleFilterVec_delete(_:  &mut AzStyleFilterVec);
             pub(crate) fn AzLogicalRectVec_delete(_:  &mut AzLogicalRectVec);
@@ -12185,6 +13537,7 @@ mod dll {
             pub(crate) fn AzInlineTextHitVec_delete(_:  &mut AzInlineTextHitVec);
             pub(crate) fn AzMonitorVec_delete(_:  &mut AzMonitorVec);
             pub(crate) fn AzVideoModeVec_delete(_:  &mut AzVideoModeVec);
+            pub(crate) fn AzAcceleratorCallbackVec_delete(_:  &mut AzAcceleratorCallbackVec);
             pub(crate) fn AzDomVec_delete(_:  &mut AzDomVec);
             pub(crate) fn AzIdOrClassVec_delete(_:  &mut AzIdOrClassVec);
             pub(crate) fn AzNodeDataInlineCssPropertyVec_delete(_:  &mut AzNodeDataInlineCssPropertyVec);
@@ -12193,6 +13546,13 @@ mod dll {
             pub(crate) fn AzStyleBackgroundRepeatVec_delete(_:  &mut AzStyleBackgroundRepeatVec);
             pub(crate) fn AzStyleBackgroundSizeVec_delete(_:  &mut AzStyleBackgroundSizeVec);
             pub(crate) fn AzStyleTransformVec_delete(_:  &mut AzStyleTransformVec);
+            pub(crate) fn AzGridTrackVec_delete(_:  &mut AzGridTrackVec);
+            pub(crate) fn AzCssPropertyTypeVec_delete(_:  &mut AzCssPropertyTypeVec);
+            pub(crate) fn AzCssVariableVec_delete(_:  &mut AzCssVariableVec);
+            pub(crate) fn AzMediaFeatureVec_delete(_:  &mut AzMediaFeatureVec);
+            pub(crate) fn AzMediaConditionVec_delete(_:  &mut AzMediaConditionVec);
+            pub(crate) fn AzCssPropertyVec_len(_:  &AzCssPropertyVec) -> usize;
+            pub(crate) fn AzCssPropertyVec_get(_:  &AzCssPropertyVec, _:  usize) -> AzOptionCssProperty;
             pub(crate) fn AzCssPropertyVec_delete(_:  &mut AzCssPropertyVec);
             pub(crate) fn AzSvgMultiPolygonVec_delete(_:  &mut AzSvgMultiPolygonVec);
             pub(crate) fn AzSvgSimpleNodeVec_delete(_:  &mut AzSvgSimpleNodeVec);
@@ -12219,8 +13579,13 @@ mod dll {
             pub(crate) fn AzDebugMessageVec_delete(_:  &mut AzDebugMessageVec);
             pub(crate) fn AzGLuintVec_delete(_:  &mut AzGLuintVec);
             pub(crate) fn AzGLintVec_delete(_:  &mut AzGLintVec);
+            pub(crate) fn AzStringVec_new() -> AzStringVec;
+            pub(crate) fn AzStringVec_len(_:  &AzStringVec) -> usize;
+            pub(crate) fn AzStringVec_get(_:  &AzStringVec, _:  usize) -> AzOptionString;
             pub(crate) fn AzStringVec_delete(_:  &mut AzStringVec);
             pub(crate) fn AzStringPairVec_delete(_:  &mut AzStringPairVec);
+            pub(crate) fn AzNormalizedLinearColorStopVec_len(_:  &AzNormalizedLinearColorStopVec) -> usize;
+            pub(crate) fn AzNormalizedLinearColorStopVec_get(_:  &AzNormalizedLinearColorStopVec, _:  usize) -> AzOptionNormalizedLinearColorStop;
             pub(crate) fn AzNormalizedLinearColorStopVec_delete(_:  &mut AzNormalizedLinearColorStopVec);
             pub(crate) fn AzNormalizedRadialColorStopVec_delete(_:  &mut AzNormalizedRadialColorStopVec);
             pub(crate) fn AzNodeIdVec_delete(_:  &mut AzNodeIdVec);
@@ -12242,10 +13607,12 @@ pub mod app {
     //! `App` construction and configuration
     use crate::dll::*;
     use core::ffi::c_void;
-    use crate::callbacks::RefAny;
-    use crate::window::WindowCreateOptions;
+    use crate::callbacks::{DomId, DomNodeId, RefAny};
+    use crate::window::{VirtualKeyCode, WindowCreateOptions};
     use crate::str::String;
-    use crate::image::ImageRef;
+    use crate::image::{ImageRef, RawImageFormat};
+    use crate::menu::ContextMenuMouseButton;
+    use crate::vec::CssPropertyTypeVec;
     /// Main application class
     
     #[doc(inline)] pub use crate::dll::AzApp as App;
@@ -12257,10 +13624,22 @@ pub mod app {
         pub fn add_window<_1: Into<WindowCreateOptions>>(&mut self, window: _1)  { unsafe { crate::dll::AzApp_addWindow(self, window.into()) } }
         /// Adds a new image identified by an ID to the image cache
         pub fn add_image<_1: Into<String>, _2: Into<ImageRef>>(&mut self, id: _1, image: _2)  { unsafe { crate::dll::AzApp_addImage(self, id.into(), image.into()) } }
+        /// Copies the pixels into a new image and adds it to the image cache under `id`. Returns `false` if `len` doesn't match the image size or the format is not supported.
+        pub fn add_image_raw<_1: Into<String>, _6: Into<RawImageFormat>>(&mut self, id: _1, data: *const u8, len: usize, width: u32, height: u32, format: _6)  -> bool { unsafe { crate::dll::AzApp_addImageRaw(self, id.into(), data, len, width, height, format.into()) } }
+        /// Replaces the pixels of the image registered under `id`, keeping its texture (i.e. for every frame of a video stream). Returns `false` if there is no image with the same size registered under `id` (use `add_image_raw` in that case) or if the data is invalid.
+        pub fn update_image_raw<_1: Into<String>, _6: Into<RawImageFormat>>(&mut self, id: _1, data: *const u8, len: usize, width: u32, height: u32, format: _6)  -> bool { unsafe { crate::dll::AzApp_updateImageRaw(self, id.into(), data, len, width, height, format.into()) } }
+        /// Removes the image identified by a CSS ID from the image cache
+        pub fn delete_image<_1: Into<String>>(&mut self, id: _1)  { unsafe { crate::dll::AzApp_deleteImage(self, id.into()) } }
         /// Returns a list of monitors - useful for setting the monitor that a window should spawn on.
         pub fn get_monitors(&self)  -> crate::vec::MonitorVec { unsafe { crate::dll::AzApp_getMonitors(self) } }
-        /// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns.
+        /// Returns whether the operating system is set to light or dark mode. Windows created with `WindowCreateOptions.theme = None` follow this setting and fire a `WindowEventFilter::ThemeChanged` event when it changes.
+        pub fn get_system_theme(&self)  -> crate::option::OptionWindowTheme { unsafe { crate::dll::AzApp_getSystemTheme(self) } }
+        /// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns. Use `run_return_code` to get the exit code instead.
         pub fn run<_1: Into<WindowCreateOptions>>(&self, window: _1)  { unsafe { crate::dll::AzApp_run(self, window.into()) } }
+        /// Same as `run`, but returns the exit code of the event loop (`0` if the last window was closed normally). Returns `-1` if the app could not be started (see `Api::last_error`) or if it is already running.
+        pub fn run_return_code<_1: Into<WindowCreateOptions>>(&self, window: _1)  -> i32 { unsafe { crate::dll::AzApp_runReturnCode(self, window.into()) } }
+        /// Moves the app into a new `TestHarness` instead of running it (the app is empty afterwards, same as after `run`). Returns `NULL` if the app is running. The harness has to be freed with `TestHarness::delete`.
+        pub fn create_test_harness<_1: Into<WindowCreateOptions>>(&self, window: _1)  -> crate::app::TestHarnessPtr { unsafe { crate::dll::AzApp_createTestHarness(self, window.into()) } }
     }
 
     impl Clone for App { fn clone(&self) -> Self { unsafe { crate::dll::AzApp_deepCopy(self) } } }
@@ -12272,11 +13651,16 @@ pub mod app {
 
         /// Constructs a default `AppConfig`, uses the layout solver currently available
         pub fn new<_1: Into<LayoutSolver>>(layout_solver: _1) -> Self { unsafe { crate::dll::AzAppConfig_new(layout_solver.into()) } }
+        /// Sets how often the windows of the app render a new frame, the default is `OnEventOnly` (an idle app doesn't render)
+        pub fn set_redraw_behavior<_1: Into<RedrawBehavior>>(&mut self, behavior: _1)  { unsafe { crate::dll::AzAppConfig_setRedrawBehavior(self, behavior.into()) } }
     }
 
     /// Configuration to set which messages should be logged.
     
     #[doc(inline)] pub use crate::dll::AzAppLogLevel as AppLogLevel;
+    /// Controls how often a window renders a new frame
+    
+    #[doc(inline)] pub use crate::dll::AzRedrawBehavior as RedrawBehavior;
     /// Version of the layout solver to use - future binary versions of azul may have more fields here, necessary so that old compiled applications don't break with newer releases of azul. Newer layout versions are opt-in only.
     
     #[doc(inline)] pub use crate::dll::AzLayoutSolver as LayoutSolver;
@@ -12289,6 +13673,75 @@ pub mod app {
         pub fn library_internal() -> Self { unsafe { crate::dll::AzSystemCallbacks_libraryInternal() } }
     }
 
+    /// Version and feature detection of the loaded library. Bindings that load the library dynamically should call these functions before anything else and compare the results with the `AZ_API_VERSION_*` and `AZ_API_ABI_HASH` defines of the header they were generated from. The functions only use primitive types, so they can be called even if the rest of the API does not match.
+    
+    #[doc(inline)] pub use crate::dll::AzApi as Api;
+    impl Api {
+
+        /// Returns the major version of the library
+        pub fn version_major() -> u32 { unsafe { crate::dll::AzApi_versionMajor() } }
+        /// Returns the minor version of the library
+        pub fn version_minor() -> u32 { unsafe { crate::dll::AzApi_versionMinor() } }
+        /// Returns the patch version of the library
+        pub fn version_patch() -> u32 { unsafe { crate::dll::AzApi_versionPatch() } }
+        /// Returns the full version of the library (i.e. `"0.0.1"`) as a static, nul-terminated UTF-8 string that must not be freed
+        pub fn version_string() -> *const u8 { unsafe { crate::dll::AzApi_versionString() } }
+        /// Returns the hash over the binary interface (struct layouts and function signatures) of the API the library was generated from
+        pub fn abi_hash() -> u64 { unsafe { crate::dll::AzApi_abiHash() } }
+        /// Returns whether the library was compiled with an optional capability, i.e. `"svg"`, `"hot-reload"` or `"wasm"`. Unknown names return `false`.
+        pub fn has_feature(name_ptr: *const u8, name_len: usize) -> bool { unsafe { crate::dll::AzApi_hasFeature(name_ptr, name_len) } }
+        /// Returns the message of the last panic that was caught inside the library, including the location and - if `RUST_BACKTRACE` is set - the backtrace
+        pub fn last_error() ->  crate::option::OptionString { unsafe { crate::dll::AzApi_lastError() } }
+        /// Resets the last error, so that `last_error` returns `None`. Returns whether an error was recorded.
+        pub fn clear_last_error() -> bool { unsafe { crate::dll::AzApi_clearLastError() } }
+    }
+
+    /// Window-less event simulation for integration tests: lays out the root window of an app without opening it. The injected mouse / keyboard events invoke the callbacks and restyle / relayout the window like a real event loop would, so that tests can check the hit nodes, the layouted rectangles and the application data afterwards. Created with `App::create_test_harness`, has to be freed with `TestHarness::delete`.
+    
+    #[doc(inline)] pub use crate::dll::AzTestHarness as TestHarness;
+    #[doc(inline)] pub use crate::dll::AzTestHarnessPtr as TestHarnessPtr;
+    impl TestHarness {
+
+        /// Compares two snapshots (see `serialize_layout`) line by line: returns `None` if they are equal, otherwise a diff with `- ` (expected) and `+ ` (actual) line prefixes for the error message of a failed test
+        pub fn diff_snapshots<_1: Into<String>, _2: Into<String>>(expected: _1, actual: _2) ->  crate::option::OptionString { unsafe { crate::dll::AzTestHarness_diffSnapshots(expected.into(), actual.into()) } }
+        /// Resizes the simulated window (in logical pixels)
+        pub fn resize(&mut self, width: f32, height: f32)  { unsafe { crate::dll::AzTestHarness_resize(self, width, height) } }
+        /// Moves the cursor to (`x`, `y`), relative to the top left corner of the window
+        pub fn mouse_move(&mut self, x: f32, y: f32)  { unsafe { crate::dll::AzTestHarness_mouseMove(self, x, y) } }
+        /// Presses a mouse button at the current cursor position
+        pub fn mouse_down<_1: Into<ContextMenuMouseButton>>(&mut self, button: _1)  { unsafe { crate::dll::AzTestHarness_mouseDown(self, button.into()) } }
+        /// Releases a mouse button at the current cursor position
+        pub fn mouse_up<_1: Into<ContextMenuMouseButton>>(&mut self, button: _1)  { unsafe { crate::dll::AzTestHarness_mouseUp(self, button.into()) } }
+        /// Types a unicode character, control characters are ignored
+        pub fn key_char(&mut self, c: u32)  { unsafe { crate::dll::AzTestHarness_keyChar(self, c) } }
+        /// Presses a key, modifier keys stay pressed until `key_up`
+        pub fn key_down<_1: Into<VirtualKeyCode>>(&mut self, key: _1)  { unsafe { crate::dll::AzTestHarness_keyDown(self, key.into()) } }
+        /// Releases a key
+        pub fn key_up<_1: Into<VirtualKeyCode>>(&mut self, key: _1)  { unsafe { crate::dll::AzTestHarness_keyUp(self, key.into()) } }
+        /// Number of nodes under the cursor
+        pub fn get_hit_node_count(&self)  -> usize { unsafe { crate::dll::AzTestHarness_getHitNodeCount(self as *const Self as *mut Self) } }
+        /// Returns the hit node at `index`, the topmost node is the last one
+        pub fn get_hit_node(&self, index: usize)  -> crate::option::OptionDomNodeId { unsafe { crate::dll::AzTestHarness_getHitNode(self as *const Self as *mut Self, index) } }
+        /// Returns the layouted rectangle of `node`, `None` if the node doesn't exist
+        pub fn get_node_rect<_1: Into<DomNodeId>>(&self, node: _1)  -> crate::option::OptionLogicalRect { unsafe { crate::dll::AzTestHarness_getNodeRect(self as *const Self as *mut Self, node.into()) } }
+        /// Returns the text of a text node, `None` for other node types
+        pub fn get_node_text<_1: Into<DomNodeId>>(&self, node: _1)  -> crate::option::OptionString { unsafe { crate::dll::AzTestHarness_getNodeText(self as *const Self as *mut Self, node.into()) } }
+        /// Lets `millis` milliseconds of simulated time pass, the window renders as many frames as the `RedrawBehavior` of the app allows
+        pub fn advance_time(&mut self, millis: u64)  { unsafe { crate::dll::AzTestHarness_advanceTime(self, millis) } }
+        /// Number of frames rendered since the harness was created (including the first frame)
+        pub fn get_frames_rendered(&self)  -> usize { unsafe { crate::dll::AzTestHarness_getFramesRendered(self as *const Self as *mut Self) } }
+        /// Returns a new reference to the application data
+        pub fn get_data(&mut self)  -> crate::callbacks::RefAny { unsafe { crate::dll::AzTestHarness_getData(self) } }
+        /// Prints the layout of the window (`dom` = 0) or of an iframe as deterministic text, so that tests can compare it against a committed golden file: one line per node with the path, the layouted rectangle, the text of text nodes and the computed `properties` (sorted by name, unset properties are skipped). Returns `None` if the DOM doesn't exist.
+        pub fn serialize_layout<_1: Into<DomId>, _2: Into<CssPropertyTypeVec>>(&self, dom: _1, properties: _2)  -> crate::option::OptionString { unsafe { crate::dll::AzTestHarness_serializeLayout(self as *const Self as *mut Self, dom.into(), properties.into()) } }
+        /// Frees the `TestHarness`, `NULL` is ignored
+        ///
+        /// # Safety
+        ///
+        /// `ptr` has to be `NULL` or a live handle, it must not be used afterwards
+        pub unsafe fn delete(ptr: TestHarnessPtr) { crate::dll::AzTestHarness_delete(ptr) }
+    }
+
 }
 
 pub mod window {
@@ -12379,7 +13832,7 @@ pub mod window {
             b_x + b_width <= a_x + a_width &&
             b_y + b_height <= a_y + a_height
         }
-    }    use crate::callbacks::LayoutCallbackType;
+    }    use crate::callbacks::{CallbackType, LayoutCallbackType, RefAny};
     /// Options on how to initially create the window
     
     #[doc(inline)] pub use crate::dll::AzWindowCreateOptions as WindowCreateOptions;
@@ -12387,6 +13840,10 @@ pub mod window {
 
         /// Creates a new window configuration with a custom layout callback
         pub fn new(layout_callback: LayoutCallbackType) -> Self { unsafe { crate::dll::AzWindowCreateOptions_new(layout_callback) } }
+        /// Sets the layout callback of the window, replacing the callback passed to `new`. Windows created with `default()` render an empty DOM until a callback is set.
+        pub fn set_layout_callback(&mut self, layout_callback: LayoutCallbackType)  { unsafe { crate::dll::AzWindowCreateOptions_setLayoutCallback(self, layout_callback) } }
+        /// Registers a keyboard shortcut for the window before it is created. Accelerators run before the `VirtualKeyDown` callbacks of the nodes: if the callback returns anything other than `DoNothing`, the nodes don't see the key.
+        pub fn add_accelerator<_1: Into<Accelerator>, _2: Into<RefAny>>(&mut self, accelerator: _1, data: _2, callback: CallbackType)  -> crate::window::AcceleratorId { unsafe { crate::dll::AzWindowCreateOptions_addAccelerator(self, accelerator.into(), data.into(), callback) } }
     }
 
     /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
@@ -12490,6 +13947,24 @@ pub mod window {
     /// Symbolic accelerator key (ctrl, alt, shift)
     
     #[doc(inline)] pub use crate::dll::AzAcceleratorKey as AcceleratorKey;
+    /// Which modifier keys are held down (left and right keys are not distinguished)
+    
+    #[doc(inline)] pub use crate::dll::AzModifiers as Modifiers;
+    /// Window-wide keyboard shortcut (i.e. `Ctrl+S`) that fires regardless of which node is focused
+    
+    #[doc(inline)] pub use crate::dll::AzAccelerator as Accelerator;
+    impl Accelerator {
+
+        /// Creates an accelerator for `key` + exactly the given `modifiers`
+        pub fn new<_1: Into<Modifiers>, _2: Into<VirtualKeyCode>>(modifiers: _1, key: _2) -> Self { unsafe { crate::dll::AzAccelerator_new(modifiers.into(), key.into()) } }
+    }
+
+    /// ID of a registered accelerator, used to replace or remove it
+    
+    #[doc(inline)] pub use crate::dll::AzAcceleratorId as AcceleratorId;
+    /// Accelerator + the callback to invoke when it is pressed
+    
+    #[doc(inline)] pub use crate::dll::AzAcceleratorCallback as AcceleratorCallback;
     /// Minimum / maximum / current size of the window in logical dimensions
     
     #[doc(inline)] pub use crate::dll::AzWindowSize as WindowSize;
@@ -12525,6 +14000,15 @@ pub mod window {
         pub fn is_key_down<_1: Into<VirtualKeyCode>>(&self, key: _1)  -> bool { unsafe { crate::dll::AzKeyboardState_isKeyDown(self, key.into()) } }
     }
 
+    /// Event of the input method editor (IME), used to compose text that can't be typed directly (CJK characters, emojis, etc.)
+    
+    #[doc(inline)] pub use crate::dll::AzImeEvent as ImeEvent;
+    /// Text that is currently being composed by the IME (usually rendered underlined)
+    
+    #[doc(inline)] pub use crate::dll::AzImePreedit as ImePreedit;
+    /// Byte range in the `ImePreedit` text
+    
+    #[doc(inline)] pub use crate::dll::AzImeCursorRange as ImeCursorRange;
     /// Current icon of the mouse cursor
     
     #[doc(inline)] pub use crate::dll::AzMouseCursorType as MouseCursorType;
@@ -12669,7 +14153,7 @@ pub mod callbacks {
                     // copy the struct from the heap to the stack and
                     // call mem::drop on U to run the destructor
                     let mut stack_mem = mem::MaybeUninit::<U>::uninit();
-                    ptr::copy_nonoverlapping((ptr as *mut c_void) as *const U, stack_mem.as_mut_ptr(), mem::size_of::<U>());
+                    ptr::copy_nonoverlapping((ptr as *mut c_void) as *const U, stack_mem.as_mut_ptr(), 1);
                     let stack_mem = stack_mem.assume_init();
                     mem::drop(stack_mem);
                 }
@@ -12740,10 +14224,11 @@ pub mod callbacks {
             struct_as_bytes.into_iter().enumerate().map(|(s_pos, s)| ((*s as u64) << s_pos)).sum()
         }
     }    use crate::str::String;
-    use crate::css::{CssProperty, CssPropertyType};
-    use crate::window::{LogicalPosition, WindowCreateOptions, WindowState};
-    use crate::image::{ImageMask, ImageRef};
-    use crate::task::{ThreadId, ThreadSendMsg, Timer, TimerId};
+    use crate::css::{Css, CssProperty, CssPropertyType};
+    use crate::window::{Accelerator, AcceleratorId, LogicalPosition, WindowCreateOptions, WindowState};
+    use crate::image::{ImageMask, ImageRef, RawImageFormat};
+    use crate::menu::Menu;
+    use crate::task::{PendingThread, ThreadId, ThreadSendMsg, Timer, TimerId};
     /// `LayoutCallback` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutCallback as LayoutCallback;
@@ -12787,18 +14272,30 @@ pub mod callbacks {
         pub fn get_current_keyboard_state(&self)  -> crate::window::KeyboardState { unsafe { crate::dll::AzCallbackInfo_getCurrentKeyboardState(self) } }
         /// Returns a copy of the internal `MouseState`. Same as `self.get_window_state().mouse_state`
         pub fn get_current_mouse_state(&self)  -> crate::window::MouseState { unsafe { crate::dll::AzCallbackInfo_getCurrentMouseState(self) } }
+        /// Returns the last event of the input method editor (IME). Same as `self.get_current_keyboard_state().current_ime_event`
+        pub fn get_ime_event(&self)  -> crate::option::OptionImeEvent { unsafe { crate::dll::AzCallbackInfo_getImeEvent(self) } }
+        /// Returns the paths of the files that are currently dragged over the window
+        pub fn get_hovered_files(&self)  -> crate::vec::StringVec { unsafe { crate::dll::AzCallbackInfo_getHoveredFiles(self) } }
+        /// Returns the paths of the files of the last drop onto the window
+        pub fn get_dropped_files(&self)  -> crate::vec::StringVec { unsafe { crate::dll::AzCallbackInfo_getDroppedFiles(self) } }
+        /// Returns the cursor position (relative to the window) at which the files were dropped, `None` if the current event is not a `On::DroppedFile` event
+        pub fn get_drop_position(&self)  -> crate::option::OptionLogicalPosition { unsafe { crate::dll::AzCallbackInfo_getDropPosition(self) } }
+        /// Returns the topmost node that the files were dropped on, `None` if the current event is not a `On::DroppedFile` event
+        pub fn get_drop_target(&self)  -> crate::option::OptionDomNodeId { unsafe { crate::dll::AzCallbackInfo_getDropTarget(self) } }
         /// Returns a copy of the current windows `WindowState`.
         pub fn get_previous_window_state(&self)  -> crate::option::OptionWindowState { unsafe { crate::dll::AzCallbackInfo_getPreviousWindowState(self) } }
         /// Returns a copy of the internal `KeyboardState`. Same as `self.get_window_state().keyboard_state`
         pub fn get_previous_keyboard_state(&self)  -> crate::option::OptionKeyboardState { unsafe { crate::dll::AzCallbackInfo_getPreviousKeyboardState(self) } }
         /// Returns a copy of the internal `MouseState`. Same as `self.get_window_state().mouse_state`
         pub fn get_previous_mouse_state(&self)  -> crate::option::OptionMouseState { unsafe { crate::dll::AzCallbackInfo_getPreviousMouseState(self) } }
-        /// Returns a copy of the current windows `RawWindowHandle`.
+        /// Returns the native handle of the window the callback was invoked on (HWND on Windows, NSWindow / NSView on macOS, Xlib or Wayland handles on Linux) or `Unsupported` if the platform doesn't provide one. The handle is only valid during the callback: the window can be closed after the callback returns, so the handle must not be stored.
         pub fn get_current_window_handle(&self)  -> crate::window::RawWindowHandle { unsafe { crate::dll::AzCallbackInfo_getCurrentWindowHandle(self) } }
         /// Returns a **reference-counted copy** of the current windows' `Gl` (context). You can use this to render OpenGL textures.
         pub fn get_gl_context(&self)  -> crate::option::OptionGl { unsafe { crate::dll::AzCallbackInfo_getGlContext(self) } }
         /// Returns the x / y offset that this node has been scrolled to by the user or `None` if the node has not been scrolled.
         pub fn get_scroll_position<_1: Into<DomNodeId>>(&self, node_id: _1)  -> crate::option::OptionLogicalPosition { unsafe { crate::dll::AzCallbackInfo_getScrollPosition(self, node_id.into()) } }
+        /// Returns the maximum x / y offset that this node can be scrolled to (size of the scrolled content minus the size of the container) or `None` if the node is not a scroll container.
+        pub fn get_scroll_extent<_1: Into<DomNodeId>>(&self, node_id: _1)  -> crate::option::OptionLogicalSize { unsafe { crate::dll::AzCallbackInfo_getScrollExtent(self, node_id.into()) } }
         /// Returns the `dataset` property of the given Node or `None` if the node doesn't have a `dataset` property.
         pub fn get_dataset<_1: Into<DomNodeId>>(&mut self, node_id: _1)  -> crate::option::OptionRefAny { unsafe { crate::dll::AzCallbackInfo_getDataset(self, node_id.into()) } }
         /// Given a dataset, returns the node ID of the "root" `RefAny`, i.e. the `RefAny` with the lowest `instance` count that is set as a `dataset` on any node.
@@ -12829,20 +14326,30 @@ pub mod callbacks {
         pub fn get_node_position<_1: Into<DomNodeId>>(&mut self, node_id: _1)  -> crate::option::OptionPositionInfo { unsafe { crate::dll::AzCallbackInfo_getNodePosition(self, node_id.into()) } }
         /// Returns the size of a given DOM node in the UI
         pub fn get_node_size<_1: Into<DomNodeId>>(&mut self, node_id: _1)  -> crate::option::OptionLogicalSize { unsafe { crate::dll::AzCallbackInfo_getNodeSize(self, node_id.into()) } }
-        /// Returns the current computed CSS property of a given DOM node in the UI
+        /// Returns the current computed CSS property of a given DOM node in the UI, including inherited and default values
         pub fn get_computed_css_property<_1: Into<DomNodeId>, _2: Into<CssPropertyType>>(&mut self, node_id: _1, property_type: _2)  -> crate::option::OptionCssProperty { unsafe { crate::dll::AzCallbackInfo_getComputedCssProperty(self, node_id.into(), property_type.into()) } }
+        /// Returns all computed CSS properties of a given DOM node, including inherited and default values
+        pub fn get_computed_css_properties<_1: Into<DomNodeId>>(&self, node_id: _1)  -> crate::vec::CssPropertyVec { unsafe { crate::dll::AzCallbackInfo_getComputedCssProperties(self, node_id.into()) } }
         /// Sets the new `WindowState` for the next frame. The window is updated after all callbacks are run.
         pub fn set_window_state<_1: Into<WindowState>>(&mut self, new_state: _1)  { unsafe { crate::dll::AzCallbackInfo_setWindowState(self, new_state.into()) } }
+        /// Moves the IME candidate window to the given position (i.e. the text cursor), relative to the top left of the window
+        pub fn set_ime_position<_1: Into<LogicalPosition>>(&mut self, position: _1)  { unsafe { crate::dll::AzCallbackInfo_setImePosition(self, position.into()) } }
         /// Sets the new `FocusTarget` for the next frame. Note that this will emit a `On::FocusLost` and `On::FocusReceived` event, if the focused node has changed.
         pub fn set_focus<_1: Into<FocusTarget>>(&mut self, target: _1)  { unsafe { crate::dll::AzCallbackInfo_setFocus(self, target.into()) } }
         /// Sets a `CssProperty` on a given node to its new value. If this property change affects the layout, this will automatically trigger a relayout and redraw of the screen.
         pub fn set_css_property<_1: Into<DomNodeId>, _2: Into<CssProperty>>(&mut self, node_id: _1, new_property: _2)  { unsafe { crate::dll::AzCallbackInfo_setCssProperty(self, node_id.into(), new_property.into()) } }
-        /// Sets the scroll position of the node
-        pub fn set_scroll_position<_1: Into<DomNodeId>, _2: Into<LogicalPosition>>(&mut self, node_id: _1, scroll_position: _2)  { unsafe { crate::dll::AzCallbackInfo_setScrollPosition(self, node_id.into(), scroll_position.into()) } }
+        /// Replaces the stylesheet of the current window (i.e. to switch between a light and a dark theme). The new stylesheet is applied to the next DOM returned by the layout callback, so this always triggers a DOM regeneration and a full relayout.
+        pub fn set_css<_1: Into<Css>>(&mut self, css: _1)  { unsafe { crate::dll::AzCallbackInfo_setCss(self, css.into()) } }
+        /// Sets the scroll position of the node for the next frame, clamped to the scrollable range of the node (see `get_scroll_extent`)
+        pub fn set_scroll_position<_1: Into<DomNodeId>, _2: Into<LogicalPosition>>(&mut self, node_id: _1, scroll_position: _2)  -> bool { unsafe { crate::dll::AzCallbackInfo_setScrollPosition(self, node_id.into(), scroll_position.into()) } }
         /// If the node is a `Text` node, overwrites the `Text` content with the new string, without requiring the entire UI to be rebuilt.
         pub fn set_string_contents<_1: Into<DomNodeId>, _2: Into<String>>(&mut self, node_id: _1, string: _2)  { unsafe { crate::dll::AzCallbackInfo_setStringContents(self, node_id.into(), string.into()) } }
         /// Adds a new image identified by an ID to the image cache
         pub fn add_image<_1: Into<String>, _2: Into<ImageRef>>(&mut self, id: _1, image: _2)  { unsafe { crate::dll::AzCallbackInfo_addImage(self, id.into(), image.into()) } }
+        /// Copies the pixels into a new image and adds it to the image cache under `id`. Returns `false` if `len` doesn't match the image size or the format is not supported.
+        pub fn add_image_raw<_1: Into<String>, _6: Into<RawImageFormat>>(&mut self, id: _1, data: *const u8, len: usize, width: u32, height: u32, format: _6)  -> bool { unsafe { crate::dll::AzCallbackInfo_addImageRaw(self, id.into(), data, len, width, height, format.into()) } }
+        /// Replaces the pixels of the image registered under `id`, keeping its texture (i.e. for every frame of a video stream). The new pixels are uploaded before the next frame is rendered. Returns `false` if there is no image with the same size registered under `id` (use `add_image_raw` in that case) or if the data is invalid.
+        pub fn update_image_raw<_1: Into<String>, _6: Into<RawImageFormat>>(&mut self, id: _1, data: *const u8, len: usize, width: u32, height: u32, format: _6)  -> bool { unsafe { crate::dll::AzCallbackInfo_updateImageRaw(self, id.into(), data, len, width, height, format.into()) } }
         /// Returns whether an image with a given CSS ID already exists
         pub fn has_image<_1: Into<String>>(&self, id: _1)  -> bool { unsafe { crate::dll::AzCallbackInfo_hasImage(self, id.into()) } }
         /// Returns the image with a given CSS ID
@@ -12851,6 +14358,16 @@ pub mod callbacks {
         pub fn update_image<_1: Into<DomNodeId>, _2: Into<ImageRef>, _3: Into<UpdateImageType>>(&mut self, node_id: _1, new_image: _2, image_type: _3)  { unsafe { crate::dll::AzCallbackInfo_updateImage(self, node_id.into(), new_image.into(), image_type.into()) } }
         /// Deletes an image identified by a CSS ID from the image cache
         pub fn delete_image<_1: Into<String>>(&mut self, id: _1)  { unsafe { crate::dll::AzCallbackInfo_deleteImage(self, id.into()) } }
+        /// Registers a keyboard shortcut for the window of the callback, see `replace_accelerator` / `remove_accelerator`
+        pub fn add_accelerator<_1: Into<Accelerator>, _2: Into<RefAny>>(&mut self, accelerator: _1, data: _2, callback: CallbackType)  -> crate::window::AcceleratorId { unsafe { crate::dll::AzCallbackInfo_addAccelerator(self, accelerator.into(), data.into(), callback) } }
+        /// Replaces the shortcut and callback of an accelerator, returns `false` if the `id` doesn't exist
+        pub fn replace_accelerator<_1: Into<AcceleratorId>, _2: Into<Accelerator>, _3: Into<RefAny>>(&mut self, id: _1, accelerator: _2, data: _3, callback: CallbackType)  -> bool { unsafe { crate::dll::AzCallbackInfo_replaceAccelerator(self, id.into(), accelerator.into(), data.into(), callback) } }
+        /// Removes an accelerator, returns `false` if the `id` doesn't exist
+        pub fn remove_accelerator<_1: Into<AcceleratorId>>(&mut self, id: _1)  -> bool { unsafe { crate::dll::AzCallbackInfo_removeAccelerator(self, id.into()) } }
+        /// Opens `menu` at `position` (relative to the window) after the callback returns. On Windows the menu is a native popup menu, on all other platforms it is drawn on top of the DOM. Clicking an item invokes its callback with the hit node of this callback.
+        pub fn open_context_menu<_1: Into<Menu>, _2: Into<LogicalPosition>>(&mut self, menu: _1, position: _2)  { unsafe { crate::dll::AzCallbackInfo_openContextMenu(self, menu.into(), position.into()) } }
+        /// Closes the menu opened with `open_context_menu` (no-op for native popup menus, which close themselves)
+        pub fn close_context_menu(&mut self)  { unsafe { crate::dll::AzCallbackInfo_closeContextMenu(self) } }
         /// If the node has an `ImageMask`, exchanges the current mask for the new mask
         pub fn update_image_mask<_1: Into<DomNodeId>, _2: Into<ImageMask>>(&mut self, node_id: _1, new_mask: _2)  { unsafe { crate::dll::AzCallbackInfo_updateImageMask(self, node_id.into(), new_mask.into()) } }
         /// Stops the propagation of the current callback event type to the parent. Events are bubbled from the inside out (children first, then parents), this event stops the propagation of the event to the parent.
@@ -12865,6 +14382,10 @@ pub mod callbacks {
         pub fn stop_timer<_1: Into<TimerId>>(&mut self, timer_id: _1)  -> bool { unsafe { crate::dll::AzCallbackInfo_stopTimer(self, timer_id.into()) } }
         /// Starts a new `Thread` to the runtime. See the documentation for `Thread` for more information.
         pub fn start_thread<_1: Into<RefAny>, _2: Into<RefAny>>(&mut self, thread_initialize_data: _1, writeback_data: _2, callback: ThreadCallbackType)  -> crate::option::OptionThreadId { unsafe { crate::dll::AzCallbackInfo_startThread(self, thread_initialize_data.into(), writeback_data.into(), callback) } }
+        /// Starts a `PendingThread` on a background thread. `on_finish` runs on the main thread with the data of the thread after the thread function returned, even if the thread was asked to stop.
+        pub fn start_pending_thread<_1: Into<PendingThread>>(&mut self, thread: _1, on_finish: CallbackType)  -> crate::option::OptionThreadId { unsafe { crate::dll::AzCallbackInfo_startPendingThread(self, thread.into(), on_finish) } }
+        /// Asks a thread to stop by sending it a `ThreadSendMsg::TerminateThread` after the callback returns. Unlike `stop_thread`, which drops the thread without waiting for it, the `on_finish` callback of a `PendingThread` still runs once the thread function returns.
+        pub fn request_thread_stop<_1: Into<ThreadId>>(&mut self, thread_id: _1)  -> bool { unsafe { crate::dll::AzCallbackInfo_requestThreadStop(self, thread_id.into()) } }
         /// Sends a message to a background thread
         pub fn send_thread_msg<_1: Into<ThreadId>, _2: Into<ThreadSendMsg>>(&mut self, thread_id: _1, msg: _2)  -> bool { unsafe { crate::dll::AzCallbackInfo_sendThreadMsg(self, thread_id.into(), msg.into()) } }
         /// Stops a thread at the nearest possible opportunity. Sends a `ThreadSendMsg::TerminateThread` message to the thread and joins the thread.
@@ -13035,6 +14556,9 @@ pub mod callbacks {
     /// `ThreadCallbackType` struct
     
     #[doc(inline)] pub use crate::dll::AzThreadCallbackType as ThreadCallbackType;
+    /// Function that runs on the background thread of a `PendingThread`. The data, sender and receiver are owned by azul and are only valid until the function returns.
+    
+    #[doc(inline)] pub use crate::dll::AzThreadFnType as ThreadFnType;
     /// `RefAnyDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzRefAnyDestructorType as RefAnyDestructorType;
@@ -13066,10 +14590,12 @@ pub mod callbacks {
 
         /// Creates a new `RefAny` instance.
         pub fn new_c<_4: Into<String>>(ptr: *const c_void, len: usize, type_id: u64, type_name: _4, destructor: RefAnyDestructorType) -> Self { unsafe { crate::dll::AzRefAny_newC(ptr, len, type_id, type_name.into(), destructor) } }
-        /// Calls the `RefAny::get_type_id` function.
+        /// Returns the type ID stored in the `RefAny`, useful for debugging callbacks that receive a `RefAny` of the wrong type
         pub fn get_type_id(&self)  -> u64 { unsafe { crate::dll::AzRefAny_getTypeId(self) } }
-        /// Calls the `RefAny::get_type_name` function.
+        /// Returns a copy of the type name stored in the `RefAny`, i.e. `"MyDataModel"`
         pub fn get_type_name(&self)  -> crate::str::String { unsafe { crate::dll::AzRefAny_getTypeName(self) } }
+        /// Returns the number of copies of the `RefAny` that are still alive, including this one. Useful for finding leaked copies.
+        pub fn strong_count(&self)  -> usize { unsafe { crate::dll::AzRefAny_strongCount(self) } }
     }
 
     impl Clone for RefAny { fn clone(&self) -> Self { unsafe { crate::dll::AzRefAny_deepCopy(self) } } }
@@ -13330,6 +14856,14 @@ pub mod dom {
         pub fn set_accessibility_info<_1: Into<AccessibilityInfo>>(&mut self, accessibility_info: _1)  { unsafe { crate::dll::AzDom_setAccessibilityInfo(self, accessibility_info.into()) } }
         /// Same as set_accessibility_info, but as a builder method
         pub fn with_accessibility_info<_1: Into<AccessibilityInfo>>(&mut self, accessibility_info: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withAccessibilityInfo(self, accessibility_info.into()) } }
+        /// Sets the accessibility role (button, list, ...) of the DOM root node, keeps the other accessibility attributes
+        pub fn set_accessibility_role<_1: Into<AccessibilityRole>>(&mut self, role: _1)  { unsafe { crate::dll::AzDom_setAccessibilityRole(self, role.into()) } }
+        /// Same as set_accessibility_role, but as a builder method
+        pub fn with_accessibility_role<_1: Into<AccessibilityRole>>(&mut self, role: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withAccessibilityRole(self, role.into()) } }
+        /// Sets the accessible name of the DOM root node that is read by screen readers, keeps the other accessibility attributes
+        pub fn set_accessibility_label<_1: Into<String>>(&mut self, label: _1)  { unsafe { crate::dll::AzDom_setAccessibilityLabel(self, label.into()) } }
+        /// Same as set_accessibility_label, but as a builder method
+        pub fn with_accessibility_label<_1: Into<String>>(&mut self, label: _1)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withAccessibilityLabel(self, label.into()) } }
         /// Sets the menu bar for the DOM root node. See `NodeData::set_menu_bar` for more information.
         pub fn set_menu_bar<_1: Into<Menu>>(&mut self, menu_bar: _1)  { unsafe { crate::dll::AzDom_setMenuBar(self, menu_bar.into()) } }
         /// Same as set_accessibility_info, but as a builder method
@@ -13342,6 +14876,14 @@ pub mod dom {
         pub fn hash(&self)  -> u64 { unsafe { crate::dll::AzDom_hash(self) } }
         /// Returns the number of nodes in the DOM, including all child DOM trees. Result is equal to `self.total_children + 1` (count of all child trees + the root node)
         pub fn node_count(&self)  -> usize { unsafe { crate::dll::AzDom_nodeCount(self) } }
+        /// Returns the node type of the root node
+        pub fn get_node_type(&self)  -> crate::dom::NodeType { unsafe { crate::dll::AzDom_getNodeType(self) } }
+        /// Returns the number of direct children of the root node
+        pub fn children_count(&self)  -> usize { unsafe { crate::dll::AzDom_childrenCount(self) } }
+        /// Returns the first node with the given id. Nodes are numbered in depth-first pre-order (root = 0), which is the same `NodeId` the node has after the `Dom` is styled
+        pub fn find_first_by_id<_1: Into<String>>(&self, id: _1)  -> crate::option::OptionNodeId { unsafe { crate::dll::AzDom_findFirstById(self, id.into()) } }
+        /// Returns all nodes with the given class, numbered the same way as in `find_first_by_id`
+        pub fn find_all_by_class<_1: Into<String>>(&self, class_name: _1)  -> crate::vec::NodeIdVec { unsafe { crate::dll::AzDom_findAllByClass(self, class_name.into()) } }
         /// Returns a HTML string that you can write to a file in order to debug the UI structure and debug potential cascading issues
         pub fn get_html_string(&mut self)  -> crate::str::String { unsafe { crate::dll::AzDom_getHtmlString(self) } }
         /// Returns a HTML for unit testing
@@ -13421,6 +14963,10 @@ pub mod dom {
         pub fn set_tab_index<_1: Into<TabIndex>>(&mut self, tab_index: _1)  { unsafe { crate::dll::AzNodeData_setTabIndex(self, tab_index.into()) } }
         /// Sets accessibility attributes for this node
         pub fn set_accessibility_info<_1: Into<AccessibilityInfo>>(&mut self, accessibility_info: _1)  { unsafe { crate::dll::AzNodeData_setAccessibilityInfo(self, accessibility_info.into()) } }
+        /// Sets the accessibility role (button, list, ...) of this node, keeps the other accessibility attributes
+        pub fn set_accessibility_role<_1: Into<AccessibilityRole>>(&mut self, role: _1)  { unsafe { crate::dll::AzNodeData_setAccessibilityRole(self, role.into()) } }
+        /// Sets the accessible name of this node that is read by screen readers, keeps the other accessibility attributes
+        pub fn set_accessibility_label<_1: Into<String>>(&mut self, label: _1)  { unsafe { crate::dll::AzNodeData_setAccessibilityLabel(self, label.into()) } }
         /// Adds a (native) menu bar: If this node is the root node the menu bar will be added to the window, else it will be displayed using the width and position of the bounding rectangle
         pub fn set_menu_bar<_1: Into<Menu>>(&mut self, menu_bar: _1)  { unsafe { crate::dll::AzNodeData_setMenuBar(self, menu_bar.into()) } }
         /// Signalizes that this node has a (native) context-aware menu. If set, the user can left-click the node to open the menu
@@ -13522,6 +15068,14 @@ pub mod menu {
         pub fn set_callback<_1: Into<RefAny>>(&mut self, data: _1, callback: CallbackType)  { unsafe { crate::dll::AzStringMenuItem_setCallback(self, data.into(), callback) } }
         /// Adds a callback to the menu item
         pub fn with_callback<_1: Into<RefAny>>(&mut self, data: _1, callback: CallbackType)  -> crate::menu::StringMenuItem { unsafe { crate::dll::AzStringMenuItem_withCallback(self, data.into(), callback) } }
+        /// Sets the keyboard shortcut of the menu item, displayed next to the label
+        pub fn set_accelerator<_1: Into<VirtualKeyCodeCombo>>(&mut self, accelerator: _1)  { unsafe { crate::dll::AzStringMenuItem_setAccelerator(self, accelerator.into()) } }
+        /// Sets the keyboard shortcut of the menu item, displayed next to the label (builder method)
+        pub fn with_accelerator<_1: Into<VirtualKeyCodeCombo>>(&mut self, accelerator: _1)  -> crate::menu::StringMenuItem { unsafe { crate::dll::AzStringMenuItem_withAccelerator(self, accelerator.into()) } }
+        /// Sets the state (normal, greyed, disabled) of the menu item, the callback of greyed or disabled items is not invoked
+        pub fn set_state<_1: Into<MenuItemState>>(&mut self, state: _1)  { unsafe { crate::dll::AzStringMenuItem_setState(self, state.into()) } }
+        /// Sets the state (normal, greyed, disabled) of the menu item (builder method)
+        pub fn with_state<_1: Into<MenuItemState>>(&mut self, state: _1)  -> crate::menu::StringMenuItem { unsafe { crate::dll::AzStringMenuItem_withState(self, state.into()) } }
         /// Adds a single child submenu to the current menu
         pub fn add_child<_1: Into<MenuItem>>(&mut self, child: _1)  { unsafe { crate::dll::AzStringMenuItem_addChild(self, child.into()) } }
         /// Adds a single child submenu to the current menu
@@ -13559,13 +15113,8 @@ pub mod css {
     use core::ffi::c_void;
 
     use crate::vec::{
-        StyleBackgroundPositionVec,
-        StyleBackgroundContentVec,
-        StyleBackgroundSizeVec,
-        StyleBackgroundRepeatVec,
-        StyleTransformVec,
-        StyleFontFamilyVec,
-        StyleFilterVec,
+        GridTrackVec,
+        CssPropertyTypeVec,
     };
 
     macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => ({
@@ -13574,6 +15123,8 @@ pub mod css {
             CssPropertyType::FontSize => CssProperty::FontSize(StyleFontSizeValue::$content_type),
             CssPropertyType::FontFamily => CssProperty::FontFamily(StyleFontFamilyVecValue::$content_type),
             CssPropertyType::TextAlign => CssProperty::TextAlign(StyleTextAlignValue::$content_type),
+            CssPropertyType::VerticalAlign => CssProperty::VerticalAlign(StyleVerticalAlignValue::$content_type),
+            CssPropertyType::Direction => CssProperty::Direction(StyleDirectionValue::$content_type),
             CssPropertyType::LetterSpacing => CssProperty::LetterSpacing(StyleLetterSpacingValue::$content_type),
             CssPropertyType::LineHeight => CssProperty::LineHeight(StyleLineHeightValue::$content_type),
             CssPropertyType::WordSpacing => CssProperty::WordSpacing(StyleWordSpacingValue::$content_type),
@@ -13600,6 +15151,10 @@ pub mod css {
             CssPropertyType::JustifyContent => CssProperty::JustifyContent(LayoutJustifyContentValue::$content_type),
             CssPropertyType::AlignItems => CssProperty::AlignItems(LayoutAlignItemsValue::$content_type),
             CssPropertyType::AlignContent => CssProperty::AlignContent(LayoutAlignContentValue::$content_type),
+            CssPropertyType::GridTemplateColumns => CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::$content_type),
+            CssPropertyType::GridTemplateRows => CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::$content_type),
+            CssPropertyType::GridColumn => CssProperty::GridColumn(LayoutGridColumnValue::$content_type),
+            CssPropertyType::GridRow => CssProperty::GridRow(LayoutGridRowValue::$content_type),
             CssPropertyType::BackgroundContent => CssProperty::BackgroundContent(StyleBackgroundContentVecValue::$content_type),
             CssPropertyType::BackgroundPosition => CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::$content_type),
             CssPropertyType::BackgroundSize => CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::$content_type),
@@ -13641,12 +15196,48 @@ pub mod css {
             CssPropertyType::TransformOrigin => CssProperty::TransformOrigin(StyleTransformOriginValue::$content_type),
             CssPropertyType::BackfaceVisibility => CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::$content_type),
             CssPropertyType::MixBlendMode => CssProperty::MixBlendMode(StyleMixBlendModeValue::$content_type),
+            CssPropertyType::Isolation => CssProperty::Isolation(StyleIsolationValue::$content_type),
+            CssPropertyType::ImageRendering => CssProperty::ImageRendering(StyleImageRenderingValue::$content_type),
             CssPropertyType::Filter => CssProperty::Filter(StyleFilterVecValue::$content_type),
             CssPropertyType::BackdropFilter => CssProperty::BackdropFilter(StyleFilterVecValue::$content_type),
             CssPropertyType::TextShadow => CssProperty::TextShadow(StyleBoxShadowValue::$content_type),
+            CssPropertyType::TransitionProperty => CssProperty::TransitionProperty(StyleTransitionPropertyValue::$content_type),
+            CssPropertyType::TransitionDuration => CssProperty::TransitionDuration(StyleTransitionDurationValue::$content_type),
+            CssPropertyType::TransitionTimingFunction => CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionValue::$content_type),
+            CssPropertyType::TransitionDelay => CssProperty::TransitionDelay(StyleTransitionDelayValue::$content_type),
+            CssPropertyType::CaretColor => CssProperty::CaretColor(StyleCaretColorValue::$content_type),
+            CssPropertyType::SelectionColor => CssProperty::SelectionColor(StyleSelectionColorValue::$content_type),
+            CssPropertyType::SelectionBackground => CssProperty::SelectionBackground(StyleSelectionBackgroundValue::$content_type),
         }
     })}
 
+    macro_rules! impl_boxed_new_static {($($boxed:ident: $value:ident),*) => ($(
+        impl $boxed {
+            /// Value for a `static` that can be used in the `const` constructors of the boxed
+            /// `Exact` variants (i.e. `CssProperty::const_font_family`), the library never frees it
+            pub const fn new_static(value: $value) -> Self { Self { run_destructor: false, value } }
+        }
+    )*)}
+
+    impl_boxed_new_static!(
+        CalcLengthBoxed: CalcLength,
+        LayoutGridTemplateColumnsBoxed: LayoutGridTemplateColumns,
+        LayoutGridTemplateRowsBoxed: LayoutGridTemplateRows,
+        StyleBackgroundContentVecBoxed: StyleBackgroundContentVec,
+        StyleBackgroundPositionVecBoxed: StyleBackgroundPositionVec,
+        StyleBackgroundRepeatVecBoxed: StyleBackgroundRepeatVec,
+        StyleBackgroundSizeVecBoxed: StyleBackgroundSizeVec,
+        StyleBoxShadowBoxed: StyleBoxShadow,
+        StyleFilterVecBoxed: StyleFilterVec,
+        StyleFontFamilyVecBoxed: StyleFontFamilyVec,
+        StylePerspectiveOriginBoxed: StylePerspectiveOrigin,
+        StyleTransformOriginBoxed: StyleTransformOrigin,
+        StyleTransformVecBoxed: StyleTransformVec,
+        StyleTransitionPropertyBoxed: StyleTransitionProperty,
+        StyleTransitionTimingFunctionBoxed: StyleTransitionTimingFunction,
+        StyleVerticalAlignBoxed: StyleVerticalAlign
+    );
+
     impl CssProperty {
 
         /// Return the type (key) of this property as a statically typed enum
@@ -13656,6 +15247,8 @@ pub mod css {
                 CssProperty::FontSize(_) => CssPropertyType::FontSize,
                 CssProperty::FontFamily(_) => CssPropertyType::FontFamily,
                 CssProperty::TextAlign(_) => CssPropertyType::TextAlign,
+                CssProperty::VerticalAlign(_) => CssPropertyType::VerticalAlign,
+                CssProperty::Direction(_) => CssPropertyType::Direction,
                 CssProperty::LetterSpacing(_) => CssPropertyType::LetterSpacing,
                 CssProperty::LineHeight(_) => CssPropertyType::LineHeight,
                 CssProperty::WordSpacing(_) => CssPropertyType::WordSpacing,
@@ -13682,6 +15275,10 @@ pub mod css {
                 CssProperty::JustifyContent(_) => CssPropertyType::JustifyContent,
                 CssProperty::AlignItems(_) => CssPropertyType::AlignItems,
                 CssProperty::AlignContent(_) => CssPropertyType::AlignContent,
+                CssProperty::GridTemplateColumns(_) => CssPropertyType::GridTemplateColumns,
+                CssProperty::GridTemplateRows(_) => CssPropertyType::GridTemplateRows,
+                CssProperty::GridColumn(_) => CssPropertyType::GridColumn,
+                CssProperty::GridRow(_) => CssPropertyType::GridRow,
                 CssProperty::BackgroundContent(_) => CssPropertyType::BackgroundContent,
                 CssProperty::BackgroundPosition(_) => CssPropertyType::BackgroundPosition,
                 CssProperty::BackgroundSize(_) => CssPropertyType::BackgroundSize,
@@ -13723,9 +15320,18 @@ pub mod css {
                 CssProperty::TransformOrigin(_) => CssPropertyType::TransformOrigin,
                 CssProperty::BackfaceVisibility(_) => CssPropertyType::BackfaceVisibility,
                 CssProperty::MixBlendMode(_) => CssPropertyType::MixBlendMode,
+                CssProperty::Isolation(_) => CssPropertyType::Isolation,
+                CssProperty::ImageRendering(_) => CssPropertyType::ImageRendering,
                 CssProperty::Filter(_) => CssPropertyType::Filter,
                 CssProperty::BackdropFilter(_) => CssPropertyType::BackdropFilter,
                 CssProperty::TextShadow(_) => CssPropertyType::TextShadow,
+                CssProperty::TransitionProperty(_) => CssPropertyType::TransitionProperty,
+                CssProperty::TransitionDuration(_) => CssPropertyType::TransitionDuration,
+                CssProperty::TransitionTimingFunction(_) => CssPropertyType::TransitionTimingFunction,
+                CssProperty::TransitionDelay(_) => CssPropertyType::TransitionDelay,
+                CssProperty::CaretColor(_) => CssPropertyType::CaretColor,
+                CssProperty::SelectionColor(_) => CssPropertyType::SelectionColor,
+                CssProperty::SelectionBackground(_) => CssPropertyType::SelectionBackground,
             }
         }
 
@@ -13738,8 +15344,12 @@ pub mod css {
 
         pub const fn text_color(input: StyleTextColor) -> Self { CssProperty::TextColor(StyleTextColorValue::Exact(input)) }
         pub const fn font_size(input: StyleFontSize) -> Self { CssProperty::FontSize(StyleFontSizeValue::Exact(input)) }
-        pub const fn font_family(input: StyleFontFamilyVec) -> Self { CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(input)) }
+        pub const fn const_font_family(input: &'static StyleFontFamilyVecBoxed) -> Self { CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(input)) }
+        pub fn font_family(input: StyleFontFamilyVec) -> Self { CssProperty::FontFamily(StyleFontFamilyVecValue::exact(input)) }
         pub const fn text_align(input: StyleTextAlign) -> Self { CssProperty::TextAlign(StyleTextAlignValue::Exact(input)) }
+        pub const fn const_vertical_align(input: &'static StyleVerticalAlignBoxed) -> Self { CssProperty::VerticalAlign(StyleVerticalAlignValue::Exact(input)) }
+        pub fn vertical_align(input: StyleVerticalAlign) -> Self { CssProperty::VerticalAlign(StyleVerticalAlignValue::exact(input)) }
+        pub const fn direction(input: StyleDirection) -> Self { CssProperty::Direction(StyleDirectionValue::Exact(input)) }
         pub const fn letter_spacing(input: StyleLetterSpacing) -> Self { CssProperty::LetterSpacing(StyleLetterSpacingValue::Exact(input)) }
         pub const fn line_height(input: StyleLineHeight) -> Self { CssProperty::LineHeight(StyleLineHeightValue::Exact(input)) }
         pub const fn word_spacing(input: StyleWordSpacing) -> Self { CssProperty::WordSpacing(StyleWordSpacingValue::Exact(input)) }
@@ -13766,10 +15376,20 @@ pub mod css {
         pub const fn justify_content(input: LayoutJustifyContent) -> Self { CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(input)) }
         pub const fn align_items(input: LayoutAlignItems) -> Self { CssProperty::AlignItems(LayoutAlignItemsValue::Exact(input)) }
         pub const fn align_content(input: LayoutAlignContent) -> Self { CssProperty::AlignContent(LayoutAlignContentValue::Exact(input)) }
-        pub const fn background_content(input: StyleBackgroundContentVec) -> Self { CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(input)) }
-        pub const fn background_position(input: StyleBackgroundPositionVec) -> Self { CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::Exact(input)) }
-        pub const fn background_size(input: StyleBackgroundSizeVec) -> Self { CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::Exact(input)) }
-        pub const fn background_repeat(input: StyleBackgroundRepeatVec) -> Self { CssProperty::BackgroundRepeat(StyleBackgroundRepeatVecValue::Exact(input)) }
+        pub const fn const_grid_template_columns(input: &'static LayoutGridTemplateColumnsBoxed) -> Self { CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::Exact(input)) }
+        pub fn grid_template_columns(input: LayoutGridTemplateColumns) -> Self { CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::exact(input)) }
+        pub const fn const_grid_template_rows(input: &'static LayoutGridTemplateRowsBoxed) -> Self { CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::Exact(input)) }
+        pub fn grid_template_rows(input: LayoutGridTemplateRows) -> Self { CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::exact(input)) }
+        pub const fn grid_column(input: LayoutGridColumn) -> Self { CssProperty::GridColumn(LayoutGridColumnValue::Exact(input)) }
+        pub const fn grid_row(input: LayoutGridRow) -> Self { CssProperty::GridRow(LayoutGridRowValue::Exact(input)) }
+        pub const fn const_background_content(input: &'static StyleBackgroundContentVecBoxed) -> Self { CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(input)) }
+        pub fn background_content(input: StyleBackgroundContentVec) -> Self { CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact(input)) }
+        pub const fn const_background_position(input: &'static StyleBackgroundPositionVecBoxed) -> Self { CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::Exact(input)) }
+        pub fn background_position(input: StyleBackgroundPositionVec) -> Self { CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::exact(input)) }
+        pub const fn const_background_size(input: &'static StyleBackgroundSizeVecBoxed) -> Self { CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::Exact(input)) }
+        pub fn background_size(input: StyleBackgroundSizeVec) -> Self { CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::exact(input)) }
+        pub const fn const_background_repeat(input: &'static StyleBackgroundRepeatVecBoxed) -> Self { CssProperty::BackgroundRepeat(StyleBackgroundRepeatVecValue::Exact(input)) }
+        pub fn background_repeat(input: StyleBackgroundRepeatVec) -> Self { CssProperty::BackgroundRepeat(StyleBackgroundRepeatVecValue::exact(input)) }
         pub const fn overflow_x(input: LayoutOverflow) -> Self { CssProperty::OverflowX(LayoutOverflowValue::Exact(input)) }
         pub const fn overflow_y(input: LayoutOverflow) -> Self { CssProperty::OverflowY(LayoutOverflowValue::Exact(input)) }
         pub const fn padding_top(input: LayoutPaddingTop) -> Self { CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(input)) }
@@ -13796,19 +15416,40 @@ pub mod css {
         pub const fn border_right_width(input: LayoutBorderRightWidth) -> Self { CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(input)) }
         pub const fn border_left_width(input: LayoutBorderLeftWidth) -> Self { CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(input)) }
         pub const fn border_bottom_width(input: LayoutBorderBottomWidth) -> Self { CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(input)) }
-        pub const fn box_shadow_left(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(input)) }
-        pub const fn box_shadow_right(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(input)) }
-        pub const fn box_shadow_top(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(input)) }
-        pub const fn box_shadow_bottom(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(input)) }
+        pub const fn const_box_shadow_left(input: &'static StyleBoxShadowBoxed) -> Self { CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(input)) }
+        pub fn box_shadow_left(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowLeft(StyleBoxShadowValue::exact(input)) }
+        pub const fn const_box_shadow_right(input: &'static StyleBoxShadowBoxed) -> Self { CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(input)) }
+        pub fn box_shadow_right(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowRight(StyleBoxShadowValue::exact(input)) }
+        pub const fn const_box_shadow_top(input: &'static StyleBoxShadowBoxed) -> Self { CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(input)) }
+        pub fn box_shadow_top(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowTop(StyleBoxShadowValue::exact(input)) }
+        pub const fn const_box_shadow_bottom(input: &'static StyleBoxShadowBoxed) -> Self { CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(input)) }
+        pub fn box_shadow_bottom(input: StyleBoxShadow) -> Self { CssProperty::BoxShadowBottom(StyleBoxShadowValue::exact(input)) }
         pub const fn opacity(input: StyleOpacity) -> Self { CssProperty::Opacity(StyleOpacityValue::Exact(input)) }
-        pub const fn transform(input: StyleTransformVec) -> Self { CssProperty::Transform(StyleTransformVecValue::Exact(input)) }
-        pub const fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
-        pub const fn perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input)) }
+        pub const fn const_transform(input: &'static StyleTransformVecBoxed) -> Self { CssProperty::Transform(StyleTransformVecValue::Exact(input)) }
+        pub fn transform(input: StyleTransformVec) -> Self { CssProperty::Transform(StyleTransformVecValue::exact(input)) }
+        pub const fn const_transform_origin(input: &'static StyleTransformOriginBoxed) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
+        pub fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::exact(input)) }
+        pub const fn const_perspective_origin(input: &'static StylePerspectiveOriginBoxed) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input)) }
+        pub fn perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::exact(input)) }
         pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self { CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input)) }
         pub const fn mix_blend_mode(input: StyleMixBlendMode) -> Self { CssProperty::MixBlendMode(StyleMixBlendModeValue::Exact(input)) }
-        pub const fn filter(input: StyleFilterVec) -> Self { CssProperty::Filter(StyleFilterVecValue::Exact(input)) }
-        pub const fn backdrop_filter(input: StyleFilterVec) -> Self { CssProperty::BackdropFilter(StyleFilterVecValue::Exact(input)) }
-        pub const fn text_shadow(input: StyleBoxShadow) -> Self { CssProperty::TextShadow(StyleBoxShadowValue::Exact(input)) }
+        pub const fn isolation(input: StyleIsolation) -> Self { CssProperty::Isolation(StyleIsolationValue::Exact(input)) }
+        pub const fn image_rendering(input: StyleImageRendering) -> Self { CssProperty::ImageRendering(StyleImageRenderingValue::Exact(input)) }
+        pub const fn const_filter(input: &'static StyleFilterVecBoxed) -> Self { CssProperty::Filter(StyleFilterVecValue::Exact(input)) }
+        pub fn filter(input: StyleFilterVec) -> Self { CssProperty::Filter(StyleFilterVecValue::exact(input)) }
+        pub const fn const_backdrop_filter(input: &'static StyleFilterVecBoxed) -> Self { CssProperty::BackdropFilter(StyleFilterVecValue::Exact(input)) }
+        pub fn backdrop_filter(input: StyleFilterVec) -> Self { CssProperty::BackdropFilter(StyleFilterVecValue::exact(input)) }
+        pub const fn const_text_shadow(input: &'static StyleBoxShadowBoxed) -> Self { CssProperty::TextShadow(StyleBoxShadowValue::Exact(input)) }
+        pub fn text_shadow(input: StyleBoxShadow) -> Self { CssProperty::TextShadow(StyleBoxShadowValue::exact(input)) }
+        pub const fn const_transition_property(input: &'static StyleTransitionPropertyBoxed) -> Self { CssProperty::TransitionProperty(StyleTransitionPropertyValue::Exact(input)) }
+        pub fn transition_property(input: StyleTransitionProperty) -> Self { CssProperty::TransitionProperty(StyleTransitionPropertyValue::exact(input)) }
+        pub const fn transition_duration(input: StyleTransitionDuration) -> Self { CssProperty::TransitionDuration(StyleTransitionDurationValue::Exact(input)) }
+        pub const fn const_transition_timing_function(input: &'static StyleTransitionTimingFunctionBoxed) -> Self { CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionValue::Exact(input)) }
+        pub fn transition_timing_function(input: StyleTransitionTimingFunction) -> Self { CssProperty::TransitionTimingFunction(StyleTransitionTimingFunctionValue::exact(input)) }
+        pub const fn transition_delay(input: StyleTransitionDelay) -> Self { CssProperty::TransitionDelay(StyleTransitionDelayValue::Exact(input)) }
+        pub const fn caret_color(input: StyleCaretColor) -> Self { CssProperty::CaretColor(StyleCaretColorValue::Exact(input)) }
+        pub const fn selection_color(input: StyleSelectionColor) -> Self { CssProperty::SelectionColor(StyleSelectionColorValue::Exact(input)) }
+        pub const fn selection_background(input: StyleSelectionBackground) -> Self { CssProperty::SelectionBackground(StyleSelectionBackgroundValue::Exact(input)) }
     }
 
     const FP_PRECISION_MULTIPLIER: f32 = 1000.0;
@@ -14209,6 +15850,7 @@ pub mod css {
     impl_percentage_value!(StyleTabWidth);
     impl_percentage_value!(StyleOpacity);
     use crate::str::String;
+    use crate::vec::{StyleBackgroundContentVec, StyleBackgroundPositionVec, StyleBackgroundRepeatVec, StyleBackgroundSizeVec, StyleFilterVec, StyleFontFamilyVec, StyleTransformVec};
     /// `CssRuleBlock` struct
     
     #[doc(inline)] pub use crate::dll::AzCssRuleBlock as CssRuleBlock;
@@ -14248,8 +15890,30 @@ pub mod css {
         pub fn empty() -> Self { unsafe { crate::dll::AzCss_empty() } }
         /// Returns a CSS style parsed from a `String`
         pub fn from_string<_1: Into<String>>(s: _1) -> Self { unsafe { crate::dll::AzCss_fromString(s.into()) } }
+        /// Sets the value of the CSS variable `--name` (the leading `--` is optional). The value is only used by `var(--name)` declarations of the same property type, i.e. setting a background only affects `background: var(--name)`. The new value is applied when the CSS is used to style a DOM again.
+        pub fn set_variable<_1: Into<String>, _2: Into<CssProperty>>(&mut self, name: _1, value: _2)  { unsafe { crate::dll::AzCss_setVariable(self, name.into(), value.into()) } }
+        /// Replaces all `var(--name)` declarations with the current values of the variables. Variables that are set afterwards have no effect anymore.
+        pub fn resolve_variables(&mut self)  { unsafe { crate::dll::AzCss_resolveVariables(self) } }
     }
 
+    /// Value of a CSS variable (`--name`) that is set at runtime
+    
+    #[doc(inline)] pub use crate::dll::AzCssVariable as CssVariable;
+    /// Condition of an `@media` group, i.e. `(max-width: 600px), (orientation: portrait)`: the group applies if any of the conditions matches
+    
+    #[doc(inline)] pub use crate::dll::AzMediaQuery as MediaQuery;
+    /// Media features combined with `and`, i.e. `(min-width: 600px) and (orientation: portrait)`
+    
+    #[doc(inline)] pub use crate::dll::AzMediaCondition as MediaCondition;
+    /// One `(feature: value)` or `(feature < value)` test of a `MediaCondition`
+    
+    #[doc(inline)] pub use crate::dll::AzMediaFeature as MediaFeature;
+    /// Value of the `orientation` media feature
+    
+    #[doc(inline)] pub use crate::dll::AzMediaOrientation as MediaOrientation;
+    /// Value of the `prefers-color-scheme` media feature
+    
+    #[doc(inline)] pub use crate::dll::AzMediaColorScheme as MediaColorScheme;
     /// `CssPropertyType` struct
     
     #[doc(inline)] pub use crate::dll::AzCssPropertyType as CssPropertyType;
@@ -14274,6 +15938,22 @@ pub mod css {
         pub fn black() -> Self { unsafe { crate::dll::AzColorU_black() } }
         /// Calls the `ColorU::to_hash` function.
         pub fn to_hash(&self)  -> crate::str::String { unsafe { crate::dll::AzColorU_toHash(self) } }
+        /// Multiplies the color with a tint, component-wise (including alpha) in normalized (0.0 - 1.0) space
+        pub fn multiply<_1: Into<ColorU>>(&self, other: _1)  -> crate::css::ColorU { unsafe { crate::dll::AzColorU_multiply(self, other.into()) } }
+        /// Adds two colors component-wise (including alpha), clamping each component at 255
+        pub fn add_saturating<_1: Into<ColorU>>(&self, other: _1)  -> crate::css::ColorU { unsafe { crate::dll::AzColorU_addSaturating(self, other.into()) } }
+        /// Rotates the hue of the color (in HSL space) by the given amount of degrees, preserving saturation, lightness and alpha
+        pub fn hue_rotate(&self, degrees: f32)  -> crate::css::ColorU { unsafe { crate::dll::AzColorU_hueRotate(self, degrees) } }
+        /// Returns a copy of the color with the red channel replaced
+        pub fn with_r(&self, r: u8)  -> crate::css::ColorU { unsafe { crate::dll::AzColorU_withR(self, r) } }
+        /// Returns a copy of the color with the green channel replaced
+        pub fn with_g(&self, g: u8)  -> crate::css::ColorU { unsafe { crate::dll::AzColorU_withG(self, g) } }
+        /// Returns a copy of the color with the blue channel replaced
+        pub fn with_b(&self, b: u8)  -> crate::css::ColorU { unsafe { crate::dll::AzColorU_withB(self, b) } }
+        /// Returns a copy of the color with the alpha channel replaced
+        pub fn with_a(&self, a: u8)  -> crate::css::ColorU { unsafe { crate::dll::AzColorU_withA(self, a) } }
+        /// Returns a copy of the color with the alpha set from a normalized (0.0 - 1.0) value
+        pub fn with_alpha_f32(&self, a: f32)  -> crate::css::ColorU { unsafe { crate::dll::AzColorU_withAlphaF32(self, a) } }
     }
 
     /// `SizeMetric` struct
@@ -14285,6 +15965,12 @@ pub mod css {
     /// `PixelValue` struct
     
     #[doc(inline)] pub use crate::dll::AzPixelValue as PixelValue;
+    /// Simplified `calc()` expression: sum of lengths with different metrics, i.e. `calc(100% - 32px)` is stored as `{ percent: 100, px: -32 }`
+    
+    #[doc(inline)] pub use crate::dll::AzCalcLength as CalcLength;
+    /// Value of a boxed `Calc` length: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzCalcLengthBoxed as CalcLengthBoxed;
     /// `PixelValueNoPercent` struct
     
     #[doc(inline)] pub use crate::dll::AzPixelValueNoPercent as PixelValueNoPercent;
@@ -14297,6 +15983,12 @@ pub mod css {
     /// `StyleMixBlendMode` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleMixBlendMode as StyleMixBlendMode;
+    /// Represents an `isolation` attribute: whether the element creates a new stacking context for `mix-blend-mode`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleIsolation as StyleIsolation;
+    /// Represents an `image-rendering` attribute: which algorithm is used to scale images and background images
+    
+    #[doc(inline)] pub use crate::dll::AzStyleImageRendering as StyleImageRendering;
     /// `StyleFilter` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleFilter as StyleFilter;
@@ -14330,6 +16022,30 @@ pub mod css {
     /// `LayoutDisplay` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutDisplay as LayoutDisplay;
+    /// Single track size of a `grid-template-columns` or `grid-template-rows` attribute
+    
+    #[doc(inline)] pub use crate::dll::AzGridTrack as GridTrack;
+    /// Argument of a `repeat()` track
+    
+    #[doc(inline)] pub use crate::dll::AzGridTrackRepeat as GridTrackRepeat;
+    /// Start or end line of a `grid-column` or `grid-row` attribute
+    
+    #[doc(inline)] pub use crate::dll::AzGridLine as GridLine;
+    /// Start / end line pair of a `grid-column` or `grid-row` attribute
+    
+    #[doc(inline)] pub use crate::dll::AzGridPlacement as GridPlacement;
+    /// `LayoutGridTemplateColumns` struct
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutGridTemplateColumns as LayoutGridTemplateColumns;
+    /// `LayoutGridTemplateRows` struct
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutGridTemplateRows as LayoutGridTemplateRows;
+    /// `LayoutGridColumn` struct
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutGridColumn as LayoutGridColumn;
+    /// `LayoutGridRow` struct
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutGridRow as LayoutGridRow;
     /// `LayoutFlexGrow` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutFlexGrow as LayoutFlexGrow;
@@ -14585,24 +16301,105 @@ pub mod css {
     /// `StyleTextAlign` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextAlign as StyleTextAlign;
+    /// Represents a `direction` attribute: the inline base direction of text and of `flex-direction: row` - default: `Ltr`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleDirection as StyleDirection;
+    /// Represents a `transition-property` attribute: which properties are animated when they change - default: `All`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransitionProperty as StyleTransitionProperty;
+    /// Represents a `transition-duration` attribute, in milliseconds
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransitionDuration as StyleTransitionDuration;
+    /// Represents a `transition-delay` attribute, in milliseconds (can be negative)
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransitionDelay as StyleTransitionDelay;
+    /// Position of the jumps of a `steps()` timing function - default: `JumpEnd`
+    
+    #[doc(inline)] pub use crate::dll::AzStepPosition as StepPosition;
+    /// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCubicBezier as StyleCubicBezier;
+    /// A `steps(count, position)` timing function
+    
+    #[doc(inline)] pub use crate::dll::AzStyleSteps as StyleSteps;
+    /// Represents a `transition-timing-function` attribute - default: `Ease`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransitionTimingFunction as StyleTransitionTimingFunction;
+    /// Represents a `vertical-align` attribute, aligns an inline item (i.e. an icon next to text) inside of its line - default: `Baseline`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleVerticalAlign as StyleVerticalAlign;
     /// `StyleTextColor` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextColor as StyleTextColor;
+    /// Represents a `caret-color` attribute: the color of the text cursor in editable text
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCaretColor as StyleCaretColor;
+    /// Represents a `-azul-selection-color` attribute: the text color of selected text (same as `::selection { color }`)
+    
+    #[doc(inline)] pub use crate::dll::AzStyleSelectionColor as StyleSelectionColor;
+    /// Represents a `-azul-selection-background-color` attribute: the highlight color behind selected text
+    
+    #[doc(inline)] pub use crate::dll::AzStyleSelectionBackground as StyleSelectionBackground;
     /// `StyleWordSpacing` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleWordSpacing as StyleWordSpacing;
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleBoxShadowBoxed as StyleBoxShadowBoxed;
     /// `StyleBoxShadowValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBoxShadowValue as StyleBoxShadowValue;
+    impl StyleBoxShadowValue {
+
+        /// Moves the `StyleBoxShadow` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleBoxShadow>>(shadow: _1) -> Self { unsafe { crate::dll::AzStyleBoxShadowValue_exact(shadow.into()) } }
+    }
+
     /// `LayoutAlignContentValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutAlignContentValue as LayoutAlignContentValue;
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutGridTemplateColumnsBoxed as LayoutGridTemplateColumnsBoxed;
+    /// `LayoutGridTemplateColumnsValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutGridTemplateColumnsValue as LayoutGridTemplateColumnsValue;
+    impl LayoutGridTemplateColumnsValue {
+
+        /// Moves the `LayoutGridTemplateColumns` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<LayoutGridTemplateColumns>>(value: _1) -> Self { unsafe { crate::dll::AzLayoutGridTemplateColumnsValue_exact(value.into()) } }
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutGridTemplateRowsBoxed as LayoutGridTemplateRowsBoxed;
+    /// `LayoutGridTemplateRowsValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutGridTemplateRowsValue as LayoutGridTemplateRowsValue;
+    impl LayoutGridTemplateRowsValue {
+
+        /// Moves the `LayoutGridTemplateRows` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<LayoutGridTemplateRows>>(value: _1) -> Self { unsafe { crate::dll::AzLayoutGridTemplateRowsValue_exact(value.into()) } }
+    }
+
+    /// `LayoutGridColumnValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutGridColumnValue as LayoutGridColumnValue;
+    /// `LayoutGridRowValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzLayoutGridRowValue as LayoutGridRowValue;
     /// `LayoutAlignItemsValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutAlignItemsValue as LayoutAlignItemsValue;
     /// `LayoutBottomValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutBottomValue as LayoutBottomValue;
+    impl LayoutBottomValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutBottomValue_calc(calc.into()) } }
+    }
+
     /// `LayoutBoxSizingValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutBoxSizingValue as LayoutBoxSizingValue;
@@ -14624,81 +16421,228 @@ pub mod css {
     /// `LayoutHeightValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutHeightValue as LayoutHeightValue;
+    impl LayoutHeightValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutHeightValue_calc(calc.into()) } }
+    }
+
     /// `LayoutJustifyContentValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutJustifyContentValue as LayoutJustifyContentValue;
     /// `LayoutLeftValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutLeftValue as LayoutLeftValue;
+    impl LayoutLeftValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutLeftValue_calc(calc.into()) } }
+    }
+
     /// `LayoutMarginBottomValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutMarginBottomValue as LayoutMarginBottomValue;
+    impl LayoutMarginBottomValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutMarginBottomValue_calc(calc.into()) } }
+    }
+
     /// `LayoutMarginLeftValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutMarginLeftValue as LayoutMarginLeftValue;
+    impl LayoutMarginLeftValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutMarginLeftValue_calc(calc.into()) } }
+    }
+
     /// `LayoutMarginRightValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutMarginRightValue as LayoutMarginRightValue;
+    impl LayoutMarginRightValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutMarginRightValue_calc(calc.into()) } }
+    }
+
     /// `LayoutMarginTopValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutMarginTopValue as LayoutMarginTopValue;
+    impl LayoutMarginTopValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutMarginTopValue_calc(calc.into()) } }
+    }
+
     /// `LayoutMaxHeightValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutMaxHeightValue as LayoutMaxHeightValue;
+    impl LayoutMaxHeightValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutMaxHeightValue_calc(calc.into()) } }
+    }
+
     /// `LayoutMaxWidthValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutMaxWidthValue as LayoutMaxWidthValue;
+    impl LayoutMaxWidthValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutMaxWidthValue_calc(calc.into()) } }
+    }
+
     /// `LayoutMinHeightValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutMinHeightValue as LayoutMinHeightValue;
+    impl LayoutMinHeightValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutMinHeightValue_calc(calc.into()) } }
+    }
+
     /// `LayoutMinWidthValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutMinWidthValue as LayoutMinWidthValue;
+    impl LayoutMinWidthValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutMinWidthValue_calc(calc.into()) } }
+    }
+
     /// `LayoutPaddingBottomValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutPaddingBottomValue as LayoutPaddingBottomValue;
+    impl LayoutPaddingBottomValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutPaddingBottomValue_calc(calc.into()) } }
+    }
+
     /// `LayoutPaddingLeftValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutPaddingLeftValue as LayoutPaddingLeftValue;
+    impl LayoutPaddingLeftValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutPaddingLeftValue_calc(calc.into()) } }
+    }
+
     /// `LayoutPaddingRightValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutPaddingRightValue as LayoutPaddingRightValue;
+    impl LayoutPaddingRightValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutPaddingRightValue_calc(calc.into()) } }
+    }
+
     /// `LayoutPaddingTopValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutPaddingTopValue as LayoutPaddingTopValue;
+    impl LayoutPaddingTopValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutPaddingTopValue_calc(calc.into()) } }
+    }
+
     /// `LayoutPositionValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutPositionValue as LayoutPositionValue;
     /// `LayoutRightValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutRightValue as LayoutRightValue;
+    impl LayoutRightValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutRightValue_calc(calc.into()) } }
+    }
+
     /// `LayoutTopValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutTopValue as LayoutTopValue;
+    impl LayoutTopValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutTopValue_calc(calc.into()) } }
+    }
+
     /// `LayoutWidthValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutWidthValue as LayoutWidthValue;
+    impl LayoutWidthValue {
+
+        /// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+        pub fn calc<_1: Into<CalcLength>>(calc: _1) -> Self { unsafe { crate::dll::AzLayoutWidthValue_calc(calc.into()) } }
+    }
+
     /// `LayoutFlexWrapValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutFlexWrapValue as LayoutFlexWrapValue;
     /// `LayoutOverflowValue` struct
     
     #[doc(inline)] pub use crate::dll::AzLayoutOverflowValue as LayoutOverflowValue;
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzScrollbarStyleBoxed as ScrollbarStyleBoxed;
     /// `ScrollbarStyleValue` struct
     
     #[doc(inline)] pub use crate::dll::AzScrollbarStyleValue as ScrollbarStyleValue;
+    impl ScrollbarStyleValue {
+
+        /// Moves the `ScrollbarStyle` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<ScrollbarStyle>>(style: _1) -> Self { unsafe { crate::dll::AzScrollbarStyleValue_exact(style.into()) } }
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleBackgroundContentVecBoxed as StyleBackgroundContentVecBoxed;
     /// `StyleBackgroundContentVecValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBackgroundContentVecValue as StyleBackgroundContentVecValue;
+    impl StyleBackgroundContentVecValue {
+
+        /// Moves the `StyleBackgroundContentVec` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleBackgroundContentVec>>(value: _1) -> Self { unsafe { crate::dll::AzStyleBackgroundContentVecValue_exact(value.into()) } }
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleBackgroundPositionVecBoxed as StyleBackgroundPositionVecBoxed;
     /// `StyleBackgroundPositionVecValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBackgroundPositionVecValue as StyleBackgroundPositionVecValue;
+    impl StyleBackgroundPositionVecValue {
+
+        /// Moves the `StyleBackgroundPositionVec` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleBackgroundPositionVec>>(value: _1) -> Self { unsafe { crate::dll::AzStyleBackgroundPositionVecValue_exact(value.into()) } }
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleBackgroundRepeatVecBoxed as StyleBackgroundRepeatVecBoxed;
     /// `StyleBackgroundRepeatVecValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBackgroundRepeatVecValue as StyleBackgroundRepeatVecValue;
+    impl StyleBackgroundRepeatVecValue {
+
+        /// Moves the `StyleBackgroundRepeatVec` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleBackgroundRepeatVec>>(value: _1) -> Self { unsafe { crate::dll::AzStyleBackgroundRepeatVecValue_exact(value.into()) } }
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleBackgroundSizeVecBoxed as StyleBackgroundSizeVecBoxed;
     /// `StyleBackgroundSizeVecValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBackgroundSizeVecValue as StyleBackgroundSizeVecValue;
+    impl StyleBackgroundSizeVecValue {
+
+        /// Moves the `StyleBackgroundSizeVec` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleBackgroundSizeVec>>(value: _1) -> Self { unsafe { crate::dll::AzStyleBackgroundSizeVecValue_exact(value.into()) } }
+    }
+
     /// `StyleBorderBottomColorValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBorderBottomColorValue as StyleBorderBottomColorValue;
@@ -14750,9 +16694,18 @@ pub mod css {
     /// `StyleCursorValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleCursorValue as StyleCursorValue;
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleFontFamilyVecBoxed as StyleFontFamilyVecBoxed;
     /// `StyleFontFamilyVecValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleFontFamilyVecValue as StyleFontFamilyVecValue;
+    impl StyleFontFamilyVecValue {
+
+        /// Moves the `StyleFontFamilyVec` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleFontFamilyVec>>(value: _1) -> Self { unsafe { crate::dll::AzStyleFontFamilyVecValue_exact(value.into()) } }
+    }
+
     /// `StyleFontSizeValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleFontSizeValue as StyleFontSizeValue;
@@ -14768,6 +16721,60 @@ pub mod css {
     /// `StyleTextAlignValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextAlignValue as StyleTextAlignValue;
+    /// `StyleDirectionValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleDirectionValue as StyleDirectionValue;
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransitionPropertyBoxed as StyleTransitionPropertyBoxed;
+    /// `StyleTransitionPropertyValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransitionPropertyValue as StyleTransitionPropertyValue;
+    impl StyleTransitionPropertyValue {
+
+        /// Moves the `StyleTransitionProperty` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleTransitionProperty>>(value: _1) -> Self { unsafe { crate::dll::AzStyleTransitionPropertyValue_exact(value.into()) } }
+    }
+
+    /// `StyleTransitionDurationValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransitionDurationValue as StyleTransitionDurationValue;
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransitionTimingFunctionBoxed as StyleTransitionTimingFunctionBoxed;
+    /// `StyleTransitionTimingFunctionValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransitionTimingFunctionValue as StyleTransitionTimingFunctionValue;
+    impl StyleTransitionTimingFunctionValue {
+
+        /// Moves the `StyleTransitionTimingFunction` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleTransitionTimingFunction>>(value: _1) -> Self { unsafe { crate::dll::AzStyleTransitionTimingFunctionValue_exact(value.into()) } }
+    }
+
+    /// `StyleTransitionDelayValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransitionDelayValue as StyleTransitionDelayValue;
+    /// `StyleCaretColorValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleCaretColorValue as StyleCaretColorValue;
+    /// `StyleSelectionColorValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleSelectionColorValue as StyleSelectionColorValue;
+    /// `StyleSelectionBackgroundValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleSelectionBackgroundValue as StyleSelectionBackgroundValue;
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleVerticalAlignBoxed as StyleVerticalAlignBoxed;
+    /// `StyleVerticalAlignValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleVerticalAlignValue as StyleVerticalAlignValue;
+    impl StyleVerticalAlignValue {
+
+        /// Moves the `StyleVerticalAlign` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleVerticalAlign>>(value: _1) -> Self { unsafe { crate::dll::AzStyleVerticalAlignValue_exact(value.into()) } }
+    }
+
     /// `StyleTextColorValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTextColorValue as StyleTextColorValue;
@@ -14777,24 +16784,66 @@ pub mod css {
     /// `StyleOpacityValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleOpacityValue as StyleOpacityValue;
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransformVecBoxed as StyleTransformVecBoxed;
     /// `StyleTransformVecValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTransformVecValue as StyleTransformVecValue;
+    impl StyleTransformVecValue {
+
+        /// Moves the `StyleTransformVec` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleTransformVec>>(value: _1) -> Self { unsafe { crate::dll::AzStyleTransformVecValue_exact(value.into()) } }
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleTransformOriginBoxed as StyleTransformOriginBoxed;
     /// `StyleTransformOriginValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTransformOriginValue as StyleTransformOriginValue;
+    impl StyleTransformOriginValue {
+
+        /// Moves the `StyleTransformOrigin` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleTransformOrigin>>(value: _1) -> Self { unsafe { crate::dll::AzStyleTransformOriginValue_exact(value.into()) } }
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStylePerspectiveOriginBoxed as StylePerspectiveOriginBoxed;
     /// `StylePerspectiveOriginValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStylePerspectiveOriginValue as StylePerspectiveOriginValue;
+    impl StylePerspectiveOriginValue {
+
+        /// Moves the `StylePerspectiveOrigin` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StylePerspectiveOrigin>>(value: _1) -> Self { unsafe { crate::dll::AzStylePerspectiveOriginValue_exact(value.into()) } }
+    }
+
     /// `StyleBackfaceVisibilityValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleBackfaceVisibilityValue as StyleBackfaceVisibilityValue;
     /// `StyleMixBlendModeValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleMixBlendModeValue as StyleMixBlendModeValue;
+    /// `StyleIsolationValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleIsolationValue as StyleIsolationValue;
+    /// `StyleImageRenderingValue` struct
+    
+    #[doc(inline)] pub use crate::dll::AzStyleImageRenderingValue as StyleImageRenderingValue;
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    
+    #[doc(inline)] pub use crate::dll::AzStyleFilterVecBoxed as StyleFilterVecBoxed;
     /// `StyleFilterVecValue` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleFilterVecValue as StyleFilterVecValue;
+    impl StyleFilterVecValue {
+
+        /// Moves the `StyleFilterVec` to the heap and wraps it in an `Exact` value
+        pub fn exact<_1: Into<StyleFilterVec>>(value: _1) -> Self { unsafe { crate::dll::AzStyleFilterVecValue_exact(value.into()) } }
+    }
+
     /// Parsed CSS key-value pair
     
     #[doc(inline)] pub use crate::dll::AzCssProperty as CssProperty;
@@ -18590,7 +20639,7 @@ pub mod task {
     //! Asyncronous timers / task / thread handlers for easy async loading
     use crate::dll::*;
     use core::ffi::c_void;
-    use crate::callbacks::{RefAny, TimerCallbackType};
+    use crate::callbacks::{RefAny, ThreadFnType, TimerCallbackType};
     use crate::time::Duration;
     /// `TimerId` struct
     
@@ -18616,17 +20665,26 @@ pub mod task {
     /// `ThreadId` struct
     
     #[doc(inline)] pub use crate::dll::AzThreadId as ThreadId;
-    /// `Thread` struct
+    /// Background thread, started via `CallbackInfo::start_thread`. The thread function receives the `thread_initialize_data`, a `ThreadSender` and a `ThreadReceiver` by value: in C, the thread function has to delete all three before it returns. Progress and results are sent back with `ThreadSender::send(ThreadReceiveMsg::WriteBack(..))`: the `WriteBackCallback` then runs on the main thread with the `writeback_data` and the sent data (which is deleted after the callback returns). Once the thread function has returned and all of its messages have been processed, the thread is removed and the `writeback_data` is deleted. `CallbackInfo::stop_thread` sends a `ThreadSendMsg::TerminateThread` and joins the thread, so the thread function should check `ThreadReceiver::receive` regularly and return early.
     
     #[doc(inline)] pub use crate::dll::AzThread as Thread;
     impl Clone for Thread { fn clone(&self) -> Self { unsafe { crate::dll::AzThread_deepCopy(self) } } }
     impl Drop for Thread { fn drop(&mut self) { if self.run_destructor { unsafe { crate::dll::AzThread_delete(self) } } } }
+    /// Background thread that has been created, but not started yet. Unlike `CallbackInfo::start_thread`, the data is moved back to the main thread once the thread function returns and passed to an `on_finish` callback. Start it with `CallbackInfo::start_pending_thread`, which consumes it - a thread that is never started has to be deleted.
+    
+    #[doc(inline)] pub use crate::dll::AzPendingThread as PendingThread;
+    impl PendingThread {
+
+        /// Creates a thread that runs `thread_fn` on `data` once it is started
+        pub fn new<_1: Into<RefAny>>(data: _1, thread_fn: ThreadFnType) -> Self { unsafe { crate::dll::AzPendingThread_new(data.into(), thread_fn) } }
+    }
+
     /// `ThreadSender` struct
     
     #[doc(inline)] pub use crate::dll::AzThreadSender as ThreadSender;
     impl ThreadSender {
 
-        /// Calls the `ThreadSender::send` function.
+        /// Sends a message from the background thread to the main thread, i.e. a `ThreadReceiveMsg::WriteBack` to update the application data with the progress of the thread.
         pub fn send<_1: Into<ThreadReceiveMsg>>(&mut self, msg: _1)  -> bool { unsafe { crate::dll::AzThreadSender_send(self, msg.into()) } }
     }
 
@@ -18637,7 +20695,7 @@ pub mod task {
     #[doc(inline)] pub use crate::dll::AzThreadReceiver as ThreadReceiver;
     impl ThreadReceiver {
 
-        /// Calls the `ThreadReceiver::receive` function.
+        /// Receives the next message from the main thread without blocking, i.e. `ThreadSendMsg::TerminateThread` if the thread should stop.
         pub fn receive(&mut self)  -> crate::option::OptionThreadSendMsg { unsafe { crate::dll::AzThreadReceiver_receive(self) } }
     }
 
@@ -18821,6 +20879,40 @@ pub mod str {
         pub fn as_refstr(&self)  -> crate::gl::Refstr { unsafe { crate::dll::AzString_asRefstr(self) } }
     }
 
+    /// Position of the text cursor (caret) in a UTF-8 string. `byte_offset` is always on a grapheme boundary, `grapheme_offset` is the number of graphemes before the cursor.
+    
+    #[doc(inline)] pub use crate::dll::AzTextCursor as TextCursor;
+    impl TextCursor {
+
+        /// Cursor before the first character
+        pub fn start() -> Self { unsafe { crate::dll::AzTextCursor_start() } }
+        /// Cursor after the last character of `text`
+        pub fn end<_1: Into<String>>(text: _1) -> Self { unsafe { crate::dll::AzTextCursor_end(text.into()) } }
+        /// Creates a cursor at `byte_offset`, moved back to the start of the grapheme if the offset is inside of a grapheme
+        pub fn from_byte_offset<_1: Into<String>>(text: _1, byte_offset: usize) -> Self { unsafe { crate::dll::AzTextCursor_fromByteOffset(text.into(), byte_offset) } }
+        /// Moves the cursor one grapheme to the right
+        pub fn next_grapheme<_1: Into<String>>(&self, text: _1)  -> crate::str::TextCursor { unsafe { crate::dll::AzTextCursor_nextGrapheme(self, text.into()) } }
+        /// Moves the cursor one grapheme to the left
+        pub fn prev_grapheme<_1: Into<String>>(&self, text: _1)  -> crate::str::TextCursor { unsafe { crate::dll::AzTextCursor_prevGrapheme(self, text.into()) } }
+        /// Moves the cursor to the end of the current or next word
+        pub fn next_word<_1: Into<String>>(&self, text: _1)  -> crate::str::TextCursor { unsafe { crate::dll::AzTextCursor_nextWord(self, text.into()) } }
+        /// Moves the cursor to the start of the current or previous word
+        pub fn prev_word<_1: Into<String>>(&self, text: _1)  -> crate::str::TextCursor { unsafe { crate::dll::AzTextCursor_prevWord(self, text.into()) } }
+    }
+
+    /// Selected range of a text. `start` is where the selection was started, `end` is where the caret is (before `start` if the text was selected backwards)
+    
+    #[doc(inline)] pub use crate::dll::AzTextSelection as TextSelection;
+    impl TextSelection {
+
+        /// Empty selection, only shows the caret
+        pub fn collapsed<_1: Into<TextCursor>>(cursor: _1) -> Self { unsafe { crate::dll::AzTextSelection_collapsed(cursor.into()) } }
+        /// Calls the `TextSelection::is_collapsed` function.
+        pub fn is_collapsed(&self)  -> bool { unsafe { crate::dll::AzTextSelection_isCollapsed(self) } }
+        /// Returns the selection with `start <= end`
+        pub fn normalized(&self)  -> crate::str::TextSelection { unsafe { crate::dll::AzTextSelection_normalized(self) } }
+    }
+
 }
 
 pub mod vec {
@@ -19115,6 +21207,16 @@ pub mod vec {
     impl_vec_clone!(AzIdOrClass, AzIdOrClassVec, IdOrClassVecDestructor);
     impl_vec!(AzStyleTransform, AzStyleTransformVec, AzStyleTransformVecDestructor, az_style_transform_vec_destructor, AzStyleTransformVec_delete);
     impl_vec_clone!(AzStyleTransform, AzStyleTransformVec, AzStyleTransformVecDestructor);
+    impl_vec!(AzGridTrack, AzGridTrackVec, AzGridTrackVecDestructor, az_grid_track_vec_destructor, AzGridTrackVec_delete);
+    impl_vec_clone!(AzGridTrack, AzGridTrackVec, AzGridTrackVecDestructor);
+    impl_vec!(AzCssPropertyType, AzCssPropertyTypeVec, AzCssPropertyTypeVecDestructor, az_css_property_type_vec_destructor, AzCssPropertyTypeVec_delete);
+    impl_vec_clone!(AzCssPropertyType, AzCssPropertyTypeVec, AzCssPropertyTypeVecDestructor);
+    impl_vec!(AzCssVariable, AzCssVariableVec, AzCssVariableVecDestructor, az_css_variable_vec_destructor, AzCssVariableVec_delete);
+    impl_vec_clone!(AzCssVariable, AzCssVariableVec, AzCssVariableVecDestructor);
+    impl_vec!(AzMediaFeature, AzMediaFeatureVec, AzMediaFeatureVecDestructor, az_media_feature_vec_destructor, AzMediaFeatureVec_delete);
+    impl_vec_clone!(AzMediaFeature, AzMediaFeatureVec, AzMediaFeatureVecDestructor);
+    impl_vec!(AzMediaCondition, AzMediaConditionVec, AzMediaConditionVecDestructor, az_media_condition_vec_destructor, AzMediaConditionVec_delete);
+    impl_vec_clone!(AzMediaCondition, AzMediaConditionVec, AzMediaConditionVecDestructor);
     impl_vec!(AzCssProperty, AzCssPropertyVec, AzCssPropertyVecDestructor, az_css_property_vec_destructor, AzCssPropertyVec_delete);
     impl_vec_clone!(AzCssProperty, AzCssPropertyVec, AzCssPropertyVecDestructor);
     impl_vec!(AzSvgMultiPolygon, AzSvgMultiPolygonVec, AzSvgMultiPolygonVecDestructor, az_svg_multi_polygon_vec_destructor, AzSvgMultiPolygonVec_delete);
@@ -19177,6 +21279,8 @@ pub mod vec {
     impl_vec_clone!(AzStyleBackgroundContent, AzStyleBackgroundContentVec, AzStyleBackgroundContentVecDestructor);
     impl_vec!(AzVideoMode, AzVideoModeVec, AzVideoModeVecDestructor, az_video_mode_vec_destructor, AzVideoModeVec_delete);
     impl_vec_clone!(AzVideoMode, AzVideoModeVec, AzVideoModeVecDestructor);
+    impl_vec!(AzAcceleratorCallback, AzAcceleratorCallbackVec, AzAcceleratorCallbackVecDestructor, az_accelerator_callback_vec_destructor, AzAcceleratorCallbackVec_delete);
+    impl_vec_clone!(AzAcceleratorCallback, AzAcceleratorCallbackVec, AzAcceleratorCallbackVecDestructor);
     impl_vec!(AzMonitor, AzMonitorVec, AzMonitorVecDestructor, az_monitor_vec_destructor, AzMonitorVec_delete);
     impl_vec_clone!(AzMonitor, AzMonitorVec, AzMonitorVecDestructor);
     impl_vec!(AzStyleFontFamily, AzStyleFontFamilyVec, AzStyleFontFamilyVecDestructor, az_style_font_family_vec_destructor, AzStyleFontFamilyVec_delete);
@@ -19329,6 +21433,9 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Vec<VideoMode>`
     
     #[doc(inline)] pub use crate::dll::AzVideoModeVec as VideoModeVec;
+    /// Wrapper over a Rust-allocated `Vec<AcceleratorCallback>`
+    
+    #[doc(inline)] pub use crate::dll::AzAcceleratorCallbackVec as AcceleratorCallbackVec;
     /// Wrapper over a Rust-allocated `Vec<Dom>`
     
     #[doc(inline)] pub use crate::dll::AzDomVec as DomVec;
@@ -19353,9 +21460,28 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
     
     #[doc(inline)] pub use crate::dll::AzStyleTransformVec as StyleTransformVec;
+    /// Wrapper over a Rust-allocated `Vec<GridTrack>`
+    
+    #[doc(inline)] pub use crate::dll::AzGridTrackVec as GridTrackVec;
+    /// Wrapper over a Rust-allocated `Vec<CssPropertyType>`
+    
+    #[doc(inline)] pub use crate::dll::AzCssPropertyTypeVec as CssPropertyTypeVec;
+    /// Wrapper over a Rust-allocated `Vec<CssVariable>`
+    
+    #[doc(inline)] pub use crate::dll::AzCssVariableVec as CssVariableVec;
+    /// Wrapper over a Rust-allocated `Vec<MediaFeature>`
+    
+    #[doc(inline)] pub use crate::dll::AzMediaFeatureVec as MediaFeatureVec;
+    /// Wrapper over a Rust-allocated `Vec<MediaCondition>`
+    
+    #[doc(inline)] pub use crate::dll::AzMediaConditionVec as MediaConditionVec;
     /// Wrapper over a Rust-allocated `Vec<CssProperty>`
     
     #[doc(inline)] pub use crate::dll::AzCssPropertyVec as CssPropertyVec;
+    impl CssPropertyVec {
+
+    }
+
     /// Wrapper over a Rust-allocated `Vec<SvgMultiPolygon>`
     
     #[doc(inline)] pub use crate::dll::AzSvgMultiPolygonVec as SvgMultiPolygonVec;
@@ -19436,12 +21562,22 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `StringVec`
     
     #[doc(inline)] pub use crate::dll::AzStringVec as StringVec;
+    impl StringVec {
+
+        /// Creates a new, empty `StringVec`
+        pub fn new() -> Self { unsafe { crate::dll::AzStringVec_new() } }
+    }
+
     /// Wrapper over a Rust-allocated `StringPairVec`
     
     #[doc(inline)] pub use crate::dll::AzStringPairVec as StringPairVec;
     /// Wrapper over a Rust-allocated `NormalizedLinearColorStopVec`
     
     #[doc(inline)] pub use crate::dll::AzNormalizedLinearColorStopVec as NormalizedLinearColorStopVec;
+    impl NormalizedLinearColorStopVec {
+
+    }
+
     /// Wrapper over a Rust-allocated `NormalizedRadialColorStopVec`
     
     #[doc(inline)] pub use crate::dll::AzNormalizedRadialColorStopVec as NormalizedRadialColorStopVec;
@@ -19613,6 +21749,12 @@ pub mod vec {
     /// `VideoModeVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzVideoModeVecDestructorType as VideoModeVecDestructorType;
+    /// `AcceleratorCallbackVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzAcceleratorCallbackVecDestructor as AcceleratorCallbackVecDestructor;
+    /// `AcceleratorCallbackVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzAcceleratorCallbackVecDestructorType as AcceleratorCallbackVecDestructorType;
     /// `DomVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzDomVecDestructor as DomVecDestructor;
@@ -19661,6 +21803,36 @@ pub mod vec {
     /// `StyleTransformVecDestructorType` struct
     
     #[doc(inline)] pub use crate::dll::AzStyleTransformVecDestructorType as StyleTransformVecDestructorType;
+    /// `GridTrackVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzGridTrackVecDestructor as GridTrackVecDestructor;
+    /// `GridTrackVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzGridTrackVecDestructorType as GridTrackVecDestructorType;
+    /// `CssPropertyTypeVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssPropertyTypeVecDestructor as CssPropertyTypeVecDestructor;
+    /// `CssPropertyTypeVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssPropertyTypeVecDestructorType as CssPropertyTypeVecDestructorType;
+    /// `CssVariableVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssVariableVecDestructor as CssVariableVecDestructor;
+    /// `CssVariableVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzCssVariableVecDestructorType as CssVariableVecDestructorType;
+    /// `MediaFeatureVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzMediaFeatureVecDestructor as MediaFeatureVecDestructor;
+    /// `MediaFeatureVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzMediaFeatureVecDestructorType as MediaFeatureVecDestructorType;
+    /// `MediaConditionVecDestructor` struct
+    
+    #[doc(inline)] pub use crate::dll::AzMediaConditionVecDestructor as MediaConditionVecDestructor;
+    /// `MediaConditionVecDestructorType` struct
+    
+    #[doc(inline)] pub use crate::dll::AzMediaConditionVecDestructorType as MediaConditionVecDestructorType;
     /// `CssPropertyVecDestructor` struct
     
     #[doc(inline)] pub use crate::dll::AzCssPropertyVecDestructor as CssPropertyVecDestructor;
@@ -20063,6 +22235,9 @@ pub mod option {
     /// `OptionSvgPoint` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionSvgPoint as OptionSvgPoint;
+    /// `OptionMediaQuery` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionMediaQuery as OptionMediaQuery;
     /// `OptionListViewOnRowClick` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionListViewOnRowClick as OptionListViewOnRowClick;
@@ -20153,6 +22328,9 @@ pub mod option {
     /// `OptionCssProperty` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionCssProperty as OptionCssProperty;
+    /// `OptionNormalizedLinearColorStop` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionNormalizedLinearColorStop as OptionNormalizedLinearColorStop;
     /// `OptionPositionInfo` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionPositionInfo as OptionPositionInfo;
@@ -20264,6 +22442,9 @@ pub mod option {
     /// `OptionLogicalPosition` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionLogicalPosition as OptionLogicalPosition;
+    /// `OptionLogicalRect` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionLogicalRect as OptionLogicalRect;
     /// `OptionPhysicalPositionI32` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionPhysicalPositionI32 as OptionPhysicalPositionI32;
@@ -20288,6 +22469,12 @@ pub mod option {
     /// `OptionLogicalSize` struct
     
     #[doc(inline)] pub use crate::dll::AzOptionLogicalSize as OptionLogicalSize;
+    /// `OptionImeEvent` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionImeEvent as OptionImeEvent;
+    /// `OptionImeCursorRange` struct
+    
+    #[doc(inline)] pub use crate::dll::AzOptionImeCursorRange as OptionImeCursorRange;
     /// Option<char> but the char is a u32, for C FFI stability reasons
     
     #[doc(inline)] pub use crate::dll::AzOptionChar as OptionChar;
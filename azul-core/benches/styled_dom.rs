@@ -0,0 +1,127 @@
+//! Heap usage and time of styling a DOM with 10 000 nodes, and time of
+//! restyling it after a class change with `StyledDom::new_incremental`
+//!
+//! Run with `cargo bench -p azul-core --bench styled_dom`
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use azul_core::dom::{Dom, IdOrClass};
+use azul_core::styled_dom::StyledDom;
+use azul_css::CssProperty;
+use azul_css_parser::CssApiWrapper;
+
+/// Allocator that keeps track of the current and the peak heap size
+struct CountingAlloc;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const ROWS: usize = 5_000;
+
+const CSS: &str = "
+    .row {
+        display: flex;
+        flex-direction: row;
+        width: 100%;
+        height: 20px;
+        padding: 2px;
+        font-family: sans-serif;
+        background: linear-gradient(#fafafa, #eeeeee);
+    }
+    .row:hover {
+        background: linear-gradient(#e5f3ff, #cce8ff);
+        box-shadow: 0px 0px 2px black;
+    }
+    .selected .cell {
+        background-color: blue;
+    }
+    .cell {
+        flex-grow: 1;
+        border: 1px solid gray;
+        background-color: white;
+        transform: translateX(1px);
+    }
+";
+
+fn build_dom(selected_row: Option<usize>) -> Dom {
+    let rows = (0..ROWS)
+        .map(|row| {
+            let mut classes = vec![IdOrClass::Class("row".into())];
+            if selected_row == Some(row) {
+                classes.push(IdOrClass::Class("selected".into()));
+            }
+            Dom::div()
+                .with_ids_and_classes(classes.into())
+                .with_children(
+                    vec![Dom::div()
+                        .with_ids_and_classes(vec![IdOrClass::Class("cell".into())].into())]
+                    .into(),
+                )
+        })
+        .collect::<Vec<_>>();
+    Dom::body().with_children(rows.into())
+}
+
+fn main() {
+    let css = CssApiWrapper::from_string(CSS.into());
+    let mut dom = build_dom(None);
+
+    let heap_before = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(heap_before, Ordering::Relaxed);
+
+    let start = Instant::now();
+    let styled_dom = StyledDom::new(&mut dom, css);
+    let elapsed = start.elapsed();
+
+    let retained = CURRENT.load(Ordering::Relaxed).saturating_sub(heap_before);
+    let peak = PEAK.load(Ordering::Relaxed) - heap_before;
+
+    println!("size_of::<CssProperty>(): {} bytes", core::mem::size_of::<CssProperty>());
+    println!("styled {} nodes in {:?}", styled_dom.node_data.len(), elapsed);
+    println!("heap: {} KiB retained, {} KiB peak", retained / 1024, peak / 1024);
+
+    // select one row: only that row and its cell have to be matched again
+    let old_dom = build_dom(None);
+    let mut new_dom = build_dom(Some(ROWS / 2));
+
+    let start = Instant::now();
+    let mut full_dom = new_dom.clone();
+    let _ = StyledDom::new(&mut full_dom, CssApiWrapper::from_string(CSS.into()));
+    let full = start.elapsed();
+
+    let start = Instant::now();
+    let diff = old_dom.diff(&new_dom);
+    let _ = StyledDom::new_incremental(
+        &mut new_dom,
+        CssApiWrapper::from_string(CSS.into()),
+        &styled_dom,
+        &diff,
+    );
+    let incremental = start.elapsed();
+
+    println!(
+        "restyle after a class change: {:?} full, {:?} diff + incremental",
+        full, incremental
+    );
+}
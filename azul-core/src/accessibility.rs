@@ -0,0 +1,281 @@
+//! Platform-independent accessibility tree
+//!
+//! The `AccessibilityTree` mirrors the node hierarchy of a `StyledDom`
+//! and stores the role and label of each node. The windowing layer keeps
+//! the tree of the last frame around and uses `AccessibilityTree::diff`
+//! to notify the platform accessibility API about changes.
+
+use crate::dom::AccessibilityRole;
+use crate::id_tree::NodeId;
+use crate::styled_dom::StyledDom;
+use crate::ui_solver::LayoutResult;
+use crate::window::{LogicalPosition, LogicalRect};
+use alloc::vec::Vec;
+use azul_css::AzString;
+
+/// Accessibility node, mirrors exactly one node of the `StyledDom`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityNode {
+    /// ID of the node in the `StyledDom`
+    pub node_id: NodeId,
+    /// Parent node, `None` for the root node
+    pub parent: Option<NodeId>,
+    /// Direct children of this node, in DOM order
+    pub children: Vec<NodeId>,
+    /// Role of the node, `None` if the node has no `AccessibilityInfo`
+    pub role: Option<AccessibilityRole>,
+    /// Accessible name of the node (for example the text on a button)
+    pub label: Option<AzString>,
+    /// Bounds of the node relative to the window, zero if the
+    /// tree was not built from a `LayoutResult`
+    pub rect: LogicalRect,
+}
+
+/// Tree of all `AccessibilityNode`s of a `StyledDom`, indexed by `NodeId`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AccessibilityTree {
+    pub nodes: Vec<AccessibilityNode>,
+}
+
+/// Change between two `AccessibilityTree`s, the `NodeId` refers to the new tree
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccessibilityTreeChange {
+    /// Children of the node were added, removed or replaced
+    ChildrenChanged(NodeId),
+    /// Role of the node has changed
+    RoleChanged(NodeId),
+    /// Label of the node has changed
+    LabelChanged(NodeId),
+}
+
+impl AccessibilityTree {
+    /// Builds the accessibility tree from the node hierarchy of the `StyledDom`
+    pub fn new(styled_dom: &StyledDom) -> Self {
+        let node_hierarchy = styled_dom.node_hierarchy.as_container();
+        let node_data = styled_dom.node_data.as_container();
+
+        let nodes = node_hierarchy
+            .internal
+            .iter()
+            .zip(node_data.internal.iter())
+            .enumerate()
+            .map(|(id, (hierarchy, data))| {
+                let node_id = NodeId::new(id);
+                AccessibilityNode {
+                    node_id,
+                    parent: hierarchy.parent_id(),
+                    children: node_id.az_children_collect(&node_hierarchy),
+                    role: data.get_accessibility_role(),
+                    label: data.get_accessibility_label().cloned(),
+                    rect: LogicalRect::zero(),
+                }
+            })
+            .collect();
+
+        Self { nodes }
+    }
+
+    /// Same as `new`, but also stores the layouted bounds of each node
+    pub fn from_layout_result(layout_result: &LayoutResult) -> Self {
+        let mut tree = Self::new(&layout_result.styled_dom);
+        let rects = layout_result.rects.as_ref();
+
+        for node in tree.nodes.iter_mut() {
+            if let Some(rect) = rects.get(node.node_id) {
+                node.rect = LogicalRect::new(rect.position.get_static_offset(), rect.size);
+            }
+        }
+
+        tree
+    }
+
+    /// Returns the root node of the tree, `None` if the tree is empty
+    #[inline]
+    pub fn root(&self) -> Option<&AccessibilityNode> {
+        self.nodes.first()
+    }
+
+    #[inline]
+    pub fn get(&self, node_id: NodeId) -> Option<&AccessibilityNode> {
+        self.nodes.get(node_id.index())
+    }
+
+    /// Returns the innermost node in the subtree of `start` that contains
+    /// `point`, later siblings are checked first since they are drawn on top
+    pub fn hit_test(&self, start: NodeId, point: LogicalPosition) -> Option<NodeId> {
+        let node = self.get(start)?;
+        node.rect.hit_test(&point)?;
+
+        let hit_child = node
+            .children
+            .iter()
+            .rev()
+            .find_map(|child| self.hit_test(*child, point));
+
+        Some(hit_child.unwrap_or(start))
+    }
+
+    /// Compares the tree of the last frame (`self`) with the tree of the
+    /// current frame and returns the changes that have to be announced
+    /// to the platform accessibility API.
+    ///
+    /// The two trees are walked in parallel from the root. If the number of
+    /// children of a node differs, the entire subtree is reported as
+    /// `ChildrenChanged` and not compared any further.
+    pub fn diff(&self, new: &AccessibilityTree) -> Vec<AccessibilityTreeChange> {
+        let mut changes = Vec::new();
+
+        match (self.root(), new.root()) {
+            (Some(old_root), Some(new_root)) => {
+                diff_nodes(self, old_root, new, new_root, &mut changes);
+            }
+            (None, Some(new_root)) => {
+                changes.push(AccessibilityTreeChange::ChildrenChanged(new_root.node_id));
+            }
+            _ => {}
+        }
+
+        changes
+    }
+}
+
+fn diff_nodes(
+    old_tree: &AccessibilityTree,
+    old: &AccessibilityNode,
+    new_tree: &AccessibilityTree,
+    new: &AccessibilityNode,
+    changes: &mut Vec<AccessibilityTreeChange>,
+) {
+    if old.role != new.role {
+        changes.push(AccessibilityTreeChange::RoleChanged(new.node_id));
+    }
+
+    if old.label != new.label {
+        changes.push(AccessibilityTreeChange::LabelChanged(new.node_id));
+    }
+
+    if old.children.len() != new.children.len() {
+        changes.push(AccessibilityTreeChange::ChildrenChanged(new.node_id));
+        return;
+    }
+
+    for (old_child, new_child) in old.children.iter().zip(new.children.iter()) {
+        if let (Some(old_child), Some(new_child)) =
+            (old_tree.get(*old_child), new_tree.get(*new_child))
+        {
+            diff_nodes(old_tree, old_child, new_tree, new_child, changes);
+        }
+    }
+}
+
+#[test]
+fn test_accessibility_tree_mirrors_dom() {
+    use crate::dom::Dom;
+    use azul_css_parser::CssApiWrapper;
+
+    let styled_dom = Dom::body()
+        .with_child(
+            Dom::div()
+                .with_accessibility_role(AccessibilityRole::PushButton)
+                .with_accessibility_label("OK".into()),
+        )
+        .with_child(Dom::div().with_accessibility_role(AccessibilityRole::List))
+        .style(CssApiWrapper::empty());
+
+    let tree = AccessibilityTree::new(&styled_dom);
+
+    assert_eq!(tree.nodes.len(), 3);
+    let root = tree.root().unwrap();
+    assert_eq!(root.parent, None);
+    assert_eq!(root.children, vec![NodeId::new(1), NodeId::new(2)]);
+    assert_eq!(root.role, None);
+
+    let button = tree.get(NodeId::new(1)).unwrap();
+    assert_eq!(button.parent, Some(NodeId::ZERO));
+    assert_eq!(button.role, Some(AccessibilityRole::PushButton));
+    assert_eq!(button.label, Some("OK".into()));
+
+    let list = tree.get(NodeId::new(2)).unwrap();
+    assert_eq!(list.role, Some(AccessibilityRole::List));
+    assert_eq!(list.label, None);
+}
+
+#[test]
+fn test_accessibility_tree_diff_on_rebuild() {
+    use crate::dom::Dom;
+    use azul_css_parser::CssApiWrapper;
+
+    let make_dom = |label: &str, item_count: usize| {
+        let list = (0..item_count)
+            .map(|_| Dom::div().with_accessibility_role(AccessibilityRole::ListItem))
+            .collect::<Dom>()
+            .with_accessibility_role(AccessibilityRole::List);
+
+        Dom::body()
+            .with_child(Dom::div().with_accessibility_label(label.into()))
+            .with_child(list)
+            .style(CssApiWrapper::empty())
+    };
+
+    let old = AccessibilityTree::new(&make_dom("Counter: 0", 2));
+
+    // identical DOM: nothing to announce
+    assert_eq!(
+        old.diff(&AccessibilityTree::new(&make_dom("Counter: 0", 2))),
+        Vec::new()
+    );
+
+    // changed label
+    assert_eq!(
+        old.diff(&AccessibilityTree::new(&make_dom("Counter: 1", 2))),
+        vec![AccessibilityTreeChange::LabelChanged(NodeId::new(1))]
+    );
+
+    // list item added
+    let new = AccessibilityTree::new(&make_dom("Counter: 0", 3));
+    assert_eq!(new.nodes.len(), 6);
+    assert_eq!(
+        old.diff(&new),
+        vec![AccessibilityTreeChange::ChildrenChanged(NodeId::new(2))]
+    );
+
+    // first frame: the entire tree is new
+    assert_eq!(
+        AccessibilityTree::default().diff(&old),
+        vec![AccessibilityTreeChange::ChildrenChanged(NodeId::ZERO)]
+    );
+}
+
+#[test]
+fn test_accessibility_tree_hit_test() {
+    use crate::dom::Dom;
+    use crate::window::LogicalSize;
+    use azul_css_parser::CssApiWrapper;
+
+    let styled_dom = Dom::body()
+        .with_child(Dom::div().with_child(Dom::div()))
+        .with_child(Dom::div())
+        .style(CssApiWrapper::empty());
+
+    let mut tree = AccessibilityTree::new(&styled_dom);
+    let rects = [
+        (0.0, 0.0, 100.0, 100.0),
+        (0.0, 0.0, 50.0, 50.0),
+        (10.0, 10.0, 10.0, 10.0),
+        // overlaps the first child
+        (40.0, 40.0, 20.0, 20.0),
+    ];
+    for (node, (x, y, w, h)) in tree.nodes.iter_mut().zip(rects.iter()) {
+        node.rect = LogicalRect::new(LogicalPosition::new(*x, *y), LogicalSize::new(*w, *h));
+    }
+
+    let hit = |x, y| tree.hit_test(NodeId::ZERO, LogicalPosition::new(x, y));
+    assert_eq!(hit(15.0, 15.0), Some(NodeId::new(2)));
+    assert_eq!(hit(5.0, 5.0), Some(NodeId::new(1)));
+    assert_eq!(hit(45.0, 45.0), Some(NodeId::new(3)));
+    assert_eq!(hit(90.0, 90.0), Some(NodeId::ZERO));
+    assert_eq!(hit(150.0, 5.0), None);
+
+    // the subtree of the first child only
+    assert_eq!(tree.hit_test(NodeId::new(1), LogicalPosition::new(45.0, 45.0)), Some(NodeId::new(1)));
+}
@@ -31,6 +31,7 @@ use core::{
     sync::atomic::{AtomicU32, AtomicUsize, Ordering as AtomicOrdering},
 };
 use rust_fontconfig::FcFontCache;
+use std::sync::Mutex;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -60,6 +61,8 @@ pub struct AppConfig {
     pub enable_tab_navigation: bool,
     /// External callbacks to create a thread or get the curent time
     pub system_callbacks: ExternalSystemCallbacks,
+    /// When the windows render a new frame (default: `RedrawBehavior::OnEventOnly`)
+    pub redraw_behavior: RedrawBehavior,
 }
 
 impl AppConfig {
@@ -71,6 +74,7 @@ impl AppConfig {
             enable_logging_on_panic: true,
             enable_tab_navigation: true,
             system_callbacks: ExternalSystemCallbacks::rust_internal(),
+            redraw_behavior: RedrawBehavior::OnEventOnly,
         }
     }
 }
@@ -93,6 +97,27 @@ pub enum AppLogLevel {
     Trace,
 }
 
+/// Controls how often a window renders a new frame
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum RedrawBehavior {
+    /// Only render after an event, timer or animation changed the
+    /// window contents, an idle window doesn't render at all
+    OnEventOnly,
+    /// Same as `OnEventOnly`, but renders at most N frames per second:
+    /// redraws requested in between are merged into the next frame.
+    /// `MaxFps(0)` is the same as `OnEventOnly`.
+    MaxFps(u16),
+    /// Render continuously, as fast as possible (i.e. for games)
+    Unlimited,
+}
+
+impl Default for RedrawBehavior {
+    fn default() -> Self {
+        RedrawBehavior::OnEventOnly
+    }
+}
+
 pub type WordIndex = usize;
 pub type GlyphIndex = usize;
 pub type LineLength = f32;
@@ -454,6 +479,37 @@ impl ImageRef {
         Self::new(DecodedImage::Gl(texture))
     }
 
+    /// Replaces the pixels of a raw image in place. The `ImageRefHash` stays
+    /// the same, so the renderer can update the existing texture instead of
+    /// registering a new image. Returns `false` (and leaves the image unchanged)
+    /// if this is not a raw image, the new image has a different size or
+    /// can't be converted.
+    ///
+    /// NOTE: all copies of this `ImageRef` see the new pixels. Must not be called
+    /// while the image is read on another thread, i.e. only from callbacks
+    /// or before the app is started.
+    pub fn update_rawimage(&mut self, image: RawImage) -> bool {
+        let (width, height) = match self.get_data() {
+            DecodedImage::Raw((descriptor, _)) => (descriptor.width, descriptor.height),
+            _ => return false,
+        };
+
+        if image.width != width || image.height != height {
+            return false;
+        }
+
+        let (new_data, new_descriptor) = match image.into_loaded_image_source() {
+            Some(s) => s,
+            None => return false,
+        };
+
+        unsafe {
+            *(self.data as *mut DecodedImage) = DecodedImage::Raw((new_descriptor, new_data));
+        }
+
+        true
+    }
+
     fn new(data: DecodedImage) -> Self {
         Self {
             data: Box::into_raw(Box::new(data)),
@@ -533,6 +589,10 @@ pub fn font_ref_get_hash(fr: &FontRef) -> u64 {
     fr.get_hash()
 }
 
+/// Callback that loads an image for a CSS image ID that hasn't been added to
+/// the `ImageCache`, i.e. to load `url("icons/save.png")` from disk on demand
+pub type ImageResolverCallbackType = fn(&AzString) -> Option<RawImage>;
+
 /// Stores the resources for the application, souch as fonts, images and cached
 /// texts, also clipboard strings
 ///
@@ -546,12 +606,28 @@ pub struct ImageCache {
     /// all other maps are library-internal only and automatically delete their resources once they
     /// aren't needed anymore
     pub image_id_map: FastHashMap<AzString, ImageRef>,
+    /// Called for CSS image IDs that are not in the `image_id_map`
+    pub image_resolver: Option<ImageResolverCallbackType>,
+    /// Results of the `image_resolver`, `None` if the resolver couldn't load the
+    /// image, so that it is only called once per image ID. The display list is
+    /// built from a shared reference, so the cache needs interior mutability.
+    resolved_image_ids: Mutex<FastHashMap<AzString, Option<ImageRef>>>,
+    /// Images whose pixels were replaced with `update_css_image_raw`, mapped to
+    /// the number of the update, so that every renderer can check if it
+    /// already uploaded the latest pixels (see `RendererResources::update_changed_css_images`)
+    updated_image_ids: FastHashMap<AzString, u64>,
+    /// Incremented on every call to `update_css_image_raw`
+    update_counter: u64,
 }
 
 impl Default for ImageCache {
     fn default() -> Self {
         Self {
             image_id_map: FastHashMap::default(),
+            image_resolver: None,
+            resolved_image_ids: Mutex::new(FastHashMap::default()),
+            updated_image_ids: FastHashMap::default(),
+            update_counter: 0,
         }
     }
 }
@@ -564,16 +640,75 @@ impl ImageCache {
     // -- ImageId cache
 
     pub fn add_css_image_id(&mut self, css_id: AzString, image: ImageRef) {
+        self.forget_resolved_image_id(&css_id);
+        self.updated_image_ids.remove(&css_id);
         self.image_id_map.insert(css_id, image);
     }
 
+    /// Replaces the pixels of the image registered under `css_id` without
+    /// changing its `ImageRefHash` (see `ImageRef::update_rawimage`), so that
+    /// the renderers can update the existing textures in place.
+    ///
+    /// Returns `false` if there is no raw image with the same size registered
+    /// under `css_id` - use `add_css_image_id` to replace the image in that case.
+    pub fn update_css_image_raw(&mut self, css_id: &AzString, image: RawImage) -> bool {
+        let updated = match self.image_id_map.get_mut(css_id) {
+            Some(s) => s.update_rawimage(image),
+            None => false,
+        };
+
+        if updated {
+            self.update_counter += 1;
+            self.updated_image_ids.insert(css_id.clone(), self.update_counter);
+        }
+
+        updated
+    }
+
     pub fn get_css_image_id(&self, css_id: &AzString) -> Option<&ImageRef> {
         self.image_id_map.get(css_id)
     }
 
     pub fn delete_css_image_id(&mut self, css_id: &AzString) {
+        self.forget_resolved_image_id(css_id);
+        self.updated_image_ids.remove(css_id);
         self.image_id_map.remove(css_id);
     }
+
+    // -- Image resolver
+
+    /// Sets the callback that is invoked when the DOM references a CSS image ID
+    /// that hasn't been added with `add_css_image_id`. Clears the results of
+    /// the previous resolver.
+    pub fn set_image_resolver(&mut self, resolver: ImageResolverCallbackType) {
+        self.image_resolver = Some(resolver);
+        if let Ok(mut resolved) = self.resolved_image_ids.lock() {
+            resolved.clear();
+        }
+    }
+
+    /// Same as `get_css_image_id`, but invokes the image resolver if the ID
+    /// hasn't been added yet. The result of the resolver is cached, including
+    /// failures, so the resolver is called at most once per image ID.
+    pub fn resolve_css_image_id(&self, css_id: &AzString) -> Option<ImageRef> {
+        if let Some(image) = self.image_id_map.get(css_id) {
+            return Some(image.clone());
+        }
+
+        let resolver = self.image_resolver?;
+        let mut resolved = self.resolved_image_ids.lock().ok()?;
+
+        resolved
+            .entry(css_id.clone())
+            .or_insert_with(|| (resolver)(css_id).and_then(ImageRef::new_rawimage))
+            .clone()
+    }
+
+    fn forget_resolved_image_id(&mut self, css_id: &AzString) {
+        if let Ok(resolved) = self.resolved_image_ids.get_mut() {
+            resolved.remove(css_id);
+        }
+    }
 }
 
 /// What type of image is this?
@@ -602,6 +737,8 @@ pub struct ResolvedImage {
 pub struct RendererResources {
     /// All image keys currently active in the RenderApi
     currently_registered_images: FastHashMap<ImageRefHash, ResolvedImage>,
+    /// Last `ImageCache::update_css_image_raw` update that was uploaded for an image
+    uploaded_image_updates: FastHashMap<ImageRefHash, u64>,
     /// All font keys currently active in the RenderApi
     currently_registered_fonts: FastHashMap<FontKey, (FontRef, FastHashMap<(Au, DpiScaleFactor), FontInstanceKey>)>,
     /// Fonts registered on the last frame
@@ -642,6 +779,7 @@ impl Default for RendererResources {
     fn default() -> Self {
         Self {
             currently_registered_images: FastHashMap::default(),
+            uploaded_image_updates: FastHashMap::default(),
             currently_registered_fonts: FastHashMap::default(),
             last_frame_registered_fonts: FastHashMap::default(),
             font_families_map: FastHashMap::default(),
@@ -679,6 +817,48 @@ impl RendererResources {
         }
     }
 
+    /// Returns the updates for all registered images whose pixels were replaced
+    /// with `ImageCache::update_css_image_raw` since the last call. The image keys
+    /// stay the same. Images that aren't registered in this renderer yet are
+    /// skipped, they are uploaded with the new pixels once they are used.
+    #[must_use]
+    pub fn update_changed_css_images(&mut self, image_cache: &ImageCache) -> Vec<UpdateImageResult> {
+        let mut updated_images = Vec::new();
+
+        for (css_id, update) in image_cache.updated_image_ids.iter() {
+            let image_ref = match image_cache.get_css_image_id(css_id) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let image_ref_hash = image_ref.get_hash();
+            if self.uploaded_image_updates.get(&image_ref_hash) == Some(update) {
+                continue;
+            }
+
+            let registered_image = match self.currently_registered_images.get_mut(&image_ref_hash) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let (descriptor, data) = match image_ref.get_data() {
+                DecodedImage::Raw((descriptor, data)) => (*descriptor, data.clone()),
+                _ => continue,
+            };
+
+            registered_image.descriptor = descriptor;
+            self.uploaded_image_updates.insert(image_ref_hash, *update);
+
+            updated_images.push(UpdateImageResult {
+                key_to_update: registered_image.key,
+                new_descriptor: descriptor,
+                new_image_data: data,
+            });
+        }
+
+        updated_images
+    }
+
     /// Updates the internal cache, adds `ResourceUpdate::Remove()`
     /// to the `all_resource_updates`
     ///
@@ -722,6 +902,33 @@ impl RendererResources {
             next_frame_image_keys.insert(*image_ref_hash);
         }
 
+        // Get all font keys + font sizes that are in the DOM for the next frame
+        // (all fonts of the next frame are already resolved at this point)
+        let mut next_frame_font_keys = BTreeMap::<FontKey, FastBTreeSet<Au>>::new();
+
+        for layout_result in new_layout_results {
+            for (font_id, font_sizes) in layout_result.styled_dom.scan_for_font_keys(self) {
+                if let ImmediateFontId::Resolved((_, font_key)) = font_id {
+                    next_frame_font_keys
+                        .entry(font_key)
+                        .or_insert_with(|| FastBTreeSet::new())
+                        .extend(font_sizes);
+                }
+            }
+        }
+
+        // Unregister the fonts and font instances that the next frame doesn't use anymore
+        self.currently_registered_fonts
+            .retain(|font_key, (_, font_instances)| {
+                match next_frame_font_keys.get(font_key) {
+                    Some(font_sizes) => {
+                        font_instances.retain(|(au, _), _| font_sizes.contains(au));
+                        true
+                    }
+                    None => false,
+                }
+            });
+
         // If the current frame contains a font key but the next frame doesn't, delete the font key
         let mut delete_font_resources = Vec::new();
         for (font_key, font_instances) in self.last_frame_registered_fonts.iter() {
@@ -767,6 +974,7 @@ impl RendererResources {
         for (image_ref_hash_to_delete, _) in delete_image_resources.iter() {
             self.currently_registered_images
                 .remove(image_ref_hash_to_delete);
+            self.uploaded_image_updates.remove(image_ref_hash_to_delete);
         }
 
         all_resource_updates.extend(
@@ -883,10 +1091,14 @@ impl RendererResources {
             /*hit_dom_node*/ callback_domnode_id,
         );
 
-        let new_imageref = (render_image_callback.callback.cb)(
-            &mut render_image_callback.data,
-            &mut gl_callback_info,
-        );
+        // if the callback panics, the old image stays registered
+        let new_imageref = crate::error::catch_panic(|| {
+            (render_image_callback.callback.cb)(
+                &mut render_image_callback.data,
+                &mut gl_callback_info,
+            )
+        })
+        .ok()?;
 
         // remove old imageref from GlTextureCache and active textures
         let existing_image_key = gl_texture_cache
@@ -1020,12 +1232,13 @@ impl RendererResources {
                             .get_background_content(node_data, &node_id, &node_state)
                             .and_then(|bg| {
                                 bg.get_property()
+                                    .map(|b| &**b)
                                     .unwrap_or(&default)
                                     .as_ref()
                                     .iter()
                                     .find_map(|b| match b {
                                         azul_css::StyleBackgroundContent::Image(id) => {
-                                            let image_ref = image_cache.get_css_image_id(id)?;
+                                            let image_ref = image_cache.resolve_css_image_id(id)?;
                                             Some(image_ref.get_hash())
                                         }
                                         _ => None,
@@ -1233,14 +1446,15 @@ impl GlTextureCache {
                             NodeType::Image(img) => {
                                 let callback_imageref_hash = img.get_hash();
 
-                                img.get_image_callback_mut().map(|gl_texture_callback| {
-                                    (
+                                img.get_image_callback_mut().and_then(|gl_texture_callback| {
+                                    crate::error::catch_panic(|| {
                                         (gl_texture_callback.callback.cb)(
                                             &mut gl_texture_callback.data,
                                             &mut gl_callback_info,
-                                        ),
-                                        callback_imageref_hash,
-                                    )
+                                        )
+                                    })
+                                    .ok()
+                                    .map(|imageref| (imageref, callback_imageref_hash))
                                 })
                             }
                             _ => None,
@@ -1489,6 +1703,38 @@ pub struct RawImage {
 }
 
 impl RawImage {
+    /// Creates an image from tightly packed 8-bit `RGBA8`, `BGRA8` or `RGB8`
+    /// pixels with straight (not premultiplied) alpha.
+    ///
+    /// Returns `None` for other formats, for an empty image or if `data` is
+    /// not exactly `width * height * bytes per pixel` long (the rows must not
+    /// be padded).
+    pub fn from_packed_bytes(
+        data: &[u8],
+        width: usize,
+        height: usize,
+        format: RawImageFormat,
+    ) -> Option<Self> {
+        let bytes_per_pixel = match format {
+            RawImageFormat::RGBA8 | RawImageFormat::BGRA8 => 4,
+            RawImageFormat::RGB8 => 3,
+            _ => return None,
+        };
+
+        let expected_len = width.checked_mul(height)?.checked_mul(bytes_per_pixel)?;
+        if expected_len == 0 || data.len() != expected_len {
+            return None;
+        }
+
+        Some(Self {
+            pixels: RawImageData::U8(data.to_vec().into()),
+            width,
+            height,
+            premultiplied_alpha: false,
+            data_format: format,
+        })
+    }
+
     /// Returns a null / empty image
     pub fn null_image() -> Self {
         Self {
@@ -2552,9 +2798,9 @@ pub fn add_fonts_and_images(
     );
 }
 
-pub fn font_size_to_au(font_size: StyleFontSize) -> Au {
-    use crate::ui_solver::DEFAULT_FONT_SIZE_PX;
-    Au::from_px(font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32))
+/// Converts a computed font size in pixels (see `StyledDom::get_em_size`) to `Au`
+pub fn font_size_to_au(font_size_px: f32) -> Au {
+    Au::from_px(font_size_px)
 }
 
 pub type FontInstanceFlags = u32;
@@ -3264,3 +3510,113 @@ pub fn add_resources(
         }
     }
 }
+
+#[test]
+fn test_image_resolver_hit_and_miss() {
+    static RESOLVER_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn resolve_image(css_id: &AzString) -> Option<RawImage> {
+        RESOLVER_CALLS.fetch_add(1, AtomicOrdering::SeqCst);
+        match css_id.as_str() {
+            "checkerboard" => Some(RawImage {
+                pixels: RawImageData::U8(vec![255; 2 * 2 * 4].into()),
+                width: 2,
+                height: 2,
+                premultiplied_alpha: true,
+                data_format: RawImageFormat::RGBA8,
+            }),
+            _ => None,
+        }
+    }
+
+    let checkerboard = AzString::from("checkerboard");
+    let missing = AzString::from("missing");
+
+    let mut image_cache = ImageCache::new();
+    assert!(image_cache.resolve_css_image_id(&checkerboard).is_none());
+    image_cache.set_image_resolver(resolve_image);
+
+    // hit: the resolver is only called once, the image is then cached
+    let image = image_cache.resolve_css_image_id(&checkerboard).unwrap();
+    assert_eq!(image.get_size(), LogicalSize::new(2.0, 2.0));
+    let cached = image_cache.resolve_css_image_id(&checkerboard).unwrap();
+    assert_eq!(cached.get_hash(), image.get_hash());
+    assert_eq!(RESOLVER_CALLS.load(AtomicOrdering::SeqCst), 1);
+    assert!(image_cache.get_css_image_id(&checkerboard).is_none());
+
+    // miss: the failure is cached as well
+    assert!(image_cache.resolve_css_image_id(&missing).is_none());
+    assert!(image_cache.resolve_css_image_id(&missing).is_none());
+    assert_eq!(RESOLVER_CALLS.load(AtomicOrdering::SeqCst), 2);
+
+    // images added by the user take precedence over the resolver
+    image_cache.add_css_image_id(missing.clone(), image.clone());
+    let added = image_cache.resolve_css_image_id(&missing).unwrap();
+    assert_eq!(added.get_hash(), image.get_hash());
+    assert_eq!(RESOLVER_CALLS.load(AtomicOrdering::SeqCst), 2);
+}
+
+#[test]
+fn test_raw_image_from_packed_bytes() {
+    let rgba = [255u8; 2 * 3 * 4];
+    assert!(RawImage::from_packed_bytes(&rgba, 2, 3, RawImageFormat::RGBA8).is_some());
+    assert!(RawImage::from_packed_bytes(&rgba, 2, 3, RawImageFormat::BGRA8).is_some());
+    // 24 bytes are not 2x3 RGB pixels
+    assert!(RawImage::from_packed_bytes(&rgba, 2, 3, RawImageFormat::RGB8).is_none());
+    assert!(RawImage::from_packed_bytes(&rgba[..18], 2, 3, RawImageFormat::RGB8).is_some());
+    // padded rows / truncated data
+    assert!(RawImage::from_packed_bytes(&rgba, 2, 2, RawImageFormat::RGBA8).is_none());
+    assert!(RawImage::from_packed_bytes(&rgba[..20], 2, 3, RawImageFormat::RGBA8).is_none());
+    assert!(RawImage::from_packed_bytes(&[], 0, 0, RawImageFormat::RGBA8).is_none());
+    assert!(RawImage::from_packed_bytes(&rgba, usize::MAX, 2, RawImageFormat::RGBA8).is_none());
+    // only 8-bit formats
+    assert!(RawImage::from_packed_bytes(&rgba, 2, 3, RawImageFormat::R8).is_none());
+}
+
+#[test]
+fn test_image_cache_update_keeps_image_key() {
+    let red = [255u8, 0, 0, 255].repeat(4);
+    let blue = [0u8, 0, 255, 255].repeat(4);
+    let webcam = AzString::from("webcam");
+
+    let mut image_cache = ImageCache::new();
+    let image = RawImage::from_packed_bytes(&red, 2, 2, RawImageFormat::RGBA8).unwrap();
+    image_cache.add_css_image_id(webcam.clone(), ImageRef::new_rawimage(image).unwrap());
+    let hash = image_cache.get_css_image_id(&webcam).unwrap().get_hash();
+
+    // simulate the image being used in a frame
+    let mut renderer_resources = RendererResources::default();
+    let key = ImageKey::unique(IdNamespace(0));
+    let descriptor = match image_cache.get_css_image_id(&webcam).unwrap().get_data() {
+        DecodedImage::Raw((descriptor, _)) => *descriptor,
+        _ => panic!("not a raw image"),
+    };
+    renderer_resources
+        .currently_registered_images
+        .insert(hash, ResolvedImage { key, descriptor });
+    assert!(renderer_resources.update_changed_css_images(&image_cache).is_empty());
+
+    let frame = RawImage::from_packed_bytes(&blue, 2, 2, RawImageFormat::RGBA8).unwrap();
+    assert!(image_cache.update_css_image_raw(&webcam, frame));
+    assert_eq!(image_cache.get_css_image_id(&webcam).unwrap().get_hash(), hash);
+
+    let updates = renderer_resources.update_changed_css_images(&image_cache);
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].key_to_update, key);
+    match &updates[0].new_image_data {
+        ImageData::Raw(bgra) => assert_eq!(&bgra.as_ref()[..4], &[255, 0, 0, 255]),
+        _ => panic!("not a raw image"),
+    }
+    // already uploaded
+    assert!(renderer_resources.update_changed_css_images(&image_cache).is_empty());
+
+    // the size of the texture can't change in place
+    let larger = RawImage::from_packed_bytes(&[0u8; 3 * 3 * 3], 3, 3, RawImageFormat::RGB8).unwrap();
+    assert!(!image_cache.update_css_image_raw(&webcam, larger));
+    let missing = RawImage::from_packed_bytes(&blue, 2, 2, RawImageFormat::RGBA8).unwrap();
+    assert!(!image_cache.update_css_image_raw(&AzString::from("missing"), missing));
+
+    image_cache.delete_css_image_id(&webcam);
+    assert!(image_cache.get_css_image_id(&webcam).is_none());
+    assert!(renderer_resources.update_changed_css_images(&image_cache).is_empty());
+}
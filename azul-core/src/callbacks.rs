@@ -4,7 +4,7 @@ use crate::gl::OptionGlContextPtr;
 use crate::{
     app_resources::{
         FontInstanceKey, IdNamespace, ImageCache, ImageMask, ImageRef, LayoutedGlyphs,
-        RendererResources, ShapedWords, WordPositions, Words,
+        RawImage, RendererResources, ShapedWords, WordPositions, Words,
     },
     id_tree::{NodeDataContainer, NodeId},
     styled_dom::{CssPropertyCache, StyledDom, StyledNode},
@@ -20,9 +20,10 @@ use crate::{
     },
     window::{AzStringPair, OptionLogicalPosition},
     window::{
-        FullWindowState, KeyboardState, LogicalPosition, LogicalRect, LogicalSize, MouseState,
-        OptionChar, PhysicalSize, RawWindowHandle, UpdateFocusWarning, WindowCreateOptions,
-        WindowFlags, WindowSize, WindowState, WindowTheme,
+        Accelerator, AcceleratorId, ContextMenuChange, ContextMenuRequest, FullWindowState,
+        ImePosition, KeyboardState, LogicalPosition, LogicalRect, LogicalSize, Menu,
+        MouseState, OptionChar, OptionImeEvent, PhysicalSize, RawWindowHandle, UpdateFocusWarning,
+        WindowCreateOptions, WindowFlags, WindowSize, WindowState, WindowTheme,
     },
     FastBTreeSet, FastHashMap,
 };
@@ -30,9 +31,10 @@ use alloc::alloc::Layout;
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
+use azul_css_parser::CssApiWrapper;
 use azul_css::{
-    AnimationInterpolationFunction, AzString, CssPath, CssProperty, CssPropertyType, FontRef,
-    InterpolateResolver, LayoutRect, LayoutSize,
+    AnimationInterpolationFunction, AzString, CssPath, CssProperty, CssPropertyType,
+    CssPropertyVec, FontRef, InterpolateResolver, LayoutRect, LayoutSize, StringVec,
 };
 use core::{
     ffi::c_void,
@@ -279,7 +281,7 @@ impl RefAny {
                 ptr::copy_nonoverlapping(
                     (ptr as *mut c_void) as *const U,
                     stack_mem.as_mut_ptr(),
-                    mem::size_of::<U>(),
+                    1,
                 );
                 let stack_mem = stack_mem.assume_init();
                 mem::drop(stack_mem);
@@ -463,6 +465,14 @@ impl RefAny {
     pub fn get_type_name(&self) -> AzString {
         self.sharing_info.downcast().type_name.clone()
     }
+
+    /// Returns the number of `RefAny`s that share the data (this instance included)
+    pub fn get_strong_count(&self) -> usize {
+        self.sharing_info
+            .downcast()
+            .num_copies
+            .load(AtomicOrdering::SeqCst)
+    }
 }
 
 impl Clone for RefAny {
@@ -544,6 +554,26 @@ pub struct ScrollPosition {
     pub children_rect: LogicalRect,
 }
 
+impl ScrollPosition {
+    /// Returns the maximum offset that the node can be scrolled to,
+    /// i.e. how much the scroll rect overflows the parent container
+    pub fn get_scroll_extent(&self) -> LogicalSize {
+        LogicalSize::new(
+            (self.children_rect.size.width - self.parent_rect.size.width).max(0.0),
+            (self.children_rect.size.height - self.parent_rect.size.height).max(0.0),
+        )
+    }
+
+    /// Clamps a scroll offset to the range `0..=get_scroll_extent()`
+    pub fn clamp_scroll_offset(&self, offset: LogicalPosition) -> LogicalPosition {
+        let extent = self.get_scroll_extent();
+        LogicalPosition::new(
+            offset.x.max(0.0).min(extent.width),
+            offset.y.max(0.0).min(extent.height),
+        )
+    }
+}
+
 #[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct DocumentId {
     pub namespace_id: IdNamespace,
@@ -623,7 +653,7 @@ pub struct ScrollHitTestItem {
 /// Implements `Display, Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Hash`
 /// for a Callback with a `.0` field:
 ///
-/// ```
+/// ```rust,ignore
 /// struct MyCallback(fn (&T));
 ///
 /// // impl Display, Debug, etc. for MyCallback
@@ -774,6 +804,24 @@ impl Default for LayoutCallback {
     }
 }
 
+impl LayoutCallback {
+    /// Invokes the layout callback, a panic is recorded as the last
+    /// error (see `azul_core::error`) and returned as `Err`
+    pub fn invoke(
+        &mut self,
+        data: &mut RefAny,
+        info: &mut LayoutCallbackInfo,
+    ) -> Result<StyledDom, AzString> {
+        crate::error::catch_panic(|| match self {
+            LayoutCallback::Raw(r) => (r.cb)(data, info),
+            LayoutCallback::Marshaled(m) => {
+                let marshal_data = &mut m.marshal_data;
+                (m.cb.cb)(marshal_data, data, info)
+            }
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
 pub struct MarshaledLayoutCallback {
@@ -802,6 +850,14 @@ pub struct Callback {
 }
 impl_callback!(Callback);
 
+impl Callback {
+    /// Invokes the callback, a panic is recorded as the last error
+    /// (see `azul_core::error`) and the callback is skipped
+    pub fn invoke(&self, data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        crate::error::catch_panic(|| (self.cb)(data, info)).unwrap_or(Update::DoNothing)
+    }
+}
+
 impl_option!(
     Callback,
     OptionCallback,
@@ -1143,6 +1199,8 @@ pub struct CallbackInfo {
     timers_removed: *mut FastBTreeSet<TimerId>,
     /// Threads removed by the callback
     threads_removed: *mut FastBTreeSet<ThreadId>,
+    /// Messages for the threads that were already running before the callback
+    thread_msgs_sent: *mut Vec<(ThreadId, ThreadSendMsg)>,
     /// Handle of the current window
     current_window_handle: *const RawWindowHandle,
     /// Used to spawn new windows from callbacks. You can use `get_current_window_handle()` to spawn child windows.
@@ -1163,6 +1221,10 @@ pub struct CallbackInfo {
     image_masks_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
     /// Mutable reference to a list of CSS property changes, so that the callbacks can change CSS properties
     css_properties_changed_in_callbacks: *mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>,
+    /// Stylesheet that should replace the stylesheet of the current window (set via `set_css`)
+    stylesheet_changed_in_callbacks: *mut Option<CssApiWrapper>,
+    /// Context menu opened or closed by the callback (set via `open_context_menu`)
+    context_menu_changed_in_callbacks: *mut Option<ContextMenuChange>,
     /// Immutable (!) reference to where the nodes are currently scrolled (current position)
     current_scroll_states: *const BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, ScrollPosition>>,
     /// Mutable map where a user can set where he wants the nodes to be scrolled to (for the next frame)
@@ -1200,6 +1262,7 @@ impl CallbackInfo {
         threads: &'a mut FastHashMap<ThreadId, Thread>,
         timers_removed: &'a mut FastBTreeSet<TimerId>,
         threads_removed: &'a mut FastBTreeSet<ThreadId>,
+        thread_msgs_sent: &'a mut Vec<(ThreadId, ThreadSendMsg)>,
         current_window_handle: &'a RawWindowHandle,
         new_windows: &'a mut Vec<WindowCreateOptions>,
         system_callbacks: &'a ExternalSystemCallbacks,
@@ -1215,6 +1278,8 @@ impl CallbackInfo {
             DomId,
             BTreeMap<NodeId, Vec<CssProperty>>,
         >,
+        stylesheet_changed_in_callbacks: &'a mut Option<CssApiWrapper>,
+        context_menu_changed_in_callbacks: &'a mut Option<ContextMenuChange>,
         current_scroll_states: &'a BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, ScrollPosition>>,
         nodes_scrolled_in_callback: &'a mut BTreeMap<
             DomId,
@@ -1238,6 +1303,7 @@ impl CallbackInfo {
             threads: threads as *mut FastHashMap<ThreadId, Thread>,
             timers_removed: timers_removed as *mut FastBTreeSet<TimerId>,
             threads_removed: threads_removed as *mut FastBTreeSet<ThreadId>,
+            thread_msgs_sent: thread_msgs_sent as *mut Vec<(ThreadId, ThreadSendMsg)>,
             new_windows: new_windows as *mut Vec<WindowCreateOptions>,
             current_window_handle: current_window_handle as *const RawWindowHandle,
             system_callbacks: system_callbacks as *const ExternalSystemCallbacks,
@@ -1251,6 +1317,10 @@ impl CallbackInfo {
                 as *mut BTreeMap<DomId, BTreeMap<NodeId, ImageMask>>,
             css_properties_changed_in_callbacks: css_properties_changed_in_callbacks
                 as *mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>,
+            stylesheet_changed_in_callbacks: stylesheet_changed_in_callbacks
+                as *mut Option<CssApiWrapper>,
+            context_menu_changed_in_callbacks: context_menu_changed_in_callbacks
+                as *mut Option<ContextMenuChange>,
             current_scroll_states: current_scroll_states
                 as *const BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, ScrollPosition>>,
             nodes_scrolled_in_callback: nodes_scrolled_in_callback
@@ -1275,6 +1345,14 @@ impl CallbackInfo {
     fn internal_get_current_window_state<'a>(&'a self) -> &'a FullWindowState {
         unsafe { &*self.current_window_state }
     }
+    fn internal_is_file_drop(&self) -> bool {
+        let current = self.internal_get_current_window_state();
+        let dropped_files_changed = match self.internal_get_previous_window_state() {
+            Some(previous) => previous.dropped_files != current.dropped_files,
+            None => true,
+        };
+        dropped_files_changed && !current.dropped_files.is_empty()
+    }
     fn internal_get_modifiable_window_state<'a>(&'a mut self) -> &'a mut WindowState {
         unsafe { &mut *self.modifiable_window_state }
     }
@@ -1302,6 +1380,9 @@ impl CallbackInfo {
     fn internal_get_threads_removed<'a>(&'a mut self) -> &'a mut FastBTreeSet<ThreadId> {
         unsafe { &mut *self.threads_removed }
     }
+    fn internal_get_thread_msgs_sent<'a>(&'a mut self) -> &'a mut Vec<(ThreadId, ThreadSendMsg)> {
+        unsafe { &mut *self.thread_msgs_sent }
+    }
     fn internal_get_new_windows<'a>(&'a mut self) -> &'a mut Vec<WindowCreateOptions> {
         unsafe { &mut *self.new_windows }
     }
@@ -1327,6 +1408,16 @@ impl CallbackInfo {
     ) -> &'a mut BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>> {
         unsafe { &mut *self.css_properties_changed_in_callbacks }
     }
+    fn internal_get_context_menu_changed_in_callbacks<'a>(
+        &'a mut self,
+    ) -> &'a mut Option<ContextMenuChange> {
+        unsafe { &mut *self.context_menu_changed_in_callbacks }
+    }
+    fn internal_get_stylesheet_changed_in_callbacks<'a>(
+        &'a mut self,
+    ) -> &'a mut Option<CssApiWrapper> {
+        unsafe { &mut *self.stylesheet_changed_in_callbacks }
+    }
     fn internal_get_nodes_scrolled_in_callback<'a>(
         &'a mut self,
     ) -> &'a mut BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, LogicalPosition>> {
@@ -1388,6 +1479,43 @@ impl CallbackInfo {
     pub fn get_current_mouse_state(&self) -> MouseState {
         self.internal_get_current_window_state().mouse_state.clone()
     }
+    /// Returns the last event of the input method editor (IME), see `KeyboardState::current_ime_event`
+    pub fn get_ime_event(&self) -> OptionImeEvent {
+        self.internal_get_current_window_state()
+            .keyboard_state
+            .current_ime_event
+            .clone()
+    }
+    /// Returns the paths of the files that are currently dragged over the window
+    pub fn get_hovered_files(&self) -> StringVec {
+        self.internal_get_current_window_state().hovered_files.clone()
+    }
+    /// Returns the paths of the files of the last drop onto the window
+    pub fn get_dropped_files(&self) -> StringVec {
+        self.internal_get_current_window_state().dropped_files.clone()
+    }
+    /// Returns the cursor position (relative to the window) at which the files were dropped,
+    /// `None` if the current event is not a `On::DroppedFile` event
+    pub fn get_drop_position(&self) -> OptionLogicalPosition {
+        if !self.internal_is_file_drop() {
+            return OptionLogicalPosition::None;
+        }
+        self.internal_get_current_window_state()
+            .mouse_state
+            .cursor_position
+            .get_position()
+            .into()
+    }
+    /// Returns the topmost node that the files were dropped on,
+    /// `None` if the current event is not a `On::DroppedFile` event
+    pub fn get_drop_target(&self) -> Option<DomNodeId> {
+        if !self.internal_is_file_drop() {
+            return None;
+        }
+        self.internal_get_current_window_state()
+            .last_hit_test
+            .get_topmost_hovered_node()
+    }
     pub fn get_previous_window_state(&self) -> Option<WindowState> {
         Some(
             self.internal_get_previous_window_state()
@@ -1412,6 +1540,10 @@ impl CallbackInfo {
                 .clone(),
         )
     }
+    /// Returns the native handle of the window the callback was invoked on.
+    ///
+    /// The handle is only valid during the callback - the window can be
+    /// closed after the callback returns, so don't store the handle.
     pub fn get_current_window_handle(&self) -> RawWindowHandle {
         self.internal_get_current_window_handle().clone()
     }
@@ -1437,11 +1569,36 @@ impl CallbackInfo {
             })
     }
 
-    pub fn set_scroll_position(&mut self, node_id: DomNodeId, scroll_position: LogicalPosition) {
+    /// Returns the maximum scroll offset of the node or `None` if the node is not a scroll container
+    pub fn get_scroll_extent(&self, node_id: DomNodeId) -> Option<LogicalSize> {
+        self.internal_get_current_scroll_states()
+            .get(&node_id.dom)?
+            .get(&node_id.node)
+            .map(|sp| sp.get_scroll_extent())
+    }
+
+    /// Scrolls the node to the given position (clamped to the scroll extent of the node)
+    /// in the next frame. Returns `false` if the node is not a scroll container.
+    pub fn set_scroll_position(
+        &mut self,
+        node_id: DomNodeId,
+        scroll_position: LogicalPosition,
+    ) -> bool {
+        let scroll_position = match self
+            .internal_get_current_scroll_states()
+            .get(&node_id.dom)
+            .and_then(|nodes| nodes.get(&node_id.node))
+        {
+            Some(sp) => sp.clamp_scroll_offset(scroll_position),
+            None => return false,
+        };
+
         self.internal_get_nodes_scrolled_in_callback()
             .entry(node_id.dom)
             .or_insert_with(|| BTreeMap::new())
             .insert(node_id.node, scroll_position);
+
+        true
     }
 
     pub fn get_parent(&self, node_id: DomNodeId) -> Option<DomNodeId> {
@@ -1573,6 +1730,45 @@ impl CallbackInfo {
         self.internal_get_modifiable_window_state().flags = new_flags;
     }
 
+    /// Registers a keyboard shortcut for the current window,
+    /// see `WindowState::add_accelerator`
+    pub fn add_accelerator(
+        &mut self,
+        accelerator: Accelerator,
+        data: RefAny,
+        callback: CallbackType,
+    ) -> AcceleratorId {
+        self.internal_get_modifiable_window_state()
+            .add_accelerator(accelerator, data, callback)
+    }
+
+    /// Replaces the shortcut and callback of an accelerator of the
+    /// current window, returns `false` if the `id` doesn't exist
+    pub fn replace_accelerator(
+        &mut self,
+        id: AcceleratorId,
+        accelerator: Accelerator,
+        data: RefAny,
+        callback: CallbackType,
+    ) -> bool {
+        self.internal_get_modifiable_window_state()
+            .replace_accelerator(id, accelerator, data, callback)
+    }
+
+    /// Removes an accelerator of the current window,
+    /// returns `false` if the `id` doesn't exist
+    pub fn remove_accelerator(&mut self, id: AcceleratorId) -> bool {
+        self.internal_get_modifiable_window_state()
+            .remove_accelerator(id)
+    }
+
+    /// Moves the IME candidate window to the given position (i.e. the text cursor),
+    /// relative to the top left of the window
+    pub fn set_ime_position(&mut self, position: LogicalPosition) {
+        self.internal_get_modifiable_window_state().ime_position =
+            ImePosition::Initialized(position);
+    }
+
     pub fn set_css_property(&mut self, node_id: DomNodeId, prop: CssProperty) {
         if let Some(nid) = node_id.node.into_crate_internal() {
             self.internal_get_css_properties_changed_in_callbacks()
@@ -1584,6 +1780,41 @@ impl CallbackInfo {
         }
     }
 
+    /// Replaces the stylesheet of the current window, i.e. for switching between
+    /// a light and a dark theme. The new stylesheet is applied to the DOM returned by
+    /// the next layout() call instead of the stylesheet of the `StyledDom`,
+    /// so this always triggers a DOM regeneration and a full relayout.
+    pub fn set_css(&mut self, css: CssApiWrapper) {
+        *self.internal_get_stylesheet_changed_in_callbacks() = Some(css);
+    }
+
+    /// Opens `menu` as a popup menu at `position` (relative to the top left corner of
+    /// the window), i.e. for a menu button. The callbacks of the menu items see the
+    /// node of this callback as the hit node, same as for `Dom::with_context_menu`.
+    pub fn open_context_menu(&mut self, menu: Menu, position: LogicalPosition) {
+        let hit_dom_node = self.get_hit_node();
+        *self.internal_get_context_menu_changed_in_callbacks() =
+            Some(ContextMenuChange::Open(ContextMenuRequest {
+                menu,
+                position,
+                hit_dom_node,
+            }));
+    }
+
+    /// Closes the popup menu opened by `open_context_menu`. Native popup menus
+    /// close themselves, so this only affects menus drawn by azul.
+    pub fn close_context_menu(&mut self) {
+        *self.internal_get_context_menu_changed_in_callbacks() = Some(ContextMenuChange::Close);
+    }
+
+    /// Copy of the `CallbackInfo` with a different hit node, used to invoke
+    /// menu callbacks with the node that the menu was opened on
+    pub(crate) fn with_hit_dom_node(&self, hit_dom_node: DomNodeId) -> Self {
+        let mut info = self.clone();
+        info.hit_dom_node = hit_dom_node;
+        info
+    }
+
     pub fn set_focus(&mut self, target: FocusTarget) {
         *self.internal_get_focus_target() = Some(target);
     }
@@ -1667,28 +1898,31 @@ impl CallbackInfo {
         Some(text_layout_options.clone())
     }
 
+    /// Returns the final value of a CSS property of a node,
+    /// see `StyledDom::get_computed_property`
     pub fn get_computed_css_property(
         &self,
         node_id: DomNodeId,
         property_type: CssPropertyType,
     ) -> Option<CssProperty> {
         let layout_result = self.internal_get_layout_results().get(node_id.dom.inner)?;
+        let nid = node_id.node.into_crate_internal()?;
+        layout_result
+            .styled_dom
+            .get_computed_property(nid, property_type)
+    }
 
-        /*
-            if node_id.dom != self.get_hit_node().dom {
-                return None;
-            }
-            let nid = node_id.node.into_crate_internal()?;
-            let css_property_cache = self.internal_get_css_property_cache();
-            let styled_nodes = self.internal_get_styled_node_states();
-            let styled_node_state = styled_nodes.internal.get(nid)?; //
-            let node_data = self.internal_get_
-        */
-
-        // TODO: can't access self.styled_dom.node_data[node_id].classes because
-        // self.styled_dom.node_data[node_id].dataset may be borrowed
-
-        None
+    /// Returns all computed CSS properties of a node,
+    /// see `StyledDom::get_computed_properties`
+    pub fn get_computed_css_properties(&self, node_id: DomNodeId) -> CssPropertyVec {
+        let nid = match node_id.node.into_crate_internal() {
+            Some(s) => s,
+            None => return CssPropertyVec::new(),
+        };
+        self.internal_get_layout_results()
+            .get(node_id.dom.inner)
+            .map(|l| l.styled_dom.get_computed_properties(nid))
+            .unwrap_or_default()
     }
 
     pub fn stop_propagation(&mut self) {
@@ -1720,6 +1954,9 @@ impl CallbackInfo {
         }
     }
 
+    /// Sends a message to a thread. Messages for threads that were started before
+    /// this callback are delivered after the callback returns, so this only returns
+    /// `false` if a thread started in this callback doesn't receive messages anymore.
     pub fn send_thread_msg(&mut self, thread_id: ThreadId, msg: ThreadSendMsg) -> bool {
         if let Some(thread) = self.internal_get_threads().get_mut(&thread_id) {
             if let Some(s) = thread.ptr.lock().ok() {
@@ -1728,7 +1965,8 @@ impl CallbackInfo {
                 false
             }
         } else {
-            false
+            self.internal_get_thread_msgs_sent().push((thread_id, msg));
+            true
         }
     }
 
@@ -1793,6 +2031,7 @@ impl CallbackInfo {
             parent_rect_height: parent_size.height,
             current_rect_width: current_size.width,
             current_rect_height: current_size.height,
+            em_size: layout_result.styled_dom.get_em_size(nid),
             get_system_time_fn: self
                 .internal_get_extern_system_callbacks()
                 .get_system_time_fn
@@ -1861,6 +2100,12 @@ impl CallbackInfo {
         self.internal_get_image_cache().delete_css_image_id(css_id);
     }
 
+    /// Replaces the pixels of an image in the internal image cache, keeping
+    /// its texture, see `ImageCache::update_css_image_raw`
+    pub fn update_image_raw(&mut self, css_id: &AzString, image: RawImage) -> bool {
+        self.internal_get_image_cache().update_css_image_raw(css_id, image)
+    }
+
     pub fn update_image(
         &mut self,
         node_id: DomNodeId,
@@ -1901,6 +2146,7 @@ impl Clone for CallbackInfo {
             threads: self.threads,
             timers_removed: self.timers_removed,
             threads_removed: self.threads_removed,
+            thread_msgs_sent: self.thread_msgs_sent,
             current_window_handle: self.current_window_handle,
             new_windows: self.new_windows,
             system_callbacks: self.system_callbacks,
@@ -1910,6 +2156,8 @@ impl Clone for CallbackInfo {
             images_changed_in_callbacks: self.images_changed_in_callbacks,
             image_masks_changed_in_callbacks: self.image_masks_changed_in_callbacks,
             css_properties_changed_in_callbacks: self.css_properties_changed_in_callbacks,
+            stylesheet_changed_in_callbacks: self.stylesheet_changed_in_callbacks,
+            context_menu_changed_in_callbacks: self.context_menu_changed_in_callbacks,
             current_scroll_states: self.current_scroll_states,
             nodes_scrolled_in_callback: self.nodes_scrolled_in_callback,
             hit_dom_node: self.hit_dom_node,
@@ -1941,6 +2189,7 @@ pub struct AnimationData {
     pub parent_rect_height: f32,
     pub current_rect_width: f32,
     pub current_rect_height: f32,
+    pub em_size: f32,
     pub get_system_time_fn: GetSystemTimeCallback,
 }
 
@@ -2004,6 +2253,7 @@ extern "C" fn drive_animation_func(
         parent_rect_height: anim_data.parent_rect_height,
         current_rect_width: anim_data.current_rect_width,
         current_rect_height: anim_data.current_rect_height,
+        em_size: anim_data.em_size,
         interpolate_func: anim_data.interpolate,
     };
 
@@ -2311,6 +2561,18 @@ pub struct IFrameCallback {
 }
 impl_callback!(IFrameCallback);
 
+impl IFrameCallback {
+    /// Invokes the callback, a panic is recorded as the last error
+    /// (see `azul_core::error`) and results in an empty iframe
+    pub fn invoke(
+        &self,
+        data: &mut RefAny,
+        info: &mut IFrameCallbackInfo,
+    ) -> IFrameCallbackReturn {
+        crate::error::catch_panic(|| (self.cb)(data, info)).unwrap_or_default()
+    }
+}
+
 #[derive(Debug)]
 #[repr(C)]
 pub struct IFrameCallbackInfo {
@@ -2893,3 +3155,169 @@ impl FocusTarget {
         }
     }
 }
+
+#[test]
+fn test_scroll_position_clamp() {
+    use azul_css::LayoutPoint;
+
+    let to_logical = |r: LayoutRect| {
+        LogicalRect::new(
+            LogicalPosition::new(r.origin.x as f32, r.origin.y as f32),
+            LogicalSize::new(r.size.width as f32, r.size.height as f32),
+        )
+    };
+
+    let parent = LayoutRect::new(LayoutPoint::new(0, 0), LayoutSize::new(100, 100));
+    let children = [
+        LayoutRect::new(LayoutPoint::new(0, 0), LayoutSize::new(100, 150)),
+        LayoutRect::new(LayoutPoint::new(0, 150), LayoutSize::new(250, 150)),
+    ];
+    let scroll_rect = parent.get_scroll_rect(children.iter().copied()).unwrap();
+
+    let scroll_position = ScrollPosition {
+        parent_rect: to_logical(parent),
+        children_rect: to_logical(scroll_rect),
+    };
+
+    assert_eq!(
+        scroll_position.get_scroll_extent(),
+        LogicalSize::new(150.0, 200.0)
+    );
+    assert_eq!(
+        scroll_position.clamp_scroll_offset(LogicalPosition::new(-10.0, 50.0)),
+        LogicalPosition::new(0.0, 50.0)
+    );
+    assert_eq!(
+        scroll_position.clamp_scroll_offset(LogicalPosition::new(500.0, 500.0)),
+        LogicalPosition::new(150.0, 200.0)
+    );
+}
+
+#[test]
+fn test_set_scroll_position() {
+    let scroll_node = DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(1))),
+    };
+    let other_node = DomNodeId {
+        dom: DomId::ROOT_ID,
+        node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(2))),
+    };
+
+    // 100x100 container with 250x300 content: scrollable by 150x200
+    let mut current_scroll_states = BTreeMap::new();
+    current_scroll_states
+        .entry(DomId::ROOT_ID)
+        .or_insert_with(BTreeMap::new)
+        .insert(
+            scroll_node.node,
+            ScrollPosition {
+                parent_rect: LogicalRect::new(
+                    LogicalPosition::zero(),
+                    LogicalSize::new(100.0, 100.0),
+                ),
+                children_rect: LogicalRect::new(
+                    LogicalPosition::zero(),
+                    LogicalSize::new(250.0, 300.0),
+                ),
+            },
+        );
+
+    let renderer_resources = RendererResources::default();
+    let previous_window_state = None;
+    let current_window_state = FullWindowState::default();
+    let mut modifiable_window_state: WindowState = current_window_state.clone().into();
+    let gl_context = OptionGlContextPtr::None;
+    let mut image_cache = ImageCache::new();
+    let mut system_fonts = FcFontCache::default();
+    let mut timers = FastHashMap::new();
+    let mut threads = FastHashMap::new();
+    let mut timers_removed = FastBTreeSet::new();
+    let mut threads_removed = FastBTreeSet::new();
+    let mut thread_msgs_sent = Vec::new();
+    let current_window_handle = RawWindowHandle::Unsupported;
+    let mut new_windows = Vec::new();
+    let system_callbacks = ExternalSystemCallbacks::rust_internal();
+    let mut stop_propagation = false;
+    let mut focus_target = None;
+    let mut words_changed = BTreeMap::new();
+    let mut images_changed = BTreeMap::new();
+    let mut image_masks_changed = BTreeMap::new();
+    let mut css_properties_changed = BTreeMap::new();
+    let mut stylesheet_changed = None;
+    let mut context_menu_changed = None;
+    let mut nodes_scrolled_in_callback = BTreeMap::new();
+
+    let mut callback_info = CallbackInfo::new(
+        &[],
+        &renderer_resources,
+        &previous_window_state,
+        &current_window_state,
+        &mut modifiable_window_state,
+        &gl_context,
+        &mut image_cache,
+        &mut system_fonts,
+        &mut timers,
+        &mut threads,
+        &mut timers_removed,
+        &mut threads_removed,
+        &mut thread_msgs_sent,
+        &current_window_handle,
+        &mut new_windows,
+        &system_callbacks,
+        &mut stop_propagation,
+        &mut focus_target,
+        &mut words_changed,
+        &mut images_changed,
+        &mut image_masks_changed,
+        &mut css_properties_changed,
+        &mut stylesheet_changed,
+        &mut context_menu_changed,
+        &current_scroll_states,
+        &mut nodes_scrolled_in_callback,
+        scroll_node,
+        OptionLogicalPosition::None,
+        OptionLogicalPosition::None,
+    );
+
+    assert!(callback_info.set_scroll_position(scroll_node, LogicalPosition::new(-10.0, 500.0)));
+    assert!(!callback_info.set_scroll_position(other_node, LogicalPosition::new(10.0, 10.0)));
+
+    let scrolled = &nodes_scrolled_in_callback[&DomId::ROOT_ID];
+    assert_eq!(scrolled.len(), 1);
+    assert_eq!(scrolled[&scroll_node.node], LogicalPosition::new(0.0, 200.0));
+}
+
+#[test]
+fn test_ref_any_drops_large_value_once() {
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Large {
+        data: [u64; 64],
+        drops: Arc<AtomicUsize>,
+    }
+
+    impl Drop for Large {
+        fn drop(&mut self) {
+            assert!(self.data.iter().enumerate().all(|(i, d)| *d == i as u64));
+            self.drops.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let drops = Arc::new(AtomicUsize::new(0));
+    let mut data = [0; 64];
+    for (i, d) in data.iter_mut().enumerate() {
+        *d = i as u64;
+    }
+
+    let a = RefAny::new(Large {
+        data,
+        drops: drops.clone(),
+    });
+    let b = a.clone();
+    drop(a);
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+    drop(b);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
@@ -33,11 +33,22 @@ pub struct VecContents {
     style_background_contents: BTreeMap<u64, StyleBackgroundContentVec>,
     style_background_positions: BTreeMap<u64, StyleBackgroundPositionVec>,
     style_transforms: BTreeMap<u64, StyleTransformVec>,
+    grid_tracks: BTreeMap<u64, GridTrackVec>,
+    css_property_types: BTreeMap<u64, CssPropertyTypeVec>,
     font_families: BTreeMap<u64, StyleFontFamilyVec>,
     linear_color_stops: BTreeMap<u64, NormalizedLinearColorStopVec>,
     radial_color_stops: BTreeMap<u64, NormalizedRadialColorStopVec>,
 }
 
+// vec property values are stored in a `BoxOrStatic`, which has to borrow a `static`
+// value that is wrapped in a `CssBoxed` (i.e. `StyleFontFamilyVecBoxed`)
+fn format_static_vec(name: &str, vec_type: &str, key: u64) -> String {
+    format!(
+        "\r\n    static {}_{}: {}Boxed = {}Boxed::new_static({}::from_const_slice({}_{}_ITEMS));",
+        name, key, vec_type, vec_type, vec_type, name, key
+    )
+}
+
 impl VecContents {
     pub fn format(&self, tabs: usize) -> String {
         let mut result = String::new();
@@ -63,6 +74,7 @@ impl VecContents {
                 "\r\n    const STYLE_FILTER_{}_ITEMS: &[StyleFilter] = &[\r\n{}{}\r\n{}];",
                 key, t2, val, t
             ));
+            result.push_str(&format_static_vec("STYLE_FILTER", "StyleFilterVec", *key));
         }
 
         for (key, item) in self.style_background_sizes.iter() {
@@ -74,6 +86,7 @@ impl VecContents {
 
             result.push_str(&format!("\r\n    const STYLE_BACKGROUND_SIZE_{}_ITEMS: &[StyleBackgroundSize] = &[\r\n{}{}\r\n{}];",
             key, t2, val, t));
+            result.push_str(&format_static_vec("STYLE_BACKGROUND_SIZE", "StyleBackgroundSizeVec", *key));
         }
 
         for (key, item) in self.style_background_repeats.iter() {
@@ -85,6 +98,7 @@ impl VecContents {
 
             result.push_str(&format!("\r\n    const STYLE_BACKGROUND_REPEAT_{}_ITEMS: &[StyleBackgroundRepeat] = &[\r\n{}{}\r\n{}];",
             key, t2, val, t));
+            result.push_str(&format_static_vec("STYLE_BACKGROUND_REPEAT", "StyleBackgroundRepeatVec", *key));
         }
 
         for (key, item) in self.style_background_contents.iter() {
@@ -96,6 +110,7 @@ impl VecContents {
 
             result.push_str(&format!("\r\n    const STYLE_BACKGROUND_CONTENT_{}_ITEMS: &[StyleBackgroundContent] = &[\r\n{}{}\r\n{}];",
             key, t2, val, t));
+            result.push_str(&format_static_vec("STYLE_BACKGROUND_CONTENT", "StyleBackgroundContentVec", *key));
         }
 
         for (key, item) in self.style_background_positions.iter() {
@@ -107,6 +122,7 @@ impl VecContents {
 
             result.push_str(&format!("\r\n    const STYLE_BACKGROUND_POSITION_{}_ITEMS: &[StyleBackgroundPosition] = &[\r\n{}{}\r\n{}];",
             key, t2, val, t));
+            result.push_str(&format_static_vec("STYLE_BACKGROUND_POSITION", "StyleBackgroundPositionVec", *key));
         }
 
         for (key, item) in self.style_transforms.iter() {
@@ -116,6 +132,33 @@ impl VecContents {
                 "\r\n    const STYLE_TRANSFORM_{}_ITEMS: &[StyleTransform] = &[\r\n{}{}\r\n{}];",
                 key, t2, val, t
             ));
+            result.push_str(&format_static_vec("STYLE_TRANSFORM", "StyleTransformVec", *key));
+        }
+
+        for (key, item) in self.grid_tracks.iter() {
+            let val = item
+                .iter()
+                .map(|track| format_grid_track(track))
+                .collect::<Vec<_>>()
+                .join(&format!(",\r\n{}", t));
+
+            result.push_str(&format!(
+                "\r\n    const GRID_TRACK_{}_ITEMS: &[GridTrack] = &[\r\n{}{}\r\n{}];",
+                key, t2, val, t
+            ));
+        }
+
+        for (key, item) in self.css_property_types.iter() {
+            let val = item
+                .iter()
+                .map(|ty| ty.format_as_rust_code(tabs + 1))
+                .collect::<Vec<_>>()
+                .join(&format!(",\r\n{}", t));
+
+            result.push_str(&format!(
+                "\r\n    const CSS_PROPERTY_TYPE_{}_ITEMS: &[CssPropertyType] = &[\r\n{}{}\r\n{}];",
+                key, t2, val, t
+            ));
         }
 
         for (key, item) in self.font_families.iter() {
@@ -125,6 +168,7 @@ impl VecContents {
                 "\r\n    const STYLE_FONT_FAMILY_{}_ITEMS: &[StyleFontFamily] = &[\r\n{}{}\r\n{}];",
                 key, t2, val, t
             ));
+            result.push_str(&format_static_vec("STYLE_FONT_FAMILY", "StyleFontFamilyVec", *key));
         }
 
         for (key, item) in self.linear_color_stops.iter() {
@@ -174,21 +218,27 @@ impl VecContents {
                         _ => {}
                     }
                 }
-                self.font_families.insert(v.get_hash(), v.clone());
+                self.font_families.insert(v.get_hash(), (**v).clone());
             }
             CssProperty::Transform(CssPropertyValue::Exact(v)) => {
-                self.style_transforms.insert(v.get_hash(), v.clone());
+                self.style_transforms.insert(v.get_hash(), (**v).clone());
+            }
+            CssProperty::GridTemplateColumns(CssPropertyValue::Exact(v)) => {
+                self.insert_grid_tracks(&v.inner);
+            }
+            CssProperty::GridTemplateRows(CssPropertyValue::Exact(v)) => {
+                self.insert_grid_tracks(&v.inner);
             }
             CssProperty::BackgroundRepeat(CssPropertyValue::Exact(v)) => {
                 self.style_background_repeats
-                    .insert(v.get_hash(), v.clone());
+                    .insert(v.get_hash(), (**v).clone());
             }
             CssProperty::BackgroundSize(CssPropertyValue::Exact(v)) => {
-                self.style_background_sizes.insert(v.get_hash(), v.clone());
+                self.style_background_sizes.insert(v.get_hash(), (**v).clone());
             }
             CssProperty::BackgroundPosition(CssPropertyValue::Exact(v)) => {
                 self.style_background_positions
-                    .insert(v.get_hash(), v.clone());
+                    .insert(v.get_hash(), (**v).clone());
             }
             CssProperty::BackgroundContent(CssPropertyValue::Exact(v)) => {
                 for background in v.iter() {
@@ -212,17 +262,32 @@ impl VecContents {
                     }
                 }
                 self.style_background_contents
-                    .insert(v.get_hash(), v.clone());
+                    .insert(v.get_hash(), (**v).clone());
             }
             CssProperty::Filter(CssPropertyValue::Exact(v)) => {
-                self.style_filters.insert(v.get_hash(), v.clone());
+                self.style_filters.insert(v.get_hash(), (**v).clone());
             }
             CssProperty::BackdropFilter(CssPropertyValue::Exact(v)) => {
-                self.style_filters.insert(v.get_hash(), v.clone());
+                self.style_filters.insert(v.get_hash(), (**v).clone());
+            }
+            CssProperty::TransitionProperty(CssPropertyValue::Exact(p)) => {
+                if let StyleTransitionProperty::List(v) = &**p {
+                    self.css_property_types.insert(v.get_hash(), v.clone());
+                }
             }
             _ => {}
         }
     }
+
+    // repeat() tracks contain their own GridTrackVec, which has to be declared separately
+    fn insert_grid_tracks(&mut self, tracks: &GridTrackVec) {
+        for track in tracks.iter() {
+            if let GridTrack::Repeat(r) = track {
+                self.insert_grid_tracks(&r.tracks);
+            }
+        }
+        self.grid_tracks.insert(tracks.get_hash(), tracks.clone());
+    }
 }
 
 pub fn css_to_rust_code(css: &Css) -> String {
@@ -260,6 +325,17 @@ pub fn css_to_rust_code(css: &Css) -> String {
         output.push_str("\t\t},\r\n");
     }
 
+    output.push_str("\t],\r\n");
+    output.push_str("\tvariables: [\r\n");
+
+    for variable in css.variables.iter() {
+        output.push_str(&format!(
+            "\t\tCssVariable {{ name: {:?}, value: {} }},\r\n",
+            variable.name,
+            format_static_css_prop(&variable.value, 2)
+        ));
+    }
+
     output.push_str("\t]\r\n");
     output.push_str("};");
 
@@ -377,6 +453,12 @@ trait FormatAsRustCode {
     fn format_as_rust_code(&self, _tabs: usize) -> String;
 }
 
+impl<T: FormatAsRustCode> FormatAsRustCode for BoxOrStatic<T> {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        format!("BoxOrStatic::from_static(css_boxed_static!({}))", (**self).format_as_rust_code(tabs))
+    }
+}
+
 pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String {
     match prop {
         CssProperty::TextColor(p) => format!(
@@ -395,6 +477,14 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
             "CssProperty::TextAlign({})",
             print_css_property_value(p, tabs, "StyleTextAlign")
         ),
+        CssProperty::VerticalAlign(p) => format!(
+            "CssProperty::VerticalAlign({})",
+            print_css_property_value(p, tabs, "StyleVerticalAlign")
+        ),
+        CssProperty::Direction(p) => format!(
+            "CssProperty::Direction({})",
+            print_css_property_value(p, tabs, "StyleDirection")
+        ),
         CssProperty::LetterSpacing(p) => format!(
             "CssProperty::LetterSpacing({})",
             print_css_property_value(p, tabs, "StyleLetterSpacing")
@@ -429,27 +519,27 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
         ),
         CssProperty::Width(p) => format!(
             "CssProperty::Width({})",
-            print_css_property_value(p, tabs, "LayoutWidth")
+            print_css_length_value(p, tabs, "LayoutWidth")
         ),
         CssProperty::Height(p) => format!(
             "CssProperty::Height({})",
-            print_css_property_value(p, tabs, "LayoutHeight")
+            print_css_length_value(p, tabs, "LayoutHeight")
         ),
         CssProperty::MinWidth(p) => format!(
             "CssProperty::MinWidth({})",
-            print_css_property_value(p, tabs, "LayoutMinWidth")
+            print_css_length_value(p, tabs, "LayoutMinWidth")
         ),
         CssProperty::MinHeight(p) => format!(
             "CssProperty::MinHeight({})",
-            print_css_property_value(p, tabs, "LayoutMinHeight")
+            print_css_length_value(p, tabs, "LayoutMinHeight")
         ),
         CssProperty::MaxWidth(p) => format!(
             "CssProperty::MaxWidth({})",
-            print_css_property_value(p, tabs, "LayoutMaxWidth")
+            print_css_length_value(p, tabs, "LayoutMaxWidth")
         ),
         CssProperty::MaxHeight(p) => format!(
             "CssProperty::MaxHeight({})",
-            print_css_property_value(p, tabs, "LayoutMaxHeight")
+            print_css_length_value(p, tabs, "LayoutMaxHeight")
         ),
         CssProperty::Position(p) => format!(
             "CssProperty::Position({})",
@@ -457,19 +547,19 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
         ),
         CssProperty::Top(p) => format!(
             "CssProperty::Top({})",
-            print_css_property_value(p, tabs, "LayoutTop")
+            print_css_length_value(p, tabs, "LayoutTop")
         ),
         CssProperty::Right(p) => format!(
             "CssProperty::Right({})",
-            print_css_property_value(p, tabs, "LayoutRight")
+            print_css_length_value(p, tabs, "LayoutRight")
         ),
         CssProperty::Left(p) => format!(
             "CssProperty::Left({})",
-            print_css_property_value(p, tabs, "LayoutLeft")
+            print_css_length_value(p, tabs, "LayoutLeft")
         ),
         CssProperty::Bottom(p) => format!(
             "CssProperty::Bottom({})",
-            print_css_property_value(p, tabs, "LayoutBottom")
+            print_css_length_value(p, tabs, "LayoutBottom")
         ),
         CssProperty::FlexWrap(p) => format!(
             "CssProperty::FlexWrap({})",
@@ -499,6 +589,22 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
             "CssProperty::AlignContent({})",
             print_css_property_value(p, tabs, "LayoutAlignContent")
         ),
+        CssProperty::GridTemplateColumns(p) => format!(
+            "CssProperty::GridTemplateColumns({})",
+            print_css_property_value(p, tabs, "LayoutGridTemplateColumns")
+        ),
+        CssProperty::GridTemplateRows(p) => format!(
+            "CssProperty::GridTemplateRows({})",
+            print_css_property_value(p, tabs, "LayoutGridTemplateRows")
+        ),
+        CssProperty::GridColumn(p) => format!(
+            "CssProperty::GridColumn({})",
+            print_css_property_value(p, tabs, "LayoutGridColumn")
+        ),
+        CssProperty::GridRow(p) => format!(
+            "CssProperty::GridRow({})",
+            print_css_property_value(p, tabs, "LayoutGridRow")
+        ),
         CssProperty::BackgroundContent(p) => format!(
             "CssProperty::BackgroundContent({})",
             print_css_property_value(p, tabs, "StyleBackgroundContentVec")
@@ -525,35 +631,35 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
         ),
         CssProperty::PaddingTop(p) => format!(
             "CssProperty::PaddingTop({})",
-            print_css_property_value(p, tabs, "LayoutPaddingTop")
+            print_css_length_value(p, tabs, "LayoutPaddingTop")
         ),
         CssProperty::PaddingLeft(p) => format!(
             "CssProperty::PaddingLeft({})",
-            print_css_property_value(p, tabs, "LayoutPaddingLeft")
+            print_css_length_value(p, tabs, "LayoutPaddingLeft")
         ),
         CssProperty::PaddingRight(p) => format!(
             "CssProperty::PaddingRight({})",
-            print_css_property_value(p, tabs, "LayoutPaddingRight")
+            print_css_length_value(p, tabs, "LayoutPaddingRight")
         ),
         CssProperty::PaddingBottom(p) => format!(
             "CssProperty::PaddingBottom({})",
-            print_css_property_value(p, tabs, "LayoutPaddingBottom")
+            print_css_length_value(p, tabs, "LayoutPaddingBottom")
         ),
         CssProperty::MarginTop(p) => format!(
             "CssProperty::MarginTop({})",
-            print_css_property_value(p, tabs, "LayoutMarginTop")
+            print_css_length_value(p, tabs, "LayoutMarginTop")
         ),
         CssProperty::MarginLeft(p) => format!(
             "CssProperty::MarginLeft({})",
-            print_css_property_value(p, tabs, "LayoutMarginLeft")
+            print_css_length_value(p, tabs, "LayoutMarginLeft")
         ),
         CssProperty::MarginRight(p) => format!(
             "CssProperty::MarginRight({})",
-            print_css_property_value(p, tabs, "LayoutMarginRight")
+            print_css_length_value(p, tabs, "LayoutMarginRight")
         ),
         CssProperty::MarginBottom(p) => format!(
             "CssProperty::MarginBottom({})",
-            print_css_property_value(p, tabs, "LayoutMarginBottom")
+            print_css_length_value(p, tabs, "LayoutMarginBottom")
         ),
         CssProperty::BorderTopLeftRadius(p) => format!(
             "CssProperty::BorderTopLeftRadius({})",
@@ -663,6 +769,14 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
             "CssProperty::MixBlendMode({})",
             print_css_property_value(p, tabs, "StyleMixBlendMode")
         ),
+        CssProperty::Isolation(p) => format!(
+            "CssProperty::Isolation({})",
+            print_css_property_value(p, tabs, "StyleIsolation")
+        ),
+        CssProperty::ImageRendering(p) => format!(
+            "CssProperty::ImageRendering({})",
+            print_css_property_value(p, tabs, "StyleImageRendering")
+        ),
         CssProperty::Filter(p) => format!(
             "CssProperty::Filter({})",
             print_css_property_value(p, tabs, "StyleFilterVec")
@@ -675,6 +789,34 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
             "CssProperty::TextShadow({})",
             print_css_property_value(p, tabs, "StyleBoxShadow")
         ),
+        CssProperty::TransitionProperty(p) => format!(
+            "CssProperty::TransitionProperty({})",
+            print_css_property_value(p, tabs, "StyleTransitionProperty")
+        ),
+        CssProperty::TransitionDuration(p) => format!(
+            "CssProperty::TransitionDuration({})",
+            print_css_property_value(p, tabs, "StyleTransitionDuration")
+        ),
+        CssProperty::TransitionTimingFunction(p) => format!(
+            "CssProperty::TransitionTimingFunction({})",
+            print_css_property_value(p, tabs, "StyleTransitionTimingFunction")
+        ),
+        CssProperty::TransitionDelay(p) => format!(
+            "CssProperty::TransitionDelay({})",
+            print_css_property_value(p, tabs, "StyleTransitionDelay")
+        ),
+        CssProperty::CaretColor(p) => format!(
+            "CssProperty::CaretColor({})",
+            print_css_property_value(p, tabs, "StyleCaretColor")
+        ),
+        CssProperty::SelectionColor(p) => format!(
+            "CssProperty::SelectionColor({})",
+            print_css_property_value(p, tabs, "StyleSelectionColor")
+        ),
+        CssProperty::SelectionBackground(p) => format!(
+            "CssProperty::SelectionBackground({})",
+            print_css_property_value(p, tabs, "StyleSelectionBackground")
+        ),
     }
 }
 
@@ -696,6 +838,34 @@ fn print_css_property_value<T: FormatAsRustCode>(
     }
 }
 
+fn print_css_length_value<T: FormatAsRustCode>(
+    prop_val: &CssLengthValue<T>,
+    tabs: usize,
+    property_value_type: &'static str,
+) -> String {
+    match prop_val {
+        CssLengthValue::Auto => format!("{}Value::Auto", property_value_type),
+        CssLengthValue::None => format!("{}Value::None", property_value_type),
+        CssLengthValue::Initial => format!("{}Value::Initial", property_value_type),
+        CssLengthValue::Inherit => format!("{}Value::Inherit", property_value_type),
+        CssLengthValue::Exact(t) => format!(
+            "{}Value::Exact({})",
+            property_value_type,
+            t.format_as_rust_code(tabs)
+        ),
+        CssLengthValue::Calc(c) => format!(
+            // not rounded to whole numbers like format_float_value, so that
+            // calc(100% / 3) stays accurate
+            "{}Value::Calc(BoxOrStatic::from_static(css_boxed_static!(CalcLength {{ px: FloatValue {{ number: {} }}, pt: FloatValue {{ number: {} }}, em: FloatValue {{ number: {} }}, percent: FloatValue {{ number: {} }} }})))",
+            property_value_type,
+            c.px.number,
+            c.pt.number,
+            c.em.number,
+            c.percent.number,
+        ),
+    }
+}
+
 fn format_dynamic_css_prop(decl: &DynamicCssProperty, tabs: usize) -> String {
     let t = String::from("    ").repeat(tabs);
     format!(
@@ -859,6 +1029,9 @@ macro_rules! impl_color_value_fmt {
 }
 
 impl_color_value_fmt!(StyleTextColor);
+impl_color_value_fmt!(StyleCaretColor);
+impl_color_value_fmt!(StyleSelectionColor);
+impl_color_value_fmt!(StyleSelectionBackground);
 impl_color_value_fmt!(StyleBorderTopColor);
 impl_color_value_fmt!(StyleBorderLeftColor);
 impl_color_value_fmt!(StyleBorderRightColor);
@@ -947,7 +1120,7 @@ impl_enum_fmt!(
 impl FormatAsRustCode for StyleFilterVec {
     fn format_as_rust_code(&self, tabs: usize) -> String {
         format!(
-            "StyleFilterVec::from_const_slice(STYLE_FILTER_{}_ITEMS)",
+            "STYLE_FILTER_{}",
             self.get_hash()
         )
     }
@@ -956,7 +1129,7 @@ impl FormatAsRustCode for StyleFilterVec {
 impl FormatAsRustCode for StyleBackgroundSizeVec {
     fn format_as_rust_code(&self, tabs: usize) -> String {
         format!(
-            "StyleBackgroundSizeVec::from_const_slice(STYLE_BACKGROUND_SIZE_{}_ITEMS)",
+            "STYLE_BACKGROUND_SIZE_{}",
             self.get_hash()
         )
     }
@@ -1006,26 +1179,26 @@ fn format_scrollbar_info(s: &ScrollbarInfo, tabs: usize) -> String {
     )
 }
 
-impl_enum_fmt!(StyleBackgroundRepeat, NoRepeat, Repeat, RepeatX, RepeatY);
+impl_enum_fmt!(StyleBackgroundRepeat, NoRepeat, Repeat, RepeatX, RepeatY, Space, Round);
 
 impl FormatAsRustCode for StyleBackgroundRepeatVec {
     fn format_as_rust_code(&self, tabs: usize) -> String {
         format!(
-            "StyleBackgroundRepeatVec::from_const_slice(STYLE_BACKGROUND_REPEAT_{}_ITEMS)",
+            "STYLE_BACKGROUND_REPEAT_{}",
             self.get_hash()
         )
     }
 }
 
-impl_enum_fmt!(LayoutDisplay, None, Flex, Block, InlineBlock);
+impl_enum_fmt!(LayoutDisplay, None, Flex, Block, InlineBlock, Grid, Inline);
 
-impl_enum_fmt!(LayoutFloat, Left, Right);
+impl_enum_fmt!(LayoutFloat, Left, Right, None);
 
 impl_enum_fmt!(LayoutBoxSizing, ContentBox, BorderBox);
 
 impl_enum_fmt!(LayoutFlexDirection, Row, RowReverse, Column, ColumnReverse);
 
-impl_enum_fmt!(LayoutFlexWrap, Wrap, NoWrap);
+impl_enum_fmt!(LayoutFlexWrap, Wrap, NoWrap, WrapReverse);
 
 impl_enum_fmt!(
     LayoutJustifyContent,
@@ -1053,9 +1226,28 @@ impl_enum_fmt!(Shape, Circle, Ellipse);
 
 impl_enum_fmt!(LayoutPosition, Static, Fixed, Absolute, Relative);
 
-impl_enum_fmt!(LayoutOverflow, Auto, Scroll, Visible, Hidden);
+impl_enum_fmt!(LayoutOverflow, Auto, Scroll, Visible, Hidden, Clip);
+
+impl_enum_fmt!(StyleTextAlign, Center, Left, Right, Start, End);
 
-impl_enum_fmt!(StyleTextAlign, Center, Left, Right);
+impl_enum_fmt!(StyleDirection, Ltr, Rtl);
+
+impl FormatAsRustCode for StyleVerticalAlign {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        use azul_css::StyleVerticalAlign::*;
+        match self {
+            Baseline => String::from("StyleVerticalAlign::Baseline"),
+            Top => String::from("StyleVerticalAlign::Top"),
+            Middle => String::from("StyleVerticalAlign::Middle"),
+            Bottom => String::from("StyleVerticalAlign::Bottom"),
+            TextTop => String::from("StyleVerticalAlign::TextTop"),
+            TextBottom => String::from("StyleVerticalAlign::TextBottom"),
+            Sub => String::from("StyleVerticalAlign::Sub"),
+            Super => String::from("StyleVerticalAlign::Super"),
+            Exact(p) => format!("StyleVerticalAlign::Exact({})", format_pixel_value(p)),
+        }
+    }
+}
 
 impl_enum_fmt!(
     DirectionCorner,
@@ -1073,10 +1265,35 @@ impl_enum_fmt!(ExtendMode, Clamp, Repeat);
 
 impl_enum_fmt!(StyleBackfaceVisibility, Visible, Hidden);
 
+impl_enum_fmt!(StyleIsolation, Auto, Isolate);
+
+impl_enum_fmt!(StyleImageRendering, Auto, CrispEdges, Pixelated);
+
+impl_enum_fmt!(StepPosition, JumpStart, JumpEnd, JumpNone, JumpBoth);
+
+impl_enum_fmt!(
+    CssPropertyType,
+    TextColor, FontSize, FontFamily, TextAlign, VerticalAlign, Direction, LetterSpacing,
+    LineHeight, WordSpacing, TabWidth, Cursor, Display, Float, BoxSizing, Width, Height,
+    MinWidth, MinHeight, MaxWidth, MaxHeight, Position, Top, Right, Left, Bottom, FlexWrap,
+    FlexDirection, FlexGrow, FlexShrink, JustifyContent, AlignItems, AlignContent,
+    GridTemplateColumns, GridTemplateRows, GridColumn, GridRow, BackgroundContent,
+    BackgroundPosition, BackgroundSize, BackgroundRepeat, OverflowX, OverflowY, PaddingTop,
+    PaddingLeft, PaddingRight, PaddingBottom, MarginTop, MarginLeft, MarginRight, MarginBottom,
+    BorderTopLeftRadius, BorderTopRightRadius, BorderBottomLeftRadius, BorderBottomRightRadius,
+    BorderTopColor, BorderRightColor, BorderLeftColor, BorderBottomColor, BorderTopStyle,
+    BorderRightStyle, BorderLeftStyle, BorderBottomStyle, BorderTopWidth, BorderRightWidth,
+    BorderLeftWidth, BorderBottomWidth, BoxShadowLeft, BoxShadowRight, BoxShadowTop,
+    BoxShadowBottom, ScrollbarStyle, Opacity, Transform, TransformOrigin, PerspectiveOrigin,
+    BackfaceVisibility, MixBlendMode, Isolation, ImageRendering, Filter, BackdropFilter,
+    TextShadow, TransitionProperty, TransitionDuration, TransitionTimingFunction, TransitionDelay,
+    CaretColor, SelectionColor, SelectionBackground
+);
+
 impl FormatAsRustCode for StyleBackgroundContentVec {
     fn format_as_rust_code(&self, tabs: usize) -> String {
         format!(
-            "StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_{}_ITEMS)",
+            "STYLE_BACKGROUND_CONTENT_{}",
             self.get_hash()
         )
     }
@@ -1186,12 +1403,155 @@ fn format_radial_color_stop(g: &NormalizedRadialColorStop) -> String {
 impl FormatAsRustCode for StyleTransformVec {
     fn format_as_rust_code(&self, tabs: usize) -> String {
         format!(
-            "StyleTransformVec::from_const_slice(STYLE_TRANSFORM_{}_ITEMS)",
+            "STYLE_TRANSFORM_{}",
+            self.get_hash()
+        )
+    }
+}
+
+impl FormatAsRustCode for GridTrackVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!(
+            "GridTrackVec::from_const_slice(GRID_TRACK_{}_ITEMS)",
             self.get_hash()
         )
     }
 }
 
+impl FormatAsRustCode for CssPropertyTypeVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!(
+            "CssPropertyTypeVec::from_const_slice(CSS_PROPERTY_TYPE_{}_ITEMS)",
+            self.get_hash()
+        )
+    }
+}
+
+impl FormatAsRustCode for StyleTransitionProperty {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        match self {
+            StyleTransitionProperty::All => format!("StyleTransitionProperty::All"),
+            StyleTransitionProperty::None => format!("StyleTransitionProperty::None"),
+            StyleTransitionProperty::List(l) => format!(
+                "StyleTransitionProperty::List({})",
+                l.format_as_rust_code(tabs)
+            ),
+        }
+    }
+}
+
+impl FormatAsRustCode for StyleTransitionDuration {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!(
+            "StyleTransitionDuration::const_ms({})",
+            libm::roundf(self.get_ms()) as isize
+        )
+    }
+}
+
+impl FormatAsRustCode for StyleTransitionDelay {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!(
+            "StyleTransitionDelay::const_ms({})",
+            libm::roundf(self.get_ms()) as isize
+        )
+    }
+}
+
+impl FormatAsRustCode for StyleTransitionTimingFunction {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        use azul_css::StyleTransitionTimingFunction::*;
+        match self {
+            Linear => format!("StyleTransitionTimingFunction::Linear"),
+            Ease => format!("StyleTransitionTimingFunction::Ease"),
+            EaseIn => format!("StyleTransitionTimingFunction::EaseIn"),
+            EaseOut => format!("StyleTransitionTimingFunction::EaseOut"),
+            EaseInOut => format!("StyleTransitionTimingFunction::EaseInOut"),
+            // raw numbers instead of format_float_value, since the
+            // control points are almost never whole numbers
+            CubicBezier(b) => format!(
+                "StyleTransitionTimingFunction::CubicBezier(StyleCubicBezier {{ x1: FloatValue {{ number: {} }}, y1: FloatValue {{ number: {} }}, x2: FloatValue {{ number: {} }}, y2: FloatValue {{ number: {} }} }})",
+                b.x1.number,
+                b.y1.number,
+                b.x2.number,
+                b.y2.number,
+            ),
+            Steps(s) => format!(
+                "StyleTransitionTimingFunction::Steps(StyleSteps {{ count: {}, position: {} }})",
+                s.count,
+                s.position.format_as_rust_code(tabs)
+            ),
+        }
+    }
+}
+
+impl FormatAsRustCode for LayoutGridTemplateColumns {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        format!(
+            "LayoutGridTemplateColumns {{ inner: {} }}",
+            self.inner.format_as_rust_code(tabs)
+        )
+    }
+}
+
+impl FormatAsRustCode for LayoutGridTemplateRows {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        format!(
+            "LayoutGridTemplateRows {{ inner: {} }}",
+            self.inner.format_as_rust_code(tabs)
+        )
+    }
+}
+
+impl FormatAsRustCode for LayoutGridColumn {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!(
+            "LayoutGridColumn {{ inner: {} }}",
+            format_grid_placement(&self.inner)
+        )
+    }
+}
+
+impl FormatAsRustCode for LayoutGridRow {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!(
+            "LayoutGridRow {{ inner: {} }}",
+            format_grid_placement(&self.inner)
+        )
+    }
+}
+
+fn format_grid_track(track: &GridTrack) -> String {
+    match track {
+        GridTrack::Px(p) => format!("GridTrack::Px({})", format_pixel_value(p)),
+        GridTrack::Fraction(f) => format!("GridTrack::Fraction({})", format_float_value(f)),
+        GridTrack::MinContent => format!("GridTrack::MinContent"),
+        GridTrack::MaxContent => format!("GridTrack::MaxContent"),
+        GridTrack::Auto => format!("GridTrack::Auto"),
+        GridTrack::Repeat(r) => format!(
+            "GridTrack::Repeat(GridTrackRepeat {{ count: {}, tracks: {} }})",
+            r.count,
+            r.tracks.format_as_rust_code(0)
+        ),
+    }
+}
+
+fn format_grid_line(line: &GridLine) -> String {
+    match line {
+        GridLine::Auto => format!("GridLine::Auto"),
+        GridLine::Line(l) => format!("GridLine::Line({})", l),
+        GridLine::Span(s) => format!("GridLine::Span({})", s),
+    }
+}
+
+fn format_grid_placement(p: &GridPlacement) -> String {
+    format!(
+        "GridPlacement {{ start: {}, end: {} }}",
+        format_grid_line(&p.start),
+        format_grid_line(&p.end)
+    )
+}
+
 fn format_style_filter(st: &StyleFilter, tabs: usize) -> String {
     let tabs_minus_one = String::from("    ").repeat(tabs);
     let tabs_str = String::from("    ").repeat(tabs + 1);
@@ -1319,7 +1679,7 @@ fn format_font_ids(font_ids: &[StyleFontFamily], tabs: usize) -> String {
 impl FormatAsRustCode for StyleFontFamilyVec {
     fn format_as_rust_code(&self, tabs: usize) -> String {
         format!(
-            "StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_{}_ITEMS)",
+            "STYLE_FONT_FAMILY_{}",
             self.get_hash()
         )
     }
@@ -1340,7 +1700,7 @@ impl FormatAsRustCode for StyleFontFamily {
 impl FormatAsRustCode for StyleBackgroundPositionVec {
     fn format_as_rust_code(&self, tabs: usize) -> String {
         format!(
-            "StyleBackgroundPositionVec::from_const_slice(STYLE_BACKGROUND_POSITION_{}_ITEMS)",
+            "STYLE_BACKGROUND_POSITION_{}",
             self.get_hash()
         )
     }
@@ -1,262 +1,560 @@
-use core::{
-    fmt,
-};
+//! Diffing of two `Dom` trees
+//!
+//! `Dom::diff` (or `StyledDom::diff`) compares the DOM of the last frame
+//! with the DOM of the current frame and reports which nodes were added,
+//! removed, moved or retained (and which fields of the retained nodes
+//! changed). All `NodeId`s are the depth-first IDs that the nodes will
+//! have in the `CompactDom` / `StyledDom`, so the diff can be used to skip
+//! work for unchanged nodes, see `StyledDom::new_incremental` and
+//! `StyledDom::get_relayout_changes`.
+//!
+//! Children of a retained node are matched by their key (see `Dom::with_key`)
+//! if they have one, otherwise by their position among the un-keyed siblings.
+//! Matched nodes with a different node type (`div` vs `p`, text vs image)
+//! are treated as a removal + insertion of the entire subtree.
+
+use crate::dom::{Dom, EventFilter, IdOrClass, NodeData, NodeType};
+use crate::id_tree::NodeId;
+use crate::styled_dom::StyledDom;
+use alloc::collections::btree_map::BTreeMap;
 use alloc::vec::Vec;
-use alloc::string::String;
-use alloc::collections::btree_set::BTreeSet;
-use crate::{
-    id_tree::{NodeId, NodeDataContainer},
-    dom::{CompactDom, NodeData},
-};
-
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DomRange {
-    pub start: NodeId,
-    pub end: NodeId,
+
+/// Fields of a retained node that differ between the old and the new DOM
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeChanges {
+    pub classes: bool,
+    pub ids: bool,
+    pub inline_css: bool,
+    /// Content of a text node
+    pub text: bool,
+    /// Event filters, callback functions or callback data types
+    pub callbacks: bool,
+    /// Tab index, clip mask, accessibility info, menus or image / iframe content
+    pub other: bool,
 }
 
-impl DomRange {
+impl NodeChanges {
+    /// Returns whether nothing has changed
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
 
-    pub fn new(start: NodeId, end: NodeId) -> Self {
-        Self { start, end }
+    /// Returns whether the change can influence which CSS rules match the
+    /// node (or its children)
+    #[inline]
+    pub fn affects_css_matching(&self) -> bool {
+        self.classes || self.ids
     }
 
-    pub fn single_node(node_id: NodeId) -> Self {
-        Self {
-            start: node_id,
-            end: node_id,
+    fn new(old: &NodeData, new: &NodeData) -> Self {
+        fn ids(n: &NodeData) -> Vec<&str> {
+            n.get_ids_and_classes()
+                .iter()
+                .filter_map(|i| match i {
+                    IdOrClass::Id(id) => Some(id.as_str()),
+                    IdOrClass::Class(_) => None,
+                })
+                .collect()
         }
-    }
-}
 
-impl fmt::Debug for DomRange {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}..{}", self.start, self.end)
-    }
-}
+        fn classes(n: &NodeData) -> Vec<&str> {
+            n.get_ids_and_classes()
+                .iter()
+                .filter_map(|i| match i {
+                    IdOrClass::Class(class) => Some(class.as_str()),
+                    IdOrClass::Id(_) => None,
+                })
+                .collect()
+        }
+
+        // the RefAny is re-created on every frame, so only compare the type of the data
+        fn callbacks(n: &NodeData) -> Vec<(EventFilter, usize, u64)> {
+            n.get_callbacks()
+                .iter()
+                .map(|cb| (cb.event, cb.callback.cb as usize, cb.data.get_type_id()))
+                .collect()
+        }
 
-impl fmt::Display for DomRange {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}..{}", self.start, self.end)
+        let (text, content) = match (old.get_node_type(), new.get_node_type()) {
+            (NodeType::Text(a), NodeType::Text(b)) => (a != b, false),
+            (a, b) => (false, a != b),
+        };
+
+        Self {
+            classes: classes(old) != classes(new),
+            ids: ids(old) != ids(new),
+            inline_css: old.get_inline_css_props().as_ref() != new.get_inline_css_props().as_ref(),
+            text,
+            callbacks: callbacks(old) != callbacks(new),
+            other: content
+                || old.get_tab_index() != new.get_tab_index()
+                || old.get_clip_mask() != new.get_clip_mask()
+                || old.get_accessibility_info() != new.get_accessibility_info()
+                || old.get_menu_bar() != new.get_menu_bar()
+                || old.get_context_menu() != new.get_context_menu(),
+        }
     }
 }
 
+/// Node that exists in both the old and the new DOM
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum DomChange {
-    /// Node is present on the new DOM, but not on the old one = add node
-    Added(DomRange),
-    /// Node is present on the old DOM, but not on the new one = remove node
-    Removed(DomRange),
+pub struct RetainedNode {
+    pub old: NodeId,
+    pub new: NodeId,
+    pub changes: NodeChanges,
 }
 
-impl fmt::Display for DomChange {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use self::DomChange::*;
-        match self {
-            Added(c) => write!(f, "+ {}", c),
-            Removed(c) => write!(f, "- {}", c),
-        }
-    }
+/// Retained node that changed its position relative to its siblings
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MovedNode {
+    pub old: NodeId,
+    pub new: NodeId,
 }
 
+/// Result of `Dom::diff`
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DomDiff {
-    /// What the actual changes nodes (not trees / subtrees) were in this diff, in order of appearance
-    pub changed_nodes: Vec<DomChange>,
+    /// Nodes of the new DOM that don't exist in the old DOM (including
+    /// all nodes of added subtrees), sorted by `NodeId`
+    pub added: Vec<NodeId>,
+    /// Nodes of the old DOM that don't exist in the new DOM (including
+    /// all nodes of removed subtrees), sorted by `NodeId`
+    pub removed: Vec<NodeId>,
+    /// Retained keyed nodes whose order relative to their siblings changed,
+    /// the minimal set of nodes that have to be moved to get the new order
+    pub moved: Vec<MovedNode>,
+    /// All nodes that exist in both DOMs, sorted by the new `NodeId`
+    pub retained: Vec<RetainedNode>,
 }
 
 impl DomDiff {
-    pub fn new(old: &CompactDom, new: &CompactDom) -> Self {
+    /// Diffs the `old` DOM against the `new` DOM
+    pub fn new(old: &Dom, new: &Dom) -> Self {
+        Self::from_trees(&DiffTree::from_dom(old), &DiffTree::from_dom(new))
+    }
+
+    /// Diffs the nodes of the `old` styled DOM against the nodes of the
+    /// `new` styled DOM (the styles of the nodes are not compared)
+    pub fn new_styled(old: &StyledDom, new: &StyledDom) -> Self {
+        Self::from_trees(
+            &DiffTree::from_styled_dom(old),
+            &DiffTree::from_styled_dom(new),
+        )
+    }
+
+    fn from_trees(old: &DiffTree, new: &DiffTree) -> Self {
+        let mut diff = DomDiff::default();
 
-        // TODO: Check if old root = new root, if not, change entire tree
+        let old_root = old.nodes[0].get_node_type().get_path();
+        let new_root = new.nodes[0].get_node_type().get_path();
 
-        let mut changes = BTreeSet::new();
-        let mut visited_nodes = NodeDataContainer::new(vec![false; new.len()]);
+        if old_root == new_root {
+            diff.retain(old, NodeId::ZERO, new, NodeId::ZERO);
+        } else {
+            diff.removed.extend(old.subtree(NodeId::ZERO));
+            diff.added.extend(new.subtree(NodeId::ZERO));
+        }
 
-        visited_nodes[NodeId::ZERO] = true;
+        diff.added.sort();
+        diff.removed.sort();
 
-        let has_root_changed = node_has_changed(
-            &old.arena.node_data[NodeId::ZERO],
-            &new.arena.node_data[NodeId::ZERO]
-        );
+        diff
+    }
+
+    /// Returns whether the two DOMs are equal (ignoring the datasets and callback data)
+    pub fn is_empty(&self) -> bool {
+        !self.has_structural_changes() && self.retained.iter().all(|r| r.changes.is_empty())
+    }
 
-        if has_root_changed == NODE_CHANGED_NOTHING {
+    /// Returns whether nodes were added, removed or moved, i.e. whether
+    /// the `NodeId` of any node is different in the new DOM
+    pub fn has_structural_changes(&self) -> bool {
+        !(self.added.is_empty() && self.removed.is_empty() && self.moved.is_empty())
+    }
 
-            diff_tree_inner(NodeId::ZERO, old, new, &mut changes, &mut visited_nodes);
-            add_visited_nodes(visited_nodes, &mut changes);
+    /// Returns the retained node for a `NodeId` of the new DOM,
+    /// `None` if the node was added
+    pub fn get_retained(&self, new_node_id: NodeId) -> Option<&RetainedNode> {
+        self.retained
+            .binary_search_by_key(&new_node_id, |r| r.new)
+            .ok()
+            .map(|i| &self.retained[i])
+    }
 
-            Self {
-                changed_nodes: optimize_changeset(changes)
-            }
+    fn retain(&mut self, old: &DiffTree, old_id: NodeId, new: &DiffTree, new_id: NodeId) {
+        self.retained.push(RetainedNode {
+            old: old_id,
+            new: new_id,
+            changes: NodeChanges::new(old.nodes[old_id.index()], new.nodes[new_id.index()]),
+        });
 
-        } else {
+        let old_child_ids = old.children(old_id);
+        let new_child_ids = new.children(new_id);
 
-            // Root changed = everything changed
-            changes.insert(DomChange::Removed(DomRange {
-                start: NodeId::ZERO,
-                end: NodeId::new(old.len() - 1)
-            }));
+        // old child index for every new child
+        let matches = match_children(&old.get(&old_child_ids), &new.get(&new_child_ids));
 
-            changes.insert(DomChange::Added(DomRange {
-                start: NodeId::ZERO,
-                end: NodeId::new(new.len() - 1)
-            }));
+        let mut old_is_matched = vec![false; old_child_ids.len()];
+        for old_index in matches.iter().flatten() {
+            old_is_matched[*old_index] = true;
+        }
+
+        for (old_index, is_matched) in old_is_matched.iter().enumerate() {
+            if !is_matched {
+                self.removed.extend(old.subtree(old_child_ids[old_index]));
+            }
+        }
+
+        // matched children that are not part of the longest
+        // increasing subsequence of old indices have moved
+        let matched = matches
+            .iter()
+            .enumerate()
+            .filter_map(|(new_index, old_index)| old_index.map(|o| (new_index, o)))
+            .collect::<Vec<_>>();
+        let in_order =
+            longest_increasing_subsequence(&matched.iter().map(|(_, o)| *o).collect::<Vec<_>>());
+        for (i, (new_index, old_index)) in matched.iter().enumerate() {
+            if !in_order[i] {
+                self.moved.push(MovedNode {
+                    old: old_child_ids[*old_index],
+                    new: new_child_ids[*new_index],
+                });
+            }
+        }
 
-            Self {
-                changed_nodes: optimize_changeset(changes)
+        for (new_index, old_index) in matches.iter().enumerate() {
+            match old_index {
+                Some(old_index) => {
+                    self.retain(old, old_child_ids[*old_index], new, new_child_ids[new_index])
+                }
+                None => self.added.extend(new.subtree(new_child_ids[new_index])),
             }
         }
     }
+}
+
+impl Dom {
+    /// Compares `self` (the DOM of the last frame) with the `new` DOM,
+    /// see `DomDiff` for details
+    pub fn diff(&self, new: &Dom) -> DomDiff {
+        DomDiff::new(self, new)
+    }
+}
+
+impl StyledDom {
+    /// Compares the nodes of `self` (the DOM of the last frame) with
+    /// the nodes of the `new` DOM, see `DomDiff` for details
+    pub fn diff(&self, new: &StyledDom) -> DomDiff {
+        DomDiff::new_styled(self, new)
+    }
+}
 
-    /// Formats the diff into a git-like `+ Node1 / - Node3` form
-    pub fn format_nicely(&self, old: &CompactDom, new: &CompactDom) -> String {
-        use self::DomChange::*;
-        self.changed_nodes.iter().map(|change| {
-            match change {
-                Added(c) => format!("+\t{}", new.arena.node_data[c.start]),
-                Removed(c) => format!("-\t{}", old.arena.node_data[c.start]),
+/// Returns the index of the matching old child for every new child.
+///
+/// Keyed children are matched by key (only the first child with a given key
+/// can be matched), un-keyed children by their position among the un-keyed
+/// children. Nodes with different node types never match.
+fn match_children(old: &[&NodeData], new: &[&NodeData]) -> Vec<Option<usize>> {
+    let mut old_keyed = BTreeMap::new();
+    let mut old_unkeyed = Vec::new();
+
+    for (i, child) in old.iter().enumerate() {
+        match child.get_key() {
+            Some(key) => {
+                old_keyed.entry(key).or_insert(i);
             }
-        }).collect::<Vec<String>>().join("\r\n")
+            None => old_unkeyed.push(i),
+        }
     }
+
+    let mut old_unkeyed = old_unkeyed.into_iter();
+
+    new.iter()
+        .map(|child| {
+            let old_index = match child.get_key() {
+                Some(key) => old_keyed.remove(&key),
+                None => old_unkeyed.next(),
+            }?;
+            let old_type = old[old_index].get_node_type().get_path();
+            if old_type == child.get_node_type().get_path() {
+                Some(old_index)
+            } else {
+                None
+            }
+        })
+        .collect()
 }
 
-impl fmt::Display for DomDiff {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for c in self.changed_nodes.iter() {
-            write!(f, "{}", c)?;
+/// Returns for every item whether it is part of the
+/// longest strictly increasing subsequence (O(n log n))
+fn longest_increasing_subsequence(items: &[usize]) -> Vec<bool> {
+    // tails[k] = index of the smallest tail item of all increasing subsequences of length k + 1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessors = vec![None; items.len()];
+
+    for (i, item) in items.iter().enumerate() {
+        let pos = tails
+            .binary_search_by(|t| items[*t].cmp(item))
+            .unwrap_or_else(|p| p);
+        predecessors[i] = if pos == 0 { None } else { Some(tails[pos - 1]) };
+        if pos == tails.len() {
+            tails.push(i);
+        } else {
+            tails[pos] = i;
         }
-        Ok(())
     }
+
+    let mut in_sequence = vec![false; items.len()];
+    let mut cur = tails.last().copied();
+    while let Some(i) = cur {
+        in_sequence[i] = true;
+        cur = predecessors[i];
+    }
+    in_sequence
 }
 
-impl DomRange {
+/// Nodes of a `Dom` or `StyledDom` in depth-first order, indexed by
+/// the same `NodeId`s as in the `CompactDom` / `StyledDom`
+struct DiffTree<'a> {
+    nodes: Vec<&'a NodeData>,
+    /// Number of nodes in the subtree (including the node itself), indexed by `NodeId`
+    subtree_len: Vec<usize>,
+}
 
-    /// Is `other` a subtree of `self`? - Assumes that the DOM was
-    /// constructed in a linear order, i.e. the child being within
-    /// the parents start / end bounds
-    pub fn contains(&self, other: &Self) -> bool {
-        other.start.index() >= self.start.index() &&
-        other.end.index() <= self.end.index()
+impl<'a> DiffTree<'a> {
+    fn from_dom(dom: &'a Dom) -> Self {
+        fn collect<'a>(dom: &'a Dom, tree: &mut DiffTree<'a>) -> usize {
+            let index = tree.nodes.len();
+            tree.nodes.push(&dom.root);
+            tree.subtree_len.push(1);
+            let len = 1 + dom
+                .children
+                .as_ref()
+                .iter()
+                .map(|c| collect(c, tree))
+                .sum::<usize>();
+            tree.subtree_len[index] = len;
+            len
+        }
+
+        let mut tree = DiffTree {
+            nodes: Vec::new(),
+            subtree_len: Vec::new(),
+        };
+        collect(dom, &mut tree);
+        tree
     }
 
-    /// Compares two DOM ranges *without* looking at the DOM hashes (not equivalent to `==`)
-    pub fn equals_range(&self, other: &Self) -> bool {
-        other.start == self.start &&
-        other.end == self.end
+    fn from_styled_dom(styled_dom: &'a StyledDom) -> Self {
+        let node_hierarchy = styled_dom.node_hierarchy.as_ref();
+        let mut subtree_len = vec![1; node_hierarchy.len()];
+
+        // children have higher node IDs than their parents
+        for (index, node) in node_hierarchy.iter().enumerate().rev() {
+            if let Some(parent) = node.parent_id() {
+                subtree_len[parent.index()] += subtree_len[index];
+            }
+        }
+
+        DiffTree {
+            nodes: styled_dom.node_data.as_ref().iter().collect(),
+            subtree_len,
+        }
     }
-}
 
-const NODE_CHANGED_NOTHING: u8  = 0x01;
-const NODE_CHANGED_TYPE: u8     = 0x02;
-const NODE_CHANGED_CLASSES: u8  = 0x04;
-const NODE_CHANGED_IDS: u8      = 0x08;
-
-// In order to test two DOM nodes for "equality", you'd need to
-// test if the node type, the classes and the ids are the same.
-// The rest of the attributes can be ignored, since they are not
-// used by the CSS engine.
-//
-// NOTE: The callbacks / etc. need to be changed!
-#[inline]
-fn node_has_changed(old: &NodeData, new: &NodeData) -> u8 {
-    let mut result = NODE_CHANGED_NOTHING;
-
-    if old.get_node_type() != new.get_node_type() {
-        result &= NODE_CHANGED_TYPE;
+    fn get(&self, node_ids: &[NodeId]) -> Vec<&'a NodeData> {
+        node_ids.iter().map(|id| self.nodes[id.index()]).collect()
     }
 
-    if old.get_classes() != new.get_classes() {
-        result &= NODE_CHANGED_CLASSES;
+    fn children(&self, parent: NodeId) -> Vec<NodeId> {
+        let end = parent.index() + self.subtree_len[parent.index()];
+        let mut next = parent.index() + 1;
+        let mut children = Vec::new();
+        while next < end {
+            children.push(NodeId::new(next));
+            next += self.subtree_len[next];
+        }
+        children
     }
 
-    if old.get_ids() != new.get_ids() {
-        result &= NODE_CHANGED_IDS;
+    fn subtree(&self, node_id: NodeId) -> impl Iterator<Item = NodeId> {
+        let start = node_id.index();
+        (start..start + self.subtree_len[start]).map(NodeId::new)
+    }
+}
+
+#[cfg(test)]
+fn flatten(dom: &Dom) -> Vec<&NodeData> {
+    fn collect<'a>(dom: &'a Dom, nodes: &mut Vec<&'a NodeData>) {
+        nodes.push(&dom.root);
+        for child in dom.children.as_ref().iter() {
+            collect(child, nodes);
+        }
     }
 
-    result
+    let mut nodes = Vec::new();
+    collect(dom, &mut nodes);
+    nodes
 }
 
-fn diff_tree_inner(
-    old_root_id: NodeId,
-    old: &CompactDom,
-    new: &CompactDom,
-    changes: &mut BTreeSet<DomChange>,
-    visited_nodes: &mut NodeDataContainer<bool>,
-) {
-    let mut node_shift = 0_isize;
+#[test]
+fn test_dom_diff_identical() {
+    let make_dom = || {
+        Dom::body()
+            .with_child(Dom::div().with_ids_and_classes(vec![IdOrClass::Class("a".into())].into()))
+            .with_child(Dom::text("hello"))
+    };
+
+    let diff = make_dom().diff(&make_dom());
+    assert!(diff.is_empty());
+    assert_eq!(diff.retained.len(), 3);
+    assert!(diff.retained.iter().all(|r| r.old == r.new));
+}
+
+#[test]
+fn test_dom_diff_changed_fields() {
+    let old = Dom::body()
+        .with_child(Dom::div().with_ids_and_classes(vec![IdOrClass::Class("a".into())].into()))
+        .with_child(Dom::text("hello"));
+    let new = Dom::body()
+        .with_child(Dom::div().with_ids_and_classes(vec![IdOrClass::Class("b".into())].into()))
+        .with_child(Dom::text("world"));
+
+    let diff = old.diff(&new);
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert!(diff.moved.is_empty());
+
+    let div = diff.get_retained(NodeId::new(1)).unwrap().changes;
+    assert!(div.classes && !div.ids && !div.text);
+    assert!(div.affects_css_matching());
+
+    let text = diff.get_retained(NodeId::new(2)).unwrap().changes;
+    assert!(text.text && !text.classes);
+    assert!(!text.affects_css_matching());
+}
 
-    for old_node_id in old_root_id.children(&old.arena.node_hierarchy) {
+#[test]
+fn test_dom_diff_keyed_children() {
+    let list = |keys: &[u64]| {
+        keys.iter()
+            .map(|k| Dom::div().with_key(*k).with_child(Dom::text("item")))
+            .collect::<Dom>()
+    };
+
+    // 1 removed, 4 added, 3 moved to the front
+    let old = list(&[1, 2, 3]);
+    let new = list(&[3, 2, 4]);
+    let diff = old.diff(&new);
+
+    // old: 0 = root, 1/2 = key 1, 3/4 = key 2, 5/6 = key 3
+    // new: 0 = root, 1/2 = key 3, 3/4 = key 2, 5/6 = key 4
+    assert_eq!(diff.removed, vec![NodeId::new(1), NodeId::new(2)]);
+    assert_eq!(diff.added, vec![NodeId::new(5), NodeId::new(6)]);
+    assert_eq!(
+        diff.moved,
+        vec![MovedNode {
+            old: NodeId::new(5),
+            new: NodeId::new(1),
+        }]
+    );
+    assert_eq!(
+        diff.retained
+            .iter()
+            .map(|r| (r.old, r.new))
+            .collect::<Vec<_>>(),
+        vec![
+            (NodeId::new(0), NodeId::new(0)),
+            (NodeId::new(5), NodeId::new(1)),
+            (NodeId::new(6), NodeId::new(2)),
+            (NodeId::new(3), NodeId::new(3)),
+            (NodeId::new(4), NodeId::new(4)),
+        ]
+    );
+}
 
-        // Node ID that corresponds to the same node in the new node tree
-        let new_node_id = NodeId::new((old_node_id.index() as isize + node_shift) as usize);
+#[test]
+fn test_dom_diff_node_type_change() {
+    let old = Dom::body().with_child(Dom::div().with_child(Dom::text("a")));
+    let new = Dom::body().with_child(Dom::text("a"));
 
-        let old_node_last_child = NodeId::new(match old.arena.node_hierarchy[old_node_id].next_sibling {
-            Some(s) => s.index(),
-            None => old.arena.node_hierarchy.len() - 1,
-        });
+    let diff = old.diff(&new);
+    assert_eq!(diff.removed, vec![NodeId::new(1), NodeId::new(2)]);
+    assert_eq!(diff.added, vec![NodeId::new(1)]);
+    assert_eq!(diff.retained.len(), 1);
 
-        match new.arena.node_data.get(new_node_id) {
-            None => {
-                // Couldn't find the new node in the new tree, old tree has more children than new
-                changes.insert(DomChange::Removed(DomRange {
-                    start: old_node_id,
-                    end: old_node_last_child,
-                }));
-            },
-            Some(new_node_data) => {
-
-                visited_nodes[new_node_id] = true;
-
-                let old_node_data = &old.arena.node_data[old_node_id];
-                let compare_nodes = node_has_changed(old_node_data, new_node_data);
-
-                if compare_nodes == NODE_CHANGED_NOTHING {
-                    diff_tree_inner(old_node_id, old, new, changes, visited_nodes);
-                } else {
-
-                    let new_node_subtree_len = new.arena.node_hierarchy.subtree_len(new_node_id);
-
-                    let next_node_id = match new.arena.node_hierarchy[new_node_id].next_sibling {
-                        Some(s) => s.index(),
-                        None => new.arena.node_hierarchy.len() - 1,
-                    };
-
-                    // remove entire old subtree, including the node itself
-                    changes.insert(DomChange::Removed(DomRange {
-                        start: old_node_id,
-                        end: old_node_last_child,
-                    }));
-
-                    // add entire new subtree, including the node itself
-                    changes.insert(DomChange::Added(DomRange {
-                        start: new_node_id,
-                        end: NodeId::new(next_node_id),
-                    }));
-
-                    node_shift += new_node_subtree_len as isize;
-
-                    for n in new_node_id.index()..next_node_id {
-                        visited_nodes[NodeId::new(n)] = true;
-                    }
-                }
-            }
-        }
-    }
+    let diff = Dom::div().diff(&Dom::body());
+    assert_eq!(diff.removed, vec![NodeId::ZERO]);
+    assert_eq!(diff.added, vec![NodeId::ZERO]);
+    assert!(diff.retained.is_empty());
 }
 
-fn add_visited_nodes(
-    visited_nodes: NodeDataContainer<bool>,
-    changes: &mut BTreeSet<DomChange>,
-) {
-    changes.extend(
-        visited_nodes
-        .linear_iter()
-        .filter_map(|node_id| if visited_nodes[node_id] { None } else { Some(node_id) })
-        .map(|node_id| DomChange::Added(DomRange::single_node(node_id)))
+#[test]
+fn test_dom_diff_apply() {
+    let old = Dom::body()
+        .with_child(Dom::div().with_key(1).with_child(Dom::text("a")))
+        .with_child(Dom::div().with_ids_and_classes(vec![IdOrClass::Class("x".into())].into()))
+        .with_child(Dom::div().with_key(2).with_child(Dom::text("b")))
+        .with_child(Dom::text("c"));
+    let new = Dom::body()
+        .with_child(Dom::div().with_key(2).with_child(Dom::text("b2")))
+        .with_child(Dom::div().with_ids_and_classes(vec![IdOrClass::Id("y".into())].into()))
+        .with_child(Dom::div().with_key(3))
+        .with_child(Dom::div().with_key(1).with_child(Dom::text("a")))
+        .with_child(Dom::br());
+
+    let diff = old.diff(&new);
+    let old_nodes = flatten(&old);
+    let new_nodes = flatten(&new);
+
+    // every node is either retained or added / removed, exactly once
+    let mut new_ids = diff.added.clone();
+    new_ids.extend(diff.retained.iter().map(|r| r.new));
+    new_ids.sort();
+    assert_eq!(
+        new_ids,
+        (0..new_nodes.len()).map(NodeId::new).collect::<Vec<_>>()
+    );
+
+    let mut old_ids = diff.removed.clone();
+    old_ids.extend(diff.retained.iter().map(|r| r.old));
+    old_ids.sort();
+    assert_eq!(
+        old_ids,
+        (0..old_nodes.len()).map(NodeId::new).collect::<Vec<_>>()
     );
+
+    // applying the diff to the old DOM (reusing all unchanged nodes) results in the new DOM
+    let applied = (0..new_nodes.len())
+        .map(|i| match diff.get_retained(NodeId::new(i)) {
+            Some(r) if r.changes.is_empty() => old_nodes[r.old.index()],
+            _ => new_nodes[i],
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(applied, new_nodes);
 }
 
-fn optimize_changeset(changes: BTreeSet<DomChange>) -> Vec<DomChange> {
-    // TODO: optimize changeset into larger chunks!
-    changes.into_iter().collect()
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_styled_dom_diff() {
+    use azul_css_parser::CssApiWrapper;
+
+    let old = Dom::body()
+        .with_child(Dom::div().with_key(1).with_child(Dom::text("a")))
+        .with_child(Dom::div().with_child(Dom::text("b")))
+        .with_child(Dom::div().with_key(2));
+    let new = Dom::body()
+        .with_child(Dom::div().with_key(2))
+        .with_child(Dom::div().with_child(Dom::text("b2")))
+        .with_child(Dom::div().with_key(1).with_child(Dom::text("a")));
+
+    let old_styled = StyledDom::new(&mut old.clone(), CssApiWrapper::empty());
+    let new_styled = StyledDom::new(&mut new.clone(), CssApiWrapper::empty());
+    assert_eq!(old_styled.diff(&new_styled), old.diff(&new));
+
+    // converting the styled DOM back results in the same DOM
+    assert!(Dom::from(new_styled).diff(&new).is_empty());
 }
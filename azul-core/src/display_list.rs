@@ -15,14 +15,14 @@ use crate::{
 use alloc::collections::btree_map::BTreeMap;
 use alloc::vec::Vec;
 use azul_css::{
-    BoxShadowClipMode, ColorU, ConicGradient, CssPropertyValue, LayoutBorderBottomWidth,
-    LayoutBorderLeftWidth, LayoutBorderRightWidth, LayoutBorderTopWidth, LayoutPoint, LayoutRect,
-    LayoutSize, LinearGradient, RadialGradient, StyleBackgroundPosition, StyleBackgroundRepeat,
-    StyleBackgroundSize, StyleBorderBottomColor, StyleBorderBottomLeftRadius,
-    StyleBorderBottomRightRadius, StyleBorderBottomStyle, StyleBorderLeftColor,
-    StyleBorderLeftStyle, StyleBorderRightColor, StyleBorderRightStyle, StyleBorderTopColor,
-    StyleBorderTopLeftRadius, StyleBorderTopRightRadius, StyleBorderTopStyle, StyleBoxShadow,
-    StyleMixBlendMode,
+    BoxOrStatic, BoxShadowClipMode, ColorU, ConicGradient, CssPropertyValue,
+    LayoutBorderBottomWidth, LayoutBorderLeftWidth, LayoutBorderRightWidth, LayoutBorderTopWidth,
+    LayoutPoint, LayoutRect, LayoutSize, LinearGradient, PixelValue, RadialGradient, SizeMetric,
+    StyleBackgroundPosition, StyleBackgroundRepeat, StyleBackgroundSize, StyleBorderBottomColor,
+    StyleBorderBottomLeftRadius, StyleBorderBottomRightRadius, StyleBorderBottomStyle,
+    StyleBorderLeftColor, StyleBorderLeftStyle, StyleBorderRightColor, StyleBorderRightStyle,
+    StyleBorderTopColor, StyleBorderTopLeftRadius, StyleBorderTopRightRadius, StyleBorderTopStyle,
+    StyleBoxShadow, StyleBoxShadowValue, StyleImageRendering, StyleIsolation, StyleMixBlendMode,
 };
 use core::fmt;
 use rust_fontconfig::FcFontCache;
@@ -124,6 +124,17 @@ impl DisplayListMsg {
         }
     }
 
+    /// Returns whether the frame has `isolation: isolate` set, i.e. whether
+    /// the blend modes of its children should not blend with the content behind it
+    pub fn is_isolated(&self) -> bool {
+        use self::DisplayListMsg::*;
+        match self {
+            Frame(f) => f.isolation == Some(StyleIsolation::Isolate),
+            ScrollFrame(sf) => sf.frame.isolation == Some(StyleIsolation::Isolate),
+            IFrame(_, _, _, _) => false,
+        }
+    }
+
     // warning: recursive function!
     pub fn has_mix_blend_mode_children(&self) -> bool {
         use self::DisplayListMsg::*;
@@ -282,6 +293,7 @@ pub struct DisplayListFrame {
     pub position: PositionInfo,
     pub flags: PrimitiveFlags,
     pub mix_blend_mode: Option<StyleMixBlendMode>,
+    pub isolation: Option<StyleIsolation>,
     pub clip_children: Option<LogicalSize>,
     pub clip_mask: Option<DisplayListImageMask>,
     /// Border radius, set to none only if overflow: visible is set!
@@ -351,6 +363,7 @@ impl DisplayListFrame {
             size: LogicalSize::new(dimensions.width as f32, dimensions.height as f32),
             clip_children: None,
             mix_blend_mode: None,
+            isolation: None,
             position: PositionInfo::Static(PositionInfoInner {
                 x_offset: root_origin.x as f32,
                 y_offset: root_origin.y as f32,
@@ -382,6 +395,16 @@ pub enum ImageRendering {
     Pixelated,
 }
 
+impl From<StyleImageRendering> for ImageRendering {
+    fn from(image_rendering: StyleImageRendering) -> Self {
+        match image_rendering {
+            StyleImageRendering::Auto => ImageRendering::Auto,
+            StyleImageRendering::CrispEdges => ImageRendering::CrispEdges,
+            StyleImageRendering::Pixelated => ImageRendering::Pixelated,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum AlphaType {
     Alpha,
@@ -410,6 +433,24 @@ impl StyleBorderRadius {
         self.bottom_left.as_mut().map(|s| s.scale_for_dpi(scale_factor));
         self.bottom_right.as_mut().map(|s| s.scale_for_dpi(scale_factor));
     }
+
+    /// Converts `em` radii into pixels, relative to the computed font
+    /// size of the node
+    pub fn resolve_em(&mut self, em_size: f32) {
+        macro_rules! resolve_em {
+            ($corner:expr) => {
+                if let Some(CssPropertyValue::Exact(r)) = $corner.as_mut() {
+                    if r.inner.metric == SizeMetric::Em {
+                        r.inner = PixelValue::px(r.inner.to_pixels_em_aware(0.0, em_size));
+                    }
+                }
+            };
+        }
+        resolve_em!(self.top_left);
+        resolve_em!(self.top_right);
+        resolve_em!(self.bottom_left);
+        resolve_em!(self.bottom_right);
+    }
 }
 
 impl fmt::Debug for StyleBorderRadius {
@@ -471,54 +512,72 @@ impl StyleBorderWidths {
         self.left.as_mut().map(|s| s.scale_for_dpi(scale_factor));
     }
 
+    /// Converts `em` widths into pixels, relative to the computed font
+    /// size of the node
+    pub fn resolve_em(&mut self, em_size: f32) {
+        macro_rules! resolve_em {
+            ($side:expr) => {
+                if let Some(CssPropertyValue::Exact(w)) = $side.as_mut() {
+                    if w.inner.metric == SizeMetric::Em {
+                        w.inner = PixelValue::px(w.inner.to_pixels_em_aware(0.0, em_size));
+                    }
+                }
+            };
+        }
+        resolve_em!(self.top);
+        resolve_em!(self.right);
+        resolve_em!(self.bottom);
+        resolve_em!(self.left);
+    }
+
     #[inline]
-    pub fn left_width(&self) -> f32 {
+    pub fn left_width(&self, em_size: f32) -> f32 {
         self.left
             .unwrap_or_default()
             .get_property_owned()
             .unwrap_or_default()
             .inner
-            .to_pixels(0.0)
+            .to_pixels_em_aware(0.0, em_size)
     }
 
     #[inline]
-    pub fn right_width(&self) -> f32 {
+    pub fn right_width(&self, em_size: f32) -> f32 {
         self.right
             .unwrap_or_default()
             .get_property_owned()
             .unwrap_or_default()
             .inner
-            .to_pixels(0.0)
+            .to_pixels_em_aware(0.0, em_size)
     }
 
     #[inline]
-    pub fn top_width(&self) -> f32 {
+    pub fn top_width(&self, em_size: f32) -> f32 {
         self.top
             .unwrap_or_default()
             .get_property_owned()
             .unwrap_or_default()
             .inner
-            .to_pixels(0.0)
+            .to_pixels_em_aware(0.0, em_size)
     }
 
     #[inline]
-    pub fn bottom_width(&self) -> f32 {
+    pub fn bottom_width(&self, em_size: f32) -> f32 {
         self.bottom
             .unwrap_or_default()
             .get_property_owned()
             .unwrap_or_default()
             .inner
-            .to_pixels(0.0)
+            .to_pixels_em_aware(0.0, em_size)
     }
 
     #[inline]
-    pub fn total_horizontal(&self) -> f32 {
-        self.left_width() + self.right_width()
+    pub fn total_horizontal(&self, em_size: f32) -> f32 {
+        self.left_width(em_size) + self.right_width(em_size)
     }
 
     #[inline]
-    pub fn total_vertical(&self) -> f32 {
-        self.top_width() + self.bottom_width()
+    pub fn total_vertical(&self, em_size: f32) -> f32 {
+        self.top_width(em_size) + self.bottom_width(em_size)
     }
 }
 
@@ -544,13 +603,25 @@ pub struct StyleBorderStyles {
 
 tlbr_debug!(StyleBorderStyles);
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BoxShadow {
     pub clip_mode: BoxShadowClipMode,
-    pub top: Option<CssPropertyValue<StyleBoxShadow>>,
-    pub right: Option<CssPropertyValue<StyleBoxShadow>>,
-    pub bottom: Option<CssPropertyValue<StyleBoxShadow>>,
-    pub left: Option<CssPropertyValue<StyleBoxShadow>>,
+    pub top: Option<StyleBoxShadowValue>,
+    pub right: Option<StyleBoxShadowValue>,
+    pub bottom: Option<StyleBoxShadowValue>,
+    pub left: Option<StyleBoxShadowValue>,
+}
+
+impl BoxShadow {
+    /// Converts `em` offsets and radii into pixels, relative to the
+    /// computed font size of the node
+    pub fn resolve_em(&mut self, em_size: f32) {
+        for side in [&mut self.top, &mut self.right, &mut self.bottom, &mut self.left] {
+            if let Some(CssPropertyValue::Exact(shadow)) = side.as_mut() {
+                BoxOrStatic::make_mut(shadow).resolve_em(em_size);
+            }
+        }
+    }
 }
 
 tlbr_debug!(BoxShadow);
@@ -570,6 +641,7 @@ pub enum LayoutRectContent {
         size: Option<StyleBackgroundSize>,
         offset: Option<StyleBackgroundPosition>,
         repeat: Option<StyleBackgroundRepeat>,
+        image_rendering: ImageRendering,
     },
     Image {
         size: LogicalSize,
@@ -608,6 +680,7 @@ impl LayoutRectContent {
                 size,
                 offset,
                 repeat,
+                image_rendering,
             } => {
                 content.scale_for_dpi(scale_factor);
                 size.as_mut().map(|s| s.scale_for_dpi(scale_factor));
@@ -670,12 +743,14 @@ impl fmt::Debug for LayoutRectContent {
                 size,
                 offset,
                 repeat,
+                image_rendering,
             } => {
                 write!(f, "Background {{\r\n")?;
                 write!(f, "    content: {:?},\r\n", content)?;
                 write!(f, "    size: {:?},\r\n", size)?;
                 write!(f, "    offset: {:?},\r\n", offset)?;
                 write!(f, "    repeat: {:?},\r\n", repeat)?;
+                write!(f, "    image_rendering: {:?},\r\n", image_rendering)?;
                 write!(f, "}}")
             }
             Image {
@@ -750,6 +825,15 @@ impl RectBackground {
             _ => { },
         }
     }
+    /// Converts the `em` center of radial and conic gradients into pixels,
+    /// relative to the computed font size of the node
+    pub fn resolve_em(&mut self, em_size: f32) {
+        match self {
+            RectBackground::RadialGradient(rg) => rg.position.resolve_em(em_size),
+            RectBackground::ConicGradient(cg) => cg.center.resolve_em(em_size),
+            _ => {}
+        }
+    }
     pub fn get_content_size(&self) -> Option<(f32, f32)> {
         match self {
             RectBackground::Image((_key, descriptor)) => {
@@ -935,6 +1019,9 @@ pub fn displaylist_handle_rect<'a>(
         return None;
     }
 
+    // em lengths (border widths, radii, shadows) are relative to the font size of the node
+    let em_size = layout_result.styled_dom.get_em_size(rect_idx);
+
     let overflow_horizontal_hidden = layout_result
         .styled_dom
         .get_css_property_cache()
@@ -960,10 +1047,18 @@ pub fn displaylist_handle_rect<'a>(
         .and_then(|p| p.get_property())
         .cloned();
 
+    let isolation = layout_result
+        .styled_dom
+        .get_css_property_cache()
+        .get_isolation(&html_node, &rect_idx, &styled_node.state)
+        .and_then(|p| p.get_property())
+        .cloned();
+
     let mut frame = DisplayListFrame {
         tag: tag_id.map(|t| t.into_crate_internal()),
         size: positioned_rect.size,
         mix_blend_mode,
+        isolation,
         clip_children: match layout_result
             .scrollable_nodes
             .clip_nodes
@@ -1052,6 +1147,8 @@ pub fn displaylist_handle_rect<'a>(
         clip_mask,
     };
 
+    frame.border_radius.resolve_em(em_size);
+
     // push box shadow
     let box_shadow_left = layout_result
         .styled_dom
@@ -1101,7 +1198,20 @@ pub fn displaylist_handle_rect<'a>(
         None
     };
 
-    frame.box_shadow = box_shadow;
+    frame.box_shadow = box_shadow.map(|mut b| {
+        b.resolve_em(em_size);
+        b
+    });
+
+    // scaling quality of the background images and the image content
+    let image_rendering = layout_result
+        .styled_dom
+        .get_css_property_cache()
+        .get_image_rendering(&html_node, &rect_idx, &styled_node.state)
+        .and_then(|p| p.get_property())
+        .copied()
+        .unwrap_or_default()
+        .into();
 
     // push background
     let bg_opt = layout_result
@@ -1134,14 +1244,17 @@ pub fn displaylist_handle_rect<'a>(
         let bg_sizes = bg_sizes_opt
             .as_ref()
             .and_then(|p| p.get_property())
+            .map(|p| &**p)
             .unwrap_or(&default_bg_size_vec);
         let bg_positions = bg_positions_opt
             .as_ref()
             .and_then(|p| p.get_property())
+            .map(|p| &**p)
             .unwrap_or(&default_bg_position_vec);
         let bg_repeats = bg_repeats_opt
             .as_ref()
             .and_then(|p| p.get_property())
+            .map(|p| &**p)
             .unwrap_or(&default_bg_repeat_vec);
 
         for (bg_index, bg) in bg.iter().enumerate() {
@@ -1153,7 +1266,7 @@ pub fn displaylist_handle_rect<'a>(
                 image_cache: &ImageCache,
                 background_image_id: &AzString,
             ) -> Option<(ImageKey, ImageDescriptor)> {
-                let image_ref = image_cache.get_css_image_id(background_image_id)?;
+                let image_ref = image_cache.resolve_css_image_id(background_image_id)?;
                 let image_ref_hash = image_ref.get_hash();
                 let ResolvedImage { key, descriptor } =
                     renderer_resources.get_image(&image_ref_hash)?;
@@ -1173,12 +1286,18 @@ pub fn displaylist_handle_rect<'a>(
             let bg_position = bg_positions.get(bg_index).or(bg_positions.get(0)).copied();
             let bg_repeat = bg_repeats.get(bg_index).or(bg_repeats.get(0)).copied();
 
-            if let Some(background_content) = background_content {
+            if let Some(mut background_content) = background_content {
+                let mut bg_size = bg_size.clone();
+                let mut bg_position = bg_position.clone();
+                background_content.resolve_em(em_size);
+                bg_size.as_mut().map(|s| s.resolve_em(em_size));
+                bg_position.as_mut().map(|p| p.resolve_em(em_size));
                 frame.content.push(LayoutRectContent::Background {
                     content: background_content,
-                    size: bg_size.clone(),
-                    offset: bg_position.clone(),
+                    size: bg_size,
+                    offset: bg_position,
                     repeat: bg_repeat.clone(),
+                    image_rendering,
                 });
             }
         }
@@ -1224,7 +1343,11 @@ pub fn displaylist_handle_rect<'a>(
                         .get_css_property_cache()
                         .get_text_shadow(&html_node, &rect_idx, &styled_node.state)
                         .and_then(|p| p.get_property())
-                        .cloned();
+                        .map(|p| {
+                            let mut text_shadow = **p;
+                            text_shadow.resolve_em(em_size);
+                            text_shadow
+                        });
 
                     frame.content.push(LayoutRectContent::Text {
                         text_shadow,
@@ -1247,7 +1370,7 @@ pub fn displaylist_handle_rect<'a>(
                 DecodedImage::NullImage { .. } => frame.content.push(LayoutRectContent::Image {
                     size: image_size,
                     offset: LogicalPosition::zero(),
-                    image_rendering: ImageRendering::Auto,
+                    image_rendering,
                     alpha_type: AlphaType::Alpha,
                     image_key: ImageKey::DUMMY,
                     background_color: ColorU::WHITE,
@@ -1259,7 +1382,7 @@ pub fn displaylist_handle_rect<'a>(
                         frame.content.push(LayoutRectContent::Image {
                             size: image_size,
                             offset: LogicalPosition::zero(),
-                            image_rendering: ImageRendering::Auto,
+                            image_rendering,
                             alpha_type: AlphaType::PremultipliedAlpha,
                             image_key: *key,
                             background_color: ColorU::WHITE,
@@ -1278,7 +1401,7 @@ pub fn displaylist_handle_rect<'a>(
                                 descriptor.height as f32,
                             ),
                             offset: LogicalPosition::zero(),
-                            image_rendering: ImageRendering::Auto,
+                            image_rendering,
                             alpha_type: AlphaType::Alpha,
                             image_key: *key,
                             background_color: ColorU::WHITE,
@@ -1330,29 +1453,32 @@ pub fn displaylist_handle_rect<'a>(
         .get_css_property_cache()
         .has_border(&html_node, &rect_idx, &styled_node.state)
     {
+        let mut widths = StyleBorderWidths {
+            top: layout_result
+                .styled_dom
+                .get_css_property_cache()
+                .get_border_top_width(&html_node, &rect_idx, &styled_node.state)
+                .cloned(),
+            left: layout_result
+                .styled_dom
+                .get_css_property_cache()
+                .get_border_left_width(&html_node, &rect_idx, &styled_node.state)
+                .cloned(),
+            bottom: layout_result
+                .styled_dom
+                .get_css_property_cache()
+                .get_border_bottom_width(&html_node, &rect_idx, &styled_node.state)
+                .cloned(),
+            right: layout_result
+                .styled_dom
+                .get_css_property_cache()
+                .get_border_right_width(&html_node, &rect_idx, &styled_node.state)
+                .cloned(),
+        };
+        widths.resolve_em(em_size);
+
         frame.content.push(LayoutRectContent::Border {
-            widths: StyleBorderWidths {
-                top: layout_result
-                    .styled_dom
-                    .get_css_property_cache()
-                    .get_border_top_width(&html_node, &rect_idx, &styled_node.state)
-                    .cloned(),
-                left: layout_result
-                    .styled_dom
-                    .get_css_property_cache()
-                    .get_border_left_width(&html_node, &rect_idx, &styled_node.state)
-                    .cloned(),
-                bottom: layout_result
-                    .styled_dom
-                    .get_css_property_cache()
-                    .get_border_bottom_width(&html_node, &rect_idx, &styled_node.state)
-                    .cloned(),
-                right: layout_result
-                    .styled_dom
-                    .get_css_property_cache()
-                    .get_border_right_width(&html_node, &rect_idx, &styled_node.state)
-                    .cloned(),
-            },
+            widths,
             colors: StyleBorderColors {
                 top: layout_result
                     .styled_dom
@@ -1415,3 +1541,88 @@ pub fn displaylist_handle_rect<'a>(
         None => Some(DisplayListMsg::Frame(frame)),
     }
 }
+
+#[test]
+fn test_image_rendering_reaches_display_list() {
+    use crate::app_resources::{ImageRef, RawImageFormat};
+    use crate::dom::Dom;
+    use crate::id_tree::NodeDataContainer;
+    use crate::ui_solver::{
+        HeightCalculatedRect, HorizontalSolvedPosition, PositionedRectangle,
+        VerticalSolvedPosition, WidthCalculatedRect,
+    };
+    use azul_css_parser::CssApiWrapper;
+
+    let image = ImageRef::invalid(16, 16, RawImageFormat::RGBA8);
+    let styled_dom = Dom::image(image)
+        .with_inline_style("image-rendering: pixelated;")
+        .style(CssApiWrapper::empty());
+
+    let node_count = styled_dom.node_data.len();
+    let layout_result = LayoutResult {
+        dom_id: DomId::ROOT_ID,
+        parent_dom_id: None,
+        styled_dom,
+        root_size: LayoutSize::new(16, 16),
+        root_position: LayoutPoint::zero(),
+        preferred_widths: NodeDataContainer::new(vec![None; node_count]),
+        preferred_heights: NodeDataContainer::new(vec![None; node_count]),
+        width_calculated_rects: NodeDataContainer::new(vec![
+            WidthCalculatedRect::default();
+            node_count
+        ]),
+        height_calculated_rects: NodeDataContainer::new(vec![
+            HeightCalculatedRect::default();
+            node_count
+        ]),
+        solved_pos_x: NodeDataContainer::new(vec![HorizontalSolvedPosition(0.0); node_count]),
+        solved_pos_y: NodeDataContainer::new(vec![VerticalSolvedPosition(0.0); node_count]),
+        layout_flex_grows: NodeDataContainer::new(vec![0.0; node_count]),
+        layout_displays: NodeDataContainer::new(vec![CssPropertyValue::Auto; node_count]),
+        layout_positions: NodeDataContainer::new(vec![Default::default(); node_count]),
+        layout_flex_directions: NodeDataContainer::new(vec![Default::default(); node_count]),
+        layout_justify_contents: NodeDataContainer::new(vec![Default::default(); node_count]),
+        rects: NodeDataContainer::new(vec![PositionedRectangle::default(); node_count]),
+        words_cache: BTreeMap::new(),
+        shaped_words_cache: BTreeMap::new(),
+        positioned_words_cache: BTreeMap::new(),
+        scrollable_nodes: Default::default(),
+        iframe_mapping: BTreeMap::new(),
+        gpu_value_cache: Default::default(),
+    };
+
+    let layout_results = [layout_result];
+    let document_id = DocumentId {
+        namespace_id: IdNamespace(0),
+        id: 0,
+    };
+    let full_window_state = FullWindowState::default();
+    let gl_texture_cache = GlTextureCache::empty();
+    let image_cache = ImageCache::new();
+    let renderer_resources = RendererResources::default();
+
+    let referenced_content = DisplayListParametersRef {
+        dom_id: DomId::ROOT_ID,
+        document_id: &document_id,
+        epoch: Epoch::new(),
+        full_window_state: &full_window_state,
+        layout_results: &layout_results,
+        gl_texture_cache: &gl_texture_cache,
+        image_cache: &image_cache,
+        renderer_resources: &renderer_resources,
+    };
+
+    let frame = match displaylist_handle_rect(NodeId::ZERO, &referenced_content) {
+        Some(DisplayListMsg::Frame(frame)) => frame,
+        other => panic!("expected a frame, got {:?}", other),
+    };
+
+    let image_rendering = frame.content.iter().find_map(|c| match c {
+        LayoutRectContent::Image {
+            image_rendering, ..
+        } => Some(*image_rendering),
+        _ => None,
+    });
+
+    assert_eq!(image_rendering, Some(ImageRendering::Pixelated));
+}
@@ -6,7 +6,7 @@ use crate::{
     styled_dom::{
         CssPropertyCache, CssPropertyCachePtr, StyleFontFamilyHash, StyledNode, StyledNodeState,
     },
-    styled_dom::{NodeHierarchyItemId, StyledDom},
+    styled_dom::{NodeHierarchyItem, NodeHierarchyItemId, StyledDom},
     window::{Menu, OptionVirtualKeyCodeCombo},
 };
 use alloc::boxed::Box;
@@ -268,7 +268,7 @@ impl EventFilter {
 /// Creates a function inside an impl <enum type> block that returns a single
 /// variant if the enum is that variant.
 ///
-/// ```rust
+/// ```rust,ignore
 /// enum A {
 ///    Abc(AbcType),
 /// }
@@ -359,6 +359,9 @@ pub enum HoverEventFilter {
     TouchMove,
     TouchEnd,
     TouchCancel,
+    ImeStateChanged,
+    ImePreedit,
+    ImeCommit,
 }
 
 impl HoverEventFilter {
@@ -388,6 +391,9 @@ impl HoverEventFilter {
             HoverEventFilter::TouchMove => None,
             HoverEventFilter::TouchEnd => None,
             HoverEventFilter::TouchCancel => None,
+            HoverEventFilter::ImeStateChanged => Some(FocusEventFilter::ImeStateChanged),
+            HoverEventFilter::ImePreedit => Some(FocusEventFilter::ImePreedit),
+            HoverEventFilter::ImeCommit => Some(FocusEventFilter::ImeCommit),
         }
     }
 }
@@ -437,6 +443,9 @@ pub enum FocusEventFilter {
     VirtualKeyUp,
     FocusReceived,
     FocusLost,
+    ImeStateChanged,
+    ImePreedit,
+    ImeCommit,
 }
 
 /// Event filter that fires when any action fires on the entire window
@@ -476,6 +485,12 @@ pub enum WindowEventFilter {
     ThemeChanged,
     WindowFocusReceived,
     WindowFocusLost,
+    /// The input method editor (IME) was enabled or disabled
+    ImeStateChanged,
+    /// The IME composition text (not yet committed) has changed
+    ImePreedit,
+    /// The IME has committed the composed text
+    ImeCommit,
 }
 
 impl WindowEventFilter {
@@ -515,6 +530,9 @@ impl WindowEventFilter {
             WindowEventFilter::ThemeChanged => None,
             WindowEventFilter::WindowFocusReceived => None, // specific to window!
             WindowEventFilter::WindowFocusLost => None,     // specific to window!
+            WindowEventFilter::ImeStateChanged => Some(HoverEventFilter::ImeStateChanged),
+            WindowEventFilter::ImePreedit => Some(HoverEventFilter::ImePreedit),
+            WindowEventFilter::ImeCommit => Some(HoverEventFilter::ImeCommit),
         }
     }
 }
@@ -781,6 +799,9 @@ impl Hash for NodeData {
             if let Some(c) = ext.context_menu.as_ref() {
                 c.hash(state);
             }
+            if let Some(c) = ext.key.as_ref() {
+                c.hash(state);
+            }
         }
     }
 }
@@ -798,6 +819,8 @@ pub struct NodeDataExt {
     pub(crate) menu_bar: Option<Box<Menu>>,
     /// Context menu that should be opened when the item is left-clicked
     pub(crate) context_menu: Option<Box<Menu>>,
+    /// Stable identity of the node among its siblings, used when diffing two DOMs
+    pub(crate) key: Option<u64>,
     // ... insert further API extensions here...
 }
 
@@ -826,6 +849,19 @@ pub struct AccessibilityInfo {
     pub default_action: OptionAzString,
 }
 
+impl Default for AccessibilityInfo {
+    fn default() -> Self {
+        Self {
+            name: None.into(),
+            value: None.into(),
+            role: AccessibilityRole::Nothing,
+            states: AccessibilityStateVec::from_const_slice(&[]),
+            accelerator: None.into(),
+            default_action: None.into(),
+        }
+    }
+}
+
 /// MSAA Accessibility role constants. For information on what each role does,
 /// see the [MSDN Role Constants page](https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles).
 #[repr(C)]
@@ -1232,6 +1268,16 @@ impl NodeData {
     pub fn get_accessibility_info(&self) -> Option<&Box<AccessibilityInfo>> {
         self.extra.as_ref().and_then(|e| e.accessibility.as_ref())
     }
+    /// Returns the role of the `AccessibilityInfo`, if any
+    #[inline]
+    pub fn get_accessibility_role(&self) -> Option<AccessibilityRole> {
+        self.get_accessibility_info().map(|a| a.role)
+    }
+    /// Returns the accessible name of the `AccessibilityInfo`, if any
+    #[inline]
+    pub fn get_accessibility_label(&self) -> Option<&AzString> {
+        self.get_accessibility_info().and_then(|a| a.name.as_ref())
+    }
     #[inline]
     pub fn get_menu_bar(&self) -> Option<&Box<Menu>> {
         self.extra.as_ref().and_then(|e| e.menu_bar.as_ref())
@@ -1240,6 +1286,10 @@ impl NodeData {
     pub fn get_context_menu(&self) -> Option<&Box<Menu>> {
         self.extra.as_ref().and_then(|e| e.context_menu.as_ref())
     }
+    #[inline]
+    pub fn get_key(&self) -> Option<u64> {
+        self.extra.as_ref().and_then(|e| e.key)
+    }
 
     #[inline(always)]
     pub fn set_node_type(&mut self, node_type: NodeType) {
@@ -1277,6 +1327,22 @@ impl NodeData {
             .get_or_insert_with(|| Box::new(NodeDataExt::default()))
             .accessibility = Some(Box::new(accessibility_info));
     }
+    /// Sets the role of the `AccessibilityInfo`, creating a default one if necessary
+    #[inline]
+    pub fn set_accessibility_role(&mut self, role: AccessibilityRole) {
+        self.get_or_insert_accessibility_info().role = role;
+    }
+    /// Sets the accessible name of the `AccessibilityInfo`, creating a default one if necessary
+    #[inline]
+    pub fn set_accessibility_label(&mut self, label: AzString) {
+        self.get_or_insert_accessibility_info().name = Some(label).into();
+    }
+    fn get_or_insert_accessibility_info(&mut self) -> &mut AccessibilityInfo {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .accessibility
+            .get_or_insert_with(|| Box::new(AccessibilityInfo::default()))
+    }
     #[inline]
     pub fn set_menu_bar(&mut self, menu_bar: Menu) {
         self.extra
@@ -1289,6 +1355,15 @@ impl NodeData {
             .get_or_insert_with(|| Box::new(NodeDataExt::default()))
             .context_menu = Some(Box::new(context_menu));
     }
+    /// Sets a key that identifies this node among its siblings when the DOM is
+    /// diffed against the DOM of the next frame (see `Dom::diff`). Without a key,
+    /// nodes are identified by their position. Keys have to be unique among siblings.
+    #[inline]
+    pub fn set_key(&mut self, key: u64) {
+        self.extra
+            .get_or_insert_with(|| Box::new(NodeDataExt::default()))
+            .key = Some(key);
+    }
 
     #[inline]
     pub fn with_context_menu(mut self, context_menu: Menu) -> Self {
@@ -1547,9 +1622,9 @@ impl Dom {
         let mut v: DomVec = Vec::new().into();
         mem::swap(&mut v, &mut self.children);
         let mut v = v.into_library_owned_vec();
+        self.estimated_total_children += child.estimated_total_children + 1;
         v.push(child);
         self.children = v.into();
-        self.estimated_total_children += 1;
     }
 
     #[inline(always)]
@@ -1573,6 +1648,46 @@ impl Dom {
         self.estimated_total_children + 1
     }
 
+    /// Returns the node type of the root node
+    pub fn get_node_type(&self) -> &NodeType {
+        self.root.get_node_type()
+    }
+
+    /// Returns the number of direct children of the root node
+    pub fn children_count(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Iterates over all nodes in depth-first pre-order, i.e. the root node first,
+    /// then the first child and its subtree, then the second child, etc.
+    ///
+    /// The position of a node in the iterator is the same as its `NodeId`
+    /// once the `Dom` is converted into a `StyledDom`.
+    pub fn iter_nodes(&self) -> impl Iterator<Item = &NodeData> {
+        let mut stack = vec![self];
+        core::iter::from_fn(move || {
+            let dom = stack.pop()?;
+            stack.extend(dom.children.as_ref().iter().rev());
+            Some(&dom.root)
+        })
+    }
+
+    /// Returns the `NodeId` (see `iter_nodes`) of the first node with the given id
+    pub fn find_first_by_id(&self, id: &str) -> Option<NodeId> {
+        self.iter_nodes()
+            .position(|node| node.has_id(id))
+            .map(NodeId::new)
+    }
+
+    /// Returns the `NodeId`s (see `iter_nodes`) of all nodes with the given class
+    pub fn find_all_by_class(&self, class: &str) -> Vec<NodeId> {
+        self.iter_nodes()
+            .enumerate()
+            .filter(|(_, node)| node.has_class(class))
+            .map(|(id, _)| NodeId::new(id))
+            .collect()
+    }
+
     #[cfg(all(feature = "multithreading", feature = "css_parser"))]
     pub fn style(&mut self, css: azul_css_parser::CssApiWrapper) -> StyledDom {
         StyledDom::new(self, css)
@@ -1633,6 +1748,31 @@ impl Dom {
         self
     }
 
+    #[inline]
+    pub fn with_accessibility_role(mut self, role: AccessibilityRole) -> Self {
+        self.root.set_accessibility_role(role);
+        self
+    }
+
+    #[inline]
+    pub fn with_accessibility_label(mut self, label: AzString) -> Self {
+        self.root.set_accessibility_label(label);
+        self
+    }
+
+    #[inline]
+    pub fn with_key(mut self, key: u64) -> Self {
+        self.root.set_key(key);
+        self
+    }
+
+    /// Offset of the last direct child from this node in the flattened arena,
+    /// requires `estimated_total_children` to be up to date
+    fn get_last_child_offset(&self) -> Option<usize> {
+        let last_child = self.children.as_ref().last()?;
+        Some(self.estimated_total_children - last_child.estimated_total_children)
+    }
+
     fn fixup_children_estimated(&mut self) -> usize {
         if self.children.is_empty() {
             self.estimated_total_children = 0;
@@ -1711,6 +1851,35 @@ impl From<Dom> for CompactDom {
     }
 }
 
+impl From<StyledDom> for Dom {
+    /// Converts the nodes of a `StyledDom` back into a `Dom`, the styles are discarded
+    fn from(styled_dom: StyledDom) -> Self {
+        fn convert_node(
+            node_id: NodeId,
+            node_hierarchy: &NodeDataContainerRef<NodeHierarchyItem>,
+            node_data: &mut [NodeData],
+        ) -> Dom {
+            let mut dom = Dom {
+                root: mem::replace(&mut node_data[node_id.index()], NodeData::div()),
+                children: DomVec::from_const_slice(&[]),
+                estimated_total_children: 0,
+            };
+            for child_id in node_id.az_children(node_hierarchy) {
+                dom.add_child(convert_node(child_id, node_hierarchy, node_data));
+            }
+            dom
+        }
+
+        let root = styled_dom.root.into_crate_internal().unwrap_or(NodeId::ZERO);
+        let mut node_data = styled_dom.node_data.into_library_owned_vec();
+        convert_node(
+            root,
+            &styled_dom.node_hierarchy.as_container(),
+            &mut node_data,
+        )
+    }
+}
+
 fn convert_dom_into_compact_dom(mut dom: Dom) -> CompactDom {
     // note: somehow convert this into a non-recursive form later on!
     fn convert_dom_into_compact_dom_internal(
@@ -1744,7 +1913,6 @@ fn convert_dom_into_compact_dom(mut dom: Dom) -> CompactDom {
         for (child_index, child_dom) in dom.children.as_mut().iter_mut().enumerate() {
             let child_node_id = NodeId::new(*cur_node_id);
             let is_last_child = (child_index + 1) == children_len;
-            let child_dom_is_empty = child_dom.children.is_empty();
             let child_node = Node {
                 parent: Some(parent_node_id),
                 previous_sibling: previous_sibling_id,
@@ -1753,11 +1921,7 @@ fn convert_dom_into_compact_dom(mut dom: Dom) -> CompactDom {
                 } else {
                     Some(child_node_id + child_dom.estimated_total_children + 1)
                 },
-                last_child: if child_dom_is_empty {
-                    None
-                } else {
-                    Some(child_node_id + child_dom.estimated_total_children)
-                },
+                last_child: child_dom.get_last_child_offset().map(|offset| child_node_id + offset),
             };
             previous_sibling_id = Some(child_node_id);
             // recurse BEFORE adding the next child
@@ -1786,11 +1950,7 @@ fn convert_dom_into_compact_dom(mut dom: Dom) -> CompactDom {
         parent: None,
         previous_sibling: None,
         next_sibling: None,
-        last_child: if dom.children.is_empty() {
-            None
-        } else {
-            Some(root_node_id + dom.estimated_total_children)
-        },
+        last_child: dom.get_last_child_offset().map(|offset| root_node_id + offset),
     };
 
     convert_dom_into_compact_dom_internal(
@@ -1813,25 +1973,25 @@ fn convert_dom_into_compact_dom(mut dom: Dom) -> CompactDom {
     }
 }
 
+#[cfg(test)]
+fn ids_and_classes(ids: &[&'static str], classes: &[&'static str]) -> IdOrClassVec {
+    ids.iter()
+        .map(|id| IdOrClass::Id(AzString::from_const_str(*id)))
+        .chain(classes.iter().map(|c| IdOrClass::Class(AzString::from_const_str(*c))))
+        .collect::<Vec<_>>()
+        .into()
+}
+
 #[test]
 fn test_compact_dom_conversion() {
     let dom: Dom = Dom::body()
-        .with_child(Dom::div().with_class("class1"))
+        .with_child(Dom::div().with_ids_and_classes(ids_and_classes(&[], &["class1"])))
         .with_child(
             Dom::div()
-                .with_class("class1")
-                .with_child(Dom::div().with_id("child_2")),
+                .with_ids_and_classes(ids_and_classes(&[], &["class1"]))
+                .with_child(Dom::div().with_ids_and_classes(ids_and_classes(&["child_2"], &[]))),
         )
-        .with_child(Dom::div().with_class("class1"));
-
-    let c0: Vec<AzString> = vec!["class1".to_string().into()];
-    let c0: StringVec = c0.into();
-    let c1: Vec<AzString> = vec!["class1".to_string().into()];
-    let c1: StringVec = c1.into();
-    let c2: Vec<AzString> = vec!["child_2".to_string().into()];
-    let c2: StringVec = c2.into();
-    let c3: Vec<AzString> = vec!["class1".to_string().into()];
-    let c3: StringVec = c3.into();
+        .with_child(Dom::div().with_ids_and_classes(ids_and_classes(&[], &["class1"])));
 
     let expected_dom: CompactDom = CompactDom {
         root: NodeId::ZERO,
@@ -1841,35 +2001,30 @@ fn test_compact_dom_conversion() {
                 parent: None,
                 previous_sibling: None,
                 next_sibling: None,
-                first_child: Some(NodeId::new(1)),
                 last_child: Some(NodeId::new(4)),
             },
                 Node /* 1 */ {
                 parent: Some(NodeId::new(0)),
                 previous_sibling: None,
                 next_sibling: Some(NodeId::new(2)),
-                first_child: None,
                 last_child: None,
             },
                 Node /* 2 */ {
                 parent: Some(NodeId::new(0)),
                 previous_sibling: Some(NodeId::new(1)),
                 next_sibling: Some(NodeId::new(4)),
-                first_child: Some(NodeId::new(3)),
                 last_child: Some(NodeId::new(3)),
             },
                 Node /* 3 */ {
                 parent: Some(NodeId::new(2)),
                 previous_sibling: None,
                 next_sibling: None,
-                first_child: None,
                 last_child: None,
             },
                 Node /* 4 */ {
                 parent: Some(NodeId::new(0)),
                 previous_sibling: Some(NodeId::new(2)),
                 next_sibling: None,
-                first_child: None,
                 last_child: None,
             },
             ],
@@ -1877,10 +2032,10 @@ fn test_compact_dom_conversion() {
         node_data: NodeDataContainer {
             internal: vec![
                 /* 0 */ NodeData::body(),
-                /* 1 */ NodeData::div().with_classes(c0),
-                /* 2 */ NodeData::div().with_classes(c1),
-                /* 3 */ NodeData::div().with_ids(c2),
-                /* 4 */ NodeData::div().with_classes(c3),
+                /* 1 */ NodeData::div().with_ids_and_classes(ids_and_classes(&[], &["class1"])),
+                /* 2 */ NodeData::div().with_ids_and_classes(ids_and_classes(&[], &["class1"])),
+                /* 3 */ NodeData::div().with_ids_and_classes(ids_and_classes(&["child_2"], &[])),
+                /* 4 */ NodeData::div().with_ids_and_classes(ids_and_classes(&[], &["class1"])),
             ],
         },
     };
@@ -1902,68 +2057,63 @@ fn test_dom_sibling_1() {
     let dom: Dom = Dom::div()
         .with_child(
             Dom::div()
-                .with_id("sibling-1")
-                .with_child(Dom::div().with_id("sibling-1-child-1")),
+                .with_ids_and_classes(ids_and_classes(&["sibling-1"], &[]))
+                .with_child(Dom::div().with_ids_and_classes(ids_and_classes(&["sibling-1-child-1"], &[]))),
         )
         .with_child(
             Dom::div()
-                .with_id("sibling-2")
-                .with_child(Dom::div().with_id("sibling-2-child-1")),
+                .with_ids_and_classes(ids_and_classes(&["sibling-2"], &[]))
+                .with_child(Dom::div().with_ids_and_classes(ids_and_classes(&["sibling-2-child-1"], &[]))),
         );
 
     let dom = convert_dom_into_compact_dom(dom);
 
-    let arena = &dom.arena;
-
     assert_eq!(NodeId::new(0), dom.root);
 
-    let v: Vec<AzString> = vec!["sibling-1".to_string().into()];
-    let v: StringVec = v.into();
-    assert_eq!(
-        v,
-        arena.node_data[arena.node_hierarchy[dom.root]
-            .first_child
-            .expect("root has no first child")]
-        .ids
-    );
+    let hierarchy = dom.node_hierarchy.as_ref();
+    let node_data = dom.node_data.as_ref();
 
-    let v: Vec<AzString> = vec!["sibling-2".to_string().into()];
-    let v: StringVec = v.into();
-    assert_eq!(
-        v,
-        arena.node_data[arena.node_hierarchy[arena.node_hierarchy[dom.root]
-            .first_child
-            .expect("root has no first child")]
-        .next_sibling
-        .expect("root has no second sibling")]
-        .ids
-    );
+    // the first child of a node is always stored directly after the node
+    let first_child = |id: NodeId| hierarchy[id].last_child.map(|_| id + 1);
 
-    let v: Vec<AzString> = vec!["sibling-1-child-1".to_string().into()];
-    let v: StringVec = v.into();
-    assert_eq!(
-        v,
-        arena.node_data[arena.node_hierarchy[arena.node_hierarchy[dom.root]
-            .first_child
-            .expect("root has no first child")]
-        .first_child
-        .expect("first child has no first child")]
-        .ids
-    );
+    let sibling_1 = first_child(dom.root).expect("root has no first child");
+    assert!(node_data[sibling_1].has_id("sibling-1"));
 
-    let v: Vec<AzString> = vec!["sibling-2-child-1".to_string().into()];
-    let v: StringVec = v.into();
-    assert_eq!(
-        v,
-        arena.node_data[arena.node_hierarchy[arena.node_hierarchy[arena.node_hierarchy[dom.root]
-            .first_child
-            .expect("root has no first child")]
-        .next_sibling
-        .expect("first child has no second sibling")]
-        .first_child
-        .expect("second sibling has no first child")]
-        .ids
-    );
+    let sibling_2 = hierarchy[sibling_1].next_sibling.expect("root has no second sibling");
+    assert!(node_data[sibling_2].has_id("sibling-2"));
+    assert_eq!(hierarchy[dom.root].last_child, Some(sibling_2));
+
+    let sibling_1_child = first_child(sibling_1).expect("first child has no first child");
+    assert!(node_data[sibling_1_child].has_id("sibling-1-child-1"));
+
+    let sibling_2_child = first_child(sibling_2).expect("second sibling has no first child");
+    assert!(node_data[sibling_2_child].has_id("sibling-2-child-1"));
+    assert_eq!(hierarchy[sibling_2_child].parent, Some(sibling_2));
+}
+
+/// `last_child` has to point to the last direct child, not to the last descendant
+#[test]
+fn test_compact_dom_last_child_nested() {
+    // body                 NodeId(0)
+    //   |-> div            NodeId(1)
+    //   |     '-> div      NodeId(2)
+    //   '-> div            NodeId(3)
+    //         '-> div      NodeId(4)
+    //               '-> div  NodeId(5)
+    let dom = Dom::body()
+        .with_child(Dom::div().with_child(Dom::div()))
+        .with_child(Dom::div().with_child(Dom::div().with_child(Dom::div())));
+
+    let dom = convert_dom_into_compact_dom(dom);
+    let hierarchy = dom.node_hierarchy.as_ref();
+
+    assert_eq!(hierarchy[NodeId::new(0)].last_child, Some(NodeId::new(3)));
+    assert_eq!(hierarchy[NodeId::new(1)].last_child, Some(NodeId::new(2)));
+    assert_eq!(hierarchy[NodeId::new(3)].last_child, Some(NodeId::new(4)));
+    assert_eq!(hierarchy[NodeId::new(4)].last_child, Some(NodeId::new(5)));
+
+    let reversed = dom.root.reverse_children(&hierarchy).collect::<Vec<_>>();
+    assert_eq!(reversed, vec![NodeId::new(3), NodeId::new(1)]);
 }
 
 #[test]
@@ -1971,11 +2121,12 @@ fn test_dom_from_iter_1() {
     use crate::id_tree::Node;
 
     let dom: Dom = (0..5)
-        .map(|e| NodeData::new(NodeType::Label(format!("{}", e + 1).into())))
+        .map(|e| Dom::text(format!("{}", e + 1)))
         .collect();
     let dom = convert_dom_into_compact_dom(dom);
 
-    let arena = &dom.arena;
+    let node_hierarchy = dom.node_hierarchy.as_ref();
+    let node_data = dom.node_data.as_ref();
 
     // We need to have 6 nodes:
     //
@@ -1986,51 +2137,81 @@ fn test_dom_from_iter_1() {
     //   |-> 4              NodeId(4)
     //   '-> 5              NodeId(5)
 
-    assert_eq!(arena.len(), 6);
+    assert_eq!(dom.len(), 6);
 
     // Check root node
     assert_eq!(
-        arena.node_hierarchy.get(NodeId::new(0)),
+        node_hierarchy.get(NodeId::new(0)),
         Some(&Node {
             parent: None,
             previous_sibling: None,
             next_sibling: None,
-            first_child: Some(NodeId::new(1)),
             last_child: Some(NodeId::new(5)),
         })
     );
     assert_eq!(
-        arena.node_data.get(NodeId::new(0)),
+        node_data.get(NodeId::new(0)),
         Some(&NodeData::new(NodeType::Div))
     );
 
     assert_eq!(
-        arena
-            .node_hierarchy
-            .get(NodeId::new(arena.node_hierarchy.len() - 1)),
+        node_hierarchy.get(NodeId::new(node_hierarchy.len() - 1)),
         Some(&Node {
             parent: Some(NodeId::new(0)),
             previous_sibling: Some(NodeId::new(4)),
             next_sibling: None,
-            first_child: None,
             last_child: None,
         })
     );
 
     assert_eq!(
-        arena.node_data.get(NodeId::new(arena.node_data.len() - 1)),
-        Some(&NodeData {
-            node_type: NodeType::Label("5".to_string().into()),
-            ..Default::default()
-        })
+        node_data.get(NodeId::new(node_data.len() - 1)),
+        Some(&NodeData::text("5"))
     );
 }
 
 /// Test that there shouldn't be a DOM that has 0 nodes
 #[test]
 fn test_zero_size_dom() {
-    let null_dom: Dom = (0..0).map(|_| NodeData::default()).collect();
+    let null_dom: Dom = (0..0).map(|_| Dom::div()).collect();
     let null_dom = convert_dom_into_compact_dom(null_dom);
+    assert!(null_dom.len() == 1);
+}
 
-    assert!(null_dom.arena.len() == 1);
+#[test]
+fn test_dom_queries() {
+    let dom = Dom::body()
+        .with_child(
+            Dom::div()
+                .with_ids_and_classes(ids_and_classes(&["header"], &["row"]))
+                .with_child(Dom::text("title").with_ids_and_classes(ids_and_classes(&[], &["label"]))),
+        )
+        .with_child(
+            Dom::div()
+                .with_ids_and_classes(ids_and_classes(&[], &["row"]))
+                .with_child(Dom::text("a").with_ids_and_classes(ids_and_classes(&[], &["label"])))
+                .with_child(Dom::text("b").with_ids_and_classes(ids_and_classes(&["footer"], &["label"]))),
+        );
+
+    assert_eq!(dom.node_count(), 6);
+    assert_eq!(dom.children_count(), 2);
+    assert_eq!(dom.get_node_type(), &NodeType::Body);
+    assert_eq!(dom.iter_nodes().count(), dom.node_count());
+
+    assert_eq!(dom.find_first_by_id("header"), Some(NodeId::new(1)));
+    assert_eq!(dom.find_first_by_id("footer"), Some(NodeId::new(5)));
+    assert_eq!(dom.find_first_by_id("row"), None);
+
+    assert_eq!(dom.find_all_by_class("row"), vec![NodeId::new(1), NodeId::new(3)]);
+    assert_eq!(
+        dom.find_all_by_class("label"),
+        vec![NodeId::new(2), NodeId::new(4), NodeId::new(5)]
+    );
+    assert!(dom.find_all_by_class("header").is_empty());
+
+    // the indices are the same as the NodeIds of the converted arena
+    let compact_dom = convert_dom_into_compact_dom(dom.clone());
+    for id in dom.find_all_by_class("label") {
+        assert!(compact_dom.node_data.as_ref()[id].has_class("label"));
+    }
 }
@@ -0,0 +1,123 @@
+//! Process-wide error reporting for panics at the callback / FFI boundary
+//!
+//! Panics that are caught before they cross into foreign code are recorded
+//! as a "last error" string, which C / C++ / Python code can read with
+//! `AzApi_lastError()` and reset with `AzApi_clearLastError()`.
+
+use alloc::string::{String, ToString};
+use azul_css::{AzString, OptionAzString};
+use core::any::Any;
+use core::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::{Mutex, Once};
+
+static LAST_ERROR: Mutex<Option<String>> = Mutex::new(None);
+static SET_UP_PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    /// Number of `catch_panic` calls on the stack of this thread
+    static CATCH_PANIC_DEPTH: Cell<usize> = Cell::new(0);
+    /// Message (with location and backtrace) that the panic hook
+    /// formatted for the panic that is currently unwinding
+    static PANIC_MESSAGE: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// Overwrites the last error
+pub fn set_last_error(error: String) {
+    if let Ok(mut e) = LAST_ERROR.lock() {
+        *e = Some(error);
+    }
+}
+
+/// Returns the last recorded error, does not reset it
+pub fn last_error() -> OptionAzString {
+    LAST_ERROR
+        .lock()
+        .ok()
+        .and_then(|e| e.clone())
+        .map(AzString::from)
+        .into()
+}
+
+/// Resets the last error to `None`, returns whether an error was recorded
+pub fn clear_last_error() -> bool {
+    LAST_ERROR
+        .lock()
+        .map(|mut e| e.take().is_some())
+        .unwrap_or(false)
+}
+
+/// Runs `f`, returns the panic message instead of unwinding further
+/// and stores the message as the last error.
+///
+/// NOTE: a panic can only be caught if it doesn't unwind through an
+/// `extern "C"` frame first - Rust aborts the process in that case.
+/// User callbacks are `extern "C"`, so a panic raised inside of a
+/// callback aborts, but a panic in a library function that the
+/// callback calls is caught by that function.
+pub fn catch_panic<T, F: FnOnce() -> T>(f: F) -> Result<T, AzString> {
+    CATCH_PANIC_DEPTH.with(|d| d.set(d.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCH_PANIC_DEPTH.with(|d| d.set(d.get() - 1));
+
+    result.map_err(|payload| {
+        let error = panic_payload_to_string(&*payload);
+        // prefer the message of the panic hook, it contains the location
+        let detailed = PANIC_MESSAGE.with(|m| m.borrow_mut().take());
+        set_last_error(detailed.unwrap_or_else(|| error.clone()));
+        error.into()
+    })
+}
+
+/// Returns whether a panic on the current thread will be caught by
+/// `catch_panic`, i.e. whether the application keeps running after it
+pub fn panic_is_caught() -> bool {
+    CATCH_PANIC_DEPTH.with(|d| d.get() > 0)
+}
+
+/// Installs a panic hook that records the panic message, location
+/// and (if `RUST_BACKTRACE` is set) the backtrace as the last error,
+/// then calls the previously installed hook.
+///
+/// Calling this function more than once has no effect.
+pub fn set_up_default_panic_hook() {
+    SET_UP_PANIC_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let message = format_panic_info(info);
+            if panic_is_caught() {
+                // catch_panic records it once the stack is unwound
+                PANIC_MESSAGE.with(|m| *m.borrow_mut() = Some(message));
+            } else {
+                set_last_error(message);
+            }
+            previous_hook(info);
+        }));
+    });
+}
+
+fn format_panic_info(info: &PanicHookInfo) -> String {
+    use std::backtrace::{Backtrace, BacktraceStatus};
+
+    let mut s = panic_payload_to_string(info.payload());
+
+    if let Some(location) = info.location() {
+        s.push_str(&format!(" (at {}:{}:{})", location.file(), location.line(), location.column()));
+    }
+
+    // only captures if RUST_BACKTRACE / RUST_LIB_BACKTRACE is set
+    let backtrace = Backtrace::capture();
+    if backtrace.status() == BacktraceStatus::Captured {
+        s.push_str(&format!("\r\n\r\nBacktrace:\r\n{}", backtrace));
+    }
+
+    s
+}
+
+fn panic_payload_to_string(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+        .unwrap_or_else(|| "panic with non-string payload".to_string())
+}
@@ -856,6 +856,25 @@ impl Drop for GlContextPtr {
 }
 
 impl GlContextPtr {
+    /// Context without any loaded OpenGL functions: every call does nothing
+    /// and returns zero. Used as a placeholder for GL objects that could not
+    /// be created, so that deleting them is a no-op.
+    pub fn null() -> Self {
+        // GenericGlContext only consists of function pointers,
+        // a null pointer marks a function as "not loaded"
+        let gl_context: GenericGlContext = unsafe { core::mem::zeroed() };
+        Self {
+            ptr: Box::new(Rc::new(GlContextPtrInner {
+                ptr: Rc::new(gl_context),
+                svg_shader: 0,
+                svg_multicolor_shader: 0,
+                fxaa_shader: 0,
+            })),
+            renderer_type: RendererType::Software,
+            run_destructor: true,
+        }
+    }
+
     pub fn get_svg_shader(&self) -> GLuint {
         self.ptr.svg_shader
     }
@@ -24,20 +24,38 @@ pub mod macros;
 /// Type definitions for various types of callbacks, as well as focus and scroll handling
 #[macro_use]
 pub mod callbacks;
+/// Accessibility tree, mirrors the `StyledDom` for screen readers
+pub mod accessibility;
 /// Functions to manage adding fonts + images, garbage collection
 pub mod app_resources;
 /// Contains functions to format a CSS stylesheet to a Rust string
 pub mod css;
+/// Diffing of two `Dom`s, used to restyle only the changed nodes
+pub mod diff;
 /// Layout and display list creation algorithm, z-index reordering of a `CachedDisplayList`
 pub mod display_list;
 /// `Dom` construction, `NodeData` and `NodeType` management functions
 pub mod dom;
-// Algorithms to create git-like diffs between two doms in linear time
-// pub mod diff;
+/// Last-error store and panic hook for panics caught at the FFI boundary
+#[cfg(feature = "std")]
+pub mod error;
+/// Panics can't be caught without `std`, `catch_panic` only runs the closure
+#[cfg(not(feature = "std"))]
+pub mod error {
+    use azul_css::AzString;
+
+    pub fn catch_panic<T, F: FnOnce() -> T>(f: F) -> Result<T, AzString> {
+        Ok(f())
+    }
+}
 /// Contains OpenGL helper functions (to compile / link shaders), `VirtualGlDriver` for unit testing
 pub mod gl;
 /// Internal, arena-based storage for Dom nodes
 pub mod id_tree;
+/// Popup menus drawn by azul on top of the root DOM
+pub mod overlay_menu;
+/// Text snapshots of a solved layout for UI regression tests
+pub mod snapshot;
 /// CSS cascading module
 pub mod style;
 /// `StyledDom` = CSSOM
@@ -46,6 +64,8 @@ pub mod styled_dom;
 pub mod svg;
 /// Async (task, thread, timer) helper functions
 pub mod task;
+/// Text cursor / selection types for text inputs
+pub mod text_cursor;
 /// Main `Layout` and `GetTextLayout` trait definition
 pub mod traits;
 /// Handles the UI layout and UI layout solver
@@ -36,7 +36,7 @@ macro_rules! impl_task_api {
 
 /// Implement the `From` trait for any type.
 /// Example usage:
-/// ```
+/// ```rust,ignore
 /// enum MyError<'a> {
 ///     Bar(BarError<'a>)
 ///     Foo(FooError<'a>)
@@ -70,7 +70,7 @@ macro_rules! impl_from {
 /// Implement `Display` for an enum.
 ///
 /// Example usage:
-/// ```
+/// ```rust,ignore
 /// enum Foo<'a> {
 ///     Bar(&'a str)
 ///     Baz(i32)
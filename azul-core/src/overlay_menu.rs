@@ -0,0 +1,173 @@
+//! Popup menus drawn by azul, for platforms without native popup menus
+//!
+//! While a `ContextMenuRequest` is stored in `WindowInternal::overlay_menu`,
+//! `regenerate_styled_dom` appends `overlay_menu_dom` to the root DOM: a
+//! transparent backdrop covering the window, with the menu items on top.
+//! Clicking an item invokes its `MenuCallback` and closes the menu,
+//! clicking the backdrop only closes the menu.
+
+use crate::callbacks::{Callback, CallbackInfo, RefAny, Update};
+use crate::dom::{CallbackData, Dom, EventFilter, HoverEventFilter};
+use crate::window::{ContextMenuRequest, LogicalSize, MenuCallback, MenuItem};
+use alloc::vec::Vec;
+
+/// Indentation of sub-menus, which are shown inline below their parent item
+const SUBMENU_INDENT_PX: usize = 12;
+
+/// Data of an enabled overlay menu item
+struct OverlayMenuItem {
+    callback: MenuCallback,
+    request: ContextMenuRequest,
+}
+
+/// Builds the DOM of an open overlay menu, `window_size` is the size of the backdrop
+pub fn overlay_menu_dom(request: &ContextMenuRequest, window_size: LogicalSize) -> Dom {
+    let mut items = Vec::new();
+    push_items(request, request.menu.items.as_ref(), 0, &mut items);
+
+    let menu = Dom::div()
+        .with_inline_style(
+            "display:flex;flex-direction:column;background-color:#ffffff;\
+             border:1px solid #a0a0a0;padding:2px 0px;",
+        )
+        .with_children(items.into());
+
+    // the row shrinks the menu to the width of its items,
+    // the column of the backdrop shrinks it to their height
+    let row = Dom::div()
+        .with_inline_style(&format!(
+            "display:flex;flex-direction:row;margin-top:{}px;margin-left:{}px;",
+            request.position.y, request.position.x,
+        ))
+        .with_child(menu);
+
+    Dom::div()
+        .with_inline_style(&format!(
+            "position:absolute;top:0px;left:0px;width:{}px;height:{}px;\
+             display:flex;flex-direction:column;",
+            window_size.width, window_size.height,
+        ))
+        .with_callbacks(
+            vec![CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::MouseUp),
+                callback: Callback { cb: close_overlay_menu },
+                data: RefAny::new(()),
+            }]
+            .into(),
+        )
+        .with_child(row)
+}
+
+fn push_items(request: &ContextMenuRequest, items: &[MenuItem], depth: usize, out: &mut Vec<Dom>) {
+    for item in items {
+        let item = match item {
+            MenuItem::String(s) => s,
+            MenuItem::Separator => {
+                out.push(Dom::div().with_inline_style(
+                    "height:1px;margin:2px 0px;background-color:#d0d0d0;",
+                ));
+                continue;
+            }
+            MenuItem::BreakLine => continue,
+        };
+
+        let color = if item.is_enabled() { "#000000" } else { "#a0a0a0" };
+
+        let mut row = Dom::div().with_inline_style(&format!(
+            "display:flex;flex-direction:row;padding:2px 16px 2px {}px;color:{};",
+            16 + depth * SUBMENU_INDENT_PX,
+            color,
+        ));
+
+        if let Some(callback) = item.callback.as_option().filter(|_| item.is_enabled()) {
+            row = row.with_callbacks(
+                vec![CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::MouseUp),
+                    callback: Callback { cb: overlay_menu_item_clicked },
+                    data: RefAny::new(OverlayMenuItem {
+                        callback: callback.clone(),
+                        request: request.clone(),
+                    }),
+                }]
+                .into(),
+            );
+        }
+
+        row = row.with_child(Dom::text(item.label.clone()).with_inline_style("flex-grow:1;"));
+        if let Some(accelerator) = item.accelerator.as_option() {
+            row = row.with_child(
+                Dom::text(format!("{}", accelerator)).with_inline_style("margin-left:24px;"),
+            );
+        }
+
+        out.push(row);
+        push_items(request, item.children.as_ref(), depth + 1, out);
+    }
+}
+
+extern "C" fn overlay_menu_item_clicked(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    let (callback, request) = match data.downcast_ref::<OverlayMenuItem>() {
+        Some(s) => (s.callback.clone(), s.request.clone()),
+        None => return Update::DoNothing,
+    };
+
+    // the backdrop must not see the click, otherwise it would close a
+    // menu that the callback opens
+    info.stop_propagation();
+    info.close_context_menu();
+
+    let mut menu_info = info.with_hit_dom_node(request.hit_dom_node);
+    let MenuCallback { callback, mut data } = callback;
+    let mut update = callback.invoke(&mut data, &mut menu_info);
+
+    // regenerate the DOM without the overlay
+    update.max_self(Update::RefreshDom);
+    update
+}
+
+extern "C" fn close_overlay_menu(_: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    info.close_context_menu();
+    Update::RefreshDom
+}
+
+#[test]
+fn test_overlay_menu_dom() {
+    use crate::callbacks::DomNodeId;
+    use crate::window::{LogicalPosition, Menu, MenuItemState, StringMenuItem};
+    use azul_css_parser::CssApiWrapper;
+
+    extern "C" fn cb(_: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        Update::DoNothing
+    }
+
+    let menu = Menu::new(
+        vec![
+            MenuItem::String(StringMenuItem::new("Cut".into()).with_callback(RefAny::new(()), cb)),
+            MenuItem::Separator,
+            MenuItem::String(
+                StringMenuItem::new("Paste".into())
+                    .with_callback(RefAny::new(()), cb)
+                    .with_state(MenuItemState::Greyed),
+            ),
+        ]
+        .into(),
+    );
+    let request = ContextMenuRequest {
+        menu,
+        position: LogicalPosition::new(10.0, 20.0),
+        hit_dom_node: DomNodeId::ROOT,
+    };
+
+    let styled_dom = overlay_menu_dom(&request, LogicalSize::new(400.0, 300.0))
+        .style(CssApiWrapper::empty());
+    let node_data = styled_dom.node_data.as_container();
+    let callbacks = node_data
+        .internal
+        .iter()
+        .map(|n| n.get_callbacks().len())
+        .collect::<Vec<_>>();
+
+    // backdrop, row, menu, "Cut" row + label, separator, "Paste" row + label:
+    // the greyed item has no callback
+    assert_eq!(callbacks, vec![1, 0, 0, 1, 0, 0, 0, 0]);
+}
@@ -0,0 +1,182 @@
+//! Text snapshots of a solved layout for UI regression tests
+//!
+//! `LayoutResult::serialize_debug` prints one line per node: the path of
+//! the node, its layouted rectangle and a configurable set of computed CSS
+//! properties. The output only depends on the DOM, the CSS and the window
+//! size (nodes in depth-first order, properties sorted by name, floats
+//! rounded to two decimals), so a test can compare it against a committed
+//! golden file instead of comparing rendered pixels. `diff_snapshots`
+//! formats the differing lines if the comparison fails.
+//!
+//! ```text
+//! body [0.00 0.00 400.00x300.00] | display: flex; font-size: 16px;
+//! body > p[0] [0.00 0.00 400.00x60.00] "0" | display: block; font-size: 50px;
+//! ```
+
+use crate::id_tree::NodeId;
+use crate::ui_solver::LayoutResult;
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_css::CssPropertyType;
+use core::fmt::Write;
+
+/// Properties printed by `LayoutResult::serialize_debug` if no list is given
+pub const DEFAULT_SNAPSHOT_PROPERTIES: &[CssPropertyType] = &[
+    CssPropertyType::Display,
+    CssPropertyType::FlexDirection,
+    CssPropertyType::FlexGrow,
+    CssPropertyType::Width,
+    CssPropertyType::Height,
+    CssPropertyType::BackgroundContent,
+    CssPropertyType::TextColor,
+    CssPropertyType::FontSize,
+];
+
+impl LayoutResult {
+    /// Prints the layouted rectangles and the computed `properties` of all
+    /// nodes of this DOM, one line per node, see the module documentation.
+    /// Properties that are not set on a node are skipped.
+    ///
+    /// NOTE: nodes of iframes are in a separate `LayoutResult`
+    pub fn serialize_debug(&self, properties: &[CssPropertyType]) -> String {
+        let mut properties = properties.to_vec();
+        properties.sort_by_key(|p| p.to_str());
+        properties.dedup();
+
+        let mut out = String::new();
+        let root = match self.styled_dom.root.into_crate_internal() {
+            Some(s) => s,
+            None => return out,
+        };
+
+        let node_hierarchy = self.styled_dom.node_hierarchy.as_container();
+        let mut stack = vec![(root, self.node_name(root, None))];
+
+        while let Some((node_id, path)) = stack.pop() {
+            self.write_node(&mut out, node_id, &path, &properties);
+
+            let children = node_id.az_children_collect(&node_hierarchy);
+            for (index, child) in children.into_iter().enumerate().rev() {
+                let child_path = format!("{} > {}", path, self.node_name(child, Some(index)));
+                stack.push((child, child_path));
+            }
+        }
+
+        out
+    }
+
+    /// `body`, `div[2]`: the tag and the index among the siblings
+    fn node_name(&self, node_id: NodeId, index: Option<usize>) -> String {
+        let node_data = self.styled_dom.node_data.as_container();
+        let tag = node_data[node_id].get_node_type().get_path();
+        match index {
+            Some(i) => format!("{}[{}]", tag, i),
+            None => format!("{}", tag),
+        }
+    }
+
+    fn write_node(&self, out: &mut String, node_id: NodeId, path: &str, properties: &[CssPropertyType]) {
+        let rect = &self.rects.as_ref()[node_id];
+        let origin = rect.position.get_static_offset();
+        let _ = write!(
+            out,
+            "{} [{} {} {}x{}]",
+            path,
+            format_f32(origin.x),
+            format_f32(origin.y),
+            format_f32(rect.size.width),
+            format_f32(rect.size.height),
+        );
+
+        let node_data = self.styled_dom.node_data.as_container();
+        if let Some(text) = node_data[node_id].get_node_type().format() {
+            let _ = write!(out, " {:?}", text);
+        }
+
+        let computed = properties
+            .iter()
+            .filter_map(|ty| self.styled_dom.get_computed_property(node_id, *ty))
+            .collect::<Vec<_>>();
+
+        if !computed.is_empty() {
+            out.push_str(" |");
+            for property in computed.iter() {
+                let _ = write!(out, " {}", property.format_css());
+            }
+        }
+
+        out.push('\n');
+    }
+}
+
+/// Rounds to two decimals, without a sign for values that round to zero
+fn format_f32(value: f32) -> String {
+    let rounded = format!("{:.2}", value);
+    if rounded == "-0.00" {
+        String::from("0.00")
+    } else {
+        rounded
+    }
+}
+
+/// Compares two snapshots line by line, returns `None` if they are equal
+/// (ignoring `\r\n` vs. `\n` line endings). Otherwise returns a readable
+/// diff: unchanged lines are prefixed with two spaces, removed (`expected`)
+/// lines with `- `, added (`actual`) lines with `+ `.
+pub fn diff_snapshots(expected: &str, actual: &str) -> Option<String> {
+    let old = expected.lines().collect::<Vec<_>>();
+    let new = actual.lines().collect::<Vec<_>>();
+    if old == new {
+        return None;
+    }
+
+    // longest common subsequence of lines, lcs[i][j] = LCS of old[i..] and new[j..]
+    let mut lcs = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            let _ = writeln!(out, "  {}", old[i]);
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            let _ = writeln!(out, "- {}", old[i]);
+            i += 1;
+        } else {
+            let _ = writeln!(out, "+ {}", new[j]);
+            j += 1;
+        }
+    }
+
+    Some(out)
+}
+
+#[test]
+fn test_format_f32() {
+    assert_eq!(format_f32(1.0), "1.00");
+    assert_eq!(format_f32(2.0 / 3.0), "0.67");
+    assert_eq!(format_f32(-0.001), "0.00");
+    assert_eq!(format_f32(-1.5), "-1.50");
+}
+
+#[test]
+fn test_diff_snapshots() {
+    assert_eq!(diff_snapshots("a\nb\n", "a\nb\n"), None);
+    assert_eq!(diff_snapshots("a\r\nb\r\n", "a\nb"), None);
+
+    let diff = diff_snapshots("body\np \"0\"\nbutton\n", "body\np \"3\"\nbutton\n").unwrap();
+    assert_eq!(diff, "  body\n- p \"0\"\n+ p \"3\"\n  button\n");
+
+    let diff = diff_snapshots("a\n", "a\nb\n").unwrap();
+    assert_eq!(diff, "  a\n+ b\n");
+}
@@ -1,13 +1,13 @@
 //! DOM tree to CSS style tree cascading
 
 use crate::{
-    dom::NodeData,
+    dom::{IdOrClass, NodeData},
     id_tree::{NodeDataContainer, NodeDataContainerRef, NodeHierarchyRef, NodeId},
     styled_dom::NodeHierarchyItem,
 };
 use alloc::vec::Vec;
 use azul_css::{
-    CssContentGroup, CssNthChildSelector::*, CssPath, CssPathPseudoSelector, CssPathSelector,
+    AzString, CssContentGroup, CssPath, CssPathPseudoSelector, CssPathSelector, HtmlCascadeInfo,
 };
 
 /// Has all the necessary information about the style CSS path
@@ -33,6 +33,76 @@ impl CascadeInfoVec {
     }
 }
 
+/// Ids and classes of a node, split up for `azul_css::matches_html_element`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct NodeIdsAndClasses {
+    pub ids: Vec<AzString>,
+    pub classes: Vec<AzString>,
+}
+
+impl NodeIdsAndClasses {
+    pub fn new(node_data: &NodeData) -> Self {
+        let mut ids = Vec::new();
+        let mut classes = Vec::new();
+        for id_or_class in node_data.get_ids_and_classes().iter() {
+            match id_or_class {
+                IdOrClass::Id(id) => ids.push(id.clone()),
+                IdOrClass::Class(class) => classes.push(class.clone()),
+            }
+        }
+        Self { ids, classes }
+    }
+}
+
+/// Returns the `HtmlCascadeInfo` of the node, followed by the one of its
+/// parent, grandparent, etc. up to the root node
+pub(crate) fn html_cascade_chain<'a>(
+    node_id: NodeId,
+    node_hierarchy: &NodeDataContainerRef<NodeHierarchyItem>,
+    node_data: &NodeDataContainerRef<NodeData>,
+    html_node_tree: &NodeDataContainerRef<CascadeInfo>,
+    ids_and_classes: &NodeDataContainerRef<'a, NodeIdsAndClasses>,
+) -> Vec<HtmlCascadeInfo<'a>> {
+    let mut chain = Vec::new();
+    let mut current_node = Some(node_id);
+    while let Some(node_id) = current_node {
+        let names = &ids_and_classes.internal[node_id.index()];
+        chain.push(HtmlCascadeInfo {
+            ids: &names.ids,
+            classes: &names.classes,
+            index_in_parent: html_node_tree[node_id].index_in_parent,
+            is_last_child: html_node_tree[node_id].is_last_child,
+            ..HtmlCascadeInfo::new(node_data[node_id].get_node_type().get_path())
+        });
+        current_node = node_hierarchy[node_id].parent_id();
+    }
+    chain
+}
+
+/// Returns if the style CSS path matches the first node of the `chain`
+/// (see `html_cascade_chain`).
+///
+/// `:hover`, `:active` and `:focus` only match on the node itself and only if
+/// they are the `expected_path_ending`, i.e. this will match "body > #main:hover",
+/// but not "body:hover > #main"
+pub(crate) fn matches_cascade_chain(
+    css_path: &CssPath,
+    chain: &[HtmlCascadeInfo],
+    expected_path_ending: Option<CssPathPseudoSelector>,
+) -> bool {
+    let (node, ancestors) = match chain.split_first() {
+        Some(s) => s,
+        None => return false,
+    };
+    let node = HtmlCascadeInfo {
+        is_hovered: expected_path_ending == Some(CssPathPseudoSelector::Hover),
+        is_active: expected_path_ending == Some(CssPathPseudoSelector::Active),
+        is_focused: expected_path_ending == Some(CssPathPseudoSelector::Focus),
+        ..*node
+    };
+    azul_css::matches_html_element(css_path, &node, ancestors)
+}
+
 /// Returns if the style CSS path matches the DOM node (i.e. if the DOM node should be styled by that element)
 pub(crate) fn matches_html_element(
     css_path: &CssPath,
@@ -42,57 +112,20 @@ pub(crate) fn matches_html_element(
     html_node_tree: &NodeDataContainerRef<CascadeInfo>,
     expected_path_ending: Option<CssPathPseudoSelector>,
 ) -> bool {
-    use self::CssGroupSplitReason::*;
-
-    if css_path.selectors.is_empty() {
-        return false;
-    }
-
+    let mut ids_and_classes = vec![NodeIdsAndClasses::default(); node_data.len()];
     let mut current_node = Some(node_id);
-    let mut direct_parent_has_to_match = false;
-    let mut last_selector_matched = true;
-
-    let mut iterator = CssGroupIterator::new(css_path.selectors.as_ref());
-    while let Some((content_group, reason)) = iterator.next() {
-        let is_last_content_group = iterator.is_last_content_group();
-        let cur_node_id = match current_node {
-            Some(c) => c,
-            None => {
-                // The node has no parent, but the CSS path
-                // still has an extra limitation - only valid if the
-                // next content group is a "*" element
-                return *content_group == [&CssPathSelector::Global];
-            }
-        };
-
-        let current_selector_matches = selector_group_matches(
-            &content_group,
-            &html_node_tree[cur_node_id],
-            &node_data[cur_node_id],
-            expected_path_ending,
-            is_last_content_group,
-        );
-
-        if direct_parent_has_to_match && !current_selector_matches {
-            // If the element was a ">" element and the current,
-            // direct parent does not match, return false
-            return false; // not executed (maybe this is the bug)
-        }
-
-        // If the current selector matches, but the previous one didn't,
-        // that means that the CSS path chain is broken and therefore doesn't match the element
-        if current_selector_matches && !last_selector_matched {
-            return false;
-        }
-
-        // Important: Set if the current selector has matched the element
-        last_selector_matched = current_selector_matches;
-        // Select if the next content group has to exactly match or if it can potentially be skipped
-        direct_parent_has_to_match = reason == DirectChildren;
-        current_node = node_hierarchy[cur_node_id].parent_id();
+    while let Some(node_id) = current_node {
+        ids_and_classes[node_id.index()] = NodeIdsAndClasses::new(&node_data[node_id]);
+        current_node = node_hierarchy[node_id].parent_id();
     }
-
-    last_selector_matched
+    let chain = html_cascade_chain(
+        node_id,
+        node_hierarchy,
+        node_data,
+        html_node_tree,
+        &NodeDataContainerRef::from_slice(&ids_and_classes),
+    );
+    matches_cascade_chain(css_path, &chain, expected_path_ending)
 }
 
 /// A CSS group is a group of css selectors in a path that specify the rule that a
@@ -197,15 +230,19 @@ pub(crate) fn construct_html_cascade_tree(
         .collect::<Vec<_>>();
 
     for (_depth, parent_id) in node_depths_sorted {
-        // Note: :nth-child() starts at 1 instead of 0
-        let index_in_parent = parent_id.preceding_siblings(node_hierarchy).count();
-
-        let parent_html_matcher = CascadeInfo {
-            index_in_parent: (index_in_parent - 1) as u32,
-            is_last_child: node_hierarchy[*parent_id].next_sibling.is_none(), // Necessary for :last selectors
-        };
+        // every other parent was already visited as a child of its own parent
+        // (counting the preceding siblings of every parent would be quadratic)
+        if node_hierarchy[*parent_id].parent.is_none() {
+            // Note: :nth-child() starts at 1 instead of 0
+            let index_in_parent = parent_id.preceding_siblings(node_hierarchy).count();
+
+            let parent_html_matcher = CascadeInfo {
+                index_in_parent: (index_in_parent - 1) as u32,
+                is_last_child: node_hierarchy[*parent_id].next_sibling.is_none(), // Necessary for :last selectors
+            };
 
-        nodes[parent_id.index()] = parent_html_matcher;
+            nodes[parent_id.index()] = parent_html_matcher;
+        }
 
         for (child_idx, child_id) in parent_id.children(node_hierarchy).enumerate() {
             let child_html_matcher = CascadeInfo {
@@ -241,152 +278,50 @@ pub fn rule_ends_with(path: &CssPath, target: Option<CssPathPseudoSelector>) ->
     }
 }
 
-/// Matches a single group of items, panics on Children or DirectChildren selectors
-///
-/// The intent is to "split" the CSS path into groups by selectors, then store and cache
-/// whether the direct or any parent has matched the path correctly
-pub(crate) fn selector_group_matches(
-    selectors: &[&CssPathSelector],
-    html_node: &CascadeInfo,
-    node_data: &NodeData,
-    expected_path_ending: Option<CssPathPseudoSelector>,
-    is_last_content_group: bool,
-) -> bool {
-    use self::CssPathSelector::*;
-
-    for selector in selectors {
-        match selector {
-            Global => {}
-            Type(t) => {
-                if node_data.get_node_type().get_path() != *t {
-                    return false;
-                }
-            }
-            Class(c) => {
-                if !node_data
-                    .get_ids_and_classes()
-                    .iter()
-                    .filter_map(|i| i.as_class())
-                    .any(|class| class == c.as_str())
-                {
-                    return false;
-                }
-            }
-            Id(id) => {
-                if !node_data
-                    .get_ids_and_classes()
-                    .iter()
-                    .filter_map(|i| i.as_id())
-                    .any(|html_id| html_id == id.as_str())
-                {
-                    return false;
-                }
-            }
-            PseudoSelector(p) => {
-                match p {
-                    CssPathPseudoSelector::First => {
-                        // Notice: index_in_parent is 1-indexed
-                        if html_node.index_in_parent != 0 {
-                            return false;
-                        }
-                    }
-                    CssPathPseudoSelector::Last => {
-                        // Notice: index_in_parent is 1-indexed
-                        if !html_node.is_last_child {
-                            return false;
-                        }
-                    }
-                    CssPathPseudoSelector::NthChild(x) => {
-                        use azul_css::CssNthChildPattern;
-                        let index_in_parent = html_node.index_in_parent + 1; // nth-child starts at 1!
-                        match *x {
-                            Number(value) => {
-                                if index_in_parent != value {
-                                    return false;
-                                }
-                            }
-                            Even => {
-                                if index_in_parent % 2 == 0 {
-                                    return false;
-                                }
-                            }
-                            Odd => {
-                                if index_in_parent % 2 == 1 {
-                                    return false;
-                                }
-                            }
-                            Pattern(CssNthChildPattern { repeat, offset }) => {
-                                if index_in_parent >= offset
-                                    && ((index_in_parent - offset) % repeat != 0)
-                                {
-                                    return false;
-                                }
-                            }
-                        }
-                    }
-
-                    // NOTE: for all other selectors such as :hover, :focus and :active,
-                    // we can only apply them if they appear in the last content group,
-                    // i.e. this will match "body > #main:hover", but not "body:hover > #main"
-                    CssPathPseudoSelector::Hover => {
-                        if !is_last_content_group {
-                            return false;
-                        }
-                        if expected_path_ending != Some(CssPathPseudoSelector::Hover) {
-                            return false;
-                        }
-                    }
-                    CssPathPseudoSelector::Active => {
-                        if !is_last_content_group {
-                            return false;
-                        }
-                        if expected_path_ending != Some(CssPathPseudoSelector::Active) {
-                            return false;
-                        }
-                    }
-                    CssPathPseudoSelector::Focus => {
-                        if !is_last_content_group {
-                            return false;
-                        }
-                        if expected_path_ending != Some(CssPathPseudoSelector::Focus) {
-                            return false;
-                        }
-                    }
-                }
-            }
-            DirectChildren | Children => {
-                // panic!("Unreachable: DirectChildren or Children in CSS path!");
-                return false;
-            }
-        }
-    }
-
-    true
-}
-
 #[test]
 fn test_case_issue_93() {
     use crate::dom::*;
     use azul_css::CssPathSelector::*;
     use azul_css::*;
 
-    fn render_tab() -> Dom {
-        Dom::div()
-            .with_class("tabwidget-tab")
-            .with_child(Dom::label("").with_class("tabwidget-tab-label"))
-            .with_child(Dom::label("").with_class("tabwidget-tab-close"))
+    fn class(class: &str) -> IdOrClass {
+        IdOrClass::Class(class.to_string().into())
     }
 
-    let dom = Dom::div().with_id("editor-rooms").with_child(
+    fn render_tab(active: bool) -> Dom {
+        let mut classes = vec![class("tabwidget-tab")];
+        if active {
+            classes.push(class("active"));
+        }
         Dom::div()
-            .with_class("tabwidget-bar")
-            .with_child(render_tab().with_class("active"))
-            .with_child(render_tab())
-            .with_child(render_tab())
-            .with_child(render_tab()),
-    );
+            .with_ids_and_classes(classes.into())
+            .with_children(
+                vec![
+                    Dom::text("").with_ids_and_classes(vec![class("tabwidget-tab-label")].into()),
+                    Dom::text("").with_ids_and_classes(vec![class("tabwidget-tab-close")].into()),
+                ]
+                .into(),
+            )
+    }
+
+    let dom = Dom::div()
+        .with_ids_and_classes(vec![IdOrClass::Id("editor-rooms".to_string().into())].into())
+        .with_children(
+            vec![Dom::div()
+                .with_ids_and_classes(vec![class("tabwidget-bar")].into())
+                .with_children(
+                    vec![
+                        render_tab(true),
+                        render_tab(false),
+                        render_tab(false),
+                        render_tab(false),
+                    ]
+                    .into(),
+                )]
+            .into(),
+        );
 
-    let dom = convert_dom_into_compact_dom(dom);
+    let dom: CompactDom = dom.into();
 
     let tab_active_close = CssPath {
         selectors: vec![
@@ -398,16 +333,27 @@ fn test_case_issue_93() {
         .into(),
     };
 
-    let node_hierarchy = &dom.arena.node_hierarchy;
-    let node_data = &dom.arena.node_data;
-    let nodes_sorted: Vec<_> = node_hierarchy.get_parents_sorted_by_depth();
-    let html_node_tree = construct_html_cascade_tree(
-        &node_hierarchy,
-        &nodes_sorted,
-        None,
-        &BTreeMap::new(),
-        false,
-    );
+    let nodes_sorted = dom.node_hierarchy.as_ref().get_parents_sorted_by_depth();
+    let html_node_tree = construct_html_cascade_tree(&dom.node_hierarchy.as_ref(), &nodes_sorted);
+    let node_hierarchy = dom
+        .node_hierarchy
+        .as_ref()
+        .internal
+        .iter()
+        .map(|n| NodeHierarchyItem::from(*n))
+        .collect::<Vec<_>>();
+    let node_hierarchy = NodeDataContainerRef::from_slice(&node_hierarchy);
+    let node_data = dom.node_data.as_ref();
+    let matches = |path: &CssPath, node_id: usize, expected_path_ending| {
+        self::matches_html_element(
+            path,
+            NodeId::new(node_id),
+            &node_hierarchy,
+            &node_data,
+            &html_node_tree.as_ref(),
+            expected_path_ending,
+        )
+    };
 
     //  rules: [
     //    ".tabwidget-tab-label"                        : ColorU::BLACK,
@@ -434,31 +380,46 @@ fn test_case_issue_93() {
     // ".tabwidget-tab.active .tabwidget-tab-label"
     // should not match
     // ".tabwidget-tab.active .tabwidget-tab-close"
-    assert_eq!(
-        matches_html_element(
-            &tab_active_close,
-            NodeId::new(3),
-            &node_hierarchy,
-            &node_data,
-            &html_node_tree
-        ),
-        false
-    );
+    assert_eq!(matches(&tab_active_close, 3, None), false);
 
     // Test 2:
     // ".tabwidget-tab.active .tabwidget-tab-close"
     // should match
     // ".tabwidget-tab.active .tabwidget-tab-close"
-    assert_eq!(
-        matches_html_element(
-            &tab_active_close,
-            NodeId::new(4),
-            &node_hierarchy,
-            &node_data,
-            &html_node_tree
-        ),
-        true
-    );
+    assert_eq!(matches(&tab_active_close, 4, None), true);
+
+    // Test 3: the close button of an inactive tab doesn't match
+    assert_eq!(matches(&tab_active_close, 7, None), false);
+
+    // Test 4: "#editor-rooms > .tabwidget-bar > .tabwidget-tab:hover" only
+    // matches the tabs and only when matching the :hover styles
+    let tab_hover = CssPath {
+        selectors: vec![
+            Id("editor-rooms".to_string().into()),
+            DirectChildren,
+            Class("tabwidget-bar".to_string().into()),
+            DirectChildren,
+            Class("tabwidget-tab".to_string().into()),
+            PseudoSelector(CssPathPseudoSelector::Hover),
+        ]
+        .into(),
+    };
+    assert_eq!(matches(&tab_hover, 5, Some(CssPathPseudoSelector::Hover)), true);
+    assert_eq!(matches(&tab_hover, 5, None), false);
+    assert_eq!(matches(&tab_hover, 5, Some(CssPathPseudoSelector::Focus)), false);
+    assert_eq!(matches(&tab_hover, 6, Some(CssPathPseudoSelector::Hover)), false);
+
+    // Test 5: ":hover" on a parent group never matches
+    let bar_hover_tab = CssPath {
+        selectors: vec![
+            Class("tabwidget-bar".to_string().into()),
+            PseudoSelector(CssPathPseudoSelector::Hover),
+            Children,
+            Class("tabwidget-tab".to_string().into()),
+        ]
+        .into(),
+    };
+    assert_eq!(matches(&bar_hover_tab, 5, Some(CssPathPseudoSelector::Hover)), false);
 }
 
 #[test]
@@ -1,44 +1,52 @@
 use crate::{
     app_resources::{Au, ImageCache, ImageRef, ImmediateFontId, RendererResources},
     callbacks::{CallbackInfo, RefAny, Update},
+    diff::DomDiff,
     dom::{
         CompactDom, Dom, NodeData, NodeDataInlineCssProperty, NodeDataVec, OptionTabIndex,
         TabIndex, TagId,
     },
     id_tree::{Node, NodeDataContainer, NodeDataContainerRef, NodeDataContainerRefMut, NodeId},
     style::{
-        construct_html_cascade_tree, matches_html_element, rule_ends_with, CascadeInfo,
-        CascadeInfoVec,
+        construct_html_cascade_tree, html_cascade_chain, matches_cascade_chain, rule_ends_with,
+        CascadeInfo, CascadeInfoVec, NodeIdsAndClasses,
     },
     window::Menu,
+    window_state::{RelayoutNodes, RelayoutWords},
     FastBTreeSet, FastHashMap,
 };
 use alloc::boxed::Box;
 use alloc::collections::btree_map::BTreeMap;
 use alloc::string::String;
 use alloc::vec::Vec;
+use azul_css::MediaEnvironment;
 use azul_css::{
-    AzString, Css, CssPath, CssProperty, CssPropertyType, LayoutAlignContentValue,
+    AzString, Css, CssPath, CssProperty, CssPropertyType, CssPropertyVec, LayoutAlignContentValue,
     LayoutAlignItemsValue, LayoutBorderBottomWidthValue, LayoutBorderLeftWidthValue,
     LayoutBorderRightWidthValue, LayoutBorderTopWidthValue, LayoutBottomValue,
     LayoutBoxSizingValue, LayoutDisplayValue, LayoutFlexDirectionValue, LayoutFlexGrowValue,
-    LayoutFlexShrinkValue, LayoutFlexWrapValue, LayoutFloatValue, LayoutHeightValue,
-    LayoutJustifyContentValue, LayoutLeftValue, LayoutMarginBottomValue, LayoutMarginLeftValue,
-    LayoutMarginRightValue, LayoutMarginTopValue, LayoutMaxHeightValue, LayoutMaxWidthValue,
-    LayoutMinHeightValue, LayoutMinWidthValue, LayoutOverflowValue, LayoutPaddingBottomValue,
-    LayoutPaddingLeftValue, LayoutPaddingRightValue, LayoutPaddingTopValue, LayoutPositionValue,
-    LayoutRightValue, LayoutTopValue, LayoutWidthValue, StyleBackfaceVisibilityValue,
-    StyleBackgroundContentVecValue, StyleBackgroundPositionVecValue, StyleBackgroundRepeatVecValue,
-    StyleBackgroundSizeVecValue, StyleBorderBottomColorValue, StyleBorderBottomLeftRadiusValue,
-    StyleBorderBottomRightRadiusValue, StyleBorderBottomStyleValue, StyleBorderLeftColorValue,
-    StyleBorderLeftStyleValue, StyleBorderRightColorValue, StyleBorderRightStyleValue,
-    StyleBorderTopColorValue, StyleBorderTopLeftRadiusValue, StyleBorderTopRightRadiusValue,
-    StyleBorderTopStyleValue, StyleBoxShadowValue, StyleCursorValue, StyleFilterVecValue,
-    StyleFontFamily, StyleFontFamilyVec, StyleFontFamilyVecValue, StyleFontSize,
-    StyleFontSizeValue, StyleLetterSpacingValue, StyleLineHeightValue, StyleMixBlendModeValue,
-    StyleOpacityValue, StylePerspectiveOriginValue, StyleTabWidthValue, StyleTextAlignValue,
-    StyleTextColor, StyleTextColorValue, StyleTransformOriginValue, StyleTransformVecValue,
-    StyleWordSpacingValue,
+    LayoutFlexShrinkValue, LayoutFlexWrapValue, LayoutFloatValue, LayoutGridColumnValue,
+    LayoutGridRowValue, LayoutGridTemplateColumnsValue, LayoutGridTemplateRowsValue,
+    LayoutHeightValue, LayoutJustifyContentValue, LayoutLeftValue, LayoutMarginBottomValue,
+    LayoutMarginLeftValue, LayoutMarginRightValue, LayoutMarginTopValue, LayoutMaxHeightValue,
+    LayoutMaxWidthValue, LayoutMinHeightValue, LayoutMinWidthValue, LayoutOverflowValue,
+    LayoutPaddingBottomValue, LayoutPaddingLeftValue, LayoutPaddingRightValue,
+    LayoutPaddingTopValue, LayoutPositionValue, LayoutRightValue, LayoutTopValue, LayoutWidthValue,
+    StyleBackfaceVisibilityValue, StyleBackgroundContentVecValue, StyleBackgroundPositionVecValue,
+    StyleBackgroundRepeatVecValue, StyleBackgroundSizeVecValue, StyleBorderBottomColorValue,
+    StyleBorderBottomLeftRadiusValue, StyleBorderBottomRightRadiusValue,
+    StyleBorderBottomStyleValue, StyleBorderLeftColorValue, StyleBorderLeftStyleValue,
+    StyleBorderRightColorValue, StyleBorderRightStyleValue, StyleBorderTopColorValue,
+    StyleBorderTopLeftRadiusValue, StyleBorderTopRightRadiusValue, StyleBorderTopStyleValue,
+    StyleBoxShadowValue, StyleCaretColorValue, StyleCursorValue, StyleDirectionValue,
+    StyleFilterVecValue, StyleFontFamily, StyleFontFamilyVec, StyleFontFamilyVecValue,
+    StyleFontSize, StyleFontSizeValue, StyleImageRenderingValue, StyleIsolationValue,
+    StyleLetterSpacingValue, StyleLineHeightValue, StyleMixBlendModeValue, StyleOpacityValue,
+    StylePerspectiveOriginValue, StyleSelectionBackgroundValue, StyleSelectionColorValue,
+    StyleTabWidthValue, StyleTextAlignValue, StyleTextColor, StyleTextColorValue,
+    StyleTransformOriginValue, StyleTransformVecValue, StyleTransitionDelayValue,
+    StyleTransitionDurationValue, StyleTransitionPropertyValue,
+    StyleTransitionTimingFunctionValue, StyleVerticalAlignValue, StyleWordSpacingValue,
 };
 use azul_css_parser::CssApiWrapper;
 use core::{
@@ -211,18 +219,71 @@ pub struct CssPropertyCache {
     pub css_hover_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_active_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub css_focus_props: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+
+    // stylesheet of the DOM if it contains `@media` queries, so that the DOM
+    // can be restyled when the window changes (see `StyledDom::set_media_environment`)
+    pub media_css: Option<Css>,
+    // environment that the `@media` queries were evaluated against
+    pub media_environment: MediaEnvironment,
+}
+
+/// Hash over which `@media` rule blocks of the (sorted) stylesheet match
+/// the environment: restyling is only necessary if the hash changes
+fn get_active_media_hash(css: &Css, media_environment: &MediaEnvironment) -> u64 {
+    use crate::css::GetHash;
+    css.rules()
+        .filter(|rule_block| rule_block.media.is_some())
+        .map(|rule_block| rule_block.matches_media(media_environment))
+        .collect::<Vec<bool>>()
+        .get_hash()
+}
+
+/// Matched CSS rules of a previous `CssPropertyCache` that can be reused
+/// when restyling, see `StyledDom::new_incremental`
+struct CssMatchReuse<'a> {
+    previous: &'a CssPropertyCache,
+    /// For every node of the new DOM: the node ID in the previous DOM, if
+    /// the same CSS rules are guaranteed to match both nodes
+    previous_node_ids: Vec<Option<NodeId>>,
+    /// Same as `previous_node_ids`, but only if the inline CSS of the node
+    /// and its parents is unchanged as well, so that both nodes inherit
+    /// the same properties
+    previous_cascade_node_ids: Vec<Option<NodeId>>,
 }
 
 impl CssPropertyCache {
-    /// Restyles the CSS property cache with a new CSS file
+    /// Restyles the CSS property cache with a new CSS file, rule blocks of
+    /// `@media` groups that don't match the `media_environment` are skipped
     #[must_use]
     pub fn restyle(
         &mut self,
         css: &mut Css,
+        media_environment: &MediaEnvironment,
         node_data: &NodeDataContainerRef<NodeData>,
         node_hierarchy: &NodeHierarchyItemVec,
         non_leaf_nodes: &ParentWithNodeDepthVec,
         html_tree: &NodeDataContainerRef<CascadeInfo>,
+    ) -> Vec<TagIdToNodeIdMapping> {
+        self.restyle_internal(
+            css,
+            media_environment,
+            node_data,
+            node_hierarchy,
+            non_leaf_nodes,
+            html_tree,
+            None,
+        )
+    }
+
+    fn restyle_internal(
+        &mut self,
+        css: &mut Css,
+        media_environment: &MediaEnvironment,
+        node_data: &NodeDataContainerRef<NodeData>,
+        node_hierarchy: &NodeHierarchyItemVec,
+        non_leaf_nodes: &ParentWithNodeDepthVec,
+        html_tree: &NodeDataContainerRef<CascadeInfo>,
+        reuse: Option<&CssMatchReuse>,
     ) -> Vec<TagIdToNodeIdMapping> {
         use azul_css::CssDeclaration;
         use azul_css::CssPathPseudoSelector::*;
@@ -231,29 +292,47 @@ impl CssPropertyCache {
 
         let css_is_empty = css.is_empty();
 
+        self.media_environment = *media_environment;
+        self.media_css = if css.has_media_queries() {
+            Some(css.clone())
+        } else {
+            None
+        };
+
         if !css_is_empty {
             css.sort_by_specificity();
+            let css_variables = &css.variables;
+
+            // the ids and classes of each node, split up once for all rules
+            let ids_and_classes = node_data.transform_nodeid(|node_id| {
+                NodeIdsAndClasses::new(&node_data[node_id])
+            });
 
             macro_rules! filter_rules {($expected_pseudo_selector:expr, $node_id:expr) => {{
-                css
-                .rules() // can not be parallelized due to specificity order matching
-                .filter(|rule_block| rule_ends_with(&rule_block.path, $expected_pseudo_selector))
-                .filter(|rule_block| matches_html_element(
-                    &rule_block.path,
+                let chain = html_cascade_chain(
                     $node_id,
                     &node_hierarchy.as_container(),
                     &node_data,
                     &html_tree,
+                    &ids_and_classes.as_ref(),
+                );
+                css
+                .rules() // can not be parallelized due to specificity order matching
+                .filter(|rule_block| rule_block.matches_media(media_environment))
+                .filter(|rule_block| rule_ends_with(&rule_block.path, $expected_pseudo_selector))
+                .filter(|rule_block| matches_cascade_chain(
+                    &rule_block.path,
+                    &chain,
                     $expected_pseudo_selector
                 ))
                 // rule matched, now copy all the styles of this rule
                 .flat_map(|matched_rule| {
                     matched_rule.declarations
                     .iter()
-                    .filter_map(move |declaration| {
+                    .map(move |declaration| {
                         match declaration {
-                            CssDeclaration::Static(s) => Some(s),
-                            CssDeclaration::Dynamic(_d) => None, // TODO: No variable support yet!
+                            CssDeclaration::Static(s) => s,
+                            CssDeclaration::Dynamic(d) => d.get_value(css_variables),
                         }
                     })
                 })
@@ -261,6 +340,29 @@ impl CssPropertyCache {
                 .collect::<Vec<CssProperty>>()
             }};}
 
+            // Same as filter_rules, but returns the properties by type (later
+            // properties override earlier ones) and copies the matched rules
+            // from the previous cache if the node is unchanged
+            macro_rules! match_rules {
+                ($expected_pseudo_selector:expr, $previous_props:ident, $node_id:expr) => {{
+                    let previous = reuse.and_then(|r| {
+                        r.previous_node_ids[$node_id.index()]
+                            .map(|old_node_id| (r.previous, old_node_id))
+                    });
+                    match previous {
+                        Some((previous, old_node_id)) => previous
+                            .$previous_props
+                            .get(&old_node_id)
+                            .cloned()
+                            .unwrap_or_default(),
+                        None => filter_rules!($expected_pseudo_selector, $node_id)
+                            .into_iter()
+                            .map(|prop| (prop.get_type(), prop))
+                            .collect::<BTreeMap<CssPropertyType, CssProperty>>(),
+                    }
+                }};
+            }
+
             // NOTE: This is wrong, but fast
             //
             // Get all nodes that end with `:hover`, `:focus` or `:active`
@@ -269,10 +371,12 @@ impl CssPropertyCache {
             // NOTE: This won't work correctly for paths with `.blah:hover > #thing`
             // but that can be fixed later
 
+            type MatchedProps = NodeDataContainer<(NodeId, BTreeMap<CssPropertyType, CssProperty>)>;
+
             // go through each HTML node (in parallel) and see which CSS rules match
-            let css_normal_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+            let css_normal_rules: MatchedProps = node_data
                 .transform_nodeid_multithreaded_optional(|node_id| {
-                    let r = filter_rules!(None, node_id);
+                    let r = match_rules!(None, css_normal_props, node_id);
                     if r.is_empty() {
                         None
                     } else {
@@ -280,9 +384,9 @@ impl CssPropertyCache {
                     }
                 });
 
-            let css_hover_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+            let css_hover_rules: MatchedProps = node_data
                 .transform_nodeid_multithreaded_optional(|node_id| {
-                    let r = filter_rules!(Some(Hover), node_id);
+                    let r = match_rules!(Some(Hover), css_hover_props, node_id);
                     if r.is_empty() {
                         None
                     } else {
@@ -290,9 +394,9 @@ impl CssPropertyCache {
                     }
                 });
 
-            let css_active_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+            let css_active_rules: MatchedProps = node_data
                 .transform_nodeid_multithreaded_optional(|node_id| {
-                    let r = filter_rules!(Some(Active), node_id);
+                    let r = match_rules!(Some(Active), css_active_props, node_id);
                     if r.is_empty() {
                         None
                     } else {
@@ -300,9 +404,9 @@ impl CssPropertyCache {
                     }
                 });
 
-            let css_focus_rules: NodeDataContainer<(NodeId, Vec<CssProperty>)> = node_data
+            let css_focus_rules: MatchedProps = node_data
                 .transform_nodeid_multithreaded_optional(|node_id| {
-                    let r = filter_rules!(Some(Focus), node_id);
+                    let r = match_rules!(Some(Focus), css_focus_props, node_id);
                     if r.is_empty() {
                         None
                     } else {
@@ -310,57 +414,36 @@ impl CssPropertyCache {
                     }
                 });
 
-            self.css_normal_props = css_normal_rules
-                .internal
-                .into_iter()
-                .map(|(n, map)| {
-                    (
-                        n,
-                        map.into_iter()
-                            .map(|prop| (prop.get_type(), prop))
-                            .collect(),
-                    )
-                })
-                .collect();
-
-            self.css_hover_props = css_hover_rules
-                .internal
-                .into_iter()
-                .map(|(n, map)| {
-                    (
-                        n,
-                        map.into_iter()
-                            .map(|prop| (prop.get_type(), prop))
-                            .collect(),
-                    )
-                })
-                .collect();
-
-            self.css_active_props = css_active_rules
-                .internal
-                .into_iter()
-                .map(|(n, map)| {
-                    (
-                        n,
-                        map.into_iter()
-                            .map(|prop| (prop.get_type(), prop))
-                            .collect(),
-                    )
-                })
-                .collect();
-
-            self.css_focus_props = css_focus_rules
-                .internal
-                .into_iter()
-                .map(|(n, map)| {
-                    (
-                        n,
-                        map.into_iter()
-                            .map(|prop| (prop.get_type(), prop))
-                            .collect(),
-                    )
-                })
-                .collect();
+            self.css_normal_props = css_normal_rules.internal.into_iter().collect();
+
+            self.css_hover_props = css_hover_rules.internal.into_iter().collect();
+
+            self.css_active_props = css_active_rules.internal.into_iter().collect();
+
+            self.css_focus_props = css_focus_rules.internal.into_iter().collect();
+        }
+
+        // Copy the inherited properties of the nodes that inherit the same
+        // properties as in the previous DOM, only the subtrees that changed
+        // have to be cascaded again
+        if let Some(r) = reuse {
+            for (new_id, old_id) in r.previous_cascade_node_ids.iter().enumerate() {
+                let (new_id, old_id) = match old_id {
+                    Some(old_id) => (NodeId::new(new_id), *old_id),
+                    None => continue,
+                };
+                let maps = [
+                    (&mut self.cascaded_normal_props, &r.previous.cascaded_normal_props),
+                    (&mut self.cascaded_hover_props, &r.previous.cascaded_hover_props),
+                    (&mut self.cascaded_active_props, &r.previous.cascaded_active_props),
+                    (&mut self.cascaded_focus_props, &r.previous.cascaded_focus_props),
+                ];
+                for (map, previous_map) in maps {
+                    if let Some(props) = previous_map.get(&old_id) {
+                        map.insert(new_id, props.clone());
+                    }
+                }
+            }
         }
 
         // Inheritance: Inherit all values of the parent to the children, but
@@ -371,6 +454,17 @@ impl CssPropertyCache {
                 None => continue,
             };
 
+            // the properties of the children were copied from the previous cache
+            let children_unchanged = reuse.map_or(false, |r| {
+                parent_id
+                    .az_children(&node_hierarchy.as_container())
+                    .all(|child_id| r.previous_cascade_node_ids[child_id.index()].is_some())
+            });
+
+            if children_unchanged {
+                continue;
+            }
+
             // Inherit CSS properties from map A -> map B
             // map B will be populated with all inherited CSS properties
             macro_rules! inherit_props {($from_inherit_map:expr, $to_inherit_map:expr) => {
@@ -413,7 +507,7 @@ impl CssPropertyCache {
                  // test whether the property is a [normal, hover, focus, active] property
                 .filter_map(|css_prop| if let NodeDataInlineCssProperty::$filter_type(p) = css_prop { Some(p) } else { None })
                 // test whether the property is inheritable
-                .filter(|css_prop| css_prop.get_type().is_inheritable())
+                .filter(|css_prop| css_prop.is_inheritable())
                 .cloned()
                 .collect::<Vec<CssProperty>>();
 
@@ -639,6 +733,9 @@ impl CssPropertyCache {
         if let Some(p) = self.get_text_align(&node_data, node_id, node_state) {
             s.push_str(&format!("text-align: {};", p.get_css_value_fmt()));
         }
+        if let Some(p) = self.get_direction(&node_data, node_id, node_state) {
+            s.push_str(&format!("direction: {};", p.get_css_value_fmt()));
+        }
         if let Some(p) = self.get_line_height(&node_data, node_id, node_state) {
             s.push_str(&format!("line-height: {};", p.get_css_value_fmt()));
         }
@@ -738,6 +835,36 @@ impl CssPropertyCache {
         if let Some(p) = self.get_backface_visibility(&node_data, node_id, node_state) {
             s.push_str(&format!("backface-visibility: {};", p.get_css_value_fmt()));
         }
+        if let Some(p) = self.get_mix_blend_mode(&node_data, node_id, node_state) {
+            s.push_str(&format!("mix-blend-mode: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_isolation(&node_data, node_id, node_state) {
+            s.push_str(&format!("isolation: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_image_rendering(&node_data, node_id, node_state) {
+            s.push_str(&format!("image-rendering: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_transition_property(&node_data, node_id, node_state) {
+            s.push_str(&format!("transition-property: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_transition_duration(&node_data, node_id, node_state) {
+            s.push_str(&format!("transition-duration: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_transition_timing_function(&node_data, node_id, node_state) {
+            s.push_str(&format!("transition-timing-function: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_transition_delay(&node_data, node_id, node_state) {
+            s.push_str(&format!("transition-delay: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_caret_color(&node_data, node_id, node_state) {
+            s.push_str(&format!("caret-color: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_selection_color(&node_data, node_id, node_state) {
+            s.push_str(&format!("-azul-selection-color: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_selection_background(&node_data, node_id, node_state) {
+            s.push_str(&format!("-azul-selection-background-color: {};", p.get_css_value_fmt()));
+        }
         if let Some(p) = self.get_display(&node_data, node_id, node_state) {
             s.push_str(&format!("display: {};", p.get_css_value_fmt()));
         }
@@ -843,6 +970,21 @@ impl CssPropertyCache {
         if let Some(p) = self.get_align_content(&node_data, node_id, node_state) {
             s.push_str(&format!("align-content: {};", p.get_css_value_fmt()));
         }
+        if let Some(p) = self.get_grid_template_columns(&node_data, node_id, node_state) {
+            s.push_str(&format!(
+                "grid-template-columns: {};",
+                p.get_css_value_fmt()
+            ));
+        }
+        if let Some(p) = self.get_grid_template_rows(&node_data, node_id, node_state) {
+            s.push_str(&format!("grid-template-rows: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_grid_column(&node_data, node_id, node_state) {
+            s.push_str(&format!("grid-column: {};", p.get_css_value_fmt()));
+        }
+        if let Some(p) = self.get_grid_row(&node_data, node_id, node_state) {
+            s.push_str(&format!("grid-row: {};", p.get_css_value_fmt()));
+        }
         s
     }
 }
@@ -902,6 +1044,9 @@ impl CssPropertyCache {
             css_hover_props: BTreeMap::new(),
             css_active_props: BTreeMap::new(),
             css_focus_props: BTreeMap::new(),
+
+            media_css: None,
+            media_environment: MediaEnvironment::default(),
         }
     }
 
@@ -927,6 +1072,10 @@ impl CssPropertyCache {
         append_css_property_vec!(css_active_props);
         append_css_property_vec!(css_focus_props);
 
+        // the nodes of `other` were styled with a different stylesheet,
+        // restyling the combined DOM with only one of them would be wrong
+        self.media_css = None;
+
         self.node_count += other.node_count;
     }
 
@@ -1006,7 +1155,7 @@ impl CssPropertyCache {
 
         font_family_opt
             .as_ref()
-            .and_then(|family| Some(family.get_property()?.clone()))
+            .and_then(|family| Some((**family.get_property()?).clone()))
             .unwrap_or(default_font_id)
     }
 
@@ -1060,12 +1209,61 @@ impl CssPropertyCache {
                 .is_some()
     }
 
+    /// Returns whether the properties that the stylesheet, the parent
+    /// nodes or the callbacks set on the node differ from the ones in
+    /// `other` (the inline CSS of the node itself is not compared)
+    fn node_props_differ(&self, other: &CssPropertyCache, node_id: &NodeId) -> bool {
+        fn maps(
+            cache: &CssPropertyCache,
+        ) -> [&BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>; 9] {
+            [
+                &cache.user_overridden_properties,
+                &cache.cascaded_normal_props,
+                &cache.cascaded_hover_props,
+                &cache.cascaded_active_props,
+                &cache.cascaded_focus_props,
+                &cache.css_normal_props,
+                &cache.css_hover_props,
+                &cache.css_active_props,
+                &cache.css_focus_props,
+            ]
+        }
+
+        maps(self)
+            .iter()
+            .zip(maps(other).iter())
+            .any(|(a, b)| a.get(node_id) != b.get(node_id))
+    }
+
     pub fn get_property<'a>(
         &'a self,
         node_data: &'a NodeData,
         node_id: &NodeId,
         node_state: &StyledNodeState,
         css_property_type: &CssPropertyType,
+    ) -> Option<&CssProperty> {
+        self.get_property_impl(node_data, node_id, node_state, css_property_type, true)
+    }
+
+    /// Same as `get_property`, but ignores the values inherited from the
+    /// parent (i.e. only returns values set on the node itself)
+    fn get_own_property<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+        css_property_type: &CssPropertyType,
+    ) -> Option<&CssProperty> {
+        self.get_property_impl(node_data, node_id, node_state, css_property_type, false)
+    }
+
+    fn get_property_impl<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+        css_property_type: &CssPropertyType,
+        include_inherited: bool,
     ) -> Option<&CssProperty> {
         // NOTE: This function is slow, but it is going to be called on every
         // node in parallel, so it should be rather fast in the end
@@ -1110,12 +1308,14 @@ impl CssPropertyCache {
                 return Some(p);
             }
 
-            if let Some(p) = self
-                .cascaded_focus_props
-                .get(node_id)
-                .and_then(|map| map.get(css_property_type))
-            {
-                return Some(p);
+            if include_inherited {
+                if let Some(p) = self
+                    .cascaded_focus_props
+                    .get(node_id)
+                    .and_then(|map| map.get(css_property_type))
+                {
+                    return Some(p);
+                }
             }
         }
 
@@ -1144,12 +1344,14 @@ impl CssPropertyCache {
                 return Some(p);
             }
 
-            if let Some(p) = self
-                .cascaded_active_props
-                .get(node_id)
-                .and_then(|map| map.get(css_property_type))
-            {
-                return Some(p);
+            if include_inherited {
+                if let Some(p) = self
+                    .cascaded_active_props
+                    .get(node_id)
+                    .and_then(|map| map.get(css_property_type))
+                {
+                    return Some(p);
+                }
             }
         }
 
@@ -1178,12 +1380,14 @@ impl CssPropertyCache {
                 return Some(p);
             }
 
-            if let Some(p) = self
-                .cascaded_hover_props
-                .get(node_id)
-                .and_then(|map| map.get(css_property_type))
-            {
-                return Some(p);
+            if include_inherited {
+                if let Some(p) = self
+                    .cascaded_hover_props
+                    .get(node_id)
+                    .and_then(|map| map.get(css_property_type))
+                {
+                    return Some(p);
+                }
             }
         }
 
@@ -1212,12 +1416,14 @@ impl CssPropertyCache {
                 return Some(p);
             }
 
-            if let Some(p) = self
-                .cascaded_normal_props
-                .get(node_id)
-                .and_then(|map| map.get(css_property_type))
-            {
-                return Some(p);
+            if include_inherited {
+                if let Some(p) = self
+                    .cascaded_normal_props
+                    .get(node_id)
+                    .and_then(|map| map.get(css_property_type))
+                {
+                    return Some(p);
+                }
             }
         }
 
@@ -1317,6 +1523,24 @@ impl CssPropertyCache {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::TextAlign)
             .and_then(|p| p.as_text_align())
     }
+    pub fn get_vertical_align<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleVerticalAlignValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::VerticalAlign)
+            .and_then(|p| p.as_vertical_align())
+    }
+    pub fn get_direction<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleDirectionValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::Direction)
+            .and_then(|p| p.as_style_direction())
+    }
     pub fn get_line_height<'a>(
         &'a self,
         node_data: &'a NodeData,
@@ -2031,6 +2255,52 @@ impl CssPropertyCache {
         )
         .and_then(|p| p.as_align_content())
     }
+    pub fn get_grid_template_columns<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a LayoutGridTemplateColumnsValue> {
+        self.get_property(
+            node_data,
+            node_id,
+            node_state,
+            &CssPropertyType::GridTemplateColumns,
+        )
+        .and_then(|p| p.as_grid_template_columns())
+    }
+    pub fn get_grid_template_rows<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a LayoutGridTemplateRowsValue> {
+        self.get_property(
+            node_data,
+            node_id,
+            node_state,
+            &CssPropertyType::GridTemplateRows,
+        )
+        .and_then(|p| p.as_grid_template_rows())
+    }
+    pub fn get_grid_column<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a LayoutGridColumnValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridColumn)
+            .and_then(|p| p.as_grid_column())
+    }
+    pub fn get_grid_row<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a LayoutGridRowValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridRow)
+            .and_then(|p| p.as_grid_row())
+    }
     pub fn get_mix_blend_mode<'a>(
         &'a self,
         node_data: &'a NodeData,
@@ -2045,6 +2315,29 @@ impl CssPropertyCache {
         )
         .and_then(|p| p.as_mix_blend_mode())
     }
+    pub fn get_isolation<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleIsolationValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::Isolation)
+            .and_then(|p| p.as_isolation())
+    }
+    pub fn get_image_rendering<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleImageRenderingValue> {
+        self.get_property(
+            node_data,
+            node_id,
+            node_state,
+            &CssPropertyType::ImageRendering,
+        )
+        .and_then(|p| p.as_image_rendering())
+    }
     pub fn get_filter<'a>(
         &'a self,
         node_data: &'a NodeData,
@@ -2072,6 +2365,69 @@ impl CssPropertyCache {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::TextShadow)
             .and_then(|p| p.as_text_shadow())
     }
+    pub fn get_transition_property<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleTransitionPropertyValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionProperty)
+            .and_then(|p| p.as_transition_property())
+    }
+    pub fn get_transition_duration<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleTransitionDurationValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionDuration)
+            .and_then(|p| p.as_transition_duration())
+    }
+    pub fn get_transition_timing_function<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleTransitionTimingFunctionValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionTimingFunction)
+            .and_then(|p| p.as_transition_timing_function())
+    }
+    pub fn get_transition_delay<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleTransitionDelayValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionDelay)
+            .and_then(|p| p.as_transition_delay())
+    }
+    pub fn get_caret_color<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleCaretColorValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::CaretColor)
+            .and_then(|p| p.as_caret_color())
+    }
+    pub fn get_selection_color<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleSelectionColorValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::SelectionColor)
+            .and_then(|p| p.as_selection_color())
+    }
+    pub fn get_selection_background<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+    ) -> Option<&'a StyleSelectionBackgroundValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::SelectionBackground)
+            .and_then(|p| p.as_selection_background())
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -2390,13 +2746,241 @@ impl Default for StyledDom {
     }
 }
 
+/// Returns for every node of the new DOM the node ID in the previous DOM
+/// if the CSS selectors of both nodes are guaranteed to match the same rules:
+/// the node and all of its parents have to be retained with the same
+/// classes, IDs and position among their siblings
+#[cfg(feature = "multithreading")]
+fn get_reusable_css_matches(
+    diff: &DomDiff,
+    node_hierarchy: &NodeDataContainerRef<NodeHierarchyItem>,
+    cascade_info: &NodeDataContainerRef<CascadeInfo>,
+    previous_cascade_info: &NodeDataContainerRef<CascadeInfo>,
+) -> Vec<Option<NodeId>> {
+    let mut reusable = vec![None; node_hierarchy.len()];
+
+    // parents have lower node IDs than their children, so a
+    // parent is always processed before any of its children
+    for retained in diff.retained.iter() {
+        let new_id = retained.new;
+        let parent_is_reusable = match node_hierarchy[new_id].parent_id() {
+            Some(parent) => reusable[parent.index()].is_some(),
+            None => true,
+        };
+        let same_position = previous_cascade_info
+            .get(retained.old)
+            .map(|c| c == &cascade_info[new_id])
+            .unwrap_or(false);
+        if parent_is_reusable && same_position && !retained.changes.affects_css_matching() {
+            reusable[new_id.index()] = Some(retained.old);
+        }
+    }
+
+    reusable
+}
+
+/// Returns for every node of the new DOM the node ID in the previous DOM
+/// if both nodes inherit the same properties: the CSS rules of the node have
+/// to be reusable (see `get_reusable_css_matches`) and the inline CSS of the
+/// node and all of its parents has to be unchanged
+#[cfg(feature = "multithreading")]
+fn get_reusable_cascades(
+    diff: &DomDiff,
+    node_hierarchy: &NodeDataContainerRef<NodeHierarchyItem>,
+    reusable_css_matches: &[Option<NodeId>],
+) -> Vec<Option<NodeId>> {
+    let mut reusable = vec![None; node_hierarchy.len()];
+
+    for retained in diff.retained.iter() {
+        let new_id = retained.new;
+        let parent_is_reusable = match node_hierarchy[new_id].parent_id() {
+            Some(parent) => reusable[parent.index()].is_some(),
+            None => true,
+        };
+        if parent_is_reusable && !retained.changes.inline_css {
+            reusable[new_id.index()] = reusable_css_matches[new_id.index()];
+        }
+    }
+
+    reusable
+}
+
+/// Layout work that is left if the layout of the previous frame is
+/// reused for a new `StyledDom`, see `StyledDom::get_relayout_changes`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IncrementalRelayout {
+    /// Changed layout properties, by node
+    pub nodes: RelayoutNodes,
+    /// New content of the text nodes whose text changed
+    pub words: RelayoutWords,
+}
+
+/// Returns whether a change of the layout property can be applied to an
+/// existing layout (by `azul_layout::do_the_relayout`). Changes to `display`,
+/// `position`, the offsets, the borders and the font properties (which
+/// require the text to be shaped again) need a full layout.
+fn can_relayout_incrementally(prop_type: &CssPropertyType) -> bool {
+    use azul_css::CssPropertyType::*;
+    match prop_type {
+        Width | MinWidth | MaxWidth | Height | MinHeight | MaxHeight | PaddingTop
+        | PaddingRight | PaddingBottom | PaddingLeft | MarginTop | MarginRight
+        | MarginBottom | MarginLeft | FlexGrow | FlexDirection | JustifyContent => true,
+        _ => false,
+    }
+}
+
 impl StyledDom {
     // NOTE: After calling this function, the DOM will be reset to an empty DOM.
     // This is for memory optimization, so that the DOM does not need to be cloned.
     //
     // The CSS will be left in-place, but will be re-ordered
+    //
+    // `@media` queries are evaluated against `MediaEnvironment::default()`,
+    // see `set_media_environment`
+    #[cfg(feature = "multithreading")]
+    pub fn new(dom: &mut Dom, css: CssApiWrapper) -> Self {
+        Self::new_internal(dom, css, MediaEnvironment::default(), None)
+    }
+
+    /// Same as `StyledDom::new`, but only matches the CSS rules against the
+    /// nodes that have changed since the `previous` frame. The CSS selectors
+    /// of a node are only re-matched if the node was added, if its classes
+    /// or IDs changed, if its position among its siblings changed or if the
+    /// same is true for one of its parents.
+    ///
+    /// The inline styles and inherited properties are only cascaded again
+    /// for the subtrees below such nodes or below nodes whose inline CSS
+    /// changed, the properties of all other nodes are copied from `previous`.
+    ///
+    /// NOTE: This saves the matching and cascading work, not the copying:
+    /// the new `StyledDom` still owns a copy of the properties of every
+    /// node and its tag IDs are generated for the whole tree, so the cost
+    /// stays linear in the number of nodes (see `benches/styled_dom.rs`).
+    ///
+    /// `previous` has to be the `StyledDom` of the old DOM of the `diff`,
+    /// styled with the same CSS, otherwise the result is undefined.
+    /// `@media` queries are evaluated against the environment of `previous`.
+    #[cfg(feature = "multithreading")]
+    pub fn new_incremental(
+        dom: &mut Dom,
+        css: CssApiWrapper,
+        previous: &StyledDom,
+        diff: &DomDiff,
+    ) -> Self {
+        let media_environment = previous.get_css_property_cache().media_environment;
+        Self::new_internal(dom, css, media_environment, Some((previous, diff)))
+    }
+
+    /// Returns the nodes that have to be relaid out if the layout of `self`
+    /// (the DOM of the last frame) is reused for the `new` DOM: the changed
+    /// layout properties of the nodes whose style changed and the new text
+    /// of the text nodes whose content changed. All other nodes keep their
+    /// layout, unless they depend on the size of a changed node.
+    ///
+    /// Returns `None` if the `new` DOM has to be laid out from scratch:
+    /// if nodes were added, removed or moved, if the content of a node
+    /// other than a text node changed (images, iframes, ...) or if a layout
+    /// property changed that can't be updated incrementally.
+    ///
+    /// `diff` has to be `self.diff(new)`.
+    pub fn get_relayout_changes(
+        &self,
+        new: &StyledDom,
+        diff: &DomDiff,
+    ) -> Option<IncrementalRelayout> {
+        use crate::dom::NodeType;
+
+        if diff.has_structural_changes() {
+            return None;
+        }
+
+        let old_cache = self.get_css_property_cache();
+        let new_cache = new.get_css_property_cache();
+        let old_node_data = self.node_data.as_container();
+        let new_node_data = new.node_data.as_container();
+        let old_styled_nodes = self.styled_nodes.as_container();
+        let new_styled_nodes = new.styled_nodes.as_container();
+
+        let mut changes = IncrementalRelayout::default();
+
+        for retained in diff.retained.iter() {
+            // without structural changes, every node keeps its node ID
+            let node_id = retained.new;
+            if retained.old != node_id || retained.changes.other {
+                return None;
+            }
+
+            if retained.changes.text {
+                if let NodeType::Text(text) = new_node_data[node_id].get_node_type() {
+                    changes.words.insert(node_id, text.clone());
+                }
+            }
+
+            let old_state = &old_styled_nodes[node_id].state;
+            let new_state = &new_styled_nodes[node_id].state;
+
+            let style_may_have_changed = retained.changes.inline_css
+                || old_state != new_state
+                || old_cache.node_props_differ(new_cache, &node_id);
+
+            if !style_may_have_changed {
+                continue;
+            }
+
+            let mut changed_props = Vec::new();
+
+            for prop_type in CssPropertyType::all()
+                .iter()
+                .filter(|p| p.can_trigger_relayout())
+            {
+                let previous_prop = old_cache.get_property(
+                    &old_node_data[node_id],
+                    &node_id,
+                    old_state,
+                    prop_type,
+                );
+                let current_prop = new_cache.get_property(
+                    &new_node_data[node_id],
+                    &node_id,
+                    new_state,
+                    prop_type,
+                );
+
+                if previous_prop == current_prop {
+                    continue;
+                }
+
+                if !can_relayout_incrementally(prop_type) {
+                    return None;
+                }
+
+                changed_props.push(ChangedCssProperty {
+                    previous_state: old_state.clone(),
+                    previous_prop: previous_prop
+                        .cloned()
+                        .unwrap_or_else(|| CssProperty::auto(*prop_type)),
+                    current_state: new_state.clone(),
+                    current_prop: current_prop
+                        .cloned()
+                        .unwrap_or_else(|| CssProperty::auto(*prop_type)),
+                });
+            }
+
+            if !changed_props.is_empty() {
+                changes.nodes.insert(node_id, changed_props);
+            }
+        }
+
+        Some(changes)
+    }
+
     #[cfg(feature = "multithreading")]
-    pub fn new(dom: &mut Dom, mut css: CssApiWrapper) -> Self {
+    fn new_internal(
+        dom: &mut Dom,
+        mut css: CssApiWrapper,
+        media_environment: MediaEnvironment,
+        previous: Option<(&StyledDom, &DomDiff)>,
+    ) -> Self {
         use crate::dom::EventFilter;
         use core::mem;
         use rayon::prelude::*;
@@ -2445,13 +3029,34 @@ impl StyledDom {
 
         let non_leaf_nodes: ParentWithNodeDepthVec = non_leaf_nodes.into();
 
+        let reuse = previous.map(|(previous, diff)| {
+            let previous_node_ids = get_reusable_css_matches(
+                diff,
+                &node_hierarchy.as_container(),
+                &html_tree.as_ref(),
+                &previous.cascade_info.as_container(),
+            );
+            let previous_cascade_node_ids = get_reusable_cascades(
+                diff,
+                &node_hierarchy.as_container(),
+                &previous_node_ids,
+            );
+            CssMatchReuse {
+                previous: &*previous.css_property_cache.ptr,
+                previous_node_ids,
+                previous_cascade_node_ids,
+            }
+        });
+
         // apply all the styles from the CSS
-        let tag_ids = css_property_cache.restyle(
+        let tag_ids = css_property_cache.restyle_internal(
             &mut css.css,
+            &media_environment,
             &compact_dom.node_data.as_ref(),
             &node_hierarchy,
             &non_leaf_nodes,
             &html_tree.as_ref(),
+            reuse.as_ref(),
         );
 
         tag_ids
@@ -2803,8 +3408,10 @@ impl StyledDom {
     pub fn restyle(&mut self, mut css: CssApiWrapper) {
         use rayon::prelude::*;
 
+        let media_environment = self.get_css_property_cache().media_environment;
         let new_tag_ids = self.css_property_cache.downcast_mut().restyle(
             &mut css.css,
+            &media_environment,
             &self.node_data.as_container(),
             &self.node_hierarchy,
             &self.non_leaf_nodes,
@@ -2836,6 +3443,41 @@ impl StyledDom {
         self.tag_ids_to_node_ids = new_tag_ids.into();
     }
 
+    /// Returns whether a different set of `@media` groups of the stylesheet
+    /// matches in the new environment than in the current one
+    ///
+    /// NOTE: always false for DOMs that were combined with `append_child`
+    pub fn media_queries_changed(&self, media_environment: &MediaEnvironment) -> bool {
+        let css_property_cache = self.get_css_property_cache();
+        match css_property_cache.media_css.as_ref() {
+            Some(css) => {
+                get_active_media_hash(css, &css_property_cache.media_environment)
+                    != get_active_media_hash(css, media_environment)
+            }
+            None => false,
+        }
+    }
+
+    /// Evaluates the `@media` queries of the stylesheet against the new
+    /// environment (i.e. after the window was resized or the theme changed)
+    /// and restyles the DOM if the set of matching `@media` groups changed.
+    /// Returns whether the DOM was restyled.
+    pub fn set_media_environment(&mut self, media_environment: MediaEnvironment) -> bool {
+        let changed = self.media_queries_changed(&media_environment);
+        let css_property_cache = self.get_css_property_cache_mut();
+        css_property_cache.media_environment = media_environment;
+
+        if !changed {
+            return false;
+        }
+
+        if let Some(css) = css_property_cache.media_css.clone() {
+            self.restyle(CssApiWrapper { css });
+        }
+
+        true
+    }
+
     /// Inserts default On::Scroll and On::Tab handle for scroll-able
     /// and tabindex-able nodes.
     #[inline]
@@ -2914,6 +3556,91 @@ impl StyledDom {
         self.styled_nodes.as_container()[*node_id].state.clone()
     }
 
+    /// Computed font size of the node in pixels, which the `em` lengths
+    /// (padding, width, transforms, etc.) of the node are resolved against.
+    ///
+    /// `em` and `%` in the `font-size` itself are relative to the computed
+    /// font size of the parent, the root node is relative to the default
+    /// font size.
+    pub fn get_em_size(&self, node_id: NodeId) -> f32 {
+        use crate::ui_solver::DEFAULT_FONT_SIZE_PX;
+        use azul_css::CssPropertyValue;
+
+        let node_hierarchy = self.node_hierarchy.as_container();
+        let node_data = self.node_data.as_container();
+        let styled_nodes = self.styled_nodes.as_container();
+        let css_property_cache = self.get_css_property_cache();
+
+        let mut ancestors = vec![node_id];
+        let mut current = node_id;
+        while let Some(parent) = node_hierarchy[current].parent_id() {
+            ancestors.push(parent);
+            current = parent;
+        }
+
+        // walk down from the root, resolving each font size against the parent
+        ancestors
+            .iter()
+            .rev()
+            .fold(DEFAULT_FONT_SIZE_PX as f32, |parent_em_size, node_id| {
+                let font_size = css_property_cache
+                    .get_own_property(
+                        &node_data[*node_id],
+                        node_id,
+                        &styled_nodes[*node_id].state,
+                        &CssPropertyType::FontSize,
+                    )
+                    .and_then(|p| p.as_font_size());
+
+                match font_size {
+                    None | Some(CssPropertyValue::Inherit) => parent_em_size,
+                    Some(CssPropertyValue::Exact(fs)) => fs
+                        .inner
+                        .to_pixels_em_aware(parent_em_size, parent_em_size),
+                    Some(_) => DEFAULT_FONT_SIZE_PX as f32,
+                }
+            })
+    }
+
+    /// Returns the final value of a CSS property of a node, as it is used by
+    /// the layout and the renderer: the user override (see
+    /// `CallbackInfo::set_css_property`), then the matched rules and inline
+    /// styles for the current `:focus` / `:active` / `:hover` state, then
+    /// the value inherited from the parent nodes.
+    ///
+    /// `font-size`, `font-family` and `color` fall back to the same defaults
+    /// as the layout, all other properties return `None` if they aren't set.
+    pub fn get_computed_property(&self, node: NodeId, ty: CssPropertyType) -> Option<CssProperty> {
+        let node_data = self.node_data.as_container();
+        let node_data = node_data.get(node)?;
+        let styled_nodes = self.styled_nodes.as_container();
+        let node_state = &styled_nodes.get(node)?.state;
+        let css_property_cache = self.get_css_property_cache();
+
+        match ty {
+            CssPropertyType::FontSize => Some(CssProperty::font_size(
+                css_property_cache.get_font_size_or_default(node_data, &node, node_state),
+            )),
+            CssPropertyType::FontFamily => Some(CssProperty::font_family(
+                css_property_cache.get_font_id_or_default(node_data, &node, node_state),
+            )),
+            CssPropertyType::TextColor => Some(CssProperty::text_color(
+                css_property_cache.get_text_color_or_default(node_data, &node, node_state),
+            )),
+            _ => css_property_cache
+                .get_property(node_data, &node, node_state, &ty)
+                .cloned(),
+        }
+    }
+
+    /// Returns all computed properties of a node, see `get_computed_property`
+    pub fn get_computed_properties(&self, node: NodeId) -> CssPropertyVec {
+        CssPropertyType::all()
+            .iter()
+            .filter_map(|ty| self.get_computed_property(node, *ty))
+            .collect()
+    }
+
     /// Scans the display list for all font IDs + their font size
     #[cfg(feature = "multithreading")]
     pub(crate) fn scan_for_font_keys(
@@ -2939,11 +3666,7 @@ impl StyledDom {
                             &self.styled_nodes.as_container()[node_id].state,
                         );
 
-                        let font_size = self.get_css_property_cache().get_font_size_or_default(
-                            &node_data,
-                            &node_id,
-                            &self.styled_nodes.as_container()[node_id].state,
-                        );
+                        let font_size = self.get_em_size(node_id);
 
                         let style_font_families_hash =
                             StyleFontFamiliesHash::new(css_font_ids.as_ref());
@@ -3025,6 +3748,7 @@ impl StyledDom {
                 if let Some(style_backgrounds) = opt_background_image {
                     v.background_image = style_backgrounds
                         .get_property()
+                        .map(|b| &**b)
                         .unwrap_or(&default_backgrounds)
                         .iter()
                         .filter_map(|bg| {
@@ -3033,8 +3757,7 @@ impl StyledDom {
                                 Image(i) => i,
                                 _ => return None,
                             };
-                            let image_ref = css_image_cache.get_css_image_id(css_image_id)?;
-                            Some(image_ref.clone())
+                            css_image_cache.resolve_css_image_id(css_image_id)
                         })
                         .collect();
                 }
@@ -3908,3 +4631,479 @@ fn recursive_get_last_child(
         }
     }
 }
+
+#[cfg(all(test, feature = "multithreading"))]
+fn get_body_background(styled_dom: &StyledDom) -> Option<azul_css::StyleBackgroundContent> {
+    let node_id = NodeId::ZERO;
+    styled_dom
+        .get_css_property_cache()
+        .get_background_content(
+            &styled_dom.node_data.as_container()[node_id],
+            &node_id,
+            &styled_dom.styled_nodes.as_container()[node_id].state,
+        )
+        .and_then(|v| v.get_property())
+        .and_then(|v| v.as_ref().first().cloned())
+}
+
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_restyle_swaps_stylesheet() {
+    use azul_css::{ColorU, StyleBackgroundContent};
+
+    // switching the theme at runtime = restyling the same DOM with another stylesheet
+    let light = || CssApiWrapper::from_string("body { background: #ffffff; }".into());
+    let dark = || CssApiWrapper::from_string("body { background: #000000; }".into());
+
+    let white = Some(StyleBackgroundContent::Color(ColorU::WHITE));
+    let black = Some(StyleBackgroundContent::Color(ColorU::BLACK));
+
+    let mut styled_dom = StyledDom::new(&mut Dom::body(), light());
+    assert_eq!(get_body_background(&styled_dom), white);
+
+    styled_dom.restyle(dark());
+    assert_eq!(get_body_background(&styled_dom), black);
+
+    styled_dom.restyle(light());
+    assert_eq!(get_body_background(&styled_dom), white);
+}
+
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_restyle_css_variable() {
+    use azul_css::{ColorU, StyleBackgroundContent};
+
+    // same as above, but the stylesheet is only parsed once
+    let mut css =
+        CssApiWrapper::from_string("body { --bg: #ffffff; background: var(--bg); }".into());
+    let set_bg = |css: &mut CssApiWrapper, color| {
+        let bg = vec![StyleBackgroundContent::Color(color)];
+        css.css.set_variable("bg".into(), CssProperty::background_content(bg.into()));
+    };
+
+    let white = Some(StyleBackgroundContent::Color(ColorU::WHITE));
+    let black = Some(StyleBackgroundContent::Color(ColorU::BLACK));
+
+    let mut styled_dom = StyledDom::new(&mut Dom::body(), css.clone());
+    assert_eq!(get_body_background(&styled_dom), white);
+
+    set_bg(&mut css, ColorU::BLACK);
+    styled_dom.restyle(css.clone());
+    assert_eq!(get_body_background(&styled_dom), black);
+
+    set_bg(&mut css, ColorU::WHITE);
+    styled_dom.restyle(css);
+    assert_eq!(get_body_background(&styled_dom), white);
+}
+
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_restyle_media_query() {
+    use azul_css::{LayoutFlexDirection, MediaEnvironment};
+
+    let css = CssApiWrapper::from_string(
+        "body { flex-direction: row; }
+        @media (max-width: 600px) { body { flex-direction: column; } }"
+            .into(),
+    );
+
+    let get_direction = |styled_dom: &StyledDom| {
+        let node_id = NodeId::ZERO;
+        styled_dom
+            .get_css_property_cache()
+            .get_flex_direction(
+                &styled_dom.node_data.as_container()[node_id],
+                &node_id,
+                &styled_dom.styled_nodes.as_container()[node_id].state,
+            )
+            .and_then(|v| v.get_property().copied())
+    };
+    let env = |width| MediaEnvironment {
+        width,
+        ..MediaEnvironment::default()
+    };
+
+    let mut styled_dom = StyledDom::new(&mut Dom::body(), css);
+    assert!(!styled_dom.set_media_environment(env(601.0)));
+    assert_eq!(get_direction(&styled_dom), Some(LayoutFlexDirection::Row));
+
+    assert!(styled_dom.media_queries_changed(&env(599.0)));
+    assert!(styled_dom.set_media_environment(env(599.0)));
+    assert_eq!(
+        get_direction(&styled_dom),
+        Some(LayoutFlexDirection::Column)
+    );
+
+    assert!(!styled_dom.set_media_environment(env(300.0)));
+    assert!(styled_dom.set_media_environment(env(601.0)));
+    assert_eq!(get_direction(&styled_dom), Some(LayoutFlexDirection::Row));
+}
+
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_em_lengths_use_node_font_size() {
+    use crate::ui_solver::{HeightCalculatedRect, WidthCalculatedRect};
+
+    let get_padding = |css: &str| {
+        let styled_dom = StyledDom::new(&mut Dom::body(), CssApiWrapper::from_string(css.into()));
+        let node_id = NodeId::ZERO;
+        let node_data = &styled_dom.node_data.as_container()[node_id];
+        let state = &styled_dom.styled_nodes.as_container()[node_id].state;
+        let cache = styled_dom.get_css_property_cache();
+        let em_size = styled_dom.get_em_size(node_id);
+
+        let width = WidthCalculatedRect {
+            padding_left: cache.get_padding_left(node_data, &node_id, state).cloned(),
+            em_size,
+            ..WidthCalculatedRect::default()
+        };
+        let height = HeightCalculatedRect {
+            padding_top: cache.get_padding_top(node_data, &node_id, state).cloned(),
+            em_size,
+            ..HeightCalculatedRect::default()
+        };
+        (
+            width.get_raw_padding_left(0.0),
+            height.get_raw_padding_top(0.0),
+        )
+    };
+
+    // 1em = the font size of the node, not the default 16px
+    assert_eq!(
+        get_padding("body { font-size: 20px; padding-left: 1em; padding-top: 1em; }"),
+        (20.0, 20.0)
+    );
+    assert_eq!(
+        get_padding("body { padding-left: 1em; padding-top: 2em; }"),
+        (16.0, 32.0)
+    );
+}
+
+#[test]
+fn test_em_font_size_is_relative_to_parent() {
+    use crate::dom::IdOrClass;
+
+    let class = |c: &str| vec![IdOrClass::Class(c.to_string().into())].into();
+    let mut dom = Dom::body().with_children(
+        vec![Dom::div().with_ids_and_classes(class("double")).with_children(
+            vec![
+                Dom::div().with_ids_and_classes(class("half")),
+                Dom::div().with_ids_and_classes(class("em")),
+            ]
+            .into(),
+        )]
+        .into(),
+    );
+    let css = "
+        body { font-size: 20px; }
+        .double { font-size: 2em; }
+        .half { font-size: 50%; }
+        .em { padding-left: 1em; }
+    ";
+    let styled_dom = StyledDom::new(&mut dom, CssApiWrapper::from_string(css.into()));
+
+    assert_eq!(styled_dom.get_em_size(NodeId::new(0)), 20.0);
+    // 2em of the parent, not 2 * 16px
+    assert_eq!(styled_dom.get_em_size(NodeId::new(1)), 40.0);
+    assert_eq!(styled_dom.get_em_size(NodeId::new(2)), 20.0);
+    // the inherited "2em" is not applied a second time
+    assert_eq!(styled_dom.get_em_size(NodeId::new(3)), 40.0);
+}
+
+#[cfg(all(test, feature = "multithreading"))]
+fn make_table_dom(selected_row: Option<usize>, text: &str) -> Dom {
+    use crate::dom::IdOrClass;
+
+    (0..100)
+        .map(|row| {
+            let mut classes = vec![IdOrClass::Class("row".into())];
+            if selected_row == Some(row) {
+                classes.push(IdOrClass::Class("selected".into()));
+            }
+            (0..99)
+                .map(|_| Dom::text(text))
+                .collect::<Dom>()
+                .with_ids_and_classes(classes.into())
+        })
+        .collect::<Dom>()
+}
+
+#[cfg(all(test, feature = "multithreading"))]
+const TABLE_CSS: &str = "
+    .row { color: red; }
+    .row:hover { color: blue; }
+    .row:last { font-size: 20px; }
+    .selected p { color: green; }
+    .selected p:focus { color: yellow; }
+";
+
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_styled_dom_new_incremental_text_change() {
+    let mut old_dom = make_table_dom(None, "a");
+    let mut new_dom = make_table_dom(None, "a");
+    new_dom.children.as_mut()[50].children.as_mut()[20] = Dom::text("b");
+
+    let diff = old_dom.diff(&new_dom);
+    assert_eq!(old_dom.node_count(), 10_001);
+    assert_eq!(diff.retained.len(), 10_001);
+    assert_eq!(
+        diff.retained
+            .iter()
+            .filter(|r| !r.changes.is_empty())
+            .count(),
+        1
+    );
+
+    let previous = StyledDom::new(&mut old_dom, CssApiWrapper::from_string(TABLE_CSS.into()));
+    let full = StyledDom::new(
+        &mut new_dom.clone(),
+        CssApiWrapper::from_string(TABLE_CSS.into()),
+    );
+    let reusable = get_reusable_css_matches(
+        &diff,
+        &full.node_hierarchy.as_container(),
+        &full.cascade_info.as_container(),
+        &previous.cascade_info.as_container(),
+    );
+
+    // no node has to be matched against the CSS again
+    assert!(reusable.iter().all(|r| r.is_some()));
+
+    let incremental = StyledDom::new_incremental(
+        &mut new_dom,
+        CssApiWrapper::from_string(TABLE_CSS.into()),
+        &previous,
+        &diff,
+    );
+    assert_eq!(incremental.css_property_cache, full.css_property_cache);
+}
+
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_styled_dom_new_incremental_class_change() {
+    let mut old_dom = make_table_dom(None, "a");
+    let mut new_dom = make_table_dom(Some(3), "a");
+
+    let diff = old_dom.diff(&new_dom);
+    let previous = StyledDom::new(&mut old_dom, CssApiWrapper::from_string(TABLE_CSS.into()));
+    let full = StyledDom::new(
+        &mut new_dom.clone(),
+        CssApiWrapper::from_string(TABLE_CSS.into()),
+    );
+    let reusable = get_reusable_css_matches(
+        &diff,
+        &full.node_hierarchy.as_container(),
+        &full.cascade_info.as_container(),
+        &previous.cascade_info.as_container(),
+    );
+
+    // only the selected row and its children are matched again
+    assert_eq!(reusable.iter().filter(|r| r.is_none()).count(), 100);
+
+    let incremental = StyledDom::new_incremental(
+        &mut new_dom,
+        CssApiWrapper::from_string(TABLE_CSS.into()),
+        &previous,
+        &diff,
+    );
+    assert_eq!(incremental.css_property_cache, full.css_property_cache);
+    assert_ne!(previous.css_property_cache, full.css_property_cache);
+}
+
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_styled_dom_new_incremental_inline_change() {
+    let mut old_dom = make_table_dom(None, "a");
+    let mut new_dom = make_table_dom(None, "a");
+    new_dom.children.as_mut()[7].set_inline_style("color: purple; font-size: 30px;");
+
+    let diff = old_dom.diff(&new_dom);
+    let previous = StyledDom::new(&mut old_dom, CssApiWrapper::from_string(TABLE_CSS.into()));
+    let full = StyledDom::new(
+        &mut new_dom.clone(),
+        CssApiWrapper::from_string(TABLE_CSS.into()),
+    );
+    let reusable = get_reusable_css_matches(
+        &diff,
+        &full.node_hierarchy.as_container(),
+        &full.cascade_info.as_container(),
+        &previous.cascade_info.as_container(),
+    );
+    let reusable_cascades =
+        get_reusable_cascades(&diff, &full.node_hierarchy.as_container(), &reusable);
+
+    // the CSS matches stay the same, only the row and its
+    // children inherit different properties
+    assert!(reusable.iter().all(|r| r.is_some()));
+    assert_eq!(reusable_cascades.iter().filter(|r| r.is_none()).count(), 100);
+
+    let incremental = StyledDom::new_incremental(
+        &mut new_dom,
+        CssApiWrapper::from_string(TABLE_CSS.into()),
+        &previous,
+        &diff,
+    );
+    assert_eq!(incremental.css_property_cache, full.css_property_cache);
+    assert_ne!(previous.css_property_cache, full.css_property_cache);
+}
+
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_styled_dom_new_incremental_equals_new() {
+    use crate::dom::IdOrClass;
+
+    let row = |key: u64, class: &str| {
+        Dom::div()
+            .with_key(key)
+            .with_ids_and_classes(vec![IdOrClass::Class(class.to_string().into())].into())
+            .with_children(vec![Dom::text("cell"), Dom::text("cell")].into())
+    };
+    let parity = |key: u64| if key % 2 == 0 { "even" } else { "odd" };
+    let css = || {
+        CssApiWrapper::from_string(
+            "
+            .even { color: red; }
+            .odd { color: blue; }
+            .even:first { font-size: 20px; }
+            .odd:last p { font-size: 30px; }
+            .selected p:nth-child(2) { color: green; }
+            "
+            .into(),
+        )
+    };
+
+    let mut old_dom = (0..50).map(|key| row(key, parity(key))).collect::<Dom>();
+
+    // remove, insert, move and restyle rows
+    let mut new_rows = (0..50)
+        .filter(|key| *key != 10)
+        .map(|key| row(key, if key == 20 { "selected" } else { parity(key) }))
+        .collect::<Vec<_>>();
+    new_rows.insert(5, row(100, "odd"));
+    let moved = new_rows.remove(40);
+    new_rows.insert(0, moved);
+    let mut new_dom = new_rows.into_iter().collect::<Dom>();
+
+    let diff = old_dom.diff(&new_dom);
+    assert!(!diff.added.is_empty());
+    assert!(!diff.removed.is_empty());
+    assert!(!diff.moved.is_empty());
+
+    let previous = StyledDom::new(&mut old_dom, css());
+    let full = StyledDom::new(&mut new_dom.clone(), css());
+    let incremental = StyledDom::new_incremental(&mut new_dom, css(), &previous, &diff);
+
+    assert_eq!(incremental, full);
+}
+
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_styled_dom_get_relayout_changes() {
+    use crate::dom::IdOrClass;
+    use azul_css::LayoutWidth;
+
+    let css = || {
+        CssApiWrapper::from_string(
+            "
+            .narrow { width: 100px; }
+            .wide { width: 200px; }
+            .red { color: red; }
+            .hidden { display: none; }
+            "
+            .into(),
+        )
+    };
+    let class = |c: &str| vec![IdOrClass::Class(c.to_string().into())].into();
+    let make_dom = |first: &str, text: &str, last: &str| {
+        Dom::body()
+            .with_child(
+                Dom::div()
+                    .with_ids_and_classes(class(first))
+                    .with_child(Dom::text(text)),
+            )
+            .with_child(
+                Dom::div()
+                    .with_ids_and_classes(class("narrow"))
+                    .with_child(Dom::div().with_child(Dom::text("clean"))),
+            )
+            .with_child(Dom::div().with_ids_and_classes(class(last)))
+    };
+
+    let old = StyledDom::new(&mut make_dom("narrow", "a", "box"), css());
+
+    // one node changes its width, one text node its text and one node
+    // its color: the clean subtree (nodes 3 - 5) is not relaid out
+    let new = StyledDom::new(&mut make_dom("wide", "b", "red"), css());
+    let changes = old.get_relayout_changes(&new, &old.diff(&new)).unwrap();
+
+    assert_eq!(changes.nodes.keys().collect::<Vec<_>>(), vec![&NodeId::new(1)]);
+    let width_change = &changes.nodes[&NodeId::new(1)];
+    assert_eq!(width_change.len(), 1);
+    assert_eq!(
+        width_change[0].current_prop,
+        CssProperty::Width(LayoutWidth::px(200.0).into())
+    );
+    assert_eq!(changes.words.len(), 1);
+    assert_eq!(changes.words[&NodeId::new(2)].as_str(), "b");
+
+    // nothing changed
+    let same = StyledDom::new(&mut make_dom("narrow", "a", "box"), css());
+    let changes = old.get_relayout_changes(&same, &old.diff(&same)).unwrap();
+    assert_eq!(changes, IncrementalRelayout::default());
+
+    // `display` can't be updated incrementally
+    let hidden = StyledDom::new(&mut make_dom("narrow", "a", "hidden"), css());
+    assert_eq!(old.get_relayout_changes(&hidden, &old.diff(&hidden)), None);
+
+    // nodes were added
+    let mut added = make_dom("narrow", "a", "box");
+    added.add_child(Dom::div());
+    let added = StyledDom::new(&mut added, css());
+    assert_eq!(old.get_relayout_changes(&added, &old.diff(&added)), None);
+}
+
+#[test]
+#[cfg(feature = "multithreading")]
+fn test_get_computed_property() {
+    use azul_css::{LayoutWidth, PixelValue, StyleFontSize};
+
+    let css = CssApiWrapper::from_string("body { font-size: 20px; } div { width: 100px; }".into());
+    let mut dom = Dom::body().with_children(vec![Dom::div()].into());
+    let styled_dom = StyledDom::new(&mut dom, css);
+
+    let body = NodeId::ZERO;
+    let div = NodeId::new(1);
+    let font_size_20 = CssProperty::font_size(StyleFontSize { inner: PixelValue::px(20.0) });
+
+    // font-size is inherited from the body
+    assert_eq!(
+        styled_dom.get_computed_property(body, CssPropertyType::FontSize),
+        Some(font_size_20.clone())
+    );
+    assert_eq!(
+        styled_dom.get_computed_property(div, CssPropertyType::FontSize),
+        Some(font_size_20.clone())
+    );
+
+    // width is not inherited and has no default
+    assert_eq!(
+        styled_dom.get_computed_property(div, CssPropertyType::Width),
+        Some(CssProperty::width(LayoutWidth::px(100.0)))
+    );
+    assert_eq!(styled_dom.get_computed_property(body, CssPropertyType::Width), None);
+
+    let computed = styled_dom.get_computed_properties(div);
+    assert!(computed.as_ref().contains(&font_size_20));
+    assert!(computed.as_ref().contains(&CssProperty::width(LayoutWidth::px(100.0))));
+
+    // unstyled: the same default font size as the layout
+    let unstyled = StyledDom::new(&mut Dom::body(), CssApiWrapper::empty());
+    assert_eq!(
+        unstyled.get_computed_property(body, CssPropertyType::FontSize),
+        Some(CssProperty::font_size(crate::ui_solver::DEFAULT_FONT_SIZE))
+    );
+
+    // node doesn't exist
+    assert_eq!(styled_dom.get_computed_property(NodeId::new(2), CssPropertyType::FontSize), None);
+}
@@ -6,7 +6,7 @@ use crate::{
         GlContextPtr, IndexBufferFormat, Texture, VertexAttribute, VertexAttributeType,
         VertexBuffer, VertexLayout, VertexLayoutDescription,
     },
-    ui_solver::{ComputedTransform3D, RotationMode},
+    ui_solver::{ComputedTransform3D, RotationMode, DEFAULT_FONT_SIZE_PX},
     window::PhysicalSizeU32,
 };
 use alloc::string::String;
@@ -800,6 +800,7 @@ impl TessellatedGPUSvgNode {
             &transform_origin,
             target_size.width as f32,
             target_size.height as f32,
+            DEFAULT_FONT_SIZE_PX as f32,
             RotationMode::ForWebRender,
         );
 
@@ -882,6 +883,7 @@ impl TessellatedColoredGPUSvgNode {
             &transform_origin,
             target_size.width as f32,
             target_size.height as f32,
+            DEFAULT_FONT_SIZE_PX as f32,
             RotationMode::ForWebRender,
         );
 
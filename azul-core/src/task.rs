@@ -749,7 +749,14 @@ impl Timer {
             _abi_ref: core::ptr::null(),
             _abi_mut: core::ptr::null_mut(),
         };
-        let mut res = (self.callback.cb)(&mut self.data, &mut timer_callback_info);
+        // a panicking timer is terminated, so that it doesn't panic on every frame
+        let mut res = crate::error::catch_panic(|| {
+            (self.callback.cb)(&mut self.data, &mut timer_callback_info)
+        })
+        .unwrap_or(TimerCallbackReturn {
+            should_update: Update::DoNothing,
+            should_terminate: TerminateTimer::Terminate,
+        });
 
         // Check if the timers timeout is reached
         if is_about_to_finish {
@@ -1251,7 +1258,10 @@ pub extern "C" fn create_thread_libstd(
 
     let thread_handle = Some(thread::spawn(move || {
         let _ = thread_check;
-        (callback.cb)(thread_initialize_data, sender_receiver, receiver_sender);
+        // the panic is recorded as the last error, the thread finishes normally
+        let _ = crate::error::catch_panic(move || {
+            (callback.cb)(thread_initialize_data, sender_receiver, receiver_sender)
+        });
         // thread_check gets dropped here, signals that the thread has finished
     }));
 
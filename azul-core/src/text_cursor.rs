@@ -0,0 +1,429 @@
+//! Caret and selection types for building text inputs
+//!
+//! Cursors store both the byte offset into the UTF-8 text and the index of
+//! the grapheme cluster, so that bindings don't have to re-implement UTF-8
+//! handling: moving the cursor never ends up inside of a character, an emoji
+//! sequence or between a letter and its combining marks.
+//!
+//! NOTE: the grapheme segmentation is a minimal version of the rules of
+//! UAX #29 (combining marks, variation selectors, emoji modifiers, ZWJ
+//! sequences, regional indicator pairs and CRLF). Hangul syllable sequences
+//! and Indic conjuncts are split into multiple graphemes.
+
+use core::ops::Range;
+
+/// Position of the text cursor (caret) in a UTF-8 string
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct TextCursor {
+    /// Byte offset into the text, always on a grapheme boundary
+    pub byte_offset: usize,
+    /// Number of graphemes before the cursor
+    pub grapheme_offset: usize,
+}
+
+impl TextCursor {
+    /// Cursor before the first character
+    pub const fn start() -> Self {
+        Self {
+            byte_offset: 0,
+            grapheme_offset: 0,
+        }
+    }
+
+    /// Cursor after the last character of `text`
+    pub fn end(text: &str) -> Self {
+        Self::from_byte_offset(text, text.len())
+    }
+
+    /// Creates a cursor at `byte_offset`, moved back to the start of the
+    /// grapheme if the offset is inside of a grapheme (or out of bounds).
+    ///
+    /// Has to count the graphemes from the start of the text, the other
+    /// functions only look at the graphemes next to the cursor.
+    pub fn from_byte_offset(text: &str, byte_offset: usize) -> Self {
+        let mut cursor = Self::start();
+        while cursor.byte_offset < text.len() {
+            let next = next_boundary(text, cursor.byte_offset);
+            if next > byte_offset {
+                break;
+            }
+            cursor = Self {
+                byte_offset: next,
+                grapheme_offset: cursor.grapheme_offset + 1,
+            };
+        }
+        cursor
+    }
+
+    /// Moves the cursor one grapheme to the right, stops at the end of the text
+    pub fn next_grapheme(&self, text: &str) -> Self {
+        let cursor = self.clamp_to(text);
+        if cursor.byte_offset >= text.len() {
+            return cursor;
+        }
+        Self {
+            byte_offset: next_boundary(text, cursor.byte_offset),
+            grapheme_offset: cursor.grapheme_offset + 1,
+        }
+    }
+
+    /// Moves the cursor one grapheme to the left, stops at the start of the text
+    pub fn prev_grapheme(&self, text: &str) -> Self {
+        let cursor = self.clamp_to(text);
+        if cursor.byte_offset == 0 {
+            return cursor;
+        }
+        Self {
+            byte_offset: prev_boundary(text, cursor.byte_offset),
+            grapheme_offset: cursor.grapheme_offset.saturating_sub(1),
+        }
+    }
+
+    /// Moves the cursor to the end of the current or next word
+    /// (i.e. `Ctrl + Right`), skipping whitespace and punctuation
+    pub fn next_word(&self, text: &str) -> Self {
+        let mut cursor = self.clamp_to(text);
+        while cursor.byte_offset < text.len() && !starts_with_word_char(&text[cursor.byte_offset..]) {
+            cursor = cursor.next_grapheme(text);
+        }
+        while cursor.byte_offset < text.len() && starts_with_word_char(&text[cursor.byte_offset..]) {
+            cursor = cursor.next_grapheme(text);
+        }
+        cursor
+    }
+
+    /// Moves the cursor to the start of the current or previous word
+    /// (i.e. `Ctrl + Left`), skipping whitespace and punctuation
+    pub fn prev_word(&self, text: &str) -> Self {
+        let mut cursor = self.clamp_to(text);
+        let mut skipping_word = false;
+        while cursor.byte_offset > 0 {
+            let prev = cursor.prev_grapheme(text);
+            let is_word = starts_with_word_char(&text[prev.byte_offset..]);
+            if skipping_word && !is_word {
+                break;
+            }
+            skipping_word |= is_word;
+            cursor = prev;
+        }
+        cursor
+    }
+
+    // cursors that don't fit the text (i.e. after the text was edited)
+    // are moved to the previous grapheme boundary
+    fn clamp_to(&self, text: &str) -> Self {
+        if self.byte_offset <= text.len() && text.is_char_boundary(self.byte_offset) {
+            *self
+        } else {
+            Self::from_byte_offset(text, self.byte_offset)
+        }
+    }
+}
+
+/// Selected range of a text. `start` is where the selection was started
+/// (the anchor), `end` is where the caret is - so `end` is before `start`
+/// if the user selected the text backwards.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct TextSelection {
+    pub start: TextCursor,
+    pub end: TextCursor,
+}
+
+impl TextSelection {
+    /// Empty selection, only shows the caret
+    pub const fn collapsed(cursor: TextCursor) -> Self {
+        Self {
+            start: cursor,
+            end: cursor,
+        }
+    }
+
+    /// Selects the entire `text`
+    pub fn all(text: &str) -> Self {
+        Self {
+            start: TextCursor::start(),
+            end: TextCursor::end(text),
+        }
+    }
+
+    pub fn is_collapsed(&self) -> bool {
+        self.start.byte_offset == self.end.byte_offset
+    }
+
+    /// Returns the selection with `start <= end`
+    pub fn normalized(&self) -> Self {
+        if self.start.byte_offset <= self.end.byte_offset {
+            *self
+        } else {
+            Self {
+                start: self.end,
+                end: self.start,
+            }
+        }
+    }
+
+    /// Byte range of the selected text
+    pub fn byte_range(&self) -> Range<usize> {
+        let n = self.normalized();
+        n.start.byte_offset..n.end.byte_offset
+    }
+
+    /// Returns the selected part of `text`, or an empty string if the
+    /// selection doesn't belong to the text (out of bounds)
+    pub fn selected_text<'a>(&self, text: &'a str) -> &'a str {
+        text.get(self.byte_range()).unwrap_or("")
+    }
+}
+
+/// Returns the start of the grapheme after the one starting at `offset`
+/// (`offset` has to be a grapheme boundary), or `text.len()`
+fn next_boundary(text: &str, offset: usize) -> usize {
+    let mut chars = text[offset..].char_indices();
+    let mut prev = match chars.next() {
+        Some((_, c)) => c,
+        None => return text.len(),
+    };
+    // number of regional indicators in a row before the current char
+    let mut regional_indicators = is_regional_indicator(prev) as usize;
+
+    for (idx, c) in chars {
+        if is_boundary(prev, c, regional_indicators) {
+            return offset + idx;
+        }
+        regional_indicators = if is_regional_indicator(c) { regional_indicators + 1 } else { 0 };
+        prev = c;
+    }
+
+    text.len()
+}
+
+/// Returns the start of the grapheme before `offset` (`offset` has to be a
+/// grapheme boundary greater than 0)
+fn prev_boundary(text: &str, offset: usize) -> usize {
+    // go back to a char that always starts a grapheme (so that the pairs of
+    // regional indicators line up), then segment forward from there
+    let mut start = 0;
+    let mut chars = text[..offset].char_indices().rev().peekable();
+    while let Some((idx, c)) = chars.next() {
+        let always_starts_grapheme = match chars.peek() {
+            None => true,
+            Some((_, p)) => {
+                *p != ZERO_WIDTH_JOINER
+                    && !is_grapheme_extend(c)
+                    && !is_regional_indicator(c)
+                    && c != '\n'
+            }
+        };
+        if always_starts_grapheme {
+            start = idx;
+            break;
+        }
+    }
+
+    loop {
+        let next = next_boundary(text, start);
+        if next >= offset {
+            return start;
+        }
+        start = next;
+    }
+}
+
+/// Returns whether a new grapheme starts at `c`. Minimal version of the
+/// rules of UAX #29, `regional_indicators` is the number of regional
+/// indicators in a row before `c`.
+fn is_boundary(prev: char, c: char, regional_indicators: usize) -> bool {
+    if prev == '\r' || prev == '\n' {
+        // CRLF is one grapheme, nothing else extends a line break
+        return !(prev == '\r' && c == '\n');
+    }
+    !(is_grapheme_extend(c)
+        || (prev == ZERO_WIDTH_JOINER && is_extended_pictographic(c))
+        || (is_regional_indicator(c) && regional_indicators % 2 == 1))
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// Characters that never start a new grapheme: combining marks,
+/// variation selectors, emoji skin tone modifiers, tags and the ZWJ itself
+fn is_grapheme_extend(c: char) -> bool {
+    match c as u32 {
+        0x0300..=0x036F // combining diacritical marks
+        | 0x0483..=0x0489
+        | 0x0591..=0x05BD
+        | 0x0610..=0x061A
+        | 0x064B..=0x065F
+        | 0x0E31 | 0x0E34..=0x0E3A | 0x0E47..=0x0E4E // thai
+        | 0x1AB0..=0x1AFF
+        | 0x1DC0..=0x1DFF
+        | 0x200C..=0x200D // zero width non-joiner / joiner
+        | 0x20D0..=0x20FF // combining marks for symbols (incl. keycap)
+        | 0xFE00..=0xFE0F // variation selectors
+        | 0xFE20..=0xFE2F
+        | 0x1F3FB..=0x1F3FF // emoji modifiers (skin tones)
+        | 0xE0020..=0xE007F // tags (subdivision flags)
+        | 0xE0100..=0xE01EF => true,
+        _ => false,
+    }
+}
+
+/// Emoji that can be joined into a ZWJ sequence (approximates the
+/// `Extended_Pictographic` property, i.e. 👨‍👩‍👧 or 🏳️‍🌈)
+fn is_extended_pictographic(c: char) -> bool {
+    match c as u32 {
+        0x00A9 | 0x00AE | 0x203C | 0x2049 | 0x2122 | 0x2139
+        | 0x2194..=0x2199
+        | 0x21A9..=0x21AA
+        | 0x231A..=0x231B
+        | 0x2328 | 0x23CF
+        | 0x23E9..=0x23F3
+        | 0x23F8..=0x23FA
+        | 0x24C2
+        | 0x25AA..=0x25AB
+        | 0x25B6 | 0x25C0
+        | 0x25FB..=0x25FE
+        | 0x2600..=0x27BF // miscellaneous symbols, dingbats
+        | 0x2934..=0x2935
+        | 0x2B05..=0x2B07
+        | 0x2B1B..=0x2B1C
+        | 0x2B50 | 0x2B55 | 0x3030 | 0x303D | 0x3297 | 0x3299
+        | 0x1F000..=0x1F1E5 // (without the regional indicators)
+        | 0x1F200..=0x1F3FA // (without the skin tone modifiers)
+        | 0x1F400..=0x1FAFF
+        | 0x1FC00..=0x1FFFD => true,
+        _ => false,
+    }
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+// the first char decides whether a grapheme is part of a word
+fn starts_with_word_char(text: &str) -> bool {
+    text
+        .chars()
+        .next()
+        .map(|c| c.is_alphanumeric() || c == '_')
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graphemes(text: &str) -> Vec<&str> {
+        let mut graphemes = Vec::new();
+        let mut start = 0;
+        while start < text.len() {
+            let end = next_boundary(text, start);
+            graphemes.push(&text[start..end]);
+            start = end;
+        }
+        graphemes
+    }
+
+    #[test]
+    fn test_grapheme_segmentation() {
+        assert_eq!(graphemes(""), Vec::<&str>::new());
+        assert_eq!(graphemes("ab"), vec!["a", "b"]);
+        // e + combining acute accent, a + combining ring + combining dot
+        assert_eq!(graphemes("e\u{301}a\u{30A}\u{307}x"), vec!["e\u{301}", "a\u{30A}\u{307}", "x"]);
+        // thumbs up + skin tone, family (ZWJ sequence), heart + variation selector
+        assert_eq!(
+            graphemes("👍🏽👨‍👩‍👧❤\u{FE0F}"),
+            vec!["👍🏽", "👨‍👩‍👧", "❤\u{FE0F}"]
+        );
+        // flags are pairs of regional indicators
+        assert_eq!(graphemes("🇩🇪🇫🇷🇮"), vec!["🇩🇪", "🇫🇷", "🇮"]);
+        assert_eq!(graphemes("a\r\nb"), vec!["a", "\r\n", "b"]);
+        // ZWJ only joins emoji, combining marks don't extend a line break
+        assert_eq!(graphemes("a\u{200D}b"), vec!["a\u{200D}", "b"]);
+        assert_eq!(graphemes("\n\u{301}"), vec!["\n", "\u{301}"]);
+    }
+
+    #[test]
+    fn test_prev_boundary() {
+        let text = "x🇩🇪🇫🇷👨‍👩‍👧e\u{301}\r\n";
+        let mut boundaries = vec![text.len()];
+        while let Some(&last) = boundaries.last() {
+            if last == 0 {
+                break;
+            }
+            boundaries.push(prev_boundary(text, last));
+        }
+        boundaries.reverse();
+
+        let mut forward = vec![0];
+        while let Some(&last) = forward.last() {
+            if last == text.len() {
+                break;
+            }
+            forward.push(next_boundary(text, last));
+        }
+        assert_eq!(boundaries, forward);
+        assert_eq!(boundaries.len(), 7);
+    }
+
+    #[test]
+    fn test_cursor_grapheme_movement() {
+        let text = "a👍🏽e\u{301}🇩🇪";
+
+        let mut cursor = TextCursor::start();
+        let mut offsets = Vec::new();
+        loop {
+            let next = cursor.next_grapheme(text);
+            if next == cursor {
+                break;
+            }
+            offsets.push((next.byte_offset, next.grapheme_offset));
+            cursor = next;
+        }
+        assert_eq!(offsets, vec![(1, 1), (9, 2), (12, 3), (20, 4)]);
+        assert_eq!(cursor, TextCursor::end(text));
+
+        let back = cursor.prev_grapheme(text).prev_grapheme(text);
+        assert_eq!(back, TextCursor { byte_offset: 9, grapheme_offset: 2 });
+        assert_eq!(TextCursor::start().prev_grapheme(text), TextCursor::start());
+
+        // offsets inside of a grapheme snap back to its start
+        assert_eq!(TextCursor::from_byte_offset(text, 5), TextCursor { byte_offset: 1, grapheme_offset: 1 });
+        assert_eq!(TextCursor::from_byte_offset(text, 100), TextCursor::end(text));
+    }
+
+    #[test]
+    fn test_cursor_word_movement() {
+        let text = "hello,  wörld_2 🎉 end";
+
+        let c = TextCursor::start().next_word(text);
+        assert_eq!(&text[..c.byte_offset], "hello");
+        let c = c.next_word(text);
+        assert_eq!(&text[..c.byte_offset], "hello,  wörld_2");
+        let c = c.next_word(text);
+        assert_eq!(c, TextCursor::end(text));
+        assert_eq!(c.next_word(text), c);
+
+        let c = c.prev_word(text);
+        assert_eq!(&text[c.byte_offset..], "end");
+        let c = c.prev_word(text);
+        assert_eq!(&text[c.byte_offset..], "wörld_2 🎉 end");
+        assert_eq!(c.prev_word(text).prev_word(text), TextCursor::start());
+    }
+
+    #[test]
+    fn test_selection() {
+        let text = "héllo wörld";
+        let anchor = TextCursor::end(text);
+        let caret = anchor.prev_word(text);
+        let selection = TextSelection { start: anchor, end: caret };
+
+        assert!(!selection.is_collapsed());
+        assert_eq!(selection.normalized(), TextSelection { start: caret, end: anchor });
+        assert_eq!(selection.selected_text(text), "wörld");
+        assert_eq!(TextSelection::all(text).selected_text(text), text);
+        assert!(TextSelection::collapsed(caret).is_collapsed());
+        assert_eq!(TextSelection::all(text).selected_text("short"), "");
+    }
+}
@@ -22,13 +22,14 @@ use alloc::boxed::Box;
 use alloc::collections::btree_map::BTreeMap;
 use alloc::vec::Vec;
 use azul_css::{
-    ColorU as StyleColorU, CssPropertyValue, LayoutBorderBottomWidth, LayoutBorderLeftWidth,
+    ColorU as StyleColorU, CssLengthValue, CssPropertyValue, LayoutBorderBottomWidth, LayoutBorderLeftWidth,
     LayoutBorderRightWidth, LayoutBorderTopWidth, LayoutBottom, LayoutBoxSizing, LayoutDisplay,
     LayoutFlexDirection, LayoutJustifyContent, LayoutLeft, LayoutMarginBottom, LayoutMarginLeft,
     LayoutMarginRight, LayoutMarginTop, LayoutOverflow, LayoutPaddingBottom, LayoutPaddingLeft,
     LayoutPaddingRight, LayoutPaddingTop, LayoutPoint, LayoutPosition, LayoutRect, LayoutRectVec,
-    LayoutRight, LayoutSize, LayoutTop, OptionF32, PixelValue, StyleBoxShadow, StyleFontSize,
+    LayoutRight, LayoutSize, LayoutTop, OptionF32, PixelValue, StyleBoxShadowValue, StyleFontSize,
     StyleTextAlign, StyleTextColor, StyleTransform, StyleTransformOrigin, StyleVerticalAlign,
+    UnitResolutionContext,
 };
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::__m256;
@@ -178,10 +179,12 @@ impl InlineTextLayout {
 #[inline]
 pub fn calculate_horizontal_shift_multiplier(horizontal_alignment: StyleTextAlign) -> Option<f32> {
     use azul_css::StyleTextAlign::*;
+    // start / end should be resolved with StyleTextAlign::resolve
+    // beforehand, if not they are treated as left-to-right
     match horizontal_alignment {
-        Left => None,
-        Center => Some(0.5), // move the line by the half width
-        Right => Some(1.0),  // move the line by the full width
+        Left | Start => None,
+        Center => Some(0.5),     // move the line by the half width
+        Right | End => Some(1.0), // move the line by the full width
     }
 }
 
@@ -189,9 +192,9 @@ pub fn calculate_horizontal_shift_multiplier(horizontal_alignment: StyleTextAlig
 pub fn calculate_vertical_shift_multiplier(vertical_alignment: StyleVerticalAlign) -> Option<f32> {
     use azul_css::StyleVerticalAlign::*;
     match vertical_alignment {
-        Top => None,
-        Center => Some(0.5), // move the line by the half width
-        Bottom => Some(1.0), // move the line by the full width
+        Middle => Some(0.5), // move the line by the half width
+        Bottom | TextBottom => Some(1.0), // move the line by the full width
+        _ => None,
     }
 }
 
@@ -302,29 +305,35 @@ impl WhConstraint {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct WidthCalculatedRect {
     pub preferred_width: WhConstraint,
 
-    pub margin_right: Option<CssPropertyValue<LayoutMarginRight>>,
-    pub margin_left: Option<CssPropertyValue<LayoutMarginLeft>>,
+    pub margin_right: Option<CssLengthValue<LayoutMarginRight>>,
+    pub margin_left: Option<CssLengthValue<LayoutMarginLeft>>,
 
-    pub padding_right: Option<CssPropertyValue<LayoutPaddingRight>>,
-    pub padding_left: Option<CssPropertyValue<LayoutPaddingLeft>>,
+    pub padding_right: Option<CssLengthValue<LayoutPaddingRight>>,
+    pub padding_left: Option<CssLengthValue<LayoutPaddingLeft>>,
 
     pub border_right: Option<CssPropertyValue<LayoutBorderRightWidth>>,
     pub border_left: Option<CssPropertyValue<LayoutBorderLeftWidth>>,
 
     pub box_sizing: LayoutBoxSizing,
 
-    pub left: Option<CssPropertyValue<LayoutLeft>>,
-    pub right: Option<CssPropertyValue<LayoutRight>>,
+    pub left: Option<CssLengthValue<LayoutLeft>>,
+    pub right: Option<CssLengthValue<LayoutRight>>,
 
     pub flex_grow_px: f32,
     pub min_inner_size_px: f32,
+    /// Computed font size in pixels, `em` lengths are resolved against it
+    pub em_size: f32,
 }
 
 impl WidthCalculatedRect {
+    fn unit_context(&self, percent_resolve: f32) -> UnitResolutionContext {
+        UnitResolutionContext::with_em_size(percent_resolve, self.em_size)
+    }
+
     pub fn overflow_width(&self) -> f32 {
         if !self.flex_grow_px.is_sign_positive() {
             self.min_inner_size_px
@@ -336,40 +345,28 @@ impl WidthCalculatedRect {
     pub fn get_border_left(&self, percent_resolve: f32) -> f32 {
         self.border_left
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
     pub fn get_border_right(&self, percent_resolve: f32) -> f32 {
         self.border_right
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
     pub fn get_raw_padding_left(&self, percent_resolve: f32) -> f32 {
         self.padding_left
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
     pub fn get_raw_padding_right(&self, percent_resolve: f32) -> f32 {
         self.padding_right
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
@@ -384,20 +381,14 @@ impl WidthCalculatedRect {
     pub fn get_margin_left(&self, percent_resolve: f32) -> f32 {
         self.margin_left
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
     pub fn get_margin_right(&self, percent_resolve: f32) -> f32 {
         self.margin_right
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
@@ -439,29 +430,35 @@ impl WidthCalculatedRect {
     }
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct HeightCalculatedRect {
     pub preferred_height: WhConstraint,
 
-    pub margin_top: Option<CssPropertyValue<LayoutMarginTop>>,
-    pub margin_bottom: Option<CssPropertyValue<LayoutMarginBottom>>,
+    pub margin_top: Option<CssLengthValue<LayoutMarginTop>>,
+    pub margin_bottom: Option<CssLengthValue<LayoutMarginBottom>>,
 
-    pub padding_top: Option<CssPropertyValue<LayoutPaddingTop>>,
-    pub padding_bottom: Option<CssPropertyValue<LayoutPaddingBottom>>,
+    pub padding_top: Option<CssLengthValue<LayoutPaddingTop>>,
+    pub padding_bottom: Option<CssLengthValue<LayoutPaddingBottom>>,
 
     pub border_top: Option<CssPropertyValue<LayoutBorderTopWidth>>,
     pub border_bottom: Option<CssPropertyValue<LayoutBorderBottomWidth>>,
 
-    pub top: Option<CssPropertyValue<LayoutTop>>,
-    pub bottom: Option<CssPropertyValue<LayoutBottom>>,
+    pub top: Option<CssLengthValue<LayoutTop>>,
+    pub bottom: Option<CssLengthValue<LayoutBottom>>,
 
     pub box_sizing: LayoutBoxSizing,
 
     pub flex_grow_px: f32,
     pub min_inner_size_px: f32,
+    /// Computed font size in pixels, `em` lengths are resolved against it
+    pub em_size: f32,
 }
 
 impl HeightCalculatedRect {
+    fn unit_context(&self, percent_resolve: f32) -> UnitResolutionContext {
+        UnitResolutionContext::with_em_size(percent_resolve, self.em_size)
+    }
+
     pub fn overflow_height(&self) -> f32 {
         if !self.flex_grow_px.is_sign_positive() {
             self.min_inner_size_px
@@ -473,40 +470,28 @@ impl HeightCalculatedRect {
     pub fn get_border_top(&self, percent_resolve: f32) -> f32 {
         self.border_top
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
     pub fn get_border_bottom(&self, percent_resolve: f32) -> f32 {
         self.border_bottom
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
     pub fn get_raw_padding_top(&self, percent_resolve: f32) -> f32 {
         self.padding_top
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
     pub fn get_raw_padding_bottom(&self, percent_resolve: f32) -> f32 {
         self.padding_bottom
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
@@ -521,20 +506,14 @@ impl HeightCalculatedRect {
     pub fn get_margin_top(&self, percent_resolve: f32) -> f32 {
         self.margin_top
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
     pub fn get_margin_bottom(&self, percent_resolve: f32) -> f32 {
         self.margin_bottom
             .as_ref()
-            .and_then(|p| {
-                p.get_property()
-                    .map(|px| px.inner.to_pixels(percent_resolve))
-            })
+            .and_then(|p| p.resolve_length(&self.unit_context(percent_resolve), |px| px.inner))
             .unwrap_or(0.0)
     }
 
@@ -703,7 +682,8 @@ impl LayoutResult {
     ) -> CachedDisplayList {
         use crate::display_list::{
             displaylist_handle_rect, push_rectangles_into_displaylist, DisplayListFrame,
-            DisplayListMsg, DisplayListParametersRef, LayoutRectContent, RectBackground,
+            DisplayListMsg, DisplayListParametersRef, ImageRendering, LayoutRectContent,
+            RectBackground,
         };
         use rayon::prelude::*;
 
@@ -763,6 +743,7 @@ impl LayoutResult {
                 size: None,
                 offset: None,
                 repeat: None,
+                image_rendering: ImageRendering::Auto,
             });
         }
 
@@ -872,7 +853,7 @@ impl LayoutResult {
                             /* virtual_scroll_offset */
                             scroll_node.virtual_child_rect.origin - scroll_node.parent_rect.origin,
                         );
-                        (iframe_node.callback.cb)(&mut iframe_node.data, &mut iframe_callback_info)
+                        iframe_node.callback.invoke(&mut iframe_node.data, &mut iframe_callback_info)
                     };
 
                     // TODO: what to do if the new iframe has less or more sub-iframes
@@ -1124,6 +1105,7 @@ impl GpuValueCache {
                         let transform_origin = transform_origin
                             .as_ref()
                             .and_then(|o| o.get_property())
+                            .map(|o| &**o)
                             .unwrap_or(&default_transform_origin);
 
                         ComputedTransform3D::from_style_transform_vec(
@@ -1131,6 +1113,7 @@ impl GpuValueCache {
                             transform_origin,
                             parent_size.width,
                             parent_size.height,
+                            styled_dom.get_em_size(node_id),
                             RotationMode::ForWebRender,
                         )
                     });
@@ -1680,12 +1663,12 @@ impl PositionInfo {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[derive(Default, Debug, Clone, PartialEq, PartialOrd)]
 pub struct StyleBoxShadowOffsets {
-    pub left: Option<CssPropertyValue<StyleBoxShadow>>,
-    pub right: Option<CssPropertyValue<StyleBoxShadow>>,
-    pub top: Option<CssPropertyValue<StyleBoxShadow>>,
-    pub bottom: Option<CssPropertyValue<StyleBoxShadow>>,
+    pub left: Option<StyleBoxShadowValue>,
+    pub right: Option<StyleBoxShadowValue>,
+    pub top: Option<StyleBoxShadowValue>,
+    pub bottom: Option<StyleBoxShadowValue>,
 }
 
 /// For some reason the rotation matrix for webrender is inverted:
@@ -1907,6 +1890,7 @@ impl ComputedTransform3D {
         transform_origin: &StyleTransformOrigin,
         percent_resolve_x: f32,
         percent_resolve_y: f32,
+        em_size: f32,
         rotation_mode: RotationMode,
     ) -> Self {
         // TODO: use correct SIMD optimization!
@@ -1926,6 +1910,7 @@ impl ComputedTransform3D {
                         transform_origin,
                         percent_resolve_x,
                         percent_resolve_y,
+                        em_size,
                         rotation_mode,
                     ));
                 }
@@ -1939,6 +1924,7 @@ impl ComputedTransform3D {
                         transform_origin,
                         percent_resolve_x,
                         percent_resolve_y,
+                        em_size,
                         rotation_mode,
                     ));
                 }
@@ -1951,6 +1937,7 @@ impl ComputedTransform3D {
                     transform_origin,
                     percent_resolve_x,
                     percent_resolve_y,
+                    em_size,
                     rotation_mode,
                 ));
             }
@@ -1960,71 +1947,85 @@ impl ComputedTransform3D {
     }
 
     /// Creates a new transform from a style transform using the
-    /// parent width as a way to resolve for percentages
+    /// parent width as a way to resolve for percentages and the
+    /// font size of the node (`em_size`) to resolve `em` lengths
     pub fn from_style_transform(
         t: &StyleTransform,
         transform_origin: &StyleTransformOrigin,
         percent_resolve_x: f32,
         percent_resolve_y: f32,
+        em_size: f32,
         rotation_mode: RotationMode,
     ) -> Self {
         use azul_css::StyleTransform::*;
         match t {
             Matrix(mat2d) => {
-                let a = mat2d.a.to_pixels(percent_resolve_x);
-                let b = mat2d.b.to_pixels(percent_resolve_x);
-                let c = mat2d.c.to_pixels(percent_resolve_x);
-                let d = mat2d.d.to_pixels(percent_resolve_x);
-                let tx = mat2d.tx.to_pixels(percent_resolve_x);
-                let ty = mat2d.ty.to_pixels(percent_resolve_x);
+                let a = mat2d.a.to_pixels_em_aware(percent_resolve_x, em_size);
+                let b = mat2d.b.to_pixels_em_aware(percent_resolve_x, em_size);
+                let c = mat2d.c.to_pixels_em_aware(percent_resolve_x, em_size);
+                let d = mat2d.d.to_pixels_em_aware(percent_resolve_x, em_size);
+                let tx = mat2d.tx.to_pixels_em_aware(percent_resolve_x, em_size);
+                let ty = mat2d.ty.to_pixels_em_aware(percent_resolve_x, em_size);
 
                 Self::new_2d(a, b, c, d, tx, ty)
             }
             Matrix3D(mat3d) => {
-                let m11 = mat3d.m11.to_pixels(percent_resolve_x);
-                let m12 = mat3d.m12.to_pixels(percent_resolve_x);
-                let m13 = mat3d.m13.to_pixels(percent_resolve_x);
-                let m14 = mat3d.m14.to_pixels(percent_resolve_x);
-                let m21 = mat3d.m21.to_pixels(percent_resolve_x);
-                let m22 = mat3d.m22.to_pixels(percent_resolve_x);
-                let m23 = mat3d.m23.to_pixels(percent_resolve_x);
-                let m24 = mat3d.m24.to_pixels(percent_resolve_x);
-                let m31 = mat3d.m31.to_pixels(percent_resolve_x);
-                let m32 = mat3d.m32.to_pixels(percent_resolve_x);
-                let m33 = mat3d.m33.to_pixels(percent_resolve_x);
-                let m34 = mat3d.m34.to_pixels(percent_resolve_x);
-                let m41 = mat3d.m41.to_pixels(percent_resolve_x);
-                let m42 = mat3d.m42.to_pixels(percent_resolve_x);
-                let m43 = mat3d.m43.to_pixels(percent_resolve_x);
-                let m44 = mat3d.m44.to_pixels(percent_resolve_x);
+                let m11 = mat3d.m11.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m12 = mat3d.m12.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m13 = mat3d.m13.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m14 = mat3d.m14.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m21 = mat3d.m21.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m22 = mat3d.m22.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m23 = mat3d.m23.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m24 = mat3d.m24.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m31 = mat3d.m31.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m32 = mat3d.m32.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m33 = mat3d.m33.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m34 = mat3d.m34.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m41 = mat3d.m41.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m42 = mat3d.m42.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m43 = mat3d.m43.to_pixels_em_aware(percent_resolve_x, em_size);
+                let m44 = mat3d.m44.to_pixels_em_aware(percent_resolve_x, em_size);
 
                 Self::new(
                     m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, m44,
                 )
             }
             Translate(trans2d) => Self::new_translation(
-                trans2d.x.to_pixels(percent_resolve_x),
-                trans2d.y.to_pixels(percent_resolve_y),
+                trans2d.x.to_pixels_em_aware(percent_resolve_x, em_size),
+                trans2d.y.to_pixels_em_aware(percent_resolve_y, em_size),
                 0.0,
             ),
             Translate3D(trans3d) => Self::new_translation(
-                trans3d.x.to_pixels(percent_resolve_x),
-                trans3d.y.to_pixels(percent_resolve_y),
-                trans3d.z.to_pixels(percent_resolve_x), // ???
+                trans3d.x.to_pixels_em_aware(percent_resolve_x, em_size),
+                trans3d.y.to_pixels_em_aware(percent_resolve_y, em_size),
+                trans3d.z.to_pixels_em_aware(percent_resolve_x, em_size), // ???
             ),
             TranslateX(trans_x) => {
-                Self::new_translation(trans_x.to_pixels(percent_resolve_x), 0.0, 0.0)
+                Self::new_translation(
+                    trans_x.to_pixels_em_aware(percent_resolve_x, em_size),
+                    0.0,
+                    0.0,
+                )
             }
             TranslateY(trans_y) => {
-                Self::new_translation(0.0, trans_y.to_pixels(percent_resolve_y), 0.0)
+                Self::new_translation(
+                    0.0,
+                    trans_y.to_pixels_em_aware(percent_resolve_y, em_size),
+                    0.0,
+                )
             }
             TranslateZ(trans_z) => {
-                Self::new_translation(0.0, 0.0, trans_z.to_pixels(percent_resolve_x))
+                Self::new_translation(
+                    0.0,
+                    0.0,
+                    trans_z.to_pixels_em_aware(percent_resolve_x, em_size),
+                )
             } // ???
             Rotate3D(rot3d) => {
                 let rotation_origin = (
-                    transform_origin.x.to_pixels(percent_resolve_x),
-                    transform_origin.y.to_pixels(percent_resolve_y),
+                    transform_origin.x.to_pixels_em_aware(percent_resolve_x, em_size),
+                    transform_origin.y.to_pixels_em_aware(percent_resolve_y, em_size),
                 );
                 Self::make_rotation(
                     rotation_origin,
@@ -2037,8 +2038,8 @@ impl ComputedTransform3D {
             }
             RotateX(angle_x) => {
                 let rotation_origin = (
-                    transform_origin.x.to_pixels(percent_resolve_x),
-                    transform_origin.y.to_pixels(percent_resolve_y),
+                    transform_origin.x.to_pixels_em_aware(percent_resolve_x, em_size),
+                    transform_origin.y.to_pixels_em_aware(percent_resolve_y, em_size),
                 );
                 Self::make_rotation(
                     rotation_origin,
@@ -2051,8 +2052,8 @@ impl ComputedTransform3D {
             }
             RotateY(angle_y) => {
                 let rotation_origin = (
-                    transform_origin.x.to_pixels(percent_resolve_x),
-                    transform_origin.y.to_pixels(percent_resolve_y),
+                    transform_origin.x.to_pixels_em_aware(percent_resolve_x, em_size),
+                    transform_origin.y.to_pixels_em_aware(percent_resolve_y, em_size),
                 );
                 Self::make_rotation(
                     rotation_origin,
@@ -2065,8 +2066,8 @@ impl ComputedTransform3D {
             }
             Rotate(angle_z) | RotateZ(angle_z) => {
                 let rotation_origin = (
-                    transform_origin.x.to_pixels(percent_resolve_x),
-                    transform_origin.y.to_pixels(percent_resolve_y),
+                    transform_origin.x.to_pixels_em_aware(percent_resolve_x, em_size),
+                    transform_origin.y.to_pixels_em_aware(percent_resolve_y, em_size),
                 );
                 Self::make_rotation(
                     rotation_origin,
@@ -2089,7 +2090,7 @@ impl ComputedTransform3D {
             Skew(skew2d) => Self::new_skew(skew2d.x.normalized(), skew2d.y.normalized()),
             SkewX(skew_x) => Self::new_skew(skew_x.normalized(), 0.0),
             SkewY(skew_y) => Self::new_skew(0.0, skew_y.normalized()),
-            Perspective(px) => Self::new_perspective(px.to_pixels(percent_resolve_x)),
+            Perspective(px) => Self::new_perspective(px.to_pixels_em_aware(percent_resolve_x, em_size)),
         }
     }
 
@@ -1,19 +1,23 @@
 use crate::gl::OptionGlContextPtr;
 use crate::{
     app_resources::{
-        Epoch, GlTextureCache, IdNamespace, ImageCache, ImageMask, ImageRef, RendererResources,
-        ResourceUpdate, DpiScaleFactor,
+        Epoch, GlTextureCache, IdNamespace, ImageCache, ImageMask, ImageRef, RedrawBehavior,
+        RendererResources, ResourceUpdate, DpiScaleFactor,
     },
     callbacks::{Callback, HitTestItem, UpdateImageType},
     callbacks::{
         CallbackType, DocumentId, DomNodeId, LayoutCallback, LayoutCallbackType, OptionCallback,
         PipelineId, RefAny, ScrollPosition, Update,
     },
+    diff::DomDiff,
     display_list::RenderCallbacks,
-    dom::NodeHierarchy,
+    dom::{Dom, NodeHierarchy},
     id_tree::NodeId,
-    styled_dom::{DomId, NodeHierarchyItemId},
-    task::{ExternalSystemCallbacks, Instant, Thread, ThreadId, Timer, TimerId},
+    styled_dom::{DomId, NodeHierarchyItemId, StyledDom},
+    task::{
+        Duration, ExternalSystemCallbacks, Instant, SystemTimeDiff, Thread, ThreadId,
+        ThreadSendMsg, Timer, TimerId,
+    },
     ui_solver::{
         ExternalScrollId, HitTest, LayoutResult, OverflowingScrollNode, QuickResizeResult,
     },
@@ -25,8 +29,8 @@ use alloc::collections::btree_map::BTreeMap;
 use alloc::collections::btree_set::BTreeSet;
 use alloc::vec::Vec;
 use azul_css::{
-    AzString, ColorU, CssPath, CssProperty, LayoutPoint, LayoutRect, LayoutSize, OptionAzString,
-    OptionF32, OptionI32, U8Vec, FloatValue,
+    AzString, ColorU, CssPath, CssProperty, LayoutPoint, LayoutRect, LayoutSize, MediaEnvironment,
+    OptionAzString, OptionF32, OptionI32, StringVec, U8Vec, FloatValue,
 };
 use core::{
     cmp::Ordering,
@@ -35,6 +39,7 @@ use core::{
     ops,
     sync::atomic::{AtomicUsize, Ordering as AtomicOrdering},
 };
+use azul_css_parser::CssApiWrapper;
 use rust_fontconfig::FcFontCache;
 
 pub const DEFAULT_TITLE: &str = "Azul App";
@@ -316,6 +321,12 @@ pub struct KeyboardState {
     /// Use when the physical location of the key is more important than the key's host GUI semantics,
     /// such as for movement controls in a first-person game (German keyboard: Z key, UK keyboard: Y key, etc.)
     pub pressed_scancodes: ScanCodeVec,
+    /// Last event of the input method editor (IME) - (READONLY)
+    ///
+    /// Fires a `On::ImeStateChanged`, `On::ImePreedit` or `On::ImeCommit` event on change.
+    /// Committed text is only delivered as `On::ImeCommit`, not as `current_char` /
+    /// `On::TextInput`, so text fields have to handle both events.
+    pub current_ime_event: OptionImeEvent,
 }
 
 impl KeyboardState {
@@ -334,6 +345,15 @@ impl KeyboardState {
     pub fn is_key_down(&self, key: VirtualKeyCode) -> bool {
         self.pressed_virtual_keycodes.iter().any(|k| *k == key)
     }
+    /// Returns which modifier keys are currently held down
+    pub fn get_modifiers(&self) -> Modifiers {
+        Modifiers {
+            shift: self.shift_down(),
+            ctrl: self.ctrl_down(),
+            alt: self.alt_down(),
+            super_key: self.super_down(),
+        }
+    }
 }
 
 impl_option!(
@@ -355,6 +375,149 @@ impl_option!(
     [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
 
+/// Which modifier keys are held down (left and right keys are not distinguished)
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    /// Windows key on Windows / Linux, Command key on macOS
+    pub super_key: bool,
+}
+
+impl Modifiers {
+    pub const NONE: Modifiers = Modifiers {
+        shift: false,
+        ctrl: false,
+        alt: false,
+        super_key: false,
+    };
+    pub const CTRL: Modifiers = Modifiers {
+        ctrl: true,
+        ..Modifiers::NONE
+    };
+    pub const SHIFT: Modifiers = Modifiers {
+        shift: true,
+        ..Modifiers::NONE
+    };
+    pub const ALT: Modifiers = Modifiers {
+        alt: true,
+        ..Modifiers::NONE
+    };
+}
+
+/// Application-wide keyboard shortcut, i.e. `Ctrl+S`: fires regardless
+/// of which node (if any) is focused, see `WindowState::add_accelerator`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct Accelerator {
+    /// Modifiers that have to be held down - other modifiers must not be held down
+    pub modifiers: Modifiers,
+    pub key: VirtualKeyCode,
+}
+
+impl Accelerator {
+    pub const fn new(modifiers: Modifiers, key: VirtualKeyCode) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Returns whether the `key` was pressed between the `previous` and the
+    /// `current` keyboard state while exactly the `modifiers` were held down
+    pub fn is_triggered(&self, current: &KeyboardState, previous: &KeyboardState) -> bool {
+        current.current_virtual_keycode.into_option() == Some(self.key)
+            && previous.current_virtual_keycode != current.current_virtual_keycode
+            && current.get_modifiers() == self.modifiers
+    }
+}
+
+static LAST_ACCELERATOR_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// ID of a registered accelerator, used to replace or remove it
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct AcceleratorId {
+    pub id: usize,
+}
+
+impl AcceleratorId {
+    /// Generates a new, unique `AcceleratorId`
+    pub fn unique() -> Self {
+        AcceleratorId {
+            id: LAST_ACCELERATOR_ID.fetch_add(1, AtomicOrdering::SeqCst),
+        }
+    }
+}
+
+/// Accelerator + the callback to invoke when it is pressed
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[repr(C)]
+pub struct AcceleratorCallback {
+    pub id: AcceleratorId,
+    pub accelerator: Accelerator,
+    pub data: RefAny,
+    pub callback: Callback,
+}
+
+impl_vec!(
+    AcceleratorCallback,
+    AcceleratorCallbackVec,
+    AcceleratorCallbackVecDestructor
+);
+impl_vec_clone!(
+    AcceleratorCallback,
+    AcceleratorCallbackVec,
+    AcceleratorCallbackVecDestructor
+);
+impl_vec_mut!(AcceleratorCallback, AcceleratorCallbackVec);
+impl_vec_debug!(AcceleratorCallback, AcceleratorCallbackVec);
+impl_vec_partialeq!(AcceleratorCallback, AcceleratorCallbackVec);
+
+/// Event of the input method editor (IME), used to compose text
+/// that can't be typed directly (CJK characters, emojis, etc.)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum ImeEvent {
+    /// The IME was enabled, `Preedit` and `Commit` events can follow
+    Enabled,
+    /// The IME was disabled
+    Disabled,
+    /// The composition text has changed, but is not committed yet
+    Preedit(ImePreedit),
+    /// The composition has finished, the text should be inserted at the cursor
+    Commit(AzString),
+}
+
+impl_option!(
+    ImeEvent,
+    OptionImeEvent,
+    copy = false,
+    [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
+
+/// Text that is currently being composed by the IME (usually rendered underlined)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct ImePreedit {
+    pub text: AzString,
+    /// Cursor / selection in the `text`, `None` if the cursor should be hidden
+    pub cursor_range: OptionImeCursorRange,
+}
+
+/// Byte range in the `ImePreedit` text
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct ImeCursorRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl_option!(
+    ImeCursorRange,
+    OptionImeCursorRange,
+    [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
+
 impl_vec!(
     VirtualKeyCode,
     VirtualKeyCodeVec,
@@ -696,6 +859,17 @@ pub struct WindowInternal {
     pub timers: BTreeMap<TimerId, Timer>,
     /// List of threads running in the background
     pub threads: BTreeMap<ThreadId, Thread>,
+    /// Stylesheet set via `CallbackInfo::set_css`: if set, it replaces the
+    /// stylesheet of the `StyledDom` returned by the layout callback
+    pub stylesheet_override: Option<CssApiWrapper>,
+    /// `stylesheet_override` that the current `layout_results` were styled
+    /// with, the next frame can only be restyled incrementally if it is the same
+    pub applied_stylesheet_override: Option<CssApiWrapper>,
+    /// Popup menu drawn on top of the root DOM, on platforms without native
+    /// popup menus (see `set_overlay_menu` and `overlay_menu::overlay_menu_dom`)
+    pub overlay_menu: Option<ContextMenuRequest>,
+    /// Decides when the window renders the next frame (see `AppConfig::redraw_behavior`)
+    pub frame_scheduler: FrameScheduler,
 }
 
 impl WindowInternal {
@@ -704,6 +878,89 @@ impl WindowInternal {
     }
 }
 
+/// When the window should render the next frame, see `FrameScheduler::next_frame`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NextFrame {
+    /// Nothing changed, the event loop can sleep until the next event
+    Idle,
+    /// Render a frame now
+    RenderNow,
+    /// A redraw was requested, but the frame rate limit is reached:
+    /// render the frame at the given time (i.e. by setting a timer)
+    RenderAt(Instant),
+}
+
+/// Tracks the redraws requested by events / timers and the rendered frames
+/// of a window, so that the shell only renders as often as the
+/// `RedrawBehavior` of the app allows
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameScheduler {
+    pub behavior: RedrawBehavior,
+    /// Set by `request_redraw`, cleared when the frame is rendered
+    redraw_requested: bool,
+    /// When the last frame was rendered (`None` before the first frame)
+    last_frame: Option<Instant>,
+    /// Number of frames rendered since the window was created
+    frames_rendered: usize,
+}
+
+impl FrameScheduler {
+    /// The first frame of the window is requested on creation
+    pub fn new(behavior: RedrawBehavior) -> Self {
+        Self {
+            behavior,
+            redraw_requested: true,
+            last_frame: None,
+            frames_rendered: 0,
+        }
+    }
+
+    /// Marks the window contents as changed, i.e. after a callback
+    /// returned `Update::RefreshDom` or a scroll animation moved
+    pub fn request_redraw(&mut self) {
+        self.redraw_requested = true;
+    }
+
+    pub fn is_redraw_requested(&self) -> bool {
+        self.redraw_requested
+    }
+
+    pub fn get_frames_rendered(&self) -> usize {
+        self.frames_rendered
+    }
+
+    /// Returns whether the window has to render a frame at the time `now`
+    pub fn next_frame(&self, now: &Instant) -> NextFrame {
+        match self.behavior {
+            RedrawBehavior::Unlimited => NextFrame::RenderNow,
+            _ if !self.redraw_requested => NextFrame::Idle,
+            RedrawBehavior::OnEventOnly | RedrawBehavior::MaxFps(0) => NextFrame::RenderNow,
+            RedrawBehavior::MaxFps(fps) => {
+                let next = match self.last_frame.as_ref() {
+                    // tick-based instants have no fixed unit, can't be limited
+                    Some(last @ Instant::System(_)) if matches!(now, Instant::System(_)) => {
+                        let interval = SystemTimeDiff::from_nanos(1_000_000_000 / fps as u64);
+                        last.add_optional_duration(Some(&Duration::System(interval)))
+                    }
+                    _ => return NextFrame::RenderNow,
+                };
+                if next <= *now {
+                    NextFrame::RenderNow
+                } else {
+                    NextFrame::RenderAt(next)
+                }
+            }
+        }
+    }
+
+    /// Has to be called by the shell after every rendered frame
+    pub fn frame_rendered(&mut self, now: Instant) {
+        self.redraw_requested = false;
+        self.last_frame = Some(now);
+        self.frames_rendered += 1;
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FullHitTest {
     pub hovered_nodes: BTreeMap<DomId, HitTest>,
@@ -717,6 +974,94 @@ impl FullHitTest {
             focused_node: focused_node.and_then(|f| Some((f.dom, f.node.into_crate_internal()?))),
         }
     }
+
+    /// Hit-tests the layouted rectangles on the CPU, without a renderer
+    /// (used for headless windows). Only nodes with a tag (hover / active /
+    /// focus styles or callbacks) can be hit, same as in the renderer hit-test.
+    ///
+    /// NOTE: ignores clip rects, scroll offsets, transforms and iframes,
+    /// only the root DOM is hit-tested.
+    pub fn new(
+        layout_results: &[LayoutResult],
+        cursor_position: &CursorPosition,
+        old_focus_node: Option<DomNodeId>,
+    ) -> Self {
+        let cursor = match cursor_position {
+            CursorPosition::InWindow(pos) => *pos,
+            CursorPosition::OutOfWindow(_) | CursorPosition::Uninitialized => {
+                return Self::empty(old_focus_node)
+            }
+        };
+
+        // same as in the renderer hit-test: the focus is reset if no
+        // focusable node was hit
+        let mut ret = Self::empty(None);
+
+        let dom_id = DomId::ROOT_ID;
+        let layout_result = match layout_results.get(dom_id.inner) {
+            Some(s) => s,
+            None => return ret,
+        };
+
+        let node_data = layout_result.styled_dom.node_data.as_container();
+        let rects = layout_result.rects.as_ref();
+
+        for tag_id_to_node_id in layout_result.styled_dom.tag_ids_to_node_ids.iter() {
+            let node_id = match tag_id_to_node_id.node_id.into_crate_internal() {
+                Some(s) => s,
+                None => continue,
+            };
+            let rect = match rects.get(node_id) {
+                Some(s) => s,
+                None => continue,
+            };
+            let bounds = LogicalRect::new(rect.position.get_static_offset(), rect.size);
+            let point_relative_to_item = match bounds.hit_test(&cursor) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let is_focusable = node_data
+                .get(node_id)
+                .map(|n| n.get_tab_index().is_some())
+                .unwrap_or(false);
+
+            if is_focusable {
+                ret.focused_node = Some((dom_id, node_id));
+            }
+
+            ret.hovered_nodes
+                .entry(dom_id)
+                .or_insert_with(|| HitTest::empty())
+                .regular_hit_test_nodes
+                .insert(
+                    node_id,
+                    HitTestItem {
+                        point_in_viewport: cursor,
+                        point_relative_to_item,
+                        is_focusable,
+                        is_iframe_hit: None,
+                    },
+                );
+        }
+
+        ret
+    }
+
+    /// Returns the hovered node that is painted last (i.e. the deepest node
+    /// in the innermost iframe), used to determine the target of a file drop
+    pub fn get_topmost_hovered_node(&self) -> Option<DomNodeId> {
+        self.hovered_nodes
+            .iter()
+            .rev()
+            .find_map(|(dom_id, hit_test)| {
+                let (node_id, _) = hit_test.regular_hit_test_nodes.iter().next_back()?;
+                Some(DomNodeId {
+                    dom: *dom_id,
+                    node: NodeHierarchyItemId::from_crate_internal(Some(*node_id)),
+                })
+            })
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -793,6 +1138,7 @@ pub struct WindowInternalInit {
     pub window_create_options: WindowCreateOptions,
     pub document_id: DocumentId,
     pub id_namespace: IdNamespace,
+    pub redraw_behavior: RedrawBehavior,
 }
 
 impl WindowInternal {
@@ -820,7 +1166,7 @@ impl WindowInternal {
 
         let epoch = Epoch::new();
 
-        let styled_dom = {
+        let mut styled_dom = {
             let layout_callback = &mut init.window_create_options.state.layout_callback;
             let mut layout_info = LayoutCallbackInfo::new(
                 init.window_create_options.state.size,
@@ -830,23 +1176,22 @@ impl WindowInternal {
                 &fc_cache_real,
             );
 
-            match layout_callback {
-                LayoutCallback::Raw(r) => (r.cb)(data, &mut layout_info),
-                LayoutCallback::Marshaled(m) => {
-                    let marshal_data = &mut m.marshal_data;
-                    (m.cb.cb)(marshal_data, data, &mut layout_info)
-                }
-            }
+            // a panicking layout callback results in an empty window
+            layout_callback
+                .invoke(data, &mut layout_info)
+                .unwrap_or_default()
         };
 
         let mut current_window_state = FullWindowState::from_window_state(
             /*window_state: */ &init.window_create_options.state,
-            /*dropped_file: */ None,
-            /*hovered_file: */ None,
+            /*dropped_files: */ StringVec::from_const_slice(&[]),
+            /*hovered_files: */ StringVec::from_const_slice(&[]),
             /*focused_node: */ None,
             /*last_hit_test: */ FullHitTest::empty(/*current_focus*/ None),
         );
 
+        styled_dom.set_media_environment(current_window_state.get_media_environment());
+
         let SolvedLayout { mut layout_results } = SolvedLayout::new(
             styled_dom,
             epoch,
@@ -914,6 +1259,10 @@ impl WindowInternal {
             timers: BTreeMap::new(),
             threads: BTreeMap::new(),
             scroll_states,
+            stylesheet_override: None,
+            applied_stylesheet_override: None,
+            overlay_menu: None,
+            frame_scheduler: FrameScheduler::new(init.redraw_behavior),
         }
     }
 
@@ -951,34 +1300,83 @@ impl WindowInternal {
                 &fc_cache_real,
             );
 
-            match layout_callback {
-                LayoutCallback::Raw(r) => (r.cb)(data, &mut layout_info),
-                LayoutCallback::Marshaled(m) => {
-                    let marshal_data = &mut m.marshal_data;
-                    (m.cb.cb)(marshal_data, data, &mut layout_info)
-                }
+            // a panicking layout callback skips the frame and keeps the old layout
+            match layout_callback.invoke(data, &mut layout_info) {
+                Ok(o) => o,
+                Err(_) => return,
             }
         };
 
+        // changes since the last frame, used to restyle and to relayout incrementally
+        let diff = self
+            .layout_results
+            .first()
+            .map(|previous| previous.styled_dom.diff(&styled_dom));
+
+        if let Some(css) = self.stylesheet_override.as_ref() {
+            match (self.layout_results.first(), diff.as_ref()) {
+                // only match the CSS of the nodes that changed since the last frame
+                (Some(previous), Some(diff))
+                    if self.applied_stylesheet_override.as_ref() == Some(css) =>
+                {
+                    let mut dom = Dom::from(styled_dom);
+                    styled_dom = StyledDom::new_incremental(
+                        &mut dom,
+                        css.clone(),
+                        &previous.styled_dom,
+                        diff,
+                    );
+                }
+                _ => styled_dom.restyle(css.clone()),
+            }
+        }
+
+        if let Some(request) = self.overlay_menu.as_ref() {
+            let mut overlay = crate::overlay_menu::overlay_menu_dom(
+                request,
+                self.current_window_state.size.dimensions,
+            );
+            styled_dom.append_child(overlay.style(CssApiWrapper::empty()));
+        }
+
+        styled_dom.set_media_environment(self.current_window_state.get_media_environment());
+
         styled_dom.insert_default_system_callbacks(DefaultCallbacksCfg {
             smooth_scroll: self.current_window_state.flags.smooth_scroll_enabled,
             enable_autotab: self.current_window_state.flags.autotab_enabled,
         });
 
-        let SolvedLayout { mut layout_results } = SolvedLayout::new(
+        let relayout = self.relayout_incremental(
             styled_dom,
-            self.epoch,
-            &self.document_id,
-            &self.current_window_state,
-            all_resource_updates,
-            id_namespace,
+            diff.as_ref(),
             image_cache,
             &fc_cache_real,
-            callbacks,
-            &mut self.renderer_resources,
+            all_resource_updates,
             current_window_dpi,
+            callbacks,
+            relayout_fn,
         );
 
+        let mut layout_results = match relayout {
+            Ok(layout_results) => layout_results,
+            Err(styled_dom) => {
+                SolvedLayout::new(
+                    styled_dom,
+                    self.epoch,
+                    &self.document_id,
+                    &self.current_window_state,
+                    all_resource_updates,
+                    id_namespace,
+                    image_cache,
+                    &fc_cache_real,
+                    callbacks,
+                    &mut self.renderer_resources,
+                    current_window_dpi,
+                )
+                .layout_results
+            }
+        };
+
         // apply the changes for the first frame
         let ht = hit_test_func(
             &self.current_window_state,
@@ -1037,6 +1435,95 @@ impl WindowInternal {
         self.epoch.increment();
         self.layout_results = layout_results;
         self.gl_texture_cache = gl_texture_cache;
+        self.applied_stylesheet_override = self.stylesheet_override.clone();
+    }
+
+    /// Reuses the layout of the last frame for the new `styled_dom` if only
+    /// the text or the layout properties of some nodes changed (see
+    /// `StyledDom::get_relayout_changes`): only the changed nodes and the
+    /// nodes that depend on their size are laid out again.
+    ///
+    /// `diff` is the diff of the DOM of the last frame to the new DOM,
+    /// before the overlay menu was appended.
+    ///
+    /// Returns the `styled_dom` if it has to be laid out from scratch.
+    #[cfg(feature = "multithreading")]
+    fn relayout_incremental(
+        &mut self,
+        styled_dom: StyledDom,
+        diff: Option<&DomDiff>,
+        image_cache: &ImageCache,
+        fc_cache: &FcFontCache,
+        all_resource_updates: &mut Vec<ResourceUpdate>,
+        current_window_dpi: DpiScaleFactor,
+        callbacks: &RenderCallbacks,
+        relayout_fn: RelayoutFn,
+    ) -> Result<Vec<LayoutResult>, StyledDom> {
+        use crate::app_resources::add_fonts_and_images;
+
+        // iframe callbacks are only invoked by a full layout
+        let previous = match self.layout_results.as_slice() {
+            [previous] => previous,
+            _ => return Err(styled_dom),
+        };
+
+        // the diff doesn't contain the nodes of the overlay menu
+        let diff = match diff {
+            Some(s) if self.overlay_menu.is_none() => s,
+            _ => return Err(styled_dom),
+        };
+
+        let changes = match previous.styled_dom.get_relayout_changes(&styled_dom, diff) {
+            Some(s) => s,
+            None => return Err(styled_dom),
+        };
+
+        // the relayout can only replace the words of text
+        // nodes that were already laid out in the last frame
+        if changes
+            .words
+            .keys()
+            .any(|node_id| !previous.words_cache.contains_key(node_id))
+        {
+            return Err(styled_dom);
+        }
+
+        // register new background images
+        add_fonts_and_images(
+            image_cache,
+            &mut self.renderer_resources,
+            current_window_dpi,
+            fc_cache,
+            self.id_namespace,
+            self.epoch,
+            &self.document_id,
+            all_resource_updates,
+            &styled_dom,
+            callbacks.load_font_fn,
+            callbacks.parse_font_fn,
+            callbacks.insert_into_active_gl_textures_fn,
+        );
+
+        let mut layout_results = core::mem::take(&mut self.layout_results);
+        layout_results[0].styled_dom = styled_dom;
+
+        let root_bounds = LayoutRect::new(
+            LayoutPoint::zero(),
+            self.current_window_state.size.get_layout_size(),
+        );
+
+        let _ = (relayout_fn)(
+            DomId::ROOT_ID,
+            root_bounds,
+            &mut layout_results[0],
+            image_cache,
+            &mut self.renderer_resources,
+            &self.document_id,
+            Some(&changes.nodes).filter(|n| !n.is_empty()),
+            Some(&changes.words).filter(|w| !w.is_empty()),
+        );
+
+        Ok(layout_results)
     }
 
     /// Returns a copy of the current scroll states + scroll positions
@@ -1113,6 +1600,48 @@ impl WindowInternal {
         )
     }
 
+    /// Opens or closes the popup menu drawn on top of the root DOM, returns
+    /// whether the DOM has to be regenerated (same as `Update::RefreshDom`)
+    pub fn set_overlay_menu(&mut self, change: ContextMenuChange) -> bool {
+        match change {
+            ContextMenuChange::Open(request) => {
+                self.overlay_menu = Some(request);
+                true
+            }
+            ContextMenuChange::Close => self.overlay_menu.take().is_some(),
+        }
+    }
+
+    /// Forwards the messages that the callbacks sent to already running threads
+    /// (see `CallbackInfo::send_thread_msg`), messages for threads that have
+    /// been removed in the meantime are dropped
+    pub fn send_thread_msgs(&mut self, msgs: Vec<(ThreadId, ThreadSendMsg)>) {
+        for (thread_id, msg) in msgs {
+            let thread = match self.threads.get_mut(&thread_id) {
+                Some(s) => s,
+                None => continue,
+            };
+            if let Ok(mut thread) = thread.ptr.lock() {
+                let _ = thread.sender_send(msg);
+            }
+        }
+    }
+
+    /// Returns whether the `@media` queries of the current stylesheets match
+    /// differently for the current window size and theme than when the DOMs
+    /// were styled: if true, the DOM has to be regenerated
+    /// (same as `Update::RefreshDom`), a quick resize is not enough
+    ///
+    /// NOTE: only the root DOM is checked, iframe callbacks don't know the
+    /// window environment (their DOMs are styled with the default environment)
+    pub fn media_queries_changed(&self) -> bool {
+        let media_environment = self.current_window_state.get_media_environment();
+        self.layout_results
+            .get(DomId::ROOT_ID.inner)
+            .map(|lr| lr.styled_dom.media_queries_changed(&media_environment))
+            .unwrap_or(false)
+    }
+
     /// Returns whether the size or position of the window changed (if true,
     /// the caller needs to update the monitor field), since the window may have
     /// moved to a different monitor
@@ -1195,6 +1724,8 @@ impl WindowInternal {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            stylesheet_changed: None,
+            context_menu: None,
             words_changed: None,
             images_changed: None,
             image_masks_changed: None,
@@ -1204,6 +1735,7 @@ impl WindowInternal {
             threads: None,
             timers_removed: None,
             threads_removed: None,
+            thread_msgs_sent: Vec::new(),
             windows_created: Vec::new(),
             cursor_changed: false,
         };
@@ -1252,6 +1784,7 @@ impl WindowInternal {
                 &mut ret_threads,
                 &mut ret_timers_removed,
                 &mut ret_threads_removed,
+                &mut ret.thread_msgs_sent,
                 current_window_handle,
                 &mut ret.windows_created,
                 system_callbacks,
@@ -1261,6 +1794,8 @@ impl WindowInternal {
                 &mut ret_images_changed,
                 &mut ret_image_masks_changed,
                 &mut ret_css_properties_changed,
+                &mut ret.stylesheet_changed,
+                &mut ret.context_menu,
                 &current_scroll_states,
                 &mut ret_nodes_scrolled_in_callbacks,
                 hit_dom_node,
@@ -1346,6 +1881,8 @@ impl WindowInternal {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            stylesheet_changed: None,
+            context_menu: None,
             words_changed: None,
             images_changed: None,
             image_masks_changed: None,
@@ -1355,6 +1892,7 @@ impl WindowInternal {
             threads: None,
             timers_removed: None,
             threads_removed: None,
+            thread_msgs_sent: Vec::new(),
             windows_created: Vec::new(),
             cursor_changed: false,
         };
@@ -1392,10 +1930,22 @@ impl WindowInternal {
                 }
             };
 
+            // check whether the thread has finished *before* receiving, so that
+            // the messages sent right before the thread exited are not lost
+            let is_finished = thread.is_finished();
+
             let _ = thread.sender_send(ThreadSendMsg::Tick);
             let update = thread.receiver_try_recv();
             let msg = match update {
-                OptionThreadReceiveMsg::None => continue,
+                OptionThreadReceiveMsg::None => {
+                    // only remove the thread once all of its messages have been processed
+                    if is_finished {
+                        ret.threads_removed
+                            .get_or_insert_with(|| BTreeSet::default())
+                            .insert(*thread_id);
+                    }
+                    continue;
+                }
                 OptionThreadReceiveMsg::Some(s) => s,
             };
 
@@ -1420,6 +1970,7 @@ impl WindowInternal {
                 &mut ret_threads,
                 &mut ret_timers_removed,
                 &mut ret_threads_removed,
+                &mut ret.thread_msgs_sent,
                 current_window_handle,
                 &mut ret.windows_created,
                 system_callbacks,
@@ -1429,6 +1980,8 @@ impl WindowInternal {
                 &mut ret_images_changed,
                 &mut ret_image_masks_changed,
                 &mut ret_css_properties_changed,
+                &mut ret.stylesheet_changed,
+                &mut ret.context_menu,
                 &current_scroll_states,
                 &mut ret_nodes_scrolled_in_callbacks,
                 hit_dom_node,
@@ -1436,15 +1989,11 @@ impl WindowInternal {
                 cursor_in_viewport,
             );
 
-            let callback_update =
-                (callback.cb)(&mut thread.writeback_data, &mut data, &mut callback_info);
+            let callback_update = crate::error::catch_panic(|| {
+                (callback.cb)(&mut thread.writeback_data, &mut data, &mut callback_info)
+            })
+            .unwrap_or(Update::DoNothing);
             ret.callbacks_update_screen.max_self(callback_update);
-
-            if thread.is_finished() {
-                ret.threads_removed
-                    .get_or_insert_with(|| BTreeSet::default())
-                    .insert(*thread_id);
-            }
         }
 
         if !ret_timers.is_empty() {
@@ -1457,7 +2006,10 @@ impl WindowInternal {
             ret.modified_window_state = Some(ret_modified_window_state);
         }
         if !ret_threads_removed.is_empty() {
-            ret.threads_removed = Some(ret_threads_removed);
+            // merge with the finished threads
+            ret.threads_removed
+                .get_or_insert_with(|| BTreeSet::default())
+                .extend(ret_threads_removed);
         }
         if !ret_timers_removed.is_empty() {
             ret.timers_removed = Some(ret_timers_removed);
@@ -1513,6 +2065,8 @@ impl WindowInternal {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            stylesheet_changed: None,
+            context_menu: None,
             words_changed: None,
             images_changed: None,
             image_masks_changed: None,
@@ -1522,6 +2076,7 @@ impl WindowInternal {
             threads: None,
             timers_removed: None,
             threads_removed: None,
+            thread_msgs_sent: Vec::new(),
             windows_created: Vec::new(),
             cursor_changed: false,
         };
@@ -1557,6 +2112,7 @@ impl WindowInternal {
             &mut ret_threads,
             &mut ret_timers_removed,
             &mut ret_threads_removed,
+            &mut ret.thread_msgs_sent,
             current_window_handle,
             &mut ret.windows_created,
             system_callbacks,
@@ -1566,6 +2122,8 @@ impl WindowInternal {
             &mut ret_images_changed,
             &mut ret_image_masks_changed,
             &mut ret_css_properties_changed,
+            &mut ret.stylesheet_changed,
+            &mut ret.context_menu,
             &current_scroll_states,
             &mut ret_nodes_scrolled_in_callbacks,
             hit_dom_node,
@@ -1573,7 +2131,7 @@ impl WindowInternal {
             cursor_in_viewport,
         );
 
-        ret.callbacks_update_screen = (callback.cb)(data, &mut callback_info);
+        ret.callbacks_update_screen = callback.invoke(data, &mut callback_info);
 
         if !ret_timers.is_empty() {
             ret.timers = Some(ret_timers);
@@ -1634,6 +2192,8 @@ impl WindowInternal {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            stylesheet_changed: None,
+            context_menu: None,
             words_changed: None,
             images_changed: None,
             image_masks_changed: None,
@@ -1643,6 +2203,7 @@ impl WindowInternal {
             threads: None,
             timers_removed: None,
             threads_removed: None,
+            thread_msgs_sent: Vec::new(),
             windows_created: Vec::new(),
             cursor_changed: false,
         };
@@ -1678,6 +2239,7 @@ impl WindowInternal {
             &mut ret_threads,
             &mut ret_timers_removed,
             &mut ret_threads_removed,
+            &mut ret.thread_msgs_sent,
             current_window_handle,
             &mut ret.windows_created,
             system_callbacks,
@@ -1687,6 +2249,8 @@ impl WindowInternal {
             &mut ret_images_changed,
             &mut ret_image_masks_changed,
             &mut ret_css_properties_changed,
+            &mut ret.stylesheet_changed,
+            &mut ret.context_menu,
             &current_scroll_states,
             &mut ret_nodes_scrolled_in_callbacks,
             hit_dom_node,
@@ -1695,7 +2259,7 @@ impl WindowInternal {
         );
 
         ret.callbacks_update_screen =
-            (menu_callback.callback.cb)(&mut menu_callback.data, &mut callback_info);
+            menu_callback.callback.invoke(&mut menu_callback.data, &mut callback_info);
 
         if !ret_timers.is_empty() {
             ret.timers = Some(ret_timers);
@@ -1870,6 +2434,8 @@ pub struct WindowState {
     pub layout_callback: LayoutCallback,
     /// Optional callback to run when the window closes
     pub close_callback: OptionCallback,
+    /// Keyboard shortcuts of this window, see `add_accelerator`
+    pub accelerators: AcceleratorCallbackVec,
 }
 
 impl_option!(
@@ -1953,13 +2519,16 @@ pub struct FullWindowState {
     /// Callback to run before the window closes. If this callback returns `DoNothing`,
     /// the window won't close, otherwise it'll close regardless
     pub close_callback: OptionCallback,
+    /// Keyboard shortcuts, dispatched before the key events of the nodes
+    pub accelerators: AcceleratorCallbackVec,
     // --
     /// Current monitor
     pub monitor: Monitor,
-    /// Whether there is a file currently hovering over the window
-    pub hovered_file: Option<AzString>, // Option<PathBuf>
-    /// Whether there was a file currently dropped on the window
-    pub dropped_file: Option<AzString>, // Option<PathBuf>
+    /// Paths of the files that are currently dragged over the window,
+    /// empty if no file drag is in progress
+    pub hovered_files: StringVec,
+    /// Paths of the files of the last drop onto the window
+    pub dropped_files: StringVec,
     /// What node is currently hovered over, default to None. Only necessary internal
     /// to the crate, for emitting `On::FocusReceived` and `On::FocusLost` events,
     /// as well as styling `:focus` elements
@@ -1987,11 +2556,12 @@ impl Default for FullWindowState {
             background_color: ColorU::WHITE,
             layout_callback: LayoutCallback::default(),
             close_callback: OptionCallback::None,
+            accelerators: AcceleratorCallbackVec::from_const_slice(&[]),
             renderer_options: RendererOptions::default(),
             monitor: Monitor::default(),
             // --
-            hovered_file: None,
-            dropped_file: None,
+            hovered_files: StringVec::from_const_slice(&[]),
+            dropped_files: StringVec::from_const_slice(&[]),
             focused_node: None,
             last_hit_test: FullHitTest::empty(None),
         }
@@ -2007,12 +2577,12 @@ impl FullWindowState {
         &self.keyboard_state
     }
 
-    pub fn get_hovered_file(&self) -> Option<&AzString> {
-        self.hovered_file.as_ref()
+    pub fn get_hovered_files(&self) -> &[AzString] {
+        self.hovered_files.as_ref()
     }
 
-    pub fn get_dropped_file(&self) -> Option<&AzString> {
-        self.dropped_file.as_ref()
+    pub fn get_dropped_files(&self) -> &[AzString] {
+        self.dropped_files.as_ref()
     }
 
     pub fn get_scroll_amount(&self) -> Option<(f32, f32)> {
@@ -2026,6 +2596,16 @@ impl FullWindowState {
         }
     }
 
+    /// Returns the size, DPI and theme of the window that CSS `@media` queries are evaluated against
+    pub fn get_media_environment(&self) -> MediaEnvironment {
+        MediaEnvironment {
+            width: self.size.dimensions.width,
+            height: self.size.dimensions.height,
+            dpi: self.size.dpi as f32,
+            dark_mode: self.theme == WindowTheme::DarkMode,
+        }
+    }
+
     /// Creates a FullWindowState from a regular WindowState,
     /// fills non-available fields with the given values
     ///
@@ -2033,8 +2613,8 @@ impl FullWindowState {
     /// to prevent state management bugs
     pub fn from_window_state(
         window_state: &WindowState,
-        dropped_file: Option<AzString>,
-        hovered_file: Option<AzString>,
+        dropped_files: StringVec,
+        hovered_files: StringVec,
         focused_node: Option<DomNodeId>,
         last_hit_test: FullHitTest,
     ) -> Self {
@@ -2054,9 +2634,10 @@ impl FullWindowState {
             background_color: window_state.background_color,
             layout_callback: window_state.layout_callback.clone(),
             close_callback: window_state.close_callback,
+            accelerators: window_state.accelerators.clone(),
             renderer_options: window_state.renderer_options,
-            dropped_file,
-            hovered_file,
+            dropped_files,
+            hovered_files,
             focused_node,
             last_hit_test,
         }
@@ -2087,6 +2668,7 @@ impl From<FullWindowState> for WindowState {
             background_color: full_window_state.background_color,
             layout_callback: full_window_state.layout_callback,
             close_callback: full_window_state.close_callback,
+            accelerators: full_window_state.accelerators,
             renderer_options: full_window_state.renderer_options,
         }
     }
@@ -2112,6 +2694,10 @@ pub struct CallCallbacksResult {
     /// If the focus target changes in the callbacks, the function will automatically
     /// restyle the DOM and set the new focus target
     pub css_properties_changed: Option<BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>>,
+    /// Stylesheet that replaces the stylesheet of the current window (see `CallbackInfo::set_css`)
+    pub stylesheet_changed: Option<CssApiWrapper>,
+    /// Popup menu opened or closed in the callbacks (see `CallbackInfo::open_context_menu`)
+    pub context_menu: Option<ContextMenuChange>,
     /// If the callbacks have scrolled any nodes, the new scroll position will be stored here
     pub nodes_scrolled_in_callbacks:
         Option<BTreeMap<DomId, BTreeMap<NodeHierarchyItemId, LogicalPosition>>>,
@@ -2125,6 +2711,8 @@ pub struct CallCallbacksResult {
     pub timers_removed: Option<FastBTreeSet<TimerId>>,
     /// Tasks that were added in the callbacks
     pub threads_removed: Option<FastBTreeSet<ThreadId>>,
+    /// Messages sent to running threads (see `CallbackInfo::send_thread_msg`)
+    pub thread_msgs_sent: Vec<(ThreadId, ThreadSendMsg)>,
     /// Windows that were created in the callbacks
     pub windows_created: Vec<WindowCreateOptions>,
     /// Whether the cursor changed in the callbacks
@@ -2469,6 +3057,58 @@ impl WindowState {
     pub fn get_hidpi_factor(&self) -> f32 {
         self.size.get_hidpi_factor()
     }
+
+    /// Registers a keyboard shortcut that invokes the `callback` regardless of
+    /// the focused node. The accelerators are dispatched before the
+    /// `VirtualKeyDown` callbacks of the nodes: if the callback returns anything
+    /// other than `Update::DoNothing`, the key event is consumed.
+    pub fn add_accelerator(
+        &mut self,
+        accelerator: Accelerator,
+        data: RefAny,
+        callback: CallbackType,
+    ) -> AcceleratorId {
+        let id = AcceleratorId::unique();
+        let mut accelerators = self.accelerators.clone().into_library_owned_vec();
+        accelerators.push(AcceleratorCallback {
+            id,
+            accelerator,
+            data,
+            callback: Callback { cb: callback },
+        });
+        self.accelerators = accelerators.into();
+        id
+    }
+
+    /// Replaces the shortcut and callback of an accelerator,
+    /// returns `false` if the `id` doesn't exist
+    pub fn replace_accelerator(
+        &mut self,
+        id: AcceleratorId,
+        accelerator: Accelerator,
+        data: RefAny,
+        callback: CallbackType,
+    ) -> bool {
+        match self.accelerators.iter_mut().find(|a| a.id == id) {
+            Some(a) => {
+                a.accelerator = accelerator;
+                a.data = data;
+                a.callback = Callback { cb: callback };
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes an accelerator, returns `false` if the `id` doesn't exist
+    pub fn remove_accelerator(&mut self, id: AcceleratorId) -> bool {
+        let mut accelerators = self.accelerators.clone().into_library_owned_vec();
+        let len = accelerators.len();
+        accelerators.retain(|a| a.id != id);
+        let removed = accelerators.len() != len;
+        self.accelerators = accelerators.into();
+        removed
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2631,6 +3271,23 @@ impl WindowCreateOptions {
             ..WindowCreateOptions::default()
         }
     }
+
+    /// Replaces the layout callback of this window, so that windows of
+    /// the same app can render different UIs
+    pub fn set_layout_callback(&mut self, callback: LayoutCallbackType) {
+        use crate::callbacks::LayoutCallbackInner;
+        self.state.layout_callback = LayoutCallback::Raw(LayoutCallbackInner { cb: callback });
+    }
+
+    /// Registers a keyboard shortcut for the window, see `WindowState::add_accelerator`
+    pub fn add_accelerator(
+        &mut self,
+        accelerator: Accelerator,
+        data: RefAny,
+        callback: CallbackType,
+    ) -> AcceleratorId {
+        self.state.add_accelerator(accelerator, data, callback)
+    }
 }
 
 #[repr(C)]
@@ -2689,6 +3346,12 @@ impl core::fmt::Display for LogicalRect {
     }
 }
 
+impl_option!(
+    LogicalRect,
+    OptionLogicalRect,
+    [Debug, Copy, Clone, PartialEq, PartialOrd]
+);
+
 impl LogicalRect {
     pub const fn zero() -> Self {
         Self::new(LogicalPosition::zero(), LogicalSize::zero())
@@ -3441,6 +4104,31 @@ impl Default for ContextMenuMouseButton {
 }
 
 impl Menu {
+    /// Returns the keyboard shortcuts of all enabled items (including sub-menus)
+    /// that have a callback, see `VirtualKeyCodeCombo::to_accelerator`
+    pub fn get_accelerators(&self) -> Vec<(Accelerator, MenuCallback)> {
+        fn collect(items: &[MenuItem], out: &mut Vec<(Accelerator, MenuCallback)>) {
+            for item in items {
+                let item = match item {
+                    MenuItem::String(s) => s,
+                    _ => continue,
+                };
+                if !item.is_enabled() {
+                    continue;
+                }
+                let accelerator = item.accelerator.as_option().and_then(|a| a.to_accelerator());
+                if let (Some(accelerator), Some(callback)) = (accelerator, item.callback.as_option()) {
+                    out.push((accelerator, callback.clone()));
+                }
+                collect(item.children.as_ref(), out);
+            }
+        }
+
+        let mut accelerators = Vec::new();
+        collect(self.items.as_ref(), &mut accelerators);
+        accelerators
+    }
+
     pub fn swap_with_default(&mut self) -> Self {
         let mut new = Self::default();
         core::mem::swap(&mut new, self);
@@ -3448,6 +4136,24 @@ impl Menu {
     }
 }
 
+/// Popup menu opened from a callback, see `CallbackInfo::open_context_menu`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContextMenuRequest {
+    pub menu: Menu,
+    /// Top left corner of the menu, relative to the window
+    pub position: LogicalPosition,
+    /// Node that the menu was opened on, passed to the callbacks of the menu items
+    pub hit_dom_node: DomNodeId,
+}
+
+/// Popup menu change requested by a callback
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextMenuChange {
+    Open(ContextMenuRequest),
+    /// Only closes menus drawn by azul, native popup menus close themselves
+    Close,
+}
+
 /// Position of where the menu should popup on the screen
 ///
 /// Ignored for application-level menus
@@ -3564,6 +4270,21 @@ impl StringMenuItem {
         .into();
         self
     }
+
+    pub fn with_accelerator(mut self, accelerator: VirtualKeyCodeCombo) -> Self {
+        self.accelerator = Some(accelerator).into();
+        self
+    }
+
+    pub fn with_state(mut self, state: MenuItemState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Returns whether clicking the menu item should invoke the callback
+    pub fn is_enabled(&self) -> bool {
+        self.state == MenuItemState::Normal
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
@@ -3572,6 +4293,62 @@ pub struct VirtualKeyCodeCombo {
     pub keys: VirtualKeyCodeVec,
 }
 
+impl VirtualKeyCodeCombo {
+    pub fn new(keys: VirtualKeyCodeVec) -> Self {
+        Self { keys }
+    }
+
+    /// Converts the combination to an `Accelerator`, i.e. `[LControl, S]` to `Ctrl+S`.
+    /// Returns `None` if the combination doesn't contain exactly one non-modifier key.
+    pub fn to_accelerator(&self) -> Option<Accelerator> {
+        use self::VirtualKeyCode::*;
+        let mut modifiers = Modifiers::NONE;
+        let mut key = None;
+        for k in self.keys.as_ref().iter() {
+            match k {
+                LControl | RControl => modifiers.ctrl = true,
+                LShift | RShift => modifiers.shift = true,
+                LAlt | RAlt => modifiers.alt = true,
+                LWin | RWin => modifiers.super_key = true,
+                other if key.is_none() => key = Some(*other),
+                _ => return None,
+            }
+        }
+        Some(Accelerator::new(modifiers, key?))
+    }
+}
+
+/// Formats the combination as a human-readable accelerator label,
+/// i.e. `[LControl, LShift, S]` as `"Ctrl+Shift+S"`
+impl core::fmt::Display for VirtualKeyCodeCombo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use self::VirtualKeyCode::*;
+        for (i, key) in self.keys.as_ref().iter().enumerate() {
+            if i != 0 {
+                write!(f, "+")?;
+            }
+            match key {
+                LControl | RControl => write!(f, "Ctrl")?,
+                LShift | RShift => write!(f, "Shift")?,
+                LAlt | RAlt => write!(f, "Alt")?,
+                LWin | RWin => write!(f, "Win")?,
+                Key1 => write!(f, "1")?,
+                Key2 => write!(f, "2")?,
+                Key3 => write!(f, "3")?,
+                Key4 => write!(f, "4")?,
+                Key5 => write!(f, "5")?,
+                Key6 => write!(f, "6")?,
+                Key7 => write!(f, "7")?,
+                Key8 => write!(f, "8")?,
+                Key9 => write!(f, "9")?,
+                Key0 => write!(f, "0")?,
+                other => write!(f, "{:?}", other)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 impl_option!(
     VirtualKeyCodeCombo,
     OptionVirtualKeyCodeCombo,
@@ -3622,3 +4399,79 @@ pub enum MenuItemState {
     /// Menu item is disabled, but NOT greyed out
     Disabled,
 }
+
+impl Default for MenuItemState {
+    fn default() -> Self {
+        MenuItemState::Normal
+    }
+}
+
+#[test]
+fn test_menu_item_is_enabled() {
+    let item = StringMenuItem::new("Save".into());
+    assert!(item.is_enabled());
+    assert!(!item.clone().with_state(MenuItemState::Greyed).is_enabled());
+    assert!(!item.with_state(MenuItemState::Disabled).is_enabled());
+}
+
+#[test]
+fn test_virtual_key_code_combo() {
+    use self::VirtualKeyCode::*;
+
+    let combo = |keys: &[VirtualKeyCode]| VirtualKeyCodeCombo::new(keys.to_vec().into());
+
+    assert_eq!(combo(&[LControl, LShift, S]).to_string(), "Ctrl+Shift+S");
+    assert_eq!(combo(&[RAlt, Key1]).to_string(), "Alt+1");
+    assert_eq!(combo(&[F5]).to_string(), "F5");
+
+    assert_eq!(
+        combo(&[LControl, LShift, S]).to_accelerator(),
+        Some(Accelerator::new(
+            Modifiers { ctrl: true, shift: true, ..Modifiers::NONE },
+            S
+        ))
+    );
+    assert_eq!(combo(&[F5]).to_accelerator(), Some(Accelerator::new(Modifiers::NONE, F5)));
+    // no key or more than one non-modifier key
+    assert_eq!(combo(&[LControl]).to_accelerator(), None);
+    assert_eq!(combo(&[LControl, A, B]).to_accelerator(), None);
+}
+
+#[test]
+fn test_menu_get_accelerators() {
+    use self::VirtualKeyCode::*;
+
+    extern "C" fn cb(_: &mut RefAny, _: &mut crate::callbacks::CallbackInfo) -> Update {
+        Update::DoNothing
+    }
+
+    let item = |label: &str, key: VirtualKeyCode| {
+        StringMenuItem::new(AzString::from(label.to_string()))
+            .with_callback(RefAny::new(()), cb)
+            .with_accelerator(VirtualKeyCodeCombo::new(vec![LControl, key].into()))
+    };
+
+    let menu = Menu::new(
+        vec![MenuItem::String(StringMenuItem::new("File".into()).with_children(
+            vec![
+                MenuItem::String(item("Open", O)),
+                MenuItem::Separator,
+                MenuItem::String(item("Save", S).with_state(MenuItemState::Greyed)),
+                // accelerator without a callback
+                MenuItem::String(
+                    StringMenuItem::new("Quit".into())
+                        .with_accelerator(VirtualKeyCodeCombo::new(vec![LControl, Q].into())),
+                ),
+            ]
+            .into(),
+        ))]
+        .into(),
+    );
+
+    let accelerators = menu
+        .get_accelerators()
+        .into_iter()
+        .map(|(a, _)| a)
+        .collect::<Vec<_>>();
+    assert_eq!(accelerators, vec![Accelerator::new(Modifiers::CTRL, O)]);
+}
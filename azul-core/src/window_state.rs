@@ -2,7 +2,7 @@
 //!
 //! # Example
 //!
-//! ```rust
+//! ```rust,ignore
 //! let mut app_state = RefAny::new(AppState { counter: 5 });
 //! let pipeline_id = PipelineId::new(0);
 //!
@@ -61,7 +61,7 @@
 //!
 //!      #break; // - for doc test
 //! }
-//! ```rust
+//! ```
 
 use crate::gl::OptionGlContextPtr;
 use crate::{
@@ -72,7 +72,10 @@ use crate::{
     styled_dom::{ChangedCssProperty, DomId, NodeHierarchyItemId},
     task::ExternalSystemCallbacks,
     ui_solver::{GpuEventChanges, LayoutResult, RelayoutChanges},
-    window::{CallCallbacksResult, FullHitTest, FullWindowState, RawWindowHandle, ScrollStates},
+    window::{
+        CallCallbacksResult, FullHitTest, FullWindowState, ImeEvent, OptionLogicalPosition,
+        RawWindowHandle, ScrollStates,
+    },
     FastBTreeSet, FastHashMap,
 };
 use alloc::boxed::Box;
@@ -667,7 +670,7 @@ impl StyleAndLayoutChanges {
             !(style_changes.iter().all(|(_, restyle_nodes)| {
                 restyle_nodes.iter().all(|(_, changed_css_properties)| {
                     changed_css_properties.iter().all(|changed_prop| {
-                        changed_prop.current_prop.get_type().is_gpu_only_property()
+                        changed_prop.current_prop.is_gpu_only_property()
                     })
                 })
             }))
@@ -1022,6 +1025,8 @@ impl CallbacksOfHitTest {
             callbacks_update_screen: Update::DoNothing,
             modified_window_state: None,
             css_properties_changed: None,
+            stylesheet_changed: None,
+            context_menu: None,
             words_changed: None,
             images_changed: None,
             image_masks_changed: None,
@@ -1031,6 +1036,7 @@ impl CallbacksOfHitTest {
             threads: None,
             timers_removed: None,
             threads_removed: None,
+            thread_msgs_sent: Vec::new(),
             windows_created: Vec::new(),
             cursor_changed: false,
         };
@@ -1038,7 +1044,37 @@ impl CallbacksOfHitTest {
 
         let current_cursor = full_window_state.mouse_state.mouse_cursor_type.clone();
 
-        if self.nodes_with_callbacks.is_empty() {
+        // keyboard shortcuts of the window, independent of the focused node
+        let previous_keyboard_state = previous_window_state
+            .as_ref()
+            .map(|s| s.keyboard_state.clone())
+            .unwrap_or_default();
+
+        // shortcuts of the menu bar items are dispatched like the window accelerators
+        let menu_bar_accelerators = layout_results
+            .first()
+            .and_then(|lr| {
+                let node_data = lr.styled_dom.node_data.as_container();
+                Some(node_data.get(NodeId::ZERO)?.get_menu_bar()?.get_accelerators())
+            })
+            .unwrap_or_default();
+
+        let triggered_accelerators = full_window_state
+            .accelerators
+            .iter()
+            .map(|a| (a.accelerator, a.data.clone(), a.callback.clone()))
+            .chain(
+                menu_bar_accelerators
+                    .into_iter()
+                    .map(|(accelerator, cb)| (accelerator, cb.data, cb.callback)),
+            )
+            .filter(|(accelerator, _, _)| {
+                accelerator.is_triggered(&full_window_state.keyboard_state, &previous_keyboard_state)
+            })
+            .map(|(_, data, callback)| (data, callback))
+            .collect::<Vec<_>>();
+
+        if self.nodes_with_callbacks.is_empty() && triggered_accelerators.is_empty() {
             // common case
             return ret;
         }
@@ -1055,6 +1091,72 @@ impl CallbacksOfHitTest {
         let mut ret_css_properties_changed = BTreeMap::new();
         let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
 
+        // accelerators run before the key events of the nodes: if one of them
+        // handles the key, the VirtualKeyDown callbacks of the nodes are skipped
+        let mut key_consumed = false;
+        for (mut data, callback) in triggered_accelerators {
+            let mut new_focus = None;
+            let mut stop_propagation = false;
+
+            let mut callback_info = CallbackInfo::new(
+                /*layout_results:*/ &layout_results,
+                /*renderer_resources:*/ renderer_resources,
+                /*previous_window_state:*/ &previous_window_state,
+                /*current_window_state:*/ &full_window_state,
+                /*modifiable_window_state:*/ &mut ret_modified_window_state,
+                /*gl_context,*/ gl_context,
+                /*image_cache,*/ image_cache,
+                /*system_fonts,*/ system_fonts,
+                /*timers:*/ &mut ret_timers,
+                /*threads:*/ &mut ret_threads,
+                /*timers_removed:*/ &mut ret_timers_removed,
+                /*threads_removed:*/ &mut ret_threads_removed,
+                /*thread_msgs_sent:*/ &mut ret.thread_msgs_sent,
+                /*current_window_handle:*/ raw_window_handle,
+                /*new_windows:*/ &mut ret.windows_created,
+                /*system_callbacks*/ system_callbacks,
+                /*stop_propagation:*/ &mut stop_propagation,
+                /*focus_target:*/ &mut new_focus,
+                /*words_changed_in_callbacks:*/ &mut ret_words_changed,
+                /*images_changed_in_callbacks:*/ &mut ret_images_changed,
+                /*image_masks_changed_in_callbacks:*/
+                &mut ret_image_masks_changed,
+                /*css_properties_changed_in_callbacks:*/
+                &mut ret_css_properties_changed,
+                /*stylesheet_changed_in_callbacks:*/ &mut ret.stylesheet_changed,
+                /*context_menu_changed_in_callbacks:*/ &mut ret.context_menu,
+                /*current_scroll_states:*/ scroll_states,
+                /*nodes_scrolled_in_callback:*/
+                &mut ret_nodes_scrolled_in_callbacks,
+                /*hit_dom_node:*/ DomNodeId::ROOT,
+                /*cursor_relative_to_item:*/ OptionLogicalPosition::None,
+                /*cursor_in_viewport:*/ OptionLogicalPosition::None,
+            );
+
+            let callback_return = callback.invoke(&mut data, &mut callback_info);
+
+            if callback_return != Update::DoNothing {
+                key_consumed = true;
+            }
+
+            ret.callbacks_update_screen.max_self(callback_return);
+
+            if let Some(new_focus) = new_focus {
+                new_focus_target = Some(new_focus);
+            }
+        }
+
+        if key_consumed {
+            for callbacks_filter_list in self.nodes_with_callbacks.values_mut() {
+                callbacks_filter_list.retain(|cbtc| match cbtc.event_filter {
+                    EventFilter::Window(WindowEventFilter::VirtualKeyDown)
+                    | EventFilter::Hover(HoverEventFilter::VirtualKeyDown)
+                    | EventFilter::Focus(FocusEventFilter::VirtualKeyDown) => false,
+                    _ => true,
+                });
+            }
+        }
+
         {
             for (dom_id, callbacks_filter_list) in self.nodes_with_callbacks.iter() {
                 let mut callbacks = BTreeMap::new();
@@ -1104,6 +1206,7 @@ impl CallbacksOfHitTest {
                                 /*threads:*/ &mut ret_threads,
                                 /*timers_removed:*/ &mut ret_timers_removed,
                                 /*threads_removed:*/ &mut ret_threads_removed,
+                                /*thread_msgs_sent:*/ &mut ret.thread_msgs_sent,
                                 /*current_window_handle:*/ raw_window_handle,
                                 /*new_windows:*/ &mut ret.windows_created,
                                 /*system_callbacks*/ system_callbacks,
@@ -1115,6 +1218,8 @@ impl CallbacksOfHitTest {
                                 &mut ret_image_masks_changed,
                                 /*css_properties_changed_in_callbacks:*/
                                 &mut ret_css_properties_changed,
+                                /*stylesheet_changed_in_callbacks:*/ &mut ret.stylesheet_changed,
+                                /*context_menu_changed_in_callbacks:*/ &mut ret.context_menu,
                                 /*current_scroll_states:*/ scroll_states,
                                 /*nodes_scrolled_in_callback:*/
                                 &mut ret_nodes_scrolled_in_callbacks,
@@ -1145,7 +1250,7 @@ impl CallbacksOfHitTest {
                                 {
                                     let mut callback_data_clone = callback_data.clone();
                                     // Invoke callback
-                                    (callback_data_clone.callback.cb)(
+                                    callback_data_clone.callback.invoke(
                                         &mut callback_data_clone.data,
                                         &mut callback_info,
                                     )
@@ -1199,6 +1304,7 @@ impl CallbacksOfHitTest {
                             /*threads:*/ &mut ret_threads,
                             /*timers_removed:*/ &mut ret_timers_removed,
                             /*threads_removed:*/ &mut ret_threads_removed,
+                            /*thread_msgs_sent:*/ &mut ret.thread_msgs_sent,
                             /*current_window_handle:*/ raw_window_handle,
                             /*new_windows:*/ &mut ret.windows_created,
                             /*system_callbacks*/ system_callbacks,
@@ -1210,6 +1316,8 @@ impl CallbacksOfHitTest {
                             &mut ret_image_masks_changed,
                             /*css_properties_changed_in_callbacks:*/
                             &mut ret_css_properties_changed,
+                            /*stylesheet_changed_in_callbacks:*/ &mut ret.stylesheet_changed,
+                            /*context_menu_changed_in_callbacks:*/ &mut ret.context_menu,
                             /*current_scroll_states:*/ scroll_states,
                             /*nodes_scrolled_in_callback:*/
                             &mut ret_nodes_scrolled_in_callbacks,
@@ -1240,7 +1348,7 @@ impl CallbacksOfHitTest {
                             {
                                 // Invoke callback
                                 let mut callback_data_clone = callback_data.clone();
-                                (callback_data_clone.callback.cb)(
+                                callback_data_clone.callback.invoke(
                                     &mut callback_data_clone.data,
                                     &mut callback_info,
                                 )
@@ -1485,6 +1593,24 @@ fn get_window_events(
         events.push(WindowEventFilter::TextInput);
     }
 
+    let cur_ime_equal = current_window_state.keyboard_state.current_ime_event
+        == previous_window_state.keyboard_state.current_ime_event;
+
+    if !cur_ime_equal {
+        match current_window_state
+            .keyboard_state
+            .current_ime_event
+            .as_ref()
+        {
+            Some(ImeEvent::Enabled) | Some(ImeEvent::Disabled) => {
+                events.push(WindowEventFilter::ImeStateChanged);
+            }
+            Some(ImeEvent::Preedit(_)) => events.push(WindowEventFilter::ImePreedit),
+            Some(ImeEvent::Commit(_)) => events.push(WindowEventFilter::ImeCommit),
+            None => {}
+        }
+    }
+
     if !cur_vk_equal
         && previous_window_state
             .keyboard_state
@@ -1500,21 +1626,26 @@ fn get_window_events(
 
     // misc events
 
-    let hovered_file_equals =
-        previous_window_state.hovered_file == current_window_state.hovered_file;
-    if previous_window_state.hovered_file.is_none()
-        && current_window_state.hovered_file.is_some()
-        && !hovered_file_equals
+    let hovered_files_equal =
+        previous_window_state.hovered_files == current_window_state.hovered_files;
+    let dropped_files_equal =
+        previous_window_state.dropped_files == current_window_state.dropped_files;
+
+    if previous_window_state.hovered_files.is_empty()
+        && !current_window_state.hovered_files.is_empty()
+        && !hovered_files_equal
     {
         events.push(WindowEventFilter::HoveredFile);
     }
 
-    if previous_window_state.hovered_file.is_some() && current_window_state.hovered_file.is_none() {
-        if current_window_state.dropped_file.is_some() {
-            events.push(WindowEventFilter::DroppedFile);
-        } else {
-            events.push(WindowEventFilter::HoveredFileCancelled);
-        }
+    // NOTE: not every platform reports a hover before the drop,
+    // so the drop is detected independently of the hovered files
+    if !dropped_files_equal && !current_window_state.dropped_files.is_empty() {
+        events.push(WindowEventFilter::DroppedFile);
+    } else if !previous_window_state.hovered_files.is_empty()
+        && current_window_state.hovered_files.is_empty()
+    {
+        events.push(WindowEventFilter::HoveredFileCancelled);
     }
 
     if current_window_state.theme != previous_window_state.theme {
@@ -1537,3 +1668,95 @@ fn get_focus_events(input: &[HoverEventFilter]) -> Vec<FocusEventFilter> {
         .filter_map(|hover_event| hover_event.to_focus_event_filter())
         .collect()
 }
+
+#[test]
+fn test_ime_window_events() {
+    use crate::window::{ImeCursorRange, ImePreedit};
+
+    let sequence = [
+        (
+            Some(ImeEvent::Enabled),
+            Some(WindowEventFilter::ImeStateChanged),
+        ),
+        (
+            Some(ImeEvent::Preedit(ImePreedit {
+                text: "にほ".into(),
+                cursor_range: Some(ImeCursorRange { start: 6, end: 6 }).into(),
+            })),
+            Some(WindowEventFilter::ImePreedit),
+        ),
+        (
+            Some(ImeEvent::Commit("日本".into())),
+            Some(WindowEventFilter::ImeCommit),
+        ),
+        // unchanged event: must not fire again
+        (Some(ImeEvent::Commit("日本".into())), None),
+        (
+            Some(ImeEvent::Disabled),
+            Some(WindowEventFilter::ImeStateChanged),
+        ),
+        (None, None),
+    ];
+
+    let mut previous = FullWindowState::default();
+    for (ime_event, expected) in sequence.iter() {
+        let mut current = previous.clone();
+        current.keyboard_state.current_ime_event = ime_event.clone().into();
+
+        let events = get_window_events(&current, &Some(previous.clone()));
+        let ime_events = events
+            .into_iter()
+            .filter(|e| match e {
+                WindowEventFilter::ImeStateChanged
+                | WindowEventFilter::ImePreedit
+                | WindowEventFilter::ImeCommit => true,
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(ime_events, expected.iter().copied().collect::<Vec<_>>());
+        previous = current;
+    }
+}
+
+#[test]
+fn test_file_drop_window_events() {
+    use azul_css::{AzString, StringVec};
+
+    fn files(paths: &[&'static str]) -> StringVec {
+        paths.iter().map(|p| AzString::from_const_str(p)).collect()
+    }
+
+    // (hovered files, dropped files, expected event)
+    let sequence: [(&[&'static str], &[&'static str], Option<WindowEventFilter>); 6] = [
+        (&["a.txt"], &[], Some(WindowEventFilter::HoveredFile)),
+        (&[], &["a.txt"], Some(WindowEventFilter::DroppedFile)),
+        // the files of the last drop are still set while the next drag is hovering
+        (&["b.txt"], &["a.txt"], Some(WindowEventFilter::HoveredFile)),
+        (&[], &["a.txt"], Some(WindowEventFilter::HoveredFileCancelled)),
+        // drop without a preceding hover event (i.e. Win32 WM_DROPFILES)
+        (&[], &["c.txt", "d.txt"], Some(WindowEventFilter::DroppedFile)),
+        (&[], &["c.txt", "d.txt"], None),
+    ];
+
+    let mut previous = FullWindowState::default();
+    for (hovered, dropped, expected) in sequence.iter() {
+        let mut current = previous.clone();
+        current.hovered_files = files(hovered);
+        current.dropped_files = files(dropped);
+
+        let events = get_window_events(&current, &Some(previous.clone()));
+        let file_events = events
+            .into_iter()
+            .filter(|e| match e {
+                WindowEventFilter::HoveredFile
+                | WindowEventFilter::DroppedFile
+                | WindowEventFilter::HoveredFileCancelled => true,
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(file_events, expected.iter().copied().collect::<Vec<_>>());
+        previous = current;
+    }
+}
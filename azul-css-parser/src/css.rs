@@ -1,21 +1,23 @@
-//! High-level types and functions related to CSS parsing
-use core::{
-    num::ParseIntError,
-    fmt,
-};
-use alloc::collections::BTreeMap;
-use alloc::string::ToString;
-use alloc::vec::Vec;
-pub use azul_simplecss::Error as CssSyntaxError;
-use azul_simplecss::Tokenizer;
-
+//! High-level types and functions related to CSS parsing
+use core::{
+    num::ParseIntError,
+    fmt,
+};
+use alloc::collections::BTreeMap;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+pub use azul_simplecss::Error as CssSyntaxError;
+use azul_simplecss::Tokenizer;
+
 use crate::css_parser;
 pub use crate::css_parser::CssParsingError;
 use azul_css::{
-    Css, CssDeclaration, Stylesheet, DynamicCssProperty, AzString,
+    Css, CssDeclaration, Stylesheet, DynamicCssProperty, AzString, CssProperty,
     CssPropertyType, CssRuleBlock, CssPath, CssPathSelector,
     CssNthChildSelector, CssPathPseudoSelector, CssNthChildSelector::*,
     NodeTypeTag, NodeTypeTagParseError, CombinedCssPropertyType, CssKeyMap,
+    MediaQuery, MediaCondition, MediaFeature, MediaOrientation, MediaColorScheme, OptionMediaQuery,
+    FloatValue, PixelValue, DEFAULT_DPI,
 };
 
 #[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
@@ -39,908 +41,1599 @@ impl CssApiWrapper {
 /// Error that can happen during the parsing of a CSS value
 #[derive(Debug, Clone, PartialEq)]
 pub struct CssParseError<'a> {
-    pub css_string: &'a str,
-    pub error: CssParseErrorInner<'a>,
-    pub location: (ErrorLocation, ErrorLocation),
-}
-
-impl<'a> CssParseError<'a> {
-    /// Returns the string between the (start, end) location
-    pub fn get_error_string(&self) -> &'a str {
-        let (start, end) = (self.location.0.original_pos, self.location.1.original_pos);
-        let s = &self.css_string[start..end];
-        s.trim()
-    }
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum CssParseErrorInner<'a> {
-    /// A hard error in the CSS syntax
-    ParseError(CssSyntaxError),
-    /// Braces are not balanced properly
-    UnclosedBlock,
-    /// Invalid syntax, such as `#div { #div: "my-value" }`
-    MalformedCss,
-    /// Error parsing dynamic CSS property, such as
-    /// `#div { width: {{ my_id }} /* no default case */ }`
-    DynamicCssParseError(DynamicCssParseError<'a>),
-    /// Error while parsing a pseudo selector (like `:aldkfja`)
-    PseudoSelectorParseError(CssPseudoSelectorParseError<'a>),
-    /// The path has to be either `*`, `div`, `p` or something like that
-    NodeTypeTag(NodeTypeTagParseError<'a>),
-    /// A certain property has an unknown key, for example: `alsdfkj: 500px` = `unknown CSS key "alsdfkj: 500px"`
-    UnknownPropertyKey(&'a str, &'a str),
-    /// `var()` can't be used on properties that expand to multiple values, since they would be ambigouus
-    /// and degrade performance - for example `margin: var(--blah)` would be ambigouus because it's not clear
-    /// when setting the variable, whether all sides should be set, instead, you have to use `margin-top: var(--blah)`,
-    /// `margin-bottom: var(--baz)` in order to work around this limitation.
-    VarOnShorthandProperty { key: CombinedCssPropertyType, value: &'a str },
-}
-
-impl_display!{ CssParseErrorInner<'a>, {
-    ParseError(e) => format!("Parse Error: {:?}", e),
-    UnclosedBlock => "Unclosed block",
-    MalformedCss => "Malformed Css",
-    DynamicCssParseError(e) => format!("{}", e),
-    PseudoSelectorParseError(e) => format!("Failed to parse pseudo-selector: {}", e),
-    NodeTypeTag(e) => format!("Failed to parse CSS selector path: {}", e),
-    UnknownPropertyKey(k, v) => format!("Unknown CSS key: \"{}: {}\"", k, v),
-    VarOnShorthandProperty { key, value } => format!(
-        "Error while parsing: \"{}: {};\": var() cannot be used on shorthand properties - use `{}-top` or `{}-x` as the key instead: ",
-        key, value, key, key
-    ),
-}}
-
-impl<'a> From<CssSyntaxError> for CssParseErrorInner<'a> {
-    fn from(e: CssSyntaxError) -> Self {
-        CssParseErrorInner::ParseError(e)
-    }
-}
-
-impl_from! { DynamicCssParseError<'a>, CssParseErrorInner::DynamicCssParseError }
-impl_from! { NodeTypeTagParseError<'a>, CssParseErrorInner::NodeTypeTag }
-impl_from! { CssPseudoSelectorParseError<'a>, CssParseErrorInner::PseudoSelectorParseError }
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum CssPseudoSelectorParseError<'a> {
-    EmptyNthChild,
-    UnknownSelector(&'a str, Option<&'a str>),
-    InvalidNthChildPattern(&'a str),
-    InvalidNthChild(ParseIntError),
-}
-
-impl<'a> From<ParseIntError> for CssPseudoSelectorParseError<'a> {
-    fn from(e: ParseIntError) -> Self { CssPseudoSelectorParseError::InvalidNthChild(e) }
-}
-
-impl_display! { CssPseudoSelectorParseError<'a>, {
-    EmptyNthChild => format!("\
-        Empty :nth-child() selector - nth-child() must at least take a number, \
-        a pattern (such as \"2n+3\") or the values \"even\" or \"odd\"."
-    ),
-    UnknownSelector(selector, value) => {
-        let format_str = match value {
-            Some(v) => format!("{}({})", selector, v),
-            None => format!("{}", selector),
-        };
-        format!("Invalid or unknown CSS pseudo-selector: ':{}'", format_str)
-    },
-    InvalidNthChildPattern(selector) => format!(
-        "Invalid pseudo-selector :{} - value has to be a \
-        number, \"even\" or \"odd\" or a pattern such as \"2n+3\"", selector
-    ),
-    InvalidNthChild(e) => format!("Invalid :nth-child pseudo-selector: ':{}'", e),
-}}
-
-/// Error that can happen during `css_parser::parse_key_value_pair`
-#[derive(Debug, Clone, PartialEq)]
-pub enum DynamicCssParseError<'a> {
-    /// The brace contents aren't valid, i.e. `var(asdlfkjasf)`
-    InvalidBraceContents(&'a str),
-    /// Unexpected value when parsing the string
-    UnexpectedValue(CssParsingError<'a>),
-}
-
-impl_display!{ DynamicCssParseError<'a>, {
-    InvalidBraceContents(e) => format!("Invalid contents of var() function: var({})", e),
-    UnexpectedValue(e) => format!("{}", e),
-}}
-
-impl<'a> From<CssParsingError<'a>> for DynamicCssParseError<'a> {
-    fn from(e: CssParsingError<'a>) -> Self {
-        DynamicCssParseError::UnexpectedValue(e)
-    }
-}
-
-/// "selector" contains the actual selector such as "nth-child" while "value" contains
-/// an optional value - for example "nth-child(3)" would be: selector: "nth-child", value: "3".
-fn pseudo_selector_from_str<'a>(selector: &'a str, value: Option<&'a str>)
--> Result<CssPathPseudoSelector, CssPseudoSelectorParseError<'a>>
-{
-    match selector {
-        "first" => Ok(CssPathPseudoSelector::First),
-        "last" => Ok(CssPathPseudoSelector::Last),
-        "hover" => Ok(CssPathPseudoSelector::Hover),
-        "active" => Ok(CssPathPseudoSelector::Active),
-        "focus" => Ok(CssPathPseudoSelector::Focus),
-        "nth-child" => {
-            let value = value.ok_or(CssPseudoSelectorParseError::EmptyNthChild)?;
-            let parsed = parse_nth_child_selector(value)?;
-            Ok(CssPathPseudoSelector::NthChild(parsed))
-        },
-        _ => {
-            Err(CssPseudoSelectorParseError::UnknownSelector(selector, value))
-        },
-    }
-}
-
-/// Parses the inner value of the `:nth-child` selector, including numbers and patterns.
-///
-/// I.e.: `"2n+3"` -> `Pattern { repeat: 2, offset: 3 }`
-fn parse_nth_child_selector<'a>(value: &'a str) -> Result<CssNthChildSelector, CssPseudoSelectorParseError<'a>> {
-
-    let value = value.trim();
-
-    if value.is_empty() {
-        return Err(CssPseudoSelectorParseError::EmptyNthChild);
-    }
-
-    if let Ok(number) = value.parse::<u32>() {
-        return Ok(Number(number));
-    }
-
-    // If the value is not a number
-    match value.as_ref() {
-        "even" => Ok(Even),
-        "odd" => Ok(Odd),
-        other => parse_nth_child_pattern(value),
-    }
-}
-
-/// Parses the pattern between the braces of a "nth-child" (such as "2n+3").
-fn parse_nth_child_pattern<'a>(value: &'a str) -> Result<CssNthChildSelector, CssPseudoSelectorParseError<'a>> {
-
-    use azul_css::CssNthChildPattern;
-
-    let value = value.trim();
-
-    if value.is_empty() {
-        return Err(CssPseudoSelectorParseError::EmptyNthChild);
-    }
-
-    // TODO: Test for "+"
-    let repeat = value.split("n").next()
-        .ok_or(CssPseudoSelectorParseError::InvalidNthChildPattern(value))?
-        .trim()
-        .parse::<u32>()?;
-
-    // In a "2n+3" form, the first .next() yields the "2n", the second .next() yields the "3"
-    let mut offset_iterator = value.split("+");
-
-    // has to succeed, since the string is verified to not be empty
-    offset_iterator.next().unwrap();
-
-    let offset = match offset_iterator.next() {
-        Some(offset_string) => {
-            let offset_string = offset_string.trim();
-            if offset_string.is_empty() {
-                return Err(CssPseudoSelectorParseError::InvalidNthChildPattern(value));
-            } else {
-                offset_string.parse::<u32>()?
-            }
-        },
-        None => 0,
-    };
-
-    Ok(Pattern(CssNthChildPattern { repeat, offset }))
-}
-
-#[test]
-fn test_css_pseudo_selector_parse() {
-
-    use self::CssPathPseudoSelector::*;
-    use self::CssPseudoSelectorParseError::*;
-    use azul_css::CssNthChildSelector::*;
-    use azul_css::CssNthChildPattern;
-    let ok_res = [
-        (("first", None), First),
-        (("last", None), Last),
-        (("hover", None), Hover),
-        (("active", None), Active),
-        (("focus", None), Focus),
-        (("nth-child", Some("4")), NthChild(Number(4))),
-        (("nth-child", Some("even")), NthChild(Even)),
-        (("nth-child", Some("odd")), NthChild(Odd)),
-        (("nth-child", Some("5n")), NthChild(Pattern(CssNthChildPattern { repeat: 5, offset: 0 }))),
-        (("nth-child", Some("2n+3")), NthChild(Pattern(CssNthChildPattern { repeat: 2, offset: 3 }))),
-    ];
-
-    let err = [
-        (("asdf", None), UnknownSelector("asdf", None)),
-        (("", None), UnknownSelector("", None)),
-        (("nth-child", Some("2n+")), InvalidNthChildPattern("2n+")),
-        // Can't test for ParseIntError because the fields are private.
-        // This is an example on why you shouldn't use core::error::Error!
-    ];
-
-    for ((selector, val), a) in &ok_res {
-        assert_eq!(pseudo_selector_from_str(selector, *val), Ok(*a));
-    }
-
-    for ((selector, val), e) in &err {
-        assert_eq!(pseudo_selector_from_str(selector, *val), Err(e.clone()));
-    }
-}
-
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct ErrorLocation {
-    pub original_pos: usize,
-}
-
-impl ErrorLocation {
-    /// Given an error location, returns the (line, column)
-    pub fn get_line_column_from_error(&self, css_string: &str) -> (usize, usize) {
-
-        let error_location = self.original_pos.saturating_sub(1);
-        let (mut line_number, mut total_characters) = (0, 0);
-
-        for line in css_string[0..error_location].lines() {
-            line_number += 1;
-            total_characters += line.chars().count();
-        }
-
-        // Rust doesn't count "\n" as a character, so we have to add the line number count on top
-        let total_characters = total_characters + line_number;
-        let column_pos = error_location - total_characters.saturating_sub(2);
-
-        (line_number, column_pos)
-    }
-}
-
-impl<'a> fmt::Display for CssParseError<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let start_location = self.location.0.get_line_column_from_error(self.css_string);
-        let end_location = self.location.1.get_line_column_from_error(self.css_string);
-        write!(f, "    start: line {}:{}\r\n    end: line {}:{}\r\n    text: \"{}\"\r\n    reason: {}",
-            start_location.0, start_location.1,
-            end_location.0, end_location.1,
-            self.get_error_string(),
-            self.error,
-        )
-    }
-}
-
-pub fn new_from_str<'a>(css_string: &'a str) -> Result<Css, CssParseError<'a>> {
-    let mut tokenizer = Tokenizer::new(css_string);
-    let (stylesheet, _warnings) = new_from_str_inner(css_string, &mut tokenizer)?;
-    Ok(Css { stylesheets: vec![stylesheet].into() })
-}
-
-/// Returns the location of where the parser is currently in the document
-fn get_error_location(tokenizer: &Tokenizer) -> ErrorLocation {
-    ErrorLocation {
-        original_pos: tokenizer.pos(),
-    }
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum CssPathParseError<'a> {
-    EmptyPath,
-    /// Invalid item encountered in string (for example a "{", "}")
-    InvalidTokenEncountered(&'a str),
-    UnexpectedEndOfStream(&'a str),
-    SyntaxError(CssSyntaxError),
-    /// The path has to be either `*`, `div`, `p` or something like that
-    NodeTypeTag(NodeTypeTagParseError<'a>),
-    /// Error while parsing a pseudo selector (like `:aldkfja`)
-    PseudoSelectorParseError(CssPseudoSelectorParseError<'a>),
-}
-
-impl_from! { NodeTypeTagParseError<'a>, CssPathParseError::NodeTypeTag }
-impl_from! { CssPseudoSelectorParseError<'a>, CssPathParseError::PseudoSelectorParseError }
-
-impl<'a> From<CssSyntaxError> for CssPathParseError<'a> {
-    fn from(e: CssSyntaxError) -> Self {
-        CssPathParseError::SyntaxError(e)
-    }
-}
-
-/// Parses a CSS path from a string (only the path,.no commas allowed)
-///
-/// ```rust
-/// # extern crate azul_css;
-/// # extern crate azul_css_parser;
-/// # use azul_css_parser::parse_css_path;
-/// # use azul_css::{
-/// #     CssPathSelector::*, CssPathPseudoSelector::*, CssPath,
-/// #     NodeTypeTag::*, CssNthChildSelector::*
-/// # };
-///
-/// assert_eq!(
-///     parse_css_path("* div #my_id > .class:nth-child(2)"),
-///     Ok(CssPath {
-///         selectors: vec![
-///             Global,
-///             Type(Div),
-///             Children,
-///             Id("my_id".to_string().into()),
-///             DirectChildren,
-///             Class("class".to_string().into()),
-///             PseudoSelector(NthChild(Number(2))),
-///         ].into()
-///     })
-/// );
-/// ```
-pub fn parse_css_path<'a>(input: &'a str) -> Result<CssPath, CssPathParseError<'a>> {
-
-    use azul_simplecss::{Token, Combinator};
-
-    let input = input.trim();
-    if input.is_empty() {
-        return Err(CssPathParseError::EmptyPath);
-    }
-
-    let mut tokenizer = Tokenizer::new(input);
-    let mut selectors = Vec::new();
-
-    loop {
-        let token = tokenizer.parse_next()?;
-        match token {
-            Token::UniversalSelector => {
-                selectors.push(CssPathSelector::Global);
-            },
-            Token::TypeSelector(div_type) => {
-                selectors.push(CssPathSelector::Type(NodeTypeTag::from_str(div_type)?));
-            },
-            Token::IdSelector(id) => {
-                selectors.push(CssPathSelector::Id(id.to_string().into()));
-            },
-            Token::ClassSelector(class) => {
-                selectors.push(CssPathSelector::Class(class.to_string().into()));
-            },
-            Token::Combinator(Combinator::GreaterThan) => {
-                selectors.push(CssPathSelector::DirectChildren);
-            },
-            Token::Combinator(Combinator::Space) => {
-                selectors.push(CssPathSelector::Children);
-            },
-            Token::PseudoClass { selector, value } => {
-                selectors.push(CssPathSelector::PseudoSelector(pseudo_selector_from_str(selector, value)?));
-            },
-            Token::EndOfStream => {
-                break;
-            }
-            _ => {
-                return Err(CssPathParseError::InvalidTokenEncountered(input));
-            }
-        }
-    }
-
-    if !selectors.is_empty() {
-        Ok(CssPath { selectors: selectors.into() })
-    } else {
-        Err(CssPathParseError::EmptyPath)
-    }
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub struct UnparsedCssRuleBlock<'a> {
-    /// The css path (full selector) of the style ruleset
-    pub path: CssPath,
-    /// `"justify-content" => "center"`
-    pub declarations: BTreeMap<&'a str, (&'a str, (ErrorLocation, ErrorLocation))>,
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub struct CssParseWarnMsg<'a> {
-    warning: CssParseWarnMsgInner<'a>,
-    location: (ErrorLocation, ErrorLocation),
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum CssParseWarnMsgInner<'a> {
-    /// Key "blah" isn't (yet) supported, so the parser didn't attempt to parse the value at all
-    UnsupportedKeyValuePair { key: &'a str, value: &'a str },
-}
-
-/// Parses a CSS string (single-threaded) and returns the parsed rules in blocks
-///
-/// May return "warning" messages, i.e. messages that just serve as a warning,
-/// instead of being actual errors. These warnings may be ignored by the caller,
-/// but can be useful for debugging.
-fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
--> Result<(Stylesheet, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
-
-    use azul_simplecss::{Token, Combinator};
-
-    let mut css_blocks = Vec::new();
-
-    // Used for error checking / checking for closed braces
-    let mut parser_in_block = false;
-    let mut block_nesting = 0_usize;
-
-    // Current css paths (i.e. `div#id, .class, p` are stored here -
-    // when the block is finished, all `current_rules` gets duplicated with
-    // one path corresponding to one set of rules each).
-    let mut current_paths = Vec::new();
-    // Current CSS declarations
-    let mut current_rules = BTreeMap::<&str, (&str, (ErrorLocation, ErrorLocation))>::new();
-    // Keep track of the current path during parsing
-    let mut last_path = Vec::new();
-
-    let mut last_error_location = ErrorLocation { original_pos: 0 };
-
-    loop {
-
-        let token = tokenizer.parse_next().map_err(|e| CssParseError {
-            css_string,
-            error: e.into(),
-            location: (last_error_location, get_error_location(tokenizer))
-        })?;
-
-        macro_rules! check_parser_is_outside_block {() => {
-            if parser_in_block {
-                return Err(CssParseError {
-                    css_string,
-                    error: CssParseErrorInner::MalformedCss,
-                    location: (last_error_location, get_error_location(tokenizer)),
-                });
-            }
-        }}
-
-        macro_rules! check_parser_is_inside_block {() => {
-            if !parser_in_block {
-                return Err(CssParseError {
-                    css_string,
-                    error: CssParseErrorInner::MalformedCss,
-                    location: (last_error_location, get_error_location(tokenizer)),
-                });
-            }
-        }}
-
-        match token {
-            Token::BlockStart => {
-                check_parser_is_outside_block!();
-                parser_in_block = true;
-                block_nesting += 1;
-                current_paths.push(last_path.clone());
-                last_path.clear();
-            },
-            Token::Comma => {
-                check_parser_is_outside_block!();
-                current_paths.push(last_path.clone());
-                last_path.clear();
-            },
-            Token::BlockEnd => {
-
-                block_nesting -= 1;
-                check_parser_is_inside_block!();
-                parser_in_block = false;
-
-                css_blocks.extend(current_paths.drain(..).map(|path| {
-                    UnparsedCssRuleBlock {
-                        path: CssPath { selectors: path.into() },
-                        declarations: current_rules.clone(),
-                    }
-                }));
-
-                current_rules.clear();
-                last_path.clear(); // technically unnecessary, but just to be sure
-            },
-
-            // tokens that adjust the last_path
-            Token::UniversalSelector => {
-                check_parser_is_outside_block!();
-                last_path.push(CssPathSelector::Global);
-            },
-            Token::TypeSelector(div_type) => {
-                check_parser_is_outside_block!();
-                last_path.push(CssPathSelector::Type(NodeTypeTag::from_str(div_type).map_err(|e| {
-                    CssParseError {
-                        css_string,
-                        error: e.into(),
-                        location: (last_error_location, get_error_location(tokenizer)),
-                    }
-                })?));
-            },
-            Token::IdSelector(id) => {
-                check_parser_is_outside_block!();
-                last_path.push(CssPathSelector::Id(id.to_string().into()));
-            },
-            Token::ClassSelector(class) => {
-                check_parser_is_outside_block!();
-                last_path.push(CssPathSelector::Class(class.to_string().into()));
-            },
-            Token::Combinator(Combinator::GreaterThan) => {
-                check_parser_is_outside_block!();
-                last_path.push(CssPathSelector::DirectChildren);
-            },
-            Token::Combinator(Combinator::Space) => {
-                check_parser_is_outside_block!();
-                last_path.push(CssPathSelector::Children);
-            },
-            Token::PseudoClass { selector, value } => {
-                check_parser_is_outside_block!();
-                last_path.push(CssPathSelector::PseudoSelector(pseudo_selector_from_str(selector, value).map_err(|e| {
-                    CssParseError {
-                        css_string,
-                        error: e.into(),
-                        location: (last_error_location, get_error_location(tokenizer)),
-                    }
-                })?));
-            },
-            Token::Declaration(key, val) => {
-                check_parser_is_inside_block!();
-                current_rules.insert(key, (val, (last_error_location, get_error_location(tokenizer))));
-            },
-            Token::EndOfStream => {
-
-                // uneven number of open / close braces
-                if block_nesting != 0 {
-                    return Err(CssParseError {
-                        css_string,
-                        error: CssParseErrorInner::UnclosedBlock,
-                        location: (last_error_location, get_error_location(tokenizer)),
-                    });
-                }
-
-                break;
-            },
-            _ => {
-                // attributes, lang-attributes and @keyframes are not supported
-            }
-        }
-
-        last_error_location = get_error_location(tokenizer);
-    }
-
-    unparsed_css_blocks_to_stylesheet(css_blocks, css_string)
-}
-
-fn unparsed_css_blocks_to_stylesheet<'a>(css_blocks: Vec<UnparsedCssRuleBlock<'a>>, css_string: &'a str)
--> Result<(Stylesheet, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
-
-    // Actually parse the properties (TODO: this could be done in parallel and in a separate function)
-    let css_key_map = azul_css::get_css_key_map();
-
-    let mut warnings = Vec::new();
-
-    let parsed_css_blocks = css_blocks.into_iter().map(|unparsed_css_block| {
-
-        let mut declarations = Vec::<CssDeclaration>::new();
-
-        for (unparsed_css_key, (unparsed_css_value, location)) in unparsed_css_block.declarations {
-            parse_css_declaration(
-                unparsed_css_key,
-                unparsed_css_value,
-                location,
-                &css_key_map,
-                &mut warnings,
-                &mut declarations,
-            ).map_err(|e| CssParseError {
-                css_string,
-                error: e.into(),
-                location,
-            })?;
-        }
-
-        Ok(CssRuleBlock {
-            path: unparsed_css_block.path.into(),
-            declarations: declarations.into(),
-        })
-    }).collect::<Result<Vec<CssRuleBlock>, CssParseError>>()?;
-
-    Ok((parsed_css_blocks.into(), warnings))
-}
-
-pub fn parse_css_declaration<'a>(
-    unparsed_css_key: &'a str,
-    unparsed_css_value: &'a str,
-    location: (ErrorLocation, ErrorLocation),
-    css_key_map: &CssKeyMap,
-    warnings: &mut Vec<CssParseWarnMsg<'a>>,
-    declarations: &mut Vec<CssDeclaration>,
-) -> Result<(), CssParseErrorInner<'a>> {
-
-    use self::CssParseErrorInner::*;
-    use self::CssParseWarnMsgInner::*;
-
-    if let Some(combined_key) = CombinedCssPropertyType::from_str(unparsed_css_key, &css_key_map) {
-        if let Some(css_var) = check_if_value_is_css_var(unparsed_css_value) {
-            // margin: var(--my-variable);
-            return Err(VarOnShorthandProperty { key: combined_key, value: unparsed_css_value });
-        } else {
-            // margin: 10px;
-            let parsed_css_properties =
-                css_parser::parse_combined_css_property(combined_key, unparsed_css_value)
-                .map_err(|e| DynamicCssParseError(e.into()))?;
-
-            declarations.extend(parsed_css_properties.into_iter().map(|val| CssDeclaration::Static(val)));
-        }
-    } else if let Some(normal_key) = CssPropertyType::from_str(unparsed_css_key, css_key_map) {
-        if let Some(css_var) = check_if_value_is_css_var(unparsed_css_value) {
-            // margin-left: var(--my-variable);
-            let (css_var_id, css_var_default) = css_var?;
-            let parsed_default_value =
-                css_parser::parse_css_property(normal_key, css_var_default)
-                .map_err(|e| DynamicCssParseError(e.into()))?;
-
-            declarations.push(CssDeclaration::Dynamic(DynamicCssProperty {
-                dynamic_id: css_var_id.to_string().into(),
-                default_value: parsed_default_value,
-            }));
-        } else {
-            // margin-left: 10px;
-            let parsed_css_value =
-                css_parser::parse_css_property(normal_key, unparsed_css_value)
-                .map_err(|e| DynamicCssParseError(e.into()))?;
-
-            declarations.push(CssDeclaration::Static(parsed_css_value));
-        }
-    } else {
-        // asldfkjasdf: 10px;
-        warnings.push(CssParseWarnMsg {
-            warning: UnsupportedKeyValuePair { key: unparsed_css_key, value: unparsed_css_value },
-            location,
-        });
-    }
-
-    Ok(())
-}
-
-fn check_if_value_is_css_var<'a>(unparsed_css_value: &'a str) -> Option<Result<(&'a str, &'a str), CssParseErrorInner<'a>>> {
-
-    const DEFAULT_VARIABLE_DEFAULT: &str = "none";
-
-    let (_, brace_contents) = css_parser::parse_parentheses(unparsed_css_value, &["var"]).ok()?;
-
-    // value is a CSS variable, i.e. var(--main-bg-color)
-    Some(match parse_css_variable_brace_contents(brace_contents) {
-        Some((variable_id, default_value)) => Ok((variable_id, default_value.unwrap_or(DEFAULT_VARIABLE_DEFAULT))),
-        None => Err(DynamicCssParseError::InvalidBraceContents(brace_contents).into()),
-    })
-}
-
-/// Parses the brace contents of a css var, i.e.:
-///
-/// ```no_run,ignore
-/// "--main-bg-col, blue" => (Some("main-bg-col"), Some("blue"))
-/// "--main-bg-col"       => (Some("main-bg-col"), None)
-/// ```
-fn parse_css_variable_brace_contents<'a>(input: &'a str) -> Option<(&'a str, Option<&'a str>)> {
-
-    let input = input.trim();
-
-    let mut split_comma_iter = input.splitn(2, ",");
-    let var_name = split_comma_iter.next()?;
-    let var_name = var_name.trim();
-
-    if !var_name.starts_with("--") {
-        return None; // no proper CSS variable name
-    }
-
-    Some((&var_name[2..], split_comma_iter.next()))
-}
-
-#[test]
-fn test_css_parse_1() {
-
-    use azul_css::*;
-
-    let parsed_css = new_from_str("
-        div#my_id .my_class:first {
-            background-color: red;
-        }
-    ").unwrap();
-
-
-    let expected_css_rules = vec![CssRuleBlock {
-        path: CssPath {
-            selectors: vec![
-                CssPathSelector::Type(NodeTypeTag::Div),
-                CssPathSelector::Id("my_id".to_string().into()),
-                CssPathSelector::Children,
-                // NOTE: This is technically wrong, the space between "#my_id"
-                // and ".my_class" is important, but gets ignored for now
-                CssPathSelector::Class("my_class".to_string().into()),
-                CssPathSelector::PseudoSelector(CssPathPseudoSelector::First),
-            ].into(),
-        },
-        declarations: vec![CssDeclaration::Static(CssProperty::BackgroundContent(
-            CssPropertyValue::Exact(vec![StyleBackgroundContent::Color(ColorU {
-                r: 255,
-                g: 0,
-                b: 0,
-                a: 255,
-            })].into()),
-        ))].into(),
-    }].into();
-
-    assert_eq!(
-        parsed_css,
-        Css {
-            stylesheets: vec![expected_css_rules].into(),
-        }
-    );
-}
-
-#[test]
-fn test_css_simple_selector_parse() {
-    use self::CssPathSelector::*;
-    use azul_css::NodeTypeTag;
-    let css = "div#id.my_class > p .new { }";
-    let parsed = vec![
-        Type(NodeTypeTag::Div),
-        Id("id".to_string().into()),
-        Class("my_class".to_string().into()),
-        DirectChildren,
-        Type(NodeTypeTag::P),
-        Children,
-        Class("new".to_string().into())
-    ];
-    assert_eq!(new_from_str(css).unwrap(), Css {
-        stylesheets: vec![Stylesheet {
-            rules: vec![CssRuleBlock {
-                path: CssPath { selectors: parsed.into() },
-                declarations: Vec::new().into(),
-            }].into(),
-        }].into(),
-    });
-}
-
-#[cfg(test)]
-mod stylesheet_parse {
-
-    use azul_css::*;
-    use super::*;
-
-    fn test_css(css: &str, expected: Vec<CssRuleBlock>) {
-        let css = new_from_str(css).unwrap();
-        assert_eq!(css, Css { stylesheets: vec![expected.into()].into() });
-    }
-
-    // Tests that an element with a single class always gets the CSS element applied properly
-    #[test]
-    fn test_apply_css_pure_class() {
-        let red = CssProperty::BackgroundContent(CssPropertyValue::Exact(StyleBackgroundContentVec::from(vec![
-            StyleBackgroundContent::Color(ColorU {
-                r: 255,
-                g: 0,
-                b: 0,
-                a: 255,
-            }),
-        ])));
-        let blue = CssProperty::BackgroundContent(CssPropertyValue::Exact(StyleBackgroundContentVec::from(vec![
-            StyleBackgroundContent::Color(ColorU {
-                r: 0,
-                g: 0,
-                b: 255,
-                a: 255,
-            }),
-        ])));
-        let black = CssProperty::BackgroundContent(CssPropertyValue::Exact(StyleBackgroundContentVec::from(vec![
-            StyleBackgroundContent::Color(ColorU {
-                r: 0,
-                g: 0,
-                b: 0,
-                a: 255,
-            }),
-        ])));
-
-        // Simple example
-        {
-            let css_1 = ".my_class { background-color: red; }";
-            let expected_rules = vec![
-                CssRuleBlock {
-                    path: CssPath {
-                        selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into(),
-                    },
-                    declarations: vec![CssDeclaration::Static(red.clone())].into(),
-                },
-            ].into();
-            test_css(css_1, expected_rules);
-        }
-
-        // Slightly more complex example
-        {
-            let css_2 = "#my_id { background-color: red; } .my_class { background-color: blue; }";
-            let expected_rules = vec![
-                CssRuleBlock {
-                    path: CssPath { selectors: vec![CssPathSelector::Id("my_id".to_string().into())].into(), },
-                    declarations: vec![CssDeclaration::Static(red.clone())].into(),
-                },
-                CssRuleBlock {
-                    path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into(), },
-                    declarations: vec![CssDeclaration::Static(blue.clone())].into(),
-                },
-            ];
-            test_css(css_2, expected_rules);
-        }
-
-        // Even more complex example
-        {
-            let css_3 = "* { background-color: black; } .my_class#my_id { background-color: red; } .my_class { background-color: blue; }";
-            let expected_rules = vec![
-                CssRuleBlock {
-                    path: CssPath { selectors: vec![CssPathSelector::Global].into() },
-                    declarations: vec![CssDeclaration::Static(black.clone())].into(),
-                },
-                CssRuleBlock {
-                    path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into()), CssPathSelector::Id("my_id".to_string().into())].into(), },
-                    declarations: vec![CssDeclaration::Static(red.clone())].into(),
-                },
-                CssRuleBlock {
-                    path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into() },
-                    declarations: vec![CssDeclaration::Static(blue.clone())].into(),
-                },
-            ].into();
-            test_css(css_3, expected_rules);
-        }
-    }
-}
-
-// Assert that order of the style rules is correct (in same order as provided in CSS form)
-#[test]
-fn test_multiple_rules() {
-    use azul_css::*;
-    use self::CssPathSelector::*;
-
-    let parsed_css = new_from_str("
-        * { }
-        * div.my_class#my_id { }
-        * div#my_id { }
-        * #my_id { }
-        div.my_class.specific#my_id { }
-    ").unwrap();
-
-    let expected_rules = vec![
-        // Rules are sorted by order of appearance in source string
-        CssRuleBlock { path: CssPath { selectors: vec![Global].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-        CssRuleBlock { path: CssPath { selectors: vec![Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
-    ];
-
-    assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into() });
-}
-
-#[test]
-fn test_case_issue_93() {
-
-    use azul_css::*;
-    use self::CssPathSelector::*;
-
-    let parsed_css = new_from_str("
-        .tabwidget-tab-label {
-          color: #FFFFFF;
-        }
-
-        .tabwidget-tab.active .tabwidget-tab-label {
-          color: #000000;
-        }
-
-        .tabwidget-tab.active .tabwidget-tab-close {
-          color: #FF0000;
-        }
-    ").unwrap();
-
-    fn declaration(classes: &[CssPathSelector], color: ColorU) -> CssRuleBlock {
-        CssRuleBlock {
-            path: CssPath {
-                selectors: classes.to_vec().into(),
-            },
-            declarations: vec![CssDeclaration::Static(CssProperty::TextColor(
-                CssPropertyValue::Exact(StyleTextColor { inner: color }),
-            ))].into(),
-        }
-    }
-
-    let expected_rules = vec![
-        declaration(&[Class("tabwidget-tab-label".to_string().into())], ColorU { r: 255, g: 255, b: 255, a: 255 }),
-        declaration(&[Class("tabwidget-tab".to_string().into()), Class("active".to_string().into()), Children, Class("tabwidget-tab-label".to_string().into())], ColorU { r: 0, g: 0, b: 0, a: 255 }),
-        declaration(&[Class("tabwidget-tab".to_string().into()), Class("active".to_string().into()), Children, Class("tabwidget-tab-close".to_string().into())], ColorU { r: 255, g: 0, b: 0, a: 255 }),
-    ];
-
-    assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into() });
-}
\ No newline at end of file
+    pub css_string: &'a str,
+    pub error: CssParseErrorInner<'a>,
+    pub location: (ErrorLocation, ErrorLocation),
+}
+
+impl<'a> CssParseError<'a> {
+    /// Returns the string between the (start, end) location
+    pub fn get_error_string(&self) -> &'a str {
+        let (start, end) = (self.location.0.original_pos, self.location.1.original_pos);
+        let s = &self.css_string[start..end];
+        s.trim()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssParseErrorInner<'a> {
+    /// A hard error in the CSS syntax
+    ParseError(CssSyntaxError),
+    /// Braces are not balanced properly
+    UnclosedBlock,
+    /// Invalid syntax, such as `#div { #div: "my-value" }`
+    MalformedCss,
+    /// Error parsing dynamic CSS property, such as
+    /// `#div { width: {{ my_id }} /* no default case */ }`
+    DynamicCssParseError(DynamicCssParseError<'a>),
+    /// Error while parsing a pseudo selector (like `:aldkfja`)
+    PseudoSelectorParseError(CssPseudoSelectorParseError<'a>),
+    /// The path has to be either `*`, `div`, `p` or something like that
+    NodeTypeTag(NodeTypeTagParseError<'a>),
+    /// A certain property has an unknown key, for example: `alsdfkj: 500px` = `unknown CSS key "alsdfkj: 500px"`
+    UnknownPropertyKey(&'a str, &'a str),
+    /// A property has no value, for example: `width: ;` = `empty value for CSS key "width"`
+    EmptyPropertyValue(&'a str),
+    /// `var()` can't be used on properties that expand to multiple values, since they would be ambigouus
+    /// and degrade performance - for example `margin: var(--blah)` would be ambigouus because it's not clear
+    /// when setting the variable, whether all sides should be set, instead, you have to use `margin-top: var(--blah)`,
+    /// `margin-bottom: var(--baz)` in order to work around this limitation.
+    VarOnShorthandProperty { key: CombinedCssPropertyType, value: &'a str },
+    /// Error while parsing the condition of an `@media` group
+    MediaQuery(MediaQueryParseError<'a>),
+}
+
+impl_display!{ CssParseErrorInner<'a>, {
+    ParseError(e) => format!("Parse Error: {:?}", e),
+    UnclosedBlock => "Unclosed block",
+    MalformedCss => "Malformed Css",
+    DynamicCssParseError(e) => format!("{}", e),
+    PseudoSelectorParseError(e) => format!("Failed to parse pseudo-selector: {}", e),
+    NodeTypeTag(e) => format!("Failed to parse CSS selector path: {}", e),
+    UnknownPropertyKey(k, v) => format!("Unknown CSS key: \"{}: {}\"", k, v),
+    EmptyPropertyValue(k) => format!("Empty value for CSS key: \"{}\"", k),
+    VarOnShorthandProperty { key, value } => format!(
+        "Error while parsing: \"{}: {};\": var() cannot be used on shorthand properties - use `{}-top` or `{}-x` as the key instead: ",
+        key, value, key, key
+    ),
+    MediaQuery(e) => format!("Failed to parse @media query: {}", e),
+}}
+
+impl<'a> From<CssSyntaxError> for CssParseErrorInner<'a> {
+    fn from(e: CssSyntaxError) -> Self {
+        CssParseErrorInner::ParseError(e)
+    }
+}
+
+impl_from! { DynamicCssParseError<'a>, CssParseErrorInner::DynamicCssParseError }
+impl_from! { NodeTypeTagParseError<'a>, CssParseErrorInner::NodeTypeTag }
+impl_from! { CssPseudoSelectorParseError<'a>, CssParseErrorInner::PseudoSelectorParseError }
+impl_from! { MediaQueryParseError<'a>, CssParseErrorInner::MediaQuery }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CssPseudoSelectorParseError<'a> {
+    EmptyNthChild,
+    UnknownSelector(&'a str, Option<&'a str>),
+    InvalidNthChildPattern(&'a str),
+    InvalidNthChild(ParseIntError),
+}
+
+impl<'a> From<ParseIntError> for CssPseudoSelectorParseError<'a> {
+    fn from(e: ParseIntError) -> Self { CssPseudoSelectorParseError::InvalidNthChild(e) }
+}
+
+impl_display! { CssPseudoSelectorParseError<'a>, {
+    EmptyNthChild => format!("\
+        Empty :nth-child() selector - nth-child() must at least take a number, \
+        a pattern (such as \"2n+3\") or the values \"even\" or \"odd\"."
+    ),
+    UnknownSelector(selector, value) => {
+        let format_str = match value {
+            Some(v) => format!("{}({})", selector, v),
+            None => format!("{}", selector),
+        };
+        format!("Invalid or unknown CSS pseudo-selector: ':{}'", format_str)
+    },
+    InvalidNthChildPattern(selector) => format!(
+        "Invalid pseudo-selector :{} - value has to be a \
+        number, \"even\" or \"odd\" or a pattern such as \"2n+3\"", selector
+    ),
+    InvalidNthChild(e) => format!("Invalid :nth-child pseudo-selector: ':{}'", e),
+}}
+
+/// Error that can happen during `css_parser::parse_key_value_pair`
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynamicCssParseError<'a> {
+    /// The brace contents aren't valid, i.e. `var(asdlfkjasf)`
+    InvalidBraceContents(&'a str),
+    /// The variable references itself, i.e. `--a: var(--b); --b: var(--a);`
+    CyclicVariable(&'a str),
+    /// Unexpected value when parsing the string
+    UnexpectedValue(CssParsingError<'a>),
+}
+
+impl_display!{ DynamicCssParseError<'a>, {
+    InvalidBraceContents(e) => format!("Invalid contents of var() function: var({})", e),
+    CyclicVariable(e) => format!("Cyclic CSS variable: --{} depends on itself", e),
+    UnexpectedValue(e) => format!("{}", e),
+}}
+
+impl<'a> From<CssParsingError<'a>> for DynamicCssParseError<'a> {
+    fn from(e: CssParsingError<'a>) -> Self {
+        DynamicCssParseError::UnexpectedValue(e)
+    }
+}
+
+/// "selector" contains the actual selector such as "nth-child" while "value" contains
+/// an optional value - for example "nth-child(3)" would be: selector: "nth-child", value: "3".
+fn pseudo_selector_from_str<'a>(selector: &'a str, value: Option<&'a str>)
+-> Result<CssPathPseudoSelector, CssPseudoSelectorParseError<'a>>
+{
+    match selector {
+        "first" => Ok(CssPathPseudoSelector::First),
+        "last" => Ok(CssPathPseudoSelector::Last),
+        "hover" => Ok(CssPathPseudoSelector::Hover),
+        "active" => Ok(CssPathPseudoSelector::Active),
+        "focus" => Ok(CssPathPseudoSelector::Focus),
+        "nth-child" => {
+            let value = value.ok_or(CssPseudoSelectorParseError::EmptyNthChild)?;
+            let parsed = parse_nth_child_selector(value)?;
+            Ok(CssPathPseudoSelector::NthChild(parsed))
+        },
+        _ => {
+            Err(CssPseudoSelectorParseError::UnknownSelector(selector, value))
+        },
+    }
+}
+
+/// Parses the inner value of the `:nth-child` selector, including numbers and patterns.
+///
+/// I.e.: `"2n+3"` -> `Pattern { repeat: 2, offset: 3 }`
+fn parse_nth_child_selector<'a>(value: &'a str) -> Result<CssNthChildSelector, CssPseudoSelectorParseError<'a>> {
+
+    let value = value.trim();
+
+    if value.is_empty() {
+        return Err(CssPseudoSelectorParseError::EmptyNthChild);
+    }
+
+    if let Ok(number) = value.parse::<u32>() {
+        return Ok(Number(number));
+    }
+
+    // If the value is not a number
+    match value.as_ref() {
+        "even" => Ok(Even),
+        "odd" => Ok(Odd),
+        other => parse_nth_child_pattern(value),
+    }
+}
+
+/// Parses the pattern between the braces of a "nth-child" (such as "2n+3").
+fn parse_nth_child_pattern<'a>(value: &'a str) -> Result<CssNthChildSelector, CssPseudoSelectorParseError<'a>> {
+
+    use azul_css::CssNthChildPattern;
+
+    let value = value.trim();
+
+    if value.is_empty() {
+        return Err(CssPseudoSelectorParseError::EmptyNthChild);
+    }
+
+    // TODO: Test for "+"
+    let repeat = value.split("n").next()
+        .ok_or(CssPseudoSelectorParseError::InvalidNthChildPattern(value))?
+        .trim()
+        .parse::<u32>()?;
+
+    // In a "2n+3" form, the first .next() yields the "2n", the second .next() yields the "3"
+    let mut offset_iterator = value.split("+");
+
+    // has to succeed, since the string is verified to not be empty
+    offset_iterator.next().unwrap();
+
+    let offset = match offset_iterator.next() {
+        Some(offset_string) => {
+            let offset_string = offset_string.trim();
+            if offset_string.is_empty() {
+                return Err(CssPseudoSelectorParseError::InvalidNthChildPattern(value));
+            } else {
+                offset_string.parse::<u32>()?
+            }
+        },
+        None => 0,
+    };
+
+    Ok(Pattern(CssNthChildPattern { repeat, offset }))
+}
+
+#[test]
+fn test_css_pseudo_selector_parse() {
+
+    use self::CssPathPseudoSelector::*;
+    use self::CssPseudoSelectorParseError::*;
+    use azul_css::CssNthChildSelector::*;
+    use azul_css::CssNthChildPattern;
+    let ok_res = [
+        (("first", None), First),
+        (("last", None), Last),
+        (("hover", None), Hover),
+        (("active", None), Active),
+        (("focus", None), Focus),
+        (("nth-child", Some("4")), NthChild(Number(4))),
+        (("nth-child", Some("even")), NthChild(Even)),
+        (("nth-child", Some("odd")), NthChild(Odd)),
+        (("nth-child", Some("5n")), NthChild(Pattern(CssNthChildPattern { repeat: 5, offset: 0 }))),
+        (("nth-child", Some("2n+3")), NthChild(Pattern(CssNthChildPattern { repeat: 2, offset: 3 }))),
+    ];
+
+    let err = [
+        (("asdf", None), UnknownSelector("asdf", None)),
+        (("", None), UnknownSelector("", None)),
+        (("nth-child", Some("2n+")), InvalidNthChildPattern("2n+")),
+        // Can't test for ParseIntError because the fields are private.
+        // This is an example on why you shouldn't use core::error::Error!
+    ];
+
+    for ((selector, val), a) in &ok_res {
+        assert_eq!(pseudo_selector_from_str(selector, *val), Ok(*a));
+    }
+
+    for ((selector, val), e) in &err {
+        assert_eq!(pseudo_selector_from_str(selector, *val), Err(e.clone()));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MediaQueryParseError<'a> {
+    /// The query is not a comma-separated list of `(feature: value)` or
+    /// `(feature < value)` conditions combined with `and` or `or`
+    InvalidSyntax(&'a str),
+    /// Unknown or unsupported media feature, such as `(hover: hover)`
+    UnknownFeature(&'a str),
+    /// Invalid value for a known feature, such as `(max-width: wide)`
+    InvalidValue(&'a str, &'a str),
+    /// `@media` group inside of another `@media` group or rule
+    Nested(&'a str),
+}
+
+impl_display! { MediaQueryParseError<'a>, {
+    InvalidSyntax(query) => format!(
+        "Invalid @media query \"{}\" - only comma-separated \"(feature: value)\" \
+        or \"(feature < value)\" conditions combined with \"and\" or \"or\" are supported", query
+    ),
+    UnknownFeature(feature) => format!("Unknown or unsupported media feature: \"{}\"", feature),
+    InvalidValue(feature, value) => format!("Invalid value for media feature \"{}\": \"{}\"", feature, value),
+    Nested(query) => format!("Nested @media groups are not supported: \"@media{}\"", query),
+}}
+
+/// Parses the condition of an `@media` group, i.e.
+/// `"(min-width: 600px) and (orientation: portrait), (400px < width <= 800px)"`
+///
+/// The group applies if any of the comma-separated parts matches, `or` works
+/// like a comma, but can't be mixed with `and` in the same part. Media types
+/// (`screen`, `print`), `not`, `only` and nested parentheses are not supported.
+pub fn parse_media_query<'a>(input: &'a str) -> Result<MediaQuery, MediaQueryParseError<'a>> {
+
+    let mut conditions = Vec::new();
+
+    for part in input.split(',') {
+
+        let mut features = Vec::new();
+        let mut combinator = None;
+        let mut rest = part.trim();
+
+        loop {
+            let condition = rest.strip_prefix('(').ok_or(MediaQueryParseError::InvalidSyntax(input))?;
+            let end = condition.find(')').ok_or(MediaQueryParseError::InvalidSyntax(input))?;
+            parse_media_feature(&condition[..end], input, &mut features)?;
+
+            rest = condition[end + 1..].trim_start();
+            if rest.is_empty() {
+                break;
+            }
+
+            let word = if rest.starts_with("and") { "and" } else { "or" };
+            rest = match rest.strip_prefix(word) {
+                Some(r) if r.starts_with(|c: char| c.is_whitespace() || c == '(') => r.trim_start(),
+                _ => return Err(MediaQueryParseError::InvalidSyntax(input)),
+            };
+
+            // (a) and (b) or (c) would need parentheses to be unambiguous
+            if *combinator.get_or_insert(word) != word {
+                return Err(MediaQueryParseError::InvalidSyntax(input));
+            }
+        }
+
+        if combinator == Some("or") {
+            conditions.extend(features.into_iter().map(|f| MediaCondition::new(vec![f])));
+        } else {
+            conditions.push(MediaCondition::new(features));
+        }
+    }
+
+    Ok(MediaQuery::any(conditions))
+}
+
+/// Parses `"max-width: 600px"` or `"400px < width <= 800px"` (the contents of the
+/// parentheses), a range can result in two features
+fn parse_media_feature<'a>(
+    input: &'a str,
+    query: &'a str,
+    features: &mut Vec<MediaFeature>,
+) -> Result<(), MediaQueryParseError<'a>> {
+
+    use self::MediaQueryParseError::*;
+
+    if !input.contains(':') {
+        return parse_media_range(input, query, features);
+    }
+
+    let mut split = input.splitn(2, ':');
+    let feature = split.next().unwrap_or("").trim();
+    let value = split.next().ok_or(InvalidSyntax(query))?.trim();
+
+    let length = || parse_media_length(feature, value);
+    let resolution = || parse_media_resolution(value).ok_or(InvalidValue(feature, value));
+
+    let parsed = match feature {
+        "min-width" => MediaFeature::MinWidth(length()?),
+        "max-width" => MediaFeature::MaxWidth(length()?),
+        "min-height" => MediaFeature::MinHeight(length()?),
+        "max-height" => MediaFeature::MaxHeight(length()?),
+        "orientation" => match value {
+            "portrait" => MediaFeature::Orientation(MediaOrientation::Portrait),
+            "landscape" => MediaFeature::Orientation(MediaOrientation::Landscape),
+            _ => return Err(InvalidValue(feature, value)),
+        },
+        "prefers-color-scheme" => match value {
+            "light" => MediaFeature::PrefersColorScheme(MediaColorScheme::Light),
+            "dark" => MediaFeature::PrefersColorScheme(MediaColorScheme::Dark),
+            _ => return Err(InvalidValue(feature, value)),
+        },
+        "resolution" => MediaFeature::Resolution(resolution()?),
+        "min-resolution" => MediaFeature::MinResolution(resolution()?),
+        "max-resolution" => MediaFeature::MaxResolution(resolution()?),
+        _ => return Err(UnknownFeature(feature)),
+    };
+
+    features.push(parsed);
+    Ok(())
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum MediaRangeOp { Lt, Le, Gt, Ge, Eq }
+
+impl MediaRangeOp {
+    /// `600px < width` is `width > 600px`
+    fn flip(self) -> Self {
+        use self::MediaRangeOp::*;
+        match self {
+            Lt => Gt,
+            Le => Ge,
+            Gt => Lt,
+            Ge => Le,
+            Eq => Eq,
+        }
+    }
+}
+
+/// Splits `"400px < width <= 800px"` into `["400px", "width", "800px"]` and `[Lt, Le]`
+fn split_media_range(input: &str) -> (Vec<&str>, Vec<MediaRangeOp>) {
+    let mut parts = Vec::new();
+    let mut ops = Vec::new();
+    let mut start = 0;
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let op = match c {
+            '<' | '>' => {
+                let or_equal = chars.peek().map(|(_, c)| *c) == Some('=');
+                if or_equal {
+                    chars.next();
+                }
+                match (c, or_equal) {
+                    ('<', false) => MediaRangeOp::Lt,
+                    ('<', true) => MediaRangeOp::Le,
+                    ('>', false) => MediaRangeOp::Gt,
+                    _ => MediaRangeOp::Ge,
+                }
+            },
+            '=' => MediaRangeOp::Eq,
+            _ => continue,
+        };
+        parts.push(input[start..i].trim());
+        ops.push(op);
+        start = chars.peek().map(|(i, _)| *i).unwrap_or(input.len());
+    }
+
+    parts.push(input[start..].trim());
+    (parts, ops)
+}
+
+/// Parses the range syntax: `"width < 600px"`, `"600px <= width"` or `"400px < width <= 800px"`
+fn parse_media_range<'a>(
+    input: &'a str,
+    query: &'a str,
+    features: &mut Vec<MediaFeature>,
+) -> Result<(), MediaQueryParseError<'a>> {
+
+    use self::MediaRangeOp::*;
+
+    let is_name = |s: &str| s.starts_with(|c: char| c.is_ascii_alphabetic());
+
+    let (parts, ops) = split_media_range(input);
+
+    if parts.len() == 2 && is_name(parts[0]) {
+        push_media_range(parts[0], ops[0], parts[1], features)
+    } else if parts.len() == 2 && is_name(parts[1]) {
+        push_media_range(parts[1], ops[0].flip(), parts[0], features)
+    } else if parts.len() == 3 && is_name(parts[1]) {
+        // both operators have to point in the same direction
+        match (ops[0], ops[1]) {
+            (Lt, Lt) | (Lt, Le) | (Le, Lt) | (Le, Le) |
+            (Gt, Gt) | (Gt, Ge) | (Ge, Gt) | (Ge, Ge) => { },
+            _ => return Err(MediaQueryParseError::InvalidSyntax(query)),
+        }
+        push_media_range(parts[1], ops[0].flip(), parts[0], features)?;
+        push_media_range(parts[1], ops[1], parts[2], features)
+    } else {
+        Err(MediaQueryParseError::InvalidSyntax(query))
+    }
+}
+
+/// Pushes the features for `feature op value`, i.e. `width <= 600px` is `max-width: 600px`
+fn push_media_range<'a>(
+    feature: &'a str,
+    op: MediaRangeOp,
+    value: &'a str,
+    features: &mut Vec<MediaFeature>,
+) -> Result<(), MediaQueryParseError<'a>> {
+
+    use self::MediaRangeOp::*;
+    use azul_css::MediaFeature::*;
+
+    let resolution = || parse_media_resolution(value).ok_or(MediaQueryParseError::InvalidValue(feature, value));
+
+    match (feature, op) {
+        ("width", Lt) => features.push(WidthLessThan(parse_media_length(feature, value)?)),
+        ("width", Le) => features.push(MaxWidth(parse_media_length(feature, value)?)),
+        ("width", Gt) => features.push(WidthGreaterThan(parse_media_length(feature, value)?)),
+        ("width", Ge) => features.push(MinWidth(parse_media_length(feature, value)?)),
+        ("width", Eq) => {
+            let v = parse_media_length(feature, value)?;
+            features.push(MinWidth(v));
+            features.push(MaxWidth(v));
+        },
+        ("height", Lt) => features.push(HeightLessThan(parse_media_length(feature, value)?)),
+        ("height", Le) => features.push(MaxHeight(parse_media_length(feature, value)?)),
+        ("height", Gt) => features.push(HeightGreaterThan(parse_media_length(feature, value)?)),
+        ("height", Ge) => features.push(MinHeight(parse_media_length(feature, value)?)),
+        ("height", Eq) => {
+            let v = parse_media_length(feature, value)?;
+            features.push(MinHeight(v));
+            features.push(MaxHeight(v));
+        },
+        ("resolution", Lt) => features.push(ResolutionLessThan(resolution()?)),
+        ("resolution", Le) => features.push(MaxResolution(resolution()?)),
+        ("resolution", Gt) => features.push(ResolutionGreaterThan(resolution()?)),
+        ("resolution", Ge) => features.push(MinResolution(resolution()?)),
+        ("resolution", Eq) => features.push(Resolution(resolution()?)),
+        _ => return Err(MediaQueryParseError::UnknownFeature(feature)),
+    }
+
+    Ok(())
+}
+
+/// Parses the length of a width / height feature, percentages are not allowed
+fn parse_media_length<'a>(feature: &'a str, value: &'a str) -> Result<PixelValue, MediaQueryParseError<'a>> {
+    crate::css_parser::parse_pixel_value_no_percent(value)
+        .map(|v| v.inner)
+        .map_err(|_| MediaQueryParseError::InvalidValue(feature, value))
+}
+
+/// Parses `"2dppx"`, `"2x"` or `"192dpi"` into dots per `px` (`2.0`)
+fn parse_media_resolution(input: &str) -> Option<FloatValue> {
+    let units = [("dppx", 1.0), ("dpi", 1.0 / DEFAULT_DPI), ("x", 1.0)];
+    for (suffix, factor) in units.iter() {
+        if let Some(number) = input.strip_suffix(suffix) {
+            let number = number.trim().parse::<f32>().ok()?;
+            return Some(FloatValue::new(number * factor));
+        }
+    }
+    None
+}
+
+#[test]
+fn test_parse_media_query() {
+
+    assert_eq!(
+        parse_media_query("(max-width: 600px)"),
+        Ok(MediaQuery::new(vec![MediaFeature::MaxWidth(PixelValue::px(600.0))]))
+    );
+    assert_eq!(
+        parse_media_query(" (min-width:40em) and (orientation: portrait)and (prefers-color-scheme: dark) "),
+        Ok(MediaQuery::new(vec![
+            MediaFeature::MinWidth(PixelValue::em(40.0)),
+            MediaFeature::Orientation(MediaOrientation::Portrait),
+            MediaFeature::PrefersColorScheme(MediaColorScheme::Dark),
+        ]))
+    );
+    assert_eq!(
+        parse_media_query("(min-resolution: 192dpi) and (max-resolution: 3x)"),
+        Ok(MediaQuery::new(vec![
+            MediaFeature::MinResolution(FloatValue::new(2.0)),
+            MediaFeature::MaxResolution(FloatValue::new(3.0)),
+        ]))
+    );
+
+    assert_eq!(
+        parse_media_query("(max-width: 600px) or (orientation: portrait), (prefers-color-scheme: dark) and (min-height: 300px)"),
+        Ok(MediaQuery::any(vec![
+            MediaCondition::new(vec![MediaFeature::MaxWidth(PixelValue::px(600.0))]),
+            MediaCondition::new(vec![MediaFeature::Orientation(MediaOrientation::Portrait)]),
+            MediaCondition::new(vec![
+                MediaFeature::PrefersColorScheme(MediaColorScheme::Dark),
+                MediaFeature::MinHeight(PixelValue::px(300.0)),
+            ]),
+        ]))
+    );
+    assert_eq!(
+        parse_media_query("(width < 600px) and (2dppx <= resolution) and (height = 300px)"),
+        Ok(MediaQuery::new(vec![
+            MediaFeature::WidthLessThan(PixelValue::px(600.0)),
+            MediaFeature::MinResolution(FloatValue::new(2.0)),
+            MediaFeature::MinHeight(PixelValue::px(300.0)),
+            MediaFeature::MaxHeight(PixelValue::px(300.0)),
+        ]))
+    );
+    assert_eq!(
+        parse_media_query("(400px < width <= 800px), (800px>=height>200px)"),
+        Ok(MediaQuery::any(vec![
+            MediaCondition::new(vec![
+                MediaFeature::WidthGreaterThan(PixelValue::px(400.0)),
+                MediaFeature::MaxWidth(PixelValue::px(800.0)),
+            ]),
+            MediaCondition::new(vec![
+                MediaFeature::MaxHeight(PixelValue::px(800.0)),
+                MediaFeature::HeightGreaterThan(PixelValue::px(200.0)),
+            ]),
+        ]))
+    );
+
+    assert_eq!(parse_media_query("screen and (max-width: 600px)"), Err(MediaQueryParseError::InvalidSyntax("screen and (max-width: 600px)")));
+    assert_eq!(parse_media_query("(max-width: 600px) and (orientation: portrait) or (min-height: 300px)"), Err(MediaQueryParseError::InvalidSyntax("(max-width: 600px) and (orientation: portrait) or (min-height: 300px)")));
+    assert_eq!(parse_media_query("(max-width: 600px),"), Err(MediaQueryParseError::InvalidSyntax("(max-width: 600px),")));
+    assert_eq!(parse_media_query("(400px < width > 800px)"), Err(MediaQueryParseError::InvalidSyntax("(400px < width > 800px)")));
+    assert_eq!(parse_media_query("(400px < 800px)"), Err(MediaQueryParseError::InvalidSyntax("(400px < 800px)")));
+    assert_eq!(parse_media_query("(aspect-ratio > 1)"), Err(MediaQueryParseError::UnknownFeature("aspect-ratio")));
+    assert_eq!(parse_media_query("(width < wide)"), Err(MediaQueryParseError::InvalidValue("width", "wide")));
+    assert_eq!(parse_media_query("(hover: hover)"), Err(MediaQueryParseError::UnknownFeature("hover")));
+    assert_eq!(parse_media_query("(max-width: 50%)"), Err(MediaQueryParseError::InvalidValue("max-width", "50%")));
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ErrorLocation {
+    pub original_pos: usize,
+}
+
+impl ErrorLocation {
+    /// Given an error location, returns the (line, column)
+    pub fn get_line_column_from_error(&self, css_string: &str) -> (usize, usize) {
+
+        let error_location = self.original_pos.saturating_sub(1);
+        let (mut line_number, mut total_characters) = (0, 0);
+
+        for line in css_string[0..error_location].lines() {
+            line_number += 1;
+            total_characters += line.chars().count();
+        }
+
+        // Rust doesn't count "\n" as a character, so we have to add the line number count on top
+        let total_characters = total_characters + line_number;
+        let column_pos = error_location - total_characters.saturating_sub(2);
+
+        (line_number, column_pos)
+    }
+}
+
+impl<'a> fmt::Display for CssParseError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let start_location = self.location.0.get_line_column_from_error(self.css_string);
+        let end_location = self.location.1.get_line_column_from_error(self.css_string);
+        write!(f, "    start: line {}:{}\r\n    end: line {}:{}\r\n    text: \"{}\"\r\n    reason: {}",
+            start_location.0, start_location.1,
+            end_location.0, end_location.1,
+            self.get_error_string(),
+            self.error,
+        )
+    }
+}
+
+pub fn new_from_str<'a>(css_string: &'a str) -> Result<Css, CssParseError<'a>> {
+
+    let mut css_blocks = Vec::new();
+
+    for group in split_media_groups(css_string)? {
+
+        let media = match group.media {
+            Some((query, offset)) => Some(parse_media_query(query).map_err(|e| CssParseError {
+                css_string,
+                error: e.into(),
+                location: (ErrorLocation { original_pos: offset }, ErrorLocation { original_pos: offset + query.len() }),
+            })?),
+            None => None,
+        };
+
+        let mut tokenizer = Tokenizer::new(group.contents);
+        css_blocks.extend(new_from_str_inner(css_string, &mut tokenizer, group.offset, media)?);
+    }
+
+    let (stylesheet, _warnings) = unparsed_css_blocks_to_stylesheet(css_blocks, css_string)?;
+    Ok(Css::new(vec![stylesheet]))
+}
+
+/// Top-level part of a stylesheet: either regular rules or the rules of one `@media` group
+#[derive(Debug, Clone, PartialEq)]
+struct CssRuleGroup<'a> {
+    /// Position of `contents` in the stylesheet
+    offset: usize,
+    contents: &'a str,
+    /// Condition of the `@media` group and its position in the stylesheet
+    media: Option<(&'a str, usize)>,
+}
+
+/// Splits the stylesheet at the top-level `@media` groups, since
+/// the tokenizer skips at-rules (including their contents)
+///
+/// NOTE: nested `@media` groups are not supported
+fn split_media_groups<'a>(css_string: &'a str) -> Result<Vec<CssRuleGroup<'a>>, CssParseError<'a>> {
+
+    const MEDIA: &str = "@media";
+
+    let bytes = css_string.as_bytes();
+    let mut groups = Vec::new();
+    let mut group_start = 0;
+    let mut depth = 0_usize;
+    // start of the current @media group: (query, query position, contents position)
+    let mut media_group = None;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            // braces in comments and strings don't count
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = css_string[i + 2..].find("*/").map(|p| i + p + 4).unwrap_or(bytes.len());
+                continue;
+            },
+            quote @ b'"' | quote @ b'\'' => {
+                i = css_string[i + 1..].find(quote as char).map(|p| i + p + 2).unwrap_or(bytes.len());
+                continue;
+            },
+            b'{' => depth += 1,
+            b'}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    if let Some((query, query_offset, contents_start)) = media_group.take() {
+                        groups.push(CssRuleGroup {
+                            offset: contents_start,
+                            contents: &css_string[contents_start..i],
+                            media: Some((query, query_offset)),
+                        });
+                        group_start = i + 1;
+                    }
+                }
+            },
+            b'@' if depth > 0 && css_string[i..].starts_with(MEDIA) => {
+                let query_offset = i + MEDIA.len();
+                let query_end = css_string[query_offset..].find(['{', ';']).map(|p| query_offset + p).unwrap_or(bytes.len());
+                return Err(CssParseError {
+                    css_string,
+                    error: MediaQueryParseError::Nested(&css_string[query_offset..query_end]).into(),
+                    location: (ErrorLocation { original_pos: i }, ErrorLocation { original_pos: query_end }),
+                });
+            },
+            b'@' if depth == 0 && css_string[i..].starts_with(MEDIA) => {
+                let block_start = css_string[i..].find('{').map(|p| i + p).ok_or(CssParseError {
+                    css_string,
+                    error: CssParseErrorInner::UnclosedBlock,
+                    location: (ErrorLocation { original_pos: i }, ErrorLocation { original_pos: bytes.len() }),
+                })?;
+
+                groups.push(CssRuleGroup {
+                    offset: group_start,
+                    contents: &css_string[group_start..i],
+                    media: None,
+                });
+
+                let query_offset = i + MEDIA.len();
+                media_group = Some((&css_string[query_offset..block_start], query_offset, block_start + 1));
+                depth = 1;
+                i = block_start + 1;
+                continue;
+            },
+            _ => { },
+        }
+        i += 1;
+    }
+
+    if let Some((_, query_offset, _)) = media_group {
+        return Err(CssParseError {
+            css_string,
+            error: CssParseErrorInner::UnclosedBlock,
+            location: (ErrorLocation { original_pos: query_offset }, ErrorLocation { original_pos: bytes.len() }),
+        });
+    }
+
+    groups.push(CssRuleGroup {
+        offset: group_start,
+        contents: &css_string[group_start..],
+        media: None,
+    });
+
+    Ok(groups)
+}
+
+/// Returns the location of where the parser is currently in the document,
+/// `offset` is the position of the tokenized string in the document
+fn get_error_location(tokenizer: &Tokenizer, offset: usize) -> ErrorLocation {
+    ErrorLocation {
+        original_pos: offset + tokenizer.pos(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssPathParseError<'a> {
+    EmptyPath,
+    /// Invalid item encountered in string (for example a "{", "}")
+    InvalidTokenEncountered(&'a str),
+    UnexpectedEndOfStream(&'a str),
+    SyntaxError(CssSyntaxError),
+    /// The path has to be either `*`, `div`, `p` or something like that
+    NodeTypeTag(NodeTypeTagParseError<'a>),
+    /// Error while parsing a pseudo selector (like `:aldkfja`)
+    PseudoSelectorParseError(CssPseudoSelectorParseError<'a>),
+}
+
+impl_from! { NodeTypeTagParseError<'a>, CssPathParseError::NodeTypeTag }
+impl_from! { CssPseudoSelectorParseError<'a>, CssPathParseError::PseudoSelectorParseError }
+
+impl<'a> From<CssSyntaxError> for CssPathParseError<'a> {
+    fn from(e: CssSyntaxError) -> Self {
+        CssPathParseError::SyntaxError(e)
+    }
+}
+
+/// Parses a CSS path from a string (only the path,.no commas allowed)
+///
+/// ```rust
+/// # extern crate azul_css;
+/// # extern crate azul_css_parser;
+/// # use azul_css_parser::parse_css_path;
+/// # use azul_css::{
+/// #     CssPathSelector::*, CssPathPseudoSelector::*, CssPath,
+/// #     NodeTypeTag::*, CssNthChildSelector::*
+/// # };
+///
+/// assert_eq!(
+///     parse_css_path("* div #my_id > .class:nth-child(2)"),
+///     Ok(CssPath {
+///         selectors: vec![
+///             Global,
+///             Type(Div),
+///             Children,
+///             Id("my_id".to_string().into()),
+///             DirectChildren,
+///             Class("class".to_string().into()),
+///             PseudoSelector(NthChild(Number(2))),
+///         ].into()
+///     })
+/// );
+/// ```
+pub fn parse_css_path<'a>(input: &'a str) -> Result<CssPath, CssPathParseError<'a>> {
+
+    use azul_simplecss::{Token, Combinator};
+
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(CssPathParseError::EmptyPath);
+    }
+
+    let mut tokenizer = Tokenizer::new(input);
+    let mut selectors = Vec::new();
+
+    loop {
+        let token = tokenizer.parse_next()?;
+        match token {
+            Token::UniversalSelector => {
+                selectors.push(CssPathSelector::Global);
+            },
+            Token::TypeSelector(div_type) => {
+                selectors.push(CssPathSelector::Type(NodeTypeTag::from_str(div_type)?));
+            },
+            Token::IdSelector(id) => {
+                selectors.push(CssPathSelector::Id(id.to_string().into()));
+            },
+            Token::ClassSelector(class) => {
+                selectors.push(CssPathSelector::Class(class.to_string().into()));
+            },
+            Token::Combinator(Combinator::GreaterThan) => {
+                selectors.push(CssPathSelector::DirectChildren);
+            },
+            Token::Combinator(Combinator::Space) => {
+                selectors.push(CssPathSelector::Children);
+            },
+            Token::PseudoClass { selector, value } => {
+                selectors.push(CssPathSelector::PseudoSelector(pseudo_selector_from_str(selector, value)?));
+            },
+            Token::EndOfStream => {
+                break;
+            }
+            _ => {
+                return Err(CssPathParseError::InvalidTokenEncountered(input));
+            }
+        }
+    }
+
+    if !selectors.is_empty() {
+        Ok(CssPath { selectors: selectors.into() })
+    } else {
+        Err(CssPathParseError::EmptyPath)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnparsedCssRuleBlock<'a> {
+    /// The css path (full selector) of the style ruleset
+    pub path: CssPath,
+    /// `"justify-content" => "center"`
+    pub declarations: BTreeMap<&'a str, (&'a str, (ErrorLocation, ErrorLocation))>,
+    /// Condition of the `@media` group that the block was declared in
+    pub media: Option<MediaQuery>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssParseWarnMsg<'a> {
+    warning: CssParseWarnMsgInner<'a>,
+    location: (ErrorLocation, ErrorLocation),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssParseWarnMsgInner<'a> {
+    /// Key "blah" isn't (yet) supported, so the parser didn't attempt to parse the value at all
+    UnsupportedKeyValuePair { key: &'a str, value: &'a str },
+}
+
+/// Tokenizes a part of the CSS string (single-threaded) and returns the unparsed rule blocks
+///
+/// `offset` is the position of the tokenized part in `css_string`, all blocks
+/// get the condition of the `@media` group that the part belongs to.
+fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>, offset: usize, media: Option<MediaQuery>)
+-> Result<Vec<UnparsedCssRuleBlock<'a>>, CssParseError<'a>> {
+
+    use azul_simplecss::{Token, Combinator};
+
+    let mut css_blocks = Vec::new();
+
+    // Used for error checking / checking for closed braces
+    let mut parser_in_block = false;
+    let mut block_nesting = 0_usize;
+
+    // Current css paths (i.e. `div#id, .class, p` are stored here -
+    // when the block is finished, all `current_rules` gets duplicated with
+    // one path corresponding to one set of rules each).
+    let mut current_paths = Vec::new();
+    // Current CSS declarations
+    let mut current_rules = BTreeMap::<&str, (&str, (ErrorLocation, ErrorLocation))>::new();
+    // Keep track of the current path during parsing
+    let mut last_path = Vec::new();
+
+    let mut last_error_location = ErrorLocation { original_pos: offset };
+
+    loop {
+
+        let token = tokenizer.parse_next().map_err(|e| CssParseError {
+            css_string,
+            error: e.into(),
+            location: (last_error_location, get_error_location(tokenizer, offset))
+        })?;
+
+        macro_rules! check_parser_is_outside_block {() => {
+            if parser_in_block {
+                return Err(CssParseError {
+                    css_string,
+                    error: CssParseErrorInner::MalformedCss,
+                    location: (last_error_location, get_error_location(tokenizer, offset)),
+                });
+            }
+        }}
+
+        macro_rules! check_parser_is_inside_block {() => {
+            if !parser_in_block {
+                return Err(CssParseError {
+                    css_string,
+                    error: CssParseErrorInner::MalformedCss,
+                    location: (last_error_location, get_error_location(tokenizer, offset)),
+                });
+            }
+        }}
+
+        match token {
+            Token::BlockStart => {
+                check_parser_is_outside_block!();
+                parser_in_block = true;
+                block_nesting += 1;
+                current_paths.push(last_path.clone());
+                last_path.clear();
+            },
+            Token::Comma => {
+                check_parser_is_outside_block!();
+                current_paths.push(last_path.clone());
+                last_path.clear();
+            },
+            Token::BlockEnd => {
+
+                block_nesting -= 1;
+                check_parser_is_inside_block!();
+                parser_in_block = false;
+
+                css_blocks.extend(current_paths.drain(..).map(|path| {
+                    UnparsedCssRuleBlock {
+                        path: CssPath { selectors: path.into() },
+                        declarations: current_rules.clone(),
+                        media: media.clone(),
+                    }
+                }));
+
+                current_rules.clear();
+                last_path.clear(); // technically unnecessary, but just to be sure
+            },
+
+            // tokens that adjust the last_path
+            Token::UniversalSelector => {
+                check_parser_is_outside_block!();
+                last_path.push(CssPathSelector::Global);
+            },
+            Token::TypeSelector(div_type) => {
+                check_parser_is_outside_block!();
+                last_path.push(CssPathSelector::Type(NodeTypeTag::from_str(div_type).map_err(|e| {
+                    CssParseError {
+                        css_string,
+                        error: e.into(),
+                        location: (last_error_location, get_error_location(tokenizer, offset)),
+                    }
+                })?));
+            },
+            Token::IdSelector(id) => {
+                check_parser_is_outside_block!();
+                last_path.push(CssPathSelector::Id(id.to_string().into()));
+            },
+            Token::ClassSelector(class) => {
+                check_parser_is_outside_block!();
+                last_path.push(CssPathSelector::Class(class.to_string().into()));
+            },
+            Token::Combinator(Combinator::GreaterThan) => {
+                check_parser_is_outside_block!();
+                last_path.push(CssPathSelector::DirectChildren);
+            },
+            Token::Combinator(Combinator::Space) => {
+                check_parser_is_outside_block!();
+                last_path.push(CssPathSelector::Children);
+            },
+            Token::PseudoClass { selector, value } => {
+                check_parser_is_outside_block!();
+                last_path.push(CssPathSelector::PseudoSelector(pseudo_selector_from_str(selector, value).map_err(|e| {
+                    CssParseError {
+                        css_string,
+                        error: e.into(),
+                        location: (last_error_location, get_error_location(tokenizer, offset)),
+                    }
+                })?));
+            },
+            Token::Declaration(key, val) => {
+                check_parser_is_inside_block!();
+                current_rules.insert(key, (val, (last_error_location, get_error_location(tokenizer, offset))));
+            },
+            Token::EndOfStream => {
+
+                // uneven number of open / close braces
+                if block_nesting != 0 {
+                    return Err(CssParseError {
+                        css_string,
+                        error: CssParseErrorInner::UnclosedBlock,
+                        location: (last_error_location, get_error_location(tokenizer, offset)),
+                    });
+                }
+
+                break;
+            },
+            _ => {
+                // attributes, lang-attributes and @keyframes are not supported
+            }
+        }
+
+        last_error_location = get_error_location(tokenizer, offset);
+    }
+
+    Ok(css_blocks)
+}
+
+/// Parses the values of the unparsed rule blocks
+///
+/// May return "warning" messages, i.e. messages that just serve as a warning,
+/// instead of being actual errors. These warnings may be ignored by the caller,
+/// but can be useful for debugging.
+fn unparsed_css_blocks_to_stylesheet<'a>(css_blocks: Vec<UnparsedCssRuleBlock<'a>>, css_string: &'a str)
+-> Result<(Stylesheet, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
+
+    // Actually parse the properties (TODO: this could be done in parallel and in a separate function)
+    let css_key_map = azul_css::get_css_key_map();
+
+    // custom properties, i.e. `--accent: #ff0000`: unlike in the browser, they
+    // are global, no matter in which block they are declared
+    let css_variables = css_blocks.iter()
+        .flat_map(|block| block.declarations.iter())
+        .filter_map(|(key, (value, _))| Some((key.strip_prefix("--")?, *value)))
+        .collect::<BTreeMap<&str, &str>>();
+
+    let mut warnings = Vec::new();
+
+    let parsed_css_blocks = css_blocks.into_iter().map(|unparsed_css_block| {
+
+        let mut declarations = Vec::<CssDeclaration>::new();
+
+        for (unparsed_css_key, (unparsed_css_value, location)) in unparsed_css_block.declarations {
+
+            if unparsed_css_key.starts_with("--") {
+                continue; // already collected in css_variables
+            }
+
+            parse_css_declaration_with_variables(
+                unparsed_css_key,
+                unparsed_css_value,
+                location,
+                &css_key_map,
+                &css_variables,
+                &mut warnings,
+                &mut declarations,
+            ).map_err(|e| CssParseError {
+                css_string,
+                error: e.into(),
+                location,
+            })?;
+        }
+
+        Ok(CssRuleBlock {
+            path: unparsed_css_block.path.into(),
+            declarations: declarations.into(),
+            media: unparsed_css_block.media.into(),
+        })
+    }).collect::<Result<Vec<CssRuleBlock>, CssParseError>>()?;
+
+    Ok((parsed_css_blocks.into(), warnings))
+}
+
+pub fn parse_css_declaration<'a>(
+    unparsed_css_key: &'a str,
+    unparsed_css_value: &'a str,
+    location: (ErrorLocation, ErrorLocation),
+    css_key_map: &CssKeyMap,
+    warnings: &mut Vec<CssParseWarnMsg<'a>>,
+    declarations: &mut Vec<CssDeclaration>,
+) -> Result<(), CssParseErrorInner<'a>> {
+    parse_css_declaration_with_variables(
+        unparsed_css_key,
+        unparsed_css_value,
+        location,
+        css_key_map,
+        &BTreeMap::new(),
+        warnings,
+        declarations,
+    )
+}
+
+/// Same as `parse_css_declaration`, but the default value of a `var(--id)` is
+/// the value of the custom property `--id` in `css_variables`, if it exists
+fn parse_css_declaration_with_variables<'a>(
+    unparsed_css_key: &'a str,
+    unparsed_css_value: &'a str,
+    location: (ErrorLocation, ErrorLocation),
+    css_key_map: &CssKeyMap,
+    css_variables: &BTreeMap<&'a str, &'a str>,
+    warnings: &mut Vec<CssParseWarnMsg<'a>>,
+    declarations: &mut Vec<CssDeclaration>,
+) -> Result<(), CssParseErrorInner<'a>> {
+
+    use self::CssParseErrorInner::*;
+    use self::CssParseWarnMsgInner::*;
+
+    if let Some(combined_key) = CombinedCssPropertyType::from_str(unparsed_css_key, &css_key_map) {
+        if let Some(css_var) = check_if_value_is_css_var(unparsed_css_value) {
+            // margin: var(--my-variable);
+            return Err(VarOnShorthandProperty { key: combined_key, value: unparsed_css_value });
+        } else {
+            // margin: 10px;
+            let parsed_css_properties =
+                css_parser::parse_combined_css_property(combined_key, unparsed_css_value)
+                .map_err(|e| DynamicCssParseError(e.into()))?;
+
+            declarations.extend(parsed_css_properties.into_iter().map(|val| CssDeclaration::Static(val)));
+        }
+    } else if let Some(normal_key) = CssPropertyType::from_str(unparsed_css_key, css_key_map) {
+        if let Some(css_var) = check_if_value_is_css_var(unparsed_css_value) {
+            // margin-left: var(--my-variable);
+            let (css_var_id, css_var_default) = css_var?;
+            let css_var_default = resolve_css_variable(css_var_id, css_var_default, css_variables)?;
+            let parsed_default_value =
+                css_parser::parse_css_property(normal_key, css_var_default)
+                .map_err(|e| DynamicCssParseError(e.into()))?;
+
+            declarations.push(CssDeclaration::Dynamic(DynamicCssProperty {
+                dynamic_id: css_var_id.to_string().into(),
+                default_value: parsed_default_value,
+            }));
+        } else {
+            // margin-left: 10px;
+            let parsed_css_value =
+                css_parser::parse_css_property(normal_key, unparsed_css_value)
+                .map_err(|e| DynamicCssParseError(e.into()))?;
+
+            declarations.push(CssDeclaration::Static(parsed_css_value));
+        }
+    } else {
+        // asldfkjasdf: 10px;
+        warnings.push(CssParseWarnMsg {
+            warning: UnsupportedKeyValuePair { key: unparsed_css_key, value: unparsed_css_value },
+            location,
+        });
+    }
+
+    Ok(())
+}
+
+/// Parses a single `key: value` pair into the properties it sets: one property for
+/// normal keys, such as `width: 10px`, or all expanded properties for shorthands,
+/// such as `padding: 1px 2px` (`padding-top`, `padding-bottom`, `padding-left`
+/// and `padding-right`).
+///
+/// Unlike `parse_css_declaration`, this function does not handle `var()` values
+/// and returns an error on unknown keys instead of a warning.
+///
+/// ```rust
+/// # extern crate azul_css_parser;
+/// # extern crate azul_css;
+/// # use azul_css::*;
+/// let key_map = get_css_key_map();
+/// assert_eq!(
+///     azul_css_parser::parse_css_key_value("width", "10px", &key_map),
+///     Ok(vec![CssProperty::Width(CssLengthValue::Exact(LayoutWidth::px(10.0)))])
+/// );
+/// ```
+pub fn parse_css_key_value<'a>(
+    key: &'a str,
+    value: &'a str,
+    css_key_map: &CssKeyMap,
+) -> Result<Vec<CssProperty>, CssParseErrorInner<'a>> {
+
+    let key = key.trim();
+    let value = value.trim();
+
+    if value.is_empty() {
+        return Err(CssParseErrorInner::EmptyPropertyValue(key));
+    }
+
+    if let Some(combined_key) = CombinedCssPropertyType::from_str(key, css_key_map) {
+        css_parser::parse_combined_css_property(combined_key, value)
+        .map_err(|e| CssParseErrorInner::DynamicCssParseError(e.into()))
+    } else if let Some(normal_key) = CssPropertyType::from_str(key, css_key_map) {
+        css_parser::parse_css_property(normal_key, value)
+        .map(|property| vec![property])
+        .map_err(|e| CssParseErrorInner::DynamicCssParseError(e.into()))
+    } else {
+        Err(CssParseErrorInner::UnknownPropertyKey(key, value))
+    }
+}
+
+fn check_if_value_is_css_var<'a>(unparsed_css_value: &'a str) -> Option<Result<(&'a str, &'a str), CssParseErrorInner<'a>>> {
+
+    const DEFAULT_VARIABLE_DEFAULT: &str = "none";
+
+    let (_, brace_contents) = css_parser::parse_parentheses(unparsed_css_value, &["var"]).ok()?;
+
+    // value is a CSS variable, i.e. var(--main-bg-color)
+    Some(match parse_css_variable_brace_contents(brace_contents) {
+        Some((variable_id, default_value)) => Ok((variable_id, default_value.unwrap_or(DEFAULT_VARIABLE_DEFAULT))),
+        None => Err(DynamicCssParseError::InvalidBraceContents(brace_contents).into()),
+    })
+}
+
+/// Returns the value of `var(--id, default)`: the value of the custom property `--id`
+/// if it is declared, otherwise the default. If the custom property is itself a
+/// `var()`, the variable it references is resolved the same way.
+fn resolve_css_variable<'a>(
+    id: &'a str,
+    default: &'a str,
+    css_variables: &BTreeMap<&'a str, &'a str>,
+) -> Result<&'a str, CssParseErrorInner<'a>> {
+
+    let (mut id, mut default) = (id, default);
+    let mut visited = Vec::new();
+
+    loop {
+        if visited.contains(&id) {
+            return Err(DynamicCssParseError::CyclicVariable(id).into());
+        }
+        visited.push(id);
+
+        let value = match css_variables.get(id) {
+            Some(value) => *value,
+            None => return Ok(default),
+        };
+
+        match check_if_value_is_css_var(value) {
+            Some(css_var) => {
+                let (next_id, next_default) = css_var?;
+                id = next_id;
+                default = next_default;
+            },
+            None => return Ok(value),
+        }
+    }
+}
+
+/// Parses the brace contents of a css var, i.e.:
+///
+/// ```no_run,ignore
+/// "--main-bg-col, blue" => (Some("main-bg-col"), Some("blue"))
+/// "--main-bg-col"       => (Some("main-bg-col"), None)
+/// ```
+fn parse_css_variable_brace_contents<'a>(input: &'a str) -> Option<(&'a str, Option<&'a str>)> {
+
+    let input = input.trim();
+
+    let mut split_comma_iter = input.splitn(2, ",");
+    let var_name = split_comma_iter.next()?;
+    let var_name = var_name.trim();
+
+    if !var_name.starts_with("--") {
+        return None; // no proper CSS variable name
+    }
+
+    Some((&var_name[2..], split_comma_iter.next()))
+}
+
+#[test]
+fn test_css_parse_1() {
+
+    use azul_css::*;
+
+    let parsed_css = new_from_str("
+        div#my_id .my_class:first {
+            background-color: red;
+        }
+    ").unwrap();
+
+
+    let expected_css_rules = vec![CssRuleBlock {
+        path: CssPath {
+            selectors: vec![
+                CssPathSelector::Type(NodeTypeTag::Div),
+                CssPathSelector::Id("my_id".to_string().into()),
+                CssPathSelector::Children,
+                // NOTE: This is technically wrong, the space between "#my_id"
+                // and ".my_class" is important, but gets ignored for now
+                CssPathSelector::Class("my_class".to_string().into()),
+                CssPathSelector::PseudoSelector(CssPathPseudoSelector::First),
+            ].into(),
+        },
+        declarations: vec![CssDeclaration::Static(CssProperty::BackgroundContent(
+            CssPropertyValue::exact(vec![StyleBackgroundContent::Color(ColorU {
+                r: 255,
+                g: 0,
+                b: 0,
+                a: 255,
+            })].into()),
+        ))].into(),
+        media: OptionMediaQuery::None,
+    }].into();
+
+    assert_eq!(
+        parsed_css,
+        Css::new(vec![expected_css_rules])
+    );
+}
+
+#[test]
+fn test_css_simple_selector_parse() {
+    use self::CssPathSelector::*;
+    use azul_css::NodeTypeTag;
+    let css = "div#id.my_class > p .new { }";
+    let parsed = vec![
+        Type(NodeTypeTag::Div),
+        Id("id".to_string().into()),
+        Class("my_class".to_string().into()),
+        DirectChildren,
+        Type(NodeTypeTag::P),
+        Children,
+        Class("new".to_string().into())
+    ];
+    assert_eq!(new_from_str(css).unwrap(), Css::new(vec![Stylesheet {
+        rules: vec![CssRuleBlock {
+            path: CssPath { selectors: parsed.into() },
+            declarations: Vec::new().into(),
+            media: OptionMediaQuery::None,
+        }].into(),
+    }]));
+}
+
+#[cfg(test)]
+mod stylesheet_parse {
+
+    use azul_css::*;
+    use super::*;
+
+    fn test_css(css: &str, expected: Vec<CssRuleBlock>) {
+        let css = new_from_str(css).unwrap();
+        assert_eq!(css, Css::new(vec![expected.into()]));
+    }
+
+    // Tests that an element with a single class always gets the CSS element applied properly
+    #[test]
+    fn test_apply_css_pure_class() {
+        let red = CssProperty::BackgroundContent(CssPropertyValue::exact(StyleBackgroundContentVec::from(vec![
+            StyleBackgroundContent::Color(ColorU {
+                r: 255,
+                g: 0,
+                b: 0,
+                a: 255,
+            }),
+        ])));
+        let blue = CssProperty::BackgroundContent(CssPropertyValue::exact(StyleBackgroundContentVec::from(vec![
+            StyleBackgroundContent::Color(ColorU {
+                r: 0,
+                g: 0,
+                b: 255,
+                a: 255,
+            }),
+        ])));
+        let black = CssProperty::BackgroundContent(CssPropertyValue::exact(StyleBackgroundContentVec::from(vec![
+            StyleBackgroundContent::Color(ColorU {
+                r: 0,
+                g: 0,
+                b: 0,
+                a: 255,
+            }),
+        ])));
+
+        // Simple example
+        {
+            let css_1 = ".my_class { background-color: red; }";
+            let expected_rules = vec![
+                CssRuleBlock {
+                    path: CssPath {
+                        selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into(),
+                    },
+                    declarations: vec![CssDeclaration::Static(red.clone())].into(),
+                    media: OptionMediaQuery::None,
+                },
+            ].into();
+            test_css(css_1, expected_rules);
+        }
+
+        // Slightly more complex example
+        {
+            let css_2 = "#my_id { background-color: red; } .my_class { background-color: blue; }";
+            let expected_rules = vec![
+                CssRuleBlock {
+                    path: CssPath { selectors: vec![CssPathSelector::Id("my_id".to_string().into())].into(), },
+                    declarations: vec![CssDeclaration::Static(red.clone())].into(),
+                    media: OptionMediaQuery::None,
+                },
+                CssRuleBlock {
+                    path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into(), },
+                    declarations: vec![CssDeclaration::Static(blue.clone())].into(),
+                    media: OptionMediaQuery::None,
+                },
+            ];
+            test_css(css_2, expected_rules);
+        }
+
+        // Even more complex example
+        {
+            let css_3 = "* { background-color: black; } .my_class#my_id { background-color: red; } .my_class { background-color: blue; }";
+            let expected_rules = vec![
+                CssRuleBlock {
+                    path: CssPath { selectors: vec![CssPathSelector::Global].into() },
+                    declarations: vec![CssDeclaration::Static(black.clone())].into(),
+                    media: OptionMediaQuery::None,
+                },
+                CssRuleBlock {
+                    path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into()), CssPathSelector::Id("my_id".to_string().into())].into(), },
+                    declarations: vec![CssDeclaration::Static(red.clone())].into(),
+                    media: OptionMediaQuery::None,
+                },
+                CssRuleBlock {
+                    path: CssPath { selectors: vec![CssPathSelector::Class("my_class".to_string().into())].into() },
+                    declarations: vec![CssDeclaration::Static(blue.clone())].into(),
+                    media: OptionMediaQuery::None,
+                },
+            ].into();
+            test_css(css_3, expected_rules);
+        }
+    }
+}
+
+// Assert that order of the style rules is correct (in same order as provided in CSS form)
+#[test]
+fn test_multiple_rules() {
+    use azul_css::*;
+    use self::CssPathSelector::*;
+
+    let parsed_css = new_from_str("
+        * { }
+        * div.my_class#my_id { }
+        * div#my_id { }
+        * #my_id { }
+        div.my_class.specific#my_id { }
+    ").unwrap();
+
+    let expected_rules = vec![
+        // Rules are sorted by order of appearance in source string
+        CssRuleBlock { path: CssPath { selectors: vec![Global].into() }, declarations: Vec::new().into(), media: OptionMediaQuery::None },
+        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media: OptionMediaQuery::None },
+        CssRuleBlock { path: CssPath { selectors: vec![Global, Type(NodeTypeTag::Div), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media: OptionMediaQuery::None },
+        CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media: OptionMediaQuery::None },
+        CssRuleBlock { path: CssPath { selectors: vec![Type(NodeTypeTag::Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into(), media: OptionMediaQuery::None },
+    ];
+
+    assert_eq!(parsed_css, Css::new(vec![expected_rules.into()]));
+}
+
+#[test]
+fn test_case_issue_93() {
+
+    use azul_css::*;
+    use self::CssPathSelector::*;
+
+    let parsed_css = new_from_str("
+        .tabwidget-tab-label {
+          color: #FFFFFF;
+        }
+
+        .tabwidget-tab.active .tabwidget-tab-label {
+          color: #000000;
+        }
+
+        .tabwidget-tab.active .tabwidget-tab-close {
+          color: #FF0000;
+        }
+    ").unwrap();
+
+    fn declaration(classes: &[CssPathSelector], color: ColorU) -> CssRuleBlock {
+        CssRuleBlock {
+            path: CssPath {
+                selectors: classes.to_vec().into(),
+            },
+            declarations: vec![CssDeclaration::Static(CssProperty::TextColor(
+                CssPropertyValue::Exact(StyleTextColor { inner: color }),
+            ))].into(),
+            media: OptionMediaQuery::None,
+        }
+    }
+
+    let expected_rules = vec![
+        declaration(&[Class("tabwidget-tab-label".to_string().into())], ColorU { r: 255, g: 255, b: 255, a: 255 }),
+        declaration(&[Class("tabwidget-tab".to_string().into()), Class("active".to_string().into()), Children, Class("tabwidget-tab-label".to_string().into())], ColorU { r: 0, g: 0, b: 0, a: 255 }),
+        declaration(&[Class("tabwidget-tab".to_string().into()), Class("active".to_string().into()), Children, Class("tabwidget-tab-close".to_string().into())], ColorU { r: 255, g: 0, b: 0, a: 255 }),
+    ];
+
+    assert_eq!(parsed_css, Css::new(vec![expected_rules.into()]));
+}
+
+#[test]
+fn test_parse_css_key_value() {
+
+    use azul_css::*;
+
+    let key_map = get_css_key_map();
+
+    assert_eq!(
+        parse_css_key_value("width", "10px", &key_map),
+        Ok(vec![CssProperty::Width(CssLengthValue::Exact(LayoutWidth::px(10.0)))])
+    );
+
+    let padding = parse_css_key_value("padding", "1px 2px", &key_map).unwrap();
+    assert_eq!(padding.len(), 4);
+    assert!(padding.contains(&CssProperty::PaddingTop(CssLengthValue::Exact(LayoutPaddingTop::px(1.0)))));
+    assert!(padding.contains(&CssProperty::PaddingBottom(CssLengthValue::Exact(LayoutPaddingBottom::px(1.0)))));
+    assert!(padding.contains(&CssProperty::PaddingLeft(CssLengthValue::Exact(LayoutPaddingLeft::px(2.0)))));
+    assert!(padding.contains(&CssProperty::PaddingRight(CssLengthValue::Exact(LayoutPaddingRight::px(2.0)))));
+
+    assert_eq!(
+        parse_css_key_value("asdfasdf", "10px", &key_map),
+        Err(CssParseErrorInner::UnknownPropertyKey("asdfasdf", "10px"))
+    );
+    assert!(parse_css_key_value("width", "abc", &key_map).is_err());
+
+    for key in &["opacity", "line-height", "tab-width", "width"] {
+        assert_eq!(
+            parse_css_key_value(key, "", &key_map),
+            Err(CssParseErrorInner::EmptyPropertyValue(key))
+        );
+        assert_eq!(
+            parse_css_key_value(key, "  ", &key_map),
+            Err(CssParseErrorInner::EmptyPropertyValue(key))
+        );
+    }
+    assert!(parse_css_key_value("opacity", "5€", &key_map).is_err());
+    assert!(parse_css_key_value("line-height", "1.5é", &key_map).is_err());
+}
+
+#[test]
+fn test_css_variables() {
+
+    use azul_css::*;
+
+    let text_color = |inner| CssProperty::TextColor(CssPropertyValue::Exact(StyleTextColor { inner }));
+
+    let css = new_from_str("
+        body { --base: #ff0000; --accent: var(--base); color: var(--accent, #0000ff); }
+        div { color: var(--unset, #0000ff); }
+    ").unwrap();
+
+    let declarations = css.rules().flat_map(|r| r.declarations.iter().cloned()).collect::<Vec<_>>();
+    assert_eq!(declarations, vec![
+        CssDeclaration::Dynamic(DynamicCssProperty {
+            dynamic_id: "accent".to_string().into(),
+            default_value: text_color(ColorU { r: 255, g: 0, b: 0, a: 255 }),
+        }),
+        CssDeclaration::Dynamic(DynamicCssProperty {
+            dynamic_id: "unset".to_string().into(),
+            default_value: text_color(ColorU { r: 0, g: 0, b: 255, a: 255 }),
+        }),
+    ]);
+
+    let cyclic = new_from_str("body { --a: var(--b); --b: var(--a); color: var(--a); }").unwrap_err();
+    assert_eq!(cyclic.error, CssParseErrorInner::DynamicCssParseError(DynamicCssParseError::CyclicVariable("a")));
+}
+
+#[test]
+fn test_media_queries() {
+
+    use azul_css::*;
+
+    let css = new_from_str("
+        div { flex-direction: row; }
+        @media (max-width: 600px) {
+            div { flex-direction: column; }
+            /* } */ p { flex-direction: column; }
+        }
+        p { flex-direction: row; }
+    ").unwrap();
+
+    let rules = css.rules().collect::<Vec<_>>();
+    assert_eq!(rules.len(), 4);
+    assert_eq!(rules[0].media, OptionMediaQuery::None);
+    assert_eq!(rules[3].media, OptionMediaQuery::None);
+
+    let query = MediaQuery::new(vec![MediaFeature::MaxWidth(PixelValue::px(600.0))]);
+    assert_eq!(rules[1].media, OptionMediaQuery::Some(query.clone()));
+    assert_eq!(rules[2].media, OptionMediaQuery::Some(query));
+    assert_eq!(rules[1].declarations.as_ref(), &[CssDeclaration::Static(CssProperty::flex_direction(LayoutFlexDirection::Column))]);
+
+    let env = |width| MediaEnvironment { width, ..Default::default() };
+    assert!(rules[1].matches_media(&env(599.0)));
+    assert!(!rules[1].matches_media(&env(601.0)));
+    assert!(rules[0].matches_media(&env(601.0)));
+
+    let unclosed = new_from_str("@media (max-width: 600px) { div { }").unwrap_err();
+    assert_eq!(unclosed.error, CssParseErrorInner::UnclosedBlock);
+
+    let invalid = new_from_str("div { } @media (hover: hover) { div { } }").unwrap_err();
+    assert_eq!(invalid.error, CssParseErrorInner::MediaQuery(MediaQueryParseError::UnknownFeature("hover")));
+    assert_eq!(invalid.get_error_string(), "(hover: hover)");
+
+    let nested = new_from_str("@media (max-width: 600px) { @media (orientation: portrait) { div { } } }").unwrap_err();
+    assert_eq!(nested.error, CssParseErrorInner::MediaQuery(MediaQueryParseError::Nested(" (orientation: portrait) ")));
+    assert_eq!(nested.get_error_string(), "@media (orientation: portrait)");
+}
@@ -6,7 +6,7 @@ use alloc::vec::Vec;
 use alloc::string::String;
 
 use azul_css::{
-    CssPropertyType, CssProperty, CombinedCssPropertyType, CssPropertyValue,
+    CssPropertyType, CssProperty, CombinedCssPropertyType, CssPropertyValue, CssLengthValue,
     LayoutOverflow, Shape, PixelValue, AngleValue, AngleMetric, PixelValueNoPercent,
     PercentageValue, FloatValue, ColorU, LinearColorStop, LinearGradient,
     RadialColorStop, RadialGradient, ConicGradient,
@@ -16,8 +16,11 @@ use azul_css::{
     BackgroundPositionHorizontal, BackgroundPositionVertical, ScrollbarStyle,
     RadialGradientSize, AzString, NormalizedLinearColorStop, NormalizedRadialColorStop,
 
-    StyleFilter, StyleMixBlendMode,
-    StyleTextColor, StyleFontSize, StyleFontFamily, StyleTextAlign,
+    StyleFilter, StyleMixBlendMode, StyleIsolation, StyleImageRendering, StyleDirection,
+    StyleTransitionProperty, StyleTransitionDuration, StyleTransitionDelay,
+    StyleTransitionTimingFunction, StyleCubicBezier, StyleSteps, StepPosition,
+    StyleCaretColor, StyleSelectionColor, StyleSelectionBackground,
+    StyleTextColor, StyleFontSize, StyleFontFamily, StyleTextAlign, StyleVerticalAlign,
     StyleLetterSpacing, StyleLineHeight, StyleWordSpacing, StyleTabWidth,
     StyleCursor, StyleBackgroundContent, StyleBackgroundPosition, StyleBackgroundSize,
     StyleBackgroundRepeat, StyleBorderTopLeftRadius, StyleBorderTopRightRadius,
@@ -36,7 +39,9 @@ use azul_css::{
     LayoutFlexDirection, LayoutFlexGrow, LayoutFlexShrink, LayoutJustifyContent,
     LayoutAlignItems, LayoutAlignContent, LayoutPaddingRight, LayoutPaddingBottom,
     LayoutMarginTop, LayoutMarginLeft, LayoutMarginRight, LayoutMarginBottom,
-    LayoutPaddingTop, LayoutPaddingLeft,
+    LayoutPaddingTop, LayoutPaddingLeft, LayoutGridTemplateColumns, LayoutGridTemplateRows,
+    LayoutGridColumn, LayoutGridRow, GridTrack, GridTrackVec, GridTrackRepeat, GridLine,
+    GridPlacement, CalcExpression,
 };
 
 pub trait FormatAsCssValue {
@@ -70,8 +75,8 @@ impl FormatAsCssValue for PixelValue {
 impl FormatAsCssValue for StyleTransform {
     fn format_as_css_value(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            StyleTransform::Matrix(m) => write!(f, "matrix({}, {}, {}, {}, {}, {})", m.a, m.b, m.c, m.d, m.tx, m.ty),
-            StyleTransform::Matrix3D(m) => write!(f, "matrix3d({}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {})", m.m11, m.m12, m.m13, m.m14, m.m21, m.m22, m.m23, m.m24, m.m31, m.m32, m.m33, m.m34, m.m41, m.m42, m.m43, m.m44),
+            StyleTransform::Matrix(m) => write!(f, "matrix({}, {}, {}, {}, {}, {})", m.a.number, m.b.number, m.c.number, m.d.number, m.tx.number, m.ty.number),
+            StyleTransform::Matrix3D(m) => write!(f, "matrix3d({}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {})", m.m11.number, m.m12.number, m.m13.number, m.m14.number, m.m21.number, m.m22.number, m.m23.number, m.m24.number, m.m31.number, m.m32.number, m.m33.number, m.m34.number, m.m41.number, m.m42.number, m.m43.number, m.m44.number),
             StyleTransform::Translate(t) => write!(f, "translate({}, {})", t.x, t.y),
             StyleTransform::Translate3D(t) => write!(f, "translate3d({}, {}, {})", t.x, t.y, t.z),
             StyleTransform::TranslateX(x) => write!(f, "translateX({})", x),
@@ -191,10 +196,10 @@ macro_rules! typed_pixel_value_parser {
 /// # extern crate azul_css;
 ///
 /// # use azul_css_parser;
-/// # use azul_css::{LayoutWidth, PixelValue, CssPropertyType, CssPropertyValue, CssProperty};
+/// # use azul_css::{LayoutWidth, PixelValue, CssPropertyType, CssLengthValue, CssProperty};
 /// assert_eq!(
 ///     azul_css_parser::parse_css_property(CssPropertyType::Width, "500px"),
-///     Ok(CssProperty::Width(CssPropertyValue::Exact(LayoutWidth { inner: PixelValue::px(500.0) })))
+///     Ok(CssProperty::Width(CssLengthValue::Exact(LayoutWidth { inner: PixelValue::px(500.0) })))
 /// )
 /// ```
 pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<CssProperty, CssParsingError<'a>> {
@@ -205,11 +210,19 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
         "none" => CssProperty::none(key),
         "initial" => CssProperty::initial(key).into(),
         "inherit" => CssProperty::inherit(key).into(),
+        value if value.starts_with("calc(") => {
+            let calc = parse_calc_expression(value)?
+                .to_calc_length()
+                .ok_or(CssCalcParseError::DivisionByZero)?;
+            CssProperty::calc(key, calc).ok_or(InvalidValueErr(value))?
+        },
         value => match key {
             TextColor                   => parse_style_text_color(value)?.into(),
             FontSize                    => parse_style_font_size(value)?.into(),
             FontFamily                  => parse_style_font_family(value)?.into(),
             TextAlign                   => parse_layout_text_align(value)?.into(),
+            VerticalAlign               => parse_style_vertical_align(value)?.into(),
+            Direction                   => parse_style_direction(value)?.into(),
             LetterSpacing               => parse_style_letter_spacing(value)?.into(),
             LineHeight                  => parse_style_line_height(value)?.into(),
             WordSpacing                 => parse_style_word_spacing(value)?.into(),
@@ -237,6 +250,10 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             JustifyContent              => parse_layout_justify_content(value)?.into(),
             AlignItems                  => parse_layout_align_items(value)?.into(),
             AlignContent                => parse_layout_align_content(value)?.into(),
+            GridTemplateColumns         => parse_layout_grid_template_columns(value)?.into(),
+            GridTemplateRows            => parse_layout_grid_template_rows(value)?.into(),
+            GridColumn                  => parse_layout_grid_column(value)?.into(),
+            GridRow                     => parse_layout_grid_row(value)?.into(),
 
             BackgroundContent           => parse_style_background_content_multiple(value)?.into(),
             BackgroundPosition          => parse_style_background_position_multiple(value)?.into(),
@@ -276,10 +293,10 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             BorderLeftWidth             => parse_style_border_left_width(value)?.into(),
             BorderBottomWidth           => parse_style_border_bottom_width(value)?.into(),
 
-            BoxShadowLeft               => CssProperty::BoxShadowLeft(CssPropertyValue::Exact(parse_style_box_shadow(value)?)).into(),
-            BoxShadowRight              => CssProperty::BoxShadowRight(CssPropertyValue::Exact(parse_style_box_shadow(value)?)).into(),
-            BoxShadowTop                => CssProperty::BoxShadowTop(CssPropertyValue::Exact(parse_style_box_shadow(value)?)).into(),
-            BoxShadowBottom             => CssProperty::BoxShadowBottom(CssPropertyValue::Exact(parse_style_box_shadow(value)?)).into(),
+            BoxShadowLeft               => CssProperty::BoxShadowLeft(CssPropertyValue::exact(parse_style_box_shadow(value)?)).into(),
+            BoxShadowRight              => CssProperty::BoxShadowRight(CssPropertyValue::exact(parse_style_box_shadow(value)?)).into(),
+            BoxShadowTop                => CssProperty::BoxShadowTop(CssPropertyValue::exact(parse_style_box_shadow(value)?)).into(),
+            BoxShadowBottom             => CssProperty::BoxShadowBottom(CssPropertyValue::exact(parse_style_box_shadow(value)?)).into(),
 
             ScrollbarStyle              => parse_scrollbar_style(value)?.into(), // TODO: stub - always returns default style
 
@@ -290,9 +307,20 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             BackfaceVisibility          => parse_style_backface_visibility(value)?.into(),
 
             MixBlendMode                => parse_style_mix_blend_mode(value)?.into(),
-            Filter                      => CssProperty::Filter(CssPropertyValue::Exact(parse_style_filter_vec(value)?)).into(),
-            BackdropFilter              => CssProperty::BackdropFilter(CssPropertyValue::Exact(parse_style_filter_vec(value)?)).into(),
-            TextShadow                  => CssProperty::TextShadow(CssPropertyValue::Exact(parse_style_box_shadow(value)?)).into(),
+            Isolation                   => parse_style_isolation(value)?.into(),
+            ImageRendering              => parse_style_image_rendering(value)?.into(),
+            Filter                      => CssProperty::Filter(CssPropertyValue::exact(parse_style_filter_vec(value)?)).into(),
+            BackdropFilter              => CssProperty::BackdropFilter(CssPropertyValue::exact(parse_style_filter_vec(value)?)).into(),
+            TextShadow                  => CssProperty::TextShadow(CssPropertyValue::exact(parse_style_box_shadow(value)?)).into(),
+
+            TransitionProperty          => parse_style_transition_property(value)?.into(),
+            TransitionDuration          => parse_style_transition_duration(value)?.into(),
+            TransitionTimingFunction    => parse_style_transition_timing_function(value)?.into(),
+            TransitionDelay             => parse_style_transition_delay(value)?.into(),
+
+            CaretColor                  => parse_style_caret_color(value)?.into(),
+            SelectionColor              => parse_style_selection_color(value)?.into(),
+            SelectionBackground         => parse_style_selection_background(value)?.into(),
         }
     })
 }
@@ -423,6 +451,14 @@ pub fn parse_combined_css_property<'a>(key: CombinedCssPropertyType, value: &'a
             vec![
                 CssPropertyType::BackgroundContent,
             ]
+        },
+        Transition => {
+            vec![
+                CssPropertyType::TransitionProperty,
+                CssPropertyType::TransitionDuration,
+                CssPropertyType::TransitionTimingFunction,
+                CssPropertyType::TransitionDelay,
+            ]
         }
     };
 
@@ -445,11 +481,8 @@ pub fn parse_combined_css_property<'a>(key: CombinedCssPropertyType, value: &'a
             ])
         },
         Overflow => {
-            let overflow = parse_layout_overflow(value)?;
-            Ok(vec![
-                CssProperty::OverflowX(overflow.into()),
-                CssProperty::OverflowY(overflow.into()),
-            ])
+            let (overflow_x, overflow_y) = parse_layout_overflow_xy(value)?;
+            Ok(CssProperty::overflow(overflow_x, overflow_y).to_vec())
         },
         Padding => {
             let padding = parse_layout_padding(value)?;
@@ -519,13 +552,7 @@ pub fn parse_combined_css_property<'a>(key: CombinedCssPropertyType, value: &'a
             ])
         },
         BoxShadow => {
-            let box_shadow = parse_style_box_shadow(value)?;
-            Ok(vec![
-               CssProperty::BoxShadowLeft(CssPropertyValue::Exact(box_shadow)),
-               CssProperty::BoxShadowRight(CssPropertyValue::Exact(box_shadow)),
-               CssProperty::BoxShadowTop(CssPropertyValue::Exact(box_shadow)),
-               CssProperty::BoxShadowBottom(CssPropertyValue::Exact(box_shadow)),
-            ])
+            Ok(expand_box_shadow_shorthand(value)?.to_vec())
         },
         BackgroundColor => {
             let color = parse_css_color(value)?;
@@ -540,10 +567,21 @@ pub fn parse_combined_css_property<'a>(key: CombinedCssPropertyType, value: &'a
             Ok(vec![
                 CssProperty::BackgroundContent(vec.into()),
             ])
+        },
+        Transition => {
+            Ok(parse_transition_shorthand(value)?.to_vec())
         }
     }
 }
 
+/// Parses the value of a `box-shadow` shorthand and returns the
+/// shadows of the four sides (left, right, top, bottom)
+pub fn expand_box_shadow_shorthand<'a>(value: &'a str)
+-> Result<[CssProperty; 4], CssShadowParseError<'a>>
+{
+    parse_style_box_shadow(value).map(CssProperty::box_shadow)
+}
+
 /// Error containing all sub-errors that could happen during CSS parsing
 ///
 /// Usually we want to crash on the first error, to notify the user of the problem.
@@ -570,6 +608,9 @@ pub enum CssParsingError<'a> {
     Opacity(OpacityParseError<'a>),
     Scrollbar(CssScrollbarStyleParseError<'a>),
     Filter(CssStyleFilterParseError<'a>),
+    Grid(CssGridParseError<'a>),
+    Calc(CssCalcParseError<'a>),
+    Transition(CssTransitionParseError<'a>),
 }
 
 impl_debug_as_display!(CssParsingError<'a>);
@@ -595,6 +636,9 @@ impl_display!{ CssParsingError<'a>, {
     Opacity(e) => format!("{}", e),
     Scrollbar(e) => format!("{}", e),
     Filter(e) => format!("{}", e),
+    Grid(e) => format!("{}", e),
+    Calc(e) => format!("{}", e),
+    Transition(e) => format!("{}", e),
 }}
 
 impl_from!(CssBorderParseError<'a>, CssParsingError::CssBorderParseError);
@@ -617,6 +661,9 @@ impl_from!(CssStylePerspectiveOriginParseError<'a>, CssParsingError::Perspective
 impl_from!(OpacityParseError<'a>, CssParsingError::Opacity);
 impl_from!(CssScrollbarStyleParseError<'a>, CssParsingError::Scrollbar);
 impl_from!(CssStyleFilterParseError<'a>, CssParsingError::Filter);
+impl_from!(CssGridParseError<'a>, CssParsingError::Grid);
+impl_from!(CssCalcParseError<'a>, CssParsingError::Calc);
+impl_from!(CssTransitionParseError<'a>, CssParsingError::Transition);
 
 impl<'a> From<PercentageParseError> for CssParsingError<'a> {
     fn from(e: PercentageParseError) -> Self {
@@ -928,6 +975,253 @@ pub fn parse_pixel_value_no_percent<'a>(input: &'a str)
     })
 }
 
+#[derive(Clone, PartialEq)]
+pub enum CssCalcParseError<'a> {
+    /// Input doesn't start with `calc(`
+    NotACalcExpression(&'a str),
+    UnexpectedToken(&'a str),
+    UnexpectedEnd,
+    UnbalancedParenthesis,
+    /// `+` and `-` have to be surrounded by whitespace: `100%-32px` is invalid
+    MissingWhitespace,
+    /// i.e. `10px * 10px` or `100% + 5`
+    TypeMismatch,
+    DivisionByZero,
+    Pixel(CssPixelValueParseError<'a>),
+}
+
+impl_debug_as_display!(CssCalcParseError<'a>);
+impl_display!{ CssCalcParseError<'a>, {
+    NotACalcExpression(e) => format!("Expected \"calc(...)\", got: \"{}\"", e),
+    UnexpectedToken(e) => format!("Unexpected token in calc(): \"{}\"", e),
+    UnexpectedEnd => format!("Unexpected end of calc() expression"),
+    UnbalancedParenthesis => format!("Unbalanced parenthesis in calc() expression"),
+    MissingWhitespace => format!("\"+\" and \"-\" in calc() have to be surrounded by whitespace"),
+    TypeMismatch => format!("Invalid calc() expression: lengths can only be added or subtracted, and multiplied or divided by numbers"),
+    DivisionByZero => format!("Division by zero in calc() expression"),
+    Pixel(e) => format!("Error parsing calc() value: {}", e),
+}}
+
+impl_from!(CssPixelValueParseError<'a>, CssCalcParseError::Pixel);
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum CalcToken<'a> {
+    /// Number or length, i.e. `2`, `-32px` or `100%`
+    Value(&'a str),
+    Plus,
+    Minus,
+    Mul,
+    Div,
+    /// `(` or `calc(`
+    OpenParen,
+    CloseParen,
+}
+
+impl<'a> CalcToken<'a> {
+    fn as_str(&self) -> &'a str {
+        match self {
+            CalcToken::Value(v) => v,
+            CalcToken::Plus => "+",
+            CalcToken::Minus => "-",
+            CalcToken::Mul => "*",
+            CalcToken::Div => "/",
+            CalcToken::OpenParen => "(",
+            CalcToken::CloseParen => ")",
+        }
+    }
+}
+
+/// Splits a calc() expression into tokens, the `bool` is
+/// whether the token is preceded by whitespace
+fn tokenize_calc_expression<'a>(input: &'a str)
+-> Result<Vec<(CalcToken<'a>, bool)>, CssCalcParseError<'a>>
+{
+    let mut tokens = Vec::new();
+    let mut whitespace_before = false;
+    let mut idx = 0;
+
+    while let Some(ch) = input[idx..].chars().next() {
+        let rest = &input[idx..];
+        let starts_number = |s: &str| s.chars().next().map(|c| c.is_ascii_digit() || c == '.').unwrap_or(false);
+
+        let (token, len) = if ch.is_whitespace() {
+            whitespace_before = true;
+            idx += ch.len_utf8();
+            continue;
+        } else if rest.starts_with("calc(") {
+            (CalcToken::OpenParen, "calc(".len())
+        } else if starts_number(rest) || (
+            (ch == '-' || ch == '+') &&
+            starts_number(&rest[1..]) &&
+            !matches!(tokens.last(), Some((CalcToken::Value(_), _)) | Some((CalcToken::CloseParen, _)))
+        ) {
+            // sign is part of the number: "-32px", but "100% -32px" is an operator
+            // with a missing whitespace, not two values
+            let len = 1 + rest[1..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '%'))
+                .unwrap_or(rest.len() - 1);
+            (CalcToken::Value(&rest[..len]), len)
+        } else {
+            let token = match ch {
+                '+' => CalcToken::Plus,
+                '-' => CalcToken::Minus,
+                '*' => CalcToken::Mul,
+                '/' => CalcToken::Div,
+                '(' => CalcToken::OpenParen,
+                ')' => CalcToken::CloseParen,
+                _ => return Err(CssCalcParseError::UnexpectedToken(rest)),
+            };
+            (token, ch.len_utf8())
+        };
+
+        tokens.push((token, whitespace_before));
+        whitespace_before = false;
+        idx += len;
+    }
+
+    Ok(tokens)
+}
+
+/// Intermediate result of a calc() sub-expression: `(2 * 3)` is
+/// a number, `(100% - 32px)` a length
+enum CalcOperand {
+    Number(f32),
+    Length(CalcExpression),
+}
+
+struct CalcParser<'a> {
+    tokens: Vec<(CalcToken<'a>, bool)>,
+    pos: usize,
+}
+
+impl<'a> CalcParser<'a> {
+
+    fn peek(&self) -> Option<CalcToken<'a>> {
+        self.tokens.get(self.pos).map(|(t, _)| *t)
+    }
+
+    fn has_whitespace_before(&self, pos: usize) -> bool {
+        self.tokens.get(pos).map(|(_, ws)| *ws).unwrap_or(false)
+    }
+
+    // sum := product (("+" | "-") product)*
+    fn parse_sum(&mut self) -> Result<CalcOperand, CssCalcParseError<'a>> {
+        let mut lhs = self.parse_product()?;
+        loop {
+            let is_add = match self.peek() {
+                Some(CalcToken::Plus) => true,
+                Some(CalcToken::Minus) => false,
+                _ => return Ok(lhs),
+            };
+            if !self.has_whitespace_before(self.pos) || !self.has_whitespace_before(self.pos + 1) {
+                return Err(CssCalcParseError::MissingWhitespace);
+            }
+            self.pos += 1;
+            let rhs = self.parse_product()?;
+            lhs = match (lhs, rhs) {
+                (CalcOperand::Number(a), CalcOperand::Number(b)) => {
+                    CalcOperand::Number(if is_add { a + b } else { a - b })
+                },
+                (CalcOperand::Length(a), CalcOperand::Length(b)) => {
+                    let (a, b) = (Box::new(a), Box::new(b));
+                    CalcOperand::Length(if is_add { CalcExpression::Add(a, b) } else { CalcExpression::Sub(a, b) })
+                },
+                _ => return Err(CssCalcParseError::TypeMismatch),
+            };
+        }
+    }
+
+    // product := value (("*" | "/") value)*
+    fn parse_product(&mut self) -> Result<CalcOperand, CssCalcParseError<'a>> {
+        let mut lhs = self.parse_value()?;
+        loop {
+            let is_mul = match self.peek() {
+                Some(CalcToken::Mul) => true,
+                Some(CalcToken::Div) => false,
+                _ => return Ok(lhs),
+            };
+            self.pos += 1;
+            let rhs = self.parse_value()?;
+            lhs = match (lhs, rhs) {
+                (_, CalcOperand::Number(b)) if !is_mul && b == 0.0 => {
+                    return Err(CssCalcParseError::DivisionByZero);
+                },
+                (CalcOperand::Number(a), CalcOperand::Number(b)) => {
+                    CalcOperand::Number(if is_mul { a * b } else { a / b })
+                },
+                (CalcOperand::Length(a), CalcOperand::Number(b)) => {
+                    let (a, b) = (Box::new(a), FloatValue::new(b));
+                    CalcOperand::Length(if is_mul { CalcExpression::Mul(a, b) } else { CalcExpression::Div(a, b) })
+                },
+                (CalcOperand::Number(a), CalcOperand::Length(b)) if is_mul => {
+                    CalcOperand::Length(CalcExpression::Mul(Box::new(b), FloatValue::new(a)))
+                },
+                _ => return Err(CssCalcParseError::TypeMismatch),
+            };
+        }
+    }
+
+    // value := number | length | "(" sum ")"
+    fn parse_value(&mut self) -> Result<CalcOperand, CssCalcParseError<'a>> {
+        let token = self.peek().ok_or(CssCalcParseError::UnexpectedEnd)?;
+        self.pos += 1;
+        match token {
+            CalcToken::Value(v) => match v.parse::<f32>() {
+                Ok(number) => Ok(CalcOperand::Number(number)),
+                Err(_) => Ok(CalcOperand::Length(CalcExpression::Value(parse_pixel_value(v)?))),
+            },
+            CalcToken::OpenParen => {
+                let inner = self.parse_sum()?;
+                match self.peek() {
+                    Some(CalcToken::CloseParen) => { self.pos += 1; Ok(inner) },
+                    Some(other) => Err(CssCalcParseError::UnexpectedToken(other.as_str())),
+                    None => Err(CssCalcParseError::UnbalancedParenthesis),
+                }
+            },
+            CalcToken::CloseParen => Err(CssCalcParseError::UnbalancedParenthesis),
+            other => Err(CssCalcParseError::UnexpectedToken(other.as_str())),
+        }
+    }
+}
+
+/// Parses a `calc()` expression, i.e. `calc((100% - 32px) / 2)`
+///
+/// ```rust
+/// # extern crate azul_css_parser;
+/// # extern crate azul_css;
+/// # use azul_css_parser::parse_calc_expression;
+/// # use azul_css::{CalcExpression, PixelValue};
+/// assert_eq!(
+///     parse_calc_expression("calc(100% - 32px)"),
+///     Ok(CalcExpression::Sub(
+///         Box::new(CalcExpression::Value(PixelValue::percent(100.0))),
+///         Box::new(CalcExpression::Value(PixelValue::px(32.0))),
+///     ))
+/// );
+/// ```
+pub fn parse_calc_expression<'a>(input: &'a str) -> Result<CalcExpression, CssCalcParseError<'a>> {
+    let input = input.trim();
+    if !input.starts_with("calc(") {
+        return Err(CssCalcParseError::NotACalcExpression(input));
+    }
+
+    let mut parser = CalcParser {
+        tokens: tokenize_calc_expression(input)?,
+        pos: 0,
+    };
+
+    // the outer "calc(...)" is parsed as a parenthesized value
+    let result = parser.parse_value()?;
+    if parser.pos != parser.tokens.len() {
+        return Err(CssCalcParseError::UnbalancedParenthesis);
+    }
+
+    match result {
+        CalcOperand::Length(expr) => Ok(expr),
+        CalcOperand::Number(_) => Err(CssCalcParseError::TypeMismatch),
+    }
+}
+
 #[derive(Clone, PartialEq, Eq)]
 pub enum PercentageParseError {
     ValueParseErr(ParseFloatError),
@@ -951,12 +1245,10 @@ pub fn parse_percentage_value(input: &str)
     let mut split_pos = 0;
     for (idx, ch) in input.char_indices() {
         if ch.is_numeric() || ch == '.' {
-            split_pos = idx;
+            split_pos = idx + ch.len_utf8();
         }
     }
 
-    split_pos += 1;
-
     let unit = &input[split_pos..];
     let mut number = input[..split_pos].parse::<f32>().map_err(|e| PercentageParseError::ValueParseErr(e))?;
 
@@ -1022,165 +1314,31 @@ pub fn parse_style_text_color<'a>(input: &'a str)
     parse_css_color(input).and_then(|ok| Ok(StyleTextColor { inner: ok }))
 }
 
+pub fn parse_style_caret_color<'a>(input: &'a str)
+-> Result<StyleCaretColor, CssColorParseError<'a>>
+{
+    parse_css_color(input).and_then(|ok| Ok(StyleCaretColor { inner: ok }))
+}
+
+pub fn parse_style_selection_color<'a>(input: &'a str)
+-> Result<StyleSelectionColor, CssColorParseError<'a>>
+{
+    parse_css_color(input).and_then(|ok| Ok(StyleSelectionColor { inner: ok }))
+}
+
+pub fn parse_style_selection_background<'a>(input: &'a str)
+-> Result<StyleSelectionBackground, CssColorParseError<'a>>
+{
+    parse_css_color(input).and_then(|ok| Ok(StyleSelectionBackground { inner: ok }))
+}
+
 /// Parse a built-in background color
 ///
 /// "blue" -> "00FF00" -> ColorF { r: 0, g: 255, b: 0 })
 pub fn parse_color_builtin<'a>(input: &'a str)
 -> Result<ColorU, CssColorParseError<'a>>
 {
-    let (r, g, b, a) = match input {
-        "AliceBlue"             | "aliceblue"                 =>  (240, 248, 255, 255),
-        "AntiqueWhite"          | "antiquewhite"              =>  (250, 235, 215, 255),
-        "Aqua"                  | "aqua"                      =>  (  0, 255, 255, 255),
-        "Aquamarine"            | "aquamarine"                =>  (127, 255, 212, 255),
-        "Azure"                 | "azure"                     =>  (240, 255, 255, 255),
-        "Beige"                 | "beige"                     =>  (245, 245, 220, 255),
-        "Bisque"                | "bisque"                    =>  (255, 228, 196, 255),
-        "Black"                 | "black"                     =>  (  0,   0,   0, 255),
-        "BlanchedAlmond"        | "blanchedalmond"            =>  (255, 235, 205, 255),
-        "Blue"                  | "blue"                      =>  (  0,   0, 255, 255),
-        "BlueViolet"            | "blueviolet"                =>  (138,  43, 226, 255),
-        "Brown"                 | "brown"                     =>  (165,  42,  42, 255),
-        "BurlyWood"             | "burlywood"                 =>  (222, 184, 135, 255),
-        "CadetBlue"             | "cadetblue"                 =>  ( 95, 158, 160, 255),
-        "Chartreuse"            | "chartreuse"                =>  (127, 255,   0, 255),
-        "Chocolate"             | "chocolate"                 =>  (210, 105,  30, 255),
-        "Coral"                 | "coral"                     =>  (255, 127,  80, 255),
-        "CornflowerBlue"        | "cornflowerblue"            =>  (100, 149, 237, 255),
-        "Cornsilk"              | "cornsilk"                  =>  (255, 248, 220, 255),
-        "Crimson"               | "crimson"                   =>  (220,  20,  60, 255),
-        "Cyan"                  | "cyan"                      =>  (  0, 255, 255, 255),
-        "DarkBlue"              | "darkblue"                  =>  (  0,   0, 139, 255),
-        "DarkCyan"              | "darkcyan"                  =>  (  0, 139, 139, 255),
-        "DarkGoldenRod"         | "darkgoldenrod"            =>  (184, 134,  11, 255),
-        "DarkGray"              | "darkgray"                  =>  (169, 169, 169, 255),
-        "DarkGrey"              | "darkgrey"                  =>  (169, 169, 169, 255),
-        "DarkGreen"             | "darkgreen"                 =>  (  0, 100,   0, 255),
-        "DarkKhaki"             | "darkkhaki"                 =>  (189, 183, 107, 255),
-        "DarkMagenta"           | "darkmagenta"               =>  (139,   0, 139, 255),
-        "DarkOliveGreen"        | "darkolivegreen"           =>  ( 85, 107,  47, 255),
-        "DarkOrange"            | "darkorange"                =>  (255, 140,   0, 255),
-        "DarkOrchid"            | "darkorchid"                =>  (153,  50, 204, 255),
-        "DarkRed"               | "darkred"                   =>  (139,   0,   0, 255),
-        "DarkSalmon"            | "darksalmon"                =>  (233, 150, 122, 255),
-        "DarkSeaGreen"          | "darkseagreen"             =>  (143, 188, 143, 255),
-        "DarkSlateBlue"         | "darkslateblue"            =>  ( 72,  61, 139, 255),
-        "DarkSlateGray"         | "darkslategray"            =>  ( 47,  79,  79, 255),
-        "DarkSlateGrey"         | "darkslategrey"            =>  ( 47,  79,  79, 255),
-        "DarkTurquoise"         | "darkturquoise"             =>  (  0, 206, 209, 255),
-        "DarkViolet"            | "darkviolet"                =>  (148,   0, 211, 255),
-        "DeepPink"              | "deeppink"                  =>  (255,  20, 147, 255),
-        "DeepSkyBlue"           | "deepskyblue"              =>  (  0, 191, 255, 255),
-        "DimGray"               | "dimgray"                   =>  (105, 105, 105, 255),
-        "DimGrey"               | "dimgrey"                   =>  (105, 105, 105, 255),
-        "DodgerBlue"            | "dodgerblue"                =>  ( 30, 144, 255, 255),
-        "FireBrick"             | "firebrick"                 =>  (178,  34,  34, 255),
-        "FloralWhite"           | "floralwhite"               =>  (255, 250, 240, 255),
-        "ForestGreen"           | "forestgreen"               =>  ( 34, 139,  34, 255),
-        "Fuchsia"               | "fuchsia"                   =>  (255,   0, 255, 255),
-        "Gainsboro"             | "gainsboro"                 =>  (220, 220, 220, 255),
-        "GhostWhite"            | "ghostwhite"                =>  (248, 248, 255, 255),
-        "Gold"                  | "gold"                      =>  (255, 215,   0, 255),
-        "GoldenRod"             | "goldenrod"                 =>  (218, 165,  32, 255),
-        "Gray"                  | "gray"                      =>  (128, 128, 128, 255),
-        "Grey"                  | "grey"                      =>  (128, 128, 128, 255),
-        "Green"                 | "green"                     =>  (  0, 128,   0, 255),
-        "GreenYellow"           | "greenyellow"               =>  (173, 255,  47, 255),
-        "HoneyDew"              | "honeydew"                  =>  (240, 255, 240, 255),
-        "HotPink"               | "hotpink"                   =>  (255, 105, 180, 255),
-        "IndianRed"             | "indianred"                 =>  (205,  92,  92, 255),
-        "Indigo"                | "indigo"                    =>  ( 75,   0, 130, 255),
-        "Ivory"                 | "ivory"                     =>  (255, 255, 240, 255),
-        "Khaki"                 | "khaki"                     =>  (240, 230, 140, 255),
-        "Lavender"              | "lavender"                  =>  (230, 230, 250, 255),
-        "LavenderBlush"         | "lavenderblush"             =>  (255, 240, 245, 255),
-        "LawnGreen"             | "lawngreen"                 =>  (124, 252,   0, 255),
-        "LemonChiffon"          | "lemonchiffon"              =>  (255, 250, 205, 255),
-        "LightBlue"             | "lightblue"                 =>  (173, 216, 230, 255),
-        "LightCoral"            | "lightcoral"                =>  (240, 128, 128, 255),
-        "LightCyan"             | "lightcyan"                 =>  (224, 255, 255, 255),
-        "LightGoldenRodYellow"  | "lightgoldenrodyellow"    =>  (250, 250, 210, 255),
-        "LightGray"             | "lightgray"                 =>  (211, 211, 211, 255),
-        "LightGrey"             | "lightgrey"                 =>  (144, 238, 144, 255),
-        "LightGreen"            | "lightgreen"                =>  (211, 211, 211, 255),
-        "LightPink"             | "lightpink"                 =>  (255, 182, 193, 255),
-        "LightSalmon"           | "lightsalmon"               =>  (255, 160, 122, 255),
-        "LightSeaGreen"         | "lightseagreen"            =>  ( 32, 178, 170, 255),
-        "LightSkyBlue"          | "lightskyblue"             =>  (135, 206, 250, 255),
-        "LightSlateGray"        | "lightslategray"           =>  (119, 136, 153, 255),
-        "LightSlateGrey"        | "lightslategrey"           =>  (119, 136, 153, 255),
-        "LightSteelBlue"        | "lightsteelblue"           =>  (176, 196, 222, 255),
-        "LightYellow"           | "lightyellow"               =>  (255, 255, 224, 255),
-        "Lime"                  | "lime"                      =>  (  0, 255,   0, 255),
-        "LimeGreen"             | "limegreen"                 =>  ( 50, 205,  50, 255),
-        "Linen"                 | "linen"                     =>  (250, 240, 230, 255),
-        "Magenta"               | "magenta"                   =>  (255,   0, 255, 255),
-        "Maroon"                | "maroon"                    =>  (128,   0,   0, 255),
-        "MediumAquaMarine"      | "mediumaquamarine"         =>  (102, 205, 170, 255),
-        "MediumBlue"            | "mediumblue"                =>  (  0,   0, 205, 255),
-        "MediumOrchid"          | "mediumorchid"              =>  (186,  85, 211, 255),
-        "MediumPurple"          | "mediumpurple"              =>  (147, 112, 219, 255),
-        "MediumSeaGreen"        | "mediumseagreen"           =>  ( 60, 179, 113, 255),
-        "MediumSlateBlue"       | "mediumslateblue"          =>  (123, 104, 238, 255),
-        "MediumSpringGreen"     | "mediumspringgreen"        =>  (  0, 250, 154, 255),
-        "MediumTurquoise"       | "mediumturquoise"           =>  ( 72, 209, 204, 255),
-        "MediumVioletRed"       | "mediumvioletred"          =>  (199,  21, 133, 255),
-        "MidnightBlue"          | "midnightblue"              =>  ( 25,  25, 112, 255),
-        "MintCream"             | "mintcream"                 =>  (245, 255, 250, 255),
-        "MistyRose"             | "mistyrose"                 =>  (255, 228, 225, 255),
-        "Moccasin"              | "moccasin"                  =>  (255, 228, 181, 255),
-        "NavajoWhite"           | "navajowhite"               =>  (255, 222, 173, 255),
-        "Navy"                  | "navy"                      =>  (  0,   0, 128, 255),
-        "OldLace"               | "oldlace"                   =>  (253, 245, 230, 255),
-        "Olive"                 | "olive"                     =>  (128, 128,   0, 255),
-        "OliveDrab"             | "olivedrab"                 =>  (107, 142,  35, 255),
-        "Orange"                | "orange"                    =>  (255, 165,   0, 255),
-        "OrangeRed"             | "orangered"                 =>  (255,  69,   0, 255),
-        "Orchid"                | "orchid"                    =>  (218, 112, 214, 255),
-        "PaleGoldenRod"         | "palegoldenrod"            =>  (238, 232, 170, 255),
-        "PaleGreen"             | "palegreen"                 =>  (152, 251, 152, 255),
-        "PaleTurquoise"         | "paleturquoise"             =>  (175, 238, 238, 255),
-        "PaleVioletRed"         | "palevioletred"            =>  (219, 112, 147, 255),
-        "PapayaWhip"            | "papayawhip"                =>  (255, 239, 213, 255),
-        "PeachPuff"             | "peachpuff"                 =>  (255, 218, 185, 255),
-        "Peru"                  | "peru"                      =>  (205, 133,  63, 255),
-        "Pink"                  | "pink"                      =>  (255, 192, 203, 255),
-        "Plum"                  | "plum"                      =>  (221, 160, 221, 255),
-        "PowderBlue"            | "powderblue"                =>  (176, 224, 230, 255),
-        "Purple"                | "purple"                    =>  (128,   0, 128, 255),
-        "RebeccaPurple"         | "rebeccapurple"             =>  (102,  51, 153, 255),
-        "Red"                   | "red"                       =>  (255,   0,   0, 255),
-        "RosyBrown"             | "rosybrown"                 =>  (188, 143, 143, 255),
-        "RoyalBlue"             | "royalblue"                 =>  ( 65, 105, 225, 255),
-        "SaddleBrown"           | "saddlebrown"               =>  (139,  69,  19, 255),
-        "Salmon"                | "salmon"                    =>  (250, 128, 114, 255),
-        "SandyBrown"            | "sandybrown"                =>  (244, 164,  96, 255),
-        "SeaGreen"              | "seagreen"                  =>  ( 46, 139,  87, 255),
-        "SeaShell"              | "seashell"                  =>  (255, 245, 238, 255),
-        "Sienna"                | "sienna"                    =>  (160,  82,  45, 255),
-        "Silver"                | "silver"                    =>  (192, 192, 192, 255),
-        "SkyBlue"               | "skyblue"                   =>  (135, 206, 235, 255),
-        "SlateBlue"             | "slateblue"                 =>  (106,  90, 205, 255),
-        "SlateGray"             | "slategray"                 =>  (112, 128, 144, 255),
-        "SlateGrey"             | "slategrey"                 =>  (112, 128, 144, 255),
-        "Snow"                  | "snow"                      =>  (255, 250, 250, 255),
-        "SpringGreen"           | "springgreen"               =>  (  0, 255, 127, 255),
-        "SteelBlue"             | "steelblue"                 =>  ( 70, 130, 180, 255),
-        "Tan"                   | "tan"                       =>  (210, 180, 140, 255),
-        "Teal"                  | "teal"                      =>  (  0, 128, 128, 255),
-        "Thistle"               | "thistle"                   =>  (216, 191, 216, 255),
-        "Tomato"                | "tomato"                    =>  (255,  99,  71, 255),
-        "Turquoise"             | "turquoise"                 =>  ( 64, 224, 208, 255),
-        "Violet"                | "violet"                    =>  (238, 130, 238, 255),
-        "Wheat"                 | "wheat"                     =>  (245, 222, 179, 255),
-        "White"                 | "white"                     =>  (255, 255, 255, 255),
-        "WhiteSmoke"            | "whitesmoke"                =>  (245, 245, 245, 255),
-        "Yellow"                | "yellow"                    =>  (255, 255,   0, 255),
-        "YellowGreen"           | "yellowgreen"               =>  (154, 205,  50, 255),
-        "Transparent"           | "transparent"               =>  (255, 255, 255,   0),
-        _ => { return Err(CssColorParseError::InvalidColor(input)); }
-    };
-    Ok(ColorU { r, g, b, a })
+    ColorU::from_named(input).ok_or(CssColorParseError::InvalidColor(input))
 }
 
 /// Parse a color of the form `rgb([0-255], [0-255], [0-255])`, or `rgba([0-255], [0-255], [0-255],
@@ -1809,14 +1967,14 @@ pub fn parse_style_background_repeat_multiple<'a>(input: &'a str) -> Result<Styl
      Ok(split_string_respect_comma(input).iter().map(|i| parse_style_background_repeat(i)).collect::<Result<Vec<_>, _>>()?.into())
 }
 
-// parses a background, such as "linear-gradient(red, green)"
+// parses a background, such as "linear-gradient(red, green)", "#ff0000" or "url(cat.png)"
 pub fn parse_style_background_content<'a>(input: &'a str) -> Result<StyleBackgroundContent, CssBackgroundParseError<'a>> {
 
     match parse_parentheses(input, &[
         "linear-gradient", "repeating-linear-gradient",
         "radial-gradient", "repeating-radial-gradient",
         "conic-gradient", "repeating-conic-gradient",
-        "image",
+        "image", "url",
     ]) {
         Ok((background_type, brace_contents)) => {
             let gradient_type = match background_type {
@@ -1827,6 +1985,7 @@ pub fn parse_style_background_content<'a>(input: &'a str) -> Result<StyleBackgro
                 "conic-gradient" => GradientType::ConicGradient,
                 "repeating-conic-gradient" => GradientType::RepeatingConicGradient,
                 "image" => { return Ok(StyleBackgroundContent::Image(parse_image(brace_contents)?)); },
+                "url" => { return Ok(StyleBackgroundContent::Image(parse_image_url(brace_contents)?)); },
                 other => { return Err(CssBackgroundParseError::Error(other)); /* unreachable */ },
             };
 
@@ -1863,20 +2022,57 @@ pub fn parse_conic_first_item<'a>(input: &'a str)
     let input = input.trim();
     if !input.starts_with("from") { return Ok(None); }
     let input = &input["from".len()..];
-    let mut iter = input.split_whitespace();
 
-    let angle = parse_angle_value(iter.next().ok_or(CssConicGradientParseError::NoAngle(input))?)?;
+    // the position can consist of two components, i.e. "at left top"
+    let (angle, position) = match input.find(" at ") {
+        Some(at) => (&input[..at], Some(&input[(at + " at ".len())..])),
+        None => (input, None),
+    };
 
-    if !(iter.next() == Some("at")) {
-        return Ok(Some((angle, StyleBackgroundPosition::default())));
+    let angle = angle.trim();
+    if angle.is_empty() {
+        return Err(CssConicGradientParseError::NoAngle(input));
     }
+    let angle = parse_angle_value(angle)?;
 
-    let remaining = iter.next().unwrap_or("");
-    let position = parse_style_background_position(&remaining)?;
+    let position = match position {
+        Some(position) => parse_style_background_position(position)?,
+        None => StyleBackgroundPosition::default(),
+    };
 
     Ok(Some((angle, position)))
 }
 
+// parse a radial gradient first item such as "circle closest-side at 50% 30%",
+// returns None if the item doesn't start with a shape (i.e. is a color stop)
+pub fn parse_radial_first_item<'a>(input: &'a str)
+-> Option<(Shape, RadialGradientSize, StyleBackgroundPosition)>
+{
+    let input = input.trim();
+    let (shape_and_size, position) = match input.find(" at ") {
+        Some(at) => (&input[..at], Some(&input[(at + " at ".len())..])),
+        None => (input, None),
+    };
+
+    let mut iter = shape_and_size.split_whitespace();
+    let shape = parse_shape(iter.next()?).ok()?;
+    let size = match iter.next() {
+        Some(size) => parse_radial_gradient_size(size).ok()?,
+        None => RadialGradientSize::default(),
+    };
+
+    if iter.next().is_some() {
+        return None;
+    }
+
+    let position = match position {
+        Some(position) => parse_style_background_position(position).ok()?,
+        None => StyleBackgroundPosition::default(),
+    };
+
+    Some((shape, size, position))
+}
+
 #[derive(Clone, PartialEq)]
 pub enum CssScrollbarStyleParseError<'a> {
     Invalid(&'a str),
@@ -2126,6 +2322,7 @@ pub enum CssStyleTransformParseError<'a> {
     PixelValueParseError(CssPixelValueParseError<'a>),
     AngleValueParseError(CssAngleValueParseError<'a>),
     PercentageValueParseError(PercentageParseError),
+    NumberParseError(ParseFloatError, &'a str),
 }
 
 impl_debug_as_display!(CssStyleTransformParseError<'a>);
@@ -2136,6 +2333,7 @@ impl_display!{ CssStyleTransformParseError<'a>, {
     PixelValueParseError(e) => format!("Invalid pixel value: {}", e),
     AngleValueParseError(e) => format!("Invalid angle value: {}", e),
     PercentageValueParseError(e) => format!("Invalid transform property - error parsing percentage: {}", e),
+    NumberParseError(e, input) => format!("Invalid number in matrix: \"{}\": {}", input, e),
 }}
 
 impl_from!(ParenthesisParseError<'a>, CssStyleTransformParseError::InvalidParenthesis);
@@ -2190,17 +2388,25 @@ pub fn parse_style_transform<'a>(input: &'a str)
         "perspective",
     ])?;
 
+    // the arguments of matrix() / matrix3d() are numbers without a unit
+    fn parse_matrix_number<'a>(input: &'a str) -> Result<PixelValue, CssStyleTransformParseError<'a>> {
+        let input = input.trim();
+        input.parse::<f32>()
+            .map(PixelValue::px)
+            .map_err(|e| CssStyleTransformParseError::NumberParseError(e, input))
+    }
+
     fn parse_matrix<'a>(input: &'a str) -> Result<StyleTransformMatrix2D, CssStyleTransformParseError<'a>> {
 
         let input = input.trim();
         let mut iter = input.split(",");
 
-        let a =  parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 0, input })?)?;
-        let b =  parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 1, input })?)?;
-        let c =  parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 2, input })?)?;
-        let d =  parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 3, input })?)?;
-        let tx = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 4, input })?)?;
-        let ty = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 5, input })?)?;
+        let a =  parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 0, input })?)?;
+        let b =  parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 1, input })?)?;
+        let c =  parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 2, input })?)?;
+        let d =  parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 3, input })?)?;
+        let tx = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 4, input })?)?;
+        let ty = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 6, got: 5, input })?)?;
 
         Ok(StyleTransformMatrix2D { a, b, c, d, tx, ty })
     }
@@ -2210,22 +2416,22 @@ pub fn parse_style_transform<'a>(input: &'a str)
         let mut iter = input.split(",");
 
         // I realize I could use a loop here, but that makes passing the variables to the StyleTransformMatrix3D simpler
-        let m11 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 0, input })?)?;
-        let m12 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 1, input })?)?;
-        let m13 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 2, input })?)?;
-        let m14 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 3, input })?)?;
-        let m21 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 4, input })?)?;
-        let m22 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 5, input })?)?;
-        let m23 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 6, input })?)?;
-        let m24 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 7, input })?)?;
-        let m31 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 8, input })?)?;
-        let m32 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 9, input })?)?;
-        let m33 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 10, input })?)?;
-        let m34 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 11, input })?)?;
-        let m41 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 12, input })?)?;
-        let m42 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 13, input })?)?;
-        let m43 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 14, input })?)?;
-        let m44 = parse_pixel_value(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 15, input })?)?;
+        let m11 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 0, input })?)?;
+        let m12 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 1, input })?)?;
+        let m13 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 2, input })?)?;
+        let m14 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 3, input })?)?;
+        let m21 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 4, input })?)?;
+        let m22 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 5, input })?)?;
+        let m23 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 6, input })?)?;
+        let m24 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 7, input })?)?;
+        let m31 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 8, input })?)?;
+        let m32 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 9, input })?)?;
+        let m33 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 10, input })?)?;
+        let m34 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 11, input })?)?;
+        let m41 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 12, input })?)?;
+        let m42 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 13, input })?)?;
+        let m43 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 14, input })?)?;
+        let m44 = parse_matrix_number(iter.next().ok_or(CssStyleTransformParseError::WrongNumberOfComponents { expected: 16, got: 15, input })?)?;
 
         Ok(StyleTransformMatrix3D { m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, m44 })
     }
@@ -2532,8 +2738,10 @@ pub fn parse_gradient<'a>(input: &'a str, background_type: GradientType)
     } else if is_radial_gradient {
         let mut radial_gradient = RadialGradient::default();
         let mut radial_gradient_stops = Vec::new();
-        if let Ok(sh) = parse_shape(first_brace_item) {
-            radial_gradient.shape = sh;
+        if let Some((shape, size, position)) = parse_radial_first_item(first_brace_item) {
+            radial_gradient.shape = shape;
+            radial_gradient.size = size;
+            radial_gradient.position = position;
         } else {
             radial_gradient_stops.push(parse_linear_color_stop(first_brace_item)?);
         }
@@ -2576,6 +2784,16 @@ pub fn parse_image<'a>(input: &'a str) -> Result<AzString, CssImageParseError<'a
     Ok(strip_quotes(input)?.into())
 }
 
+/// Same as `parse_image`, but the contents of `url()` may also be unquoted
+pub fn parse_image_url<'a>(input: &'a str) -> Result<AzString, CssImageParseError<'a>> {
+    let input = input.trim();
+    if input.is_empty() || input.starts_with('"') || input.starts_with('\'') {
+        parse_image(input)
+    } else {
+        Ok(QuoteStripped(input).into())
+    }
+}
+
 /// Strip quotes from an input, given that both quotes use either `"` or `'`, but not both.
 ///
 /// # Example
@@ -2869,13 +3087,7 @@ impl_display!{ CssDirectionCornerParseError<'a>, {
 pub fn parse_direction_corner<'a>(input: &'a str)
 -> Result<DirectionCorner, CssDirectionCornerParseError<'a>>
 {
-    match input {
-        "right" => Ok(DirectionCorner::Right),
-        "left" => Ok(DirectionCorner::Left),
-        "top" => Ok(DirectionCorner::Top),
-        "bottom" => Ok(DirectionCorner::Bottom),
-        _ => { Err(CssDirectionCornerParseError::InvalidDirection(input))}
-    }
+    DirectionCorner::from_str(input).ok_or(CssDirectionCornerParseError::InvalidDirection(input))
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -2955,6 +3167,171 @@ pub fn parse_layout_flex_shrink<'a>(input: &'a str) -> Result<LayoutFlexShrink,
     }
 }
 
+#[derive(Clone, PartialEq)]
+pub enum CssGridParseError<'a> {
+    InvalidTrack(&'a str),
+    InvalidRepeat(&'a str),
+    InvalidLine(&'a str),
+    TooManyComponents(&'a str),
+    InvalidParenthesis(ParenthesisParseError<'a>),
+    Pixel(CssPixelValueParseError<'a>),
+}
+
+impl_debug_as_display!(CssGridParseError<'a>);
+impl_display!{ CssGridParseError<'a>, {
+    InvalidTrack(e) => format!("Invalid grid track: \"{}\"", e),
+    InvalidRepeat(e) => format!("Invalid repeat(), expected \"repeat(count, tracks)\": \"{}\"", e),
+    InvalidLine(e) => format!("Invalid grid line: \"{}\"", e),
+    TooManyComponents(e) => format!("Too many components, expected \"start / end\": \"{}\"", e),
+    InvalidParenthesis(e) => format!("Invalid grid track - parenthesis error: {}", e),
+    Pixel(e) => format!("Error parsing grid track size: {}", e),
+}}
+
+impl_from!(ParenthesisParseError<'a>, CssGridParseError::InvalidParenthesis);
+impl_from!(CssPixelValueParseError<'a>, CssGridParseError::Pixel);
+
+/// Splits a track list at whitespace, except inside of parentheses:
+/// `"100px repeat(2, 1fr auto)"` -> `["100px", "repeat(2, 1fr auto)"]`
+fn split_string_respect_whitespace<'a>(input: &'a str) -> Vec<&'a str> {
+
+    let mut items = Vec::<&str>::new();
+    let mut depth = 0_usize;
+    let mut item_start = None;
+
+    for (idx, ch) in input.char_indices() {
+        if ch.is_whitespace() && depth == 0 {
+            if let Some(start) = item_start.take() {
+                items.push(&input[start..idx]);
+            }
+            continue;
+        }
+
+        match ch {
+            '(' => { depth += 1; },
+            ')' => { depth = depth.saturating_sub(1); },
+            _ => { },
+        }
+
+        if item_start.is_none() {
+            item_start = Some(idx);
+        }
+    }
+
+    if let Some(start) = item_start {
+        items.push(&input[start..]);
+    }
+
+    items
+}
+
+/// Parses a single grid track, i.e. `100px`, `1fr`, `auto` or `repeat(3, 1fr)`
+pub fn parse_grid_track<'a>(input: &'a str) -> Result<GridTrack, CssGridParseError<'a>> {
+
+    let input = input.trim();
+
+    match input {
+        "auto" => return Ok(GridTrack::Auto),
+        "min-content" => return Ok(GridTrack::MinContent),
+        "max-content" => return Ok(GridTrack::MaxContent),
+        _ => { },
+    }
+
+    if input.contains('(') {
+        let (_, repeat_args) = parse_parentheses(input, &["repeat"])?;
+        let comma = repeat_args.find(',').ok_or(CssGridParseError::InvalidRepeat(input))?;
+        let count = repeat_args[..comma].trim().parse::<u32>()
+            .map_err(|_| CssGridParseError::InvalidRepeat(input))?;
+        let tracks = parse_grid_track_list(&repeat_args[(comma + 1)..])?;
+        if count == 0 || tracks.is_empty() {
+            return Err(CssGridParseError::InvalidRepeat(input));
+        }
+        return Ok(GridTrack::Repeat(GridTrackRepeat { count, tracks }));
+    }
+
+    if input.ends_with("fr") {
+        return parse_float_value(&input[..(input.len() - 2)])
+            .map(|f| GridTrack::Fraction(f))
+            .map_err(|_| CssGridParseError::InvalidTrack(input));
+    }
+
+    Ok(GridTrack::Px(parse_pixel_value(input)?))
+}
+
+/// Parses a whitespace-separated list of grid tracks, `none` is an empty list
+pub fn parse_grid_track_list<'a>(input: &'a str) -> Result<GridTrackVec, CssGridParseError<'a>> {
+    let input = input.trim();
+    if input == "none" {
+        return Ok(GridTrackVec::from_const_slice(&[]));
+    }
+    let tracks = split_string_respect_whitespace(input).into_iter()
+        .map(parse_grid_track)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(tracks.into())
+}
+
+/// Parses a single grid line, i.e. `auto`, `2`, `-1` or `span 2`
+pub fn parse_grid_line<'a>(input: &'a str) -> Result<GridLine, CssGridParseError<'a>> {
+
+    let input = input.trim();
+
+    if input == "auto" {
+        return Ok(GridLine::Auto);
+    }
+
+    if input.starts_with("span") {
+        return match input["span".len()..].trim().parse::<u16>() {
+            Ok(span) if span > 0 => Ok(GridLine::Span(span)),
+            _ => Err(CssGridParseError::InvalidLine(input)),
+        };
+    }
+
+    match input.parse::<i16>() {
+        Ok(line) if line != 0 => Ok(GridLine::Line(line)),
+        _ => Err(CssGridParseError::InvalidLine(input)),
+    }
+}
+
+/// Parses a `start / end` grid placement, the end line defaults to `auto`
+pub fn parse_grid_placement<'a>(input: &'a str) -> Result<GridPlacement, CssGridParseError<'a>> {
+
+    let mut iter = input.split('/');
+    let start = parse_grid_line(iter.next().unwrap_or(""))?;
+    let end = match iter.next() {
+        Some(end) => parse_grid_line(end)?,
+        None => GridLine::Auto,
+    };
+
+    if iter.next().is_some() {
+        return Err(CssGridParseError::TooManyComponents(input));
+    }
+
+    Ok(GridPlacement { start, end })
+}
+
+pub fn parse_layout_grid_template_columns<'a>(input: &'a str)
+-> Result<LayoutGridTemplateColumns, CssGridParseError<'a>>
+{
+    parse_grid_track_list(input).and_then(|e| Ok(LayoutGridTemplateColumns { inner: e }))
+}
+
+pub fn parse_layout_grid_template_rows<'a>(input: &'a str)
+-> Result<LayoutGridTemplateRows, CssGridParseError<'a>>
+{
+    parse_grid_track_list(input).and_then(|e| Ok(LayoutGridTemplateRows { inner: e }))
+}
+
+pub fn parse_layout_grid_column<'a>(input: &'a str)
+-> Result<LayoutGridColumn, CssGridParseError<'a>>
+{
+    parse_grid_placement(input).and_then(|e| Ok(LayoutGridColumn { inner: e }))
+}
+
+pub fn parse_layout_grid_row<'a>(input: &'a str)
+-> Result<LayoutGridRow, CssGridParseError<'a>>
+{
+    parse_grid_placement(input).and_then(|e| Ok(LayoutGridRow { inner: e }))
+}
+
 pub fn parse_style_tab_width(input: &str)
 -> Result<StyleTabWidth, PercentageParseError>
 {
@@ -3014,9 +3391,9 @@ impl<'a> From<UnclosedQuotesError<'a>> for CssStyleFontFamilyParseError<'a> {
 /// # use azul_css::{StyleFontFamily, StyleFontFamilyVec};
 /// let input = "\"Helvetica\", 'Arial', Times New Roman";
 /// let fonts: StyleFontFamilyVec = vec![
-///     StyleFontFamily::Native("Helvetica".into()),
-///     StyleFontFamily::Native("Arial".into()),
-///     StyleFontFamily::Native("Times New Roman".into()),
+///     StyleFontFamily::System("Helvetica".into()),
+///     StyleFontFamily::System("Arial".into()),
+///     StyleFontFamily::System("Times New Roman".into()),
 /// ].into();
 ///
 /// assert_eq!(parse_style_font_family(input), Ok(fonts));
@@ -3118,6 +3495,193 @@ multi_type_parser!(parse_style_mix_blend_mode, StyleMixBlendMode,
     ["color", Color],
     ["luminosity", Luminosity]);
 
+multi_type_parser!(parse_style_isolation, StyleIsolation,
+    ["auto", Auto],
+    ["isolate", Isolate]);
+
+multi_type_parser!(parse_style_image_rendering, StyleImageRendering,
+    ["auto", Auto],
+    ["crisp-edges", CrispEdges],
+    ["pixelated", Pixelated]);
+
+#[derive(Clone, PartialEq)]
+pub enum CssTransitionParseError<'a> {
+    InvalidTime(&'a str),
+    NegativeDuration(&'a str),
+    InvalidProperty(&'a str),
+    InvalidTimingFunction(&'a str),
+    InvalidCubicBezier(&'a str),
+    InvalidSteps(&'a str),
+    TooManyValues(&'a str),
+    MultipleTransitions(&'a str),
+}
+
+impl_debug_as_display!(CssTransitionParseError<'a>);
+impl_display!{ CssTransitionParseError<'a>, {
+    InvalidTime(e) => format!("Invalid time, expected i.e. \"300ms\" or \"0.3s\": \"{}\"", e),
+    NegativeDuration(e) => format!("Transition duration can't be negative: \"{}\"", e),
+    InvalidProperty(e) => format!("Invalid transition property, expected \"all\", \"none\" or a list of CSS keys: \"{}\"", e),
+    InvalidTimingFunction(e) => format!("Invalid timing function: \"{}\"", e),
+    InvalidCubicBezier(e) => format!("Invalid cubic-bezier(), expected four numbers with x1 and x2 in 0..=1: \"{}\"", e),
+    InvalidSteps(e) => format!("Invalid steps(), expected \"steps(count, position)\": \"{}\"", e),
+    TooManyValues(e) => format!("Too many values in transition: \"{}\"", e),
+    MultipleTransitions(e) => format!("Only one transition per shorthand is supported: \"{}\"", e),
+}}
+
+/// Parses a CSS time (`300ms`, `0.3s` or `0`) into milliseconds
+fn parse_transition_time<'a>(input: &'a str) -> Result<f32, CssTransitionParseError<'a>> {
+    let input = input.trim();
+    let ms = if let Some(ms) = input.strip_suffix("ms") {
+        ms.parse::<f32>().ok()
+    } else if let Some(s) = input.strip_suffix('s') {
+        s.parse::<f32>().ok().map(|s| s * 1000.0)
+    } else if input == "0" {
+        Some(0.0)
+    } else {
+        None
+    };
+    ms.filter(|ms| ms.is_finite()).ok_or(CssTransitionParseError::InvalidTime(input))
+}
+
+/// Parses a `transition-duration` attribute, i.e. `"300ms"` or `"0.3s"`
+pub fn parse_style_transition_duration<'a>(input: &'a str)
+-> Result<StyleTransitionDuration, CssTransitionParseError<'a>>
+{
+    let ms = parse_transition_time(input)?;
+    if ms < 0.0 {
+        return Err(CssTransitionParseError::NegativeDuration(input.trim()));
+    }
+    Ok(StyleTransitionDuration::ms(ms))
+}
+
+/// Parses a `transition-delay` attribute, i.e. `"300ms"` or `"-0.1s"`
+pub fn parse_style_transition_delay<'a>(input: &'a str)
+-> Result<StyleTransitionDelay, CssTransitionParseError<'a>>
+{
+    parse_transition_time(input).map(StyleTransitionDelay::ms)
+}
+
+/// Parses a `transition-property` attribute: `"all"`, `"none"` or
+/// a comma-separated list of CSS keys, i.e. `"opacity, width"`
+pub fn parse_style_transition_property<'a>(input: &'a str)
+-> Result<StyleTransitionProperty, CssTransitionParseError<'a>>
+{
+    let input = input.trim();
+    match input {
+        "all" => return Ok(StyleTransitionProperty::All),
+        "none" => return Ok(StyleTransitionProperty::None),
+        _ => { },
+    }
+
+    let key_map = azul_css::get_css_key_map();
+    let properties = input
+        .split(',')
+        .map(|p| {
+            let p = p.trim();
+            CssPropertyType::from_str(p, &key_map).ok_or(CssTransitionParseError::InvalidProperty(p))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(StyleTransitionProperty::List(properties.into()))
+}
+
+/// Parses a `transition-timing-function` attribute: a keyword (`"ease-in"`, `"step-end"`),
+/// `"cubic-bezier(x1, y1, x2, y2)"` or `"steps(count, position)"`
+pub fn parse_style_transition_timing_function<'a>(input: &'a str)
+-> Result<StyleTransitionTimingFunction, CssTransitionParseError<'a>>
+{
+    use azul_css::StyleTransitionTimingFunction::*;
+
+    let input = input.trim();
+    match input {
+        "linear" => return Ok(Linear),
+        "ease" => return Ok(Ease),
+        "ease-in" => return Ok(EaseIn),
+        "ease-out" => return Ok(EaseOut),
+        "ease-in-out" => return Ok(EaseInOut),
+        "step-start" => return Ok(Steps(StyleSteps { count: 1, position: StepPosition::JumpStart })),
+        "step-end" => return Ok(Steps(StyleSteps { count: 1, position: StepPosition::JumpEnd })),
+        _ => { },
+    }
+
+    let (function, args) = parse_parentheses(input, &["cubic-bezier", "steps"])
+        .map_err(|_| CssTransitionParseError::InvalidTimingFunction(input))?;
+    let args = args.split(',').map(|a| a.trim()).collect::<Vec<_>>();
+
+    match function {
+        "cubic-bezier" => {
+            let points = args.iter().map(|a| a.parse::<f32>().ok()).collect::<Option<Vec<_>>>();
+            match points.as_deref() {
+                Some(&[x1, y1, x2, y2]) if x1 >= 0.0 && x1 <= 1.0 && x2 >= 0.0 && x2 <= 1.0 => {
+                    Ok(CubicBezier(StyleCubicBezier::new(x1, y1, x2, y2)))
+                },
+                _ => Err(CssTransitionParseError::InvalidCubicBezier(input)),
+            }
+        },
+        _ => {
+            let count = args[0].parse::<u32>().ok().filter(|c| *c > 0)
+                .ok_or(CssTransitionParseError::InvalidSteps(input))?;
+            let position = match args.get(1).copied() {
+                None | Some("end") => StepPosition::JumpEnd,
+                Some("start") => StepPosition::JumpStart,
+                Some(p) => StepPosition::from_str(p).ok_or(CssTransitionParseError::InvalidSteps(input))?,
+            };
+            // jump-none needs at least two steps, otherwise there is nothing to jump between
+            if args.len() > 2 || (position == StepPosition::JumpNone && count < 2) {
+                return Err(CssTransitionParseError::InvalidSteps(input));
+            }
+            Ok(Steps(StyleSteps { count, position }))
+        },
+    }
+}
+
+/// Parses the value of a `transition` shorthand, i.e. `"opacity 300ms ease-in 50ms"`,
+/// and returns the `transition-property`, `-duration`, `-timing-function` and `-delay`.
+///
+/// The values can be in any order, the first time is the duration and the second
+/// time is the delay. Values that are left out are set to their defaults.
+pub fn parse_transition_shorthand<'a>(input: &'a str)
+-> Result<[CssProperty; 4], CssTransitionParseError<'a>>
+{
+    let input = input.trim();
+    if split_string_respect_comma(input).len() > 1 {
+        return Err(CssTransitionParseError::MultipleTransitions(input));
+    }
+
+    let mut property = None;
+    let mut duration = None;
+    let mut timing_function = None;
+    let mut delay = None;
+
+    for item in split_string_respect_whitespace(input) {
+        if let Ok(ms) = parse_transition_time(item) {
+            if duration.is_none() {
+                duration = Some(parse_style_transition_duration(item)?);
+            } else if delay.is_none() {
+                delay = Some(StyleTransitionDelay::ms(ms));
+            } else {
+                return Err(CssTransitionParseError::TooManyValues(input));
+            }
+        } else if let Ok(t) = parse_style_transition_timing_function(item) {
+            if timing_function.is_some() {
+                return Err(CssTransitionParseError::TooManyValues(input));
+            }
+            timing_function = Some(t);
+        } else if property.is_none() {
+            property = Some(parse_style_transition_property(item)?);
+        } else {
+            return Err(CssTransitionParseError::TooManyValues(input));
+        }
+    }
+
+    Ok([
+        CssProperty::transition_property(property.unwrap_or_default()),
+        CssProperty::transition_duration(duration.unwrap_or_default()),
+        CssProperty::transition_timing_function(timing_function.unwrap_or_default()),
+        CssProperty::transition_delay(delay.unwrap_or_default()),
+    ])
+}
+
 multi_type_parser!(parse_style_border_style, BorderStyle,
     ["none", None],
     ["solid", Solid],
@@ -3199,17 +3763,22 @@ multi_type_parser!(parse_style_background_repeat, StyleBackgroundRepeat,
                     ["no-repeat", NoRepeat],
                     ["repeat", Repeat],
                     ["repeat-x", RepeatX],
-                    ["repeat-y", RepeatY]);
+                    ["repeat-y", RepeatY],
+                    ["space", Space],
+                    ["round", Round]);
 
 multi_type_parser!(parse_layout_display, LayoutDisplay,
                     ["none", None],
                     ["flex", Flex],
                     ["block", Block],
-                    ["inline-block", InlineBlock]);
+                    ["inline-block", InlineBlock],
+                    ["grid", Grid],
+                    ["inline", Inline]);
 
 multi_type_parser!(parse_layout_float, LayoutFloat,
                     ["left", Left],
-                    ["right", Right]);
+                    ["right", Right],
+                    ["none", None]);
 
 multi_type_parser!(parse_layout_box_sizing, LayoutBoxSizing,
     ["content-box", ContentBox],
@@ -3223,7 +3792,8 @@ multi_type_parser!(parse_layout_direction, LayoutFlexDirection,
 
 multi_type_parser!(parse_layout_wrap, LayoutFlexWrap,
                     ["wrap", Wrap],
-                    ["nowrap", NoWrap]);
+                    ["nowrap", NoWrap],
+                    ["wrap-reverse", WrapReverse]);
 
 multi_type_parser!(parse_layout_justify_content, LayoutJustifyContent,
                     ["flex-start", Start],
@@ -3251,6 +3821,12 @@ multi_type_parser!(parse_shape, Shape,
                     ["circle", Circle],
                     ["ellipse", Ellipse]);
 
+multi_type_parser!(parse_radial_gradient_size, RadialGradientSize,
+                    ["closest-side", ClosestSide],
+                    ["closest-corner", ClosestCorner],
+                    ["farthest-side", FarthestSide],
+                    ["farthest-corner", FarthestCorner]);
+
 multi_type_parser!(parse_layout_position, LayoutPosition,
                     ["static", Static],
                     ["fixed", Fixed],
@@ -3261,18 +3837,93 @@ multi_type_parser!(parse_layout_overflow, LayoutOverflow,
                     ["auto", Auto],
                     ["scroll", Scroll],
                     ["visible", Visible],
-                    ["hidden", Hidden]);
+                    ["hidden", Hidden],
+                    ["clip", Clip]);
+
+/// Parses the `overflow` shorthand: either one value for both axes
+/// (`overflow: hidden`) or the x and y values (`overflow: hidden auto`)
+pub fn parse_layout_overflow_xy<'a>(input: &'a str)
+-> Result<(LayoutOverflow, LayoutOverflow), InvalidValueErr<'a>>
+{
+    let input = input.trim();
+    let mut iter = input.split_whitespace();
+
+    let overflow_x = parse_layout_overflow(iter.next().unwrap_or(input))?;
+    let overflow_y = match iter.next() {
+        Some(y) => parse_layout_overflow(y)?,
+        None => overflow_x,
+    };
+
+    if iter.next().is_some() {
+        return Err(InvalidValueErr(input));
+    }
+
+    Ok((overflow_x, overflow_y))
+}
 
 multi_type_parser!(parse_layout_text_align, StyleTextAlign,
                     ["center", Center],
                     ["left", Left],
-                    ["right", Right]);
+                    ["right", Right],
+                    ["start", Start],
+                    ["end", End]);
+
+multi_type_parser!(parse_style_direction, StyleDirection,
+                    ["ltr", Ltr],
+                    ["rtl", Rtl]);
+
+/// Parses a `vertical-align` attribute: either a keyword
+/// (`baseline`, `middle`, `text-top`, ...) or a length (`-2px`, `50%`)
+pub fn parse_style_vertical_align<'a>(input: &'a str)
+-> Result<StyleVerticalAlign, CssPixelValueParseError<'a>>
+{
+    use azul_css::StyleVerticalAlign::*;
+    let input = input.trim();
+    match input {
+        "baseline" => Ok(Baseline),
+        "top" => Ok(Top),
+        "middle" => Ok(Middle),
+        "bottom" => Ok(Bottom),
+        "text-top" => Ok(TextTop),
+        "text-bottom" => Ok(TextBottom),
+        "sub" => Ok(Sub),
+        "super" => Ok(Super),
+        other => Ok(Exact(parse_pixel_value(other)?)),
+    }
+}
 
 #[cfg(test)]
 mod css_tests {
     use super::*;
 
 
+    #[test]
+    fn test_parse_isolation() {
+        assert_eq!(parse_style_isolation("isolate"), Ok(StyleIsolation::Isolate));
+        assert_eq!(parse_style_isolation("auto"), Ok(StyleIsolation::Auto));
+        assert!(parse_style_isolation("isolated").is_err());
+        assert_eq!(
+            parse_css_property(CssPropertyType::Isolation, "isolate"),
+            Ok(CssProperty::isolation(StyleIsolation::Isolate))
+        );
+        assert_eq!(
+            parse_css_property(CssPropertyType::MixBlendMode, "color-dodge"),
+            Ok(CssProperty::mix_blend_mode(StyleMixBlendMode::ColorDodge))
+        );
+    }
+
+    #[test]
+    fn test_parse_image_rendering() {
+        assert_eq!(parse_style_image_rendering("pixelated"), Ok(StyleImageRendering::Pixelated));
+        assert_eq!(parse_style_image_rendering("crisp-edges"), Ok(StyleImageRendering::CrispEdges));
+        assert_eq!(parse_style_image_rendering("auto"), Ok(StyleImageRendering::Auto));
+        assert!(parse_style_image_rendering("smooth").is_err());
+        assert_eq!(
+            parse_css_property(CssPropertyType::ImageRendering, "pixelated"),
+            Ok(CssProperty::image_rendering(StyleImageRendering::Pixelated))
+        );
+    }
+
     #[test]
     fn test_parse_box_shadow_1() {
         assert_eq!(
@@ -3479,6 +4130,26 @@ mod css_tests {
         );
     }
 
+    #[test]
+    fn test_expand_box_shadow_shorthand() {
+        use azul_css::{collapse_box_shadow, CssPropertyVec};
+
+        let shadow = parse_style_box_shadow("5px 10px 5px 10px #888888 inset").unwrap();
+        let expanded = expand_box_shadow_shorthand("5px 10px 5px 10px #888888 inset").unwrap();
+
+        assert_eq!(expanded, CssProperty::box_shadow(shadow));
+        assert_eq!(collapse_box_shadow(&expanded), Some(shadow));
+        assert_eq!(
+            parse_combined_css_property(CombinedCssPropertyType::BoxShadow, "5px 10px 5px 10px #888888 inset"),
+            Ok(expanded.to_vec())
+        );
+
+        // compact form round-trips through the parser
+        let css = CssPropertyVec::from_vec(expanded.to_vec()).to_css_string();
+        assert_eq!(css, "box-shadow: 5px 10px 5px 10px #888888ff inset;");
+        assert_eq!(expand_box_shadow_shorthand(&css["box-shadow: ".len()..css.len() - 1]), Ok(expanded));
+    }
+
 
     #[test]
     fn test_parse_css_border_1() {
@@ -3820,6 +4491,56 @@ mod css_tests {
         })));
     }
 
+    #[test]
+    fn test_gradient_to_css_string_round_trip() {
+        use azul_css::PrintAsCssValue;
+
+        let gradients = vec![
+            StyleBackgroundContent::LinearGradient(LinearGradient::builder()
+                .to_corner(DirectionCorner::BottomRight)
+                .stop(ColorU::RED)
+                .stop_at(ColorU::BLUE, 80.0)
+                .build().unwrap()),
+            StyleBackgroundContent::LinearGradient(LinearGradient::builder()
+                .angle(AngleValue::deg(45.0))
+                .stop_at(ColorU { r: 255, g: 0, b: 0, a: 128 }, 10.0)
+                .stop(ColorU::WHITE)
+                .stop_at(ColorU::BLACK, 90.0)
+                .repeat()
+                .build().unwrap()),
+            StyleBackgroundContent::RadialGradient(RadialGradient::builder()
+                .shape(Shape::Circle)
+                .stop(ColorU::RED)
+                .stop(ColorU::BLUE)
+                .build().unwrap()),
+            StyleBackgroundContent::RadialGradient(RadialGradient::builder()
+                .size(RadialGradientSize::ClosestSide)
+                .at(StyleBackgroundPosition {
+                    horizontal: BackgroundPositionHorizontal::Center,
+                    vertical: BackgroundPositionVertical::Bottom,
+                })
+                .stop(ColorU::RED)
+                .stop_at(ColorU::BLUE, 30.0)
+                .repeat()
+                .build().unwrap()),
+            StyleBackgroundContent::ConicGradient(ConicGradient::builder()
+                .from_angle(AngleValue::deg(30.0))
+                .at(StyleBackgroundPosition {
+                    horizontal: BackgroundPositionHorizontal::Left,
+                    vertical: BackgroundPositionVertical::Top,
+                })
+                .stop(ColorU::RED)
+                .stop_at(ColorU::BLUE, 90.0)
+                .stop(ColorU::RED)
+                .build().unwrap()),
+        ];
+
+        for gradient in gradients {
+            let css = gradient.print_as_css_value();
+            assert_eq!(parse_style_background_content(&css), Ok(gradient), "{}", css);
+        }
+    }
+
     /*
     // This test currently fails, but it's not that important to fix right now
     #[test]
@@ -3990,6 +4711,22 @@ mod css_tests {
         assert_eq!(parse_style_transform("rotate(25deg)"), Ok(StyleTransform::Rotate(AngleValue::deg(25.0))));
     }
 
+    #[test]
+    fn test_parse_transform_matrix() {
+        use azul_css::{PrintAsCssValue, StyleTransformMatrix2D};
+        assert_eq!(parse_style_transform("matrix(2, 0, 0, 0.5, 10, -20)"), Ok(StyleTransform::Matrix(StyleTransformMatrix2D {
+            a: PixelValue::px(2.0),
+            b: PixelValue::px(0.0),
+            c: PixelValue::px(0.0),
+            d: PixelValue::px(0.5),
+            tx: PixelValue::px(10.0),
+            ty: PixelValue::px(-20.0),
+        })));
+        assert!(parse_style_transform("matrix(2, 0, 0, 0.5, 10px, -20px)").is_err());
+        let m = parse_style_transform("matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 5, 6, 0, 1)").unwrap();
+        assert_eq!(m.print_as_css_value(), "matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 5, 6, 0, 1)");
+    }
+
     #[test]
     fn test_parse_direction() {
         assert_eq!(parse_direction("60.9grad"), Ok(Direction::Angle(AngleValue::grad(60.9))));
@@ -4127,8 +4864,8 @@ mod css_tests {
         use azul_css::{AzString, StringVec};
         use crate::alloc::string::ToString;
         let fonts0: Vec<StyleFontFamily> = vec![
-            StyleFontFamily::Native("Webly Sleeky UI".to_string().into()),
-            StyleFontFamily::Native("monospace".to_string().into()),
+            StyleFontFamily::System("Webly Sleeky UI".to_string().into()),
+            StyleFontFamily::System("monospace".to_string().into()),
         ];
         let fonts0: StyleFontFamilyVec = fonts0.into();
         assert_eq!(parse_style_font_family("\"Webly Sleeky UI\", monospace"), Ok(fonts0));
@@ -4139,7 +4876,7 @@ mod css_tests {
         use azul_css::{AzString, StringVec};
         use crate::alloc::string::ToString;
         let fonts0: Vec<StyleFontFamily> = vec![
-            StyleFontFamily::Native("Webly Sleeky UI".to_string().into()),
+            StyleFontFamily::System("Webly Sleeky UI".to_string().into()),
         ];
         let fonts0: StyleFontFamilyVec = fonts0.into();
         assert_eq!(parse_style_font_family("'Webly Sleeky UI'"), Ok(fonts0));
@@ -4154,6 +4891,106 @@ mod css_tests {
         );
     }
 
+    #[test]
+    fn test_parse_background_content_dispatch() {
+        use crate::alloc::string::ToString;
+
+        assert_eq!(
+            parse_style_background_content("#ff000080"),
+            Ok(StyleBackgroundContent::Color(ColorU { r: 255, g: 0, b: 0, a: 128 }))
+        );
+
+        assert_eq!(parse_style_background_content("linear-gradient(#000, white)"),
+            Ok(StyleBackgroundContent::LinearGradient(LinearGradient {
+                direction: Direction::FromTo(DirectionCorners {
+                    from: DirectionCorner::Top,
+                    to: DirectionCorner::Bottom,
+                }),
+                extend_mode: ExtendMode::Clamp,
+                stops: vec![
+                    NormalizedLinearColorStop {
+                        offset: PercentageValue::new(0.0),
+                        color: ColorU { r: 0, g: 0, b: 0, a: 255 },
+                    },
+                    NormalizedLinearColorStop {
+                        offset: PercentageValue::new(100.0),
+                        color: ColorU { r: 255, g: 255, b: 255, a: 255 },
+                    }
+                ].into(),
+            })));
+
+        assert_eq!(
+            parse_style_background_content("url(cat01.png)"),
+            Ok(StyleBackgroundContent::Image("cat01.png".to_string().into()))
+        );
+        assert_eq!(
+            parse_style_background_content("url('Cat 01')"),
+            Ok(StyleBackgroundContent::Image("Cat 01".to_string().into()))
+        );
+        assert!(parse_style_background_content("url()").is_err());
+    }
+
+    #[test]
+    fn test_parse_grid_template() {
+        assert_eq!(
+            parse_layout_grid_template_columns("100px 1fr auto"),
+            Ok(LayoutGridTemplateColumns { inner: vec![
+                GridTrack::Px(PixelValue::px(100.0)),
+                GridTrack::Fraction(FloatValue::new(1.0)),
+                GridTrack::Auto,
+            ].into() })
+        );
+        assert_eq!(
+            parse_layout_grid_template_rows("min-content repeat(3, 1fr)"),
+            Ok(LayoutGridTemplateRows { inner: vec![
+                GridTrack::MinContent,
+                GridTrack::Repeat(GridTrackRepeat {
+                    count: 3,
+                    tracks: vec![GridTrack::Fraction(FloatValue::new(1.0))].into(),
+                }),
+            ].into() })
+        );
+        assert_eq!(
+            parse_layout_grid_template_columns("none"),
+            Ok(LayoutGridTemplateColumns::default())
+        );
+        assert!(parse_layout_grid_template_columns("repeat(0, 1fr)").is_err());
+        assert!(parse_layout_grid_template_columns("minmax(10px, 1fr)").is_err());
+    }
+
+    #[test]
+    fn test_parse_grid_placement() {
+        assert_eq!(
+            parse_layout_grid_column("2"),
+            Ok(LayoutGridColumn { inner: GridPlacement { start: GridLine::Line(2), end: GridLine::Auto } })
+        );
+        assert_eq!(
+            parse_layout_grid_row("1 / span 2"),
+            Ok(LayoutGridRow { inner: GridPlacement { start: GridLine::Line(1), end: GridLine::Span(2) } })
+        );
+        assert_eq!(
+            parse_layout_grid_column("span 3 / -1"),
+            Ok(LayoutGridColumn { inner: GridPlacement { start: GridLine::Span(3), end: GridLine::Line(-1) } })
+        );
+        assert!(parse_layout_grid_row("0").is_err());
+        assert!(parse_layout_grid_row("1 / 2 / 3").is_err());
+    }
+
+    #[test]
+    fn test_grid_print_round_trip() {
+        use azul_css::PrintAsCssValue;
+
+        for input in &["repeat(3, 1fr)", "100px repeat(2, 1fr auto) max-content"] {
+            let parsed = parse_layout_grid_template_columns(input).unwrap();
+            assert_eq!(parsed.print_as_css_value(), *input);
+        }
+
+        for input in &["auto", "2", "1 / span 2"] {
+            let parsed = parse_layout_grid_row(input).unwrap();
+            assert_eq!(parsed.print_as_css_value(), *input);
+        }
+    }
+
     #[test]
     fn test_parse_padding_1() {
         assert_eq!(
@@ -4206,6 +5043,17 @@ mod css_tests {
         );
     }
 
+    #[test]
+    fn test_parse_layout_display_none() {
+        assert_eq!(parse_layout_display("none"), Ok(LayoutDisplay::None));
+        // "none" is handled for all properties before the display parser runs,
+        // the layout treats CssPropertyValue::None the same as LayoutDisplay::None
+        assert_eq!(
+            parse_css_property(CssPropertyType::Display, "none"),
+            Ok(CssProperty::Display(CssPropertyValue::None))
+        );
+    }
+
     #[test]
     fn test_parse_percentage_value_1() {
         assert_eq!(
@@ -4222,6 +5070,17 @@ mod css_tests {
         );
     }
 
+    #[test]
+    fn test_parse_percentage_value_3() {
+        assert!(parse_percentage_value("").is_err());
+        assert_eq!(
+            parse_percentage_value("5€"),
+            Err(PercentageParseError::InvalidUnit("€".to_string().into()))
+        );
+        // non-ASCII digit: not a valid float, but must not split inside the char
+        assert!(parse_percentage_value("5٣%").is_err());
+    }
+
     #[test]
     fn test_parse_angle_value_1() {
         assert_eq!(
@@ -4245,4 +5104,162 @@ mod css_tests {
             Ok(AngleValue::grad(20.4))
         );
     }
+
+    #[test]
+    fn test_parse_calc_expression() {
+        let px = |input: &str| {
+            parse_calc_expression(input).unwrap().evaluate(&azul_css::UnitResolutionContext::new(200.0)).unwrap()
+        };
+        assert_eq!(px("calc(100% - 32px)"), 168.0);
+        assert_eq!(px("calc((100% - 32px) / 2)"), 84.0);
+        assert_eq!(px("calc(2 * (10px + 1em))"), 52.0);
+        assert_eq!(px("calc(calc(50% + 10px) * 2)"), 220.0);
+        assert_eq!(px("calc(-10px + 5px)"), -5.0);
+    }
+
+    #[test]
+    fn test_parse_calc_expression_errors() {
+        assert_eq!(parse_calc_expression("100% - 32px"), Err(CssCalcParseError::NotACalcExpression("100% - 32px")));
+        assert_eq!(parse_calc_expression("calc(100%-32px)"), Err(CssCalcParseError::MissingWhitespace));
+        assert_eq!(parse_calc_expression("calc(100% -32px)"), Err(CssCalcParseError::MissingWhitespace));
+        assert_eq!(parse_calc_expression("calc(100% / 0)"), Err(CssCalcParseError::DivisionByZero));
+        assert_eq!(parse_calc_expression("calc(10px * 10px)"), Err(CssCalcParseError::TypeMismatch));
+        assert_eq!(parse_calc_expression("calc(100% + 5)"), Err(CssCalcParseError::TypeMismatch));
+        assert_eq!(parse_calc_expression("calc(5)"), Err(CssCalcParseError::TypeMismatch));
+        assert_eq!(parse_calc_expression("calc((100% - 5px)"), Err(CssCalcParseError::UnbalancedParenthesis));
+        assert_eq!(parse_calc_expression("calc(100% - 5px))"), Err(CssCalcParseError::UnbalancedParenthesis));
+        assert_eq!(parse_calc_expression("calc(* 2)"), Err(CssCalcParseError::UnexpectedToken("*")));
+    }
+
+    #[test]
+    fn test_parse_calc_round_trip() {
+        let expr = parse_calc_expression("calc(100% - (10px - 2px))").unwrap();
+        assert_eq!(expr.to_string(), "calc(100% - (10px - 2px))");
+        assert_eq!(parse_calc_expression(&expr.to_string()), Ok(expr.clone()));
+
+        let calc = expr.to_calc_length().unwrap();
+        assert_eq!(calc.to_string(), "calc(100% - 8px)");
+        assert_eq!(
+            parse_css_property(CssPropertyType::Width, "calc(100% - (10px - 2px))"),
+            Ok(CssProperty::Width(CssLengthValue::calc(calc)))
+        );
+        assert_eq!(
+            CssProperty::Width(CssLengthValue::calc(calc)).value(),
+            "calc(100% - 8px)"
+        );
+        assert!(parse_css_property(CssPropertyType::TextColor, "calc(10px)").is_err());
+
+        let pt = parse_css_property(CssPropertyType::Width, "calc(3pt + 5%)").unwrap();
+        assert_eq!(pt.value(), "calc(5% + 3pt)");
+        assert_eq!(parse_css_property(CssPropertyType::Width, &pt.value()), Ok(pt));
+    }
+
+    #[test]
+    fn test_parse_transition() {
+        assert_eq!(parse_style_transition_duration("300ms"), Ok(StyleTransitionDuration::const_ms(300)));
+        assert_eq!(parse_style_transition_duration(" 0.5s "), Ok(StyleTransitionDuration::const_ms(500)));
+        assert!(parse_style_transition_duration("-1s").is_err());
+        assert!(parse_style_transition_duration("300").is_err());
+        assert_eq!(parse_style_transition_delay("-100ms"), Ok(StyleTransitionDelay::const_ms(-100)));
+
+        assert_eq!(
+            parse_style_transition_property("opacity, width"),
+            Ok(StyleTransitionProperty::List(vec![CssPropertyType::Opacity, CssPropertyType::Width].into()))
+        );
+        assert!(parse_style_transition_property("opacity, colour").is_err());
+
+        assert_eq!(parse_style_transition_timing_function("ease-in-out"), Ok(StyleTransitionTimingFunction::EaseInOut));
+        assert_eq!(
+            parse_style_transition_timing_function("cubic-bezier(0.1, 0.7, 1.0, 0.1)"),
+            Ok(StyleTransitionTimingFunction::CubicBezier(StyleCubicBezier::new(0.1, 0.7, 1.0, 0.1)))
+        );
+        assert!(parse_style_transition_timing_function("cubic-bezier(1.1, 0, 0, 1)").is_err());
+        assert_eq!(
+            parse_style_transition_timing_function("steps(4, start)"),
+            Ok(StyleTransitionTimingFunction::Steps(StyleSteps { count: 4, position: StepPosition::JumpStart }))
+        );
+        assert!(parse_style_transition_timing_function("steps(1, jump-none)").is_err());
+        assert!(parse_style_transition_timing_function("bounce").is_err());
+
+        assert_eq!(
+            parse_combined_css_property(CombinedCssPropertyType::Transition, "opacity 300ms steps(2) 1s"),
+            Ok(vec![
+                CssProperty::transition_property(StyleTransitionProperty::List(vec![CssPropertyType::Opacity].into())),
+                CssProperty::transition_duration(StyleTransitionDuration::const_ms(300)),
+                CssProperty::transition_timing_function(StyleTransitionTimingFunction::Steps(StyleSteps {
+                    count: 2,
+                    position: StepPosition::JumpEnd,
+                })),
+                CssProperty::transition_delay(StyleTransitionDelay::const_ms(1000)),
+            ])
+        );
+        assert_eq!(
+            parse_transition_shorthand("200ms"),
+            Ok([
+                CssProperty::transition_property(StyleTransitionProperty::All),
+                CssProperty::transition_duration(StyleTransitionDuration::const_ms(200)),
+                CssProperty::transition_timing_function(StyleTransitionTimingFunction::Ease),
+                CssProperty::transition_delay(StyleTransitionDelay::const_ms(0)),
+            ])
+        );
+        assert!(parse_transition_shorthand("opacity 1s, width 2s").is_err());
+        assert!(parse_transition_shorthand("opacity 1s 2s 3s").is_err());
+    }
+
+    #[test]
+    fn test_parse_caret_and_selection_colors() {
+        assert_eq!(
+            parse_css_property(CssPropertyType::CaretColor, "red"),
+            Ok(CssProperty::caret_color(StyleCaretColor { inner: ColorU::RED }))
+        );
+        assert_eq!(
+            parse_css_property(CssPropertyType::CaretColor, "auto"),
+            Ok(CssProperty::CaretColor(CssPropertyValue::Auto))
+        );
+        assert_eq!(
+            parse_css_property(CssPropertyType::SelectionBackground, "#0000ff"),
+            Ok(CssProperty::selection_background(StyleSelectionBackground { inner: ColorU::BLUE }))
+        );
+        assert_eq!(parse_style_selection_color("white"), Ok(StyleSelectionColor { inner: ColorU::WHITE }));
+        assert!(parse_style_caret_color("10px").is_err());
+    }
+
+    #[test]
+    fn test_parse_style_direction() {
+        assert_eq!(parse_style_direction("rtl"), Ok(StyleDirection::Rtl));
+        assert!(parse_style_direction("right-to-left").is_err());
+        assert_eq!(parse_layout_text_align("start"), Ok(StyleTextAlign::Start));
+        assert_eq!(
+            parse_css_property(CssPropertyType::Direction, "rtl"),
+            Ok(CssProperty::direction(StyleDirection::Rtl))
+        );
+    }
+
+    #[test]
+    fn test_parse_vertical_align() {
+        assert_eq!(parse_style_vertical_align("middle"), Ok(StyleVerticalAlign::Middle));
+        assert_eq!(parse_style_vertical_align(" text-bottom "), Ok(StyleVerticalAlign::TextBottom));
+        assert_eq!(parse_style_vertical_align("-3px"), Ok(StyleVerticalAlign::Exact(PixelValue::px(-3.0))));
+        assert!(parse_style_vertical_align("center").is_err());
+        assert_eq!(
+            parse_css_property(CssPropertyType::VerticalAlign, "super"),
+            Ok(CssProperty::vertical_align(StyleVerticalAlign::Super))
+        );
+    }
+
+    #[test]
+    fn test_parse_overflow_shorthand() {
+        assert_eq!(
+            parse_combined_css_property(CombinedCssPropertyType::Overflow, "clip"),
+            Ok(CssProperty::overflow(LayoutOverflow::Clip, LayoutOverflow::Clip).to_vec())
+        );
+        assert_eq!(
+            parse_combined_css_property(CombinedCssPropertyType::Overflow, "hidden auto"),
+            Ok(CssProperty::overflow(LayoutOverflow::Hidden, LayoutOverflow::Auto).to_vec())
+        );
+        assert_eq!(parse_layout_overflow_xy("  scroll   visible "), Ok((LayoutOverflow::Scroll, LayoutOverflow::Visible)));
+        assert_eq!(parse_layout_overflow_xy("hidden auto scroll"), Err(InvalidValueErr("hidden auto scroll")));
+        assert_eq!(parse_layout_overflow_xy("hidden foo"), Err(InvalidValueErr("foo")));
+        assert_eq!(parse_layout_overflow_xy(""), Err(InvalidValueErr("")));
+    }
 }
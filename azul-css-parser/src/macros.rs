@@ -1,7 +1,7 @@
 /// Implement `Display` for an enum.
 ///
 /// Example usage:
-/// ```
+/// ```rust,ignore
 /// enum Foo<'a> {
 ///     Bar(&'a str)
 ///     Baz(i32)
@@ -73,7 +73,7 @@ macro_rules! impl_debug_as_display {
 
 /// Implement the `From` trait for any type.
 /// Example usage:
-/// ```
+/// ```rust,ignore
 /// enum MyError<'a> {
 ///     Bar(BarError<'a>)
 ///     Foo(FooError<'a>)
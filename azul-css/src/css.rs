@@ -1,9 +1,14 @@
 //! Types and methods used to describe the style of an application
-use crate::css_properties::{CssProperty, CssPropertyType};
+use crate::css_properties::{
+    CalcLength, CssProperty, CssPropertyType, FloatValue, PixelValue, UnitResolutionContext,
+    DEFAULT_DPI,
+};
 use crate::AzString;
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt;
+use core::mem::ManuallyDrop;
 
 /// Css stylesheet - contains a parsed CSS stylesheet in "rule blocks",
 /// i.e. blocks of key-value pairs associated with a selector path.
@@ -14,6 +19,9 @@ pub struct Css {
     /// For example, when overriding native styles, the `.sort_by_specificy()` function
     /// should not mix the two stylesheets during sorting.
     pub stylesheets: StylesheetVec,
+    /// Values of the `var(--name)` variables that are set at runtime
+    /// (sorted by name, see `Css::set_variable`)
+    pub variables: CssVariableVec,
 }
 
 impl_vec!(Stylesheet, StylesheetVec, StylesheetVecDestructor);
@@ -28,9 +36,16 @@ impl Css {
         self.stylesheets.iter().all(|s| s.rules.as_ref().is_empty())
     }
 
+    /// Returns whether any rule block is inside of an `@media` group, i.e.
+    /// whether the style depends on the `MediaEnvironment`
+    pub fn has_media_queries(&self) -> bool {
+        self.rules().any(|rule| rule.media.is_some())
+    }
+
     pub fn new(stylesheets: Vec<Stylesheet>) -> Self {
         Self {
             stylesheets: stylesheets.into(),
+            variables: CssVariableVec::from_const_slice(&[]),
         }
     }
 }
@@ -151,6 +166,256 @@ pub struct DynamicCssProperty {
     pub default_value: CssProperty,
 }
 
+impl DynamicCssProperty {
+    /// Returns the value of the variable if it is set in `variables` for the
+    /// same property type, otherwise the default value
+    pub fn get_value<'a>(&'a self, variables: &'a CssVariableVec) -> &'a CssProperty {
+        let variables = variables.as_ref();
+        match find_variable(variables, self.dynamic_id.as_str()) {
+            Ok(i) if variables[i].value.get_type() == self.default_value.get_type() => {
+                &variables[i].value
+            }
+            _ => &self.default_value,
+        }
+    }
+}
+
+/// Runtime value of a CSS variable, such as `--accent: #ff0000`
+///
+/// Variables are typed: `var(--accent)` only resolves to the value
+/// if it is used for the same property, i.e. a `background: var(--accent)`
+/// ignores a `color` value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct CssVariable {
+    /// Name of the variable without the leading `--`
+    pub name: AzString,
+    pub value: CssProperty,
+}
+
+impl_vec!(CssVariable, CssVariableVec, CssVariableVecDestructor);
+impl_vec_mut!(CssVariable, CssVariableVec);
+impl_vec_debug!(CssVariable, CssVariableVec);
+impl_vec_partialord!(CssVariable, CssVariableVec);
+impl_vec_clone!(CssVariable, CssVariableVec, CssVariableVecDestructor);
+impl_vec_partialeq!(CssVariable, CssVariableVec);
+
+// variables are sorted by name, so that they can be binary-searched
+fn find_variable(variables: &[CssVariable], name: &str) -> Result<usize, usize> {
+    variables.binary_search_by(|v| v.name.as_str().cmp(name))
+}
+
+/// Owned or `'static` pointer to a property value that is too large to be
+/// stored inline, so that a `CssProperty` stays small (vectors, shadows, ...)
+///
+/// Properties can still be created in `const` items with `BoxOrStatic::from_static`,
+/// only values created at runtime (i.e. by the CSS parser) are allocated.
+///
+/// C ABI: a (never null) pointer to a `CssBoxed<T>`, which stores whether the
+/// allocation is owned by the library (and freed when the property is dropped)
+/// next to the value.
+///
+/// Safety: the pointer is `*const` because it may point into a `static`. It is only
+/// written through (`make_mut`) or freed (`into_inner`, `Drop`) if `run_destructor`
+/// is set, in which case it was created by `Box::into_raw` in `BoxOrStatic::new` and
+/// is owned exclusively by this `BoxOrStatic` (`Clone` copies the value to a new box).
+#[repr(transparent)]
+pub struct BoxOrStatic<T> {
+    ptr: *const CssBoxed<T>,
+}
+
+/// Value of a `BoxOrStatic`: `run_destructor` is only set for values that were
+/// allocated by the library, values in a `static` are created with `CssBoxed::new_static`
+///
+/// The value is never dropped together with the `CssBoxed` (only `BoxOrStatic` drops it,
+/// and only if `run_destructor` is set), so a `CssBoxed` has no drop glue and
+/// `&const { CssBoxed::new_static(value) }` is promoted to a `'static` reference,
+/// see the `css_boxed_static!` macro.
+#[derive(Debug)]
+#[repr(C)]
+pub struct CssBoxed<T> {
+    run_destructor: bool,
+    pub value: ManuallyDrop<T>,
+}
+
+impl<T> CssBoxed<T> {
+    /// Value that can be stored in a `static` and borrowed with `BoxOrStatic::from_static`
+    #[inline]
+    pub const fn new_static(value: T) -> Self {
+        Self {
+            run_destructor: false,
+            value: ManuallyDrop::new(value),
+        }
+    }
+}
+
+/// Creates a `&'static CssBoxed<T>` from a constant expression, so that the `const_*`
+/// constructors of `CssProperty` can be called with a value inside of `const` and
+/// `static` items, without declaring a separate `static` for every boxed value:
+///
+/// ```rust
+/// # use azul_css::*;
+/// static PROPS: &[CssProperty] = &[CssProperty::const_background_content(
+///     css_boxed_static!(StyleBackgroundContentVec::from_const_slice(&[])),
+/// )];
+/// ```
+///
+/// (a `const fn` can't borrow its argument for `'static`, so this has to be a macro)
+#[macro_export]
+macro_rules! css_boxed_static {
+    ($value:expr) => {
+        &const { $crate::CssBoxed::new_static($value) }
+    };
+}
+
+impl<T> BoxOrStatic<T> {
+    /// Moves the value to the heap
+    #[inline]
+    pub fn new(value: T) -> Self {
+        let boxed = Box::new(CssBoxed {
+            run_destructor: true,
+            value: ManuallyDrop::new(value),
+        });
+        Self {
+            ptr: Box::into_raw(boxed),
+        }
+    }
+
+    /// Borrows a value from a `static`, does not allocate
+    #[inline]
+    pub const fn from_static(value: &'static CssBoxed<T>) -> Self {
+        Self { ptr: value }
+    }
+
+    /// Returns whether the value is borrowed (associated function, like
+    /// `Rc::make_mut`, so that it doesn't shadow methods of `T`)
+    #[inline]
+    pub fn is_static(this: &Self) -> bool {
+        !this.boxed().run_destructor
+    }
+
+    #[inline]
+    fn boxed(&self) -> &CssBoxed<T> {
+        unsafe { &*self.ptr }
+    }
+
+    /// Returns a mutable reference to the value, copies a borrowed value to the heap first
+    pub fn make_mut(this: &mut Self) -> &mut T
+    where
+        T: Clone,
+    {
+        if Self::is_static(this) {
+            *this = Self::new(T::clone(this));
+        }
+        // Safety: the value is not static (anymore), so the pointer came from
+        // `Box::into_raw` and `&mut self` guarantees that it isn't borrowed
+        unsafe { &mut (*(this.ptr as *mut CssBoxed<T>)).value }
+    }
+
+    /// Returns the value, clones it if it is borrowed
+    pub fn into_inner(this: Self) -> T
+    where
+        T: Clone,
+    {
+        if Self::is_static(&this) {
+            return T::clone(&this);
+        }
+        // Safety: see `Drop`, `forget` prevents the box from being freed twice
+        let boxed = unsafe { Box::from_raw(this.ptr as *mut CssBoxed<T>) };
+        core::mem::forget(this);
+        ManuallyDrop::into_inner(boxed.value)
+    }
+}
+
+impl<T> Drop for BoxOrStatic<T> {
+    fn drop(&mut self) {
+        // Values in a `static` have `run_destructor` set to `false` and are never freed.
+        // Otherwise the pointer came from `Box::into_raw` in `BoxOrStatic::new` and is
+        // not shared with any other `BoxOrStatic`, so it can be freed here
+        if !Self::is_static(self) {
+            let mut boxed = unsafe { Box::from_raw(self.ptr as *mut CssBoxed<T>) };
+            unsafe { ManuallyDrop::drop(&mut boxed.value) };
+        }
+    }
+}
+
+unsafe impl<T: Send> Send for BoxOrStatic<T> {}
+unsafe impl<T: Sync> Sync for BoxOrStatic<T> {}
+
+impl<T> core::ops::Deref for BoxOrStatic<T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.boxed().value
+    }
+}
+
+impl<T: Clone> Clone for BoxOrStatic<T> {
+    fn clone(&self) -> Self {
+        if Self::is_static(self) {
+            Self { ptr: self.ptr }
+        } else {
+            Self::new(T::clone(self))
+        }
+    }
+}
+
+impl<T> From<T> for BoxOrStatic<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Default> Default for BoxOrStatic<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for BoxOrStatic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for BoxOrStatic<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for BoxOrStatic<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq> Eq for BoxOrStatic<T> {}
+
+impl<T: PartialOrd> PartialOrd for BoxOrStatic<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord> Ord for BoxOrStatic<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: core::hash::Hash> core::hash::Hash for BoxOrStatic<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<T: PrintAsCssValue> PrintAsCssValue for BoxOrStatic<T> {
+    fn print_as_css_value(&self) -> String {
+        (**self).print_as_css_value()
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(C, u8)] // necessary for ABI stability
 pub enum CssPropertyValue<T> {
@@ -196,6 +461,26 @@ impl<T> From<T> for CssPropertyValue<T> {
     }
 }
 
+impl<T> From<T> for CssPropertyValue<BoxOrStatic<T>> {
+    fn from(c: T) -> Self {
+        CssPropertyValue::Exact(BoxOrStatic::new(c))
+    }
+}
+
+impl<T> CssPropertyValue<BoxOrStatic<T>> {
+    /// Moves the value to the heap and wraps it in an `Exact` value
+    #[inline]
+    pub fn exact(value: T) -> Self {
+        CssPropertyValue::Exact(BoxOrStatic::new(value))
+    }
+
+    /// `Exact` value that borrows a `static` value, see `BoxOrStatic::from_static`
+    #[inline]
+    pub const fn exact_static(value: &'static CssBoxed<T>) -> Self {
+        CssPropertyValue::Exact(BoxOrStatic::from_static(value))
+    }
+}
+
 impl<T> CssPropertyValue<T> {
     /// Transforms a `CssPropertyValue<T>` into a `CssPropertyValue<U>` by applying a mapping function
     #[inline]
@@ -256,6 +541,18 @@ impl<T> CssPropertyValue<T> {
             _ => false,
         }
     }
+
+    /// Resolves an `Exact` length into pixels, `get_pixel_value` returns the
+    /// `PixelValue` of the `Exact` variant (usually `|v| v.inner`). Same as
+    /// `CssLengthValue::resolve_length` for properties that can't be `calc()`.
+    #[inline]
+    pub fn resolve_length<F: Fn(&T) -> PixelValue>(
+        &self,
+        ctx: &UnitResolutionContext,
+        get_pixel_value: F,
+    ) -> Option<f32> {
+        self.get_property().map(|e| get_pixel_value(e).resolve(ctx))
+    }
 }
 
 impl<T: Default> CssPropertyValue<T> {
@@ -276,6 +573,154 @@ impl<T: Default> Default for CssPropertyValue<T> {
     }
 }
 
+/// Value of a length property (`width`, `top`, `padding-left`, ...): same as
+/// `CssPropertyValue`, but can also hold a `calc()` expression. Read these
+/// properties with `resolve_length`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C, u8)] // necessary for ABI stability
+pub enum CssLengthValue<T> {
+    Auto,
+    None,
+    Initial,
+    Inherit,
+    Exact(T),
+    Calc(BoxOrStatic<CalcLength>),
+}
+
+impl<T: PrintAsCssValue> CssLengthValue<T> {
+    pub fn get_css_value_fmt(&self) -> String {
+        match self {
+            CssLengthValue::Auto => format!("auto"),
+            CssLengthValue::None => format!("none"),
+            CssLengthValue::Initial => format!("initial"),
+            CssLengthValue::Inherit => format!("inherit"),
+            CssLengthValue::Exact(e) => e.print_as_css_value(),
+            CssLengthValue::Calc(c) => format!("{}", c),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CssLengthValue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::CssLengthValue::*;
+        match self {
+            Auto => write!(f, "auto"),
+            None => write!(f, "none"),
+            Initial => write!(f, "initial"),
+            Inherit => write!(f, "inherit"),
+            Exact(e) => write!(f, "{}", e),
+            Calc(c) => write!(f, "{}", c),
+        }
+    }
+}
+
+impl<T> From<T> for CssLengthValue<T> {
+    fn from(c: T) -> Self {
+        CssLengthValue::Exact(c)
+    }
+}
+
+impl<T> CssLengthValue<T> {
+    /// Moves the `calc()` length to the heap and wraps it in a `Calc` value
+    #[inline]
+    pub fn calc(calc: CalcLength) -> Self {
+        CssLengthValue::Calc(BoxOrStatic::new(calc))
+    }
+
+    /// Transforms a `CssLengthValue<T>` into a `CssLengthValue<U>` by applying a mapping function
+    #[inline]
+    pub fn map_property<F: Fn(T) -> U, U>(self, map_fn: F) -> CssLengthValue<U> {
+        match self {
+            CssLengthValue::Exact(c) => CssLengthValue::Exact(map_fn(c)),
+            CssLengthValue::Auto => CssLengthValue::Auto,
+            CssLengthValue::None => CssLengthValue::None,
+            CssLengthValue::Initial => CssLengthValue::Initial,
+            CssLengthValue::Inherit => CssLengthValue::Inherit,
+            CssLengthValue::Calc(c) => CssLengthValue::Calc(c),
+        }
+    }
+
+    #[inline]
+    pub fn get_property(&self) -> Option<&T> {
+        match self {
+            CssLengthValue::Exact(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn get_property_owned(self) -> Option<T> {
+        match self {
+            CssLengthValue::Exact(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn is_auto(&self) -> bool {
+        matches!(self, CssLengthValue::Auto)
+    }
+
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        matches!(self, CssLengthValue::None)
+    }
+
+    #[inline]
+    pub fn is_initial(&self) -> bool {
+        matches!(self, CssLengthValue::Initial)
+    }
+
+    #[inline]
+    pub fn is_inherit(&self) -> bool {
+        matches!(self, CssLengthValue::Inherit)
+    }
+
+    #[inline]
+    pub fn get_calc(&self) -> Option<&CalcLength> {
+        match self {
+            CssLengthValue::Calc(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Resolves an `Exact` or `Calc` length into pixels, `get_pixel_value`
+    /// returns the `PixelValue` of the `Exact` variant (usually `|v| v.inner`).
+    /// Returns `None` for `auto`, `none`, `initial` and `inherit`.
+    #[inline]
+    pub fn resolve_length<F: Fn(&T) -> PixelValue>(
+        &self,
+        ctx: &UnitResolutionContext,
+        get_pixel_value: F,
+    ) -> Option<f32> {
+        match self {
+            CssLengthValue::Exact(e) => Some(get_pixel_value(e).resolve(ctx)),
+            CssLengthValue::Calc(c) => Some(c.evaluate(ctx)),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Default> CssLengthValue<T> {
+    /// NOTE: returns `None` for `Calc`, which depends on the containing
+    /// block - read it with `resolve_length` instead
+    #[inline]
+    pub fn get_property_or_default(self) -> Option<T> {
+        match self {
+            CssLengthValue::Auto | CssLengthValue::Initial => Some(T::default()),
+            CssLengthValue::Exact(c) => Some(c),
+            CssLengthValue::None | CssLengthValue::Inherit | CssLengthValue::Calc(_) => None,
+        }
+    }
+}
+
+impl<T: Default> Default for CssLengthValue<T> {
+    #[inline]
+    fn default() -> Self {
+        CssLengthValue::Exact(T::default())
+    }
+}
+
 impl DynamicCssProperty {
     pub fn is_inheritable(&self) -> bool {
         // Dynamic style properties should not be inheritable,
@@ -299,6 +744,9 @@ pub struct CssRuleBlock {
     /// `"justify-content: center"` =>
     /// `CssDeclaration::Static(CssProperty::JustifyContent(LayoutJustifyContent::Center))`
     pub declarations: CssDeclarationVec,
+    /// Condition of the `@media` group that the block was declared in:
+    /// the block is skipped during the cascade if the query doesn't match
+    pub media: OptionMediaQuery,
 }
 
 impl_vec!(
@@ -324,6 +772,276 @@ impl CssRuleBlock {
         Self {
             path,
             declarations: declarations.into(),
+            media: OptionMediaQuery::None,
+        }
+    }
+
+    /// Returns whether the block applies in the given environment,
+    /// blocks outside of an `@media` group always apply
+    pub fn matches_media(&self, env: &MediaEnvironment) -> bool {
+        match self.media.as_ref() {
+            Some(query) => query.evaluate(env),
+            None => true,
+        }
+    }
+}
+
+/// Condition of an `@media` group, i.e. `(max-width: 600px), (orientation: portrait)`:
+/// the rules of the group only apply if any of the comma-separated conditions
+/// matches the `MediaEnvironment`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct MediaQuery {
+    /// Conditions separated by `,` (or `or`)
+    pub conditions: MediaConditionVec,
+}
+
+impl_option!(
+    MediaQuery,
+    OptionMediaQuery,
+    copy = false,
+    [Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord]
+);
+
+impl MediaQuery {
+    /// Creates a query with a single condition
+    pub fn new(features: Vec<MediaFeature>) -> Self {
+        Self::any(vec![MediaCondition::new(features)])
+    }
+
+    /// Creates a query that matches if any of the conditions match
+    pub fn any(conditions: Vec<MediaCondition>) -> Self {
+        Self {
+            conditions: conditions.into(),
+        }
+    }
+
+    /// Returns whether any condition of the query matches the environment
+    pub fn evaluate(&self, env: &MediaEnvironment) -> bool {
+        self.conditions.iter().any(|condition| condition.evaluate(env))
+    }
+}
+
+impl fmt::Display for MediaQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, condition) in self.conditions.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", condition)?;
+        }
+        Ok(())
+    }
+}
+
+/// Media features combined with `and`, i.e. `(min-width: 600px) and (orientation: portrait)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct MediaCondition {
+    /// An empty condition always matches
+    pub features: MediaFeatureVec,
+}
+
+impl_vec!(MediaCondition, MediaConditionVec, MediaConditionVecDestructor);
+impl_vec_debug!(MediaCondition, MediaConditionVec);
+impl_vec_partialord!(MediaCondition, MediaConditionVec);
+impl_vec_ord!(MediaCondition, MediaConditionVec);
+impl_vec_clone!(MediaCondition, MediaConditionVec, MediaConditionVecDestructor);
+impl_vec_partialeq!(MediaCondition, MediaConditionVec);
+impl_vec_eq!(MediaCondition, MediaConditionVec);
+impl_vec_hash!(MediaCondition, MediaConditionVec);
+
+impl MediaCondition {
+    pub fn new(features: Vec<MediaFeature>) -> Self {
+        Self {
+            features: features.into(),
+        }
+    }
+
+    /// Returns whether all features of the condition match the environment
+    pub fn evaluate(&self, env: &MediaEnvironment) -> bool {
+        self.features.iter().all(|feature| feature.evaluate(env))
+    }
+}
+
+impl fmt::Display for MediaCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, feature) in self.features.iter().enumerate() {
+            if i != 0 {
+                write!(f, " and ")?;
+            }
+            write!(f, "({})", feature)?;
+        }
+        Ok(())
+    }
+}
+
+/// One `(feature: value)` or `(feature < value)` test of a `MediaCondition`
+///
+/// Lengths are resolved against the default font size (`1em` = 16px),
+/// the resolution is given in dots per `px` (`1dppx` = 96 DPI).
+/// The range syntax maps `<=` / `>=` to the `Max` / `Min` variants,
+/// only the strict comparisons have their own variants.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C, u8)]
+pub enum MediaFeature {
+    /// `(min-width: 600px)`: the window is at least 600px wide
+    MinWidth(PixelValue),
+    /// `(max-width: 600px)`: the window is at most 600px wide
+    MaxWidth(PixelValue),
+    MinHeight(PixelValue),
+    MaxHeight(PixelValue),
+    /// `(width < 600px)`: the window is less than 600px wide
+    WidthLessThan(PixelValue),
+    /// `(width > 600px)`: the window is more than 600px wide
+    WidthGreaterThan(PixelValue),
+    HeightLessThan(PixelValue),
+    HeightGreaterThan(PixelValue),
+    /// `(orientation: portrait)`
+    Orientation(MediaOrientation),
+    /// `(prefers-color-scheme: dark)`
+    PrefersColorScheme(MediaColorScheme),
+    /// `(resolution: 2dppx)`
+    Resolution(FloatValue),
+    MinResolution(FloatValue),
+    MaxResolution(FloatValue),
+    ResolutionLessThan(FloatValue),
+    ResolutionGreaterThan(FloatValue),
+}
+
+impl_vec!(MediaFeature, MediaFeatureVec, MediaFeatureVecDestructor);
+impl_vec_debug!(MediaFeature, MediaFeatureVec);
+impl_vec_partialord!(MediaFeature, MediaFeatureVec);
+impl_vec_ord!(MediaFeature, MediaFeatureVec);
+impl_vec_clone!(MediaFeature, MediaFeatureVec, MediaFeatureVecDestructor);
+impl_vec_partialeq!(MediaFeature, MediaFeatureVec);
+impl_vec_eq!(MediaFeature, MediaFeatureVec);
+impl_vec_hash!(MediaFeature, MediaFeatureVec);
+
+impl MediaFeature {
+    /// Returns whether the feature matches the environment
+    pub fn evaluate(&self, env: &MediaEnvironment) -> bool {
+        use self::MediaFeature::*;
+        let length = |v: &PixelValue| v.resolve(&UnitResolutionContext::new(0.0));
+        let dppx = FloatValue::new(env.dpi / DEFAULT_DPI);
+        match self {
+            MinWidth(v) => env.width >= length(v),
+            MaxWidth(v) => env.width <= length(v),
+            MinHeight(v) => env.height >= length(v),
+            MaxHeight(v) => env.height <= length(v),
+            WidthLessThan(v) => env.width < length(v),
+            WidthGreaterThan(v) => env.width > length(v),
+            HeightLessThan(v) => env.height < length(v),
+            HeightGreaterThan(v) => env.height > length(v),
+            Orientation(o) => *o == env.get_orientation(),
+            PrefersColorScheme(c) => *c == env.get_color_scheme(),
+            Resolution(r) => dppx == *r,
+            MinResolution(r) => dppx >= *r,
+            MaxResolution(r) => dppx <= *r,
+            ResolutionLessThan(r) => dppx < *r,
+            ResolutionGreaterThan(r) => dppx > *r,
+        }
+    }
+}
+
+impl fmt::Display for MediaFeature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::MediaFeature::*;
+        match self {
+            MinWidth(v) => write!(f, "min-width: {}", v),
+            MaxWidth(v) => write!(f, "max-width: {}", v),
+            MinHeight(v) => write!(f, "min-height: {}", v),
+            MaxHeight(v) => write!(f, "max-height: {}", v),
+            WidthLessThan(v) => write!(f, "width < {}", v),
+            WidthGreaterThan(v) => write!(f, "width > {}", v),
+            HeightLessThan(v) => write!(f, "height < {}", v),
+            HeightGreaterThan(v) => write!(f, "height > {}", v),
+            Orientation(o) => write!(f, "orientation: {}", o),
+            PrefersColorScheme(c) => write!(f, "prefers-color-scheme: {}", c),
+            Resolution(r) => write!(f, "resolution: {}dppx", r),
+            MinResolution(r) => write!(f, "min-resolution: {}dppx", r),
+            MaxResolution(r) => write!(f, "max-resolution: {}dppx", r),
+            ResolutionLessThan(r) => write!(f, "resolution < {}dppx", r),
+            ResolutionGreaterThan(r) => write!(f, "resolution > {}dppx", r),
+        }
+    }
+}
+
+/// Value of the `orientation` media feature
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub enum MediaOrientation {
+    /// The window is at least as high as it is wide
+    Portrait,
+    Landscape,
+}
+
+impl fmt::Display for MediaOrientation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MediaOrientation::Portrait => write!(f, "portrait"),
+            MediaOrientation::Landscape => write!(f, "landscape"),
+        }
+    }
+}
+
+/// Value of the `prefers-color-scheme` media feature
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub enum MediaColorScheme {
+    Light,
+    Dark,
+}
+
+impl fmt::Display for MediaColorScheme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MediaColorScheme::Light => write!(f, "light"),
+            MediaColorScheme::Dark => write!(f, "dark"),
+        }
+    }
+}
+
+/// State of the window that `@media` queries are evaluated against
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct MediaEnvironment {
+    /// Width of the window in logical pixels
+    pub width: f32,
+    /// Height of the window in logical pixels
+    pub height: f32,
+    /// DPI of the monitor, `DEFAULT_DPI` at a hidpi factor of 1.0
+    pub dpi: f32,
+    /// Whether the window uses the dark theme
+    pub dark_mode: bool,
+}
+
+impl Default for MediaEnvironment {
+    /// Same size as the default window: 640x480, 96 DPI, light theme
+    fn default() -> Self {
+        Self {
+            width: 640.0,
+            height: 480.0,
+            dpi: DEFAULT_DPI,
+            dark_mode: false,
+        }
+    }
+}
+
+impl MediaEnvironment {
+    pub fn get_orientation(&self) -> MediaOrientation {
+        if self.height >= self.width {
+            MediaOrientation::Portrait
+        } else {
+            MediaOrientation::Landscape
+        }
+    }
+
+    pub fn get_color_scheme(&self) -> MediaColorScheme {
+        if self.dark_mode {
+            MediaColorScheme::Dark
+        } else {
+            MediaColorScheme::Light
         }
     }
 }
@@ -422,6 +1140,25 @@ impl CssPath {
             selectors: selectors.into(),
         }
     }
+
+    /// Returns the `(id, class, type)` specificity of this path, as described on
+    /// [the w3 website](http://www.w3.org/TR/selectors/#specificity).
+    ///
+    /// Pseudo selectors (`:hover`, `:nth-child()`, ...) count as classes,
+    /// `*` and the combinators do not count at all.
+    pub fn specificity(&self) -> (u32, u32, u32) {
+        use self::CssPathSelector::*;
+        let mut specificity = (0, 0, 0);
+        for selector in self.selectors.iter() {
+            match selector {
+                Id(_) => specificity.0 += 1,
+                Class(_) | PseudoSelector(_) => specificity.1 += 1,
+                Type(_) => specificity.2 += 1,
+                Global | DirectChildren | Children => {}
+            }
+        }
+        specificity
+    }
 }
 
 impl fmt::Display for CssPath {
@@ -524,6 +1261,26 @@ impl fmt::Display for CssNthChildSelector {
     }
 }
 
+impl CssNthChildSelector {
+    /// Returns whether a node at the given position in its parent matches
+    /// this selector. Note that `index` starts at 1, same as in CSS.
+    pub fn matches(&self, index: u32) -> bool {
+        use self::CssNthChildSelector::*;
+        match *self {
+            Number(value) => index == value,
+            Even => index % 2 == 0,
+            Odd => index % 2 == 1,
+            Pattern(CssNthChildPattern { repeat, offset }) => {
+                if repeat == 0 {
+                    index == offset
+                } else {
+                    index >= offset && (index - offset) % repeat == 0
+                }
+            }
+        }
+    }
+}
+
 impl fmt::Display for CssPathPseudoSelector {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::CssPathPseudoSelector::*;
@@ -558,6 +1315,52 @@ impl Css {
             css: self,
         }
     }
+
+    /// Sets the value of the variable `--name`, every `var(--name)` for
+    /// the same property type resolves to `value` on the next restyle,
+    /// without having to parse the stylesheet again.
+    ///
+    /// The leading `--` of the name is optional.
+    pub fn set_variable(&mut self, name: AzString, value: CssProperty) {
+        let name = match name.as_str().strip_prefix("--") {
+            Some(s) => AzString::from(String::from(s)),
+            None => name,
+        };
+        match find_variable(self.variables.as_ref(), name.as_str()) {
+            Ok(i) => self.variables.as_mut()[i].value = value,
+            Err(i) => self.variables.insert(i, CssVariable { name, value }),
+        }
+    }
+
+    /// Returns the runtime value of the variable `--name`, if set
+    pub fn get_variable(&self, name: &str) -> Option<&CssProperty> {
+        let name = name.strip_prefix("--").unwrap_or(name);
+        let variables = self.variables.as_ref();
+        find_variable(variables, name)
+            .ok()
+            .map(|i| &variables[i].value)
+    }
+
+    /// Replaces every `var(--name)` declaration with a static declaration
+    /// of the current value of the variable (or its default value, if the
+    /// variable is not set), so that the cascade doesn't have to look up
+    /// the variables on every restyle.
+    ///
+    /// Variables that are set after this call have no effect anymore.
+    pub fn resolve_variables(&mut self) {
+        let variables = &self.variables;
+        for stylesheet in self.stylesheets.as_mut().iter_mut() {
+            for block in stylesheet.rules.as_mut().iter_mut() {
+                for declaration in block.declarations.as_mut().iter_mut() {
+                    let value = match declaration {
+                        CssDeclaration::Dynamic(d) => d.get_value(variables).clone(),
+                        CssDeclaration::Static(_) => continue,
+                    };
+                    *declaration = CssDeclaration::Static(value);
+                }
+            }
+        }
+    }
 }
 
 pub struct RuleIterator<'a> {
@@ -602,40 +1405,137 @@ impl Stylesheet {
 /// Returns specificity of the given css path. Further information can be found on
 /// [the w3 website](http://www.w3.org/TR/selectors/#specificity).
 fn get_specificity(path: &CssPath) -> (usize, usize, usize, usize) {
-    let id_count = path
-        .selectors
-        .iter()
-        .filter(|x| {
-            if let CssPathSelector::Id(_) = x {
-                true
-            } else {
-                false
-            }
-        })
-        .count();
-    let class_count = path
-        .selectors
-        .iter()
-        .filter(|x| {
-            if let CssPathSelector::Class(_) = x {
-                true
-            } else {
-                false
-            }
-        })
-        .count();
-    let div_count = path
-        .selectors
-        .iter()
-        .filter(|x| {
-            if let CssPathSelector::Type(_) = x {
-                true
-            } else {
-                false
+    let (id_count, class_count, type_count) = path.specificity();
+    (
+        id_count as usize,
+        class_count as usize,
+        type_count as usize,
+        path.selectors.len(),
+    )
+}
+
+/// Everything about a single node that is necessary to match it against a
+/// `CssPath`, without depending on the DOM types
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HtmlCascadeInfo<'a> {
+    /// `div`, `p`, etc.
+    pub node_type: NodeTypeTag,
+    /// The `#ids` of the node
+    pub ids: &'a [AzString],
+    /// The `.classes` of the node
+    pub classes: &'a [AzString],
+    /// Position of the node in its parent, starting at 0
+    pub index_in_parent: u32,
+    /// Whether the node has no next sibling (necessary for `:last`)
+    pub is_last_child: bool,
+    /// Whether the mouse is over the node (`:hover`)
+    pub is_hovered: bool,
+    /// Whether the mouse is pressed and over the node (`:active`)
+    pub is_active: bool,
+    /// Whether the node has focus (`:focus`)
+    pub is_focused: bool,
+}
+
+impl<'a> HtmlCascadeInfo<'a> {
+    /// Creates the cascade info for a node without any ids, classes or pseudo state
+    pub const fn new(node_type: NodeTypeTag) -> Self {
+        Self {
+            node_type,
+            ids: &[],
+            classes: &[],
+            index_in_parent: 0,
+            is_last_child: false,
+            is_hovered: false,
+            is_active: false,
+            is_focused: false,
+        }
+    }
+}
+
+/// Returns if the CSS path matches the given node (i.e. if the node should be styled
+/// by the rule belonging to that path).
+///
+/// `ancestors` has to start with the direct parent of the node, followed by its
+/// parent and so on, up to the root node.
+pub fn matches_html_element(
+    path: &CssPath,
+    node: &HtmlCascadeInfo,
+    ancestors: &[HtmlCascadeInfo],
+) -> bool {
+    use self::CssPathSelector::*;
+
+    if path.selectors.is_empty() {
+        return false;
+    }
+
+    // split "body > .foo.main #baz" into [(body), (> .foo.main), ( #baz)],
+    // each group remembers whether it has to be a direct child of the previous group
+    let mut groups = Vec::new();
+    let mut group_start = 0;
+    let mut is_direct_child = false;
+    let selectors = path.selectors.as_ref();
+    for (idx, selector) in selectors.iter().enumerate() {
+        match selector {
+            Children | DirectChildren => {
+                groups.push((&selectors[group_start..idx], is_direct_child));
+                group_start = idx + 1;
+                is_direct_child = *selector == DirectChildren;
             }
-        })
-        .count();
-    (id_count, class_count, div_count, path.selectors.len())
+            _ => {}
+        }
+    }
+    groups.push((&selectors[group_start..], is_direct_child));
+
+    content_groups_match(&groups, node, ancestors)
+}
+
+fn content_groups_match(
+    groups: &[(&[CssPathSelector], bool)],
+    node: &HtmlCascadeInfo,
+    ancestors: &[HtmlCascadeInfo],
+) -> bool {
+    let ((selectors, is_direct_child), parent_groups) = match groups.split_last() {
+        Some(s) => s,
+        None => return true,
+    };
+
+    if !content_group_matches(selectors, node) {
+        return false;
+    }
+
+    if parent_groups.is_empty() {
+        return true;
+    }
+
+    if *is_direct_child {
+        match ancestors.split_first() {
+            Some((parent, rest)) => content_groups_match(parent_groups, parent, rest),
+            None => false,
+        }
+    } else {
+        (0..ancestors.len())
+            .any(|i| content_groups_match(parent_groups, &ancestors[i], &ancestors[(i + 1)..]))
+    }
+}
+
+fn content_group_matches(selectors: &[CssPathSelector], node: &HtmlCascadeInfo) -> bool {
+    use self::CssPathSelector::*;
+
+    selectors.iter().all(|selector| match selector {
+        Global => true,
+        Type(t) => node.node_type == *t,
+        Class(c) => node.classes.iter().any(|class| class == c),
+        Id(id) => node.ids.iter().any(|html_id| html_id == id),
+        PseudoSelector(p) => match p {
+            CssPathPseudoSelector::First => node.index_in_parent == 0,
+            CssPathPseudoSelector::Last => node.is_last_child,
+            CssPathPseudoSelector::NthChild(x) => x.matches(node.index_in_parent + 1),
+            CssPathPseudoSelector::Hover => node.is_hovered,
+            CssPathPseudoSelector::Active => node.is_active,
+            CssPathPseudoSelector::Focus => node.is_focused,
+        },
+        DirectChildren | Children => false,
+    })
 }
 
 #[test]
@@ -676,7 +1576,7 @@ fn test_specificity_sort() {
     use crate::NodeTypeTag::*;
     use alloc::string::ToString;
 
-    let input_style = Stylesheet {
+    let mut input_style = Stylesheet {
         rules: vec![
             // Rules are sorted from lowest-specificity to highest specificity
             CssRuleBlock {
@@ -684,6 +1584,7 @@ fn test_specificity_sort() {
                     selectors: vec![Global].into(),
                 },
                 declarations: Vec::new().into(),
+                media: OptionMediaQuery::None,
             },
             CssRuleBlock {
                 path: CssPath {
@@ -696,18 +1597,21 @@ fn test_specificity_sort() {
                     .into(),
                 },
                 declarations: Vec::new().into(),
+                media: OptionMediaQuery::None,
             },
             CssRuleBlock {
                 path: CssPath {
                     selectors: vec![Global, Type(Div), Id("my_id".to_string().into())].into(),
                 },
                 declarations: Vec::new().into(),
+                media: OptionMediaQuery::None,
             },
             CssRuleBlock {
                 path: CssPath {
                     selectors: vec![Global, Id("my_id".to_string().into())].into(),
                 },
                 declarations: Vec::new().into(),
+                media: OptionMediaQuery::None,
             },
             CssRuleBlock {
                 path: CssPath {
@@ -720,11 +1624,12 @@ fn test_specificity_sort() {
                     .into(),
                 },
                 declarations: Vec::new().into(),
+                media: OptionMediaQuery::None,
             },
         ]
         .into(),
-    }
-    .sort_by_specificity();
+    };
+    input_style.sort_by_specificity();
 
     let expected_style = Stylesheet {
         rules: vec![
@@ -734,18 +1639,21 @@ fn test_specificity_sort() {
                     selectors: vec![Global].into(),
                 },
                 declarations: Vec::new().into(),
+                media: OptionMediaQuery::None,
             },
             CssRuleBlock {
                 path: CssPath {
                     selectors: vec![Global, Id("my_id".to_string().into())].into(),
                 },
                 declarations: Vec::new().into(),
+                media: OptionMediaQuery::None,
             },
             CssRuleBlock {
                 path: CssPath {
                     selectors: vec![Global, Type(Div), Id("my_id".to_string().into())].into(),
                 },
                 declarations: Vec::new().into(),
+                media: OptionMediaQuery::None,
             },
             CssRuleBlock {
                 path: CssPath {
@@ -758,6 +1666,7 @@ fn test_specificity_sort() {
                     .into(),
                 },
                 declarations: Vec::new().into(),
+                media: OptionMediaQuery::None,
             },
             CssRuleBlock {
                 path: CssPath {
@@ -770,6 +1679,7 @@ fn test_specificity_sort() {
                     .into(),
                 },
                 declarations: Vec::new().into(),
+                media: OptionMediaQuery::None,
             },
         ]
         .into(),
@@ -777,3 +1687,323 @@ fn test_specificity_sort() {
 
     assert_eq!(input_style, expected_style);
 }
+
+#[test]
+fn test_css_path_specificity() {
+    use self::CssPathSelector::*;
+    use alloc::string::ToString;
+
+    assert_eq!(CssPath::new(vec![Global]).specificity(), (0, 0, 0));
+    assert_eq!(
+        CssPath::new(vec![
+            Type(NodeTypeTag::Div),
+            Children,
+            Class("a".to_string().into()),
+            PseudoSelector(CssPathPseudoSelector::Hover),
+            DirectChildren,
+            Id("b".to_string().into()),
+        ])
+        .specificity(),
+        (1, 2, 1)
+    );
+}
+
+#[test]
+fn test_nth_child_selector_matches() {
+    use self::CssNthChildSelector::*;
+
+    let matching = |s: CssNthChildSelector| (1..=8).filter(|i| s.matches(*i)).collect::<Vec<_>>();
+
+    assert_eq!(matching(Number(3)), vec![3]);
+    assert_eq!(matching(Even), vec![2, 4, 6, 8]);
+    assert_eq!(matching(Odd), vec![1, 3, 5, 7]);
+    // 2n+1
+    assert_eq!(
+        matching(Pattern(CssNthChildPattern {
+            repeat: 2,
+            offset: 1
+        })),
+        vec![1, 3, 5, 7]
+    );
+    // 3n+2
+    assert_eq!(
+        matching(Pattern(CssNthChildPattern {
+            repeat: 3,
+            offset: 2
+        })),
+        vec![2, 5, 8]
+    );
+    // 0n+4
+    assert_eq!(
+        matching(Pattern(CssNthChildPattern {
+            repeat: 0,
+            offset: 4
+        })),
+        vec![4]
+    );
+}
+
+#[test]
+fn test_matches_html_element() {
+    use self::CssPathSelector::*;
+    use self::NodeTypeTag::*;
+    use alloc::string::ToString;
+
+    let main_id: [AzString; 1] = ["main".to_string().into()];
+    let item_class: [AzString; 1] = ["item".to_string().into()];
+
+    let body = HtmlCascadeInfo::new(Body);
+    let main = HtmlCascadeInfo {
+        ids: &main_id,
+        ..HtmlCascadeInfo::new(Div)
+    };
+    let wrapper = HtmlCascadeInfo::new(Div);
+    let item = HtmlCascadeInfo {
+        classes: &item_class,
+        index_in_parent: 2,
+        is_last_child: true,
+        ..HtmlCascadeInfo::new(P)
+    };
+
+    // item is "body > #main > div > p.item"
+    let ancestors = [wrapper, main, body];
+    let matches = |selectors: Vec<CssPathSelector>, node: &HtmlCascadeInfo| {
+        matches_html_element(&CssPath::new(selectors), node, &ancestors)
+    };
+    let class = |c: &str| Class(c.to_string().into());
+    let id = |i: &str| Id(i.to_string().into());
+
+    assert!(matches(vec![Global], &item));
+    assert!(matches(vec![Type(P)], &item));
+    assert!(!matches(vec![Type(Div)], &item));
+    assert!(matches(vec![Type(P), class("item")], &item));
+    assert!(!matches(vec![Type(P), class("other")], &item));
+    assert!(!matches(vec![id("main")], &item));
+    assert!(!matches(vec![], &item));
+
+    // descendant combinator
+    assert!(matches(vec![id("main"), Children, class("item")], &item));
+    assert!(matches(vec![Type(Body), Children, Type(P)], &item));
+    assert!(matches(
+        vec![Type(Body), Children, id("main"), Children, Type(P)],
+        &item
+    ));
+    assert!(!matches(vec![Type(Img), Children, Type(P)], &item));
+    assert!(!matches(vec![Type(P), Children, id("main")], &item));
+
+    // child combinator
+    assert!(matches(vec![Type(Div), DirectChildren, Type(P)], &item));
+    assert!(!matches(vec![id("main"), DirectChildren, Type(P)], &item));
+    assert!(matches(
+        vec![
+            id("main"),
+            DirectChildren,
+            Type(Div),
+            DirectChildren,
+            Type(P)
+        ],
+        &item
+    ));
+    assert!(matches(
+        vec![Type(Body), DirectChildren, Type(Div), Children, Type(P)],
+        &item
+    ));
+    // "div div > p" has to backtrack: the first "div" ancestor is the direct parent
+    assert!(matches(
+        vec![Type(Div), Children, Type(Div), DirectChildren, Type(P)],
+        &item
+    ));
+    assert!(!matches(vec![Type(Body), DirectChildren, Type(P)], &item));
+    assert!(!matches_html_element(
+        &CssPath::new(vec![Type(Div), DirectChildren, Type(Body)]),
+        &body,
+        &[]
+    ));
+
+    // structural pseudo selectors, item is the third and last child
+    let pseudo = |p: CssPathPseudoSelector| vec![Type(P), PseudoSelector(p)];
+    assert!(matches(pseudo(CssPathPseudoSelector::Last), &item));
+    assert!(!matches(pseudo(CssPathPseudoSelector::First), &item));
+    assert!(matches(
+        vec![Type(Div), PseudoSelector(CssPathPseudoSelector::First)],
+        &main
+    ));
+    let nth = |n: CssNthChildSelector| pseudo(CssPathPseudoSelector::NthChild(n));
+    assert!(matches(nth(CssNthChildSelector::Number(3)), &item));
+    assert!(!matches(nth(CssNthChildSelector::Number(2)), &item));
+    assert!(matches(nth(CssNthChildSelector::Odd), &item));
+    assert!(!matches(nth(CssNthChildSelector::Even), &item));
+    let pattern =
+        |repeat, offset| CssNthChildSelector::Pattern(CssNthChildPattern { repeat, offset });
+    assert!(matches(nth(pattern(2, 1)), &item));
+    assert!(!matches(nth(pattern(2, 0)), &item));
+    assert!(matches(nth(pattern(3, 0)), &item));
+
+    // dynamic pseudo selectors
+    let hover = vec![Type(P), PseudoSelector(CssPathPseudoSelector::Hover)];
+    assert!(!matches(hover.clone(), &item));
+    assert!(matches(
+        hover,
+        &HtmlCascadeInfo {
+            is_hovered: true,
+            ..item
+        }
+    ));
+    let active = vec![Type(P), PseudoSelector(CssPathPseudoSelector::Active)];
+    assert!(!matches(
+        active.clone(),
+        &HtmlCascadeInfo {
+            is_hovered: true,
+            ..item
+        }
+    ));
+    assert!(matches(
+        active,
+        &HtmlCascadeInfo {
+            is_active: true,
+            ..item
+        }
+    ));
+    let focus = vec![
+        id("main"),
+        PseudoSelector(CssPathPseudoSelector::Focus),
+        Children,
+        Type(P),
+    ];
+    assert!(!matches(focus.clone(), &item));
+    let focused_ancestors = [
+        wrapper,
+        HtmlCascadeInfo {
+            is_focused: true,
+            ..main
+        },
+        body,
+    ];
+    assert!(matches_html_element(
+        &CssPath::new(focus),
+        &item,
+        &focused_ancestors
+    ));
+}
+
+#[test]
+fn test_css_variables() {
+    use crate::css_properties::{ColorU, StyleTextColor};
+
+    let red = CssProperty::text_color(StyleTextColor { inner: ColorU::RED });
+    let blue = CssProperty::text_color(StyleTextColor { inner: ColorU::BLUE });
+    let width = CssProperty::width(crate::LayoutWidth::const_px(10));
+
+    let mut css = Css::empty();
+    css.set_variable(AzString::from_const_str("--fg"), red.clone());
+    css.set_variable(AzString::from_const_str("bg"), width.clone());
+    assert_eq!(css.get_variable("fg"), Some(&red));
+    css.set_variable(AzString::from_const_str("fg"), blue.clone());
+    assert_eq!(css.get_variable("--fg"), Some(&blue));
+    assert_eq!(css.variables.len(), 2);
+
+    let fg = DynamicCssProperty {
+        dynamic_id: AzString::from_const_str("fg"),
+        default_value: red.clone(),
+    };
+    assert_eq!(fg.get_value(&css.variables), &blue);
+
+    // unset variable or variable of another type: default value
+    let bg = DynamicCssProperty {
+        dynamic_id: AzString::from_const_str("bg"),
+        default_value: red.clone(),
+    };
+    assert_eq!(bg.get_value(&css.variables), &red);
+    let unset = DynamicCssProperty {
+        dynamic_id: AzString::from_const_str("unset"),
+        default_value: red.clone(),
+    };
+    assert_eq!(unset.get_value(&css.variables), &red);
+
+    let declarations = vec![
+        CssDeclaration::Dynamic(fg),
+        CssDeclaration::Dynamic(unset),
+        CssDeclaration::Static(width.clone()),
+    ];
+    css.stylesheets = vec![Stylesheet {
+        rules: vec![CssRuleBlock {
+            path: CssPath { selectors: Vec::new().into() },
+            declarations: declarations.into(),
+            media: None.into(),
+        }]
+        .into(),
+    }]
+    .into();
+    css.resolve_variables();
+    assert_eq!(
+        css.stylesheets.as_ref()[0].rules.as_ref()[0].declarations.as_ref(),
+        &[
+            CssDeclaration::Static(blue),
+            CssDeclaration::Static(red),
+            CssDeclaration::Static(width),
+        ][..]
+    );
+}
+
+#[test]
+fn test_media_query_evaluate() {
+    use self::MediaFeature::*;
+
+    let max_600 = MediaQuery::new(vec![MaxWidth(PixelValue::px(600.0))]);
+    let env = |width, height| MediaEnvironment {
+        width,
+        height,
+        ..Default::default()
+    };
+    assert!(max_600.evaluate(&env(599.0, 400.0)));
+    assert!(max_600.evaluate(&env(600.0, 400.0)));
+    assert!(!max_600.evaluate(&env(601.0, 400.0)));
+
+    // 40em = 640px
+    let min_40em = MediaQuery::new(vec![MinWidth(PixelValue::em(40.0))]);
+    assert!(!min_40em.evaluate(&env(639.0, 400.0)));
+    assert!(min_40em.evaluate(&env(640.0, 400.0)));
+
+    let portrait_dark = MediaQuery::new(vec![
+        Orientation(MediaOrientation::Portrait),
+        PrefersColorScheme(MediaColorScheme::Dark),
+    ]);
+    let mut phone = env(400.0, 800.0);
+    assert!(!portrait_dark.evaluate(&phone));
+    phone.dark_mode = true;
+    assert!(portrait_dark.evaluate(&phone));
+    assert!(!portrait_dark.evaluate(&MediaEnvironment {
+        dark_mode: true,
+        ..env(800.0, 400.0)
+    }));
+
+    let hidpi = MediaQuery::new(vec![MinResolution(FloatValue::new(2.0))]);
+    assert!(!hidpi.evaluate(&MediaEnvironment::default()));
+    assert!(hidpi.evaluate(&MediaEnvironment {
+        dpi: 192.0,
+        ..Default::default()
+    }));
+
+    assert!(MediaQuery::new(Vec::new()).evaluate(&phone));
+    assert_eq!(
+        portrait_dark.to_string(),
+        "(orientation: portrait) and (prefers-color-scheme: dark)"
+    );
+
+    // (width < 600px), (prefers-color-scheme: dark)
+    let narrow_or_dark = MediaQuery::any(vec![
+        MediaCondition::new(vec![WidthLessThan(PixelValue::px(600.0))]),
+        MediaCondition::new(vec![PrefersColorScheme(MediaColorScheme::Dark)]),
+    ]);
+    assert!(narrow_or_dark.evaluate(&env(599.0, 400.0)));
+    assert!(!narrow_or_dark.evaluate(&env(600.0, 400.0)));
+    assert!(narrow_or_dark.evaluate(&MediaEnvironment {
+        dark_mode: true,
+        ..env(600.0, 400.0)
+    }));
+    assert!(!MediaQuery::any(Vec::new()).evaluate(&phone));
+    assert_eq!(
+        narrow_or_dark.to_string(),
+        "(width < 600px), (prefers-color-scheme: dark)"
+    );
+}
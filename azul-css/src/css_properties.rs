@@ -1,6 +1,6 @@
 //! Provides a public API with datatypes used to describe style properties of DOM nodes.
 
-use crate::css::CssPropertyValue;
+use crate::css::{BoxOrStatic, CssBoxed, CssLengthValue, CssPropertyValue, PrintAsCssValue};
 use crate::{AzString, OptionI16, OptionU16, OptionU32, U8Vec};
 use alloc::boxed::Box;
 use alloc::collections::btree_map::BTreeMap;
@@ -14,9 +14,12 @@ use core::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 
 /// Currently hard-coded: Height of one em in pixels
 pub const EM_HEIGHT: f32 = 16.0;
-pub const PT_TO_PX: f32 = 96.0 / 72.0;
+/// DPI of the logical coordinate system: one CSS `px` is 1/96 of an inch
+pub const DEFAULT_DPI: f32 = 96.0;
+/// One `pt` is 1/72 of an inch, so at the default DPI 1pt = 1.333px
+pub const PT_TO_PX: f32 = DEFAULT_DPI / 72.0;
 
-const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str); 12] = [
+const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str); 13] = [
     (CombinedCssPropertyType::BorderRadius, "border-radius"),
     (CombinedCssPropertyType::Overflow, "overflow"),
     (CombinedCssPropertyType::Padding, "padding"),
@@ -29,10 +32,11 @@ const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str);
     (CombinedCssPropertyType::BoxShadow, "box-shadow"),
     (CombinedCssPropertyType::BackgroundColor, "background-color"),
     (CombinedCssPropertyType::BackgroundImage, "background-image"),
+    (CombinedCssPropertyType::Transition, "transition"),
 ];
 
 /// Map between CSS keys and a statically typed enum
-const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 74] = [
+const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 89] = [
     (CssPropertyType::Display, "display"),
     (CssPropertyType::Float, "float"),
     (CssPropertyType::BoxSizing, "box-sizing"),
@@ -40,6 +44,8 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 74] = [
     (CssPropertyType::FontSize, "font-size"),
     (CssPropertyType::FontFamily, "font-family"),
     (CssPropertyType::TextAlign, "text-align"),
+    (CssPropertyType::VerticalAlign, "vertical-align"),
+    (CssPropertyType::Direction, "direction"),
     (CssPropertyType::LetterSpacing, "letter-spacing"),
     (CssPropertyType::LineHeight, "line-height"),
     (CssPropertyType::WordSpacing, "word-spacing"),
@@ -63,6 +69,13 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 74] = [
     (CssPropertyType::JustifyContent, "justify-content"),
     (CssPropertyType::AlignItems, "align-items"),
     (CssPropertyType::AlignContent, "align-content"),
+    (
+        CssPropertyType::GridTemplateColumns,
+        "grid-template-columns",
+    ),
+    (CssPropertyType::GridTemplateRows, "grid-template-rows"),
+    (CssPropertyType::GridColumn, "grid-column"),
+    (CssPropertyType::GridRow, "grid-row"),
     (CssPropertyType::OverflowX, "overflow-x"),
     (CssPropertyType::OverflowY, "overflow-y"),
     (CssPropertyType::PaddingTop, "padding-top"),
@@ -116,11 +129,32 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str); 74] = [
     (CssPropertyType::TransformOrigin, "transform-origin"),
     (CssPropertyType::BackfaceVisibility, "backface-visibility"),
     (CssPropertyType::MixBlendMode, "mix-blend-mode"),
+    (CssPropertyType::Isolation, "isolation"),
+    (CssPropertyType::ImageRendering, "image-rendering"),
     (CssPropertyType::Filter, "filter"),
     (CssPropertyType::BackdropFilter, "backdrop-filter"),
     (CssPropertyType::TextShadow, "text-shadow"),
+    (CssPropertyType::TransitionProperty, "transition-property"),
+    (CssPropertyType::TransitionDuration, "transition-duration"),
+    (CssPropertyType::TransitionTimingFunction, "transition-timing-function"),
+    (CssPropertyType::TransitionDelay, "transition-delay"),
+    (CssPropertyType::CaretColor, "caret-color"),
+    (CssPropertyType::SelectionColor, "-azul-selection-color"),
+    (CssPropertyType::SelectionBackground, "-azul-selection-background-color"),
 ];
 
+/// List of all `CssPropertyType`s, derived from the `CSS_PROPERTY_KEY_MAP`
+/// so that the two can't get out of sync
+const CSS_PROPERTY_TYPES: [CssPropertyType; CSS_PROPERTY_KEY_MAP.len()] = {
+    let mut types = [CssPropertyType::TextColor; CSS_PROPERTY_KEY_MAP.len()];
+    let mut i = 0;
+    while i < CSS_PROPERTY_KEY_MAP.len() {
+        types[i] = CSS_PROPERTY_KEY_MAP[i].0;
+        i += 1;
+    }
+    types
+};
+
 // The following types are present in webrender, however, azul-css should not
 // depend on webrender, just to have the same types, azul-css should be a standalone crate.
 
@@ -260,6 +294,94 @@ impl LayoutRect {
         Self::union([*self, children_union].iter().map(|r| *r))
     }
 
+    /// Converts the (logical) rect into physical pixels.
+    ///
+    /// The edges are rounded instead of the origin and size, so that two rects
+    /// sharing an edge in logical pixels also share it in physical pixels.
+    /// Rounding the size separately would leave one-pixel seams or overlaps
+    /// between adjacent rects at fractional HiDPI factors such as 1.5.
+    #[inline]
+    pub fn to_physical(&self, hidpi_factor: f32) -> Self {
+        let round = |v: isize| DipPixel(v as f32).to_physical(hidpi_factor).round();
+        let min_x = round(self.min_x());
+        let min_y = round(self.min_y());
+        let max_x = round(self.max_x());
+        let max_y = round(self.max_y());
+        Self::new(
+            LayoutPoint::new(min_x, min_y),
+            LayoutSize::new(max_x - min_x, max_y - min_y),
+        )
+    }
+
+    /// Multiplies the origin and size with the `factor` (i.e. the HiDPI factor)
+    #[inline]
+    pub fn scale_to_f32(&self, factor: f32) -> LayoutRectF32 {
+        LayoutRectF32::new(
+            self.origin.x as f32 * factor,
+            self.origin.y as f32 * factor,
+            self.size.width as f32 * factor,
+            self.size.height as f32 * factor,
+        )
+    }
+
+    /// Same as `scale_to_f32`, but rounds the scaled origin and size to integers.
+    ///
+    /// NOTE: Origin and size are rounded separately, use `to_physical` if
+    /// adjacent rects have to stay adjacent after scaling.
+    #[inline]
+    pub fn scale_rounded(&self, factor: f32) -> Self {
+        let scaled = self.scale_to_f32(factor);
+        Self::new(
+            LayoutPoint::new(libm::roundf(scaled.x) as isize, libm::roundf(scaled.y) as isize),
+            LayoutSize::round(scaled.width, scaled.height),
+        )
+    }
+
+    /// Splits the rect into a left and a right part, `at` is the width of the
+    /// left part (clamped to the width of the rect)
+    #[inline]
+    pub fn split_horizontal(&self, at: isize) -> (Self, Self) {
+        let at = at.max(0).min(self.size.width.max(0));
+        (
+            Self::new(self.origin, LayoutSize::new(at, self.size.height)),
+            Self::new(
+                LayoutPoint::new(self.origin.x + at, self.origin.y),
+                LayoutSize::new(self.size.width - at, self.size.height),
+            ),
+        )
+    }
+
+    /// Splits the rect into a top and a bottom part, `at` is the height of the
+    /// top part (clamped to the height of the rect)
+    #[inline]
+    pub fn split_vertical(&self, at: isize) -> (Self, Self) {
+        let at = at.max(0).min(self.size.height.max(0));
+        (
+            Self::new(self.origin, LayoutSize::new(self.size.width, at)),
+            Self::new(
+                LayoutPoint::new(self.origin.x, self.origin.y + at),
+                LayoutSize::new(self.size.width, self.size.height - at),
+            ),
+        )
+    }
+
+    /// Shrinks the rect by the (rounded) offsets on each side, i.e. to get
+    /// the content rect from the padding. The size is clamped to zero.
+    #[inline]
+    pub fn inset(&self, offsets: LayoutSideOffsets) -> Self {
+        let top = libm::roundf(offsets.top.get()) as isize;
+        let right = libm::roundf(offsets.right.get()) as isize;
+        let bottom = libm::roundf(offsets.bottom.get()) as isize;
+        let left = libm::roundf(offsets.left.get()) as isize;
+        Self::new(
+            LayoutPoint::new(self.origin.x + left, self.origin.y + top),
+            LayoutSize::new(
+                (self.size.width - left - right).max(0),
+                (self.size.height - top - bottom).max(0),
+            ),
+        )
+    }
+
     // Returns if b overlaps a
     #[inline(always)]
     pub const fn contains_rect(&self, b: &LayoutRect) -> bool {
@@ -282,6 +404,138 @@ impl LayoutRect {
     }
 }
 
+/// Device-independent (logical) pixel, the unit of all layout calculations.
+/// One `DipPixel` covers `hidpi_factor` physical pixels on the screen.
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct DipPixel(pub f32);
+
+/// Physical (device) pixel, the unit of the framebuffer and of the OS window APIs
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct PhysicalPixel(pub f32);
+
+impl DipPixel {
+    #[inline]
+    pub fn to_physical(self, hidpi_factor: f32) -> PhysicalPixel {
+        PhysicalPixel(self.0 * hidpi_factor)
+    }
+}
+
+impl PhysicalPixel {
+    #[inline]
+    pub fn to_logical(self, hidpi_factor: f32) -> DipPixel {
+        DipPixel(self.0 / hidpi_factor)
+    }
+
+    /// Rounds to the nearest whole device pixel
+    #[inline]
+    pub fn round(self) -> isize {
+        libm::roundf(self.0) as isize
+    }
+}
+
+/// Rectangle (x, y, width, height) in layout space, before rounding
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct LayoutRectF32 {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl LayoutRectF32 {
+    #[inline(always)]
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self { x, y, width, height }
+    }
+    #[inline(always)]
+    pub fn max_x(&self) -> f32 {
+        self.x + self.width
+    }
+    #[inline(always)]
+    pub fn max_y(&self) -> f32 {
+        self.y + self.height
+    }
+}
+
+/// How `PixelSnap` moves a coordinate onto the device pixel grid
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum SnapPolicy {
+    Round,
+    Floor,
+    Ceil,
+    /// Don't snap, keep subpixel coordinates
+    None,
+}
+
+impl Default for SnapPolicy {
+    fn default() -> Self {
+        SnapPolicy::Round
+    }
+}
+
+impl SnapPolicy {
+    #[inline]
+    fn apply(&self, device_px: f32) -> f32 {
+        match self {
+            SnapPolicy::Round => libm::roundf(device_px),
+            SnapPolicy::Floor => libm::floorf(device_px),
+            SnapPolicy::Ceil => libm::ceilf(device_px),
+            SnapPolicy::None => device_px,
+        }
+    }
+}
+
+/// Snaps logical coordinates to whole device pixels.
+///
+/// Only the edges of a rect are snapped, the size is derived from the snapped
+/// edges: two rects that share an edge before snapping also share it
+/// afterwards, so there are no seams or overlaps at fractional DPI factors.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PixelSnap;
+
+impl PixelSnap {
+    /// Snaps all four edges of the (logical) `rect` to the device pixel grid
+    /// of `dpi` and returns the rect in logical units
+    pub fn snap_rect(rect: LayoutRectF32, dpi: f32, policy: SnapPolicy) -> LayoutRectF32 {
+        if policy == SnapPolicy::None || dpi <= 0.0 {
+            return rect;
+        }
+        let snap = |v: f32| policy.apply(v * dpi) / dpi;
+        let min_x = snap(rect.x);
+        let min_y = snap(rect.y);
+        let max_x = snap(rect.max_x());
+        let max_y = snap(rect.max_y());
+        LayoutRectF32::new(min_x, min_y, max_x - min_x, max_y - min_y)
+    }
+
+    /// Rounds the border widths to whole device pixels. Borders that would
+    /// round down to zero are kept at one device pixel, so that hairline
+    /// borders stay visible.
+    pub fn snap_border_widths(widths: &LayoutSideOffsets, dpi: f32) -> LayoutSideOffsets {
+        if dpi <= 0.0 {
+            return *widths;
+        }
+        let snap = |w: FloatValue| {
+            let w = w.get();
+            if w <= 0.0 {
+                0.0
+            } else {
+                libm::roundf(w * dpi).max(1.0) / dpi
+            }
+        };
+        LayoutSideOffsets::new(
+            snap(widths.top),
+            snap(widths.right),
+            snap(widths.bottom),
+            snap(widths.left),
+        )
+    }
+}
+
 /// Only used for calculations: Size (width, height) in layout space.
 #[derive(Copy, Default, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
 #[repr(C)]
@@ -390,6 +644,128 @@ pub struct LayoutSideOffsets {
     pub left: FloatValue,
 }
 
+impl LayoutSideOffsets {
+    #[inline]
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self {
+            top: FloatValue::new(top),
+            right: FloatValue::new(right),
+            bottom: FloatValue::new(bottom),
+            left: FloatValue::new(left),
+        }
+    }
+
+    #[inline]
+    pub const fn zero() -> Self {
+        Self {
+            top: FloatValue::const_new(0),
+            right: FloatValue::const_new(0),
+            bottom: FloatValue::const_new(0),
+            left: FloatValue::const_new(0),
+        }
+    }
+
+    /// Same offset on all four sides
+    #[inline]
+    pub fn uniform(value: f32) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    /// Sum of the left and right offsets
+    #[inline]
+    pub fn horizontal(&self) -> f32 {
+        self.left.get() + self.right.get()
+    }
+
+    /// Sum of the top and bottom offsets
+    #[inline]
+    pub fn vertical(&self) -> f32 {
+        self.top.get() + self.bottom.get()
+    }
+
+    /// Resolves the four `padding-*` properties to pixels, percentages are
+    /// resolved against the `percent_basis`, `em` against the `em_size`
+    pub fn from_padding(
+        top: &LayoutPaddingTop,
+        right: &LayoutPaddingRight,
+        bottom: &LayoutPaddingBottom,
+        left: &LayoutPaddingLeft,
+        percent_basis: f32,
+        em_size: f32,
+    ) -> Self {
+        Self::new(
+            top.inner.to_pixels_em_aware(percent_basis, em_size),
+            right.inner.to_pixels_em_aware(percent_basis, em_size),
+            bottom.inner.to_pixels_em_aware(percent_basis, em_size),
+            left.inner.to_pixels_em_aware(percent_basis, em_size),
+        )
+    }
+
+    /// Resolves the four `margin-*` properties to pixels, percentages are
+    /// resolved against the `percent_basis`, `em` against the `em_size`
+    pub fn from_margins(
+        top: &LayoutMarginTop,
+        right: &LayoutMarginRight,
+        bottom: &LayoutMarginBottom,
+        left: &LayoutMarginLeft,
+        percent_basis: f32,
+        em_size: f32,
+    ) -> Self {
+        Self::new(
+            top.inner.to_pixels_em_aware(percent_basis, em_size),
+            right.inner.to_pixels_em_aware(percent_basis, em_size),
+            bottom.inner.to_pixels_em_aware(percent_basis, em_size),
+            left.inner.to_pixels_em_aware(percent_basis, em_size),
+        )
+    }
+}
+
+impl Default for LayoutSideOffsets {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl core::ops::Add for LayoutSideOffsets {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self {
+            top: FloatValue {
+                number: self.top.number + other.top.number,
+            },
+            right: FloatValue {
+                number: self.right.number + other.right.number,
+            },
+            bottom: FloatValue {
+                number: self.bottom.number + other.bottom.number,
+            },
+            left: FloatValue {
+                number: self.left.number + other.left.number,
+            },
+        }
+    }
+}
+
+impl core::ops::Sub for LayoutSideOffsets {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self {
+            top: FloatValue {
+                number: self.top.number - other.top.number,
+            },
+            right: FloatValue {
+                number: self.right.number - other.right.number,
+            },
+            bottom: FloatValue {
+                number: self.bottom.number - other.bottom.number,
+            },
+            left: FloatValue {
+                number: self.left.number - other.left.number,
+            },
+        }
+    }
+}
+
 /// u8-based color, range 0 to 255 (similar to webrenders ColorU)
 #[derive(Debug, Copy, Clone, PartialEq, Ord, PartialOrd, Eq, Hash)]
 #[repr(C)]
@@ -419,6 +795,49 @@ impl fmt::Display for ColorU {
     }
 }
 
+// (255, 0, 0) => (0.0, 1.0, 0.5), hue in degrees, saturation and lightness in 0.0 - 1.0
+fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let delta = max - min;
+
+    if delta == 0.0 {
+        return (0.0, 0.0, l);
+    }
+
+    let s = delta / (1.0 - libm::fabsf(2.0 * l - 1.0));
+    let h = if max == r {
+        60.0 * libm::fmodf((g - b) / delta, 6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    (if h < 0.0 { h + 360.0 } else { h }, s, l)
+}
+
+// inverse of rgb_to_hsl, expects the hue to be in the 0.0 - 360.0 range
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (u8, u8, u8) {
+    let c = (1.0 - libm::fabsf(2.0 * l - 1.0)) * s;
+    let x = c * (1.0 - libm::fabsf(libm::fmodf(h / 60.0, 2.0) - 1.0));
+    let m = l - c / 2.0;
+
+    let (r, g, b) = match (h / 60.0) as usize {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    let to_u8 = |v: f32| libm::roundf((v + m) * 255.0).max(0.0).min(255.0) as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
 impl ColorU {
     pub const ALPHA_TRANSPARENT: u8 = 0;
     pub const ALPHA_OPAQUE: u8 = 255;
@@ -464,6 +883,206 @@ impl ColorU {
         Self { r, g, b, a: 255 }
     }
 
+    /// Parses a hex color with or without the leading hash, i.e. `"#f00"`,
+    /// `"#ff000080"` or `"00ff00"`. Accepts 3, 4, 6 or 8 hex digits.
+    pub fn from_hex(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let input = input.strip_prefix('#').unwrap_or(input);
+
+        let digits = input
+            .chars()
+            .map(|c| c.to_digit(16).map(|d| d as u8))
+            .collect::<Option<Vec<u8>>>()?;
+
+        match digits.as_slice() {
+            [r, g, b] => Some(Self::new_rgb(r * 17, g * 17, b * 17)),
+            [r, g, b, a] => Some(Self {
+                r: r * 17,
+                g: g * 17,
+                b: b * 17,
+                a: a * 17,
+            }),
+            [r1, r2, g1, g2, b1, b2] => {
+                Some(Self::new_rgb(r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2))
+            }
+            [r1, r2, g1, g2, b1, b2, a1, a2] => Some(Self {
+                r: r1 * 16 + r2,
+                g: g1 * 16 + g2,
+                b: b1 * 16 + b2,
+                a: a1 * 16 + a2,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Parses a hex color starting with `#` or a named CSS color,
+    /// i.e. `"#ff0000"` or `"red"`
+    pub fn from_str(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if input.starts_with('#') {
+            Self::from_hex(input)
+        } else {
+            Self::from_named(input)
+        }
+    }
+
+    /// Parses a named CSS color, i.e. `"red"` or `"CornflowerBlue"`
+    pub fn from_named(input: &str) -> Option<Self> {
+        let (r, g, b, a) = match input.trim() {
+            "AliceBlue" | "aliceblue" => (240, 248, 255, 255),
+            "AntiqueWhite" | "antiquewhite" => (250, 235, 215, 255),
+            "Aqua" | "aqua" => (0, 255, 255, 255),
+            "Aquamarine" | "aquamarine" => (127, 255, 212, 255),
+            "Azure" | "azure" => (240, 255, 255, 255),
+            "Beige" | "beige" => (245, 245, 220, 255),
+            "Bisque" | "bisque" => (255, 228, 196, 255),
+            "Black" | "black" => (0, 0, 0, 255),
+            "BlanchedAlmond" | "blanchedalmond" => (255, 235, 205, 255),
+            "Blue" | "blue" => (0, 0, 255, 255),
+            "BlueViolet" | "blueviolet" => (138, 43, 226, 255),
+            "Brown" | "brown" => (165, 42, 42, 255),
+            "BurlyWood" | "burlywood" => (222, 184, 135, 255),
+            "CadetBlue" | "cadetblue" => (95, 158, 160, 255),
+            "Chartreuse" | "chartreuse" => (127, 255, 0, 255),
+            "Chocolate" | "chocolate" => (210, 105, 30, 255),
+            "Coral" | "coral" => (255, 127, 80, 255),
+            "CornflowerBlue" | "cornflowerblue" => (100, 149, 237, 255),
+            "Cornsilk" | "cornsilk" => (255, 248, 220, 255),
+            "Crimson" | "crimson" => (220, 20, 60, 255),
+            "Cyan" | "cyan" => (0, 255, 255, 255),
+            "DarkBlue" | "darkblue" => (0, 0, 139, 255),
+            "DarkCyan" | "darkcyan" => (0, 139, 139, 255),
+            "DarkGoldenRod" | "darkgoldenrod" => (184, 134, 11, 255),
+            "DarkGray" | "darkgray" => (169, 169, 169, 255),
+            "DarkGrey" | "darkgrey" => (169, 169, 169, 255),
+            "DarkGreen" | "darkgreen" => (0, 100, 0, 255),
+            "DarkKhaki" | "darkkhaki" => (189, 183, 107, 255),
+            "DarkMagenta" | "darkmagenta" => (139, 0, 139, 255),
+            "DarkOliveGreen" | "darkolivegreen" => (85, 107, 47, 255),
+            "DarkOrange" | "darkorange" => (255, 140, 0, 255),
+            "DarkOrchid" | "darkorchid" => (153, 50, 204, 255),
+            "DarkRed" | "darkred" => (139, 0, 0, 255),
+            "DarkSalmon" | "darksalmon" => (233, 150, 122, 255),
+            "DarkSeaGreen" | "darkseagreen" => (143, 188, 143, 255),
+            "DarkSlateBlue" | "darkslateblue" => (72, 61, 139, 255),
+            "DarkSlateGray" | "darkslategray" => (47, 79, 79, 255),
+            "DarkSlateGrey" | "darkslategrey" => (47, 79, 79, 255),
+            "DarkTurquoise" | "darkturquoise" => (0, 206, 209, 255),
+            "DarkViolet" | "darkviolet" => (148, 0, 211, 255),
+            "DeepPink" | "deeppink" => (255, 20, 147, 255),
+            "DeepSkyBlue" | "deepskyblue" => (0, 191, 255, 255),
+            "DimGray" | "dimgray" => (105, 105, 105, 255),
+            "DimGrey" | "dimgrey" => (105, 105, 105, 255),
+            "DodgerBlue" | "dodgerblue" => (30, 144, 255, 255),
+            "FireBrick" | "firebrick" => (178, 34, 34, 255),
+            "FloralWhite" | "floralwhite" => (255, 250, 240, 255),
+            "ForestGreen" | "forestgreen" => (34, 139, 34, 255),
+            "Fuchsia" | "fuchsia" => (255, 0, 255, 255),
+            "Gainsboro" | "gainsboro" => (220, 220, 220, 255),
+            "GhostWhite" | "ghostwhite" => (248, 248, 255, 255),
+            "Gold" | "gold" => (255, 215, 0, 255),
+            "GoldenRod" | "goldenrod" => (218, 165, 32, 255),
+            "Gray" | "gray" => (128, 128, 128, 255),
+            "Grey" | "grey" => (128, 128, 128, 255),
+            "Green" | "green" => (0, 128, 0, 255),
+            "GreenYellow" | "greenyellow" => (173, 255, 47, 255),
+            "HoneyDew" | "honeydew" => (240, 255, 240, 255),
+            "HotPink" | "hotpink" => (255, 105, 180, 255),
+            "IndianRed" | "indianred" => (205, 92, 92, 255),
+            "Indigo" | "indigo" => (75, 0, 130, 255),
+            "Ivory" | "ivory" => (255, 255, 240, 255),
+            "Khaki" | "khaki" => (240, 230, 140, 255),
+            "Lavender" | "lavender" => (230, 230, 250, 255),
+            "LavenderBlush" | "lavenderblush" => (255, 240, 245, 255),
+            "LawnGreen" | "lawngreen" => (124, 252, 0, 255),
+            "LemonChiffon" | "lemonchiffon" => (255, 250, 205, 255),
+            "LightBlue" | "lightblue" => (173, 216, 230, 255),
+            "LightCoral" | "lightcoral" => (240, 128, 128, 255),
+            "LightCyan" | "lightcyan" => (224, 255, 255, 255),
+            "LightGoldenRodYellow" | "lightgoldenrodyellow" => (250, 250, 210, 255),
+            "LightGray" | "lightgray" => (211, 211, 211, 255),
+            "LightGrey" | "lightgrey" => (144, 238, 144, 255),
+            "LightGreen" | "lightgreen" => (211, 211, 211, 255),
+            "LightPink" | "lightpink" => (255, 182, 193, 255),
+            "LightSalmon" | "lightsalmon" => (255, 160, 122, 255),
+            "LightSeaGreen" | "lightseagreen" => (32, 178, 170, 255),
+            "LightSkyBlue" | "lightskyblue" => (135, 206, 250, 255),
+            "LightSlateGray" | "lightslategray" => (119, 136, 153, 255),
+            "LightSlateGrey" | "lightslategrey" => (119, 136, 153, 255),
+            "LightSteelBlue" | "lightsteelblue" => (176, 196, 222, 255),
+            "LightYellow" | "lightyellow" => (255, 255, 224, 255),
+            "Lime" | "lime" => (0, 255, 0, 255),
+            "LimeGreen" | "limegreen" => (50, 205, 50, 255),
+            "Linen" | "linen" => (250, 240, 230, 255),
+            "Magenta" | "magenta" => (255, 0, 255, 255),
+            "Maroon" | "maroon" => (128, 0, 0, 255),
+            "MediumAquaMarine" | "mediumaquamarine" => (102, 205, 170, 255),
+            "MediumBlue" | "mediumblue" => (0, 0, 205, 255),
+            "MediumOrchid" | "mediumorchid" => (186, 85, 211, 255),
+            "MediumPurple" | "mediumpurple" => (147, 112, 219, 255),
+            "MediumSeaGreen" | "mediumseagreen" => (60, 179, 113, 255),
+            "MediumSlateBlue" | "mediumslateblue" => (123, 104, 238, 255),
+            "MediumSpringGreen" | "mediumspringgreen" => (0, 250, 154, 255),
+            "MediumTurquoise" | "mediumturquoise" => (72, 209, 204, 255),
+            "MediumVioletRed" | "mediumvioletred" => (199, 21, 133, 255),
+            "MidnightBlue" | "midnightblue" => (25, 25, 112, 255),
+            "MintCream" | "mintcream" => (245, 255, 250, 255),
+            "MistyRose" | "mistyrose" => (255, 228, 225, 255),
+            "Moccasin" | "moccasin" => (255, 228, 181, 255),
+            "NavajoWhite" | "navajowhite" => (255, 222, 173, 255),
+            "Navy" | "navy" => (0, 0, 128, 255),
+            "OldLace" | "oldlace" => (253, 245, 230, 255),
+            "Olive" | "olive" => (128, 128, 0, 255),
+            "OliveDrab" | "olivedrab" => (107, 142, 35, 255),
+            "Orange" | "orange" => (255, 165, 0, 255),
+            "OrangeRed" | "orangered" => (255, 69, 0, 255),
+            "Orchid" | "orchid" => (218, 112, 214, 255),
+            "PaleGoldenRod" | "palegoldenrod" => (238, 232, 170, 255),
+            "PaleGreen" | "palegreen" => (152, 251, 152, 255),
+            "PaleTurquoise" | "paleturquoise" => (175, 238, 238, 255),
+            "PaleVioletRed" | "palevioletred" => (219, 112, 147, 255),
+            "PapayaWhip" | "papayawhip" => (255, 239, 213, 255),
+            "PeachPuff" | "peachpuff" => (255, 218, 185, 255),
+            "Peru" | "peru" => (205, 133, 63, 255),
+            "Pink" | "pink" => (255, 192, 203, 255),
+            "Plum" | "plum" => (221, 160, 221, 255),
+            "PowderBlue" | "powderblue" => (176, 224, 230, 255),
+            "Purple" | "purple" => (128, 0, 128, 255),
+            "RebeccaPurple" | "rebeccapurple" => (102, 51, 153, 255),
+            "Red" | "red" => (255, 0, 0, 255),
+            "RosyBrown" | "rosybrown" => (188, 143, 143, 255),
+            "RoyalBlue" | "royalblue" => (65, 105, 225, 255),
+            "SaddleBrown" | "saddlebrown" => (139, 69, 19, 255),
+            "Salmon" | "salmon" => (250, 128, 114, 255),
+            "SandyBrown" | "sandybrown" => (244, 164, 96, 255),
+            "SeaGreen" | "seagreen" => (46, 139, 87, 255),
+            "SeaShell" | "seashell" => (255, 245, 238, 255),
+            "Sienna" | "sienna" => (160, 82, 45, 255),
+            "Silver" | "silver" => (192, 192, 192, 255),
+            "SkyBlue" | "skyblue" => (135, 206, 235, 255),
+            "SlateBlue" | "slateblue" => (106, 90, 205, 255),
+            "SlateGray" | "slategray" => (112, 128, 144, 255),
+            "SlateGrey" | "slategrey" => (112, 128, 144, 255),
+            "Snow" | "snow" => (255, 250, 250, 255),
+            "SpringGreen" | "springgreen" => (0, 255, 127, 255),
+            "SteelBlue" | "steelblue" => (70, 130, 180, 255),
+            "Tan" | "tan" => (210, 180, 140, 255),
+            "Teal" | "teal" => (0, 128, 128, 255),
+            "Thistle" | "thistle" => (216, 191, 216, 255),
+            "Tomato" | "tomato" => (255, 99, 71, 255),
+            "Turquoise" | "turquoise" => (64, 224, 208, 255),
+            "Violet" | "violet" => (238, 130, 238, 255),
+            "Wheat" | "wheat" => (245, 222, 179, 255),
+            "White" | "white" => (255, 255, 255, 255),
+            "WhiteSmoke" | "whitesmoke" => (245, 245, 245, 255),
+            "Yellow" | "yellow" => (255, 255, 0, 255),
+            "YellowGreen" | "yellowgreen" => (154, 205, 50, 255),
+            "Transparent" | "transparent" => (255, 255, 255, 0),
+            _ => return None,
+        };
+        Some(Self { r, g, b, a })
+    }
+
     pub fn interpolate(&self, other: &Self, t: f32) -> Self {
         Self {
             r: libm::roundf(self.r as f32 + (other.r as f32 - self.r as f32) * t) as u8,
@@ -473,6 +1092,66 @@ impl ColorU {
         }
     }
 
+    /// Multiplies the color with a tint, component-wise (including alpha)
+    /// in normalized (0.0 - 1.0) space, i.e. `WHITE.multiply(&RED) == RED`
+    pub fn multiply(&self, other: &ColorU) -> ColorU {
+        let mul = |a: u8, b: u8| ((a as u16 * b as u16 + 127) / 255) as u8;
+        Self {
+            r: mul(self.r, other.r),
+            g: mul(self.g, other.g),
+            b: mul(self.b, other.b),
+            a: mul(self.a, other.a),
+        }
+    }
+
+    /// Adds two colors component-wise (including alpha),
+    /// clamping each component at 255
+    pub const fn add_saturating(&self, other: &ColorU) -> ColorU {
+        Self {
+            r: self.r.saturating_add(other.r),
+            g: self.g.saturating_add(other.g),
+            b: self.b.saturating_add(other.b),
+            a: self.a.saturating_add(other.a),
+        }
+    }
+
+    /// Returns a copy of the color with the red channel replaced
+    pub const fn with_r(&self, r: u8) -> ColorU {
+        Self { r, ..*self }
+    }
+
+    /// Returns a copy of the color with the green channel replaced
+    pub const fn with_g(&self, g: u8) -> ColorU {
+        Self { g, ..*self }
+    }
+
+    /// Returns a copy of the color with the blue channel replaced
+    pub const fn with_b(&self, b: u8) -> ColorU {
+        Self { b, ..*self }
+    }
+
+    /// Returns a copy of the color with the alpha channel replaced
+    pub const fn with_a(&self, a: u8) -> ColorU {
+        Self { a, ..*self }
+    }
+
+    /// Returns a copy of the color with the alpha set from a
+    /// normalized (0.0 - 1.0) value, out-of-range values are clamped
+    pub fn with_alpha_f32(&self, a: f32) -> ColorU {
+        self.with_a((a.max(0.0).min(1.0) * 255.0).round() as u8)
+    }
+
+    /// Rotates the hue of the color (in HSL space) by the given amount of degrees,
+    /// i.e. red rotated by 120 degrees is green. Saturation, lightness and alpha
+    /// are preserved, the hue wraps around at 360 degrees.
+    pub fn hue_rotate(&self, degrees: f32) -> ColorU {
+        let (h, s, l) = rgb_to_hsl(self.r, self.g, self.b);
+        let h = libm::fmodf(h + degrees, 360.0);
+        let h = if h < 0.0 { h + 360.0 } else { h };
+        let (r, g, b) = hsl_to_rgb(h, s, l);
+        Self { r, g, b, a: self.a }
+    }
+
     pub const fn has_alpha(&self) -> bool {
         self.a != Self::ALPHA_OPAQUE
     }
@@ -540,6 +1219,73 @@ impl ColorF {
         b: 0.0,
         a: Self::ALPHA_TRANSPARENT,
     };
+
+    /// Linear interpolation between two colors (including alpha), `t = 0.0`
+    /// returns `self`, `t = 1.0` returns `other`. Unlike
+    /// `ColorU::interpolate` the result is not rounded to 8 bits.
+    pub fn lerp(&self, other: &ColorF, t: f32) -> ColorF {
+        *self + (*other - *self) * t
+    }
+
+    /// Clamps every channel (including alpha) to the 0.0 - 1.0 range
+    pub fn clamp(&self) -> ColorF {
+        ColorF {
+            r: self.r.max(0.0).min(1.0),
+            g: self.g.max(0.0).min(1.0),
+            b: self.b.max(0.0).min(1.0),
+            a: self.a.max(0.0).min(1.0),
+        }
+    }
+
+    /// Multiplies the RGB channels with the alpha channel, as expected by
+    /// premultiplied-alpha blending. The color is not clamped.
+    pub fn premultiply(&self) -> ColorF {
+        ColorF {
+            r: self.r * self.a,
+            g: self.g * self.a,
+            b: self.b * self.a,
+            a: self.a,
+        }
+    }
+}
+
+// Arithmetic is component-wise (including alpha) and not clamped,
+// so that intermediate values can leave the 0.0 - 1.0 range
+
+impl core::ops::Add for ColorF {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self {
+            r: self.r + other.r,
+            g: self.g + other.g,
+            b: self.b + other.b,
+            a: self.a + other.a,
+        }
+    }
+}
+
+impl core::ops::Sub for ColorF {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self {
+            r: self.r - other.r,
+            g: self.g - other.g,
+            b: self.b - other.b,
+            a: self.a - other.a,
+        }
+    }
+}
+
+impl core::ops::Mul<f32> for ColorF {
+    type Output = Self;
+    fn mul(self, factor: f32) -> Self {
+        Self {
+            r: self.r * factor,
+            g: self.g * factor,
+            b: self.b * factor,
+            a: self.a * factor,
+        }
+    }
 }
 
 impl From<ColorU> for ColorF {
@@ -555,11 +1301,12 @@ impl From<ColorU> for ColorF {
 
 impl From<ColorF> for ColorU {
     fn from(input: ColorF) -> ColorU {
+        let input = input.clamp();
         ColorU {
-            r: (input.r.min(1.0) * 255.0) as u8,
-            g: (input.g.min(1.0) * 255.0) as u8,
-            b: (input.b.min(1.0) * 255.0) as u8,
-            a: (input.a.min(1.0) * 255.0) as u8,
+            r: (input.r * 255.0) as u8,
+            g: (input.g * 255.0) as u8,
+            b: (input.b * 255.0) as u8,
+            a: (input.a * 255.0) as u8,
         }
     }
 }
@@ -572,11 +1319,12 @@ pub enum BorderDetails {
 
 /// Represents a normal `border` property (no image border / nine-patch border)
 #[derive(Debug, Copy, Clone, PartialEq, Ord, PartialOrd, Eq, Hash)]
+#[repr(C)]
 pub struct NormalBorder {
-    pub left: BorderSide,
-    pub right: BorderSide,
-    pub top: BorderSide,
-    pub bottom: BorderSide,
+    pub left: StyleBorderSide,
+    pub right: StyleBorderSide,
+    pub top: StyleBorderSide,
+    pub bottom: StyleBorderSide,
     pub radius: Option<(
         StyleBorderTopLeftRadius,
         StyleBorderTopRightRadius,
@@ -585,6 +1333,85 @@ pub struct NormalBorder {
     )>,
 }
 
+impl NormalBorder {
+    /// Border with the same width, style and color on all four sides and no radius
+    pub const fn uniform(side: StyleBorderSide) -> Self {
+        Self {
+            left: side,
+            right: side,
+            top: side,
+            bottom: side,
+            radius: None,
+        }
+    }
+
+    /// Combines the per-side `border-*-width`, `border-*-style` and
+    /// `border-*-color` properties, each tuple is in CSS order
+    /// (top, right, bottom, left)
+    pub fn from_css_properties(
+        widths: (
+            &LayoutBorderTopWidth,
+            &LayoutBorderRightWidth,
+            &LayoutBorderBottomWidth,
+            &LayoutBorderLeftWidth,
+        ),
+        styles: (
+            &StyleBorderTopStyle,
+            &StyleBorderRightStyle,
+            &StyleBorderBottomStyle,
+            &StyleBorderLeftStyle,
+        ),
+        colors: (
+            &StyleBorderTopColor,
+            &StyleBorderRightColor,
+            &StyleBorderBottomColor,
+            &StyleBorderLeftColor,
+        ),
+        radius: Option<(
+            StyleBorderTopLeftRadius,
+            StyleBorderTopRightRadius,
+            StyleBorderBottomLeftRadius,
+            StyleBorderBottomRightRadius,
+        )>,
+    ) -> Self {
+        Self {
+            top: StyleBorderSide::new(widths.0.inner, styles.0.inner, colors.0.inner),
+            right: StyleBorderSide::new(widths.1.inner, styles.1.inner, colors.1.inner),
+            bottom: StyleBorderSide::new(widths.2.inner, styles.2.inner, colors.2.inner),
+            left: StyleBorderSide::new(widths.3.inner, styles.3.inner, colors.3.inner),
+            radius,
+        }
+    }
+
+    /// Splits the border into the twelve per-side width / style / color
+    /// properties (plus the four radius properties if set)
+    pub fn to_css_properties(&self) -> Vec<CssProperty> {
+        let mut properties = vec![
+            CssProperty::border_top_width(LayoutBorderTopWidth { inner: self.top.border_width }),
+            CssProperty::border_top_style(StyleBorderTopStyle { inner: self.top.border_style }),
+            CssProperty::border_top_color(StyleBorderTopColor { inner: self.top.border_color }),
+            CssProperty::border_right_width(LayoutBorderRightWidth { inner: self.right.border_width }),
+            CssProperty::border_right_style(StyleBorderRightStyle { inner: self.right.border_style }),
+            CssProperty::border_right_color(StyleBorderRightColor { inner: self.right.border_color }),
+            CssProperty::border_bottom_width(LayoutBorderBottomWidth { inner: self.bottom.border_width }),
+            CssProperty::border_bottom_style(StyleBorderBottomStyle { inner: self.bottom.border_style }),
+            CssProperty::border_bottom_color(StyleBorderBottomColor { inner: self.bottom.border_color }),
+            CssProperty::border_left_width(LayoutBorderLeftWidth { inner: self.left.border_width }),
+            CssProperty::border_left_style(StyleBorderLeftStyle { inner: self.left.border_style }),
+            CssProperty::border_left_color(StyleBorderLeftColor { inner: self.left.border_color }),
+        ];
+
+        if let Some((top_left, top_right, bottom_left, bottom_right)) = self.radius {
+            properties.push(CssProperty::border_top_left_radius(top_left));
+            properties.push(CssProperty::border_top_right_radius(top_right));
+            properties.push(CssProperty::border_bottom_left_radius(bottom_left));
+            properties.push(CssProperty::border_bottom_right_radius(bottom_right));
+        }
+
+        properties
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Ord, PartialOrd, Eq, Hash)]
 #[repr(C)]
 pub struct BorderSide {
@@ -592,72 +1419,132 @@ pub struct BorderSide {
     pub style: BorderStyle,
 }
 
-/// What direction should a `box-shadow` be clipped in (inset or outset)
-#[derive(Debug, Copy, Clone, PartialEq, Ord, PartialOrd, Eq, Hash)]
-#[repr(C)]
-pub enum BoxShadowClipMode {
-    Outset,
-    Inset,
+/// Error returned by the `FromStr` / `TryFrom<&str>` implementations of the
+/// keyword enums (`ExtendMode`, `Shape`, etc.)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidKeywordError {
+    /// The (trimmed) input that could not be parsed
+    pub input: String,
+    /// All keywords that would have been valid in this position
+    pub expected: &'static [&'static str],
 }
 
-impl fmt::Display for BoxShadowClipMode {
+impl fmt::Display for InvalidKeywordError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use self::BoxShadowClipMode::*;
-        match self {
-            Outset => write!(f, "outset"),
-            Inset => write!(f, "inset"),
-        }
+        write!(
+            f,
+            "invalid keyword \"{}\", expected one of: {}",
+            self.input,
+            self.expected.join(", ")
+        )
     }
 }
 
-/// Whether a `gradient` should be repeated or clamped to the edges.
-#[derive(Debug, Copy, Clone, PartialEq, Ord, PartialOrd, Eq, Hash)]
-#[repr(C)]
-pub enum ExtendMode {
-    Clamp,
-    Repeat,
-}
+/// Implements `Display`, `FromStr` and `TryFrom<&str>` for an enum that
+/// consists only of CSS keywords, so that the keyword table only exists once
+macro_rules! impl_keyword_enum {
+    ($enum:ident, $([$keyword:literal, $variant:ident]),+ $(,)?) => {
+        impl $enum {
+            /// All valid CSS keywords, in the same order as `ALL_VARIANTS`
+            pub const KEYWORDS: &'static [&'static str] = &[$($keyword),+];
+            /// All variants of the enum, in the same order as `KEYWORDS`
+            pub const ALL_VARIANTS: &'static [$enum] = &[$($enum::$variant),+];
 
-impl Default for ExtendMode {
-    fn default() -> Self {
-        ExtendMode::Clamp
-    }
-}
+            /// Parses the CSS keyword, ignoring surrounding whitespace.
+            /// Use `str::parse` instead to get the list of valid keywords on error.
+            pub fn from_str(input: &str) -> Option<Self> {
+                input.parse().ok()
+            }
 
-/// Style of a `border`: solid, double, dash, ridge, etc.
-#[derive(Debug, Copy, Clone, PartialEq, Ord, PartialOrd, Eq, Hash)]
-#[repr(C)]
-pub enum BorderStyle {
-    None,
-    Solid,
-    Double,
-    Dotted,
-    Dashed,
-    Hidden,
-    Groove,
-    Ridge,
-    Inset,
+            /// Returns the CSS keyword of this value
+            pub const fn as_keyword(&self) -> &'static str {
+                match self {
+                    $($enum::$variant => $keyword,)+
+                }
+            }
+        }
+
+        impl fmt::Display for $enum {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(self.as_keyword())
+            }
+        }
+
+        impl core::str::FromStr for $enum {
+            type Err = InvalidKeywordError;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                let input = input.trim();
+                match input {
+                    $($keyword => Ok($enum::$variant),)+
+                    _ => Err(InvalidKeywordError {
+                        input: input.into(),
+                        expected: Self::KEYWORDS,
+                    }),
+                }
+            }
+        }
+
+        impl<'a> core::convert::TryFrom<&'a str> for $enum {
+            type Error = InvalidKeywordError;
+
+            fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+                input.parse()
+            }
+        }
+    };
+}
+
+/// What direction should a `box-shadow` be clipped in (inset or outset)
+#[derive(Debug, Copy, Clone, PartialEq, Ord, PartialOrd, Eq, Hash)]
+#[repr(C)]
+pub enum BoxShadowClipMode {
     Outset,
+    Inset,
 }
 
-impl fmt::Display for BorderStyle {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use self::BorderStyle::*;
-        match self {
-            None => write!(f, "none"),
-            Solid => write!(f, "solid"),
-            Double => write!(f, "double"),
-            Dotted => write!(f, "dotted"),
-            Dashed => write!(f, "dashed"),
-            Hidden => write!(f, "hidden"),
-            Groove => write!(f, "groove"),
-            Ridge => write!(f, "ridge"),
-            Inset => write!(f, "inset"),
-            Outset => write!(f, "outset"),
-        }
+impl_keyword_enum!(
+    BoxShadowClipMode,
+    ["outset", Outset],
+    ["inset", Inset],
+);
+
+/// Whether a `gradient` should be repeated or clamped to the edges.
+#[derive(Debug, Copy, Clone, PartialEq, Ord, PartialOrd, Eq, Hash)]
+#[repr(C)]
+pub enum ExtendMode {
+    Clamp,
+    Repeat,
+}
+
+impl Default for ExtendMode {
+    fn default() -> Self {
+        ExtendMode::Clamp
     }
 }
 
+impl_keyword_enum!(
+    ExtendMode,
+    ["clamp", Clamp],
+    ["repeat", Repeat],
+);
+
+/// Style of a `border`: solid, double, dash, ridge, etc.
+#[derive(Debug, Copy, Clone, PartialEq, Ord, PartialOrd, Eq, Hash)]
+#[repr(C)]
+pub enum BorderStyle {
+    None,
+    Solid,
+    Double,
+    Dotted,
+    Dashed,
+    Hidden,
+    Groove,
+    Ridge,
+    Inset,
+    Outset,
+}
+
 impl BorderStyle {
     pub fn normalize_border(self) -> Option<BorderStyleNoNone> {
         match self {
@@ -694,6 +1581,20 @@ impl Default for BorderStyle {
     }
 }
 
+impl_keyword_enum!(
+    BorderStyle,
+    ["none", None],
+    ["solid", Solid],
+    ["double", Double],
+    ["dotted", Dotted],
+    ["dashed", Dashed],
+    ["hidden", Hidden],
+    ["groove", Groove],
+    ["ridge", Ridge],
+    ["inset", Inset],
+    ["outset", Outset],
+);
+
 #[derive(Debug, Copy, Clone, PartialEq, Ord, PartialOrd, Eq, Hash)]
 pub struct NinePatchBorder {
     // not implemented or parse-able yet, so no fields!
@@ -926,8 +1827,9 @@ pub enum CombinedCssPropertyType {
     BorderBottom,
     Padding,
     BoxShadow,
-    BackgroundColor, // BackgroundContent::Colo
-    BackgroundImage, // BackgroundContent::Colo
+    BackgroundColor, // BackgroundContent::Color
+    BackgroundImage, // BackgroundContent::Image
+    Transition,
 }
 
 impl fmt::Display for CombinedCssPropertyType {
@@ -935,7 +1837,7 @@ impl fmt::Display for CombinedCssPropertyType {
         let key = COMBINED_CSS_PROPERTIES_KEY_MAP
             .iter()
             .find(|(v, _)| *v == *self)
-            .and_then(|(k, _)| Some(k))
+            .map(|(_, k)| *k)
             .unwrap();
         write!(f, "{}", key)
     }
@@ -1000,6 +1902,8 @@ pub enum CssPropertyType {
     FontSize,
     FontFamily,
     TextAlign,
+    VerticalAlign,
+    Direction,
     LetterSpacing,
     LineHeight,
     WordSpacing,
@@ -1026,6 +1930,10 @@ pub enum CssPropertyType {
     JustifyContent,
     AlignItems,
     AlignContent,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridColumn,
+    GridRow,
     BackgroundContent,
     BackgroundPosition,
     BackgroundSize,
@@ -1067,9 +1975,18 @@ pub enum CssPropertyType {
     PerspectiveOrigin,
     BackfaceVisibility,
     MixBlendMode,
+    Isolation,
+    ImageRendering,
     Filter,
     BackdropFilter,
     TextShadow,
+    TransitionProperty,
+    TransitionDuration,
+    TransitionTimingFunction,
+    TransitionDelay,
+    CaretColor,
+    SelectionColor,
+    SelectionBackground,
 }
 
 impl CssPropertyType {
@@ -1089,6 +2006,18 @@ impl CssPropertyType {
         map.non_shorthands.get(input).and_then(|x| Some(*x))
     }
 
+    /// Returns all supported property types, in the order of the CSS key map
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use azul_css::CssPropertyType;
+    /// assert!(CssPropertyType::all().contains(&CssPropertyType::Width));
+    /// ```
+    pub fn all() -> &'static [CssPropertyType] {
+        &CSS_PROPERTY_TYPES
+    }
+
     /// Returns the original string that was used to construct this `CssPropertyType`.
     pub fn to_str(&self) -> &'static str {
         match self {
@@ -1096,6 +2025,8 @@ impl CssPropertyType {
             CssPropertyType::FontSize => "font-size",
             CssPropertyType::FontFamily => "font-family",
             CssPropertyType::TextAlign => "text-align",
+            CssPropertyType::VerticalAlign => "vertical-align",
+            CssPropertyType::Direction => "direction",
             CssPropertyType::LetterSpacing => "letter-spacing",
             CssPropertyType::LineHeight => "line-height",
             CssPropertyType::WordSpacing => "word-spacing",
@@ -1122,6 +2053,10 @@ impl CssPropertyType {
             CssPropertyType::JustifyContent => "justify-content",
             CssPropertyType::AlignItems => "align-items",
             CssPropertyType::AlignContent => "align-content",
+            CssPropertyType::GridTemplateColumns => "grid-template-columns",
+            CssPropertyType::GridTemplateRows => "grid-template-rows",
+            CssPropertyType::GridColumn => "grid-column",
+            CssPropertyType::GridRow => "grid-row",
             CssPropertyType::BackgroundContent => "background",
             CssPropertyType::BackgroundPosition => "background-position",
             CssPropertyType::BackgroundSize => "background-size",
@@ -1163,9 +2098,18 @@ impl CssPropertyType {
             CssPropertyType::PerspectiveOrigin => "perspective-origin",
             CssPropertyType::BackfaceVisibility => "backface-visibility",
             CssPropertyType::MixBlendMode => "mix-blend-mode",
+            CssPropertyType::Isolation => "isolation",
+            CssPropertyType::ImageRendering => "image-rendering",
             CssPropertyType::Filter => "filter",
             CssPropertyType::BackdropFilter => "backdrop-filter",
             CssPropertyType::TextShadow => "text-shadow",
+            CssPropertyType::TransitionProperty => "transition-property",
+            CssPropertyType::TransitionDuration => "transition-duration",
+            CssPropertyType::TransitionTimingFunction => "transition-timing-function",
+            CssPropertyType::TransitionDelay => "transition-delay",
+            CssPropertyType::CaretColor => "caret-color",
+            CssPropertyType::SelectionColor => "-azul-selection-color",
+            CssPropertyType::SelectionBackground => "-azul-selection-background-color",
         }
     }
 
@@ -1173,11 +2117,158 @@ impl CssPropertyType {
     pub fn is_inheritable(&self) -> bool {
         use self::CssPropertyType::*;
         match self {
-            TextColor | FontFamily | FontSize | LineHeight | TextAlign => true,
+            TextColor
+            | FontFamily
+            | FontSize
+            | LineHeight
+            | TextAlign
+            | Direction
+            | ImageRendering
+            | CaretColor
+            | SelectionColor
+            | SelectionBackground => true,
             _ => false,
         }
     }
 
+    /// Returns the initial value of the property as defined by the CSS spec,
+    /// i.e. the value of `width: initial` (or of an inheritable property
+    /// on the root node).
+    ///
+    /// This is not the same as `Default::default()`: the defaults are the
+    /// values that the layout solver uses for unset properties, such as
+    /// `flex-direction: column`. Properties that don't have a concrete value
+    /// as their initial value (`width: auto`, `max-width: none`) return
+    /// the `Auto` / `None` keyword.
+    ///
+    /// See `azul_default_value` for the values that the layout uses instead.
+    ///
+    /// NOTE: The initial `currentcolor` of the border colors isn't supported,
+    /// black is used instead.
+    pub fn initial_value(&self) -> CssProperty {
+        use self::CssPropertyValue::{Auto, Exact, None};
+
+        match self {
+            CssPropertyType::TextColor => CssProperty::text_color(StyleTextColor { inner: ColorU::BLACK }),
+            CssPropertyType::FontSize => CssProperty::font_size(StyleFontSize::const_px(16)),
+            CssPropertyType::FontFamily => CssProperty::font_family(StyleFontFamilyVec::from_const_slice(&[])),
+            CssPropertyType::TextAlign => CssProperty::text_align(StyleTextAlign::Left),
+            CssPropertyType::VerticalAlign => CssProperty::vertical_align(StyleVerticalAlign::Baseline),
+            CssPropertyType::Direction => CssProperty::direction(StyleDirection::Ltr),
+            CssPropertyType::LetterSpacing => CssProperty::letter_spacing(StyleLetterSpacing::const_px(0)),
+            CssPropertyType::LineHeight => CssProperty::line_height(StyleLineHeight::default()),
+            CssPropertyType::WordSpacing => CssProperty::word_spacing(StyleWordSpacing::const_px(0)),
+            CssPropertyType::TabWidth => CssProperty::tab_width(StyleTabWidth::default()),
+            CssPropertyType::Cursor => CssProperty::cursor(StyleCursor::Default),
+            CssPropertyType::Display => CssProperty::display(LayoutDisplay::Inline),
+            CssPropertyType::Float => CssProperty::float(LayoutFloat::None),
+            CssPropertyType::BoxSizing => CssProperty::box_sizing(LayoutBoxSizing::ContentBox),
+            CssPropertyType::Width => CssProperty::Width(CssLengthValue::Auto),
+            CssPropertyType::Height => CssProperty::Height(CssLengthValue::Auto),
+            CssPropertyType::MinWidth => CssProperty::min_width(LayoutMinWidth::const_px(0)),
+            CssPropertyType::MinHeight => CssProperty::min_height(LayoutMinHeight::const_px(0)),
+            CssPropertyType::MaxWidth => CssProperty::MaxWidth(CssLengthValue::None),
+            CssPropertyType::MaxHeight => CssProperty::MaxHeight(CssLengthValue::None),
+            CssPropertyType::Position => CssProperty::position(LayoutPosition::Static),
+            CssPropertyType::Top => CssProperty::Top(CssLengthValue::Auto),
+            CssPropertyType::Right => CssProperty::Right(CssLengthValue::Auto),
+            CssPropertyType::Left => CssProperty::Left(CssLengthValue::Auto),
+            CssPropertyType::Bottom => CssProperty::Bottom(CssLengthValue::Auto),
+            CssPropertyType::FlexWrap => CssProperty::flex_wrap(LayoutFlexWrap::NoWrap),
+            CssPropertyType::FlexDirection => CssProperty::flex_direction(LayoutFlexDirection::Row),
+            CssPropertyType::FlexGrow => CssProperty::flex_grow(LayoutFlexGrow::const_new(0)),
+            CssPropertyType::FlexShrink => CssProperty::flex_shrink(LayoutFlexShrink::const_new(1)),
+            CssPropertyType::JustifyContent => CssProperty::justify_content(LayoutJustifyContent::Start),
+            CssPropertyType::AlignItems => CssProperty::align_items(LayoutAlignItems::Stretch),
+            CssPropertyType::AlignContent => CssProperty::align_content(LayoutAlignContent::Stretch),
+            CssPropertyType::GridTemplateColumns => CssProperty::grid_template_columns(LayoutGridTemplateColumns::default()),
+            CssPropertyType::GridTemplateRows => CssProperty::grid_template_rows(LayoutGridTemplateRows::default()),
+            CssPropertyType::GridColumn => CssProperty::grid_column(LayoutGridColumn::default()),
+            CssPropertyType::GridRow => CssProperty::grid_row(LayoutGridRow::default()),
+            CssPropertyType::BackgroundContent => CssProperty::background_content(StyleBackgroundContentVec::from_const_slice(&[])),
+            CssPropertyType::BackgroundPosition => CssProperty::background_position(vec![StyleBackgroundPosition::default()].into()),
+            // no layers = `auto`, every image keeps its size
+            CssPropertyType::BackgroundSize => CssProperty::background_size(StyleBackgroundSizeVec::from_const_slice(&[])),
+            CssPropertyType::BackgroundRepeat => CssProperty::background_repeat(vec![StyleBackgroundRepeat::Repeat].into()),
+            CssPropertyType::OverflowX => CssProperty::overflow_x(LayoutOverflow::Visible),
+            CssPropertyType::OverflowY => CssProperty::overflow_y(LayoutOverflow::Visible),
+            CssPropertyType::PaddingTop => CssProperty::padding_top(LayoutPaddingTop::const_px(0)),
+            CssPropertyType::PaddingLeft => CssProperty::padding_left(LayoutPaddingLeft::const_px(0)),
+            CssPropertyType::PaddingRight => CssProperty::padding_right(LayoutPaddingRight::const_px(0)),
+            CssPropertyType::PaddingBottom => CssProperty::padding_bottom(LayoutPaddingBottom::const_px(0)),
+            CssPropertyType::MarginTop => CssProperty::margin_top(LayoutMarginTop::const_px(0)),
+            CssPropertyType::MarginLeft => CssProperty::margin_left(LayoutMarginLeft::const_px(0)),
+            CssPropertyType::MarginRight => CssProperty::margin_right(LayoutMarginRight::const_px(0)),
+            CssPropertyType::MarginBottom => CssProperty::margin_bottom(LayoutMarginBottom::const_px(0)),
+            CssPropertyType::BorderTopLeftRadius => CssProperty::border_top_left_radius(StyleBorderTopLeftRadius::const_px(0)),
+            CssPropertyType::BorderTopRightRadius => CssProperty::border_top_right_radius(StyleBorderTopRightRadius::const_px(0)),
+            CssPropertyType::BorderBottomLeftRadius => CssProperty::border_bottom_left_radius(StyleBorderBottomLeftRadius::const_px(0)),
+            CssPropertyType::BorderBottomRightRadius => CssProperty::border_bottom_right_radius(StyleBorderBottomRightRadius::const_px(0)),
+            CssPropertyType::BorderTopColor => CssProperty::border_top_color(StyleBorderTopColor { inner: ColorU::BLACK }),
+            CssPropertyType::BorderRightColor => CssProperty::border_right_color(StyleBorderRightColor { inner: ColorU::BLACK }),
+            CssPropertyType::BorderLeftColor => CssProperty::border_left_color(StyleBorderLeftColor { inner: ColorU::BLACK }),
+            CssPropertyType::BorderBottomColor => CssProperty::border_bottom_color(StyleBorderBottomColor { inner: ColorU::BLACK }),
+            CssPropertyType::BorderTopStyle => CssProperty::border_top_style(StyleBorderTopStyle { inner: BorderStyle::None }),
+            CssPropertyType::BorderRightStyle => CssProperty::border_right_style(StyleBorderRightStyle { inner: BorderStyle::None }),
+            CssPropertyType::BorderLeftStyle => CssProperty::border_left_style(StyleBorderLeftStyle { inner: BorderStyle::None }),
+            CssPropertyType::BorderBottomStyle => CssProperty::border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::None }),
+            // `medium`
+            CssPropertyType::BorderTopWidth => CssProperty::border_top_width(LayoutBorderTopWidth::const_px(3)),
+            CssPropertyType::BorderRightWidth => CssProperty::border_right_width(LayoutBorderRightWidth::const_px(3)),
+            CssPropertyType::BorderLeftWidth => CssProperty::border_left_width(LayoutBorderLeftWidth::const_px(3)),
+            CssPropertyType::BorderBottomWidth => CssProperty::border_bottom_width(LayoutBorderBottomWidth::const_px(3)),
+            CssPropertyType::BoxShadowLeft => CssProperty::BoxShadowLeft(None),
+            CssPropertyType::BoxShadowRight => CssProperty::BoxShadowRight(None),
+            CssPropertyType::BoxShadowTop => CssProperty::BoxShadowTop(None),
+            CssPropertyType::BoxShadowBottom => CssProperty::BoxShadowBottom(None),
+            CssPropertyType::ScrollbarStyle => CssProperty::ScrollbarStyle(CssPropertyValue::exact(ScrollbarStyle::default())),
+            CssPropertyType::Opacity => CssProperty::opacity(StyleOpacity::const_new(100)),
+            CssPropertyType::Transform => CssProperty::transform(StyleTransformVec::from_const_slice(&[])),
+            CssPropertyType::TransformOrigin => CssProperty::transform_origin(StyleTransformOrigin {
+                x: PixelValue::const_percent(50),
+                y: PixelValue::const_percent(50),
+            }),
+            CssPropertyType::PerspectiveOrigin => CssProperty::perspective_origin(StylePerspectiveOrigin {
+                x: PixelValue::const_percent(50),
+                y: PixelValue::const_percent(50),
+            }),
+            CssPropertyType::BackfaceVisibility => CssProperty::backface_visiblity(StyleBackfaceVisibility::Visible),
+            CssPropertyType::MixBlendMode => CssProperty::mix_blend_mode(StyleMixBlendMode::Normal),
+            CssPropertyType::Isolation => CssProperty::isolation(StyleIsolation::Auto),
+            CssPropertyType::ImageRendering => CssProperty::image_rendering(StyleImageRendering::Auto),
+            CssPropertyType::Filter => CssProperty::Filter(CssPropertyValue::exact(StyleFilterVec::from_const_slice(&[]))),
+            CssPropertyType::BackdropFilter => CssProperty::BackdropFilter(CssPropertyValue::exact(StyleFilterVec::from_const_slice(&[]))),
+            CssPropertyType::TextShadow => CssProperty::TextShadow(None),
+            CssPropertyType::TransitionProperty => CssProperty::transition_property(StyleTransitionProperty::All),
+            CssPropertyType::TransitionDuration => CssProperty::transition_duration(StyleTransitionDuration::const_ms(0)),
+            CssPropertyType::TransitionTimingFunction => CssProperty::transition_timing_function(StyleTransitionTimingFunction::Ease),
+            CssPropertyType::TransitionDelay => CssProperty::transition_delay(StyleTransitionDelay::const_ms(0)),
+            // `auto`: the text color / the platform highlight colors
+            CssPropertyType::CaretColor => CssProperty::CaretColor(Auto),
+            CssPropertyType::SelectionColor => CssProperty::SelectionColor(Auto),
+            CssPropertyType::SelectionBackground => CssProperty::SelectionBackground(Auto),
+        }
+    }
+
+    /// Returns the value that the layout solver uses if the property isn't set,
+    /// i.e. the `Default` of the property value. This is not the CSS initial
+    /// value for `display` (`flex`), `float` (`left`), the flex properties
+    /// and `overflow` (`auto`), for every other property it is the same as
+    /// `initial_value`.
+    pub fn azul_default_value(&self) -> CssProperty {
+        match self {
+            CssPropertyType::Display => CssProperty::display(LayoutDisplay::default()),
+            CssPropertyType::Float => CssProperty::float(LayoutFloat::default()),
+            CssPropertyType::FlexDirection => CssProperty::flex_direction(LayoutFlexDirection::default()),
+            CssPropertyType::FlexWrap => CssProperty::flex_wrap(LayoutFlexWrap::default()),
+            CssPropertyType::FlexShrink => CssProperty::flex_shrink(LayoutFlexShrink::default()),
+            CssPropertyType::AlignItems => CssProperty::align_items(LayoutAlignItems::default()),
+            CssPropertyType::OverflowX => CssProperty::overflow_x(LayoutOverflow::default()),
+            CssPropertyType::OverflowY => CssProperty::overflow_y(LayoutOverflow::default()),
+            _ => self.initial_value(),
+        }
+    }
+
     /// Returns whether this property can trigger a re-layout (important for incremental layout and caching layouted DOMs).
     pub fn can_trigger_relayout(&self) -> bool {
         use self::CssPropertyType::*;
@@ -1218,9 +2309,18 @@ impl CssPropertyType {
             | PerspectiveOrigin
             | BackfaceVisibility
             | MixBlendMode
+            | Isolation
+            | ImageRendering
             | Filter
             | BackdropFilter
-            | TextShadow => false,
+            | TextShadow
+            | TransitionProperty
+            | TransitionDuration
+            | TransitionTimingFunction
+            | TransitionDelay
+            | CaretColor
+            | SelectionColor
+            | SelectionBackground => false,
             _ => true,
         }
     }
@@ -1233,6 +2333,29 @@ impl CssPropertyType {
             _ => false
         }
     }
+
+    /// Returns the group of the shorthand property that this property can be
+    /// expanded from (ex. `margin-top` is part of `margin`), or `0` if
+    /// no shorthand sets this property.
+    ///
+    /// Used by `CssProperty::cascade_sort_key` to keep the longhands of
+    /// one shorthand next to each other.
+    pub fn cascade_category(&self) -> u8 {
+        use self::CssPropertyType::*;
+        match self {
+            BackgroundContent | BackgroundPosition | BackgroundSize | BackgroundRepeat => 1,
+            OverflowX | OverflowY => 2,
+            PaddingTop | PaddingLeft | PaddingRight | PaddingBottom => 3,
+            MarginTop | MarginLeft | MarginRight | MarginBottom => 4,
+            BorderTopLeftRadius | BorderTopRightRadius |
+            BorderBottomLeftRadius | BorderBottomRightRadius => 5,
+            BorderTopColor | BorderRightColor | BorderLeftColor | BorderBottomColor |
+            BorderTopStyle | BorderRightStyle | BorderLeftStyle | BorderBottomStyle |
+            BorderTopWidth | BorderRightWidth | BorderLeftWidth | BorderBottomWidth => 6,
+            BoxShadowLeft | BoxShadowRight | BoxShadowTop | BoxShadowBottom => 7,
+            _ => 0,
+        }
+    }
 }
 
 impl fmt::Debug for CssPropertyType {
@@ -1241,6 +2364,9 @@ impl fmt::Debug for CssPropertyType {
     }
 }
 
+// NOTE: Display (used when serializing stylesheets) goes through the `match` in
+// `CssPropertyType::to_str()`, which compiles to a jump table, so printing a key
+// is O(1) and doesn't need to search the `CSS_PROPERTY_KEY_MAP`.
 impl fmt::Display for CssPropertyType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.to_str())
@@ -1248,6 +2374,14 @@ impl fmt::Display for CssPropertyType {
 }
 
 /// Represents one parsed CSS key-value pair, such as `"width: 20px"` => `CssProperty::Width(LayoutWidth::px(20.0))`
+///
+/// NOTE: The derived `Ord` compares the enum discriminant, then the value.
+/// It only exists so that properties can be used as keys in a `BTreeMap`
+/// and has nothing to do with the cascade order: use
+/// `CssProperty::cascade_sort_key` / `CssPropertyVec::sort_for_cascade` instead.
+///
+/// NOTE: Every styled node stores its properties as `CssProperty`, so the
+/// enum is kept small: large payloads (i.e. `ScrollbarStyle`) are boxed.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(C, u8)]
 pub enum CssProperty {
@@ -1255,6 +2389,8 @@ pub enum CssProperty {
     FontSize(StyleFontSizeValue),
     FontFamily(StyleFontFamilyVecValue),
     TextAlign(StyleTextAlignValue),
+    VerticalAlign(StyleVerticalAlignValue),
+    Direction(StyleDirectionValue),
     LetterSpacing(StyleLetterSpacingValue),
     LineHeight(StyleLineHeightValue),
     WordSpacing(StyleWordSpacingValue),
@@ -1281,6 +2417,10 @@ pub enum CssProperty {
     JustifyContent(LayoutJustifyContentValue),
     AlignItems(LayoutAlignItemsValue),
     AlignContent(LayoutAlignContentValue),
+    GridTemplateColumns(LayoutGridTemplateColumnsValue),
+    GridTemplateRows(LayoutGridTemplateRowsValue),
+    GridColumn(LayoutGridColumnValue),
+    GridRow(LayoutGridRowValue),
     BackgroundContent(StyleBackgroundContentVecValue),
     BackgroundPosition(StyleBackgroundPositionVecValue),
     BackgroundSize(StyleBackgroundSizeVecValue),
@@ -1322,10 +2462,26 @@ pub enum CssProperty {
     PerspectiveOrigin(StylePerspectiveOriginValue),
     BackfaceVisibility(StyleBackfaceVisibilityValue),
     MixBlendMode(StyleMixBlendModeValue),
+    Isolation(StyleIsolationValue),
+    ImageRendering(StyleImageRenderingValue),
     Filter(StyleFilterVecValue),
     BackdropFilter(StyleFilterVecValue),
     TextShadow(StyleBoxShadowValue),
-}
+    TransitionProperty(StyleTransitionPropertyValue),
+    TransitionDuration(StyleTransitionDurationValue),
+    TransitionTimingFunction(StyleTransitionTimingFunctionValue),
+    TransitionDelay(StyleTransitionDelayValue),
+    CaretColor(StyleCaretColorValue),
+    SelectionColor(StyleSelectionColorValue),
+    SelectionBackground(StyleSelectionBackgroundValue),
+}
+
+// Every payload larger than a pointer is stored in a `BoxOrStatic` (including
+// the `CalcLength` of `calc()` lengths), so that a `CssProperty` is a tag plus
+// 24 bytes (the largest inline payload is a `CssPropertyValue<PixelValue>`).
+// Styling a DOM copies properties around a lot, see `benches/styled_dom.rs`
+#[cfg(target_pointer_width = "64")]
+const _: () = assert!(core::mem::size_of::<CssProperty>() <= 32);
 
 impl_option!(
     CssProperty,
@@ -1347,6 +2503,10 @@ macro_rules! css_property_from_type {
             CssPropertyType::TextAlign => {
                 CssProperty::TextAlign(StyleTextAlignValue::$content_type)
             }
+            CssPropertyType::VerticalAlign => {
+                CssProperty::VerticalAlign(StyleVerticalAlignValue::$content_type)
+            }
+            CssPropertyType::Direction => CssProperty::Direction(StyleDirectionValue::$content_type),
             CssPropertyType::LetterSpacing => {
                 CssProperty::LetterSpacing(StyleLetterSpacingValue::$content_type)
             }
@@ -1395,6 +2555,16 @@ macro_rules! css_property_from_type {
             CssPropertyType::AlignContent => {
                 CssProperty::AlignContent(LayoutAlignContentValue::$content_type)
             }
+            CssPropertyType::GridTemplateColumns => {
+                CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::$content_type)
+            }
+            CssPropertyType::GridTemplateRows => {
+                CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::$content_type)
+            }
+            CssPropertyType::GridColumn => {
+                CssProperty::GridColumn(LayoutGridColumnValue::$content_type)
+            }
+            CssPropertyType::GridRow => CssProperty::GridRow(LayoutGridRowValue::$content_type),
             CssPropertyType::BackgroundContent => {
                 CssProperty::BackgroundContent(StyleBackgroundContentVecValue::$content_type)
             }
@@ -1516,6 +2686,12 @@ macro_rules! css_property_from_type {
             CssPropertyType::MixBlendMode => {
                 CssProperty::MixBlendMode(StyleMixBlendModeValue::$content_type)
             }
+            CssPropertyType::Isolation => {
+                CssProperty::Isolation(StyleIsolationValue::$content_type)
+            }
+            CssPropertyType::ImageRendering => {
+                CssProperty::ImageRendering(StyleImageRenderingValue::$content_type)
+            }
             CssPropertyType::Filter => CssProperty::Filter(StyleFilterVecValue::$content_type),
             CssPropertyType::BackdropFilter => {
                 CssProperty::BackdropFilter(StyleFilterVecValue::$content_type)
@@ -1523,6 +2699,21 @@ macro_rules! css_property_from_type {
             CssPropertyType::TextShadow => {
                 CssProperty::TextShadow(StyleBoxShadowValue::$content_type)
             }
+            CssPropertyType::TransitionProperty => {
+                CssProperty::TransitionProperty(StyleTransitionPropertyValue::$content_type)
+            }
+            CssPropertyType::TransitionDuration => {
+                CssProperty::TransitionDuration(StyleTransitionDurationValue::$content_type)
+            }
+            CssPropertyType::TransitionTimingFunction => CssProperty::TransitionTimingFunction(
+                StyleTransitionTimingFunctionValue::$content_type,
+            ),
+            CssPropertyType::TransitionDelay => {
+                CssProperty::TransitionDelay(StyleTransitionDelayValue::$content_type)
+            }
+            CssPropertyType::CaretColor => CssProperty::CaretColor(StyleCaretColorValue::$content_type),
+            CssPropertyType::SelectionColor => CssProperty::SelectionColor(StyleSelectionColorValue::$content_type),
+            CssPropertyType::SelectionBackground => CssProperty::SelectionBackground(StyleSelectionBackgroundValue::$content_type),
         }
     }};
 }
@@ -1535,6 +2726,8 @@ impl CssProperty {
             FontSize(c) => c.is_initial(),
             FontFamily(c) => c.is_initial(),
             TextAlign(c) => c.is_initial(),
+            VerticalAlign(c) => c.is_initial(),
+            Direction(c) => c.is_initial(),
             LetterSpacing(c) => c.is_initial(),
             LineHeight(c) => c.is_initial(),
             WordSpacing(c) => c.is_initial(),
@@ -1561,6 +2754,10 @@ impl CssProperty {
             JustifyContent(c) => c.is_initial(),
             AlignItems(c) => c.is_initial(),
             AlignContent(c) => c.is_initial(),
+            GridTemplateColumns(c) => c.is_initial(),
+            GridTemplateRows(c) => c.is_initial(),
+            GridColumn(c) => c.is_initial(),
+            GridRow(c) => c.is_initial(),
             BackgroundContent(c) => c.is_initial(),
             BackgroundPosition(c) => c.is_initial(),
             BackgroundSize(c) => c.is_initial(),
@@ -1602,9 +2799,18 @@ impl CssProperty {
             PerspectiveOrigin(c) => c.is_initial(),
             BackfaceVisibility(c) => c.is_initial(),
             MixBlendMode(c) => c.is_initial(),
+            Isolation(c) => c.is_initial(),
+            ImageRendering(c) => c.is_initial(),
             Filter(c) => c.is_initial(),
             BackdropFilter(c) => c.is_initial(),
             TextShadow(c) => c.is_initial(),
+            TransitionProperty(c) => c.is_initial(),
+            TransitionDuration(c) => c.is_initial(),
+            TransitionTimingFunction(c) => c.is_initial(),
+            TransitionDelay(c) => c.is_initial(),
+            CaretColor(c) => c.is_initial(),
+            SelectionColor(c) => c.is_initial(),
+            SelectionBackground(c) => c.is_initial(),
         }
     }
 
@@ -1627,8 +2833,8 @@ impl CssProperty {
     pub const fn const_font_size(input: StyleFontSize) -> Self {
         CssProperty::FontSize(StyleFontSizeValue::Exact(input))
     }
-    pub const fn const_font_family(input: StyleFontFamilyVec) -> Self {
-        CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(input))
+    pub const fn const_font_family(input: &'static CssBoxed<StyleFontFamilyVec>) -> Self {
+        CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(BoxOrStatic::from_static(input)))
     }
     pub const fn const_text_align(input: StyleTextAlign) -> Self {
         CssProperty::TextAlign(StyleTextAlignValue::Exact(input))
@@ -1711,17 +2917,29 @@ impl CssProperty {
     pub const fn const_align_content(input: LayoutAlignContent) -> Self {
         CssProperty::AlignContent(LayoutAlignContentValue::Exact(input))
     }
-    pub const fn const_background_content(input: StyleBackgroundContentVec) -> Self {
-        CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(input))
+    pub const fn const_grid_template_columns(input: &'static CssBoxed<LayoutGridTemplateColumns>) -> Self {
+        CssProperty::GridTemplateColumns(LayoutGridTemplateColumnsValue::Exact(BoxOrStatic::from_static(input)))
+    }
+    pub const fn const_grid_template_rows(input: &'static CssBoxed<LayoutGridTemplateRows>) -> Self {
+        CssProperty::GridTemplateRows(LayoutGridTemplateRowsValue::Exact(BoxOrStatic::from_static(input)))
+    }
+    pub const fn const_grid_column(input: LayoutGridColumn) -> Self {
+        CssProperty::GridColumn(LayoutGridColumnValue::Exact(input))
+    }
+    pub const fn const_grid_row(input: LayoutGridRow) -> Self {
+        CssProperty::GridRow(LayoutGridRowValue::Exact(input))
     }
-    pub const fn const_background_position(input: StyleBackgroundPositionVec) -> Self {
-        CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::Exact(input))
+    pub const fn const_background_content(input: &'static CssBoxed<StyleBackgroundContentVec>) -> Self {
+        CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(BoxOrStatic::from_static(input)))
     }
-    pub const fn const_background_size(input: StyleBackgroundSizeVec) -> Self {
-        CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::Exact(input))
+    pub const fn const_background_position(input: &'static CssBoxed<StyleBackgroundPositionVec>) -> Self {
+        CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::Exact(BoxOrStatic::from_static(input)))
     }
-    pub const fn const_background_repeat(input: StyleBackgroundRepeatVec) -> Self {
-        CssProperty::BackgroundRepeat(StyleBackgroundRepeatVecValue::Exact(input))
+    pub const fn const_background_size(input: &'static CssBoxed<StyleBackgroundSizeVec>) -> Self {
+        CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::Exact(BoxOrStatic::from_static(input)))
+    }
+    pub const fn const_background_repeat(input: &'static CssBoxed<StyleBackgroundRepeatVec>) -> Self {
+        CssProperty::BackgroundRepeat(StyleBackgroundRepeatVecValue::Exact(BoxOrStatic::from_static(input)))
     }
     pub const fn const_overflow_x(input: LayoutOverflow) -> Self {
         CssProperty::OverflowX(LayoutOverflowValue::Exact(input))
@@ -1801,29 +3019,29 @@ impl CssProperty {
     pub const fn const_border_bottom_width(input: LayoutBorderBottomWidth) -> Self {
         CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(input))
     }
-    pub const fn const_box_shadow_left(input: StyleBoxShadow) -> Self {
-        CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(input))
+    pub const fn const_box_shadow_left(input: &'static CssBoxed<StyleBoxShadow>) -> Self {
+        CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(BoxOrStatic::from_static(input)))
     }
-    pub const fn const_box_shadow_right(input: StyleBoxShadow) -> Self {
-        CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(input))
+    pub const fn const_box_shadow_right(input: &'static CssBoxed<StyleBoxShadow>) -> Self {
+        CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(BoxOrStatic::from_static(input)))
     }
-    pub const fn const_box_shadow_top(input: StyleBoxShadow) -> Self {
-        CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(input))
+    pub const fn const_box_shadow_top(input: &'static CssBoxed<StyleBoxShadow>) -> Self {
+        CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(BoxOrStatic::from_static(input)))
     }
-    pub const fn const_box_shadow_bottom(input: StyleBoxShadow) -> Self {
-        CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(input))
+    pub const fn const_box_shadow_bottom(input: &'static CssBoxed<StyleBoxShadow>) -> Self {
+        CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(BoxOrStatic::from_static(input)))
     }
     pub const fn const_opacity(input: StyleOpacity) -> Self {
         CssProperty::Opacity(StyleOpacityValue::Exact(input))
     }
-    pub const fn const_transform(input: StyleTransformVec) -> Self {
-        CssProperty::Transform(StyleTransformVecValue::Exact(input))
+    pub const fn const_transform(input: &'static CssBoxed<StyleTransformVec>) -> Self {
+        CssProperty::Transform(StyleTransformVecValue::Exact(BoxOrStatic::from_static(input)))
     }
-    pub const fn const_transform_origin(input: StyleTransformOrigin) -> Self {
-        CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input))
+    pub const fn const_transform_origin(input: &'static CssBoxed<StyleTransformOrigin>) -> Self {
+        CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(BoxOrStatic::from_static(input)))
     }
-    pub const fn const_perspective_origin(input: StylePerspectiveOrigin) -> Self {
-        CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input))
+    pub const fn const_perspective_origin(input: &'static CssBoxed<StylePerspectiveOrigin>) -> Self {
+        CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(BoxOrStatic::from_static(input)))
     }
     pub const fn const_backface_visiblity(input: StyleBackfaceVisibility) -> Self {
         CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input))
@@ -2011,6 +3229,62 @@ impl SvgCubicCurve {
         (a_x * t * t * t) + (b_x * t * t) + (c_x * t) + self.start.y as f64
     }
 
+    /// Solves the curve for the `t` at which it reaches the given x value.
+    ///
+    /// The curve parameter is found with Newton's method, which converges in
+    /// a few iterations for most curves. Flat curves (where the slope gets
+    /// close to zero) fall back to bisection, which always converges as long
+    /// as `x(t)` is monotonic, i.e. for all CSS easing curves.
+    pub fn get_t_at_x(&self, x: f64) -> f64 {
+        const EPSILON: f64 = 1e-6;
+        const NEWTON_ITERATIONS: usize = 8;
+        const BISECTION_ITERATIONS: usize = 32;
+
+        let c_x = 3.0 * (self.ctrl_1.x as f64 - self.start.x as f64);
+        let b_x = 3.0 * (self.ctrl_2.x as f64 - self.ctrl_1.x as f64) - c_x;
+        let a_x = self.end.x as f64 - self.start.x as f64 - c_x - b_x;
+
+        let mut t = x;
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = self.get_x_at_t(t) - x;
+            if error.abs() < EPSILON {
+                return t;
+            }
+            let slope = (3.0 * a_x * t + 2.0 * b_x) * t + c_x;
+            if slope.abs() < EPSILON {
+                break;
+            }
+            t -= error / slope;
+            if t < 0.0 || t > 1.0 {
+                break;
+            }
+        }
+
+        let mut lower = 0.0;
+        let mut upper = 1.0;
+        t = x;
+        for _ in 0..BISECTION_ITERATIONS {
+            let error = self.get_x_at_t(t) - x;
+            if error.abs() < EPSILON {
+                break;
+            }
+            if error > 0.0 {
+                upper = t;
+            } else {
+                lower = t;
+            }
+            t = (lower + upper) / 2.0;
+        }
+
+        t
+    }
+
+    /// Evaluates the curve as an easing function, i.e. returns the
+    /// y value at the given x value (see `get_t_at_x`)
+    pub fn get_y_at_x(&self, x: f64) -> f64 {
+        self.get_y_at_t(self.get_t_at_x(x))
+    }
+
     pub fn get_length(&self) -> f64 {
         // NOTE: this arc length parametrization is not very precise, but fast
         let mut arc_length = 0.0;
@@ -2269,7 +3543,7 @@ impl AnimationInterpolationFunction {
     }
 
     pub fn evaluate(self, t: f64) -> f32 {
-        self.get_curve().get_y_at_t(t) as f32
+        self.get_curve().get_y_at_x(t) as f32
     }
 }
 
@@ -2281,6 +3555,7 @@ pub struct InterpolateResolver {
     pub parent_rect_height: f32,
     pub current_rect_width: f32,
     pub current_rect_height: f32,
+    pub em_size: f32,
 }
 
 impl CssProperty {
@@ -2294,6 +3569,8 @@ impl CssProperty {
             CssProperty::FontSize(v) => v.get_css_value_fmt(),
             CssProperty::FontFamily(v) => v.get_css_value_fmt(),
             CssProperty::TextAlign(v) => v.get_css_value_fmt(),
+            CssProperty::VerticalAlign(v) => v.get_css_value_fmt(),
+            CssProperty::Direction(v) => v.get_css_value_fmt(),
             CssProperty::LetterSpacing(v) => v.get_css_value_fmt(),
             CssProperty::LineHeight(v) => v.get_css_value_fmt(),
             CssProperty::WordSpacing(v) => v.get_css_value_fmt(),
@@ -2320,6 +3597,10 @@ impl CssProperty {
             CssProperty::JustifyContent(v) => v.get_css_value_fmt(),
             CssProperty::AlignItems(v) => v.get_css_value_fmt(),
             CssProperty::AlignContent(v) => v.get_css_value_fmt(),
+            CssProperty::GridTemplateColumns(v) => v.get_css_value_fmt(),
+            CssProperty::GridTemplateRows(v) => v.get_css_value_fmt(),
+            CssProperty::GridColumn(v) => v.get_css_value_fmt(),
+            CssProperty::GridRow(v) => v.get_css_value_fmt(),
             CssProperty::BackgroundContent(v) => v.get_css_value_fmt(),
             CssProperty::BackgroundPosition(v) => v.get_css_value_fmt(),
             CssProperty::BackgroundSize(v) => v.get_css_value_fmt(),
@@ -2361,9 +3642,18 @@ impl CssProperty {
             CssProperty::PerspectiveOrigin(v) => v.get_css_value_fmt(),
             CssProperty::BackfaceVisibility(v) => v.get_css_value_fmt(),
             CssProperty::MixBlendMode(v) => v.get_css_value_fmt(),
+            CssProperty::Isolation(v) => v.get_css_value_fmt(),
+            CssProperty::ImageRendering(v) => v.get_css_value_fmt(),
             CssProperty::Filter(v) => v.get_css_value_fmt(),
             CssProperty::BackdropFilter(v) => v.get_css_value_fmt(),
             CssProperty::TextShadow(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionProperty(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionDuration(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionTimingFunction(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionDelay(v) => v.get_css_value_fmt(),
+            CssProperty::CaretColor(v) => v.get_css_value_fmt(),
+            CssProperty::SelectionColor(v) => v.get_css_value_fmt(),
+            CssProperty::SelectionBackground(v) => v.get_css_value_fmt(),
         }
     }
 
@@ -2425,7 +3715,7 @@ impl CssProperty {
                     .copied()
                     .unwrap_or(LayoutWidth::px(interpolate_resolver.current_rect_width));
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::Width(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::Width(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::Height(start), CssProperty::Height(end)) => {
                 let start = start
@@ -2433,47 +3723,47 @@ impl CssProperty {
                     .copied()
                     .unwrap_or(LayoutHeight::px(interpolate_resolver.current_rect_height));
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::Height(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::Height(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::MinWidth(start), CssProperty::MinWidth(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::MinWidth(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::MinWidth(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::MinHeight(start), CssProperty::MinHeight(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::MinHeight(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::MinHeight(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::MaxWidth(start), CssProperty::MaxWidth(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::MaxWidth(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::MaxWidth(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::MaxHeight(start), CssProperty::MaxHeight(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::MaxHeight(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::MaxHeight(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::Top(start), CssProperty::Top(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::Top(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::Top(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::Right(start), CssProperty::Right(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::Right(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::Right(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::Left(start), CssProperty::Left(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::Left(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::Left(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::Bottom(start), CssProperty::Bottom(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::Bottom(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::Bottom(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::FlexGrow(start), CssProperty::FlexGrow(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
@@ -2488,42 +3778,42 @@ impl CssProperty {
             (CssProperty::PaddingTop(start), CssProperty::PaddingTop(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::PaddingTop(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::PaddingTop(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::PaddingLeft(start), CssProperty::PaddingLeft(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::PaddingLeft(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::PaddingLeft(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::PaddingRight(start), CssProperty::PaddingRight(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::PaddingRight(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::PaddingRight(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::PaddingBottom(start), CssProperty::PaddingBottom(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::PaddingBottom(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::PaddingBottom(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::MarginTop(start), CssProperty::MarginTop(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::MarginTop(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::MarginTop(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::MarginLeft(start), CssProperty::MarginLeft(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::MarginLeft(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::MarginLeft(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::MarginRight(start), CssProperty::MarginRight(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::MarginRight(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::MarginRight(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::MarginBottom(start), CssProperty::MarginBottom(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::MarginBottom(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                CssProperty::MarginBottom(CssLengthValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::BorderTopLeftRadius(start), CssProperty::BorderTopLeftRadius(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
@@ -2605,19 +3895,26 @@ impl CssProperty {
                 CssProperty::Opacity(CssPropertyValue::Exact(start.interpolate(&end, t)))
             }
             (CssProperty::TransformOrigin(start), CssProperty::TransformOrigin(end)) => {
-                let start = start.get_property().copied().unwrap_or_default();
-                let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::TransformOrigin(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                let start = start.get_property().map(|s| **s).unwrap_or_default();
+                let end = end.get_property().map(|e| **e).unwrap_or_default();
+                CssProperty::TransformOrigin(CssPropertyValue::exact(start.interpolate(&end, t)))
             }
             (CssProperty::PerspectiveOrigin(start), CssProperty::PerspectiveOrigin(end)) => {
-                let start = start.get_property().copied().unwrap_or_default();
-                let end = end.get_property().copied().unwrap_or_default();
-                CssProperty::PerspectiveOrigin(CssPropertyValue::Exact(start.interpolate(&end, t)))
+                let start = start.get_property().map(|s| **s).unwrap_or_default();
+                let end = end.get_property().map(|e| **e).unwrap_or_default();
+                CssProperty::PerspectiveOrigin(CssPropertyValue::exact(start.interpolate(&end, t)))
+            }
+            (CssProperty::Transform(start), CssProperty::Transform(end)) => {
+                let start = start.get_property().map(|s| (**s).clone()).unwrap_or_default();
+                let end = end.get_property().map(|e| (**e).clone()).unwrap_or_default();
+                let ctx = TransformResolutionContext {
+                    reference_width: interpolate_resolver.current_rect_width,
+                    reference_height: interpolate_resolver.current_rect_height,
+                    em_size: interpolate_resolver.em_size,
+                };
+                CssProperty::Transform(CssPropertyValue::exact(start.interpolate(&end, t, &ctx)))
             }
             /*
-            animate transform:
-            CssProperty::Transform(CssPropertyValue<StyleTransformVec>),
-
             animate box shadow:
             CssProperty::BoxShadowLeft(CssPropertyValue<StyleBoxShadow>),
             CssProperty::BoxShadowRight(CssPropertyValue<StyleBoxShadow>),
@@ -2641,6 +3938,29 @@ impl CssProperty {
     }
 }
 
+/// FNV-1a hasher: unlike `std::collections::hash_map::DefaultHasher`,
+/// the output doesn't depend on a random seed
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= *b as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
 impl_vec!(CssProperty, CssPropertyVec, CssPropertyVecDestructor);
 impl_vec_debug!(CssProperty, CssPropertyVec);
 impl_vec_partialord!(CssProperty, CssPropertyVec);
@@ -2650,6 +3970,157 @@ impl_vec_partialeq!(CssProperty, CssPropertyVec);
 impl_vec_eq!(CssProperty, CssPropertyVec);
 impl_vec_hash!(CssProperty, CssPropertyVec);
 
+impl CssPropertyVec {
+    /// Hashes all properties that can trigger a re-layout (see
+    /// `CssProperty::can_trigger_relayout`), in order. Paint-only properties
+    /// (such as `color` or `opacity`) don't change the hash, so the result
+    /// can be used as a key for caching layout results.
+    ///
+    /// The hash is stable between runs of the same build.
+    pub fn layout_hash(&self) -> u64 {
+        let mut hasher = FnvHasher::default();
+        for prop in self.iter().filter(|p| p.can_trigger_relayout()) {
+            prop.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Counterpart to `layout_hash`: hashes all properties that can't
+    /// trigger a re-layout (colors, backgrounds, border radii, shadows,
+    /// opacity, transforms, ...), in order. If only the `paint_hash` changes,
+    /// the node only has to be repainted.
+    pub fn paint_hash(&self) -> u64 {
+        let mut hasher = FnvHasher::default();
+        for prop in self.iter().filter(|p| !p.can_trigger_relayout()) {
+            prop.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Sorts the properties by `CssProperty::cascade_sort_key` and removes
+    /// all properties that are overwritten by a later property of the same type.
+    ///
+    /// The properties have to be in declaration order (ex. sorted by specificity,
+    /// then source order). Shorthands are already expanded into their longhands
+    /// by the parser, so `background: red; background-color: blue;` are two
+    /// `BackgroundContent` properties: the sort is stable, so the one declared
+    /// last (`blue`) is kept - just like the last insert into the
+    /// `BTreeMap<CssPropertyType, CssProperty>` of the styled DOM wins.
+    pub fn sort_for_cascade(&mut self) {
+        use alloc::collections::btree_set::BTreeSet;
+
+        let mut props = core::mem::replace(self, CssPropertyVec::new()).into_library_owned_vec();
+        props.sort_by_key(|p| p.cascade_sort_key());
+
+        // keep the last property of every type
+        let mut seen = BTreeSet::new();
+        let mut deduped = props
+            .into_iter()
+            .rev()
+            .filter(|p| seen.insert(p.get_type()))
+            .collect::<Vec<_>>();
+        deduped.reverse();
+
+        *self = CssPropertyVec::from_vec(deduped);
+    }
+
+    /// Formats the properties as CSS declarations (`key: value;`), separated
+    /// by spaces. If the shadows of all four sides are equal, they are
+    /// printed as a single `box-shadow` declaration. `overflow-x` and
+    /// `overflow-y` are printed as a single `overflow` declaration, which
+    /// only has one value if both axes are equal.
+    pub fn to_css_string(&self) -> String {
+        let props = self.as_ref();
+        let box_shadow = collapse_box_shadow(props);
+        let overflow = collapse_overflow(props);
+        let mut box_shadow_printed = false;
+        let mut overflow_printed = false;
+        let mut declarations = Vec::new();
+
+        for prop in props {
+            let is_box_shadow_side = match prop {
+                CssProperty::BoxShadowLeft(_)
+                | CssProperty::BoxShadowRight(_)
+                | CssProperty::BoxShadowTop(_)
+                | CssProperty::BoxShadowBottom(_) => true,
+                _ => false,
+            };
+            let is_overflow_axis = match prop {
+                CssProperty::OverflowX(_) | CssProperty::OverflowY(_) => true,
+                _ => false,
+            };
+
+            match (box_shadow, overflow) {
+                (Some(shadow), _) if is_box_shadow_side => {
+                    if !box_shadow_printed {
+                        declarations.push(format!(
+                            "box-shadow: {};",
+                            shadow.print_as_css_value().trim_end()
+                        ));
+                        box_shadow_printed = true;
+                    }
+                }
+                (_, Some((x, y))) if is_overflow_axis => {
+                    if !overflow_printed {
+                        if x == y {
+                            declarations.push(format!("overflow: {};", x));
+                        } else {
+                            declarations.push(format!("overflow: {} {};", x, y));
+                        }
+                        overflow_printed = true;
+                    }
+                }
+                _ => declarations.push(prop.format_css()),
+            }
+        }
+
+        declarations.join(" ")
+    }
+}
+
+/// Inverse of `CssProperty::box_shadow`: returns the shadow if all four
+/// sides are set to the same (exact) value. If a side is set multiple
+/// times, the last declaration wins.
+pub fn collapse_box_shadow(props: &[CssProperty]) -> Option<StyleBoxShadow> {
+    let mut sides = [None; 4];
+
+    for prop in props {
+        let (side, value) = match prop {
+            CssProperty::BoxShadowLeft(v) => (0, v),
+            CssProperty::BoxShadowRight(v) => (1, v),
+            CssProperty::BoxShadowTop(v) => (2, v),
+            CssProperty::BoxShadowBottom(v) => (3, v),
+            _ => continue,
+        };
+        sides[side] = Some(value.get_property().map(|v| **v));
+    }
+
+    let first = sides[0]??;
+    if sides.iter().all(|side| *side == Some(Some(first))) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+/// Inverse of `CssProperty::overflow`: returns the `(x, y)` overflow if
+/// both axes are set to an exact value. If an axis is set multiple times,
+/// the last declaration wins.
+pub fn collapse_overflow(props: &[CssProperty]) -> Option<(LayoutOverflow, LayoutOverflow)> {
+    let mut axes = [None; 2];
+
+    for prop in props {
+        let (axis, value) = match prop {
+            CssProperty::OverflowX(v) => (0, v),
+            CssProperty::OverflowY(v) => (1, v),
+            _ => continue,
+        };
+        axes[axis] = Some(value.get_property().copied());
+    }
+
+    Some((axes[0]??, axes[1]??))
+}
+
 macro_rules! css_property_from_type {
     ($prop_type:expr, $content_type:ident) => {{
         match $prop_type {
@@ -2657,6 +4128,10 @@ macro_rules! css_property_from_type {
             CssPropertyType::FontSize => CssProperty::FontSize(CssPropertyValue::$content_type),
             CssPropertyType::FontFamily => CssProperty::FontFamily(CssPropertyValue::$content_type),
             CssPropertyType::TextAlign => CssProperty::TextAlign(CssPropertyValue::$content_type),
+            CssPropertyType::VerticalAlign => {
+                CssProperty::VerticalAlign(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::Direction => CssProperty::Direction(CssPropertyValue::$content_type),
             CssPropertyType::LetterSpacing => {
                 CssProperty::LetterSpacing(CssPropertyValue::$content_type)
             }
@@ -2669,17 +4144,17 @@ macro_rules! css_property_from_type {
             CssPropertyType::Display => CssProperty::Display(CssPropertyValue::$content_type),
             CssPropertyType::Float => CssProperty::Float(CssPropertyValue::$content_type),
             CssPropertyType::BoxSizing => CssProperty::BoxSizing(CssPropertyValue::$content_type),
-            CssPropertyType::Width => CssProperty::Width(CssPropertyValue::$content_type),
-            CssPropertyType::Height => CssProperty::Height(CssPropertyValue::$content_type),
-            CssPropertyType::MinWidth => CssProperty::MinWidth(CssPropertyValue::$content_type),
-            CssPropertyType::MinHeight => CssProperty::MinHeight(CssPropertyValue::$content_type),
-            CssPropertyType::MaxWidth => CssProperty::MaxWidth(CssPropertyValue::$content_type),
-            CssPropertyType::MaxHeight => CssProperty::MaxHeight(CssPropertyValue::$content_type),
+            CssPropertyType::Width => CssProperty::Width(CssLengthValue::$content_type),
+            CssPropertyType::Height => CssProperty::Height(CssLengthValue::$content_type),
+            CssPropertyType::MinWidth => CssProperty::MinWidth(CssLengthValue::$content_type),
+            CssPropertyType::MinHeight => CssProperty::MinHeight(CssLengthValue::$content_type),
+            CssPropertyType::MaxWidth => CssProperty::MaxWidth(CssLengthValue::$content_type),
+            CssPropertyType::MaxHeight => CssProperty::MaxHeight(CssLengthValue::$content_type),
             CssPropertyType::Position => CssProperty::Position(CssPropertyValue::$content_type),
-            CssPropertyType::Top => CssProperty::Top(CssPropertyValue::$content_type),
-            CssPropertyType::Right => CssProperty::Right(CssPropertyValue::$content_type),
-            CssPropertyType::Left => CssProperty::Left(CssPropertyValue::$content_type),
-            CssPropertyType::Bottom => CssProperty::Bottom(CssPropertyValue::$content_type),
+            CssPropertyType::Top => CssProperty::Top(CssLengthValue::$content_type),
+            CssPropertyType::Right => CssProperty::Right(CssLengthValue::$content_type),
+            CssPropertyType::Left => CssProperty::Left(CssLengthValue::$content_type),
+            CssPropertyType::Bottom => CssProperty::Bottom(CssLengthValue::$content_type),
             CssPropertyType::FlexWrap => CssProperty::FlexWrap(CssPropertyValue::$content_type),
             CssPropertyType::FlexDirection => {
                 CssProperty::FlexDirection(CssPropertyValue::$content_type)
@@ -2693,25 +4168,33 @@ macro_rules! css_property_from_type {
             CssPropertyType::AlignContent => {
                 CssProperty::AlignContent(CssPropertyValue::$content_type)
             }
+            CssPropertyType::GridTemplateColumns => {
+                CssProperty::GridTemplateColumns(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::GridTemplateRows => {
+                CssProperty::GridTemplateRows(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::GridColumn => CssProperty::GridColumn(CssPropertyValue::$content_type),
+            CssPropertyType::GridRow => CssProperty::GridRow(CssPropertyValue::$content_type),
             CssPropertyType::OverflowX => CssProperty::OverflowX(CssPropertyValue::$content_type),
             CssPropertyType::OverflowY => CssProperty::OverflowY(CssPropertyValue::$content_type),
-            CssPropertyType::PaddingTop => CssProperty::PaddingTop(CssPropertyValue::$content_type),
+            CssPropertyType::PaddingTop => CssProperty::PaddingTop(CssLengthValue::$content_type),
             CssPropertyType::PaddingLeft => {
-                CssProperty::PaddingLeft(CssPropertyValue::$content_type)
+                CssProperty::PaddingLeft(CssLengthValue::$content_type)
             }
             CssPropertyType::PaddingRight => {
-                CssProperty::PaddingRight(CssPropertyValue::$content_type)
+                CssProperty::PaddingRight(CssLengthValue::$content_type)
             }
             CssPropertyType::PaddingBottom => {
-                CssProperty::PaddingBottom(CssPropertyValue::$content_type)
+                CssProperty::PaddingBottom(CssLengthValue::$content_type)
             }
-            CssPropertyType::MarginTop => CssProperty::MarginTop(CssPropertyValue::$content_type),
-            CssPropertyType::MarginLeft => CssProperty::MarginLeft(CssPropertyValue::$content_type),
+            CssPropertyType::MarginTop => CssProperty::MarginTop(CssLengthValue::$content_type),
+            CssPropertyType::MarginLeft => CssProperty::MarginLeft(CssLengthValue::$content_type),
             CssPropertyType::MarginRight => {
-                CssProperty::MarginRight(CssPropertyValue::$content_type)
+                CssProperty::MarginRight(CssLengthValue::$content_type)
             }
             CssPropertyType::MarginBottom => {
-                CssProperty::MarginBottom(CssPropertyValue::$content_type)
+                CssProperty::MarginBottom(CssLengthValue::$content_type)
             }
             CssPropertyType::BackgroundContent => {
                 CssProperty::BackgroundContent(CssPropertyValue::$content_type)
@@ -2802,11 +4285,30 @@ macro_rules! css_property_from_type {
             CssPropertyType::MixBlendMode => {
                 CssProperty::MixBlendMode(CssPropertyValue::$content_type)
             }
+            CssPropertyType::Isolation => CssProperty::Isolation(CssPropertyValue::$content_type),
+            CssPropertyType::ImageRendering => {
+                CssProperty::ImageRendering(CssPropertyValue::$content_type)
+            }
             CssPropertyType::Filter => CssProperty::Filter(CssPropertyValue::$content_type),
             CssPropertyType::BackdropFilter => {
                 CssProperty::BackdropFilter(CssPropertyValue::$content_type)
             }
             CssPropertyType::TextShadow => CssProperty::TextShadow(CssPropertyValue::$content_type),
+            CssPropertyType::TransitionProperty => {
+                CssProperty::TransitionProperty(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::TransitionDuration => {
+                CssProperty::TransitionDuration(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::TransitionTimingFunction => {
+                CssProperty::TransitionTimingFunction(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::TransitionDelay => {
+                CssProperty::TransitionDelay(CssPropertyValue::$content_type)
+            }
+            CssPropertyType::CaretColor => CssProperty::CaretColor(CssPropertyValue::$content_type),
+            CssPropertyType::SelectionColor => CssProperty::SelectionColor(CssPropertyValue::$content_type),
+            CssPropertyType::SelectionBackground => CssProperty::SelectionBackground(CssPropertyValue::$content_type),
         }
     }};
 }
@@ -2819,6 +4321,8 @@ impl CssProperty {
             CssProperty::FontSize(_) => CssPropertyType::FontSize,
             CssProperty::FontFamily(_) => CssPropertyType::FontFamily,
             CssProperty::TextAlign(_) => CssPropertyType::TextAlign,
+            CssProperty::VerticalAlign(_) => CssPropertyType::VerticalAlign,
+            CssProperty::Direction(_) => CssPropertyType::Direction,
             CssProperty::LetterSpacing(_) => CssPropertyType::LetterSpacing,
             CssProperty::LineHeight(_) => CssPropertyType::LineHeight,
             CssProperty::WordSpacing(_) => CssPropertyType::WordSpacing,
@@ -2845,6 +4349,10 @@ impl CssProperty {
             CssProperty::JustifyContent(_) => CssPropertyType::JustifyContent,
             CssProperty::AlignItems(_) => CssPropertyType::AlignItems,
             CssProperty::AlignContent(_) => CssPropertyType::AlignContent,
+            CssProperty::GridTemplateColumns(_) => CssPropertyType::GridTemplateColumns,
+            CssProperty::GridTemplateRows(_) => CssPropertyType::GridTemplateRows,
+            CssProperty::GridColumn(_) => CssPropertyType::GridColumn,
+            CssProperty::GridRow(_) => CssPropertyType::GridRow,
             CssProperty::BackgroundContent(_) => CssPropertyType::BackgroundContent,
             CssProperty::BackgroundPosition(_) => CssPropertyType::BackgroundPosition,
             CssProperty::BackgroundSize(_) => CssPropertyType::BackgroundSize,
@@ -2886,9 +4394,101 @@ impl CssProperty {
             CssProperty::TransformOrigin(_) => CssPropertyType::TransformOrigin,
             CssProperty::BackfaceVisibility(_) => CssPropertyType::BackfaceVisibility,
             CssProperty::MixBlendMode(_) => CssPropertyType::MixBlendMode,
+            CssProperty::Isolation(_) => CssPropertyType::Isolation,
+            CssProperty::ImageRendering(_) => CssPropertyType::ImageRendering,
             CssProperty::Filter(_) => CssPropertyType::Filter,
             CssProperty::BackdropFilter(_) => CssPropertyType::BackdropFilter,
             CssProperty::TextShadow(_) => CssPropertyType::TextShadow,
+            CssProperty::TransitionProperty(_) => CssPropertyType::TransitionProperty,
+            CssProperty::TransitionDuration(_) => CssPropertyType::TransitionDuration,
+            CssProperty::TransitionTimingFunction(_) => CssPropertyType::TransitionTimingFunction,
+            CssProperty::TransitionDelay(_) => CssPropertyType::TransitionDelay,
+            CssProperty::CaretColor(_) => CssPropertyType::CaretColor,
+            CssProperty::SelectionColor(_) => CssPropertyType::SelectionColor,
+            CssProperty::SelectionBackground(_) => CssPropertyType::SelectionBackground,
+        }
+    }
+
+    /// Returns whether this property will be inherited during cascading,
+    /// see `CssPropertyType::is_inheritable`
+    pub fn is_inheritable(&self) -> bool {
+        self.get_type().is_inheritable()
+    }
+
+    /// Returns whether this property can trigger a re-layout,
+    /// see `CssPropertyType::can_trigger_relayout`
+    pub fn can_trigger_relayout(&self) -> bool {
+        self.get_type().can_trigger_relayout()
+    }
+
+    /// Returns whether this property is a GPU property,
+    /// see `CssPropertyType::is_gpu_only_property`
+    pub fn is_gpu_only_property(&self) -> bool {
+        self.get_type().is_gpu_only_property()
+    }
+
+    /// Returns the key that `CssPropertyVec::sort_for_cascade` sorts by:
+    /// the shorthand group (see `CssPropertyType::cascade_category`),
+    /// then the property type
+    pub fn cascade_sort_key(&self) -> (u8, CssPropertyType) {
+        let prop_type = self.get_type();
+        (prop_type.cascade_category(), prop_type)
+    }
+
+    /// Replaces the `initial`, `inherit`, `auto` and `none` keywords with
+    /// the value they stand for:
+    ///
+    /// - `initial` resolves to `prop_type.initial_value()`
+    /// - `inherit` resolves to the computed value of the parent, or to the
+    ///   initial value on the root node (`parent_value` is `None`)
+    /// - `auto` / `none` resolve to the matching value if the property has
+    ///   one (`display: none`, `overflow: auto`, ...), stay as they are if
+    ///   they are the initial value (`width: auto`) and resolve to the
+    ///   initial value if they are invalid for the property
+    ///
+    /// Exact values and `calc()` are returned unchanged. `prop_type`
+    /// has to be the type of `self`.
+    pub fn resolve_keyword(
+        &self,
+        parent_value: Option<&CssProperty>,
+        prop_type: CssPropertyType,
+    ) -> CssProperty {
+        if *self == CssProperty::initial(prop_type) {
+            prop_type.initial_value()
+        } else if *self == CssProperty::inherit(prop_type) {
+            match parent_value {
+                Some(p) if p.get_type() == prop_type => p.resolve_keyword(None, prop_type),
+                _ => prop_type.initial_value(),
+            }
+        } else if *self == CssProperty::auto(prop_type) {
+            Self::resolve_auto(prop_type).unwrap_or_else(|| prop_type.initial_value())
+        } else if *self == CssProperty::none(prop_type) {
+            Self::resolve_none(prop_type).unwrap_or_else(|| prop_type.initial_value())
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Concrete value of `auto` for properties where it isn't the initial value
+    fn resolve_auto(prop_type: CssPropertyType) -> Option<CssProperty> {
+        match prop_type {
+            CssPropertyType::OverflowX => Some(CssProperty::overflow_x(LayoutOverflow::Auto)),
+            CssPropertyType::OverflowY => Some(CssProperty::overflow_y(LayoutOverflow::Auto)),
+            _ => Option::None,
+        }
+    }
+
+    /// Concrete value of `none` for properties where it isn't the initial value
+    fn resolve_none(prop_type: CssPropertyType) -> Option<CssProperty> {
+        let none = BorderStyle::None;
+        match prop_type {
+            CssPropertyType::Display => Some(CssProperty::display(LayoutDisplay::None)),
+            CssPropertyType::BorderTopStyle => Some(CssProperty::border_top_style(StyleBorderTopStyle { inner: none })),
+            CssPropertyType::BorderRightStyle => Some(CssProperty::border_right_style(StyleBorderRightStyle { inner: none })),
+            CssPropertyType::BorderLeftStyle => Some(CssProperty::border_left_style(StyleBorderLeftStyle { inner: none })),
+            CssPropertyType::BorderBottomStyle => Some(CssProperty::border_bottom_style(StyleBorderBottomStyle { inner: none })),
+            CssPropertyType::TransitionProperty => Some(CssProperty::transition_property(StyleTransitionProperty::None)),
+            _ => Option::None,
         }
     }
 
@@ -2907,18 +4507,52 @@ impl CssProperty {
         css_property_from_type!(prop_type, Inherit)
     }
 
+    /// Creates a `calc()` value for the given property, returns `None`
+    /// if the property isn't a length (`width`, `top`, `margin-left`, ...)
+    pub fn calc(prop_type: CssPropertyType, calc: CalcLength) -> Option<Self> {
+        use self::CssPropertyType::*;
+        let value = match prop_type {
+            Width => CssProperty::Width(CssLengthValue::calc(calc)),
+            Height => CssProperty::Height(CssLengthValue::calc(calc)),
+            MinWidth => CssProperty::MinWidth(CssLengthValue::calc(calc)),
+            MinHeight => CssProperty::MinHeight(CssLengthValue::calc(calc)),
+            MaxWidth => CssProperty::MaxWidth(CssLengthValue::calc(calc)),
+            MaxHeight => CssProperty::MaxHeight(CssLengthValue::calc(calc)),
+            Top => CssProperty::Top(CssLengthValue::calc(calc)),
+            Right => CssProperty::Right(CssLengthValue::calc(calc)),
+            Left => CssProperty::Left(CssLengthValue::calc(calc)),
+            Bottom => CssProperty::Bottom(CssLengthValue::calc(calc)),
+            PaddingTop => CssProperty::PaddingTop(CssLengthValue::calc(calc)),
+            PaddingLeft => CssProperty::PaddingLeft(CssLengthValue::calc(calc)),
+            PaddingRight => CssProperty::PaddingRight(CssLengthValue::calc(calc)),
+            PaddingBottom => CssProperty::PaddingBottom(CssLengthValue::calc(calc)),
+            MarginTop => CssProperty::MarginTop(CssLengthValue::calc(calc)),
+            MarginLeft => CssProperty::MarginLeft(CssLengthValue::calc(calc)),
+            MarginRight => CssProperty::MarginRight(CssLengthValue::calc(calc)),
+            MarginBottom => CssProperty::MarginBottom(CssLengthValue::calc(calc)),
+            _ => return None,
+        };
+        Some(value)
+    }
+
     pub const fn text_color(input: StyleTextColor) -> Self {
         CssProperty::TextColor(CssPropertyValue::Exact(input))
     }
     pub const fn font_size(input: StyleFontSize) -> Self {
         CssProperty::FontSize(CssPropertyValue::Exact(input))
     }
-    pub const fn font_family(input: StyleFontFamilyVec) -> Self {
-        CssProperty::FontFamily(CssPropertyValue::Exact(input))
+    pub fn font_family(input: StyleFontFamilyVec) -> Self {
+        CssProperty::FontFamily(CssPropertyValue::Exact(BoxOrStatic::new(input)))
     }
     pub const fn text_align(input: StyleTextAlign) -> Self {
         CssProperty::TextAlign(CssPropertyValue::Exact(input))
     }
+    pub fn vertical_align(input: StyleVerticalAlign) -> Self {
+        CssProperty::VerticalAlign(CssPropertyValue::Exact(BoxOrStatic::new(input)))
+    }
+    pub const fn direction(input: StyleDirection) -> Self {
+        CssProperty::Direction(CssPropertyValue::Exact(input))
+    }
     pub const fn letter_spacing(input: StyleLetterSpacing) -> Self {
         CssProperty::LetterSpacing(CssPropertyValue::Exact(input))
     }
@@ -2944,37 +4578,37 @@ impl CssProperty {
         CssProperty::BoxSizing(CssPropertyValue::Exact(input))
     }
     pub const fn width(input: LayoutWidth) -> Self {
-        CssProperty::Width(CssPropertyValue::Exact(input))
+        CssProperty::Width(CssLengthValue::Exact(input))
     }
     pub const fn height(input: LayoutHeight) -> Self {
-        CssProperty::Height(CssPropertyValue::Exact(input))
+        CssProperty::Height(CssLengthValue::Exact(input))
     }
     pub const fn min_width(input: LayoutMinWidth) -> Self {
-        CssProperty::MinWidth(CssPropertyValue::Exact(input))
+        CssProperty::MinWidth(CssLengthValue::Exact(input))
     }
     pub const fn min_height(input: LayoutMinHeight) -> Self {
-        CssProperty::MinHeight(CssPropertyValue::Exact(input))
+        CssProperty::MinHeight(CssLengthValue::Exact(input))
     }
     pub const fn max_width(input: LayoutMaxWidth) -> Self {
-        CssProperty::MaxWidth(CssPropertyValue::Exact(input))
+        CssProperty::MaxWidth(CssLengthValue::Exact(input))
     }
     pub const fn max_height(input: LayoutMaxHeight) -> Self {
-        CssProperty::MaxHeight(CssPropertyValue::Exact(input))
+        CssProperty::MaxHeight(CssLengthValue::Exact(input))
     }
     pub const fn position(input: LayoutPosition) -> Self {
         CssProperty::Position(CssPropertyValue::Exact(input))
     }
     pub const fn top(input: LayoutTop) -> Self {
-        CssProperty::Top(CssPropertyValue::Exact(input))
+        CssProperty::Top(CssLengthValue::Exact(input))
     }
     pub const fn right(input: LayoutRight) -> Self {
-        CssProperty::Right(CssPropertyValue::Exact(input))
+        CssProperty::Right(CssLengthValue::Exact(input))
     }
     pub const fn left(input: LayoutLeft) -> Self {
-        CssProperty::Left(CssPropertyValue::Exact(input))
+        CssProperty::Left(CssLengthValue::Exact(input))
     }
     pub const fn bottom(input: LayoutBottom) -> Self {
-        CssProperty::Bottom(CssPropertyValue::Exact(input))
+        CssProperty::Bottom(CssLengthValue::Exact(input))
     }
     pub const fn flex_wrap(input: LayoutFlexWrap) -> Self {
         CssProperty::FlexWrap(CssPropertyValue::Exact(input))
@@ -2997,17 +4631,29 @@ impl CssProperty {
     pub const fn align_content(input: LayoutAlignContent) -> Self {
         CssProperty::AlignContent(CssPropertyValue::Exact(input))
     }
-    pub const fn background_content(input: StyleBackgroundContentVec) -> Self {
-        CssProperty::BackgroundContent(CssPropertyValue::Exact(input))
+    pub fn grid_template_columns(input: LayoutGridTemplateColumns) -> Self {
+        CssProperty::GridTemplateColumns(CssPropertyValue::Exact(BoxOrStatic::new(input)))
+    }
+    pub fn grid_template_rows(input: LayoutGridTemplateRows) -> Self {
+        CssProperty::GridTemplateRows(CssPropertyValue::Exact(BoxOrStatic::new(input)))
     }
-    pub const fn background_position(input: StyleBackgroundPositionVec) -> Self {
-        CssProperty::BackgroundPosition(CssPropertyValue::Exact(input))
+    pub const fn grid_column(input: LayoutGridColumn) -> Self {
+        CssProperty::GridColumn(CssPropertyValue::Exact(input))
     }
-    pub const fn background_size(input: StyleBackgroundSizeVec) -> Self {
-        CssProperty::BackgroundSize(CssPropertyValue::Exact(input))
+    pub const fn grid_row(input: LayoutGridRow) -> Self {
+        CssProperty::GridRow(CssPropertyValue::Exact(input))
     }
-    pub const fn background_repeat(input: StyleBackgroundRepeatVec) -> Self {
-        CssProperty::BackgroundRepeat(CssPropertyValue::Exact(input))
+    pub fn background_content(input: StyleBackgroundContentVec) -> Self {
+        CssProperty::BackgroundContent(CssPropertyValue::Exact(BoxOrStatic::new(input)))
+    }
+    pub fn background_position(input: StyleBackgroundPositionVec) -> Self {
+        CssProperty::BackgroundPosition(CssPropertyValue::Exact(BoxOrStatic::new(input)))
+    }
+    pub fn background_size(input: StyleBackgroundSizeVec) -> Self {
+        CssProperty::BackgroundSize(CssPropertyValue::Exact(BoxOrStatic::new(input)))
+    }
+    pub fn background_repeat(input: StyleBackgroundRepeatVec) -> Self {
+        CssProperty::BackgroundRepeat(CssPropertyValue::Exact(BoxOrStatic::new(input)))
     }
     pub const fn overflow_x(input: LayoutOverflow) -> Self {
         CssProperty::OverflowX(CssPropertyValue::Exact(input))
@@ -3015,29 +4661,33 @@ impl CssProperty {
     pub const fn overflow_y(input: LayoutOverflow) -> Self {
         CssProperty::OverflowY(CssPropertyValue::Exact(input))
     }
+    /// Sets `overflow-x` and `overflow-y` (`overflow: x y` shorthand)
+    pub const fn overflow(x: LayoutOverflow, y: LayoutOverflow) -> [Self; 2] {
+        [Self::overflow_x(x), Self::overflow_y(y)]
+    }
     pub const fn padding_top(input: LayoutPaddingTop) -> Self {
-        CssProperty::PaddingTop(CssPropertyValue::Exact(input))
+        CssProperty::PaddingTop(CssLengthValue::Exact(input))
     }
     pub const fn padding_left(input: LayoutPaddingLeft) -> Self {
-        CssProperty::PaddingLeft(CssPropertyValue::Exact(input))
+        CssProperty::PaddingLeft(CssLengthValue::Exact(input))
     }
     pub const fn padding_right(input: LayoutPaddingRight) -> Self {
-        CssProperty::PaddingRight(CssPropertyValue::Exact(input))
+        CssProperty::PaddingRight(CssLengthValue::Exact(input))
     }
     pub const fn padding_bottom(input: LayoutPaddingBottom) -> Self {
-        CssProperty::PaddingBottom(CssPropertyValue::Exact(input))
+        CssProperty::PaddingBottom(CssLengthValue::Exact(input))
     }
     pub const fn margin_top(input: LayoutMarginTop) -> Self {
-        CssProperty::MarginTop(CssPropertyValue::Exact(input))
+        CssProperty::MarginTop(CssLengthValue::Exact(input))
     }
     pub const fn margin_left(input: LayoutMarginLeft) -> Self {
-        CssProperty::MarginLeft(CssPropertyValue::Exact(input))
+        CssProperty::MarginLeft(CssLengthValue::Exact(input))
     }
     pub const fn margin_right(input: LayoutMarginRight) -> Self {
-        CssProperty::MarginRight(CssPropertyValue::Exact(input))
+        CssProperty::MarginRight(CssLengthValue::Exact(input))
     }
     pub const fn margin_bottom(input: LayoutMarginBottom) -> Self {
-        CssProperty::MarginBottom(CssPropertyValue::Exact(input))
+        CssProperty::MarginBottom(CssLengthValue::Exact(input))
     }
     pub const fn border_top_left_radius(input: StyleBorderTopLeftRadius) -> Self {
         CssProperty::BorderTopLeftRadius(CssPropertyValue::Exact(input))
@@ -3087,33 +4737,72 @@ impl CssProperty {
     pub const fn border_bottom_width(input: LayoutBorderBottomWidth) -> Self {
         CssProperty::BorderBottomWidth(CssPropertyValue::Exact(input))
     }
-    pub const fn box_shadow_left(input: StyleBoxShadow) -> Self {
-        CssProperty::BoxShadowLeft(CssPropertyValue::Exact(input))
+    pub fn box_shadow_left(input: StyleBoxShadow) -> Self {
+        CssProperty::BoxShadowLeft(CssPropertyValue::Exact(BoxOrStatic::new(input)))
     }
-    pub const fn box_shadow_right(input: StyleBoxShadow) -> Self {
-        CssProperty::BoxShadowRight(CssPropertyValue::Exact(input))
+    pub fn box_shadow_right(input: StyleBoxShadow) -> Self {
+        CssProperty::BoxShadowRight(CssPropertyValue::Exact(BoxOrStatic::new(input)))
     }
-    pub const fn box_shadow_top(input: StyleBoxShadow) -> Self {
-        CssProperty::BoxShadowTop(CssPropertyValue::Exact(input))
+    pub fn box_shadow_top(input: StyleBoxShadow) -> Self {
+        CssProperty::BoxShadowTop(CssPropertyValue::Exact(BoxOrStatic::new(input)))
     }
-    pub const fn box_shadow_bottom(input: StyleBoxShadow) -> Self {
-        CssProperty::BoxShadowBottom(CssPropertyValue::Exact(input))
+    pub fn box_shadow_bottom(input: StyleBoxShadow) -> Self {
+        CssProperty::BoxShadowBottom(CssPropertyValue::Exact(BoxOrStatic::new(input)))
+    }
+    /// Sets the same shadow on all four sides (`box-shadow` shorthand)
+    pub fn box_shadow(input: StyleBoxShadow) -> [Self; 4] {
+        [
+            Self::box_shadow_left(input),
+            Self::box_shadow_right(input),
+            Self::box_shadow_top(input),
+            Self::box_shadow_bottom(input),
+        ]
     }
     pub const fn opacity(input: StyleOpacity) -> Self {
         CssProperty::Opacity(CssPropertyValue::Exact(input))
     }
-    pub const fn transform(input: StyleTransformVec) -> Self {
-        CssProperty::Transform(CssPropertyValue::Exact(input))
+    pub fn transform(input: StyleTransformVec) -> Self {
+        CssProperty::Transform(CssPropertyValue::Exact(BoxOrStatic::new(input)))
     }
-    pub const fn transform_origin(input: StyleTransformOrigin) -> Self {
-        CssProperty::TransformOrigin(CssPropertyValue::Exact(input))
+    pub fn transform_origin(input: StyleTransformOrigin) -> Self {
+        CssProperty::TransformOrigin(CssPropertyValue::Exact(BoxOrStatic::new(input)))
     }
-    pub const fn perspective_origin(input: StylePerspectiveOrigin) -> Self {
-        CssProperty::PerspectiveOrigin(CssPropertyValue::Exact(input))
+    pub fn perspective_origin(input: StylePerspectiveOrigin) -> Self {
+        CssProperty::PerspectiveOrigin(CssPropertyValue::Exact(BoxOrStatic::new(input)))
     }
     pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self {
         CssProperty::BackfaceVisibility(CssPropertyValue::Exact(input))
     }
+    pub const fn mix_blend_mode(input: StyleMixBlendMode) -> Self {
+        CssProperty::MixBlendMode(CssPropertyValue::Exact(input))
+    }
+    pub const fn isolation(input: StyleIsolation) -> Self {
+        CssProperty::Isolation(CssPropertyValue::Exact(input))
+    }
+    pub const fn image_rendering(input: StyleImageRendering) -> Self {
+        CssProperty::ImageRendering(CssPropertyValue::Exact(input))
+    }
+    pub fn transition_property(input: StyleTransitionProperty) -> Self {
+        CssProperty::TransitionProperty(CssPropertyValue::Exact(BoxOrStatic::new(input)))
+    }
+    pub const fn transition_duration(input: StyleTransitionDuration) -> Self {
+        CssProperty::TransitionDuration(CssPropertyValue::Exact(input))
+    }
+    pub fn transition_timing_function(input: StyleTransitionTimingFunction) -> Self {
+        CssProperty::TransitionTimingFunction(CssPropertyValue::Exact(BoxOrStatic::new(input)))
+    }
+    pub const fn transition_delay(input: StyleTransitionDelay) -> Self {
+        CssProperty::TransitionDelay(CssPropertyValue::Exact(input))
+    }
+    pub const fn caret_color(input: StyleCaretColor) -> Self {
+        CssProperty::CaretColor(CssPropertyValue::Exact(input))
+    }
+    pub const fn selection_color(input: StyleSelectionColor) -> Self {
+        CssProperty::SelectionColor(CssPropertyValue::Exact(input))
+    }
+    pub const fn selection_background(input: StyleSelectionBackground) -> Self {
+        CssProperty::SelectionBackground(CssPropertyValue::Exact(input))
+    }
 
     // functions that downcast to the concrete CSS type (style)
 
@@ -3165,6 +4854,18 @@ impl CssProperty {
             _ => None,
         }
     }
+    pub const fn as_vertical_align(&self) -> Option<&StyleVerticalAlignValue> {
+        match self {
+            CssProperty::VerticalAlign(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_style_direction(&self) -> Option<&StyleDirectionValue> {
+        match self {
+            CssProperty::Direction(f) => Some(f),
+            _ => None,
+        }
+    }
     pub const fn as_line_height(&self) -> Option<&StyleLineHeightValue> {
         match self {
             CssProperty::LineHeight(f) => Some(f),
@@ -3329,6 +5030,18 @@ impl CssProperty {
             _ => None,
         }
     }
+    pub const fn as_isolation(&self) -> Option<&StyleIsolationValue> {
+        match self {
+            CssProperty::Isolation(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_image_rendering(&self) -> Option<&StyleImageRenderingValue> {
+        match self {
+            CssProperty::ImageRendering(f) => Some(f),
+            _ => None,
+        }
+    }
     pub const fn as_filter(&self) -> Option<&StyleFilterVecValue> {
         match self {
             CssProperty::Filter(f) => Some(f),
@@ -3347,22 +5060,66 @@ impl CssProperty {
             _ => None,
         }
     }
-
-    // functions that downcast to the concrete CSS type (layout)
-
-    pub const fn as_display(&self) -> Option<&LayoutDisplayValue> {
+    pub const fn as_transition_property(&self) -> Option<&StyleTransitionPropertyValue> {
         match self {
-            CssProperty::Display(f) => Some(f),
+            CssProperty::TransitionProperty(f) => Some(f),
             _ => None,
         }
     }
-    pub const fn as_float(&self) -> Option<&LayoutFloatValue> {
+    pub const fn as_transition_duration(&self) -> Option<&StyleTransitionDurationValue> {
         match self {
-            CssProperty::Float(f) => Some(f),
+            CssProperty::TransitionDuration(f) => Some(f),
             _ => None,
         }
     }
-    pub const fn as_box_sizing(&self) -> Option<&LayoutBoxSizingValue> {
+    pub const fn as_transition_timing_function(
+        &self,
+    ) -> Option<&StyleTransitionTimingFunctionValue> {
+        match self {
+            CssProperty::TransitionTimingFunction(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_transition_delay(&self) -> Option<&StyleTransitionDelayValue> {
+        match self {
+            CssProperty::TransitionDelay(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_caret_color(&self) -> Option<&StyleCaretColorValue> {
+        match self {
+            CssProperty::CaretColor(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_selection_color(&self) -> Option<&StyleSelectionColorValue> {
+        match self {
+            CssProperty::SelectionColor(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_selection_background(&self) -> Option<&StyleSelectionBackgroundValue> {
+        match self {
+            CssProperty::SelectionBackground(f) => Some(f),
+            _ => None,
+        }
+    }
+
+    // functions that downcast to the concrete CSS type (layout)
+
+    pub const fn as_display(&self) -> Option<&LayoutDisplayValue> {
+        match self {
+            CssProperty::Display(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_float(&self) -> Option<&LayoutFloatValue> {
+        match self {
+            CssProperty::Float(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_box_sizing(&self) -> Option<&LayoutBoxSizingValue> {
         match self {
             CssProperty::BoxSizing(f) => Some(f),
             _ => None,
@@ -3560,13 +5317,37 @@ impl CssProperty {
             _ => None,
         }
     }
+    pub const fn as_grid_template_columns(&self) -> Option<&LayoutGridTemplateColumnsValue> {
+        match self {
+            CssProperty::GridTemplateColumns(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_grid_template_rows(&self) -> Option<&LayoutGridTemplateRowsValue> {
+        match self {
+            CssProperty::GridTemplateRows(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_grid_column(&self) -> Option<&LayoutGridColumnValue> {
+        match self {
+            CssProperty::GridColumn(f) => Some(f),
+            _ => None,
+        }
+    }
+    pub const fn as_grid_row(&self) -> Option<&LayoutGridRowValue> {
+        match self {
+            CssProperty::GridRow(f) => Some(f),
+            _ => None,
+        }
+    }
 }
 
 macro_rules! impl_from_css_prop {
     ($a:ident, $b:ident::$enum_type:ident) => {
         impl From<$a> for $b {
             fn from(e: $a) -> Self {
-                $b::$enum_type(CssPropertyValue::from(e))
+                $b::$enum_type(e.into())
             }
         }
     };
@@ -3576,6 +5357,8 @@ impl_from_css_prop!(StyleTextColor, CssProperty::TextColor);
 impl_from_css_prop!(StyleFontSize, CssProperty::FontSize);
 impl_from_css_prop!(StyleFontFamilyVec, CssProperty::FontFamily);
 impl_from_css_prop!(StyleTextAlign, CssProperty::TextAlign);
+impl_from_css_prop!(StyleVerticalAlign, CssProperty::VerticalAlign);
+impl_from_css_prop!(StyleDirection, CssProperty::Direction);
 impl_from_css_prop!(StyleLetterSpacing, CssProperty::LetterSpacing);
 impl_from_css_prop!(StyleLineHeight, CssProperty::LineHeight);
 impl_from_css_prop!(StyleWordSpacing, CssProperty::WordSpacing);
@@ -3602,6 +5385,10 @@ impl_from_css_prop!(LayoutFlexShrink, CssProperty::FlexShrink);
 impl_from_css_prop!(LayoutJustifyContent, CssProperty::JustifyContent);
 impl_from_css_prop!(LayoutAlignItems, CssProperty::AlignItems);
 impl_from_css_prop!(LayoutAlignContent, CssProperty::AlignContent);
+impl_from_css_prop!(LayoutGridTemplateColumns, CssProperty::GridTemplateColumns);
+impl_from_css_prop!(LayoutGridTemplateRows, CssProperty::GridTemplateRows);
+impl_from_css_prop!(LayoutGridColumn, CssProperty::GridColumn);
+impl_from_css_prop!(LayoutGridRow, CssProperty::GridRow);
 impl_from_css_prop!(StyleBackgroundContentVec, CssProperty::BackgroundContent);
 impl_from_css_prop!(StyleBackgroundPositionVec, CssProperty::BackgroundPosition);
 impl_from_css_prop!(StyleBackgroundSizeVec, CssProperty::BackgroundSize);
@@ -3636,13 +5423,27 @@ impl_from_css_prop!(LayoutBorderTopWidth, CssProperty::BorderTopWidth);
 impl_from_css_prop!(LayoutBorderRightWidth, CssProperty::BorderRightWidth);
 impl_from_css_prop!(LayoutBorderLeftWidth, CssProperty::BorderLeftWidth);
 impl_from_css_prop!(LayoutBorderBottomWidth, CssProperty::BorderBottomWidth);
-impl_from_css_prop!(ScrollbarStyle, CssProperty::ScrollbarStyle);
 impl_from_css_prop!(StyleOpacity, CssProperty::Opacity);
 impl_from_css_prop!(StyleTransformVec, CssProperty::Transform);
 impl_from_css_prop!(StyleTransformOrigin, CssProperty::TransformOrigin);
 impl_from_css_prop!(StylePerspectiveOrigin, CssProperty::PerspectiveOrigin);
 impl_from_css_prop!(StyleBackfaceVisibility, CssProperty::BackfaceVisibility);
 impl_from_css_prop!(StyleMixBlendMode, CssProperty::MixBlendMode);
+impl_from_css_prop!(StyleIsolation, CssProperty::Isolation);
+impl_from_css_prop!(StyleImageRendering, CssProperty::ImageRendering);
+impl_from_css_prop!(StyleTransitionProperty, CssProperty::TransitionProperty);
+impl_from_css_prop!(StyleTransitionDuration, CssProperty::TransitionDuration);
+impl_from_css_prop!(StyleTransitionTimingFunction, CssProperty::TransitionTimingFunction);
+impl_from_css_prop!(StyleTransitionDelay, CssProperty::TransitionDelay);
+impl_from_css_prop!(StyleCaretColor, CssProperty::CaretColor);
+impl_from_css_prop!(StyleSelectionColor, CssProperty::SelectionColor);
+impl_from_css_prop!(StyleSelectionBackground, CssProperty::SelectionBackground);
+
+impl From<ScrollbarStyle> for CssProperty {
+    fn from(e: ScrollbarStyle) -> Self {
+        CssProperty::ScrollbarStyle(CssPropertyValue::exact(e))
+    }
+}
 
 /// Multiplier for floating point accuracy. Elements such as px or %
 /// are only accurate until a certain number of decimal points, therefore
@@ -3684,8 +5485,24 @@ impl ::core::fmt::Debug for PixelValueNoPercent {
 }
 
 impl PixelValueNoPercent {
+    /// NOTE: `em` is resolved against the default font size (`EM_HEIGHT`),
+    /// see `PixelValue::to_pixels`
+    #[deprecated(note = "resolves `em` against a fixed 16px, use `to_pixels_em_aware`")]
     pub fn to_pixels(&self) -> f32 {
-        self.inner.to_pixels(0.0)
+        self.inner.to_pixels_with_dpi(0.0, None)
+    }
+
+    /// Same as `to_pixels()`, but resolves `em` against `em_size`
+    pub fn to_pixels_em_aware(&self, em_size: f32) -> f32 {
+        self.inner.to_pixels_em_aware(0.0, em_size)
+    }
+
+    /// Parses a length like `PixelValue::from_str`, but rejects percentages
+    pub fn from_str(input: &str) -> Option<Self> {
+        match PixelValue::from_str(input)? {
+            PixelValue { metric: SizeMetric::Percent, .. } => None,
+            inner => Some(Self { inner }),
+        }
     }
 }
 
@@ -3826,6 +5643,30 @@ impl AngleValue {
         }
     }
 
+    /// Parses an angle with a `deg`, `rad`, `grad`, `turn` or `%` suffix, i.e. `"45deg"` => `AngleValue::deg(45.0)`
+    pub fn from_str(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let metrics = [
+            ("deg", AngleMetric::Degree),
+            ("turn", AngleMetric::Turn),
+            ("grad", AngleMetric::Grad),
+            ("rad", AngleMetric::Radians),
+            ("%", AngleMetric::Percent),
+        ];
+
+        for (suffix, metric) in metrics.iter() {
+            if input.ends_with(suffix) {
+                let value = input[..input.len() - suffix.len()].trim();
+                return value
+                    .parse::<f32>()
+                    .ok()
+                    .map(|v| Self::from_metric(*metric, v));
+            }
+        }
+
+        None
+    }
+
     /// Returns the value of the AngleMetric in degrees
     #[inline]
     pub fn to_degrees(&self) -> f32 {
@@ -3844,6 +5685,63 @@ impl AngleValue {
         }
         val
     }
+
+    // keeps the sign and the number of full turns, so that
+    // rotate(0deg) => rotate(1turn) is a full rotation
+    #[inline]
+    fn to_degrees_unclamped(&self) -> f32 {
+        match self.metric {
+            AngleMetric::Degree => self.number.get(),
+            AngleMetric::Radians => self.number.get() / (2.0 * core::f32::consts::PI) * 360.0,
+            AngleMetric::Grad => self.number.get() / 400.0 * 360.0,
+            AngleMetric::Turn => self.number.get() * 360.0,
+            AngleMetric::Percent => self.number.get() / 100.0 * 360.0,
+        }
+    }
+
+    #[inline]
+    pub fn interpolate(&self, other: &Self, t: f32) -> Self {
+        if self.metric == other.metric {
+            Self {
+                metric: self.metric,
+                number: self.number.interpolate(&other.number, t),
+            }
+        } else {
+            let self_deg = self.to_degrees_unclamped();
+            let other_deg = other.to_degrees_unclamped();
+            Self::deg(self_deg + (other_deg - self_deg) * t)
+        }
+    }
+}
+
+// 370deg => 10deg, -90deg => 270deg
+fn normalize_degrees(deg: f32) -> f32 {
+    let deg = libm::fmodf(deg, 360.0);
+    if deg < 0.0 {
+        deg + 360.0
+    } else {
+        deg
+    }
+}
+
+/// Adds two angles of any metric, the result is in degrees and normalized to 0 - 360deg
+impl core::ops::Add for AngleValue {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::deg(normalize_degrees(
+            self.to_degrees_unclamped() + other.to_degrees_unclamped(),
+        ))
+    }
+}
+
+/// Subtracts two angles of any metric, the result is in degrees and normalized to 0 - 360deg
+impl core::ops::Sub for AngleValue {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::deg(normalize_degrees(
+            self.to_degrees_unclamped() - other.to_degrees_unclamped(),
+        ))
+    }
 }
 
 #[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -3878,7 +5776,7 @@ impl fmt::Display for SizeMetric {
         match self {
             Px => write!(f, "px"),
             Pt => write!(f, "pt"),
-            Em => write!(f, "pt"),
+            Em => write!(f, "em"),
             Percent => write!(f, "%"),
         }
     }
@@ -3955,6 +5853,34 @@ impl PixelValue {
         }
     }
 
+    /// Parses a length with a `px`, `em`, `pt` or `%` suffix, i.e. `"25%"` => `PixelValue::percent(25.0)`.
+    /// A unitless `0` is also accepted, other unitless or non-finite numbers are not.
+    pub fn from_str(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let metrics = [
+            ("px", SizeMetric::Px),
+            ("em", SizeMetric::Em),
+            ("pt", SizeMetric::Pt),
+            ("%", SizeMetric::Percent),
+        ];
+
+        for (suffix, metric) in metrics.iter() {
+            if input.ends_with(suffix) {
+                let value = input[..input.len() - suffix.len()].trim();
+                return value
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|v| v.is_finite())
+                    .map(|v| Self::from_metric(*metric, v));
+            }
+        }
+
+        match input.parse::<f32>() {
+            Ok(v) if v == 0.0 => Some(Self::zero()),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn interpolate(&self, other: &Self, t: f32) -> Self {
         if self.metric == other.metric {
@@ -3965,8 +5891,12 @@ impl PixelValue {
         } else {
             // TODO: how to interpolate between different metrics
             // (interpolate between % and em? - currently impossible)
-            let self_px_interp = self.to_pixels(0.0);
-            let other_px_interp = other.to_pixels(0.0);
+            //
+            // NOTE: the font size of the node is not known here, so `em`
+            // is resolved against the default font size (`EM_HEIGHT`)
+            let ctx = UnitResolutionContext::new(0.0);
+            let self_px_interp = self.resolve(&ctx);
+            let other_px_interp = other.resolve(&ctx);
             Self::from_metric(
                 SizeMetric::Px,
                 self_px_interp + (other_px_interp - self_px_interp) * t,
@@ -3975,17 +5905,248 @@ impl PixelValue {
     }
 
     /// Returns the value of the SizeMetric in pixels
+    ///
+    /// NOTE: `em` is resolved against the default font size (`EM_HEIGHT`),
+    /// not against the font size of the node - use `to_pixels_em_aware` for
+    /// lengths of a styled node
+    #[deprecated(note = "resolves `em` against a fixed 16px, use `to_pixels_em_aware`")]
     #[inline]
     pub fn to_pixels(&self, percent_resolve: f32) -> f32 {
+        self.to_pixels_with_dpi(percent_resolve, None)
+    }
+
+    /// Same as `to_pixels()`, but resolves `em` against `em_size`
+    /// (the computed font size of the node in pixels)
+    #[inline]
+    pub fn to_pixels_em_aware(&self, percent_resolve: f32, em_size: f32) -> f32 {
+        self.resolve(&UnitResolutionContext::with_em_size(percent_resolve, em_size))
+    }
+
+    /// Same as `to_pixels()`, but returns the value in pixels of a display with
+    /// the given DPI (`None` = `DEFAULT_DPI`, i.e. logical pixels). Use
+    /// `Some(hidpi_factor * DEFAULT_DPI)` to get physical pixels: `px`, `em`
+    /// and `pt` are absolute lengths and scale with the DPI, `percent_resolve`
+    /// has to be given in the target pixels already.
+    #[inline]
+    pub fn to_pixels_with_dpi(&self, percent_resolve: f32, dpi: Option<f32>) -> f32 {
+        let px_scale = dpi.map(|dpi| dpi / DEFAULT_DPI).unwrap_or(1.0);
         match self.metric {
-            SizeMetric::Px => self.number.get(),
-            SizeMetric::Pt => self.number.get() * PT_TO_PX,
-            SizeMetric::Em => self.number.get() * EM_HEIGHT,
+            SizeMetric::Px => self.number.get() * px_scale,
+            SizeMetric::Pt => self.number.get() * PT_TO_PX * px_scale,
+            SizeMetric::Em => self.number.get() * EM_HEIGHT * px_scale,
             SizeMetric::Percent => self.number.get() / 100.0 * percent_resolve,
         }
     }
 }
 
+/// Values that are needed to resolve a relative length (`%`, `em`) into pixels
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct UnitResolutionContext {
+    /// Size in pixels that `100%` refers to (usually the width or height of the parent)
+    pub percent_resolve: f32,
+    /// Font size in pixels that `1em` refers to
+    pub em_size: f32,
+}
+
+impl UnitResolutionContext {
+    /// Creates a context that resolves `em` against the default font size (`EM_HEIGHT`)
+    #[inline]
+    pub const fn new(percent_resolve: f32) -> Self {
+        Self {
+            percent_resolve,
+            em_size: EM_HEIGHT,
+        }
+    }
+
+    /// Creates a context that resolves `em` against the given font size in pixels
+    #[inline]
+    pub const fn with_em_size(percent_resolve: f32, em_size: f32) -> Self {
+        Self {
+            percent_resolve,
+            em_size,
+        }
+    }
+}
+
+impl PixelValue {
+    /// Same as `to_pixels()`, but resolves `em` against the font size of the context
+    #[inline]
+    pub fn resolve(&self, ctx: &UnitResolutionContext) -> f32 {
+        match self.metric {
+            SizeMetric::Em => self.number.get() * ctx.em_size,
+            _ => self.to_pixels_with_dpi(ctx.percent_resolve, None),
+        }
+    }
+}
+
+/// Parsed `calc()` expression, i.e. `calc((100% - 32px) / 2)`
+///
+/// Only lengths can be added or subtracted, the right-hand side of a
+/// multiplication or division is always a plain number. Because of that,
+/// every expression can be simplified into a `CalcLength` via
+/// `to_calc_length()`, which is what gets stored in a `CssPropertyValue`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CalcExpression {
+    Value(PixelValue),
+    Add(Box<CalcExpression>, Box<CalcExpression>),
+    Sub(Box<CalcExpression>, Box<CalcExpression>),
+    Mul(Box<CalcExpression>, FloatValue),
+    Div(Box<CalcExpression>, FloatValue),
+}
+
+impl CalcExpression {
+    /// Evaluates the expression in pixels, returns `None` if the expression
+    /// contains a division by zero (same as `to_calc_length()`, which is
+    /// what a parsed property stores, so both give the same result).
+    pub fn evaluate(&self, ctx: &UnitResolutionContext) -> Option<f32> {
+        Some(self.to_calc_length()?.evaluate(ctx))
+    }
+
+    /// Simplifies the expression into a sum of `px`, `pt`, `em` and `%`,
+    /// returns `None` if the expression contains a division by zero.
+    pub fn to_calc_length(&self) -> Option<CalcLength> {
+        use self::CalcExpression::*;
+        match self {
+            Value(v) => Some(CalcLength::from_pixel_value(*v)),
+            Add(a, b) => Some(a.to_calc_length()?.add(&b.to_calc_length()?)),
+            Sub(a, b) => Some(a.to_calc_length()?.add(&b.to_calc_length()?.scale(-1.0))),
+            Mul(a, n) => Some(a.to_calc_length()?.scale(n.get())),
+            Div(a, n) => {
+                if n.get() == 0.0 {
+                    None
+                } else {
+                    Some(a.to_calc_length()?.scale(1.0 / n.get()))
+                }
+            }
+        }
+    }
+
+    fn fmt_inner(&self, f: &mut fmt::Formatter, parenthesize_sum: bool) -> fmt::Result {
+        use self::CalcExpression::*;
+        match self {
+            Value(v) => write!(f, "{}", v),
+            Add(a, b) | Sub(a, b) => {
+                let op = if let Add(_, _) = self { "+" } else { "-" };
+                if parenthesize_sum {
+                    write!(f, "(")?;
+                }
+                a.fmt_inner(f, false)?;
+                write!(f, " {} ", op)?;
+                // a - (b + c) != a - b + c
+                b.fmt_inner(f, true)?;
+                if parenthesize_sum {
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+            Mul(a, n) => {
+                a.fmt_inner(f, true)?;
+                write!(f, " * {}", n)
+            }
+            Div(a, n) => {
+                a.fmt_inner(f, true)?;
+                write!(f, " / {}", n)
+            }
+        }
+    }
+}
+
+impl fmt::Display for CalcExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "calc(")?;
+        self.fmt_inner(f, false)?;
+        write!(f, ")")
+    }
+}
+
+/// Simplified `calc()` expression: sum of lengths with different metrics,
+/// i.e. `calc(100% - 32px)` is stored as `{ percent: 100, px: -32 }`.
+///
+/// Stored in a `BoxOrStatic` in `CssLengthValue::Calc` (instead of a boxed
+/// `CalcExpression`), so that `calc()`, which is rare, doesn't make every
+/// length value larger.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct CalcLength {
+    pub px: FloatValue,
+    pub pt: FloatValue,
+    pub em: FloatValue,
+    pub percent: FloatValue,
+}
+
+impl CalcLength {
+    pub fn from_pixel_value(value: PixelValue) -> Self {
+        let mut calc = Self::default();
+        match value.metric {
+            SizeMetric::Px => calc.px = value.number,
+            SizeMetric::Pt => calc.pt = value.number,
+            SizeMetric::Em => calc.em = value.number,
+            SizeMetric::Percent => calc.percent = value.number,
+        }
+        calc
+    }
+
+    #[inline]
+    pub fn add(&self, other: &Self) -> Self {
+        Self {
+            px: FloatValue::new(self.px.get() + other.px.get()),
+            pt: FloatValue::new(self.pt.get() + other.pt.get()),
+            em: FloatValue::new(self.em.get() + other.em.get()),
+            percent: FloatValue::new(self.percent.get() + other.percent.get()),
+        }
+    }
+
+    #[inline]
+    pub fn scale(&self, factor: f32) -> Self {
+        Self {
+            px: FloatValue::new(self.px.get() * factor),
+            pt: FloatValue::new(self.pt.get() * factor),
+            em: FloatValue::new(self.em.get() * factor),
+            percent: FloatValue::new(self.percent.get() * factor),
+        }
+    }
+
+    /// Returns the length in pixels
+    pub fn evaluate(&self, ctx: &UnitResolutionContext) -> f32 {
+        self.px.get()
+            + self.pt.get() * PT_TO_PX
+            + self.em.get() * ctx.em_size
+            + self.percent.get() / 100.0 * ctx.percent_resolve
+    }
+
+    /// Terms of the sum in the order `%`, `em`, `pt`, `px` (zero terms are skipped when printing)
+    fn terms(&self) -> [PixelValue; 4] {
+        [
+            PixelValue { metric: SizeMetric::Percent, number: self.percent },
+            PixelValue { metric: SizeMetric::Em, number: self.em },
+            PixelValue { metric: SizeMetric::Pt, number: self.pt },
+            PixelValue { metric: SizeMetric::Px, number: self.px },
+        ]
+    }
+}
+
+impl fmt::Display for CalcLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "calc(")?;
+        let mut is_first = true;
+        for term in self.terms().iter().filter(|t| t.number.number != 0) {
+            if is_first {
+                write!(f, "{}", term)?;
+            } else if term.number.number < 0 {
+                let abs = PixelValue::from_metric(term.metric, -term.number.get());
+                write!(f, " - {}", abs)?;
+            } else {
+                write!(f, " + {}", term)?;
+            }
+            is_first = false;
+        }
+        if is_first {
+            write!(f, "0px")?;
+        }
+        write!(f, ")")
+    }
+}
+
 /// Wrapper around FloatValue, represents a percentage instead
 /// of just being a regular floating-point value, i.e `5` = `5%`
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -4023,6 +6184,12 @@ impl PercentageValue {
         }
     }
 
+    /// Parses a percentage with a `%` suffix, i.e. `"50%"` => `PercentageValue::new(50.0)`
+    pub fn from_str(input: &str) -> Option<Self> {
+        let value = input.trim().strip_suffix('%')?.trim();
+        value.parse::<f32>().ok().map(Self::new)
+    }
+
     #[inline]
     pub fn get(&self) -> f32 {
         self.number.get()
@@ -4142,6 +6309,23 @@ impl StyleBackgroundSize {
             _ => { },
         }
     }
+
+    /// Converts `em` sizes into pixels, relative to the computed font
+    /// size of the node
+    pub fn resolve_em(&mut self, em_size: f32) {
+        if let StyleBackgroundSize::ExactSize(a) = self {
+            for q in a.iter_mut() {
+                resolve_em_to_px(q, em_size);
+            }
+        }
+    }
+}
+
+// Replaces an `em` length with the same length in pixels
+fn resolve_em_to_px(value: &mut PixelValue, em_size: f32) {
+    if value.metric == SizeMetric::Em {
+        *value = PixelValue::px(value.to_pixels_em_aware(0.0, em_size));
+    }
 }
 
 impl Default for StyleBackgroundSize {
@@ -4176,10 +6360,100 @@ pub struct StyleBackgroundPosition {
 }
 
 impl StyleBackgroundPosition {
+    /// Parses a position such as `"center"`, `"left top"` or `"25% 75%"`.
+    /// A single keyword sets the other axis to `center`. Two keywords can
+    /// be in any order (`"top left"`), lengths are horizontal first.
+    pub fn from_str(input: &str) -> Option<Self> {
+        let is_keyword = |s: &str| matches!(s, "left" | "center" | "right" | "top" | "bottom");
+        let mut components = input.split_whitespace();
+        match (components.next()?, components.next(), components.next()) {
+            (first, None, None) => match BackgroundPositionHorizontal::from_str(first) {
+                Some(horizontal) => Some(Self {
+                    horizontal,
+                    vertical: BackgroundPositionVertical::Center,
+                }),
+                None => Some(Self {
+                    horizontal: BackgroundPositionHorizontal::Center,
+                    vertical: BackgroundPositionVertical::from_str(first)?,
+                }),
+            },
+            (first, Some(second), None) => {
+                let horizontal_first = BackgroundPositionHorizontal::from_str(first)
+                    .zip(BackgroundPositionVertical::from_str(second));
+                let (horizontal, vertical) = match horizontal_first {
+                    Some(s) => s,
+                    None if is_keyword(first) && is_keyword(second) => (
+                        BackgroundPositionHorizontal::from_str(second)?,
+                        BackgroundPositionVertical::from_str(first)?,
+                    ),
+                    None => return None,
+                };
+                Some(Self { horizontal, vertical })
+            }
+            _ => None,
+        }
+    }
+
+    pub const fn center() -> Self {
+        Self {
+            horizontal: BackgroundPositionHorizontal::Center,
+            vertical: BackgroundPositionVertical::Center,
+        }
+    }
+
+    pub const fn top_left() -> Self {
+        Self {
+            horizontal: BackgroundPositionHorizontal::Left,
+            vertical: BackgroundPositionVertical::Top,
+        }
+    }
+
+    pub const fn top_right() -> Self {
+        Self {
+            horizontal: BackgroundPositionHorizontal::Right,
+            vertical: BackgroundPositionVertical::Top,
+        }
+    }
+
+    pub const fn bottom_left() -> Self {
+        Self {
+            horizontal: BackgroundPositionHorizontal::Left,
+            vertical: BackgroundPositionVertical::Bottom,
+        }
+    }
+
+    pub const fn bottom_right() -> Self {
+        Self {
+            horizontal: BackgroundPositionHorizontal::Right,
+            vertical: BackgroundPositionVertical::Bottom,
+        }
+    }
+
+    /// Returns the offset of an image of the size `image` inside of the
+    /// background `area`, relative to the top left corner of the area.
+    /// Percentages resolve against `area - image`, so that `100%` aligns
+    /// the right / bottom edges, `em` resolves against the `em_size`.
+    pub fn resolve(&self, area: LayoutSize, image: LayoutSize, em_size: f32) -> LayoutPoint {
+        let x = self.horizontal.resolve(area.width as f32, image.width as f32, em_size);
+        let y = self.vertical.resolve(area.height as f32, image.height as f32, em_size);
+        LayoutPoint::new(libm::roundf(x) as isize, libm::roundf(y) as isize)
+    }
+
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         self.horizontal.scale_for_dpi(scale_factor);
         self.vertical.scale_for_dpi(scale_factor);
     }
+
+    /// Converts `em` offsets into pixels, relative to the computed font
+    /// size of the node
+    pub fn resolve_em(&mut self, em_size: f32) {
+        if let BackgroundPositionHorizontal::Exact(e) = &mut self.horizontal {
+            resolve_em_to_px(e, em_size);
+        }
+        if let BackgroundPositionVertical::Exact(e) = &mut self.vertical {
+            resolve_em_to_px(e, em_size);
+        }
+    }
 }
 
 impl_vec!(
@@ -4218,6 +6492,29 @@ pub enum BackgroundPositionHorizontal {
 }
 
 impl BackgroundPositionHorizontal {
+    /// Parses a horizontal position, i.e. `"left"` => `BackgroundPositionHorizontal::Left`
+    /// or `"25%"` => `BackgroundPositionHorizontal::Exact(PixelValue::percent(25.0))`
+    pub fn from_str(input: &str) -> Option<Self> {
+        match input.trim() {
+            "left" => Some(BackgroundPositionHorizontal::Left),
+            "center" => Some(BackgroundPositionHorizontal::Center),
+            "right" => Some(BackgroundPositionHorizontal::Right),
+            other => PixelValue::from_str(other).map(BackgroundPositionHorizontal::Exact),
+        }
+    }
+
+    /// Returns the x offset of an image that is `image_width` wide inside
+    /// of an area that is `area_width` wide, see `StyleBackgroundPosition::resolve`
+    pub fn resolve(&self, area_width: f32, image_width: f32, em_size: f32) -> f32 {
+        let free_space = area_width - image_width;
+        match self {
+            BackgroundPositionHorizontal::Left => 0.0,
+            BackgroundPositionHorizontal::Center => free_space / 2.0,
+            BackgroundPositionHorizontal::Right => free_space,
+            BackgroundPositionHorizontal::Exact(e) => e.to_pixels_em_aware(free_space, em_size),
+        }
+    }
+
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         match self {
             BackgroundPositionHorizontal::Exact(s) => { s.scale_for_dpi(scale_factor); },
@@ -4236,6 +6533,29 @@ pub enum BackgroundPositionVertical {
 }
 
 impl BackgroundPositionVertical {
+    /// Parses a vertical position, i.e. `"top"` => `BackgroundPositionVertical::Top`
+    /// or `"25%"` => `BackgroundPositionVertical::Exact(PixelValue::percent(25.0))`
+    pub fn from_str(input: &str) -> Option<Self> {
+        match input.trim() {
+            "top" => Some(BackgroundPositionVertical::Top),
+            "center" => Some(BackgroundPositionVertical::Center),
+            "bottom" => Some(BackgroundPositionVertical::Bottom),
+            other => PixelValue::from_str(other).map(BackgroundPositionVertical::Exact),
+        }
+    }
+
+    /// Returns the y offset of an image that is `image_height` high inside
+    /// of an area that is `area_height` high, see `StyleBackgroundPosition::resolve`
+    pub fn resolve(&self, area_height: f32, image_height: f32, em_size: f32) -> f32 {
+        let free_space = area_height - image_height;
+        match self {
+            BackgroundPositionVertical::Top => 0.0,
+            BackgroundPositionVertical::Center => free_space / 2.0,
+            BackgroundPositionVertical::Bottom => free_space,
+            BackgroundPositionVertical::Exact(e) => e.to_pixels_em_aware(free_space, em_size),
+        }
+    }
+
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         match self {
             BackgroundPositionVertical::Exact(s) => { s.scale_for_dpi(scale_factor); },
@@ -4244,7 +6564,7 @@ impl BackgroundPositionVertical {
     }
 }
 
-/// Represents a `background-repeat` attribute
+/// Represents a `background-repeat` attribute - default: `Repeat`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum StyleBackgroundRepeat {
@@ -4252,6 +6572,10 @@ pub enum StyleBackgroundRepeat {
     Repeat,
     RepeatX,
     RepeatY,
+    /// Repeats as many whole tiles as fit, the leftover space is distributed between the tiles
+    Space,
+    /// Repeats whole tiles, scaling the tile so that a whole number of tiles fills the area
+    Round,
 }
 
 impl_vec!(
@@ -4277,24 +6601,151 @@ impl Default for StyleBackgroundRepeat {
     }
 }
 
-/// Represents a `color` attribute
-#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(C)]
-pub struct StyleTextColor {
-    pub inner: ColorU,
-}
+impl_keyword_enum!(
+    StyleBackgroundRepeat,
+    ["no-repeat", NoRepeat],
+    ["repeat", Repeat],
+    ["repeat-x", RepeatX],
+    ["repeat-y", RepeatY],
+    ["space", Space],
+    ["round", Round],
+);
 
-derive_debug_zero!(StyleTextColor);
-derive_display_zero!(StyleTextColor);
+/// How a background image is tiled in the background area,
+/// see `StyleBackgroundRepeat::compute_tiles`
+///
+/// The tile at `(x, y)` is positioned at
+/// `area.origin + (x * (tile_width + gap_x), y * (tile_height + gap_y))`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct BackgroundTiling {
+    /// Width of a single tile (scaled for `Round`)
+    pub tile_width: f32,
+    /// Height of a single tile (scaled for `Round`)
+    pub tile_height: f32,
+    /// Horizontal space between two tiles (only non-zero for `Space`)
+    pub gap_x: f32,
+    /// Vertical space between two tiles (only non-zero for `Space`)
+    pub gap_y: f32,
+    /// Number of tiles in horizontal direction
+    pub count_x: usize,
+    /// Number of tiles in vertical direction
+    pub count_y: usize,
+}
+
+impl StyleBackgroundRepeat {
+    /// Computes the size, spacing and number of tiles for a `tile` repeated over `area`
+    pub fn compute_tiles(&self, tile: LayoutSize, area: LayoutRect) -> BackgroundTiling {
+        use self::StyleBackgroundRepeat::*;
+
+        let (repeat_x, repeat_y) = match self {
+            NoRepeat => (NoRepeat, NoRepeat),
+            Repeat => (Repeat, Repeat),
+            RepeatX => (Repeat, NoRepeat),
+            RepeatY => (NoRepeat, Repeat),
+            Space => (Space, Space),
+            Round => (Round, Round),
+        };
 
-impl StyleTextColor {
-    pub fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let (tile_width, gap_x, count_x) =
+            Self::compute_tiles_axis(repeat_x, tile.width as f32, area.size.width as f32);
+        let (tile_height, gap_y, count_y) =
+            Self::compute_tiles_axis(repeat_y, tile.height as f32, area.size.height as f32);
+
+        BackgroundTiling {
+            tile_width,
+            tile_height,
+            gap_x,
+            gap_y,
+            count_x,
+            count_y,
+        }
+    }
+
+    // returns (tile size, gap, tile count) along one axis
+    fn compute_tiles_axis(repeat: Self, tile: f32, area: f32) -> (f32, f32, usize) {
+        use self::StyleBackgroundRepeat::*;
+
+        if tile <= 0.0 || area <= 0.0 {
+            return (tile, 0.0, 0);
+        }
+
+        match repeat {
+            NoRepeat | RepeatX | RepeatY => (tile, 0.0, 1),
+            Repeat => (tile, 0.0, libm::ceilf(area / tile) as usize),
+            Space => {
+                // if only one tile fits, it is drawn once without any gap
+                let count = libm::floorf(area / tile) as usize;
+                if count <= 1 {
+                    (tile, 0.0, 1)
+                } else {
+                    let gap = (area - count as f32 * tile) / (count - 1) as f32;
+                    (tile, gap, count)
+                }
+            }
+            Round => {
+                let count = (libm::roundf(area / tile) as usize).max(1);
+                (area / count as f32, 0.0, count)
+            }
+        }
+    }
+}
+
+/// Represents a `color` attribute
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleTextColor {
+    pub inner: ColorU,
+}
+
+derive_debug_zero!(StyleTextColor);
+derive_display_zero!(StyleTextColor);
+
+impl StyleTextColor {
+    pub fn interpolate(&self, other: &Self, t: f32) -> Self {
         Self {
             inner: self.inner.interpolate(&other.inner, t),
         }
     }
+
+    /// Parses a hex or named color, see `ColorU::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        ColorU::from_str(input).map(|inner| Self { inner })
+    }
+}
+
+/// Represents a `caret-color` attribute: the color of the text cursor in
+/// editable text. `caret-color: auto` uses the `color` of the text.
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleCaretColor {
+    pub inner: ColorU,
+}
+
+/// Represents a `-azul-selection-color` attribute: the text color of selected text
+///
+/// Azul has no pseudo-elements, so the `::selection { color }` of CSS is
+/// set as a property on the text (or the text input) instead.
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleSelectionColor {
+    pub inner: ColorU,
 }
 
+/// Represents a `-azul-selection-background-color` attribute: the
+/// highlight color behind selected text, same as `::selection { background-color }`
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleSelectionBackground {
+    pub inner: ColorU,
+}
+
+derive_debug_zero!(StyleCaretColor);
+derive_display_zero!(StyleCaretColor);
+derive_debug_zero!(StyleSelectionColor);
+derive_display_zero!(StyleSelectionColor);
+derive_debug_zero!(StyleSelectionBackground);
+derive_display_zero!(StyleSelectionBackground);
+
 // -- TODO: Technically, border-radius can take two values for each corner!
 
 /// Represents a `border-top-width` attribute
@@ -4433,48 +6884,88 @@ impl StyleBorderTopLeftRadius {
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         self.inner.scale_for_dpi(scale_factor);
     }
+
+    /// Parses a length (`2px`, `0.5em`, ...), see `PixelValue::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
 }
 
 impl StyleBorderTopRightRadius {
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         self.inner.scale_for_dpi(scale_factor);
     }
+
+    /// Parses a length (`2px`, `0.5em`, ...), see `PixelValue::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
 }
 
 impl StyleBorderBottomLeftRadius {
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         self.inner.scale_for_dpi(scale_factor);
     }
+
+    /// Parses a length (`2px`, `0.5em`, ...), see `PixelValue::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
 }
 
 impl StyleBorderBottomRightRadius {
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         self.inner.scale_for_dpi(scale_factor);
     }
+
+    /// Parses a length (`2px`, `0.5em`, ...), see `PixelValue::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
 }
 
 impl LayoutBorderTopWidth {
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         self.inner.scale_for_dpi(scale_factor);
     }
+
+    /// Parses a length (`2px`, `0.5em`, ...), see `PixelValue::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
 }
 
 impl LayoutBorderRightWidth {
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         self.inner.scale_for_dpi(scale_factor);
     }
+
+    /// Parses a length (`2px`, `0.5em`, ...), see `PixelValue::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
 }
 
 impl LayoutBorderBottomWidth {
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         self.inner.scale_for_dpi(scale_factor);
     }
+
+    /// Parses a length (`2px`, `0.5em`, ...), see `PixelValue::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
 }
 
 impl LayoutBorderLeftWidth {
     pub fn scale_for_dpi(&mut self, scale_factor: f32) {
         self.inner.scale_for_dpi(scale_factor);
     }
+
+    /// Parses a length (`2px`, `0.5em`, ...), see `PixelValue::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
 }
 
 /// Represents a `border-top-width` attribute
@@ -4543,6 +7034,11 @@ impl StyleBorderTopColor {
             inner: self.inner.interpolate(&other.inner, t),
         }
     }
+
+    /// Parses a hex or named color, see `ColorU::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        ColorU::from_str(input).map(|inner| Self { inner })
+    }
 }
 impl StyleBorderLeftColor {
     pub fn interpolate(&self, other: &Self, t: f32) -> Self {
@@ -4550,6 +7046,11 @@ impl StyleBorderLeftColor {
             inner: self.inner.interpolate(&other.inner, t),
         }
     }
+
+    /// Parses a hex or named color, see `ColorU::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        ColorU::from_str(input).map(|inner| Self { inner })
+    }
 }
 impl StyleBorderRightColor {
     pub fn interpolate(&self, other: &Self, t: f32) -> Self {
@@ -4557,6 +7058,11 @@ impl StyleBorderRightColor {
             inner: self.inner.interpolate(&other.inner, t),
         }
     }
+
+    /// Parses a hex or named color, see `ColorU::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        ColorU::from_str(input).map(|inner| Self { inner })
+    }
 }
 impl StyleBorderBottomColor {
     pub fn interpolate(&self, other: &Self, t: f32) -> Self {
@@ -4564,6 +7070,11 @@ impl StyleBorderBottomColor {
             inner: self.inner.interpolate(&other.inner, t),
         }
     }
+
+    /// Parses a hex or named color, see `ColorU::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        ColorU::from_str(input).map(|inner| Self { inner })
+    }
 }
 derive_debug_zero!(StyleBorderTopColor);
 derive_debug_zero!(StyleBorderLeftColor);
@@ -4576,12 +7087,29 @@ derive_display_zero!(StyleBorderRightColor);
 derive_display_zero!(StyleBorderBottomColor);
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
 pub struct StyleBorderSide {
     pub border_width: PixelValue,
     pub border_style: BorderStyle,
     pub border_color: ColorU,
 }
 
+impl StyleBorderSide {
+    pub const fn new(border_width: PixelValue, border_style: BorderStyle, border_color: ColorU) -> Self {
+        Self {
+            border_width,
+            border_style,
+            border_color,
+        }
+    }
+
+    /// Same as `new`, for the common case of a border that is equal on all
+    /// sides, see `NormalBorder::uniform`
+    pub const fn uniform(border_width: PixelValue, border_style: BorderStyle, border_color: ColorU) -> Self {
+        Self::new(border_width, border_style, border_color)
+    }
+}
+
 // missing StyleBorderRadius & LayoutRect
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -4601,6 +7129,153 @@ impl StyleBoxShadow {
         self.blur_radius.scale_for_dpi(scale_factor);
         self.spread_radius.scale_for_dpi(scale_factor);
     }
+
+    /// Converts the `em` offsets and radii into pixels, relative to the
+    /// computed font size of the node
+    pub fn resolve_em(&mut self, em_size: f32) {
+        let resolve = |v: &mut PixelValueNoPercent| {
+            if v.inner.metric == SizeMetric::Em {
+                v.inner = PixelValue::px(v.to_pixels_em_aware(em_size));
+            }
+        };
+        resolve(&mut self.offset[0]);
+        resolve(&mut self.offset[1]);
+        resolve(&mut self.blur_radius);
+        resolve(&mut self.spread_radius);
+    }
+
+    /// Parses a single shadow of a `box-shadow`, i.e. `"2px 2px 4px 0px #00000080"`
+    /// or `"inset red 5px 10px"`: two offsets, an optional blur (not negative) and
+    /// spread radius, an optional color (defaults to black) and an optional `inset`
+    /// keyword. The color and `inset` can be before or after the lengths.
+    pub fn from_str(input: &str) -> Option<Self> {
+        let mut box_shadow = StyleBoxShadow {
+            offset: [PixelValueNoPercent::default(); 2],
+            color: ColorU::BLACK,
+            blur_radius: PixelValueNoPercent::default(),
+            spread_radius: PixelValueNoPercent::default(),
+            clip_mode: BoxShadowClipMode::Outset,
+        };
+
+        let mut lengths = Vec::new();
+        let mut color = None;
+        // lengths have to be contiguous: set after the first value following them
+        let mut lengths_done = false;
+
+        for part in input.split_whitespace() {
+            if let Some(length) = PixelValueNoPercent::from_str(part) {
+                if lengths_done {
+                    return None;
+                }
+                lengths.push(length);
+                continue;
+            }
+
+            lengths_done = !lengths.is_empty();
+
+            if part == "inset" && box_shadow.clip_mode == BoxShadowClipMode::Outset {
+                box_shadow.clip_mode = BoxShadowClipMode::Inset;
+            } else if color.is_none() {
+                color = Some(ColorU::from_str(part)?);
+            } else {
+                return None;
+            }
+        }
+
+        match lengths.as_slice() {
+            [x, y] => box_shadow.offset = [*x, *y],
+            [x, y, blur] => {
+                box_shadow.offset = [*x, *y];
+                box_shadow.blur_radius = *blur;
+            }
+            [x, y, blur, spread] => {
+                box_shadow.offset = [*x, *y];
+                box_shadow.blur_radius = *blur;
+                box_shadow.spread_radius = *spread;
+            }
+            _ => return None,
+        }
+
+        if box_shadow.blur_radius.inner.number.get() < 0.0 {
+            return None;
+        }
+
+        if let Some(color) = color {
+            box_shadow.color = color;
+        }
+
+        Some(box_shadow)
+    }
+
+    /// How far the blur of a shadow extends, relative to the `blur_radius`.
+    ///
+    /// The blur is a gaussian blur with a standard deviation of
+    /// `blur_radius / 2` (as defined by the CSS spec). After 3 standard
+    /// deviations the shadow is practically invisible, so the blur extends
+    /// `1.5 * blur_radius` from the edge of the shadow (same as in WebRender).
+    pub const BLUR_EXTENT_FACTOR: f32 = 1.5;
+
+    /// Distance from the edge of the node to the outer edge of the blurred
+    /// shadow, without the offset (can be negative for a negative spread).
+    /// `em` lengths are resolved against the `em_size`.
+    pub fn get_blur_extent(&self, em_size: f32) -> f32 {
+        self.blur_radius.to_pixels_em_aware(em_size) * Self::BLUR_EXTENT_FACTOR
+            + self.spread_radius.to_pixels_em_aware(em_size)
+    }
+
+    /// Returns how far the shadow extends outside of the node on each side
+    /// (offset + blurred extent, zero if the shadow doesn't reach past the edge).
+    ///
+    /// NOTE: The clip mode is not checked, inset shadows never draw outside
+    /// of the node, see `get_clip_rect`.
+    pub fn get_bounds_inflation(&self, em_size: f32) -> LayoutSideOffsets {
+        let extent = self.get_blur_extent(em_size);
+        let offset_x = self.offset[0].to_pixels_em_aware(em_size);
+        let offset_y = self.offset[1].to_pixels_em_aware(em_size);
+        LayoutSideOffsets::new(
+            (extent - offset_y).max(0.0),
+            (extent + offset_x).max(0.0),
+            (extent + offset_y).max(0.0),
+            (extent - offset_x).max(0.0),
+        )
+    }
+
+    /// Returns the area that the shadow of the node at `node_rect` can draw to:
+    /// the node rect itself for inset shadows, otherwise the node rect plus the
+    /// bounds inflation (rounded up to whole pixels).
+    pub fn get_clip_rect(&self, node_rect: LayoutRect, em_size: f32) -> LayoutRect {
+        if self.clip_mode == BoxShadowClipMode::Inset {
+            return node_rect;
+        }
+
+        let inflation = self.get_bounds_inflation(em_size);
+        let top = libm::ceilf(inflation.top.get()) as isize;
+        let right = libm::ceilf(inflation.right.get()) as isize;
+        let bottom = libm::ceilf(inflation.bottom.get()) as isize;
+        let left = libm::ceilf(inflation.left.get()) as isize;
+        LayoutRect::new(
+            LayoutPoint::new(node_rect.origin.x - left, node_rect.origin.y - top),
+            LayoutSize::new(
+                node_rect.size.width + left + right,
+                node_rect.size.height + top + bottom,
+            ),
+        )
+    }
+}
+
+impl fmt::Display for StyleBoxShadow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} ",
+            self.offset[0], self.offset[1], self.blur_radius, self.spread_radius
+        )?;
+        self.color.write_hash(f)?;
+        if self.clip_mode == BoxShadowClipMode::Inset {
+            write!(f, " inset")?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -4630,6 +7305,58 @@ impl_vec_partialeq!(StyleBackgroundContent, StyleBackgroundContentVec);
 impl_vec_eq!(StyleBackgroundContent, StyleBackgroundContentVec);
 impl_vec_hash!(StyleBackgroundContent, StyleBackgroundContentVec);
 
+impl StyleBackgroundContentVec {
+    /// Single plain background color, same as `background: <color>`
+    pub fn solid(color: ColorU) -> Self {
+        Self::from_single(StyleBackgroundContent::Color(color))
+    }
+
+    /// Background consisting of only one layer
+    pub fn from_single(content: StyleBackgroundContent) -> Self {
+        vec![content].into()
+    }
+}
+
+impl StyleBackgroundContent {
+    /// Parses a single `background` layer: a hex or named color, a
+    /// (repeating) linear / radial / conic gradient or an image id given
+    /// as `url(...)` or `image(...)`, i.e. `"url('logo')"`
+    pub fn from_str(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let name = input.split('(').next()?.trim_end();
+        match name.strip_prefix("repeating-").unwrap_or(name) {
+            "linear-gradient" => {
+                LinearGradient::from_str(input).map(StyleBackgroundContent::LinearGradient)
+            }
+            "radial-gradient" => {
+                RadialGradient::from_str(input).map(StyleBackgroundContent::RadialGradient)
+            }
+            "conic-gradient" => {
+                ConicGradient::from_str(input).map(StyleBackgroundContent::ConicGradient)
+            }
+            "url" | "image" if name.len() < input.len() => {
+                let id = input[name.len()..]
+                    .trim_start()
+                    .strip_prefix('(')?
+                    .strip_suffix(')')?
+                    .trim();
+                let id = match id.chars().next() {
+                    Some(quote @ '"') | Some(quote @ '\'') => {
+                        id.strip_prefix(quote)?.strip_suffix(quote)?
+                    }
+                    _ => id,
+                };
+                if id.is_empty() {
+                    None
+                } else {
+                    Some(StyleBackgroundContent::Image(id.to_string().into()))
+                }
+            }
+            _ => ColorU::from_str(input).map(StyleBackgroundContent::Color),
+        }
+    }
+}
+
 impl Default for StyleBackgroundContent {
     fn default() -> StyleBackgroundContent {
         StyleBackgroundContent::Color(ColorU::TRANSPARENT)
@@ -4660,6 +7387,138 @@ impl Default for LinearGradient {
     }
 }
 
+impl LinearGradient {
+    /// Parses a `linear-gradient(...)` or `repeating-linear-gradient(...)`,
+    /// i.e. `"linear-gradient(45deg, red, blue 50%)"`. The direction is
+    /// optional and defaults to `to bottom`. Colors have to be given as
+    /// hex or named colors, at least two color stops are required.
+    pub fn from_str(input: &str) -> Option<Self> {
+        let (extend_mode, args) = parse_gradient_function(input, "linear-gradient")?;
+        let mut args = args.split(',').map(str::trim).peekable();
+
+        let direction = match args.peek().and_then(|first| Direction::from_str(first)) {
+            Some(direction) => {
+                args.next();
+                direction
+            }
+            None => Direction::default(),
+        };
+
+        let stops = args
+            .map(LinearColorStop::from_str)
+            .collect::<Option<Vec<_>>>()?;
+
+        if stops.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            direction,
+            extend_mode,
+            stops: LinearColorStop::get_normalized_linear_stops(&stops).into(),
+        })
+    }
+
+    /// Starts building a gradient, i.e.
+    /// `LinearGradient::builder().to_corner(DirectionCorner::Right).stop(ColorU::RED).stop(ColorU::BLUE).build()`
+    pub fn builder() -> LinearGradientBuilder {
+        LinearGradientBuilder::default()
+    }
+
+    /// Formats the gradient as `linear-gradient(...)` or `repeating-linear-gradient(...)`,
+    /// i.e. `"linear-gradient(to bottom right, #ff0000ff 0%, #0000ffff 80%)"`
+    pub fn to_css_string(&self) -> String {
+        format_gradient_function("linear-gradient", self.extend_mode, self)
+    }
+}
+
+/// Builder for a `LinearGradient`, see `LinearGradient::builder`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LinearGradientBuilder {
+    gradient: LinearGradient,
+    stops: Vec<LinearColorStop>,
+}
+
+impl LinearGradientBuilder {
+    /// Points the gradient towards a side or corner, i.e. `to bottom right`
+    pub fn to_corner(mut self, corner: DirectionCorner) -> Self {
+        self.gradient.direction = Direction::FromTo(DirectionCorners {
+            from: corner.opposite(),
+            to: corner,
+        });
+        self
+    }
+
+    /// Sets the direction of the gradient as an angle, i.e. `45deg`
+    pub fn angle(mut self, angle: AngleValue) -> Self {
+        self.gradient.direction = Direction::Angle(angle);
+        self
+    }
+
+    /// Adds a color stop, its offset is distributed evenly between the surrounding stops
+    pub fn stop(mut self, color: ColorU) -> Self {
+        self.stops.push(LinearColorStop {
+            offset: OptionPercentageValue::None,
+            color,
+        });
+        self
+    }
+
+    /// Adds a color stop at the given offset in percent
+    pub fn stop_at(mut self, color: ColorU, percent: f32) -> Self {
+        self.stops.push(LinearColorStop {
+            offset: OptionPercentageValue::Some(PercentageValue::new(percent)),
+            color,
+        });
+        self
+    }
+
+    /// Repeats the gradient (`repeating-linear-gradient`)
+    pub fn repeat(mut self) -> Self {
+        self.gradient.extend_mode = ExtendMode::Repeat;
+        self
+    }
+
+    /// Returns `None` if no color stop was added
+    pub fn build(self) -> Option<LinearGradient> {
+        if self.stops.is_empty() {
+            return None;
+        }
+        Some(LinearGradient {
+            stops: LinearColorStop::get_normalized_linear_stops(&self.stops).into(),
+            ..self.gradient
+        })
+    }
+}
+
+// LinearGradient { extend_mode: Repeat, .. } => "repeating-linear-gradient(...)"
+fn format_gradient_function<T: PrintAsCssValue>(
+    name: &str,
+    extend_mode: ExtendMode,
+    args: &T,
+) -> String {
+    let prefix = match extend_mode {
+        ExtendMode::Repeat => "repeating-",
+        ExtendMode::Clamp => "",
+    };
+    format!("{}{}({})", prefix, name, args.print_as_css_value())
+}
+
+// "repeating-linear-gradient(a, b)" => (ExtendMode::Repeat, "a, b")
+fn parse_gradient_function<'a>(input: &'a str, name: &str) -> Option<(ExtendMode, &'a str)> {
+    let input = input.trim();
+    let (extend_mode, input) = match input.strip_prefix("repeating-") {
+        Some(s) => (ExtendMode::Repeat, s),
+        None => (ExtendMode::Clamp, input),
+    };
+    let args = input
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    Some((extend_mode, args))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct ConicGradient {
@@ -4683,6 +7542,115 @@ impl Default for ConicGradient {
     }
 }
 
+impl ConicGradient {
+    /// Parses a `conic-gradient(...)` or `repeating-conic-gradient(...)`,
+    /// i.e. `"conic-gradient(from 45deg at center, red, blue 180deg)"`.
+    /// The `from <angle>` and `at <position>` parts are optional and default
+    /// to `0deg` and `center`, the offsets of the color stops are angles.
+    pub fn from_str(input: &str) -> Option<Self> {
+        let (extend_mode, args) = parse_gradient_function(input, "conic-gradient")?;
+        let mut args = args.split(',').map(str::trim).peekable();
+        let mut gradient = Self {
+            extend_mode,
+            ..Default::default()
+        };
+
+        let first = *args.peek()?;
+        if first.starts_with("from ") || first.starts_with("at ") {
+            args.next();
+            let (angle, center) = match first.find("at ") {
+                Some(at) => (&first[..at], Some(&first[(at + "at ".len())..])),
+                None => (first, None),
+            };
+            if let Some(angle) = angle.trim().strip_prefix("from") {
+                gradient.angle = AngleValue::from_str(angle)?;
+            }
+            if let Some(center) = center {
+                gradient.center = StyleBackgroundPosition::from_str(center)?;
+            }
+        }
+
+        let stops = args
+            .map(RadialColorStop::from_str)
+            .collect::<Option<Vec<_>>>()?;
+
+        if stops.len() < 2 {
+            return None;
+        }
+
+        gradient.stops = RadialColorStop::get_normalized_radial_stops(&stops).into();
+        Some(gradient)
+    }
+
+    /// Starts building a gradient, i.e.
+    /// `ConicGradient::builder().from_angle(AngleValue::deg(90.0)).stop(ColorU::RED).stop(ColorU::BLUE).build()`
+    pub fn builder() -> ConicGradientBuilder {
+        ConicGradientBuilder::default()
+    }
+
+    /// Formats the gradient as `conic-gradient(...)` or `repeating-conic-gradient(...)`,
+    /// i.e. `"conic-gradient(from 0deg at center center, #ff0000ff 0deg, #0000ffff 360deg)"`
+    pub fn to_css_string(&self) -> String {
+        format_gradient_function("conic-gradient", self.extend_mode, self)
+    }
+}
+
+/// Builder for a `ConicGradient`, see `ConicGradient::builder`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ConicGradientBuilder {
+    gradient: ConicGradient,
+    stops: Vec<RadialColorStop>,
+}
+
+impl ConicGradientBuilder {
+    /// Sets the angle the gradient starts at, i.e. `from 90deg`
+    pub fn from_angle(mut self, angle: AngleValue) -> Self {
+        self.gradient.angle = angle;
+        self
+    }
+
+    /// Sets the center of the gradient, i.e. `at left top`
+    pub fn at(mut self, center: StyleBackgroundPosition) -> Self {
+        self.gradient.center = center;
+        self
+    }
+
+    /// Adds a color stop, its angle is distributed evenly between the surrounding stops
+    pub fn stop(mut self, color: ColorU) -> Self {
+        self.stops.push(RadialColorStop {
+            offset: OptionAngleValue::None,
+            color,
+        });
+        self
+    }
+
+    /// Adds a color stop at the given angle in degrees
+    pub fn stop_at(mut self, color: ColorU, degrees: f32) -> Self {
+        self.stops.push(RadialColorStop {
+            offset: OptionAngleValue::Some(AngleValue::deg(degrees)),
+            color,
+        });
+        self
+    }
+
+    /// Repeats the gradient (`repeating-conic-gradient`)
+    pub fn repeat(mut self) -> Self {
+        self.gradient.extend_mode = ExtendMode::Repeat;
+        self
+    }
+
+    /// Returns `None` if no color stop was added
+    pub fn build(self) -> Option<ConicGradient> {
+        if self.stops.is_empty() {
+            return None;
+        }
+        Some(ConicGradient {
+            stops: RadialColorStop::get_normalized_radial_stops(&self.stops).into(),
+            ..self.gradient
+        })
+    }
+}
+
 // normalized linear color stop
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -4862,7 +7830,143 @@ impl Default for RadialGradient {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+impl RadialGradient {
+    /// Parses a `radial-gradient(...)` or `repeating-radial-gradient(...)`,
+    /// i.e. `"radial-gradient(circle closest-side at center center, red, blue)"`.
+    /// The shape is optional and defaults to `ellipse`, the size and position
+    /// can only be given after a shape. The color stops are parsed the same way
+    /// as in `LinearGradient::from_str`.
+    pub fn from_str(input: &str) -> Option<Self> {
+        let (extend_mode, args) = parse_gradient_function(input, "radial-gradient")?;
+        let mut args = args.split(',').map(str::trim).peekable();
+
+        let (shape, size, position) = match args.peek().and_then(|first| Self::parse_first_item(first)) {
+            Some(first_item) => {
+                args.next();
+                first_item
+            }
+            None => (
+                Shape::default(),
+                RadialGradientSize::default(),
+                StyleBackgroundPosition::default(),
+            ),
+        };
+
+        let stops = args
+            .map(LinearColorStop::from_str)
+            .collect::<Option<Vec<_>>>()?;
+
+        if stops.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            shape,
+            size,
+            position,
+            extend_mode,
+            stops: LinearColorStop::get_normalized_linear_stops(&stops).into(),
+        })
+    }
+
+    // "circle closest-side at left top" => (Circle, ClosestSide, left top)
+    fn parse_first_item(input: &str) -> Option<(Shape, RadialGradientSize, StyleBackgroundPosition)> {
+        let (shape_and_size, position) = match input.find(" at ") {
+            Some(at) => (&input[..at], Some(&input[(at + " at ".len())..])),
+            None => (input, None),
+        };
+
+        let mut shape_and_size = shape_and_size.split_whitespace();
+        let shape = Shape::from_str(shape_and_size.next()?)?;
+        let size = match (shape_and_size.next(), shape_and_size.next()) {
+            (None, _) => RadialGradientSize::default(),
+            (Some(size), None) => RadialGradientSize::from_str(size)?,
+            (Some(_), Some(_)) => return None,
+        };
+        let position = match position {
+            Some(position) => StyleBackgroundPosition::from_str(position)?,
+            None => StyleBackgroundPosition::default(),
+        };
+
+        Some((shape, size, position))
+    }
+
+    /// Starts building a gradient, i.e.
+    /// `RadialGradient::builder().shape(Shape::Circle).stop(ColorU::RED).stop(ColorU::BLUE).build()`
+    pub fn builder() -> RadialGradientBuilder {
+        RadialGradientBuilder::default()
+    }
+
+    /// Formats the gradient as `radial-gradient(...)` or `repeating-radial-gradient(...)`,
+    /// i.e. `"radial-gradient(circle, #ff0000ff 0%, #0000ffff 100%)"`
+    pub fn to_css_string(&self) -> String {
+        format_gradient_function("radial-gradient", self.extend_mode, self)
+    }
+}
+
+/// Builder for a `RadialGradient`, see `RadialGradient::builder`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RadialGradientBuilder {
+    gradient: RadialGradient,
+    stops: Vec<LinearColorStop>,
+}
+
+impl RadialGradientBuilder {
+    /// Sets the ending shape of the gradient (`circle` or `ellipse`)
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.gradient.shape = shape;
+        self
+    }
+
+    /// Sets the size of the ending shape, i.e. `closest-side`
+    pub fn size(mut self, size: RadialGradientSize) -> Self {
+        self.gradient.size = size;
+        self
+    }
+
+    /// Sets the center of the gradient, i.e. `at center center`
+    pub fn at(mut self, position: StyleBackgroundPosition) -> Self {
+        self.gradient.position = position;
+        self
+    }
+
+    /// Adds a color stop, its offset is distributed evenly between the surrounding stops
+    pub fn stop(mut self, color: ColorU) -> Self {
+        self.stops.push(LinearColorStop {
+            offset: OptionPercentageValue::None,
+            color,
+        });
+        self
+    }
+
+    /// Adds a color stop at the given offset in percent
+    pub fn stop_at(mut self, color: ColorU, percent: f32) -> Self {
+        self.stops.push(LinearColorStop {
+            offset: OptionPercentageValue::Some(PercentageValue::new(percent)),
+            color,
+        });
+        self
+    }
+
+    /// Repeats the gradient (`repeating-radial-gradient`)
+    pub fn repeat(mut self) -> Self {
+        self.gradient.extend_mode = ExtendMode::Repeat;
+        self
+    }
+
+    /// Returns `None` if no color stop was added
+    pub fn build(self) -> Option<RadialGradient> {
+        if self.stops.is_empty() {
+            return None;
+        }
+        Some(RadialGradient {
+            stops: LinearColorStop::get_normalized_linear_stops(&self.stops).into(),
+            ..self.gradient
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum RadialGradientSize {
     // The gradient's ending shape meets the side of the box closest to its center
@@ -4886,6 +7990,14 @@ impl Default for RadialGradientSize {
     }
 }
 
+impl_keyword_enum!(
+    RadialGradientSize,
+    ["closest-side", ClosestSide],
+    ["closest-corner", ClosestCorner],
+    ["farthest-side", FarthestSide],
+    ["farthest-corner", FarthestCorner],
+);
+
 impl RadialGradientSize {
     pub fn get_size(&self, parent_rect: LayoutRect, gradient_center: LayoutPosition) -> LayoutSize {
         // TODO!
@@ -4919,9 +8031,33 @@ impl Default for Direction {
 }
 
 impl Direction {
-    /// Calculates the points of the gradient stops for angled linear gradients
-    pub fn to_points(&self, rect: &LayoutRect) -> (LayoutPoint, LayoutPoint) {
-        match self {
+    /// Parses a gradient direction, either an angle such as `"45deg"` or
+    /// a side or corner such as `"to right"` or `"to top left"`
+    pub fn from_str(input: &str) -> Option<Self> {
+        if let Some(angle) = AngleValue::from_str(input) {
+            return Some(Direction::Angle(angle));
+        }
+
+        let mut keywords = input.split_whitespace();
+        if keywords.next()? != "to" {
+            return None;
+        }
+
+        let to = match (keywords.next(), keywords.next(), keywords.next()) {
+            (Some(side), None, None) => DirectionCorner::from_str(side)?,
+            (Some(a), Some(b), None) => DirectionCorner::from_keywords(a, b)?,
+            _ => return None,
+        };
+
+        Some(Direction::FromTo(DirectionCorners {
+            from: to.opposite(),
+            to,
+        }))
+    }
+
+    /// Calculates the points of the gradient stops for angled linear gradients
+    pub fn to_points(&self, rect: &LayoutRect) -> (LayoutPoint, LayoutPoint) {
+        match self {
             Direction::Angle(angle_value) => {
                 // note: assumes that the LayoutRect has positive sides
 
@@ -5005,6 +8141,12 @@ impl Default for Shape {
     }
 }
 
+impl_keyword_enum!(
+    Shape,
+    ["ellipse", Ellipse],
+    ["circle", Circle],
+);
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum StyleCursor {
@@ -5076,6 +8218,40 @@ impl Default for StyleCursor {
     }
 }
 
+impl_keyword_enum!(
+    StyleCursor,
+    ["alias", Alias],
+    ["all-scroll", AllScroll],
+    ["cell", Cell],
+    ["col-resize", ColResize],
+    ["context-menu", ContextMenu],
+    ["copy", Copy],
+    ["crosshair", Crosshair],
+    ["default", Default],
+    ["e-resize", EResize],
+    ["ew-resize", EwResize],
+    ["grab", Grab],
+    ["grabbing", Grabbing],
+    ["help", Help],
+    ["move", Move],
+    ["n-resize", NResize],
+    ["ns-resize", NsResize],
+    ["nesw-resize", NeswResize],
+    ["nwse-resize", NwseResize],
+    ["pointer", Pointer],
+    ["progress", Progress],
+    ["row-resize", RowResize],
+    ["s-resize", SResize],
+    ["se-resize", SeResize],
+    ["text", Text],
+    ["unset", Unset],
+    ["vertical-text", VerticalText],
+    ["w-resize", WResize],
+    ["wait", Wait],
+    ["zoom-in", ZoomIn],
+    ["zoom-out", ZoomOut],
+);
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum DirectionCorner {
@@ -5134,6 +8310,24 @@ impl DirectionCorner {
         }
     }
 
+    /// Parses a single side keyword, i.e. `"top"` => `DirectionCorner::Top`
+    pub fn from_str(input: &str) -> Option<Self> {
+        match input.trim() {
+            "right" => Some(DirectionCorner::Right),
+            "left" => Some(DirectionCorner::Left),
+            "top" => Some(DirectionCorner::Top),
+            "bottom" => Some(DirectionCorner::Bottom),
+            _ => None,
+        }
+    }
+
+    /// Parses a pair of side keywords into a corner, i.e. `("top", "right")` =>
+    /// `DirectionCorner::TopRight`. Returns `None` if the keywords are invalid or
+    /// don't form a corner (such as `("left", "right")`)
+    pub fn from_keywords(a: &str, b: &str) -> Option<Self> {
+        Self::from_str(a)?.combine(&Self::from_str(b)?)
+    }
+
     pub const fn to_point(&self, rect: &LayoutRect) -> LayoutPoint {
         use self::DirectionCorner::*;
         match *self {
@@ -5177,6 +8371,22 @@ pub struct RadialColorStop {
     pub color: ColorU,
 }
 
+impl RadialColorStop {
+    /// Parses a color stop such as `"red"` or `"#00ff00 90deg"`
+    pub fn from_str(input: &str) -> Option<Self> {
+        let mut parts = input.split_whitespace();
+        let color = ColorU::from_str(parts.next()?)?;
+
+        let offset = match (parts.next(), parts.next()) {
+            (None, _) => OptionAngleValue::None,
+            (Some(offset), None) => OptionAngleValue::Some(AngleValue::from_str(offset)?),
+            (Some(_), Some(_)) => return None,
+        };
+
+        Some(Self { offset, color })
+    }
+}
+
 impl_vec!(
     NormalizedRadialColorStop,
     NormalizedRadialColorStopVec,
@@ -5201,6 +8411,22 @@ pub struct LinearColorStop {
     pub color: ColorU,
 }
 
+impl LinearColorStop {
+    /// Parses a color stop such as `"red"`, `"#00ff00 50%"` or `"blue 100%"`
+    pub fn from_str(input: &str) -> Option<Self> {
+        let mut parts = input.split_whitespace();
+        let color = ColorU::from_str(parts.next()?)?;
+
+        let offset = match (parts.next(), parts.next()) {
+            (None, _) => OptionPercentageValue::None,
+            (Some(offset), None) => OptionPercentageValue::Some(PercentageValue::from_str(offset)?),
+            (Some(_), Some(_)) => return None,
+        };
+
+        Some(Self { offset, color })
+    }
+}
+
 impl_vec!(
     NormalizedLinearColorStop,
     NormalizedLinearColorStopVec,
@@ -5218,6 +8444,13 @@ impl_vec_partialeq!(NormalizedLinearColorStop, NormalizedLinearColorStopVec);
 impl_vec_eq!(NormalizedLinearColorStop, NormalizedLinearColorStopVec);
 impl_vec_hash!(NormalizedLinearColorStop, NormalizedLinearColorStopVec);
 
+impl_option!(
+    NormalizedLinearColorStop,
+    OptionNormalizedLinearColorStop,
+    copy = false,
+    [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
+
 /// Represents a `width` attribute
 #[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5307,6 +8540,67 @@ impl_pixel_value!(LayoutBottom);
 impl_pixel_value!(LayoutRight);
 impl_pixel_value!(LayoutLeft);
 
+// CSS resolves percentages of `top` / `bottom` against the height and
+// percentages of `left` / `right` against the width of the containing block
+fn resolve_offset(
+    value: &PixelValue,
+    containing_block: LayoutSize,
+    vertical: bool,
+    em_size: f32,
+) -> f32 {
+    let percent_resolve = if vertical {
+        containing_block.height
+    } else {
+        containing_block.width
+    };
+    value.to_pixels_em_aware(percent_resolve as f32, em_size)
+}
+
+macro_rules! impl_offset_resolve {
+    ($struct:ident, $vertical:expr, $doc:expr) => {
+        impl $struct {
+            #[doc = $doc]
+            #[inline]
+            pub fn resolve(&self, containing_block: LayoutSize, em_size: f32) -> f32 {
+                resolve_offset(&self.inner, containing_block, $vertical, em_size)
+            }
+        }
+
+        impl CssLengthValue<$struct> {
+            /// Same as `resolve` on the inner value, but returns `None` for `auto`,
+            /// `initial` (which is `auto` for offsets) and `none`: in that case the
+            /// position is determined by the opposite offset or by the static
+            /// position of the node. `inherit` also returns `None`, it has to be
+            /// replaced by the value of the parent during the cascade.
+            #[inline]
+            pub fn resolve(&self, containing_block: LayoutSize, em_size: f32) -> Option<f32> {
+                self.get_property().map(|p| p.resolve(containing_block, em_size))
+            }
+        }
+    };
+}
+
+impl_offset_resolve!(
+    LayoutTop,
+    true,
+    "Resolves `top` to pixels, percentages are relative to the height of the containing block"
+);
+impl_offset_resolve!(
+    LayoutBottom,
+    true,
+    "Resolves `bottom` to pixels, percentages are relative to the height of the containing block"
+);
+impl_offset_resolve!(
+    LayoutRight,
+    false,
+    "Resolves `right` to pixels, percentages are relative to the width of the containing block"
+);
+impl_offset_resolve!(
+    LayoutLeft,
+    false,
+    "Resolves `left` to pixels, percentages are relative to the width of the containing block"
+);
+
 /// Represents a `padding-top` attribute
 #[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5416,6 +8710,14 @@ impl Default for LayoutFlexDirection {
     }
 }
 
+impl_keyword_enum!(
+    LayoutFlexDirection,
+    ["row", Row],
+    ["row-reverse", RowReverse],
+    ["column", Column],
+    ["column-reverse", ColumnReverse],
+);
+
 impl LayoutFlexDirection {
     pub fn get_axis(&self) -> LayoutAxis {
         use self::{LayoutAxis::*, LayoutFlexDirection::*};
@@ -5429,9 +8731,52 @@ impl LayoutFlexDirection {
     pub fn is_reverse(&self) -> bool {
         *self == LayoutFlexDirection::RowReverse || *self == LayoutFlexDirection::ColumnReverse
     }
+
+    /// Resolves the flex direction to screen directions: `row` runs from the
+    /// inline-start to the inline-end edge, so it goes right-to-left if the
+    /// `direction` is `rtl`. Columns are not affected by the `direction`.
+    pub const fn resolve_physical(&self, direction: StyleDirection) -> PhysicalFlexDirection {
+        use self::LayoutFlexDirection::*;
+        match (self, direction) {
+            (Row, StyleDirection::Ltr) | (RowReverse, StyleDirection::Rtl) => {
+                PhysicalFlexDirection::LeftToRight
+            }
+            (Row, StyleDirection::Rtl) | (RowReverse, StyleDirection::Ltr) => {
+                PhysicalFlexDirection::RightToLeft
+            }
+            (Column, _) => PhysicalFlexDirection::TopToBottom,
+            (ColumnReverse, _) => PhysicalFlexDirection::BottomToTop,
+        }
+    }
 }
 
-/// Represents a `flex-direction` attribute - default: `Column`
+/// Direction in which flex items are placed on the screen, see
+/// `LayoutFlexDirection::resolve_physical`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum PhysicalFlexDirection {
+    LeftToRight,
+    RightToLeft,
+    TopToBottom,
+    BottomToTop,
+}
+
+impl PhysicalFlexDirection {
+    pub fn get_axis(&self) -> LayoutAxis {
+        use self::PhysicalFlexDirection::*;
+        match self {
+            LeftToRight | RightToLeft => LayoutAxis::Horizontal,
+            TopToBottom | BottomToTop => LayoutAxis::Vertical,
+        }
+    }
+
+    /// Returns true if the items are placed starting from the right / bottom edge
+    pub fn is_reverse(&self) -> bool {
+        *self == PhysicalFlexDirection::RightToLeft || *self == PhysicalFlexDirection::BottomToTop
+    }
+}
+
+/// Represents a `box-sizing` attribute - default: `ContentBox`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum LayoutBoxSizing {
@@ -5445,6 +8790,12 @@ impl Default for LayoutBoxSizing {
     }
 }
 
+impl_keyword_enum!(
+    LayoutBoxSizing,
+    ["content-box", ContentBox],
+    ["border-box", BorderBox],
+);
+
 /// Represents a `line-height` attribute
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5462,7 +8813,22 @@ impl Default for StyleLineHeight {
     }
 }
 
-/// Represents a `tab-width` attribute
+impl StyleLineHeight {
+    /// Parses a line height as a percentage (`"150%"`) or as a unitless
+    /// multiplier of the font size (`"1.5"` => `150%`)
+    pub fn from_str(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let inner = match PercentageValue::from_str(input) {
+            Some(p) => p,
+            None => PercentageValue::new(input.parse::<f32>().ok()? * 100.0),
+        };
+        Some(Self { inner })
+    }
+}
+
+/// Represents a `tab-width` attribute: the width of a tab character as a
+/// multiple of the width of a space character, stored as a percentage
+/// (`tab-width: 4` is stored as `400%`, `inner.normalized()` returns `4.0`)
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct StyleTabWidth {
@@ -5479,6 +8845,20 @@ impl Default for StyleTabWidth {
     }
 }
 
+impl StyleTabWidth {
+    /// Parses a unitless number of spaces (`"4"` => `400%`) or a percentage
+    /// (`"400%"`), same as `parse_style_tab_width` in azul-css-parser.
+    /// Lengths (`"20px"`) can't be represented and return `None`.
+    pub fn from_str(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let inner = match PercentageValue::from_str(input) {
+            Some(p) => p,
+            None => PercentageValue::new(input.parse::<f32>().ok()? * 100.0),
+        };
+        Some(Self { inner })
+    }
+}
+
 /// Represents a `letter-spacing` attribute
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5496,6 +8876,13 @@ impl Default for StyleLetterSpacing {
 
 impl_pixel_value!(StyleLetterSpacing);
 
+impl StyleLetterSpacing {
+    /// Parses a length (`"2px"`, `"0.1em"`), see `PixelValue::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
+}
+
 /// Represents a `word-spacing` attribute
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5505,6 +8892,13 @@ pub struct StyleWordSpacing {
 
 impl_pixel_value!(StyleWordSpacing);
 
+impl StyleWordSpacing {
+    /// Parses a length (`"2px"`, `"0.1em"`), see `PixelValue::from_str`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
+}
+
 impl Default for StyleWordSpacing {
     fn default() -> Self {
         Self {
@@ -5524,13 +8918,23 @@ pub enum LayoutAxis {
 }
 
 /// Represents a `display` attribute
+///
+/// Defaults to `Flex`, since every node is laid out as a flex container
+/// unless specified otherwise.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum LayoutDisplay {
+    /// `display: none`: the node and its entire subtree are removed from
+    /// the layout, i.e. they produce no boxes and take up no space
+    /// (their layout rects are set to zero)
     None,
     Flex,
     Block,
     InlineBlock,
+    Grid,
+    /// `display: inline` (the initial value in the CSS spec): Azul has no
+    /// inline formatting context yet, so the node is laid out like a block
+    Inline,
 }
 
 impl Default for LayoutDisplay {
@@ -5539,12 +8943,23 @@ impl Default for LayoutDisplay {
     }
 }
 
-/// Represents a `float` attribute
+impl_keyword_enum!(
+    LayoutDisplay,
+    ["none", None],
+    ["flex", Flex],
+    ["block", Block],
+    ["inline-block", InlineBlock],
+    ["grid", Grid],
+    ["inline", Inline],
+);
+
+/// Represents a `float` attribute - default: `Left`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum LayoutFloat {
     Left,
     Right,
+    None,
 }
 
 impl Default for LayoutFloat {
@@ -5553,6 +8968,13 @@ impl Default for LayoutFloat {
     }
 }
 
+impl_keyword_enum!(
+    LayoutFloat,
+    ["left", Left],
+    ["right", Right],
+    ["none", None],
+);
+
 /// Represents a `position` attribute - default: `Static`
 ///
 /// NOTE: No inline positioning is supported.
@@ -5566,9 +8988,20 @@ pub enum LayoutPosition {
 }
 
 impl LayoutPosition {
+    /// Returns whether the node is "positioned" in the CSS sense, i.e. anything
+    /// but `static` (same as `PositionInfo::is_positioned`)
     pub fn is_positioned(&self) -> bool {
         *self != LayoutPosition::Static
     }
+
+    /// Returns whether the node is taken out of the normal flow, i.e.
+    /// `absolute` or `fixed`. Relative nodes still take up space in their parent.
+    pub fn is_out_of_flow(&self) -> bool {
+        match self {
+            LayoutPosition::Absolute | LayoutPosition::Fixed => true,
+            LayoutPosition::Static | LayoutPosition::Relative => false,
+        }
+    }
 }
 
 impl Default for LayoutPosition {
@@ -5577,12 +9010,22 @@ impl Default for LayoutPosition {
     }
 }
 
+impl_keyword_enum!(
+    LayoutPosition,
+    ["static", Static],
+    ["relative", Relative],
+    ["absolute", Absolute],
+    ["fixed", Fixed],
+);
+
 /// Represents a `flex-wrap` attribute - default: `Wrap`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum LayoutFlexWrap {
     Wrap,
     NoWrap,
+    /// Same as `Wrap`, but the lines are stacked in reverse order (cross-start <-> cross-end)
+    WrapReverse,
 }
 
 impl Default for LayoutFlexWrap {
@@ -5591,6 +9034,13 @@ impl Default for LayoutFlexWrap {
     }
 }
 
+impl_keyword_enum!(
+    LayoutFlexWrap,
+    ["wrap", Wrap],
+    ["nowrap", NoWrap],
+    ["wrap-reverse", WrapReverse],
+);
+
 /// Represents a `justify-content` attribute
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5616,6 +9066,16 @@ impl Default for LayoutJustifyContent {
     }
 }
 
+impl_keyword_enum!(
+    LayoutJustifyContent,
+    ["flex-start", Start],
+    ["flex-end", End],
+    ["center", Center],
+    ["space-between", SpaceBetween],
+    ["space-around", SpaceAround],
+    ["space-evenly", SpaceEvenly],
+);
+
 /// Represents a `align-items` attribute
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5636,6 +9096,14 @@ impl Default for LayoutAlignItems {
     }
 }
 
+impl_keyword_enum!(
+    LayoutAlignItems,
+    ["stretch", Stretch],
+    ["center", Center],
+    ["flex-start", FlexStart],
+    ["flex-end", FlexEnd],
+);
+
 /// Represents a `align-content` attribute
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5660,6 +9128,104 @@ impl Default for LayoutAlignContent {
     }
 }
 
+impl_keyword_enum!(
+    LayoutAlignContent,
+    ["stretch", Stretch],
+    ["center", Center],
+    ["flex-start", Start],
+    ["flex-end", End],
+    ["space-between", SpaceBetween],
+    ["space-around", SpaceAround],
+);
+
+/// Single track size of a `grid-template-columns` or `grid-template-rows` attribute
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum GridTrack {
+    /// Fixed track size, i.e. `100px`
+    Px(PixelValue),
+    /// Flexible track size, i.e. `1fr`
+    Fraction(FloatValue),
+    MinContent,
+    MaxContent,
+    Auto,
+    /// `repeat(3, 1fr)`: repeats the inner track list `count` times
+    Repeat(GridTrackRepeat),
+}
+
+impl_vec!(GridTrack, GridTrackVec, GridTrackVecDestructor);
+impl_vec_debug!(GridTrack, GridTrackVec);
+impl_vec_partialord!(GridTrack, GridTrackVec);
+impl_vec_ord!(GridTrack, GridTrackVec);
+impl_vec_clone!(GridTrack, GridTrackVec, GridTrackVecDestructor);
+impl_vec_partialeq!(GridTrack, GridTrackVec);
+impl_vec_eq!(GridTrack, GridTrackVec);
+impl_vec_hash!(GridTrack, GridTrackVec);
+
+/// Argument of a `repeat()` track: stores the repeated tracks in a
+/// `GridTrackVec` instead of a `Box<GridTrack>` to stay FFI-safe
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GridTrackRepeat {
+    pub count: u32,
+    pub tracks: GridTrackVec,
+}
+
+/// Start or end line of a `grid-column` or `grid-row` attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum GridLine {
+    /// Automatic placement
+    Auto,
+    /// 1-based line number, negative numbers count from the end
+    /// (16 bits, so that a `grid-row` fits into a `CssProperty` without boxing)
+    Line(i16),
+    /// `span 2`: spans the given number of tracks
+    Span(u16),
+}
+
+impl Default for GridLine {
+    fn default() -> Self {
+        GridLine::Auto
+    }
+}
+
+/// Start / end line pair of a `grid-column` or `grid-row` attribute
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GridPlacement {
+    pub start: GridLine,
+    pub end: GridLine,
+}
+
+/// Represents a `grid-template-columns` attribute
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutGridTemplateColumns {
+    pub inner: GridTrackVec,
+}
+
+/// Represents a `grid-template-rows` attribute
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutGridTemplateRows {
+    pub inner: GridTrackVec,
+}
+
+/// Represents a `grid-column` attribute
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutGridColumn {
+    pub inner: GridPlacement,
+}
+
+/// Represents a `grid-row` attribute
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutGridRow {
+    pub inner: GridPlacement,
+}
+
 /// Represents a `overflow-x` or `overflow-y` property, see
 /// [`TextOverflowBehaviour`](./struct.TextOverflowBehaviour.html) - default: `Auto`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -5673,6 +9239,9 @@ pub enum LayoutOverflow {
     Hidden,
     /// Doesn't show a scroll bar, simply overflows the text
     Visible,
+    /// Clips the content like `Hidden`, but never creates a scroll container,
+    /// so the content can't be scrolled programmatically either
+    Clip,
 }
 
 impl Default for LayoutOverflow {
@@ -5681,18 +9250,37 @@ impl Default for LayoutOverflow {
     }
 }
 
+impl_keyword_enum!(
+    LayoutOverflow,
+    ["scroll", Scroll],
+    ["auto", Auto],
+    ["hidden", Hidden],
+    ["visible", Visible],
+    ["clip", Clip],
+);
+
 impl LayoutOverflow {
     /// Returns whether this overflow value needs to display the scrollbars.
     ///
     /// - `overflow:scroll` always shows the scrollbar
     /// - `overflow:auto` only shows the scrollbar when the content is currently overflowing
-    /// - `overflow:hidden` and `overflow:visible` do not show any scrollbars
+    /// - `overflow:hidden`, `overflow:clip` and `overflow:visible` do not show any scrollbars
     pub fn needs_scrollbar(&self, currently_overflowing: bool) -> bool {
         use self::LayoutOverflow::*;
         match self {
             Scroll => true,
             Auto => currently_overflowing,
-            Hidden | Visible => false,
+            Hidden | Visible | Clip => false,
+        }
+    }
+
+    /// Returns whether the node can be scrolled (by the user or programmatically),
+    /// i.e. whether it is a `scroll`, `auto` or `hidden` node
+    pub fn can_create_scroll_container(&self) -> bool {
+        use self::LayoutOverflow::*;
+        match self {
+            Scroll | Auto | Hidden => true,
+            Visible | Clip => false,
         }
     }
 
@@ -5702,18 +9290,53 @@ impl LayoutOverflow {
         *self == LayoutOverflow::Visible
     }
 
+    /// Returns whether the node clips its children without showing
+    /// any scrollbars (`overflow:hidden` or `overflow:clip`)
     pub fn is_overflow_hidden(&self) -> bool {
-        *self == LayoutOverflow::Hidden
+        match self {
+            LayoutOverflow::Hidden | LayoutOverflow::Clip => true,
+            _ => false,
+        }
+    }
+
+    /// Returns whether a node with the given `overflow-x` / `overflow-y`
+    /// clips its children, i.e. whether any of them is not `visible`
+    pub fn clips_children(overflow_x: LayoutOverflow, overflow_y: LayoutOverflow) -> bool {
+        !(overflow_x.is_overflow_visible() && overflow_y.is_overflow_visible())
+    }
+
+    /// Computes the used `overflow-x` / `overflow-y` pair: if one of them
+    /// creates a scroll container (`scroll`, `auto` or `hidden`), the other
+    /// one can't stay `visible` / `clip`, so `visible` becomes `auto` and
+    /// `clip` becomes `hidden` (see CSS Overflow Module Level 3, 3.1)
+    pub fn resolve_xy(x: LayoutOverflow, y: LayoutOverflow) -> (LayoutOverflow, LayoutOverflow) {
+        use self::LayoutOverflow::*;
+
+        let resolve = |o: LayoutOverflow| match o {
+            Visible => Auto,
+            Clip => Hidden,
+            other => other,
+        };
+
+        if x.can_create_scroll_container() || y.can_create_scroll_container() {
+            (resolve(x), resolve(y))
+        } else {
+            (x, y)
+        }
     }
 }
 
-/// Horizontal text alignment enum (left, center, right) - default: `Center`
+/// Horizontal text alignment enum (left, center, right, start, end) - default: `Left`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum StyleTextAlign {
     Left,
     Center,
     Right,
+    /// Aligns to the inline-start edge: left for `ltr`, right for `rtl`
+    Start,
+    /// Aligns to the inline-end edge: right for `ltr`, left for `rtl`
+    End,
 }
 
 impl Default for StyleTextAlign {
@@ -5722,18 +9345,145 @@ impl Default for StyleTextAlign {
     }
 }
 
-/// Vertical text alignment enum (top, center, bottom) - default: `Center`
+impl_keyword_enum!(
+    StyleTextAlign,
+    ["left", Left],
+    ["center", Center],
+    ["right", Right],
+    ["start", Start],
+    ["end", End],
+);
+
+impl StyleTextAlign {
+    /// Resolves the logical `start` / `end` alignments to `Left` / `Right`
+    /// for the given text direction, other values are returned as-is
+    pub const fn resolve(&self, direction: StyleDirection) -> StyleTextAlign {
+        use self::StyleTextAlign::*;
+        match (self, direction) {
+            (Start, StyleDirection::Ltr) | (End, StyleDirection::Rtl) => Left,
+            (Start, StyleDirection::Rtl) | (End, StyleDirection::Ltr) => Right,
+            (other, _) => *other,
+        }
+    }
+}
+
+/// Represents a `direction` attribute: the inline base direction of
+/// text and of `flex-direction: row` - default: `Ltr`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
+pub enum StyleDirection {
+    Ltr,
+    Rtl,
+}
+
+impl Default for StyleDirection {
+    fn default() -> Self {
+        StyleDirection::Ltr
+    }
+}
+
+impl_keyword_enum!(
+    StyleDirection,
+    ["ltr", Ltr],
+    ["rtl", Rtl],
+);
+
+/// Represents a `vertical-align` attribute, aligns an inline item
+/// (i.e. an icon next to text) inside of its line - default: `Baseline`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
 pub enum StyleVerticalAlign {
+    /// Bottom of the item sits on the baseline of the line
+    Baseline,
+    /// Top of the item is aligned with the top of the line
     Top,
-    Center,
+    /// Item is centered in the line
+    Middle,
+    /// Bottom of the item is aligned with the bottom of the line
     Bottom,
+    /// Same as `Top` (no font metrics available yet)
+    TextTop,
+    /// Same as `Bottom` (no font metrics available yet)
+    TextBottom,
+    /// Like `Baseline`, but lowered to the subscript position
+    Sub,
+    /// Like `Baseline`, but raised to the superscript position
+    Super,
+    /// Raises the item above the baseline (negative values lower it),
+    /// percentages refer to the line height
+    Exact(PixelValue),
 }
 
 impl Default for StyleVerticalAlign {
     fn default() -> Self {
-        StyleVerticalAlign::Top
+        StyleVerticalAlign::Baseline
+    }
+}
+
+/// Baseline shift of `vertical-align: sub`, relative to the line height
+const VERTICAL_ALIGN_SUB_SHIFT: f32 = 0.2;
+/// Baseline shift of `vertical-align: super`, relative to the line height
+const VERTICAL_ALIGN_SUPER_SHIFT: f32 = 0.33;
+
+impl StyleVerticalAlign {
+    /// Parses a keyword (`"middle"`, `"text-top"`, ...) or a length (`"-2px"`, `"50%"`)
+    pub fn from_str(input: &str) -> Option<Self> {
+        use self::StyleVerticalAlign::*;
+        match input.trim() {
+            "baseline" => Some(Baseline),
+            "top" => Some(Top),
+            "middle" => Some(Middle),
+            "bottom" => Some(Bottom),
+            "text-top" => Some(TextTop),
+            "text-bottom" => Some(TextBottom),
+            "sub" => Some(Sub),
+            "super" => Some(Super),
+            other => PixelValue::from_str(other).map(Exact),
+        }
+    }
+
+    /// Returns the vertical offset of the top edge of an inline item,
+    /// relative to the top of its line.
+    ///
+    /// - `line_height`: height of the line the item is placed in
+    /// - `element_height`: height of the item itself
+    /// - `baseline`: distance from the top of the line to its baseline
+    /// - `em_size`: font size of the item, for `em` lengths
+    pub fn resolve_offset(
+        &self,
+        line_height: f32,
+        element_height: f32,
+        baseline: f32,
+        em_size: f32,
+    ) -> f32 {
+        use self::StyleVerticalAlign::*;
+        let on_baseline = baseline - element_height;
+        match self {
+            Baseline => on_baseline,
+            Top | TextTop => 0.0,
+            Middle => (line_height - element_height) / 2.0,
+            Bottom | TextBottom => line_height - element_height,
+            Sub => on_baseline + line_height * VERTICAL_ALIGN_SUB_SHIFT,
+            Super => on_baseline - line_height * VERTICAL_ALIGN_SUPER_SHIFT,
+            Exact(p) => on_baseline - p.to_pixels_em_aware(line_height, em_size),
+        }
+    }
+}
+
+impl fmt::Display for StyleVerticalAlign {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::StyleVerticalAlign::*;
+        match self {
+            Baseline => write!(f, "baseline"),
+            Top => write!(f, "top"),
+            Middle => write!(f, "middle"),
+            Bottom => write!(f, "bottom"),
+            TextTop => write!(f, "text-top"),
+            TextBottom => write!(f, "text-bottom"),
+            Sub => write!(f, "sub"),
+            Super => write!(f, "super"),
+            Exact(p) => write!(f, "{}", p),
+        }
     }
 }
 
@@ -5744,6 +9494,8 @@ pub struct StyleOpacity {
     pub inner: PercentageValue,
 }
 
+/// NOTE: unlike in CSS, the default opacity is `0%` (fully transparent),
+/// use `StyleOpacity::opaque()` for a fully opaque value
 impl Default for StyleOpacity {
     fn default() -> Self {
         StyleOpacity {
@@ -5754,6 +9506,27 @@ impl Default for StyleOpacity {
 
 impl_percentage_value!(StyleOpacity);
 
+impl StyleOpacity {
+    /// Returns a fully opaque value (`opacity: 1`)
+    #[inline]
+    pub const fn opaque() -> Self {
+        Self::const_new(100)
+    }
+
+    /// Parses an opacity as a number (`"0.5"`) or a percentage (`"50%"`),
+    /// the result is clamped to the range `0..=1`
+    pub fn from_str(input: &str) -> Option<Self> {
+        let inner = parse_transform_factor(input.trim())?;
+        Some(Self { inner }.clamped())
+    }
+
+    /// Returns the opacity clamped to the range `0..=1` (`0%..=100%`)
+    #[inline]
+    pub fn clamped(&self) -> Self {
+        Self::new(self.inner.get().max(0.0).min(100.0))
+    }
+}
+
 /// Represents a `perspective-origin` attribute
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5763,6 +9536,13 @@ pub struct StylePerspectiveOrigin {
 }
 
 impl StylePerspectiveOrigin {
+    /// Parses an origin such as `"50% 50%"`, `"left top"` or `"10px 20px"`,
+    /// same as `background-position`. A single value sets `x`, `y` is then `center`.
+    pub fn from_str(input: &str) -> Option<Self> {
+        let (x, y) = parse_origin(input)?;
+        Some(Self { x, y })
+    }
+
     pub fn interpolate(&self, other: &Self, t: f32) -> Self {
         Self {
             x: self.x.interpolate(&other.x, t),
@@ -5789,6 +9569,13 @@ pub struct StyleTransformOrigin {
 }
 
 impl StyleTransformOrigin {
+    /// Parses an origin such as `"50% 50%"`, `"left top"` or `"10px 20px"`,
+    /// same as `background-position`. A single value sets `x`, `y` is then `center`.
+    pub fn from_str(input: &str) -> Option<Self> {
+        let (x, y) = parse_origin(input)?;
+        Some(Self { x, y })
+    }
+
     pub fn interpolate(&self, other: &Self, t: f32) -> Self {
         Self {
             x: self.x.interpolate(&other.x, t),
@@ -5806,6 +9593,26 @@ impl Default for StyleTransformOrigin {
     }
 }
 
+// keywords are converted to percentages, i.e. `"left"` => `0%`, `"center"` => `50%`
+fn parse_origin(input: &str) -> Option<(PixelValue, PixelValue)> {
+    let position = StyleBackgroundPosition::from_str(input)?;
+
+    let x = match position.horizontal {
+        BackgroundPositionHorizontal::Left => PixelValue::const_percent(0),
+        BackgroundPositionHorizontal::Center => PixelValue::const_percent(50),
+        BackgroundPositionHorizontal::Right => PixelValue::const_percent(100),
+        BackgroundPositionHorizontal::Exact(x) => x,
+    };
+    let y = match position.vertical {
+        BackgroundPositionVertical::Top => PixelValue::const_percent(0),
+        BackgroundPositionVertical::Center => PixelValue::const_percent(50),
+        BackgroundPositionVertical::Bottom => PixelValue::const_percent(100),
+        BackgroundPositionVertical::Exact(y) => y,
+    };
+
+    Some((x, y))
+}
+
 /// Represents a `backface-visibility` attribute
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -5820,6 +9627,12 @@ impl Default for StyleBackfaceVisibility {
     }
 }
 
+impl_keyword_enum!(
+    StyleBackfaceVisibility,
+    ["hidden", Hidden],
+    ["visible", Visible],
+);
+
 /// Represents an `opacity` attribute
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C, u8)]
@@ -5864,6 +9677,8 @@ impl_vec_partialeq!(StyleTransform, StyleTransformVec);
 impl_vec_eq!(StyleTransform, StyleTransformVec);
 impl_vec_hash!(StyleTransform, StyleTransformVec);
 
+/// Arguments of `matrix()`. CSS only allows plain numbers here,
+/// they are stored as `px` values.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct StyleTransformMatrix2D {
@@ -5875,6 +9690,7 @@ pub struct StyleTransformMatrix2D {
     pub ty: PixelValue,
 }
 
+/// Arguments of `matrix3d()`, plain numbers stored as `px` values
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct StyleTransformMatrix3D {
@@ -5942,42 +9758,577 @@ pub struct StyleTransformSkew2D {
     pub y: PercentageValue,
 }
 
-pub type StyleBackgroundContentVecValue = CssPropertyValue<StyleBackgroundContentVec>;
-pub type StyleBackgroundPositionVecValue = CssPropertyValue<StyleBackgroundPositionVec>;
-pub type StyleBackgroundSizeVecValue = CssPropertyValue<StyleBackgroundSizeVec>;
-pub type StyleBackgroundRepeatVecValue = CssPropertyValue<StyleBackgroundRepeatVec>;
-pub type StyleFontSizeValue = CssPropertyValue<StyleFontSize>;
-pub type StyleFontFamilyVecValue = CssPropertyValue<StyleFontFamilyVec>;
-pub type StyleTextColorValue = CssPropertyValue<StyleTextColor>;
-pub type StyleTextAlignValue = CssPropertyValue<StyleTextAlign>;
-pub type StyleLineHeightValue = CssPropertyValue<StyleLineHeight>;
-pub type StyleLetterSpacingValue = CssPropertyValue<StyleLetterSpacing>;
-pub type StyleWordSpacingValue = CssPropertyValue<StyleWordSpacing>;
-pub type StyleTabWidthValue = CssPropertyValue<StyleTabWidth>;
-pub type StyleCursorValue = CssPropertyValue<StyleCursor>;
-pub type StyleBoxShadowValue = CssPropertyValue<StyleBoxShadow>;
-pub type StyleBorderTopColorValue = CssPropertyValue<StyleBorderTopColor>;
-pub type StyleBorderLeftColorValue = CssPropertyValue<StyleBorderLeftColor>;
-pub type StyleBorderRightColorValue = CssPropertyValue<StyleBorderRightColor>;
-pub type StyleBorderBottomColorValue = CssPropertyValue<StyleBorderBottomColor>;
-pub type StyleBorderTopStyleValue = CssPropertyValue<StyleBorderTopStyle>;
-pub type StyleBorderLeftStyleValue = CssPropertyValue<StyleBorderLeftStyle>;
-pub type StyleBorderRightStyleValue = CssPropertyValue<StyleBorderRightStyle>;
-pub type StyleBorderBottomStyleValue = CssPropertyValue<StyleBorderBottomStyle>;
-pub type StyleBorderTopLeftRadiusValue = CssPropertyValue<StyleBorderTopLeftRadius>;
-pub type StyleBorderTopRightRadiusValue = CssPropertyValue<StyleBorderTopRightRadius>;
-pub type StyleBorderBottomLeftRadiusValue = CssPropertyValue<StyleBorderBottomLeftRadius>;
-pub type StyleBorderBottomRightRadiusValue = CssPropertyValue<StyleBorderBottomRightRadius>;
-pub type StyleOpacityValue = CssPropertyValue<StyleOpacity>;
-pub type StyleTransformVecValue = CssPropertyValue<StyleTransformVec>;
-pub type StyleTransformOriginValue = CssPropertyValue<StyleTransformOrigin>;
-pub type StylePerspectiveOriginValue = CssPropertyValue<StylePerspectiveOrigin>;
-pub type StyleBackfaceVisibilityValue = CssPropertyValue<StyleBackfaceVisibility>;
-pub type StyleMixBlendModeValue = CssPropertyValue<StyleMixBlendMode>;
-pub type StyleFilterVecValue = CssPropertyValue<StyleFilterVec>;
-pub type ScrollbarStyleValue = CssPropertyValue<ScrollbarStyle>;
-pub type LayoutDisplayValue = CssPropertyValue<LayoutDisplay>;
-impl_option!(
+/// Size of the reference box and font size of a transformed node, used
+/// to resolve percentages and `em` lengths in `translate()` functions when
+/// transforms have to be converted into matrices (see
+/// `StyleTransformVec::interpolate`)
+#[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+pub struct TransformResolutionContext {
+    pub reference_width: f32,
+    pub reference_height: f32,
+    pub em_size: f32,
+}
+
+impl StyleTransform {
+    /// Parses a single transform function, i.e. `"translate(10px, 20px)"` or `"rotate(45deg)"`.
+    ///
+    /// Lengths need a unit, scale and skew factors are either a number
+    /// (`"scale(2)"`) or a percentage (`"scale(200%)"`).
+    pub fn from_str(input: &str) -> Option<Self> {
+        use self::StyleTransform::*;
+
+        let input = input.trim();
+        let open = input.find('(')?;
+        let name = input[..open].trim();
+        let args = input[open + 1..].strip_suffix(')')?;
+        let args = args.split(',').map(|a| a.trim()).collect::<Vec<_>>();
+
+        let px = PixelValue::from_str;
+        let angle = AngleValue::from_str;
+        let factor = parse_transform_factor;
+        let number = parse_matrix_number;
+
+        let transform = match (name, args.as_slice()) {
+            ("matrix", [a, b, c, d, tx, ty]) => Matrix(StyleTransformMatrix2D {
+                a: number(a)?,
+                b: number(b)?,
+                c: number(c)?,
+                d: number(d)?,
+                tx: number(tx)?,
+                ty: number(ty)?,
+            }),
+            (
+                "matrix3d",
+                [m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, m44],
+            ) => Matrix3D(StyleTransformMatrix3D {
+                m11: number(m11)?,
+                m12: number(m12)?,
+                m13: number(m13)?,
+                m14: number(m14)?,
+                m21: number(m21)?,
+                m22: number(m22)?,
+                m23: number(m23)?,
+                m24: number(m24)?,
+                m31: number(m31)?,
+                m32: number(m32)?,
+                m33: number(m33)?,
+                m34: number(m34)?,
+                m41: number(m41)?,
+                m42: number(m42)?,
+                m43: number(m43)?,
+                m44: number(m44)?,
+            }),
+            ("translate", [x]) => Translate(StyleTransformTranslate2D {
+                x: px(x)?,
+                y: PixelValue::zero(),
+            }),
+            ("translate", [x, y]) => Translate(StyleTransformTranslate2D {
+                x: px(x)?,
+                y: px(y)?,
+            }),
+            ("translate3d", [x, y, z]) => Translate3D(StyleTransformTranslate3D {
+                x: px(x)?,
+                y: px(y)?,
+                z: px(z)?,
+            }),
+            ("translateX", [x]) => TranslateX(px(x)?),
+            ("translateY", [y]) => TranslateY(px(y)?),
+            ("translateZ", [z]) => TranslateZ(px(z)?),
+            ("rotate", [a]) => Rotate(angle(a)?),
+            ("rotate3d", [x, y, z, a]) => Rotate3D(StyleTransformRotate3D {
+                x: factor(x)?,
+                y: factor(y)?,
+                z: factor(z)?,
+                angle: angle(a)?,
+            }),
+            ("rotateX", [a]) => RotateX(angle(a)?),
+            ("rotateY", [a]) => RotateY(angle(a)?),
+            ("rotateZ", [a]) => RotateZ(angle(a)?),
+            ("scale", [s]) => Scale(StyleTransformScale2D {
+                x: factor(s)?,
+                y: factor(s)?,
+            }),
+            ("scale", [x, y]) => Scale(StyleTransformScale2D {
+                x: factor(x)?,
+                y: factor(y)?,
+            }),
+            ("scale3d", [x, y, z]) => Scale3D(StyleTransformScale3D {
+                x: factor(x)?,
+                y: factor(y)?,
+                z: factor(z)?,
+            }),
+            ("scaleX", [x]) => ScaleX(factor(x)?),
+            ("scaleY", [y]) => ScaleY(factor(y)?),
+            ("scaleZ", [z]) => ScaleZ(factor(z)?),
+            ("skew", [x]) => Skew(StyleTransformSkew2D {
+                x: factor(x)?,
+                y: PercentageValue::new(0.0),
+            }),
+            ("skew", [x, y]) => Skew(StyleTransformSkew2D {
+                x: factor(x)?,
+                y: factor(y)?,
+            }),
+            ("skewX", [x]) => SkewX(factor(x)?),
+            ("skewY", [y]) => SkewY(factor(y)?),
+            ("perspective", [d]) => Perspective(px(d)?),
+            _ => return None,
+        };
+
+        Some(transform)
+    }
+
+    /// Returns the function of the same type that doesn't transform
+    /// anything, i.e. `translateX(0px)` for `translateX(50px)`.
+    /// Returns `None` for `perspective()`, which has no identity value.
+    pub fn get_identity(&self) -> Option<Self> {
+        use self::StyleTransform::*;
+
+        let px = PixelValue::zero();
+        let zero = PercentageValue::new(0.0);
+        let one = PercentageValue::new(100.0);
+        let deg = AngleValue::zero();
+
+        let identity = match self {
+            Matrix(_) => Matrix(StyleTransformMatrix2D {
+                a: PixelValue::const_px(1),
+                b: px,
+                c: px,
+                d: PixelValue::const_px(1),
+                tx: px,
+                ty: px,
+            }),
+            Matrix3D(_) => Matrix3D(StyleTransformMatrix3D {
+                m11: PixelValue::const_px(1),
+                m12: px,
+                m13: px,
+                m14: px,
+                m21: px,
+                m22: PixelValue::const_px(1),
+                m23: px,
+                m24: px,
+                m31: px,
+                m32: px,
+                m33: PixelValue::const_px(1),
+                m34: px,
+                m41: px,
+                m42: px,
+                m43: px,
+                m44: PixelValue::const_px(1),
+            }),
+            Translate(_) => Translate(StyleTransformTranslate2D { x: px, y: px }),
+            Translate3D(_) => Translate3D(StyleTransformTranslate3D {
+                x: px,
+                y: px,
+                z: px,
+            }),
+            TranslateX(_) => TranslateX(px),
+            TranslateY(_) => TranslateY(px),
+            TranslateZ(_) => TranslateZ(px),
+            Rotate(_) => Rotate(deg),
+            Rotate3D(r) => Rotate3D(StyleTransformRotate3D { angle: deg, ..*r }),
+            RotateX(_) => RotateX(deg),
+            RotateY(_) => RotateY(deg),
+            RotateZ(_) => RotateZ(deg),
+            Scale(_) => Scale(StyleTransformScale2D { x: one, y: one }),
+            Scale3D(_) => Scale3D(StyleTransformScale3D {
+                x: one,
+                y: one,
+                z: one,
+            }),
+            ScaleX(_) => ScaleX(one),
+            ScaleY(_) => ScaleY(one),
+            ScaleZ(_) => ScaleZ(one),
+            Skew(_) => Skew(StyleTransformSkew2D { x: zero, y: zero }),
+            SkewX(_) => SkewX(zero),
+            SkewY(_) => SkewY(zero),
+            Perspective(_) => return None,
+        };
+
+        Some(identity)
+    }
+
+    /// Interpolates the arguments of two functions of the same type.
+    /// Returns `None` if the functions have a different type, if the
+    /// axes of two `rotate3d()` functions differ or if the functions
+    /// are matrices, which have to be decomposed first.
+    pub fn interpolate(&self, other: &Self, t: f32) -> Option<Self> {
+        use self::StyleTransform::*;
+
+        let interpolated = match (self, other) {
+            (Translate(a), Translate(b)) => Translate(StyleTransformTranslate2D {
+                x: a.x.interpolate(&b.x, t),
+                y: a.y.interpolate(&b.y, t),
+            }),
+            (Translate3D(a), Translate3D(b)) => Translate3D(StyleTransformTranslate3D {
+                x: a.x.interpolate(&b.x, t),
+                y: a.y.interpolate(&b.y, t),
+                z: a.z.interpolate(&b.z, t),
+            }),
+            (TranslateX(a), TranslateX(b)) => TranslateX(a.interpolate(b, t)),
+            (TranslateY(a), TranslateY(b)) => TranslateY(a.interpolate(b, t)),
+            (TranslateZ(a), TranslateZ(b)) => TranslateZ(a.interpolate(b, t)),
+            (Rotate(a), Rotate(b)) => Rotate(a.interpolate(b, t)),
+            (Rotate3D(a), Rotate3D(b)) if (a.x, a.y, a.z) == (b.x, b.y, b.z) => {
+                Rotate3D(StyleTransformRotate3D {
+                    angle: a.angle.interpolate(&b.angle, t),
+                    ..*a
+                })
+            }
+            (RotateX(a), RotateX(b)) => RotateX(a.interpolate(b, t)),
+            (RotateY(a), RotateY(b)) => RotateY(a.interpolate(b, t)),
+            (RotateZ(a), RotateZ(b)) => RotateZ(a.interpolate(b, t)),
+            (Scale(a), Scale(b)) => Scale(StyleTransformScale2D {
+                x: a.x.interpolate(&b.x, t),
+                y: a.y.interpolate(&b.y, t),
+            }),
+            (Scale3D(a), Scale3D(b)) => Scale3D(StyleTransformScale3D {
+                x: a.x.interpolate(&b.x, t),
+                y: a.y.interpolate(&b.y, t),
+                z: a.z.interpolate(&b.z, t),
+            }),
+            (ScaleX(a), ScaleX(b)) => ScaleX(a.interpolate(b, t)),
+            (ScaleY(a), ScaleY(b)) => ScaleY(a.interpolate(b, t)),
+            (ScaleZ(a), ScaleZ(b)) => ScaleZ(a.interpolate(b, t)),
+            (Skew(a), Skew(b)) => Skew(StyleTransformSkew2D {
+                x: a.x.interpolate(&b.x, t),
+                y: a.y.interpolate(&b.y, t),
+            }),
+            (SkewX(a), SkewX(b)) => SkewX(a.interpolate(b, t)),
+            (SkewY(a), SkewY(b)) => SkewY(a.interpolate(b, t)),
+            (Perspective(a), Perspective(b)) => Perspective(a.interpolate(b, t)),
+            _ => return None,
+        };
+
+        Some(interpolated)
+    }
+
+    // Converts the function into a 2D matrix `[a, b, c, d, tx, ty]`,
+    // 3D functions are flattened onto the XY plane. Skew factors are
+    // interpreted as degrees, same as in `ComputedTransform3D`.
+    fn to_matrix_2d(&self, ctx: &TransformResolutionContext) -> [f32; 6] {
+        use self::StyleTransform::*;
+
+        let (w, h) = (ctx.reference_width, ctx.reference_height);
+        let px = |v: &PixelValue, reference: f32| v.to_pixels_em_aware(reference, ctx.em_size);
+        let rotate = |angle: &AngleValue| {
+            let rad = angle.to_degrees_unclamped().to_radians();
+            let (sin, cos) = (libm::sinf(rad), libm::cosf(rad));
+            [cos, sin, -sin, cos, 0.0, 0.0]
+        };
+        let tan = |p: &PercentageValue| libm::tanf(p.normalized().to_radians());
+
+        match self {
+            Matrix(m) => [
+                px(&m.a, w),
+                px(&m.b, w),
+                px(&m.c, w),
+                px(&m.d, w),
+                px(&m.tx, w),
+                px(&m.ty, h),
+            ],
+            Matrix3D(m) => [
+                px(&m.m11, w),
+                px(&m.m12, w),
+                px(&m.m21, w),
+                px(&m.m22, w),
+                px(&m.m41, w),
+                px(&m.m42, h),
+            ],
+            Translate(t) => [1.0, 0.0, 0.0, 1.0, px(&t.x, w), px(&t.y, h)],
+            Translate3D(t) => [1.0, 0.0, 0.0, 1.0, px(&t.x, w), px(&t.y, h)],
+            TranslateX(x) => [1.0, 0.0, 0.0, 1.0, px(x, w), 0.0],
+            TranslateY(y) => [1.0, 0.0, 0.0, 1.0, 0.0, px(y, h)],
+            Rotate(angle) | RotateZ(angle) => rotate(angle),
+            Rotate3D(r) => {
+                // upper left 2x2 of the 3D rotation matrix
+                let (x, y, z) = (r.x.normalized(), r.y.normalized(), r.z.normalized());
+                let len = libm::sqrtf(x * x + y * y + z * z);
+                if len == 0.0 {
+                    return [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+                }
+                let (x, y, z) = (x / len, y / len, z / len);
+                let rad = r.angle.to_degrees_unclamped().to_radians();
+                let (sin, cos) = (libm::sinf(rad), libm::cosf(rad));
+                let c = 1.0 - cos;
+                [
+                    cos + x * x * c,
+                    x * y * c + z * sin,
+                    x * y * c - z * sin,
+                    cos + y * y * c,
+                    0.0,
+                    0.0,
+                ]
+            }
+            RotateX(angle) => {
+                let cos = libm::cosf(angle.to_degrees_unclamped().to_radians());
+                [1.0, 0.0, 0.0, cos, 0.0, 0.0]
+            }
+            RotateY(angle) => {
+                let cos = libm::cosf(angle.to_degrees_unclamped().to_radians());
+                [cos, 0.0, 0.0, 1.0, 0.0, 0.0]
+            }
+            Scale(s) => [s.x.normalized(), 0.0, 0.0, s.y.normalized(), 0.0, 0.0],
+            Scale3D(s) => [s.x.normalized(), 0.0, 0.0, s.y.normalized(), 0.0, 0.0],
+            ScaleX(x) => [x.normalized(), 0.0, 0.0, 1.0, 0.0, 0.0],
+            ScaleY(y) => [1.0, 0.0, 0.0, y.normalized(), 0.0, 0.0],
+            Skew(s) => [1.0, tan(&s.y), tan(&s.x), 1.0, 0.0, 0.0],
+            SkewX(x) => [1.0, 0.0, tan(x), 1.0, 0.0, 0.0],
+            SkewY(y) => [1.0, tan(y), 0.0, 1.0, 0.0, 0.0],
+            TranslateZ(_) | ScaleZ(_) | Perspective(_) => [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+        }
+    }
+}
+
+impl StyleTransformVec {
+    /// Parses a space-separated list of transform functions,
+    /// i.e. `"translate(10px, 20px) rotate(45deg)"`. Returns `None`
+    /// if any of the functions can't be parsed.
+    pub fn from_str(input: &str) -> Option<Self> {
+        let mut transforms = Vec::new();
+        let mut depth = 0_usize;
+        let mut start = 0;
+
+        for (i, c) in input.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.checked_sub(1)?,
+                // only split between functions, not between their arguments
+                c if c.is_whitespace() && depth == 0 => {
+                    let function = input[start..i].trim();
+                    if !function.is_empty() {
+                        transforms.push(StyleTransform::from_str(function)?);
+                    }
+                    start = i + c.len_utf8();
+                }
+                _ => {}
+            }
+        }
+
+        let function = input[start..].trim();
+        if !function.is_empty() {
+            transforms.push(StyleTransform::from_str(function)?);
+        }
+
+        Some(transforms.into())
+    }
+
+    /// Interpolates between two transform lists, for animating `transform`.
+    ///
+    /// If both lists have functions of the same type at the same position,
+    /// the arguments of each function are interpolated separately. The
+    /// shorter list (i.e. an empty list) is padded with identity functions.
+    /// Otherwise both lists are converted to 2D matrices and the decomposed
+    /// translation, rotation, scale and skew are interpolated, the result is
+    /// then a single `matrix()` function. 3D functions are flattened onto
+    /// the XY plane in that case.
+    pub fn interpolate(&self, other: &Self, t: f32, ctx: &TransformResolutionContext) -> Self {
+        let from = self.as_ref();
+        let to = other.as_ref();
+
+        let pairwise = (0..from.len().max(to.len()))
+            .map(|i| match (from.get(i), to.get(i)) {
+                (Some(a), Some(b)) => a.interpolate(b, t),
+                (Some(a), None) => a.interpolate(&a.get_identity()?, t),
+                (None, Some(b)) => b.get_identity()?.interpolate(b, t),
+                (None, None) => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        if let Some(pairwise) = pairwise {
+            return pairwise.into();
+        }
+
+        let from = DecomposedTransform2D::new(transform_list_to_matrix_2d(from, ctx));
+        let to = DecomposedTransform2D::new(transform_list_to_matrix_2d(to, ctx));
+        let [a, b, c, d, tx, ty] = from.interpolate(&to, t).recompose();
+
+        vec![StyleTransform::Matrix(StyleTransformMatrix2D {
+            a: PixelValue::px(a),
+            b: PixelValue::px(b),
+            c: PixelValue::px(c),
+            d: PixelValue::px(d),
+            tx: PixelValue::px(tx),
+            ty: PixelValue::px(ty),
+        })]
+        .into()
+    }
+}
+
+/// Parses an argument of `matrix()` / `matrix3d()`: a plain number without
+/// a unit, stored as a `px` value in `StyleTransformMatrix2D` / `3D`
+fn parse_matrix_number(input: &str) -> Option<PixelValue> {
+    input.trim().parse::<f32>().ok().map(PixelValue::px)
+}
+
+// same as in the CSS parser: `0.5` => 50%, `50%` => 50%
+fn parse_transform_factor(input: &str) -> Option<PercentageValue> {
+    if input.ends_with('%') {
+        PercentageValue::from_str(input)
+    } else {
+        input
+            .parse::<f32>()
+            .ok()
+            .map(|f| PercentageValue::new(f * 100.0))
+    }
+}
+
+// multiplies the matrices of all functions, in the order in which they are applied
+fn transform_list_to_matrix_2d(
+    transforms: &[StyleTransform],
+    ctx: &TransformResolutionContext,
+) -> [f32; 6] {
+    transforms
+        .iter()
+        .fold([1.0, 0.0, 0.0, 1.0, 0.0, 0.0], |m1, t| {
+            let m2 = t.to_matrix_2d(ctx);
+            [
+                m1[0] * m2[0] + m1[2] * m2[1],
+                m1[1] * m2[0] + m1[3] * m2[1],
+                m1[0] * m2[2] + m1[2] * m2[3],
+                m1[1] * m2[2] + m1[3] * m2[3],
+                m1[0] * m2[4] + m1[2] * m2[5] + m1[4],
+                m1[1] * m2[4] + m1[3] * m2[5] + m1[5],
+            ]
+        })
+}
+
+/// 2D matrix decomposed into translate(tx, ty) rotate(angle) skewX(atan(skew))
+/// scale(scale_x, scale_y), angles in radians
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct DecomposedTransform2D {
+    tx: f32,
+    ty: f32,
+    angle: f32,
+    skew: f32,
+    scale_x: f32,
+    scale_y: f32,
+}
+
+impl DecomposedTransform2D {
+    fn new([a, b, c, d, tx, ty]: [f32; 6]) -> Self {
+        let scale_x = libm::hypotf(a, b);
+        let angle = if scale_x == 0.0 {
+            0.0
+        } else {
+            libm::atan2f(b, a)
+        };
+        let (sin, cos) = (libm::sinf(angle), libm::cosf(angle));
+
+        // second column of the matrix in the rotated coordinate system,
+        // a negative scale_y represents a reflection
+        let shear = c * cos + d * sin;
+        let scale_y = d * cos - c * sin;
+        let skew = if scale_y == 0.0 { 0.0 } else { shear / scale_y };
+
+        Self {
+            tx,
+            ty,
+            angle,
+            skew,
+            scale_x,
+            scale_y,
+        }
+    }
+
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        use core::f32::consts::PI;
+
+        // rotate along the shortest path
+        let mut other_angle = other.angle;
+        if other_angle - self.angle > PI {
+            other_angle -= 2.0 * PI;
+        } else if self.angle - other_angle > PI {
+            other_angle += 2.0 * PI;
+        }
+
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+
+        Self {
+            tx: lerp(self.tx, other.tx),
+            ty: lerp(self.ty, other.ty),
+            angle: lerp(self.angle, other_angle),
+            skew: lerp(self.skew, other.skew),
+            scale_x: lerp(self.scale_x, other.scale_x),
+            scale_y: lerp(self.scale_y, other.scale_y),
+        }
+    }
+
+    fn recompose(&self) -> [f32; 6] {
+        let (sin, cos) = (libm::sinf(self.angle), libm::cosf(self.angle));
+        let shear = self.skew * self.scale_y;
+        [
+            self.scale_x * cos,
+            self.scale_x * sin,
+            shear * cos - self.scale_y * sin,
+            shear * sin + self.scale_y * cos,
+            self.tx,
+            self.ty,
+        ]
+    }
+}
+
+pub type StyleBackgroundContentVecValue = CssPropertyValue<BoxOrStatic<StyleBackgroundContentVec>>;
+pub type StyleBackgroundContentVecBoxed = CssBoxed<StyleBackgroundContentVec>;
+pub type StyleBackgroundPositionVecValue = CssPropertyValue<BoxOrStatic<StyleBackgroundPositionVec>>;
+pub type StyleBackgroundPositionVecBoxed = CssBoxed<StyleBackgroundPositionVec>;
+pub type StyleBackgroundSizeVecValue = CssPropertyValue<BoxOrStatic<StyleBackgroundSizeVec>>;
+pub type StyleBackgroundSizeVecBoxed = CssBoxed<StyleBackgroundSizeVec>;
+pub type StyleBackgroundRepeatVecValue = CssPropertyValue<BoxOrStatic<StyleBackgroundRepeatVec>>;
+pub type StyleBackgroundRepeatVecBoxed = CssBoxed<StyleBackgroundRepeatVec>;
+pub type StyleFontSizeValue = CssPropertyValue<StyleFontSize>;
+pub type StyleFontFamilyVecValue = CssPropertyValue<BoxOrStatic<StyleFontFamilyVec>>;
+pub type StyleFontFamilyVecBoxed = CssBoxed<StyleFontFamilyVec>;
+pub type StyleTextColorValue = CssPropertyValue<StyleTextColor>;
+pub type StyleTextAlignValue = CssPropertyValue<StyleTextAlign>;
+pub type StyleVerticalAlignValue = CssPropertyValue<BoxOrStatic<StyleVerticalAlign>>;
+pub type StyleVerticalAlignBoxed = CssBoxed<StyleVerticalAlign>;
+pub type StyleDirectionValue = CssPropertyValue<StyleDirection>;
+pub type StyleLineHeightValue = CssPropertyValue<StyleLineHeight>;
+pub type StyleLetterSpacingValue = CssPropertyValue<StyleLetterSpacing>;
+pub type StyleWordSpacingValue = CssPropertyValue<StyleWordSpacing>;
+pub type StyleTabWidthValue = CssPropertyValue<StyleTabWidth>;
+pub type StyleCursorValue = CssPropertyValue<StyleCursor>;
+pub type StyleBoxShadowValue = CssPropertyValue<BoxOrStatic<StyleBoxShadow>>;
+pub type StyleBoxShadowBoxed = CssBoxed<StyleBoxShadow>;
+pub type CalcLengthBoxed = CssBoxed<CalcLength>;
+pub type StyleBorderTopColorValue = CssPropertyValue<StyleBorderTopColor>;
+pub type StyleBorderLeftColorValue = CssPropertyValue<StyleBorderLeftColor>;
+pub type StyleBorderRightColorValue = CssPropertyValue<StyleBorderRightColor>;
+pub type StyleBorderBottomColorValue = CssPropertyValue<StyleBorderBottomColor>;
+pub type StyleBorderTopStyleValue = CssPropertyValue<StyleBorderTopStyle>;
+pub type StyleBorderLeftStyleValue = CssPropertyValue<StyleBorderLeftStyle>;
+pub type StyleBorderRightStyleValue = CssPropertyValue<StyleBorderRightStyle>;
+pub type StyleBorderBottomStyleValue = CssPropertyValue<StyleBorderBottomStyle>;
+pub type StyleBorderTopLeftRadiusValue = CssPropertyValue<StyleBorderTopLeftRadius>;
+pub type StyleBorderTopRightRadiusValue = CssPropertyValue<StyleBorderTopRightRadius>;
+pub type StyleBorderBottomLeftRadiusValue = CssPropertyValue<StyleBorderBottomLeftRadius>;
+pub type StyleBorderBottomRightRadiusValue = CssPropertyValue<StyleBorderBottomRightRadius>;
+pub type StyleOpacityValue = CssPropertyValue<StyleOpacity>;
+pub type StyleTransformVecValue = CssPropertyValue<BoxOrStatic<StyleTransformVec>>;
+pub type StyleTransformVecBoxed = CssBoxed<StyleTransformVec>;
+pub type StyleTransformOriginValue = CssPropertyValue<BoxOrStatic<StyleTransformOrigin>>;
+pub type StyleTransformOriginBoxed = CssBoxed<StyleTransformOrigin>;
+pub type StylePerspectiveOriginValue = CssPropertyValue<BoxOrStatic<StylePerspectiveOrigin>>;
+pub type StylePerspectiveOriginBoxed = CssBoxed<StylePerspectiveOrigin>;
+pub type StyleBackfaceVisibilityValue = CssPropertyValue<StyleBackfaceVisibility>;
+pub type StyleMixBlendModeValue = CssPropertyValue<StyleMixBlendMode>;
+pub type StyleIsolationValue = CssPropertyValue<StyleIsolation>;
+pub type StyleImageRenderingValue = CssPropertyValue<StyleImageRendering>;
+pub type StyleTransitionPropertyValue = CssPropertyValue<BoxOrStatic<StyleTransitionProperty>>;
+pub type StyleTransitionPropertyBoxed = CssBoxed<StyleTransitionProperty>;
+pub type StyleTransitionDurationValue = CssPropertyValue<StyleTransitionDuration>;
+pub type StyleTransitionTimingFunctionValue = CssPropertyValue<BoxOrStatic<StyleTransitionTimingFunction>>;
+pub type StyleTransitionTimingFunctionBoxed = CssBoxed<StyleTransitionTimingFunction>;
+pub type StyleTransitionDelayValue = CssPropertyValue<StyleTransitionDelay>;
+pub type StyleCaretColorValue = CssPropertyValue<StyleCaretColor>;
+pub type StyleSelectionColorValue = CssPropertyValue<StyleSelectionColor>;
+pub type StyleSelectionBackgroundValue = CssPropertyValue<StyleSelectionBackground>;
+pub type StyleFilterVecValue = CssPropertyValue<BoxOrStatic<StyleFilterVec>>;
+pub type StyleFilterVecBoxed = CssBoxed<StyleFilterVec>;
+pub type ScrollbarStyleValue = CssPropertyValue<BoxOrStatic<ScrollbarStyle>>;
+pub type ScrollbarStyleBoxed = CssBoxed<ScrollbarStyle>;
+pub type LayoutDisplayValue = CssPropertyValue<LayoutDisplay>;
+impl_option!(
     LayoutDisplayValue,
     OptionLayoutDisplayValue,
     copy = false,
@@ -5997,42 +10348,42 @@ impl_option!(
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutWidthValue = CssPropertyValue<LayoutWidth>;
+pub type LayoutWidthValue = CssLengthValue<LayoutWidth>;
 impl_option!(
     LayoutWidthValue,
     OptionLayoutWidthValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutHeightValue = CssPropertyValue<LayoutHeight>;
+pub type LayoutHeightValue = CssLengthValue<LayoutHeight>;
 impl_option!(
     LayoutHeightValue,
     OptionLayoutHeightValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutMinWidthValue = CssPropertyValue<LayoutMinWidth>;
+pub type LayoutMinWidthValue = CssLengthValue<LayoutMinWidth>;
 impl_option!(
     LayoutMinWidthValue,
     OptionLayoutMinWidthValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutMinHeightValue = CssPropertyValue<LayoutMinHeight>;
+pub type LayoutMinHeightValue = CssLengthValue<LayoutMinHeight>;
 impl_option!(
     LayoutMinHeightValue,
     OptionLayoutMinHeightValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutMaxWidthValue = CssPropertyValue<LayoutMaxWidth>;
+pub type LayoutMaxWidthValue = CssLengthValue<LayoutMaxWidth>;
 impl_option!(
     LayoutMaxWidthValue,
     OptionLayoutMaxWidthValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutMaxHeightValue = CssPropertyValue<LayoutMaxHeight>;
+pub type LayoutMaxHeightValue = CssLengthValue<LayoutMaxHeight>;
 impl_option!(
     LayoutMaxHeightValue,
     OptionLayoutMaxHeightValue,
@@ -6046,84 +10397,84 @@ impl_option!(
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutTopValue = CssPropertyValue<LayoutTop>;
+pub type LayoutTopValue = CssLengthValue<LayoutTop>;
 impl_option!(
     LayoutTopValue,
     OptionLayoutTopValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutBottomValue = CssPropertyValue<LayoutBottom>;
+pub type LayoutBottomValue = CssLengthValue<LayoutBottom>;
 impl_option!(
     LayoutBottomValue,
     OptionLayoutBottomValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutRightValue = CssPropertyValue<LayoutRight>;
+pub type LayoutRightValue = CssLengthValue<LayoutRight>;
 impl_option!(
     LayoutRightValue,
     OptionLayoutRightValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutLeftValue = CssPropertyValue<LayoutLeft>;
+pub type LayoutLeftValue = CssLengthValue<LayoutLeft>;
 impl_option!(
     LayoutLeftValue,
     OptionLayoutLeftValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutPaddingTopValue = CssPropertyValue<LayoutPaddingTop>;
+pub type LayoutPaddingTopValue = CssLengthValue<LayoutPaddingTop>;
 impl_option!(
     LayoutPaddingTopValue,
     OptionLayoutPaddingTopValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutPaddingBottomValue = CssPropertyValue<LayoutPaddingBottom>;
+pub type LayoutPaddingBottomValue = CssLengthValue<LayoutPaddingBottom>;
 impl_option!(
     LayoutPaddingBottomValue,
     OptionLayoutPaddingBottomValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutPaddingLeftValue = CssPropertyValue<LayoutPaddingLeft>;
+pub type LayoutPaddingLeftValue = CssLengthValue<LayoutPaddingLeft>;
 impl_option!(
     LayoutPaddingLeftValue,
     OptionLayoutPaddingLeftValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutPaddingRightValue = CssPropertyValue<LayoutPaddingRight>;
+pub type LayoutPaddingRightValue = CssLengthValue<LayoutPaddingRight>;
 impl_option!(
     LayoutPaddingRightValue,
     OptionLayoutPaddingRightValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutMarginTopValue = CssPropertyValue<LayoutMarginTop>;
+pub type LayoutMarginTopValue = CssLengthValue<LayoutMarginTop>;
 impl_option!(
     LayoutMarginTopValue,
     OptionLayoutMarginTopValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutMarginBottomValue = CssPropertyValue<LayoutMarginBottom>;
+pub type LayoutMarginBottomValue = CssLengthValue<LayoutMarginBottom>;
 impl_option!(
     LayoutMarginBottomValue,
     OptionLayoutMarginBottomValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutMarginLeftValue = CssPropertyValue<LayoutMarginLeft>;
+pub type LayoutMarginLeftValue = CssLengthValue<LayoutMarginLeft>;
 impl_option!(
     LayoutMarginLeftValue,
     OptionLayoutMarginLeftValue,
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
-pub type LayoutMarginRightValue = CssPropertyValue<LayoutMarginRight>;
+pub type LayoutMarginRightValue = CssLengthValue<LayoutMarginRight>;
 impl_option!(
     LayoutMarginRightValue,
     OptionLayoutMarginRightValue,
@@ -6190,6 +10541,36 @@ impl_option!(
     copy = false,
     [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
 );
+pub type LayoutGridTemplateColumnsValue = CssPropertyValue<BoxOrStatic<LayoutGridTemplateColumns>>;
+pub type LayoutGridTemplateColumnsBoxed = CssBoxed<LayoutGridTemplateColumns>;
+impl_option!(
+    LayoutGridTemplateColumnsValue,
+    OptionLayoutGridTemplateColumnsValue,
+    copy = false,
+    [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
+pub type LayoutGridTemplateRowsValue = CssPropertyValue<BoxOrStatic<LayoutGridTemplateRows>>;
+pub type LayoutGridTemplateRowsBoxed = CssBoxed<LayoutGridTemplateRows>;
+impl_option!(
+    LayoutGridTemplateRowsValue,
+    OptionLayoutGridTemplateRowsValue,
+    copy = false,
+    [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
+pub type LayoutGridColumnValue = CssPropertyValue<LayoutGridColumn>;
+impl_option!(
+    LayoutGridColumnValue,
+    OptionLayoutGridColumnValue,
+    copy = false,
+    [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
+pub type LayoutGridRowValue = CssPropertyValue<LayoutGridRow>;
+impl_option!(
+    LayoutGridRowValue,
+    OptionLayoutGridRowValue,
+    copy = false,
+    [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]
+);
 
 /// Holds info necessary for layouting / styling scrollbars (-webkit-scrollbar)
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6246,16 +10627,176 @@ impl Default for ScrollbarInfo {
     }
 }
 
+impl ScrollbarInfo {
+    /// Minimum length of the thumb in pixels, so that it can still be
+    /// grabbed if the content is very large
+    pub const MIN_THUMB_LENGTH: isize = 20;
+
+    /// Computes the track, thumb and button rectangles of a scrollbar along
+    /// the right (vertical) or bottom (horizontal) edge of the `container`.
+    ///
+    /// The buttons are squares of `width` pixels at both ends of the track,
+    /// they are only laid out if the `button` is not transparent. The thumb
+    /// is `padding_left` / `padding_right` pixels thinner than the track.
+    ///
+    /// `em` lengths are resolved against the `em_size`. Returns `None` if the
+    /// content doesn't overflow the container (no scrollbar necessary).
+    pub fn compute_layout(
+        &self,
+        container: LayoutRect,
+        content_size: LayoutSize,
+        scroll_offset: LayoutPoint,
+        orientation: ScrollbarOrientation,
+        em_size: f32,
+    ) -> Option<ScrollbarLayout> {
+        use self::ScrollbarOrientation::*;
+
+        let (container_length, content_length, scroll_offset) = match orientation {
+            Horizontal => (container.size.width, content_size.width, scroll_offset.x),
+            Vertical => (container.size.height, content_size.height, scroll_offset.y),
+        };
+
+        let max_scroll_offset = content_length - container_length;
+        if max_scroll_offset <= 0 || container_length <= 0 {
+            return None;
+        }
+
+        let resolve = |value: &PixelValue, percent_resolve: f32| {
+            libm::roundf(value.to_pixels_em_aware(percent_resolve, em_size))
+        };
+        let thickness = resolve(&self.width.inner, 0.0).max(0.0) as isize;
+        let padding_left = resolve(&self.padding_left.inner, thickness as f32) as isize;
+        let padding_right = resolve(&self.padding_right.inner, thickness as f32) as isize;
+        let thumb_thickness = (thickness - padding_left - padding_right).max(0);
+
+        let button_length = match &self.button {
+            StyleBackgroundContent::Color(c) if c.a == ColorU::ALPHA_TRANSPARENT => 0,
+            _ => thickness.min(container_length / 2),
+        };
+
+        let thumb_area_length = container_length - 2 * button_length;
+        let thumb_length = libm::roundf(
+            thumb_area_length as f32 * container_length as f32 / content_length as f32,
+        ) as isize;
+        let thumb_length = thumb_length
+            .max(Self::MIN_THUMB_LENGTH)
+            .min(thumb_area_length);
+        let thumb_travel = thumb_area_length - thumb_length;
+
+        let scroll_offset = scroll_offset.max(0).min(max_scroll_offset);
+        let thumb_offset = libm::roundf(
+            thumb_travel as f32 * scroll_offset as f32 / max_scroll_offset as f32,
+        ) as isize;
+
+        // track along the right (vertical) or bottom (horizontal) edge
+        let (along, across) = match orientation {
+            Horizontal => (container.origin.x, container.max_y() - thickness),
+            Vertical => (container.origin.y, container.max_x() - thickness),
+        };
+
+        // (start, length) along the scrollbar + (start, thickness) across it
+        let rect = |along: isize, length: isize, across: isize, thickness: isize| match orientation {
+            Horizontal => LayoutRect::new(
+                LayoutPoint::new(along, across),
+                LayoutSize::new(length, thickness),
+            ),
+            Vertical => LayoutRect::new(
+                LayoutPoint::new(across, along),
+                LayoutSize::new(thickness, length),
+            ),
+        };
+
+        let thumb_area_start = along + button_length;
+
+        Some(ScrollbarLayout {
+            orientation,
+            track: rect(along, container_length, across, thickness),
+            thumb: rect(
+                thumb_area_start + thumb_offset,
+                thumb_length,
+                across + padding_left,
+                thumb_thickness,
+            ),
+            button_start: rect(along, button_length, across, thickness),
+            button_end: rect(
+                along + container_length - button_length,
+                button_length,
+                across,
+                thickness,
+            ),
+            thumb_area_start,
+            thumb_travel,
+            max_scroll_offset,
+        })
+    }
+}
+
+/// Direction of a scrollbar
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum ScrollbarOrientation {
+    Horizontal,
+    Vertical,
+}
+
+/// Geometry of a scrollbar, see `ScrollbarInfo::compute_layout`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ScrollbarLayout {
+    pub orientation: ScrollbarOrientation,
+    /// Whole scrollbar, including the buttons
+    pub track: LayoutRect,
+    /// Draggable thumb, between the two buttons
+    pub thumb: LayoutRect,
+    /// Top (vertical) or left (horizontal) button, zero-sized if there are no buttons
+    pub button_start: LayoutRect,
+    /// Bottom (vertical) or right (horizontal) button, zero-sized if there are no buttons
+    pub button_end: LayoutRect,
+    /// Position of the thumb at scroll offset 0 (y for vertical, x for horizontal scrollbars)
+    pub thumb_area_start: isize,
+    /// Distance the thumb can be moved
+    pub thumb_travel: isize,
+    /// Maximum scroll offset (content length - container length)
+    pub max_scroll_offset: isize,
+}
+
+impl ScrollbarLayout {
+    /// Scroll offset for the thumb at `thumb_top` (the x position for
+    /// horizontal scrollbars), used while the thumb is dragged.
+    /// Positions outside of the track are clamped.
+    pub fn offset_for_thumb_position(&self, thumb_top: isize) -> isize {
+        if self.thumb_travel <= 0 {
+            return 0;
+        }
+        let thumb_offset = (thumb_top - self.thumb_area_start)
+            .max(0)
+            .min(self.thumb_travel);
+        libm::roundf(
+            thumb_offset as f32 * self.max_scroll_offset as f32 / self.thumb_travel as f32,
+        ) as isize
+    }
+}
+
 /// Scrollbar style
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct ScrollbarStyle {
-    /// Vertical scrollbar style, if any
+    /// Horizontal scrollbar style
     pub horizontal: ScrollbarInfo,
-    /// Horizontal scrollbar style, if any
+    /// Vertical scrollbar style
     pub vertical: ScrollbarInfo,
 }
 
+impl ScrollbarStyle {
+    /// Uses the same style for the horizontal and the vertical scrollbar
+    pub fn uniform(info: ScrollbarInfo) -> Self {
+        Self {
+            horizontal: info.clone(),
+            vertical: info,
+        }
+    }
+}
+
 /// Represents a `font-size` attribute
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -6273,6 +10814,13 @@ impl Default for StyleFontSize {
 
 impl_pixel_value!(StyleFontSize);
 
+impl StyleFontSize {
+    /// Parses a font size with a `px`, `em`, `pt` or `%` suffix, i.e. `"12px"`
+    pub fn from_str(input: &str) -> Option<Self> {
+        PixelValue::from_str(input).map(|inner| Self { inner })
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct FontMetrics {
@@ -6769,31 +11317,322 @@ impl Default for StyleMixBlendMode {
     }
 }
 
-impl fmt::Display for StyleMixBlendMode {
+impl_keyword_enum!(
+    StyleMixBlendMode,
+    ["normal", Normal],
+    ["multiply", Multiply],
+    ["screen", Screen],
+    ["overlay", Overlay],
+    ["darken", Darken],
+    ["lighten", Lighten],
+    ["color-dodge", ColorDodge],
+    ["color-burn", ColorBurn],
+    ["hard-light", HardLight],
+    ["soft-light", SoftLight],
+    ["difference", Difference],
+    ["exclusion", Exclusion],
+    ["hue", Hue],
+    ["saturation", Saturation],
+    ["color", Color],
+    ["luminosity", Luminosity],
+);
+
+/// Represents an `isolation` attribute: whether the element creates a new
+/// stacking context, so that `mix-blend-mode` of its children only blends
+/// with the contents of the element instead of everything behind it
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleIsolation {
+    Auto,
+    Isolate,
+}
+
+impl Default for StyleIsolation {
+    fn default() -> StyleIsolation {
+        StyleIsolation::Auto
+    }
+}
+
+impl_keyword_enum!(
+    StyleIsolation,
+    ["auto", Auto],
+    ["isolate", Isolate],
+);
+
+/// Represents an `image-rendering` attribute: which algorithm is used to scale
+/// images and background images, `pixelated` / `crisp-edges` disable smoothing
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleImageRendering {
+    Auto,
+    CrispEdges,
+    Pixelated,
+}
+
+impl Default for StyleImageRendering {
+    fn default() -> StyleImageRendering {
+        StyleImageRendering::Auto
+    }
+}
+
+impl_keyword_enum!(
+    StyleImageRendering,
+    ["auto", Auto],
+    ["crisp-edges", CrispEdges],
+    ["pixelated", Pixelated],
+);
+
+impl_vec!(CssPropertyType, CssPropertyTypeVec, CssPropertyTypeVecDestructor);
+impl_vec_clone!(CssPropertyType, CssPropertyTypeVec, CssPropertyTypeVecDestructor);
+impl_vec_debug!(CssPropertyType, CssPropertyTypeVec);
+impl_vec_eq!(CssPropertyType, CssPropertyTypeVec);
+impl_vec_ord!(CssPropertyType, CssPropertyTypeVec);
+impl_vec_hash!(CssPropertyType, CssPropertyTypeVec);
+impl_vec_partialeq!(CssPropertyType, CssPropertyTypeVec);
+impl_vec_partialord!(CssPropertyType, CssPropertyTypeVec);
+
+/// Represents a `transition-property` attribute: which properties are
+/// animated when their value changes - default: `All`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum StyleTransitionProperty {
+    All,
+    None,
+    List(CssPropertyTypeVec),
+}
+
+impl Default for StyleTransitionProperty {
+    fn default() -> Self {
+        StyleTransitionProperty::All
+    }
+}
+
+impl StyleTransitionProperty {
+    /// Returns whether a change of the given property should be animated
+    pub fn contains(&self, property: &CssPropertyType) -> bool {
+        match self {
+            StyleTransitionProperty::All => true,
+            StyleTransitionProperty::None => false,
+            StyleTransitionProperty::List(l) => l.iter().any(|p| p == property),
+        }
+    }
+}
+
+impl fmt::Display for StyleTransitionProperty {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use self::StyleMixBlendMode::*;
-        write!(
-            f,
-            "{}",
-            match self {
-                Normal => "normal",
-                Multiply => "multiply",
-                Screen => "screen",
-                Overlay => "overlay",
-                Darken => "darken",
-                Lighten => "lighten",
-                ColorDodge => "color-dodge",
-                ColorBurn => "color-burn",
-                HardLight => "hard-light",
-                SoftLight => "soft-light",
-                Difference => "difference",
-                Exclusion => "exclusion",
-                Hue => "hue",
-                Saturation => "saturation",
-                Color => "color",
-                Luminosity => "luminosity",
+        match self {
+            StyleTransitionProperty::All => write!(f, "all"),
+            StyleTransitionProperty::None => write!(f, "none"),
+            StyleTransitionProperty::List(l) => write!(
+                f,
+                "{}",
+                l.iter()
+                    .map(|p| p.to_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// Represents a `transition-duration` attribute, in milliseconds - default: `0ms`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleTransitionDuration {
+    pub inner: FloatValue,
+}
+
+/// Represents a `transition-delay` attribute, in milliseconds - default: `0ms`.
+/// A negative delay starts the transition part-way through.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleTransitionDelay {
+    pub inner: FloatValue,
+}
+
+macro_rules! impl_transition_time {
+    ($struct:ident) => {
+        impl $struct {
+            #[inline]
+            pub const fn const_ms(value: isize) -> Self {
+                Self {
+                    inner: FloatValue::const_new(value),
+                }
             }
-        )
+            #[inline]
+            pub fn ms(value: f32) -> Self {
+                Self {
+                    inner: FloatValue::new(value),
+                }
+            }
+            #[inline]
+            pub fn seconds(value: f32) -> Self {
+                Self::ms(value * 1000.0)
+            }
+            #[inline]
+            pub fn get_ms(&self) -> f32 {
+                self.inner.get()
+            }
+        }
+
+        impl fmt::Display for $struct {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}ms", self.inner.get())
+            }
+        }
+    };
+}
+
+impl_transition_time!(StyleTransitionDuration);
+impl_transition_time!(StyleTransitionDelay);
+
+/// Position of the jumps of a `steps()` timing function - default: `JumpEnd`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StepPosition {
+    /// The first jump happens when the transition begins (`start`)
+    JumpStart,
+    /// The last jump happens when the transition ends (`end`)
+    JumpEnd,
+    /// No jump at the start or end, the value is held at both ends
+    JumpNone,
+    /// Jumps both at the start and at the end
+    JumpBoth,
+}
+
+impl Default for StepPosition {
+    fn default() -> Self {
+        StepPosition::JumpEnd
+    }
+}
+
+impl_keyword_enum!(
+    StepPosition,
+    ["jump-start", JumpStart],
+    ["jump-end", JumpEnd],
+    ["jump-none", JumpNone],
+    ["jump-both", JumpBoth],
+);
+
+/// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function,
+/// `x1` and `x2` have to be in the range `0..=1`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleCubicBezier {
+    pub x1: FloatValue,
+    pub y1: FloatValue,
+    pub x2: FloatValue,
+    pub y2: FloatValue,
+}
+
+impl StyleCubicBezier {
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            x1: FloatValue::new(x1),
+            y1: FloatValue::new(y1),
+            x2: FloatValue::new(x2),
+            y2: FloatValue::new(y2),
+        }
+    }
+
+    /// Returns the curve from `(0, 0)` to `(1, 1)` described by the
+    /// control points, with `x1` and `x2` clamped to `0..=1`
+    pub fn get_curve(&self) -> SvgCubicCurve {
+        SvgCubicCurve {
+            start: SvgPoint { x: 0.0, y: 0.0 },
+            ctrl_1: SvgPoint {
+                x: self.x1.get().max(0.0).min(1.0),
+                y: self.y1.get(),
+            },
+            ctrl_2: SvgPoint {
+                x: self.x2.get().max(0.0).min(1.0),
+                y: self.y2.get(),
+            },
+            end: SvgPoint { x: 1.0, y: 1.0 },
+        }
+    }
+
+    /// Returns the output progress for the input progress `t` (`0..=1`)
+    pub fn evaluate(&self, t: f32) -> f32 {
+        AnimationInterpolationFunction::CubicBezier(self.get_curve()).evaluate(t as f64)
+    }
+}
+
+/// Arguments of a `steps(count, position)` timing function
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleSteps {
+    pub count: u32,
+    pub position: StepPosition,
+}
+
+impl StyleSteps {
+    /// Returns the output progress for the input progress `t` (`0..=1`)
+    pub fn evaluate(&self, t: f32) -> f32 {
+        let count = self.count.max(1) as f32;
+        let mut step = libm::floorf(t * count);
+        if let StepPosition::JumpStart | StepPosition::JumpBoth = self.position {
+            step += 1.0;
+        }
+        let jumps = match self.position {
+            StepPosition::JumpStart | StepPosition::JumpEnd => count,
+            StepPosition::JumpNone => (count - 1.0).max(1.0),
+            StepPosition::JumpBoth => count + 1.0,
+        };
+        step.max(0.0).min(jumps) / jumps
+    }
+}
+
+/// Represents a `transition-timing-function` attribute - default: `Ease`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum StyleTransitionTimingFunction {
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(StyleCubicBezier),
+    Steps(StyleSteps),
+}
+
+impl Default for StyleTransitionTimingFunction {
+    fn default() -> Self {
+        StyleTransitionTimingFunction::Ease
+    }
+}
+
+impl StyleTransitionTimingFunction {
+    /// Returns the output progress of the transition for the input
+    /// progress `t`, which is clamped to `0..=1`. The output can be
+    /// outside of `0..=1` for `cubic-bezier()` curves that overshoot.
+    pub fn evaluate(&self, t: f32) -> f32 {
+        use self::StyleTransitionTimingFunction::*;
+        let t = t.max(0.0).min(1.0);
+        match self {
+            Linear => t,
+            Ease => AnimationInterpolationFunction::Ease.evaluate(t as f64),
+            EaseIn => AnimationInterpolationFunction::EaseIn.evaluate(t as f64),
+            EaseOut => AnimationInterpolationFunction::EaseOut.evaluate(t as f64),
+            EaseInOut => AnimationInterpolationFunction::EaseInOut.evaluate(t as f64),
+            CubicBezier(c) => c.evaluate(t),
+            Steps(s) => s.evaluate(t),
+        }
+    }
+}
+
+impl fmt::Display for StyleTransitionTimingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::StyleTransitionTimingFunction::*;
+        match self {
+            Linear => write!(f, "linear"),
+            Ease => write!(f, "ease"),
+            EaseIn => write!(f, "ease-in"),
+            EaseOut => write!(f, "ease-out"),
+            EaseInOut => write!(f, "ease-in-out"),
+            CubicBezier(c) => write!(f, "cubic-bezier({}, {}, {}, {})", c.x1, c.y1, c.x2, c.y2),
+            Steps(s) => write!(f, "steps({}, {})", s.count, s.position),
+        }
     }
 }
 
@@ -6850,3 +11689,2326 @@ pub enum StyleCompositeFilter {
     Lighter,
     Arithmetic([FloatValue; 4]),
 }
+
+#[test]
+fn test_css_property_type_all() {
+    let map = get_css_key_map();
+    let all = CssPropertyType::all();
+    assert_eq!(all.len(), CSS_PROPERTY_KEY_MAP.len());
+    assert_eq!(all.len(), map.non_shorthands.len());
+    for ty in all {
+        assert_eq!(CssPropertyType::from_str(ty.to_str(), &map), Some(*ty));
+    }
+}
+
+#[test]
+fn test_css_property_type_display() {
+    use alloc::string::ToString;
+    for (ty, key) in CSS_PROPERTY_KEY_MAP.iter() {
+        assert_eq!(ty.to_string(), *key);
+    }
+    for (ty, key) in COMBINED_CSS_PROPERTIES_KEY_MAP.iter() {
+        assert_eq!(ty.to_string(), *key);
+    }
+}
+
+#[test]
+fn test_extend_mode_shape_round_trip() {
+    use alloc::string::ToString;
+
+    assert_eq!(ExtendMode::Repeat.to_string(), "repeat");
+    assert_eq!(
+        ExtendMode::from_str(&ExtendMode::Repeat.to_string()),
+        Some(ExtendMode::Repeat)
+    );
+    assert_eq!(
+        ExtendMode::from_str(&ExtendMode::Clamp.to_string()),
+        Some(ExtendMode::Clamp)
+    );
+    assert_eq!(ExtendMode::from_str("mirror"), None);
+
+    assert_eq!(Shape::Circle.to_string(), "circle");
+    assert_eq!(
+        Shape::from_str(&Shape::Circle.to_string()),
+        Some(Shape::Circle)
+    );
+    assert_eq!(
+        Shape::from_str(&Shape::Ellipse.to_string()),
+        Some(Shape::Ellipse)
+    );
+    assert_eq!(Shape::from_str("square"), None);
+}
+
+#[test]
+fn test_direction_corner_from_keywords() {
+    assert_eq!(DirectionCorner::from_str("top"), Some(DirectionCorner::Top));
+    assert_eq!(DirectionCorner::from_str("middle"), None);
+
+    // "to top right"
+    let keywords = "to top right".split_whitespace().collect::<Vec<_>>();
+    assert_eq!(keywords[0], "to");
+    assert_eq!(
+        DirectionCorner::from_keywords(keywords[1], keywords[2]),
+        Some(DirectionCorner::TopRight)
+    );
+    assert_eq!(
+        DirectionCorner::from_keywords("left", "bottom"),
+        Some(DirectionCorner::BottomLeft)
+    );
+
+    assert_eq!(DirectionCorner::from_keywords("left", "right"), None);
+    assert_eq!(DirectionCorner::from_keywords("top", "top"), None);
+    assert_eq!(DirectionCorner::from_keywords("top", "center"), None);
+}
+
+#[test]
+fn test_layout_side_offsets() {
+    let basis = 200.0;
+
+    let padding = LayoutSideOffsets::from_padding(
+        &LayoutPaddingTop::percent(10.0),
+        &LayoutPaddingRight::px(5.0),
+        &LayoutPaddingBottom::percent(25.0),
+        &LayoutPaddingLeft::percent(50.0),
+        basis,
+        EM_HEIGHT,
+    );
+    assert_eq!(padding, LayoutSideOffsets::new(20.0, 5.0, 50.0, 100.0));
+    assert_eq!(padding.horizontal(), 105.0);
+    assert_eq!(padding.vertical(), 70.0);
+
+    let margin = LayoutSideOffsets::from_margins(
+        &LayoutMarginTop::px(1.0),
+        &LayoutMarginRight::percent(1.0),
+        &LayoutMarginBottom::px(3.0),
+        &LayoutMarginLeft::px(4.0),
+        basis,
+        EM_HEIGHT,
+    );
+    assert_eq!(margin, LayoutSideOffsets::new(1.0, 2.0, 3.0, 4.0));
+
+    // em is relative to the font size of the node
+    let em_padding = LayoutSideOffsets::from_padding(
+        &LayoutPaddingTop::em(1.0),
+        &LayoutPaddingRight::em(2.0),
+        &LayoutPaddingBottom::px(1.0),
+        &LayoutPaddingLeft::px(1.0),
+        basis,
+        10.0,
+    );
+    assert_eq!(em_padding, LayoutSideOffsets::new(10.0, 20.0, 1.0, 1.0));
+
+    assert_eq!(
+        padding + margin,
+        LayoutSideOffsets::new(21.0, 7.0, 53.0, 104.0)
+    );
+    assert_eq!(padding + margin - margin, padding);
+    assert_eq!(LayoutSideOffsets::uniform(0.0), LayoutSideOffsets::zero());
+    assert_eq!(LayoutSideOffsets::default(), LayoutSideOffsets::zero());
+}
+
+#[test]
+fn test_background_position_from_str() {
+    use self::BackgroundPositionHorizontal as H;
+    use self::BackgroundPositionVertical as V;
+
+    assert_eq!(H::from_str("left"), Some(H::Left));
+    assert_eq!(H::from_str("center"), Some(H::Center));
+    assert_eq!(H::from_str("right"), Some(H::Right));
+    assert_eq!(
+        H::from_str("25%"),
+        Some(H::Exact(PixelValue::percent(25.0)))
+    );
+    assert_eq!(H::from_str(" 10px "), Some(H::Exact(PixelValue::px(10.0))));
+    assert_eq!(H::from_str("top"), None);
+
+    assert_eq!(V::from_str("top"), Some(V::Top));
+    assert_eq!(V::from_str("center"), Some(V::Center));
+    assert_eq!(V::from_str("bottom"), Some(V::Bottom));
+    assert_eq!(
+        V::from_str("25%"),
+        Some(V::Exact(PixelValue::percent(25.0)))
+    );
+    assert_eq!(V::from_str("left"), None);
+
+    assert_eq!(PixelValue::from_str("1.5em"), Some(PixelValue::em(1.5)));
+    assert_eq!(PixelValue::from_str("px"), None);
+    assert_eq!(PixelValue::from_str("25"), None);
+    assert_eq!(PixelValue::from_str("0"), Some(PixelValue::zero()));
+    assert_eq!(PixelValue::from_str("NaNpx"), None);
+    assert_eq!(PixelValue::from_str("infem"), None);
+}
+
+#[test]
+fn test_background_position_keyword_order() {
+    use self::BackgroundPositionHorizontal as H;
+    use self::BackgroundPositionVertical as V;
+
+    let position = |horizontal, vertical| Some(StyleBackgroundPosition { horizontal, vertical });
+
+    assert_eq!(StyleBackgroundPosition::from_str("left top"), position(H::Left, V::Top));
+    assert_eq!(StyleBackgroundPosition::from_str("top left"), position(H::Left, V::Top));
+    assert_eq!(StyleBackgroundPosition::from_str("center left"), position(H::Left, V::Center));
+    assert_eq!(StyleBackgroundPosition::from_str("bottom center"), position(H::Center, V::Bottom));
+    assert_eq!(StyleBackgroundPosition::from_str("center center"), position(H::Center, V::Center));
+
+    // only two keywords can be swapped, a length is always horizontal first
+    assert_eq!(StyleBackgroundPosition::from_str("top 10px"), None);
+    assert_eq!(StyleBackgroundPosition::from_str("left right"), None);
+    assert_eq!(StyleBackgroundPosition::from_str("top bottom"), None);
+}
+
+#[test]
+fn test_linear_gradient_from_str_angle() {
+    let gradient = LinearGradient::from_str("linear-gradient(45deg, red, blue 50%)").unwrap();
+    assert_eq!(gradient.direction, Direction::Angle(AngleValue::deg(45.0)));
+    assert_eq!(gradient.extend_mode, ExtendMode::Clamp);
+    assert_eq!(
+        gradient.stops.as_ref(),
+        &[
+            NormalizedLinearColorStop {
+                offset: PercentageValue::new(0.0),
+                color: ColorU::RED,
+            },
+            NormalizedLinearColorStop {
+                offset: PercentageValue::new(50.0),
+                color: ColorU::BLUE,
+            },
+        ][..]
+    );
+
+    // direction defaults to "to bottom"
+    let gradient = LinearGradient::from_str("linear-gradient(#f00, #0000ff)").unwrap();
+    assert_eq!(gradient.direction, Direction::default());
+    assert_eq!(gradient.stops.as_ref()[1].color, ColorU::BLUE);
+
+    assert_eq!(
+        LinearGradient::from_str("linear-gradient(45deg, red)"),
+        None
+    );
+    assert_eq!(
+        LinearGradient::from_str("linear-gradient(45deg, red, nocolor)"),
+        None
+    );
+    assert_eq!(LinearGradient::from_str("radial-gradient(red, blue)"), None);
+}
+
+#[test]
+fn test_linear_gradient_from_str_to_right() {
+    let gradient = LinearGradient::from_str(
+        "repeating-linear-gradient(to right, #ff000080 10%, white, black 90%)",
+    )
+    .unwrap();
+    assert_eq!(
+        gradient.direction,
+        Direction::FromTo(DirectionCorners {
+            from: DirectionCorner::Left,
+            to: DirectionCorner::Right,
+        })
+    );
+    assert_eq!(gradient.extend_mode, ExtendMode::Repeat);
+
+    let stops = gradient.stops.as_ref();
+    assert_eq!(stops.len(), 3);
+    assert_eq!(
+        stops[0].color,
+        ColorU {
+            r: 255,
+            g: 0,
+            b: 0,
+            a: 128
+        }
+    );
+    assert_eq!(stops[0].offset, PercentageValue::new(10.0));
+    assert_eq!(stops[1].color, ColorU::WHITE);
+    assert_eq!(stops[2].offset, PercentageValue::new(90.0));
+
+    let gradient = LinearGradient::from_str("linear-gradient(to top left, red, blue)").unwrap();
+    assert_eq!(
+        gradient.direction,
+        Direction::FromTo(DirectionCorners {
+            from: DirectionCorner::BottomRight,
+            to: DirectionCorner::TopLeft,
+        })
+    );
+
+    assert_eq!(
+        LinearGradient::from_str("linear-gradient(to left right, red, blue)"),
+        None
+    );
+}
+
+#[test]
+fn test_radial_gradient_from_str_shape() {
+    let gradient = RadialGradient::from_str("radial-gradient(circle, red, blue)").unwrap();
+    assert_eq!(gradient.shape, Shape::Circle);
+    assert_eq!(gradient.extend_mode, ExtendMode::Clamp);
+    assert_eq!(
+        gradient.stops.as_ref(),
+        &[
+            NormalizedLinearColorStop {
+                offset: PercentageValue::new(0.0),
+                color: ColorU::RED,
+            },
+            NormalizedLinearColorStop {
+                offset: PercentageValue::new(100.0),
+                color: ColorU::BLUE,
+            },
+        ][..]
+    );
+
+    let gradient =
+        RadialGradient::from_str("radial-gradient(ellipse, #fff 20%, black 80%)").unwrap();
+    assert_eq!(gradient.shape, Shape::Ellipse);
+    let stops = gradient.stops.as_ref();
+    assert_eq!(stops[0].color, ColorU::WHITE);
+    assert_eq!(stops[0].offset, PercentageValue::new(20.0));
+    assert_eq!(stops[1].offset, PercentageValue::new(80.0));
+
+    let gradient =
+        RadialGradient::from_str("repeating-radial-gradient(circle, red, blue)").unwrap();
+    assert_eq!(gradient.extend_mode, ExtendMode::Repeat);
+}
+
+#[test]
+fn test_radial_gradient_from_str_defaults() {
+    let gradient = RadialGradient::from_str("radial-gradient(red, green, blue)").unwrap();
+    assert_eq!(gradient.shape, Shape::Ellipse);
+    assert_eq!(gradient.extend_mode, ExtendMode::Clamp);
+    assert_eq!(gradient.size, RadialGradientSize::default());
+    assert_eq!(gradient.position, StyleBackgroundPosition::default());
+    let stops = gradient.stops.as_ref();
+    assert_eq!(stops.len(), 3);
+    assert_eq!(stops[1].offset, PercentageValue::new(50.0));
+
+    assert_eq!(
+        RadialGradient::from_str("radial-gradient(circle, red)"),
+        None
+    );
+    assert_eq!(RadialGradient::from_str("linear-gradient(red, blue)"), None);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_physical_pixel_conversion() {
+    for &hidpi in &[1.0, 1.5, 2.0] {
+        let physical = DipPixel(10.0).to_physical(hidpi);
+        assert_eq!(physical, PhysicalPixel(10.0 * hidpi));
+        assert_eq!(physical.to_logical(hidpi), DipPixel(10.0));
+
+        let dpi = Some(hidpi * DEFAULT_DPI);
+        assert_eq!(
+            PixelValue::px(10.0).to_pixels_with_dpi(0.0, dpi),
+            10.0 * hidpi
+        );
+        assert_eq!(
+            PixelValue::pt(72.0).to_pixels_with_dpi(0.0, dpi),
+            DEFAULT_DPI * hidpi
+        );
+        assert_eq!(
+            PixelValue::em(1.0).to_pixels_with_dpi(0.0, dpi),
+            EM_HEIGHT * hidpi
+        );
+        assert_eq!(
+            PixelValue::percent(50.0).to_pixels_with_dpi(300.0, dpi),
+            150.0
+        );
+    }
+
+    assert_eq!(
+        PixelValue::pt(12.0).to_pixels_with_dpi(0.0, None),
+        PixelValue::pt(12.0).to_pixels(0.0)
+    );
+    assert_eq!(PixelValue::pt(72.0).to_pixels(0.0), 96.0);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_pixel_value_to_pixels_em_aware() {
+    // 1em = the font size of the node, not the default 16px
+    assert_eq!(PixelValue::em(1.0).to_pixels_em_aware(0.0, 20.0), 20.0);
+    assert_eq!(PixelValue::em(1.0).to_pixels(0.0), EM_HEIGHT);
+    assert_eq!(PixelValue::em(1.5).to_pixels_em_aware(0.0, 10.0), 15.0);
+
+    // other metrics don't depend on the font size
+    assert_eq!(PixelValue::px(5.0).to_pixels_em_aware(0.0, 20.0), 5.0);
+    assert_eq!(PixelValue::percent(50.0).to_pixels_em_aware(300.0, 20.0), 150.0);
+
+    let no_percent = PixelValueNoPercent {
+        inner: PixelValue::em(2.0),
+    };
+    assert_eq!(no_percent.to_pixels_em_aware(20.0), 40.0);
+    assert_eq!(no_percent.to_pixels(), 2.0 * EM_HEIGHT);
+}
+
+#[test]
+fn test_layout_rect_to_physical_no_seams() {
+    for &hidpi in &[1.0, 1.5, 2.0] {
+        // row of adjacent rects with odd widths: at 1.5x every other edge is at x.5
+        let mut x = 0;
+        let mut last_max_x = 0;
+        for width in 1..20 {
+            let rect = LayoutRect::new(LayoutPoint::new(x, 3), LayoutSize::new(width, 5));
+            let physical = rect.to_physical(hidpi);
+            assert_eq!(physical.min_x(), last_max_x, "gap at hidpi {}", hidpi);
+            assert!((physical.width() as f32 - width as f32 * hidpi).abs() <= 1.0);
+            last_max_x = physical.max_x();
+            x += width;
+        }
+        assert_eq!(last_max_x, libm::roundf(x as f32 * hidpi) as isize);
+    }
+
+    let rect = LayoutRect::new(LayoutPoint::new(1, 1), LayoutSize::new(3, 3));
+    assert_eq!(
+        rect.to_physical(1.5),
+        LayoutRect::new(LayoutPoint::new(2, 2), LayoutSize::new(4, 4))
+    );
+    assert_eq!(rect.to_physical(1.0), rect);
+}
+
+#[test]
+fn test_conic_gradient_from_str_from_at() {
+    let gradient =
+        ConicGradient::from_str("conic-gradient(from 45deg at left top, red, blue 180deg)")
+            .unwrap();
+    assert_eq!(gradient.angle, AngleValue::deg(45.0));
+    assert_eq!(
+        gradient.center,
+        StyleBackgroundPosition {
+            horizontal: BackgroundPositionHorizontal::Left,
+            vertical: BackgroundPositionVertical::Top,
+        }
+    );
+    assert_eq!(gradient.extend_mode, ExtendMode::Clamp);
+    assert_eq!(
+        gradient.stops.as_ref(),
+        &[
+            NormalizedRadialColorStop {
+                angle: AngleValue::deg(0.0),
+                color: ColorU::RED,
+            },
+            NormalizedRadialColorStop {
+                angle: AngleValue::deg(180.0),
+                color: ColorU::BLUE,
+            },
+        ][..]
+    );
+
+    let gradient =
+        ConicGradient::from_str("conic-gradient(from 0.5turn at center, red, blue)").unwrap();
+    assert_eq!(gradient.angle, AngleValue::turn(0.5));
+    assert_eq!(gradient.center, ConicGradient::default().center);
+
+    let gradient = ConicGradient::from_str("conic-gradient(at 25% 75%, red, blue)").unwrap();
+    assert_eq!(gradient.angle, AngleValue::default());
+    assert_eq!(
+        gradient.center,
+        StyleBackgroundPosition {
+            horizontal: BackgroundPositionHorizontal::Exact(PixelValue::percent(25.0)),
+            vertical: BackgroundPositionVertical::Exact(PixelValue::percent(75.0)),
+        }
+    );
+
+    let gradient = ConicGradient::from_str("conic-gradient(at top left, red, blue)").unwrap();
+    assert_eq!(gradient.center, StyleBackgroundPosition::top_left());
+
+    assert_eq!(
+        ConicGradient::from_str("conic-gradient(from nodeg, red, blue)"),
+        None
+    );
+    assert_eq!(
+        ConicGradient::from_str("conic-gradient(from 45deg at middle, red, blue)"),
+        None
+    );
+}
+
+#[test]
+fn test_conic_gradient_from_str_minimal() {
+    let gradient = ConicGradient::from_str("conic-gradient(red, blue)").unwrap();
+    assert_eq!(gradient.angle, AngleValue::deg(0.0));
+    assert_eq!(
+        gradient.center,
+        StyleBackgroundPosition {
+            horizontal: BackgroundPositionHorizontal::Center,
+            vertical: BackgroundPositionVertical::Center,
+        }
+    );
+    assert_eq!(gradient.stops.as_ref().len(), 2);
+    assert_eq!(gradient.stops.as_ref()[1].angle, AngleValue::deg(360.0));
+
+    let gradient = ConicGradient::from_str("repeating-conic-gradient(red, blue 10%)").unwrap();
+    assert_eq!(gradient.extend_mode, ExtendMode::Repeat);
+
+    assert_eq!(ConicGradient::from_str("conic-gradient(red)"), None);
+}
+
+#[test]
+fn test_background_content_from_str() {
+    assert_eq!(
+        StyleBackgroundContent::from_str("#ff0000"),
+        Some(StyleBackgroundContent::Color(ColorU::RED))
+    );
+    assert_eq!(
+        StyleBackgroundContent::from_str(" blue "),
+        Some(StyleBackgroundContent::Color(ColorU::BLUE))
+    );
+
+    let linear = "linear-gradient(to right, red, blue)";
+    assert_eq!(
+        StyleBackgroundContent::from_str(linear),
+        LinearGradient::from_str(linear).map(StyleBackgroundContent::LinearGradient)
+    );
+    match StyleBackgroundContent::from_str("repeating-linear-gradient(red, blue 50%)") {
+        Some(StyleBackgroundContent::LinearGradient(g)) => {
+            assert_eq!(g.extend_mode, ExtendMode::Repeat);
+            assert_eq!(g.stops.as_ref().len(), 2);
+        }
+        other => panic!("expected a linear gradient, got {:?}", other),
+    }
+
+    let radial = "radial-gradient(circle, red, blue)";
+    assert_eq!(
+        StyleBackgroundContent::from_str(radial),
+        RadialGradient::from_str(radial).map(StyleBackgroundContent::RadialGradient)
+    );
+    assert!(StyleBackgroundContent::from_str(radial).is_some());
+
+    let conic = "conic-gradient(from 90deg, red, blue)";
+    assert_eq!(
+        StyleBackgroundContent::from_str(conic),
+        ConicGradient::from_str(conic).map(StyleBackgroundContent::ConicGradient)
+    );
+    assert!(StyleBackgroundContent::from_str(conic).is_some());
+
+    let logo = Some(StyleBackgroundContent::Image(AzString::from_const_str("logo")));
+    assert_eq!(StyleBackgroundContent::from_str("url(logo)"), logo);
+    assert_eq!(StyleBackgroundContent::from_str("url(\"logo\")"), logo);
+    assert_eq!(StyleBackgroundContent::from_str("image( 'logo' )"), logo);
+
+    assert_eq!(StyleBackgroundContent::from_str("url()"), None);
+    assert_eq!(StyleBackgroundContent::from_str("url('logo)"), None);
+    assert_eq!(StyleBackgroundContent::from_str("linear-gradient(red)"), None);
+    assert_eq!(StyleBackgroundContent::from_str("nocolor"), None);
+    assert_eq!(StyleBackgroundContent::from_str(""), None);
+}
+
+#[test]
+fn test_box_shadow_from_str_round_trip() {
+    let input = "2px 2px 4px 0px #00000080";
+    let shadow = StyleBoxShadow::from_str(input).unwrap();
+    assert_eq!(
+        shadow,
+        StyleBoxShadow {
+            offset: [
+                PixelValueNoPercent {
+                    inner: PixelValue::px(2.0)
+                },
+                PixelValueNoPercent {
+                    inner: PixelValue::px(2.0)
+                },
+            ],
+            color: ColorU {
+                r: 0,
+                g: 0,
+                b: 0,
+                a: 128
+            },
+            blur_radius: PixelValueNoPercent {
+                inner: PixelValue::px(4.0)
+            },
+            spread_radius: PixelValueNoPercent {
+                inner: PixelValue::px(0.0)
+            },
+            clip_mode: BoxShadowClipMode::Outset,
+        }
+    );
+    assert_eq!(shadow.to_string(), input);
+    assert_eq!(StyleBoxShadow::from_str(&shadow.to_string()), Some(shadow));
+
+    let inset = StyleBoxShadow::from_str("inset 1em 5px red").unwrap();
+    assert_eq!(inset.clip_mode, BoxShadowClipMode::Inset);
+    assert_eq!(inset.color, ColorU::RED);
+    assert_eq!(inset.to_string(), "1em 5px 0px 0px #ff0000ff inset");
+    assert_eq!(StyleBoxShadow::from_str(&inset.to_string()), Some(inset));
+
+    assert_eq!(StyleBoxShadow::from_str("5px"), None);
+    assert_eq!(StyleBoxShadow::from_str("5px 10%"), None);
+    assert_eq!(StyleBoxShadow::from_str("5px red 10px"), None);
+    assert_eq!(StyleBoxShadow::from_str("5px inset 10px"), None);
+    assert_eq!(StyleBoxShadow::from_str("1px 1px 1px 1px 1px"), None);
+}
+
+#[test]
+fn test_box_shadow_from_str_color_first() {
+    let px = |v| PixelValueNoPercent { inner: PixelValue::px(v) };
+
+    let shadow = StyleBoxShadow::from_str("red 2px 2px").unwrap();
+    assert_eq!(shadow.color, ColorU::RED);
+    assert_eq!(shadow.offset[0], px(2.0));
+    assert_eq!(shadow.offset[1], px(2.0));
+    assert_eq!(StyleBoxShadow::from_str("2px 2px red"), Some(shadow));
+
+    let inset = StyleBoxShadow::from_str("inset #0000ff 1px 2px 3px 4px").unwrap();
+    assert_eq!(inset.clip_mode, BoxShadowClipMode::Inset);
+    assert_eq!(inset.color, ColorU::BLUE);
+    assert_eq!(inset.blur_radius, px(3.0));
+    assert_eq!(inset.spread_radius, px(4.0));
+    assert_eq!(StyleBoxShadow::from_str("1px 2px 3px 4px #0000ff inset"), Some(inset));
+
+    assert_eq!(StyleBoxShadow::from_str("red 2px 2px blue"), None);
+}
+
+#[test]
+fn test_box_shadow_from_str_negative_blur() {
+    let px = |v| PixelValueNoPercent { inner: PixelValue::px(v) };
+
+    assert_eq!(StyleBoxShadow::from_str("2px 2px -4px red"), None);
+    assert_eq!(StyleBoxShadow::from_str("red 2px 2px -1px 0px"), None);
+
+    // negative offsets and spread radii are valid
+    let shadow = StyleBoxShadow::from_str("-2px -2px 0px -3px").unwrap();
+    assert_eq!(shadow.offset[0], px(-2.0));
+    assert_eq!(shadow.spread_radius, px(-3.0));
+}
+
+#[test]
+fn test_layout_offset_resolve_percent() {
+    let containing_block = LayoutSize::new(200, 100);
+
+    assert_eq!(
+        LayoutTop {
+            inner: PixelValue::percent(50.0)
+        }
+        .resolve(containing_block, EM_HEIGHT),
+        50.0
+    );
+    assert_eq!(
+        LayoutBottom {
+            inner: PixelValue::percent(50.0)
+        }
+        .resolve(containing_block, EM_HEIGHT),
+        50.0
+    );
+    assert_eq!(
+        LayoutLeft {
+            inner: PixelValue::percent(50.0)
+        }
+        .resolve(containing_block, EM_HEIGHT),
+        100.0
+    );
+    assert_eq!(
+        LayoutRight {
+            inner: PixelValue::percent(50.0)
+        }
+        .resolve(containing_block, EM_HEIGHT),
+        100.0
+    );
+
+    assert_eq!(LayoutTop::px(10.0).resolve(containing_block, EM_HEIGHT), 10.0);
+    assert_eq!(
+        CssLengthValue::Exact(LayoutLeft::px(10.0)).resolve(containing_block, EM_HEIGHT),
+        Some(10.0)
+    );
+    assert_eq!(
+        CssLengthValue::<LayoutLeft>::Auto.resolve(containing_block, EM_HEIGHT),
+        None
+    );
+    // em is relative to the font size of the node
+    assert_eq!(
+        LayoutTop {
+            inner: PixelValue::em(2.0)
+        }
+        .resolve(containing_block, 10.0),
+        20.0
+    );
+}
+
+#[test]
+fn test_style_transform_from_str() {
+    assert_eq!(
+        StyleTransform::from_str("translate(10px, 20px)"),
+        Some(StyleTransform::Translate(StyleTransformTranslate2D {
+            x: PixelValue::px(10.0),
+            y: PixelValue::px(20.0),
+        }))
+    );
+    assert_eq!(
+        StyleTransform::from_str("rotate(45deg)"),
+        Some(StyleTransform::Rotate(AngleValue::deg(45.0)))
+    );
+    assert_eq!(
+        StyleTransform::from_str("scale(2)"),
+        Some(StyleTransform::Scale(StyleTransformScale2D {
+            x: PercentageValue::new(200.0),
+            y: PercentageValue::new(200.0),
+        }))
+    );
+    assert_eq!(
+        StyleTransform::from_str(" translateY( 5em ) "),
+        Some(StyleTransform::TranslateY(PixelValue::em(5.0)))
+    );
+
+    assert_eq!(StyleTransform::from_str("rotate(45px)"), None);
+    assert_eq!(
+        StyleTransform::from_str("translate(10px, 20px, 30px)"),
+        None
+    );
+    assert_eq!(StyleTransform::from_str("matrix(1px, 2px)"), None);
+    assert_eq!(
+        StyleTransform::from_str("matrix(2, 0, 0, 2, 10px, 20px)"),
+        None
+    );
+    assert_eq!(
+        StyleTransform::from_str("matrix(2, 0, 0, 0.5, 10, -20.5)"),
+        Some(StyleTransform::Matrix(StyleTransformMatrix2D {
+            a: PixelValue::px(2.0),
+            b: PixelValue::px(0.0),
+            c: PixelValue::px(0.0),
+            d: PixelValue::px(0.5),
+            tx: PixelValue::px(10.0),
+            ty: PixelValue::px(-20.5),
+        }))
+    );
+    assert_eq!(StyleTransform::from_str("spin(45deg)"), None);
+    assert_eq!(StyleTransform::from_str("rotate(45deg"), None);
+}
+
+#[test]
+fn test_style_transform_vec_from_str() {
+    assert_eq!(
+        StyleTransformVec::from_str("rotate(45deg) scale(2)"),
+        Some(
+            vec![
+                StyleTransform::Rotate(AngleValue::deg(45.0)),
+                StyleTransform::Scale(StyleTransformScale2D {
+                    x: PercentageValue::new(200.0),
+                    y: PercentageValue::new(200.0),
+                }),
+            ]
+            .into()
+        )
+    );
+
+    // the space after the comma is part of the function
+    assert_eq!(
+        StyleTransformVec::from_str("  translate(10px, 20px)   rotateX( 90deg ) "),
+        Some(
+            vec![
+                StyleTransform::Translate(StyleTransformTranslate2D {
+                    x: PixelValue::px(10.0),
+                    y: PixelValue::px(20.0),
+                }),
+                StyleTransform::RotateX(AngleValue::deg(90.0)),
+            ]
+            .into()
+        )
+    );
+
+    assert_eq!(
+        StyleTransformVec::from_str(""),
+        Some(StyleTransformVec::from_const_slice(&[]))
+    );
+    assert_eq!(StyleTransformVec::from_str("rotate(45deg) spin(2)"), None);
+    assert_eq!(StyleTransformVec::from_str("rotate(45deg))"), None);
+}
+
+#[test]
+fn test_style_transform_vec_interpolate() {
+    let ctx = TransformResolutionContext {
+        reference_width: 200.0,
+        reference_height: 100.0,
+        em_size: 10.0,
+    };
+    let transforms = |s: &str| StyleTransformVec::from_str(s).unwrap();
+
+    assert_eq!(
+        transforms("translateX(0px)").interpolate(&transforms("translateX(100px)"), 0.25, &ctx),
+        transforms("translateX(25px)")
+    );
+    assert_eq!(
+        transforms("rotate(0deg)").interpolate(&transforms("rotate(90deg)"), 0.5, &ctx),
+        transforms("rotate(45deg)")
+    );
+
+    // empty list = identity functions
+    assert_eq!(
+        StyleTransformVec::from_const_slice(&[]).interpolate(&transforms("scaleX(3)"), 0.5, &ctx),
+        transforms("scaleX(2)")
+    );
+
+    // different functions: interpolate the decomposed matrices
+    assert_eq!(
+        transforms("translate(50%, 0px)").interpolate(&transforms("scale(2)"), 0.5, &ctx),
+        transforms("matrix(1.5, 0, 0, 1.5, 50, 0)")
+    );
+    // em is relative to the font size of the node, not the default 16px
+    assert_eq!(
+        transforms("translateY(2em)").interpolate(&transforms("scale(1)"), 0.5, &ctx),
+        transforms("matrix(1, 0, 0, 1, 0, 10)")
+    );
+}
+
+#[test]
+fn test_transform_origin_from_str() {
+    assert_eq!(
+        StyleTransformOrigin::from_str("left top"),
+        Some(StyleTransformOrigin {
+            x: PixelValue::percent(0.0),
+            y: PixelValue::percent(0.0),
+        })
+    );
+    assert_eq!(
+        StyleTransformOrigin::from_str("top left"),
+        StyleTransformOrigin::from_str("left top")
+    );
+    assert_eq!(
+        StyleTransformOrigin::from_str("right"),
+        Some(StyleTransformOrigin {
+            x: PixelValue::percent(100.0),
+            y: PixelValue::percent(50.0),
+        })
+    );
+    assert_eq!(
+        StyleTransformOrigin::from_str("bottom"),
+        Some(StyleTransformOrigin {
+            x: PixelValue::percent(50.0),
+            y: PixelValue::percent(100.0),
+        })
+    );
+    assert_eq!(
+        StyleTransformOrigin::from_str("50% 50%"),
+        Some(StyleTransformOrigin::default())
+    );
+    assert_eq!(
+        StylePerspectiveOrigin::from_str("10px 2em"),
+        Some(StylePerspectiveOrigin {
+            x: PixelValue::px(10.0),
+            y: PixelValue::em(2.0),
+        })
+    );
+    assert_eq!(
+        StylePerspectiveOrigin::from_str("25%"),
+        Some(StylePerspectiveOrigin {
+            x: PixelValue::percent(25.0),
+            y: PixelValue::percent(50.0),
+        })
+    );
+    assert_eq!(StylePerspectiveOrigin::from_str(""), None);
+}
+
+#[test]
+fn test_style_opacity_from_str() {
+    assert_eq!(StyleOpacity::from_str("50%"), Some(StyleOpacity::new(50.0)));
+    assert_eq!(StyleOpacity::from_str("0.5"), Some(StyleOpacity::new(50.0)));
+    assert_eq!(StyleOpacity::from_str("1.5"), Some(StyleOpacity::opaque()));
+    assert_eq!(StyleOpacity::from_str("-0.2"), Some(StyleOpacity::new(0.0)));
+    assert_eq!(StyleOpacity::from_str("half"), None);
+    assert_eq!(StyleOpacity::new(250.0).clamped(), StyleOpacity::opaque());
+}
+
+#[test]
+fn test_collapse_box_shadow() {
+    let shadow = StyleBoxShadow {
+        offset: [
+            PixelValueNoPercent {
+                inner: PixelValue::zero(),
+            },
+            PixelValueNoPercent {
+                inner: PixelValue::px(2.0),
+            },
+        ],
+        color: ColorU {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 128,
+        },
+        blur_radius: PixelValueNoPercent {
+            inner: PixelValue::px(4.0),
+        },
+        spread_radius: PixelValueNoPercent {
+            inner: PixelValue::zero(),
+        },
+        clip_mode: BoxShadowClipMode::Inset,
+    };
+    let other = StyleBoxShadow {
+        clip_mode: BoxShadowClipMode::Outset,
+        ..shadow
+    };
+
+    let mut props = CssProperty::box_shadow(shadow).to_vec();
+    assert_eq!(collapse_box_shadow(&props), Some(shadow));
+
+    // asymmetric: the top shadow differs
+    props[2] = CssProperty::box_shadow_top(other);
+    assert_eq!(collapse_box_shadow(&props), None);
+    assert_eq!(collapse_box_shadow(&props[..2]), None);
+
+    // a later declaration overrides the earlier one
+    props.push(CssProperty::box_shadow_top(shadow));
+    assert_eq!(collapse_box_shadow(&props), Some(shadow));
+
+    let mut vec = vec![CssProperty::opacity(StyleOpacity::opaque())];
+    vec.extend_from_slice(&CssProperty::box_shadow(shadow));
+    assert_eq!(
+        CssPropertyVec::from_vec(vec.clone()).to_css_string(),
+        "opacity: 100%; box-shadow: 0px 2px 4px 0px #00000080 inset;"
+    );
+
+    vec[1] = CssProperty::box_shadow_left(other);
+    let css = CssPropertyVec::from_vec(vec).to_css_string();
+    assert!(css.contains("box-shadow-left: "));
+    assert!(!css.contains("box-shadow: "));
+}
+
+#[test]
+fn test_backface_visibility_from_str() {
+    for v in [
+        StyleBackfaceVisibility::Visible,
+        StyleBackfaceVisibility::Hidden,
+    ]
+    .iter()
+    {
+        assert_eq!(
+            StyleBackfaceVisibility::from_str(&format!("{}", v)),
+            Some(*v)
+        );
+    }
+    assert_eq!(
+        StyleBackfaceVisibility::from_str(" hidden "),
+        Some(StyleBackfaceVisibility::Hidden)
+    );
+    assert_eq!(StyleBackfaceVisibility::from_str("collapse"), None);
+}
+
+#[test]
+fn test_keyword_enums_round_trip() {
+    use core::convert::TryFrom;
+
+    macro_rules! check_round_trip {
+        ($($enum:ident),+ $(,)?) => {$({
+            assert_eq!($enum::ALL_VARIANTS.len(), $enum::KEYWORDS.len());
+            for v in $enum::ALL_VARIANTS.iter() {
+                let keyword = v.to_string();
+                assert_eq!(keyword, v.as_keyword());
+                assert_eq!(keyword.parse::<$enum>(), Ok(*v));
+                assert_eq!($enum::try_from(keyword.as_str()), Ok(*v));
+                assert_eq!($enum::from_str(&format!(" {} ", keyword)), Some(*v));
+            }
+            let err = "not-a-keyword".parse::<$enum>().unwrap_err();
+            assert_eq!(err.input, "not-a-keyword");
+            assert_eq!(err.expected, $enum::KEYWORDS);
+        })+};
+    }
+
+    check_round_trip!(
+        BoxShadowClipMode,
+        ExtendMode,
+        BorderStyle,
+        Shape,
+        RadialGradientSize,
+        StyleCursor,
+        StyleBackgroundRepeat,
+        LayoutFlexDirection,
+        LayoutBoxSizing,
+        LayoutDisplay,
+        LayoutFloat,
+        LayoutPosition,
+        LayoutFlexWrap,
+        LayoutJustifyContent,
+        LayoutAlignItems,
+        LayoutAlignContent,
+        LayoutOverflow,
+        StyleTextAlign,
+        StyleDirection,
+        StyleBackfaceVisibility,
+        StyleMixBlendMode,
+        StyleIsolation,
+        StyleImageRendering,
+    );
+
+    assert_eq!(
+        InvalidKeywordError {
+            input: "top".into(),
+            expected: &["clamp", "repeat"]
+        }
+        .to_string(),
+        "invalid keyword \"top\", expected one of: clamp, repeat"
+    );
+}
+
+#[test]
+fn test_layout_display_from_str() {
+    for (keyword, display) in [
+        ("none", LayoutDisplay::None),
+        ("flex", LayoutDisplay::Flex),
+        ("block", LayoutDisplay::Block),
+        ("inline-block", LayoutDisplay::InlineBlock),
+        ("grid", LayoutDisplay::Grid),
+        ("inline", LayoutDisplay::Inline),
+    ]
+    .iter()
+    {
+        assert_eq!(LayoutDisplay::from_str(keyword), Some(*display));
+        assert_eq!(display.to_string(), *keyword);
+    }
+    assert_eq!(LayoutDisplay::from_str(" none "), Some(LayoutDisplay::None));
+    assert_eq!(LayoutDisplay::from_str("contents"), None);
+    assert_eq!(LayoutDisplay::default(), LayoutDisplay::Flex);
+}
+
+#[test]
+fn test_layout_position_from_str() {
+    for (keyword, position, positioned, out_of_flow) in [
+        ("static", LayoutPosition::Static, false, false),
+        ("relative", LayoutPosition::Relative, true, false),
+        ("absolute", LayoutPosition::Absolute, true, true),
+        ("fixed", LayoutPosition::Fixed, true, true),
+    ]
+    .iter()
+    {
+        assert_eq!(LayoutPosition::from_str(keyword), Some(*position));
+        assert_eq!(position.to_string(), *keyword);
+        assert_eq!(position.is_positioned(), *positioned);
+        assert_eq!(position.is_out_of_flow(), *out_of_flow);
+    }
+    assert_eq!(LayoutPosition::from_str("sticky"), None);
+}
+
+#[test]
+fn test_flex_alignment_from_str() {
+    assert_eq!(
+        LayoutJustifyContent::from_str("flex-start"),
+        Some(LayoutJustifyContent::Start)
+    );
+    assert_eq!(
+        LayoutJustifyContent::from_str("space-between"),
+        Some(LayoutJustifyContent::SpaceBetween)
+    );
+    assert_eq!(LayoutJustifyContent::SpaceEvenly.to_string(), "space-evenly");
+    assert_eq!(LayoutJustifyContent::from_str("stretch"), None);
+
+    assert_eq!(
+        LayoutAlignItems::from_str("flex-end"),
+        Some(LayoutAlignItems::FlexEnd)
+    );
+    assert_eq!(
+        LayoutAlignItems::from_str("stretch"),
+        Some(LayoutAlignItems::Stretch)
+    );
+    assert_eq!(LayoutAlignItems::Center.to_string(), "center");
+    assert_eq!(LayoutAlignItems::from_str("space-around"), None);
+
+    assert_eq!(
+        LayoutAlignContent::from_str("space-around"),
+        Some(LayoutAlignContent::SpaceAround)
+    );
+    assert_eq!(
+        LayoutAlignContent::from_str("flex-start"),
+        Some(LayoutAlignContent::Start)
+    );
+    assert_eq!(LayoutAlignContent::End.to_string(), "flex-end");
+    assert_eq!(LayoutAlignContent::from_str("baseline"), None);
+}
+
+#[test]
+fn test_background_repeat_compute_tiles() {
+    let tile = LayoutSize::new(30, 30);
+    let area = LayoutRect::new(LayoutPoint::zero(), LayoutSize::new(100, 100));
+
+    let round = StyleBackgroundRepeat::Round.compute_tiles(tile, area);
+    assert_eq!((round.count_x, round.count_y), (3, 3));
+    assert!((round.tile_width - 100.0 / 3.0).abs() < 0.001);
+    assert!((round.tile_height - 100.0 / 3.0).abs() < 0.001);
+    assert_eq!((round.gap_x, round.gap_y), (0.0, 0.0));
+
+    let space = StyleBackgroundRepeat::Space.compute_tiles(tile, area);
+    assert_eq!((space.count_x, space.count_y), (3, 3));
+    assert_eq!((space.tile_width, space.tile_height), (30.0, 30.0));
+    assert_eq!((space.gap_x, space.gap_y), (5.0, 5.0));
+
+    let repeat_x = StyleBackgroundRepeat::RepeatX.compute_tiles(tile, area);
+    assert_eq!((repeat_x.count_x, repeat_x.count_y), (4, 1));
+
+    let no_repeat = StyleBackgroundRepeat::NoRepeat.compute_tiles(tile, area);
+    assert_eq!((no_repeat.count_x, no_repeat.count_y), (1, 1));
+
+    assert_eq!(StyleBackgroundRepeat::from_str("space"), Some(StyleBackgroundRepeat::Space));
+    assert_eq!(StyleBackgroundRepeat::Round.to_string(), "round");
+}
+
+#[test]
+fn test_flex_direction_and_wrap_from_str() {
+    for (keyword, direction) in [
+        ("row", LayoutFlexDirection::Row),
+        ("row-reverse", LayoutFlexDirection::RowReverse),
+        ("column", LayoutFlexDirection::Column),
+        ("column-reverse", LayoutFlexDirection::ColumnReverse),
+    ]
+    .iter()
+    {
+        assert_eq!(LayoutFlexDirection::from_str(keyword), Some(*direction));
+        assert_eq!(direction.to_string(), *keyword);
+    }
+    assert_eq!(LayoutFlexDirection::from_str("row reverse"), None);
+
+    for (keyword, wrap) in [
+        ("wrap", LayoutFlexWrap::Wrap),
+        ("nowrap", LayoutFlexWrap::NoWrap),
+        ("wrap-reverse", LayoutFlexWrap::WrapReverse),
+    ]
+    .iter()
+    {
+        assert_eq!(LayoutFlexWrap::from_str(keyword), Some(*wrap));
+        assert_eq!(wrap.to_string(), *keyword);
+    }
+    assert_eq!(LayoutFlexWrap::from_str("no-wrap"), None);
+}
+
+#[test]
+fn test_text_alignment_from_str() {
+    for (keyword, align) in [
+        ("left", StyleTextAlign::Left),
+        ("center", StyleTextAlign::Center),
+        ("right", StyleTextAlign::Right),
+        ("start", StyleTextAlign::Start),
+        ("end", StyleTextAlign::End),
+    ]
+    .iter()
+    {
+        assert_eq!(StyleTextAlign::from_str(keyword), Some(*align));
+        assert_eq!(align.to_string(), *keyword);
+    }
+    assert_eq!(StyleTextAlign::from_str("justify"), None);
+
+    for (keyword, align) in [
+        ("baseline", StyleVerticalAlign::Baseline),
+        ("top", StyleVerticalAlign::Top),
+        ("middle", StyleVerticalAlign::Middle),
+        ("bottom", StyleVerticalAlign::Bottom),
+        ("text-top", StyleVerticalAlign::TextTop),
+        ("text-bottom", StyleVerticalAlign::TextBottom),
+        ("sub", StyleVerticalAlign::Sub),
+        ("super", StyleVerticalAlign::Super),
+        ("-2px", StyleVerticalAlign::Exact(PixelValue::px(-2.0))),
+        ("50%", StyleVerticalAlign::Exact(PixelValue::percent(50.0))),
+    ]
+    .iter()
+    {
+        assert_eq!(StyleVerticalAlign::from_str(keyword), Some(*align));
+        assert_eq!(align.to_string(), *keyword);
+    }
+    assert_eq!(StyleVerticalAlign::from_str("center"), None);
+}
+
+#[test]
+fn test_calc_expression_evaluate() {
+    use self::CalcExpression::*;
+
+    let ctx = UnitResolutionContext {
+        percent_resolve: 400.0,
+        em_size: 20.0,
+    };
+
+    // (100% - 2em) / 4 * 3
+    let expr = Mul(
+        Box::new(Div(
+            Box::new(Sub(
+                Box::new(Value(PixelValue::percent(100.0))),
+                Box::new(Value(PixelValue::em(2.0))),
+            )),
+            FloatValue::new(4.0),
+        )),
+        FloatValue::new(3.0),
+    );
+    assert_eq!(expr.evaluate(&ctx), Some(270.0));
+    assert_eq!(expr.to_string(), "calc((100% - 2em) / 4 * 3)");
+
+    let calc = expr.to_calc_length().unwrap();
+    assert_eq!(calc.evaluate(&ctx), 270.0);
+    assert_eq!(calc.to_string(), "calc(75% - 1.5em)");
+    let pt = CalcLength::from_pixel_value(PixelValue::pt(-3.0));
+    assert_eq!(pt.to_string(), "calc(-3pt)");
+    assert_eq!(pt.evaluate(&ctx), -4.0);
+    assert_eq!(CalcLength::default().to_string(), "calc(0px)");
+
+    // division by zero can neither be simplified nor evaluated
+    let div_zero = Div(Box::new(Value(PixelValue::px(10.0))), FloatValue::new(0.0));
+    assert_eq!(div_zero.evaluate(&ctx), None);
+    assert_eq!(div_zero.to_calc_length(), None);
+
+    let value: LayoutWidthValue = CssLengthValue::calc(calc);
+    assert_eq!(value.resolve_length(&ctx, |w| w.inner), Some(270.0));
+    assert_eq!(
+        CssLengthValue::Exact(LayoutWidth::percent(50.0)).resolve_length(&ctx, |w| w.inner),
+        Some(200.0)
+    );
+    assert_eq!(
+        CssLengthValue::<LayoutWidth>::Auto.resolve_length(&ctx, |w| w.inner),
+        None
+    );
+    assert!(CssProperty::calc(CssPropertyType::MarginLeft, calc).is_some());
+    assert!(CssProperty::calc(CssPropertyType::Display, calc).is_none());
+}
+
+#[test]
+fn test_box_sizing_and_float_from_str() {
+    assert_eq!(LayoutBoxSizing::from_str("content-box"), Some(LayoutBoxSizing::ContentBox));
+    assert_eq!(LayoutBoxSizing::from_str("border-box"), Some(LayoutBoxSizing::BorderBox));
+    assert_eq!(LayoutBoxSizing::from_str("padding-box"), None);
+
+    assert_eq!(LayoutFloat::from_str("left"), Some(LayoutFloat::Left));
+    assert_eq!(LayoutFloat::from_str("right"), Some(LayoutFloat::Right));
+    assert_eq!(LayoutFloat::from_str("none"), Some(LayoutFloat::None));
+    assert_eq!(LayoutFloat::from_str("center"), None);
+    assert_eq!(LayoutFloat::default(), LayoutFloat::Left);
+}
+
+#[test]
+fn test_font_size_and_line_height_from_str() {
+    assert_eq!(StyleFontSize::from_str("12px"), Some(StyleFontSize::px(12.0)));
+    assert_eq!(StyleFontSize::from_str(" 1.5em "), Some(StyleFontSize::em(1.5)));
+    assert_eq!(StyleFontSize::from_str("12"), None);
+    assert_eq!(StyleFontSize::from_str("large"), None);
+
+    let line_height = |p: f32| StyleLineHeight { inner: PercentageValue::new(p) };
+    assert_eq!(StyleLineHeight::from_str("1.5"), Some(line_height(150.0)));
+    assert_eq!(StyleLineHeight::from_str("150%"), Some(line_height(150.0)));
+    assert_eq!(StyleLineHeight::from_str("1"), Some(line_height(100.0)));
+    assert_eq!(StyleLineHeight::from_str("1.5px"), None);
+}
+
+#[test]
+fn test_overflow_clip() {
+    assert_eq!(LayoutOverflow::from_str("clip"), Some(LayoutOverflow::Clip));
+    assert_eq!(LayoutOverflow::Clip.to_string(), "clip");
+
+    // clip never requests a scrollbar, not even when overflowing
+    assert!(!LayoutOverflow::Clip.needs_scrollbar(true));
+    assert!(!LayoutOverflow::Clip.needs_scrollbar(false));
+    assert!(!LayoutOverflow::Clip.can_create_scroll_container());
+    assert!(LayoutOverflow::Clip.is_overflow_hidden());
+    assert!(!LayoutOverflow::Clip.is_overflow_visible());
+    assert!(LayoutOverflow::Hidden.can_create_scroll_container());
+    assert!(LayoutOverflow::Auto.needs_scrollbar(true));
+}
+
+#[test]
+fn test_collapse_overflow() {
+    use LayoutOverflow::*;
+
+    let mut props = CssProperty::overflow(Hidden, Hidden).to_vec();
+    assert_eq!(collapse_overflow(&props), Some((Hidden, Hidden)));
+    assert_eq!(collapse_overflow(&props[..1]), None);
+    assert_eq!(
+        CssPropertyVec::from_vec(props.clone()).to_css_string(),
+        "overflow: hidden;"
+    );
+
+    // a later declaration overrides the earlier one
+    props.push(CssProperty::overflow_y(Auto));
+    assert_eq!(collapse_overflow(&props), Some((Hidden, Auto)));
+    assert_eq!(
+        CssPropertyVec::from_vec(props).to_css_string(),
+        "overflow: hidden auto;"
+    );
+
+    let props = vec![
+        CssProperty::OverflowX(CssPropertyValue::Inherit),
+        CssProperty::overflow_y(Clip),
+    ];
+    assert_eq!(collapse_overflow(&props), None);
+    assert_eq!(
+        CssPropertyVec::from_vec(props).to_css_string(),
+        "overflow-x: inherit; overflow-y: clip;"
+    );
+}
+
+#[test]
+fn test_css_property_relayout_delegates() {
+    let width = CssProperty::width(LayoutWidth::px(100.0));
+    assert!(width.can_trigger_relayout());
+    assert!(!width.is_inheritable());
+    assert!(!width.is_gpu_only_property());
+
+    let text_color = CssProperty::text_color(StyleTextColor {
+        inner: ColorU::BLACK,
+    });
+    assert!(!text_color.can_trigger_relayout());
+    assert!(text_color.is_inheritable());
+
+    let opacity = CssProperty::opacity(StyleOpacity::opaque());
+    assert!(opacity.is_gpu_only_property());
+    assert!(!opacity.can_trigger_relayout());
+}
+
+#[test]
+fn test_css_property_vec_accessors() {
+    let props = [
+        CssProperty::width(LayoutWidth::px(100.0)),
+        CssProperty::opacity(StyleOpacity::opaque()),
+    ];
+
+    let vec = CssPropertyVec::from_slice(&props);
+    assert_eq!(vec.len(), 2);
+    assert!(!vec.is_empty());
+    assert_eq!(vec.as_ref(), &props[..]);
+    assert_eq!(vec.get(1), Some(&props[1]));
+    assert_eq!(vec.get(2), None);
+    assert_eq!(vec.iter().filter(|p| p.can_trigger_relayout()).count(), 1);
+
+    let collected = props.iter().cloned().collect::<CssPropertyVec>();
+    assert_eq!(collected, vec);
+    assert!(CssPropertyVec::new().is_empty());
+}
+
+#[test]
+fn test_linear_color_stop_vec_accessors() {
+    let stops = [
+        NormalizedLinearColorStop {
+            offset: PercentageValue::const_new(0),
+            color: ColorU::RED,
+        },
+        NormalizedLinearColorStop {
+            offset: PercentageValue::const_new(100),
+            color: ColorU::WHITE,
+        },
+    ];
+
+    let vec = NormalizedLinearColorStopVec::from_slice(&stops);
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec.get(0).map(|s| s.color), Some(ColorU::RED));
+    assert_eq!(vec.get(5), None);
+    assert_eq!(
+        vec.iter().map(|s| s.offset.get()).collect::<Vec<_>>(),
+        vec![0.0, 100.0]
+    );
+    assert_eq!(
+        OptionNormalizedLinearColorStop::from(vec.get(1).cloned()),
+        OptionNormalizedLinearColorStop::Some(stops[1].clone())
+    );
+
+    let collected = stops.iter().rev().cloned().collect::<NormalizedLinearColorStopVec>();
+    assert_eq!(collected.as_slice()[0], stops[1]);
+}
+
+#[test]
+fn test_vertical_align_resolve_offset() {
+    // 20px line, baseline 16px below the top of the line, 10px high icon
+    let (line_height, element_height, baseline) = (20.0, 10.0, 16.0);
+    let offset = |align: StyleVerticalAlign| {
+        align.resolve_offset(line_height, element_height, baseline, 10.0)
+    };
+
+    assert_eq!(offset(StyleVerticalAlign::Baseline), 6.0);
+    assert_eq!(offset(StyleVerticalAlign::Top), 0.0);
+    assert_eq!(offset(StyleVerticalAlign::Middle), 5.0);
+    assert_eq!(offset(StyleVerticalAlign::Bottom), 10.0);
+    assert!(offset(StyleVerticalAlign::Sub) > offset(StyleVerticalAlign::Baseline));
+    assert!(offset(StyleVerticalAlign::Super) < offset(StyleVerticalAlign::Baseline));
+
+    // positive values raise the item, negative values lower it
+    assert_eq!(offset(StyleVerticalAlign::Exact(PixelValue::px(2.0))), 4.0);
+    assert_eq!(offset(StyleVerticalAlign::Exact(PixelValue::px(-4.0))), 10.0);
+    // percentages refer to the line height
+    assert_eq!(offset(StyleVerticalAlign::Exact(PixelValue::percent(-50.0))), 16.0);
+    // em refers to the font size of the item
+    assert_eq!(offset(StyleVerticalAlign::Exact(PixelValue::em(0.5))), 1.0);
+
+    let prop = CssProperty::vertical_align(StyleVerticalAlign::Middle);
+    assert_eq!(prop.get_type(), CssPropertyType::VerticalAlign);
+    assert!(prop.can_trigger_relayout());
+    assert!(!prop.is_inheritable());
+    assert_eq!(prop.format_css(), "vertical-align: middle;");
+}
+
+#[test]
+fn test_background_content_does_not_trigger_relayout() {
+    let color = CssProperty::background_content(StyleBackgroundContentVec::from_vec(vec![
+        StyleBackgroundContent::Color(ColorU::RED),
+    ]));
+    let image = CssProperty::background_content(StyleBackgroundContentVec::from_vec(vec![
+        StyleBackgroundContent::Image("image.png".into()),
+    ]));
+
+    // both background-color and background-image are stored as BackgroundContent
+    assert_eq!(color.get_type(), CssPropertyType::BackgroundContent);
+    assert_eq!(image.get_type(), CssPropertyType::BackgroundContent);
+    assert!(!color.can_trigger_relayout());
+    assert!(!image.can_trigger_relayout());
+
+    for ty in [
+        CssPropertyType::BackgroundContent,
+        CssPropertyType::BackgroundPosition,
+        CssPropertyType::BackgroundSize,
+        CssPropertyType::BackgroundRepeat,
+    ]
+    .iter()
+    {
+        assert!(!ty.can_trigger_relayout());
+        assert!(!CssProperty::initial(*ty).can_trigger_relayout());
+    }
+}
+
+#[test]
+fn test_color_u_multiply_add() {
+    assert_eq!(ColorU::WHITE.multiply(&ColorU::RED), ColorU::RED);
+    assert_eq!(ColorU::RED.multiply(&ColorU::BLUE), ColorU::BLACK);
+    assert_eq!(ColorU::WHITE.multiply(&ColorU::TRANSPARENT), ColorU::TRANSPARENT);
+    assert_eq!(
+        ColorU::new_rgb(128, 128, 128).multiply(&ColorU::new_rgb(128, 255, 0)),
+        ColorU::new_rgb(64, 128, 0)
+    );
+
+    assert_eq!(ColorU::BLACK.add_saturating(&ColorU::BLUE), ColorU::BLUE);
+    assert_eq!(
+        ColorU::new_rgb(200, 10, 0).add_saturating(&ColorU::new_rgb(100, 10, 0)),
+        ColorU::new_rgb(255, 20, 0)
+    );
+    assert_eq!(ColorU::TRANSPARENT.add_saturating(&ColorU::TRANSPARENT).a, 0);
+}
+
+#[test]
+fn test_color_u_with_channels() {
+    assert_eq!(ColorU::RED.with_a(128), ColorU { r: 255, g: 0, b: 0, a: 128 });
+    assert_eq!(
+        ColorU::BLACK.with_r(10).with_g(20).with_b(30).with_a(40),
+        ColorU { r: 10, g: 20, b: 30, a: 40 }
+    );
+    assert_eq!(ColorU::RED.with_alpha_f32(0.5).a, 128);
+    assert_eq!(ColorU::RED.with_alpha_f32(0.0), ColorU::RED.with_a(0));
+    assert_eq!(ColorU::RED.with_alpha_f32(2.0), ColorU::RED);
+    assert_eq!(ColorU::RED.with_alpha_f32(-1.0).a, 0);
+}
+
+#[test]
+fn test_gradient_builders() {
+    let linear = LinearGradient::builder()
+        .to_corner(DirectionCorner::BottomRight)
+        .stop(ColorU::RED)
+        .stop_at(ColorU::BLUE, 80.0)
+        .repeat()
+        .build()
+        .unwrap();
+    assert_eq!(
+        linear.direction,
+        Direction::FromTo(DirectionCorners {
+            from: DirectionCorner::TopLeft,
+            to: DirectionCorner::BottomRight,
+        })
+    );
+    assert_eq!(linear.extend_mode, ExtendMode::Repeat);
+    assert_eq!(linear.stops.as_ref()[1].offset, PercentageValue::new(80.0));
+    assert_eq!(
+        linear.to_css_string(),
+        "repeating-linear-gradient(to bottom right, #ff0000ff 0%, #0000ffff 80%)"
+    );
+
+    let radial = RadialGradient::builder().stop(ColorU::RED).stop(ColorU::BLUE).build().unwrap();
+    assert_eq!(radial.extend_mode, ExtendMode::Clamp);
+    assert_eq!(
+        radial.to_css_string(),
+        "radial-gradient(ellipse, #ff0000ff 0%, #0000ffff 100%)"
+    );
+
+    let conic = ConicGradient::builder()
+        .from_angle(AngleValue::deg(90.0))
+        .stop(ColorU::RED)
+        .stop(ColorU::BLUE)
+        .build()
+        .unwrap();
+    assert_eq!(
+        conic.to_css_string(),
+        "conic-gradient(from 90deg at center center, #ff0000ff 0deg, #0000ffff 360deg)"
+    );
+
+    assert_eq!(LinearGradient::builder().build(), None);
+    assert_eq!(RadialGradient::builder().repeat().build(), None);
+    assert_eq!(ConicGradient::builder().build(), None);
+}
+
+#[test]
+fn test_gradient_to_css_string_round_trip() {
+    let linear = [
+        LinearGradient::builder().stop(ColorU::RED).stop(ColorU::BLUE).build(),
+        LinearGradient::builder()
+            .angle(AngleValue::deg(45.0))
+            .stop_at(ColorU::RED.with_a(128), 10.0)
+            .stop(ColorU::WHITE)
+            .stop_at(ColorU::BLACK, 90.0)
+            .repeat()
+            .build(),
+    ];
+    for gradient in linear.iter().map(|g| g.clone().unwrap()) {
+        assert_eq!(LinearGradient::from_str(&gradient.to_css_string()), Some(gradient));
+    }
+
+    let radial = [
+        RadialGradient::builder().stop(ColorU::RED).stop(ColorU::BLUE).build(),
+        RadialGradient::builder()
+            .shape(Shape::Circle)
+            .size(RadialGradientSize::ClosestSide)
+            .at(StyleBackgroundPosition {
+                horizontal: BackgroundPositionHorizontal::Center,
+                vertical: BackgroundPositionVertical::Bottom,
+            })
+            .stop(ColorU::RED)
+            .stop_at(ColorU::BLUE, 30.0)
+            .repeat()
+            .build(),
+    ];
+    for gradient in radial.iter().map(|g| g.clone().unwrap()) {
+        assert_eq!(RadialGradient::from_str(&gradient.to_css_string()), Some(gradient));
+    }
+
+    let conic = ConicGradient::builder()
+        .from_angle(AngleValue::deg(30.0))
+        .at(StyleBackgroundPosition {
+            horizontal: BackgroundPositionHorizontal::Left,
+            vertical: BackgroundPositionVertical::Top,
+        })
+        .stop(ColorU::RED)
+        .stop_at(ColorU::BLUE, 90.0)
+        .stop(ColorU::RED)
+        .build()
+        .unwrap();
+    assert_eq!(ConicGradient::from_str(&conic.to_css_string()), Some(conic));
+}
+
+#[test]
+fn test_color_u_hue_rotate() {
+    assert_eq!(ColorU::RED.hue_rotate(120.0), ColorU::GREEN);
+    assert_eq!(ColorU::RED.hue_rotate(240.0), ColorU::BLUE);
+    assert_eq!(ColorU::RED.hue_rotate(360.0), ColorU::RED);
+    assert_eq!(ColorU::RED.hue_rotate(-120.0), ColorU::BLUE);
+    assert_eq!(ColorU::RED.hue_rotate(480.0), ColorU::GREEN);
+    assert_eq!(ColorU::RED.with_a(128).hue_rotate(120.0), ColorU::GREEN.with_a(128));
+
+    // grays have no hue
+    assert_eq!(ColorU::WHITE.hue_rotate(90.0), ColorU::WHITE);
+    assert_eq!(ColorU::new_rgb(128, 128, 128).hue_rotate(90.0), ColorU::new_rgb(128, 128, 128));
+
+    // saturation and lightness are preserved
+    let c = ColorU::new_rgb(200, 100, 50);
+    assert_eq!(c.hue_rotate(90.0).hue_rotate(270.0), c);
+}
+
+#[test]
+fn test_angle_value_add_sub() {
+    assert_eq!(AngleValue::deg(350.0) + AngleValue::deg(20.0), AngleValue::deg(10.0));
+    assert_eq!(AngleValue::deg(10.0) - AngleValue::deg(20.0), AngleValue::deg(350.0));
+    assert_eq!(AngleValue::deg(90.0) + AngleValue::turn(0.5), AngleValue::deg(270.0));
+    assert_eq!(AngleValue::turn(1.0) - AngleValue::percent(25.0), AngleValue::deg(270.0));
+    assert_eq!(AngleValue::deg(180.0) + AngleValue::deg(180.0), AngleValue::deg(0.0));
+    assert_eq!(AngleValue::deg(-30.0) + AngleValue::deg(0.0), AngleValue::deg(330.0));
+}
+
+#[test]
+fn test_css_property_sort_for_cascade() {
+    let red = StyleBackgroundContentVec::from_vec(vec![StyleBackgroundContent::Color(ColorU::RED)]);
+    let blue = StyleBackgroundContentVec::from_vec(vec![StyleBackgroundContent::Color(ColorU::BLUE)]);
+
+    // background: red; margin-top: 5px; background-color: blue; width: 10px;
+    let mut props = CssPropertyVec::from_vec(vec![
+        CssProperty::background_content(red),
+        CssProperty::margin_top(LayoutMarginTop::px(5.0)),
+        CssProperty::background_content(blue.clone()),
+        CssProperty::width(LayoutWidth::px(10.0)),
+    ]);
+    props.sort_for_cascade();
+
+    assert_eq!(
+        props.as_ref(),
+        &[
+            CssProperty::width(LayoutWidth::px(10.0)),
+            CssProperty::background_content(blue),
+            CssProperty::margin_top(LayoutMarginTop::px(5.0)),
+        ][..]
+    );
+
+    let keys = props.iter().map(|p| p.cascade_sort_key()).collect::<Vec<_>>();
+    let mut sorted = keys.clone();
+    sorted.sort();
+    assert_eq!(keys, sorted);
+}
+
+#[test]
+fn test_pixel_value_no_percent_from_str() {
+    assert_eq!(
+        PixelValueNoPercent::from_str("4px"),
+        Some(PixelValueNoPercent { inner: PixelValue::px(4.0) })
+    );
+    assert_eq!(
+        PixelValueNoPercent::from_str("1.5em"),
+        Some(PixelValueNoPercent { inner: PixelValue::em(1.5) })
+    );
+    assert_eq!(PixelValueNoPercent::from_str("50%"), None);
+    assert_eq!(PixelValueNoPercent::from_str("px"), None);
+}
+
+#[test]
+fn test_pixel_snap_adjacent_rects() {
+    let dpi = 1.5;
+    let a = LayoutRectF32::new(0.0, 0.0, 10.25, 10.0);
+    let b = LayoutRectF32::new(10.25, 0.0, 10.25, 10.0);
+
+    let a = PixelSnap::snap_rect(a, dpi, SnapPolicy::Round);
+    let b = PixelSnap::snap_rect(b, dpi, SnapPolicy::Round);
+
+    // no gap, no overlap
+    assert_eq!(a.max_x(), b.x);
+    // edges are on whole device pixels: 0, 15, 31
+    assert_eq!(a.x * dpi, 0.0);
+    assert_eq!(a.max_x() * dpi, 15.0);
+    assert_eq!(b.max_x() * dpi, 31.0);
+
+    let floor = PixelSnap::snap_rect(LayoutRectF32::new(10.25, 0.0, 10.25, 10.0), dpi, SnapPolicy::Floor);
+    assert_eq!((floor.x * dpi, floor.max_x() * dpi), (15.0, 30.0));
+    let ceil = PixelSnap::snap_rect(LayoutRectF32::new(10.25, 0.0, 10.25, 10.0), dpi, SnapPolicy::Ceil);
+    assert_eq!((ceil.x * dpi, ceil.max_x() * dpi), (16.0, 31.0));
+    let unsnapped = LayoutRectF32::new(10.25, 0.0, 10.25, 10.0);
+    assert_eq!(PixelSnap::snap_rect(unsnapped, dpi, SnapPolicy::None), unsnapped);
+}
+
+#[test]
+fn test_pixel_snap_border_widths() {
+    let widths = LayoutSideOffsets::new(0.25, 1.0, 0.0, 2.5);
+    let snapped = PixelSnap::snap_border_widths(&widths, 1.5);
+    // FloatValue is fixed-point, so 1/1.5 isn't exactly representable
+    let device_px = |w: FloatValue| libm::roundf(w.get() * 1.5 * 100.0) / 100.0;
+    // hairline stays one device pixel wide, zero stays zero
+    assert_eq!(device_px(snapped.top), 1.0);
+    assert_eq!(device_px(snapped.right), 2.0);
+    assert_eq!(snapped.bottom.get(), 0.0);
+    assert_eq!(device_px(snapped.left), 4.0);
+}
+
+#[test]
+fn test_css_property_vec_layout_hash() {
+    let props = |color: ColorU, width: f32| {
+        CssPropertyVec::from_vec(vec![
+            CssProperty::text_color(StyleTextColor { inner: color }),
+            CssProperty::width(LayoutWidth::px(width)),
+        ])
+    };
+
+    let base = props(ColorU::RED, 10.0).layout_hash();
+    assert_eq!(base, props(ColorU::RED, 10.0).layout_hash());
+    // paint-only change
+    assert_eq!(base, props(ColorU::BLUE, 10.0).layout_hash());
+    // layout change
+    assert_ne!(base, props(ColorU::RED, 20.0).layout_hash());
+}
+
+#[test]
+fn test_css_property_vec_paint_hash() {
+    let props = |color: ColorU, width: f32| {
+        CssPropertyVec::from_vec(vec![
+            CssProperty::text_color(StyleTextColor { inner: color }),
+            CssProperty::width(LayoutWidth::px(width)),
+        ])
+    };
+
+    let base = props(ColorU::RED, 10.0).paint_hash();
+    assert_eq!(base, props(ColorU::RED, 10.0).paint_hash());
+    // layout-only change
+    assert_eq!(base, props(ColorU::RED, 20.0).paint_hash());
+    // paint change
+    assert_ne!(base, props(ColorU::BLUE, 10.0).paint_hash());
+}
+
+#[test]
+fn test_layout_rect_scale() {
+    let rect = LayoutRect::new(LayoutPoint::new(1, 3), LayoutSize::new(10, 20));
+
+    assert_eq!(rect.scale_to_f32(2.0), LayoutRectF32::new(2.0, 6.0, 20.0, 40.0));
+    assert_eq!(rect.scale_to_f32(1.5), LayoutRectF32::new(1.5, 4.5, 15.0, 30.0));
+
+    assert_eq!(
+        rect.scale_rounded(2.0),
+        LayoutRect::new(LayoutPoint::new(2, 6), LayoutSize::new(20, 40))
+    );
+    // 1.5 and 4.5 are rounded away from zero
+    assert_eq!(
+        rect.scale_rounded(1.5),
+        LayoutRect::new(LayoutPoint::new(2, 5), LayoutSize::new(15, 30))
+    );
+    let odd = LayoutRect::new(LayoutPoint::zero(), LayoutSize::new(11, 21));
+    assert_eq!(odd.scale_rounded(1.5).size, LayoutSize::new(17, 32));
+}
+
+#[test]
+fn test_direction_resolves_logical_alignment() {
+    assert_eq!(StyleDirection::from_str("rtl"), Some(StyleDirection::Rtl));
+    assert_eq!(StyleDirection::default(), StyleDirection::Ltr);
+
+    assert_eq!(StyleTextAlign::Start.resolve(StyleDirection::Rtl), StyleTextAlign::Right);
+    assert_eq!(StyleTextAlign::End.resolve(StyleDirection::Rtl), StyleTextAlign::Left);
+    assert_eq!(StyleTextAlign::Start.resolve(StyleDirection::Ltr), StyleTextAlign::Left);
+    assert_eq!(StyleTextAlign::End.resolve(StyleDirection::Ltr), StyleTextAlign::Right);
+    assert_eq!(StyleTextAlign::Center.resolve(StyleDirection::Rtl), StyleTextAlign::Center);
+    assert_eq!(StyleTextAlign::Left.resolve(StyleDirection::Rtl), StyleTextAlign::Left);
+
+    assert_eq!(
+        LayoutFlexDirection::Row.resolve_physical(StyleDirection::Rtl),
+        PhysicalFlexDirection::RightToLeft
+    );
+    assert_eq!(
+        LayoutFlexDirection::RowReverse.resolve_physical(StyleDirection::Rtl),
+        PhysicalFlexDirection::LeftToRight
+    );
+    assert_eq!(
+        LayoutFlexDirection::Column.resolve_physical(StyleDirection::Rtl),
+        PhysicalFlexDirection::TopToBottom
+    );
+
+    assert!(CssPropertyType::Direction.is_inheritable());
+    assert!(CssPropertyType::Direction.can_trigger_relayout());
+    assert_eq!(
+        CssPropertyType::from_str("direction", &get_css_key_map()),
+        Some(CssPropertyType::Direction)
+    );
+}
+
+#[test]
+fn test_layout_rect_split() {
+    let rect = LayoutRect::new(LayoutPoint::new(10, 5), LayoutSize::new(100, 40));
+
+    let (left, right) = rect.split_horizontal(30);
+    assert_eq!(left, LayoutRect::new(LayoutPoint::new(10, 5), LayoutSize::new(30, 40)));
+    assert_eq!(right, LayoutRect::new(LayoutPoint::new(40, 5), LayoutSize::new(70, 40)));
+
+    let (top, bottom) = rect.split_vertical(15);
+    assert_eq!(top, LayoutRect::new(LayoutPoint::new(10, 5), LayoutSize::new(100, 15)));
+    assert_eq!(bottom, LayoutRect::new(LayoutPoint::new(10, 20), LayoutSize::new(100, 25)));
+
+    // out-of-range offsets are clamped to the rect
+    assert_eq!(rect.split_horizontal(-5).0.size.width, 0);
+    let (all, rest) = rect.split_horizontal(500);
+    assert_eq!(all, rect);
+    assert_eq!(rest, LayoutRect::new(LayoutPoint::new(110, 5), LayoutSize::new(0, 40)));
+    assert_eq!(rect.split_vertical(100).1.size.height, 0);
+}
+
+#[test]
+fn test_layout_rect_inset() {
+    let rect = LayoutRect::new(LayoutPoint::new(10, 5), LayoutSize::new(100, 40));
+
+    assert_eq!(
+        rect.inset(LayoutSideOffsets::uniform(5.0)),
+        LayoutRect::new(LayoutPoint::new(15, 10), LayoutSize::new(90, 30))
+    );
+    // top, right, bottom, left - fractional offsets are rounded
+    assert_eq!(
+        rect.inset(LayoutSideOffsets::new(2.0, 10.0, 4.6, 20.4)),
+        LayoutRect::new(LayoutPoint::new(30, 7), LayoutSize::new(70, 33))
+    );
+    // insets larger than the rect clamp the size to zero
+    assert_eq!(rect.inset(LayoutSideOffsets::uniform(60.0)).size, LayoutSize::zero());
+}
+
+#[test]
+fn test_transition_timing_function_evaluate() {
+    use self::StyleTransitionTimingFunction::*;
+
+    let approx = |f: StyleTransitionTimingFunction, t: f32, expected: f32| {
+        let v = f.evaluate(t);
+        assert!((v - expected).abs() < 1e-3, "{} at {}: {} != {}", f, t, v, expected);
+    };
+
+    // reference values of the CSS easing curves
+    approx(Ease, 0.25, 0.4085);
+    approx(Ease, 0.5, 0.8024);
+    approx(Ease, 0.75, 0.9605);
+    approx(EaseIn, 0.5, 0.3154);
+    approx(EaseOut, 0.5, 0.6846);
+    approx(EaseInOut, 0.25, 0.1292);
+    approx(EaseInOut, 0.5, 0.5);
+    approx(Linear, 0.3, 0.3);
+    approx(CubicBezier(StyleCubicBezier::new(0.25, 0.1, 0.25, 1.0)), 0.5, 0.8024);
+
+    // animations share the same solver, the input is the x value of the curve
+    let ease = AnimationInterpolationFunction::Ease.evaluate(0.5);
+    assert!((ease - 0.8024).abs() < 1e-3, "{}", ease);
+
+    for f in [Linear, Ease, EaseIn, EaseOut, EaseInOut].iter() {
+        approx(*f, 0.0, 0.0);
+        approx(*f, 1.0, 1.0);
+        // input is clamped
+        approx(*f, 1.5, 1.0);
+    }
+
+    // flat start and end: slope is zero at the ends, needs the bisection fallback
+    approx(CubicBezier(StyleCubicBezier::new(1.0, 0.0, 0.0, 1.0)), 0.5, 0.5);
+    // overshooting curve
+    assert!(CubicBezier(StyleCubicBezier::new(0.3, 1.5, 0.7, 1.5)).evaluate(0.5) > 1.0);
+
+    let steps = |count, position| Steps(StyleSteps { count, position });
+    approx(steps(4, StepPosition::JumpEnd), 0.0, 0.0);
+    approx(steps(4, StepPosition::JumpEnd), 0.3, 0.25);
+    approx(steps(4, StepPosition::JumpEnd), 1.0, 1.0);
+    approx(steps(4, StepPosition::JumpStart), 0.0, 0.25);
+    approx(steps(4, StepPosition::JumpStart), 0.3, 0.5);
+    approx(steps(2, StepPosition::JumpBoth), 0.0, 1.0 / 3.0);
+    approx(steps(3, StepPosition::JumpNone), 0.5, 0.5);
+    approx(steps(3, StepPosition::JumpNone), 1.0, 1.0);
+}
+
+#[test]
+fn test_transition_properties() {
+    let property = StyleTransitionProperty::List(
+        vec![CssPropertyType::Opacity, CssPropertyType::Width].into(),
+    );
+    assert!(property.contains(&CssPropertyType::Width));
+    assert!(!property.contains(&CssPropertyType::Height));
+    assert!(StyleTransitionProperty::All.contains(&CssPropertyType::Height));
+    assert_eq!(property.to_string(), "opacity, width");
+
+    assert_eq!(StyleTransitionDuration::seconds(0.3), StyleTransitionDuration::const_ms(300));
+    assert_eq!(StyleTransitionDelay::ms(-50.0).to_string(), "-50ms");
+    assert_eq!(
+        StyleTransitionTimingFunction::Steps(StyleSteps {
+            count: 4,
+            position: StepPosition::JumpStart
+        })
+        .to_string(),
+        "steps(4, jump-start)"
+    );
+
+    let map = get_css_key_map();
+    for ty in [
+        CssPropertyType::TransitionProperty,
+        CssPropertyType::TransitionDuration,
+        CssPropertyType::TransitionTimingFunction,
+        CssPropertyType::TransitionDelay,
+    ]
+    .iter()
+    {
+        assert_eq!(CssPropertyType::from_str(ty.to_str(), &map), Some(*ty));
+        assert!(!ty.can_trigger_relayout());
+    }
+    assert_eq!(
+        CombinedCssPropertyType::from_str("transition", &map),
+        Some(CombinedCssPropertyType::Transition)
+    );
+}
+
+#[test]
+fn test_css_property_size() {
+    use core::mem::size_of;
+
+    // large values are boxed (a tag and a pointer), otherwise the scrollbar
+    // style alone makes every property > 1KB
+    assert_eq!(size_of::<ScrollbarStyleValue>(), 2 * size_of::<usize>());
+    assert_eq!(size_of::<StyleBoxShadowValue>(), 2 * size_of::<usize>());
+    assert_eq!(size_of::<StyleBackgroundContentVecValue>(), 2 * size_of::<usize>());
+    assert!(size_of::<CssProperty>() <= 4 * size_of::<usize>());
+
+    let style = ScrollbarStyle::default();
+    let prop = CssProperty::from(style.clone());
+    match &prop {
+        CssProperty::ScrollbarStyle(CssPropertyValue::Exact(s)) => assert_eq!(**s, style),
+        other => panic!("expected boxed scrollbar style, got {:?}", other),
+    }
+    assert_eq!(prop.clone(), prop);
+}
+
+#[test]
+fn test_box_or_static() {
+    static SHADOW: StyleBoxShadowBoxed = CssBoxed::new_static(StyleBoxShadow {
+        offset: [
+            PixelValueNoPercent { inner: PixelValue::const_px(1) },
+            PixelValueNoPercent { inner: PixelValue::const_px(2) },
+        ],
+        color: ColorU { r: 0, g: 0, b: 0, a: 255 },
+        blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(3) },
+        spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
+        clip_mode: BoxShadowClipMode::Outset,
+    });
+
+    // usable in `const` items, borrowed values are not copied when cloning
+    const PROP: CssProperty = CssProperty::const_box_shadow_left(&SHADOW);
+    let borrowed = match PROP.clone() {
+        CssProperty::BoxShadowLeft(CssPropertyValue::Exact(s)) => s,
+        other => panic!("expected box shadow, got {:?}", other),
+    };
+    assert!(BoxOrStatic::is_static(&borrowed));
+    assert!(core::ptr::eq(&*borrowed, &*SHADOW.value));
+
+    let mut owned = borrowed.clone();
+    BoxOrStatic::make_mut(&mut owned).blur_radius = PixelValueNoPercent { inner: PixelValue::const_px(5) };
+    assert!(!BoxOrStatic::is_static(&owned));
+    assert_eq!(SHADOW.value.blur_radius.inner, PixelValue::const_px(3));
+    assert_ne!(owned, borrowed);
+    assert_eq!(owned.clone(), owned);
+    assert_eq!(BoxOrStatic::into_inner(owned).blur_radius.inner, PixelValue::const_px(5));
+
+    assert_eq!(CssProperty::box_shadow_left(*SHADOW.value), PROP);
+
+    // same value, without a separate `static`
+    const INLINE: CssProperty = CssProperty::const_box_shadow_left(crate::css_boxed_static!(StyleBoxShadow {
+        offset: [
+            PixelValueNoPercent { inner: PixelValue::const_px(1) },
+            PixelValueNoPercent { inner: PixelValue::const_px(2) },
+        ],
+        color: ColorU { r: 0, g: 0, b: 0, a: 255 },
+        blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(3) },
+        spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
+        clip_mode: BoxShadowClipMode::Outset,
+    }));
+    assert_eq!(INLINE, PROP);
+}
+
+#[test]
+fn test_initial_and_inherit_resolution() {
+    use self::CssPropertyType as T;
+
+    let root_font_size = CssProperty::font_size(StyleFontSize::const_px(16));
+    let parent_font_size = CssProperty::font_size(StyleFontSize::const_px(24));
+    let width = CssProperty::width(LayoutWidth::const_px(100));
+
+    // (property, parent value, expected)
+    let cases = [
+        (CssProperty::inherit(T::FontSize), None, root_font_size.clone()),
+        (CssProperty::inherit(T::FontSize), Some(&parent_font_size), parent_font_size.clone()),
+        (CssProperty::inherit(T::FontSize), Some(&width), root_font_size.clone()),
+        (CssProperty::initial(T::FontSize), Some(&parent_font_size), root_font_size.clone()),
+        (CssProperty::initial(T::TextColor), None, CssProperty::text_color(StyleTextColor { inner: ColorU::BLACK })),
+        (CssProperty::initial(T::Width), None, CssProperty::auto(T::Width)),
+        (CssProperty::inherit(T::Width), Some(&width), width.clone()),
+        (CssProperty::initial(T::MaxWidth), None, CssProperty::none(T::MaxWidth)),
+        (CssProperty::initial(T::Opacity), None, CssProperty::opacity(StyleOpacity::const_new(100))),
+        (CssProperty::initial(T::FlexDirection), None, CssProperty::flex_direction(LayoutFlexDirection::Row)),
+        (CssProperty::initial(T::FlexShrink), None, CssProperty::flex_shrink(LayoutFlexShrink::const_new(1))),
+        (CssProperty::initial(T::OverflowX), None, CssProperty::overflow_x(LayoutOverflow::Visible)),
+        (CssProperty::auto(T::OverflowY), None, CssProperty::overflow_y(LayoutOverflow::Auto)),
+        (CssProperty::none(T::Display), None, CssProperty::display(LayoutDisplay::None)),
+        (
+            CssProperty::none(T::BorderTopStyle),
+            None,
+            CssProperty::border_top_style(StyleBorderTopStyle { inner: BorderStyle::None }),
+        ),
+        (CssProperty::initial(T::BorderLeftWidth), None, CssProperty::border_left_width(LayoutBorderLeftWidth::const_px(3))),
+        (
+            CssProperty::initial(T::PerspectiveOrigin),
+            None,
+            CssProperty::perspective_origin(StylePerspectiveOrigin {
+                x: PixelValue::const_percent(50),
+                y: PixelValue::const_percent(50),
+            }),
+        ),
+        (CssProperty::initial(T::BoxShadowTop), None, CssProperty::none(T::BoxShadowTop)),
+        // exact values are not touched
+        (width.clone(), Some(&parent_font_size), width.clone()),
+    ];
+
+    for (prop, parent, expected) in cases.iter() {
+        let resolved = prop.resolve_keyword(*parent, prop.get_type());
+        assert_eq!(resolved, *expected, "resolving {:?}", prop);
+    }
+
+    // every initial value has the type it was requested for
+    for ty in get_css_key_map().non_shorthands.values() {
+        assert_eq!(ty.initial_value().get_type(), *ty);
+        assert_eq!(ty.azul_default_value().get_type(), *ty);
+    }
+
+    // spec initial values, the layout defaults are separate
+    assert_eq!(T::Display.initial_value(), CssProperty::display(LayoutDisplay::Inline));
+    assert_eq!(T::Float.initial_value(), CssProperty::float(LayoutFloat::None));
+    assert_eq!(T::Display.azul_default_value(), CssProperty::display(LayoutDisplay::Flex));
+    assert_eq!(T::Float.azul_default_value(), CssProperty::float(LayoutFloat::Left));
+    assert_eq!(T::Width.azul_default_value(), T::Width.initial_value());
+}
+
+#[test]
+fn test_color_f_arithmetic() {
+    let gray = ColorF {
+        r: 0.5,
+        g: 0.5,
+        b: 0.5,
+        a: 1.0,
+    };
+    assert_eq!(ColorF::BLACK.lerp(&ColorF::WHITE, 0.5), gray);
+    assert_eq!(ColorF::BLACK.lerp(&ColorF::WHITE, 0.0), ColorF::BLACK);
+    assert_eq!(ColorF::BLACK.lerp(&ColorF::WHITE, 1.0), ColorF::WHITE);
+
+    // no rounding to 8 bits (127.5 / 255)
+    assert_eq!(ColorF::TRANSPARENT.lerp(&ColorF::WHITE, 0.25).r, 0.25);
+
+    assert_eq!(
+        ColorF::WHITE * 0.5,
+        ColorF {
+            r: 0.5,
+            g: 0.5,
+            b: 0.5,
+            a: 0.5
+        }
+    );
+    assert_eq!(gray + gray, ColorF { a: 2.0, ..ColorF::WHITE });
+    assert_eq!(ColorF::WHITE - gray, ColorF { a: 0.0, ..gray });
+}
+
+#[test]
+fn test_color_f_clamp_premultiply() {
+    let out_of_range = ColorF {
+        r: -0.5,
+        g: 0.5,
+        b: 1.5,
+        a: 2.0,
+    };
+    assert_eq!(
+        out_of_range.clamp(),
+        ColorF {
+            r: 0.0,
+            g: 0.5,
+            b: 1.0,
+            a: 1.0
+        }
+    );
+    assert_eq!(ColorF { a: -1.0, ..ColorF::WHITE }.clamp(), ColorF { a: 0.0, ..ColorF::WHITE });
+    assert_eq!(
+        ColorU::from(out_of_range),
+        ColorU {
+            r: 0,
+            g: 127,
+            b: 255,
+            a: 255
+        }
+    );
+
+    let half_red = ColorF {
+        r: 1.0,
+        g: 0.5,
+        b: 0.0,
+        a: 0.5,
+    };
+    assert_eq!(
+        half_red.premultiply(),
+        ColorF {
+            r: 0.5,
+            g: 0.25,
+            b: 0.0,
+            a: 0.5
+        }
+    );
+    assert_eq!(ColorF::WHITE.premultiply(), ColorF::WHITE);
+    assert_eq!(ColorF::TRANSPARENT.premultiply(), ColorF::TRANSPARENT);
+}
+
+#[test]
+fn test_box_shadow_inflation() {
+    let node_rect = LayoutRect::new(LayoutPoint::new(100, 100), LayoutSize::new(50, 20));
+    let shadow = |input: &str| StyleBoxShadow::from_str(input).unwrap();
+
+    // no blur, no spread: only the offset sticks out
+    let hard = shadow("5px 10px");
+    assert_eq!(hard.get_bounds_inflation(EM_HEIGHT), LayoutSideOffsets::new(0.0, 5.0, 10.0, 0.0));
+    assert_eq!(
+        hard.get_clip_rect(node_rect, EM_HEIGHT),
+        LayoutRect::new(LayoutPoint::new(100, 100), LayoutSize::new(55, 30))
+    );
+
+    // blur + spread: 1.5 * 4px + 10px = 16px on every side
+    let spread = shadow("0px 0px 4px 10px");
+    assert_eq!(spread.get_bounds_inflation(EM_HEIGHT), LayoutSideOffsets::uniform(16.0));
+    assert_eq!(
+        spread.get_clip_rect(node_rect, EM_HEIGHT),
+        LayoutRect::new(LayoutPoint::new(84, 84), LayoutSize::new(82, 52))
+    );
+
+    // the offset moves the shadow, a large offset hides one side completely
+    let offset = shadow("-20px 0px 2px");
+    assert_eq!(offset.get_bounds_inflation(EM_HEIGHT), LayoutSideOffsets::new(3.0, 0.0, 3.0, 23.0));
+
+    // negative spread shrinks the shadow
+    assert_eq!(shadow("0px 0px 0px -5px").get_bounds_inflation(EM_HEIGHT), LayoutSideOffsets::zero());
+
+    // inset shadows never draw outside of the node
+    let inset = shadow("inset 5px 5px 10px 10px");
+    assert_eq!(inset.get_clip_rect(node_rect, EM_HEIGHT), node_rect);
+
+    // em lengths are relative to the font size of the node
+    assert_eq!(shadow("1em 0px").get_bounds_inflation(10.0), LayoutSideOffsets::new(0.0, 10.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_color_f_to_color_u_clamps() {
+    // every channel is clamped to [0.0, 1.0] before scaling to u8
+    for (input, expected) in [(-0.5, 0), (-0.0, 0), (1.5, 255), (f32::INFINITY, 255)] {
+        let c = ColorF {
+            r: input,
+            g: input,
+            b: input,
+            a: input,
+        };
+        assert_eq!(
+            ColorU::from(c),
+            ColorU {
+                r: expected,
+                g: expected,
+                b: expected,
+                a: expected
+            }
+        );
+    }
+}
+
+#[test]
+fn test_background_content_vec_solid() {
+    let red = ColorU {
+        r: 255,
+        g: 0,
+        b: 0,
+        a: 255,
+    };
+    let background = StyleBackgroundContentVec::solid(red);
+    assert_eq!(background.len(), 1);
+    assert_eq!(background.as_ref().first(), Some(&StyleBackgroundContent::Color(red)));
+
+    let logo = AzString::from_const_str("logo");
+    let image = StyleBackgroundContentVec::from_single(logo.clone().into());
+    assert_eq!(image.as_ref(), &[StyleBackgroundContent::Image(logo)]);
+}
+
+#[test]
+fn test_scrollbar_compute_layout() {
+    let info = ScrollbarInfo {
+        button: StyleBackgroundContent::default(),
+        ..ScrollbarInfo::default()
+    };
+    let container = LayoutRect::new(LayoutPoint::new(0, 0), LayoutSize::new(100, 200));
+    let content = LayoutSize::new(100, 1000);
+    let vertical = |offset: isize| {
+        info.compute_layout(
+            container,
+            content,
+            LayoutPoint::new(0, offset),
+            ScrollbarOrientation::Vertical,
+            EM_HEIGHT,
+        )
+    };
+
+    let layout = vertical(0).unwrap();
+    assert_eq!(
+        layout.track,
+        LayoutRect::new(LayoutPoint::new(83, 0), LayoutSize::new(17, 200))
+    );
+    // 200px / 1000px = 20% of the track, 2px padding on both sides
+    assert_eq!(
+        layout.thumb,
+        LayoutRect::new(LayoutPoint::new(85, 0), LayoutSize::new(13, 40))
+    );
+    assert_eq!(layout.button_start.size, LayoutSize::new(17, 0));
+
+    // dragging the thumb by 80px scrolls by 400px and vice versa
+    assert_eq!(layout.offset_for_thumb_position(80), 400);
+    assert_eq!(vertical(400).unwrap().thumb.origin.y, 80);
+    assert_eq!(layout.offset_for_thumb_position(-10), 0);
+    assert_eq!(layout.offset_for_thumb_position(500), 800);
+
+    // no overflow: no scrollbar
+    assert!(info
+        .compute_layout(
+            container,
+            LayoutSize::new(100, 200),
+            LayoutPoint::zero(),
+            ScrollbarOrientation::Vertical,
+            EM_HEIGHT,
+        )
+        .is_none());
+    assert!(info
+        .compute_layout(
+            container,
+            content,
+            LayoutPoint::zero(),
+            ScrollbarOrientation::Horizontal,
+            EM_HEIGHT,
+        )
+        .is_none());
+
+    // buttons shrink the track of the thumb, very long content is limited by the minimum thumb size
+    let layout = ScrollbarInfo::default()
+        .compute_layout(
+            container,
+            LayoutSize::new(100, 100_000),
+            LayoutPoint::zero(),
+            ScrollbarOrientation::Vertical,
+            EM_HEIGHT,
+        )
+        .unwrap();
+    assert_eq!(layout.button_start, LayoutRect::new(LayoutPoint::new(83, 0), LayoutSize::new(17, 17)));
+    assert_eq!(layout.button_end, LayoutRect::new(LayoutPoint::new(83, 183), LayoutSize::new(17, 17)));
+    assert_eq!(layout.thumb.origin.y, 17);
+    assert_eq!(layout.thumb.size.height, ScrollbarInfo::MIN_THUMB_LENGTH);
+
+    // em widths are relative to the font size of the node
+    let em_info = ScrollbarInfo {
+        width: LayoutWidth { inner: PixelValue::em(2.0) },
+        ..info.clone()
+    };
+    let layout = em_info
+        .compute_layout(
+            container,
+            content,
+            LayoutPoint::zero(),
+            ScrollbarOrientation::Vertical,
+            10.0,
+        )
+        .unwrap();
+    assert_eq!(layout.track.size.width, 20);
+}
+
+#[test]
+fn test_scrollbar_style_uniform() {
+    let info = ScrollbarInfo {
+        width: LayoutWidth::px(8.0),
+        thumb: StyleBackgroundContent::Color(ColorU::BLACK),
+        ..ScrollbarInfo::default()
+    };
+
+    let style = ScrollbarStyle::uniform(info.clone());
+    assert_eq!(style.horizontal, info);
+    assert_eq!(style.vertical, info);
+
+    assert_eq!(
+        ScrollbarStyle::uniform(ScrollbarInfo::default()),
+        ScrollbarStyle::default()
+    );
+}
+
+#[test]
+fn test_overflow_resolve_xy() {
+    use self::LayoutOverflow::*;
+    assert_eq!(LayoutOverflow::resolve_xy(Visible, Hidden), (Auto, Hidden));
+    assert_eq!(LayoutOverflow::resolve_xy(Hidden, Visible), (Hidden, Auto));
+    assert_eq!(LayoutOverflow::resolve_xy(Scroll, Visible), (Scroll, Auto));
+    assert_eq!(LayoutOverflow::resolve_xy(Visible, Auto), (Auto, Auto));
+    assert_eq!(LayoutOverflow::resolve_xy(Clip, Scroll), (Hidden, Scroll));
+    // neither value creates a scroll container: unchanged
+    assert_eq!(LayoutOverflow::resolve_xy(Visible, Visible), (Visible, Visible));
+    assert_eq!(LayoutOverflow::resolve_xy(Visible, Clip), (Visible, Clip));
+    assert_eq!(LayoutOverflow::resolve_xy(Hidden, Scroll), (Hidden, Scroll));
+}
+
+#[test]
+fn test_overflow_clips_children() {
+    use self::LayoutOverflow::*;
+    assert!(!LayoutOverflow::clips_children(Visible, Visible));
+    assert!(LayoutOverflow::clips_children(Hidden, Visible));
+    assert!(LayoutOverflow::clips_children(Visible, Clip));
+    assert!(LayoutOverflow::clips_children(Scroll, Auto));
+}
+
+#[test]
+fn test_background_position_resolve() {
+    let area = LayoutSize::new(300, 200);
+    let image = LayoutSize::new(100, 100);
+    let resolve = |p: StyleBackgroundPosition| p.resolve(area, image, 10.0);
+
+    assert_eq!(resolve(StyleBackgroundPosition::top_left()), LayoutPoint::new(0, 0));
+    assert_eq!(resolve(StyleBackgroundPosition::top_right()), LayoutPoint::new(200, 0));
+    assert_eq!(resolve(StyleBackgroundPosition::bottom_left()), LayoutPoint::new(0, 100));
+    assert_eq!(resolve(StyleBackgroundPosition::bottom_right()), LayoutPoint::new(200, 100));
+    assert_eq!(resolve(StyleBackgroundPosition::center()), LayoutPoint::new(100, 50));
+    // percentages resolve against (area - image), pixels are literal
+    assert_eq!(resolve(StyleBackgroundPosition::from_str("25% 25%").unwrap()), LayoutPoint::new(50, 25));
+    assert_eq!(resolve(StyleBackgroundPosition::from_str("10px 100%").unwrap()), LayoutPoint::new(10, 100));
+    // em is relative to the font size of the node
+    assert_eq!(resolve(StyleBackgroundPosition::from_str("2em 1em").unwrap()), LayoutPoint::new(20, 10));
+
+    // the display list converts em to pixels before scaling for HiDPI
+    let mut position = StyleBackgroundPosition::from_str("2em 50%").unwrap();
+    position.resolve_em(10.0);
+    assert_eq!(position, StyleBackgroundPosition::from_str("20px 50%").unwrap());
+    let mut size = StyleBackgroundSize::ExactSize([PixelValue::em(1.5), PixelValue::px(4.0)]);
+    size.resolve_em(10.0);
+    assert_eq!(size, StyleBackgroundSize::ExactSize([PixelValue::px(15.0), PixelValue::px(4.0)]));
+}
+
+#[test]
+fn test_text_color_from_str() {
+    assert_eq!(StyleTextColor::from_str("#ff0000"), Some(StyleTextColor { inner: ColorU::RED }));
+    assert_eq!(StyleTextColor::from_str(" blue "), Some(StyleTextColor { inner: ColorU::BLUE }));
+    assert_eq!(StyleTextColor::from_str("notacolor"), None);
+    assert_eq!(StyleBorderLeftColor::from_str("#00f"), Some(StyleBorderLeftColor { inner: ColorU::BLUE }));
+}
+
+#[test]
+fn test_border_width_radius_from_str() {
+    assert_eq!(LayoutBorderTopWidth::from_str("2px"), Some(LayoutBorderTopWidth::const_px(2)));
+    assert_eq!(LayoutBorderLeftWidth::from_str(" 1em "), Some(LayoutBorderLeftWidth::const_em(1)));
+    assert_eq!(LayoutBorderBottomWidth::from_str("thin"), None);
+    assert_eq!(StyleBorderTopLeftRadius::from_str("5px"), Some(StyleBorderTopLeftRadius::const_px(5)));
+    assert_eq!(StyleBorderBottomRightRadius::from_str("50%"), Some(StyleBorderBottomRightRadius::const_percent(50)));
+}
+
+#[test]
+fn test_normal_border_css_properties_roundtrip() {
+    let side = StyleBorderSide::uniform(PixelValue::const_px(2), BorderStyle::Solid, ColorU::RED);
+    let mut border = NormalBorder::uniform(side);
+    border.left = StyleBorderSide::new(PixelValue::const_em(1), BorderStyle::Dashed, ColorU::BLUE);
+
+    let properties = border.to_css_properties();
+    assert_eq!(properties.len(), 12);
+    assert_eq!(properties[0], CssProperty::border_top_width(LayoutBorderTopWidth::const_px(2)));
+    assert_eq!(properties[9], CssProperty::border_left_width(LayoutBorderLeftWidth::const_em(1)));
+
+    let radius = (
+        StyleBorderTopLeftRadius::const_px(1),
+        StyleBorderTopRightRadius::const_px(2),
+        StyleBorderBottomLeftRadius::const_px(3),
+        StyleBorderBottomRightRadius::const_px(4),
+    );
+    let from_css = |p: &[CssProperty], radius| {
+        macro_rules! exact {
+            ($i:expr, $as_fn:ident) => {
+                p[$i].$as_fn().and_then(|v| v.get_property()).unwrap()
+            };
+        }
+        NormalBorder::from_css_properties(
+            (
+                exact!(0, as_border_top_width),
+                exact!(3, as_border_right_width),
+                exact!(6, as_border_bottom_width),
+                exact!(9, as_border_left_width),
+            ),
+            (
+                exact!(1, as_border_top_style),
+                exact!(4, as_border_right_style),
+                exact!(7, as_border_bottom_style),
+                exact!(10, as_border_left_style),
+            ),
+            (
+                exact!(2, as_border_top_color),
+                exact!(5, as_border_right_color),
+                exact!(8, as_border_bottom_color),
+                exact!(11, as_border_left_color),
+            ),
+            radius,
+        )
+    };
+    assert_eq!(from_css(&properties, None), border);
+
+    border.radius = Some(radius);
+    let properties = border.to_css_properties();
+    assert_eq!(properties.len(), 16);
+    assert_eq!(properties[15], CssProperty::border_bottom_right_radius(StyleBorderBottomRightRadius::const_px(4)));
+    assert_eq!(from_css(&properties, Some(radius)), border);
+}
+
+#[test]
+fn test_spacing_from_str() {
+    assert_eq!(StyleLetterSpacing::from_str("2px"), Some(StyleLetterSpacing::const_px(2)));
+    assert_eq!(StyleLetterSpacing::from_str("normal"), None);
+    assert_eq!(StyleWordSpacing::from_str(" 1em "), Some(StyleWordSpacing::const_em(1)));
+    assert_eq!(StyleWordSpacing::from_str("1"), None);
+}
+
+#[test]
+fn test_tab_width_from_str() {
+    assert_eq!(StyleTabWidth::from_str("4"), Some(StyleTabWidth::const_new(400)));
+    assert_eq!(StyleTabWidth::from_str("4").map(|t| t.inner.normalized()), Some(4.0));
+    assert_eq!(StyleTabWidth::from_str("200%"), Some(StyleTabWidth::const_new(200)));
+    assert_eq!(StyleTabWidth::from_str("20px"), None);
+}
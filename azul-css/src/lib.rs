@@ -446,7 +446,7 @@ macro_rules! impl_vec_mut {
             /// Appends elements to `Self` from other buffer.
             #[inline]
             unsafe fn append_elements(&mut self, other: *const [$struct_type]) {
-                let count = (*other).len();
+                let count = (&*other).len();
                 self.reserve(count);
                 let len = self.len();
                 core::ptr::copy_nonoverlapping(
@@ -554,6 +554,13 @@ macro_rules! impl_vec_clone {
                 }
             }
 
+            /// Creates a library-owned `Vec` by cloning the items of a borrowed slice
+            /// (use `from_const_slice` to avoid the copy for &'static memory)
+            #[inline]
+            pub fn from_slice(input: &[$struct_type]) -> Self {
+                Self::from_vec(input.to_vec())
+            }
+
             /// NOTE: CLONES the memory if the memory is external or &'static
             /// Moves the memory out if the memory is library-allocated
             #[inline(always)]
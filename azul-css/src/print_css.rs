@@ -55,12 +55,42 @@ impl PrintAsCssValue for StyleMixBlendMode {
     }
 }
 
+impl PrintAsCssValue for StyleIsolation {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl PrintAsCssValue for StyleImageRendering {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self)
+    }
+}
+
 impl PrintAsCssValue for StyleTextColor {
     fn print_as_css_value(&self) -> String {
         self.inner.to_hash()
     }
 }
 
+impl PrintAsCssValue for StyleCaretColor {
+    fn print_as_css_value(&self) -> String {
+        self.inner.to_hash()
+    }
+}
+
+impl PrintAsCssValue for StyleSelectionColor {
+    fn print_as_css_value(&self) -> String {
+        self.inner.to_hash()
+    }
+}
+
+impl PrintAsCssValue for StyleSelectionBackground {
+    fn print_as_css_value(&self) -> String {
+        self.inner.to_hash()
+    }
+}
+
 impl PrintAsCssValue for StyleFontSize {
     fn print_as_css_value(&self) -> String {
         format!("{}", self.inner)
@@ -82,10 +112,48 @@ impl PrintAsCssValue for StyleTextAlign {
             StyleTextAlign::Left => "left",
             StyleTextAlign::Center => "center",
             StyleTextAlign::Right => "right",
+            StyleTextAlign::Start => "start",
+            StyleTextAlign::End => "end",
         })
     }
 }
 
+impl PrintAsCssValue for StyleDirection {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionProperty {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionDuration {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionTimingFunction {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionDelay {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl PrintAsCssValue for StyleVerticalAlign {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self)
+    }
+}
+
 impl PrintAsCssValue for StyleLetterSpacing {
     fn print_as_css_value(&self) -> String {
         format!("{}", self.inner)
@@ -112,38 +180,7 @@ impl PrintAsCssValue for StyleTabWidth {
 
 impl PrintAsCssValue for StyleCursor {
     fn print_as_css_value(&self) -> String {
-        String::from(match self {
-            StyleCursor::Alias => "alias",
-            StyleCursor::AllScroll => "all-scroll",
-            StyleCursor::Cell => "cell",
-            StyleCursor::ColResize => "col-resize",
-            StyleCursor::ContextMenu => "context-menu",
-            StyleCursor::Copy => "copy",
-            StyleCursor::Crosshair => "crosshair",
-            StyleCursor::Default => "default",
-            StyleCursor::EResize => "e-resize",
-            StyleCursor::EwResize => "ew-resize",
-            StyleCursor::Grab => "grab",
-            StyleCursor::Grabbing => "grabbing",
-            StyleCursor::Help => "help",
-            StyleCursor::Move => "move",
-            StyleCursor::NResize => "n-resize",
-            StyleCursor::NsResize => "ns-resize",
-            StyleCursor::NeswResize => "nesw-resize",
-            StyleCursor::NwseResize => "nwse-resize",
-            StyleCursor::Pointer => "pointer",
-            StyleCursor::Progress => "progress",
-            StyleCursor::RowResize => "row-resize",
-            StyleCursor::SResize => "s-resize",
-            StyleCursor::SeResize => "se-resize",
-            StyleCursor::Text => "text",
-            StyleCursor::Unset => "unset",
-            StyleCursor::VerticalText => "vertical-text",
-            StyleCursor::WResize => "w-resize",
-            StyleCursor::Wait => "wait",
-            StyleCursor::ZoomIn => "zoom-in",
-            StyleCursor::ZoomOut => "zoom-out",
-        })
+        String::from(self.as_keyword())
     }
 }
 
@@ -154,6 +191,8 @@ impl PrintAsCssValue for LayoutDisplay {
             LayoutDisplay::Flex => "flex",
             LayoutDisplay::Block => "block",
             LayoutDisplay::InlineBlock => "inline-block",
+            LayoutDisplay::Grid => "grid",
+            LayoutDisplay::Inline => "inline",
         })
     }
 }
@@ -163,6 +202,7 @@ impl PrintAsCssValue for LayoutFloat {
         String::from(match self {
             LayoutFloat::Left => "left",
             LayoutFloat::Right => "right",
+            LayoutFloat::None => "none",
         })
     }
 }
@@ -252,6 +292,7 @@ impl PrintAsCssValue for LayoutFlexWrap {
         String::from(match self {
             LayoutFlexWrap::Wrap => "wrap",
             LayoutFlexWrap::NoWrap => "nowrap",
+            LayoutFlexWrap::WrapReverse => "wrap-reverse",
         })
     }
 }
@@ -316,6 +357,82 @@ impl PrintAsCssValue for LayoutAlignContent {
     }
 }
 
+impl PrintAsCssValue for GridTrack {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            GridTrack::Px(p) => format!("{}", p),
+            GridTrack::Fraction(f) => format!("{}fr", f),
+            GridTrack::MinContent => format!("min-content"),
+            GridTrack::MaxContent => format!("max-content"),
+            GridTrack::Auto => format!("auto"),
+            GridTrack::Repeat(r) => {
+                format!("repeat({}, {})", r.count, r.tracks.print_as_css_value())
+            }
+        }
+    }
+}
+
+impl PrintAsCssValue for GridTrackVec {
+    fn print_as_css_value(&self) -> String {
+        if self.as_ref().is_empty() {
+            return String::from("none");
+        }
+        self.as_ref()
+            .iter()
+            .map(|t| t.print_as_css_value())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl PrintAsCssValue for GridLine {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            GridLine::Auto => format!("auto"),
+            GridLine::Line(l) => format!("{}", l),
+            GridLine::Span(s) => format!("span {}", s),
+        }
+    }
+}
+
+impl PrintAsCssValue for GridPlacement {
+    fn print_as_css_value(&self) -> String {
+        if self.end == GridLine::Auto {
+            self.start.print_as_css_value()
+        } else {
+            format!(
+                "{} / {}",
+                self.start.print_as_css_value(),
+                self.end.print_as_css_value()
+            )
+        }
+    }
+}
+
+impl PrintAsCssValue for LayoutGridTemplateColumns {
+    fn print_as_css_value(&self) -> String {
+        self.inner.print_as_css_value()
+    }
+}
+
+impl PrintAsCssValue for LayoutGridTemplateRows {
+    fn print_as_css_value(&self) -> String {
+        self.inner.print_as_css_value()
+    }
+}
+
+impl PrintAsCssValue for LayoutGridColumn {
+    fn print_as_css_value(&self) -> String {
+        self.inner.print_as_css_value()
+    }
+}
+
+impl PrintAsCssValue for LayoutGridRow {
+    fn print_as_css_value(&self) -> String {
+        self.inner.print_as_css_value()
+    }
+}
+
 impl PrintAsCssValue for StyleFilterVec {
     fn print_as_css_value(&self) -> String {
         self.as_ref()
@@ -373,6 +490,7 @@ impl PrintAsCssValue for LayoutOverflow {
             LayoutOverflow::Auto => "auto",
             LayoutOverflow::Hidden => "hidden",
             LayoutOverflow::Visible => "visible",
+            LayoutOverflow::Clip => "clip",
         })
     }
 }
@@ -593,26 +711,26 @@ impl PrintAsCssValue for StyleTransform {
         match self {
             StyleTransform::Matrix(m) => format!(
                 "matrix({}, {}, {}, {}, {}, {})",
-                m.a, m.b, m.c, m.d, m.tx, m.ty
+                m.a.number, m.b.number, m.c.number, m.d.number, m.tx.number, m.ty.number
             ),
             StyleTransform::Matrix3D(m) => format!(
                 "matrix3d({}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {})",
-                m.m11,
-                m.m12,
-                m.m13,
-                m.m14,
-                m.m21,
-                m.m22,
-                m.m23,
-                m.m24,
-                m.m31,
-                m.m32,
-                m.m33,
-                m.m34,
-                m.m41,
-                m.m42,
-                m.m43,
-                m.m44
+                m.m11.number,
+                m.m12.number,
+                m.m13.number,
+                m.m14.number,
+                m.m21.number,
+                m.m22.number,
+                m.m23.number,
+                m.m24.number,
+                m.m31.number,
+                m.m32.number,
+                m.m33.number,
+                m.m34.number,
+                m.m41.number,
+                m.m42.number,
+                m.m43.number,
+                m.m44.number
             ),
             StyleTransform::Translate(t) => format!("translate({}, {})", t.x, t.y),
             StyleTransform::Translate3D(t) => format!("translate3d({}, {}, {})", t.x, t.y, t.z),
@@ -642,79 +760,52 @@ impl PrintAsCssValue for StyleTransform {
 impl PrintAsCssValue for StyleBackgroundContent {
     fn print_as_css_value(&self) -> String {
         match self {
-            StyleBackgroundContent::LinearGradient(lg) => {
-                if lg.extend_mode == ExtendMode::Repeat {
-                    format!("repeating-linear-gradient({})", lg.print_as_css_value())
-                } else {
-                    format!("linear-gradient({})", lg.print_as_css_value())
-                }
-            }
-            StyleBackgroundContent::RadialGradient(rg) => {
-                if rg.extend_mode == ExtendMode::Repeat {
-                    format!("repeating-radial-gradient({})", rg.print_as_css_value())
-                } else {
-                    format!("radial-gradient({})", rg.print_as_css_value())
-                }
-            }
-            StyleBackgroundContent::ConicGradient(cg) => {
-                if cg.extend_mode == ExtendMode::Repeat {
-                    format!("repeating-conic-gradient({})", cg.print_as_css_value())
-                } else {
-                    format!("conic-gradient({})", cg.print_as_css_value())
-                }
-            }
+            StyleBackgroundContent::LinearGradient(lg) => lg.to_css_string(),
+            StyleBackgroundContent::RadialGradient(rg) => rg.to_css_string(),
+            StyleBackgroundContent::ConicGradient(cg) => cg.to_css_string(),
             StyleBackgroundContent::Image(id) => format!("url(\"{}\")", id.as_str()),
             StyleBackgroundContent::Color(c) => c.to_hash(),
         }
     }
 }
 
+// "first, stop1, stop2" - the stops are omitted if there are none
+fn join_gradient_args<T: PrintAsCssValue>(first: String, stops: &[T]) -> String {
+    core::iter::once(first)
+        .chain(stops.iter().map(|s| s.print_as_css_value()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Only prints the arguments of the gradient function, see LinearGradient::to_css_string
 impl PrintAsCssValue for LinearGradient {
     fn print_as_css_value(&self) -> String {
-        let t = if self.stops.is_empty() { "" } else { ", " };
-        format!(
-            "{}{}{}",
-            match self.direction {
-                Direction::Angle(a) => format!("{}", a),
-                Direction::FromTo(d) => format!("from {} to {}", d.from, d.to),
-            },
-            t,
-            self.stops
-                .iter()
-                .map(|s| s.print_as_css_value())
-                .collect::<Vec<_>>()
-                .join(", ")
-        )
+        let direction = match self.direction {
+            Direction::Angle(a) => format!("{}", a),
+            // "from" is always the opposite of "to" when parsed
+            Direction::FromTo(d) => format!("to {}", d.to),
+        };
+        join_gradient_args(direction, self.stops.as_ref())
     }
 }
 
 impl PrintAsCssValue for NormalizedLinearColorStop {
     fn print_as_css_value(&self) -> String {
-        format!("{}{}", self.offset, self.color.to_hash())
+        format!("{} {}", self.color.to_hash(), self.offset)
     }
 }
 
+// Only prints the arguments of the gradient function, see RadialGradient::to_css_string
 impl PrintAsCssValue for RadialGradient {
     fn print_as_css_value(&self) -> String {
-        format!(
-            "{} {} at {}, {}",
-            match self.shape {
-                Shape::Ellipse => "ellipse",
-                Shape::Circle => "circle",
-            },
-            match self.size {
-                RadialGradientSize::ClosestSide => "closest-side",
-                RadialGradientSize::ClosestCorner => "closest-corner",
-                RadialGradientSize::FarthestSide => "farthest-side",
-                RadialGradientSize::FarthestCorner => "farthest-corner",
-            },
-            self.position.print_as_css_value(),
-            self.stops
-                .iter()
-                .map(|s| s.print_as_css_value())
-                .collect::<Vec<_>>()
-                .join(", ")
-        )
+        let mut first = format!("{}", self.shape);
+        if self.size != RadialGradientSize::default() {
+            first.push_str(&format!(" {}", self.size));
+        }
+        if self.position != StyleBackgroundPosition::default() {
+            first.push_str(&format!(" at {}", self.position.print_as_css_value()));
+        }
+        join_gradient_args(first, self.stops.as_ref())
     }
 }
 
@@ -750,22 +841,15 @@ impl PrintAsCssValue for StyleBackgroundSize {
 
 impl PrintAsCssValue for NormalizedRadialColorStop {
     fn print_as_css_value(&self) -> String {
-        format!("{}{}", self.angle, self.color.to_hash())
+        format!("{} {}", self.color.to_hash(), self.angle)
     }
 }
 
+// Only prints the arguments of the gradient function, see ConicGradient::to_css_string
 impl PrintAsCssValue for ConicGradient {
     fn print_as_css_value(&self) -> String {
-        format!(
-            "from {} at {}, {}",
-            self.angle,
-            self.center.print_as_css_value(),
-            self.stops
-                .iter()
-                .map(|s| s.print_as_css_value())
-                .collect::<Vec<_>>()
-                .join(", ")
-        )
+        let first = format!("from {} at {}", self.angle, self.center.print_as_css_value());
+        join_gradient_args(first, self.stops.as_ref())
     }
 }
 
@@ -776,6 +860,8 @@ impl PrintAsCssValue for StyleBackgroundRepeat {
             StyleBackgroundRepeat::Repeat => "repeat",
             StyleBackgroundRepeat::RepeatX => "repeat-x",
             StyleBackgroundRepeat::RepeatY => "repeat-y",
+            StyleBackgroundRepeat::Space => "space",
+            StyleBackgroundRepeat::Round => "round",
         })
     }
 }
@@ -1,12 +1,13 @@
 use alloc::sync::Arc;
 use azul_core::{
-    app_resources::{AppConfig, ImageCache, ImageRef},
+    app_resources::{AppConfig, ImageCache, ImageRef, ImageResolverCallbackType, RawImage},
     callbacks::{RefAny, Update},
     display_list::RenderCallbacks,
     task::{Timer, TimerId},
-    window::{MonitorVec, WindowCreateOptions},
+    window::{MonitorVec, OptionWindowTheme, WindowCreateOptions},
 };
 use azul_css::AzString;
+use crate::test_harness::TestHarness;
 use clipboard2::{Clipboard as _, ClipboardError, SystemClipboard};
 use rust_fontconfig::FcFontCache;
 use std::fmt;
@@ -53,6 +54,19 @@ impl AzAppPtr {
         }
     }
 
+    pub fn update_image_raw(&mut self, css_id: &AzString, image: RawImage) -> bool {
+        match self.ptr.try_lock() {
+            Ok(mut l) => l.update_image_raw(css_id, image),
+            Err(_) => false,
+        }
+    }
+
+    pub fn delete_image(&mut self, css_id: &AzString) {
+        if let Ok(mut l) = self.ptr.try_lock() {
+            l.delete_image(css_id);
+        }
+    }
+
     pub fn get_monitors(&self) -> MonitorVec {
         self.ptr
             .lock()
@@ -60,6 +74,13 @@ impl AzAppPtr {
             .unwrap_or(MonitorVec::from_const_slice(&[]))
     }
 
+    pub fn get_system_theme(&self) -> OptionWindowTheme {
+        self.ptr
+            .lock()
+            .map(|m| m.get_system_theme())
+            .unwrap_or(OptionWindowTheme::None)
+    }
+
     pub fn run(&self, root_window: WindowCreateOptions) {
         if let Ok(mut l) = self.ptr.try_lock() {
             let mut app = App::new(RefAny::new(Dummy { _dummy: 0 }), l.config.clone());
@@ -67,6 +88,28 @@ impl AzAppPtr {
             app.run(root_window)
         }
     }
+
+    /// Same as `run`, but returns the exit code, see `App::run_return_code`.
+    /// Returns `-1` if the app is already running.
+    pub fn run_return_code(&self, root_window: WindowCreateOptions) -> i32 {
+        match self.ptr.try_lock() {
+            Ok(mut l) => {
+                let mut app = App::new(RefAny::new(Dummy { _dummy: 0 }), l.config.clone());
+                core::mem::swap(&mut *l, &mut app);
+                app.run_return_code(root_window)
+            }
+            Err(_) => -1,
+        }
+    }
+
+    /// Moves the app into a window-less `TestHarness` instead of running it,
+    /// returns `None` if the app is already running.
+    pub fn create_test_harness(&self, root_window: WindowCreateOptions) -> Option<TestHarness> {
+        let mut l = self.ptr.try_lock().ok()?;
+        let mut app = App::new(RefAny::new(Dummy { _dummy: 0 }), l.config.clone());
+        core::mem::swap(&mut *l, &mut app);
+        Some(TestHarness::new(app, root_window))
+    }
 }
 
 // NOTE: must be repr(C), otherwise UB
@@ -93,7 +136,6 @@ pub struct App {
 }
 
 impl App {
-    #[cfg(not(test))]
     #[allow(unused_variables)]
     /// Creates a new, empty application using a specified callback.
     ///
@@ -126,6 +168,10 @@ impl App {
             }
         }
 
+        // records panics for AzApi_lastError(), then calls the logging hook
+        // installed above - so it has to be installed last
+        azul_core::error::set_up_default_panic_hook();
+
         Self {
             windows: Vec::new(),
             data: initial_data,
@@ -140,6 +186,22 @@ impl App {
         self.image_cache.add_css_image_id(css_id, image);
     }
 
+    /// Replaces the pixels of an image added with `add_image`, keeping its
+    /// texture, see `ImageCache::update_css_image_raw`
+    pub fn update_image_raw(&mut self, css_id: &AzString, image: RawImage) -> bool {
+        self.image_cache.update_css_image_raw(css_id, image)
+    }
+
+    /// Removes an image added with `add_image` from the image cache
+    pub fn delete_image(&mut self, css_id: &AzString) {
+        self.image_cache.delete_css_image_id(css_id);
+    }
+
+    /// Sets the callback that loads the images for CSS IDs that haven't been added with `add_image`
+    pub fn set_image_resolver(&mut self, resolver: ImageResolverCallbackType) {
+        self.image_cache.set_image_resolver(resolver);
+    }
+
     /// Spawn a new window on the screen. Note that this should only be used to
     /// create extra windows, the default window will be the window submitted to
     /// the `.run` method.
@@ -162,25 +224,64 @@ impl App {
         }
     }
 
+    /// Returns the light / dark mode preference of the operating system,
+    /// `None` if the platform doesn't support dark mode or it can't be queried
+    pub fn get_system_theme(&self) -> OptionWindowTheme {
+        #[cfg(target_os = "windows")] {
+            crate::shell::win32::get_system_theme()
+        }
+
+        #[cfg(target_os = "linux")] {
+            crate::shell::x11::get_system_theme()
+        }
+
+        #[cfg(target_os = "macos")] {
+            crate::shell::appkit::get_system_theme()
+        }
+    }
+
     /// Start the rendering loop for the currently added windows. The run() function
     /// takes one `WindowCreateOptions` as an argument, which is the "root" window, i.e.
     /// the main application window.
-    #[cfg(all(not(test), feature = "std"))]
-    pub fn run(mut self, root_window: WindowCreateOptions) {
+    #[cfg(feature = "std")]
+    pub fn run(self, root_window: WindowCreateOptions) {
+        if let Err(e) = self.run_event_loop(root_window) {
+            crate::dialogs::msg_box(&e);
+            println!("{}", e);
+        }
+    }
+
+    /// Runs the application like `run`, but returns the exit code of the
+    /// event loop (`0` if the last window was closed normally), so that
+    /// C hosts can propagate it.
+    ///
+    /// Does not show an error dialog if the app fails to start: `-1` is
+    /// returned instead and the error is stored as the last error
+    /// (see `azul_core::error::last_error`).
+    #[cfg(feature = "std")]
+    pub fn run_return_code(self, root_window: WindowCreateOptions) -> i32 {
+        match self.run_event_loop(root_window) {
+            Ok(exit_code) => exit_code as i32,
+            Err(e) => {
+                azul_core::error::set_last_error(e);
+                -1
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn run_event_loop(self, root_window: WindowCreateOptions) -> Result<isize, String> {
 
         #[cfg(target_os = "windows")]
-        let err = crate::shell::win32::run(self, root_window);
+        let result = crate::shell::win32::run(self, root_window);
 
         #[cfg(target_os = "linux")]
-        let err = crate::shell::x11::run(self, root_window);
+        let result = crate::shell::x11::run(self, root_window);
 
         #[cfg(target_os = "macos")]
-        let err = crate::shell::appkit::run(self, root_window);
+        let result = crate::shell::appkit::run(self, root_window);
 
-        if let Err(e) = err {
-            crate::dialogs::msg_box(&format!("{:?}", e));
-            println!("{:?}", e);
-        }
+        result.map_err(|e| format!("{:?}", e))
     }
 }
 
@@ -62,10 +62,15 @@ pub mod str;
 pub mod file;
 /// Bindings to the native file-chooser, color picker, etc. dialogs
 pub mod dialogs;
+/// Interop with the `raw-window-handle` crate
+pub mod window_handle;
+pub mod test_harness;
 pub use azul_core::dom;
+pub use azul_core::error;
 pub use azul_core::gl;
 pub use azul_core::styled_dom;
 pub use azul_core::style;
+pub use azul_core::snapshot;
 /// Font & image resource handling, lookup and caching
 pub mod resources {
     pub use azul_core::app_resources::*;
@@ -79,6 +84,7 @@ pub mod ui_solver {
         PositionInfoInner,
         ResolvedTextLayoutOptions,
         OptionResolvedTextLayoutOptions,
+        LayoutResult,
     };
 }
 
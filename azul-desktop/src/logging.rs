@@ -40,11 +40,17 @@ pub(crate) fn set_up_logging(log_level: LevelFilter) {
 
 /// In the (rare) case of a panic, print it to the stdout, log it to the file and
 /// prompt the user with a message box.
+///
+/// Panics in callbacks are caught and don't exit the program, they are only
+/// logged. Calling this function more than once has no effect.
 pub(crate) fn set_up_panic_hooks() {
 
     use std::panic::{self, PanicInfo};
+    use std::sync::Once;
     use backtrace::{Backtrace, BacktraceFrame};
 
+    static SET_UP_PANIC_HOOKS: Once = Once::new();
+
     fn panic_fn(panic_info: &PanicInfo) {
 
         use std::thread;
@@ -72,8 +78,10 @@ pub(crate) fn set_up_panic_hooks() {
         let thread = thread::current();
         let thread_name = thread.name().unwrap_or("<unnamed thread>");
 
+        let is_caught = azul_core::error::panic_is_caught();
+
         let error_str = format!(
-            "An unexpected panic ocurred, the program has to exit.\r\n\
+            "{}\r\n\
              Please report this error and attach the log file found in the directory of the executable.\r\n\
              \r\n\
              The error ocurred in: {} in thread {}\r\n\
@@ -84,6 +92,11 @@ pub(crate) fn set_up_panic_hooks() {
              Backtrace:\r\n\
              \r\n\
              {}\r\n",
+            if is_caught {
+                "An unexpected panic ocurred in a callback, the callback was skipped."
+            } else {
+                "An unexpected panic ocurred, the program has to exit."
+            },
             location_str.unwrap_or(format!("<unknown location>")), thread_name, panic_str, backtrace_str);
 
         #[cfg(target_os = "linux")]
@@ -96,7 +109,7 @@ pub(crate) fn set_up_panic_hooks() {
         // TODO: invoke external app crash handler with the location to the log file
         error!("{}", error_str);
 
-        if SHOULD_ENABLE_PANIC_HOOK.load(Ordering::SeqCst) {
+        if !is_caught && SHOULD_ENABLE_PANIC_HOOK.load(Ordering::SeqCst) {
             #[cfg(not(target_os = "linux"))]
             msg_box_ok("Unexpected fatal error", &error_str, ::tinyfiledialogs::MessageBoxIcon::Error);
             #[cfg(target_os = "linux")]
@@ -167,5 +180,5 @@ pub(crate) fn set_up_panic_hooks() {
             .join("\r\n")
     }
 
-    panic::set_hook(Box::new(panic_fn));
+    SET_UP_PANIC_HOOKS.call_once(|| panic::set_hook(Box::new(panic_fn)));
 }
\ No newline at end of file
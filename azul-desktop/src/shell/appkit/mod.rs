@@ -33,7 +33,7 @@ use azul_core::{
     display_list::RenderCallbacks,
     window::{
         LogicalSize, Menu, MenuCallback, MenuItem,
-        MonitorVec, WindowCreateOptions, WindowInternal,
+        MonitorVec, OptionWindowTheme, WindowCreateOptions, WindowInternal,
         WindowState, FullWindowState, ScrollResult,
         MouseCursorType, CallCallbacksResult
     },
@@ -115,6 +115,10 @@ pub fn get_monitors(app: &App) -> MonitorVec {
     MonitorVec::from_const_slice(&[]) // TODO
 }
 
+pub fn get_system_theme() -> OptionWindowTheme {
+    OptionWindowTheme::None // TODO
+}
+
 
 pub struct EventLoop<T: 'static> {
     /// The delegate is only weakly referenced by NSApplication, so we keep
@@ -0,0 +1,626 @@
+// Win32 accessibility (MSAA) server
+//
+// Every node of the root DOM is exposed as its own `IAccessible` object.
+// The root node is the client area of the window (`OBJID_CLIENT`), all
+// other nodes use their `NodeId` as a custom (positive) object ID, so that
+// screen readers can resolve the objects of `NotifyWinEvent` events via
+// `WM_GETOBJECT`. Child IDs other than `CHILDID_SELF` are not used.
+//
+// The objects only hold a reference to the `AccessibleWindow`, never to the
+// `App`, since `WM_GETOBJECT` can arrive while the app is borrowed.
+
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+use azul_core::{
+    accessibility::{AccessibilityNode, AccessibilityTree, AccessibilityTreeChange},
+    id_tree::NodeId,
+    ui_solver::LayoutResult,
+    window::LogicalPosition,
+};
+use core::{
+    cell::{Cell, RefCell},
+    ffi::c_void,
+    ptr,
+};
+use winapi::{
+    shared::{
+        guiddef::{IsEqualGUID, REFIID},
+        minwindef::{DWORD, LPARAM, LRESULT, UINT, WORD, WPARAM},
+        ntdef::{LONG, ULONG},
+        windef::{HWND, POINT},
+        winerror::{
+            CO_E_OBJNOTCONNECTED, DISP_E_MEMBERNOTFOUND, E_FAIL, E_INVALIDARG, E_NOINTERFACE,
+            E_NOTIMPL, E_POINTER, HRESULT, S_FALSE, S_OK,
+        },
+        wtypes::{BSTR, VARTYPE, VT_DISPATCH, VT_EMPTY, VT_I4},
+        wtypesbase::LPOLESTR,
+    },
+    um::{
+        oaidl::{DISPID, DISPPARAMS, EXCEPINFO, IDispatch, IDispatchVtbl, ITypeInfo, VARIANT},
+        oleauto::SysAllocStringLen,
+        unknwnbase::{IUnknown, IUnknownVtbl},
+        winnt::LCID,
+        winuser::{
+            ClientToScreen, NotifyWinEvent, CHILDID_SELF, EVENT_OBJECT_NAMECHANGE,
+            EVENT_OBJECT_REORDER, OBJID_CLIENT, OBJID_WINDOW,
+        },
+    },
+    Interface, RIDL,
+};
+
+// not exported by winapi
+RIDL! {#[uuid(0x618736e0, 0x3c3d, 0x11cf, 0x81, 0x0c, 0x00, 0xaa, 0x00, 0x38, 0x9b, 0x71)]
+interface IAccessible(IAccessibleVtbl): IDispatch(IDispatchVtbl) {
+    fn get_accParent(ppdispParent: *mut *mut IDispatch,) -> HRESULT,
+    fn get_accChildCount(pcountChildren: *mut LONG,) -> HRESULT,
+    fn get_accChild(varChild: VARIANT, ppdispChild: *mut *mut IDispatch,) -> HRESULT,
+    fn get_accName(varChild: VARIANT, pszName: *mut BSTR,) -> HRESULT,
+    fn get_accValue(varChild: VARIANT, pszValue: *mut BSTR,) -> HRESULT,
+    fn get_accDescription(varChild: VARIANT, pszDescription: *mut BSTR,) -> HRESULT,
+    fn get_accRole(varChild: VARIANT, pvarRole: *mut VARIANT,) -> HRESULT,
+    fn get_accState(varChild: VARIANT, pvarState: *mut VARIANT,) -> HRESULT,
+    fn get_accHelp(varChild: VARIANT, pszHelp: *mut BSTR,) -> HRESULT,
+    fn get_accHelpTopic(pszHelpFile: *mut BSTR, varChild: VARIANT, pidTopic: *mut LONG,) -> HRESULT,
+    fn get_accKeyboardShortcut(varChild: VARIANT, pszKeyboardShortcut: *mut BSTR,) -> HRESULT,
+    fn get_accFocus(pvarChild: *mut VARIANT,) -> HRESULT,
+    fn get_accSelection(pvarChildren: *mut VARIANT,) -> HRESULT,
+    fn get_accDefaultAction(varChild: VARIANT, pszDefaultAction: *mut BSTR,) -> HRESULT,
+    fn accSelect(flagsSelect: LONG, varChild: VARIANT,) -> HRESULT,
+    fn accLocation(
+        pxLeft: *mut LONG,
+        pyTop: *mut LONG,
+        pcxWidth: *mut LONG,
+        pcyHeight: *mut LONG,
+        varChild: VARIANT,
+    ) -> HRESULT,
+    fn accNavigate(navDir: LONG, varStart: VARIANT, pvarEndUpAt: *mut VARIANT,) -> HRESULT,
+    fn accHitTest(xLeft: LONG, yTop: LONG, pvarChild: *mut VARIANT,) -> HRESULT,
+    fn accDoDefaultAction(varChild: VARIANT,) -> HRESULT,
+    fn put_accName(varChild: VARIANT, szName: BSTR,) -> HRESULT,
+    fn put_accValue(varChild: VARIANT, szValue: BSTR,) -> HRESULT,
+}}
+
+#[link(name = "oleacc")]
+extern "system" {
+    fn LresultFromObject(riid: REFIID, wparam: WPARAM, punk: *mut IUnknown) -> LRESULT;
+    fn AccessibleObjectFromWindow(hwnd: HWND, dw_id: DWORD, riid: REFIID, ppv_object: *mut *mut c_void) -> HRESULT;
+}
+
+// accNavigate directions, spatial navigation (up, down, left, right) is not supported
+const NAVDIR_NEXT: LONG = 5;
+const NAVDIR_PREVIOUS: LONG = 6;
+const NAVDIR_FIRSTCHILD: LONG = 7;
+const NAVDIR_LASTCHILD: LONG = 8;
+
+/// Accessibility tree of the window, shared between the window and
+/// the `IAccessible` objects handed out to accessibility clients
+pub(crate) struct AccessibleWindow {
+    hwnd: HWND,
+    tree: AccessibilityTree,
+    hidpi_factor: f32,
+}
+
+pub(crate) type SharedAccessibleWindow = Rc<RefCell<AccessibleWindow>>;
+
+impl AccessibleWindow {
+    /// Empty on startup, so that the first frame announces the entire tree
+    pub(crate) fn new(hwnd: HWND) -> SharedAccessibleWindow {
+        Rc::new(RefCell::new(Self {
+            hwnd,
+            tree: AccessibilityTree::default(),
+            hidpi_factor: 1.0,
+        }))
+    }
+}
+
+/// Builds the accessibility tree of the root DOM
+pub(crate) fn build_accessibility_tree(layout_results: &[LayoutResult]) -> AccessibilityTree {
+    layout_results
+        .first()
+        .map(AccessibilityTree::from_layout_result)
+        .unwrap_or_default()
+}
+
+/// Rebuilds the accessibility tree after the DOM has been regenerated
+/// and notifies the accessibility clients about the changed nodes
+pub(crate) fn update_accessibility_tree(
+    window: &SharedAccessibleWindow,
+    layout_results: &[LayoutResult],
+    hidpi_factor: f32,
+) {
+    let new_tree = build_accessibility_tree(layout_results);
+
+    let (hwnd, changes) = {
+        let mut window = window.borrow_mut();
+        let changes = window.tree.diff(&new_tree);
+        window.tree = new_tree;
+        window.hidpi_factor = hidpi_factor;
+        (window.hwnd, changes)
+    };
+
+    // in-context clients query the objects from inside of NotifyWinEvent,
+    // so the window must not be borrowed here
+    for change in changes {
+        let (event, node_id) = match change {
+            AccessibilityTreeChange::ChildrenChanged(node_id) => (EVENT_OBJECT_REORDER, node_id),
+            // MSAA has no event for role changes, clients
+            // re-query the properties of reordered nodes
+            AccessibilityTreeChange::RoleChanged(node_id) => (EVENT_OBJECT_REORDER, node_id),
+            AccessibilityTreeChange::LabelChanged(node_id) => (EVENT_OBJECT_NAMECHANGE, node_id),
+        };
+
+        unsafe { NotifyWinEvent(event, hwnd, object_id(node_id), CHILDID_SELF) };
+    }
+}
+
+/// Answers `WM_GETOBJECT`, returns `None` if the message
+/// should be handled by `DefWindowProcW` instead
+pub(crate) fn get_object(window: &SharedAccessibleWindow, wparam: WPARAM, lparam: LPARAM) -> Option<LRESULT> {
+    let node_id = node_id_from_object_id(lparam as DWORD as LONG)?;
+    window.try_borrow().ok()?.tree.get(node_id)?;
+
+    let object = AccessibleNode::new(window.clone(), node_id);
+    unsafe {
+        let lresult = LresultFromObject(&IAccessible::uuidof(), wparam, object as *mut IUnknown);
+        // LresultFromObject holds its own reference
+        (*object).Release();
+        Some(lresult)
+    }
+}
+
+fn object_id(node_id: NodeId) -> LONG {
+    if node_id == NodeId::ZERO {
+        OBJID_CLIENT
+    } else {
+        node_id.index() as LONG
+    }
+}
+
+fn node_id_from_object_id(object_id: LONG) -> Option<NodeId> {
+    if object_id == OBJID_CLIENT {
+        Some(NodeId::ZERO)
+    } else if object_id > 0 {
+        Some(NodeId::new(object_id as usize))
+    } else {
+        None
+    }
+}
+
+/// `ROLE_SYSTEM_*` constant of the node
+fn role_id(node: &AccessibilityNode) -> LONG {
+    use azul_core::dom::AccessibilityRole;
+    match node.role {
+        // AccessibilityRole is declared in the order of the ROLE_SYSTEM_*
+        // constants, starting at ROLE_SYSTEM_TITLEBAR (1)
+        Some(role) if role != AccessibilityRole::Nothing => role as LONG + 1,
+        _ if node.parent.is_none() => 0xa, // ROLE_SYSTEM_CLIENT
+        _ => 0x14, // ROLE_SYSTEM_GROUPING
+    }
+}
+
+/// `IAccessible` object of one node in the root DOM
+#[repr(C)]
+struct AccessibleNode {
+    // must be the first field, the object is used as an `IAccessible`
+    vtbl: *const IAccessibleVtbl,
+    ref_count: Cell<ULONG>,
+    window: SharedAccessibleWindow,
+    node_id: NodeId,
+}
+
+impl AccessibleNode {
+    /// Returns a new object with a reference count of 1
+    fn new(window: SharedAccessibleWindow, node_id: NodeId) -> *mut IAccessible {
+        Box::into_raw(Box::new(Self {
+            vtbl: &ACCESSIBLE_NODE_VTBL,
+            ref_count: Cell::new(1),
+            window,
+            node_id,
+        })) as *mut IAccessible
+    }
+}
+
+static ACCESSIBLE_NODE_VTBL: IAccessibleVtbl = IAccessibleVtbl {
+    parent: IDispatchVtbl {
+        parent: IUnknownVtbl {
+            QueryInterface: query_interface,
+            AddRef: add_ref,
+            Release: release,
+        },
+        GetTypeInfoCount: get_type_info_count,
+        GetTypeInfo: get_type_info,
+        GetIDsOfNames: get_ids_of_names,
+        Invoke: invoke,
+    },
+    get_accParent: get_acc_parent,
+    get_accChildCount: get_acc_child_count,
+    get_accChild: get_acc_child,
+    get_accName: get_acc_name,
+    get_accValue: get_acc_value,
+    get_accDescription: get_acc_description,
+    get_accRole: get_acc_role,
+    get_accState: get_acc_state,
+    get_accHelp: get_acc_help,
+    get_accHelpTopic: get_acc_help_topic,
+    get_accKeyboardShortcut: get_acc_keyboard_shortcut,
+    get_accFocus: get_acc_focus,
+    get_accSelection: get_acc_selection,
+    get_accDefaultAction: get_acc_default_action,
+    accSelect: acc_select,
+    accLocation: acc_location,
+    accNavigate: acc_navigate,
+    accHitTest: acc_hit_test,
+    accDoDefaultAction: acc_do_default_action,
+    put_accName: put_acc_name,
+    put_accValue: put_acc_value,
+};
+
+// --- helpers
+
+/// Calls `f` with the node of the object, fails if the node was
+/// removed since the object was handed out
+unsafe fn with_node<F>(this: *mut IAccessible, f: F) -> HRESULT
+where
+    F: FnOnce(&AccessibleWindow, &AccessibilityNode) -> HRESULT,
+{
+    let this = &*(this as *const AccessibleNode);
+    let window = match this.window.try_borrow() {
+        Ok(w) => w,
+        Err(_) => return E_FAIL,
+    };
+    match window.tree.get(this.node_id) {
+        Some(node) => f(&window, node),
+        None => CO_E_OBJNOTCONNECTED,
+    }
+}
+
+/// Same as `with_node`, but only accepts `CHILDID_SELF` as the child ID
+unsafe fn with_self<F>(this: *mut IAccessible, var_id: &VARIANT, f: F) -> HRESULT
+where
+    F: FnOnce(&AccessibleWindow, &AccessibilityNode) -> HRESULT,
+{
+    if get_i4(var_id) != Some(CHILDID_SELF) {
+        return E_INVALIDARG;
+    }
+    with_node(this, f)
+}
+
+/// New object for another node of the same window
+unsafe fn new_dispatch(this: *mut IAccessible, node_id: NodeId) -> *mut IDispatch {
+    let this = &*(this as *const AccessibleNode);
+    AccessibleNode::new(this.window.clone(), node_id) as *mut IDispatch
+}
+
+unsafe fn get_i4(var: &VARIANT) -> Option<LONG> {
+    let var = var.n1.n2();
+    if var.vt == VT_I4 as VARTYPE {
+        Some(*var.n3.lVal())
+    } else {
+        None
+    }
+}
+
+unsafe fn set_empty(var: *mut VARIANT) {
+    (*var).n1.n2_mut().vt = VT_EMPTY as VARTYPE;
+}
+
+unsafe fn set_i4(var: *mut VARIANT, value: LONG) {
+    let var = (*var).n1.n2_mut();
+    var.vt = VT_I4 as VARTYPE;
+    *var.n3.lVal_mut() = value;
+}
+
+unsafe fn set_dispatch(var: *mut VARIANT, value: *mut IDispatch) {
+    let var = (*var).n1.n2_mut();
+    var.vt = VT_DISPATCH as VARTYPE;
+    *var.n3.pdispVal_mut() = value;
+}
+
+fn to_bstr(s: &str) -> BSTR {
+    let s = s.encode_utf16().collect::<Vec<u16>>();
+    unsafe { SysAllocStringLen(s.as_ptr(), s.len() as UINT) }
+}
+
+/// Top left corner of the client area in physical screen coordinates
+fn client_origin(hwnd: HWND) -> POINT {
+    let mut origin = POINT { x: 0, y: 0 };
+    unsafe { ClientToScreen(hwnd, &mut origin) };
+    origin
+}
+
+// --- IUnknown
+
+unsafe extern "system" fn query_interface(this: *mut IUnknown, riid: REFIID, ppv: *mut *mut c_void) -> HRESULT {
+    if ppv.is_null() {
+        return E_POINTER;
+    }
+    let riid = &*riid;
+    if IsEqualGUID(riid, &IUnknown::uuidof())
+        || IsEqualGUID(riid, &IDispatch::uuidof())
+        || IsEqualGUID(riid, &IAccessible::uuidof())
+    {
+        add_ref(this);
+        *ppv = this as *mut c_void;
+        S_OK
+    } else {
+        *ppv = ptr::null_mut();
+        E_NOINTERFACE
+    }
+}
+
+unsafe extern "system" fn add_ref(this: *mut IUnknown) -> ULONG {
+    let this = &*(this as *const AccessibleNode);
+    this.ref_count.set(this.ref_count.get() + 1);
+    this.ref_count.get()
+}
+
+unsafe extern "system" fn release(this: *mut IUnknown) -> ULONG {
+    let ref_count = {
+        let this = &*(this as *const AccessibleNode);
+        this.ref_count.set(this.ref_count.get() - 1);
+        this.ref_count.get()
+    };
+    if ref_count == 0 {
+        let _ = Box::from_raw(this as *mut AccessibleNode);
+    }
+    ref_count
+}
+
+// --- IDispatch, clients use the IAccessible vtable directly
+
+unsafe extern "system" fn get_type_info_count(_this: *mut IDispatch, pctinfo: *mut UINT) -> HRESULT {
+    if pctinfo.is_null() {
+        return E_POINTER;
+    }
+    *pctinfo = 0;
+    S_OK
+}
+
+unsafe extern "system" fn get_type_info(_this: *mut IDispatch, _itinfo: UINT, _lcid: LCID, _pptinfo: *mut *mut ITypeInfo) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn get_ids_of_names(
+    _this: *mut IDispatch,
+    _riid: REFIID,
+    _names: *mut LPOLESTR,
+    _count: UINT,
+    _lcid: LCID,
+    _dispids: *mut DISPID,
+) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn invoke(
+    _this: *mut IDispatch,
+    _dispid: DISPID,
+    _riid: REFIID,
+    _lcid: LCID,
+    _flags: WORD,
+    _params: *mut DISPPARAMS,
+    _result: *mut VARIANT,
+    _excepinfo: *mut EXCEPINFO,
+    _arg_err: *mut UINT,
+) -> HRESULT {
+    E_NOTIMPL
+}
+
+// --- IAccessible
+
+unsafe extern "system" fn get_acc_parent(this: *mut IAccessible, ppdisp: *mut *mut IDispatch) -> HRESULT {
+    if ppdisp.is_null() {
+        return E_POINTER;
+    }
+    *ppdisp = ptr::null_mut();
+    with_node(this, |window, node| match node.parent {
+        Some(parent) => {
+            *ppdisp = new_dispatch(this, parent);
+            S_OK
+        }
+        // the parent of the client area is the window itself
+        None => AccessibleObjectFromWindow(
+            window.hwnd,
+            OBJID_WINDOW as DWORD,
+            &IDispatch::uuidof(),
+            ppdisp as *mut *mut c_void,
+        ),
+    })
+}
+
+unsafe extern "system" fn get_acc_child_count(this: *mut IAccessible, pcount: *mut LONG) -> HRESULT {
+    if pcount.is_null() {
+        return E_POINTER;
+    }
+    with_node(this, |_, node| {
+        *pcount = node.children.len() as LONG;
+        S_OK
+    })
+}
+
+unsafe extern "system" fn get_acc_child(this: *mut IAccessible, var_child: VARIANT, ppdisp: *mut *mut IDispatch) -> HRESULT {
+    if ppdisp.is_null() {
+        return E_POINTER;
+    }
+    *ppdisp = ptr::null_mut();
+    // child IDs are 1-based indices into the children of the node
+    let index = match get_i4(&var_child) {
+        Some(i) if i > 0 => i as usize - 1,
+        _ => return E_INVALIDARG,
+    };
+    with_node(this, |window, node| match node.children.get(index) {
+        Some(child) => {
+            *ppdisp = new_dispatch(this, *child);
+            S_OK
+        }
+        None => E_INVALIDARG,
+    })
+}
+
+unsafe extern "system" fn get_acc_name(this: *mut IAccessible, var_id: VARIANT, pname: *mut BSTR) -> HRESULT {
+    if pname.is_null() {
+        return E_POINTER;
+    }
+    *pname = ptr::null_mut();
+    with_self(this, &var_id, |_, node| match node.label.as_ref() {
+        Some(label) => {
+            *pname = to_bstr(label.as_str());
+            S_OK
+        }
+        None => S_FALSE,
+    })
+}
+
+unsafe extern "system" fn get_acc_value(_this: *mut IAccessible, _var_id: VARIANT, _pvalue: *mut BSTR) -> HRESULT {
+    DISP_E_MEMBERNOTFOUND
+}
+
+unsafe extern "system" fn get_acc_description(_this: *mut IAccessible, _var_id: VARIANT, _pdescription: *mut BSTR) -> HRESULT {
+    DISP_E_MEMBERNOTFOUND
+}
+
+unsafe extern "system" fn get_acc_role(this: *mut IAccessible, var_id: VARIANT, prole: *mut VARIANT) -> HRESULT {
+    if prole.is_null() {
+        return E_POINTER;
+    }
+    set_empty(prole);
+    with_self(this, &var_id, |_, node| {
+        set_i4(prole, role_id(node));
+        S_OK
+    })
+}
+
+unsafe extern "system" fn get_acc_state(this: *mut IAccessible, var_id: VARIANT, pstate: *mut VARIANT) -> HRESULT {
+    if pstate.is_null() {
+        return E_POINTER;
+    }
+    set_empty(pstate);
+    with_self(this, &var_id, |_, _| {
+        // STATE_SYSTEM_NORMAL, states are not tracked yet
+        set_i4(pstate, 0);
+        S_OK
+    })
+}
+
+unsafe extern "system" fn get_acc_help(_this: *mut IAccessible, _var_id: VARIANT, _phelp: *mut BSTR) -> HRESULT {
+    DISP_E_MEMBERNOTFOUND
+}
+
+unsafe extern "system" fn get_acc_help_topic(_this: *mut IAccessible, _phelp_file: *mut BSTR, _var_id: VARIANT, _ptopic: *mut LONG) -> HRESULT {
+    DISP_E_MEMBERNOTFOUND
+}
+
+unsafe extern "system" fn get_acc_keyboard_shortcut(_this: *mut IAccessible, _var_id: VARIANT, _pshortcut: *mut BSTR) -> HRESULT {
+    DISP_E_MEMBERNOTFOUND
+}
+
+unsafe extern "system" fn get_acc_focus(_this: *mut IAccessible, pvar: *mut VARIANT) -> HRESULT {
+    if pvar.is_null() {
+        return E_POINTER;
+    }
+    // no node has the keyboard focus
+    set_empty(pvar);
+    S_FALSE
+}
+
+unsafe extern "system" fn get_acc_selection(_this: *mut IAccessible, _pvar: *mut VARIANT) -> HRESULT {
+    DISP_E_MEMBERNOTFOUND
+}
+
+unsafe extern "system" fn get_acc_default_action(_this: *mut IAccessible, _var_id: VARIANT, _paction: *mut BSTR) -> HRESULT {
+    DISP_E_MEMBERNOTFOUND
+}
+
+unsafe extern "system" fn acc_select(_this: *mut IAccessible, _flags: LONG, _var_id: VARIANT) -> HRESULT {
+    DISP_E_MEMBERNOTFOUND
+}
+
+unsafe extern "system" fn acc_location(
+    this: *mut IAccessible,
+    pleft: *mut LONG,
+    ptop: *mut LONG,
+    pwidth: *mut LONG,
+    pheight: *mut LONG,
+    var_id: VARIANT,
+) -> HRESULT {
+    if pleft.is_null() || ptop.is_null() || pwidth.is_null() || pheight.is_null() {
+        return E_POINTER;
+    }
+    with_self(this, &var_id, |window, node| {
+        let mut rect = node.rect;
+        rect.scale_for_dpi(window.hidpi_factor);
+        let origin = client_origin(window.hwnd);
+        *pleft = origin.x + libm::roundf(rect.origin.x) as LONG;
+        *ptop = origin.y + libm::roundf(rect.origin.y) as LONG;
+        *pwidth = libm::roundf(rect.size.width) as LONG;
+        *pheight = libm::roundf(rect.size.height) as LONG;
+        S_OK
+    })
+}
+
+unsafe extern "system" fn acc_navigate(this: *mut IAccessible, nav_dir: LONG, var_start: VARIANT, pvar_end: *mut VARIANT) -> HRESULT {
+    if pvar_end.is_null() {
+        return E_POINTER;
+    }
+    set_empty(pvar_end);
+    with_self(this, &var_start, |window, node| {
+        let siblings = node
+            .parent
+            .and_then(|p| window.tree.get(p))
+            .map(|p| p.children.as_slice())
+            .unwrap_or_default();
+        let position = siblings.iter().position(|s| *s == node.node_id);
+
+        let target = match nav_dir {
+            NAVDIR_FIRSTCHILD => node.children.first().copied(),
+            NAVDIR_LASTCHILD => node.children.last().copied(),
+            NAVDIR_NEXT => position.and_then(|i| siblings.get(i + 1)).copied(),
+            NAVDIR_PREVIOUS => position.and_then(|i| i.checked_sub(1)).and_then(|i| siblings.get(i)).copied(),
+            _ => None,
+        };
+
+        match target {
+            Some(target) => {
+                set_dispatch(pvar_end, new_dispatch(this, target));
+                S_OK
+            }
+            None => S_FALSE,
+        }
+    })
+}
+
+unsafe extern "system" fn acc_hit_test(this: *mut IAccessible, x: LONG, y: LONG, pvar: *mut VARIANT) -> HRESULT {
+    if pvar.is_null() {
+        return E_POINTER;
+    }
+    set_empty(pvar);
+    with_node(this, |window, node| {
+        let origin = client_origin(window.hwnd);
+        let point = LogicalPosition::new(
+            (x - origin.x) as f32 / window.hidpi_factor,
+            (y - origin.y) as f32 / window.hidpi_factor,
+        );
+
+        match window.tree.hit_test(node.node_id, point) {
+            Some(hit) if hit == node.node_id => {
+                set_i4(pvar, CHILDID_SELF);
+                S_OK
+            }
+            Some(hit) => {
+                set_dispatch(pvar, new_dispatch(this, hit));
+                S_OK
+            }
+            None => S_FALSE,
+        }
+    })
+}
+
+unsafe extern "system" fn acc_do_default_action(_this: *mut IAccessible, _var_id: VARIANT) -> HRESULT {
+    DISP_E_MEMBERNOTFOUND
+}
+
+unsafe extern "system" fn put_acc_name(_this: *mut IAccessible, _var_id: VARIANT, _name: BSTR) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn put_acc_value(_this: *mut IAccessible, _var_id: VARIANT, _value: BSTR) -> HRESULT {
+    E_NOTIMPL
+}
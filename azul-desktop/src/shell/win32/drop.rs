@@ -0,0 +1,45 @@
+// Win32 file drop handling
+//
+// Reads the file paths and the drop position of a WM_DROPFILES message.
+// The window is created with WS_EX_ACCEPTFILES, so no OLE drop target
+// is registered: Windows only reports the drop, not the hovering.
+
+use alloc::vec::Vec;
+use azul_core::window::LogicalPosition;
+use azul_css::{AzString, StringVec};
+use core::ptr;
+use winapi::{
+    shared::{minwindef::UINT, windef::POINT},
+    um::shellapi::{DragFinish, DragQueryFileW, DragQueryPoint, HDROP},
+};
+
+/// Dropped files + cursor position (relative to the window) of a `WM_DROPFILES` message.
+///
+/// Takes ownership of the `HDROP` and releases it via `DragFinish`.
+pub(crate) unsafe fn get_dropped_files(hdrop: HDROP, hidpi_factor: f32) -> (StringVec, LogicalPosition) {
+    // passing 0xFFFFFFFF as the index returns the number of files
+    let file_count = DragQueryFileW(hdrop, 0xFFFFFFFF, ptr::null_mut(), 0);
+
+    let mut files = Vec::with_capacity(file_count as usize);
+    for i in 0..file_count {
+        // length in UTF-16 characters, not including the terminating nul
+        let len = DragQueryFileW(hdrop, i, ptr::null_mut(), 0) as usize;
+        if len == 0 {
+            continue;
+        }
+        let mut buf = vec![0u16; len + 1];
+        let copied = DragQueryFileW(hdrop, i, buf.as_mut_ptr(), buf.len() as UINT) as usize;
+        files.push(AzString::from(String::from_utf16_lossy(&buf[..copied])));
+    }
+
+    let mut point = POINT { x: 0, y: 0 };
+    DragQueryPoint(hdrop, &mut point);
+    DragFinish(hdrop);
+
+    let position = LogicalPosition::new(
+        point.x as f32 / hidpi_factor,
+        point.y as f32 / hidpi_factor,
+    );
+
+    (StringVec::from_vec(files), position)
+}
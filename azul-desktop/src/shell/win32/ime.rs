@@ -0,0 +1,112 @@
+// Win32 input method editor (IME) handling
+//
+// Translates WM_IME_COMPOSITION messages into azul `ImeEvent`s and
+// moves the IME candidate window to the `ImePosition` of the window state
+
+use azul_core::window::{ImeCursorRange, ImeEvent, ImePreedit, LogicalPosition};
+use core::ptr;
+use winapi::{
+    ctypes::c_void,
+    shared::{
+        minwindef::{DWORD, LPARAM},
+        windef::{HWND, POINT},
+    },
+    um::imm::{
+        ImmGetContext, ImmReleaseContext, ImmSetCompositionWindow, CFS_POINT, COMPOSITIONFORM, HIMC,
+    },
+};
+
+// not exported by winapi
+const GCS_COMPSTR: DWORD = 0x0008;
+const GCS_CURSORPOS: DWORD = 0x0080;
+const GCS_RESULTSTR: DWORD = 0x0800;
+
+#[link(name = "imm32")]
+extern "system" {
+    fn ImmGetCompositionStringW(himc: HIMC, index: DWORD, buf: *mut c_void, buf_len: DWORD) -> i32;
+}
+
+/// Reads the composition string of a `WM_IME_COMPOSITION` message:
+/// returns `ImeEvent::Commit` if the composition has finished,
+/// `ImeEvent::Preedit` if the composition text has changed
+pub(crate) unsafe fn get_composition_event(hwnd: HWND, lparam: LPARAM) -> Option<ImeEvent> {
+    let himc = ImmGetContext(hwnd);
+    if himc.is_null() {
+        return None;
+    }
+
+    let flags = lparam as DWORD;
+
+    let event = if flags & GCS_RESULTSTR != 0 {
+        get_composition_string(himc, GCS_RESULTSTR).map(|text| ImeEvent::Commit(text.into()))
+    } else if flags & GCS_COMPSTR != 0 {
+        get_composition_string(himc, GCS_COMPSTR).map(|text| {
+            let cursor_range = if flags & GCS_CURSORPOS != 0 {
+                let cursor = ImmGetCompositionStringW(himc, GCS_CURSORPOS, ptr::null_mut(), 0);
+                let byte_offset = utf16_offset_to_byte_offset(&text, cursor.max(0) as usize);
+                Some(ImeCursorRange {
+                    start: byte_offset,
+                    end: byte_offset,
+                })
+            } else {
+                None
+            };
+
+            ImeEvent::Preedit(ImePreedit {
+                text: text.into(),
+                cursor_range: cursor_range.into(),
+            })
+        })
+    } else {
+        None
+    };
+
+    ImmReleaseContext(hwnd, himc);
+
+    event
+}
+
+/// Moves the IME candidate window to the given position in the client area
+pub(crate) unsafe fn set_ime_position(hwnd: HWND, position: LogicalPosition, hidpi_factor: f32) {
+    let himc = ImmGetContext(hwnd);
+    if himc.is_null() {
+        return;
+    }
+
+    let physical = position.to_physical(hidpi_factor);
+    let mut composition_form = COMPOSITIONFORM {
+        dwStyle: CFS_POINT,
+        ptCurrentPos: POINT {
+            x: physical.x as i32,
+            y: physical.y as i32,
+        },
+        rcArea: core::mem::zeroed(),
+    };
+
+    ImmSetCompositionWindow(himc, &mut composition_form);
+    ImmReleaseContext(hwnd, himc);
+}
+
+unsafe fn get_composition_string(himc: HIMC, index: DWORD) -> Option<String> {
+    // returns the length in bytes, not in UTF-16 code units
+    let len = ImmGetCompositionStringW(himc, index, ptr::null_mut(), 0);
+    if len < 0 {
+        return None;
+    }
+
+    let mut buf = vec![0_u16; len as usize / 2];
+    ImmGetCompositionStringW(himc, index, buf.as_mut_ptr() as *mut c_void, len as DWORD);
+    String::from_utf16(&buf).ok()
+}
+
+// the IME cursor is given in UTF-16 code units, azul uses byte offsets
+fn utf16_offset_to_byte_offset(text: &str, utf16_offset: usize) -> usize {
+    let mut cur_utf16 = 0;
+    for (byte_offset, c) in text.char_indices() {
+        if cur_utf16 >= utf16_offset {
+            return byte_offset;
+        }
+        cur_utf16 += c.len_utf16();
+    }
+    text.len()
+}
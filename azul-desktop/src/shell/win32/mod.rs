@@ -6,6 +6,10 @@
 
 mod event;
 mod dpi;
+mod ime;
+mod drop;
+#[cfg(feature = "accessibility")]
+mod accessibility;
 
 use crate::{
     app::{App, LazyFcCache},
@@ -35,16 +39,18 @@ use azul_core::{
         DomNodeId, DocumentId
     },
     gl::OptionGlContextPtr,
-    task::{Thread, ThreadId, Timer, TimerId},
+    task::{Duration, Instant, Thread, ThreadId, Timer, TimerId},
     ui_solver::LayoutResult,
     styled_dom::DomId,
     dom::NodeId,
     display_list::RenderCallbacks,
     window::{
-        LogicalSize, Menu, MenuCallback, MenuItem,
-        MonitorVec, WindowCreateOptions, WindowInternal,
+        LogicalSize, Menu, MenuCallback, MenuItem, MenuItemState,
+        ImeEvent, ImePosition, MonitorVec, WindowCreateOptions, WindowInternal,
+        WindowTheme, OptionWindowTheme,
         WindowState, FullWindowState, ScrollResult,
-        MouseCursorType, CallCallbacksResult
+        MouseCursorType, CallCallbacksResult, NextFrame,
+        ContextMenuChange, LogicalPosition,
     },
     window_state::NodesToCheck,
 };
@@ -94,6 +100,8 @@ type TIMERPTR = winapi::shared::basetsd::UINT_PTR;
 const AZ_TICK_REGENERATE_DOM: usize = 1;
 // ID sent by WM_TIMER to check the thread results
 const AZ_THREAD_TICK: usize = 2;
+// ID sent by WM_TIMER when a frame delayed by the frame rate limit is due
+const AZ_TICK_REDRAW: usize = 3;
 
 const AZ_REGENERATE_DOM: u32 = WM_APP + 1;
 const AZ_REGENERATE_DISPLAY_LIST: u32 = WM_APP + 2;
@@ -123,6 +131,37 @@ pub fn get_monitors(app: &App) -> MonitorVec {
     MonitorVec::from_const_slice(&[]) // TODO
 }
 
+/// Reads the "app mode" (light / dark) from the registry, returns `None`
+/// on Windows versions before Windows 10 1809 (no dark mode support)
+pub fn get_system_theme() -> OptionWindowTheme {
+    use winapi::shared::minwindef::DWORD;
+    use winapi::shared::winerror::ERROR_SUCCESS;
+    use winapi::um::winreg::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+
+    let subkey = encode_wide("Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize");
+    let value = encode_wide("AppsUseLightTheme");
+    let mut data: DWORD = 0;
+    let mut data_size = mem::size_of::<DWORD>() as DWORD;
+
+    let result = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            subkey.as_ptr(),
+            value.as_ptr(),
+            RRF_RT_REG_DWORD,
+            ptr::null_mut(),
+            &mut data as *mut DWORD as *mut _,
+            &mut data_size,
+        )
+    };
+
+    if result != ERROR_SUCCESS as i32 {
+        return OptionWindowTheme::None;
+    }
+
+    OptionWindowTheme::Some(if data == 0 { WindowTheme::DarkMode } else { WindowTheme::LightMode })
+}
+
 /// Main function that starts when app.run() is invoked
 pub fn run(app: App, root_window: WindowCreateOptions) -> Result<isize, WindowsStartupError> {
 
@@ -1608,6 +1647,11 @@ struct Window {
     thread_timer_running: Option<TIMERPTR>,
     /// characters are combined via two following wparam messages
     high_surrogate: Option<u16>,
+    /// Whether the theme follows the OS theme (`WindowCreateOptions.theme` was set to `None`)
+    theme_follows_system: bool,
+    /// Accessibility tree of the last frame, shared with the `IAccessible` objects of the window
+    #[cfg(feature = "accessibility")]
+    accessibility: accessibility::SharedAccessibleWindow,
 }
 
 impl fmt::Debug for Window {
@@ -1655,6 +1699,54 @@ impl Window {
         self.hwnd as usize
     }
 
+    // Opens a native popup menu at `pos` (relative to the client area), the
+    // WM_COMMAND of the clicked item invokes its callback on `hit_dom_node`
+    unsafe fn open_context_menu(&mut self, context_menu: &Menu, pos: LogicalPosition, hit_dom_node: DomNodeId) {
+
+        use winapi::um::winuser::{
+            CreatePopupMenu, TrackPopupMenu, SetForegroundWindow,
+            GetClientRect, ClientToScreen,
+            TPM_TOPALIGN, TPM_LEFTALIGN,
+        };
+
+        let mut hPopupMenu = CreatePopupMenu();
+        let mut callbacks = BTreeMap::new();
+        let hidpi_factor = self.internal.current_window_state.size.get_hidpi_factor();
+
+        WindowsMenuBar::recursive_construct_menu(
+            &mut hPopupMenu,
+            &context_menu.items.as_ref(),
+            &mut callbacks,
+        );
+
+        let align = match context_menu.position {
+            _ => TPM_TOPALIGN | TPM_LEFTALIGN, // TODO
+        };
+
+        // get the current top left edge of the window rect
+        let mut rect: RECT = mem::zeroed();
+        GetClientRect(self.hwnd, &mut rect);
+
+        let mut top_left = POINT { x: rect.left, y: rect.top };
+        ClientToScreen(self.hwnd, &mut top_left);
+
+        self.context_menu = Some(CurrentContextMenu {
+            callbacks,
+            hit_dom_node,
+        });
+
+        SetForegroundWindow(self.hwnd);
+        TrackPopupMenu(
+            hPopupMenu,
+            align,
+            top_left.x + (libm::roundf(pos.x * hidpi_factor) as i32),
+            top_left.y + (libm::roundf(pos.y * hidpi_factor) as i32),
+            0,
+            self.hwnd,
+            ptr::null_mut()
+        );
+    }
+
     // Creates a new HWND according to the options
     fn create(
         hinstance: HINSTANCE,
@@ -1763,6 +1855,11 @@ impl Window {
         let dpi_factor = self::dpi::dpi_to_scale_factor(dpi);
 
         options.state.size.dpi = dpi;
+        let theme_follows_system = options.theme.is_none();
+        options.state.theme = match options.theme {
+            OptionWindowTheme::Some(theme) => theme,
+            OptionWindowTheme::None => get_system_theme().into_option().unwrap_or_default(),
+        };
 
         // Window created, now try initializing OpenGL context
         let renderer_types = match options.renderer.into_option() {
@@ -1923,6 +2020,7 @@ impl Window {
             let fc_cache = &mut appdata_lock.fc_cache;
             let image_cache = &appdata_lock.image_cache;
             let data = &mut appdata_lock.data;
+            let redraw_behavior = appdata_lock.config.redraw_behavior;
 
             fc_cache.apply_closure(|fc_cache| {
                 WindowInternal::new(
@@ -1930,6 +2028,7 @@ impl Window {
                         window_create_options: options.clone(),
                         document_id,
                         id_namespace,
+                        redraw_behavior,
                     },
                     data,
                     image_cache,
@@ -2106,6 +2205,9 @@ impl Window {
             timers: BTreeMap::new(),
             thread_timer_running: None,
             high_surrogate: None,
+            theme_follows_system,
+            #[cfg(feature = "accessibility")]
+            accessibility: accessibility::AccessibleWindow::new(hwnd),
         };
 
         // invoke the create callback, if there is any
@@ -2222,6 +2324,29 @@ impl Window {
         }
     }
 
+    // Posts a WM_PAINT or - if the frame rate limit of the AppConfig::redraw_behavior
+    // is reached - starts the AZ_TICK_REDRAW timer for the time when the next frame is due
+    fn request_redraw(&mut self) {
+
+        use winapi::um::winuser::{PostMessageW, SetTimer, WM_PAINT};
+
+        self.internal.frame_scheduler.request_redraw();
+
+        let now = Instant::from(std::time::Instant::now());
+        match self.internal.frame_scheduler.next_frame(&now) {
+            NextFrame::Idle => { },
+            NextFrame::RenderNow => unsafe { PostMessageW(self.hwnd, WM_PAINT, 0, 0); },
+            NextFrame::RenderAt(next) => {
+                let millis = match next.duration_since(&now) {
+                    Duration::System(s) => s.millis().max(1).min(u32::MAX as u64) as u32,
+                    Duration::Tick(_) => 1,
+                };
+                // re-starting the timer replaces the previous one
+                unsafe { SetTimer(self.hwnd, AZ_TICK_REDRAW, millis, None) };
+            },
+        }
+    }
+
     // Stop all timers that have a NodeId attached to them because in the next
     // frame the NodeId would be invalid, leading to crashes / panics
     fn stop_timers_with_node_ids(&mut self) {
@@ -2480,27 +2605,41 @@ impl WindowsMenuBar {
         use winapi::shared::basetsd::UINT_PTR;
         use winapi::um::winuser::{AppendMenuW, CreateMenu};
         use winapi::um::winuser::{MF_MENUBREAK, MF_POPUP, MF_SEPARATOR, MF_STRING};
+        use winapi::um::winuser::{MF_DISABLED, MF_GRAYED};
 
         for item in items.as_ref() {
             match item {
                 MenuItem::String(mi) => {
+
+                    let state_flags = match mi.state {
+                        MenuItemState::Normal => 0,
+                        MenuItemState::Greyed => MF_GRAYED,
+                        MenuItemState::Disabled => MF_DISABLED,
+                    };
+
+                    // "Copy\tCtrl+C" - Windows renders the text after the tab right-aligned
+                    let label = match mi.accelerator.as_ref() {
+                        Some(a) => format!("{}\t{}", mi.label.as_str(), a),
+                        None => mi.label.as_str().to_string(),
+                    };
+
                     if mi.children.as_ref().is_empty() {
                         // no children
                         let command = match mi.callback.as_ref() {
-                            None => 0,
-                            Some(c) => {
+                            Some(c) if mi.is_enabled() => {
                                 let new_command_id =
                                     Self::get_new_command_id().min(core::u16::MAX as usize) as u16;
                                 command_map.insert(new_command_id, c.clone());
                                 new_command_id as usize
                             }
+                            _ => 0,
                         };
                         unsafe {
                             AppendMenuW(
                                 *menu,
-                                MF_STRING,
+                                MF_STRING | state_flags,
                                 command,
-                                convert_widestring(mi.label.as_str()).as_ptr(),
+                                convert_widestring(&label).as_ptr(),
                             )
                         };
                     } else {
@@ -2513,9 +2652,9 @@ impl WindowsMenuBar {
                         unsafe {
                             AppendMenuW(
                                 *menu,
-                                MF_POPUP,
+                                MF_POPUP | state_flags,
                                 root as UINT_PTR,
-                                convert_widestring(mi.label.as_str()).as_ptr(),
+                                convert_widestring(&label).as_ptr(),
                             )
                         };
                     }
@@ -2551,6 +2690,8 @@ unsafe extern "system" fn WindowProc(
         WM_QUIT, WM_HSCROLL, WM_VSCROLL, WM_WINDOWPOSCHANGED,
         WM_KEYUP, WM_KEYDOWN, WM_SYSKEYUP, WM_SYSKEYDOWN,
         WM_CHAR, WM_SYSCHAR, WHEEL_DELTA, WM_SETFOCUS, WM_KILLFOCUS,
+        WM_IME_STARTCOMPOSITION, WM_IME_COMPOSITION, WM_IME_ENDCOMPOSITION,
+        WM_DROPFILES, WM_SETTINGCHANGE, WM_GETOBJECT,
 
         VK_F4,
         CREATESTRUCTW, GWLP_USERDATA,
@@ -2653,6 +2794,13 @@ unsafe extern "system" fn WindowProc(
                     // stop timers that have a DomNodeId attached to them
                     current_window.stop_timers_with_node_ids();
 
+                    #[cfg(feature = "accessibility")]
+                    accessibility::update_accessibility_tree(
+                        &current_window.accessibility,
+                        &current_window.internal.layout_results,
+                        current_window.internal.current_window_state.size.get_hidpi_factor(),
+                    );
+
                     let mut gl = &mut current_window.gl_functions.functions;
                     gl.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
                     gl.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
@@ -2857,7 +3005,7 @@ unsafe extern "system" fn WindowProc(
                         true,
                     );
 
-                    PostMessageW(hwnd, WM_PAINT, 0, 0);
+                    current_window.request_redraw();
                     mem::drop(app_borrow);
                     0
                 } else {
@@ -2875,7 +3023,7 @@ unsafe extern "system" fn WindowProc(
                             false,
                         );
 
-                        PostMessageW(hwnd, WM_PAINT, 0, 0);
+                        current_window.request_redraw();
                     },
                     None => { },
                 }
@@ -2898,6 +3046,15 @@ unsafe extern "system" fn WindowProc(
                 mem::drop(app_borrow);
                 1
             },
+            #[cfg(feature = "accessibility")]
+            WM_GETOBJECT => {
+                let accessible_window = app_borrow.windows.get(&hwnd_key).map(|w| w.accessibility.clone());
+                mem::drop(app_borrow);
+                match accessible_window.and_then(|w| accessibility::get_object(&w, wparam, lparam)) {
+                    Some(lresult) => lresult,
+                    None => DefWindowProcW(hwnd, msg, wparam, lparam),
+                }
+            },
             WM_SETFOCUS => {
                 if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
                     current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
@@ -3008,6 +3165,7 @@ unsafe extern "system" fn WindowProc(
 
                             current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
                             current_window.internal.current_window_state.keyboard_state.current_char = None.into();
+                            current_window.internal.current_window_state.keyboard_state.current_ime_event = None.into();
                             current_window.internal.current_window_state.keyboard_state.pressed_scancodes.insert_hm_item(scancode);
                             if let Some(vk) = vk {
                                 current_window.internal.current_window_state.keyboard_state.current_virtual_keycode = Some(vk).into();
@@ -3080,6 +3238,107 @@ unsafe extern "system" fn WindowProc(
                     DefWindowProcW(hwnd, msg, wparam, lparam)
                 }
             },
+            WM_IME_STARTCOMPOSITION | WM_IME_COMPOSITION | WM_IME_ENDCOMPOSITION => {
+                let mut is_commit = false;
+
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                    let ime_event = match msg {
+                        WM_IME_STARTCOMPOSITION => Some(ImeEvent::Enabled),
+                        WM_IME_ENDCOMPOSITION => Some(ImeEvent::Disabled),
+                        _ => ime::get_composition_event(hwnd, lparam),
+                    };
+
+                    if let Some(ime_event) = ime_event {
+                        is_commit = matches!(ime_event, ImeEvent::Commit(_));
+                        current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                        current_window.internal.current_window_state.keyboard_state.current_ime_event = Some(ime_event).into();
+                        PostMessageW(current_window.hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                    }
+                }
+
+                mem::drop(app_borrow);
+
+                if is_commit {
+                    // NOTE: the default window procedure would send the committed text
+                    // again as WM_IME_CHAR / WM_CHAR messages, but it has already been
+                    // delivered as On::ImeCommit
+                    0
+                } else {
+                    // draws the default composition window
+                    DefWindowProcW(hwnd, msg, wparam, lparam)
+                }
+            },
+            WM_SETTINGCHANGE => {
+                // lparam is the name of the changed setting, "ImmersiveColorSet"
+                // is sent when the user switches between light and dark mode
+                let setting_name = if lparam == 0 {
+                    String::new()
+                } else {
+                    let ptr = lparam as *const u16;
+                    let len = (0..).take_while(|i| *ptr.offset(*i) != 0).count();
+                    String::from_utf16_lossy(core::slice::from_raw_parts(ptr, len))
+                };
+
+                if setting_name == "ImmersiveColorSet" {
+                    if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+                        // windows with a fixed theme don't follow the OS theme
+                        if current_window.theme_follows_system {
+                            if let Some(theme) = get_system_theme().into_option() {
+                                if theme != current_window.internal.current_window_state.theme {
+                                    // fires a WindowEventFilter::ThemeChanged event
+                                    current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
+                                    current_window.internal.current_window_state.theme = theme;
+                                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                                    // restyle if a prefers-color-scheme @media query flipped
+                                    if current_window.internal.media_queries_changed() {
+                                        PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                mem::drop(app_borrow);
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            },
+            WM_DROPFILES => {
+
+                use azul_core::window::CursorPosition;
+                use azul_css::StringVec;
+
+                if let Some(current_window) = app_borrow.windows.get_mut(&hwnd_key) {
+
+                    let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
+                    let (dropped_files, drop_position) = drop::get_dropped_files(wparam as _, hidpi_factor);
+
+                    // clear the files of the last drop first, so that dropping
+                    // the same files twice still fires a On::DroppedFile event
+                    current_window.internal.current_window_state.dropped_files = StringVec::from_const_slice(&[]);
+                    let previous_state = current_window.internal.current_window_state.clone();
+                    current_window.internal.previous_window_state = Some(previous_state);
+                    current_window.internal.current_window_state.hovered_files = StringVec::from_const_slice(&[]);
+                    current_window.internal.current_window_state.dropped_files = dropped_files;
+                    current_window.internal.current_window_state.mouse_state.cursor_position = CursorPosition::InWindow(drop_position);
+
+                    // hit test at the drop position, so that the files are dropped
+                    // on the nodes under the cursor instead of the last hovered nodes
+                    let hit_test = crate::wr_translate::fullhittest_new_webrender(
+                        &*current_window.hit_tester.resolve(),
+                        current_window.internal.document_id,
+                        current_window.internal.current_window_state.focused_node,
+                        &current_window.internal.layout_results,
+                        &current_window.internal.current_window_state.mouse_state.cursor_position,
+                        hidpi_factor,
+                    );
+                    current_window.internal.current_window_state.last_hit_test = hit_test;
+
+                    PostMessageW(hwnd, AZ_REDO_HIT_TEST, 0, 0);
+                }
+
+                mem::drop(app_borrow);
+                0
+            },
             WM_KEYUP | WM_SYSKEYUP => {
                 use self::event::process_key_params;
                 if let Some((scancode, vk)) = process_key_params(wparam, lparam) {
@@ -3154,54 +3413,11 @@ unsafe extern "system" fn WindowProc(
                     current_window.internal.previous_window_state = Some(previous_state);
 
                     // open context menu
-                    if let Some((context_menu, hit, node_id)) = current_window.internal.get_context_menu() {
-
-                        use winapi::um::winuser::{
-                            CreatePopupMenu, TrackPopupMenu, SetForegroundWindow,
-                            GetClientRect, ClientToScreen,
-                            TPM_TOPALIGN, TPM_LEFTALIGN,
-                        };
-
-                        let mut hPopupMenu = CreatePopupMenu();
-                        let mut callbacks = BTreeMap::new();
-                        let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
-
-                        WindowsMenuBar::recursive_construct_menu(
-                            &mut hPopupMenu,
-                            &context_menu.items.as_ref(),
-                            &mut callbacks,
-                        );
+                    let context_menu = current_window.internal.get_context_menu()
+                        .map(|(menu, hit, node_id)| ((**menu).clone(), hit.point_in_viewport, node_id));
 
-                        let align = match context_menu.position {
-                            _ => TPM_TOPALIGN | TPM_LEFTALIGN, // TODO
-                        };
-
-                        // get the current top left edge of the window rect
-                        let mut rect: RECT = unsafe { mem::zeroed() };
-                        GetClientRect(hwnd, &mut rect);
-
-                        let mut top_left = POINT { x: rect.left, y: rect.top };
-                        ClientToScreen(hwnd, &mut top_left);
-
-                        let pos = match context_menu.position {
-                            _ => hit.point_in_viewport, // TODO
-                        };
-
-                        current_window.context_menu = Some(CurrentContextMenu {
-                            callbacks,
-                            hit_dom_node: node_id,
-                        });
-
-                        SetForegroundWindow(hwnd);
-                        TrackPopupMenu(
-                            hPopupMenu,
-                            align,
-                            top_left.x + (libm::roundf(pos.x * hidpi_factor) as i32),
-                            top_left.y + (libm::roundf(pos.y * hidpi_factor) as i32),
-                            0,
-                            hwnd,
-                            ptr::null_mut()
-                        );
+                    if let Some((context_menu, pos, node_id)) = context_menu {
+                        current_window.open_context_menu(&context_menu, pos, node_id);
                     }
 
                     current_window.internal.current_window_state.mouse_state.right_down = false;
@@ -3246,54 +3462,11 @@ unsafe extern "system" fn WindowProc(
                     current_window.internal.previous_window_state = Some(previous_state);
 
                     // open context menu
-                    if let Some((context_menu, hit, node_id)) = current_window.internal.get_context_menu() {
+                    let context_menu = current_window.internal.get_context_menu()
+                        .map(|(menu, hit, node_id)| ((**menu).clone(), hit.point_in_viewport, node_id));
 
-                        use winapi::um::winuser::{
-                            CreatePopupMenu, TrackPopupMenu, SetForegroundWindow,
-                            GetClientRect, ClientToScreen,
-                            TPM_TOPALIGN, TPM_LEFTALIGN,
-                        };
-
-                        let mut hPopupMenu = CreatePopupMenu();
-                        let mut callbacks = BTreeMap::new();
-                        let hidpi_factor = current_window.internal.current_window_state.size.get_hidpi_factor();
-
-                        WindowsMenuBar::recursive_construct_menu(
-                            &mut hPopupMenu,
-                            &context_menu.items.as_ref(),
-                            &mut callbacks,
-                        );
-
-                        let align = match context_menu.position {
-                            _ => TPM_TOPALIGN | TPM_LEFTALIGN, // TODO
-                        };
-
-                        // get the current top left edge of the window rect
-                        let mut rect: RECT = unsafe { mem::zeroed() };
-                        GetClientRect(hwnd, &mut rect);
-
-                        let mut top_left = POINT { x: rect.left, y: rect.top };
-                        ClientToScreen(hwnd, &mut top_left);
-
-                        let pos = match context_menu.position {
-                            _ => hit.point_in_viewport, // TODO
-                        };
-
-                        current_window.context_menu = Some(CurrentContextMenu {
-                            callbacks,
-                            hit_dom_node: node_id,
-                        });
-
-                        SetForegroundWindow(hwnd);
-                        TrackPopupMenu(
-                            hPopupMenu,
-                            align,
-                            top_left.x + (libm::roundf(pos.x * hidpi_factor) as i32),
-                            top_left.y + (libm::roundf(pos.y * hidpi_factor) as i32),
-                            0,
-                            hwnd,
-                            ptr::null_mut()
-                        );
+                    if let Some((context_menu, pos, node_id)) = context_menu {
+                        current_window.open_context_menu(&context_menu, pos, node_id);
                     }
 
                     current_window.internal.current_window_state.mouse_state.left_down = false;
@@ -3411,6 +3584,11 @@ unsafe extern "system" fn WindowProc(
                         current_window.internal.previous_window_state = Some(current_window.internal.current_window_state.clone());
                         current_window.internal.current_window_state = new_window_state;
 
+                        // the window crossed a breakpoint of a @media query
+                        if current_window.internal.media_queries_changed() {
+                            PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        }
+
                         txn.set_document_view(
                             WrDeviceIntRect::from_size(
                                 WrDeviceIntSize::new(new_width as i32, new_height as i32),
@@ -3525,6 +3703,15 @@ unsafe extern "system" fn WindowProc(
 
                 wglMakeCurrent(ptr::null_mut(), ptr::null_mut());
                 ReleaseDC(hwnd, hDC);
+
+                // RedrawBehavior::Unlimited: render the next frame right away
+                let now = Instant::from(std::time::Instant::now());
+                let frame_scheduler = &mut current_window.internal.frame_scheduler;
+                frame_scheduler.frame_rendered(now.clone());
+                if frame_scheduler.next_frame(&now) == NextFrame::RenderNow {
+                    PostMessageW(hwnd, WM_PAINT, 0, 0);
+                }
+
                 mem::drop(app_borrow);
                 DefWindowProcW(hwnd, msg, wparam, lparam)
             },
@@ -3551,6 +3738,14 @@ unsafe extern "system" fn WindowProc(
                         mem::drop(app_borrow);
                         return DefWindowProcW(hwnd, msg, wparam, lparam)
                     },
+                    AZ_TICK_REDRAW => {
+                        // frame delayed by the frame rate limit is due
+                        use winapi::um::winuser::KillTimer;
+                        KillTimer(hwnd, AZ_TICK_REDRAW);
+                        PostMessageW(hwnd, WM_PAINT, 0, 0);
+                        mem::drop(app_borrow);
+                        return DefWindowProcW(hwnd, msg, wparam, lparam)
+                    },
                     AZ_THREAD_TICK => {
 
                         // tick every 16ms to process new thread messages
@@ -4170,6 +4365,15 @@ fn process_callback_results(
         }
     }
 
+    // images updated with CallbackInfo::update_image_raw
+    let updated_images = window.internal.renderer_resources.update_changed_css_images(&*image_cache);
+    if !updated_images.is_empty() {
+        let mut txn = WrTransaction::new();
+        wr_synchronize_updated_images(updated_images, &window.internal.document_id, &mut txn);
+        window.render_api.send_transaction(wr_translate_document_id(window.internal.document_id), txn);
+        result = result.max_self(ProcessEventResult::ShouldReRenderCurrentWindow);
+    }
+
     window.start_stop_timers(
         callback_results.timers.unwrap_or_default(),
         callback_results.timers_removed.unwrap_or_default()
@@ -4178,11 +4382,17 @@ fn process_callback_results(
         callback_results.threads.unwrap_or_default(),
         callback_results.threads_removed.unwrap_or_default()
     );
+    window.internal.send_thread_msgs(callback_results.thread_msgs_sent);
 
     for w in callback_results.windows_created {
         new_windows.push(w);
     }
 
+    // popup menus are native on win32 and close themselves, so only Open is handled
+    if let Some(ContextMenuChange::Open(request)) = callback_results.context_menu.take() {
+        unsafe { window.open_context_menu(&request.menu, request.position, request.hit_dom_node); }
+    }
+
 
     let scroll = window.internal.current_window_state.process_system_scroll(&window.internal.scroll_states);
     let need_scroll_render = scroll.is_some();
@@ -4193,8 +4403,8 @@ fn process_callback_results(
         }
         window.internal.current_window_state = FullWindowState::from_window_state(
             modified,
-            window.internal.current_window_state.dropped_file.clone(),
-            window.internal.current_window_state.hovered_file.clone(),
+            window.internal.current_window_state.dropped_files.clone(),
+            window.internal.current_window_state.hovered_files.clone(),
             window.internal.current_window_state.focused_node.clone(),
             window.internal.current_window_state.last_hit_test.clone(),
         );
@@ -4215,6 +4425,12 @@ fn process_callback_results(
         &window.internal.previous_window_state
     );
 
+    // a new stylesheet needs a full restyle + relayout
+    if let Some(css) = callback_results.stylesheet_changed.take() {
+        window.internal.stylesheet_override = Some(css);
+        return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
+    }
+
     if layout_callback_changed {
         return ProcessEventResult::ShouldRegenerateDomCurrentWindow;
     } else {
@@ -4367,6 +4583,16 @@ fn synchronize_window_state_with_os(
     current_state: &FullWindowState
 ) {
     // TODO: window.set_title
+
+    let ime_position_changed = previous_state
+        .map(|p| p.ime_position != current_state.ime_position)
+        .unwrap_or(true);
+
+    if ime_position_changed {
+        if let ImePosition::Initialized(position) = current_state.ime_position {
+            unsafe { ime::set_ime_position(window, position, current_state.size.get_hidpi_factor()); }
+        }
+    }
 }
 
 fn send_resource_updates(
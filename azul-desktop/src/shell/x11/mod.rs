@@ -27,14 +27,14 @@ use azul_core::{
         DomNodeId, DocumentId
     },
     gl::OptionGlContextPtr,
-    task::{Thread, ThreadId, Timer, TimerId},
+    task::{Instant, Thread, ThreadId, Timer, TimerId},
     ui_solver::LayoutResult,
     styled_dom::DomId,
     dom::NodeId,
     display_list::RenderCallbacks,
     window::{
         LogicalSize, Menu, MenuCallback, MenuItem,
-        MonitorVec, WindowCreateOptions, WindowInternal,
+        MonitorVec, OptionWindowTheme, WindowCreateOptions, WindowInternal,
         WindowState, FullWindowState, ScrollResult,
         MouseCursorType, CallCallbacksResult
     },
@@ -120,6 +120,10 @@ pub fn get_monitors(app: &App) -> MonitorVec {
     MonitorVec::from_const_slice(&[]) // TODO
 }
 
+pub fn get_system_theme() -> OptionWindowTheme {
+    OptionWindowTheme::None // TODO
+}
+
 // Minimal typedefs from <EGL/egl.h>
 
 type EGLDisplay = *mut c_void;
@@ -1049,6 +1053,10 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
                         let _ = r.render(framebuffer_size, 0);
                     }
 
+                    // NOTE: the X11 event loop only renders on Expose / resize,
+                    // so the RedrawBehavior is not applied yet, frames are only counted
+                    window.internal.frame_scheduler.frame_rendered(Instant::from(std::time::Instant::now()));
+
                     let swap_result = (window.egl.eglSwapBuffers)(window.egl_display, window.egl_surface);
                     if swap_result != EGL_TRUE {
                         return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
@@ -1094,6 +1102,8 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
                         let _ = r.render(framebuffer_size, 0);
                     }
 
+                    window.internal.frame_scheduler.frame_rendered(Instant::from(std::time::Instant::now()));
+
                     let swap_result = (window.egl.eglSwapBuffers)(window.egl_display, window.egl_surface);
                     if swap_result != EGL_TRUE {
                         return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
@@ -1428,8 +1438,6 @@ impl X11Window {
 
         let dpi_scale_factor = dpy.get_dpi_scale_factor();
         options.state.size.dpi = (dpi_scale_factor.max(0.0) * 96.0).round() as u32;
-        options.state.size.hidpi_factor = dpi_scale_factor;
-        options.state.size.system_hidpi_factor = dpi_scale_factor;
 
         let logical_size = options.state.size.dimensions;
         let physical_size = logical_size.to_physical(dpi_scale_factor);
@@ -1636,6 +1644,7 @@ impl X11Window {
         let fc_cache = &mut appdata_lock.fc_cache;
         let image_cache = &appdata_lock.image_cache;
         let data = &mut appdata_lock.data;
+        let redraw_behavior = appdata_lock.config.redraw_behavior;
 
         let mut initial_resource_updates = Vec::new();
         let mut internal = fc_cache.apply_closure(|fc_cache| {
@@ -1646,6 +1655,7 @@ impl X11Window {
                     window_create_options: options.clone(),
                     document_id,
                     id_namespace,
+                    redraw_behavior,
                 },
                 data,
                 image_cache,
@@ -1661,7 +1671,7 @@ impl X11Window {
                         window_state.focused_node,
                         layout_results,
                         &window_state.mouse_state.cursor_position,
-                        window_state.size.hidpi_factor,
+                        window_state.size.get_hidpi_factor(),
                     )
                 },
             )
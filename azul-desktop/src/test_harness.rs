@@ -0,0 +1,528 @@
+//! Window-less event simulation for integration tests
+//!
+//! A `TestHarness` runs the layout callback of a window without opening it
+//! and without OpenGL / WebRender. Every injected event runs the same
+//! callback -> restyle -> relayout pipeline as the event loop of a real
+//! window, so that tests can click on buttons, type text and then check the
+//! hit nodes, the layouted rectangles and the application data.
+//!
+//! NOTE: hit-testing is done on the CPU (see `FullHitTest::new`), so clip
+//! rects, scroll offsets, transforms and iframes are ignored. Timers,
+//! threads, new windows, scrolling and image updates returned by callbacks
+//! are not simulated either. Context menus are drawn on top of the DOM,
+//! see `azul_core::overlay_menu`.
+//!
+//! `serialize_layout` / `assert_layout_snapshot` print the layouted
+//! rectangles and computed styles of all nodes as text, so that tests can
+//! compare the layout against a committed golden file, see
+//! `azul_core::snapshot`.
+//!
+//! Time only passes in `advance_time`: the harness "renders" a frame
+//! (without drawing anything) whenever the `RedrawBehavior` of the app
+//! allows it, so that tests can check how many frames a window renders.
+
+use crate::app::{App, LazyFcCache};
+use azul_core::{
+    app_resources::{AppConfig, IdNamespace, ImageCache},
+    callbacks::{DocumentId, DomNodeId, RefAny, Update},
+    dom::NodeType,
+    gl::OptionGlContextPtr,
+    snapshot::diff_snapshots,
+    styled_dom::{DomId, StyledDom},
+    task::Instant,
+    ui_solver::LayoutResult,
+    window::{
+        ContextMenuChange, ContextMenuMouseButton, ContextMenuRequest, CursorPosition,
+        FullHitTest, FullWindowState, LogicalPosition, LogicalRect, LogicalSize, NextFrame,
+        RawWindowHandle, VirtualKeyCode, WindowCreateOptions, WindowInternal, WindowInternalInit,
+    },
+    window_state::{CallbacksOfHitTest, Events, NodesToCheck, StyleAndLayoutChanges},
+};
+use azul_css::{AzString, CssPropertyType};
+
+/// Application with one simulated, headless window
+#[derive(Debug)]
+pub struct TestHarness {
+    /// Application data, passed to the layout callback and all callbacks
+    pub data: RefAny,
+    pub config: AppConfig,
+    pub image_cache: ImageCache,
+    pub fc_cache: LazyFcCache,
+    /// State of the simulated window, the `current_window_state` is the
+    /// window state that the callbacks see
+    pub window: WindowInternal,
+    /// Start of the simulated clock
+    clock_start: std::time::Instant,
+    /// Simulated time since the window was created, see `advance_time`
+    elapsed_millis: u64,
+}
+
+impl TestHarness {
+    /// Lays out `root_window` as if it was opened by `App::run`.
+    /// Windows added with `App::add_window` are ignored.
+    pub fn new(app: App, root_window: WindowCreateOptions) -> Self {
+        let App {
+            mut data,
+            config,
+            image_cache,
+            mut fc_cache,
+            ..
+        } = app;
+
+        let id_namespace = IdNamespace(0);
+        let document_id = DocumentId {
+            namespace_id: id_namespace,
+            id: 0,
+        };
+
+        let window = fc_cache.apply_closure(|fc_cache| {
+            WindowInternal::new(
+                WindowInternalInit {
+                    window_create_options: root_window,
+                    document_id,
+                    id_namespace,
+                    redraw_behavior: config.redraw_behavior,
+                },
+                &mut data,
+                &image_cache,
+                &OptionGlContextPtr::None,
+                &mut Vec::new(),
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, _, layout_results| {
+                    FullHitTest::new(
+                        layout_results,
+                        &window_state.mouse_state.cursor_position,
+                        window_state.focused_node,
+                    )
+                },
+            )
+        });
+
+        let mut harness = Self {
+            data,
+            config,
+            image_cache,
+            fc_cache,
+            window,
+            clock_start: std::time::Instant::now(),
+            elapsed_millis: 0,
+        };
+
+        // the first frame, rendered when the window is shown
+        harness.render_frame_if_due();
+        harness
+    }
+
+    /// Resizes the window to `width` x `height` logical pixels
+    pub fn resize(&mut self, width: f32, height: f32) {
+        let mut new_window_state = self.window.current_window_state.clone();
+        new_window_state.size.dimensions = LogicalSize::new(width, height);
+        self.quick_resize(&new_window_state);
+        self.window.previous_window_state = Some(self.window.current_window_state.clone());
+        self.window.current_window_state = new_window_state;
+        // same as in the shell: crossing a @media breakpoint regenerates the DOM
+        if self.window.media_queries_changed() {
+            let previous_window_state = self.window.previous_window_state.take();
+            self.regenerate_dom();
+            // still fire the resize event
+            self.window.previous_window_state = previous_window_state;
+        }
+        self.window.frame_scheduler.request_redraw();
+        self.update_hit_test();
+        self.process_event();
+    }
+
+    /// Moves the cursor to (`x`, `y`), in logical pixels relative to the
+    /// top left corner of the window
+    pub fn mouse_move(&mut self, x: f32, y: f32) {
+        self.window.previous_window_state = Some(self.window.current_window_state.clone());
+        self.window.current_window_state.mouse_state.cursor_position =
+            CursorPosition::InWindow(LogicalPosition::new(x, y));
+        self.update_hit_test();
+        self.process_event();
+    }
+
+    /// Presses `button` at the current cursor position
+    pub fn mouse_down(&mut self, button: ContextMenuMouseButton) {
+        self.set_mouse_button(button, true);
+    }
+
+    /// Releases `button` at the current cursor position
+    pub fn mouse_up(&mut self, button: ContextMenuMouseButton) {
+        self.set_mouse_button(button, false);
+    }
+
+    /// Types the unicode character `c` (same as a `WM_CHAR` message),
+    /// invalid or control characters are ignored
+    pub fn key_char(&mut self, c: u32) {
+        match core::char::from_u32(c) {
+            Some(c) if !c.is_control() => {}
+            _ => return,
+        }
+
+        self.window.previous_window_state = Some(self.window.current_window_state.clone());
+        self.window.current_window_state.keyboard_state.current_char = Some(c).into();
+        self.process_event();
+
+        // release the key, so that typing the same character again
+        // is registered as a new text input event
+        self.window.current_window_state.keyboard_state.current_char = None.into();
+    }
+
+    /// Presses the key `vk` (same as a `WM_KEYDOWN` message), modifier keys
+    /// stay pressed until `key_up` is called, i.e. `key_down(LControl)`,
+    /// `key_down(S)` triggers a `Ctrl+S` accelerator
+    pub fn key_down(&mut self, vk: VirtualKeyCode) {
+        self.window.previous_window_state = Some(self.window.current_window_state.clone());
+        let keyboard_state = &mut self.window.current_window_state.keyboard_state;
+        keyboard_state.current_char = None.into();
+        keyboard_state.current_virtual_keycode = Some(vk).into();
+        keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
+        self.process_event();
+    }
+
+    /// Releases the key `vk` (same as a `WM_KEYUP` message)
+    pub fn key_up(&mut self, vk: VirtualKeyCode) {
+        self.window.previous_window_state = Some(self.window.current_window_state.clone());
+        let keyboard_state = &mut self.window.current_window_state.keyboard_state;
+        keyboard_state.current_char = None.into();
+        keyboard_state.current_virtual_keycode = None.into();
+        keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
+        self.process_event();
+    }
+
+    /// Lets `millis` milliseconds of simulated time pass, rendering at most
+    /// one frame per millisecond (so `RedrawBehavior::Unlimited` renders
+    /// 1000 frames per simulated second)
+    pub fn advance_time(&mut self, millis: u64) {
+        for _ in 0..millis {
+            self.elapsed_millis += 1;
+            self.render_frame_if_due();
+        }
+    }
+
+    /// Returns how many frames were rendered since the harness was created
+    /// (including the first frame)
+    pub fn get_frames_rendered(&self) -> usize {
+        self.window.frame_scheduler.get_frames_rendered()
+    }
+
+    /// Returns all nodes under the cursor, the topmost node is the last one
+    pub fn get_hit_nodes(&self) -> Vec<DomNodeId> {
+        use azul_core::styled_dom::NodeHierarchyItemId;
+
+        self.window
+            .current_window_state
+            .last_hit_test
+            .hovered_nodes
+            .iter()
+            .flat_map(|(dom_id, hit_test)| {
+                hit_test.regular_hit_test_nodes.keys().map(move |node_id| DomNodeId {
+                    dom: *dom_id,
+                    node: NodeHierarchyItemId::from_crate_internal(Some(*node_id)),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the layouted rectangle of a node (position relative to the
+    /// window, size without margins)
+    pub fn get_node_rect(&self, node: DomNodeId) -> Option<LogicalRect> {
+        let layout_result = self.window.layout_results.get(node.dom.inner)?;
+        let rects = layout_result.rects.as_ref();
+        let rect = rects.get(node.node.into_crate_internal()?)?;
+        Some(LogicalRect::new(rect.position.get_static_offset(), rect.size))
+    }
+
+    /// Returns the text of a text node
+    pub fn get_node_text(&self, node: DomNodeId) -> Option<AzString> {
+        let styled_dom = self.get_styled_dom(node.dom)?;
+        let node_data = styled_dom.node_data.as_container();
+        match node_data.get(node.node.into_crate_internal()?)?.get_node_type() {
+            NodeType::Text(t) => Some(t.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the current DOM of the window (`DomId::ROOT_ID`) or of an iframe
+    pub fn get_styled_dom(&self, dom_id: DomId) -> Option<&StyledDom> {
+        self.window
+            .layout_results
+            .get(dom_id.inner)
+            .map(|l| &l.styled_dom)
+    }
+
+    /// Returns the solved layout of the window (`DomId::ROOT_ID`) or of an iframe
+    pub fn get_layout_result(&self, dom_id: DomId) -> Option<&LayoutResult> {
+        self.window.layout_results.get(dom_id.inner)
+    }
+
+    /// Prints the layout of all DOMs (the window and its iframes) with the
+    /// given computed `properties`, see `LayoutResult::serialize_debug`
+    pub fn serialize_layout(&self, properties: &[CssPropertyType]) -> String {
+        let mut out = String::new();
+        for layout_result in self.window.layout_results.iter() {
+            out.push_str(&format!("# dom {}\n", layout_result.dom_id.inner));
+            out.push_str(&layout_result.serialize_debug(properties));
+        }
+        out
+    }
+
+    /// Compares `serialize_layout(properties)` against the golden file at
+    /// `golden_path` and panics with a line diff if they differ.
+    ///
+    /// If the `AZUL_UPDATE_SNAPSHOTS` environment variable is set, the file
+    /// is (over)written instead, so that intended layout changes only need
+    /// to be committed. A missing golden file is an error, otherwise a test
+    /// would silently pass on a machine that doesn't have the file.
+    pub fn assert_layout_snapshot(&self, golden_path: &str, properties: &[CssPropertyType]) {
+        let actual = self.serialize_layout(properties);
+
+        let expected = match std::env::var_os("AZUL_UPDATE_SNAPSHOTS") {
+            None => match std::fs::read_to_string(golden_path) {
+                Ok(s) => s,
+                Err(e) => panic!(
+                    "could not read layout snapshot {}: {}, \
+                     run with AZUL_UPDATE_SNAPSHOTS=1 to create it",
+                    golden_path, e
+                ),
+            },
+            Some(_) => {
+                if let Some(dir) = std::path::Path::new(golden_path).parent() {
+                    let _ = std::fs::create_dir_all(dir);
+                }
+                if let Err(e) = std::fs::write(golden_path, &actual) {
+                    panic!("could not write layout snapshot {}: {}", golden_path, e);
+                }
+                return;
+            }
+        };
+
+        if let Some(diff) = diff_snapshots(&expected, &actual) {
+            panic!(
+                "layout snapshot {} does not match (- expected, + actual), \
+                 rerun with AZUL_UPDATE_SNAPSHOTS=1 to accept the changes:\n{}",
+                golden_path, diff
+            );
+        }
+    }
+
+    /// Returns the application data
+    pub fn get_data(&mut self) -> &mut RefAny {
+        &mut self.data
+    }
+
+    fn set_mouse_button(&mut self, button: ContextMenuMouseButton, down: bool) {
+        // same as WM_RBUTTONUP in the win32 shell: releasing the button over
+        // a node with a context menu opens the menu at the cursor
+        let context_menu = if down {
+            None
+        } else {
+            let cursor = self.window.current_window_state.mouse_state.cursor_position;
+            self.window
+                .get_context_menu()
+                .filter(|(menu, _, _)| menu.context_mouse_btn == button)
+                .zip(cursor.get_position())
+                .map(|((menu, _, hit_dom_node), position)| ContextMenuRequest {
+                    menu: (**menu).clone(),
+                    position,
+                    hit_dom_node,
+                })
+        };
+
+        self.window.previous_window_state = Some(self.window.current_window_state.clone());
+        let mouse_state = &mut self.window.current_window_state.mouse_state;
+        match button {
+            ContextMenuMouseButton::Left => mouse_state.left_down = down,
+            ContextMenuMouseButton::Right => mouse_state.right_down = down,
+            ContextMenuMouseButton::Middle => mouse_state.middle_down = down,
+        }
+        self.process_event();
+
+        if let Some(request) = context_menu {
+            self.window.set_overlay_menu(ContextMenuChange::Open(request));
+            self.regenerate_dom();
+            self.window.frame_scheduler.request_redraw();
+            self.render_frame_if_due();
+        }
+    }
+
+    /// Current time of the simulated clock
+    fn now(&self) -> Instant {
+        Instant::from(self.clock_start + std::time::Duration::from_millis(self.elapsed_millis))
+    }
+
+    /// "Renders" a frame if one was requested and the frame rate limit allows it
+    fn render_frame_if_due(&mut self) {
+        let now = self.now();
+        if self.window.frame_scheduler.next_frame(&now) == NextFrame::RenderNow {
+            self.window.frame_scheduler.frame_rendered(now);
+        }
+    }
+
+    fn update_hit_test(&mut self) {
+        let current_window_state = &mut self.window.current_window_state;
+        current_window_state.last_hit_test = FullHitTest::new(
+            &self.window.layout_results,
+            &current_window_state.mouse_state.cursor_position,
+            current_window_state.focused_node,
+        );
+    }
+
+    fn quick_resize(&mut self, new_window_state: &FullWindowState) {
+        let window = &mut self.window;
+        let image_cache = &self.image_cache;
+        self.fc_cache.apply_closure(|fc_cache| {
+            window.do_quick_resize(
+                image_cache,
+                &crate::app::CALLBACKS,
+                azul_layout::do_the_relayout,
+                fc_cache,
+                &OptionGlContextPtr::None,
+                &new_window_state.size,
+                new_window_state.theme,
+            )
+        });
+    }
+
+    /// Invokes the callbacks for the difference between the previous and
+    /// the current window state, then restyles / relayouts the window
+    /// (same as `process_event` + `process_callback_results` in the shell)
+    fn process_event(&mut self) {
+        let events = Events::new(
+            &self.window.current_window_state,
+            &self.window.previous_window_state,
+        );
+
+        let nodes_to_check =
+            NodesToCheck::new(&self.window.current_window_state.last_hit_test, &events);
+
+        let window = &mut self.window;
+        let image_cache = &mut self.image_cache;
+        let config = &self.config;
+        let mut callback_results = self.fc_cache.apply_closure(|fc_cache| {
+            let mut callbacks =
+                CallbacksOfHitTest::new(&nodes_to_check, &events, &window.layout_results);
+            let current_scroll_states = window.get_current_scroll_states();
+            callbacks.call(
+                &window.previous_window_state,
+                &window.current_window_state,
+                &RawWindowHandle::Unsupported,
+                &current_scroll_states,
+                &OptionGlContextPtr::None,
+                &mut window.layout_results,
+                &mut window.scroll_states,
+                image_cache,
+                fc_cache,
+                &config.system_callbacks,
+                &window.renderer_resources,
+            )
+        });
+
+        if let Some(modified) = callback_results.modified_window_state.as_ref() {
+            let new_window_state = FullWindowState::from_window_state(
+                modified,
+                self.window.current_window_state.dropped_files.clone(),
+                self.window.current_window_state.hovered_files.clone(),
+                self.window.current_window_state.focused_node,
+                self.window.current_window_state.last_hit_test.clone(),
+            );
+            if new_window_state.size.get_layout_size()
+                != self.window.current_window_state.size.get_layout_size()
+            {
+                self.quick_resize(&new_window_state);
+            }
+            self.window.current_window_state = new_window_state;
+        }
+
+        let layout_callback_changed = self
+            .window
+            .current_window_state
+            .layout_callback_changed(&self.window.previous_window_state);
+
+        let mut regenerate_dom = layout_callback_changed;
+
+        // a new stylesheet needs a full restyle + relayout
+        if let Some(css) = callback_results.stylesheet_changed.take() {
+            self.window.stylesheet_override = Some(css);
+            regenerate_dom = true;
+        }
+
+        if let Some(change) = callback_results.context_menu.take() {
+            regenerate_dom |= self.window.set_overlay_menu(change);
+        }
+
+        match callback_results.callbacks_update_screen {
+            Update::RefreshDom | Update::RefreshDomAllWindows => regenerate_dom = true,
+            Update::DoNothing => {}
+        }
+
+        if regenerate_dom {
+            self.regenerate_dom();
+            self.window.frame_scheduler.request_redraw();
+            self.render_frame_if_due();
+            return;
+        }
+
+        let style_layout_changes = StyleAndLayoutChanges::new(
+            &nodes_to_check,
+            &mut self.window.layout_results,
+            &self.image_cache,
+            &mut self.window.renderer_resources,
+            self.window.current_window_state.size.get_layout_size(),
+            &self.window.document_id,
+            callback_results.css_properties_changed.as_ref(),
+            callback_results.words_changed.as_ref(),
+            &callback_results.update_focused_node,
+            azul_layout::do_the_relayout,
+        );
+
+        if let Some(focus_change) = style_layout_changes.focus_change.clone() {
+            self.window.current_window_state.focused_node = focus_change.new;
+        }
+
+        // nodes may have moved: update the hovered nodes for the next event
+        if style_layout_changes.did_resize_nodes() {
+            self.update_hit_test();
+        }
+
+        if !style_layout_changes.is_empty() {
+            self.window.frame_scheduler.request_redraw();
+        }
+        self.render_frame_if_due();
+    }
+
+    /// Calls the layout callback again (after a callback returned `Update::RefreshDom`)
+    fn regenerate_dom(&mut self) {
+        // same as in the shell: regenerating the DOM resets the focus
+        self.window.current_window_state.focused_node = None;
+
+        let window = &mut self.window;
+        let data = &mut self.data;
+        let image_cache = &self.image_cache;
+        self.fc_cache.apply_closure(|fc_cache| {
+            let dpi = window.get_dpi_scale_factor();
+            window.regenerate_styled_dom(
+                data,
+                image_cache,
+                &OptionGlContextPtr::None,
+                &mut Vec::new(),
+                dpi,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, _, layout_results| {
+                    FullHitTest::new(
+                        layout_results,
+                        &window_state.mouse_state.cursor_position,
+                        window_state.focused_node,
+                    )
+                },
+            );
+        });
+
+        self.window.previous_window_state = None;
+    }
+}
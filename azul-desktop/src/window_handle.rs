@@ -0,0 +1,176 @@
+//! Interop with the `raw-window-handle` crate
+//!
+//! Wraps the `RawWindowHandle` of a window (see `CallbackInfo::get_current_window_handle`)
+//! so that it can be passed to crates that take a `HasRawWindowHandle`, i.e. for
+//! native video overlays, Vulkan surfaces or platform dialogs.
+
+use azul_core::{callbacks::CallbackInfo, window::RawWindowHandle};
+use raw_window_handle as rwh;
+
+/// Native handle of a window, implements `HasRawWindowHandle` and `HasRawDisplayHandle`
+///
+/// The handle is only valid for as long as the window is open. Handles obtained
+/// in a callback should not be stored after the callback has returned.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WindowHandle {
+    handle: RawWindowHandle,
+}
+
+impl WindowHandle {
+    /// Returns `None` if the handle is `RawWindowHandle::Unsupported`
+    pub fn new(handle: RawWindowHandle) -> Option<Self> {
+        match handle {
+            RawWindowHandle::Unsupported => None,
+            handle => Some(Self { handle }),
+        }
+    }
+
+    /// Returns the handle of the window the callback was invoked on
+    pub fn from_callback_info(info: &CallbackInfo) -> Option<Self> {
+        Self::new(info.get_current_window_handle())
+    }
+
+    pub fn get_raw(&self) -> RawWindowHandle {
+        self.handle
+    }
+}
+
+unsafe impl rwh::HasRawWindowHandle for WindowHandle {
+    fn raw_window_handle(&self) -> rwh::RawWindowHandle {
+        match self.handle {
+            RawWindowHandle::IOS(h) => {
+                let mut handle = rwh::UiKitWindowHandle::empty();
+                handle.ui_window = h.ui_window;
+                handle.ui_view = h.ui_view;
+                handle.ui_view_controller = h.ui_view_controller;
+                rwh::RawWindowHandle::UiKit(handle)
+            }
+            RawWindowHandle::MacOS(h) => {
+                let mut handle = rwh::AppKitWindowHandle::empty();
+                handle.ns_window = h.ns_window;
+                handle.ns_view = h.ns_view;
+                rwh::RawWindowHandle::AppKit(handle)
+            }
+            RawWindowHandle::Xlib(h) => {
+                let mut handle = rwh::XlibWindowHandle::empty();
+                handle.window = h.window as _;
+                rwh::RawWindowHandle::Xlib(handle)
+            }
+            RawWindowHandle::Xcb(h) => {
+                let mut handle = rwh::XcbWindowHandle::empty();
+                handle.window = h.window;
+                rwh::RawWindowHandle::Xcb(handle)
+            }
+            RawWindowHandle::Wayland(h) => {
+                let mut handle = rwh::WaylandWindowHandle::empty();
+                handle.surface = h.surface;
+                rwh::RawWindowHandle::Wayland(handle)
+            }
+            RawWindowHandle::Windows(h) => {
+                let mut handle = rwh::Win32WindowHandle::empty();
+                handle.hwnd = h.hwnd;
+                handle.hinstance = h.hinstance;
+                rwh::RawWindowHandle::Win32(handle)
+            }
+            RawWindowHandle::Web(h) => {
+                let mut handle = rwh::WebWindowHandle::empty();
+                handle.id = h.id;
+                rwh::RawWindowHandle::Web(handle)
+            }
+            RawWindowHandle::Android(h) => {
+                let mut handle = rwh::AndroidNdkWindowHandle::empty();
+                handle.a_native_window = h.a_native_window;
+                rwh::RawWindowHandle::AndroidNdk(handle)
+            }
+            // filtered out in WindowHandle::new
+            RawWindowHandle::Unsupported => unreachable!(),
+        }
+    }
+}
+
+unsafe impl rwh::HasRawDisplayHandle for WindowHandle {
+    fn raw_display_handle(&self) -> rwh::RawDisplayHandle {
+        match self.handle {
+            RawWindowHandle::IOS(_) => rwh::RawDisplayHandle::UiKit(rwh::UiKitDisplayHandle::empty()),
+            RawWindowHandle::MacOS(_) => rwh::RawDisplayHandle::AppKit(rwh::AppKitDisplayHandle::empty()),
+            RawWindowHandle::Xlib(h) => {
+                let mut handle = rwh::XlibDisplayHandle::empty();
+                handle.display = h.display;
+                rwh::RawDisplayHandle::Xlib(handle)
+            }
+            RawWindowHandle::Xcb(h) => {
+                let mut handle = rwh::XcbDisplayHandle::empty();
+                handle.connection = h.connection;
+                rwh::RawDisplayHandle::Xcb(handle)
+            }
+            RawWindowHandle::Wayland(h) => {
+                let mut handle = rwh::WaylandDisplayHandle::empty();
+                handle.display = h.display;
+                rwh::RawDisplayHandle::Wayland(handle)
+            }
+            RawWindowHandle::Windows(_) => rwh::RawDisplayHandle::Windows(rwh::WindowsDisplayHandle::empty()),
+            RawWindowHandle::Web(_) => rwh::RawDisplayHandle::Web(rwh::WebDisplayHandle::empty()),
+            RawWindowHandle::Android(_) => rwh::RawDisplayHandle::Android(rwh::AndroidDisplayHandle::empty()),
+            RawWindowHandle::Unsupported => unreachable!(),
+        }
+    }
+}
+
+#[cfg(all(test, target_os = "windows"))]
+mod tests {
+    use super::*;
+    use azul_core::window::WindowsHandle;
+    use raw_window_handle::HasRawWindowHandle;
+
+    fn encode_wide(s: &str) -> Vec<u16> {
+        s.encode_utf16().chain(Some(0)).collect()
+    }
+
+    #[test]
+    fn test_win32_handle_round_trip() {
+        use core::ptr;
+        use winapi::um::libloaderapi::GetModuleHandleW;
+        use winapi::um::winuser::{CreateWindowExW, DestroyWindow, GetWindowTextW};
+
+        let class_name = encode_wide("STATIC");
+        let title = encode_wide("azul window handle test");
+
+        unsafe {
+            let hinstance = GetModuleHandleW(ptr::null());
+            let hwnd = CreateWindowExW(
+                0,
+                class_name.as_ptr(),
+                title.as_ptr(),
+                0,
+                0,
+                0,
+                100,
+                100,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                hinstance,
+                ptr::null_mut(),
+            );
+            assert!(!hwnd.is_null());
+
+            let handle = WindowHandle::new(RawWindowHandle::Windows(WindowsHandle {
+                hwnd: hwnd as *mut _,
+                hinstance: hinstance as *mut _,
+            }))
+            .unwrap();
+
+            let win32 = match handle.raw_window_handle() {
+                rwh::RawWindowHandle::Win32(h) => h,
+                other => panic!("expected a Win32 handle, got {:?}", other),
+            };
+
+            let mut buf = [0u16; 64];
+            let len = GetWindowTextW(win32.hwnd as _, buf.as_mut_ptr(), buf.len() as i32);
+            assert_eq!(String::from_utf16_lossy(&buf[..len as usize]), "azul window handle test");
+
+            DestroyWindow(hwnd);
+        }
+
+        assert_eq!(WindowHandle::new(RawWindowHandle::Unsupported), None);
+    }
+}
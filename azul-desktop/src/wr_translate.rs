@@ -132,6 +132,11 @@ use webrender::Renderer;
 use alloc::sync::Arc;
 use core::mem;
 
+/// `em` lengths in the display list are already converted to pixels, relative to the
+/// font size of each node (see the `resolve_em` functions in `azul_core::display_list`),
+/// so the default font size is only used for values that never contain `em`
+const DISPLAY_LIST_EM_SIZE: f32 = azul_css::EM_HEIGHT;
+
 pub enum AsyncHitTester {
     Requested(WrHitTesterRequest),
     Resolved(Arc<dyn WrApiHitTester>),
@@ -360,7 +365,6 @@ pub(crate) fn wr_synchronize_updated_images(
 }
 
 /// Returns the size fo the built display list
-#[cfg(not(test))]
 pub(crate) fn rebuild_display_list(
     internal: &mut WindowInternal,
     render_api: &mut WrRenderApi,
@@ -411,6 +415,11 @@ pub(crate) fn rebuild_display_list(
             .filter_map(wr_translate_resource_update)
             .collect(),
     );
+
+    // upload the pixels of images that were updated with ImageCache::update_css_image_raw
+    let updated_images = internal.renderer_resources.update_changed_css_images(image_cache);
+    wr_synchronize_updated_images(updated_images, &internal.document_id, &mut txn);
+
     txn.set_display_list(
         wr_translate_epoch(internal.epoch),
         None,
@@ -798,17 +807,17 @@ pub fn wr_translate_border_radius(border_radius: StyleBorderRadius, rect_size: L
 
     // The "w / h" is necessary to convert percentage-based values into pixels, for example "border-radius: 50%;"
 
-    let top_left_px_h = top_left.and_then(|tl| tl.get_property_or_default()).unwrap_or_default().inner.to_pixels(w);
-    let top_left_px_v = top_left.and_then(|tl| tl.get_property_or_default()).unwrap_or_default().inner.to_pixels(h);
+    let top_left_px_h = top_left.and_then(|tl| tl.get_property_or_default()).unwrap_or_default().inner.to_pixels_em_aware(w, DISPLAY_LIST_EM_SIZE);
+    let top_left_px_v = top_left.and_then(|tl| tl.get_property_or_default()).unwrap_or_default().inner.to_pixels_em_aware(h, DISPLAY_LIST_EM_SIZE);
 
-    let top_right_px_h = top_right.and_then(|tr| tr.get_property_or_default()).unwrap_or_default().inner.to_pixels(w);
-    let top_right_px_v = top_right.and_then(|tr| tr.get_property_or_default()).unwrap_or_default().inner.to_pixels(h);
+    let top_right_px_h = top_right.and_then(|tr| tr.get_property_or_default()).unwrap_or_default().inner.to_pixels_em_aware(w, DISPLAY_LIST_EM_SIZE);
+    let top_right_px_v = top_right.and_then(|tr| tr.get_property_or_default()).unwrap_or_default().inner.to_pixels_em_aware(h, DISPLAY_LIST_EM_SIZE);
 
-    let bottom_left_px_h = bottom_left.and_then(|bl| bl.get_property_or_default()).unwrap_or_default().inner.to_pixels(w);
-    let bottom_left_px_v = bottom_left.and_then(|bl| bl.get_property_or_default()).unwrap_or_default().inner.to_pixels(h);
+    let bottom_left_px_h = bottom_left.and_then(|bl| bl.get_property_or_default()).unwrap_or_default().inner.to_pixels_em_aware(w, DISPLAY_LIST_EM_SIZE);
+    let bottom_left_px_v = bottom_left.and_then(|bl| bl.get_property_or_default()).unwrap_or_default().inner.to_pixels_em_aware(h, DISPLAY_LIST_EM_SIZE);
 
-    let bottom_right_px_h = bottom_right.and_then(|br| br.get_property_or_default()).unwrap_or_default().inner.to_pixels(w);
-    let bottom_right_px_v = bottom_right.and_then(|br| br.get_property_or_default()).unwrap_or_default().inner.to_pixels(h);
+    let bottom_right_px_h = bottom_right.and_then(|br| br.get_property_or_default()).unwrap_or_default().inner.to_pixels_em_aware(w, DISPLAY_LIST_EM_SIZE);
+    let bottom_right_px_v = bottom_right.and_then(|br| br.get_property_or_default()).unwrap_or_default().inner.to_pixels_em_aware(h, DISPLAY_LIST_EM_SIZE);
 
     WrBorderRadius {
         top_left: WrLayoutSize::new(top_left_px_h as f32, top_left_px_v as f32),
@@ -1128,7 +1137,7 @@ fn push_display_list_msg(
     document_id: DocumentId,
     render_api: &mut WrRenderApi,
     builder: &mut WrDisplayListBuilder,
-    msg: DisplayListMsg,
+    mut msg: DisplayListMsg,
     parent_spatial_id: WrSpatialId,
     parent_clip_id: WrClipId,
     positioned_items: &mut Vec<(WrSpatialId, WrClipId)>,
@@ -1164,6 +1173,8 @@ fn push_display_list_msg(
         },
     };
 
+    let (relative_x, relative_y) = snap_to_device_pixels(&mut msg, relative_x, relative_y);
+
     // All rectangles are transformed in relation to the parent node,
     // so we have to push the parent as a "reference frame", optionally
     // adding an (animatable) transformation on top
@@ -1171,7 +1182,8 @@ fn push_display_list_msg(
     let opacity = msg.get_opacity_key();
     let mix_blend_mode = msg.get_mix_blend_mode();
     let has_mix_blend_mode_children = msg.has_mix_blend_mode_children();
-    let should_push_stacking_context = transform.is_some() || opacity.is_some() || mix_blend_mode.is_some() || has_mix_blend_mode_children;
+    let is_isolated = msg.is_isolated();
+    let should_push_stacking_context = transform.is_some() || opacity.is_some() || mix_blend_mode.is_some() || has_mix_blend_mode_children || is_isolated;
 
     let property_binding = match transform {
         Some(s) => WrPropertyBinding::Binding(
@@ -1209,7 +1221,7 @@ fn push_display_list_msg(
         // let backdrop_filters = ...
 
         let mut stacking_context_flags = WrStackingContextFlags::empty();
-        if has_mix_blend_mode_children {
+        if has_mix_blend_mode_children || is_isolated {
             stacking_context_flags.set(WrStackingContextFlags::IS_BLEND_CONTAINER, true);
         }
 
@@ -1300,6 +1312,30 @@ fn push_display_list_msg(
     builder.pop_reference_frame();
 }
 
+/// Snaps the edges of the rect to whole device pixels, so that adjacent
+/// rects don't have seams or overlaps. Returns the snapped (relative) origin.
+///
+/// The display list is already scaled to physical pixels at this point
+/// (see `CachedDisplayList::scale_for_dpi`), so the DPI factor is 1.0
+fn snap_to_device_pixels(msg: &mut DisplayListMsg, x: f32, y: f32) -> (f32, f32) {
+    use azul_css::{LayoutRectF32, PixelSnap, SnapPolicy};
+
+    let size = match msg {
+        DisplayListMsg::IFrame(_, clip_size, _, _) => clip_size,
+        DisplayListMsg::Frame(f) => &mut f.size,
+        DisplayListMsg::ScrollFrame(sf) => &mut sf.frame.size,
+    };
+
+    let snapped = PixelSnap::snap_rect(
+        LayoutRectF32::new(x, y, size.width, size.height),
+        1.0,
+        SnapPolicy::Round,
+    );
+    size.width = snapped.width;
+    size.height = snapped.height;
+    (snapped.x, snapped.y)
+}
+
 #[inline]
 fn push_frame(
     document_id: DocumentId,
@@ -1555,9 +1591,12 @@ fn push_display_list_content(
                         WrTransformStyle::Flat,
                         WrMixBlendMode::Normal,
                         &[WrFilterOp::DropShadow(WrShadow {
-                            offset: WrLayoutVector2D::new(offset[0].to_pixels(), offset[1].to_pixels()),
+                            offset: WrLayoutVector2D::new(
+                                offset[0].to_pixels_em_aware(DISPLAY_LIST_EM_SIZE),
+                                offset[1].to_pixels_em_aware(DISPLAY_LIST_EM_SIZE),
+                            ),
                             color: wr_translate_color_f(color.clone().into()),
-                            blur_radius: blur_radius.to_pixels(),
+                            blur_radius: blur_radius.to_pixels_em_aware(DISPLAY_LIST_EM_SIZE),
                         })],
                         &[],
                         &[],
@@ -1572,19 +1611,19 @@ fn push_display_list_content(
                     builder.pop_stacking_context();
                 }
             },
-            Background { content, size, offset, repeat  } => {
+            Background { content, size, offset, repeat, image_rendering } => {
                 let mut background_info = normal_info.clone();
                 background_info.clip_id = content_clip.get_or_insert_with(|| {
                     define_border_radius_clip(builder, clip_rect, wr_border_radius, normal_info.spatial_id, parent_clip_id)
                 }).clone();
-                background::push_background(builder, &background_info, content, *size, *offset, *repeat);
+                background::push_background(builder, &background_info, content, *size, *offset, *repeat, *image_rendering);
             },
             Image { size, offset, image_rendering, alpha_type, image_key, background_color } => {
                 let mut image_info = normal_info.clone();
                 image_info.clip_id = content_clip.get_or_insert_with(|| {
                     define_border_radius_clip(builder, clip_rect, wr_border_radius, normal_info.spatial_id, parent_clip_id)
                 }).clone();
-                image::push_image(builder, &image_info, *size, *offset, *image_key, *alpha_type, *image_rendering, *background_color);
+                image::push_image(builder, &image_info, *size, LogicalSize::zero(), *offset, *image_key, *alpha_type, *image_rendering, *background_color);
             },
             Border { widths, colors, styles } => {
                 // no clip necessary because item will always be in parent bounds
@@ -1659,10 +1698,10 @@ mod background {
     };
     use azul_css::{
         StyleBackgroundSize, StyleBackgroundPosition, StyleBackgroundRepeat,
-        RadialGradient, LinearGradient, ConicGradient, ColorU, LayoutSize, LayoutPoint,
+        RadialGradient, LinearGradient, ConicGradient, ColorU, LayoutSize, LayoutPoint, LayoutRect,
     };
     use azul_core::{
-        display_list::RectBackground,
+        display_list::{ImageRendering, RectBackground},
         window::{LogicalSize, LogicalPosition},
         app_resources::ImageKey,
     };
@@ -1681,6 +1720,7 @@ mod background {
         background_size: Option<StyleBackgroundSize>,
         background_position: Option<StyleBackgroundPosition>,
         background_repeat: Option<StyleBackgroundRepeat>,
+        image_rendering: ImageRendering,
     ) {
         use azul_core::display_list::RectBackground::*;
 
@@ -1690,7 +1730,7 @@ mod background {
             LinearGradient(g)    => push_linear_gradient_background(builder, &info, g.clone(), background_position, background_size, content_size),
             RadialGradient(rg)   => push_radial_gradient_background(builder, &info, rg.clone(), background_position, background_size, content_size),
             ConicGradient(cg)    => push_conic_gradient_background(builder, &info, cg.clone(), background_position, background_size, content_size),
-            Image((key, _))      => push_image_background(builder, &info, *key, background_position, background_size, background_repeat, content_size),
+            Image((key, _))      => push_image_background(builder, info, *key, background_position, background_size, background_repeat, content_size, image_rendering),
             Color(col)           => push_color_background(builder, &info, *col, background_position, background_size, background_repeat, content_size),
         }
     }
@@ -1726,7 +1766,7 @@ mod background {
 
         if stops.len() < 2 { return; }
 
-        let center = calculate_background_position(width, height, conic_gradient.center, background_size);
+        let center = calculate_gradient_center(conic_gradient.center, background_size);
         let center = WrLayoutPoint::new(center.x, center.y);
 
         let gradient = builder.create_conic_gradient(
@@ -1768,7 +1808,7 @@ mod background {
         offset_info.clip_rect.min.x += offset.x;
         offset_info.clip_rect.min.y += offset.y;
 
-        let center = calculate_background_position(width, height, radial_gradient.position, background_size);
+        let center = calculate_gradient_center(radial_gradient.position, background_size);
         let center = WrLayoutPoint::new(center.x, center.y);
 
         let stops: Vec<WrGradientStop> = radial_gradient.stops.iter().map(|gradient_pre|
@@ -1862,8 +1902,9 @@ mod background {
         background_size: Option<StyleBackgroundSize>,
         background_repeat: Option<StyleBackgroundRepeat>,
         content_size: Option<(f32, f32)>,
+        image_rendering: ImageRendering,
     ) {
-        use azul_core::display_list::{AlphaType, ImageRendering};
+        use azul_core::display_list::AlphaType;
 
         let background_position = background_position.unwrap_or_default();
         let background_repeat = background_repeat.unwrap_or_default();
@@ -1877,12 +1918,29 @@ mod background {
         );
         let background_repeat_info = get_background_repeat_info(info, background_repeat, background_size);
 
+        // "space" and "round" change the tile size / the space between the tiles
+        let (background_size, tile_spacing) = match background_repeat {
+            StyleBackgroundRepeat::Space | StyleBackgroundRepeat::Round => {
+                let tiling = background_repeat.compute_tiles(
+                    LayoutSize::round(background_size.width, background_size.height),
+                    LayoutRect::new(
+                        LayoutPoint::zero(),
+                        LayoutSize::round(clip_rect_size.width, clip_rect_size.height),
+                    ),
+                );
+                (
+                    LogicalSize::new(tiling.tile_width, tiling.tile_height),
+                    LogicalSize::new(tiling.gap_x, tiling.gap_y),
+                )
+            },
+            _ => (background_size, LogicalSize::zero()),
+        };
+
         // TODO: customize this for image backgrounds?
         let alpha_type = AlphaType::PremultipliedAlpha;
-        let image_rendering = ImageRendering::Auto;
         let background_color = ColorU { r: 0, g: 0, b: 0, a: 255 };
 
-        image::push_image(builder, &background_repeat_info, background_size, background_position, image_key, alpha_type, image_rendering, background_color);
+        image::push_image(builder, &background_repeat_info, background_size, tile_spacing, background_position, image_key, alpha_type, image_rendering, background_color);
     }
 
     fn push_color_background(
@@ -1936,7 +1994,7 @@ mod background {
                 ),
                 .. *info
             },
-            Repeat => *info,
+            Repeat | Space | Round => *info,
             RepeatX => WrCommonItemProperties {
                 clip_rect: WrLayoutRect::from_origin_and_size(
                     WrLayoutPoint::new(info.clip_rect.min.x, info.clip_rect.min.y),
@@ -1979,8 +2037,8 @@ mod background {
 
         let ratio = match bg_size {
             StyleBackgroundSize::ExactSize([w, h]) => {
-                let w = w.to_pixels(clip_rect_size.width);
-                let h = h.to_pixels(clip_rect_size.height);
+                let w = w.to_pixels_em_aware(clip_rect_size.width, super::DISPLAY_LIST_EM_SIZE);
+                let h = h.to_pixels_em_aware(clip_rect_size.height, super::DISPLAY_LIST_EM_SIZE);
                 w.min(h)
             },
             StyleBackgroundSize::Contain => content_aspect_ratio.width.min(content_aspect_ratio.height),
@@ -1990,32 +2048,30 @@ mod background {
         LogicalSize::new(content_size.0 * ratio, content_size.1 * ratio)
     }
 
-    /// Transforma background-position attribute into pixel coordinates
+    /// Offset of the background image inside of the (`width` x `height`)
+    /// background area, see `StyleBackgroundPosition::resolve`
     fn calculate_background_position(
         width: f32,
         height: f32,
         background_position: StyleBackgroundPosition,
         background_size: LogicalSize,
     ) -> LogicalPosition {
+        LogicalPosition {
+            x: background_position.horizontal.resolve(width, background_size.width, super::DISPLAY_LIST_EM_SIZE),
+            y: background_position.vertical.resolve(height, background_size.height, super::DISPLAY_LIST_EM_SIZE),
+        }
+    }
 
-        use azul_css::BackgroundPositionVertical;
-        use azul_css::BackgroundPositionHorizontal;
-
-        let horizontal_offset = match background_position.horizontal {
-            BackgroundPositionHorizontal::Right => 0.0,
-            BackgroundPositionHorizontal::Center => (width - background_size.width) / 2.0,
-            BackgroundPositionHorizontal::Left => (width - background_size.width),
-            BackgroundPositionHorizontal::Exact(e) => e.to_pixels(width),
-        };
-
-        let vertical_offset = match background_position.vertical {
-            BackgroundPositionVertical::Top => 0.0,
-            BackgroundPositionVertical::Center => (height - background_size.height) / 2.0,
-            BackgroundPositionVertical::Bottom => (height - background_size.height),
-            BackgroundPositionVertical::Exact(e) => e.to_pixels(height),
-        };
-
-        LogicalPosition { x: horizontal_offset, y: vertical_offset }
+    /// Center of a radial / conic gradient: a point inside of the gradient
+    /// box, so percentages resolve against the whole box
+    fn calculate_gradient_center(
+        position: StyleBackgroundPosition,
+        background_size: LogicalSize,
+    ) -> LogicalPosition {
+        LogicalPosition {
+            x: position.horizontal.resolve(background_size.width, 0.0, super::DISPLAY_LIST_EM_SIZE),
+            y: position.vertical.resolve(background_size.height, 0.0, super::DISPLAY_LIST_EM_SIZE),
+        }
     }
 }
 
@@ -2037,6 +2093,7 @@ mod image {
         builder: &mut WrDisplayListBuilder,
         info: &WrCommonItemProperties,
         size: LogicalSize,
+        tile_spacing: LogicalSize,
         offset: LogicalPosition,
         image_key: ImageKey,
         alpha_type: AlphaType,
@@ -2047,19 +2104,16 @@ mod image {
             wr_translate_image_rendering, wr_translate_alpha_type,
             wr_translate_color_u, wr_translate_image_key, wr_translate_logical_size,
         };
-        use webrender::api::units::LayoutSize as WrLayoutSize;
 
         let mut offset_info = *info;
         offset_info.clip_rect.min.x += offset.x;
         offset_info.clip_rect.min.y += offset.y;
 
-        let tile_spacing = WrLayoutSize::zero();
-
         builder.push_repeating_image(
             &offset_info,
             offset_info.clip_rect,
             wr_translate_logical_size(size),
-            tile_spacing,
+            wr_translate_logical_size(tile_spacing),
             wr_translate_image_rendering(image_rendering),
             wr_translate_alpha_type(alpha_type),
             wr_translate_image_key(image_key),
@@ -2075,6 +2129,7 @@ mod box_shadow {
         display_list::{BoxShadow, StyleBorderRadius},
         window::LogicalRect,
         window::LogicalSize,
+        window::LogicalPosition,
     };
     use webrender::api::{
         ClipId as WrClipId,
@@ -2105,12 +2160,12 @@ mod box_shadow {
         parent_clip_id: WrClipId,
     ) {
         use self::ShouldPushShadow::*;
-        use azul_css::CssPropertyValue;
+        use azul_css::{CssPropertyValue, StyleBoxShadowValue};
 
         let BoxShadow { clip_mode, top, left, bottom, right } = box_shadow;
 
-        fn translate_shadow_side(input: &Option<CssPropertyValue<StyleBoxShadow>>) -> Option<StyleBoxShadow> {
-            input.and_then(|prop| prop.get_property().cloned())
+        fn translate_shadow_side(input: &Option<StyleBoxShadowValue>) -> Option<StyleBoxShadow> {
+            input.as_ref().and_then(|prop| prop.get_property().map(|p| **p))
         }
 
         let (top, left, bottom, right) = (
@@ -2210,7 +2265,7 @@ mod box_shadow {
         parent_clip_id: WrClipId,
     ) {
         let is_inset_shadow = current_shadow.clip_mode == BoxShadowClipMode::Inset;
-        let origin_displace = (current_shadow.spread_radius.to_pixels() + current_shadow.blur_radius.to_pixels()) * 2.0;
+        let origin_displace = current_shadow.get_blur_extent(super::DISPLAY_LIST_EM_SIZE).max(0.0);
 
         let mut shadow_bounds = bounds;
         let mut clip_rect = bounds;
@@ -2305,10 +2360,13 @@ mod box_shadow {
         builder.push_box_shadow(
             &info,
             wr_translate_logical_rect(bounds),
-            LayoutVector2D::new(pre_shadow.offset[0].to_pixels(), pre_shadow.offset[1].to_pixels()),
+            LayoutVector2D::new(
+                pre_shadow.offset[0].to_pixels_em_aware(super::DISPLAY_LIST_EM_SIZE),
+                pre_shadow.offset[1].to_pixels_em_aware(super::DISPLAY_LIST_EM_SIZE),
+            ),
             wr_translate_color_f(apply_gamma(pre_shadow.color.into())),
-            pre_shadow.blur_radius.to_pixels(),
-            pre_shadow.spread_radius.to_pixels(),
+            pre_shadow.blur_radius.to_pixels_em_aware(super::DISPLAY_LIST_EM_SIZE),
+            pre_shadow.spread_radius.to_pixels_em_aware(super::DISPLAY_LIST_EM_SIZE),
             wr_translate_border_radius(border_radius, bounds.size),
             wr_translate_box_shadow_clip_mode(pre_shadow.clip_mode)
         );
@@ -2331,6 +2389,7 @@ mod box_shadow {
         }
     }
 
+    // Same as StyleBoxShadow::get_clip_rect, but without rounding to whole pixels
     fn get_clip_rect(pre_shadow: &StyleBoxShadow, bounds: LogicalRect) -> LogicalRect {
         if pre_shadow.clip_mode == BoxShadowClipMode::Inset {
             // inset shadows do not work like outset shadows
@@ -2339,15 +2398,17 @@ mod box_shadow {
             bounds
         } else {
             // calculate the maximum extent of the outset shadow
-            let mut clip_rect = bounds;
-
-            let origin_displace = (pre_shadow.spread_radius.to_pixels() + pre_shadow.blur_radius.to_pixels()) * 2.0;
-            clip_rect.origin.x = clip_rect.origin.x - pre_shadow.offset[0].to_pixels() - origin_displace;
-            clip_rect.origin.y = clip_rect.origin.y - pre_shadow.offset[1].to_pixels() - origin_displace;
-
-            clip_rect.size.height = clip_rect.size.height + (origin_displace * 2.0);
-            clip_rect.size.width = clip_rect.size.width + (origin_displace * 2.0);
-            clip_rect
+            let inflation = pre_shadow.get_bounds_inflation(super::DISPLAY_LIST_EM_SIZE);
+            LogicalRect::new(
+                LogicalPosition::new(
+                    bounds.origin.x - inflation.left.get(),
+                    bounds.origin.y - inflation.top.get(),
+                ),
+                LogicalSize::new(
+                    bounds.size.width + inflation.horizontal(),
+                    bounds.size.height + inflation.vertical(),
+                ),
+            )
         }
     }
 
@@ -2364,7 +2425,8 @@ mod border {
         BorderSide as WrBorderSide,
     };
     use azul_css::{
-        LayoutSize, BorderStyle, BorderStyleNoNone, CssPropertyValue, PixelValue
+        LayoutSize, BorderStyle, BorderStyleNoNone, CssPropertyValue, PixelValue,
+        LayoutSideOffsets as CssLayoutSideOffsets, PixelSnap,
     };
     use azul_core::{
         display_list::{StyleBorderRadius, StyleBorderWidths, StyleBorderColors, StyleBorderStyles},
@@ -2385,12 +2447,12 @@ mod border {
         widths: StyleBorderWidths,
         colors: StyleBorderColors,
         styles: StyleBorderStyles,
-        current_hidpi_factor: f32,
+        _current_hidpi_factor: f32,
     ) {
         let clip_rect_size = info.clip_rect.size();
         let rect_size = LogicalSize::new(clip_rect_size.width, clip_rect_size.height);
 
-        if let Some((border_widths, border_details)) = get_webrender_border(rect_size, radii, widths, colors, styles, current_hidpi_factor) {
+        if let Some((border_widths, border_details)) = get_webrender_border(rect_size, radii, widths, colors, styles) {
             builder.push_border(&info, info.clip_rect, border_widths, border_details);
         }
     }
@@ -2404,7 +2466,6 @@ mod border {
         widths: StyleBorderWidths,
         colors: StyleBorderColors,
         styles: StyleBorderStyles,
-        hidpi: f32,
     ) -> Option<(WrLayoutSideOffsets, WrBorderDetails)> {
 
         use super::{wr_translate_color_u, wr_translate_border_radius};
@@ -2456,14 +2517,20 @@ mod border {
            colors.left.and_then(|cl| cl.get_property_or_default()).unwrap_or_default(),
         );
 
-        // NOTE: if the HiDPI factor is not set to an even number, this will result
-        // in uneven border widths. In order to reduce this bug, we multiply the border width
-        // with the HiDPI factor, then round the result (to get an even number), then divide again
+        // NOTE: the widths are already scaled to physical pixels (see
+        // `StyleBorderWidths::scale_for_dpi`), round them to whole device pixels
+        // so that borders don't render with uneven widths at fractional HiDPI factors
+        let border_widths = PixelSnap::snap_border_widths(&CssLayoutSideOffsets::new(
+            width_top.map(|v| v.to_pixels_em_aware(rect_size.height, super::DISPLAY_LIST_EM_SIZE)).unwrap_or(0.0),
+            width_right.map(|v| v.to_pixels_em_aware(rect_size.width, super::DISPLAY_LIST_EM_SIZE)).unwrap_or(0.0),
+            width_bottom.map(|v| v.to_pixels_em_aware(rect_size.height, super::DISPLAY_LIST_EM_SIZE)).unwrap_or(0.0),
+            width_left.map(|v| v.to_pixels_em_aware(rect_size.width, super::DISPLAY_LIST_EM_SIZE)).unwrap_or(0.0),
+        ), 1.0);
         let border_widths = WrLayoutSideOffsets::new(
-            width_top.map(|v| (v.to_pixels(rect_size.height) * hidpi).floor() / hidpi).unwrap_or(0.0),
-            width_right.map(|v| (v.to_pixels(rect_size.width) * hidpi).floor() / hidpi).unwrap_or(0.0),
-            width_bottom.map(|v| (v.to_pixels(rect_size.height) * hidpi).floor() / hidpi).unwrap_or(0.0),
-            width_left.map(|v| (v.to_pixels(rect_size.width) * hidpi).floor() / hidpi).unwrap_or(0.0),
+            border_widths.top.get(),
+            border_widths.right.get(),
+            border_widths.bottom.get(),
+            border_widths.left.get(),
         );
 
         let border_details = WrBorderDetails::Normal(WrNormalBorder {
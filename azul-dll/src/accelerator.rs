@@ -0,0 +1,108 @@
+//! Keyboard shortcuts for language bindings
+//!
+//! An accelerator (i.e. `Ctrl+S`) belongs to the window instead of a node,
+//! so it fires regardless of which node is focused. Accelerators run before
+//! the `VirtualKeyDown` callbacks of the nodes: if the callback returns
+//! anything other than `DoNothing`, the nodes don't see the key.
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        az_test_harness, AzAccelerator_new, AzApp, AzApp_createTestHarness, AzModifiers,
+        AzTestHarnessPtr, AzTestHarness_getData, AzTestHarness_keyDown, AzTestHarness_keyUp,
+        AzVirtualKeyCode, AzWindowCreateOptions, AzWindowCreateOptions_addAccelerator,
+    };
+    use azul_impl::{
+        callbacks::{Callback, CallbackInfo, LayoutCallbackInfo, RefAny, Update},
+        css::Css,
+        dom::{CallbackData, Dom, On},
+        resources::{AppConfig, LayoutSolverVersion},
+        styled_dom::StyledDom,
+    };
+
+    #[derive(Default)]
+    struct DataModel {
+        saved: usize,
+        refreshed: usize,
+        node_key_downs: usize,
+    }
+
+    extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+        Dom::body()
+            .with_callbacks(
+                vec![CallbackData {
+                    event: On::VirtualKeyDown.into(),
+                    callback: Callback { cb: on_node_key_down },
+                    data: data.clone(),
+                }]
+                .into(),
+            )
+            .style(Css::empty())
+    }
+
+    extern "C" fn on_node_key_down(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        match data.downcast_mut::<DataModel>() {
+            Some(mut d) => d.node_key_downs += 1,
+            None => {}
+        }
+        Update::DoNothing
+    }
+
+    extern "C" fn on_save(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        match data.downcast_mut::<DataModel>() {
+            Some(mut d) => d.saved += 1,
+            None => return Update::DoNothing,
+        }
+        Update::RefreshDom
+    }
+
+    extern "C" fn on_refresh(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        match data.downcast_mut::<DataModel>() {
+            Some(mut d) => d.refreshed += 1,
+            None => return Update::DoNothing,
+        }
+        Update::RefreshDom
+    }
+
+    fn counts(harness: AzTestHarnessPtr) -> (usize, usize, usize) {
+        unsafe { AzTestHarness_getData(harness) }
+            .downcast_ref::<DataModel>()
+            .map(|d| (d.saved, d.refreshed, d.node_key_downs))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_accelerator_consumes_key() {
+        let data = RefAny::new(DataModel::default());
+        let app = AzApp::new(data.clone(), AppConfig::new(LayoutSolverVersion::Default));
+        let mut window = AzWindowCreateOptions::new(layout);
+        let ctrl_s = AzAccelerator_new(AzModifiers::CTRL, AzVirtualKeyCode::S);
+        AzWindowCreateOptions_addAccelerator(&mut window, ctrl_s, data.clone(), on_save);
+        let f5 = AzAccelerator_new(AzModifiers::NONE, AzVirtualKeyCode::F5);
+        AzWindowCreateOptions_addAccelerator(&mut window, f5, data, on_refresh);
+
+        let harness = AzApp_createTestHarness(&app, window);
+        assert!(!harness.is_null());
+
+        // Ctrl+S fires once, S without Ctrl doesn't
+        unsafe {
+            AzTestHarness_keyDown(harness, AzVirtualKeyCode::LControl);
+            AzTestHarness_keyDown(harness, AzVirtualKeyCode::S);
+            AzTestHarness_keyUp(harness, AzVirtualKeyCode::S);
+            AzTestHarness_keyUp(harness, AzVirtualKeyCode::LControl);
+            AzTestHarness_keyDown(harness, AzVirtualKeyCode::S);
+            AzTestHarness_keyUp(harness, AzVirtualKeyCode::S);
+        }
+        assert_eq!(counts(harness).0, 1);
+
+        // the S above reached the node, the F5 is consumed by the accelerator
+        let node_key_downs = counts(harness).2;
+        unsafe {
+            AzTestHarness_keyDown(harness, AzVirtualKeyCode::F5);
+            AzTestHarness_keyUp(harness, AzVirtualKeyCode::F5);
+        }
+        assert_eq!(counts(harness), (1, 1, node_key_downs));
+
+        unsafe { az_test_harness::delete(harness) };
+    }
+}
@@ -0,0 +1,52 @@
+//! Running the application from language bindings
+//!
+//! `AzApp_run` mirrors `App::run` and never returns a status. C hosts
+//! that want to propagate the exit code of the event loop can use
+//! `AzApp_runReturnCode` instead.
+
+#[cfg(test)]
+mod tests {
+    use crate::{AzApp, AzApp_runReturnCode, AzWindowCreateOptions};
+    use azul_impl::{
+        callbacks::{Callback, CallbackInfo, LayoutCallbackInfo, OptionCallback, RefAny, Update},
+        resources::{AppConfig, LayoutSolverVersion},
+        styled_dom::StyledDom,
+    };
+
+    extern "C" fn layout(_: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+        StyledDom::default()
+    }
+
+    extern "C" fn close_window(_: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        let mut state = info.get_current_window_state();
+        state.flags.is_about_to_close = true;
+        info.set_window_state(state);
+        Update::DoNothing
+    }
+
+    #[test]
+    fn test_run_return_code_while_running() {
+        let app = AzApp::new(RefAny::new(0_usize), AppConfig::new(LayoutSolverVersion::Default));
+        // same as calling it from a callback of the running app
+        let _running = app.ptr.lock().unwrap();
+        assert_eq!(AzApp_runReturnCode(&app, AzWindowCreateOptions::new(layout)), -1);
+    }
+
+    // AppKit can only run on the main thread, cargo runs tests on other threads
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn test_run_return_code_on_close() {
+        // opens a window: skipped on headless X11 machines
+        #[cfg(target_os = "linux")]
+        {
+            if std::env::var_os("DISPLAY").is_none() {
+                return;
+            }
+        }
+
+        let app = AzApp::new(RefAny::new(0_usize), AppConfig::new(LayoutSolverVersion::Default));
+        let mut window = AzWindowCreateOptions::new(layout);
+        window.create_callback = OptionCallback::Some(Callback { cb: close_window });
+        assert_eq!(AzApp_runReturnCode(&app, window), 0);
+    }
+}
@@ -0,0 +1,34 @@
+//! CSS variables for language bindings
+//!
+//! Switching between a dark and a light theme only needs to change the
+//! value of a `var(--name)` instead of parsing a new stylesheet. The new
+//! value (`AzCss_setVariable`) is applied when the CSS is used to style
+//! a DOM again.
+
+#[cfg(test)]
+mod tests {
+    use crate::{AzCss, AzCss_resolveVariables, AzCss_setVariable, AzCssProperty};
+    use azul_impl::css::{ColorU, StyleTextColor};
+
+    #[test]
+    fn test_set_variable() {
+        let mut css = AzCss::from_string("body { color: var(--fg, #ffffff); }".into());
+        let black = AzCssProperty::text_color(StyleTextColor { inner: ColorU::BLACK });
+
+        AzCss_setVariable(&mut css, "--fg".into(), black.clone());
+        assert_eq!(css.css.get_variable("fg"), Some(&black));
+    }
+
+    #[test]
+    fn test_resolve_variables() {
+        use azul_impl::css::CssDeclaration;
+
+        let mut css = AzCss::from_string("body { color: var(--fg, #ffffff); }".into());
+        let black = AzCssProperty::text_color(StyleTextColor { inner: ColorU::BLACK });
+
+        AzCss_setVariable(&mut css, "fg".into(), black.clone());
+        AzCss_resolveVariables(&mut css);
+        let declarations = css.css.rules().next().unwrap().declarations.as_ref();
+        assert_eq!(declarations, &[CssDeclaration::Static(black)][..]);
+    }
+}
@@ -0,0 +1,353 @@
+//! Error reporting for language bindings
+//!
+//! Every exported function runs its body through `ffi_guard!`, so a panic
+//! never unwinds into foreign code: the panic is recorded as the last error
+//! and the function returns a fallback value instead. Bindings can retrieve
+//! the message afterwards with `AzApi_lastError`.
+//!
+//! The fallback is `Default::default()` of the return type (`false`, `0`,
+//! `None`, an empty string, a null pointer, ...). Return types without a
+//! default implement `PanicSentinel` below: an empty `Dom`, an `Err(..)`,
+//! a GL object with the id `0`, ... - something the caller can keep using.
+//! `*_deepCopy` is not guarded, cloning an object can't panic.
+//!
+//! A panic inside a callback usually comes from one of the exported functions
+//! that the callback calls, so it is caught there and the callback continues
+//! with the fallback value. Callbacks are `extern "C"`: a panic raised directly
+//! in a Rust callback can't unwind through the callback frame, Rust aborts the
+//! process before it reaches the library.
+
+use crate::*;
+use core::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+/// Runs the body of an exported function, a panic is recorded as the last
+/// error and `$ret` is replaced with a fallback value (see module docs)
+macro_rules! ffi_guard {
+    ($ret:ty, $body:block) => {{
+        #[allow(unused_imports)]
+        use crate::error::{DefaultFallback, SentinelFallback};
+        match azul_impl::error::catch_panic(|| -> $ret { $body }) {
+            Ok(o) => o,
+            Err(_) => (&crate::error::PanicFallback::<$ret>::new()).fallback(),
+        }
+    }};
+}
+
+pub(crate) use ffi_guard;
+
+/// Selects the fallback of `ffi_guard!` at compile time: method resolution
+/// picks `DefaultFallback` if `T: Default`, otherwise `SentinelFallback`
+pub struct PanicFallback<T>(PhantomData<T>);
+
+impl<T> PanicFallback<T> {
+    pub fn new() -> Self {
+        PanicFallback(PhantomData)
+    }
+}
+
+pub trait DefaultFallback<T> {
+    fn fallback(&self) -> T;
+}
+
+impl<T: Default> DefaultFallback<T> for PanicFallback<T> {
+    fn fallback(&self) -> T {
+        T::default()
+    }
+}
+
+pub trait SentinelFallback<T> {
+    fn fallback(&self) -> T;
+}
+
+impl<T: PanicSentinel> SentinelFallback<T> for &PanicFallback<T> {
+    fn fallback(&self) -> T {
+        T::panic_sentinel()
+    }
+}
+
+/// Value that an exported function returns after a panic if the
+/// return type has no `Default`. Must not panic itself.
+pub trait PanicSentinel {
+    fn panic_sentinel() -> Self;
+}
+
+macro_rules! impl_panic_sentinel {
+    ($($t:ty => $sentinel:expr),* $(,)?) => {
+        $(
+            impl PanicSentinel for $t {
+                fn panic_sentinel() -> Self {
+                    $sentinel
+                }
+            }
+        )*
+    };
+}
+
+// callbacks of the sentinel timers / menus: do nothing
+extern "C" fn skip_callback(_: &mut AzRefAny, _: &mut AzCallbackInfo) -> AzUpdate {
+    AzUpdate::DoNothing
+}
+
+extern "C" fn terminate_timer(_: &mut AzRefAny, _: &mut AzTimerCallbackInfo) -> AzTimerCallbackReturn {
+    AzTimerCallbackReturn {
+        should_update: AzUpdate::DoNothing,
+        should_terminate: AzTerminateTimer::Terminate,
+    }
+}
+
+// thread function of the sentinel thread: returns immediately
+extern "C" fn skip_thread(_: &mut AzRefAny, _: &mut AzThreadSender, _: &mut AzThreadReceiver) {}
+
+fn vertex_array_object_sentinel() -> AzVertexArrayObject {
+    let empty_layout = AzVertexLayout { fields: AzVertexAttributeVec::from_const_slice(&[]) };
+    AzVertexArrayObject::new(empty_layout, 0, AzGl::null())
+}
+
+// GL objects with the id 0 and a context that doesn't load any OpenGL functions,
+// so that dropping them doesn't touch the GL state of the application
+fn vertex_buffer_sentinel() -> AzVertexBuffer {
+    AzVertexBuffer::new_raw(0, 0, vertex_array_object_sentinel(), 0, 0, AzIndexBufferFormat::Triangles)
+}
+
+impl_panic_sentinel! {
+    AzDom => AzDom::div(),
+    AzNodeType => AzNodeType::Div,
+    AzDomNodeId => AzDomNodeId { dom: AzDomId::ROOT_ID, node: AzNodeId::NONE },
+    AzEventFilter => AzEventFilter::Application(AzApplicationEventFilter::DeviceConnected),
+    AzCssProperty => AzCssProperty::none(AzCssPropertyType::Display),
+    AzStyleBoxShadowValue => AzStyleBoxShadowValue::None,
+    AzInlineText => AzInlineText {
+        lines: AzInlineLineVec::from_const_slice(&[]),
+        content_size: AzLogicalSize::zero(),
+        font_size_px: 0.0,
+        last_word_index: 0,
+        baseline_descender_px: 0.0,
+    },
+    AzAccelerator => AzAccelerator::new(AzModifiers::NONE, AzVirtualKeyCode::Unlabeled),
+    // ids count up from zero, usize::MAX never refers to a registered item
+    AzAcceleratorId => AzAcceleratorId { id: usize::MAX },
+    AzTimerId => AzTimerId { id: usize::MAX },
+    AzMenuCallback => AzMenuCallback {
+        callback: AzCallback { cb: skip_callback },
+        data: AzRefAny::new(()),
+    },
+    AzStringMenuItem => AzStringMenuItem::new(AzString::from_const_str("")),
+    AzPendingThread => AzPendingThread { data: AzRefAny::new(()), thread_fn: skip_thread },
+    AzButton => AzButton::new(AzString::from_const_str("")),
+    AzCheckBox => AzCheckBox::new(false),
+    AzLabel => AzLabel::new(AzString::from_const_str("")),
+    AzProgressBar => AzProgressBar::new(0.0),
+    AzFrame => AzFrame::new(AzString::from_const_str(""), AzDom::div()),
+    AzApp => AzApp {
+        // no windows and an empty font cache, doesn't spawn the font cache thread
+        ptr: Box::new(Arc::new(Mutex::new(azul_impl::app::App {
+            data: AzRefAny::new(()),
+            config: AzAppConfig::new(AzLayoutSolver::Default),
+            windows: Vec::new(),
+            image_cache: azul_impl::resources::ImageCache::new(),
+            fc_cache: azul_impl::app::LazyFcCache::InProgress(None),
+        }))),
+        run_destructor: true,
+    },
+    AzAppConfig => AzAppConfig::new(AzLayoutSolver::Default),
+    AzSystemCallbacks => AzSystemCallbacks::rust_internal(),
+    AzGetSystemTimeFn => AzSystemCallbacks::rust_internal().get_system_time_fn,
+    AzInstant => AzInstant::Tick(AzSystemTick::new(0)),
+    AzTimer => AzTimer::new(
+        AzRefAny::new(()),
+        terminate_timer,
+        AzSystemCallbacks::rust_internal().get_system_time_fn,
+    ),
+    AzRefAny => AzRefAny::new(()),
+    AzRawWindowHandle => AzRawWindowHandle::Unsupported,
+    AzHidpiAdjustedBounds => AzHidpiAdjustedBounds::from_bounds(AzLayoutSize::zero(), 1.0),
+    AzPhysicalSizeU32 => AzPhysicalSizeU32::zero(),
+    AzMsgBoxOkCancel => AzMsgBoxOkCancel::Cancel,
+    AzMsgBoxYesNo => AzMsgBoxYesNo::No,
+    AzRefstr => "".into(),
+    AzU8VecRef => (&[][..]).into(),
+    AzImageRef => AzImageRef::invalid(0, 0, AzRawImageFormat::RGBA8),
+    AzRawImage => AzRawImage::null_image(),
+    AzResultRawImageDecodeImageError => AzResultRawImageDecodeImageError::Err(AzDecodeImageError::Unknown),
+    AzResultU8VecEncodeImageError => AzResultU8VecEncodeImageError::Err(AzEncodeImageError::Unknown),
+    AzResultXmlXmlError => AzResultXmlXmlError::Err(AzXmlError::NoRootNode),
+    AzResultSvgSvgParseError => AzResultSvgSvgParseError::Err(AzSvgParseError::ParsingFailed(AzXmlError::NoRootNode)),
+    AzResultSvgXmlNodeSvgParseError => AzResultSvgXmlNodeSvgParseError::Err(AzSvgParseError::ParsingFailed(AzXmlError::NoRootNode)),
+    AzSvgXmlNode => azul_impl::svg::svgxmlnode_empty(),
+    AzSvgPath => AzSvgPath { items: AzSvgPathElementVec::from_const_slice(&[]) },
+    AzSvgMultiPolygon => AzSvgMultiPolygon { rings: AzSvgPathVec::from_const_slice(&[]) },
+    AzSvgVector => AzSvgVector { x: 0.0, y: 0.0 },
+    AzTessellatedSvgNodeVecRef => AzTessellatedSvgNodeVecRef { ptr: core::ptr::NonNull::dangling().as_ptr(), len: 0 },
+    AzTessellatedColoredSvgNodeVecRef => AzTessellatedColoredSvgNodeVecRef { ptr: core::ptr::NonNull::dangling().as_ptr(), len: 0 },
+    AzTessellatedGPUSvgNode => AzTessellatedGPUSvgNode { vertex_index_buffer: vertex_buffer_sentinel() },
+    AzTessellatedColoredGPUSvgNode => AzTessellatedColoredGPUSvgNode { vertex_index_buffer: vertex_buffer_sentinel() },
+    AzTexture => AzTexture::new(
+        0,
+        AzTextureFlags::default(),
+        AzPhysicalSizeU32::zero(),
+        AzColorU::TRANSPARENT,
+        AzGl::null(),
+        AzRawImageFormat::RGBA8,
+    ),
+    AzVertexArrayObject => vertex_array_object_sentinel(),
+    AzVertexBuffer => vertex_buffer_sentinel(),
+    AzGlType => AzGlType::Gl,
+    AzGLsyncPtr => AzGLsyncPtr { ptr: core::ptr::null(), run_destructor: false },
+    AzGlVoidPtrMut => AzGlVoidPtrMut { ptr: core::ptr::null_mut() },
+    AzGlShaderPrecisionFormatReturn => AzGlShaderPrecisionFormatReturn { _0: 0, _1: 0, _2: 0 },
+    AzGetProgramBinaryReturn => AzGetProgramBinaryReturn { _0: AzU8Vec::from_const_slice(&[]), _1: 0 },
+    AzGetActiveAttribReturn => AzGetActiveAttribReturn { _0: 0, _1: 0, _2: AzString::from_const_str("") },
+    AzGetActiveUniformReturn => AzGetActiveUniformReturn { _0: 0, _1: 0, _2: AzString::from_const_str("") },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AzApp, AzInstant, AzWindowCreateOptions};
+    use azul_impl::{
+        callbacks::{Callback, CallbackInfo, LayoutCallbackInfo, RefAny, Update},
+        css::Css,
+        dom::{CallbackData, Dom, EventFilter, HoverEventFilter},
+        resources::{AppConfig, LayoutSolverVersion},
+        styled_dom::StyledDom,
+        task::SystemTick,
+    };
+    use std::sync::Mutex;
+
+    // the last error is process-wide
+    static LAST_ERROR_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_last_error_after_caught_panic() {
+        let _lock = LAST_ERROR_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        AzApi_clearLastError();
+        assert!(AzApi_lastError().is_none());
+
+        let result = azul_impl::error::catch_panic(|| -> u32 { panic!("layout failed") });
+        assert_eq!(result.unwrap_err().as_str(), "layout failed");
+        assert!(AzApi_lastError().as_ref().unwrap().as_str().contains("layout failed"));
+
+        AzApi_clearLastError();
+        assert!(AzApi_lastError().is_none());
+    }
+
+    #[derive(Default)]
+    struct DataModel {
+        layouts: usize,
+        durations: Vec<bool>,
+    }
+
+    extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+        // panics inside azul: a system time can't be compared with a tick count
+        let tick = AzInstant::Tick(SystemTick::new(1));
+        let duration = crate::AzInstant_durationSince(&tick, std::time::Instant::now().into());
+
+        if let Some(mut d) = data.downcast_mut::<DataModel>() {
+            d.layouts += 1;
+            d.durations.push(duration.is_some());
+        }
+        Dom::body().style(Css::empty())
+    }
+
+    #[test]
+    fn test_panic_in_layout_callback() {
+        let _lock = LAST_ERROR_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        AzApi_clearLastError();
+
+        let data = RefAny::new(DataModel::default());
+        let app = AzApp::new(data, AppConfig::new(LayoutSolverVersion::Default));
+        let harness = AzApp_createTestHarness(&app, AzWindowCreateOptions::new(layout));
+        assert!(!harness.is_null());
+
+        let error = AzApi_lastError().into_option().unwrap();
+        assert!(error.as_str().contains("Duration from a SystemTime and a Tick instant"));
+
+        // the callback got the fallback value and the window keeps working
+        unsafe { AzTestHarness_resize(harness, 400.0, 300.0) };
+        let (layouts, durations) = unsafe { AzTestHarness_getData(harness) }
+            .downcast_ref::<DataModel>()
+            .map(|d| (d.layouts, d.durations.clone()))
+            .unwrap();
+        assert!(layouts >= 1);
+        assert!(durations.iter().all(|is_some| !is_some));
+
+        AzApi_clearLastError();
+        unsafe { az_test_harness::delete(harness) };
+    }
+
+    extern "C" fn click_layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+        let callbacks = vec![CallbackData {
+            event: EventFilter::Hover(HoverEventFilter::MouseUp),
+            callback: Callback { cb: on_click },
+            data: data.clone(),
+        }];
+        Dom::body()
+            .with_child(Dom::div().with_inline_style("flex-grow: 1").with_callbacks(callbacks.into()))
+            .style(Css::empty())
+    }
+
+    extern "C" fn on_click(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        // panics inside azul, same as in `layout`
+        let tick = AzInstant::Tick(SystemTick::new(1));
+        let duration = crate::AzInstant_durationSince(&tick, std::time::Instant::now().into());
+
+        if let Some(mut d) = data.downcast_mut::<DataModel>() {
+            d.layouts += 1;
+            d.durations.push(duration.is_some());
+        }
+        Update::DoNothing
+    }
+
+    #[test]
+    fn test_panic_in_event_callback() {
+        let _lock = LAST_ERROR_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        AzApi_clearLastError();
+
+        let data = RefAny::new(DataModel::default());
+        let app = AzApp::new(data, AppConfig::new(LayoutSolverVersion::Default));
+        let harness = AzApp_createTestHarness(&app, AzWindowCreateOptions::new(click_layout));
+        assert!(!harness.is_null());
+        unsafe { AzTestHarness_resize(harness, 400.0, 300.0) };
+
+        for _ in 0..2 {
+            unsafe {
+                AzTestHarness_mouseMove(harness, 200.0, 150.0);
+                AzTestHarness_mouseDown(harness, AzContextMenuMouseButton::Left);
+                AzTestHarness_mouseUp(harness, AzContextMenuMouseButton::Left);
+            }
+        }
+
+        let error = AzApi_lastError().into_option().unwrap();
+        assert!(error.as_str().contains("Duration from a SystemTime and a Tick instant"));
+
+        // the callback continued both times, the window kept processing events
+        let (clicks, durations) = unsafe { AzTestHarness_getData(harness) }
+            .downcast_ref::<DataModel>()
+            .map(|d| (d.layouts, d.durations.clone()))
+            .unwrap();
+        assert_eq!(clicks, 2);
+        assert_eq!(durations, vec![false, false]);
+
+        AzApi_clearLastError();
+        unsafe { az_test_harness::delete(harness) };
+    }
+
+    #[test]
+    fn test_panic_sentinel_fallback() {
+        let _lock = LAST_ERROR_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        AzApi_clearLastError();
+
+        // types without a `Default` impl return their sentinel
+        let timer_id = ffi_guard!(AzTimerId, { panic!("no timer") });
+        assert_eq!(timer_id.id, usize::MAX);
+        let dom = ffi_guard!(AzDom, { panic!("no dom") });
+        assert_eq!(dom.children.as_ref().len(), 0);
+        // types with a `Default` impl return the default value
+        let count = ffi_guard!(usize, { panic!("no count") });
+        assert_eq!(count, 0);
+
+        assert!(AzApi_lastError().as_ref().unwrap().as_str().contains("no count"));
+        AzApi_clearLastError();
+    }
+}
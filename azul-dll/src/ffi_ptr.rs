@@ -0,0 +1,291 @@
+//! Opaque pointers for language bindings
+//!
+//! Types that are not `repr(C)` are handed out as a plain `*mut T` to a
+//! boxed value. `impl_ffi_ptr!` generates the constructor, the destructor
+//! and the conversion back into a reference, so that all handles are
+//! created, checked and freed the same way.
+//!
+//! With the `ffi-pointer-checks` feature every live pointer is recorded in
+//! a global registry: deleting a pointer twice, using it after it was
+//! deleted or passing a pointer of another type aborts with a message that
+//! names the function instead of corrupting memory. The handle stays a
+//! single pointer, so the ABI is the same with and without the feature.
+//! NOTE: the allocator can reuse the address of a deleted object, a stale
+//! pointer to a new object of the same type is not detected.
+
+use core::fmt;
+
+/// Generates a `*mut $ty` handle type and a module `$prefix` with:
+///
+/// - `new(value) -> $ptr`: boxes the value
+/// - `delete(ptr)`: exported as `<ty>_delete`, ignores `NULL`
+/// - `downcast(ptr, function) -> &mut $ty`: for the functions taking the handle
+/// - `take(ptr, function) -> $ty`: for the functions consuming the handle
+/// - `shallow_copy(ptr) -> $ptr`: exported as `<ty>_shallowCopy`, only
+///   generated with `shallow_copy` for types where `Clone` only copies a
+///   reference (`Arc`, `RefAny`, ...)
+///
+/// ```rust,ignore
+/// impl_ffi_ptr!(AzTestHarness, AzTestHarnessPtr, az_test_harness);
+/// ```
+macro_rules! impl_ffi_ptr {
+    ($ty:ty, $ptr:ident, $prefix:ident) => {
+        $crate::ffi_ptr::impl_ffi_ptr!(@module $ty, $ptr, $prefix, {});
+    };
+    ($ty:ty, $ptr:ident, $prefix:ident, shallow_copy) => {
+        $crate::ffi_ptr::impl_ffi_ptr!(@module $ty, $ptr, $prefix, {
+            /// Returns a new handle sharing the same data
+            ///
+            /// # Safety
+            ///
+            /// `ptr` has to be a live handle, see `downcast`
+            #[export_name = concat!(stringify!($ty), "_shallowCopy")]
+            pub unsafe extern "C" fn shallow_copy(ptr: $ptr) -> $ptr {
+                $crate::error::ffi_guard!($ptr, {
+                    let function = concat!(stringify!($ty), "_shallowCopy");
+                    new(unsafe { downcast(ptr, function) }.clone())
+                })
+            }
+        });
+    };
+    (@module $ty:ty, $ptr:ident, $prefix:ident, { $($extra:tt)* }) => {
+        pub type $ptr = *mut $ty;
+
+        /// Constructor, destructor and checked access for the handle
+        pub mod $prefix {
+            use super::*;
+
+            /// Moves `value` to the heap, the handle has to be freed with `delete`
+            pub fn new(value: $ty) -> $ptr {
+                $crate::ffi_ptr::into_raw(value)
+            }
+
+            /// Frees the value, `NULL` is ignored
+            ///
+            /// # Safety
+            ///
+            /// `ptr` has to be `NULL` or a live handle, it must not be used afterwards
+            #[export_name = concat!(stringify!($ty), "_delete")]
+            pub unsafe extern "C" fn delete(ptr: $ptr) {
+                $crate::error::ffi_guard!((), {
+                    let function = concat!(stringify!($ty), "_delete");
+                    unsafe { $crate::ffi_ptr::delete(ptr, function) }
+                })
+            }
+
+            /// Returns the value behind the handle, aborts with a message
+            /// naming `function` if the handle is `NULL` (or not live, see
+            /// the `ffi-pointer-checks` feature)
+            ///
+            /// # Safety
+            ///
+            /// Without `ffi-pointer-checks`, `ptr` has to be a live handle
+            /// that is not accessed from anywhere else during `'a`
+            pub unsafe fn downcast<'a>(ptr: $ptr, function: &'static str) -> &'a mut $ty {
+                $crate::ffi_ptr::downcast(ptr, function)
+            }
+
+            /// Moves the value out of the handle and frees the handle,
+            /// aborts like `downcast`
+            ///
+            /// # Safety
+            ///
+            /// Same as `downcast`, the handle must not be used afterwards
+            pub unsafe fn take(ptr: $ptr, function: &'static str) -> $ty {
+                $crate::ffi_ptr::take(ptr, function)
+            }
+
+            $($extra)*
+        }
+    };
+}
+
+pub(crate) use impl_ffi_ptr;
+
+/// Reason why a handle was rejected
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FfiPtrError {
+    /// The handle is `NULL`
+    Null {
+        function: &'static str,
+        expected: &'static str,
+    },
+    /// The handle was already deleted or was not created by azul
+    NotLive {
+        function: &'static str,
+        ptr: usize,
+        expected: &'static str,
+    },
+    /// The handle points to another type
+    WrongType {
+        function: &'static str,
+        ptr: usize,
+        expected: &'static str,
+        found: &'static str,
+    },
+}
+
+impl fmt::Display for FfiPtrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::FfiPtrError::*;
+        match self {
+            Null { function, expected } => {
+                write!(f, "{}: expected a {}, got NULL", function, expected)
+            }
+            NotLive { function, ptr, expected } => write!(
+                f,
+                "{}: 0x{:x} is not a live {} (deleted twice, used after delete or not created by azul)",
+                function, ptr, expected
+            ),
+            WrongType { function, ptr, expected, found } => {
+                write!(f, "{}: 0x{:x} is a {}, expected a {}", function, ptr, found, expected)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ffi-pointer-checks")]
+mod registry {
+    use alloc::collections::BTreeMap;
+    use core::any::TypeId;
+    use std::sync::Mutex;
+
+    /// Address of every live handle -> type of the boxed value
+    static LIVE_POINTERS: Mutex<BTreeMap<usize, (TypeId, &'static str)>> =
+        Mutex::new(BTreeMap::new());
+
+    pub fn insert(ptr: usize, type_id: TypeId, type_name: &'static str) {
+        if let Ok(mut l) = LIVE_POINTERS.lock() {
+            l.insert(ptr, (type_id, type_name));
+        }
+    }
+
+    pub fn get(ptr: usize) -> Option<(TypeId, &'static str)> {
+        LIVE_POINTERS.lock().ok()?.get(&ptr).copied()
+    }
+
+    pub fn remove(ptr: usize) {
+        if let Ok(mut l) = LIVE_POINTERS.lock() {
+            l.remove(&ptr);
+        }
+    }
+}
+
+/// Checks that `ptr` is a live handle to a `T`. Without the
+/// `ffi-pointer-checks` feature, only `NULL` is rejected.
+pub fn check<T: 'static>(ptr: *const T, function: &'static str) -> Result<(), FfiPtrError> {
+    let expected = core::any::type_name::<T>();
+
+    if ptr.is_null() {
+        return Err(FfiPtrError::Null { function, expected });
+    }
+
+    #[cfg(feature = "ffi-pointer-checks")]
+    {
+        let ptr = ptr as usize;
+        match registry::get(ptr) {
+            None => return Err(FfiPtrError::NotLive { function, ptr, expected }),
+            Some((type_id, found)) if type_id != core::any::TypeId::of::<T>() => {
+                return Err(FfiPtrError::WrongType { function, ptr, expected, found });
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks `ptr` (see `check`) and removes it from the live handles,
+/// so that deleting it a second time is rejected
+pub fn unregister<T: 'static>(ptr: *const T, function: &'static str) -> Result<(), FfiPtrError> {
+    check(ptr, function)?;
+    #[cfg(feature = "ffi-pointer-checks")]
+    registry::remove(ptr as usize);
+    Ok(())
+}
+
+fn abort_on_error(result: Result<(), FfiPtrError>) {
+    if let Err(e) = result {
+        eprintln!("azul: invalid pointer in {}", e);
+        std::process::abort();
+    }
+}
+
+pub(crate) fn into_raw<T: 'static>(value: T) -> *mut T {
+    let ptr = Box::into_raw(Box::new(value));
+    #[cfg(feature = "ffi-pointer-checks")]
+    registry::insert(ptr as usize, core::any::TypeId::of::<T>(), core::any::type_name::<T>());
+    ptr
+}
+
+pub(crate) unsafe fn delete<T: 'static>(ptr: *mut T, function: &'static str) {
+    if ptr.is_null() {
+        return;
+    }
+    abort_on_error(unregister(ptr, function));
+    drop(Box::from_raw(ptr));
+}
+
+pub(crate) unsafe fn take<T: 'static>(ptr: *mut T, function: &'static str) -> T {
+    abort_on_error(unregister(ptr, function));
+    *Box::from_raw(ptr)
+}
+
+pub(crate) unsafe fn downcast<'a, T: 'static>(ptr: *mut T, function: &'static str) -> &'a mut T {
+    abort_on_error(check(ptr, function));
+    &mut *ptr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::sync::Arc;
+
+    #[derive(Debug, Clone)]
+    pub struct Counter {
+        value: Arc<usize>,
+    }
+
+    impl_ffi_ptr!(Counter, CounterPtr, az_test_counter, shallow_copy);
+
+    #[test]
+    fn test_new_downcast_delete() {
+        let ptr = az_test_counter::new(Counter { value: Arc::new(5) });
+        assert_eq!(check(ptr, "test"), Ok(()));
+        assert_eq!(*unsafe { az_test_counter::downcast(ptr, "test") }.value, 5);
+
+        let copy = unsafe { az_test_counter::shallow_copy(ptr) };
+        assert_ne!(copy, ptr);
+        assert_eq!(Arc::strong_count(&unsafe { az_test_counter::downcast(copy, "test") }.value), 2);
+
+        let counter = unsafe { az_test_counter::take(copy, "test") };
+        assert_eq!(Arc::strong_count(&counter.value), 2);
+        drop(counter);
+
+        unsafe {
+            az_test_counter::delete(ptr);
+            az_test_counter::delete(core::ptr::null_mut());
+        }
+
+        let null: CounterPtr = core::ptr::null_mut();
+        assert!(matches!(check(null, "Counter_get"), Err(FfiPtrError::Null { .. })));
+    }
+
+    #[test]
+    #[cfg(feature = "ffi-pointer-checks")]
+    fn test_double_delete_is_detected() {
+        let ptr = az_test_counter::new(Counter { value: Arc::new(0) });
+        unsafe { az_test_counter::delete(ptr) };
+
+        // same check as the second Counter_delete, which would abort
+        let err = unregister(ptr, "Counter_delete").unwrap_err();
+        assert!(err.to_string().starts_with("Counter_delete: "));
+        assert!(check(ptr, "Counter_get").is_err());
+
+        // a handle of another type is rejected, too
+        let other = into_raw(0_u32);
+        let err = check(other as *const Counter, "Counter_get").unwrap_err();
+        assert!(matches!(err, FfiPtrError::WrongType { found: "u32", .. }));
+        unsafe { delete(other, "test") };
+    }
+}
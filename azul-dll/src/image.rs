@@ -0,0 +1,118 @@
+//! Image resource management for language bindings
+//!
+//! Registers tightly packed pixel data under a CSS image ID, so that
+//! `background: image("id")` and image nodes can use it, and replaces the
+//! pixels later on (i.e. for every frame of a webcam stream) without
+//! re-allocating the texture.
+
+use crate::{AzApp, AzCallbackInfo, AzRawImageFormat, AzString};
+use azul_impl::resources::{ImageRef, RawImage};
+
+/// Copies `len` bytes of packed `RGBA8`, `BGRA8` or `RGB8` pixels
+/// (straight alpha, no row padding) into a `RawImage`
+///
+/// # Safety
+///
+/// `data` has to be NULL or point to `len` readable bytes
+unsafe fn raw_image_from_bytes(
+    data: *const u8,
+    len: usize,
+    width: u32,
+    height: u32,
+    format: AzRawImageFormat,
+) -> Option<RawImage> {
+    if data.is_null() {
+        return None;
+    }
+    let bytes = core::slice::from_raw_parts(data, len);
+    RawImage::from_packed_bytes(bytes, width as usize, height as usize, format)
+}
+
+/// Implementation of `AzApp_addImageRaw`
+pub(crate) unsafe fn app_add_image_raw(
+    app: &mut AzApp,
+    id: AzString,
+    data: *const u8,
+    len: usize,
+    width: u32,
+    height: u32,
+    format: AzRawImageFormat,
+) -> bool {
+    match raw_image_from_bytes(data, len, width, height, format).and_then(ImageRef::new_rawimage) {
+        Some(image) => {
+            app.add_image(id, image);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Implementation of `AzApp_updateImageRaw`
+pub(crate) unsafe fn app_update_image_raw(
+    app: &mut AzApp,
+    id: AzString,
+    data: *const u8,
+    len: usize,
+    width: u32,
+    height: u32,
+    format: AzRawImageFormat,
+) -> bool {
+    match raw_image_from_bytes(data, len, width, height, format) {
+        Some(image) => app.update_image_raw(&id, image),
+        None => false,
+    }
+}
+
+/// Implementation of `AzCallbackInfo_addImageRaw`
+pub(crate) unsafe fn callback_info_add_image_raw(
+    info: &mut AzCallbackInfo,
+    id: AzString,
+    data: *const u8,
+    len: usize,
+    width: u32,
+    height: u32,
+    format: AzRawImageFormat,
+) -> bool {
+    match raw_image_from_bytes(data, len, width, height, format).and_then(ImageRef::new_rawimage) {
+        Some(image) => {
+            info.add_image(id, image);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Implementation of `AzCallbackInfo_updateImageRaw`
+pub(crate) unsafe fn callback_info_update_image_raw(
+    info: &mut AzCallbackInfo,
+    id: AzString,
+    data: *const u8,
+    len: usize,
+    width: u32,
+    height: u32,
+    format: AzRawImageFormat,
+) -> bool {
+    match raw_image_from_bytes(data, len, width, height, format) {
+        Some(image) => info.update_image_raw(&id, image),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_image_from_bytes_validates_len() {
+        let rgb = [0u8; 4 * 2 * 3];
+        let raw_image_from_bytes = |data, len, width, height, format| unsafe {
+            raw_image_from_bytes(data, len, width, height, format)
+        };
+        assert!(raw_image_from_bytes(rgb.as_ptr(), rgb.len(), 4, 2, AzRawImageFormat::RGB8).is_some());
+        // one byte short / one row of padding
+        assert!(raw_image_from_bytes(rgb.as_ptr(), rgb.len() - 1, 4, 2, AzRawImageFormat::RGB8).is_none());
+        assert!(raw_image_from_bytes(rgb.as_ptr(), rgb.len(), 4, 1, AzRawImageFormat::RGB8).is_none());
+        assert!(raw_image_from_bytes(core::ptr::null(), 0, 0, 0, AzRawImageFormat::RGBA8).is_none());
+        assert!(raw_image_from_bytes(rgb.as_ptr(), rgb.len(), 4, 2, AzRawImageFormat::R16).is_none());
+    }
+}
@@ -16,23 +16,61 @@ use core::ffi::c_void;
 pub mod widgets;
 #[cfg(all(feature = "python-extension", feature = "link_dynamic", not(feature = "link-static")))]
 pub mod python;
+pub mod version;
+pub mod error;
+pub mod image;
+pub mod app;
+pub mod window;
+pub mod ref_any;
+pub mod ffi_ptr;
+pub mod test_harness;
+pub mod css;
+pub mod accelerator;
+pub mod menu;
+pub mod thread;
+pub mod snapshot;
+
+use error::ffi_guard;
+
+/// Hash over the binary interface of the API, see `AzApi_abiHash`
+pub(crate) const AZ_API_ABI_HASH: u64 = 0x37827d2f72e8e9df;
 
 
 /// Main application class
 pub use azul_impl::app::AzAppPtr as AzAppTT;
 pub use AzAppTT as AzApp;
 /// Creates a new App instance from the given `AppConfig`
-#[no_mangle] pub extern "C" fn AzApp_new(data: AzRefAny, config: AzAppConfig) -> AzApp { azul_impl::app::AzAppPtr::new(data, config) }
+#[no_mangle] pub extern "C" fn AzApp_new(data: AzRefAny, config: AzAppConfig) -> AzApp { ffi_guard!(AzApp, { azul_impl::app::AzAppPtr::new(data, config) }) }
 /// Spawn a new window on the screen when the app is run.
-#[no_mangle] pub extern "C" fn AzApp_addWindow(app: &mut AzApp, window: AzWindowCreateOptions) { app.add_window(window) }
+#[no_mangle] pub extern "C" fn AzApp_addWindow(app: &mut AzApp, window: AzWindowCreateOptions) { ffi_guard!((), { app.add_window(window) }) }
 /// Adds a new image identified by an ID to the image cache
-#[no_mangle] pub extern "C" fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { app.add_image(id, image) }
+#[no_mangle] pub extern "C" fn AzApp_addImage(app: &mut AzApp, id: AzString, image: AzImageRef) { ffi_guard!((), { app.add_image(id, image) }) }
+/// Copies the pixels into a new image and adds it to the image cache under `id`. Returns `false` if `len` doesn't match the image size or the format is not supported.
+///
+/// # Safety
+///
+/// `data` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzApp_addImageRaw(app: &mut AzApp, id: AzString, data: *const u8, len: usize, width: u32, height: u32, format: AzRawImageFormat) -> bool { ffi_guard!(bool, { crate::image::app_add_image_raw(app, id, data, len, width, height, format) }) }
+/// Replaces the pixels of the image registered under `id`, keeping its texture (i.e. for every frame of a video stream). Returns `false` if there is no image with the same size registered under `id` (use `add_image_raw` in that case) or if the data is invalid.
+///
+/// # Safety
+///
+/// `data` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzApp_updateImageRaw(app: &mut AzApp, id: AzString, data: *const u8, len: usize, width: u32, height: u32, format: AzRawImageFormat) -> bool { ffi_guard!(bool, { crate::image::app_update_image_raw(app, id, data, len, width, height, format) }) }
+/// Removes the image identified by a CSS ID from the image cache
+#[no_mangle] pub extern "C" fn AzApp_deleteImage(app: &mut AzApp, id: AzString) { ffi_guard!((), { app.delete_image(&id) }) }
 /// Returns a list of monitors - useful for setting the monitor that a window should spawn on.
-#[no_mangle] pub extern "C" fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { app.get_monitors() }
-/// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns.
-#[no_mangle] pub extern "C" fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) { app.run(window) }
+#[no_mangle] pub extern "C" fn AzApp_getMonitors(app: &AzApp) -> AzMonitorVec { ffi_guard!(AzMonitorVec, { app.get_monitors() }) }
+/// Returns whether the operating system is set to light or dark mode. Windows created with `WindowCreateOptions.theme = None` follow this setting and fire a `WindowEventFilter::ThemeChanged` event when it changes.
+#[no_mangle] pub extern "C" fn AzApp_getSystemTheme(app: &AzApp) -> AzOptionWindowTheme { ffi_guard!(AzOptionWindowTheme, { app.get_system_theme() }) }
+/// Runs the application. Due to platform restrictions (specifically `WinMain` on Windows), this function never returns. Use `run_return_code` to get the exit code instead.
+#[no_mangle] pub extern "C" fn AzApp_run(app: &AzApp, window: AzWindowCreateOptions) { ffi_guard!((), { app.run(window) }) }
+/// Same as `run`, but returns the exit code of the event loop (`0` if the last window was closed normally). Returns `-1` if the app could not be started (see `Api::last_error`) or if it is already running.
+#[no_mangle] pub extern "C" fn AzApp_runReturnCode(app: &AzApp, window: AzWindowCreateOptions) -> i32 { ffi_guard!(i32, { app.run_return_code(window) }) }
+/// Moves the app into a new `TestHarness` instead of running it (the app is empty afterwards, same as after `run`). Returns `NULL` if the app is running. The harness has to be freed with `TestHarness::delete`.
+#[no_mangle] pub extern "C" fn AzApp_createTestHarness(app: &AzApp, window: AzWindowCreateOptions) -> AzTestHarnessPtr { ffi_guard!(AzTestHarnessPtr, { match app.create_test_harness(window) { Some(harness) => az_test_harness::new(harness), None => core::ptr::null_mut() } }) }
 /// Destructor: Takes ownership of the `App` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzApp_delete(object: &mut AzApp) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzApp_delete(object: &mut AzApp) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzApp_deepCopy(object: &AzApp) -> AzApp { object.clone() }
 
@@ -40,12 +78,18 @@ pub use AzAppTT as AzApp;
 pub use azul_impl::resources::AppConfig as AzAppConfigTT;
 pub use AzAppConfigTT as AzAppConfig;
 /// Constructs a default `AppConfig`, uses the layout solver currently available
-#[no_mangle] pub extern "C" fn AzAppConfig_new(layout_solver: AzLayoutSolver) -> AzAppConfig { AzAppConfig::new(layout_solver) }
+#[no_mangle] pub extern "C" fn AzAppConfig_new(layout_solver: AzLayoutSolver) -> AzAppConfig { ffi_guard!(AzAppConfig, { AzAppConfig::new(layout_solver) }) }
+/// Sets how often the windows of the app render a new frame, the default is `OnEventOnly` (an idle app doesn't render)
+#[no_mangle] pub extern "C" fn AzAppConfig_setRedrawBehavior(appconfig: &mut AzAppConfig, behavior: AzRedrawBehavior) { ffi_guard!((), { appconfig.redraw_behavior = behavior; }) }
 
 /// Configuration to set which messages should be logged.
 pub use azul_impl::resources::AppLogLevel as AzAppLogLevelTT;
 pub use AzAppLogLevelTT as AzAppLogLevel;
 
+/// Controls how often a window renders a new frame
+pub use azul_impl::resources::RedrawBehavior as AzRedrawBehaviorTT;
+pub use AzRedrawBehaviorTT as AzRedrawBehavior;
+
 /// Version of the layout solver to use - future binary versions of azul may have more fields here, necessary so that old compiled applications don't break with newer releases of azul. Newer layout versions are opt-in only.
 pub use azul_impl::resources::LayoutSolverVersion as AzLayoutSolverTT;
 pub use AzLayoutSolverTT as AzLayoutSolver;
@@ -54,15 +98,140 @@ pub use AzLayoutSolverTT as AzLayoutSolver;
 pub use azul_impl::task::ExternalSystemCallbacks as AzSystemCallbacksTT;
 pub use AzSystemCallbacksTT as AzSystemCallbacks;
 /// Use the default, library-internal callbacks instead of providing your own
-#[no_mangle] pub extern "C" fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks { AzSystemCallbacks::rust_internal() }
+#[no_mangle] pub extern "C" fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks { ffi_guard!(AzSystemCallbacks, { AzSystemCallbacks::rust_internal() }) }
+
+/// Version and feature detection of the loaded library. Bindings that load the library dynamically should call these functions before anything else and compare the results with the `AZ_API_VERSION_*` and `AZ_API_ABI_HASH` defines of the header they were generated from. The functions only use primitive types, so they can be called even if the rest of the API does not match.
+pub use crate::version::Api as AzApiTT;
+pub use AzApiTT as AzApi;
+/// Returns the major version of the library
+#[no_mangle] pub extern "C" fn AzApi_versionMajor() -> u32 { ffi_guard!(u32, { crate::version::version_major() }) }
+/// Returns the minor version of the library
+#[no_mangle] pub extern "C" fn AzApi_versionMinor() -> u32 { ffi_guard!(u32, { crate::version::version_minor() }) }
+/// Returns the patch version of the library
+#[no_mangle] pub extern "C" fn AzApi_versionPatch() -> u32 { ffi_guard!(u32, { crate::version::version_patch() }) }
+/// Returns the full version of the library (i.e. `"0.0.1"`) as a static, nul-terminated UTF-8 string that must not be freed
+#[no_mangle] pub extern "C" fn AzApi_versionString() -> *const u8 { ffi_guard!(*const u8, { crate::version::version_string() }) }
+/// Returns the hash over the binary interface (struct layouts and function signatures) of the API the library was generated from
+#[no_mangle] pub extern "C" fn AzApi_abiHash() -> u64 { ffi_guard!(u64, { crate::AZ_API_ABI_HASH }) }
+/// Returns whether the library was compiled with an optional capability, i.e. `"svg"`, `"hot-reload"` or `"wasm"`. Unknown names return `false`.
+///
+/// # Safety
+///
+/// `name_ptr` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzApi_hasFeature(name_ptr: *const u8, name_len: usize) -> bool { ffi_guard!(bool, { crate::version::has_feature(name_ptr, name_len) }) }
+/// Returns the message of the last panic that was caught inside the library, including the location and - if `RUST_BACKTRACE` is set - the backtrace
+#[no_mangle] pub extern "C" fn AzApi_lastError() -> AzOptionString { ffi_guard!(AzOptionString, { azul_impl::error::last_error() }) }
+/// Resets the last error, so that `last_error` returns `None`. Returns whether an error was recorded.
+#[no_mangle] pub extern "C" fn AzApi_clearLastError() -> bool { ffi_guard!(bool, { azul_impl::error::clear_last_error() }) }
+
+/// Window-less event simulation for integration tests: lays out the root window of an app without opening it. The injected mouse / keyboard events invoke the callbacks and restyle / relayout the window like a real event loop would, so that tests can check the hit nodes, the layouted rectangles and the application data afterwards. Created with `App::create_test_harness`, has to be freed with `TestHarness::delete`.
+pub use azul_impl::test_harness::TestHarness as AzTestHarness;
+ffi_ptr::impl_ffi_ptr!(AzTestHarness, AzTestHarnessPtr, az_test_harness);
+pub use az_test_harness::delete as AzTestHarness_delete;
+/// Compares two snapshots (see `serialize_layout`) line by line: returns `None` if they are equal, otherwise a diff with `- ` (expected) and `+ ` (actual) line prefixes for the error message of a failed test
+#[no_mangle] pub extern "C" fn AzTestHarness_diffSnapshots(expected: AzString, actual: AzString) -> AzOptionString { ffi_guard!(AzOptionString, { azul_impl::snapshot::diff_snapshots(expected.as_str(), actual.as_str()).map(AzString::from).into() }) }
+/// Resizes the simulated window (in logical pixels)
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_resize(testharness: AzTestHarnessPtr, width: f32, height: f32) { ffi_guard!((), { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_resize") }; testharness.resize(width, height) }) }
+/// Moves the cursor to (`x`, `y`), relative to the top left corner of the window
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_mouseMove(testharness: AzTestHarnessPtr, x: f32, y: f32) { ffi_guard!((), { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_mouseMove") }; testharness.mouse_move(x, y) }) }
+/// Presses a mouse button at the current cursor position
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_mouseDown(testharness: AzTestHarnessPtr, button: AzContextMenuMouseButton) { ffi_guard!((), { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_mouseDown") }; testharness.mouse_down(button) }) }
+/// Releases a mouse button at the current cursor position
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_mouseUp(testharness: AzTestHarnessPtr, button: AzContextMenuMouseButton) { ffi_guard!((), { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_mouseUp") }; testharness.mouse_up(button) }) }
+/// Types a unicode character, control characters are ignored
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_keyChar(testharness: AzTestHarnessPtr, c: u32) { ffi_guard!((), { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_keyChar") }; testharness.key_char(c) }) }
+/// Presses a key, modifier keys stay pressed until `key_up`
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_keyDown(testharness: AzTestHarnessPtr, key: AzVirtualKeyCode) { ffi_guard!((), { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_keyDown") }; testharness.key_down(key) }) }
+/// Releases a key
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_keyUp(testharness: AzTestHarnessPtr, key: AzVirtualKeyCode) { ffi_guard!((), { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_keyUp") }; testharness.key_up(key) }) }
+/// Number of nodes under the cursor
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_getHitNodeCount(testharness: AzTestHarnessPtr) -> usize { ffi_guard!(usize, { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_getHitNodeCount") }; testharness.get_hit_nodes().len() }) }
+/// Returns the hit node at `index`, the topmost node is the last one
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_getHitNode(testharness: AzTestHarnessPtr, index: usize) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_getHitNode") }; testharness.get_hit_nodes().get(index).copied().into() }) }
+/// Returns the layouted rectangle of `node`, `None` if the node doesn't exist
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_getNodeRect(testharness: AzTestHarnessPtr, node: AzDomNodeId) -> AzOptionLogicalRect { ffi_guard!(AzOptionLogicalRect, { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_getNodeRect") }; testharness.get_node_rect(node).into() }) }
+/// Returns the text of a text node, `None` for other node types
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_getNodeText(testharness: AzTestHarnessPtr, node: AzDomNodeId) -> AzOptionString { ffi_guard!(AzOptionString, { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_getNodeText") }; testharness.get_node_text(node).into() }) }
+/// Lets `millis` milliseconds of simulated time pass, the window renders as many frames as the `RedrawBehavior` of the app allows
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_advanceTime(testharness: AzTestHarnessPtr, millis: u64) { ffi_guard!((), { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_advanceTime") }; testharness.advance_time(millis) }) }
+/// Number of frames rendered since the harness was created (including the first frame)
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_getFramesRendered(testharness: AzTestHarnessPtr) -> usize { ffi_guard!(usize, { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_getFramesRendered") }; testharness.get_frames_rendered() }) }
+/// Returns a new reference to the application data
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_getData(testharness: AzTestHarnessPtr) -> AzRefAny { ffi_guard!(AzRefAny, { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_getData") }; testharness.get_data().clone() }) }
+/// Prints the layout of the window (`dom` = 0) or of an iframe as deterministic text, so that tests can compare it against a committed golden file: one line per node with the path, the layouted rectangle, the text of text nodes and the computed `properties` (sorted by name, unset properties are skipped). Returns `None` if the DOM doesn't exist.
+///
+/// # Safety
+///
+/// `testharness` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTestHarness_serializeLayout(testharness: AzTestHarnessPtr, dom: AzDomId, properties: AzCssPropertyTypeVec) -> AzOptionString { ffi_guard!(AzOptionString, { let testharness = unsafe { az_test_harness::downcast(testharness, "AzTestHarness_serializeLayout") }; crate::snapshot::serialize_layout(testharness, dom, properties) }) }
 
 /// Options on how to initially create the window
 pub use azul_core::window::WindowCreateOptions as AzWindowCreateOptionsTT;
 pub use AzWindowCreateOptionsTT as AzWindowCreateOptions;
 /// Creates a new window configuration with a custom layout callback
-#[no_mangle] pub extern "C" fn AzWindowCreateOptions_new(layout_callback: AzLayoutCallbackType) -> AzWindowCreateOptions { AzWindowCreateOptions::new(layout_callback) }
+#[no_mangle] pub extern "C" fn AzWindowCreateOptions_new(layout_callback: AzLayoutCallbackType) -> AzWindowCreateOptions { ffi_guard!(AzWindowCreateOptions, { AzWindowCreateOptions::new(layout_callback) }) }
+/// Sets the layout callback of the window, replacing the callback passed to `new`. Windows created with `default()` render an empty DOM until a callback is set.
+#[no_mangle] pub extern "C" fn AzWindowCreateOptions_setLayoutCallback(windowcreateoptions: &mut AzWindowCreateOptions, layout_callback: AzLayoutCallbackType) { ffi_guard!((), { windowcreateoptions.set_layout_callback(layout_callback) }) }
+/// Registers a keyboard shortcut for the window before it is created. Accelerators run before the `VirtualKeyDown` callbacks of the nodes: if the callback returns anything other than `DoNothing`, the nodes don't see the key.
+#[no_mangle] pub extern "C" fn AzWindowCreateOptions_addAccelerator(windowcreateoptions: &mut AzWindowCreateOptions, accelerator: AzAccelerator, data: AzRefAny, callback: AzCallbackType) -> AzAcceleratorId { ffi_guard!(AzAcceleratorId, { windowcreateoptions.add_accelerator(accelerator, data, callback) }) }
 /// Destructor: Takes ownership of the `WindowCreateOptions` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzWindowCreateOptions_delete(object: &mut AzWindowCreateOptions) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzWindowCreateOptions_delete(object: &mut AzWindowCreateOptions) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
 pub use azul_core::window::RendererOptions as AzRendererOptionsTT;
@@ -149,16 +318,16 @@ pub use azul_core::window::LogicalPosition as AzLogicalPositionTT;
 pub use AzLogicalPositionTT as AzLogicalPosition;
 /// Creates a new `LogicalPosition` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `LogicalPosition::new()` constructor.
-#[no_mangle] pub extern "C" fn AzLogicalPosition_new(x: f32, y: f32) -> AzLogicalPosition { AzLogicalPosition::new(x, y) }
+#[no_mangle] pub extern "C" fn AzLogicalPosition_new(x: f32, y: f32) -> AzLogicalPosition { ffi_guard!(AzLogicalPosition, { AzLogicalPosition::new(x, y) }) }
 /// Creates a new `LogicalPosition` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `LogicalPosition::zero()` constructor.
-#[no_mangle] pub extern "C" fn AzLogicalPosition_zero() -> AzLogicalPosition { AzLogicalPosition::zero() }
+#[no_mangle] pub extern "C" fn AzLogicalPosition_zero() -> AzLogicalPosition { ffi_guard!(AzLogicalPosition, { AzLogicalPosition::zero() }) }
 
 /// A size in "logical" (non-HiDPI-adjusted) pixels in floating-point units
 pub use azul_core::window::LogicalSize as AzLogicalSizeTT;
 pub use AzLogicalSizeTT as AzLogicalSize;
 /// Equivalent to the Rust `LogicalSize::to_physical()` function.
-#[no_mangle] pub extern "C" fn AzLogicalSize_toPhysical(logicalsize: &AzLogicalSize, hidpi_factor: f32) -> AzPhysicalSizeU32 { logicalsize.to_physical(hidpi_factor) }
+#[no_mangle] pub extern "C" fn AzLogicalSize_toPhysical(logicalsize: &AzLogicalSize, hidpi_factor: f32) -> AzPhysicalSizeU32 { ffi_guard!(AzPhysicalSizeU32, { logicalsize.to_physical(hidpi_factor) }) }
 
 /// Unique hash of a window icon, so that azul does not have to compare the actual bytes to see wether the window icon has changed.
 pub use azul_core::window::IconKey as AzIconKeyTT;
@@ -168,25 +337,25 @@ pub use AzIconKeyTT as AzIconKey;
 pub use azul_core::window::SmallWindowIconBytes as AzSmallWindowIconBytesTT;
 pub use AzSmallWindowIconBytesTT as AzSmallWindowIconBytes;
 /// Destructor: Takes ownership of the `SmallWindowIconBytes` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSmallWindowIconBytes_delete(object: &mut AzSmallWindowIconBytes) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSmallWindowIconBytes_delete(object: &mut AzSmallWindowIconBytes) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Large (32x32x4) window icon, usually used on high-resolution displays (instead of `SmallWindowIcon`)
 pub use azul_core::window::LargeWindowIconBytes as AzLargeWindowIconBytesTT;
 pub use AzLargeWindowIconBytesTT as AzLargeWindowIconBytes;
 /// Destructor: Takes ownership of the `LargeWindowIconBytes` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzLargeWindowIconBytes_delete(object: &mut AzLargeWindowIconBytes) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzLargeWindowIconBytes_delete(object: &mut AzLargeWindowIconBytes) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Window "favicon", usually shown in the top left of the window on Windows
 pub use azul_core::window::WindowIcon as AzWindowIconTT;
 pub use AzWindowIconTT as AzWindowIcon;
 /// Destructor: Takes ownership of the `WindowIcon` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzWindowIcon_delete(object: &mut AzWindowIcon) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzWindowIcon_delete(object: &mut AzWindowIcon) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Application taskbar icon, 256x256x4 bytes in size
 pub use azul_core::window::TaskBarIcon as AzTaskBarIconTT;
 pub use AzTaskBarIconTT as AzTaskBarIcon;
 /// Destructor: Takes ownership of the `TaskBarIcon` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTaskBarIcon_delete(object: &mut AzTaskBarIcon) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTaskBarIcon_delete(object: &mut AzTaskBarIcon) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Symbolic name for a keyboard key, does **not** take the keyboard locale into account
 pub use azul_core::window::VirtualKeyCode as AzVirtualKeyCodeTT;
@@ -196,11 +365,31 @@ pub use AzVirtualKeyCodeTT as AzVirtualKeyCode;
 pub use azul_core::window::AcceleratorKey as AzAcceleratorKeyTT;
 pub use AzAcceleratorKeyTT as AzAcceleratorKey;
 
+/// Which modifier keys are held down (left and right keys are not distinguished)
+pub use azul_core::window::Modifiers as AzModifiersTT;
+pub use AzModifiersTT as AzModifiers;
+
+/// Window-wide keyboard shortcut (i.e. `Ctrl+S`) that fires regardless of which node is focused
+pub use azul_core::window::Accelerator as AzAcceleratorTT;
+pub use AzAcceleratorTT as AzAccelerator;
+/// Creates an accelerator for `key` + exactly the given `modifiers`
+#[no_mangle] pub extern "C" fn AzAccelerator_new(modifiers: AzModifiers, key: AzVirtualKeyCode) -> AzAccelerator { ffi_guard!(AzAccelerator, { AzAccelerator::new(modifiers, key) }) }
+
+/// ID of a registered accelerator, used to replace or remove it
+pub use azul_core::window::AcceleratorId as AzAcceleratorIdTT;
+pub use AzAcceleratorIdTT as AzAcceleratorId;
+
+/// Accelerator + the callback to invoke when it is pressed
+pub use azul_core::window::AcceleratorCallback as AzAcceleratorCallbackTT;
+pub use AzAcceleratorCallbackTT as AzAcceleratorCallback;
+/// Destructor: Takes ownership of the `AcceleratorCallback` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzAcceleratorCallback_delete(object: &mut AzAcceleratorCallback) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
 /// Minimum / maximum / current size of the window in logical dimensions
 pub use azul_core::window::WindowSize as AzWindowSizeTT;
 pub use AzWindowSizeTT as AzWindowSize;
 /// Returns the hidpi factor of the bounds
-#[no_mangle] pub extern "C" fn AzWindowSize_getHidpiFactor(windowsize: &AzWindowSize) -> f32 { windowsize.get_hidpi_factor() }
+#[no_mangle] pub extern "C" fn AzWindowSize_getHidpiFactor(windowsize: &AzWindowSize) -> f32 { ffi_guard!(f32, { windowsize.get_hidpi_factor() }) }
 
 /// Boolean flags relating to the current window state
 pub use azul_core::window::WindowFlags as AzWindowFlagsTT;
@@ -218,17 +407,33 @@ pub use AzDebugStateTT as AzDebugState;
 pub use azul_core::window::KeyboardState as AzKeyboardStateTT;
 pub use AzKeyboardStateTT as AzKeyboardState;
 /// Returns if the `SHIFT` key is held down (left OR right shift)
-#[no_mangle] pub extern "C" fn AzKeyboardState_shiftDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.shift_down() }
+#[no_mangle] pub extern "C" fn AzKeyboardState_shiftDown(keyboardstate: &AzKeyboardState) -> bool { ffi_guard!(bool, { keyboardstate.shift_down() }) }
 /// Returns if the `CTRL` key is held down
-#[no_mangle] pub extern "C" fn AzKeyboardState_ctrlDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.ctrl_down() }
+#[no_mangle] pub extern "C" fn AzKeyboardState_ctrlDown(keyboardstate: &AzKeyboardState) -> bool { ffi_guard!(bool, { keyboardstate.ctrl_down() }) }
 /// Returns if the `ALT` key is held down
-#[no_mangle] pub extern "C" fn AzKeyboardState_altDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.alt_down() }
+#[no_mangle] pub extern "C" fn AzKeyboardState_altDown(keyboardstate: &AzKeyboardState) -> bool { ffi_guard!(bool, { keyboardstate.alt_down() }) }
 /// Returns if the `SUPER` ("Windows") key is held down
-#[no_mangle] pub extern "C" fn AzKeyboardState_superDown(keyboardstate: &AzKeyboardState) -> bool { keyboardstate.super_down() }
+#[no_mangle] pub extern "C" fn AzKeyboardState_superDown(keyboardstate: &AzKeyboardState) -> bool { ffi_guard!(bool, { keyboardstate.super_down() }) }
 /// Returns if a key is held down
-#[no_mangle] pub extern "C" fn AzKeyboardState_isKeyDown(keyboardstate: &AzKeyboardState, key: AzVirtualKeyCode) -> bool { keyboardstate.is_key_down(key) }
+#[no_mangle] pub extern "C" fn AzKeyboardState_isKeyDown(keyboardstate: &AzKeyboardState, key: AzVirtualKeyCode) -> bool { ffi_guard!(bool, { keyboardstate.is_key_down(key) }) }
 /// Destructor: Takes ownership of the `KeyboardState` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzKeyboardState_delete(object: &mut AzKeyboardState) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzKeyboardState_delete(object: &mut AzKeyboardState) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Event of the input method editor (IME), used to compose text that can't be typed directly (CJK characters, emojis, etc.)
+pub use azul_core::window::ImeEvent as AzImeEventTT;
+pub use AzImeEventTT as AzImeEvent;
+/// Destructor: Takes ownership of the `ImeEvent` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzImeEvent_delete(object: &mut AzImeEvent) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Text that is currently being composed by the IME (usually rendered underlined)
+pub use azul_core::window::ImePreedit as AzImePreeditTT;
+pub use AzImePreeditTT as AzImePreedit;
+/// Destructor: Takes ownership of the `ImePreedit` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzImePreedit_delete(object: &mut AzImePreedit) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Byte range in the `ImePreedit` text
+pub use azul_core::window::ImeCursorRange as AzImeCursorRangeTT;
+pub use AzImeCursorRangeTT as AzImeCursorRange;
 
 /// Current icon of the mouse cursor
 pub use azul_core::window::MouseCursorType as AzMouseCursorTypeTT;
@@ -238,7 +443,7 @@ pub use AzMouseCursorTypeTT as AzMouseCursorType;
 pub use azul_core::window::CursorPosition as AzCursorPositionTT;
 pub use AzCursorPositionTT as AzCursorPosition;
 /// Equivalent to the Rust `CursorPosition::get_position()` function.
-#[no_mangle] pub extern "C" fn AzCursorPosition_getPosition(cursorposition: &AzCursorPosition) -> AzOptionLogicalPosition { cursorposition.get_position().into() }
+#[no_mangle] pub extern "C" fn AzCursorPosition_getPosition(cursorposition: &AzCursorPosition) -> AzOptionLogicalPosition { ffi_guard!(AzOptionLogicalPosition, { cursorposition.get_position().into() }) }
 
 /// Current mouse / cursor state
 pub use azul_core::window::MouseState as AzMouseStateTT;
@@ -248,19 +453,19 @@ pub use AzMouseStateTT as AzMouseState;
 pub use azul_core::window::PlatformSpecificOptions as AzPlatformSpecificOptionsTT;
 pub use AzPlatformSpecificOptionsTT as AzPlatformSpecificOptions;
 /// Destructor: Takes ownership of the `PlatformSpecificOptions` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzPlatformSpecificOptions_delete(object: &mut AzPlatformSpecificOptions) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzPlatformSpecificOptions_delete(object: &mut AzPlatformSpecificOptions) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Window configuration specific to Win32
 pub use azul_core::window::WindowsWindowOptions as AzWindowsWindowOptionsTT;
 pub use AzWindowsWindowOptionsTT as AzWindowsWindowOptions;
 /// Destructor: Takes ownership of the `WindowsWindowOptions` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzWindowsWindowOptions_delete(object: &mut AzWindowsWindowOptions) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzWindowsWindowOptions_delete(object: &mut AzWindowsWindowOptions) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// CSD theme of the window title / button controls
 pub use azul_core::window::WaylandTheme as AzWaylandThemeTT;
 pub use AzWaylandThemeTT as AzWaylandTheme;
 /// Destructor: Takes ownership of the `WaylandTheme` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzWaylandTheme_delete(object: &mut AzWaylandTheme) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzWaylandTheme_delete(object: &mut AzWaylandTheme) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Renderer type of the current windows OpenGL context
 pub use azul_core::window::RendererType as AzRendererTypeTT;
@@ -270,13 +475,13 @@ pub use AzRendererTypeTT as AzRendererType;
 pub use azul_core::window::AzStringPair as AzStringPairTT;
 pub use AzStringPairTT as AzStringPair;
 /// Destructor: Takes ownership of the `StringPair` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStringPair_delete(object: &mut AzStringPair) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStringPair_delete(object: &mut AzStringPair) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
 pub use azul_core::window::LinuxWindowOptions as AzLinuxWindowOptionsTT;
 pub use AzLinuxWindowOptionsTT as AzLinuxWindowOptions;
 /// Destructor: Takes ownership of the `LinuxWindowOptions` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzLinuxWindowOptions_delete(object: &mut AzLinuxWindowOptions) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzLinuxWindowOptions_delete(object: &mut AzLinuxWindowOptions) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `MacWindowOptions` struct
 pub use azul_core::window::MacWindowOptions as AzMacWindowOptionsTT;
@@ -310,7 +515,7 @@ pub use AzTouchStateTT as AzTouchState;
 pub use azul_core::window::Monitor as AzMonitorTT;
 pub use AzMonitorTT as AzMonitor;
 /// Destructor: Takes ownership of the `Monitor` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzMonitor_delete(object: &mut AzMonitor) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzMonitor_delete(object: &mut AzMonitor) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Describes a rendering configuration for a monitor
 pub use azul_core::window::VideoMode as AzVideoModeTT;
@@ -320,23 +525,23 @@ pub use AzVideoModeTT as AzVideoMode;
 pub use azul_core::window::WindowState as AzWindowStateTT;
 pub use AzWindowStateTT as AzWindowState;
 /// Creates a new WindowState with default settings and a custom layout callback
-#[no_mangle] pub extern "C" fn AzWindowState_new(layout_callback: AzLayoutCallbackType) -> AzWindowState { AzWindowState::new(layout_callback) }
+#[no_mangle] pub extern "C" fn AzWindowState_new(layout_callback: AzLayoutCallbackType) -> AzWindowState { ffi_guard!(AzWindowState, { AzWindowState::new(layout_callback) }) }
 /// Creates a default WindowState with an empty layout callback - useful only if you use the Rust `WindowState { .. WindowState::default() }` intialization syntax.
-#[no_mangle] pub extern "C" fn AzWindowState_default() -> AzWindowState { AzWindowState::default() }
+#[no_mangle] pub extern "C" fn AzWindowState_default() -> AzWindowState { ffi_guard!(AzWindowState, { AzWindowState::default() }) }
 /// Destructor: Takes ownership of the `WindowState` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzWindowState_delete(object: &mut AzWindowState) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzWindowState_delete(object: &mut AzWindowState) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutCallback` struct
 pub use azul_impl::callbacks::LayoutCallback as AzLayoutCallbackTT;
 pub use AzLayoutCallbackTT as AzLayoutCallback;
 /// Destructor: Takes ownership of the `LayoutCallback` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzLayoutCallback_delete(object: &mut AzLayoutCallback) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzLayoutCallback_delete(object: &mut AzLayoutCallback) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// C-ABI stable wrapper over a `MarshaledLayoutCallback`
 pub use azul_impl::callbacks::MarshaledLayoutCallback as AzMarshaledLayoutCallbackTT;
 pub use AzMarshaledLayoutCallbackTT as AzMarshaledLayoutCallback;
 /// Destructor: Takes ownership of the `MarshaledLayoutCallback` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzMarshaledLayoutCallback_delete(object: &mut AzMarshaledLayoutCallback) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzMarshaledLayoutCallback_delete(object: &mut AzMarshaledLayoutCallback) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// C-ABI stable wrapper over a `MarshaledLayoutCallbackInner`
 pub use azul_impl::callbacks::MarshaledLayoutCallbackInner as AzMarshaledLayoutCallbackInnerTT;
@@ -357,103 +562,147 @@ pub type AzCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo) -> A
 pub use azul_impl::callbacks::CallbackInfo as AzCallbackInfoTT;
 pub use AzCallbackInfoTT as AzCallbackInfo;
 /// Returns the `DomNodeId` of the element that the callback was attached to.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getHitNode(callbackinfo: &AzCallbackInfo) -> AzDomNodeId { callbackinfo.get_hit_node() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getHitNode(callbackinfo: &AzCallbackInfo) -> AzDomNodeId { ffi_guard!(AzDomNodeId, { callbackinfo.get_hit_node() }) }
 /// Returns the function pointer necessary to query the current time.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getSystemTimeFn(callbackinfo: &AzCallbackInfo) -> AzGetSystemTimeFn { callbackinfo.get_system_time_fn() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getSystemTimeFn(callbackinfo: &AzCallbackInfo) -> AzGetSystemTimeFn { ffi_guard!(AzGetSystemTimeFn, { callbackinfo.get_system_time_fn() }) }
 /// Returns the `LayoutPoint` of the cursor in the viewport (relative to the origin of the `Dom`). Set to `None` if the cursor is not in the current window.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getCursorRelativeToViewport(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { callbackinfo.get_cursor_relative_to_viewport() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getCursorRelativeToViewport(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { ffi_guard!(AzOptionLogicalPosition, { callbackinfo.get_cursor_relative_to_viewport() }) }
 /// Returns the `LayoutPoint` of the cursor in the viewport (relative to the origin of the `Dom`). Set to `None` if the cursor is not hovering over the current node.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getCursorRelativeToNode(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { callbackinfo.get_cursor_relative_to_node() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getCursorRelativeToNode(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { ffi_guard!(AzOptionLogicalPosition, { callbackinfo.get_cursor_relative_to_node() }) }
 /// Returns a copy of the current windows `WindowState`.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentWindowState(callbackinfo: &AzCallbackInfo) -> AzWindowState { callbackinfo.get_current_window_state() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentWindowState(callbackinfo: &AzCallbackInfo) -> AzWindowState { ffi_guard!(AzWindowState, { callbackinfo.get_current_window_state() }) }
 /// Returns a copy of the internal `KeyboardState`. Same as `self.get_window_state().keyboard_state`
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentKeyboardState(callbackinfo: &AzCallbackInfo) -> AzKeyboardState { callbackinfo.get_current_keyboard_state() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentKeyboardState(callbackinfo: &AzCallbackInfo) -> AzKeyboardState { ffi_guard!(AzKeyboardState, { callbackinfo.get_current_keyboard_state() }) }
 /// Returns a copy of the internal `MouseState`. Same as `self.get_window_state().mouse_state`
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentMouseState(callbackinfo: &AzCallbackInfo) -> AzMouseState { callbackinfo.get_current_mouse_state() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentMouseState(callbackinfo: &AzCallbackInfo) -> AzMouseState { ffi_guard!(AzMouseState, { callbackinfo.get_current_mouse_state() }) }
+/// Returns the last event of the input method editor (IME). Same as `self.get_current_keyboard_state().current_ime_event`
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getImeEvent(callbackinfo: &AzCallbackInfo) -> AzOptionImeEvent { ffi_guard!(AzOptionImeEvent, { callbackinfo.get_ime_event() }) }
+/// Returns the paths of the files that are currently dragged over the window
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getHoveredFiles(callbackinfo: &AzCallbackInfo) -> AzStringVec { ffi_guard!(AzStringVec, { callbackinfo.get_hovered_files() }) }
+/// Returns the paths of the files of the last drop onto the window
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getDroppedFiles(callbackinfo: &AzCallbackInfo) -> AzStringVec { ffi_guard!(AzStringVec, { callbackinfo.get_dropped_files() }) }
+/// Returns the cursor position (relative to the window) at which the files were dropped, `None` if the current event is not a `On::DroppedFile` event
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getDropPosition(callbackinfo: &AzCallbackInfo) -> AzOptionLogicalPosition { ffi_guard!(AzOptionLogicalPosition, { callbackinfo.get_drop_position() }) }
+/// Returns the topmost node that the files were dropped on, `None` if the current event is not a `On::DroppedFile` event
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getDropTarget(callbackinfo: &AzCallbackInfo) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { callbackinfo.get_drop_target().into() }) }
 /// Returns a copy of the current windows `WindowState`.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getPreviousWindowState(callbackinfo: &AzCallbackInfo) -> AzOptionWindowState { callbackinfo.get_previous_window_state().into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getPreviousWindowState(callbackinfo: &AzCallbackInfo) -> AzOptionWindowState { ffi_guard!(AzOptionWindowState, { callbackinfo.get_previous_window_state().into() }) }
 /// Returns a copy of the internal `KeyboardState`. Same as `self.get_window_state().keyboard_state`
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getPreviousKeyboardState(callbackinfo: &AzCallbackInfo) -> AzOptionKeyboardState { callbackinfo.get_previous_keyboard_state().into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getPreviousKeyboardState(callbackinfo: &AzCallbackInfo) -> AzOptionKeyboardState { ffi_guard!(AzOptionKeyboardState, { callbackinfo.get_previous_keyboard_state().into() }) }
 /// Returns a copy of the internal `MouseState`. Same as `self.get_window_state().mouse_state`
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getPreviousMouseState(callbackinfo: &AzCallbackInfo) -> AzOptionMouseState { callbackinfo.get_previous_mouse_state().into() }
-/// Returns a copy of the current windows `RawWindowHandle`.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentWindowHandle(callbackinfo: &AzCallbackInfo) -> AzRawWindowHandle { callbackinfo.get_current_window_handle() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getPreviousMouseState(callbackinfo: &AzCallbackInfo) -> AzOptionMouseState { ffi_guard!(AzOptionMouseState, { callbackinfo.get_previous_mouse_state().into() }) }
+/// Returns the native handle of the window the callback was invoked on (HWND on Windows, NSWindow / NSView on macOS, Xlib or Wayland handles on Linux) or `Unsupported` if the platform doesn't provide one. The handle is only valid during the callback: the window can be closed after the callback returns, so the handle must not be stored.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentWindowHandle(callbackinfo: &AzCallbackInfo) -> AzRawWindowHandle { ffi_guard!(AzRawWindowHandle, { callbackinfo.get_current_window_handle() }) }
 /// Returns a **reference-counted copy** of the current windows' `Gl` (context). You can use this to render OpenGL textures.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getGlContext(callbackinfo: &AzCallbackInfo) -> AzOptionGl { callbackinfo.get_gl_context() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getGlContext(callbackinfo: &AzCallbackInfo) -> AzOptionGl { ffi_guard!(AzOptionGl, { callbackinfo.get_gl_context() }) }
 /// Returns the x / y offset that this node has been scrolled to by the user or `None` if the node has not been scrolled.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getScrollPosition(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalPosition { callbackinfo.get_scroll_position(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getScrollPosition(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalPosition { ffi_guard!(AzOptionLogicalPosition, { callbackinfo.get_scroll_position(node_id).into() }) }
+/// Returns the maximum x / y offset that this node can be scrolled to (size of the scrolled content minus the size of the container) or `None` if the node is not a scroll container.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getScrollExtent(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalSize { ffi_guard!(AzOptionLogicalSize, { callbackinfo.get_scroll_extent(node_id).into() }) }
 /// Returns the `dataset` property of the given Node or `None` if the node doesn't have a `dataset` property.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getDataset(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionRefAny { callbackinfo.get_dataset(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getDataset(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionRefAny { ffi_guard!(AzOptionRefAny, { callbackinfo.get_dataset(node_id).into() }) }
 /// Given a dataset, returns the node ID of the "root" `RefAny`, i.e. the `RefAny` with the lowest `instance` count that is set as a `dataset` on any node.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getNodeIdOfRootDataset(callbackinfo: &mut AzCallbackInfo, dataset: AzRefAny) -> AzOptionDomNodeId { callbackinfo.get_node_id_of_root_dataset(dataset).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getNodeIdOfRootDataset(callbackinfo: &mut AzCallbackInfo, dataset: AzRefAny) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { callbackinfo.get_node_id_of_root_dataset(dataset).into() }) }
 /// If the node is a `Text` node, returns a copy of the internal string contents.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getStringContents(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionString { callbackinfo.get_string_contents(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getStringContents(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionString { ffi_guard!(AzOptionString, { callbackinfo.get_string_contents(node_id).into() }) }
 /// If the node is a `Text` node, returns the layouted inline glyphs of the text currently rendered on the screen
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getInlineText(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionInlineText { callbackinfo.get_inline_text(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getInlineText(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionInlineText { ffi_guard!(AzOptionInlineText, { callbackinfo.get_inline_text(node_id).into() }) }
 /// If the node is a `Text` node, returns the `FontRef` that was used to render this node. Useful for getting font metrics for a text string
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getFontRef(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionFontRef { callbackinfo.get_font_ref(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getFontRef(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionFontRef { ffi_guard!(AzOptionFontRef, { callbackinfo.get_font_ref(node_id).into() }) }
 /// Equivalent to the Rust `CallbackInfo::get_text_layout_options()` function.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getTextLayoutOptions(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionResolvedTextLayoutOptions { callbackinfo.get_text_layout_options(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getTextLayoutOptions(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionResolvedTextLayoutOptions { ffi_guard!(AzOptionResolvedTextLayoutOptions, { callbackinfo.get_text_layout_options(node_id).into() }) }
 /// Similar to `get_inline_text()`: If the node is a `Text` node, shape the `text` string with the same parameters as the current text and return the calculated InlineTextLayout. Necessary to calculate text cursor offsets and to detect when a line overflows content.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_shapeText(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId, text: AzString) -> AzOptionInlineText { azul_impl::text_layout::callback_info_shape_text(callbackinfo, node_id, text).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_shapeText(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId, text: AzString) -> AzOptionInlineText { ffi_guard!(AzOptionInlineText, { azul_impl::text_layout::callback_info_shape_text(callbackinfo, node_id, text).into() }) }
 /// Returns the index of the node relative to the parent node.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getIndexInParent(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> usize { let mut t = 0; let mut n = node_id; while let Some(prev) = callbackinfo.get_previous_sibling(n) { n = prev; t += 1; } t }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getIndexInParent(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> usize { ffi_guard!(usize, { let mut t = 0; let mut n = node_id; while let Some(prev) = callbackinfo.get_previous_sibling(n) { n = prev; t += 1; } t }) }
 /// Returns the parent `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getParent(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { callbackinfo.get_parent(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getParent(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { callbackinfo.get_parent(node_id).into() }) }
 /// Returns the previous siblings `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getPreviousSibling(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { callbackinfo.get_previous_sibling(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getPreviousSibling(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { callbackinfo.get_previous_sibling(node_id).into() }) }
 /// Returns the next siblings `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getNextSibling(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { callbackinfo.get_next_sibling(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getNextSibling(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { callbackinfo.get_next_sibling(node_id).into() }) }
 /// Returns the next siblings `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getFirstChild(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { callbackinfo.get_first_child(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getFirstChild(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { callbackinfo.get_first_child(node_id).into() }) }
 /// Returns the next siblings `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getLastChild(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { callbackinfo.get_last_child(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getLastChild(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { callbackinfo.get_last_child(node_id).into() }) }
 /// Returns the position of a given DOM node in the UI
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getNodePosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionPositionInfo { callbackinfo.get_node_position(node_id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getNodePosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionPositionInfo { ffi_guard!(AzOptionPositionInfo, { callbackinfo.get_node_position(node_id).into() }) }
 /// Returns the size of a given DOM node in the UI
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getNodeSize(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalSize { callbackinfo.get_node_size(node_id).into() }
-/// Returns the current computed CSS property of a given DOM node in the UI
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getComputedCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, property_type: AzCssPropertyType) -> AzOptionCssProperty { callbackinfo.get_computed_css_property(node_id, property_type).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getNodeSize(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId) -> AzOptionLogicalSize { ffi_guard!(AzOptionLogicalSize, { callbackinfo.get_node_size(node_id).into() }) }
+/// Returns the current computed CSS property of a given DOM node in the UI, including inherited and default values
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getComputedCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, property_type: AzCssPropertyType) -> AzOptionCssProperty { ffi_guard!(AzOptionCssProperty, { callbackinfo.get_computed_css_property(node_id, property_type).into() }) }
+/// Returns all computed CSS properties of a given DOM node, including inherited and default values
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getComputedCssProperties(callbackinfo: &AzCallbackInfo, node_id: AzDomNodeId) -> AzCssPropertyVec { ffi_guard!(AzCssPropertyVec, { callbackinfo.get_computed_css_properties(node_id) }) }
 /// Sets the new `WindowState` for the next frame. The window is updated after all callbacks are run.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_setWindowState(callbackinfo: &mut AzCallbackInfo, new_state: AzWindowState) { callbackinfo.set_window_state(new_state); }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setWindowState(callbackinfo: &mut AzCallbackInfo, new_state: AzWindowState) { ffi_guard!((), { callbackinfo.set_window_state(new_state); }) }
+/// Moves the IME candidate window to the given position (i.e. the text cursor), relative to the top left of the window
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setImePosition(callbackinfo: &mut AzCallbackInfo, position: AzLogicalPosition) { ffi_guard!((), { callbackinfo.set_ime_position(position); }) }
 /// Sets the new `FocusTarget` for the next frame. Note that this will emit a `On::FocusLost` and `On::FocusReceived` event, if the focused node has changed.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_setFocus(callbackinfo: &mut AzCallbackInfo, target: AzFocusTarget) { callbackinfo.set_focus(target); }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setFocus(callbackinfo: &mut AzCallbackInfo, target: AzFocusTarget) { ffi_guard!((), { callbackinfo.set_focus(target); }) }
 /// Sets a `CssProperty` on a given node to its new value. If this property change affects the layout, this will automatically trigger a relayout and redraw of the screen.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_setCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_property: AzCssProperty) { callbackinfo.set_css_property(node_id, new_property);  }
-/// Sets the scroll position of the node
-#[no_mangle] pub extern "C" fn AzCallbackInfo_setScrollPosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) { callbackinfo.set_scroll_position(node_id, scroll_position) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setCssProperty(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_property: AzCssProperty) { ffi_guard!((), { callbackinfo.set_css_property(node_id, new_property);  }) }
+/// Replaces the stylesheet of the current window (i.e. to switch between a light and a dark theme). The new stylesheet is applied to the next DOM returned by the layout callback, so this always triggers a DOM regeneration and a full relayout.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setCss(callbackinfo: &mut AzCallbackInfo, css: AzCss) { ffi_guard!((), { callbackinfo.set_css(css);  }) }
+/// Sets the scroll position of the node for the next frame, clamped to the scrollable range of the node (see `get_scroll_extent`)
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setScrollPosition(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) -> bool { ffi_guard!(bool, { callbackinfo.set_scroll_position(node_id, scroll_position) }) }
 /// If the node is a `Text` node, overwrites the `Text` content with the new string, without requiring the entire UI to be rebuilt.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_setStringContents(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, string: AzString) { callbackinfo.set_string_contents(node_id, string) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setStringContents(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, string: AzString) { ffi_guard!((), { callbackinfo.set_string_contents(node_id, string) }) }
 /// Adds a new image identified by an ID to the image cache
-#[no_mangle] pub extern "C" fn AzCallbackInfo_addImage(callbackinfo: &mut AzCallbackInfo, id: AzString, image: AzImageRef) { callbackinfo.add_image(id, image) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_addImage(callbackinfo: &mut AzCallbackInfo, id: AzString, image: AzImageRef) { ffi_guard!((), { callbackinfo.add_image(id, image) }) }
+/// Copies the pixels into a new image and adds it to the image cache under `id`. Returns `false` if `len` doesn't match the image size or the format is not supported.
+///
+/// # Safety
+///
+/// `data` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzCallbackInfo_addImageRaw(callbackinfo: &mut AzCallbackInfo, id: AzString, data: *const u8, len: usize, width: u32, height: u32, format: AzRawImageFormat) -> bool { ffi_guard!(bool, { crate::image::callback_info_add_image_raw(callbackinfo, id, data, len, width, height, format) }) }
+/// Replaces the pixels of the image registered under `id`, keeping its texture (i.e. for every frame of a video stream). The new pixels are uploaded before the next frame is rendered. Returns `false` if there is no image with the same size registered under `id` (use `add_image_raw` in that case) or if the data is invalid.
+///
+/// # Safety
+///
+/// `data` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzCallbackInfo_updateImageRaw(callbackinfo: &mut AzCallbackInfo, id: AzString, data: *const u8, len: usize, width: u32, height: u32, format: AzRawImageFormat) -> bool { ffi_guard!(bool, { crate::image::callback_info_update_image_raw(callbackinfo, id, data, len, width, height, format) }) }
 /// Returns whether an image with a given CSS ID already exists
-#[no_mangle] pub extern "C" fn AzCallbackInfo_hasImage(callbackinfo: &AzCallbackInfo, id: AzString) -> bool { callbackinfo.has_image(&id) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_hasImage(callbackinfo: &AzCallbackInfo, id: AzString) -> bool { ffi_guard!(bool, { callbackinfo.has_image(&id) }) }
 /// Returns the image with a given CSS ID
-#[no_mangle] pub extern "C" fn AzCallbackInfo_getImage(callbackinfo: &AzCallbackInfo, id: AzString) -> AzOptionImageRef { callbackinfo.get_image(&id).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getImage(callbackinfo: &AzCallbackInfo, id: AzString) -> AzOptionImageRef { ffi_guard!(AzOptionImageRef, { callbackinfo.get_image(&id).into() }) }
 /// If the node is an `Image`, exchanges the current image with a new source
-#[no_mangle] pub extern "C" fn AzCallbackInfo_updateImage(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_image: AzImageRef, image_type: AzUpdateImageType) { callbackinfo.update_image(node_id, new_image, image_type) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_updateImage(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_image: AzImageRef, image_type: AzUpdateImageType) { ffi_guard!((), { callbackinfo.update_image(node_id, new_image, image_type) }) }
 /// Deletes an image identified by a CSS ID from the image cache
-#[no_mangle] pub extern "C" fn AzCallbackInfo_deleteImage(callbackinfo: &mut AzCallbackInfo, id: AzString) { callbackinfo.delete_image(&id) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_deleteImage(callbackinfo: &mut AzCallbackInfo, id: AzString) { ffi_guard!((), { callbackinfo.delete_image(&id) }) }
+/// Registers a keyboard shortcut for the window of the callback, see `replace_accelerator` / `remove_accelerator`
+#[no_mangle] pub extern "C" fn AzCallbackInfo_addAccelerator(callbackinfo: &mut AzCallbackInfo, accelerator: AzAccelerator, data: AzRefAny, callback: AzCallbackType) -> AzAcceleratorId { ffi_guard!(AzAcceleratorId, { callbackinfo.add_accelerator(accelerator, data, callback) }) }
+/// Replaces the shortcut and callback of an accelerator, returns `false` if the `id` doesn't exist
+#[no_mangle] pub extern "C" fn AzCallbackInfo_replaceAccelerator(callbackinfo: &mut AzCallbackInfo, id: AzAcceleratorId, accelerator: AzAccelerator, data: AzRefAny, callback: AzCallbackType) -> bool { ffi_guard!(bool, { callbackinfo.replace_accelerator(id, accelerator, data, callback) }) }
+/// Removes an accelerator, returns `false` if the `id` doesn't exist
+#[no_mangle] pub extern "C" fn AzCallbackInfo_removeAccelerator(callbackinfo: &mut AzCallbackInfo, id: AzAcceleratorId) -> bool { ffi_guard!(bool, { callbackinfo.remove_accelerator(id) }) }
+/// Opens `menu` at `position` (relative to the window) after the callback returns. On Windows the menu is a native popup menu, on all other platforms it is drawn on top of the DOM. Clicking an item invokes its callback with the hit node of this callback.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_openContextMenu(callbackinfo: &mut AzCallbackInfo, menu: AzMenu, position: AzLogicalPosition) { ffi_guard!((), { callbackinfo.open_context_menu(menu, position) }) }
+/// Closes the menu opened with `open_context_menu` (no-op for native popup menus, which close themselves)
+#[no_mangle] pub extern "C" fn AzCallbackInfo_closeContextMenu(callbackinfo: &mut AzCallbackInfo) { ffi_guard!((), { callbackinfo.close_context_menu() }) }
 /// If the node has an `ImageMask`, exchanges the current mask for the new mask
-#[no_mangle] pub extern "C" fn AzCallbackInfo_updateImageMask(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_mask: AzImageMask) { callbackinfo.update_image_mask(node_id, new_mask) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_updateImageMask(callbackinfo: &mut AzCallbackInfo, node_id: AzDomNodeId, new_mask: AzImageMask) { ffi_guard!((), { callbackinfo.update_image_mask(node_id, new_mask) }) }
 /// Stops the propagation of the current callback event type to the parent. Events are bubbled from the inside out (children first, then parents), this event stops the propagation of the event to the parent.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_stopPropagation(callbackinfo: &mut AzCallbackInfo) { callbackinfo.stop_propagation(); }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_stopPropagation(callbackinfo: &mut AzCallbackInfo) { ffi_guard!((), { callbackinfo.stop_propagation(); }) }
 /// Spawns a new window with the given `WindowCreateOptions`.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) { callbackinfo.create_window(new_window); }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) { ffi_guard!((), { callbackinfo.create_window(new_window); }) }
 /// Adds a new `Timer` to the runtime. See the documentation for `Timer` for more information.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_startTimer(callbackinfo: &mut AzCallbackInfo, timer: AzTimer) -> AzTimerId { callbackinfo.start_timer(timer) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_startTimer(callbackinfo: &mut AzCallbackInfo, timer: AzTimer) -> AzTimerId { ffi_guard!(AzTimerId, { callbackinfo.start_timer(timer) }) }
 /// Starts an animation timer on a give NodeId - same as a `Timer`, but uses a pre-configured interpolation function to drive the animation timer
-#[no_mangle] pub extern "C" fn AzCallbackInfo_startAnimation(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, animation: AzAnimation) -> AzOptionTimerId { callbackinfo.start_animation(node, animation).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_startAnimation(callbackinfo: &mut AzCallbackInfo, node: AzDomNodeId, animation: AzAnimation) -> AzOptionTimerId { ffi_guard!(AzOptionTimerId, { callbackinfo.start_animation(node, animation).into() }) }
 /// Stops / cancels a `Timer`. See the documentation for `Timer` for more information.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_stopTimer(callbackinfo: &mut AzCallbackInfo, timer_id: AzTimerId) -> bool { callbackinfo.stop_timer(timer_id) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_stopTimer(callbackinfo: &mut AzCallbackInfo, timer_id: AzTimerId) -> bool { ffi_guard!(bool, { callbackinfo.stop_timer(timer_id) }) }
 /// Starts a new `Thread` to the runtime. See the documentation for `Thread` for more information.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_startThread(callbackinfo: &mut AzCallbackInfo, thread_initialize_data: AzRefAny, writeback_data: AzRefAny, callback: AzThreadCallbackType) -> AzOptionThreadId { callbackinfo.start_thread(thread_initialize_data, writeback_data, callback).into() }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_startThread(callbackinfo: &mut AzCallbackInfo, thread_initialize_data: AzRefAny, writeback_data: AzRefAny, callback: AzThreadCallbackType) -> AzOptionThreadId { ffi_guard!(AzOptionThreadId, { callbackinfo.start_thread(thread_initialize_data, writeback_data, callback).into() }) }
+/// Starts a `PendingThread` on a background thread. `on_finish` runs on the main thread with the data of the thread after the thread function returned, even if the thread was asked to stop.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_startPendingThread(callbackinfo: &mut AzCallbackInfo, thread: AzPendingThread, on_finish: AzCallbackType) -> AzOptionThreadId { ffi_guard!(AzOptionThreadId, { crate::thread::start_pending_thread(callbackinfo, thread, on_finish) }) }
+/// Asks a thread to stop by sending it a `ThreadSendMsg::TerminateThread` after the callback returns. Unlike `stop_thread`, which drops the thread without waiting for it, the `on_finish` callback of a `PendingThread` still runs once the thread function returns.
+#[no_mangle] pub extern "C" fn AzCallbackInfo_requestThreadStop(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId) -> bool { ffi_guard!(bool, { callbackinfo.send_thread_msg(thread_id, AzThreadSendMsg::TerminateThread) }) }
 /// Sends a message to a background thread
-#[no_mangle] pub extern "C" fn AzCallbackInfo_sendThreadMsg(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId, msg: AzThreadSendMsg) -> bool { callbackinfo.send_thread_msg(thread_id, msg) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_sendThreadMsg(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId, msg: AzThreadSendMsg) -> bool { ffi_guard!(bool, { callbackinfo.send_thread_msg(thread_id, msg) }) }
 /// Stops a thread at the nearest possible opportunity. Sends a `ThreadSendMsg::TerminateThread` message to the thread and joins the thread.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_stopThread(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId) -> bool { callbackinfo.stop_thread(thread_id) }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_stopThread(callbackinfo: &mut AzCallbackInfo, thread_id: AzThreadId) -> bool { ffi_guard!(bool, { callbackinfo.stop_thread(thread_id) }) }
 /// Destructor: Takes ownership of the `CallbackInfo` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCallbackInfo_delete(object: &mut AzCallbackInfo) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCallbackInfo_delete(object: &mut AzCallbackInfo) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Which type of image should be updated: background image (the CSS background) or content image (the <img src=""> content)
 pub use azul_impl::callbacks::UpdateImageType as AzUpdateImageTypeTT;
@@ -479,11 +728,11 @@ pub use AzDomNodeIdTT as AzDomNodeId;
 pub use azul_impl::ui_solver::PositionInfo as AzPositionInfoTT;
 pub use AzPositionInfoTT as AzPositionInfo;
 /// Equivalent to the Rust `PositionInfo::is_positioned()` function.
-#[no_mangle] pub extern "C" fn AzPositionInfo_isPositioned(positioninfo: &AzPositionInfo) -> bool { positioninfo.is_positioned() }
+#[no_mangle] pub extern "C" fn AzPositionInfo_isPositioned(positioninfo: &AzPositionInfo) -> bool { ffi_guard!(bool, { positioninfo.is_positioned() }) }
 /// Equivalent to the Rust `PositionInfo::get_static_offset()` function.
-#[no_mangle] pub extern "C" fn AzPositionInfo_getStaticOffset(positioninfo: &AzPositionInfo) -> AzLogicalPosition { positioninfo.get_static_offset() }
+#[no_mangle] pub extern "C" fn AzPositionInfo_getStaticOffset(positioninfo: &AzPositionInfo) -> AzLogicalPosition { ffi_guard!(AzLogicalPosition, { positioninfo.get_static_offset() }) }
 /// Equivalent to the Rust `PositionInfo::get_relative_offset()` function.
-#[no_mangle] pub extern "C" fn AzPositionInfo_getRelativeOffset(positioninfo: &AzPositionInfo) -> AzLogicalPosition { positioninfo.get_relative_offset() }
+#[no_mangle] pub extern "C" fn AzPositionInfo_getRelativeOffset(positioninfo: &AzPositionInfo) -> AzLogicalPosition { ffi_guard!(AzLogicalPosition, { positioninfo.get_relative_offset() }) }
 
 /// Re-export of rust-allocated (stack based) `PositionInfoInner` struct
 pub use azul_impl::ui_solver::PositionInfoInner as AzPositionInfoInnerTT;
@@ -493,37 +742,37 @@ pub use AzPositionInfoInnerTT as AzPositionInfoInner;
 pub use azul_impl::callbacks::HidpiAdjustedBounds as AzHidpiAdjustedBoundsTT;
 pub use AzHidpiAdjustedBoundsTT as AzHidpiAdjustedBounds;
 /// Returns the size of the bounds in logical units
-#[no_mangle] pub extern "C" fn AzHidpiAdjustedBounds_getLogicalSize(hidpiadjustedbounds: &AzHidpiAdjustedBounds) -> AzLogicalSize { hidpiadjustedbounds.get_logical_size() }
+#[no_mangle] pub extern "C" fn AzHidpiAdjustedBounds_getLogicalSize(hidpiadjustedbounds: &AzHidpiAdjustedBounds) -> AzLogicalSize { ffi_guard!(AzLogicalSize, { hidpiadjustedbounds.get_logical_size() }) }
 /// Returns the size of the bounds in physical units
-#[no_mangle] pub extern "C" fn AzHidpiAdjustedBounds_getPhysicalSize(hidpiadjustedbounds: &AzHidpiAdjustedBounds) -> AzPhysicalSizeU32 { hidpiadjustedbounds.get_physical_size() }
+#[no_mangle] pub extern "C" fn AzHidpiAdjustedBounds_getPhysicalSize(hidpiadjustedbounds: &AzHidpiAdjustedBounds) -> AzPhysicalSizeU32 { ffi_guard!(AzPhysicalSizeU32, { hidpiadjustedbounds.get_physical_size() }) }
 /// Returns the hidpi factor of the bounds
-#[no_mangle] pub extern "C" fn AzHidpiAdjustedBounds_getHidpiFactor(hidpiadjustedbounds: &AzHidpiAdjustedBounds) -> f32 { hidpiadjustedbounds.get_hidpi_factor() }
+#[no_mangle] pub extern "C" fn AzHidpiAdjustedBounds_getHidpiFactor(hidpiadjustedbounds: &AzHidpiAdjustedBounds) -> f32 { ffi_guard!(f32, { hidpiadjustedbounds.get_hidpi_factor() }) }
 
 /// Re-export of rust-allocated (stack based) `InlineText` struct
 pub use azul_impl::callbacks::InlineText as AzInlineTextTT;
 pub use AzInlineTextTT as AzInlineText;
 /// Hit-tests the inline text, returns detailed information about which glyph / word / line, etc. the position (usually the mouse cursor) is currently over. Result may be empty (no hits) or contain more than one result (cursor is hovering over multiple overlapping glyphs at once).
-#[no_mangle] pub extern "C" fn AzInlineText_hitTest(inlinetext: &AzInlineText, position: AzLogicalPosition) -> AzInlineTextHitVec { inlinetext.hit_test(position).into() }
+#[no_mangle] pub extern "C" fn AzInlineText_hitTest(inlinetext: &AzInlineText, position: AzLogicalPosition) -> AzInlineTextHitVec { ffi_guard!(AzInlineTextHitVec, { inlinetext.hit_test(position).into() }) }
 /// Destructor: Takes ownership of the `InlineText` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInlineText_delete(object: &mut AzInlineText) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInlineText_delete(object: &mut AzInlineText) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InlineLine` struct
 pub use azul_impl::callbacks::InlineLine as AzInlineLineTT;
 pub use AzInlineLineTT as AzInlineLine;
 /// Destructor: Takes ownership of the `InlineLine` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInlineLine_delete(object: &mut AzInlineLine) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInlineLine_delete(object: &mut AzInlineLine) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InlineWord` struct
 pub use azul_core::callbacks::InlineWord as AzInlineWordTT;
 pub use AzInlineWordTT as AzInlineWord;
 /// Destructor: Takes ownership of the `InlineWord` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInlineWord_delete(object: &mut AzInlineWord) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInlineWord_delete(object: &mut AzInlineWord) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InlineTextContents` struct
 pub use azul_core::callbacks::InlineTextContents as AzInlineTextContentsTT;
 pub use AzInlineTextContentsTT as AzInlineTextContents;
 /// Destructor: Takes ownership of the `InlineTextContents` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInlineTextContents_delete(object: &mut AzInlineTextContents) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInlineTextContents_delete(object: &mut AzInlineTextContents) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InlineGlyph` struct
 pub use azul_core::callbacks::InlineGlyph as AzInlineGlyphTT;
@@ -537,28 +786,28 @@ pub use AzInlineTextHitTT as AzInlineTextHit;
 pub use azul_impl::callbacks::FocusTarget as AzFocusTargetTT;
 pub use AzFocusTargetTT as AzFocusTarget;
 /// Destructor: Takes ownership of the `FocusTarget` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFocusTarget_delete(object: &mut AzFocusTarget) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFocusTarget_delete(object: &mut AzFocusTarget) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// CSS path to set the keyboard input focus
 pub use azul_impl::callbacks::FocusTargetPath as AzFocusTargetPathTT;
 pub use AzFocusTargetPathTT as AzFocusTargetPath;
 /// Destructor: Takes ownership of the `FocusTargetPath` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFocusTargetPath_delete(object: &mut AzFocusTargetPath) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFocusTargetPath_delete(object: &mut AzFocusTargetPath) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ResolvedTextLayoutOptions` struct
 pub use azul_impl::ui_solver::ResolvedTextLayoutOptions as AzResolvedTextLayoutOptionsTT;
 pub use AzResolvedTextLayoutOptionsTT as AzResolvedTextLayoutOptions;
 /// Creates a new `ResolvedTextLayoutOptions` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `ResolvedTextLayoutOptions::default()` constructor.
-#[no_mangle] pub extern "C" fn AzResolvedTextLayoutOptions_default() -> AzResolvedTextLayoutOptions { AzResolvedTextLayoutOptions::default() }
+#[no_mangle] pub extern "C" fn AzResolvedTextLayoutOptions_default() -> AzResolvedTextLayoutOptions { ffi_guard!(AzResolvedTextLayoutOptions, { AzResolvedTextLayoutOptions::default() }) }
 /// Destructor: Takes ownership of the `ResolvedTextLayoutOptions` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzResolvedTextLayoutOptions_delete(object: &mut AzResolvedTextLayoutOptions) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzResolvedTextLayoutOptions_delete(object: &mut AzResolvedTextLayoutOptions) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Animation struct to start a new animation
 pub use azul_impl::callbacks::Animation as AzAnimationTT;
 pub use AzAnimationTT as AzAnimation;
 /// Destructor: Takes ownership of the `Animation` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzAnimation_delete(object: &mut AzAnimation) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzAnimation_delete(object: &mut AzAnimation) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// How should an animation repeat (loop, ping-pong, etc.)
 pub use azul_impl::callbacks::AnimationRepeat as AzAnimationRepeatTT;
@@ -585,7 +834,7 @@ pub use AzIFrameCallbackInfoTT as AzIFrameCallbackInfo;
 pub use azul_impl::callbacks::IFrameCallbackReturn as AzIFrameCallbackReturnTT;
 pub use AzIFrameCallbackReturnTT as AzIFrameCallbackReturn;
 /// Destructor: Takes ownership of the `IFrameCallbackReturn` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzIFrameCallbackReturn_delete(object: &mut AzIFrameCallbackReturn) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzIFrameCallbackReturn_delete(object: &mut AzIFrameCallbackReturn) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `RenderImageCallback` struct
 pub use azul_impl::callbacks::RenderImageCallback as AzRenderImageCallbackTT;
@@ -596,27 +845,27 @@ pub type AzRenderImageCallbackType = extern "C" fn(&mut AzRefAny, &mut AzRenderI
 pub use azul_impl::callbacks::RenderImageCallbackInfo as AzRenderImageCallbackInfoTT;
 pub use AzRenderImageCallbackInfoTT as AzRenderImageCallbackInfo;
 /// Returns a copy of the internal `Gl`
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getGlContext(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzOptionGl { renderimagecallbackinfo.get_gl_context().into() }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getGlContext(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzOptionGl { ffi_guard!(AzOptionGl, { renderimagecallbackinfo.get_gl_context().into() }) }
 /// Returns a copy of the internal `HidpiAdjustedBounds`
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getBounds(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzHidpiAdjustedBounds { renderimagecallbackinfo.get_bounds() }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getBounds(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzHidpiAdjustedBounds { ffi_guard!(AzHidpiAdjustedBounds, { renderimagecallbackinfo.get_bounds() }) }
 /// Returns the `DomNodeId` that this callback was called on
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getCallbackNodeId(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzDomNodeId { renderimagecallbackinfo.get_callback_node_id() }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getCallbackNodeId(renderimagecallbackinfo: &AzRenderImageCallbackInfo) -> AzDomNodeId { ffi_guard!(AzDomNodeId, { renderimagecallbackinfo.get_callback_node_id() }) }
 /// If the node is a `Text` node, returns the layouted inline glyphs
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getInlineText(renderimagecallbackinfo: &AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionInlineText { renderimagecallbackinfo.get_inline_text(node_id).into() }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getInlineText(renderimagecallbackinfo: &AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionInlineText { ffi_guard!(AzOptionInlineText, { renderimagecallbackinfo.get_inline_text(node_id).into() }) }
 /// Returns the index of the node relative to the parent node.
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getIndexInParent(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> usize { let mut t = 0; let mut n = node_id; while let Some(prev) = renderimagecallbackinfo.get_previous_sibling(n) { n = prev; t += 1; } t }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getIndexInParent(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> usize { ffi_guard!(usize, { let mut t = 0; let mut n = node_id; while let Some(prev) = renderimagecallbackinfo.get_previous_sibling(n) { n = prev; t += 1; } t }) }
 /// Returns the parent `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getParent(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { renderimagecallbackinfo.get_parent(node_id).into() }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getParent(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { renderimagecallbackinfo.get_parent(node_id).into() }) }
 /// Returns the previous siblings `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getPreviousSibling(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { renderimagecallbackinfo.get_previous_sibling(node_id).into() }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getPreviousSibling(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { renderimagecallbackinfo.get_previous_sibling(node_id).into() }) }
 /// Returns the next siblings `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getNextSibling(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { renderimagecallbackinfo.get_next_sibling(node_id).into() }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getNextSibling(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { renderimagecallbackinfo.get_next_sibling(node_id).into() }) }
 /// Returns the next siblings `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getFirstChild(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { renderimagecallbackinfo.get_first_child(node_id).into() }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getFirstChild(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { renderimagecallbackinfo.get_first_child(node_id).into() }) }
 /// Returns the next siblings `DomNodeId` of the given `DomNodeId`. Returns `None` on an invalid NodeId.
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getLastChild(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { renderimagecallbackinfo.get_last_child(node_id).into() }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_getLastChild(renderimagecallbackinfo: &mut AzRenderImageCallbackInfo, node_id: AzDomNodeId) -> AzOptionDomNodeId { ffi_guard!(AzOptionDomNodeId, { renderimagecallbackinfo.get_last_child(node_id).into() }) }
 /// Destructor: Takes ownership of the `RenderImageCallbackInfo` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_delete(object: &mut AzRenderImageCallbackInfo) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzRenderImageCallbackInfo_delete(object: &mut AzRenderImageCallbackInfo) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TimerCallback` struct
 pub use azul_impl::callbacks::TimerCallback as AzTimerCallbackTT;
@@ -627,7 +876,7 @@ pub type AzTimerCallbackType = extern "C" fn(&mut AzRefAny, &mut AzTimerCallback
 pub use azul_impl::callbacks::TimerCallbackInfo as AzTimerCallbackInfoTT;
 pub use AzTimerCallbackInfoTT as AzTimerCallbackInfo;
 /// Destructor: Takes ownership of the `TimerCallbackInfo` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTimerCallbackInfo_delete(object: &mut AzTimerCallbackInfo) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTimerCallbackInfo_delete(object: &mut AzTimerCallbackInfo) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TimerCallbackReturn` struct
 pub use azul_impl::callbacks::TimerCallbackReturn as AzTimerCallbackReturnTT;
@@ -643,24 +892,25 @@ pub use azul_impl::callbacks::ThreadCallback as AzThreadCallbackTT;
 pub use AzThreadCallbackTT as AzThreadCallback;
 
 pub type AzThreadCallbackType = extern "C" fn(AzRefAny, AzThreadSender, AzThreadReceiver);
+pub type AzThreadFnType = extern "C" fn(&mut AzRefAny, &mut AzThreadSender, &mut AzThreadReceiver);
 pub type AzRefAnyDestructorType = extern "C" fn(&mut c_void);
 /// Re-export of rust-allocated (stack based) `RefCount` struct
 pub use azul_impl::callbacks::RefCount as AzRefCountTT;
 pub use AzRefCountTT as AzRefCount;
 /// Equivalent to the Rust `RefCount::can_be_shared()` function.
-#[no_mangle] pub extern "C" fn AzRefCount_canBeShared(refcount: &AzRefCount) -> bool { refcount.can_be_shared() }
+#[no_mangle] pub extern "C" fn AzRefCount_canBeShared(refcount: &AzRefCount) -> bool { ffi_guard!(bool, { refcount.can_be_shared() }) }
 /// Equivalent to the Rust `RefCount::can_be_shared_mut()` function.
-#[no_mangle] pub extern "C" fn AzRefCount_canBeSharedMut(refcount: &AzRefCount) -> bool { refcount.can_be_shared_mut() }
+#[no_mangle] pub extern "C" fn AzRefCount_canBeSharedMut(refcount: &AzRefCount) -> bool { ffi_guard!(bool, { refcount.can_be_shared_mut() }) }
 /// Equivalent to the Rust `RefCount::increase_ref()` function.
-#[no_mangle] pub extern "C" fn AzRefCount_increaseRef(refcount: &mut AzRefCount) { refcount.increase_ref() }
+#[no_mangle] pub extern "C" fn AzRefCount_increaseRef(refcount: &mut AzRefCount) { ffi_guard!((), { refcount.increase_ref() }) }
 /// Equivalent to the Rust `RefCount::decrease_ref()` function.
-#[no_mangle] pub extern "C" fn AzRefCount_decreaseRef(refcount: &mut AzRefCount) { refcount.decrease_ref() }
+#[no_mangle] pub extern "C" fn AzRefCount_decreaseRef(refcount: &mut AzRefCount) { ffi_guard!((), { refcount.decrease_ref() }) }
 /// Equivalent to the Rust `RefCount::increase_refmut()` function.
-#[no_mangle] pub extern "C" fn AzRefCount_increaseRefmut(refcount: &mut AzRefCount) { refcount.increase_refmut() }
+#[no_mangle] pub extern "C" fn AzRefCount_increaseRefmut(refcount: &mut AzRefCount) { ffi_guard!((), { refcount.increase_refmut() }) }
 /// Equivalent to the Rust `RefCount::decrease_refmut()` function.
-#[no_mangle] pub extern "C" fn AzRefCount_decreaseRefmut(refcount: &mut AzRefCount) { refcount.decrease_refmut() }
+#[no_mangle] pub extern "C" fn AzRefCount_decreaseRefmut(refcount: &mut AzRefCount) { ffi_guard!((), { refcount.decrease_refmut() }) }
 /// Destructor: Takes ownership of the `RefCount` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzRefCount_delete(object: &mut AzRefCount) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzRefCount_delete(object: &mut AzRefCount) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzRefCount_deepCopy(object: &AzRefCount) -> AzRefCount { object.clone() }
 
@@ -669,13 +919,19 @@ pub use azul_impl::callbacks::RefAny as AzRefAnyTT;
 pub use AzRefAnyTT as AzRefAny;
 /// Creates a new `RefAny` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `RefAny::new_c()` constructor.
-#[no_mangle] pub extern "C" fn AzRefAny_newC(ptr: *const c_void, len: usize, type_id: u64, type_name: AzString, destructor: AzRefAnyDestructorType) -> AzRefAny { AzRefAny::new_c(ptr, len, type_id, type_name, destructor) }
-/// Equivalent to the Rust `RefAny::get_type_id()` function.
-#[no_mangle] pub extern "C" fn AzRefAny_getTypeId(refany: &AzRefAny) -> u64 { refany.get_type_id() }
-/// Equivalent to the Rust `RefAny::get_type_name()` function.
-#[no_mangle] pub extern "C" fn AzRefAny_getTypeName(refany: &AzRefAny) -> AzString { refany.get_type_name() }
+///
+/// # Safety
+///
+/// `ptr` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzRefAny_newC(ptr: *const c_void, len: usize, type_id: u64, type_name: AzString, destructor: AzRefAnyDestructorType) -> AzRefAny { ffi_guard!(AzRefAny, { AzRefAny::new_c(ptr, len, type_id, type_name, destructor) }) }
+/// Returns the type ID stored in the `RefAny`, useful for debugging callbacks that receive a `RefAny` of the wrong type
+#[no_mangle] pub extern "C" fn AzRefAny_getTypeId(refany: &AzRefAny) -> u64 { ffi_guard!(u64, { refany.get_type_id() }) }
+/// Returns a copy of the type name stored in the `RefAny`, i.e. `"MyDataModel"`
+#[no_mangle] pub extern "C" fn AzRefAny_getTypeName(refany: &AzRefAny) -> AzString { ffi_guard!(AzString, { refany.get_type_name() }) }
+/// Returns the number of copies of the `RefAny` that are still alive, including this one. Useful for finding leaked copies.
+#[no_mangle] pub extern "C" fn AzRefAny_strongCount(refany: &AzRefAny) -> usize { ffi_guard!(usize, { refany.get_strong_count() }) }
 /// Destructor: Takes ownership of the `RefAny` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzRefAny_delete(object: &mut AzRefAny) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzRefAny_delete(object: &mut AzRefAny) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzRefAny_deepCopy(object: &AzRefAny) -> AzRefAny { object.clone() }
 
@@ -683,244 +939,264 @@ pub use AzRefAnyTT as AzRefAny;
 pub use azul_impl::callbacks::LayoutCallbackInfo as AzLayoutCallbackInfoTT;
 pub use AzLayoutCallbackInfoTT as AzLayoutCallbackInfo;
 /// Returns a copy of the OpenGL context
-#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getGlContext(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzOptionGl { layoutcallbackinfo.get_gl_context() }
+#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getGlContext(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzOptionGl { ffi_guard!(AzOptionGl, { layoutcallbackinfo.get_gl_context() }) }
 /// Returns all system-native fonts with their respective file paths as values
-#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getSystemFonts(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzStringPairVec { layoutcallbackinfo.get_system_fonts().into() }
+#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getSystemFonts(layoutcallbackinfo: &AzLayoutCallbackInfo) -> AzStringPairVec { ffi_guard!(AzStringPairVec, { layoutcallbackinfo.get_system_fonts().into() }) }
 /// Returns an `ImageRef` referenced by a CSS ID
-#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getImage(layoutcallbackinfo: &AzLayoutCallbackInfo, id: AzString) -> AzOptionImageRef { layoutcallbackinfo.get_image(&id).into() }
+#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_getImage(layoutcallbackinfo: &AzLayoutCallbackInfo, id: AzString) -> AzOptionImageRef { ffi_guard!(AzOptionImageRef, { layoutcallbackinfo.get_image(&id).into() }) }
 /// Destructor: Takes ownership of the `LayoutCallbackInfo` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_delete(object: &mut AzLayoutCallbackInfo) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzLayoutCallbackInfo_delete(object: &mut AzLayoutCallbackInfo) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `Dom` struct
 pub use azul_impl::dom::Dom as AzDomTT;
 pub use AzDomTT as AzDom;
 /// Creates a new `Dom` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Dom::new()` constructor.
-#[no_mangle] pub extern "C" fn AzDom_new(node_type: AzNodeType) -> AzDom { AzDom::new(node_type) }
+#[no_mangle] pub extern "C" fn AzDom_new(node_type: AzNodeType) -> AzDom { ffi_guard!(AzDom, { AzDom::new(node_type) }) }
 /// Creates a new `Dom` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Dom::body()` constructor.
-#[no_mangle] pub extern "C" fn AzDom_body() -> AzDom { AzDom::body() }
+#[no_mangle] pub extern "C" fn AzDom_body() -> AzDom { ffi_guard!(AzDom, { AzDom::body() }) }
 /// Creates a new `Dom` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Dom::div()` constructor.
-#[no_mangle] pub extern "C" fn AzDom_div() -> AzDom { AzDom::div() }
+#[no_mangle] pub extern "C" fn AzDom_div() -> AzDom { ffi_guard!(AzDom, { AzDom::div() }) }
 /// Creates a new `Dom` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Dom::br()` constructor.
-#[no_mangle] pub extern "C" fn AzDom_br() -> AzDom { AzDom::br() }
+#[no_mangle] pub extern "C" fn AzDom_br() -> AzDom { ffi_guard!(AzDom, { AzDom::br() }) }
 /// Creates a new `Dom` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Dom::text()` constructor.
-#[no_mangle] pub extern "C" fn AzDom_text(string: AzString) -> AzDom { AzDom::text(string) }
+#[no_mangle] pub extern "C" fn AzDom_text(string: AzString) -> AzDom { ffi_guard!(AzDom, { AzDom::text(string) }) }
 /// Creates a new `Dom` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Dom::image()` constructor.
-#[no_mangle] pub extern "C" fn AzDom_image(image: AzImageRef) -> AzDom { AzDom::image(image) }
+#[no_mangle] pub extern "C" fn AzDom_image(image: AzImageRef) -> AzDom { ffi_guard!(AzDom, { AzDom::image(image) }) }
 /// Creates a new `Dom` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Dom::iframe()` constructor.
-#[no_mangle] pub extern "C" fn AzDom_iframe(data: AzRefAny, callback: AzIFrameCallbackType) -> AzDom { AzDom::iframe(data, callback) }
+#[no_mangle] pub extern "C" fn AzDom_iframe(data: AzRefAny, callback: AzIFrameCallbackType) -> AzDom { ffi_guard!(AzDom, { AzDom::iframe(data, callback) }) }
 /// Equivalent to the Rust `Dom::set_node_type()` function.
-#[no_mangle] pub extern "C" fn AzDom_setNodeType(dom: &mut AzDom, node_type: AzNodeType) { dom.root.set_node_type(node_type) }
+#[no_mangle] pub extern "C" fn AzDom_setNodeType(dom: &mut AzDom, node_type: AzNodeType) { ffi_guard!((), { dom.root.set_node_type(node_type) }) }
 /// Equivalent to the Rust `Dom::with_node_type()` function.
-#[no_mangle] pub extern "C" fn AzDom_withNodeType(dom: &mut AzDom, node_type: AzNodeType) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_node_type(node_type); dom }
+#[no_mangle] pub extern "C" fn AzDom_withNodeType(dom: &mut AzDom, node_type: AzNodeType) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_node_type(node_type); dom }) }
 /// Equivalent to the Rust `Dom::set_dataset()` function.
-#[no_mangle] pub extern "C" fn AzDom_setDataset(dom: &mut AzDom, dataset: AzRefAny) { dom.root.set_dataset(Some(dataset).into()) }
+#[no_mangle] pub extern "C" fn AzDom_setDataset(dom: &mut AzDom, dataset: AzRefAny) { ffi_guard!((), { dom.root.set_dataset(Some(dataset).into()) }) }
 /// Equivalent to the Rust `Dom::with_dataset()` function.
-#[no_mangle] pub extern "C" fn AzDom_withDataset(dom: &mut AzDom, dataset: AzRefAny) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_dataset(Some(dataset).into()); dom }
+#[no_mangle] pub extern "C" fn AzDom_withDataset(dom: &mut AzDom, dataset: AzRefAny) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_dataset(Some(dataset).into()); dom }) }
 /// Equivalent to the Rust `Dom::set_ids_and_classes()` function.
-#[no_mangle] pub extern "C" fn AzDom_setIdsAndClasses(dom: &mut AzDom, ids_and_classes: AzIdOrClassVec) { dom.root.set_ids_and_classes(ids_and_classes) }
+#[no_mangle] pub extern "C" fn AzDom_setIdsAndClasses(dom: &mut AzDom, ids_and_classes: AzIdOrClassVec) { ffi_guard!((), { dom.root.set_ids_and_classes(ids_and_classes) }) }
 /// Equivalent to the Rust `Dom::with_ids_and_classes()` function.
-#[no_mangle] pub extern "C" fn AzDom_withIdsAndClasses(dom: &mut AzDom, ids_and_classes: AzIdOrClassVec) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_ids_and_classes(ids_and_classes); dom }
+#[no_mangle] pub extern "C" fn AzDom_withIdsAndClasses(dom: &mut AzDom, ids_and_classes: AzIdOrClassVec) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_ids_and_classes(ids_and_classes); dom }) }
 /// Equivalent to the Rust `Dom::set_callbacks()` function.
-#[no_mangle] pub extern "C" fn AzDom_setCallbacks(dom: &mut AzDom, callbacks: AzCallbackDataVec) { dom.root.set_callbacks(callbacks) }
+#[no_mangle] pub extern "C" fn AzDom_setCallbacks(dom: &mut AzDom, callbacks: AzCallbackDataVec) { ffi_guard!((), { dom.root.set_callbacks(callbacks) }) }
 /// Equivalent to the Rust `Dom::with_callbacks()` function.
-#[no_mangle] pub extern "C" fn AzDom_withCallbacks(dom: &mut AzDom, callbacks: AzCallbackDataVec) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_callbacks(callbacks); dom }
+#[no_mangle] pub extern "C" fn AzDom_withCallbacks(dom: &mut AzDom, callbacks: AzCallbackDataVec) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_callbacks(callbacks); dom }) }
 /// Equivalent to the Rust `Dom::set_inline_css_props()` function.
-#[no_mangle] pub extern "C" fn AzDom_setInlineCssProps(dom: &mut AzDom, css_properties: AzNodeDataInlineCssPropertyVec) { dom.root.set_inline_css_props(css_properties) }
+#[no_mangle] pub extern "C" fn AzDom_setInlineCssProps(dom: &mut AzDom, css_properties: AzNodeDataInlineCssPropertyVec) { ffi_guard!((), { dom.root.set_inline_css_props(css_properties) }) }
 /// Equivalent to the Rust `Dom::with_inline_css_props()` function.
-#[no_mangle] pub extern "C" fn AzDom_withInlineCssProps(dom: &mut AzDom, css_properties: AzNodeDataInlineCssPropertyVec) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_inline_css_props(css_properties); dom }
+#[no_mangle] pub extern "C" fn AzDom_withInlineCssProps(dom: &mut AzDom, css_properties: AzNodeDataInlineCssPropertyVec) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_inline_css_props(css_properties); dom }) }
 /// Adds a child node to this DOM (potentially heap-allocates in Rust code). Swaps `self` with a default `Dom` in order to prevent accidental copies.
-#[no_mangle] pub extern "C" fn AzDom_addCallback(dom: &mut AzDom, event: AzEventFilter, data: AzRefAny, callback: AzCallbackType) { dom.root.add_callback(event, data, callback) }
+#[no_mangle] pub extern "C" fn AzDom_addCallback(dom: &mut AzDom, event: AzEventFilter, data: AzRefAny, callback: AzCallbackType) { ffi_guard!((), { dom.root.add_callback(event, data, callback) }) }
 /// Same as add_child, but as a builder method.
-#[no_mangle] pub extern "C" fn AzDom_withCallback(dom: &mut AzDom, event: AzEventFilter, data: AzRefAny, callback: AzCallbackType) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.add_callback(event, data, callback); dom }
+#[no_mangle] pub extern "C" fn AzDom_withCallback(dom: &mut AzDom, event: AzEventFilter, data: AzRefAny, callback: AzCallbackType) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.add_callback(event, data, callback); dom }) }
 /// Adds a child node to this DOM (potentially heap-allocates in Rust code). Swaps `self` with a default `Dom` in order to prevent accidental copies.
-#[no_mangle] pub extern "C" fn AzDom_addChild(dom: &mut AzDom, child: AzDom) { dom.add_child(child) }
+#[no_mangle] pub extern "C" fn AzDom_addChild(dom: &mut AzDom, child: AzDom) { ffi_guard!((), { dom.add_child(child) }) }
 /// Same as add_child, but as a builder method.
-#[no_mangle] pub extern "C" fn AzDom_withChild(dom: &mut AzDom, child: AzDom) -> AzDom { dom.with_child(child) }
+#[no_mangle] pub extern "C" fn AzDom_withChild(dom: &mut AzDom, child: AzDom) -> AzDom { ffi_guard!(AzDom, { dom.with_child(child) }) }
 /// Adds a child node to this DOM (potentially heap-allocates in Rust code). Swaps `self` with a default `Dom` in order to prevent accidental copies.
-#[no_mangle] pub extern "C" fn AzDom_setChildren(dom: &mut AzDom, children: AzDomVec) { dom.set_children(children) }
+#[no_mangle] pub extern "C" fn AzDom_setChildren(dom: &mut AzDom, children: AzDomVec) { ffi_guard!((), { dom.set_children(children) }) }
 /// Same as set_children, but as a builder method.
-#[no_mangle] pub extern "C" fn AzDom_withChildren(dom: &mut AzDom, children: AzDomVec) -> AzDom { let mut dom = dom.swap_with_default(); dom.set_children(children); dom }
+#[no_mangle] pub extern "C" fn AzDom_withChildren(dom: &mut AzDom, children: AzDomVec) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.set_children(children); dom }) }
 /// Adds an CSS ID to the DOM root node.
-#[no_mangle] pub extern "C" fn AzDom_addId(dom: &mut AzDom, id: AzString) { dom.root.add_id(id) }
+#[no_mangle] pub extern "C" fn AzDom_addId(dom: &mut AzDom, id: AzString) { ffi_guard!((), { dom.root.add_id(id) }) }
 /// Same as add_id, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withId(dom: &mut AzDom, id: AzString) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.add_id(id); dom }
+#[no_mangle] pub extern "C" fn AzDom_withId(dom: &mut AzDom, id: AzString) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.add_id(id); dom }) }
 /// Adds a CSS class to the DOM root node.
-#[no_mangle] pub extern "C" fn AzDom_addClass(dom: &mut AzDom, class: AzString) { dom.root.add_class(class) }
+#[no_mangle] pub extern "C" fn AzDom_addClass(dom: &mut AzDom, class: AzString) { ffi_guard!((), { dom.root.add_class(class) }) }
 /// Same as add_class, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withClass(dom: &mut AzDom, class: AzString) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.add_class(class); dom }
+#[no_mangle] pub extern "C" fn AzDom_withClass(dom: &mut AzDom, class: AzString) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.add_class(class); dom }) }
 /// Adds an inline (normal) CSS property to the DOM root node.
-#[no_mangle] pub extern "C" fn AzDom_addCssProperty(dom: &mut AzDom, prop: AzCssProperty) { dom.root.add_normal_css_property(prop) }
+#[no_mangle] pub extern "C" fn AzDom_addCssProperty(dom: &mut AzDom, prop: AzCssProperty) { ffi_guard!((), { dom.root.add_normal_css_property(prop) }) }
 /// Same as add_class, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withCssProperty(dom: &mut AzDom, prop: AzCssProperty) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.add_normal_css_property(prop); dom }
+#[no_mangle] pub extern "C" fn AzDom_withCssProperty(dom: &mut AzDom, prop: AzCssProperty) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.add_normal_css_property(prop); dom }) }
 /// Adds an inline (hover) CSS property to the DOM root node.
-#[no_mangle] pub extern "C" fn AzDom_addHoverCssProperty(dom: &mut AzDom, prop: AzCssProperty) { dom.root.add_hover_css_property(prop) }
+#[no_mangle] pub extern "C" fn AzDom_addHoverCssProperty(dom: &mut AzDom, prop: AzCssProperty) { ffi_guard!((), { dom.root.add_hover_css_property(prop) }) }
 /// Same as add_class, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withHoverCssProperty(dom: &mut AzDom, prop: AzCssProperty) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.add_hover_css_property(prop); dom }
+#[no_mangle] pub extern "C" fn AzDom_withHoverCssProperty(dom: &mut AzDom, prop: AzCssProperty) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.add_hover_css_property(prop); dom }) }
 /// Adds an inline (hover) CSS property to the DOM root node.
-#[no_mangle] pub extern "C" fn AzDom_addActiveCssProperty(dom: &mut AzDom, prop: AzCssProperty) { dom.root.add_active_css_property(prop) }
+#[no_mangle] pub extern "C" fn AzDom_addActiveCssProperty(dom: &mut AzDom, prop: AzCssProperty) { ffi_guard!((), { dom.root.add_active_css_property(prop) }) }
 /// Same as add_class, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withActiveCssProperty(dom: &mut AzDom, prop: AzCssProperty) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.add_active_css_property(prop); dom }
+#[no_mangle] pub extern "C" fn AzDom_withActiveCssProperty(dom: &mut AzDom, prop: AzCssProperty) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.add_active_css_property(prop); dom }) }
 /// Adds an inline (hover) CSS property to the DOM root node.
-#[no_mangle] pub extern "C" fn AzDom_addFocusCssProperty(dom: &mut AzDom, prop: AzCssProperty) { dom.root.add_focus_css_property(prop) }
+#[no_mangle] pub extern "C" fn AzDom_addFocusCssProperty(dom: &mut AzDom, prop: AzCssProperty) { ffi_guard!((), { dom.root.add_focus_css_property(prop) }) }
 /// Same as add_class, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withFocusCssProperty(dom: &mut AzDom, prop: AzCssProperty) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.add_focus_css_property(prop); dom }
+#[no_mangle] pub extern "C" fn AzDom_withFocusCssProperty(dom: &mut AzDom, prop: AzCssProperty) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.add_focus_css_property(prop); dom }) }
 /// Equivalent to the Rust `Dom::set_inline_style()` function.
-#[no_mangle] pub extern "C" fn AzDom_setInlineStyle(dom: &mut AzDom, style: AzString) { AzNodeData_setInlineStyle(&mut dom.root, style); }
+#[no_mangle] pub extern "C" fn AzDom_setInlineStyle(dom: &mut AzDom, style: AzString) { ffi_guard!((), { AzNodeData_setInlineStyle(&mut dom.root, style); }) }
 /// Equivalent to the Rust `Dom::with_inline_style()` function.
-#[no_mangle] pub extern "C" fn AzDom_withInlineStyle(dom: &mut AzDom, style: AzString) -> AzDom { let mut dom = dom.swap_with_default(); AzNodeData_setInlineStyle(&mut dom.root, style); dom }
+#[no_mangle] pub extern "C" fn AzDom_withInlineStyle(dom: &mut AzDom, style: AzString) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); AzNodeData_setInlineStyle(&mut dom.root, style); dom }) }
 /// Equivalent to the Rust `Dom::set_inline_hover_style()` function.
-#[no_mangle] pub extern "C" fn AzDom_setInlineHoverStyle(dom: &mut AzDom, style: AzString) { AzNodeData_setInlineHoverStyle(&mut dom.root, style); }
+#[no_mangle] pub extern "C" fn AzDom_setInlineHoverStyle(dom: &mut AzDom, style: AzString) { ffi_guard!((), { AzNodeData_setInlineHoverStyle(&mut dom.root, style); }) }
 /// Equivalent to the Rust `Dom::with_inline_hover_style()` function.
-#[no_mangle] pub extern "C" fn AzDom_withInlineHoverStyle(dom: &mut AzDom, style: AzString) -> AzDom { let mut dom = dom.swap_with_default(); AzNodeData_setInlineHoverStyle(&mut dom.root, style); dom }
+#[no_mangle] pub extern "C" fn AzDom_withInlineHoverStyle(dom: &mut AzDom, style: AzString) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); AzNodeData_setInlineHoverStyle(&mut dom.root, style); dom }) }
 /// Equivalent to the Rust `Dom::set_inline_active_style()` function.
-#[no_mangle] pub extern "C" fn AzDom_setInlineActiveStyle(dom: &mut AzDom, style: AzString) { AzNodeData_setInlineActiveStyle(&mut dom.root, style); }
+#[no_mangle] pub extern "C" fn AzDom_setInlineActiveStyle(dom: &mut AzDom, style: AzString) { ffi_guard!((), { AzNodeData_setInlineActiveStyle(&mut dom.root, style); }) }
 /// Equivalent to the Rust `Dom::with_inline_active_style()` function.
-#[no_mangle] pub extern "C" fn AzDom_withInlineActiveStyle(dom: &mut AzDom, style: AzString) -> AzDom { let mut dom = dom.swap_with_default(); AzNodeData_setInlineActiveStyle(&mut dom.root, style); dom }
+#[no_mangle] pub extern "C" fn AzDom_withInlineActiveStyle(dom: &mut AzDom, style: AzString) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); AzNodeData_setInlineActiveStyle(&mut dom.root, style); dom }) }
 /// Equivalent to the Rust `Dom::set_inline_focus_style()` function.
-#[no_mangle] pub extern "C" fn AzDom_setInlineFocusStyle(dom: &mut AzDom, style: AzString) { AzNodeData_setInlineFocusStyle(&mut dom.root, style); }
+#[no_mangle] pub extern "C" fn AzDom_setInlineFocusStyle(dom: &mut AzDom, style: AzString) { ffi_guard!((), { AzNodeData_setInlineFocusStyle(&mut dom.root, style); }) }
 /// Equivalent to the Rust `Dom::with_inline_focus_style()` function.
-#[no_mangle] pub extern "C" fn AzDom_withInlineFocusStyle(dom: &mut AzDom, style: AzString) -> AzDom { let mut dom = dom.swap_with_default(); AzNodeData_setInlineFocusStyle(&mut dom.root, style); dom }
+#[no_mangle] pub extern "C" fn AzDom_withInlineFocusStyle(dom: &mut AzDom, style: AzString) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); AzNodeData_setInlineFocusStyle(&mut dom.root, style); dom }) }
 /// Sets the clip mask for the DOM root node.
-#[no_mangle] pub extern "C" fn AzDom_setClipMask(dom: &mut AzDom, clip_mask: AzImageMask) { dom.root.set_clip_mask(clip_mask) }
+#[no_mangle] pub extern "C" fn AzDom_setClipMask(dom: &mut AzDom, clip_mask: AzImageMask) { ffi_guard!((), { dom.root.set_clip_mask(clip_mask) }) }
 /// Same as set_clip_mask, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withClipMask(dom: &mut AzDom, clip_mask: AzImageMask) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_clip_mask(clip_mask); dom }
+#[no_mangle] pub extern "C" fn AzDom_withClipMask(dom: &mut AzDom, clip_mask: AzImageMask) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_clip_mask(clip_mask); dom }) }
 /// Sets the tab index for the DOM root node.
-#[no_mangle] pub extern "C" fn AzDom_setTabIndex(dom: &mut AzDom, tab_index: AzTabIndex) { dom.root.set_tab_index(tab_index) }
+#[no_mangle] pub extern "C" fn AzDom_setTabIndex(dom: &mut AzDom, tab_index: AzTabIndex) { ffi_guard!((), { dom.root.set_tab_index(tab_index) }) }
 /// Same as set_tab_index, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withTabIndex(dom: &mut AzDom, tab_index: AzTabIndex) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_tab_index(tab_index); dom }
+#[no_mangle] pub extern "C" fn AzDom_withTabIndex(dom: &mut AzDom, tab_index: AzTabIndex) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_tab_index(tab_index); dom }) }
 /// Sets accessibility attributes for the DOM root node.
-#[no_mangle] pub extern "C" fn AzDom_setAccessibilityInfo(dom: &mut AzDom, accessibility_info: AzAccessibilityInfo) { dom.root.set_accessibility_info(accessibility_info) }
+#[no_mangle] pub extern "C" fn AzDom_setAccessibilityInfo(dom: &mut AzDom, accessibility_info: AzAccessibilityInfo) { ffi_guard!((), { dom.root.set_accessibility_info(accessibility_info) }) }
 /// Same as set_accessibility_info, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withAccessibilityInfo(dom: &mut AzDom, accessibility_info: AzAccessibilityInfo) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_accessibility_info(accessibility_info); dom }
+#[no_mangle] pub extern "C" fn AzDom_withAccessibilityInfo(dom: &mut AzDom, accessibility_info: AzAccessibilityInfo) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_accessibility_info(accessibility_info); dom }) }
+/// Sets the accessibility role (button, list, ...) of the DOM root node, keeps the other accessibility attributes
+#[no_mangle] pub extern "C" fn AzDom_setAccessibilityRole(dom: &mut AzDom, role: AzAccessibilityRole) { ffi_guard!((), { dom.root.set_accessibility_role(role) }) }
+/// Same as set_accessibility_role, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withAccessibilityRole(dom: &mut AzDom, role: AzAccessibilityRole) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_accessibility_role(role); dom }) }
+/// Sets the accessible name of the DOM root node that is read by screen readers, keeps the other accessibility attributes
+#[no_mangle] pub extern "C" fn AzDom_setAccessibilityLabel(dom: &mut AzDom, label: AzString) { ffi_guard!((), { dom.root.set_accessibility_label(label) }) }
+/// Same as set_accessibility_label, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withAccessibilityLabel(dom: &mut AzDom, label: AzString) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_accessibility_label(label); dom }) }
 /// Sets the menu bar for the DOM root node. See `NodeData::set_menu_bar` for more information.
-#[no_mangle] pub extern "C" fn AzDom_setMenuBar(dom: &mut AzDom, menu_bar: AzMenu) { dom.root.set_menu_bar(menu_bar) }
+#[no_mangle] pub extern "C" fn AzDom_setMenuBar(dom: &mut AzDom, menu_bar: AzMenu) { ffi_guard!((), { dom.root.set_menu_bar(menu_bar) }) }
 /// Same as set_accessibility_info, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withMenuBar(dom: &mut AzDom, menu_bar: AzMenu) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_menu_bar(menu_bar); dom }
+#[no_mangle] pub extern "C" fn AzDom_withMenuBar(dom: &mut AzDom, menu_bar: AzMenu) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_menu_bar(menu_bar); dom }) }
 /// Sets the context menu for the DOM root node. See `NodeData::set_context_menu` for more information.
-#[no_mangle] pub extern "C" fn AzDom_setContextMenu(dom: &mut AzDom, context_menu: AzMenu) { dom.root.set_context_menu(context_menu) }
+#[no_mangle] pub extern "C" fn AzDom_setContextMenu(dom: &mut AzDom, context_menu: AzMenu) { ffi_guard!((), { dom.root.set_context_menu(context_menu) }) }
 /// Same as set_context_menu, but as a builder method
-#[no_mangle] pub extern "C" fn AzDom_withContextMenu(dom: &mut AzDom, context_menu: AzMenu) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_context_menu(context_menu); dom }
+#[no_mangle] pub extern "C" fn AzDom_withContextMenu(dom: &mut AzDom, context_menu: AzMenu) -> AzDom { ffi_guard!(AzDom, { let mut dom = dom.swap_with_default(); dom.root.set_context_menu(context_menu); dom }) }
 /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
-#[no_mangle] pub extern "C" fn AzDom_hash(dom: &AzDom) -> u64 { dom.root.calculate_node_data_hash().0 }
+#[no_mangle] pub extern "C" fn AzDom_hash(dom: &AzDom) -> u64 { ffi_guard!(u64, { dom.root.calculate_node_data_hash().0 }) }
 /// Returns the number of nodes in the DOM, including all child DOM trees. Result is equal to `self.total_children + 1` (count of all child trees + the root node)
-#[no_mangle] pub extern "C" fn AzDom_nodeCount(dom: &AzDom) -> usize { dom.node_count() }
+#[no_mangle] pub extern "C" fn AzDom_nodeCount(dom: &AzDom) -> usize { ffi_guard!(usize, { dom.node_count() }) }
+/// Returns the node type of the root node
+#[no_mangle] pub extern "C" fn AzDom_getNodeType(dom: &AzDom) -> AzNodeType { ffi_guard!(AzNodeType, { dom.get_node_type().clone() }) }
+/// Returns the number of direct children of the root node
+#[no_mangle] pub extern "C" fn AzDom_childrenCount(dom: &AzDom) -> usize { ffi_guard!(usize, { dom.children_count() }) }
+/// Returns the first node with the given id. Nodes are numbered in depth-first pre-order (root = 0), which is the same `NodeId` the node has after the `Dom` is styled
+#[no_mangle] pub extern "C" fn AzDom_findFirstById(dom: &AzDom, id: AzString) -> AzOptionNodeId { ffi_guard!(AzOptionNodeId, { dom.find_first_by_id(id.as_str()).map(|n| AzNodeId::from_crate_internal(Some(n))).into() }) }
+/// Returns all nodes with the given class, numbered the same way as in `find_first_by_id`
+#[no_mangle] pub extern "C" fn AzDom_findAllByClass(dom: &AzDom, class_name: AzString) -> AzNodeIdVec { ffi_guard!(AzNodeIdVec, { dom.find_all_by_class(class_name.as_str()).into_iter().map(|n| AzNodeId::from_crate_internal(Some(n))).collect::<Vec<_>>().into() }) }
 /// Returns a HTML string that you can write to a file in order to debug the UI structure and debug potential cascading issues
-#[no_mangle] pub extern "C" fn AzDom_getHtmlString(dom: &mut AzDom) -> AzString { dom.style(AzCss::empty()).get_html_string("", "", false).into() }
+#[no_mangle] pub extern "C" fn AzDom_getHtmlString(dom: &mut AzDom) -> AzString { ffi_guard!(AzString, { dom.style(AzCss::empty()).get_html_string("", "", false).into() }) }
 /// Returns a HTML for unit testing
-#[no_mangle] pub extern "C" fn AzDom_getHtmlStringTest(dom: &mut AzDom) -> AzString { dom.style(AzCss::empty()).get_html_string("", "", true).into() }
+#[no_mangle] pub extern "C" fn AzDom_getHtmlStringTest(dom: &mut AzDom) -> AzString { ffi_guard!(AzString, { dom.style(AzCss::empty()).get_html_string("", "", true).into() }) }
 /// Same as `StyledDom::new(dom, css)`: NOTE - replaces self with an empty DOM, in order to prevent cloning the DOM entirely
-#[no_mangle] pub extern "C" fn AzDom_style(dom: &mut AzDom, css: AzCss) -> AzStyledDom { dom.style(css) }
+#[no_mangle] pub extern "C" fn AzDom_style(dom: &mut AzDom, css: AzCss) -> AzStyledDom { ffi_guard!(AzStyledDom, { dom.style(css) }) }
 /// Destructor: Takes ownership of the `Dom` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzDom_delete(object: &mut AzDom) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzDom_delete(object: &mut AzDom) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `IFrameNode` struct
 pub use azul_impl::dom::IFrameNode as AzIFrameNodeTT;
 pub use AzIFrameNodeTT as AzIFrameNode;
 /// Destructor: Takes ownership of the `IFrameNode` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzIFrameNode_delete(object: &mut AzIFrameNode) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzIFrameNode_delete(object: &mut AzIFrameNode) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `CallbackData` struct
 pub use azul_impl::dom::CallbackData as AzCallbackDataTT;
 pub use AzCallbackDataTT as AzCallbackData;
 /// Destructor: Takes ownership of the `CallbackData` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCallbackData_delete(object: &mut AzCallbackData) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCallbackData_delete(object: &mut AzCallbackData) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Represents one single DOM node (node type, classes, ids and callbacks are stored here)
 pub use azul_impl::dom::NodeData as AzNodeDataTT;
 pub use AzNodeDataTT as AzNodeData;
 /// Creates an new, empty `NodeData` struct
-#[no_mangle] pub extern "C" fn AzNodeData_new(node_type: AzNodeType) -> AzNodeData { AzNodeData::new(node_type) }
+#[no_mangle] pub extern "C" fn AzNodeData_new(node_type: AzNodeType) -> AzNodeData { ffi_guard!(AzNodeData, { AzNodeData::new(node_type) }) }
 /// Creates a new `NodeData` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `NodeData::body()` constructor.
-#[no_mangle] pub extern "C" fn AzNodeData_body() -> AzNodeData { AzNodeData::body() }
+#[no_mangle] pub extern "C" fn AzNodeData_body() -> AzNodeData { ffi_guard!(AzNodeData, { AzNodeData::body() }) }
 /// Creates a new `NodeData` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `NodeData::div()` constructor.
-#[no_mangle] pub extern "C" fn AzNodeData_div() -> AzNodeData { AzNodeData::div() }
+#[no_mangle] pub extern "C" fn AzNodeData_div() -> AzNodeData { ffi_guard!(AzNodeData, { AzNodeData::div() }) }
 /// Creates a new `NodeData` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `NodeData::br()` constructor.
-#[no_mangle] pub extern "C" fn AzNodeData_br() -> AzNodeData { AzNodeData::br() }
+#[no_mangle] pub extern "C" fn AzNodeData_br() -> AzNodeData { ffi_guard!(AzNodeData, { AzNodeData::br() }) }
 /// Creates a new `NodeData` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `NodeData::text()` constructor.
-#[no_mangle] pub extern "C" fn AzNodeData_text(string: AzString) -> AzNodeData { AzNodeData::text(string) }
+#[no_mangle] pub extern "C" fn AzNodeData_text(string: AzString) -> AzNodeData { ffi_guard!(AzNodeData, { AzNodeData::text(string) }) }
 /// Creates a new `NodeData` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `NodeData::image()` constructor.
-#[no_mangle] pub extern "C" fn AzNodeData_image(image: AzImageRef) -> AzNodeData { AzNodeData::image(image) }
+#[no_mangle] pub extern "C" fn AzNodeData_image(image: AzImageRef) -> AzNodeData { ffi_guard!(AzNodeData, { AzNodeData::image(image) }) }
 /// Creates a new `NodeData` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `NodeData::iframe()` constructor.
-#[no_mangle] pub extern "C" fn AzNodeData_iframe(data: AzRefAny, callback: AzIFrameCallbackType) -> AzNodeData { AzNodeData::iframe(data, callback) }
+#[no_mangle] pub extern "C" fn AzNodeData_iframe(data: AzRefAny, callback: AzIFrameCallbackType) -> AzNodeData { ffi_guard!(AzNodeData, { AzNodeData::iframe(data, callback) }) }
 /// Equivalent to the Rust `NodeData::set_node_type()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_setNodeType(nodedata: &mut AzNodeData, node_type: AzNodeType) { nodedata.set_node_type(node_type) }
+#[no_mangle] pub extern "C" fn AzNodeData_setNodeType(nodedata: &mut AzNodeData, node_type: AzNodeType) { ffi_guard!((), { nodedata.set_node_type(node_type) }) }
 /// Equivalent to the Rust `NodeData::with_node_type()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_withNodeType(nodedata: &mut AzNodeData, node_type: AzNodeType) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); nodedata.set_node_type(node_type); nodedata }
+#[no_mangle] pub extern "C" fn AzNodeData_withNodeType(nodedata: &mut AzNodeData, node_type: AzNodeType) -> AzNodeData { ffi_guard!(AzNodeData, { let mut nodedata = nodedata.swap_with_default(); nodedata.set_node_type(node_type); nodedata }) }
 /// Equivalent to the Rust `NodeData::set_dataset()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_setDataset(nodedata: &mut AzNodeData, dataset: AzRefAny) { nodedata.set_dataset(Some(dataset).into()) }
+#[no_mangle] pub extern "C" fn AzNodeData_setDataset(nodedata: &mut AzNodeData, dataset: AzRefAny) { ffi_guard!((), { nodedata.set_dataset(Some(dataset).into()) }) }
 /// Equivalent to the Rust `NodeData::with_dataset()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_withDataset(nodedata: &mut AzNodeData, dataset: AzRefAny) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); nodedata.set_dataset(Some(dataset).into()); nodedata }
+#[no_mangle] pub extern "C" fn AzNodeData_withDataset(nodedata: &mut AzNodeData, dataset: AzRefAny) -> AzNodeData { ffi_guard!(AzNodeData, { let mut nodedata = nodedata.swap_with_default(); nodedata.set_dataset(Some(dataset).into()); nodedata }) }
 /// Equivalent to the Rust `NodeData::set_ids_and_classes()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_setIdsAndClasses(nodedata: &mut AzNodeData, ids_and_classes: AzIdOrClassVec) { nodedata.set_ids_and_classes(ids_and_classes) }
+#[no_mangle] pub extern "C" fn AzNodeData_setIdsAndClasses(nodedata: &mut AzNodeData, ids_and_classes: AzIdOrClassVec) { ffi_guard!((), { nodedata.set_ids_and_classes(ids_and_classes) }) }
 /// Equivalent to the Rust `NodeData::with_ids_and_classes()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_withIdsAndClasses(nodedata: &mut AzNodeData, ids_and_classes: AzIdOrClassVec) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); nodedata.set_ids_and_classes(ids_and_classes); nodedata }
+#[no_mangle] pub extern "C" fn AzNodeData_withIdsAndClasses(nodedata: &mut AzNodeData, ids_and_classes: AzIdOrClassVec) -> AzNodeData { ffi_guard!(AzNodeData, { let mut nodedata = nodedata.swap_with_default(); nodedata.set_ids_and_classes(ids_and_classes); nodedata }) }
 /// Adds a callback this DOM (potentially heap-allocates in Rust code)
-#[no_mangle] pub extern "C" fn AzNodeData_addCallback(nodedata: &mut AzNodeData, event: AzEventFilter, data: AzRefAny, callback: AzCallbackType) { nodedata.add_callback(event, data, callback) }
+#[no_mangle] pub extern "C" fn AzNodeData_addCallback(nodedata: &mut AzNodeData, event: AzEventFilter, data: AzRefAny, callback: AzCallbackType) { ffi_guard!((), { nodedata.add_callback(event, data, callback) }) }
 /// Same as add_child, but as a builder method.
-#[no_mangle] pub extern "C" fn AzNodeData_withCallback(nodedata: &mut AzNodeData, event: AzEventFilter, data: AzRefAny, callback: AzCallbackType) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); nodedata.add_callback(event, data, callback); nodedata }
+#[no_mangle] pub extern "C" fn AzNodeData_withCallback(nodedata: &mut AzNodeData, event: AzEventFilter, data: AzRefAny, callback: AzCallbackType) -> AzNodeData { ffi_guard!(AzNodeData, { let mut nodedata = nodedata.swap_with_default(); nodedata.add_callback(event, data, callback); nodedata }) }
 /// Equivalent to the Rust `NodeData::set_callbacks()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_setCallbacks(nodedata: &mut AzNodeData, callbacks: AzCallbackDataVec) { nodedata.set_callbacks(callbacks) }
+#[no_mangle] pub extern "C" fn AzNodeData_setCallbacks(nodedata: &mut AzNodeData, callbacks: AzCallbackDataVec) { ffi_guard!((), { nodedata.set_callbacks(callbacks) }) }
 /// Equivalent to the Rust `NodeData::with_callbacks()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_withCallbacks(nodedata: &mut AzNodeData, callbacks: AzCallbackDataVec) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); nodedata.set_callbacks(callbacks); nodedata }
+#[no_mangle] pub extern "C" fn AzNodeData_withCallbacks(nodedata: &mut AzNodeData, callbacks: AzCallbackDataVec) -> AzNodeData { ffi_guard!(AzNodeData, { let mut nodedata = nodedata.swap_with_default(); nodedata.set_callbacks(callbacks); nodedata }) }
 /// Equivalent to the Rust `NodeData::set_inline_css_props()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_setInlineCssProps(nodedata: &mut AzNodeData, css_properties: AzNodeDataInlineCssPropertyVec) { nodedata.set_inline_css_props(css_properties) }
+#[no_mangle] pub extern "C" fn AzNodeData_setInlineCssProps(nodedata: &mut AzNodeData, css_properties: AzNodeDataInlineCssPropertyVec) { ffi_guard!((), { nodedata.set_inline_css_props(css_properties) }) }
 /// Equivalent to the Rust `NodeData::with_inline_css_props()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_withInlineCssProps(nodedata: &mut AzNodeData, css_properties: AzNodeDataInlineCssPropertyVec) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); nodedata.set_inline_css_props(css_properties); nodedata }
+#[no_mangle] pub extern "C" fn AzNodeData_withInlineCssProps(nodedata: &mut AzNodeData, css_properties: AzNodeDataInlineCssPropertyVec) -> AzNodeData { ffi_guard!(AzNodeData, { let mut nodedata = nodedata.swap_with_default(); nodedata.set_inline_css_props(css_properties); nodedata }) }
 /// Equivalent to the Rust `NodeData::set_inline_style()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_setInlineStyle(nodedata: &mut AzNodeData, style: AzString) { nodedata.set_inline_css_props(nodedata.get_inline_css_props().with_append(AzNodeDataInlineCssPropertyVec::parse_normal(style.as_str()))) }
+#[no_mangle] pub extern "C" fn AzNodeData_setInlineStyle(nodedata: &mut AzNodeData, style: AzString) { ffi_guard!((), { nodedata.set_inline_css_props(nodedata.get_inline_css_props().with_append(AzNodeDataInlineCssPropertyVec::parse_normal(style.as_str()))) }) }
 /// Equivalent to the Rust `NodeData::with_inline_style()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_withInlineStyle(nodedata: &mut AzNodeData, style: AzString) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); AzNodeData_setInlineStyle(&mut nodedata, style); nodedata }
+#[no_mangle] pub extern "C" fn AzNodeData_withInlineStyle(nodedata: &mut AzNodeData, style: AzString) -> AzNodeData { ffi_guard!(AzNodeData, { let mut nodedata = nodedata.swap_with_default(); AzNodeData_setInlineStyle(&mut nodedata, style); nodedata }) }
 /// Equivalent to the Rust `NodeData::set_inline_hover_style()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_setInlineHoverStyle(nodedata: &mut AzNodeData, style: AzString) { nodedata.set_inline_css_props(nodedata.get_inline_css_props().with_append(AzNodeDataInlineCssPropertyVec::parse_hover(style.as_str()))) }
+#[no_mangle] pub extern "C" fn AzNodeData_setInlineHoverStyle(nodedata: &mut AzNodeData, style: AzString) { ffi_guard!((), { nodedata.set_inline_css_props(nodedata.get_inline_css_props().with_append(AzNodeDataInlineCssPropertyVec::parse_hover(style.as_str()))) }) }
 /// Equivalent to the Rust `NodeData::with_inline_hover_style()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_withInlineHoverStyle(nodedata: &mut AzNodeData, style: AzString) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); AzNodeData_setInlineHoverStyle(&mut nodedata, style); nodedata }
+#[no_mangle] pub extern "C" fn AzNodeData_withInlineHoverStyle(nodedata: &mut AzNodeData, style: AzString) -> AzNodeData { ffi_guard!(AzNodeData, { let mut nodedata = nodedata.swap_with_default(); AzNodeData_setInlineHoverStyle(&mut nodedata, style); nodedata }) }
 /// Equivalent to the Rust `NodeData::set_inline_active_style()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_setInlineActiveStyle(nodedata: &mut AzNodeData, style: AzString) { nodedata.set_inline_css_props(nodedata.get_inline_css_props().with_append(AzNodeDataInlineCssPropertyVec::parse_active(style.as_str()))) }
+#[no_mangle] pub extern "C" fn AzNodeData_setInlineActiveStyle(nodedata: &mut AzNodeData, style: AzString) { ffi_guard!((), { nodedata.set_inline_css_props(nodedata.get_inline_css_props().with_append(AzNodeDataInlineCssPropertyVec::parse_active(style.as_str()))) }) }
 /// Equivalent to the Rust `NodeData::with_inline_active_style()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_withInlineActiveStyle(nodedata: &mut AzNodeData, style: AzString) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); AzNodeData_setInlineActiveStyle(&mut nodedata, style); nodedata }
+#[no_mangle] pub extern "C" fn AzNodeData_withInlineActiveStyle(nodedata: &mut AzNodeData, style: AzString) -> AzNodeData { ffi_guard!(AzNodeData, { let mut nodedata = nodedata.swap_with_default(); AzNodeData_setInlineActiveStyle(&mut nodedata, style); nodedata }) }
 /// Equivalent to the Rust `NodeData::set_inline_focus_style()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_setInlineFocusStyle(nodedata: &mut AzNodeData, style: AzString) { nodedata.set_inline_css_props(nodedata.get_inline_css_props().with_append(AzNodeDataInlineCssPropertyVec::parse_focus(style.as_str()))) }
+#[no_mangle] pub extern "C" fn AzNodeData_setInlineFocusStyle(nodedata: &mut AzNodeData, style: AzString) { ffi_guard!((), { nodedata.set_inline_css_props(nodedata.get_inline_css_props().with_append(AzNodeDataInlineCssPropertyVec::parse_focus(style.as_str()))) }) }
 /// Equivalent to the Rust `NodeData::with_inline_focus_style()` function.
-#[no_mangle] pub extern "C" fn AzNodeData_withInlineFocusStyle(nodedata: &mut AzNodeData, style: AzString) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); AzNodeData_setInlineFocusStyle(&mut nodedata, style); nodedata }
+#[no_mangle] pub extern "C" fn AzNodeData_withInlineFocusStyle(nodedata: &mut AzNodeData, style: AzString) -> AzNodeData { ffi_guard!(AzNodeData, { let mut nodedata = nodedata.swap_with_default(); AzNodeData_setInlineFocusStyle(&mut nodedata, style); nodedata }) }
 /// Sets the `extra.clip_mask` field for this node
-#[no_mangle] pub extern "C" fn AzNodeData_setClipMask(nodedata: &mut AzNodeData, image_mask: AzImageMask) { nodedata.set_clip_mask(image_mask) }
+#[no_mangle] pub extern "C" fn AzNodeData_setClipMask(nodedata: &mut AzNodeData, image_mask: AzImageMask) { ffi_guard!((), { nodedata.set_clip_mask(image_mask) }) }
 /// Sets the tab index for this node
-#[no_mangle] pub extern "C" fn AzNodeData_setTabIndex(nodedata: &mut AzNodeData, tab_index: AzTabIndex) { nodedata.set_tab_index(tab_index) }
+#[no_mangle] pub extern "C" fn AzNodeData_setTabIndex(nodedata: &mut AzNodeData, tab_index: AzTabIndex) { ffi_guard!((), { nodedata.set_tab_index(tab_index) }) }
 /// Sets accessibility attributes for this node
-#[no_mangle] pub extern "C" fn AzNodeData_setAccessibilityInfo(nodedata: &mut AzNodeData, accessibility_info: AzAccessibilityInfo) { nodedata.set_accessibility_info(accessibility_info) }
+#[no_mangle] pub extern "C" fn AzNodeData_setAccessibilityInfo(nodedata: &mut AzNodeData, accessibility_info: AzAccessibilityInfo) { ffi_guard!((), { nodedata.set_accessibility_info(accessibility_info) }) }
+/// Sets the accessibility role (button, list, ...) of this node, keeps the other accessibility attributes
+#[no_mangle] pub extern "C" fn AzNodeData_setAccessibilityRole(nodedata: &mut AzNodeData, role: AzAccessibilityRole) { ffi_guard!((), { nodedata.set_accessibility_role(role) }) }
+/// Sets the accessible name of this node that is read by screen readers, keeps the other accessibility attributes
+#[no_mangle] pub extern "C" fn AzNodeData_setAccessibilityLabel(nodedata: &mut AzNodeData, label: AzString) { ffi_guard!((), { nodedata.set_accessibility_label(label) }) }
 /// Adds a (native) menu bar: If this node is the root node the menu bar will be added to the window, else it will be displayed using the width and position of the bounding rectangle
-#[no_mangle] pub extern "C" fn AzNodeData_setMenuBar(nodedata: &mut AzNodeData, menu_bar: AzMenu) { nodedata.set_menu_bar(menu_bar) }
+#[no_mangle] pub extern "C" fn AzNodeData_setMenuBar(nodedata: &mut AzNodeData, menu_bar: AzMenu) { ffi_guard!((), { nodedata.set_menu_bar(menu_bar) }) }
 /// Signalizes that this node has a (native) context-aware menu. If set, the user can left-click the node to open the menu
-#[no_mangle] pub extern "C" fn AzNodeData_setContextMenu(nodedata: &mut AzNodeData, context_menu: AzMenu) { nodedata.set_context_menu(context_menu) }
+#[no_mangle] pub extern "C" fn AzNodeData_setContextMenu(nodedata: &mut AzNodeData, context_menu: AzMenu) { ffi_guard!((), { nodedata.set_context_menu(context_menu) }) }
 /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
-#[no_mangle] pub extern "C" fn AzNodeData_hash(nodedata: &AzNodeData) -> u64 { nodedata.calculate_node_data_hash().0 }
+#[no_mangle] pub extern "C" fn AzNodeData_hash(nodedata: &AzNodeData) -> u64 { ffi_guard!(u64, { nodedata.calculate_node_data_hash().0 }) }
 /// Destructor: Takes ownership of the `NodeData` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeData_delete(object: &mut AzNodeData) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeData_delete(object: &mut AzNodeData) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// List of core DOM node types built-into by `azul`
 pub use azul_impl::dom::NodeType as AzNodeTypeTT;
 pub use AzNodeTypeTT as AzNodeType;
 /// Destructor: Takes ownership of the `NodeType` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeType_delete(object: &mut AzNodeType) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeType_delete(object: &mut AzNodeType) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// When to call a callback action - `On::MouseOver`, `On::MouseOut`, etc.
 pub use azul_impl::dom::On as AzOnTT;
 pub use AzOnTT as AzOn;
 /// Converts the `On` shorthand into a `EventFilter`
-#[no_mangle] pub extern "C" fn AzOn_intoEventFilter(on: AzOn) -> AzEventFilter { on.into() }
+#[no_mangle] pub extern "C" fn AzOn_intoEventFilter(on: AzOn) -> AzEventFilter { ffi_guard!(AzEventFilter, { on.into() }) }
 
 /// Re-export of rust-allocated (stack based) `EventFilter` struct
 pub use azul_impl::dom::EventFilter as AzEventFilterTT;
@@ -954,7 +1230,7 @@ pub use AzApplicationEventFilterTT as AzApplicationEventFilter;
 pub use azul_impl::dom::AccessibilityInfo as AzAccessibilityInfoTT;
 pub use AzAccessibilityInfoTT as AzAccessibilityInfo;
 /// Destructor: Takes ownership of the `AccessibilityInfo` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzAccessibilityInfo_delete(object: &mut AzAccessibilityInfo) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzAccessibilityInfo_delete(object: &mut AzAccessibilityInfo) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// MSAA Accessibility role constants. For information on what each role does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles">MSDN Role Constants page</a>
 pub use azul_impl::dom::AccessibilityRole as AzAccessibilityRoleTT;
@@ -972,25 +1248,25 @@ pub use AzTabIndexTT as AzTabIndex;
 pub use azul_impl::dom::IdOrClass as AzIdOrClassTT;
 pub use AzIdOrClassTT as AzIdOrClass;
 /// Destructor: Takes ownership of the `IdOrClass` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzIdOrClass_delete(object: &mut AzIdOrClass) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzIdOrClass_delete(object: &mut AzIdOrClass) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NodeDataInlineCssProperty` struct
 pub use azul_impl::dom::NodeDataInlineCssProperty as AzNodeDataInlineCssPropertyTT;
 pub use AzNodeDataInlineCssPropertyTT as AzNodeDataInlineCssProperty;
 /// Destructor: Takes ownership of the `NodeDataInlineCssProperty` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeDataInlineCssProperty_delete(object: &mut AzNodeDataInlineCssProperty) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeDataInlineCssProperty_delete(object: &mut AzNodeDataInlineCssProperty) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Menu struct (application / window menu, dropdown menu, context menu). Modeled after the Windows API
 pub use azul_core::window::Menu as AzMenuTT;
 pub use AzMenuTT as AzMenu;
 /// Creates an new, empty Menu
-#[no_mangle] pub extern "C" fn AzMenu_new(items: AzMenuItemVec) -> AzMenu { AzMenu::new(items) }
+#[no_mangle] pub extern "C" fn AzMenu_new(items: AzMenuItemVec) -> AzMenu { ffi_guard!(AzMenu, { AzMenu::new(items) }) }
 /// Sets the popup position of the menu, ignored on menu bars
-#[no_mangle] pub extern "C" fn AzMenu_setPopupPosition(menu: &mut AzMenu, position: AzMenuPopupPosition) { menu.position = position; }
+#[no_mangle] pub extern "C" fn AzMenu_setPopupPosition(menu: &mut AzMenu, position: AzMenuPopupPosition) { ffi_guard!((), { menu.position = position; }) }
 /// Sets the popup position of the menu, ignored on menu bars (builder method)
-#[no_mangle] pub extern "C" fn AzMenu_withPopupPosition(menu: &mut AzMenu, position: AzMenuPopupPosition) -> AzMenu { let mut menu = menu.swap_with_default(); menu.position = position; menu }
+#[no_mangle] pub extern "C" fn AzMenu_withPopupPosition(menu: &mut AzMenu, position: AzMenuPopupPosition) -> AzMenu { ffi_guard!(AzMenu, { let mut menu = menu.swap_with_default(); menu.position = position; menu }) }
 /// Destructor: Takes ownership of the `Menu` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzMenu_delete(object: &mut AzMenu) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzMenu_delete(object: &mut AzMenu) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Determines whether this context menu should pop up on a left, right or middle click
 pub use azul_core::window::ContextMenuMouseButton as AzContextMenuMouseButtonTT;
@@ -1004,48 +1280,56 @@ pub use AzMenuPopupPositionTT as AzMenuPopupPosition;
 pub use azul_core::window::MenuItem as AzMenuItemTT;
 pub use AzMenuItemTT as AzMenuItem;
 /// Destructor: Takes ownership of the `MenuItem` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzMenuItem_delete(object: &mut AzMenuItem) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzMenuItem_delete(object: &mut AzMenuItem) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Regular labeled menu item
 pub use azul_core::window::StringMenuItem as AzStringMenuItemTT;
 pub use AzStringMenuItemTT as AzStringMenuItem;
 /// Creates a new menu item
-#[no_mangle] pub extern "C" fn AzStringMenuItem_new(label: AzString) -> AzStringMenuItem { AzStringMenuItem::new(label) }
+#[no_mangle] pub extern "C" fn AzStringMenuItem_new(label: AzString) -> AzStringMenuItem { ffi_guard!(AzStringMenuItem, { AzStringMenuItem::new(label) }) }
 /// Adds a callback to the menu item
-#[no_mangle] pub extern "C" fn AzStringMenuItem_setCallback(stringmenuitem: &mut AzStringMenuItem, data: AzRefAny, callback: AzCallbackType) { stringmenuitem.callback = AzOptionMenuCallback::Some(AzMenuCallback { data, callback: AzCallback { cb: callback } }); }
+#[no_mangle] pub extern "C" fn AzStringMenuItem_setCallback(stringmenuitem: &mut AzStringMenuItem, data: AzRefAny, callback: AzCallbackType) { ffi_guard!((), { stringmenuitem.callback = AzOptionMenuCallback::Some(AzMenuCallback { data, callback: AzCallback { cb: callback } }); }) }
 /// Adds a callback to the menu item
-#[no_mangle] pub extern "C" fn AzStringMenuItem_withCallback(stringmenuitem: &mut AzStringMenuItem, data: AzRefAny, callback: AzCallbackType) -> AzStringMenuItem { let mut stringmenuitem = stringmenuitem.swap_with_default(); stringmenuitem.callback = AzOptionMenuCallback::Some(AzMenuCallback { data, callback: AzCallback { cb: callback } }); stringmenuitem }
+#[no_mangle] pub extern "C" fn AzStringMenuItem_withCallback(stringmenuitem: &mut AzStringMenuItem, data: AzRefAny, callback: AzCallbackType) -> AzStringMenuItem { ffi_guard!(AzStringMenuItem, { let mut stringmenuitem = stringmenuitem.swap_with_default(); stringmenuitem.callback = AzOptionMenuCallback::Some(AzMenuCallback { data, callback: AzCallback { cb: callback } }); stringmenuitem }) }
+/// Sets the keyboard shortcut of the menu item, displayed next to the label
+#[no_mangle] pub extern "C" fn AzStringMenuItem_setAccelerator(stringmenuitem: &mut AzStringMenuItem, accelerator: AzVirtualKeyCodeCombo) { ffi_guard!((), { stringmenuitem.accelerator = AzOptionVirtualKeyCodeCombo::Some(accelerator); }) }
+/// Sets the keyboard shortcut of the menu item, displayed next to the label (builder method)
+#[no_mangle] pub extern "C" fn AzStringMenuItem_withAccelerator(stringmenuitem: &mut AzStringMenuItem, accelerator: AzVirtualKeyCodeCombo) -> AzStringMenuItem { ffi_guard!(AzStringMenuItem, { let mut stringmenuitem = stringmenuitem.swap_with_default(); stringmenuitem.accelerator = AzOptionVirtualKeyCodeCombo::Some(accelerator); stringmenuitem }) }
+/// Sets the state (normal, greyed, disabled) of the menu item, the callback of greyed or disabled items is not invoked
+#[no_mangle] pub extern "C" fn AzStringMenuItem_setState(stringmenuitem: &mut AzStringMenuItem, state: AzMenuItemState) { ffi_guard!((), { stringmenuitem.state = state; }) }
+/// Sets the state (normal, greyed, disabled) of the menu item (builder method)
+#[no_mangle] pub extern "C" fn AzStringMenuItem_withState(stringmenuitem: &mut AzStringMenuItem, state: AzMenuItemState) -> AzStringMenuItem { ffi_guard!(AzStringMenuItem, { let mut stringmenuitem = stringmenuitem.swap_with_default(); stringmenuitem.state = state; stringmenuitem }) }
 /// Adds a single child submenu to the current menu
-#[no_mangle] pub extern "C" fn AzStringMenuItem_addChild(stringmenuitem: &mut AzStringMenuItem, child: AzMenuItem) { let mut m = stringmenuitem.children.clone().into_library_owned_vec(); m.push(child); stringmenuitem.children = m.into(); }
+#[no_mangle] pub extern "C" fn AzStringMenuItem_addChild(stringmenuitem: &mut AzStringMenuItem, child: AzMenuItem) { ffi_guard!((), { let mut m = stringmenuitem.children.clone().into_library_owned_vec(); m.push(child); stringmenuitem.children = m.into(); }) }
 /// Adds a single child submenu to the current menu
-#[no_mangle] pub extern "C" fn AzStringMenuItem_withChild(stringmenuitem: &mut AzStringMenuItem, child: AzMenuItem) -> AzStringMenuItem { let mut stringmenuitem = stringmenuitem.swap_with_default(); let mut m = stringmenuitem.children.clone().into_library_owned_vec(); m.push(child); stringmenuitem.children = m.into(); stringmenuitem }
+#[no_mangle] pub extern "C" fn AzStringMenuItem_withChild(stringmenuitem: &mut AzStringMenuItem, child: AzMenuItem) -> AzStringMenuItem { ffi_guard!(AzStringMenuItem, { let mut stringmenuitem = stringmenuitem.swap_with_default(); let mut m = stringmenuitem.children.clone().into_library_owned_vec(); m.push(child); stringmenuitem.children = m.into(); stringmenuitem }) }
 /// Sets the children of this menu
-#[no_mangle] pub extern "C" fn AzStringMenuItem_setChildren(stringmenuitem: &mut AzStringMenuItem, children: AzMenuItemVec) { stringmenuitem.children = children; }
+#[no_mangle] pub extern "C" fn AzStringMenuItem_setChildren(stringmenuitem: &mut AzStringMenuItem, children: AzMenuItemVec) { ffi_guard!((), { stringmenuitem.children = children; }) }
 /// Adds a child submenu to the current menu
-#[no_mangle] pub extern "C" fn AzStringMenuItem_withChildren(stringmenuitem: &mut AzStringMenuItem, children: AzMenuItemVec) -> AzStringMenuItem { let mut stringmenuitem = stringmenuitem.swap_with_default(); stringmenuitem.children = children; stringmenuitem }
+#[no_mangle] pub extern "C" fn AzStringMenuItem_withChildren(stringmenuitem: &mut AzStringMenuItem, children: AzMenuItemVec) -> AzStringMenuItem { ffi_guard!(AzStringMenuItem, { let mut stringmenuitem = stringmenuitem.swap_with_default(); stringmenuitem.children = children; stringmenuitem }) }
 /// Destructor: Takes ownership of the `StringMenuItem` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStringMenuItem_delete(object: &mut AzStringMenuItem) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStringMenuItem_delete(object: &mut AzStringMenuItem) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Combination of virtual key codes that have to be pressed together
 pub use azul_core::window::VirtualKeyCodeCombo as AzVirtualKeyCodeComboTT;
 pub use AzVirtualKeyCodeComboTT as AzVirtualKeyCodeCombo;
 /// Destructor: Takes ownership of the `VirtualKeyCodeCombo` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzVirtualKeyCodeCombo_delete(object: &mut AzVirtualKeyCodeCombo) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzVirtualKeyCodeCombo_delete(object: &mut AzVirtualKeyCodeCombo) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
 pub use azul_core::window::MenuCallback as AzMenuCallbackTT;
 pub use AzMenuCallbackTT as AzMenuCallback;
 /// Creates a new `MenuCallback` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `MenuCallback::new()` constructor.
-#[no_mangle] pub extern "C" fn AzMenuCallback_new(data: AzRefAny, callback: AzCallbackType) -> AzMenuCallback { AzMenuCallback { callback: AzCallback { cb: callback }, data } }
+#[no_mangle] pub extern "C" fn AzMenuCallback_new(data: AzRefAny, callback: AzCallbackType) -> AzMenuCallback { ffi_guard!(AzMenuCallback, { AzMenuCallback { callback: AzCallback { cb: callback }, data } }) }
 /// Destructor: Takes ownership of the `MenuCallback` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzMenuCallback_delete(object: &mut AzMenuCallback) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzMenuCallback_delete(object: &mut AzMenuCallback) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Icon of a menu entry
 pub use azul_core::window::MenuItemIcon as AzMenuItemIconTT;
 pub use AzMenuItemIconTT as AzMenuItemIcon;
 /// Destructor: Takes ownership of the `MenuItemIcon` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzMenuItemIcon_delete(object: &mut AzMenuItemIcon) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzMenuItemIcon_delete(object: &mut AzMenuItemIcon) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Describes the state of a menu item
 pub use azul_core::window::MenuItemState as AzMenuItemStateTT;
@@ -1055,31 +1339,31 @@ pub use AzMenuItemStateTT as AzMenuItemState;
 pub use azul_impl::css::CssRuleBlock as AzCssRuleBlockTT;
 pub use AzCssRuleBlockTT as AzCssRuleBlock;
 /// Destructor: Takes ownership of the `CssRuleBlock` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssRuleBlock_delete(object: &mut AzCssRuleBlock) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCssRuleBlock_delete(object: &mut AzCssRuleBlock) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `CssDeclaration` struct
 pub use azul_impl::css::CssDeclaration as AzCssDeclarationTT;
 pub use AzCssDeclarationTT as AzCssDeclaration;
 /// Destructor: Takes ownership of the `CssDeclaration` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssDeclaration_delete(object: &mut AzCssDeclaration) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCssDeclaration_delete(object: &mut AzCssDeclaration) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `DynamicCssProperty` struct
 pub use azul_impl::css::DynamicCssProperty as AzDynamicCssPropertyTT;
 pub use AzDynamicCssPropertyTT as AzDynamicCssProperty;
 /// Destructor: Takes ownership of the `DynamicCssProperty` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzDynamicCssProperty_delete(object: &mut AzDynamicCssProperty) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzDynamicCssProperty_delete(object: &mut AzDynamicCssProperty) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `CssPath` struct
 pub use azul_impl::css::CssPath as AzCssPathTT;
 pub use AzCssPathTT as AzCssPath;
 /// Destructor: Takes ownership of the `CssPath` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssPath_delete(object: &mut AzCssPath) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCssPath_delete(object: &mut AzCssPath) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `CssPathSelector` struct
 pub use azul_impl::css::CssPathSelector as AzCssPathSelectorTT;
 pub use AzCssPathSelectorTT as AzCssPathSelector;
 /// Destructor: Takes ownership of the `CssPathSelector` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssPathSelector_delete(object: &mut AzCssPathSelector) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCssPathSelector_delete(object: &mut AzCssPathSelector) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NodeTypeKey` struct
 pub use azul_impl::css::NodeTypeTag as AzNodeTypeKeyTT;
@@ -1101,17 +1385,51 @@ pub use AzCssNthChildPatternTT as AzCssNthChildPattern;
 pub use azul_impl::css::Stylesheet as AzStylesheetTT;
 pub use AzStylesheetTT as AzStylesheet;
 /// Destructor: Takes ownership of the `Stylesheet` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStylesheet_delete(object: &mut AzStylesheet) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStylesheet_delete(object: &mut AzStylesheet) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `Css` struct
 pub use azul_impl::css::Css as AzCssTT;
 pub use AzCssTT as AzCss;
 /// Returns an empty CSS style
-#[no_mangle] pub extern "C" fn AzCss_empty() -> AzCss { AzCss::empty() }
+#[no_mangle] pub extern "C" fn AzCss_empty() -> AzCss { ffi_guard!(AzCss, { AzCss::empty() }) }
 /// Returns a CSS style parsed from a `String`
-#[no_mangle] pub extern "C" fn AzCss_fromString(s: AzString) -> AzCss { AzCss::from_string(s) }
+#[no_mangle] pub extern "C" fn AzCss_fromString(s: AzString) -> AzCss { ffi_guard!(AzCss, { AzCss::from_string(s) }) }
+/// Sets the value of the CSS variable `--name` (the leading `--` is optional). The value is only used by `var(--name)` declarations of the same property type, i.e. setting a background only affects `background: var(--name)`. The new value is applied when the CSS is used to style a DOM again.
+#[no_mangle] pub extern "C" fn AzCss_setVariable(css: &mut AzCss, name: AzString, value: AzCssProperty) { ffi_guard!((), { css.css.set_variable(name, value) }) }
+/// Replaces all `var(--name)` declarations with the current values of the variables. Variables that are set afterwards have no effect anymore.
+#[no_mangle] pub extern "C" fn AzCss_resolveVariables(css: &mut AzCss) { ffi_guard!((), { css.css.resolve_variables() }) }
 /// Destructor: Takes ownership of the `Css` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCss_delete(object: &mut AzCss) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCss_delete(object: &mut AzCss) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Value of a CSS variable (`--name`) that is set at runtime
+pub use azul_impl::css::CssVariable as AzCssVariableTT;
+pub use AzCssVariableTT as AzCssVariable;
+/// Destructor: Takes ownership of the `CssVariable` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssVariable_delete(object: &mut AzCssVariable) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Condition of an `@media` group, i.e. `(max-width: 600px), (orientation: portrait)`: the group applies if any of the conditions matches
+pub use azul_impl::css::MediaQuery as AzMediaQueryTT;
+pub use AzMediaQueryTT as AzMediaQuery;
+/// Destructor: Takes ownership of the `MediaQuery` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzMediaQuery_delete(object: &mut AzMediaQuery) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Media features combined with `and`, i.e. `(min-width: 600px) and (orientation: portrait)`
+pub use azul_impl::css::MediaCondition as AzMediaConditionTT;
+pub use AzMediaConditionTT as AzMediaCondition;
+/// Destructor: Takes ownership of the `MediaCondition` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzMediaCondition_delete(object: &mut AzMediaCondition) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// One `(feature: value)` or `(feature < value)` test of a `MediaCondition`
+pub use azul_impl::css::MediaFeature as AzMediaFeatureTT;
+pub use AzMediaFeatureTT as AzMediaFeature;
+
+/// Value of the `orientation` media feature
+pub use azul_impl::css::MediaOrientation as AzMediaOrientationTT;
+pub use AzMediaOrientationTT as AzMediaOrientation;
+
+/// Value of the `prefers-color-scheme` media feature
+pub use azul_impl::css::MediaColorScheme as AzMediaColorSchemeTT;
+pub use AzMediaColorSchemeTT as AzMediaColorScheme;
 
 /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
 pub use azul_impl::css::CssPropertyType as AzCssPropertyTypeTT;
@@ -1130,18 +1448,34 @@ pub use azul_impl::css::ColorU as AzColorUTT;
 pub use AzColorUTT as AzColorU;
 /// Creates a new `ColorU` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `ColorU::from_str()` constructor.
-#[no_mangle] pub extern "C" fn AzColorU_fromStr(string: AzString) -> AzColorU { azul_impl::css::css_parser::parse_css_color(string.as_str()).ok().unwrap_or(AzColorU::BLACK) }
+#[no_mangle] pub extern "C" fn AzColorU_fromStr(string: AzString) -> AzColorU { ffi_guard!(AzColorU, { azul_impl::css::css_parser::parse_css_color(string.as_str()).ok().unwrap_or(AzColorU::BLACK) }) }
 /// Creates a new `ColorU` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `ColorU::transparent()` constructor.
-#[no_mangle] pub extern "C" fn AzColorU_transparent() -> AzColorU { AzColorU::TRANSPARENT }
+#[no_mangle] pub extern "C" fn AzColorU_transparent() -> AzColorU { ffi_guard!(AzColorU, { AzColorU::TRANSPARENT }) }
 /// Creates a new `ColorU` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `ColorU::white()` constructor.
-#[no_mangle] pub extern "C" fn AzColorU_white() -> AzColorU { AzColorU::WHITE }
+#[no_mangle] pub extern "C" fn AzColorU_white() -> AzColorU { ffi_guard!(AzColorU, { AzColorU::WHITE }) }
 /// Creates a new `ColorU` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `ColorU::black()` constructor.
-#[no_mangle] pub extern "C" fn AzColorU_black() -> AzColorU { AzColorU::BLACK }
+#[no_mangle] pub extern "C" fn AzColorU_black() -> AzColorU { ffi_guard!(AzColorU, { AzColorU::BLACK }) }
 /// Equivalent to the Rust `ColorU::to_hash()` function.
-#[no_mangle] pub extern "C" fn AzColorU_toHash(coloru: &AzColorU) -> AzString { coloru.to_hash().into() }
+#[no_mangle] pub extern "C" fn AzColorU_toHash(coloru: &AzColorU) -> AzString { ffi_guard!(AzString, { coloru.to_hash().into() }) }
+/// Multiplies the color with a tint, component-wise (including alpha) in normalized (0.0 - 1.0) space
+#[no_mangle] pub extern "C" fn AzColorU_multiply(coloru: &AzColorU, other: AzColorU) -> AzColorU { ffi_guard!(AzColorU, { coloru.multiply(&other) }) }
+/// Adds two colors component-wise (including alpha), clamping each component at 255
+#[no_mangle] pub extern "C" fn AzColorU_addSaturating(coloru: &AzColorU, other: AzColorU) -> AzColorU { ffi_guard!(AzColorU, { coloru.add_saturating(&other) }) }
+/// Rotates the hue of the color (in HSL space) by the given amount of degrees, preserving saturation, lightness and alpha
+#[no_mangle] pub extern "C" fn AzColorU_hueRotate(coloru: &AzColorU, degrees: f32) -> AzColorU { ffi_guard!(AzColorU, { coloru.hue_rotate(degrees) }) }
+/// Returns a copy of the color with the red channel replaced
+#[no_mangle] pub extern "C" fn AzColorU_withR(coloru: &AzColorU, r: u8) -> AzColorU { ffi_guard!(AzColorU, { coloru.with_r(r) }) }
+/// Returns a copy of the color with the green channel replaced
+#[no_mangle] pub extern "C" fn AzColorU_withG(coloru: &AzColorU, g: u8) -> AzColorU { ffi_guard!(AzColorU, { coloru.with_g(g) }) }
+/// Returns a copy of the color with the blue channel replaced
+#[no_mangle] pub extern "C" fn AzColorU_withB(coloru: &AzColorU, b: u8) -> AzColorU { ffi_guard!(AzColorU, { coloru.with_b(b) }) }
+/// Returns a copy of the color with the alpha channel replaced
+#[no_mangle] pub extern "C" fn AzColorU_withA(coloru: &AzColorU, a: u8) -> AzColorU { ffi_guard!(AzColorU, { coloru.with_a(a) }) }
+/// Returns a copy of the color with the alpha set from a normalized (0.0 - 1.0) value
+#[no_mangle] pub extern "C" fn AzColorU_withAlphaF32(coloru: &AzColorU, a: f32) -> AzColorU { ffi_guard!(AzColorU, { coloru.with_alpha_f32(a) }) }
 
 /// Re-export of rust-allocated (stack based) `SizeMetric` struct
 pub use azul_impl::css::SizeMetric as AzSizeMetricTT;
@@ -1155,6 +1489,14 @@ pub use AzFloatValueTT as AzFloatValue;
 pub use azul_impl::css::PixelValue as AzPixelValueTT;
 pub use AzPixelValueTT as AzPixelValue;
 
+/// Simplified `calc()` expression: sum of lengths with different metrics, i.e. `calc(100% - 32px)` is stored as `{ percent: 100, px: -32 }`
+pub use azul_impl::css::CalcLength as AzCalcLengthTT;
+pub use AzCalcLengthTT as AzCalcLength;
+
+/// Value of a boxed `Calc` length: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::CalcLengthBoxed as AzCalcLengthBoxedTT;
+pub use AzCalcLengthBoxedTT as AzCalcLengthBoxed;
+
 /// Re-export of rust-allocated (stack based) `PixelValueNoPercent` struct
 pub use azul_impl::css::PixelValueNoPercent as AzPixelValueNoPercentTT;
 pub use AzPixelValueNoPercentTT as AzPixelValueNoPercent;
@@ -1171,6 +1513,14 @@ pub use AzStyleBoxShadowTT as AzStyleBoxShadow;
 pub use azul_impl::css::StyleMixBlendMode as AzStyleMixBlendModeTT;
 pub use AzStyleMixBlendModeTT as AzStyleMixBlendMode;
 
+/// Represents an `isolation` attribute: whether the element creates a new stacking context for `mix-blend-mode`
+pub use azul_impl::css::StyleIsolation as AzStyleIsolationTT;
+pub use AzStyleIsolationTT as AzStyleIsolation;
+
+/// Represents an `image-rendering` attribute: which algorithm is used to scale images and background images
+pub use azul_impl::css::StyleImageRendering as AzStyleImageRenderingTT;
+pub use AzStyleImageRenderingTT as AzStyleImageRendering;
+
 /// Re-export of rust-allocated (stack based) `StyleFilter` struct
 pub use azul_impl::css::StyleFilter as AzStyleFilterTT;
 pub use AzStyleFilterTT as AzStyleFilter;
@@ -1215,6 +1565,46 @@ pub use AzLayoutFlexDirectionTT as AzLayoutFlexDirection;
 pub use azul_impl::css::LayoutDisplay as AzLayoutDisplayTT;
 pub use AzLayoutDisplayTT as AzLayoutDisplay;
 
+/// Single track size of a `grid-template-columns` or `grid-template-rows` attribute
+pub use azul_impl::css::GridTrack as AzGridTrackTT;
+pub use AzGridTrackTT as AzGridTrack;
+/// Destructor: Takes ownership of the `GridTrack` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzGridTrack_delete(object: &mut AzGridTrack) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Argument of a `repeat()` track
+pub use azul_impl::css::GridTrackRepeat as AzGridTrackRepeatTT;
+pub use AzGridTrackRepeatTT as AzGridTrackRepeat;
+/// Destructor: Takes ownership of the `GridTrackRepeat` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzGridTrackRepeat_delete(object: &mut AzGridTrackRepeat) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Start or end line of a `grid-column` or `grid-row` attribute
+pub use azul_impl::css::GridLine as AzGridLineTT;
+pub use AzGridLineTT as AzGridLine;
+
+/// Start / end line pair of a `grid-column` or `grid-row` attribute
+pub use azul_impl::css::GridPlacement as AzGridPlacementTT;
+pub use AzGridPlacementTT as AzGridPlacement;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumns` struct
+pub use azul_impl::css::LayoutGridTemplateColumns as AzLayoutGridTemplateColumnsTT;
+pub use AzLayoutGridTemplateColumnsTT as AzLayoutGridTemplateColumns;
+/// Destructor: Takes ownership of the `LayoutGridTemplateColumns` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzLayoutGridTemplateColumns_delete(object: &mut AzLayoutGridTemplateColumns) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateRows` struct
+pub use azul_impl::css::LayoutGridTemplateRows as AzLayoutGridTemplateRowsTT;
+pub use AzLayoutGridTemplateRowsTT as AzLayoutGridTemplateRows;
+/// Destructor: Takes ownership of the `LayoutGridTemplateRows` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzLayoutGridTemplateRows_delete(object: &mut AzLayoutGridTemplateRows) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Re-export of rust-allocated (stack based) `LayoutGridColumn` struct
+pub use azul_impl::css::LayoutGridColumn as AzLayoutGridColumnTT;
+pub use AzLayoutGridColumnTT as AzLayoutGridColumn;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridRow` struct
+pub use azul_impl::css::LayoutGridRow as AzLayoutGridRowTT;
+pub use AzLayoutGridRowTT as AzLayoutGridRow;
+
 /// Re-export of rust-allocated (stack based) `LayoutFlexGrow` struct
 pub use azul_impl::css::LayoutFlexGrow as AzLayoutFlexGrowTT;
 pub use AzLayoutFlexGrowTT as AzLayoutFlexGrow;
@@ -1323,7 +1713,7 @@ pub use AzAngleMetricTT as AzAngleMetric;
 pub use azul_impl::css::AngleValue as AzAngleValueTT;
 pub use AzAngleValueTT as AzAngleValue;
 /// Equivalent to the Rust `AngleValue::get_degrees()` function.
-#[no_mangle] pub extern "C" fn AzAngleValue_getDegrees(anglevalue: &AzAngleValue) -> f32 { anglevalue.to_degrees() }
+#[no_mangle] pub extern "C" fn AzAngleValue_getDegrees(anglevalue: &AzAngleValue) -> f32 { ffi_guard!(f32, { anglevalue.to_degrees() }) }
 
 /// Re-export of rust-allocated (stack based) `NormalizedLinearColorStop` struct
 pub use azul_impl::css::NormalizedLinearColorStop as AzNormalizedLinearColorStopTT;
@@ -1353,7 +1743,7 @@ pub use AzExtendModeTT as AzExtendMode;
 pub use azul_impl::css::LinearGradient as AzLinearGradientTT;
 pub use AzLinearGradientTT as AzLinearGradient;
 /// Destructor: Takes ownership of the `LinearGradient` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzLinearGradient_delete(object: &mut AzLinearGradient) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzLinearGradient_delete(object: &mut AzLinearGradient) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `Shape` struct
 pub use azul_impl::css::Shape as AzShapeTT;
@@ -1367,19 +1757,19 @@ pub use AzRadialGradientSizeTT as AzRadialGradientSize;
 pub use azul_impl::css::RadialGradient as AzRadialGradientTT;
 pub use AzRadialGradientTT as AzRadialGradient;
 /// Destructor: Takes ownership of the `RadialGradient` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzRadialGradient_delete(object: &mut AzRadialGradient) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzRadialGradient_delete(object: &mut AzRadialGradient) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ConicGradient` struct
 pub use azul_impl::css::ConicGradient as AzConicGradientTT;
 pub use AzConicGradientTT as AzConicGradient;
 /// Destructor: Takes ownership of the `ConicGradient` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzConicGradient_delete(object: &mut AzConicGradient) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzConicGradient_delete(object: &mut AzConicGradient) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
 pub use azul_impl::css::StyleBackgroundContent as AzStyleBackgroundContentTT;
 pub use AzStyleBackgroundContentTT as AzStyleBackgroundContent;
 /// Destructor: Takes ownership of the `StyleBackgroundContent` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleBackgroundContent_delete(object: &mut AzStyleBackgroundContent) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleBackgroundContent_delete(object: &mut AzStyleBackgroundContent) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `BackgroundPositionHorizontal` struct
 pub use azul_impl::css::BackgroundPositionHorizontal as AzBackgroundPositionHorizontalTT;
@@ -1473,13 +1863,13 @@ pub use AzLayoutBorderTopWidthTT as AzLayoutBorderTopWidth;
 pub use azul_impl::css::ScrollbarInfo as AzScrollbarInfoTT;
 pub use AzScrollbarInfoTT as AzScrollbarInfo;
 /// Destructor: Takes ownership of the `ScrollbarInfo` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzScrollbarInfo_delete(object: &mut AzScrollbarInfo) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzScrollbarInfo_delete(object: &mut AzScrollbarInfo) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ScrollbarStyle` struct
 pub use azul_impl::css::ScrollbarStyle as AzScrollbarStyleTT;
 pub use AzScrollbarStyleTT as AzScrollbarStyle;
 /// Destructor: Takes ownership of the `ScrollbarStyle` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzScrollbarStyle_delete(object: &mut AzScrollbarStyle) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzScrollbarStyle_delete(object: &mut AzScrollbarStyle) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyleCursor` struct
 pub use azul_impl::css::StyleCursor as AzStyleCursorTT;
@@ -1489,7 +1879,7 @@ pub use AzStyleCursorTT as AzStyleCursor;
 pub use azul_impl::css::StyleFontFamily as AzStyleFontFamilyTT;
 pub use AzStyleFontFamilyTT as AzStyleFontFamily;
 /// Destructor: Takes ownership of the `StyleFontFamily` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleFontFamily_delete(object: &mut AzStyleFontFamily) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleFontFamily_delete(object: &mut AzStyleFontFamily) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyleFontSize` struct
 pub use azul_impl::css::StyleFontSize as AzStyleFontSizeTT;
@@ -1516,7 +1906,7 @@ pub use azul_impl::css::StyleTransformOrigin as AzStyleTransformOriginTT;
 pub use AzStyleTransformOriginTT as AzStyleTransformOrigin;
 
 /// Re-export of rust-allocated (stack based) `StylePerspectiveOrigin` struct
-pub use azul_impl::css::StyleTransformOrigin as AzStylePerspectiveOriginTT;
+pub use azul_impl::css::StylePerspectiveOrigin as AzStylePerspectiveOriginTT;
 pub use AzStylePerspectiveOriginTT as AzStylePerspectiveOrigin;
 
 /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibility` struct
@@ -1563,22 +1953,114 @@ pub use AzStyleTransformSkew2DTT as AzStyleTransformSkew2D;
 pub use azul_impl::css::StyleTextAlign as AzStyleTextAlignTT;
 pub use AzStyleTextAlignTT as AzStyleTextAlign;
 
+/// Represents a `direction` attribute: the inline base direction of text and of `flex-direction: row` - default: `Ltr`
+pub use azul_impl::css::StyleDirection as AzStyleDirectionTT;
+pub use AzStyleDirectionTT as AzStyleDirection;
+
+/// Represents a `transition-property` attribute: which properties are animated when they change - default: `All`
+pub use azul_impl::css::StyleTransitionProperty as AzStyleTransitionPropertyTT;
+pub use AzStyleTransitionPropertyTT as AzStyleTransitionProperty;
+/// Destructor: Takes ownership of the `StyleTransitionProperty` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTransitionProperty_delete(object: &mut AzStyleTransitionProperty) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Represents a `transition-duration` attribute, in milliseconds
+pub use azul_impl::css::StyleTransitionDuration as AzStyleTransitionDurationTT;
+pub use AzStyleTransitionDurationTT as AzStyleTransitionDuration;
+
+/// Represents a `transition-delay` attribute, in milliseconds (can be negative)
+pub use azul_impl::css::StyleTransitionDelay as AzStyleTransitionDelayTT;
+pub use AzStyleTransitionDelayTT as AzStyleTransitionDelay;
+
+/// Position of the jumps of a `steps()` timing function - default: `JumpEnd`
+pub use azul_impl::css::StepPosition as AzStepPositionTT;
+pub use AzStepPositionTT as AzStepPosition;
+
+/// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+pub use azul_impl::css::StyleCubicBezier as AzStyleCubicBezierTT;
+pub use AzStyleCubicBezierTT as AzStyleCubicBezier;
+
+/// A `steps(count, position)` timing function
+pub use azul_impl::css::StyleSteps as AzStyleStepsTT;
+pub use AzStyleStepsTT as AzStyleSteps;
+
+/// Represents a `transition-timing-function` attribute - default: `Ease`
+pub use azul_impl::css::StyleTransitionTimingFunction as AzStyleTransitionTimingFunctionTT;
+pub use AzStyleTransitionTimingFunctionTT as AzStyleTransitionTimingFunction;
+
+/// Represents a `vertical-align` attribute, aligns an inline item (i.e. an icon next to text) inside of its line - default: `Baseline`
+pub use azul_impl::css::StyleVerticalAlign as AzStyleVerticalAlignTT;
+pub use AzStyleVerticalAlignTT as AzStyleVerticalAlign;
+
 /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
 pub use azul_impl::css::StyleTextColor as AzStyleTextColorTT;
 pub use AzStyleTextColorTT as AzStyleTextColor;
 
+/// Represents a `caret-color` attribute: the color of the text cursor in editable text
+pub use azul_impl::css::StyleCaretColor as AzStyleCaretColorTT;
+pub use AzStyleCaretColorTT as AzStyleCaretColor;
+
+/// Represents a `-azul-selection-color` attribute: the text color of selected text (same as `::selection { color }`)
+pub use azul_impl::css::StyleSelectionColor as AzStyleSelectionColorTT;
+pub use AzStyleSelectionColorTT as AzStyleSelectionColor;
+
+/// Represents a `-azul-selection-background-color` attribute: the highlight color behind selected text
+pub use azul_impl::css::StyleSelectionBackground as AzStyleSelectionBackgroundTT;
+pub use AzStyleSelectionBackgroundTT as AzStyleSelectionBackground;
+
 /// Re-export of rust-allocated (stack based) `StyleWordSpacing` struct
 pub use azul_impl::css::StyleWordSpacing as AzStyleWordSpacingTT;
 pub use AzStyleWordSpacingTT as AzStyleWordSpacing;
 
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleBoxShadowBoxed as AzStyleBoxShadowBoxedTT;
+pub use AzStyleBoxShadowBoxedTT as AzStyleBoxShadowBoxed;
+
 /// Re-export of rust-allocated (stack based) `StyleBoxShadowValue` struct
 pub use azul_impl::css::StyleBoxShadowValue as AzStyleBoxShadowValueTT;
 pub use AzStyleBoxShadowValueTT as AzStyleBoxShadowValue;
+/// Moves the `StyleBoxShadow` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleBoxShadowValue_exact(shadow: AzStyleBoxShadow) -> AzStyleBoxShadowValue { ffi_guard!(AzStyleBoxShadowValue, { AzStyleBoxShadowValue::exact(shadow) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutAlignContentValue` struct
 pub use azul_impl::css::LayoutAlignContentValue as AzLayoutAlignContentValueTT;
 pub use AzLayoutAlignContentValueTT as AzLayoutAlignContentValue;
 
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::LayoutGridTemplateColumnsBoxed as AzLayoutGridTemplateColumnsBoxedTT;
+pub use AzLayoutGridTemplateColumnsBoxedTT as AzLayoutGridTemplateColumnsBoxed;
+/// Destructor: Takes ownership of the `LayoutGridTemplateColumnsBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzLayoutGridTemplateColumnsBoxed_delete(object: &mut AzLayoutGridTemplateColumnsBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumnsValue` struct
+pub use azul_impl::css::LayoutGridTemplateColumnsValue as AzLayoutGridTemplateColumnsValueTT;
+pub use AzLayoutGridTemplateColumnsValueTT as AzLayoutGridTemplateColumnsValue;
+/// Moves the `LayoutGridTemplateColumns` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzLayoutGridTemplateColumnsValue_exact(value: AzLayoutGridTemplateColumns) -> AzLayoutGridTemplateColumnsValue { ffi_guard!(AzLayoutGridTemplateColumnsValue, { AzLayoutGridTemplateColumnsValue::exact(value) }) }
+/// Destructor: Takes ownership of the `LayoutGridTemplateColumnsValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzLayoutGridTemplateColumnsValue_delete(object: &mut AzLayoutGridTemplateColumnsValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::LayoutGridTemplateRowsBoxed as AzLayoutGridTemplateRowsBoxedTT;
+pub use AzLayoutGridTemplateRowsBoxedTT as AzLayoutGridTemplateRowsBoxed;
+/// Destructor: Takes ownership of the `LayoutGridTemplateRowsBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzLayoutGridTemplateRowsBoxed_delete(object: &mut AzLayoutGridTemplateRowsBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateRowsValue` struct
+pub use azul_impl::css::LayoutGridTemplateRowsValue as AzLayoutGridTemplateRowsValueTT;
+pub use AzLayoutGridTemplateRowsValueTT as AzLayoutGridTemplateRowsValue;
+/// Moves the `LayoutGridTemplateRows` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzLayoutGridTemplateRowsValue_exact(value: AzLayoutGridTemplateRows) -> AzLayoutGridTemplateRowsValue { ffi_guard!(AzLayoutGridTemplateRowsValue, { AzLayoutGridTemplateRowsValue::exact(value) }) }
+/// Destructor: Takes ownership of the `LayoutGridTemplateRowsValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzLayoutGridTemplateRowsValue_delete(object: &mut AzLayoutGridTemplateRowsValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Re-export of rust-allocated (stack based) `LayoutGridColumnValue` struct
+pub use azul_impl::css::LayoutGridColumnValue as AzLayoutGridColumnValueTT;
+pub use AzLayoutGridColumnValueTT as AzLayoutGridColumnValue;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridRowValue` struct
+pub use azul_impl::css::LayoutGridRowValue as AzLayoutGridRowValueTT;
+pub use AzLayoutGridRowValueTT as AzLayoutGridRowValue;
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
 pub use azul_impl::css::LayoutAlignItemsValue as AzLayoutAlignItemsValueTT;
 pub use AzLayoutAlignItemsValueTT as AzLayoutAlignItemsValue;
@@ -1586,6 +2068,8 @@ pub use AzLayoutAlignItemsValueTT as AzLayoutAlignItemsValue;
 /// Re-export of rust-allocated (stack based) `LayoutBottomValue` struct
 pub use azul_impl::css::LayoutBottomValue as AzLayoutBottomValueTT;
 pub use AzLayoutBottomValueTT as AzLayoutBottomValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutBottomValue_calc(calc: AzCalcLength) -> AzLayoutBottomValue { ffi_guard!(AzLayoutBottomValue, { AzLayoutBottomValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutBoxSizingValue` struct
 pub use azul_impl::css::LayoutBoxSizingValue as AzLayoutBoxSizingValueTT;
@@ -1614,6 +2098,8 @@ pub use AzLayoutFloatValueTT as AzLayoutFloatValue;
 /// Re-export of rust-allocated (stack based) `LayoutHeightValue` struct
 pub use azul_impl::css::LayoutHeightValue as AzLayoutHeightValueTT;
 pub use AzLayoutHeightValueTT as AzLayoutHeightValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutHeightValue_calc(calc: AzCalcLength) -> AzLayoutHeightValue { ffi_guard!(AzLayoutHeightValue, { AzLayoutHeightValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutJustifyContentValue` struct
 pub use azul_impl::css::LayoutJustifyContentValue as AzLayoutJustifyContentValueTT;
@@ -1622,54 +2108,80 @@ pub use AzLayoutJustifyContentValueTT as AzLayoutJustifyContentValue;
 /// Re-export of rust-allocated (stack based) `LayoutLeftValue` struct
 pub use azul_impl::css::LayoutLeftValue as AzLayoutLeftValueTT;
 pub use AzLayoutLeftValueTT as AzLayoutLeftValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutLeftValue_calc(calc: AzCalcLength) -> AzLayoutLeftValue { ffi_guard!(AzLayoutLeftValue, { AzLayoutLeftValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutMarginBottomValue` struct
 pub use azul_impl::css::LayoutMarginBottomValue as AzLayoutMarginBottomValueTT;
 pub use AzLayoutMarginBottomValueTT as AzLayoutMarginBottomValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutMarginBottomValue_calc(calc: AzCalcLength) -> AzLayoutMarginBottomValue { ffi_guard!(AzLayoutMarginBottomValue, { AzLayoutMarginBottomValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutMarginLeftValue` struct
 pub use azul_impl::css::LayoutMarginLeftValue as AzLayoutMarginLeftValueTT;
 pub use AzLayoutMarginLeftValueTT as AzLayoutMarginLeftValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutMarginLeftValue_calc(calc: AzCalcLength) -> AzLayoutMarginLeftValue { ffi_guard!(AzLayoutMarginLeftValue, { AzLayoutMarginLeftValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutMarginRightValue` struct
 pub use azul_impl::css::LayoutMarginRightValue as AzLayoutMarginRightValueTT;
 pub use AzLayoutMarginRightValueTT as AzLayoutMarginRightValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutMarginRightValue_calc(calc: AzCalcLength) -> AzLayoutMarginRightValue { ffi_guard!(AzLayoutMarginRightValue, { AzLayoutMarginRightValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutMarginTopValue` struct
 pub use azul_impl::css::LayoutMarginTopValue as AzLayoutMarginTopValueTT;
 pub use AzLayoutMarginTopValueTT as AzLayoutMarginTopValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutMarginTopValue_calc(calc: AzCalcLength) -> AzLayoutMarginTopValue { ffi_guard!(AzLayoutMarginTopValue, { AzLayoutMarginTopValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutMaxHeightValue` struct
 pub use azul_impl::css::LayoutMaxHeightValue as AzLayoutMaxHeightValueTT;
 pub use AzLayoutMaxHeightValueTT as AzLayoutMaxHeightValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutMaxHeightValue_calc(calc: AzCalcLength) -> AzLayoutMaxHeightValue { ffi_guard!(AzLayoutMaxHeightValue, { AzLayoutMaxHeightValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutMaxWidthValue` struct
 pub use azul_impl::css::LayoutMaxWidthValue as AzLayoutMaxWidthValueTT;
 pub use AzLayoutMaxWidthValueTT as AzLayoutMaxWidthValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutMaxWidthValue_calc(calc: AzCalcLength) -> AzLayoutMaxWidthValue { ffi_guard!(AzLayoutMaxWidthValue, { AzLayoutMaxWidthValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutMinHeightValue` struct
 pub use azul_impl::css::LayoutMinHeightValue as AzLayoutMinHeightValueTT;
 pub use AzLayoutMinHeightValueTT as AzLayoutMinHeightValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutMinHeightValue_calc(calc: AzCalcLength) -> AzLayoutMinHeightValue { ffi_guard!(AzLayoutMinHeightValue, { AzLayoutMinHeightValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutMinWidthValue` struct
 pub use azul_impl::css::LayoutMinWidthValue as AzLayoutMinWidthValueTT;
 pub use AzLayoutMinWidthValueTT as AzLayoutMinWidthValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutMinWidthValue_calc(calc: AzCalcLength) -> AzLayoutMinWidthValue { ffi_guard!(AzLayoutMinWidthValue, { AzLayoutMinWidthValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutPaddingBottomValue` struct
 pub use azul_impl::css::LayoutPaddingBottomValue as AzLayoutPaddingBottomValueTT;
 pub use AzLayoutPaddingBottomValueTT as AzLayoutPaddingBottomValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutPaddingBottomValue_calc(calc: AzCalcLength) -> AzLayoutPaddingBottomValue { ffi_guard!(AzLayoutPaddingBottomValue, { AzLayoutPaddingBottomValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutPaddingLeftValue` struct
 pub use azul_impl::css::LayoutPaddingLeftValue as AzLayoutPaddingLeftValueTT;
 pub use AzLayoutPaddingLeftValueTT as AzLayoutPaddingLeftValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutPaddingLeftValue_calc(calc: AzCalcLength) -> AzLayoutPaddingLeftValue { ffi_guard!(AzLayoutPaddingLeftValue, { AzLayoutPaddingLeftValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutPaddingRightValue` struct
 pub use azul_impl::css::LayoutPaddingRightValue as AzLayoutPaddingRightValueTT;
 pub use AzLayoutPaddingRightValueTT as AzLayoutPaddingRightValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutPaddingRightValue_calc(calc: AzCalcLength) -> AzLayoutPaddingRightValue { ffi_guard!(AzLayoutPaddingRightValue, { AzLayoutPaddingRightValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutPaddingTopValue` struct
 pub use azul_impl::css::LayoutPaddingTopValue as AzLayoutPaddingTopValueTT;
 pub use AzLayoutPaddingTopValueTT as AzLayoutPaddingTopValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutPaddingTopValue_calc(calc: AzCalcLength) -> AzLayoutPaddingTopValue { ffi_guard!(AzLayoutPaddingTopValue, { AzLayoutPaddingTopValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutPositionValue` struct
 pub use azul_impl::css::LayoutPositionValue as AzLayoutPositionValueTT;
@@ -1678,14 +2190,20 @@ pub use AzLayoutPositionValueTT as AzLayoutPositionValue;
 /// Re-export of rust-allocated (stack based) `LayoutRightValue` struct
 pub use azul_impl::css::LayoutRightValue as AzLayoutRightValueTT;
 pub use AzLayoutRightValueTT as AzLayoutRightValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutRightValue_calc(calc: AzCalcLength) -> AzLayoutRightValue { ffi_guard!(AzLayoutRightValue, { AzLayoutRightValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutTopValue` struct
 pub use azul_impl::css::LayoutTopValue as AzLayoutTopValueTT;
 pub use AzLayoutTopValueTT as AzLayoutTopValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutTopValue_calc(calc: AzCalcLength) -> AzLayoutTopValue { ffi_guard!(AzLayoutTopValue, { AzLayoutTopValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutWidthValue` struct
 pub use azul_impl::css::LayoutWidthValue as AzLayoutWidthValueTT;
 pub use AzLayoutWidthValueTT as AzLayoutWidthValue;
+/// Moves the `CalcLength` to the heap and wraps it in a `Calc` value
+#[no_mangle] pub extern "C" fn AzLayoutWidthValue_calc(calc: AzCalcLength) -> AzLayoutWidthValue { ffi_guard!(AzLayoutWidthValue, { AzLayoutWidthValue::calc(calc) }) }
 
 /// Re-export of rust-allocated (stack based) `LayoutFlexWrapValue` struct
 pub use azul_impl::css::LayoutFlexWrapValue as AzLayoutFlexWrapValueTT;
@@ -1695,35 +2213,75 @@ pub use AzLayoutFlexWrapValueTT as AzLayoutFlexWrapValue;
 pub use azul_impl::css::LayoutOverflowValue as AzLayoutOverflowValueTT;
 pub use AzLayoutOverflowValueTT as AzLayoutOverflowValue;
 
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::ScrollbarStyleBoxed as AzScrollbarStyleBoxedTT;
+pub use AzScrollbarStyleBoxedTT as AzScrollbarStyleBoxed;
+/// Destructor: Takes ownership of the `ScrollbarStyleBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzScrollbarStyleBoxed_delete(object: &mut AzScrollbarStyleBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
 /// Re-export of rust-allocated (stack based) `ScrollbarStyleValue` struct
 pub use azul_impl::css::ScrollbarStyleValue as AzScrollbarStyleValueTT;
 pub use AzScrollbarStyleValueTT as AzScrollbarStyleValue;
+/// Moves the `ScrollbarStyle` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzScrollbarStyleValue_exact(style: AzScrollbarStyle) -> AzScrollbarStyleValue { ffi_guard!(AzScrollbarStyleValue, { AzScrollbarStyleValue::exact(style) }) }
 /// Destructor: Takes ownership of the `ScrollbarStyleValue` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzScrollbarStyleValue_delete(object: &mut AzScrollbarStyleValue) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzScrollbarStyleValue_delete(object: &mut AzScrollbarStyleValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleBackgroundContentVecBoxed as AzStyleBackgroundContentVecBoxedTT;
+pub use AzStyleBackgroundContentVecBoxedTT as AzStyleBackgroundContentVecBoxed;
+/// Destructor: Takes ownership of the `StyleBackgroundContentVecBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleBackgroundContentVecBoxed_delete(object: &mut AzStyleBackgroundContentVecBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
 pub use azul_impl::css::StyleBackgroundContentVecValue as AzStyleBackgroundContentVecValueTT;
 pub use AzStyleBackgroundContentVecValueTT as AzStyleBackgroundContentVecValue;
+/// Moves the `StyleBackgroundContentVec` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleBackgroundContentVecValue_exact(value: AzStyleBackgroundContentVec) -> AzStyleBackgroundContentVecValue { ffi_guard!(AzStyleBackgroundContentVecValue, { AzStyleBackgroundContentVecValue::exact(value) }) }
 /// Destructor: Takes ownership of the `StyleBackgroundContentVecValue` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleBackgroundContentVecValue_delete(object: &mut AzStyleBackgroundContentVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleBackgroundContentVecValue_delete(object: &mut AzStyleBackgroundContentVecValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleBackgroundPositionVecBoxed as AzStyleBackgroundPositionVecBoxedTT;
+pub use AzStyleBackgroundPositionVecBoxedTT as AzStyleBackgroundPositionVecBoxed;
+/// Destructor: Takes ownership of the `StyleBackgroundPositionVecBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleBackgroundPositionVecBoxed_delete(object: &mut AzStyleBackgroundPositionVecBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyleBackgroundPositionVecValue` struct
 pub use azul_impl::css::StyleBackgroundPositionVecValue as AzStyleBackgroundPositionVecValueTT;
 pub use AzStyleBackgroundPositionVecValueTT as AzStyleBackgroundPositionVecValue;
+/// Moves the `StyleBackgroundPositionVec` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleBackgroundPositionVecValue_exact(value: AzStyleBackgroundPositionVec) -> AzStyleBackgroundPositionVecValue { ffi_guard!(AzStyleBackgroundPositionVecValue, { AzStyleBackgroundPositionVecValue::exact(value) }) }
 /// Destructor: Takes ownership of the `StyleBackgroundPositionVecValue` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleBackgroundPositionVecValue_delete(object: &mut AzStyleBackgroundPositionVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleBackgroundPositionVecValue_delete(object: &mut AzStyleBackgroundPositionVecValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleBackgroundRepeatVecBoxed as AzStyleBackgroundRepeatVecBoxedTT;
+pub use AzStyleBackgroundRepeatVecBoxedTT as AzStyleBackgroundRepeatVecBoxed;
+/// Destructor: Takes ownership of the `StyleBackgroundRepeatVecBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleBackgroundRepeatVecBoxed_delete(object: &mut AzStyleBackgroundRepeatVecBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyleBackgroundRepeatVecValue` struct
 pub use azul_impl::css::StyleBackgroundRepeatVecValue as AzStyleBackgroundRepeatVecValueTT;
 pub use AzStyleBackgroundRepeatVecValueTT as AzStyleBackgroundRepeatVecValue;
+/// Moves the `StyleBackgroundRepeatVec` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleBackgroundRepeatVecValue_exact(value: AzStyleBackgroundRepeatVec) -> AzStyleBackgroundRepeatVecValue { ffi_guard!(AzStyleBackgroundRepeatVecValue, { AzStyleBackgroundRepeatVecValue::exact(value) }) }
 /// Destructor: Takes ownership of the `StyleBackgroundRepeatVecValue` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleBackgroundRepeatVecValue_delete(object: &mut AzStyleBackgroundRepeatVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleBackgroundRepeatVecValue_delete(object: &mut AzStyleBackgroundRepeatVecValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleBackgroundSizeVecBoxed as AzStyleBackgroundSizeVecBoxedTT;
+pub use AzStyleBackgroundSizeVecBoxedTT as AzStyleBackgroundSizeVecBoxed;
+/// Destructor: Takes ownership of the `StyleBackgroundSizeVecBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleBackgroundSizeVecBoxed_delete(object: &mut AzStyleBackgroundSizeVecBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyleBackgroundSizeVecValue` struct
 pub use azul_impl::css::StyleBackgroundSizeVecValue as AzStyleBackgroundSizeVecValueTT;
 pub use AzStyleBackgroundSizeVecValueTT as AzStyleBackgroundSizeVecValue;
+/// Moves the `StyleBackgroundSizeVec` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleBackgroundSizeVecValue_exact(value: AzStyleBackgroundSizeVec) -> AzStyleBackgroundSizeVecValue { ffi_guard!(AzStyleBackgroundSizeVecValue, { AzStyleBackgroundSizeVecValue::exact(value) }) }
 /// Destructor: Takes ownership of the `StyleBackgroundSizeVecValue` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleBackgroundSizeVecValue_delete(object: &mut AzStyleBackgroundSizeVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleBackgroundSizeVecValue_delete(object: &mut AzStyleBackgroundSizeVecValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyleBorderBottomColorValue` struct
 pub use azul_impl::css::StyleBorderBottomColorValue as AzStyleBorderBottomColorValueTT;
@@ -1793,11 +2351,19 @@ pub use AzLayoutBorderTopWidthValueTT as AzLayoutBorderTopWidthValue;
 pub use azul_impl::css::StyleCursorValue as AzStyleCursorValueTT;
 pub use AzStyleCursorValueTT as AzStyleCursorValue;
 
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleFontFamilyVecBoxed as AzStyleFontFamilyVecBoxedTT;
+pub use AzStyleFontFamilyVecBoxedTT as AzStyleFontFamilyVecBoxed;
+/// Destructor: Takes ownership of the `StyleFontFamilyVecBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleFontFamilyVecBoxed_delete(object: &mut AzStyleFontFamilyVecBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
 pub use azul_impl::css::StyleFontFamilyVecValue as AzStyleFontFamilyVecValueTT;
 pub use AzStyleFontFamilyVecValueTT as AzStyleFontFamilyVecValue;
+/// Moves the `StyleFontFamilyVec` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleFontFamilyVecValue_exact(value: AzStyleFontFamilyVec) -> AzStyleFontFamilyVecValue { ffi_guard!(AzStyleFontFamilyVecValue, { AzStyleFontFamilyVecValue::exact(value) }) }
 /// Destructor: Takes ownership of the `StyleFontFamilyVecValue` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleFontFamilyVecValue_delete(object: &mut AzStyleFontFamilyVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleFontFamilyVecValue_delete(object: &mut AzStyleFontFamilyVecValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyleFontSizeValue` struct
 pub use azul_impl::css::StyleFontSizeValue as AzStyleFontSizeValueTT;
@@ -1819,6 +2385,64 @@ pub use AzStyleTabWidthValueTT as AzStyleTabWidthValue;
 pub use azul_impl::css::StyleTextAlignValue as AzStyleTextAlignValueTT;
 pub use AzStyleTextAlignValueTT as AzStyleTextAlignValue;
 
+/// Re-export of rust-allocated (stack based) `StyleDirectionValue` struct
+pub use azul_impl::css::StyleDirectionValue as AzStyleDirectionValueTT;
+pub use AzStyleDirectionValueTT as AzStyleDirectionValue;
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleTransitionPropertyBoxed as AzStyleTransitionPropertyBoxedTT;
+pub use AzStyleTransitionPropertyBoxedTT as AzStyleTransitionPropertyBoxed;
+/// Destructor: Takes ownership of the `StyleTransitionPropertyBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTransitionPropertyBoxed_delete(object: &mut AzStyleTransitionPropertyBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionPropertyValue` struct
+pub use azul_impl::css::StyleTransitionPropertyValue as AzStyleTransitionPropertyValueTT;
+pub use AzStyleTransitionPropertyValueTT as AzStyleTransitionPropertyValue;
+/// Moves the `StyleTransitionProperty` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleTransitionPropertyValue_exact(value: AzStyleTransitionProperty) -> AzStyleTransitionPropertyValue { ffi_guard!(AzStyleTransitionPropertyValue, { AzStyleTransitionPropertyValue::exact(value) }) }
+/// Destructor: Takes ownership of the `StyleTransitionPropertyValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTransitionPropertyValue_delete(object: &mut AzStyleTransitionPropertyValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDurationValue` struct
+pub use azul_impl::css::StyleTransitionDurationValue as AzStyleTransitionDurationValueTT;
+pub use AzStyleTransitionDurationValueTT as AzStyleTransitionDurationValue;
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleTransitionTimingFunctionBoxed as AzStyleTransitionTimingFunctionBoxedTT;
+pub use AzStyleTransitionTimingFunctionBoxedTT as AzStyleTransitionTimingFunctionBoxed;
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionValue` struct
+pub use azul_impl::css::StyleTransitionTimingFunctionValue as AzStyleTransitionTimingFunctionValueTT;
+pub use AzStyleTransitionTimingFunctionValueTT as AzStyleTransitionTimingFunctionValue;
+/// Moves the `StyleTransitionTimingFunction` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleTransitionTimingFunctionValue_exact(value: AzStyleTransitionTimingFunction) -> AzStyleTransitionTimingFunctionValue { ffi_guard!(AzStyleTransitionTimingFunctionValue, { AzStyleTransitionTimingFunctionValue::exact(value) }) }
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDelayValue` struct
+pub use azul_impl::css::StyleTransitionDelayValue as AzStyleTransitionDelayValueTT;
+pub use AzStyleTransitionDelayValueTT as AzStyleTransitionDelayValue;
+
+/// Re-export of rust-allocated (stack based) `StyleCaretColorValue` struct
+pub use azul_impl::css::StyleCaretColorValue as AzStyleCaretColorValueTT;
+pub use AzStyleCaretColorValueTT as AzStyleCaretColorValue;
+
+/// Re-export of rust-allocated (stack based) `StyleSelectionColorValue` struct
+pub use azul_impl::css::StyleSelectionColorValue as AzStyleSelectionColorValueTT;
+pub use AzStyleSelectionColorValueTT as AzStyleSelectionColorValue;
+
+/// Re-export of rust-allocated (stack based) `StyleSelectionBackgroundValue` struct
+pub use azul_impl::css::StyleSelectionBackgroundValue as AzStyleSelectionBackgroundValueTT;
+pub use AzStyleSelectionBackgroundValueTT as AzStyleSelectionBackgroundValue;
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleVerticalAlignBoxed as AzStyleVerticalAlignBoxedTT;
+pub use AzStyleVerticalAlignBoxedTT as AzStyleVerticalAlignBoxed;
+
+/// Re-export of rust-allocated (stack based) `StyleVerticalAlignValue` struct
+pub use azul_impl::css::StyleVerticalAlignValue as AzStyleVerticalAlignValueTT;
+pub use AzStyleVerticalAlignValueTT as AzStyleVerticalAlignValue;
+/// Moves the `StyleVerticalAlign` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleVerticalAlignValue_exact(value: AzStyleVerticalAlign) -> AzStyleVerticalAlignValue { ffi_guard!(AzStyleVerticalAlignValue, { AzStyleVerticalAlignValue::exact(value) }) }
+
 /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
 pub use azul_impl::css::StyleTextColorValue as AzStyleTextColorValueTT;
 pub use AzStyleTextColorValueTT as AzStyleTextColorValue;
@@ -1831,19 +2455,39 @@ pub use AzStyleWordSpacingValueTT as AzStyleWordSpacingValue;
 pub use azul_impl::css::StyleOpacityValue as AzStyleOpacityValueTT;
 pub use AzStyleOpacityValueTT as AzStyleOpacityValue;
 
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleTransformVecBoxed as AzStyleTransformVecBoxedTT;
+pub use AzStyleTransformVecBoxedTT as AzStyleTransformVecBoxed;
+/// Destructor: Takes ownership of the `StyleTransformVecBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTransformVecBoxed_delete(object: &mut AzStyleTransformVecBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
 /// Re-export of rust-allocated (stack based) `StyleTransformVecValue` struct
 pub use azul_impl::css::StyleTransformVecValue as AzStyleTransformVecValueTT;
 pub use AzStyleTransformVecValueTT as AzStyleTransformVecValue;
+/// Moves the `StyleTransformVec` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleTransformVecValue_exact(value: AzStyleTransformVec) -> AzStyleTransformVecValue { ffi_guard!(AzStyleTransformVecValue, { AzStyleTransformVecValue::exact(value) }) }
 /// Destructor: Takes ownership of the `StyleTransformVecValue` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleTransformVecValue_delete(object: &mut AzStyleTransformVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleTransformVecValue_delete(object: &mut AzStyleTransformVecValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleTransformOriginBoxed as AzStyleTransformOriginBoxedTT;
+pub use AzStyleTransformOriginBoxedTT as AzStyleTransformOriginBoxed;
 
 /// Re-export of rust-allocated (stack based) `StyleTransformOriginValue` struct
 pub use azul_impl::css::StyleTransformOriginValue as AzStyleTransformOriginValueTT;
 pub use AzStyleTransformOriginValueTT as AzStyleTransformOriginValue;
+/// Moves the `StyleTransformOrigin` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleTransformOriginValue_exact(value: AzStyleTransformOrigin) -> AzStyleTransformOriginValue { ffi_guard!(AzStyleTransformOriginValue, { AzStyleTransformOriginValue::exact(value) }) }
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StylePerspectiveOriginBoxed as AzStylePerspectiveOriginBoxedTT;
+pub use AzStylePerspectiveOriginBoxedTT as AzStylePerspectiveOriginBoxed;
 
 /// Re-export of rust-allocated (stack based) `StylePerspectiveOriginValue` struct
 pub use azul_impl::css::StylePerspectiveOriginValue as AzStylePerspectiveOriginValueTT;
 pub use AzStylePerspectiveOriginValueTT as AzStylePerspectiveOriginValue;
+/// Moves the `StylePerspectiveOrigin` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStylePerspectiveOriginValue_exact(value: AzStylePerspectiveOrigin) -> AzStylePerspectiveOriginValue { ffi_guard!(AzStylePerspectiveOriginValue, { AzStylePerspectiveOriginValue::exact(value) }) }
 
 /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibilityValue` struct
 pub use azul_impl::css::StyleBackfaceVisibilityValue as AzStyleBackfaceVisibilityValueTT;
@@ -1853,31 +2497,47 @@ pub use AzStyleBackfaceVisibilityValueTT as AzStyleBackfaceVisibilityValue;
 pub use azul_impl::css::StyleMixBlendModeValue as AzStyleMixBlendModeValueTT;
 pub use AzStyleMixBlendModeValueTT as AzStyleMixBlendModeValue;
 
+/// Re-export of rust-allocated (stack based) `StyleIsolationValue` struct
+pub use azul_impl::css::StyleIsolationValue as AzStyleIsolationValueTT;
+pub use AzStyleIsolationValueTT as AzStyleIsolationValue;
+
+/// Re-export of rust-allocated (stack based) `StyleImageRenderingValue` struct
+pub use azul_impl::css::StyleImageRenderingValue as AzStyleImageRenderingValueTT;
+pub use AzStyleImageRenderingValueTT as AzStyleImageRenderingValue;
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+pub use azul_impl::css::StyleFilterVecBoxed as AzStyleFilterVecBoxedTT;
+pub use AzStyleFilterVecBoxedTT as AzStyleFilterVecBoxed;
+/// Destructor: Takes ownership of the `StyleFilterVecBoxed` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleFilterVecBoxed_delete(object: &mut AzStyleFilterVecBoxed) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
 /// Re-export of rust-allocated (stack based) `StyleFilterVecValue` struct
 pub use azul_impl::css::StyleFilterVecValue as AzStyleFilterVecValueTT;
 pub use AzStyleFilterVecValueTT as AzStyleFilterVecValue;
+/// Moves the `StyleFilterVec` to the heap and wraps it in an `Exact` value
+#[no_mangle] pub extern "C" fn AzStyleFilterVecValue_exact(value: AzStyleFilterVec) -> AzStyleFilterVecValue { ffi_guard!(AzStyleFilterVecValue, { AzStyleFilterVecValue::exact(value) }) }
 /// Destructor: Takes ownership of the `StyleFilterVecValue` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleFilterVecValue_delete(object: &mut AzStyleFilterVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleFilterVecValue_delete(object: &mut AzStyleFilterVecValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Parsed CSS key-value pair
 pub use azul_impl::css::CssProperty as AzCssPropertyTT;
 pub use AzCssPropertyTT as AzCssProperty;
 /// Returns the key of the CSS property as a string, i.e. `background`
-#[no_mangle] pub extern "C" fn AzCssProperty_getKeyString(cssproperty: &AzCssProperty) -> AzString { cssproperty.key().into() }
+#[no_mangle] pub extern "C" fn AzCssProperty_getKeyString(cssproperty: &AzCssProperty) -> AzString { ffi_guard!(AzString, { cssproperty.key().into() }) }
 /// Returns the value of the CSS property as a string, i.e. `linear-gradient(red, blue)`
-#[no_mangle] pub extern "C" fn AzCssProperty_getValueString(cssproperty: &AzCssProperty) -> AzString { cssproperty.value().into() }
+#[no_mangle] pub extern "C" fn AzCssProperty_getValueString(cssproperty: &AzCssProperty) -> AzString { ffi_guard!(AzString, { cssproperty.value().into() }) }
 /// Returns the CSS key-value pair as a string, i.e. `background: linear-gradient(red, blue)`
-#[no_mangle] pub extern "C" fn AzCssProperty_getKeyValueString(cssproperty: &AzCssProperty) -> AzString { cssproperty.format_css().into() }
+#[no_mangle] pub extern "C" fn AzCssProperty_getKeyValueString(cssproperty: &AzCssProperty) -> AzString { ffi_guard!(AzString, { cssproperty.format_css().into() }) }
 /// Interpolates two CSS properties given a value `t` ranging from 0.0 to 1.0. The interpolation function can be set on the `context` (`Ease`, `Linear`, etc.).
-#[no_mangle] pub extern "C" fn AzCssProperty_interpolate(cssproperty: &AzCssProperty, other: AzCssProperty, t: f32, context: AzInterpolateContext) -> AzCssProperty { cssproperty.interpolate(&other, t, &context) }
+#[no_mangle] pub extern "C" fn AzCssProperty_interpolate(cssproperty: &AzCssProperty, other: AzCssProperty, t: f32, context: AzInterpolateContext) -> AzCssProperty { ffi_guard!(AzCssProperty, { cssproperty.interpolate(&other, t, &context) }) }
 /// Destructor: Takes ownership of the `CssProperty` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssProperty_delete(object: &mut AzCssProperty) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCssProperty_delete(object: &mut AzCssProperty) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `Ribbon` struct
 pub use crate::widgets::ribbon::Ribbon as AzRibbonTT;
 pub use AzRibbonTT as AzRibbon;
 /// Equivalent to the Rust `Ribbon::dom()` function.
-#[no_mangle] pub extern "C" fn AzRibbon_dom(ribbon: &mut AzRibbon, callback: AzRibbonOnTabClickedCallback, data: AzRefAny) -> AzDom { ribbon.dom(callback, data) }
+#[no_mangle] pub extern "C" fn AzRibbon_dom(ribbon: &mut AzRibbon, callback: AzRibbonOnTabClickedCallback, data: AzRefAny) -> AzDom { ffi_guard!(AzDom, { ribbon.dom(callback, data) }) }
 
 /// Re-export of rust-allocated (stack based) `RibbonOnTabClickedCallback` struct
 pub use crate::widgets::ribbon::RibbonOnTabClickedCallback as AzRibbonOnTabClickedCallbackTT;
@@ -1888,57 +2548,57 @@ pub type AzRibbonOnTabClickedCallbackType = extern "C" fn(&mut AzRefAny, &mut Az
 pub use crate::widgets::button::Button as AzButtonTT;
 pub use AzButtonTT as AzButton;
 /// Creates a new labeled button
-#[no_mangle] pub extern "C" fn AzButton_new(label: AzString) -> AzButton { AzButton::new(label) }
+#[no_mangle] pub extern "C" fn AzButton_new(label: AzString) -> AzButton { ffi_guard!(AzButton, { AzButton::new(label) }) }
 /// Equivalent to the Rust `Button::set_on_click()` function.
-#[no_mangle] pub extern "C" fn AzButton_setOnClick(button: &mut AzButton, data: AzRefAny, callback: AzCallbackType) { button.set_on_click(data, callback); }
+#[no_mangle] pub extern "C" fn AzButton_setOnClick(button: &mut AzButton, data: AzRefAny, callback: AzCallbackType) { ffi_guard!((), { button.set_on_click(data, callback); }) }
 /// Equivalent to the Rust `Button::with_on_click()` function.
-#[no_mangle] pub extern "C" fn AzButton_withOnClick(button: &mut AzButton, data: AzRefAny, callback: AzCallbackType) -> AzButton { let mut button = button.swap_with_default(); button.set_on_click(data, callback); button }
+#[no_mangle] pub extern "C" fn AzButton_withOnClick(button: &mut AzButton, data: AzRefAny, callback: AzCallbackType) -> AzButton { ffi_guard!(AzButton, { let mut button = button.swap_with_default(); button.set_on_click(data, callback); button }) }
 /// Equivalent to the Rust `Button::dom()` function.
-#[no_mangle] pub extern "C" fn AzButton_dom(button: &mut AzButton) -> AzDom { button.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzButton_dom(button: &mut AzButton) -> AzDom { ffi_guard!(AzDom, { button.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `Button` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzButton_delete(object: &mut AzButton) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzButton_delete(object: &mut AzButton) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ButtonOnClick` struct
 pub use crate::widgets::button::ButtonOnClick as AzButtonOnClickTT;
 pub use AzButtonOnClickTT as AzButtonOnClick;
 /// Destructor: Takes ownership of the `ButtonOnClick` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzButtonOnClick_delete(object: &mut AzButtonOnClick) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzButtonOnClick_delete(object: &mut AzButtonOnClick) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `FileInput` struct
 pub use crate::widgets::file_input::FileInput as AzFileInputTT;
 pub use AzFileInputTT as AzFileInput;
 /// Creates a new file input button
-#[no_mangle] pub extern "C" fn AzFileInput_new(path: AzOptionString) -> AzFileInput { AzFileInput::new(path) }
+#[no_mangle] pub extern "C" fn AzFileInput_new(path: AzOptionString) -> AzFileInput { ffi_guard!(AzFileInput, { AzFileInput::new(path) }) }
 /// Equivalent to the Rust `FileInput::set_default_text()` function.
-#[no_mangle] pub extern "C" fn AzFileInput_setDefaultText(fileinput: &mut AzFileInput, default_text: AzString) { fileinput.set_default_text(default_text); }
+#[no_mangle] pub extern "C" fn AzFileInput_setDefaultText(fileinput: &mut AzFileInput, default_text: AzString) { ffi_guard!((), { fileinput.set_default_text(default_text); }) }
 /// Equivalent to the Rust `FileInput::with_default_text()` function.
-#[no_mangle] pub extern "C" fn AzFileInput_withDefaultText(fileinput: &mut AzFileInput, default_text: AzString) -> AzFileInput { let mut fileinput = fileinput.swap_with_default(); fileinput.set_default_text(default_text); fileinput }
+#[no_mangle] pub extern "C" fn AzFileInput_withDefaultText(fileinput: &mut AzFileInput, default_text: AzString) -> AzFileInput { ffi_guard!(AzFileInput, { let mut fileinput = fileinput.swap_with_default(); fileinput.set_default_text(default_text); fileinput }) }
 /// Equivalent to the Rust `FileInput::set_on_path_change()` function.
-#[no_mangle] pub extern "C" fn AzFileInput_setOnPathChange(fileinput: &mut AzFileInput, data: AzRefAny, callback: AzFileInputOnPathChangeCallbackType) { fileinput.set_on_path_change(data, callback); }
+#[no_mangle] pub extern "C" fn AzFileInput_setOnPathChange(fileinput: &mut AzFileInput, data: AzRefAny, callback: AzFileInputOnPathChangeCallbackType) { ffi_guard!((), { fileinput.set_on_path_change(data, callback); }) }
 /// Equivalent to the Rust `FileInput::with_on_path_change()` function.
-#[no_mangle] pub extern "C" fn AzFileInput_withOnPathChange(fileinput: &mut AzFileInput, data: AzRefAny, callback: AzFileInputOnPathChangeCallbackType) -> AzFileInput { let mut fileinput = fileinput.swap_with_default(); fileinput.set_on_path_change(data, callback); fileinput }
+#[no_mangle] pub extern "C" fn AzFileInput_withOnPathChange(fileinput: &mut AzFileInput, data: AzRefAny, callback: AzFileInputOnPathChangeCallbackType) -> AzFileInput { ffi_guard!(AzFileInput, { let mut fileinput = fileinput.swap_with_default(); fileinput.set_on_path_change(data, callback); fileinput }) }
 /// Equivalent to the Rust `FileInput::dom()` function.
-#[no_mangle] pub extern "C" fn AzFileInput_dom(fileinput: &mut AzFileInput) -> AzDom { fileinput.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzFileInput_dom(fileinput: &mut AzFileInput) -> AzDom { ffi_guard!(AzDom, { fileinput.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `FileInput` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFileInput_delete(object: &mut AzFileInput) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFileInput_delete(object: &mut AzFileInput) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `FileInputStateWrapper` struct
 pub use crate::widgets::file_input::FileInputStateWrapper as AzFileInputStateWrapperTT;
 pub use AzFileInputStateWrapperTT as AzFileInputStateWrapper;
 /// Destructor: Takes ownership of the `FileInputStateWrapper` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFileInputStateWrapper_delete(object: &mut AzFileInputStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFileInputStateWrapper_delete(object: &mut AzFileInputStateWrapper) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `FileInputState` struct
 pub use crate::widgets::file_input::FileInputState as AzFileInputStateTT;
 pub use AzFileInputStateTT as AzFileInputState;
 /// Destructor: Takes ownership of the `FileInputState` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFileInputState_delete(object: &mut AzFileInputState) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFileInputState_delete(object: &mut AzFileInputState) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `FileInputOnPathChange` struct
 pub use crate::widgets::file_input::FileInputOnPathChange as AzFileInputOnPathChangeTT;
 pub use AzFileInputOnPathChangeTT as AzFileInputOnPathChange;
 /// Destructor: Takes ownership of the `FileInputOnPathChange` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFileInputOnPathChange_delete(object: &mut AzFileInputOnPathChange) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFileInputOnPathChange_delete(object: &mut AzFileInputOnPathChange) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `FileInputOnPathChangeCallback` struct
 pub use crate::widgets::file_input::FileInputOnPathChangeCallback as AzFileInputOnPathChangeCallbackTT;
@@ -1949,27 +2609,27 @@ pub type AzFileInputOnPathChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut
 pub use crate::widgets::check_box::CheckBox as AzCheckBoxTT;
 pub use AzCheckBoxTT as AzCheckBox;
 /// Creates a new checkbox, disabled or enabled
-#[no_mangle] pub extern "C" fn AzCheckBox_new(checked: bool) -> AzCheckBox { AzCheckBox::new(checked) }
+#[no_mangle] pub extern "C" fn AzCheckBox_new(checked: bool) -> AzCheckBox { ffi_guard!(AzCheckBox, { AzCheckBox::new(checked) }) }
 /// Equivalent to the Rust `CheckBox::set_on_toggle()` function.
-#[no_mangle] pub extern "C" fn AzCheckBox_setOnToggle(checkbox: &mut AzCheckBox, data: AzRefAny, callback: AzCheckBoxOnToggleCallbackType) { checkbox.set_on_toggle(data, callback); }
+#[no_mangle] pub extern "C" fn AzCheckBox_setOnToggle(checkbox: &mut AzCheckBox, data: AzRefAny, callback: AzCheckBoxOnToggleCallbackType) { ffi_guard!((), { checkbox.set_on_toggle(data, callback); }) }
 /// Equivalent to the Rust `CheckBox::with_on_toggle()` function.
-#[no_mangle] pub extern "C" fn AzCheckBox_withOnToggle(checkbox: &mut AzCheckBox, data: AzRefAny, callback: AzCheckBoxOnToggleCallbackType) -> AzCheckBox { let mut checkbox = checkbox.swap_with_default(); checkbox.set_on_toggle(data, callback); checkbox }
+#[no_mangle] pub extern "C" fn AzCheckBox_withOnToggle(checkbox: &mut AzCheckBox, data: AzRefAny, callback: AzCheckBoxOnToggleCallbackType) -> AzCheckBox { ffi_guard!(AzCheckBox, { let mut checkbox = checkbox.swap_with_default(); checkbox.set_on_toggle(data, callback); checkbox }) }
 /// Equivalent to the Rust `CheckBox::dom()` function.
-#[no_mangle] pub extern "C" fn AzCheckBox_dom(checkbox: &mut AzCheckBox) -> AzDom { checkbox.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzCheckBox_dom(checkbox: &mut AzCheckBox) -> AzDom { ffi_guard!(AzDom, { checkbox.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `CheckBox` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCheckBox_delete(object: &mut AzCheckBox) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCheckBox_delete(object: &mut AzCheckBox) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `CheckBoxStateWrapper` struct
 pub use crate::widgets::check_box::CheckBoxStateWrapper as AzCheckBoxStateWrapperTT;
 pub use AzCheckBoxStateWrapperTT as AzCheckBoxStateWrapper;
 /// Destructor: Takes ownership of the `CheckBoxStateWrapper` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCheckBoxStateWrapper_delete(object: &mut AzCheckBoxStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCheckBoxStateWrapper_delete(object: &mut AzCheckBoxStateWrapper) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `CheckBoxOnToggle` struct
 pub use crate::widgets::check_box::CheckBoxOnToggle as AzCheckBoxOnToggleTT;
 pub use AzCheckBoxOnToggleTT as AzCheckBoxOnToggle;
 /// Destructor: Takes ownership of the `CheckBoxOnToggle` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCheckBoxOnToggle_delete(object: &mut AzCheckBoxOnToggle) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCheckBoxOnToggle_delete(object: &mut AzCheckBoxOnToggle) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `CheckBoxOnToggleCallback` struct
 pub use crate::widgets::check_box::CheckBoxOnToggleCallback as AzCheckBoxOnToggleCallbackTT;
@@ -1985,32 +2645,32 @@ pub use crate::widgets::label::Label as AzLabelTT;
 pub use AzLabelTT as AzLabel;
 /// Creates a new `Label` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Label::new()` constructor.
-#[no_mangle] pub extern "C" fn AzLabel_new(text: AzString) -> AzLabel { AzLabel::new(text) }
+#[no_mangle] pub extern "C" fn AzLabel_new(text: AzString) -> AzLabel { ffi_guard!(AzLabel, { AzLabel::new(text) }) }
 /// Equivalent to the Rust `Label::dom()` function.
-#[no_mangle] pub extern "C" fn AzLabel_dom(label: &mut AzLabel) -> AzDom { label.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzLabel_dom(label: &mut AzLabel) -> AzDom { ffi_guard!(AzDom, { label.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `Label` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzLabel_delete(object: &mut AzLabel) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzLabel_delete(object: &mut AzLabel) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ColorInput` struct
 pub use crate::widgets::color_input::ColorInput as AzColorInputTT;
 pub use AzColorInputTT as AzColorInput;
 /// Creates a new `ColorInput` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `ColorInput::new()` constructor.
-#[no_mangle] pub extern "C" fn AzColorInput_new(color: AzColorU) -> AzColorInput { AzColorInput::new(color) }
+#[no_mangle] pub extern "C" fn AzColorInput_new(color: AzColorU) -> AzColorInput { ffi_guard!(AzColorInput, { AzColorInput::new(color) }) }
 /// Equivalent to the Rust `ColorInput::set_on_value_change()` function.
-#[no_mangle] pub extern "C" fn AzColorInput_setOnValueChange(colorinput: &mut AzColorInput, data: AzRefAny, callback: AzColorInputOnValueChangeCallbackType) { colorinput.set_on_value_change(data, callback) }
+#[no_mangle] pub extern "C" fn AzColorInput_setOnValueChange(colorinput: &mut AzColorInput, data: AzRefAny, callback: AzColorInputOnValueChangeCallbackType) { ffi_guard!((), { colorinput.set_on_value_change(data, callback) }) }
 /// Equivalent to the Rust `ColorInput::with_on_value_change()` function.
-#[no_mangle] pub extern "C" fn AzColorInput_withOnValueChange(colorinput: &mut AzColorInput, data: AzRefAny, callback: AzColorInputOnValueChangeCallbackType) -> AzColorInput { let mut colorinput = colorinput.swap_with_default(); colorinput.set_on_value_change(data, callback); colorinput }
+#[no_mangle] pub extern "C" fn AzColorInput_withOnValueChange(colorinput: &mut AzColorInput, data: AzRefAny, callback: AzColorInputOnValueChangeCallbackType) -> AzColorInput { ffi_guard!(AzColorInput, { let mut colorinput = colorinput.swap_with_default(); colorinput.set_on_value_change(data, callback); colorinput }) }
 /// Equivalent to the Rust `ColorInput::dom()` function.
-#[no_mangle] pub extern "C" fn AzColorInput_dom(colorinput: &mut AzColorInput) -> AzDom { colorinput.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzColorInput_dom(colorinput: &mut AzColorInput) -> AzDom { ffi_guard!(AzDom, { colorinput.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `ColorInput` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzColorInput_delete(object: &mut AzColorInput) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzColorInput_delete(object: &mut AzColorInput) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ColorInputStateWrapper` struct
 pub use crate::widgets::color_input::ColorInputStateWrapper as AzColorInputStateWrapperTT;
 pub use AzColorInputStateWrapperTT as AzColorInputStateWrapper;
 /// Destructor: Takes ownership of the `ColorInputStateWrapper` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzColorInputStateWrapper_delete(object: &mut AzColorInputStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzColorInputStateWrapper_delete(object: &mut AzColorInputStateWrapper) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ColorInputState` struct
 pub use crate::widgets::color_input::ColorInputState as AzColorInputStateTT;
@@ -2020,7 +2680,7 @@ pub use AzColorInputStateTT as AzColorInputState;
 pub use crate::widgets::color_input::ColorInputOnValueChange as AzColorInputOnValueChangeTT;
 pub use AzColorInputOnValueChangeTT as AzColorInputOnValueChange;
 /// Destructor: Takes ownership of the `ColorInputOnValueChange` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzColorInputOnValueChange_delete(object: &mut AzColorInputOnValueChange) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzColorInputOnValueChange_delete(object: &mut AzColorInputOnValueChange) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ColorInputOnValueChangeCallback` struct
 pub use crate::widgets::color_input::ColorInputOnValueChangeCallback as AzColorInputOnValueChangeCallbackTT;
@@ -2032,57 +2692,57 @@ pub use crate::widgets::text_input::TextInput as AzTextInputTT;
 pub use AzTextInputTT as AzTextInput;
 /// Creates a new `TextInput` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `TextInput::new()` constructor.
-#[no_mangle] pub extern "C" fn AzTextInput_new() -> AzTextInput { AzTextInput::new() }
+#[no_mangle] pub extern "C" fn AzTextInput_new() -> AzTextInput { ffi_guard!(AzTextInput, { AzTextInput::new() }) }
 /// Equivalent to the Rust `TextInput::set_text()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_setText(textinput: &mut AzTextInput, text: AzString) { textinput.set_text(text) }
+#[no_mangle] pub extern "C" fn AzTextInput_setText(textinput: &mut AzTextInput, text: AzString) { ffi_guard!((), { textinput.set_text(text) }) }
 /// Equivalent to the Rust `TextInput::with_text()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_withText(textinput: &mut AzTextInput, text: AzString) -> AzTextInput { textinput.with_text(text) }
+#[no_mangle] pub extern "C" fn AzTextInput_withText(textinput: &mut AzTextInput, text: AzString) -> AzTextInput { ffi_guard!(AzTextInput, { textinput.with_text(text) }) }
 /// Equivalent to the Rust `TextInput::set_placeholder()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_setPlaceholder(textinput: &mut AzTextInput, text: AzString) { textinput.set_placeholder(text) }
+#[no_mangle] pub extern "C" fn AzTextInput_setPlaceholder(textinput: &mut AzTextInput, text: AzString) { ffi_guard!((), { textinput.set_placeholder(text) }) }
 /// Equivalent to the Rust `TextInput::with_placeholder()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_withPlaceholder(textinput: &mut AzTextInput, text: AzString) -> AzTextInput { textinput.with_placeholder(text) }
+#[no_mangle] pub extern "C" fn AzTextInput_withPlaceholder(textinput: &mut AzTextInput, text: AzString) -> AzTextInput { ffi_guard!(AzTextInput, { textinput.with_placeholder(text) }) }
 /// Equivalent to the Rust `TextInput::set_on_text_input()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_setOnTextInput(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) { textinput.set_on_text_input(data, callback) }
+#[no_mangle] pub extern "C" fn AzTextInput_setOnTextInput(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) { ffi_guard!((), { textinput.set_on_text_input(data, callback) }) }
 /// Equivalent to the Rust `TextInput::with_on_text_input()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_withOnTextInput(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_on_text_input(data, callback); textinput }
+#[no_mangle] pub extern "C" fn AzTextInput_withOnTextInput(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) -> AzTextInput { ffi_guard!(AzTextInput, { let mut textinput = textinput.swap_with_default(); textinput.set_on_text_input(data, callback); textinput }) }
 /// Equivalent to the Rust `TextInput::set_on_virtual_key_down()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_setOnVirtualKeyDown(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) { textinput.set_on_virtual_key_down(data, callback) }
+#[no_mangle] pub extern "C" fn AzTextInput_setOnVirtualKeyDown(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) { ffi_guard!((), { textinput.set_on_virtual_key_down(data, callback) }) }
 /// Equivalent to the Rust `TextInput::with_on_virtual_key_down()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_withOnVirtualKeyDown(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_on_virtual_key_down(data, callback); textinput }
+#[no_mangle] pub extern "C" fn AzTextInput_withOnVirtualKeyDown(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) -> AzTextInput { ffi_guard!(AzTextInput, { let mut textinput = textinput.swap_with_default(); textinput.set_on_virtual_key_down(data, callback); textinput }) }
 /// Equivalent to the Rust `TextInput::set_on_focus_lost()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_setOnFocusLost(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnFocusLostCallbackType) { textinput.set_on_focus_lost(data, callback) }
+#[no_mangle] pub extern "C" fn AzTextInput_setOnFocusLost(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnFocusLostCallbackType) { ffi_guard!((), { textinput.set_on_focus_lost(data, callback) }) }
 /// Equivalent to the Rust `TextInput::with_on_focus_lost()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_withOnFocusLost(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnFocusLostCallbackType) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_on_focus_lost(data, callback); textinput }
+#[no_mangle] pub extern "C" fn AzTextInput_withOnFocusLost(textinput: &mut AzTextInput, data: AzRefAny, callback: AzTextInputOnFocusLostCallbackType) -> AzTextInput { ffi_guard!(AzTextInput, { let mut textinput = textinput.swap_with_default(); textinput.set_on_focus_lost(data, callback); textinput }) }
 /// Equivalent to the Rust `TextInput::set_placeholder_style()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_setPlaceholderStyle(textinput: &mut AzTextInput, placeholder_style: AzNodeDataInlineCssPropertyVec) { textinput.set_placeholder_style(placeholder_style) }
+#[no_mangle] pub extern "C" fn AzTextInput_setPlaceholderStyle(textinput: &mut AzTextInput, placeholder_style: AzNodeDataInlineCssPropertyVec) { ffi_guard!((), { textinput.set_placeholder_style(placeholder_style) }) }
 /// Equivalent to the Rust `TextInput::with_placeholder_style()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_withPlaceholderStyle(textinput: &mut AzTextInput, placeholder_style: AzNodeDataInlineCssPropertyVec) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_placeholder_style(placeholder_style); textinput }
+#[no_mangle] pub extern "C" fn AzTextInput_withPlaceholderStyle(textinput: &mut AzTextInput, placeholder_style: AzNodeDataInlineCssPropertyVec) -> AzTextInput { ffi_guard!(AzTextInput, { let mut textinput = textinput.swap_with_default(); textinput.set_placeholder_style(placeholder_style); textinput }) }
 /// Equivalent to the Rust `TextInput::set_container_style()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_setContainerStyle(textinput: &mut AzTextInput, container_style: AzNodeDataInlineCssPropertyVec) {  textinput.set_container_style(container_style) }
+#[no_mangle] pub extern "C" fn AzTextInput_setContainerStyle(textinput: &mut AzTextInput, container_style: AzNodeDataInlineCssPropertyVec) { ffi_guard!((), {  textinput.set_container_style(container_style) }) }
 /// Equivalent to the Rust `TextInput::with_container_style()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_withContainerStyle(textinput: &mut AzTextInput, container_style: AzNodeDataInlineCssPropertyVec) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_container_style(container_style); textinput }
+#[no_mangle] pub extern "C" fn AzTextInput_withContainerStyle(textinput: &mut AzTextInput, container_style: AzNodeDataInlineCssPropertyVec) -> AzTextInput { ffi_guard!(AzTextInput, { let mut textinput = textinput.swap_with_default(); textinput.set_container_style(container_style); textinput }) }
 /// Equivalent to the Rust `TextInput::set_label_style()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_setLabelStyle(textinput: &mut AzTextInput, label_style: AzNodeDataInlineCssPropertyVec) { textinput.set_container_style(label_style) }
+#[no_mangle] pub extern "C" fn AzTextInput_setLabelStyle(textinput: &mut AzTextInput, label_style: AzNodeDataInlineCssPropertyVec) { ffi_guard!((), { textinput.set_container_style(label_style) }) }
 /// Equivalent to the Rust `TextInput::with_label_style()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_withLabelStyle(textinput: &mut AzTextInput, label_style: AzNodeDataInlineCssPropertyVec) -> AzTextInput { let mut textinput = textinput.swap_with_default(); textinput.set_label_style(label_style); textinput }
+#[no_mangle] pub extern "C" fn AzTextInput_withLabelStyle(textinput: &mut AzTextInput, label_style: AzNodeDataInlineCssPropertyVec) -> AzTextInput { ffi_guard!(AzTextInput, { let mut textinput = textinput.swap_with_default(); textinput.set_label_style(label_style); textinput }) }
 /// Equivalent to the Rust `TextInput::dom()` function.
-#[no_mangle] pub extern "C" fn AzTextInput_dom(textinput: &mut AzTextInput) -> AzDom { textinput.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzTextInput_dom(textinput: &mut AzTextInput) -> AzDom { ffi_guard!(AzDom, { textinput.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `TextInput` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTextInput_delete(object: &mut AzTextInput) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTextInput_delete(object: &mut AzTextInput) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TextInputStateWrapper` struct
 pub use crate::widgets::text_input::TextInputStateWrapper as AzTextInputStateWrapperTT;
 pub use AzTextInputStateWrapperTT as AzTextInputStateWrapper;
 /// Destructor: Takes ownership of the `TextInputStateWrapper` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTextInputStateWrapper_delete(object: &mut AzTextInputStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTextInputStateWrapper_delete(object: &mut AzTextInputStateWrapper) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TextInputState` struct
 pub use crate::widgets::text_input::TextInputState as AzTextInputStateTT;
 pub use AzTextInputStateTT as AzTextInputState;
 /// Equivalent to the Rust `TextInputState::get_text()` function.
-#[no_mangle] pub extern "C" fn AzTextInputState_getText(textinputstate: &AzTextInputState) -> AzString { textinputstate.get_text().into() }
+#[no_mangle] pub extern "C" fn AzTextInputState_getText(textinputstate: &AzTextInputState) -> AzString { ffi_guard!(AzString, { textinputstate.get_text().into() }) }
 /// Destructor: Takes ownership of the `TextInputState` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTextInputState_delete(object: &mut AzTextInputState) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTextInputState_delete(object: &mut AzTextInputState) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TextInputSelection` struct
 pub use crate::widgets::text_input::TextInputSelection as AzTextInputSelectionTT;
@@ -2096,7 +2756,7 @@ pub use AzTextInputSelectionRangeTT as AzTextInputSelectionRange;
 pub use crate::widgets::text_input::TextInputOnTextInput as AzTextInputOnTextInputTT;
 pub use AzTextInputOnTextInputTT as AzTextInputOnTextInput;
 /// Destructor: Takes ownership of the `TextInputOnTextInput` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTextInputOnTextInput_delete(object: &mut AzTextInputOnTextInput) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTextInputOnTextInput_delete(object: &mut AzTextInputOnTextInput) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TextInputOnTextInputCallback` struct
 pub use crate::widgets::text_input::TextInputOnTextInputCallback as AzTextInputOnTextInputCallbackTT;
@@ -2107,7 +2767,7 @@ pub type AzTextInputOnTextInputCallbackType = extern "C" fn(&mut AzRefAny, &mut
 pub use crate::widgets::text_input::TextInputOnVirtualKeyDown as AzTextInputOnVirtualKeyDownTT;
 pub use AzTextInputOnVirtualKeyDownTT as AzTextInputOnVirtualKeyDown;
 /// Destructor: Takes ownership of the `TextInputOnVirtualKeyDown` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTextInputOnVirtualKeyDown_delete(object: &mut AzTextInputOnVirtualKeyDown) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTextInputOnVirtualKeyDown_delete(object: &mut AzTextInputOnVirtualKeyDown) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TextInputOnVirtualKeyDownCallback` struct
 pub use crate::widgets::text_input::TextInputOnVirtualKeyDownCallback as AzTextInputOnVirtualKeyDownCallbackTT;
@@ -2118,7 +2778,7 @@ pub type AzTextInputOnVirtualKeyDownCallbackType = extern "C" fn(&mut AzRefAny,
 pub use crate::widgets::text_input::TextInputOnFocusLost as AzTextInputOnFocusLostTT;
 pub use AzTextInputOnFocusLostTT as AzTextInputOnFocusLost;
 /// Destructor: Takes ownership of the `TextInputOnFocusLost` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTextInputOnFocusLost_delete(object: &mut AzTextInputOnFocusLost) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTextInputOnFocusLost_delete(object: &mut AzTextInputOnFocusLost) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TextInputOnFocusLostCallback` struct
 pub use crate::widgets::text_input::TextInputOnFocusLostCallback as AzTextInputOnFocusLostCallbackTT;
@@ -2138,45 +2798,45 @@ pub use crate::widgets::number_input::NumberInput as AzNumberInputTT;
 pub use AzNumberInputTT as AzNumberInput;
 /// Creates a new `NumberInput` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `NumberInput::new()` constructor.
-#[no_mangle] pub extern "C" fn AzNumberInput_new(number: f32) -> AzNumberInput { AzNumberInput::new(number) }
+#[no_mangle] pub extern "C" fn AzNumberInput_new(number: f32) -> AzNumberInput { ffi_guard!(AzNumberInput, { AzNumberInput::new(number) }) }
 /// Equivalent to the Rust `NumberInput::set_on_text_input()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_setOnTextInput(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) { numberinput.set_on_text_input(data, callback) }
+#[no_mangle] pub extern "C" fn AzNumberInput_setOnTextInput(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) { ffi_guard!((), { numberinput.set_on_text_input(data, callback) }) }
 /// Equivalent to the Rust `NumberInput::with_on_text_input()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_withOnTextInput(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_on_text_input(data, callback); numberinput }
+#[no_mangle] pub extern "C" fn AzNumberInput_withOnTextInput(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnTextInputCallbackType) -> AzNumberInput { ffi_guard!(AzNumberInput, { let mut numberinput = numberinput.swap_with_default(); numberinput.set_on_text_input(data, callback); numberinput }) }
 /// Equivalent to the Rust `NumberInput::set_on_virtual_key_down()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_setOnVirtualKeyDown(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) { numberinput.set_on_virtual_key_down(data, callback) }
+#[no_mangle] pub extern "C" fn AzNumberInput_setOnVirtualKeyDown(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) { ffi_guard!((), { numberinput.set_on_virtual_key_down(data, callback) }) }
 /// Equivalent to the Rust `NumberInput::with_on_virtual_key_down()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_withOnVirtualKeyDown(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_on_virtual_key_down(data, callback); numberinput }
+#[no_mangle] pub extern "C" fn AzNumberInput_withOnVirtualKeyDown(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzTextInputOnVirtualKeyDownCallbackType) -> AzNumberInput { ffi_guard!(AzNumberInput, { let mut numberinput = numberinput.swap_with_default(); numberinput.set_on_virtual_key_down(data, callback); numberinput }) }
 /// Equivalent to the Rust `NumberInput::set_on_focus_lost()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_setOnFocusLost(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnFocusLostCallbackType) { numberinput.set_on_focus_lost(data, callback) }
+#[no_mangle] pub extern "C" fn AzNumberInput_setOnFocusLost(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnFocusLostCallbackType) { ffi_guard!((), { numberinput.set_on_focus_lost(data, callback) }) }
 /// Equivalent to the Rust `NumberInput::with_on_focus_lost()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_withOnFocusLost(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnFocusLostCallbackType) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_on_focus_lost(data, callback); numberinput }
+#[no_mangle] pub extern "C" fn AzNumberInput_withOnFocusLost(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnFocusLostCallbackType) -> AzNumberInput { ffi_guard!(AzNumberInput, { let mut numberinput = numberinput.swap_with_default(); numberinput.set_on_focus_lost(data, callback); numberinput }) }
 /// Equivalent to the Rust `NumberInput::set_placeholder_style()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_setPlaceholderStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) { numberinput.set_placeholder_style(style) }
+#[no_mangle] pub extern "C" fn AzNumberInput_setPlaceholderStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) { ffi_guard!((), { numberinput.set_placeholder_style(style) }) }
 /// Equivalent to the Rust `NumberInput::with_placeholder_style()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_withPlaceholderStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_placeholder_style(style); numberinput }
+#[no_mangle] pub extern "C" fn AzNumberInput_withPlaceholderStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { ffi_guard!(AzNumberInput, { let mut numberinput = numberinput.swap_with_default(); numberinput.set_placeholder_style(style); numberinput }) }
 /// Equivalent to the Rust `NumberInput::set_container_style()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_setContainerStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) { numberinput.set_container_style(style) }
+#[no_mangle] pub extern "C" fn AzNumberInput_setContainerStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) { ffi_guard!((), { numberinput.set_container_style(style) }) }
 /// Equivalent to the Rust `NumberInput::with_container_style()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_withContainerStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_container_style(style); numberinput }
+#[no_mangle] pub extern "C" fn AzNumberInput_withContainerStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { ffi_guard!(AzNumberInput, { let mut numberinput = numberinput.swap_with_default(); numberinput.set_container_style(style); numberinput }) }
 /// Equivalent to the Rust `NumberInput::set_label_style()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_setLabelStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) { numberinput.set_label_style(style) }
+#[no_mangle] pub extern "C" fn AzNumberInput_setLabelStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) { ffi_guard!((), { numberinput.set_label_style(style) }) }
 /// Equivalent to the Rust `NumberInput::with_label_style()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_withLabelStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_label_style(style); numberinput }
+#[no_mangle] pub extern "C" fn AzNumberInput_withLabelStyle(numberinput: &mut AzNumberInput, style: AzNodeDataInlineCssPropertyVec) -> AzNumberInput { ffi_guard!(AzNumberInput, { let mut numberinput = numberinput.swap_with_default(); numberinput.set_label_style(style); numberinput }) }
 /// Equivalent to the Rust `NumberInput::set_on_value_change()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_setOnValueChange(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnValueChangeCallbackType) { numberinput.set_on_value_change(data, callback) }
+#[no_mangle] pub extern "C" fn AzNumberInput_setOnValueChange(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnValueChangeCallbackType) { ffi_guard!((), { numberinput.set_on_value_change(data, callback) }) }
 /// Equivalent to the Rust `NumberInput::with_on_value_change()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_withOnValueChange(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnValueChangeCallbackType) -> AzNumberInput { let mut numberinput = numberinput.swap_with_default(); numberinput.set_on_value_change(data, callback); numberinput }
+#[no_mangle] pub extern "C" fn AzNumberInput_withOnValueChange(numberinput: &mut AzNumberInput, data: AzRefAny, callback: AzNumberInputOnValueChangeCallbackType) -> AzNumberInput { ffi_guard!(AzNumberInput, { let mut numberinput = numberinput.swap_with_default(); numberinput.set_on_value_change(data, callback); numberinput }) }
 /// Equivalent to the Rust `NumberInput::dom()` function.
-#[no_mangle] pub extern "C" fn AzNumberInput_dom(numberinput: &mut AzNumberInput) -> AzDom { numberinput.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzNumberInput_dom(numberinput: &mut AzNumberInput) -> AzDom { ffi_guard!(AzDom, { numberinput.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `NumberInput` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNumberInput_delete(object: &mut AzNumberInput) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNumberInput_delete(object: &mut AzNumberInput) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
 pub use crate::widgets::number_input::NumberInputStateWrapper as AzNumberInputStateWrapperTT;
 pub use AzNumberInputStateWrapperTT as AzNumberInputStateWrapper;
 /// Destructor: Takes ownership of the `NumberInputStateWrapper` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNumberInputStateWrapper_delete(object: &mut AzNumberInputStateWrapper) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNumberInputStateWrapper_delete(object: &mut AzNumberInputStateWrapper) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NumberInputState` struct
 pub use crate::widgets::number_input::NumberInputState as AzNumberInputStateTT;
@@ -2186,7 +2846,7 @@ pub use AzNumberInputStateTT as AzNumberInputState;
 pub use crate::widgets::number_input::NumberInputOnValueChange as AzNumberInputOnValueChangeTT;
 pub use AzNumberInputOnValueChangeTT as AzNumberInputOnValueChange;
 /// Destructor: Takes ownership of the `NumberInputOnValueChange` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNumberInputOnValueChange_delete(object: &mut AzNumberInputOnValueChange) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNumberInputOnValueChange_delete(object: &mut AzNumberInputOnValueChange) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NumberInputOnValueChangeCallback` struct
 pub use crate::widgets::number_input::NumberInputOnValueChangeCallback as AzNumberInputOnValueChangeCallbackTT;
@@ -2197,7 +2857,7 @@ pub type AzNumberInputOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &
 pub use crate::widgets::number_input::NumberInputOnFocusLost as AzNumberInputOnFocusLostTT;
 pub use AzNumberInputOnFocusLostTT as AzNumberInputOnFocusLost;
 /// Destructor: Takes ownership of the `NumberInputOnFocusLost` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNumberInputOnFocusLost_delete(object: &mut AzNumberInputOnFocusLost) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNumberInputOnFocusLost_delete(object: &mut AzNumberInputOnFocusLost) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NumberInputOnFocusLostCallback` struct
 pub use crate::widgets::number_input::NumberInputOnFocusLostCallback as AzNumberInputOnFocusLostCallbackTT;
@@ -2209,23 +2869,23 @@ pub use crate::widgets::progressbar::ProgressBar as AzProgressBarTT;
 pub use AzProgressBarTT as AzProgressBar;
 /// Creates a new `ProgressBar` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `ProgressBar::new()` constructor.
-#[no_mangle] pub extern "C" fn AzProgressBar_new(percent_done: f32) -> AzProgressBar { AzProgressBar::new(percent_done) }
+#[no_mangle] pub extern "C" fn AzProgressBar_new(percent_done: f32) -> AzProgressBar { ffi_guard!(AzProgressBar, { AzProgressBar::new(percent_done) }) }
 /// Equivalent to the Rust `ProgressBar::set_height()` function.
-#[no_mangle] pub extern "C" fn AzProgressBar_setHeight(progressbar: &mut AzProgressBar, height: AzPixelValue) { progressbar.set_height(height); }
+#[no_mangle] pub extern "C" fn AzProgressBar_setHeight(progressbar: &mut AzProgressBar, height: AzPixelValue) { ffi_guard!((), { progressbar.set_height(height); }) }
 /// Equivalent to the Rust `ProgressBar::with_height()` function.
-#[no_mangle] pub extern "C" fn AzProgressBar_withHeight(progressbar: &mut AzProgressBar, height: AzPixelValue) -> AzProgressBar { let mut progressbar = progressbar.swap_with_default(); progressbar.set_height(height); progressbar }
+#[no_mangle] pub extern "C" fn AzProgressBar_withHeight(progressbar: &mut AzProgressBar, height: AzPixelValue) -> AzProgressBar { ffi_guard!(AzProgressBar, { let mut progressbar = progressbar.swap_with_default(); progressbar.set_height(height); progressbar }) }
 /// Equivalent to the Rust `ProgressBar::set_container_background()` function.
-#[no_mangle] pub extern "C" fn AzProgressBar_setContainerBackground(progressbar: &mut AzProgressBar, background: AzStyleBackgroundContentVec) { progressbar.set_container_background(background); }
+#[no_mangle] pub extern "C" fn AzProgressBar_setContainerBackground(progressbar: &mut AzProgressBar, background: AzStyleBackgroundContentVec) { ffi_guard!((), { progressbar.set_container_background(background); }) }
 /// Equivalent to the Rust `ProgressBar::with_container_style()` function.
-#[no_mangle] pub extern "C" fn AzProgressBar_withContainerStyle(progressbar: &mut AzProgressBar, background: AzStyleBackgroundContentVec) -> AzProgressBar { let mut progressbar = progressbar.swap_with_default(); progressbar.set_container_background(background); progressbar }
+#[no_mangle] pub extern "C" fn AzProgressBar_withContainerStyle(progressbar: &mut AzProgressBar, background: AzStyleBackgroundContentVec) -> AzProgressBar { ffi_guard!(AzProgressBar, { let mut progressbar = progressbar.swap_with_default(); progressbar.set_container_background(background); progressbar }) }
 /// Equivalent to the Rust `ProgressBar::set_bar_background()` function.
-#[no_mangle] pub extern "C" fn AzProgressBar_setBarBackground(progressbar: &mut AzProgressBar, background: AzStyleBackgroundContentVec) { progressbar.set_bar_background(background); }
+#[no_mangle] pub extern "C" fn AzProgressBar_setBarBackground(progressbar: &mut AzProgressBar, background: AzStyleBackgroundContentVec) { ffi_guard!((), { progressbar.set_bar_background(background); }) }
 /// Equivalent to the Rust `ProgressBar::with_bar_background()` function.
-#[no_mangle] pub extern "C" fn AzProgressBar_withBarBackground(progressbar: &mut AzProgressBar, background: AzStyleBackgroundContentVec) -> AzProgressBar { let mut progressbar = progressbar.swap_with_default(); progressbar.set_bar_background(background); progressbar }
+#[no_mangle] pub extern "C" fn AzProgressBar_withBarBackground(progressbar: &mut AzProgressBar, background: AzStyleBackgroundContentVec) -> AzProgressBar { ffi_guard!(AzProgressBar, { let mut progressbar = progressbar.swap_with_default(); progressbar.set_bar_background(background); progressbar }) }
 /// Equivalent to the Rust `ProgressBar::dom()` function.
-#[no_mangle] pub extern "C" fn AzProgressBar_dom(progressbar: &mut AzProgressBar) -> AzDom { progressbar.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzProgressBar_dom(progressbar: &mut AzProgressBar) -> AzDom { ffi_guard!(AzDom, { progressbar.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `ProgressBar` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzProgressBar_delete(object: &mut AzProgressBar) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzProgressBar_delete(object: &mut AzProgressBar) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ProgressBarState` struct
 pub use crate::widgets::progressbar::ProgressBarState as AzProgressBarStateTT;
@@ -2236,19 +2896,19 @@ pub use crate::widgets::tabs::TabHeader as AzTabHeaderTT;
 pub use AzTabHeaderTT as AzTabHeader;
 /// Creates a new `TabHeader` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `TabHeader::new()` constructor.
-#[no_mangle] pub extern "C" fn AzTabHeader_new(tabs: AzStringVec) -> AzTabHeader { AzTabHeader::new(tabs) }
+#[no_mangle] pub extern "C" fn AzTabHeader_new(tabs: AzStringVec) -> AzTabHeader { ffi_guard!(AzTabHeader, { AzTabHeader::new(tabs) }) }
 /// Equivalent to the Rust `TabHeader::set_active_tab()` function.
-#[no_mangle] pub extern "C" fn AzTabHeader_setActiveTab(tabheader: &mut AzTabHeader, active_tab: usize) { tabheader.set_active_tab(active_tab) }
+#[no_mangle] pub extern "C" fn AzTabHeader_setActiveTab(tabheader: &mut AzTabHeader, active_tab: usize) { ffi_guard!((), { tabheader.set_active_tab(active_tab) }) }
 /// Equivalent to the Rust `TabHeader::with_active_tab()` function.
-#[no_mangle] pub extern "C" fn AzTabHeader_withActiveTab(tabheader: &mut AzTabHeader, active_tab: usize) -> AzTabHeader { tabheader.with_active_tab(active_tab) }
+#[no_mangle] pub extern "C" fn AzTabHeader_withActiveTab(tabheader: &mut AzTabHeader, active_tab: usize) -> AzTabHeader { ffi_guard!(AzTabHeader, { tabheader.with_active_tab(active_tab) }) }
 /// Equivalent to the Rust `TabHeader::set_on_click()` function.
-#[no_mangle] pub extern "C" fn AzTabHeader_setOnClick(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnClickCallbackType) { tabheader.set_on_click(data, callback) }
+#[no_mangle] pub extern "C" fn AzTabHeader_setOnClick(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnClickCallbackType) { ffi_guard!((), { tabheader.set_on_click(data, callback) }) }
 /// Equivalent to the Rust `TabHeader::with_on_click()` function.
-#[no_mangle] pub extern "C" fn AzTabHeader_withOnClick(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnClickCallbackType) -> AzTabHeader { tabheader.with_on_click(data, callback) }
+#[no_mangle] pub extern "C" fn AzTabHeader_withOnClick(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnClickCallbackType) -> AzTabHeader { ffi_guard!(AzTabHeader, { tabheader.with_on_click(data, callback) }) }
 /// Equivalent to the Rust `TabHeader::dom()` function.
-#[no_mangle] pub extern "C" fn AzTabHeader_dom(tabheader: &mut AzTabHeader) -> AzDom { tabheader.dom() }
+#[no_mangle] pub extern "C" fn AzTabHeader_dom(tabheader: &mut AzTabHeader) -> AzDom { ffi_guard!(AzDom, { tabheader.dom() }) }
 /// Destructor: Takes ownership of the `TabHeader` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTabHeader_delete(object: &mut AzTabHeader) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTabHeader_delete(object: &mut AzTabHeader) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TabHeaderState` struct
 pub use crate::widgets::tabs::TabHeaderState as AzTabHeaderStateTT;
@@ -2259,21 +2919,21 @@ pub use crate::widgets::tabs::TabContent as AzTabContentTT;
 pub use AzTabContentTT as AzTabContent;
 /// Creates a new `TabContent` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `TabContent::new()` constructor.
-#[no_mangle] pub extern "C" fn AzTabContent_new(content: AzDom) -> AzTabContent { AzTabContent::new(content) }
+#[no_mangle] pub extern "C" fn AzTabContent_new(content: AzDom) -> AzTabContent { ffi_guard!(AzTabContent, { AzTabContent::new(content) }) }
 /// Equivalent to the Rust `TabContent::set_padding()` function.
-#[no_mangle] pub extern "C" fn AzTabContent_setPadding(tabcontent: &mut AzTabContent, has_padding: bool) { tabcontent.set_padding(has_padding) }
+#[no_mangle] pub extern "C" fn AzTabContent_setPadding(tabcontent: &mut AzTabContent, has_padding: bool) { ffi_guard!((), { tabcontent.set_padding(has_padding) }) }
 /// Equivalent to the Rust `TabContent::with_padding()` function.
-#[no_mangle] pub extern "C" fn AzTabContent_withPadding(tabcontent: &mut AzTabContent, has_padding: bool) -> AzTabContent { tabcontent.with_padding(has_padding) }
+#[no_mangle] pub extern "C" fn AzTabContent_withPadding(tabcontent: &mut AzTabContent, has_padding: bool) -> AzTabContent { ffi_guard!(AzTabContent, { tabcontent.with_padding(has_padding) }) }
 /// Equivalent to the Rust `TabContent::dom()` function.
-#[no_mangle] pub extern "C" fn AzTabContent_dom(tabcontent: &mut AzTabContent) -> AzDom { tabcontent.dom() }
+#[no_mangle] pub extern "C" fn AzTabContent_dom(tabcontent: &mut AzTabContent) -> AzDom { ffi_guard!(AzDom, { tabcontent.dom() }) }
 /// Destructor: Takes ownership of the `TabContent` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTabContent_delete(object: &mut AzTabContent) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTabContent_delete(object: &mut AzTabContent) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TabOnClick` struct
 pub use crate::widgets::tabs::TabOnClick as AzTabOnClickTT;
 pub use AzTabOnClickTT as AzTabOnClick;
 /// Destructor: Takes ownership of the `TabOnClick` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTabOnClick_delete(object: &mut AzTabOnClick) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTabOnClick_delete(object: &mut AzTabOnClick) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TabOnClickCallback` struct
 pub use crate::widgets::tabs::TabOnClickCallback as AzTabOnClickCallbackTT;
@@ -2285,41 +2945,41 @@ pub use crate::widgets::frame::Frame as AzFrameTT;
 pub use AzFrameTT as AzFrame;
 /// Creates a new `Frame` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Frame::new()` constructor.
-#[no_mangle] pub extern "C" fn AzFrame_new(title: AzString, dom: AzDom) -> AzFrame { AzFrame::new(title, dom) }
+#[no_mangle] pub extern "C" fn AzFrame_new(title: AzString, dom: AzDom) -> AzFrame { ffi_guard!(AzFrame, { AzFrame::new(title, dom) }) }
 /// Equivalent to the Rust `Frame::set_flex_grow()` function.
-#[no_mangle] pub extern "C" fn AzFrame_setFlexGrow(frame: &mut AzFrame, flex_grow: f32) { frame.set_flex_grow(flex_grow) }
+#[no_mangle] pub extern "C" fn AzFrame_setFlexGrow(frame: &mut AzFrame, flex_grow: f32) { ffi_guard!((), { frame.set_flex_grow(flex_grow) }) }
 /// Equivalent to the Rust `Frame::with_flex_grow()` function.
-#[no_mangle] pub extern "C" fn AzFrame_withFlexGrow(frame: &mut AzFrame, flex_grow: f32) -> AzFrame { let mut frame = frame.swap_with_default(); frame.set_flex_grow(flex_grow); frame }
+#[no_mangle] pub extern "C" fn AzFrame_withFlexGrow(frame: &mut AzFrame, flex_grow: f32) -> AzFrame { ffi_guard!(AzFrame, { let mut frame = frame.swap_with_default(); frame.set_flex_grow(flex_grow); frame }) }
 /// Equivalent to the Rust `Frame::dom()` function.
-#[no_mangle] pub extern "C" fn AzFrame_dom(frame: &mut AzFrame) -> AzDom { let mut frame = frame.swap_with_default(); frame.dom() }
+#[no_mangle] pub extern "C" fn AzFrame_dom(frame: &mut AzFrame) -> AzDom { ffi_guard!(AzDom, { let mut frame = frame.swap_with_default(); frame.dom() }) }
 /// Destructor: Takes ownership of the `Frame` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFrame_delete(object: &mut AzFrame) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFrame_delete(object: &mut AzFrame) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NodeGraph` struct
 pub use crate::widgets::node_graph::NodeGraph as AzNodeGraphTT;
 pub use AzNodeGraphTT as AzNodeGraph;
 /// Equivalent to the Rust `NodeGraph::dom()` function.
-#[no_mangle] pub extern "C" fn AzNodeGraph_dom(nodegraph: &mut AzNodeGraph) -> AzDom { let mut nodegraph = nodegraph.swap_with_default(); nodegraph.dom() }
+#[no_mangle] pub extern "C" fn AzNodeGraph_dom(nodegraph: &mut AzNodeGraph) -> AzDom { ffi_guard!(AzDom, { let mut nodegraph = nodegraph.swap_with_default(); nodegraph.dom() }) }
 /// Destructor: Takes ownership of the `NodeGraph` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeGraph_delete(object: &mut AzNodeGraph) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeGraph_delete(object: &mut AzNodeGraph) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NodeTypeIdInfoMap` struct
 pub use crate::widgets::node_graph::NodeTypeIdInfoMap as AzNodeTypeIdInfoMapTT;
 pub use AzNodeTypeIdInfoMapTT as AzNodeTypeIdInfoMap;
 /// Destructor: Takes ownership of the `NodeTypeIdInfoMap` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeTypeIdInfoMap_delete(object: &mut AzNodeTypeIdInfoMap) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeTypeIdInfoMap_delete(object: &mut AzNodeTypeIdInfoMap) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InputOutputTypeIdInfoMap` struct
 pub use crate::widgets::node_graph::InputOutputTypeIdInfoMap as AzInputOutputTypeIdInfoMapTT;
 pub use AzInputOutputTypeIdInfoMapTT as AzInputOutputTypeIdInfoMap;
 /// Destructor: Takes ownership of the `InputOutputTypeIdInfoMap` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInputOutputTypeIdInfoMap_delete(object: &mut AzInputOutputTypeIdInfoMap) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInputOutputTypeIdInfoMap_delete(object: &mut AzInputOutputTypeIdInfoMap) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NodeIdNodeMap` struct
 pub use crate::widgets::node_graph::NodeIdNodeMap as AzNodeIdNodeMapTT;
 pub use AzNodeIdNodeMapTT as AzNodeIdNodeMap;
 /// Destructor: Takes ownership of the `NodeIdNodeMap` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeIdNodeMap_delete(object: &mut AzNodeIdNodeMap) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeIdNodeMap_delete(object: &mut AzNodeIdNodeMap) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NodeGraphStyle` struct
 pub use crate::widgets::node_graph::NodeGraphStyle as AzNodeGraphStyleTT;
@@ -2329,7 +2989,7 @@ pub use AzNodeGraphStyleTT as AzNodeGraphStyle;
 pub use crate::widgets::node_graph::NodeGraphCallbacks as AzNodeGraphCallbacksTT;
 pub use AzNodeGraphCallbacksTT as AzNodeGraphCallbacks;
 /// Destructor: Takes ownership of the `NodeGraphCallbacks` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeGraphCallbacks_delete(object: &mut AzNodeGraphCallbacks) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeGraphCallbacks_delete(object: &mut AzNodeGraphCallbacks) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzNodeGraphOnNodeAddedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeTypeId, AzNodeGraphNodeId, AzNodePosition) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeAddedCallback` struct
@@ -2340,7 +3000,7 @@ pub use AzNodeGraphOnNodeAddedCallbackTT as AzNodeGraphOnNodeAddedCallback;
 pub use crate::widgets::node_graph::OnNodeAdded as AzNodeGraphOnNodeAddedTT;
 pub use AzNodeGraphOnNodeAddedTT as AzNodeGraphOnNodeAdded;
 /// Destructor: Takes ownership of the `NodeGraphOnNodeAdded` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeAdded_delete(object: &mut AzNodeGraphOnNodeAdded) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeAdded_delete(object: &mut AzNodeGraphOnNodeAdded) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzNodeGraphOnNodeRemovedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeRemovedCallback` struct
@@ -2351,7 +3011,7 @@ pub use AzNodeGraphOnNodeRemovedCallbackTT as AzNodeGraphOnNodeRemovedCallback;
 pub use crate::widgets::node_graph::OnNodeRemoved as AzNodeGraphOnNodeRemovedTT;
 pub use AzNodeGraphOnNodeRemovedTT as AzNodeGraphOnNodeRemoved;
 /// Destructor: Takes ownership of the `NodeGraphOnNodeRemoved` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeRemoved_delete(object: &mut AzNodeGraphOnNodeRemoved) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeRemoved_delete(object: &mut AzNodeGraphOnNodeRemoved) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzNodeGraphOnNodeGraphDraggedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzGraphDragAmount) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphDraggedCallback` struct
@@ -2362,7 +3022,7 @@ pub use AzNodeGraphOnNodeGraphDraggedCallbackTT as AzNodeGraphOnNodeGraphDragged
 pub use crate::widgets::node_graph::OnNodeGraphDragged as AzNodeGraphOnNodeGraphDraggedTT;
 pub use AzNodeGraphOnNodeGraphDraggedTT as AzNodeGraphOnNodeGraphDragged;
 /// Destructor: Takes ownership of the `NodeGraphOnNodeGraphDragged` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeGraphDragged_delete(object: &mut AzNodeGraphOnNodeGraphDragged) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeGraphDragged_delete(object: &mut AzNodeGraphOnNodeGraphDragged) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzNodeGraphOnNodeDraggedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, AzNodeDragAmount) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeDraggedCallback` struct
@@ -2373,7 +3033,7 @@ pub use AzNodeGraphOnNodeDraggedCallbackTT as AzNodeGraphOnNodeDraggedCallback;
 pub use crate::widgets::node_graph::OnNodeDragged as AzNodeGraphOnNodeDraggedTT;
 pub use AzNodeGraphOnNodeDraggedTT as AzNodeGraphOnNodeDragged;
 /// Destructor: Takes ownership of the `NodeGraphOnNodeDragged` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeDragged_delete(object: &mut AzNodeGraphOnNodeDragged) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeDragged_delete(object: &mut AzNodeGraphOnNodeDragged) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzNodeGraphOnNodeConnectedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize, AzNodeGraphNodeId, usize) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeConnectedCallback` struct
@@ -2384,7 +3044,7 @@ pub use AzNodeGraphOnNodeConnectedCallbackTT as AzNodeGraphOnNodeConnectedCallba
 pub use crate::widgets::node_graph::OnNodeConnected as AzNodeGraphOnNodeConnectedTT;
 pub use AzNodeGraphOnNodeConnectedTT as AzNodeGraphOnNodeConnected;
 /// Destructor: Takes ownership of the `NodeGraphOnNodeConnected` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeConnected_delete(object: &mut AzNodeGraphOnNodeConnected) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeConnected_delete(object: &mut AzNodeGraphOnNodeConnected) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzNodeGraphOnNodeInputDisconnectedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeInputDisconnectedCallback` struct
@@ -2395,7 +3055,7 @@ pub use AzNodeGraphOnNodeInputDisconnectedCallbackTT as AzNodeGraphOnNodeInputDi
 pub use crate::widgets::node_graph::OnNodeInputDisconnected as AzNodeGraphOnNodeInputDisconnectedTT;
 pub use AzNodeGraphOnNodeInputDisconnectedTT as AzNodeGraphOnNodeInputDisconnected;
 /// Destructor: Takes ownership of the `NodeGraphOnNodeInputDisconnected` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeInputDisconnected_delete(object: &mut AzNodeGraphOnNodeInputDisconnected) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeInputDisconnected_delete(object: &mut AzNodeGraphOnNodeInputDisconnected) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzNodeGraphOnNodeOutputDisconnectedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeOutputDisconnectedCallback` struct
@@ -2406,7 +3066,7 @@ pub use AzNodeGraphOnNodeOutputDisconnectedCallbackTT as AzNodeGraphOnNodeOutput
 pub use crate::widgets::node_graph::OnNodeOutputDisconnected as AzNodeGraphOnNodeOutputDisconnectedTT;
 pub use AzNodeGraphOnNodeOutputDisconnectedTT as AzNodeGraphOnNodeOutputDisconnected;
 /// Destructor: Takes ownership of the `NodeGraphOnNodeOutputDisconnected` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeOutputDisconnected_delete(object: &mut AzNodeGraphOnNodeOutputDisconnected) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeOutputDisconnected_delete(object: &mut AzNodeGraphOnNodeOutputDisconnected) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzNodeGraphOnNodeFieldEditedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize, AzNodeTypeId, AzNodeTypeFieldValue) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeFieldEditedCallback` struct
@@ -2417,7 +3077,7 @@ pub use AzNodeGraphOnNodeFieldEditedCallbackTT as AzNodeGraphOnNodeFieldEditedCa
 pub use crate::widgets::node_graph::OnNodeFieldEdited as AzNodeGraphOnNodeFieldEditedTT;
 pub use AzNodeGraphOnNodeFieldEditedTT as AzNodeGraphOnNodeFieldEdited;
 /// Destructor: Takes ownership of the `NodeGraphOnNodeFieldEdited` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeFieldEdited_delete(object: &mut AzNodeGraphOnNodeFieldEdited) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeGraphOnNodeFieldEdited_delete(object: &mut AzNodeGraphOnNodeFieldEdited) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InputOutputTypeId` struct
 pub use crate::widgets::node_graph::InputOutputTypeId as AzInputOutputTypeIdTT;
@@ -2435,25 +3095,25 @@ pub use AzNodeGraphNodeIdTT as AzNodeGraphNodeId;
 pub use crate::widgets::node_graph::Node as AzNodeTT;
 pub use AzNodeTT as AzNode;
 /// Destructor: Takes ownership of the `Node` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNode_delete(object: &mut AzNode) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNode_delete(object: &mut AzNode) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NodeTypeField` struct
 pub use crate::widgets::node_graph::NodeTypeField as AzNodeTypeFieldTT;
 pub use AzNodeTypeFieldTT as AzNodeTypeField;
 /// Destructor: Takes ownership of the `NodeTypeField` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeTypeField_delete(object: &mut AzNodeTypeField) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeTypeField_delete(object: &mut AzNodeTypeField) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NodeTypeFieldValue` struct
 pub use crate::widgets::node_graph::NodeTypeFieldValue as AzNodeTypeFieldValueTT;
 pub use AzNodeTypeFieldValueTT as AzNodeTypeFieldValue;
 /// Destructor: Takes ownership of the `NodeTypeFieldValue` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeTypeFieldValue_delete(object: &mut AzNodeTypeFieldValue) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeTypeFieldValue_delete(object: &mut AzNodeTypeFieldValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InputConnection` struct
 pub use crate::widgets::node_graph::InputConnection as AzInputConnectionTT;
 pub use AzInputConnectionTT as AzInputConnection;
 /// Destructor: Takes ownership of the `InputConnection` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInputConnection_delete(object: &mut AzInputConnection) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInputConnection_delete(object: &mut AzInputConnection) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OutputNodeAndIndex` struct
 pub use crate::widgets::node_graph::OutputNodeAndIndex as AzOutputNodeAndIndexTT;
@@ -2463,7 +3123,7 @@ pub use AzOutputNodeAndIndexTT as AzOutputNodeAndIndex;
 pub use crate::widgets::node_graph::OutputConnection as AzOutputConnectionTT;
 pub use AzOutputConnectionTT as AzOutputConnection;
 /// Destructor: Takes ownership of the `OutputConnection` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOutputConnection_delete(object: &mut AzOutputConnection) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOutputConnection_delete(object: &mut AzOutputConnection) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InputNodeAndIndex` struct
 pub use crate::widgets::node_graph::InputNodeAndIndex as AzInputNodeAndIndexTT;
@@ -2473,13 +3133,13 @@ pub use AzInputNodeAndIndexTT as AzInputNodeAndIndex;
 pub use crate::widgets::node_graph::NodeTypeInfo as AzNodeTypeInfoTT;
 pub use AzNodeTypeInfoTT as AzNodeTypeInfo;
 /// Destructor: Takes ownership of the `NodeTypeInfo` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeTypeInfo_delete(object: &mut AzNodeTypeInfo) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeTypeInfo_delete(object: &mut AzNodeTypeInfo) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InputOutputInfo` struct
 pub use crate::widgets::node_graph::InputOutputInfo as AzInputOutputInfoTT;
 pub use AzInputOutputInfoTT as AzInputOutputInfo;
 /// Destructor: Takes ownership of the `InputOutputInfo` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInputOutputInfo_delete(object: &mut AzInputOutputInfo) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInputOutputInfo_delete(object: &mut AzInputOutputInfo) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NodePosition` struct
 pub use crate::widgets::node_graph::NodePosition as AzNodePositionTT;
@@ -2498,25 +3158,25 @@ pub use crate::widgets::list_view::ListView as AzListViewTT;
 pub use AzListViewTT as AzListView;
 /// Creates a new `ListView` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `ListView::new()` constructor.
-#[no_mangle] pub extern "C" fn AzListView_new(columns: AzStringVec) -> AzListView { AzListView::new(columns) }
+#[no_mangle] pub extern "C" fn AzListView_new(columns: AzStringVec) -> AzListView { ffi_guard!(AzListView, { AzListView::new(columns) }) }
 /// Equivalent to the Rust `ListView::with_rows()` function.
-#[no_mangle] pub extern "C" fn AzListView_withRows(listview: &mut AzListView, rows: AzListViewRowVec) -> AzListView { listview.with_rows(rows) }
+#[no_mangle] pub extern "C" fn AzListView_withRows(listview: &mut AzListView, rows: AzListViewRowVec) -> AzListView { ffi_guard!(AzListView, { listview.with_rows(rows) }) }
 /// Equivalent to the Rust `ListView::dom()` function.
-#[no_mangle] pub extern "C" fn AzListView_dom(listview: &mut AzListView) -> AzDom { listview.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzListView_dom(listview: &mut AzListView) -> AzDom { ffi_guard!(AzDom, { listview.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `ListView` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzListView_delete(object: &mut AzListView) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzListView_delete(object: &mut AzListView) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ListViewRow` struct
 pub use crate::widgets::list_view::ListViewRow as AzListViewRowTT;
 pub use AzListViewRowTT as AzListViewRow;
 /// Destructor: Takes ownership of the `ListViewRow` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzListViewRow_delete(object: &mut AzListViewRow) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzListViewRow_delete(object: &mut AzListViewRow) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ListViewState` struct
 pub use crate::widgets::list_view::ListViewState as AzListViewStateTT;
 pub use AzListViewStateTT as AzListViewState;
 /// Destructor: Takes ownership of the `ListViewState` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzListViewState_delete(object: &mut AzListViewState) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzListViewState_delete(object: &mut AzListViewState) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzListViewOnLazyLoadScrollCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `ListViewOnLazyLoadScrollCallback` struct
@@ -2527,7 +3187,7 @@ pub use AzListViewOnLazyLoadScrollCallbackTT as AzListViewOnLazyLoadScrollCallba
 pub use crate::widgets::list_view::ListViewOnLazyLoadScroll as AzListViewOnLazyLoadScrollTT;
 pub use AzListViewOnLazyLoadScrollTT as AzListViewOnLazyLoadScroll;
 /// Destructor: Takes ownership of the `ListViewOnLazyLoadScroll` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzListViewOnLazyLoadScroll_delete(object: &mut AzListViewOnLazyLoadScroll) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzListViewOnLazyLoadScroll_delete(object: &mut AzListViewOnLazyLoadScroll) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzListViewOnColumnClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `ListViewOnColumnClickCallback` struct
@@ -2538,7 +3198,7 @@ pub use AzListViewOnColumnClickCallbackTT as AzListViewOnColumnClickCallback;
 pub use crate::widgets::list_view::ListViewOnColumnClick as AzListViewOnColumnClickTT;
 pub use AzListViewOnColumnClickTT as AzListViewOnColumnClick;
 /// Destructor: Takes ownership of the `ListViewOnColumnClick` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzListViewOnColumnClick_delete(object: &mut AzListViewOnColumnClick) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzListViewOnColumnClick_delete(object: &mut AzListViewOnColumnClick) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzListViewOnRowClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `ListViewOnRowClickCallback` struct
@@ -2549,29 +3209,29 @@ pub use AzListViewOnRowClickCallbackTT as AzListViewOnRowClickCallback;
 pub use crate::widgets::list_view::ListViewOnRowClick as AzListViewOnRowClickTT;
 pub use AzListViewOnRowClickTT as AzListViewOnRowClick;
 /// Destructor: Takes ownership of the `ListViewOnRowClick` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzListViewOnRowClick_delete(object: &mut AzListViewOnRowClick) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzListViewOnRowClick_delete(object: &mut AzListViewOnRowClick) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TreeView` struct
 pub use crate::widgets::tree_view::TreeView as AzTreeViewTT;
 pub use AzTreeViewTT as AzTreeView;
 /// Creates a new `TreeView` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `TreeView::new()` constructor.
-#[no_mangle] pub extern "C" fn AzTreeView_new(root: AzString) -> AzTreeView { AzTreeView::new(root) }
+#[no_mangle] pub extern "C" fn AzTreeView_new(root: AzString) -> AzTreeView { ffi_guard!(AzTreeView, { AzTreeView::new(root) }) }
 /// Equivalent to the Rust `TreeView::dom()` function.
-#[no_mangle] pub extern "C" fn AzTreeView_dom(treeview: &mut AzTreeView) -> AzDom { treeview.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzTreeView_dom(treeview: &mut AzTreeView) -> AzDom { ffi_guard!(AzDom, { treeview.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `TreeView` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTreeView_delete(object: &mut AzTreeView) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTreeView_delete(object: &mut AzTreeView) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `DropDown` struct
 pub use crate::widgets::drop_down::DropDown as AzDropDownTT;
 pub use AzDropDownTT as AzDropDown;
 /// Creates a new `DropDown` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `DropDown::new()` constructor.
-#[no_mangle] pub extern "C" fn AzDropDown_new(choices: AzStringVec) -> AzDropDown { AzDropDown::new(choices) }
+#[no_mangle] pub extern "C" fn AzDropDown_new(choices: AzStringVec) -> AzDropDown { ffi_guard!(AzDropDown, { AzDropDown::new(choices) }) }
 /// Equivalent to the Rust `DropDown::dom()` function.
-#[no_mangle] pub extern "C" fn AzDropDown_dom(dropdown: &mut AzDropDown) -> AzDom { dropdown.swap_with_default().dom() }
+#[no_mangle] pub extern "C" fn AzDropDown_dom(dropdown: &mut AzDropDown) -> AzDom { ffi_guard!(AzDom, { dropdown.swap_with_default().dom() }) }
 /// Destructor: Takes ownership of the `DropDown` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzDropDown_delete(object: &mut AzDropDown) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzDropDown_delete(object: &mut AzDropDown) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChangeCallback` struct
@@ -2582,7 +3242,7 @@ pub use AzDropDownOnChoiceChangeCallbackTT as AzDropDownOnChoiceChangeCallback;
 pub use crate::widgets::drop_down::DropDownOnChoiceChange as AzDropDownOnChoiceChangeTT;
 pub use AzDropDownOnChoiceChangeTT as AzDropDownOnChoiceChange;
 /// Destructor: Takes ownership of the `DropDownOnChoiceChange` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzDropDownOnChoiceChange_delete(object: &mut AzDropDownOnChoiceChange) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzDropDownOnChoiceChange_delete(object: &mut AzDropDownOnChoiceChange) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NodeHierarchyItem` struct
 pub use azul_impl::styled_dom::NodeHierarchyItem as AzNodeHierarchyItemTT;
@@ -2596,7 +3256,7 @@ pub use AzCascadeInfoTT as AzCascadeInfo;
 pub use azul_impl::styled_dom::CssPropertySource as AzCssPropertySourceTT;
 pub use AzCssPropertySourceTT as AzCssPropertySource;
 /// Destructor: Takes ownership of the `CssPropertySource` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssPropertySource_delete(object: &mut AzCssPropertySource) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCssPropertySource_delete(object: &mut AzCssPropertySource) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyledNodeState` struct
 pub use azul_impl::styled_dom::StyledNodeState as AzStyledNodeStateTT;
@@ -2614,7 +3274,7 @@ pub use AzTagIdTT as AzTagId;
 pub use azul_impl::styled_dom::TagIdToNodeIdMapping as AzTagIdToNodeIdMappingTT;
 pub use AzTagIdToNodeIdMappingTT as AzTagIdToNodeIdMapping;
 /// Destructor: Takes ownership of the `TagIdToNodeIdMapping` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTagIdToNodeIdMapping_delete(object: &mut AzTagIdToNodeIdMapping) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTagIdToNodeIdMapping_delete(object: &mut AzTagIdToNodeIdMapping) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ParentWithNodeDepth` struct
 pub use azul_impl::styled_dom::ParentWithNodeDepth as AzParentWithNodeDepthTT;
@@ -2624,7 +3284,7 @@ pub use AzParentWithNodeDepthTT as AzParentWithNodeDepth;
 pub use azul_impl::styled_dom::CssPropertyCachePtr as AzCssPropertyCacheTT;
 pub use AzCssPropertyCacheTT as AzCssPropertyCache;
 /// Destructor: Takes ownership of the `CssPropertyCache` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssPropertyCache_delete(object: &mut AzCssPropertyCache) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzCssPropertyCache_delete(object: &mut AzCssPropertyCache) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzCssPropertyCache_deepCopy(object: &AzCssPropertyCache) -> AzCssPropertyCache { object.clone() }
 
@@ -2632,58 +3292,66 @@ pub use AzCssPropertyCacheTT as AzCssPropertyCache;
 pub use azul_impl::styled_dom::StyledDom as AzStyledDomTT;
 pub use AzStyledDomTT as AzStyledDom;
 /// Styles a `Dom` with the given `Css`, returning the `StyledDom` - complexity `O(count(dom_nodes) * count(css_blocks))`: make sure that the `Dom` and the `Css` are as small as possible, use inline CSS if the performance isn't good enough
-#[no_mangle] pub extern "C" fn AzStyledDom_new(dom: AzDom, css: AzCss) -> AzStyledDom { let mut dom = dom; let mut css = css; AzStyledDom::new(&mut dom, css) }
+#[no_mangle] pub extern "C" fn AzStyledDom_new(dom: AzDom, css: AzCss) -> AzStyledDom { ffi_guard!(AzStyledDom, { let mut dom = dom; let mut css = css; AzStyledDom::new(&mut dom, css) }) }
 /// Returns a default, empty `Dom`, usually returned if you don't want to crash in an error case.
-#[no_mangle] pub extern "C" fn AzStyledDom_default() -> AzStyledDom { AzStyledDom::default() }
+#[no_mangle] pub extern "C" fn AzStyledDom_default() -> AzStyledDom { ffi_guard!(AzStyledDom, { AzStyledDom::default() }) }
 /// Returns a DOM loaded from an XML file
-#[no_mangle] pub extern "C" fn AzStyledDom_fromXml(xml_string: AzString) -> AzStyledDom { azul_impl::app::extra::styled_dom_from_str(xml_string.as_str()) }
+#[no_mangle] pub extern "C" fn AzStyledDom_fromXml(xml_string: AzString) -> AzStyledDom { ffi_guard!(AzStyledDom, { azul_impl::app::extra::styled_dom_from_str(xml_string.as_str()) }) }
 /// Same as `from_xml`, but loads the file relative to the current directory
-#[no_mangle] pub extern "C" fn AzStyledDom_fromFile(xml_file_path: AzString) -> AzStyledDom { azul_impl::app::extra::styled_dom_from_file(xml_file_path.as_str()) }
+#[no_mangle] pub extern "C" fn AzStyledDom_fromFile(xml_file_path: AzString) -> AzStyledDom { ffi_guard!(AzStyledDom, { azul_impl::app::extra::styled_dom_from_file(xml_file_path.as_str()) }) }
 /// Appends an already styled list of DOM nodes to the current `dom.root` - complexity `O(count(dom.dom_nodes))`
-#[no_mangle] pub extern "C" fn AzStyledDom_appendChild(styleddom: &mut AzStyledDom, dom: AzStyledDom) { styleddom.append_child(dom); }
+#[no_mangle] pub extern "C" fn AzStyledDom_appendChild(styleddom: &mut AzStyledDom, dom: AzStyledDom) { ffi_guard!((), { styleddom.append_child(dom); }) }
 /// Same as `append_child()`, but as a builder method
-#[no_mangle] pub extern "C" fn AzStyledDom_withChild(styleddom: &mut AzStyledDom, dom: AzStyledDom) -> AzStyledDom { styleddom.with_child(dom) }
+#[no_mangle] pub extern "C" fn AzStyledDom_withChild(styleddom: &mut AzStyledDom, dom: AzStyledDom) -> AzStyledDom { ffi_guard!(AzStyledDom, { styleddom.with_child(dom) }) }
 /// Restyles an already styled DOM with a new CSS - overwrites old styles, but does not replace them, useful for implementing user styles that are applied on top of the existing application style
-#[no_mangle] pub extern "C" fn AzStyledDom_restyle(styleddom: &mut AzStyledDom, css: AzCss) { styleddom.restyle(css); }
+#[no_mangle] pub extern "C" fn AzStyledDom_restyle(styleddom: &mut AzStyledDom, css: AzCss) { ffi_guard!((), { styleddom.restyle(css); }) }
 /// Returns the number of nodes in the styled DOM
-#[no_mangle] pub extern "C" fn AzStyledDom_nodeCount(styleddom: &AzStyledDom) -> usize { styleddom.node_count() }
+#[no_mangle] pub extern "C" fn AzStyledDom_nodeCount(styleddom: &AzStyledDom) -> usize { ffi_guard!(usize, { styleddom.node_count() }) }
 /// Returns a HTML string that you can write to a file in order to debug the UI structure and debug potential cascading issues
-#[no_mangle] pub extern "C" fn AzStyledDom_getHtmlString(styleddom: &AzStyledDom) -> AzString { styleddom.get_html_string("", "", false).into() }
+#[no_mangle] pub extern "C" fn AzStyledDom_getHtmlString(styleddom: &AzStyledDom) -> AzString { ffi_guard!(AzString, { styleddom.get_html_string("", "", false).into() }) }
 /// Returns a HTML for unit testing
-#[no_mangle] pub extern "C" fn AzStyledDom_getHtmlStringTest(styleddom: &AzStyledDom) -> AzString { styleddom.get_html_string("", "", true).into() }
+#[no_mangle] pub extern "C" fn AzStyledDom_getHtmlStringTest(styleddom: &AzStyledDom) -> AzString { ffi_guard!(AzString, { styleddom.get_html_string("", "", true).into() }) }
 /// Adds a menu to the root node
-#[no_mangle] pub extern "C" fn AzStyledDom_setMenuBar(styleddom: &mut AzStyledDom, menu: AzMenu) { styleddom.set_menu_bar(menu) }
+#[no_mangle] pub extern "C" fn AzStyledDom_setMenuBar(styleddom: &mut AzStyledDom, menu: AzMenu) { ffi_guard!((), { styleddom.set_menu_bar(menu) }) }
 /// Adds a menu to the root node (builder method)
-#[no_mangle] pub extern "C" fn AzStyledDom_withMenuBar(styleddom: &mut AzStyledDom, menu: AzMenu) -> AzStyledDom { let mut styleddom = styleddom.swap_with_default(); styleddom.set_menu_bar(menu); styleddom }
+#[no_mangle] pub extern "C" fn AzStyledDom_withMenuBar(styleddom: &mut AzStyledDom, menu: AzMenu) -> AzStyledDom { ffi_guard!(AzStyledDom, { let mut styleddom = styleddom.swap_with_default(); styleddom.set_menu_bar(menu); styleddom }) }
 /// Adds a context menu to the root node
-#[no_mangle] pub extern "C" fn AzStyledDom_setContextMenu(styleddom: &mut AzStyledDom, menu: AzMenu) { styleddom.set_context_menu(menu) }
+#[no_mangle] pub extern "C" fn AzStyledDom_setContextMenu(styleddom: &mut AzStyledDom, menu: AzMenu) { ffi_guard!((), { styleddom.set_context_menu(menu) }) }
 /// Adds a context menu to the root node (builder method)
-#[no_mangle] pub extern "C" fn AzStyledDom_withContextMenu(styleddom: &mut AzStyledDom, menu: AzMenu) -> AzStyledDom { let mut styleddom = styleddom.swap_with_default(); styleddom.set_context_menu(menu); styleddom }
+#[no_mangle] pub extern "C" fn AzStyledDom_withContextMenu(styleddom: &mut AzStyledDom, menu: AzMenu) -> AzStyledDom { ffi_guard!(AzStyledDom, { let mut styleddom = styleddom.swap_with_default(); styleddom.set_context_menu(menu); styleddom }) }
 /// Destructor: Takes ownership of the `StyledDom` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyledDom_delete(object: &mut AzStyledDom) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyledDom_delete(object: &mut AzStyledDom) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `Texture` struct
 pub use azul_impl::gl::Texture as AzTextureTT;
 pub use AzTextureTT as AzTexture;
 /// Creates a new `Texture` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Texture::new()` constructor.
-#[no_mangle] pub extern "C" fn AzTexture_new(texture_id: u32, flags: AzTextureFlags, size: AzPhysicalSizeU32, background_color: AzColorU, gl_context: AzGl, format: AzRawImageFormat) -> AzTexture { AzTexture::new(texture_id, flags, size, background_color, gl_context, format) }
+#[no_mangle] pub extern "C" fn AzTexture_new(texture_id: u32, flags: AzTextureFlags, size: AzPhysicalSizeU32, background_color: AzColorU, gl_context: AzGl, format: AzRawImageFormat) -> AzTexture { ffi_guard!(AzTexture, { AzTexture::new(texture_id, flags, size, background_color, gl_context, format) }) }
 /// Allocates an OpenGL texture of a given size with a single red channel (used for image masks)
-#[no_mangle] pub extern "C" fn AzTexture_allocateRgba8(gl: AzGl, size: AzPhysicalSizeU32, background: AzColorU) -> AzTexture { AzTexture::allocate_rgba8(gl, size, background) }
+#[no_mangle] pub extern "C" fn AzTexture_allocateRgba8(gl: AzGl, size: AzPhysicalSizeU32, background: AzColorU) -> AzTexture { ffi_guard!(AzTexture, { AzTexture::allocate_rgba8(gl, size, background) }) }
 /// Allocates an OpenGL texture of a given size with a single red channel (used for image masks)
-#[no_mangle] pub extern "C" fn AzTexture_allocateClipMask(gl: AzGl, size: AzPhysicalSizeU32, background: AzColorU) -> AzTexture { azul_impl::svg::allocate_clipmask_texture(gl, size) }
+#[no_mangle] pub extern "C" fn AzTexture_allocateClipMask(gl: AzGl, size: AzPhysicalSizeU32, background: AzColorU) -> AzTexture { ffi_guard!(AzTexture, { azul_impl::svg::allocate_clipmask_texture(gl, size) }) }
 /// Clears the texture with the currently set background color
-#[no_mangle] pub extern "C" fn AzTexture_clear(texture: &mut AzTexture) { texture.clear() }
+#[no_mangle] pub extern "C" fn AzTexture_clear(texture: &mut AzTexture) { ffi_guard!((), { texture.clear() }) }
 /// Draws a vertex / index buffer (aka. `&TessellatedSvgNode`) to the texture
-#[no_mangle] pub extern "C" fn AzTexture_drawClipMask(texture: &mut AzTexture, node: AzTessellatedSvgNode) -> bool { azul_impl::svg::render_tessellated_node_gpu(texture, &node).is_some() }
+#[no_mangle] pub extern "C" fn AzTexture_drawClipMask(texture: &mut AzTexture, node: AzTessellatedSvgNode) -> bool { ffi_guard!(bool, { azul_impl::svg::render_tessellated_node_gpu(texture, &node).is_some() }) }
 /// Draws a `&TessellatedGPUSvgNode` with the given color to the texture
-#[no_mangle] pub extern "C" fn AzTexture_drawTesselatedSvgGpuNode(texture: &mut AzTexture, node: *const AzTessellatedGPUSvgNode, size: AzPhysicalSizeU32, color: AzColorU, transforms: AzStyleTransformVec) -> bool { let node = unsafe { &*node }; node.draw(texture, size, color, transforms) }
+///
+/// # Safety
+///
+/// `node` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTexture_drawTesselatedSvgGpuNode(texture: &mut AzTexture, node: *const AzTessellatedGPUSvgNode, size: AzPhysicalSizeU32, color: AzColorU, transforms: AzStyleTransformVec) -> bool { ffi_guard!(bool, { let node = unsafe { &*node }; node.draw(texture, size, color, transforms) }) }
 /// Draws a `&TessellatedColoredGPUSvgNode` with the given color to the texture
-#[no_mangle] pub extern "C" fn AzTexture_drawTesselatedColoredSvgGpuNode(texture: &mut AzTexture, node: *const AzTessellatedColoredGPUSvgNode, size: AzPhysicalSizeU32, transforms: AzStyleTransformVec) -> bool { let node = unsafe { &*node }; node.draw(texture, size, transforms) }
+///
+/// # Safety
+///
+/// `node` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTexture_drawTesselatedColoredSvgGpuNode(texture: &mut AzTexture, node: *const AzTessellatedColoredGPUSvgNode, size: AzPhysicalSizeU32, transforms: AzStyleTransformVec) -> bool { ffi_guard!(bool, { let node = unsafe { &*node }; node.draw(texture, size, transforms) }) }
 /// Applies an FXAA filter to the texture
-#[no_mangle] pub extern "C" fn AzTexture_applyFxaa(texture: &mut AzTexture) -> bool { azul_impl::svg::apply_fxaa(texture).is_some() }
+#[no_mangle] pub extern "C" fn AzTexture_applyFxaa(texture: &mut AzTexture) -> bool { ffi_guard!(bool, { azul_impl::svg::apply_fxaa(texture).is_some() }) }
 /// Destructor: Takes ownership of the `Texture` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTexture_delete(object: &mut AzTexture) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzTexture_delete(object: &mut AzTexture) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzTexture_deepCopy(object: &AzTexture) -> AzTexture { object.clone() }
 
@@ -2691,7 +3359,7 @@ pub use AzTextureTT as AzTexture;
 pub use azul_impl::gl::GlVoidPtrConst as AzGlVoidPtrConstTT;
 pub use AzGlVoidPtrConstTT as AzGlVoidPtrConst;
 /// Destructor: Takes ownership of the `GlVoidPtrConst` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzGlVoidPtrConst_delete(object: &mut AzGlVoidPtrConst) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzGlVoidPtrConst_delete(object: &mut AzGlVoidPtrConst) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzGlVoidPtrConst_deepCopy(object: &AzGlVoidPtrConst) -> AzGlVoidPtrConst { object.clone() }
 
@@ -2703,449 +3371,449 @@ pub use AzGlVoidPtrMutTT as AzGlVoidPtrMut;
 pub use azul_impl::gl::GlContextPtr as AzGlTT;
 pub use AzGlTT as AzGl;
 /// Equivalent to the Rust `Gl::get_type()` function.
-#[no_mangle] pub extern "C" fn AzGl_getType(gl: &AzGl) -> AzGlType { gl.get_type() }
+#[no_mangle] pub extern "C" fn AzGl_getType(gl: &AzGl) -> AzGlType { ffi_guard!(AzGlType, { gl.get_type() }) }
 /// Equivalent to the Rust `Gl::buffer_data_untyped()` function.
-#[no_mangle] pub extern "C" fn AzGl_bufferDataUntyped(gl: &AzGl, target: u32, size: isize, data: AzGlVoidPtrConst, usage: u32) { gl.buffer_data_untyped(target, size, data, usage) }
+#[no_mangle] pub extern "C" fn AzGl_bufferDataUntyped(gl: &AzGl, target: u32, size: isize, data: AzGlVoidPtrConst, usage: u32) { ffi_guard!((), { gl.buffer_data_untyped(target, size, data, usage) }) }
 /// Equivalent to the Rust `Gl::buffer_sub_data_untyped()` function.
-#[no_mangle] pub extern "C" fn AzGl_bufferSubDataUntyped(gl: &AzGl, target: u32, offset: isize, size: isize, data: AzGlVoidPtrConst) { gl.buffer_sub_data_untyped(target, offset, size, data) }
+#[no_mangle] pub extern "C" fn AzGl_bufferSubDataUntyped(gl: &AzGl, target: u32, offset: isize, size: isize, data: AzGlVoidPtrConst) { ffi_guard!((), { gl.buffer_sub_data_untyped(target, offset, size, data) }) }
 /// Equivalent to the Rust `Gl::map_buffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_mapBuffer(gl: &AzGl, target: u32, access: u32) -> AzGlVoidPtrMut { gl.map_buffer(target, access) }
+#[no_mangle] pub extern "C" fn AzGl_mapBuffer(gl: &AzGl, target: u32, access: u32) -> AzGlVoidPtrMut { ffi_guard!(AzGlVoidPtrMut, { gl.map_buffer(target, access) }) }
 /// Equivalent to the Rust `Gl::map_buffer_range()` function.
-#[no_mangle] pub extern "C" fn AzGl_mapBufferRange(gl: &AzGl, target: u32, offset: isize, length: isize, access: u32) -> AzGlVoidPtrMut { gl.map_buffer_range(target, offset, length, access) }
+#[no_mangle] pub extern "C" fn AzGl_mapBufferRange(gl: &AzGl, target: u32, offset: isize, length: isize, access: u32) -> AzGlVoidPtrMut { ffi_guard!(AzGlVoidPtrMut, { gl.map_buffer_range(target, offset, length, access) }) }
 /// Equivalent to the Rust `Gl::unmap_buffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_unmapBuffer(gl: &AzGl, target: u32) -> u8 { gl.unmap_buffer(target) }
+#[no_mangle] pub extern "C" fn AzGl_unmapBuffer(gl: &AzGl, target: u32) -> u8 { ffi_guard!(u8, { gl.unmap_buffer(target) }) }
 /// Equivalent to the Rust `Gl::tex_buffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_texBuffer(gl: &AzGl, target: u32, internal_format: u32, buffer: u32) { gl.tex_buffer(target, internal_format, buffer) }
+#[no_mangle] pub extern "C" fn AzGl_texBuffer(gl: &AzGl, target: u32, internal_format: u32, buffer: u32) { ffi_guard!((), { gl.tex_buffer(target, internal_format, buffer) }) }
 /// Equivalent to the Rust `Gl::shader_source()` function.
-#[no_mangle] pub extern "C" fn AzGl_shaderSource(gl: &AzGl, shader: u32, strings: AzStringVec) { gl.shader_source(shader, strings) }
+#[no_mangle] pub extern "C" fn AzGl_shaderSource(gl: &AzGl, shader: u32, strings: AzStringVec) { ffi_guard!((), { gl.shader_source(shader, strings) }) }
 /// Equivalent to the Rust `Gl::read_buffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_readBuffer(gl: &AzGl, mode: u32) { gl.read_buffer(mode) }
+#[no_mangle] pub extern "C" fn AzGl_readBuffer(gl: &AzGl, mode: u32) { ffi_guard!((), { gl.read_buffer(mode) }) }
 /// Equivalent to the Rust `Gl::read_pixels_into_buffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_readPixelsIntoBuffer(gl: &AzGl, x: i32, y: i32, width: i32, height: i32, format: u32, pixel_type: u32, dst_buffer: AzU8VecRefMut) { gl.read_pixels_into_buffer(x, y, width, height, format, pixel_type, dst_buffer) }
+#[no_mangle] pub extern "C" fn AzGl_readPixelsIntoBuffer(gl: &AzGl, x: i32, y: i32, width: i32, height: i32, format: u32, pixel_type: u32, dst_buffer: AzU8VecRefMut) { ffi_guard!((), { gl.read_pixels_into_buffer(x, y, width, height, format, pixel_type, dst_buffer) }) }
 /// Equivalent to the Rust `Gl::read_pixels()` function.
-#[no_mangle] pub extern "C" fn AzGl_readPixels(gl: &AzGl, x: i32, y: i32, width: i32, height: i32, format: u32, pixel_type: u32) -> AzU8Vec { gl.read_pixels(x, y, width, height, format, pixel_type) }
+#[no_mangle] pub extern "C" fn AzGl_readPixels(gl: &AzGl, x: i32, y: i32, width: i32, height: i32, format: u32, pixel_type: u32) -> AzU8Vec { ffi_guard!(AzU8Vec, { gl.read_pixels(x, y, width, height, format, pixel_type) }) }
 /// Equivalent to the Rust `Gl::read_pixels_into_pbo()` function.
-#[no_mangle] pub extern "C" fn AzGl_readPixelsIntoPbo(gl: &AzGl, x: i32, y: i32, width: i32, height: i32, format: u32, pixel_type: u32) { gl.read_pixels_into_pbo(x, y, width, height, format, pixel_type) }
+#[no_mangle] pub extern "C" fn AzGl_readPixelsIntoPbo(gl: &AzGl, x: i32, y: i32, width: i32, height: i32, format: u32, pixel_type: u32) { ffi_guard!((), { gl.read_pixels_into_pbo(x, y, width, height, format, pixel_type) }) }
 /// Equivalent to the Rust `Gl::sample_coverage()` function.
-#[no_mangle] pub extern "C" fn AzGl_sampleCoverage(gl: &AzGl, value: f32, invert: bool) { gl.sample_coverage(value, invert) }
+#[no_mangle] pub extern "C" fn AzGl_sampleCoverage(gl: &AzGl, value: f32, invert: bool) { ffi_guard!((), { gl.sample_coverage(value, invert) }) }
 /// Equivalent to the Rust `Gl::polygon_offset()` function.
-#[no_mangle] pub extern "C" fn AzGl_polygonOffset(gl: &AzGl, factor: f32, units: f32) { gl.polygon_offset(factor, units) }
+#[no_mangle] pub extern "C" fn AzGl_polygonOffset(gl: &AzGl, factor: f32, units: f32) { ffi_guard!((), { gl.polygon_offset(factor, units) }) }
 /// Equivalent to the Rust `Gl::pixel_store_i()` function.
-#[no_mangle] pub extern "C" fn AzGl_pixelStoreI(gl: &AzGl, name: u32, param: i32) { gl.pixel_store_i(name, param) }
+#[no_mangle] pub extern "C" fn AzGl_pixelStoreI(gl: &AzGl, name: u32, param: i32) { ffi_guard!((), { gl.pixel_store_i(name, param) }) }
 /// Equivalent to the Rust `Gl::gen_buffers()` function.
-#[no_mangle] pub extern "C" fn AzGl_genBuffers(gl: &AzGl, n: i32) -> AzGLuintVec { gl.gen_buffers(n) }
+#[no_mangle] pub extern "C" fn AzGl_genBuffers(gl: &AzGl, n: i32) -> AzGLuintVec { ffi_guard!(AzGLuintVec, { gl.gen_buffers(n) }) }
 /// Equivalent to the Rust `Gl::gen_renderbuffers()` function.
-#[no_mangle] pub extern "C" fn AzGl_genRenderbuffers(gl: &AzGl, n: i32) -> AzGLuintVec { gl.gen_renderbuffers(n) }
+#[no_mangle] pub extern "C" fn AzGl_genRenderbuffers(gl: &AzGl, n: i32) -> AzGLuintVec { ffi_guard!(AzGLuintVec, { gl.gen_renderbuffers(n) }) }
 /// Equivalent to the Rust `Gl::gen_framebuffers()` function.
-#[no_mangle] pub extern "C" fn AzGl_genFramebuffers(gl: &AzGl, n: i32) -> AzGLuintVec { gl.gen_framebuffers(n) }
+#[no_mangle] pub extern "C" fn AzGl_genFramebuffers(gl: &AzGl, n: i32) -> AzGLuintVec { ffi_guard!(AzGLuintVec, { gl.gen_framebuffers(n) }) }
 /// Equivalent to the Rust `Gl::gen_textures()` function.
-#[no_mangle] pub extern "C" fn AzGl_genTextures(gl: &AzGl, n: i32) -> AzGLuintVec { gl.gen_textures(n) }
+#[no_mangle] pub extern "C" fn AzGl_genTextures(gl: &AzGl, n: i32) -> AzGLuintVec { ffi_guard!(AzGLuintVec, { gl.gen_textures(n) }) }
 /// Equivalent to the Rust `Gl::gen_vertex_arrays()` function.
-#[no_mangle] pub extern "C" fn AzGl_genVertexArrays(gl: &AzGl, n: i32) -> AzGLuintVec { gl.gen_vertex_arrays(n) }
+#[no_mangle] pub extern "C" fn AzGl_genVertexArrays(gl: &AzGl, n: i32) -> AzGLuintVec { ffi_guard!(AzGLuintVec, { gl.gen_vertex_arrays(n) }) }
 /// Equivalent to the Rust `Gl::gen_queries()` function.
-#[no_mangle] pub extern "C" fn AzGl_genQueries(gl: &AzGl, n: i32) -> AzGLuintVec { gl.gen_queries(n) }
+#[no_mangle] pub extern "C" fn AzGl_genQueries(gl: &AzGl, n: i32) -> AzGLuintVec { ffi_guard!(AzGLuintVec, { gl.gen_queries(n) }) }
 /// Equivalent to the Rust `Gl::begin_query()` function.
-#[no_mangle] pub extern "C" fn AzGl_beginQuery(gl: &AzGl, target: u32, id: u32) { gl.begin_query(target, id) }
+#[no_mangle] pub extern "C" fn AzGl_beginQuery(gl: &AzGl, target: u32, id: u32) { ffi_guard!((), { gl.begin_query(target, id) }) }
 /// Equivalent to the Rust `Gl::end_query()` function.
-#[no_mangle] pub extern "C" fn AzGl_endQuery(gl: &AzGl, target: u32) { gl.end_query(target) }
+#[no_mangle] pub extern "C" fn AzGl_endQuery(gl: &AzGl, target: u32) { ffi_guard!((), { gl.end_query(target) }) }
 /// Equivalent to the Rust `Gl::query_counter()` function.
-#[no_mangle] pub extern "C" fn AzGl_queryCounter(gl: &AzGl, id: u32, target: u32) { gl.query_counter(id, target) }
+#[no_mangle] pub extern "C" fn AzGl_queryCounter(gl: &AzGl, id: u32, target: u32) { ffi_guard!((), { gl.query_counter(id, target) }) }
 /// Equivalent to the Rust `Gl::get_query_object_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getQueryObjectIv(gl: &AzGl, id: u32, pname: u32) -> i32 { gl.get_query_object_iv(id, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getQueryObjectIv(gl: &AzGl, id: u32, pname: u32) -> i32 { ffi_guard!(i32, { gl.get_query_object_iv(id, pname) }) }
 /// Equivalent to the Rust `Gl::get_query_object_uiv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getQueryObjectUiv(gl: &AzGl, id: u32, pname: u32) -> u32 { gl.get_query_object_uiv(id, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getQueryObjectUiv(gl: &AzGl, id: u32, pname: u32) -> u32 { ffi_guard!(u32, { gl.get_query_object_uiv(id, pname) }) }
 /// Equivalent to the Rust `Gl::get_query_object_i64v()` function.
-#[no_mangle] pub extern "C" fn AzGl_getQueryObjectI64V(gl: &AzGl, id: u32, pname: u32) -> i64 { gl.get_query_object_i64v(id, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getQueryObjectI64V(gl: &AzGl, id: u32, pname: u32) -> i64 { ffi_guard!(i64, { gl.get_query_object_i64v(id, pname) }) }
 /// Equivalent to the Rust `Gl::get_query_object_ui64v()` function.
-#[no_mangle] pub extern "C" fn AzGl_getQueryObjectUi64V(gl: &AzGl, id: u32, pname: u32) -> u64 { gl.get_query_object_ui64v(id, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getQueryObjectUi64V(gl: &AzGl, id: u32, pname: u32) -> u64 { ffi_guard!(u64, { gl.get_query_object_ui64v(id, pname) }) }
 /// Equivalent to the Rust `Gl::delete_queries()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteQueries(gl: &AzGl, queries: AzGLuintVecRef) { gl.delete_queries(queries) }
+#[no_mangle] pub extern "C" fn AzGl_deleteQueries(gl: &AzGl, queries: AzGLuintVecRef) { ffi_guard!((), { gl.delete_queries(queries) }) }
 /// Equivalent to the Rust `Gl::delete_vertex_arrays()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteVertexArrays(gl: &AzGl, vertex_arrays: AzGLuintVecRef) { gl.delete_vertex_arrays(vertex_arrays) }
+#[no_mangle] pub extern "C" fn AzGl_deleteVertexArrays(gl: &AzGl, vertex_arrays: AzGLuintVecRef) { ffi_guard!((), { gl.delete_vertex_arrays(vertex_arrays) }) }
 /// Equivalent to the Rust `Gl::delete_buffers()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteBuffers(gl: &AzGl, buffers: AzGLuintVecRef) { gl.delete_buffers(buffers) }
+#[no_mangle] pub extern "C" fn AzGl_deleteBuffers(gl: &AzGl, buffers: AzGLuintVecRef) { ffi_guard!((), { gl.delete_buffers(buffers) }) }
 /// Equivalent to the Rust `Gl::delete_renderbuffers()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteRenderbuffers(gl: &AzGl, renderbuffers: AzGLuintVecRef) { gl.delete_renderbuffers(renderbuffers) }
+#[no_mangle] pub extern "C" fn AzGl_deleteRenderbuffers(gl: &AzGl, renderbuffers: AzGLuintVecRef) { ffi_guard!((), { gl.delete_renderbuffers(renderbuffers) }) }
 /// Equivalent to the Rust `Gl::delete_framebuffers()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteFramebuffers(gl: &AzGl, framebuffers: AzGLuintVecRef) { gl.delete_framebuffers(framebuffers) }
+#[no_mangle] pub extern "C" fn AzGl_deleteFramebuffers(gl: &AzGl, framebuffers: AzGLuintVecRef) { ffi_guard!((), { gl.delete_framebuffers(framebuffers) }) }
 /// Equivalent to the Rust `Gl::delete_textures()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteTextures(gl: &AzGl, textures: AzGLuintVecRef) { gl.delete_textures(textures) }
+#[no_mangle] pub extern "C" fn AzGl_deleteTextures(gl: &AzGl, textures: AzGLuintVecRef) { ffi_guard!((), { gl.delete_textures(textures) }) }
 /// Equivalent to the Rust `Gl::framebuffer_renderbuffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_framebufferRenderbuffer(gl: &AzGl, target: u32, attachment: u32, renderbuffertarget: u32, renderbuffer: u32) { gl.framebuffer_renderbuffer(target, attachment, renderbuffertarget, renderbuffer) }
+#[no_mangle] pub extern "C" fn AzGl_framebufferRenderbuffer(gl: &AzGl, target: u32, attachment: u32, renderbuffertarget: u32, renderbuffer: u32) { ffi_guard!((), { gl.framebuffer_renderbuffer(target, attachment, renderbuffertarget, renderbuffer) }) }
 /// Equivalent to the Rust `Gl::renderbuffer_storage()` function.
-#[no_mangle] pub extern "C" fn AzGl_renderbufferStorage(gl: &AzGl, target: u32, internalformat: u32, width: i32, height: i32) { gl.renderbuffer_storage(target, internalformat, width, height) }
+#[no_mangle] pub extern "C" fn AzGl_renderbufferStorage(gl: &AzGl, target: u32, internalformat: u32, width: i32, height: i32) { ffi_guard!((), { gl.renderbuffer_storage(target, internalformat, width, height) }) }
 /// Equivalent to the Rust `Gl::depth_func()` function.
-#[no_mangle] pub extern "C" fn AzGl_depthFunc(gl: &AzGl, func: u32) { gl.depth_func(func) }
+#[no_mangle] pub extern "C" fn AzGl_depthFunc(gl: &AzGl, func: u32) { ffi_guard!((), { gl.depth_func(func) }) }
 /// Equivalent to the Rust `Gl::active_texture()` function.
-#[no_mangle] pub extern "C" fn AzGl_activeTexture(gl: &AzGl, texture: u32) { gl.active_texture(texture) }
+#[no_mangle] pub extern "C" fn AzGl_activeTexture(gl: &AzGl, texture: u32) { ffi_guard!((), { gl.active_texture(texture) }) }
 /// Equivalent to the Rust `Gl::attach_shader()` function.
-#[no_mangle] pub extern "C" fn AzGl_attachShader(gl: &AzGl, program: u32, shader: u32) { gl.attach_shader(program, shader) }
+#[no_mangle] pub extern "C" fn AzGl_attachShader(gl: &AzGl, program: u32, shader: u32) { ffi_guard!((), { gl.attach_shader(program, shader) }) }
 /// Equivalent to the Rust `Gl::bind_attrib_location()` function.
-#[no_mangle] pub extern "C" fn AzGl_bindAttribLocation(gl: &AzGl, program: u32, index: u32, name: AzRefstr) { gl.bind_attrib_location(program, index, name) }
+#[no_mangle] pub extern "C" fn AzGl_bindAttribLocation(gl: &AzGl, program: u32, index: u32, name: AzRefstr) { ffi_guard!((), { gl.bind_attrib_location(program, index, name) }) }
 /// Equivalent to the Rust `Gl::get_uniform_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getUniformIv(gl: &AzGl, program: u32, location: i32, result: AzGLintVecRefMut) { gl.get_uniform_iv(program, location, result) }
+#[no_mangle] pub extern "C" fn AzGl_getUniformIv(gl: &AzGl, program: u32, location: i32, result: AzGLintVecRefMut) { ffi_guard!((), { gl.get_uniform_iv(program, location, result) }) }
 /// Equivalent to the Rust `Gl::get_uniform_fv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getUniformFv(gl: &AzGl, program: u32, location: i32, result: AzGLfloatVecRefMut) { gl.get_uniform_fv(program, location, result) }
+#[no_mangle] pub extern "C" fn AzGl_getUniformFv(gl: &AzGl, program: u32, location: i32, result: AzGLfloatVecRefMut) { ffi_guard!((), { gl.get_uniform_fv(program, location, result) }) }
 /// Equivalent to the Rust `Gl::get_uniform_block_index()` function.
-#[no_mangle] pub extern "C" fn AzGl_getUniformBlockIndex(gl: &AzGl, program: u32, name: AzRefstr) -> u32 { gl.get_uniform_block_index(program, name) }
+#[no_mangle] pub extern "C" fn AzGl_getUniformBlockIndex(gl: &AzGl, program: u32, name: AzRefstr) -> u32 { ffi_guard!(u32, { gl.get_uniform_block_index(program, name) }) }
 /// Equivalent to the Rust `Gl::get_uniform_indices()` function.
-#[no_mangle] pub extern "C" fn AzGl_getUniformIndices(gl: &AzGl, program: u32, names: AzRefstrVecRef) -> AzGLuintVec { gl.get_uniform_indices(program, names) }
+#[no_mangle] pub extern "C" fn AzGl_getUniformIndices(gl: &AzGl, program: u32, names: AzRefstrVecRef) -> AzGLuintVec { ffi_guard!(AzGLuintVec, { gl.get_uniform_indices(program, names) }) }
 /// Equivalent to the Rust `Gl::bind_buffer_base()` function.
-#[no_mangle] pub extern "C" fn AzGl_bindBufferBase(gl: &AzGl, target: u32, index: u32, buffer: u32) { gl.bind_buffer_base(target, index, buffer) }
+#[no_mangle] pub extern "C" fn AzGl_bindBufferBase(gl: &AzGl, target: u32, index: u32, buffer: u32) { ffi_guard!((), { gl.bind_buffer_base(target, index, buffer) }) }
 /// Equivalent to the Rust `Gl::bind_buffer_range()` function.
-#[no_mangle] pub extern "C" fn AzGl_bindBufferRange(gl: &AzGl, target: u32, index: u32, buffer: u32, offset: isize, size: isize) { gl.bind_buffer_range(target, index, buffer, offset, size) }
+#[no_mangle] pub extern "C" fn AzGl_bindBufferRange(gl: &AzGl, target: u32, index: u32, buffer: u32, offset: isize, size: isize) { ffi_guard!((), { gl.bind_buffer_range(target, index, buffer, offset, size) }) }
 /// Equivalent to the Rust `Gl::uniform_block_binding()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniformBlockBinding(gl: &AzGl, program: u32, uniform_block_index: u32, uniform_block_binding: u32) { gl.uniform_block_binding(program, uniform_block_index, uniform_block_binding) }
+#[no_mangle] pub extern "C" fn AzGl_uniformBlockBinding(gl: &AzGl, program: u32, uniform_block_index: u32, uniform_block_binding: u32) { ffi_guard!((), { gl.uniform_block_binding(program, uniform_block_index, uniform_block_binding) }) }
 /// Equivalent to the Rust `Gl::bind_buffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_bindBuffer(gl: &AzGl, target: u32, buffer: u32) { gl.bind_buffer(target, buffer) }
+#[no_mangle] pub extern "C" fn AzGl_bindBuffer(gl: &AzGl, target: u32, buffer: u32) { ffi_guard!((), { gl.bind_buffer(target, buffer) }) }
 /// Equivalent to the Rust `Gl::bind_vertex_array()` function.
-#[no_mangle] pub extern "C" fn AzGl_bindVertexArray(gl: &AzGl, vao: u32) { gl.bind_vertex_array(vao) }
+#[no_mangle] pub extern "C" fn AzGl_bindVertexArray(gl: &AzGl, vao: u32) { ffi_guard!((), { gl.bind_vertex_array(vao) }) }
 /// Equivalent to the Rust `Gl::bind_renderbuffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_bindRenderbuffer(gl: &AzGl, target: u32, renderbuffer: u32) { gl.bind_renderbuffer(target, renderbuffer) }
+#[no_mangle] pub extern "C" fn AzGl_bindRenderbuffer(gl: &AzGl, target: u32, renderbuffer: u32) { ffi_guard!((), { gl.bind_renderbuffer(target, renderbuffer) }) }
 /// Equivalent to the Rust `Gl::bind_framebuffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_bindFramebuffer(gl: &AzGl, target: u32, framebuffer: u32) { gl.bind_framebuffer(target, framebuffer) }
+#[no_mangle] pub extern "C" fn AzGl_bindFramebuffer(gl: &AzGl, target: u32, framebuffer: u32) { ffi_guard!((), { gl.bind_framebuffer(target, framebuffer) }) }
 /// Equivalent to the Rust `Gl::bind_texture()` function.
-#[no_mangle] pub extern "C" fn AzGl_bindTexture(gl: &AzGl, target: u32, texture: u32) { gl.bind_texture(target, texture) }
+#[no_mangle] pub extern "C" fn AzGl_bindTexture(gl: &AzGl, target: u32, texture: u32) { ffi_guard!((), { gl.bind_texture(target, texture) }) }
 /// Equivalent to the Rust `Gl::draw_buffers()` function.
-#[no_mangle] pub extern "C" fn AzGl_drawBuffers(gl: &AzGl, bufs: AzGLenumVecRef) { gl.draw_buffers(bufs) }
+#[no_mangle] pub extern "C" fn AzGl_drawBuffers(gl: &AzGl, bufs: AzGLenumVecRef) { ffi_guard!((), { gl.draw_buffers(bufs) }) }
 /// Equivalent to the Rust `Gl::tex_image_2d()` function.
-#[no_mangle] pub extern "C" fn AzGl_texImage2D(gl: &AzGl, target: u32, level: i32, internal_format: i32, width: i32, height: i32, border: i32, format: u32, ty: u32, opt_data: AzOptionU8VecRef) { gl.tex_image_2d(target, level, internal_format, width, height, border, format, ty, opt_data) }
+#[no_mangle] pub extern "C" fn AzGl_texImage2D(gl: &AzGl, target: u32, level: i32, internal_format: i32, width: i32, height: i32, border: i32, format: u32, ty: u32, opt_data: AzOptionU8VecRef) { ffi_guard!((), { gl.tex_image_2d(target, level, internal_format, width, height, border, format, ty, opt_data) }) }
 /// Equivalent to the Rust `Gl::compressed_tex_image_2d()` function.
-#[no_mangle] pub extern "C" fn AzGl_compressedTexImage2D(gl: &AzGl, target: u32, level: i32, internal_format: u32, width: i32, height: i32, border: i32, data: AzU8VecRef) { gl.compressed_tex_image_2d(target, level, internal_format, width, height, border, data) }
+#[no_mangle] pub extern "C" fn AzGl_compressedTexImage2D(gl: &AzGl, target: u32, level: i32, internal_format: u32, width: i32, height: i32, border: i32, data: AzU8VecRef) { ffi_guard!((), { gl.compressed_tex_image_2d(target, level, internal_format, width, height, border, data) }) }
 /// Equivalent to the Rust `Gl::compressed_tex_sub_image_2d()` function.
-#[no_mangle] pub extern "C" fn AzGl_compressedTexSubImage2D(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, width: i32, height: i32, format: u32, data: AzU8VecRef) { gl.compressed_tex_sub_image_2d(target, level, xoffset, yoffset, width, height, format, data) }
+#[no_mangle] pub extern "C" fn AzGl_compressedTexSubImage2D(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, width: i32, height: i32, format: u32, data: AzU8VecRef) { ffi_guard!((), { gl.compressed_tex_sub_image_2d(target, level, xoffset, yoffset, width, height, format, data) }) }
 /// Equivalent to the Rust `Gl::tex_image_3d()` function.
-#[no_mangle] pub extern "C" fn AzGl_texImage3D(gl: &AzGl, target: u32, level: i32, internal_format: i32, width: i32, height: i32, depth: i32, border: i32, format: u32, ty: u32, opt_data: AzOptionU8VecRef) { gl.tex_image_3d(target, level, internal_format, width, height, depth, border, format, ty, opt_data) }
+#[no_mangle] pub extern "C" fn AzGl_texImage3D(gl: &AzGl, target: u32, level: i32, internal_format: i32, width: i32, height: i32, depth: i32, border: i32, format: u32, ty: u32, opt_data: AzOptionU8VecRef) { ffi_guard!((), { gl.tex_image_3d(target, level, internal_format, width, height, depth, border, format, ty, opt_data) }) }
 /// Equivalent to the Rust `Gl::copy_tex_image_2d()` function.
-#[no_mangle] pub extern "C" fn AzGl_copyTexImage2D(gl: &AzGl, target: u32, level: i32, internal_format: u32, x: i32, y: i32, width: i32, height: i32, border: i32) { gl.copy_tex_image_2d(target, level, internal_format, x, y, width, height, border) }
+#[no_mangle] pub extern "C" fn AzGl_copyTexImage2D(gl: &AzGl, target: u32, level: i32, internal_format: u32, x: i32, y: i32, width: i32, height: i32, border: i32) { ffi_guard!((), { gl.copy_tex_image_2d(target, level, internal_format, x, y, width, height, border) }) }
 /// Equivalent to the Rust `Gl::copy_tex_sub_image_2d()` function.
-#[no_mangle] pub extern "C" fn AzGl_copyTexSubImage2D(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, x: i32, y: i32, width: i32, height: i32) { gl.copy_tex_sub_image_2d(target, level, xoffset, yoffset, x, y, width, height) }
+#[no_mangle] pub extern "C" fn AzGl_copyTexSubImage2D(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, x: i32, y: i32, width: i32, height: i32) { ffi_guard!((), { gl.copy_tex_sub_image_2d(target, level, xoffset, yoffset, x, y, width, height) }) }
 /// Equivalent to the Rust `Gl::copy_tex_sub_image_3d()` function.
-#[no_mangle] pub extern "C" fn AzGl_copyTexSubImage3D(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, x: i32, y: i32, width: i32, height: i32) { gl.copy_tex_sub_image_3d(target, level, xoffset, yoffset, zoffset, x, y, width, height) }
+#[no_mangle] pub extern "C" fn AzGl_copyTexSubImage3D(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, x: i32, y: i32, width: i32, height: i32) { ffi_guard!((), { gl.copy_tex_sub_image_3d(target, level, xoffset, yoffset, zoffset, x, y, width, height) }) }
 /// Equivalent to the Rust `Gl::tex_sub_image_2d()` function.
-#[no_mangle] pub extern "C" fn AzGl_texSubImage2D(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, width: i32, height: i32, format: u32, ty: u32, data: AzU8VecRef) { gl.tex_sub_image_2d(target, level, xoffset, yoffset, width, height, format, ty, data) }
+#[no_mangle] pub extern "C" fn AzGl_texSubImage2D(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, width: i32, height: i32, format: u32, ty: u32, data: AzU8VecRef) { ffi_guard!((), { gl.tex_sub_image_2d(target, level, xoffset, yoffset, width, height, format, ty, data) }) }
 /// Equivalent to the Rust `Gl::tex_sub_image_2d_pbo()` function.
-#[no_mangle] pub extern "C" fn AzGl_texSubImage2DPbo(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, width: i32, height: i32, format: u32, ty: u32, offset: usize) { gl.tex_sub_image_2d_pbo(target, level, xoffset, yoffset, width, height, format, ty, offset) }
+#[no_mangle] pub extern "C" fn AzGl_texSubImage2DPbo(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, width: i32, height: i32, format: u32, ty: u32, offset: usize) { ffi_guard!((), { gl.tex_sub_image_2d_pbo(target, level, xoffset, yoffset, width, height, format, ty, offset) }) }
 /// Equivalent to the Rust `Gl::tex_sub_image_3d()` function.
-#[no_mangle] pub extern "C" fn AzGl_texSubImage3D(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: i32, height: i32, depth: i32, format: u32, ty: u32, data: AzU8VecRef) { gl.tex_sub_image_3d(target, level, xoffset, yoffset, zoffset, width, height, depth, format, ty, data) }
+#[no_mangle] pub extern "C" fn AzGl_texSubImage3D(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: i32, height: i32, depth: i32, format: u32, ty: u32, data: AzU8VecRef) { ffi_guard!((), { gl.tex_sub_image_3d(target, level, xoffset, yoffset, zoffset, width, height, depth, format, ty, data) }) }
 /// Equivalent to the Rust `Gl::tex_sub_image_3d_pbo()` function.
-#[no_mangle] pub extern "C" fn AzGl_texSubImage3DPbo(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: i32, height: i32, depth: i32, format: u32, ty: u32, offset: usize) { gl.tex_sub_image_3d_pbo(target, level, xoffset, yoffset, zoffset, width, height, depth, format, ty, offset) }
+#[no_mangle] pub extern "C" fn AzGl_texSubImage3DPbo(gl: &AzGl, target: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: i32, height: i32, depth: i32, format: u32, ty: u32, offset: usize) { ffi_guard!((), { gl.tex_sub_image_3d_pbo(target, level, xoffset, yoffset, zoffset, width, height, depth, format, ty, offset) }) }
 /// Equivalent to the Rust `Gl::tex_storage_2d()` function.
-#[no_mangle] pub extern "C" fn AzGl_texStorage2D(gl: &AzGl, target: u32, levels: i32, internal_format: u32, width: i32, height: i32) { gl.tex_storage_2d(target, levels, internal_format, width, height) }
+#[no_mangle] pub extern "C" fn AzGl_texStorage2D(gl: &AzGl, target: u32, levels: i32, internal_format: u32, width: i32, height: i32) { ffi_guard!((), { gl.tex_storage_2d(target, levels, internal_format, width, height) }) }
 /// Equivalent to the Rust `Gl::tex_storage_3d()` function.
-#[no_mangle] pub extern "C" fn AzGl_texStorage3D(gl: &AzGl, target: u32, levels: i32, internal_format: u32, width: i32, height: i32, depth: i32) { gl.tex_storage_3d(target, levels, internal_format, width, height, depth) }
+#[no_mangle] pub extern "C" fn AzGl_texStorage3D(gl: &AzGl, target: u32, levels: i32, internal_format: u32, width: i32, height: i32, depth: i32) { ffi_guard!((), { gl.tex_storage_3d(target, levels, internal_format, width, height, depth) }) }
 /// Equivalent to the Rust `Gl::get_tex_image_into_buffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_getTexImageIntoBuffer(gl: &AzGl, target: u32, level: i32, format: u32, ty: u32, output: AzU8VecRefMut) { gl.get_tex_image_into_buffer(target, level, format, ty, output) }
+#[no_mangle] pub extern "C" fn AzGl_getTexImageIntoBuffer(gl: &AzGl, target: u32, level: i32, format: u32, ty: u32, output: AzU8VecRefMut) { ffi_guard!((), { gl.get_tex_image_into_buffer(target, level, format, ty, output) }) }
 /// Equivalent to the Rust `Gl::copy_image_sub_data()` function.
-#[no_mangle] pub extern "C" fn AzGl_copyImageSubData(gl: &AzGl, src_name: u32, src_target: u32, src_level: i32, src_x: i32, src_y: i32, src_z: i32, dst_name: u32, dst_target: u32, dst_level: i32, dst_x: i32, dst_y: i32, dst_z: i32, src_width: i32, src_height: i32, src_depth: i32) { gl.copy_image_sub_data(src_name, src_target, src_level, src_x, src_y, src_z, dst_name, dst_target, dst_level, dst_x, dst_y, dst_z, src_width, src_height, src_depth) }
+#[no_mangle] pub extern "C" fn AzGl_copyImageSubData(gl: &AzGl, src_name: u32, src_target: u32, src_level: i32, src_x: i32, src_y: i32, src_z: i32, dst_name: u32, dst_target: u32, dst_level: i32, dst_x: i32, dst_y: i32, dst_z: i32, src_width: i32, src_height: i32, src_depth: i32) { ffi_guard!((), { gl.copy_image_sub_data(src_name, src_target, src_level, src_x, src_y, src_z, dst_name, dst_target, dst_level, dst_x, dst_y, dst_z, src_width, src_height, src_depth) }) }
 /// Equivalent to the Rust `Gl::invalidate_framebuffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_invalidateFramebuffer(gl: &AzGl, target: u32, attachments: AzGLenumVecRef) { gl.invalidate_framebuffer(target, attachments) }
+#[no_mangle] pub extern "C" fn AzGl_invalidateFramebuffer(gl: &AzGl, target: u32, attachments: AzGLenumVecRef) { ffi_guard!((), { gl.invalidate_framebuffer(target, attachments) }) }
 /// Equivalent to the Rust `Gl::invalidate_sub_framebuffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_invalidateSubFramebuffer(gl: &AzGl, target: u32, attachments: AzGLenumVecRef, xoffset: i32, yoffset: i32, width: i32, height: i32) { gl.invalidate_sub_framebuffer(target, attachments, xoffset, yoffset, width, height) }
+#[no_mangle] pub extern "C" fn AzGl_invalidateSubFramebuffer(gl: &AzGl, target: u32, attachments: AzGLenumVecRef, xoffset: i32, yoffset: i32, width: i32, height: i32) { ffi_guard!((), { gl.invalidate_sub_framebuffer(target, attachments, xoffset, yoffset, width, height) }) }
 /// Equivalent to the Rust `Gl::get_integer_v()` function.
-#[no_mangle] pub extern "C" fn AzGl_getIntegerV(gl: &AzGl, name: u32, result: AzGLintVecRefMut) { gl.get_integer_v(name, result) }
+#[no_mangle] pub extern "C" fn AzGl_getIntegerV(gl: &AzGl, name: u32, result: AzGLintVecRefMut) { ffi_guard!((), { gl.get_integer_v(name, result) }) }
 /// Equivalent to the Rust `Gl::get_integer_64v()` function.
-#[no_mangle] pub extern "C" fn AzGl_getInteger64V(gl: &AzGl, name: u32, result: AzGLint64VecRefMut) { gl.get_integer_64v(name, result) }
+#[no_mangle] pub extern "C" fn AzGl_getInteger64V(gl: &AzGl, name: u32, result: AzGLint64VecRefMut) { ffi_guard!((), { gl.get_integer_64v(name, result) }) }
 /// Equivalent to the Rust `Gl::get_integer_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getIntegerIv(gl: &AzGl, name: u32, index: u32, result: AzGLintVecRefMut) { gl.get_integer_iv(name, index, result) }
+#[no_mangle] pub extern "C" fn AzGl_getIntegerIv(gl: &AzGl, name: u32, index: u32, result: AzGLintVecRefMut) { ffi_guard!((), { gl.get_integer_iv(name, index, result) }) }
 /// Equivalent to the Rust `Gl::get_integer_64iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getInteger64Iv(gl: &AzGl, name: u32, index: u32, result: AzGLint64VecRefMut) { gl.get_integer_64iv(name, index, result) }
+#[no_mangle] pub extern "C" fn AzGl_getInteger64Iv(gl: &AzGl, name: u32, index: u32, result: AzGLint64VecRefMut) { ffi_guard!((), { gl.get_integer_64iv(name, index, result) }) }
 /// Equivalent to the Rust `Gl::get_boolean_v()` function.
-#[no_mangle] pub extern "C" fn AzGl_getBooleanV(gl: &AzGl, name: u32, result: AzGLbooleanVecRefMut) { gl.get_boolean_v(name, result) }
+#[no_mangle] pub extern "C" fn AzGl_getBooleanV(gl: &AzGl, name: u32, result: AzGLbooleanVecRefMut) { ffi_guard!((), { gl.get_boolean_v(name, result) }) }
 /// Equivalent to the Rust `Gl::get_float_v()` function.
-#[no_mangle] pub extern "C" fn AzGl_getFloatV(gl: &AzGl, name: u32, result: AzGLfloatVecRefMut) { gl.get_float_v(name, result) }
+#[no_mangle] pub extern "C" fn AzGl_getFloatV(gl: &AzGl, name: u32, result: AzGLfloatVecRefMut) { ffi_guard!((), { gl.get_float_v(name, result) }) }
 /// Equivalent to the Rust `Gl::get_framebuffer_attachment_parameter_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getFramebufferAttachmentParameterIv(gl: &AzGl, target: u32, attachment: u32, pname: u32) -> i32 { gl.get_framebuffer_attachment_parameter_iv(target, attachment, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getFramebufferAttachmentParameterIv(gl: &AzGl, target: u32, attachment: u32, pname: u32) -> i32 { ffi_guard!(i32, { gl.get_framebuffer_attachment_parameter_iv(target, attachment, pname) }) }
 /// Equivalent to the Rust `Gl::get_renderbuffer_parameter_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getRenderbufferParameterIv(gl: &AzGl, target: u32, pname: u32) -> i32 { gl.get_renderbuffer_parameter_iv(target, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getRenderbufferParameterIv(gl: &AzGl, target: u32, pname: u32) -> i32 { ffi_guard!(i32, { gl.get_renderbuffer_parameter_iv(target, pname) }) }
 /// Equivalent to the Rust `Gl::get_tex_parameter_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getTexParameterIv(gl: &AzGl, target: u32, name: u32) -> i32 { gl.get_tex_parameter_iv(target, name) }
+#[no_mangle] pub extern "C" fn AzGl_getTexParameterIv(gl: &AzGl, target: u32, name: u32) -> i32 { ffi_guard!(i32, { gl.get_tex_parameter_iv(target, name) }) }
 /// Equivalent to the Rust `Gl::get_tex_parameter_fv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getTexParameterFv(gl: &AzGl, target: u32, name: u32) -> f32 { gl.get_tex_parameter_fv(target, name) }
+#[no_mangle] pub extern "C" fn AzGl_getTexParameterFv(gl: &AzGl, target: u32, name: u32) -> f32 { ffi_guard!(f32, { gl.get_tex_parameter_fv(target, name) }) }
 /// Equivalent to the Rust `Gl::tex_parameter_i()` function.
-#[no_mangle] pub extern "C" fn AzGl_texParameterI(gl: &AzGl, target: u32, pname: u32, param: i32) { gl.tex_parameter_i(target, pname, param) }
+#[no_mangle] pub extern "C" fn AzGl_texParameterI(gl: &AzGl, target: u32, pname: u32, param: i32) { ffi_guard!((), { gl.tex_parameter_i(target, pname, param) }) }
 /// Equivalent to the Rust `Gl::tex_parameter_f()` function.
-#[no_mangle] pub extern "C" fn AzGl_texParameterF(gl: &AzGl, target: u32, pname: u32, param: f32) { gl.tex_parameter_f(target, pname, param) }
+#[no_mangle] pub extern "C" fn AzGl_texParameterF(gl: &AzGl, target: u32, pname: u32, param: f32) { ffi_guard!((), { gl.tex_parameter_f(target, pname, param) }) }
 /// Equivalent to the Rust `Gl::framebuffer_texture_2d()` function.
-#[no_mangle] pub extern "C" fn AzGl_framebufferTexture2D(gl: &AzGl, target: u32, attachment: u32, textarget: u32, texture: u32, level: i32) { gl.framebuffer_texture_2d(target, attachment, textarget, texture, level) }
+#[no_mangle] pub extern "C" fn AzGl_framebufferTexture2D(gl: &AzGl, target: u32, attachment: u32, textarget: u32, texture: u32, level: i32) { ffi_guard!((), { gl.framebuffer_texture_2d(target, attachment, textarget, texture, level) }) }
 /// Equivalent to the Rust `Gl::framebuffer_texture_layer()` function.
-#[no_mangle] pub extern "C" fn AzGl_framebufferTextureLayer(gl: &AzGl, target: u32, attachment: u32, texture: u32, level: i32, layer: i32) { gl.framebuffer_texture_layer(target, attachment, texture, level, layer) }
+#[no_mangle] pub extern "C" fn AzGl_framebufferTextureLayer(gl: &AzGl, target: u32, attachment: u32, texture: u32, level: i32, layer: i32) { ffi_guard!((), { gl.framebuffer_texture_layer(target, attachment, texture, level, layer) }) }
 /// Equivalent to the Rust `Gl::blit_framebuffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_blitFramebuffer(gl: &AzGl, src_x0: i32, src_y0: i32, src_x1: i32, src_y1: i32, dst_x0: i32, dst_y0: i32, dst_x1: i32, dst_y1: i32, mask: u32, filter: u32) { gl.blit_framebuffer(src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter) }
+#[no_mangle] pub extern "C" fn AzGl_blitFramebuffer(gl: &AzGl, src_x0: i32, src_y0: i32, src_x1: i32, src_y1: i32, dst_x0: i32, dst_y0: i32, dst_x1: i32, dst_y1: i32, mask: u32, filter: u32) { ffi_guard!((), { gl.blit_framebuffer(src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter) }) }
 /// Equivalent to the Rust `Gl::vertex_attrib_4f()` function.
-#[no_mangle] pub extern "C" fn AzGl_vertexAttrib4F(gl: &AzGl, index: u32, x: f32, y: f32, z: f32, w: f32) { gl.vertex_attrib_4f(index, x, y, z, w) }
+#[no_mangle] pub extern "C" fn AzGl_vertexAttrib4F(gl: &AzGl, index: u32, x: f32, y: f32, z: f32, w: f32) { ffi_guard!((), { gl.vertex_attrib_4f(index, x, y, z, w) }) }
 /// Equivalent to the Rust `Gl::vertex_attrib_pointer_f32()` function.
-#[no_mangle] pub extern "C" fn AzGl_vertexAttribPointerF32(gl: &AzGl, index: u32, size: i32, normalized: bool, stride: i32, offset: u32) { gl.vertex_attrib_pointer_f32(index, size, normalized, stride, offset) }
+#[no_mangle] pub extern "C" fn AzGl_vertexAttribPointerF32(gl: &AzGl, index: u32, size: i32, normalized: bool, stride: i32, offset: u32) { ffi_guard!((), { gl.vertex_attrib_pointer_f32(index, size, normalized, stride, offset) }) }
 /// Equivalent to the Rust `Gl::vertex_attrib_pointer()` function.
-#[no_mangle] pub extern "C" fn AzGl_vertexAttribPointer(gl: &AzGl, index: u32, size: i32, type_: u32, normalized: bool, stride: i32, offset: u32) { gl.vertex_attrib_pointer(index, size, type_, normalized, stride, offset) }
+#[no_mangle] pub extern "C" fn AzGl_vertexAttribPointer(gl: &AzGl, index: u32, size: i32, type_: u32, normalized: bool, stride: i32, offset: u32) { ffi_guard!((), { gl.vertex_attrib_pointer(index, size, type_, normalized, stride, offset) }) }
 /// Equivalent to the Rust `Gl::vertex_attrib_i_pointer()` function.
-#[no_mangle] pub extern "C" fn AzGl_vertexAttribIPointer(gl: &AzGl, index: u32, size: i32, type_: u32, stride: i32, offset: u32) { gl.vertex_attrib_i_pointer(index, size, type_, stride, offset) }
+#[no_mangle] pub extern "C" fn AzGl_vertexAttribIPointer(gl: &AzGl, index: u32, size: i32, type_: u32, stride: i32, offset: u32) { ffi_guard!((), { gl.vertex_attrib_i_pointer(index, size, type_, stride, offset) }) }
 /// Equivalent to the Rust `Gl::vertex_attrib_divisor()` function.
-#[no_mangle] pub extern "C" fn AzGl_vertexAttribDivisor(gl: &AzGl, index: u32, divisor: u32) { gl.vertex_attrib_divisor(index, divisor) }
+#[no_mangle] pub extern "C" fn AzGl_vertexAttribDivisor(gl: &AzGl, index: u32, divisor: u32) { ffi_guard!((), { gl.vertex_attrib_divisor(index, divisor) }) }
 /// Equivalent to the Rust `Gl::viewport()` function.
-#[no_mangle] pub extern "C" fn AzGl_viewport(gl: &AzGl, x: i32, y: i32, width: i32, height: i32) { gl.viewport(x, y, width, height) }
+#[no_mangle] pub extern "C" fn AzGl_viewport(gl: &AzGl, x: i32, y: i32, width: i32, height: i32) { ffi_guard!((), { gl.viewport(x, y, width, height) }) }
 /// Equivalent to the Rust `Gl::scissor()` function.
-#[no_mangle] pub extern "C" fn AzGl_scissor(gl: &AzGl, x: i32, y: i32, width: i32, height: i32) { gl.scissor(x, y, width, height) }
+#[no_mangle] pub extern "C" fn AzGl_scissor(gl: &AzGl, x: i32, y: i32, width: i32, height: i32) { ffi_guard!((), { gl.scissor(x, y, width, height) }) }
 /// Equivalent to the Rust `Gl::line_width()` function.
-#[no_mangle] pub extern "C" fn AzGl_lineWidth(gl: &AzGl, width: f32) { gl.line_width(width) }
+#[no_mangle] pub extern "C" fn AzGl_lineWidth(gl: &AzGl, width: f32) { ffi_guard!((), { gl.line_width(width) }) }
 /// Equivalent to the Rust `Gl::use_program()` function.
-#[no_mangle] pub extern "C" fn AzGl_useProgram(gl: &AzGl, program: u32) { gl.use_program(program) }
+#[no_mangle] pub extern "C" fn AzGl_useProgram(gl: &AzGl, program: u32) { ffi_guard!((), { gl.use_program(program) }) }
 /// Equivalent to the Rust `Gl::validate_program()` function.
-#[no_mangle] pub extern "C" fn AzGl_validateProgram(gl: &AzGl, program: u32) { gl.validate_program(program) }
+#[no_mangle] pub extern "C" fn AzGl_validateProgram(gl: &AzGl, program: u32) { ffi_guard!((), { gl.validate_program(program) }) }
 /// Equivalent to the Rust `Gl::draw_arrays()` function.
-#[no_mangle] pub extern "C" fn AzGl_drawArrays(gl: &AzGl, mode: u32, first: i32, count: i32) { gl.draw_arrays(mode, first, count) }
+#[no_mangle] pub extern "C" fn AzGl_drawArrays(gl: &AzGl, mode: u32, first: i32, count: i32) { ffi_guard!((), { gl.draw_arrays(mode, first, count) }) }
 /// Equivalent to the Rust `Gl::draw_arrays_instanced()` function.
-#[no_mangle] pub extern "C" fn AzGl_drawArraysInstanced(gl: &AzGl, mode: u32, first: i32, count: i32, primcount: i32) { gl.draw_arrays_instanced(mode, first, count, primcount) }
+#[no_mangle] pub extern "C" fn AzGl_drawArraysInstanced(gl: &AzGl, mode: u32, first: i32, count: i32, primcount: i32) { ffi_guard!((), { gl.draw_arrays_instanced(mode, first, count, primcount) }) }
 /// Equivalent to the Rust `Gl::draw_elements()` function.
-#[no_mangle] pub extern "C" fn AzGl_drawElements(gl: &AzGl, mode: u32, count: i32, element_type: u32, indices_offset: u32) { gl.draw_elements(mode, count, element_type, indices_offset) }
+#[no_mangle] pub extern "C" fn AzGl_drawElements(gl: &AzGl, mode: u32, count: i32, element_type: u32, indices_offset: u32) { ffi_guard!((), { gl.draw_elements(mode, count, element_type, indices_offset) }) }
 /// Equivalent to the Rust `Gl::draw_elements_instanced()` function.
-#[no_mangle] pub extern "C" fn AzGl_drawElementsInstanced(gl: &AzGl, mode: u32, count: i32, element_type: u32, indices_offset: u32, primcount: i32) { gl.draw_elements_instanced(mode, count, element_type, indices_offset, primcount) }
+#[no_mangle] pub extern "C" fn AzGl_drawElementsInstanced(gl: &AzGl, mode: u32, count: i32, element_type: u32, indices_offset: u32, primcount: i32) { ffi_guard!((), { gl.draw_elements_instanced(mode, count, element_type, indices_offset, primcount) }) }
 /// Equivalent to the Rust `Gl::blend_color()` function.
-#[no_mangle] pub extern "C" fn AzGl_blendColor(gl: &AzGl, r: f32, g: f32, b: f32, a: f32) { gl.blend_color(r, g, b, a) }
+#[no_mangle] pub extern "C" fn AzGl_blendColor(gl: &AzGl, r: f32, g: f32, b: f32, a: f32) { ffi_guard!((), { gl.blend_color(r, g, b, a) }) }
 /// Equivalent to the Rust `Gl::blend_func()` function.
-#[no_mangle] pub extern "C" fn AzGl_blendFunc(gl: &AzGl, sfactor: u32, dfactor: u32) { gl.blend_func(sfactor, dfactor) }
+#[no_mangle] pub extern "C" fn AzGl_blendFunc(gl: &AzGl, sfactor: u32, dfactor: u32) { ffi_guard!((), { gl.blend_func(sfactor, dfactor) }) }
 /// Equivalent to the Rust `Gl::blend_func_separate()` function.
-#[no_mangle] pub extern "C" fn AzGl_blendFuncSeparate(gl: &AzGl, src_rgb: u32, dest_rgb: u32, src_alpha: u32, dest_alpha: u32) { gl.blend_func_separate(src_rgb, dest_rgb, src_alpha, dest_alpha) }
+#[no_mangle] pub extern "C" fn AzGl_blendFuncSeparate(gl: &AzGl, src_rgb: u32, dest_rgb: u32, src_alpha: u32, dest_alpha: u32) { ffi_guard!((), { gl.blend_func_separate(src_rgb, dest_rgb, src_alpha, dest_alpha) }) }
 /// Equivalent to the Rust `Gl::blend_equation()` function.
-#[no_mangle] pub extern "C" fn AzGl_blendEquation(gl: &AzGl, mode: u32) { gl.blend_equation(mode) }
+#[no_mangle] pub extern "C" fn AzGl_blendEquation(gl: &AzGl, mode: u32) { ffi_guard!((), { gl.blend_equation(mode) }) }
 /// Equivalent to the Rust `Gl::blend_equation_separate()` function.
-#[no_mangle] pub extern "C" fn AzGl_blendEquationSeparate(gl: &AzGl, mode_rgb: u32, mode_alpha: u32) { gl.blend_equation_separate(mode_rgb, mode_alpha) }
+#[no_mangle] pub extern "C" fn AzGl_blendEquationSeparate(gl: &AzGl, mode_rgb: u32, mode_alpha: u32) { ffi_guard!((), { gl.blend_equation_separate(mode_rgb, mode_alpha) }) }
 /// Equivalent to the Rust `Gl::color_mask()` function.
-#[no_mangle] pub extern "C" fn AzGl_colorMask(gl: &AzGl, r: bool, g: bool, b: bool, a: bool) { gl.color_mask(r, g, b, a) }
+#[no_mangle] pub extern "C" fn AzGl_colorMask(gl: &AzGl, r: bool, g: bool, b: bool, a: bool) { ffi_guard!((), { gl.color_mask(r, g, b, a) }) }
 /// Equivalent to the Rust `Gl::cull_face()` function.
-#[no_mangle] pub extern "C" fn AzGl_cullFace(gl: &AzGl, mode: u32) { gl.cull_face(mode) }
+#[no_mangle] pub extern "C" fn AzGl_cullFace(gl: &AzGl, mode: u32) { ffi_guard!((), { gl.cull_face(mode) }) }
 /// Equivalent to the Rust `Gl::front_face()` function.
-#[no_mangle] pub extern "C" fn AzGl_frontFace(gl: &AzGl, mode: u32) { gl.front_face(mode) }
+#[no_mangle] pub extern "C" fn AzGl_frontFace(gl: &AzGl, mode: u32) { ffi_guard!((), { gl.front_face(mode) }) }
 /// Equivalent to the Rust `Gl::enable()` function.
-#[no_mangle] pub extern "C" fn AzGl_enable(gl: &AzGl, cap: u32) { gl.enable(cap) }
+#[no_mangle] pub extern "C" fn AzGl_enable(gl: &AzGl, cap: u32) { ffi_guard!((), { gl.enable(cap) }) }
 /// Equivalent to the Rust `Gl::disable()` function.
-#[no_mangle] pub extern "C" fn AzGl_disable(gl: &AzGl, cap: u32) { gl.disable(cap) }
+#[no_mangle] pub extern "C" fn AzGl_disable(gl: &AzGl, cap: u32) { ffi_guard!((), { gl.disable(cap) }) }
 /// Equivalent to the Rust `Gl::hint()` function.
-#[no_mangle] pub extern "C" fn AzGl_hint(gl: &AzGl, param_name: u32, param_val: u32) { gl.hint(param_name, param_val) }
+#[no_mangle] pub extern "C" fn AzGl_hint(gl: &AzGl, param_name: u32, param_val: u32) { ffi_guard!((), { gl.hint(param_name, param_val) }) }
 /// Equivalent to the Rust `Gl::is_enabled()` function.
-#[no_mangle] pub extern "C" fn AzGl_isEnabled(gl: &AzGl, cap: u32) -> u8 { gl.is_enabled(cap) }
+#[no_mangle] pub extern "C" fn AzGl_isEnabled(gl: &AzGl, cap: u32) -> u8 { ffi_guard!(u8, { gl.is_enabled(cap) }) }
 /// Equivalent to the Rust `Gl::is_shader()` function.
-#[no_mangle] pub extern "C" fn AzGl_isShader(gl: &AzGl, shader: u32) -> u8 { gl.is_shader(shader) }
+#[no_mangle] pub extern "C" fn AzGl_isShader(gl: &AzGl, shader: u32) -> u8 { ffi_guard!(u8, { gl.is_shader(shader) }) }
 /// Equivalent to the Rust `Gl::is_texture()` function.
-#[no_mangle] pub extern "C" fn AzGl_isTexture(gl: &AzGl, texture: u32) -> u8 { gl.is_texture(texture) }
+#[no_mangle] pub extern "C" fn AzGl_isTexture(gl: &AzGl, texture: u32) -> u8 { ffi_guard!(u8, { gl.is_texture(texture) }) }
 /// Equivalent to the Rust `Gl::is_framebuffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_isFramebuffer(gl: &AzGl, framebuffer: u32) -> u8 { gl.is_framebuffer(framebuffer) }
+#[no_mangle] pub extern "C" fn AzGl_isFramebuffer(gl: &AzGl, framebuffer: u32) -> u8 { ffi_guard!(u8, { gl.is_framebuffer(framebuffer) }) }
 /// Equivalent to the Rust `Gl::is_renderbuffer()` function.
-#[no_mangle] pub extern "C" fn AzGl_isRenderbuffer(gl: &AzGl, renderbuffer: u32) -> u8 { gl.is_renderbuffer(renderbuffer) }
+#[no_mangle] pub extern "C" fn AzGl_isRenderbuffer(gl: &AzGl, renderbuffer: u32) -> u8 { ffi_guard!(u8, { gl.is_renderbuffer(renderbuffer) }) }
 /// Equivalent to the Rust `Gl::check_frame_buffer_status()` function.
-#[no_mangle] pub extern "C" fn AzGl_checkFrameBufferStatus(gl: &AzGl, target: u32) -> u32 { gl.check_frame_buffer_status(target) }
+#[no_mangle] pub extern "C" fn AzGl_checkFrameBufferStatus(gl: &AzGl, target: u32) -> u32 { ffi_guard!(u32, { gl.check_frame_buffer_status(target) }) }
 /// Equivalent to the Rust `Gl::enable_vertex_attrib_array()` function.
-#[no_mangle] pub extern "C" fn AzGl_enableVertexAttribArray(gl: &AzGl, index: u32) { gl.enable_vertex_attrib_array(index) }
+#[no_mangle] pub extern "C" fn AzGl_enableVertexAttribArray(gl: &AzGl, index: u32) { ffi_guard!((), { gl.enable_vertex_attrib_array(index) }) }
 /// Equivalent to the Rust `Gl::disable_vertex_attrib_array()` function.
-#[no_mangle] pub extern "C" fn AzGl_disableVertexAttribArray(gl: &AzGl, index: u32) { gl.disable_vertex_attrib_array(index) }
+#[no_mangle] pub extern "C" fn AzGl_disableVertexAttribArray(gl: &AzGl, index: u32) { ffi_guard!((), { gl.disable_vertex_attrib_array(index) }) }
 /// Equivalent to the Rust `Gl::uniform_1f()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform1F(gl: &AzGl, location: i32, v0: f32) { gl.uniform_1f(location, v0) }
+#[no_mangle] pub extern "C" fn AzGl_uniform1F(gl: &AzGl, location: i32, v0: f32) { ffi_guard!((), { gl.uniform_1f(location, v0) }) }
 /// Equivalent to the Rust `Gl::uniform_1fv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform1Fv(gl: &AzGl, location: i32, values: AzF32VecRef) { gl.uniform_1fv(location, values) }
+#[no_mangle] pub extern "C" fn AzGl_uniform1Fv(gl: &AzGl, location: i32, values: AzF32VecRef) { ffi_guard!((), { gl.uniform_1fv(location, values) }) }
 /// Equivalent to the Rust `Gl::uniform_1i()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform1I(gl: &AzGl, location: i32, v0: i32) { gl.uniform_1i(location, v0) }
+#[no_mangle] pub extern "C" fn AzGl_uniform1I(gl: &AzGl, location: i32, v0: i32) { ffi_guard!((), { gl.uniform_1i(location, v0) }) }
 /// Equivalent to the Rust `Gl::uniform_1iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform1Iv(gl: &AzGl, location: i32, values: AzI32VecRef) { gl.uniform_1iv(location, values) }
+#[no_mangle] pub extern "C" fn AzGl_uniform1Iv(gl: &AzGl, location: i32, values: AzI32VecRef) { ffi_guard!((), { gl.uniform_1iv(location, values) }) }
 /// Equivalent to the Rust `Gl::uniform_1ui()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform1Ui(gl: &AzGl, location: i32, v0: u32) { gl.uniform_1ui(location, v0) }
+#[no_mangle] pub extern "C" fn AzGl_uniform1Ui(gl: &AzGl, location: i32, v0: u32) { ffi_guard!((), { gl.uniform_1ui(location, v0) }) }
 /// Equivalent to the Rust `Gl::uniform_2f()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform2F(gl: &AzGl, location: i32, v0: f32, v1: f32) { gl.uniform_2f(location, v0, v1) }
+#[no_mangle] pub extern "C" fn AzGl_uniform2F(gl: &AzGl, location: i32, v0: f32, v1: f32) { ffi_guard!((), { gl.uniform_2f(location, v0, v1) }) }
 /// Equivalent to the Rust `Gl::uniform_2fv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform2Fv(gl: &AzGl, location: i32, values: AzF32VecRef) { gl.uniform_2fv(location, values) }
+#[no_mangle] pub extern "C" fn AzGl_uniform2Fv(gl: &AzGl, location: i32, values: AzF32VecRef) { ffi_guard!((), { gl.uniform_2fv(location, values) }) }
 /// Equivalent to the Rust `Gl::uniform_2i()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform2I(gl: &AzGl, location: i32, v0: i32, v1: i32) { gl.uniform_2i(location, v0, v1) }
+#[no_mangle] pub extern "C" fn AzGl_uniform2I(gl: &AzGl, location: i32, v0: i32, v1: i32) { ffi_guard!((), { gl.uniform_2i(location, v0, v1) }) }
 /// Equivalent to the Rust `Gl::uniform_2iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform2Iv(gl: &AzGl, location: i32, values: AzI32VecRef) { gl.uniform_2iv(location, values) }
+#[no_mangle] pub extern "C" fn AzGl_uniform2Iv(gl: &AzGl, location: i32, values: AzI32VecRef) { ffi_guard!((), { gl.uniform_2iv(location, values) }) }
 /// Equivalent to the Rust `Gl::uniform_2ui()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform2Ui(gl: &AzGl, location: i32, v0: u32, v1: u32) { gl.uniform_2ui(location, v0, v1) }
+#[no_mangle] pub extern "C" fn AzGl_uniform2Ui(gl: &AzGl, location: i32, v0: u32, v1: u32) { ffi_guard!((), { gl.uniform_2ui(location, v0, v1) }) }
 /// Equivalent to the Rust `Gl::uniform_3f()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform3F(gl: &AzGl, location: i32, v0: f32, v1: f32, v2: f32) { gl.uniform_3f(location, v0, v1, v2) }
+#[no_mangle] pub extern "C" fn AzGl_uniform3F(gl: &AzGl, location: i32, v0: f32, v1: f32, v2: f32) { ffi_guard!((), { gl.uniform_3f(location, v0, v1, v2) }) }
 /// Equivalent to the Rust `Gl::uniform_3fv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform3Fv(gl: &AzGl, location: i32, values: AzF32VecRef) { gl.uniform_3fv(location, values) }
+#[no_mangle] pub extern "C" fn AzGl_uniform3Fv(gl: &AzGl, location: i32, values: AzF32VecRef) { ffi_guard!((), { gl.uniform_3fv(location, values) }) }
 /// Equivalent to the Rust `Gl::uniform_3i()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform3I(gl: &AzGl, location: i32, v0: i32, v1: i32, v2: i32) { gl.uniform_3i(location, v0, v1, v2) }
+#[no_mangle] pub extern "C" fn AzGl_uniform3I(gl: &AzGl, location: i32, v0: i32, v1: i32, v2: i32) { ffi_guard!((), { gl.uniform_3i(location, v0, v1, v2) }) }
 /// Equivalent to the Rust `Gl::uniform_3iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform3Iv(gl: &AzGl, location: i32, values: AzI32VecRef) { gl.uniform_3iv(location, values) }
+#[no_mangle] pub extern "C" fn AzGl_uniform3Iv(gl: &AzGl, location: i32, values: AzI32VecRef) { ffi_guard!((), { gl.uniform_3iv(location, values) }) }
 /// Equivalent to the Rust `Gl::uniform_3ui()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform3Ui(gl: &AzGl, location: i32, v0: u32, v1: u32, v2: u32) { gl.uniform_3ui(location, v0, v1, v2) }
+#[no_mangle] pub extern "C" fn AzGl_uniform3Ui(gl: &AzGl, location: i32, v0: u32, v1: u32, v2: u32) { ffi_guard!((), { gl.uniform_3ui(location, v0, v1, v2) }) }
 /// Equivalent to the Rust `Gl::uniform_4f()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform4F(gl: &AzGl, location: i32, x: f32, y: f32, z: f32, w: f32) { gl.uniform_4f(location, x, y, z, w) }
+#[no_mangle] pub extern "C" fn AzGl_uniform4F(gl: &AzGl, location: i32, x: f32, y: f32, z: f32, w: f32) { ffi_guard!((), { gl.uniform_4f(location, x, y, z, w) }) }
 /// Equivalent to the Rust `Gl::uniform_4i()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform4I(gl: &AzGl, location: i32, x: i32, y: i32, z: i32, w: i32) { gl.uniform_4i(location, x, y, z, w) }
+#[no_mangle] pub extern "C" fn AzGl_uniform4I(gl: &AzGl, location: i32, x: i32, y: i32, z: i32, w: i32) { ffi_guard!((), { gl.uniform_4i(location, x, y, z, w) }) }
 /// Equivalent to the Rust `Gl::uniform_4iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform4Iv(gl: &AzGl, location: i32, values: AzI32VecRef) { gl.uniform_4iv(location, values) }
+#[no_mangle] pub extern "C" fn AzGl_uniform4Iv(gl: &AzGl, location: i32, values: AzI32VecRef) { ffi_guard!((), { gl.uniform_4iv(location, values) }) }
 /// Equivalent to the Rust `Gl::uniform_4ui()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform4Ui(gl: &AzGl, location: i32, x: u32, y: u32, z: u32, w: u32) { gl.uniform_4ui(location, x, y, z, w) }
+#[no_mangle] pub extern "C" fn AzGl_uniform4Ui(gl: &AzGl, location: i32, x: u32, y: u32, z: u32, w: u32) { ffi_guard!((), { gl.uniform_4ui(location, x, y, z, w) }) }
 /// Equivalent to the Rust `Gl::uniform_4fv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniform4Fv(gl: &AzGl, location: i32, values: AzF32VecRef) { gl.uniform_4fv(location, values) }
+#[no_mangle] pub extern "C" fn AzGl_uniform4Fv(gl: &AzGl, location: i32, values: AzF32VecRef) { ffi_guard!((), { gl.uniform_4fv(location, values) }) }
 /// Equivalent to the Rust `Gl::uniform_matrix_2fv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniformMatrix2Fv(gl: &AzGl, location: i32, transpose: bool, value: AzF32VecRef) { gl.uniform_matrix_2fv(location, transpose, value) }
+#[no_mangle] pub extern "C" fn AzGl_uniformMatrix2Fv(gl: &AzGl, location: i32, transpose: bool, value: AzF32VecRef) { ffi_guard!((), { gl.uniform_matrix_2fv(location, transpose, value) }) }
 /// Equivalent to the Rust `Gl::uniform_matrix_3fv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniformMatrix3Fv(gl: &AzGl, location: i32, transpose: bool, value: AzF32VecRef) { gl.uniform_matrix_3fv(location, transpose, value) }
+#[no_mangle] pub extern "C" fn AzGl_uniformMatrix3Fv(gl: &AzGl, location: i32, transpose: bool, value: AzF32VecRef) { ffi_guard!((), { gl.uniform_matrix_3fv(location, transpose, value) }) }
 /// Equivalent to the Rust `Gl::uniform_matrix_4fv()` function.
-#[no_mangle] pub extern "C" fn AzGl_uniformMatrix4Fv(gl: &AzGl, location: i32, transpose: bool, value: AzF32VecRef) { gl.uniform_matrix_4fv(location, transpose, value) }
+#[no_mangle] pub extern "C" fn AzGl_uniformMatrix4Fv(gl: &AzGl, location: i32, transpose: bool, value: AzF32VecRef) { ffi_guard!((), { gl.uniform_matrix_4fv(location, transpose, value) }) }
 /// Equivalent to the Rust `Gl::depth_mask()` function.
-#[no_mangle] pub extern "C" fn AzGl_depthMask(gl: &AzGl, flag: bool) { gl.depth_mask(flag) }
+#[no_mangle] pub extern "C" fn AzGl_depthMask(gl: &AzGl, flag: bool) { ffi_guard!((), { gl.depth_mask(flag) }) }
 /// Equivalent to the Rust `Gl::depth_range()` function.
-#[no_mangle] pub extern "C" fn AzGl_depthRange(gl: &AzGl, near: f64, far: f64) { gl.depth_range(near, far) }
+#[no_mangle] pub extern "C" fn AzGl_depthRange(gl: &AzGl, near: f64, far: f64) { ffi_guard!((), { gl.depth_range(near, far) }) }
 /// Equivalent to the Rust `Gl::get_active_attrib()` function.
-#[no_mangle] pub extern "C" fn AzGl_getActiveAttrib(gl: &AzGl, program: u32, index: u32) -> AzGetActiveAttribReturn { gl.get_active_attrib(program, index) }
+#[no_mangle] pub extern "C" fn AzGl_getActiveAttrib(gl: &AzGl, program: u32, index: u32) -> AzGetActiveAttribReturn { ffi_guard!(AzGetActiveAttribReturn, { gl.get_active_attrib(program, index) }) }
 /// Equivalent to the Rust `Gl::get_active_uniform()` function.
-#[no_mangle] pub extern "C" fn AzGl_getActiveUniform(gl: &AzGl, program: u32, index: u32) -> AzGetActiveUniformReturn { gl.get_active_uniform(program, index) }
+#[no_mangle] pub extern "C" fn AzGl_getActiveUniform(gl: &AzGl, program: u32, index: u32) -> AzGetActiveUniformReturn { ffi_guard!(AzGetActiveUniformReturn, { gl.get_active_uniform(program, index) }) }
 /// Equivalent to the Rust `Gl::get_active_uniforms_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getActiveUniformsIv(gl: &AzGl, program: u32, indices: AzGLuintVec, pname: u32) -> AzGLintVec { gl.get_active_uniforms_iv(program, indices, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getActiveUniformsIv(gl: &AzGl, program: u32, indices: AzGLuintVec, pname: u32) -> AzGLintVec { ffi_guard!(AzGLintVec, { gl.get_active_uniforms_iv(program, indices, pname) }) }
 /// Equivalent to the Rust `Gl::get_active_uniform_block_i()` function.
-#[no_mangle] pub extern "C" fn AzGl_getActiveUniformBlockI(gl: &AzGl, program: u32, index: u32, pname: u32) -> i32 { gl.get_active_uniform_block_i(program, index, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getActiveUniformBlockI(gl: &AzGl, program: u32, index: u32, pname: u32) -> i32 { ffi_guard!(i32, { gl.get_active_uniform_block_i(program, index, pname) }) }
 /// Equivalent to the Rust `Gl::get_active_uniform_block_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getActiveUniformBlockIv(gl: &AzGl, program: u32, index: u32, pname: u32) -> AzGLintVec { gl.get_active_uniform_block_iv(program, index, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getActiveUniformBlockIv(gl: &AzGl, program: u32, index: u32, pname: u32) -> AzGLintVec { ffi_guard!(AzGLintVec, { gl.get_active_uniform_block_iv(program, index, pname) }) }
 /// Equivalent to the Rust `Gl::get_active_uniform_block_name()` function.
-#[no_mangle] pub extern "C" fn AzGl_getActiveUniformBlockName(gl: &AzGl, program: u32, index: u32) -> AzString { gl.get_active_uniform_block_name(program, index) }
+#[no_mangle] pub extern "C" fn AzGl_getActiveUniformBlockName(gl: &AzGl, program: u32, index: u32) -> AzString { ffi_guard!(AzString, { gl.get_active_uniform_block_name(program, index) }) }
 /// Equivalent to the Rust `Gl::get_attrib_location()` function.
-#[no_mangle] pub extern "C" fn AzGl_getAttribLocation(gl: &AzGl, program: u32, name: AzRefstr) -> i32 { gl.get_attrib_location(program, name) }
+#[no_mangle] pub extern "C" fn AzGl_getAttribLocation(gl: &AzGl, program: u32, name: AzRefstr) -> i32 { ffi_guard!(i32, { gl.get_attrib_location(program, name) }) }
 /// Equivalent to the Rust `Gl::get_frag_data_location()` function.
-#[no_mangle] pub extern "C" fn AzGl_getFragDataLocation(gl: &AzGl, program: u32, name: AzRefstr) -> i32 { gl.get_frag_data_location(program, name) }
+#[no_mangle] pub extern "C" fn AzGl_getFragDataLocation(gl: &AzGl, program: u32, name: AzRefstr) -> i32 { ffi_guard!(i32, { gl.get_frag_data_location(program, name) }) }
 /// Equivalent to the Rust `Gl::get_uniform_location()` function.
-#[no_mangle] pub extern "C" fn AzGl_getUniformLocation(gl: &AzGl, program: u32, name: AzRefstr) -> i32 { gl.get_uniform_location(program, name) }
+#[no_mangle] pub extern "C" fn AzGl_getUniformLocation(gl: &AzGl, program: u32, name: AzRefstr) -> i32 { ffi_guard!(i32, { gl.get_uniform_location(program, name) }) }
 /// Equivalent to the Rust `Gl::get_program_info_log()` function.
-#[no_mangle] pub extern "C" fn AzGl_getProgramInfoLog(gl: &AzGl, program: u32) -> AzString { gl.get_program_info_log(program) }
+#[no_mangle] pub extern "C" fn AzGl_getProgramInfoLog(gl: &AzGl, program: u32) -> AzString { ffi_guard!(AzString, { gl.get_program_info_log(program) }) }
 /// Equivalent to the Rust `Gl::get_program_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getProgramIv(gl: &AzGl, program: u32, pname: u32, result: AzGLintVecRefMut) { gl.get_program_iv(program, pname, result) }
+#[no_mangle] pub extern "C" fn AzGl_getProgramIv(gl: &AzGl, program: u32, pname: u32, result: AzGLintVecRefMut) { ffi_guard!((), { gl.get_program_iv(program, pname, result) }) }
 /// Equivalent to the Rust `Gl::get_program_binary()` function.
-#[no_mangle] pub extern "C" fn AzGl_getProgramBinary(gl: &AzGl, program: u32) -> AzGetProgramBinaryReturn { gl.get_program_binary(program) }
+#[no_mangle] pub extern "C" fn AzGl_getProgramBinary(gl: &AzGl, program: u32) -> AzGetProgramBinaryReturn { ffi_guard!(AzGetProgramBinaryReturn, { gl.get_program_binary(program) }) }
 /// Equivalent to the Rust `Gl::program_binary()` function.
-#[no_mangle] pub extern "C" fn AzGl_programBinary(gl: &AzGl, program: u32, format: u32, binary: AzU8VecRef) { gl.program_binary(program, format, binary) }
+#[no_mangle] pub extern "C" fn AzGl_programBinary(gl: &AzGl, program: u32, format: u32, binary: AzU8VecRef) { ffi_guard!((), { gl.program_binary(program, format, binary) }) }
 /// Equivalent to the Rust `Gl::program_parameter_i()` function.
-#[no_mangle] pub extern "C" fn AzGl_programParameterI(gl: &AzGl, program: u32, pname: u32, value: i32) { gl.program_parameter_i(program, pname, value) }
+#[no_mangle] pub extern "C" fn AzGl_programParameterI(gl: &AzGl, program: u32, pname: u32, value: i32) { ffi_guard!((), { gl.program_parameter_i(program, pname, value) }) }
 /// Equivalent to the Rust `Gl::get_vertex_attrib_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getVertexAttribIv(gl: &AzGl, index: u32, pname: u32, result: AzGLintVecRefMut) { gl.get_vertex_attrib_iv(index, pname, result) }
+#[no_mangle] pub extern "C" fn AzGl_getVertexAttribIv(gl: &AzGl, index: u32, pname: u32, result: AzGLintVecRefMut) { ffi_guard!((), { gl.get_vertex_attrib_iv(index, pname, result) }) }
 /// Equivalent to the Rust `Gl::get_vertex_attrib_fv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getVertexAttribFv(gl: &AzGl, index: u32, pname: u32, result: AzGLfloatVecRefMut) { gl.get_vertex_attrib_fv(index, pname, result) }
+#[no_mangle] pub extern "C" fn AzGl_getVertexAttribFv(gl: &AzGl, index: u32, pname: u32, result: AzGLfloatVecRefMut) { ffi_guard!((), { gl.get_vertex_attrib_fv(index, pname, result) }) }
 /// Equivalent to the Rust `Gl::get_vertex_attrib_pointer_v()` function.
-#[no_mangle] pub extern "C" fn AzGl_getVertexAttribPointerV(gl: &AzGl, index: u32, pname: u32) -> isize { gl.get_vertex_attrib_pointer_v(index, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getVertexAttribPointerV(gl: &AzGl, index: u32, pname: u32) -> isize { ffi_guard!(isize, { gl.get_vertex_attrib_pointer_v(index, pname) }) }
 /// Equivalent to the Rust `Gl::get_buffer_parameter_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getBufferParameterIv(gl: &AzGl, target: u32, pname: u32) -> i32 { gl.get_buffer_parameter_iv(target, pname) }
+#[no_mangle] pub extern "C" fn AzGl_getBufferParameterIv(gl: &AzGl, target: u32, pname: u32) -> i32 { ffi_guard!(i32, { gl.get_buffer_parameter_iv(target, pname) }) }
 /// Equivalent to the Rust `Gl::get_shader_info_log()` function.
-#[no_mangle] pub extern "C" fn AzGl_getShaderInfoLog(gl: &AzGl, shader: u32) -> AzString { gl.get_shader_info_log(shader) }
+#[no_mangle] pub extern "C" fn AzGl_getShaderInfoLog(gl: &AzGl, shader: u32) -> AzString { ffi_guard!(AzString, { gl.get_shader_info_log(shader) }) }
 /// Equivalent to the Rust `Gl::get_string()` function.
-#[no_mangle] pub extern "C" fn AzGl_getString(gl: &AzGl, which: u32) -> AzString { gl.get_string(which) }
+#[no_mangle] pub extern "C" fn AzGl_getString(gl: &AzGl, which: u32) -> AzString { ffi_guard!(AzString, { gl.get_string(which) }) }
 /// Equivalent to the Rust `Gl::get_string_i()` function.
-#[no_mangle] pub extern "C" fn AzGl_getStringI(gl: &AzGl, which: u32, index: u32) -> AzString { gl.get_string_i(which, index) }
+#[no_mangle] pub extern "C" fn AzGl_getStringI(gl: &AzGl, which: u32, index: u32) -> AzString { ffi_guard!(AzString, { gl.get_string_i(which, index) }) }
 /// Equivalent to the Rust `Gl::get_shader_iv()` function.
-#[no_mangle] pub extern "C" fn AzGl_getShaderIv(gl: &AzGl, shader: u32, pname: u32, result: AzGLintVecRefMut) { gl.get_shader_iv(shader, pname, result) }
+#[no_mangle] pub extern "C" fn AzGl_getShaderIv(gl: &AzGl, shader: u32, pname: u32, result: AzGLintVecRefMut) { ffi_guard!((), { gl.get_shader_iv(shader, pname, result) }) }
 /// Equivalent to the Rust `Gl::get_shader_precision_format()` function.
-#[no_mangle] pub extern "C" fn AzGl_getShaderPrecisionFormat(gl: &AzGl, shader_type: u32, precision_type: u32) -> AzGlShaderPrecisionFormatReturn { gl.get_shader_precision_format(shader_type, precision_type) }
+#[no_mangle] pub extern "C" fn AzGl_getShaderPrecisionFormat(gl: &AzGl, shader_type: u32, precision_type: u32) -> AzGlShaderPrecisionFormatReturn { ffi_guard!(AzGlShaderPrecisionFormatReturn, { gl.get_shader_precision_format(shader_type, precision_type) }) }
 /// Equivalent to the Rust `Gl::compile_shader()` function.
-#[no_mangle] pub extern "C" fn AzGl_compileShader(gl: &AzGl, shader: u32) { gl.compile_shader(shader) }
+#[no_mangle] pub extern "C" fn AzGl_compileShader(gl: &AzGl, shader: u32) { ffi_guard!((), { gl.compile_shader(shader) }) }
 /// Equivalent to the Rust `Gl::create_program()` function.
-#[no_mangle] pub extern "C" fn AzGl_createProgram(gl: &AzGl) -> u32 { gl.create_program() }
+#[no_mangle] pub extern "C" fn AzGl_createProgram(gl: &AzGl) -> u32 { ffi_guard!(u32, { gl.create_program() }) }
 /// Equivalent to the Rust `Gl::delete_program()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteProgram(gl: &AzGl, program: u32) { gl.delete_program(program) }
+#[no_mangle] pub extern "C" fn AzGl_deleteProgram(gl: &AzGl, program: u32) { ffi_guard!((), { gl.delete_program(program) }) }
 /// Equivalent to the Rust `Gl::create_shader()` function.
-#[no_mangle] pub extern "C" fn AzGl_createShader(gl: &AzGl, shader_type: u32) -> u32 { gl.create_shader(shader_type) }
+#[no_mangle] pub extern "C" fn AzGl_createShader(gl: &AzGl, shader_type: u32) -> u32 { ffi_guard!(u32, { gl.create_shader(shader_type) }) }
 /// Equivalent to the Rust `Gl::delete_shader()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteShader(gl: &AzGl, shader: u32) { gl.delete_shader(shader) }
+#[no_mangle] pub extern "C" fn AzGl_deleteShader(gl: &AzGl, shader: u32) { ffi_guard!((), { gl.delete_shader(shader) }) }
 /// Equivalent to the Rust `Gl::detach_shader()` function.
-#[no_mangle] pub extern "C" fn AzGl_detachShader(gl: &AzGl, program: u32, shader: u32) { gl.detach_shader(program, shader) }
+#[no_mangle] pub extern "C" fn AzGl_detachShader(gl: &AzGl, program: u32, shader: u32) { ffi_guard!((), { gl.detach_shader(program, shader) }) }
 /// Equivalent to the Rust `Gl::link_program()` function.
-#[no_mangle] pub extern "C" fn AzGl_linkProgram(gl: &AzGl, program: u32) { gl.link_program(program) }
+#[no_mangle] pub extern "C" fn AzGl_linkProgram(gl: &AzGl, program: u32) { ffi_guard!((), { gl.link_program(program) }) }
 /// Equivalent to the Rust `Gl::clear_color()` function.
-#[no_mangle] pub extern "C" fn AzGl_clearColor(gl: &AzGl, r: f32, g: f32, b: f32, a: f32) { gl.clear_color(r, g, b, a) }
+#[no_mangle] pub extern "C" fn AzGl_clearColor(gl: &AzGl, r: f32, g: f32, b: f32, a: f32) { ffi_guard!((), { gl.clear_color(r, g, b, a) }) }
 /// Equivalent to the Rust `Gl::clear()` function.
-#[no_mangle] pub extern "C" fn AzGl_clear(gl: &AzGl, buffer_mask: u32) { gl.clear(buffer_mask) }
+#[no_mangle] pub extern "C" fn AzGl_clear(gl: &AzGl, buffer_mask: u32) { ffi_guard!((), { gl.clear(buffer_mask) }) }
 /// Equivalent to the Rust `Gl::clear_depth()` function.
-#[no_mangle] pub extern "C" fn AzGl_clearDepth(gl: &AzGl, depth: f64) { gl.clear_depth(depth) }
+#[no_mangle] pub extern "C" fn AzGl_clearDepth(gl: &AzGl, depth: f64) { ffi_guard!((), { gl.clear_depth(depth) }) }
 /// Equivalent to the Rust `Gl::clear_stencil()` function.
-#[no_mangle] pub extern "C" fn AzGl_clearStencil(gl: &AzGl, s: i32) { gl.clear_stencil(s) }
+#[no_mangle] pub extern "C" fn AzGl_clearStencil(gl: &AzGl, s: i32) { ffi_guard!((), { gl.clear_stencil(s) }) }
 /// Equivalent to the Rust `Gl::flush()` function.
-#[no_mangle] pub extern "C" fn AzGl_flush(gl: &AzGl) { gl.flush() }
+#[no_mangle] pub extern "C" fn AzGl_flush(gl: &AzGl) { ffi_guard!((), { gl.flush() }) }
 /// Equivalent to the Rust `Gl::finish()` function.
-#[no_mangle] pub extern "C" fn AzGl_finish(gl: &AzGl) { gl.finish() }
+#[no_mangle] pub extern "C" fn AzGl_finish(gl: &AzGl) { ffi_guard!((), { gl.finish() }) }
 /// Equivalent to the Rust `Gl::get_error()` function.
-#[no_mangle] pub extern "C" fn AzGl_getError(gl: &AzGl) -> u32 { gl.get_error() }
+#[no_mangle] pub extern "C" fn AzGl_getError(gl: &AzGl) -> u32 { ffi_guard!(u32, { gl.get_error() }) }
 /// Equivalent to the Rust `Gl::stencil_mask()` function.
-#[no_mangle] pub extern "C" fn AzGl_stencilMask(gl: &AzGl, mask: u32) { gl.stencil_mask(mask) }
+#[no_mangle] pub extern "C" fn AzGl_stencilMask(gl: &AzGl, mask: u32) { ffi_guard!((), { gl.stencil_mask(mask) }) }
 /// Equivalent to the Rust `Gl::stencil_mask_separate()` function.
-#[no_mangle] pub extern "C" fn AzGl_stencilMaskSeparate(gl: &AzGl, face: u32, mask: u32) { gl.stencil_mask_separate(face, mask) }
+#[no_mangle] pub extern "C" fn AzGl_stencilMaskSeparate(gl: &AzGl, face: u32, mask: u32) { ffi_guard!((), { gl.stencil_mask_separate(face, mask) }) }
 /// Equivalent to the Rust `Gl::stencil_func()` function.
-#[no_mangle] pub extern "C" fn AzGl_stencilFunc(gl: &AzGl, func: u32, ref_: i32, mask: u32) { gl.stencil_func(func, ref_, mask) }
+#[no_mangle] pub extern "C" fn AzGl_stencilFunc(gl: &AzGl, func: u32, ref_: i32, mask: u32) { ffi_guard!((), { gl.stencil_func(func, ref_, mask) }) }
 /// Equivalent to the Rust `Gl::stencil_func_separate()` function.
-#[no_mangle] pub extern "C" fn AzGl_stencilFuncSeparate(gl: &AzGl, face: u32, func: u32, ref_: i32, mask: u32) { gl.stencil_func_separate(face, func, ref_, mask) }
+#[no_mangle] pub extern "C" fn AzGl_stencilFuncSeparate(gl: &AzGl, face: u32, func: u32, ref_: i32, mask: u32) { ffi_guard!((), { gl.stencil_func_separate(face, func, ref_, mask) }) }
 /// Equivalent to the Rust `Gl::stencil_op()` function.
-#[no_mangle] pub extern "C" fn AzGl_stencilOp(gl: &AzGl, sfail: u32, dpfail: u32, dppass: u32) { gl.stencil_op(sfail, dpfail, dppass) }
+#[no_mangle] pub extern "C" fn AzGl_stencilOp(gl: &AzGl, sfail: u32, dpfail: u32, dppass: u32) { ffi_guard!((), { gl.stencil_op(sfail, dpfail, dppass) }) }
 /// Equivalent to the Rust `Gl::stencil_op_separate()` function.
-#[no_mangle] pub extern "C" fn AzGl_stencilOpSeparate(gl: &AzGl, face: u32, sfail: u32, dpfail: u32, dppass: u32) { gl.stencil_op_separate(face, sfail, dpfail, dppass) }
+#[no_mangle] pub extern "C" fn AzGl_stencilOpSeparate(gl: &AzGl, face: u32, sfail: u32, dpfail: u32, dppass: u32) { ffi_guard!((), { gl.stencil_op_separate(face, sfail, dpfail, dppass) }) }
 /// Equivalent to the Rust `Gl::egl_image_target_texture2d_oes()` function.
-#[no_mangle] pub extern "C" fn AzGl_eglImageTargetTexture2DOes(gl: &AzGl, target: u32, image: AzGlVoidPtrConst) { gl.egl_image_target_texture2d_oes(target, image) }
+#[no_mangle] pub extern "C" fn AzGl_eglImageTargetTexture2DOes(gl: &AzGl, target: u32, image: AzGlVoidPtrConst) { ffi_guard!((), { gl.egl_image_target_texture2d_oes(target, image) }) }
 /// Equivalent to the Rust `Gl::generate_mipmap()` function.
-#[no_mangle] pub extern "C" fn AzGl_generateMipmap(gl: &AzGl, target: u32) { gl.generate_mipmap(target) }
+#[no_mangle] pub extern "C" fn AzGl_generateMipmap(gl: &AzGl, target: u32) { ffi_guard!((), { gl.generate_mipmap(target) }) }
 /// Equivalent to the Rust `Gl::insert_event_marker_ext()` function.
-#[no_mangle] pub extern "C" fn AzGl_insertEventMarkerExt(gl: &AzGl, message: AzRefstr) { gl.insert_event_marker_ext(message) }
+#[no_mangle] pub extern "C" fn AzGl_insertEventMarkerExt(gl: &AzGl, message: AzRefstr) { ffi_guard!((), { gl.insert_event_marker_ext(message) }) }
 /// Equivalent to the Rust `Gl::push_group_marker_ext()` function.
-#[no_mangle] pub extern "C" fn AzGl_pushGroupMarkerExt(gl: &AzGl, message: AzRefstr) { gl.push_group_marker_ext(message) }
+#[no_mangle] pub extern "C" fn AzGl_pushGroupMarkerExt(gl: &AzGl, message: AzRefstr) { ffi_guard!((), { gl.push_group_marker_ext(message) }) }
 /// Equivalent to the Rust `Gl::pop_group_marker_ext()` function.
-#[no_mangle] pub extern "C" fn AzGl_popGroupMarkerExt(gl: &AzGl) { gl.pop_group_marker_ext() }
+#[no_mangle] pub extern "C" fn AzGl_popGroupMarkerExt(gl: &AzGl) { ffi_guard!((), { gl.pop_group_marker_ext() }) }
 /// Equivalent to the Rust `Gl::debug_message_insert_khr()` function.
-#[no_mangle] pub extern "C" fn AzGl_debugMessageInsertKhr(gl: &AzGl, source: u32, type_: u32, id: u32, severity: u32, message: AzRefstr) { gl.debug_message_insert_khr(source, type_, id, severity, message) }
+#[no_mangle] pub extern "C" fn AzGl_debugMessageInsertKhr(gl: &AzGl, source: u32, type_: u32, id: u32, severity: u32, message: AzRefstr) { ffi_guard!((), { gl.debug_message_insert_khr(source, type_, id, severity, message) }) }
 /// Equivalent to the Rust `Gl::push_debug_group_khr()` function.
-#[no_mangle] pub extern "C" fn AzGl_pushDebugGroupKhr(gl: &AzGl, source: u32, id: u32, message: AzRefstr) { gl.push_debug_group_khr(source, id, message) }
+#[no_mangle] pub extern "C" fn AzGl_pushDebugGroupKhr(gl: &AzGl, source: u32, id: u32, message: AzRefstr) { ffi_guard!((), { gl.push_debug_group_khr(source, id, message) }) }
 /// Equivalent to the Rust `Gl::pop_debug_group_khr()` function.
-#[no_mangle] pub extern "C" fn AzGl_popDebugGroupKhr(gl: &AzGl) { gl.pop_debug_group_khr() }
+#[no_mangle] pub extern "C" fn AzGl_popDebugGroupKhr(gl: &AzGl) { ffi_guard!((), { gl.pop_debug_group_khr() }) }
 /// Equivalent to the Rust `Gl::fence_sync()` function.
-#[no_mangle] pub extern "C" fn AzGl_fenceSync(gl: &AzGl, condition: u32, flags: u32) -> AzGLsyncPtr { gl.fence_sync(condition, flags) }
+#[no_mangle] pub extern "C" fn AzGl_fenceSync(gl: &AzGl, condition: u32, flags: u32) -> AzGLsyncPtr { ffi_guard!(AzGLsyncPtr, { gl.fence_sync(condition, flags) }) }
 /// Equivalent to the Rust `Gl::client_wait_sync()` function.
-#[no_mangle] pub extern "C" fn AzGl_clientWaitSync(gl: &AzGl, sync: AzGLsyncPtr, flags: u32, timeout: u64) -> u32 { gl.client_wait_sync(sync, flags, timeout) }
+#[no_mangle] pub extern "C" fn AzGl_clientWaitSync(gl: &AzGl, sync: AzGLsyncPtr, flags: u32, timeout: u64) -> u32 { ffi_guard!(u32, { gl.client_wait_sync(sync, flags, timeout) }) }
 /// Equivalent to the Rust `Gl::wait_sync()` function.
-#[no_mangle] pub extern "C" fn AzGl_waitSync(gl: &AzGl, sync: AzGLsyncPtr, flags: u32, timeout: u64) { gl.wait_sync(sync, flags, timeout) }
+#[no_mangle] pub extern "C" fn AzGl_waitSync(gl: &AzGl, sync: AzGLsyncPtr, flags: u32, timeout: u64) { ffi_guard!((), { gl.wait_sync(sync, flags, timeout) }) }
 /// Equivalent to the Rust `Gl::delete_sync()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteSync(gl: &AzGl, sync: AzGLsyncPtr) { gl.delete_sync(sync) }
+#[no_mangle] pub extern "C" fn AzGl_deleteSync(gl: &AzGl, sync: AzGLsyncPtr) { ffi_guard!((), { gl.delete_sync(sync) }) }
 /// Equivalent to the Rust `Gl::texture_range_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_textureRangeApple(gl: &AzGl, target: u32, data: AzU8VecRef) { gl.texture_range_apple(target, data) }
+#[no_mangle] pub extern "C" fn AzGl_textureRangeApple(gl: &AzGl, target: u32, data: AzU8VecRef) { ffi_guard!((), { gl.texture_range_apple(target, data) }) }
 /// Equivalent to the Rust `Gl::gen_fences_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_genFencesApple(gl: &AzGl, n: i32) -> AzGLuintVec { gl.gen_fences_apple(n) }
+#[no_mangle] pub extern "C" fn AzGl_genFencesApple(gl: &AzGl, n: i32) -> AzGLuintVec { ffi_guard!(AzGLuintVec, { gl.gen_fences_apple(n) }) }
 /// Equivalent to the Rust `Gl::delete_fences_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteFencesApple(gl: &AzGl, fences: AzGLuintVecRef) { gl.delete_fences_apple(fences) }
+#[no_mangle] pub extern "C" fn AzGl_deleteFencesApple(gl: &AzGl, fences: AzGLuintVecRef) { ffi_guard!((), { gl.delete_fences_apple(fences) }) }
 /// Equivalent to the Rust `Gl::set_fence_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_setFenceApple(gl: &AzGl, fence: u32) { gl.set_fence_apple(fence) }
+#[no_mangle] pub extern "C" fn AzGl_setFenceApple(gl: &AzGl, fence: u32) { ffi_guard!((), { gl.set_fence_apple(fence) }) }
 /// Equivalent to the Rust `Gl::finish_fence_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_finishFenceApple(gl: &AzGl, fence: u32) { gl.finish_fence_apple(fence) }
+#[no_mangle] pub extern "C" fn AzGl_finishFenceApple(gl: &AzGl, fence: u32) { ffi_guard!((), { gl.finish_fence_apple(fence) }) }
 /// Equivalent to the Rust `Gl::test_fence_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_testFenceApple(gl: &AzGl, fence: u32) { gl.test_fence_apple(fence) }
+#[no_mangle] pub extern "C" fn AzGl_testFenceApple(gl: &AzGl, fence: u32) { ffi_guard!((), { gl.test_fence_apple(fence) }) }
 /// Equivalent to the Rust `Gl::test_object_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_testObjectApple(gl: &AzGl, object: u32, name: u32) -> u8 { gl.test_object_apple(object, name) }
+#[no_mangle] pub extern "C" fn AzGl_testObjectApple(gl: &AzGl, object: u32, name: u32) -> u8 { ffi_guard!(u8, { gl.test_object_apple(object, name) }) }
 /// Equivalent to the Rust `Gl::finish_object_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_finishObjectApple(gl: &AzGl, object: u32, name: u32) { gl.finish_object_apple(object, name) }
+#[no_mangle] pub extern "C" fn AzGl_finishObjectApple(gl: &AzGl, object: u32, name: u32) { ffi_guard!((), { gl.finish_object_apple(object, name) }) }
 /// Equivalent to the Rust `Gl::get_frag_data_index()` function.
-#[no_mangle] pub extern "C" fn AzGl_getFragDataIndex(gl: &AzGl, program: u32, name: AzRefstr) -> i32 { gl.get_frag_data_index(program, name) }
+#[no_mangle] pub extern "C" fn AzGl_getFragDataIndex(gl: &AzGl, program: u32, name: AzRefstr) -> i32 { ffi_guard!(i32, { gl.get_frag_data_index(program, name) }) }
 /// Equivalent to the Rust `Gl::blend_barrier_khr()` function.
-#[no_mangle] pub extern "C" fn AzGl_blendBarrierKhr(gl: &AzGl) { gl.blend_barrier_khr() }
+#[no_mangle] pub extern "C" fn AzGl_blendBarrierKhr(gl: &AzGl) { ffi_guard!((), { gl.blend_barrier_khr() }) }
 /// Equivalent to the Rust `Gl::bind_frag_data_location_indexed()` function.
-#[no_mangle] pub extern "C" fn AzGl_bindFragDataLocationIndexed(gl: &AzGl, program: u32, color_number: u32, index: u32, name: AzRefstr) { gl.bind_frag_data_location_indexed(program, color_number, index, name) }
+#[no_mangle] pub extern "C" fn AzGl_bindFragDataLocationIndexed(gl: &AzGl, program: u32, color_number: u32, index: u32, name: AzRefstr) { ffi_guard!((), { gl.bind_frag_data_location_indexed(program, color_number, index, name) }) }
 /// Equivalent to the Rust `Gl::get_debug_messages()` function.
-#[no_mangle] pub extern "C" fn AzGl_getDebugMessages(gl: &AzGl) -> AzDebugMessageVec { gl.get_debug_messages() }
+#[no_mangle] pub extern "C" fn AzGl_getDebugMessages(gl: &AzGl) -> AzDebugMessageVec { ffi_guard!(AzDebugMessageVec, { gl.get_debug_messages() }) }
 /// Equivalent to the Rust `Gl::provoking_vertex_angle()` function.
-#[no_mangle] pub extern "C" fn AzGl_provokingVertexAngle(gl: &AzGl, mode: u32) { gl.provoking_vertex_angle(mode) }
+#[no_mangle] pub extern "C" fn AzGl_provokingVertexAngle(gl: &AzGl, mode: u32) { ffi_guard!((), { gl.provoking_vertex_angle(mode) }) }
 /// Equivalent to the Rust `Gl::gen_vertex_arrays_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_genVertexArraysApple(gl: &AzGl, n: i32) -> AzGLuintVec { gl.gen_vertex_arrays_apple(n) }
+#[no_mangle] pub extern "C" fn AzGl_genVertexArraysApple(gl: &AzGl, n: i32) -> AzGLuintVec { ffi_guard!(AzGLuintVec, { gl.gen_vertex_arrays_apple(n) }) }
 /// Equivalent to the Rust `Gl::bind_vertex_array_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_bindVertexArrayApple(gl: &AzGl, vao: u32) { gl.bind_vertex_array_apple(vao) }
+#[no_mangle] pub extern "C" fn AzGl_bindVertexArrayApple(gl: &AzGl, vao: u32) { ffi_guard!((), { gl.bind_vertex_array_apple(vao) }) }
 /// Equivalent to the Rust `Gl::delete_vertex_arrays_apple()` function.
-#[no_mangle] pub extern "C" fn AzGl_deleteVertexArraysApple(gl: &AzGl, vertex_arrays: AzGLuintVecRef) { gl.delete_vertex_arrays_apple(vertex_arrays) }
+#[no_mangle] pub extern "C" fn AzGl_deleteVertexArraysApple(gl: &AzGl, vertex_arrays: AzGLuintVecRef) { ffi_guard!((), { gl.delete_vertex_arrays_apple(vertex_arrays) }) }
 /// Equivalent to the Rust `Gl::copy_texture_chromium()` function.
-#[no_mangle] pub extern "C" fn AzGl_copyTextureChromium(gl: &AzGl, source_id: u32, source_level: i32, dest_target: u32, dest_id: u32, dest_level: i32, internal_format: i32, dest_type: u32, unpack_flip_y: u8, unpack_premultiply_alpha: u8, unpack_unmultiply_alpha: u8) { gl.copy_texture_chromium(source_id, source_level, dest_target, dest_id, dest_level, internal_format, dest_type, unpack_flip_y, unpack_premultiply_alpha, unpack_unmultiply_alpha) }
+#[no_mangle] pub extern "C" fn AzGl_copyTextureChromium(gl: &AzGl, source_id: u32, source_level: i32, dest_target: u32, dest_id: u32, dest_level: i32, internal_format: i32, dest_type: u32, unpack_flip_y: u8, unpack_premultiply_alpha: u8, unpack_unmultiply_alpha: u8) { ffi_guard!((), { gl.copy_texture_chromium(source_id, source_level, dest_target, dest_id, dest_level, internal_format, dest_type, unpack_flip_y, unpack_premultiply_alpha, unpack_unmultiply_alpha) }) }
 /// Equivalent to the Rust `Gl::copy_sub_texture_chromium()` function.
-#[no_mangle] pub extern "C" fn AzGl_copySubTextureChromium(gl: &AzGl, source_id: u32, source_level: i32, dest_target: u32, dest_id: u32, dest_level: i32, x_offset: i32, y_offset: i32, x: i32, y: i32, width: i32, height: i32, unpack_flip_y: u8, unpack_premultiply_alpha: u8, unpack_unmultiply_alpha: u8) { gl.copy_sub_texture_chromium(source_id, source_level, dest_target, dest_id, dest_level, x_offset, y_offset, x, y, width, height, unpack_flip_y, unpack_premultiply_alpha, unpack_unmultiply_alpha) }
+#[no_mangle] pub extern "C" fn AzGl_copySubTextureChromium(gl: &AzGl, source_id: u32, source_level: i32, dest_target: u32, dest_id: u32, dest_level: i32, x_offset: i32, y_offset: i32, x: i32, y: i32, width: i32, height: i32, unpack_flip_y: u8, unpack_premultiply_alpha: u8, unpack_unmultiply_alpha: u8) { ffi_guard!((), { gl.copy_sub_texture_chromium(source_id, source_level, dest_target, dest_id, dest_level, x_offset, y_offset, x, y, width, height, unpack_flip_y, unpack_premultiply_alpha, unpack_unmultiply_alpha) }) }
 /// Equivalent to the Rust `Gl::egl_image_target_renderbuffer_storage_oes()` function.
-#[no_mangle] pub extern "C" fn AzGl_eglImageTargetRenderbufferStorageOes(gl: &AzGl, target: u32, image: AzGlVoidPtrConst) { gl.egl_image_target_renderbuffer_storage_oes(target, image) }
+#[no_mangle] pub extern "C" fn AzGl_eglImageTargetRenderbufferStorageOes(gl: &AzGl, target: u32, image: AzGlVoidPtrConst) { ffi_guard!((), { gl.egl_image_target_renderbuffer_storage_oes(target, image) }) }
 /// Equivalent to the Rust `Gl::copy_texture_3d_angle()` function.
-#[no_mangle] pub extern "C" fn AzGl_copyTexture3DAngle(gl: &AzGl, source_id: u32, source_level: i32, dest_target: u32, dest_id: u32, dest_level: i32, internal_format: i32, dest_type: u32, unpack_flip_y: u8, unpack_premultiply_alpha: u8, unpack_unmultiply_alpha: u8) { gl.copy_texture_3d_angle(source_id, source_level, dest_target, dest_id, dest_level, internal_format, dest_type, unpack_flip_y, unpack_premultiply_alpha, unpack_unmultiply_alpha) }
+#[no_mangle] pub extern "C" fn AzGl_copyTexture3DAngle(gl: &AzGl, source_id: u32, source_level: i32, dest_target: u32, dest_id: u32, dest_level: i32, internal_format: i32, dest_type: u32, unpack_flip_y: u8, unpack_premultiply_alpha: u8, unpack_unmultiply_alpha: u8) { ffi_guard!((), { gl.copy_texture_3d_angle(source_id, source_level, dest_target, dest_id, dest_level, internal_format, dest_type, unpack_flip_y, unpack_premultiply_alpha, unpack_unmultiply_alpha) }) }
 /// Equivalent to the Rust `Gl::copy_sub_texture_3d_angle()` function.
-#[no_mangle] pub extern "C" fn AzGl_copySubTexture3DAngle(gl: &AzGl, source_id: u32, source_level: i32, dest_target: u32, dest_id: u32, dest_level: i32, x_offset: i32, y_offset: i32, z_offset: i32, x: i32, y: i32, z: i32, width: i32, height: i32, depth: i32, unpack_flip_y: u8, unpack_premultiply_alpha: u8, unpack_unmultiply_alpha: u8) { gl.copy_sub_texture_3d_angle(source_id, source_level, dest_target, dest_id, dest_level, x_offset, y_offset, z_offset, x, y, z, width, height, depth, unpack_flip_y, unpack_premultiply_alpha, unpack_unmultiply_alpha) }
+#[no_mangle] pub extern "C" fn AzGl_copySubTexture3DAngle(gl: &AzGl, source_id: u32, source_level: i32, dest_target: u32, dest_id: u32, dest_level: i32, x_offset: i32, y_offset: i32, z_offset: i32, x: i32, y: i32, z: i32, width: i32, height: i32, depth: i32, unpack_flip_y: u8, unpack_premultiply_alpha: u8, unpack_unmultiply_alpha: u8) { ffi_guard!((), { gl.copy_sub_texture_3d_angle(source_id, source_level, dest_target, dest_id, dest_level, x_offset, y_offset, z_offset, x, y, z, width, height, depth, unpack_flip_y, unpack_premultiply_alpha, unpack_unmultiply_alpha) }) }
 /// Equivalent to the Rust `Gl::buffer_storage()` function.
-#[no_mangle] pub extern "C" fn AzGl_bufferStorage(gl: &AzGl, target: u32, size: isize, data: AzGlVoidPtrConst, flags: u32) { gl.buffer_storage(target, size, data, flags) }
+#[no_mangle] pub extern "C" fn AzGl_bufferStorage(gl: &AzGl, target: u32, size: isize, data: AzGlVoidPtrConst, flags: u32) { ffi_guard!((), { gl.buffer_storage(target, size, data, flags) }) }
 /// Equivalent to the Rust `Gl::flush_mapped_buffer_range()` function.
-#[no_mangle] pub extern "C" fn AzGl_flushMappedBufferRange(gl: &AzGl, target: u32, offset: isize, length: isize) { gl.flush_mapped_buffer_range(target, offset, length) }
+#[no_mangle] pub extern "C" fn AzGl_flushMappedBufferRange(gl: &AzGl, target: u32, offset: isize, length: isize) { ffi_guard!((), { gl.flush_mapped_buffer_range(target, offset, length) }) }
 /// Destructor: Takes ownership of the `Gl` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzGl_delete(object: &mut AzGl) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzGl_delete(object: &mut AzGl) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzGl_deepCopy(object: &AzGl) -> AzGl { object.clone() }
 
@@ -3161,22 +3829,22 @@ pub use AzVertexAttributeTypeTT as AzVertexAttributeType;
 pub use azul_impl::gl::VertexAttribute as AzVertexAttributeTT;
 pub use AzVertexAttributeTT as AzVertexAttribute;
 /// Destructor: Takes ownership of the `VertexAttribute` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzVertexAttribute_delete(object: &mut AzVertexAttribute) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzVertexAttribute_delete(object: &mut AzVertexAttribute) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `VertexLayout` struct
 pub use azul_impl::gl::VertexLayout as AzVertexLayoutTT;
 pub use AzVertexLayoutTT as AzVertexLayout;
 /// Destructor: Takes ownership of the `VertexLayout` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzVertexLayout_delete(object: &mut AzVertexLayout) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzVertexLayout_delete(object: &mut AzVertexLayout) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `VertexArrayObject` struct
 pub use azul_impl::gl::VertexArrayObject as AzVertexArrayObjectTT;
 pub use AzVertexArrayObjectTT as AzVertexArrayObject;
 /// Creates a new `VertexArrayObject` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `VertexArrayObject::new()` constructor.
-#[no_mangle] pub extern "C" fn AzVertexArrayObject_new(vertex_layout: AzVertexLayout, vao_id: u32, gl_context: AzGl) -> AzVertexArrayObject { AzVertexArrayObject::new(vertex_layout, vao_id, gl_context) }
+#[no_mangle] pub extern "C" fn AzVertexArrayObject_new(vertex_layout: AzVertexLayout, vao_id: u32, gl_context: AzGl) -> AzVertexArrayObject { ffi_guard!(AzVertexArrayObject, { AzVertexArrayObject::new(vertex_layout, vao_id, gl_context) }) }
 /// Destructor: Takes ownership of the `VertexArrayObject` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzVertexArrayObject_delete(object: &mut AzVertexArrayObject) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzVertexArrayObject_delete(object: &mut AzVertexArrayObject) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzVertexArrayObject_deepCopy(object: &AzVertexArrayObject) -> AzVertexArrayObject { object.clone() }
 
@@ -3189,9 +3857,9 @@ pub use azul_impl::gl::VertexBuffer as AzVertexBufferTT;
 pub use AzVertexBufferTT as AzVertexBuffer;
 /// Creates a new `VertexBuffer` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `VertexBuffer::new()` constructor.
-#[no_mangle] pub extern "C" fn AzVertexBuffer_new(vertex_buffer_id: u32, vertex_buffer_len: usize, vao: AzVertexArrayObject, index_buffer_id: u32, index_buffer_len: usize, index_buffer_format: AzIndexBufferFormat) -> AzVertexBuffer { AzVertexBuffer::new_raw(vertex_buffer_id, vertex_buffer_len, vao, index_buffer_id, index_buffer_len, index_buffer_format) }
+#[no_mangle] pub extern "C" fn AzVertexBuffer_new(vertex_buffer_id: u32, vertex_buffer_len: usize, vao: AzVertexArrayObject, index_buffer_id: u32, index_buffer_len: usize, index_buffer_format: AzIndexBufferFormat) -> AzVertexBuffer { ffi_guard!(AzVertexBuffer, { AzVertexBuffer::new_raw(vertex_buffer_id, vertex_buffer_len, vao, index_buffer_id, index_buffer_len, index_buffer_format) }) }
 /// Destructor: Takes ownership of the `VertexBuffer` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzVertexBuffer_delete(object: &mut AzVertexBuffer) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzVertexBuffer_delete(object: &mut AzVertexBuffer) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzVertexBuffer_deepCopy(object: &AzVertexBuffer) -> AzVertexBuffer { object.clone() }
 
@@ -3203,7 +3871,7 @@ pub use AzGlTypeTT as AzGlType;
 pub use azul_impl::gl::AzDebugMessage as AzDebugMessageTT;
 pub use AzDebugMessageTT as AzDebugMessage;
 /// Destructor: Takes ownership of the `DebugMessage` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzDebugMessage_delete(object: &mut AzDebugMessage) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzDebugMessage_delete(object: &mut AzDebugMessage) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// C-ABI stable reexport of `&[u8]`
 pub use azul_impl::gl::U8VecRef as AzU8VecRefTT;
@@ -3257,19 +3925,19 @@ pub use AzRefstrTT as AzRefstr;
 pub use azul_impl::gl::GetProgramBinaryReturn as AzGetProgramBinaryReturnTT;
 pub use AzGetProgramBinaryReturnTT as AzGetProgramBinaryReturn;
 /// Destructor: Takes ownership of the `GetProgramBinaryReturn` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzGetProgramBinaryReturn_delete(object: &mut AzGetProgramBinaryReturn) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzGetProgramBinaryReturn_delete(object: &mut AzGetProgramBinaryReturn) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// C-ABI stable reexport of `(i32, u32, AzString)`
 pub use azul_impl::gl::GetActiveAttribReturn as AzGetActiveAttribReturnTT;
 pub use AzGetActiveAttribReturnTT as AzGetActiveAttribReturn;
 /// Destructor: Takes ownership of the `GetActiveAttribReturn` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzGetActiveAttribReturn_delete(object: &mut AzGetActiveAttribReturn) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzGetActiveAttribReturn_delete(object: &mut AzGetActiveAttribReturn) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// C-ABI stable reexport of `*const gleam::gl::GLsync`
 pub use azul_impl::gl::GLsyncPtr as AzGLsyncPtrTT;
 pub use AzGLsyncPtrTT as AzGLsyncPtr;
 /// Destructor: Takes ownership of the `GLsyncPtr` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzGLsyncPtr_delete(object: &mut AzGLsyncPtr) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzGLsyncPtr_delete(object: &mut AzGLsyncPtr) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzGLsyncPtr_deepCopy(object: &AzGLsyncPtr) -> AzGLsyncPtr { object.clone() }
 
@@ -3277,41 +3945,41 @@ pub use AzGLsyncPtrTT as AzGLsyncPtr;
 pub use azul_impl::gl::GetActiveUniformReturn as AzGetActiveUniformReturnTT;
 pub use AzGetActiveUniformReturnTT as AzGetActiveUniformReturn;
 /// Destructor: Takes ownership of the `GetActiveUniformReturn` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzGetActiveUniformReturn_delete(object: &mut AzGetActiveUniformReturn) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzGetActiveUniformReturn_delete(object: &mut AzGetActiveUniformReturn) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TextureFlags` struct
 pub use azul_impl::gl::TextureFlags as AzTextureFlagsTT;
 pub use AzTextureFlagsTT as AzTextureFlags;
 /// Default texture flags (not opaque, not a video texture)
-#[no_mangle] pub extern "C" fn AzTextureFlags_default() -> AzTextureFlags { AzTextureFlags::default() }
+#[no_mangle] pub extern "C" fn AzTextureFlags_default() -> AzTextureFlags { ffi_guard!(AzTextureFlags, { AzTextureFlags::default() }) }
 
 /// Re-export of rust-allocated (stack based) `ImageRef` struct
 pub use azul_impl::resources::ImageRef as AzImageRefTT;
 pub use AzImageRefTT as AzImageRef;
 /// Creates an "invalid" image with a width and height that reserves an image key, but does not render anything
-#[no_mangle] pub extern "C" fn AzImageRef_invalid(width: usize, height: usize, format: AzRawImageFormat) -> AzImageRef { AzImageRef::invalid(width, height, format) }
+#[no_mangle] pub extern "C" fn AzImageRef_invalid(width: usize, height: usize, format: AzRawImageFormat) -> AzImageRef { ffi_guard!(AzImageRef, { AzImageRef::invalid(width, height, format) }) }
 /// Creates an image reference from a CPU-backed buffer
-#[no_mangle] pub extern "C" fn AzImageRef_rawImage(data: AzRawImage) -> AzOptionImageRef { AzImageRef::new_rawimage(data).into() }
+#[no_mangle] pub extern "C" fn AzImageRef_rawImage(data: AzRawImage) -> AzOptionImageRef { ffi_guard!(AzOptionImageRef, { AzImageRef::new_rawimage(data).into() }) }
 /// Creates an image reference from an OpenGL texture
-#[no_mangle] pub extern "C" fn AzImageRef_glTexture(texture: AzTexture) -> AzImageRef { AzImageRef::new_gltexture(texture) }
+#[no_mangle] pub extern "C" fn AzImageRef_glTexture(texture: AzTexture) -> AzImageRef { ffi_guard!(AzImageRef, { AzImageRef::new_gltexture(texture) }) }
 /// Creates an image reference from a callback that is going to be rendered with the given nodes computed size
-#[no_mangle] pub extern "C" fn AzImageRef_callback(data: AzRefAny, callback: AzRenderImageCallbackType) -> AzImageRef { AzImageRef::callback(callback, data) }
+#[no_mangle] pub extern "C" fn AzImageRef_callback(data: AzRefAny, callback: AzRenderImageCallbackType) -> AzImageRef { ffi_guard!(AzImageRef, { AzImageRef::callback(callback, data) }) }
 /// Creates a new copy of the image bytes instead of shallow-copying the reference
-#[no_mangle] pub extern "C" fn AzImageRef_cloneBytes(imageref: &AzImageRef) -> AzImageRef { imageref.deep_copy() }
+#[no_mangle] pub extern "C" fn AzImageRef_cloneBytes(imageref: &AzImageRef) -> AzImageRef { ffi_guard!(AzImageRef, { imageref.deep_copy() }) }
 /// Returns whether the image is a null (invalid) image
-#[no_mangle] pub extern "C" fn AzImageRef_isInvalid(imageref: &AzImageRef) -> bool { imageref.is_null_image() }
+#[no_mangle] pub extern "C" fn AzImageRef_isInvalid(imageref: &AzImageRef) -> bool { ffi_guard!(bool, { imageref.is_null_image() }) }
 /// Returns whether the image is a GL texture
-#[no_mangle] pub extern "C" fn AzImageRef_isGlTexture(imageref: &AzImageRef) -> bool { imageref.is_gl_texture() }
+#[no_mangle] pub extern "C" fn AzImageRef_isGlTexture(imageref: &AzImageRef) -> bool { ffi_guard!(bool, { imageref.is_gl_texture() }) }
 /// Returns whether the image is a raw (CPU-decoded) image
-#[no_mangle] pub extern "C" fn AzImageRef_isRawImage(imageref: &AzImageRef) -> bool { imageref.is_raw_image() }
+#[no_mangle] pub extern "C" fn AzImageRef_isRawImage(imageref: &AzImageRef) -> bool { ffi_guard!(bool, { imageref.is_raw_image() }) }
 /// Returns whether the image is a `RenderImageCallback`
-#[no_mangle] pub extern "C" fn AzImageRef_isCallback(imageref: &AzImageRef) -> bool { imageref.is_callback() }
+#[no_mangle] pub extern "C" fn AzImageRef_isCallback(imageref: &AzImageRef) -> bool { ffi_guard!(bool, { imageref.is_callback() }) }
 /// If the image is a RawImage, returns a COPY of the internal image bytes (useful for encoding the RawImage / exporting the ImageRef to a file)
-#[no_mangle] pub extern "C" fn AzImageRef_getRawImage(imageref: &AzImageRef) -> AzOptionRawImage { imageref.get_rawimage().into() }
+#[no_mangle] pub extern "C" fn AzImageRef_getRawImage(imageref: &AzImageRef) -> AzOptionRawImage { ffi_guard!(AzOptionRawImage, { imageref.get_rawimage().into() }) }
 /// Returns the hash of the ImageRef (fast)
-#[no_mangle] pub extern "C" fn AzImageRef_getHash(imageref: &AzImageRef) -> u64 { imageref.get_hash().0 as u64 }
+#[no_mangle] pub extern "C" fn AzImageRef_getHash(imageref: &AzImageRef) -> u64 { ffi_guard!(u64, { imageref.get_hash().0 as u64 }) }
 /// Destructor: Takes ownership of the `ImageRef` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzImageRef_delete(object: &mut AzImageRef) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzImageRef_delete(object: &mut AzImageRef) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzImageRef_deepCopy(object: &AzImageRef) -> AzImageRef { object.clone() }
 
@@ -3319,35 +3987,35 @@ pub use AzImageRefTT as AzImageRef;
 pub use azul_impl::resources::RawImage as AzRawImageTT;
 pub use AzRawImageTT as AzRawImage;
 /// Returns a zero-sized image
-#[no_mangle] pub extern "C" fn AzRawImage_empty() -> AzRawImage { AzRawImage::null_image() }
+#[no_mangle] pub extern "C" fn AzRawImage_empty() -> AzRawImage { ffi_guard!(AzRawImage, { AzRawImage::null_image() }) }
 /// Allocates a width * height, single-channel image with zeroed bytes
-#[no_mangle] pub extern "C" fn AzRawImage_allocateClipMask(size: AzLayoutSize) -> AzRawImage { AzRawImage::allocate_mask(size) }
+#[no_mangle] pub extern "C" fn AzRawImage_allocateClipMask(size: AzLayoutSize) -> AzRawImage { ffi_guard!(AzRawImage, { AzRawImage::allocate_mask(size) }) }
 /// Decodes a RawImage from any supported image format - automatically guesses the format based on magic header
-#[no_mangle] pub extern "C" fn AzRawImage_decodeImageBytesAny(bytes: AzU8VecRef) -> AzResultRawImageDecodeImageError { azul_impl::resources::decode::decode_raw_image_from_any_bytes(bytes.as_slice()) }
+#[no_mangle] pub extern "C" fn AzRawImage_decodeImageBytesAny(bytes: AzU8VecRef) -> AzResultRawImageDecodeImageError { ffi_guard!(AzResultRawImageDecodeImageError, { azul_impl::resources::decode::decode_raw_image_from_any_bytes(bytes.as_slice()) }) }
 /// Equivalent to the Rust `RawImage::draw_clip_mask()` function.
-#[no_mangle] pub extern "C" fn AzRawImage_drawClipMask(rawimage: &mut AzRawImage, node: AzSvgNode, style: AzSvgStyle) -> bool { azul_impl::svg::render_node_clipmask_cpu(rawimage, &node, style).is_some() }
+#[no_mangle] pub extern "C" fn AzRawImage_drawClipMask(rawimage: &mut AzRawImage, node: AzSvgNode, style: AzSvgStyle) -> bool { ffi_guard!(bool, { azul_impl::svg::render_node_clipmask_cpu(rawimage, &node, style).is_some() }) }
 /// Encodes the RawImage in the BMP image format
-#[no_mangle] pub extern "C" fn AzRawImage_encodeBmp(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { azul_impl::resources::encode::encode_bmp(rawimage) }
+#[no_mangle] pub extern "C" fn AzRawImage_encodeBmp(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { ffi_guard!(AzResultU8VecEncodeImageError, { azul_impl::resources::encode::encode_bmp(rawimage) }) }
 /// Encodes the RawImage in the PNG image format
-#[no_mangle] pub extern "C" fn AzRawImage_encodePng(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { azul_impl::resources::encode::encode_png(rawimage) }
+#[no_mangle] pub extern "C" fn AzRawImage_encodePng(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { ffi_guard!(AzResultU8VecEncodeImageError, { azul_impl::resources::encode::encode_png(rawimage) }) }
 /// Encodes the RawImage in the JPG image format
-#[no_mangle] pub extern "C" fn AzRawImage_encodeJpeg(rawimage: &AzRawImage, quality: u8) -> AzResultU8VecEncodeImageError { azul_impl::resources::encode::encode_jpeg(rawimage, quality) }
+#[no_mangle] pub extern "C" fn AzRawImage_encodeJpeg(rawimage: &AzRawImage, quality: u8) -> AzResultU8VecEncodeImageError { ffi_guard!(AzResultU8VecEncodeImageError, { azul_impl::resources::encode::encode_jpeg(rawimage, quality) }) }
 /// Encodes the RawImage in the TGA image format
-#[no_mangle] pub extern "C" fn AzRawImage_encodeTga(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { azul_impl::resources::encode::encode_tga(rawimage) }
+#[no_mangle] pub extern "C" fn AzRawImage_encodeTga(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { ffi_guard!(AzResultU8VecEncodeImageError, { azul_impl::resources::encode::encode_tga(rawimage) }) }
 /// Encodes the RawImage in the PNM image format
-#[no_mangle] pub extern "C" fn AzRawImage_encodePnm(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { azul_impl::resources::encode::encode_pnm(rawimage) }
+#[no_mangle] pub extern "C" fn AzRawImage_encodePnm(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { ffi_guard!(AzResultU8VecEncodeImageError, { azul_impl::resources::encode::encode_pnm(rawimage) }) }
 /// Encodes the RawImage in the GIF image format
-#[no_mangle] pub extern "C" fn AzRawImage_encodeGif(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { azul_impl::resources::encode::encode_gif(rawimage) }
+#[no_mangle] pub extern "C" fn AzRawImage_encodeGif(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { ffi_guard!(AzResultU8VecEncodeImageError, { azul_impl::resources::encode::encode_gif(rawimage) }) }
 /// Encodes the RawImage in the TIFF image format
-#[no_mangle] pub extern "C" fn AzRawImage_encodeTiff(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { azul_impl::resources::encode::encode_tiff(rawimage) }
+#[no_mangle] pub extern "C" fn AzRawImage_encodeTiff(rawimage: &AzRawImage) -> AzResultU8VecEncodeImageError { ffi_guard!(AzResultU8VecEncodeImageError, { azul_impl::resources::encode::encode_tiff(rawimage) }) }
 /// Destructor: Takes ownership of the `RawImage` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzRawImage_delete(object: &mut AzRawImage) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzRawImage_delete(object: &mut AzRawImage) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ImageMask` struct
 pub use azul_impl::resources::ImageMask as AzImageMaskTT;
 pub use AzImageMaskTT as AzImageMask;
 /// Destructor: Takes ownership of the `ImageMask` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzImageMask_delete(object: &mut AzImageMask) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzImageMask_delete(object: &mut AzImageMask) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `RawImageFormat` struct
 pub use azul_impl::resources::RawImageFormat as AzRawImageFormatTT;
@@ -3365,82 +4033,82 @@ pub use AzDecodeImageErrorTT as AzDecodeImageError;
 pub use azul_impl::resources::RawImageData as AzRawImageDataTT;
 pub use AzRawImageDataTT as AzRawImageData;
 /// Destructor: Takes ownership of the `RawImageData` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzRawImageData_delete(object: &mut AzRawImageData) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzRawImageData_delete(object: &mut AzRawImageData) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzParsedFontDestructorFnType = extern "C" fn(&mut c_void);
 /// Re-export of rust-allocated (stack based) `FontMetrics` struct
 pub use azul_impl::css::FontMetrics as AzFontMetricsTT;
 pub use AzFontMetricsTT as AzFontMetrics;
 /// Returns a FontMetrics struct with all fields set to 0
-#[no_mangle] pub extern "C" fn AzFontMetrics_zero() -> AzFontMetrics { AzFontMetrics::zero() }
+#[no_mangle] pub extern "C" fn AzFontMetrics_zero() -> AzFontMetrics { ffi_guard!(AzFontMetrics, { AzFontMetrics::zero() }) }
 /// If set, use `OS/2.sTypoAscender - OS/2.sTypoDescender + OS/2.sTypoLineGap` to calculate the height.
-#[no_mangle] pub extern "C" fn AzFontMetrics_useTypoMetrics(fontmetrics: &AzFontMetrics) -> bool { fontmetrics.use_typo_metrics() }
+#[no_mangle] pub extern "C" fn AzFontMetrics_useTypoMetrics(fontmetrics: &AzFontMetrics) -> bool { ffi_guard!(bool, { fontmetrics.use_typo_metrics() }) }
 /// Equivalent to the Rust `FontMetrics::get_ascender()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getAscender(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_ascender(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getAscender(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_ascender(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_descender()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getDescender(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_descender(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getDescender(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_descender(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_line_gap()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getLineGap(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_line_gap(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getLineGap(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_line_gap(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_x_min()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getXMin(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_x_min(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getXMin(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_x_min(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_min()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYMin(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_min(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYMin(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_min(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_x_max()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getXMax(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_x_max(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getXMax(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_x_max(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_max()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYMax(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_max(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYMax(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_max(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_advance_width_max()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getAdvanceWidthMax(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_advance_width_max(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getAdvanceWidthMax(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_advance_width_max(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_min_left_side_bearing()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getMinLeftSideBearing(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_min_left_side_bearing(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getMinLeftSideBearing(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_min_left_side_bearing(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_min_right_side_bearing()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getMinRightSideBearing(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_min_right_side_bearing(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getMinRightSideBearing(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_min_right_side_bearing(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_x_max_extent()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getXMaxExtent(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_x_max_extent(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getXMaxExtent(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_x_max_extent(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_x_avg_char_width()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getXAvgCharWidth(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_x_avg_char_width(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getXAvgCharWidth(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_x_avg_char_width(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_subscript_x_size()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYSubscriptXSize(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_subscript_x_size(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYSubscriptXSize(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_subscript_x_size(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_subscript_y_size()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYSubscriptYSize(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_subscript_y_size(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYSubscriptYSize(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_subscript_y_size(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_subscript_x_offset()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYSubscriptXOffset(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_subscript_x_offset(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYSubscriptXOffset(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_subscript_x_offset(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_subscript_y_offset()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYSubscriptYOffset(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_subscript_y_offset(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYSubscriptYOffset(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_subscript_y_offset(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_superscript_x_size()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYSuperscriptXSize(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_superscript_x_size(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYSuperscriptXSize(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_superscript_x_size(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_superscript_y_size()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYSuperscriptYSize(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_superscript_y_size(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYSuperscriptYSize(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_superscript_y_size(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_superscript_x_offset()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYSuperscriptXOffset(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_superscript_x_offset(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYSuperscriptXOffset(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_superscript_x_offset(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_superscript_y_offset()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYSuperscriptYOffset(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_superscript_y_offset(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYSuperscriptYOffset(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_superscript_y_offset(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_strikeout_size()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYStrikeoutSize(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_strikeout_size(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYStrikeoutSize(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_strikeout_size(target_font_size) }) }
 /// Equivalent to the Rust `FontMetrics::get_y_strikeout_position()` function.
-#[no_mangle] pub extern "C" fn AzFontMetrics_getYStrikeoutPosition(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { fontmetrics.get_y_strikeout_position(target_font_size) }
+#[no_mangle] pub extern "C" fn AzFontMetrics_getYStrikeoutPosition(fontmetrics: &AzFontMetrics, target_font_size: f32) -> f32 { ffi_guard!(f32, { fontmetrics.get_y_strikeout_position(target_font_size) }) }
 
 /// Source data of a font file (bytes)
 pub use azul_impl::resources::LoadedFontSource as AzFontSourceTT;
 pub use AzFontSourceTT as AzFontSource;
 /// Destructor: Takes ownership of the `FontSource` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFontSource_delete(object: &mut AzFontSource) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFontSource_delete(object: &mut AzFontSource) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Atomically reference-counted parsed font data
 pub use azul_impl::css::FontRef as AzFontRefTT;
 pub use AzFontRefTT as AzFontRef;
 /// Parses a new font from bytes. Returns `None` if the font could not be parsed correctly.
-#[no_mangle] pub extern "C" fn AzFontRef_parse(source: AzFontSource) -> AzOptionFontRef { azul_impl::text_layout::parse_font_fn(source).into() }
+#[no_mangle] pub extern "C" fn AzFontRef_parse(source: AzFontSource) -> AzOptionFontRef { ffi_guard!(AzOptionFontRef, { azul_impl::text_layout::parse_font_fn(source).into() }) }
 /// Returns the font bytes of the underlying font source
-#[no_mangle] pub extern "C" fn AzFontRef_getBytes(fontref: &AzFontRef) -> AzU8Vec { fontref.get_bytes() }
+#[no_mangle] pub extern "C" fn AzFontRef_getBytes(fontref: &AzFontRef) -> AzU8Vec { ffi_guard!(AzU8Vec, { fontref.get_bytes() }) }
 /// Returns the font metrics of the parsed font
-#[no_mangle] pub extern "C" fn AzFontRef_getFontMetrics(fontref: &AzFontRef) -> AzFontMetrics { azul_impl::text_layout::get_font_metrics_fontref(fontref) }
+#[no_mangle] pub extern "C" fn AzFontRef_getFontMetrics(fontref: &AzFontRef) -> AzFontMetrics { ffi_guard!(AzFontMetrics, { azul_impl::text_layout::get_font_metrics_fontref(fontref) }) }
 /// Returns the text layout of the shaped text
-#[no_mangle] pub extern "C" fn AzFontRef_shapeText(fontref: &AzFontRef, text: AzRefstr, options: AzResolvedTextLayoutOptions) -> AzInlineText { azul_impl::text_layout::shape_text(fontref, text.as_str(), &options) }
+#[no_mangle] pub extern "C" fn AzFontRef_shapeText(fontref: &AzFontRef, text: AzRefstr, options: AzResolvedTextLayoutOptions) -> AzInlineText { ffi_guard!(AzInlineText, { azul_impl::text_layout::shape_text(fontref, text.as_str(), &options) }) }
 /// Returns the hash of the FontRef (fast)
-#[no_mangle] pub extern "C" fn AzFontRef_getHash(fontref: &AzFontRef) -> u64 { azul_core::app_resources::font_ref_get_hash(fontref) }
+#[no_mangle] pub extern "C" fn AzFontRef_getHash(fontref: &AzFontRef) -> u64 { ffi_guard!(u64, { azul_core::app_resources::font_ref_get_hash(fontref) }) }
 /// Destructor: Takes ownership of the `FontRef` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFontRef_delete(object: &mut AzFontRef) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzFontRef_delete(object: &mut AzFontRef) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzFontRef_deepCopy(object: &AzFontRef) -> AzFontRef { object.clone() }
 
@@ -3449,18 +4117,18 @@ pub use azul_impl::svg::Svg as AzSvgTT;
 pub use AzSvgTT as AzSvg;
 /// Creates a new `Svg` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Svg::from_string()` constructor.
-#[no_mangle] pub extern "C" fn AzSvg_fromString(svg_string: AzString, parse_options: AzSvgParseOptions) -> AzResultSvgSvgParseError { match azul_impl::svg::svg_parse(svg_string.as_ref().as_bytes(), parse_options) { Ok(o) => azul_impl::svg::ResultSvgSvgParseError::Ok(unsafe { core::mem::transmute(o) }), Err(e) => azul_impl::svg::ResultSvgSvgParseError::Err(e) } }
+#[no_mangle] pub extern "C" fn AzSvg_fromString(svg_string: AzString, parse_options: AzSvgParseOptions) -> AzResultSvgSvgParseError { ffi_guard!(AzResultSvgSvgParseError, { match azul_impl::svg::svg_parse(svg_string.as_ref().as_bytes(), parse_options) { Ok(o) => azul_impl::svg::ResultSvgSvgParseError::Ok(unsafe { core::mem::transmute(o) }), Err(e) => azul_impl::svg::ResultSvgSvgParseError::Err(e) } }) }
 /// Creates a new `Svg` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Svg::from_bytes()` constructor.
-#[no_mangle] pub extern "C" fn AzSvg_fromBytes(svg_bytes: AzU8VecRef, parse_options: AzSvgParseOptions) -> AzResultSvgSvgParseError { match azul_impl::svg::svg_parse(svg_bytes.as_slice(), parse_options) { Ok(o) => azul_impl::svg::ResultSvgSvgParseError::Ok(unsafe { core::mem::transmute(o) }), Err(e) => azul_impl::svg::ResultSvgSvgParseError::Err(e) } }
+#[no_mangle] pub extern "C" fn AzSvg_fromBytes(svg_bytes: AzU8VecRef, parse_options: AzSvgParseOptions) -> AzResultSvgSvgParseError { ffi_guard!(AzResultSvgSvgParseError, { match azul_impl::svg::svg_parse(svg_bytes.as_slice(), parse_options) { Ok(o) => azul_impl::svg::ResultSvgSvgParseError::Ok(unsafe { core::mem::transmute(o) }), Err(e) => azul_impl::svg::ResultSvgSvgParseError::Err(e) } }) }
 /// Equivalent to the Rust `Svg::get_root()` function.
-#[no_mangle] pub extern "C" fn AzSvg_getRoot(svg: &AzSvg) -> AzSvgXmlNode { azul_impl::svg::svg_root(svg) }
+#[no_mangle] pub extern "C" fn AzSvg_getRoot(svg: &AzSvg) -> AzSvgXmlNode { ffi_guard!(AzSvgXmlNode, { azul_impl::svg::svg_root(svg) }) }
 /// Equivalent to the Rust `Svg::render()` function.
-#[no_mangle] pub extern "C" fn AzSvg_render(svg: &AzSvg, options: AzSvgRenderOptions) -> AzOptionRawImage { azul_impl::svg::svg_render(svg, options).into() }
+#[no_mangle] pub extern "C" fn AzSvg_render(svg: &AzSvg, options: AzSvgRenderOptions) -> AzOptionRawImage { ffi_guard!(AzOptionRawImage, { azul_impl::svg::svg_render(svg, options).into() }) }
 /// Equivalent to the Rust `Svg::to_string()` function.
-#[no_mangle] pub extern "C" fn AzSvg_toString(svg: &AzSvg, options: AzSvgStringFormatOptions) -> AzString { azul_impl::svg::svg_to_string(svg, options).into() }
+#[no_mangle] pub extern "C" fn AzSvg_toString(svg: &AzSvg, options: AzSvgStringFormatOptions) -> AzString { ffi_guard!(AzString, { azul_impl::svg::svg_to_string(svg, options).into() }) }
 /// Destructor: Takes ownership of the `Svg` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvg_delete(object: &mut AzSvg) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzSvg_delete(object: &mut AzSvg) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzSvg_deepCopy(object: &AzSvg) -> AzSvg { object.clone() }
 
@@ -3469,9 +4137,9 @@ pub use azul_impl::svg::SvgXmlNode as AzSvgXmlNodeTT;
 pub use AzSvgXmlNodeTT as AzSvgXmlNode;
 /// Creates a new `SvgXmlNode` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `SvgXmlNode::parse_from()` constructor.
-#[no_mangle] pub extern "C" fn AzSvgXmlNode_parseFrom(svg_bytes: AzU8VecRef, parse_options: AzSvgParseOptions) -> AzResultSvgXmlNodeSvgParseError { match azul_impl::svg::svgxmlnode_parse(svg_bytes.as_slice(), parse_options) { Ok(o) => azul_impl::svg::ResultSvgXmlNodeSvgParseError::Ok(unsafe { core::mem::transmute(o) }), Err(e) => azul_impl::svg::ResultSvgXmlNodeSvgParseError::Err(e) } }
+#[no_mangle] pub extern "C" fn AzSvgXmlNode_parseFrom(svg_bytes: AzU8VecRef, parse_options: AzSvgParseOptions) -> AzResultSvgXmlNodeSvgParseError { ffi_guard!(AzResultSvgXmlNodeSvgParseError, { match azul_impl::svg::svgxmlnode_parse(svg_bytes.as_slice(), parse_options) { Ok(o) => azul_impl::svg::ResultSvgXmlNodeSvgParseError::Ok(unsafe { core::mem::transmute(o) }), Err(e) => azul_impl::svg::ResultSvgXmlNodeSvgParseError::Err(e) } }) }
 /// Destructor: Takes ownership of the `SvgXmlNode` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgXmlNode_delete(object: &mut AzSvgXmlNode) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzSvgXmlNode_delete(object: &mut AzSvgXmlNode) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzSvgXmlNode_deepCopy(object: &AzSvgXmlNode) -> AzSvgXmlNode { object.clone() }
 
@@ -3479,217 +4147,217 @@ pub use AzSvgXmlNodeTT as AzSvgXmlNode;
 pub use azul_impl::svg::SvgMultiPolygon as AzSvgMultiPolygonTT;
 pub use AzSvgMultiPolygonTT as AzSvgMultiPolygon;
 /// Returns the bounds of the polygon
-#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_getBounds(svgmultipolygon: &AzSvgMultiPolygon) -> AzSvgRect { svgmultipolygon.get_bounds() }
+#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_getBounds(svgmultipolygon: &AzSvgMultiPolygon) -> AzSvgRect { ffi_guard!(AzSvgRect, { svgmultipolygon.get_bounds() }) }
 /// Returns whether the polygon contains a point
-#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_containsPoint(svgmultipolygon: &AzSvgMultiPolygon, point: AzSvgPoint, fill_rule: AzSvgFillRule, tolerance: f32) -> bool { azul_impl::svg::polygon_contains_point(svgmultipolygon, point, fill_rule, tolerance) }
+#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_containsPoint(svgmultipolygon: &AzSvgMultiPolygon, point: AzSvgPoint, fill_rule: AzSvgFillRule, tolerance: f32) -> bool { ffi_guard!(bool, { azul_impl::svg::polygon_contains_point(svgmultipolygon, point, fill_rule, tolerance) }) }
 /// Unions two MultiPolygons, returns the unioned MultiPolygon
-#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_union(svgmultipolygon: &AzSvgMultiPolygon, other: AzSvgMultiPolygon) -> AzSvgMultiPolygon { azul_impl::svg::svg_multi_polygon_union(svgmultipolygon, &other) }
+#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_union(svgmultipolygon: &AzSvgMultiPolygon, other: AzSvgMultiPolygon) -> AzSvgMultiPolygon { ffi_guard!(AzSvgMultiPolygon, { azul_impl::svg::svg_multi_polygon_union(svgmultipolygon, &other) }) }
 /// Intersects two MultiPolygons, returns the intersected MultiPolygon
-#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_intersection(svgmultipolygon: &AzSvgMultiPolygon, other: AzSvgMultiPolygon) -> AzSvgMultiPolygon { azul_impl::svg::svg_multi_polygon_intersection(svgmultipolygon, &other) }
+#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_intersection(svgmultipolygon: &AzSvgMultiPolygon, other: AzSvgMultiPolygon) -> AzSvgMultiPolygon { ffi_guard!(AzSvgMultiPolygon, { azul_impl::svg::svg_multi_polygon_intersection(svgmultipolygon, &other) }) }
 /// Calculates the difference two MultiPolygons, returns a MultiPolygon
-#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_difference(svgmultipolygon: &AzSvgMultiPolygon, other: AzSvgMultiPolygon) -> AzSvgMultiPolygon { azul_impl::svg::svg_multi_polygon_difference(svgmultipolygon, &other) }
+#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_difference(svgmultipolygon: &AzSvgMultiPolygon, other: AzSvgMultiPolygon) -> AzSvgMultiPolygon { ffi_guard!(AzSvgMultiPolygon, { azul_impl::svg::svg_multi_polygon_difference(svgmultipolygon, &other) }) }
 /// Xors two MultiPolygons, returns a MultiPolygon
-#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_xor(svgmultipolygon: &AzSvgMultiPolygon, other: AzSvgMultiPolygon) -> AzSvgMultiPolygon { azul_impl::svg::svg_multi_polygon_xor(svgmultipolygon, &other) }
+#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_xor(svgmultipolygon: &AzSvgMultiPolygon, other: AzSvgMultiPolygon) -> AzSvgMultiPolygon { ffi_guard!(AzSvgMultiPolygon, { azul_impl::svg::svg_multi_polygon_xor(svgmultipolygon, &other) }) }
 /// Equivalent to the Rust `SvgMultiPolygon::tessellate_fill()` function.
-#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_tessellateFill(svgmultipolygon: &AzSvgMultiPolygon, fill_style: AzSvgFillStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_multi_polygon_fill(svgmultipolygon, fill_style) }
+#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_tessellateFill(svgmultipolygon: &AzSvgMultiPolygon, fill_style: AzSvgFillStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_multi_polygon_fill(svgmultipolygon, fill_style) }) }
 /// Equivalent to the Rust `SvgMultiPolygon::tessellate_stroke()` function.
-#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_tessellateStroke(svgmultipolygon: &AzSvgMultiPolygon, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_multi_polygon_stroke(svgmultipolygon, stroke_style) }
+#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_tessellateStroke(svgmultipolygon: &AzSvgMultiPolygon, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_multi_polygon_stroke(svgmultipolygon, stroke_style) }) }
 /// Destructor: Takes ownership of the `SvgMultiPolygon` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_delete(object: &mut AzSvgMultiPolygon) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgMultiPolygon_delete(object: &mut AzSvgMultiPolygon) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `SvgNode` struct
 pub use azul_impl::svg::SvgNode as AzSvgNodeTT;
 pub use AzSvgNodeTT as AzSvgNode;
 /// Equivalent to the Rust `SvgNode::tessellate_fill()` function.
-#[no_mangle] pub extern "C" fn AzSvgNode_tessellateFill(svgnode: &AzSvgNode, fill_style: AzSvgFillStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_node_fill(svgnode, fill_style) }
+#[no_mangle] pub extern "C" fn AzSvgNode_tessellateFill(svgnode: &AzSvgNode, fill_style: AzSvgFillStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_node_fill(svgnode, fill_style) }) }
 /// Equivalent to the Rust `SvgNode::tessellate_stroke()` function.
-#[no_mangle] pub extern "C" fn AzSvgNode_tessellateStroke(svgnode: &AzSvgNode, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_node_stroke(svgnode, stroke_style) }
+#[no_mangle] pub extern "C" fn AzSvgNode_tessellateStroke(svgnode: &AzSvgNode, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_node_stroke(svgnode, stroke_style) }) }
 /// Returns whether the shape is closed
-#[no_mangle] pub extern "C" fn AzSvgNode_isClosed(svgnode: &AzSvgNode) -> bool { svgnode.is_closed() }
+#[no_mangle] pub extern "C" fn AzSvgNode_isClosed(svgnode: &AzSvgNode) -> bool { ffi_guard!(bool, { svgnode.is_closed() }) }
 /// Returns the bounds of the polygon
-#[no_mangle] pub extern "C" fn AzSvgNode_containsPoint(svgnode: &AzSvgNode, point: AzSvgPoint, fill_rule: AzSvgFillRule, tolerance: f32) -> bool { azul_impl::svg::svg_node_contains_point(svgnode, point, fill_rule, tolerance) }
+#[no_mangle] pub extern "C" fn AzSvgNode_containsPoint(svgnode: &AzSvgNode, point: AzSvgPoint, fill_rule: AzSvgFillRule, tolerance: f32) -> bool { ffi_guard!(bool, { azul_impl::svg::svg_node_contains_point(svgnode, point, fill_rule, tolerance) }) }
 /// Equivalent to the Rust `SvgNode::get_bounds()` function.
-#[no_mangle] pub extern "C" fn AzSvgNode_getBounds(svgnode: &AzSvgNode) -> AzSvgRect { svgnode.get_bounds() }
+#[no_mangle] pub extern "C" fn AzSvgNode_getBounds(svgnode: &AzSvgNode) -> AzSvgRect { ffi_guard!(AzSvgRect, { svgnode.get_bounds() }) }
 /// Destructor: Takes ownership of the `SvgNode` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgNode_delete(object: &mut AzSvgNode) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgNode_delete(object: &mut AzSvgNode) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `SvgSimpleNode` struct
 pub use azul_impl::svg::SvgSimpleNode as AzSvgSimpleNodeTT;
 pub use AzSvgSimpleNodeTT as AzSvgSimpleNode;
 /// Equivalent to the Rust `SvgSimpleNode::get_bounds()` function.
-#[no_mangle] pub extern "C" fn AzSvgSimpleNode_getBounds(svgsimplenode: &AzSvgSimpleNode) -> AzSvgRect { svgsimplenode.get_bounds() }
+#[no_mangle] pub extern "C" fn AzSvgSimpleNode_getBounds(svgsimplenode: &AzSvgSimpleNode) -> AzSvgRect { ffi_guard!(AzSvgRect, { svgsimplenode.get_bounds() }) }
 /// Destructor: Takes ownership of the `SvgSimpleNode` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgSimpleNode_delete(object: &mut AzSvgSimpleNode) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgSimpleNode_delete(object: &mut AzSvgSimpleNode) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `SvgStyledNode` struct
 pub use azul_impl::svg::SvgStyledNode as AzSvgStyledNodeTT;
 pub use AzSvgStyledNodeTT as AzSvgStyledNode;
 /// Equivalent to the Rust `SvgStyledNode::tessellate()` function.
-#[no_mangle] pub extern "C" fn AzSvgStyledNode_tessellate(svgstylednode: &AzSvgStyledNode) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_styled_node(svgstylednode) }
+#[no_mangle] pub extern "C" fn AzSvgStyledNode_tessellate(svgstylednode: &AzSvgStyledNode) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_styled_node(svgstylednode) }) }
 /// Destructor: Takes ownership of the `SvgStyledNode` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgStyledNode_delete(object: &mut AzSvgStyledNode) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgStyledNode_delete(object: &mut AzSvgStyledNode) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `SvgCircle` struct
 pub use azul_impl::svg::SvgCircle as AzSvgCircleTT;
 pub use AzSvgCircleTT as AzSvgCircle;
 /// Equivalent to the Rust `SvgCircle::tessellate_fill()` function.
-#[no_mangle] pub extern "C" fn AzSvgCircle_tessellateFill(svgcircle: &AzSvgCircle, fill_style: AzSvgFillStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_circle_fill(svgcircle, fill_style) }
+#[no_mangle] pub extern "C" fn AzSvgCircle_tessellateFill(svgcircle: &AzSvgCircle, fill_style: AzSvgFillStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_circle_fill(svgcircle, fill_style) }) }
 /// Equivalent to the Rust `SvgCircle::tessellate_stroke()` function.
-#[no_mangle] pub extern "C" fn AzSvgCircle_tessellateStroke(svgcircle: &AzSvgCircle, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_circle_stroke(svgcircle, stroke_style) }
+#[no_mangle] pub extern "C" fn AzSvgCircle_tessellateStroke(svgcircle: &AzSvgCircle, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_circle_stroke(svgcircle, stroke_style) }) }
 
 /// Re-export of rust-allocated (stack based) `SvgPath` struct
 pub use azul_impl::svg::SvgPath as AzSvgPathTT;
 pub use AzSvgPathTT as AzSvgPath;
 /// Returns whether the path is closed
-#[no_mangle] pub extern "C" fn AzSvgPath_isClosed(svgpath: &AzSvgPath) -> bool { svgpath.is_closed() }
+#[no_mangle] pub extern "C" fn AzSvgPath_isClosed(svgpath: &AzSvgPath) -> bool { ffi_guard!(bool, { svgpath.is_closed() }) }
 /// Reverses the order of points in the path so that the path runs in the opposite direction afterwards
-#[no_mangle] pub extern "C" fn AzSvgPath_reverse(svgpath: &mut AzSvgPath) { svgpath.reverse() }
+#[no_mangle] pub extern "C" fn AzSvgPath_reverse(svgpath: &mut AzSvgPath) { ffi_guard!((), { svgpath.reverse() }) }
 /// Returns the first point of the path (or None if path has no items)
-#[no_mangle] pub extern "C" fn AzSvgPath_getStart(svgpath: &AzSvgPath) -> AzOptionSvgPoint { svgpath.get_start().into() }
+#[no_mangle] pub extern "C" fn AzSvgPath_getStart(svgpath: &AzSvgPath) -> AzOptionSvgPoint { ffi_guard!(AzOptionSvgPoint, { svgpath.get_start().into() }) }
 /// Returns the first point of the path (or None if path has no items)
-#[no_mangle] pub extern "C" fn AzSvgPath_getEnd(svgpath: &AzSvgPath) -> AzOptionSvgPoint { svgpath.get_end().into() }
+#[no_mangle] pub extern "C" fn AzSvgPath_getEnd(svgpath: &AzSvgPath) -> AzOptionSvgPoint { ffi_guard!(AzOptionSvgPoint, { svgpath.get_end().into() }) }
 /// Returns the axis-aligned bounding rect of this path
-#[no_mangle] pub extern "C" fn AzSvgPath_getBounds(svgpath: &mut AzSvgPath) -> AzSvgRect { svgpath.get_bounds() }
+#[no_mangle] pub extern "C" fn AzSvgPath_getBounds(svgpath: &mut AzSvgPath) -> AzSvgRect { ffi_guard!(AzSvgRect, { svgpath.get_bounds() }) }
 /// Adds a path to the end of the current path
-#[no_mangle] pub extern "C" fn AzSvgPath_joinWith(svgpath: &mut AzSvgPath, path: AzSvgPath) { svgpath.join_with(path); }
+#[no_mangle] pub extern "C" fn AzSvgPath_joinWith(svgpath: &mut AzSvgPath, path: AzSvgPath) { ffi_guard!((), { svgpath.join_with(path); }) }
 /// Offset the path by a certain distance. Will create bezier curves around the edges when the path is closed
-#[no_mangle] pub extern "C" fn AzSvgPath_offset(svgpath: &mut AzSvgPath, distance: f32, join: AzSvgLineJoin, cap: AzSvgLineCap) -> AzSvgPath { azul_impl::svg::svg_path_offset(svgpath, distance, join, cap) }
+#[no_mangle] pub extern "C" fn AzSvgPath_offset(svgpath: &mut AzSvgPath, distance: f32, join: AzSvgLineJoin, cap: AzSvgLineCap) -> AzSvgPath { ffi_guard!(AzSvgPath, { azul_impl::svg::svg_path_offset(svgpath, distance, join, cap) }) }
 /// Round the edges with a cubic curve
-#[no_mangle] pub extern "C" fn AzSvgPath_bevel(svgpath: &mut AzSvgPath, distance: f32) -> AzSvgPath { azul_impl::svg::svg_path_bevel(svgpath, distance) }
+#[no_mangle] pub extern "C" fn AzSvgPath_bevel(svgpath: &mut AzSvgPath, distance: f32) -> AzSvgPath { ffi_guard!(AzSvgPath, { azul_impl::svg::svg_path_bevel(svgpath, distance) }) }
 /// Equivalent to the Rust `SvgPath::tessellate_fill()` function.
-#[no_mangle] pub extern "C" fn AzSvgPath_tessellateFill(svgpath: &AzSvgPath, fill_style: AzSvgFillStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_path_fill(svgpath, fill_style) }
+#[no_mangle] pub extern "C" fn AzSvgPath_tessellateFill(svgpath: &AzSvgPath, fill_style: AzSvgFillStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_path_fill(svgpath, fill_style) }) }
 /// Equivalent to the Rust `SvgPath::tessellate_stroke()` function.
-#[no_mangle] pub extern "C" fn AzSvgPath_tessellateStroke(svgpath: &AzSvgPath, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_path_stroke(svgpath, stroke_style) }
+#[no_mangle] pub extern "C" fn AzSvgPath_tessellateStroke(svgpath: &AzSvgPath, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_path_stroke(svgpath, stroke_style) }) }
 /// Destructor: Takes ownership of the `SvgPath` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgPath_delete(object: &mut AzSvgPath) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgPath_delete(object: &mut AzSvgPath) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `SvgPathElement` struct
 pub use azul_impl::svg::SvgPathElement as AzSvgPathElementTT;
 pub use AzSvgPathElementTT as AzSvgPathElement;
 /// Reverses the order of points in the path so that the path runs in the opposite direction afterwards
-#[no_mangle] pub extern "C" fn AzSvgPathElement_reverse(svgpathelement: &mut AzSvgPathElement) { svgpathelement.reverse() }
+#[no_mangle] pub extern "C" fn AzSvgPathElement_reverse(svgpathelement: &mut AzSvgPathElement) { ffi_guard!((), { svgpathelement.reverse() }) }
 /// Returns the starting point of this item
-#[no_mangle] pub extern "C" fn AzSvgPathElement_getStart(svgpathelement: &AzSvgPathElement) -> AzSvgPoint { svgpathelement.get_start() }
+#[no_mangle] pub extern "C" fn AzSvgPathElement_getStart(svgpathelement: &AzSvgPathElement) -> AzSvgPoint { ffi_guard!(AzSvgPoint, { svgpathelement.get_start() }) }
 /// Returns the ending point of this item
-#[no_mangle] pub extern "C" fn AzSvgPathElement_getEnd(svgpathelement: &AzSvgPathElement) -> AzSvgPoint { svgpathelement.get_end() }
+#[no_mangle] pub extern "C" fn AzSvgPathElement_getEnd(svgpathelement: &AzSvgPathElement) -> AzSvgPoint { ffi_guard!(AzSvgPoint, { svgpathelement.get_end() }) }
 /// Returns the bounding box of this item
-#[no_mangle] pub extern "C" fn AzSvgPathElement_getBounds(svgpathelement: &AzSvgPathElement) -> AzSvgRect { svgpathelement.get_bounds() }
+#[no_mangle] pub extern "C" fn AzSvgPathElement_getBounds(svgpathelement: &AzSvgPathElement) -> AzSvgRect { ffi_guard!(AzSvgRect, { svgpathelement.get_bounds() }) }
 /// Returns the length of the line or curve
-#[no_mangle] pub extern "C" fn AzSvgPathElement_getLength(svgpathelement: &AzSvgPathElement) -> f64 { svgpathelement.get_length() }
+#[no_mangle] pub extern "C" fn AzSvgPathElement_getLength(svgpathelement: &AzSvgPathElement) -> f64 { ffi_guard!(f64, { svgpathelement.get_length() }) }
 /// Returns the interpolation value t (between 0 and 1) at the given offset from the line or curve start
-#[no_mangle] pub extern "C" fn AzSvgPathElement_getTAtOffset(svgpathelement: &AzSvgPathElement, offset: f64) -> f64 { svgpathelement.get_t_at_offset(offset) }
+#[no_mangle] pub extern "C" fn AzSvgPathElement_getTAtOffset(svgpathelement: &AzSvgPathElement, offset: f64) -> f64 { ffi_guard!(f64, { svgpathelement.get_t_at_offset(offset) }) }
 /// Returns the point on the line or curve at t (t = interpolation value between 0 and 1)
-#[no_mangle] pub extern "C" fn AzSvgPathElement_getXAtT(svgpathelement: &AzSvgPathElement, t: f64) -> f64 { svgpathelement.get_x_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgPathElement_getXAtT(svgpathelement: &AzSvgPathElement, t: f64) -> f64 { ffi_guard!(f64, { svgpathelement.get_x_at_t(t) }) }
 /// Returns the y position of the line or curve at t
-#[no_mangle] pub extern "C" fn AzSvgPathElement_getYAtT(svgpathelement: &AzSvgPathElement, t: f64) -> f64 { svgpathelement.get_y_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgPathElement_getYAtT(svgpathelement: &AzSvgPathElement, t: f64) -> f64 { ffi_guard!(f64, { svgpathelement.get_y_at_t(t) }) }
 /// Returns the angle in DEGREES of the line or curve at t (t = interpolation value between 0 and 1)
-#[no_mangle] pub extern "C" fn AzSvgPathElement_getTangentVectorAtT(svgpathelement: &AzSvgPathElement, t: f64) -> AzSvgVector { svgpathelement.get_tangent_vector_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgPathElement_getTangentVectorAtT(svgpathelement: &AzSvgPathElement, t: f64) -> AzSvgVector { ffi_guard!(AzSvgVector, { svgpathelement.get_tangent_vector_at_t(t) }) }
 /// Equivalent to the Rust `SvgPathElement::tessellate_stroke()` function.
-#[no_mangle] pub extern "C" fn AzSvgPathElement_tessellateStroke(svgpathelement: &AzSvgPathElement, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_svgpathelement_stroke(svgpathelement, stroke_style) }
+#[no_mangle] pub extern "C" fn AzSvgPathElement_tessellateStroke(svgpathelement: &AzSvgPathElement, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_svgpathelement_stroke(svgpathelement, stroke_style) }) }
 
 /// Re-export of rust-allocated (stack based) `SvgPoint` struct
 pub use azul_impl::svg::SvgPoint as AzSvgPointTT;
 pub use AzSvgPointTT as AzSvgPoint;
 /// Returns the euclidean distance to some other point
-#[no_mangle] pub extern "C" fn AzSvgPoint_distance(svgpoint: &AzSvgPoint, other: AzSvgPoint) -> f64 { svgpoint.distance(other) }
+#[no_mangle] pub extern "C" fn AzSvgPoint_distance(svgpoint: &AzSvgPoint, other: AzSvgPoint) -> f64 { ffi_guard!(f64, { svgpoint.distance(other) }) }
 
 /// Re-export of rust-allocated (stack based) `SvgVector` struct
 pub use azul_impl::svg::SvgVector as AzSvgVectorTT;
 pub use AzSvgVectorTT as AzSvgVector;
 /// Returns the angle of this vector in degrees
-#[no_mangle] pub extern "C" fn AzSvgVector_angleDegrees(svgvector: &AzSvgVector) -> f64 { svgvector.angle_degrees() }
+#[no_mangle] pub extern "C" fn AzSvgVector_angleDegrees(svgvector: &AzSvgVector) -> f64 { ffi_guard!(f64, { svgvector.angle_degrees() }) }
 /// Normalizes the vector, returning the normalized vector
-#[no_mangle] pub extern "C" fn AzSvgVector_normalize(svgvector: &AzSvgVector) -> AzSvgVector { svgvector.normalize() }
+#[no_mangle] pub extern "C" fn AzSvgVector_normalize(svgvector: &AzSvgVector) -> AzSvgVector { ffi_guard!(AzSvgVector, { svgvector.normalize() }) }
 /// Rotates the vector 90 degrees counter clockwise, returning the rotated vector
-#[no_mangle] pub extern "C" fn AzSvgVector_rotate90DegCcw(svgvector: &AzSvgVector) -> AzSvgVector { svgvector.rotate_90deg_ccw() }
+#[no_mangle] pub extern "C" fn AzSvgVector_rotate90DegCcw(svgvector: &AzSvgVector) -> AzSvgVector { ffi_guard!(AzSvgVector, { svgvector.rotate_90deg_ccw() }) }
 
 /// Re-export of rust-allocated (stack based) `SvgLine` struct
 pub use azul_impl::svg::SvgLine as AzSvgLineTT;
 pub use AzSvgLineTT as AzSvgLine;
 /// Reverses the order of points in the path so that the path runs in the opposite direction afterwards
-#[no_mangle] pub extern "C" fn AzSvgLine_reverse(svgline: &mut AzSvgLine) { svgline.reverse() }
+#[no_mangle] pub extern "C" fn AzSvgLine_reverse(svgline: &mut AzSvgLine) { ffi_guard!((), { svgline.reverse() }) }
 /// Returns the starting point of this item
-#[no_mangle] pub extern "C" fn AzSvgLine_getStart(svgline: &AzSvgLine) -> AzSvgPoint { svgline.get_start() }
+#[no_mangle] pub extern "C" fn AzSvgLine_getStart(svgline: &AzSvgLine) -> AzSvgPoint { ffi_guard!(AzSvgPoint, { svgline.get_start() }) }
 /// Returns the ending point of this item
-#[no_mangle] pub extern "C" fn AzSvgLine_getEnd(svgline: &AzSvgLine) -> AzSvgPoint { svgline.get_end() }
+#[no_mangle] pub extern "C" fn AzSvgLine_getEnd(svgline: &AzSvgLine) -> AzSvgPoint { ffi_guard!(AzSvgPoint, { svgline.get_end() }) }
 /// Returns the bounding box of this item
-#[no_mangle] pub extern "C" fn AzSvgLine_getBounds(svgline: &AzSvgLine) -> AzSvgRect { svgline.get_bounds() }
+#[no_mangle] pub extern "C" fn AzSvgLine_getBounds(svgline: &AzSvgLine) -> AzSvgRect { ffi_guard!(AzSvgRect, { svgline.get_bounds() }) }
 /// Returns the length of the line or curve
-#[no_mangle] pub extern "C" fn AzSvgLine_getLength(svgline: &AzSvgLine) -> f64 { svgline.get_length() }
+#[no_mangle] pub extern "C" fn AzSvgLine_getLength(svgline: &AzSvgLine) -> f64 { ffi_guard!(f64, { svgline.get_length() }) }
 /// Returns the interpolation value t (between 0 and 1) at the given offset from the line or curve start
-#[no_mangle] pub extern "C" fn AzSvgLine_getTAtOffset(svgline: &AzSvgLine, offset: f64) -> f64 { svgline.get_t_at_offset(offset) }
+#[no_mangle] pub extern "C" fn AzSvgLine_getTAtOffset(svgline: &AzSvgLine, offset: f64) -> f64 { ffi_guard!(f64, { svgline.get_t_at_offset(offset) }) }
 /// Returns the point on the line or curve at t (t = interpolation value between 0 and 1)
-#[no_mangle] pub extern "C" fn AzSvgLine_getXAtT(svgline: &AzSvgLine, t: f64) -> f64 { svgline.get_x_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgLine_getXAtT(svgline: &AzSvgLine, t: f64) -> f64 { ffi_guard!(f64, { svgline.get_x_at_t(t) }) }
 /// Returns the y position of the line or curve at t
-#[no_mangle] pub extern "C" fn AzSvgLine_getYAtT(svgline: &AzSvgLine, t: f64) -> f64 { svgline.get_y_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgLine_getYAtT(svgline: &AzSvgLine, t: f64) -> f64 { ffi_guard!(f64, { svgline.get_y_at_t(t) }) }
 /// Returns the angle in DEGREES of the line or curve at t (t = interpolation value between 0 and 1)
-#[no_mangle] pub extern "C" fn AzSvgLine_getTangentVectorAtT(svgline: &AzSvgLine, t: f64) -> AzSvgVector { svgline.get_tangent_vector_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgLine_getTangentVectorAtT(svgline: &AzSvgLine, t: f64) -> AzSvgVector { ffi_guard!(AzSvgVector, { svgline.get_tangent_vector_at_t(t) }) }
 /// Intersect two lines EVEN IF THEY ARE DISTINCT. Only returns None on parallel lines (never intersect)
-#[no_mangle] pub extern "C" fn AzSvgLine_intersect(svgline: &AzSvgLine, other: AzSvgLine) -> AzOptionSvgPoint { azul_impl::svg::raw_line_intersection(svgline, &other).into() }
+#[no_mangle] pub extern "C" fn AzSvgLine_intersect(svgline: &AzSvgLine, other: AzSvgLine) -> AzOptionSvgPoint { ffi_guard!(AzOptionSvgPoint, { azul_impl::svg::raw_line_intersection(svgline, &other).into() }) }
 /// Equivalent to the Rust `SvgLine::tessellate_stroke()` function.
-#[no_mangle] pub extern "C" fn AzSvgLine_tessellateStroke(svgline: &AzSvgLine, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_line_stroke(svgline, stroke_style) }
+#[no_mangle] pub extern "C" fn AzSvgLine_tessellateStroke(svgline: &AzSvgLine, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_line_stroke(svgline, stroke_style) }) }
 
 /// Re-export of rust-allocated (stack based) `SvgQuadraticCurve` struct
 pub use azul_impl::svg::SvgQuadraticCurve as AzSvgQuadraticCurveTT;
 pub use AzSvgQuadraticCurveTT as AzSvgQuadraticCurve;
 /// Reverses the order of points in the curve so that the curve runs in the opposite direction afterwards
-#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_reverse(svgquadraticcurve: &mut AzSvgQuadraticCurve) { svgquadraticcurve.reverse() }
+#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_reverse(svgquadraticcurve: &mut AzSvgQuadraticCurve) { ffi_guard!((), { svgquadraticcurve.reverse() }) }
 /// Returns the starting point of this item
-#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getStart(svgquadraticcurve: &AzSvgQuadraticCurve) -> AzSvgPoint { svgquadraticcurve.get_start() }
+#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getStart(svgquadraticcurve: &AzSvgQuadraticCurve) -> AzSvgPoint { ffi_guard!(AzSvgPoint, { svgquadraticcurve.get_start() }) }
 /// Returns the ending point of this item
-#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getEnd(svgquadraticcurve: &AzSvgQuadraticCurve) -> AzSvgPoint { svgquadraticcurve.get_end() }
+#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getEnd(svgquadraticcurve: &AzSvgQuadraticCurve) -> AzSvgPoint { ffi_guard!(AzSvgPoint, { svgquadraticcurve.get_end() }) }
 /// Returns the bounding box of this item
-#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getBounds(svgquadraticcurve: &AzSvgQuadraticCurve) -> AzSvgRect { svgquadraticcurve.get_bounds() }
+#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getBounds(svgquadraticcurve: &AzSvgQuadraticCurve) -> AzSvgRect { ffi_guard!(AzSvgRect, { svgquadraticcurve.get_bounds() }) }
 /// Returns the length of the line or curve
-#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getLength(svgquadraticcurve: &AzSvgQuadraticCurve) -> f64 { svgquadraticcurve.get_length() }
+#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getLength(svgquadraticcurve: &AzSvgQuadraticCurve) -> f64 { ffi_guard!(f64, { svgquadraticcurve.get_length() }) }
 /// Returns the interpolation value t (between 0 and 1) at the given offset from the line or curve start
-#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getTAtOffset(svgquadraticcurve: &AzSvgQuadraticCurve, offset: f64) -> f64 { svgquadraticcurve.get_t_at_offset(offset) }
+#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getTAtOffset(svgquadraticcurve: &AzSvgQuadraticCurve, offset: f64) -> f64 { ffi_guard!(f64, { svgquadraticcurve.get_t_at_offset(offset) }) }
 /// Returns the point on the line or curve at t (t = interpolation value between 0 and 1)
-#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getXAtT(svgquadraticcurve: &AzSvgQuadraticCurve, t: f64) -> f64 { svgquadraticcurve.get_x_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getXAtT(svgquadraticcurve: &AzSvgQuadraticCurve, t: f64) -> f64 { ffi_guard!(f64, { svgquadraticcurve.get_x_at_t(t) }) }
 /// Returns the y position of the line or curve at t
-#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getYAtT(svgquadraticcurve: &AzSvgQuadraticCurve, t: f64) -> f64 { svgquadraticcurve.get_y_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getYAtT(svgquadraticcurve: &AzSvgQuadraticCurve, t: f64) -> f64 { ffi_guard!(f64, { svgquadraticcurve.get_y_at_t(t) }) }
 /// Returns the angle in DEGREES of the line or curve at t (t = interpolation value between 0 and 1)
-#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getTangentVectorAtT(svgquadraticcurve: &AzSvgQuadraticCurve, t: f64) -> AzSvgVector { svgquadraticcurve.get_tangent_vector_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_getTangentVectorAtT(svgquadraticcurve: &AzSvgQuadraticCurve, t: f64) -> AzSvgVector { ffi_guard!(AzSvgVector, { svgquadraticcurve.get_tangent_vector_at_t(t) }) }
 /// Equivalent to the Rust `SvgQuadraticCurve::tessellate_stroke()` function.
-#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_tessellateStroke(svgquadraticcurve: &AzSvgQuadraticCurve, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_quadraticcurve_stroke(svgquadraticcurve, stroke_style) }
+#[no_mangle] pub extern "C" fn AzSvgQuadraticCurve_tessellateStroke(svgquadraticcurve: &AzSvgQuadraticCurve, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_quadraticcurve_stroke(svgquadraticcurve, stroke_style) }) }
 
 /// Re-export of rust-allocated (stack based) `SvgCubicCurve` struct
 pub use azul_impl::svg::SvgCubicCurve as AzSvgCubicCurveTT;
 pub use AzSvgCubicCurveTT as AzSvgCubicCurve;
 /// Reverses the order of points in the curve so that the curve runs in the opposite direction afterwards
-#[no_mangle] pub extern "C" fn AzSvgCubicCurve_reverse(svgcubiccurve: &mut AzSvgCubicCurve) { svgcubiccurve.reverse() }
+#[no_mangle] pub extern "C" fn AzSvgCubicCurve_reverse(svgcubiccurve: &mut AzSvgCubicCurve) { ffi_guard!((), { svgcubiccurve.reverse() }) }
 /// Returns the starting point of this item
-#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getStart(svgcubiccurve: &AzSvgCubicCurve) -> AzSvgPoint { svgcubiccurve.get_start() }
+#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getStart(svgcubiccurve: &AzSvgCubicCurve) -> AzSvgPoint { ffi_guard!(AzSvgPoint, { svgcubiccurve.get_start() }) }
 /// Returns the ending point of this item
-#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getEnd(svgcubiccurve: &AzSvgCubicCurve) -> AzSvgPoint { svgcubiccurve.get_end() }
+#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getEnd(svgcubiccurve: &AzSvgCubicCurve) -> AzSvgPoint { ffi_guard!(AzSvgPoint, { svgcubiccurve.get_end() }) }
 /// Returns the bounding box of this item
-#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getBounds(svgcubiccurve: &AzSvgCubicCurve) -> AzSvgRect { svgcubiccurve.get_bounds() }
+#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getBounds(svgcubiccurve: &AzSvgCubicCurve) -> AzSvgRect { ffi_guard!(AzSvgRect, { svgcubiccurve.get_bounds() }) }
 /// Returns the length of the line or curve
-#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getLength(svgcubiccurve: &AzSvgCubicCurve) -> f64 { svgcubiccurve.get_length() }
+#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getLength(svgcubiccurve: &AzSvgCubicCurve) -> f64 { ffi_guard!(f64, { svgcubiccurve.get_length() }) }
 /// Returns the interpolation value t (between 0 and 1) at the given offset from the line or curve start
-#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getTAtOffset(svgcubiccurve: &AzSvgCubicCurve, offset: f64) -> f64 { svgcubiccurve.get_t_at_offset(offset) }
+#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getTAtOffset(svgcubiccurve: &AzSvgCubicCurve, offset: f64) -> f64 { ffi_guard!(f64, { svgcubiccurve.get_t_at_offset(offset) }) }
 /// Returns the point on the line or curve at t (t = interpolation value between 0 and 1)
-#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getXAtT(svgcubiccurve: &AzSvgCubicCurve, t: f64) -> f64 { svgcubiccurve.get_x_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getXAtT(svgcubiccurve: &AzSvgCubicCurve, t: f64) -> f64 { ffi_guard!(f64, { svgcubiccurve.get_x_at_t(t) }) }
 /// Returns the y position of the line or curve at t
-#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getYAtT(svgcubiccurve: &AzSvgCubicCurve, t: f64) -> f64 { svgcubiccurve.get_y_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getYAtT(svgcubiccurve: &AzSvgCubicCurve, t: f64) -> f64 { ffi_guard!(f64, { svgcubiccurve.get_y_at_t(t) }) }
 /// Returns the angle in DEGREES of the line or curve at t (t = interpolation value between 0 and 1)
-#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getTangentVectorAtT(svgcubiccurve: &AzSvgCubicCurve, t: f64) -> AzSvgVector { svgcubiccurve.get_tangent_vector_at_t(t) }
+#[no_mangle] pub extern "C" fn AzSvgCubicCurve_getTangentVectorAtT(svgcubiccurve: &AzSvgCubicCurve, t: f64) -> AzSvgVector { ffi_guard!(AzSvgVector, { svgcubiccurve.get_tangent_vector_at_t(t) }) }
 /// Equivalent to the Rust `SvgCubicCurve::tessellate_stroke()` function.
-#[no_mangle] pub extern "C" fn AzSvgCubicCurve_tessellateStroke(svgcubiccurve: &AzSvgCubicCurve, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_cubiccurve_stroke(svgcubiccurve, stroke_style) }
+#[no_mangle] pub extern "C" fn AzSvgCubicCurve_tessellateStroke(svgcubiccurve: &AzSvgCubicCurve, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_cubiccurve_stroke(svgcubiccurve, stroke_style) }) }
 
 /// Re-export of rust-allocated (stack based) `SvgRect` struct
 pub use azul_impl::svg::SvgRect as AzSvgRectTT;
 pub use AzSvgRectTT as AzSvgRect;
 /// Equivalent to the Rust `SvgRect::get_center()` function.
-#[no_mangle] pub extern "C" fn AzSvgRect_getCenter(svgrect: &AzSvgRect) -> AzSvgPoint { svgrect.get_center() }
+#[no_mangle] pub extern "C" fn AzSvgRect_getCenter(svgrect: &AzSvgRect) -> AzSvgPoint { ffi_guard!(AzSvgPoint, { svgrect.get_center() }) }
 /// Equivalent to the Rust `SvgRect::contains_point()` function.
-#[no_mangle] pub extern "C" fn AzSvgRect_containsPoint(svgrect: &AzSvgRect, point: AzSvgPoint) -> bool { svgrect.contains_point(point.x, point.y) }
+#[no_mangle] pub extern "C" fn AzSvgRect_containsPoint(svgrect: &AzSvgRect, point: AzSvgPoint) -> bool { ffi_guard!(bool, { svgrect.contains_point(point.x, point.y) }) }
 /// Equivalent to the Rust `SvgRect::expand()` function.
-#[no_mangle] pub extern "C" fn AzSvgRect_expand(svgrect: &AzSvgRect, padding_top: f32, padding_bottom: f32, padding_left: f32, padding_right: f32) -> AzSvgRect { svgrect.expand(padding_top, padding_bottom, padding_left, padding_right) }
+#[no_mangle] pub extern "C" fn AzSvgRect_expand(svgrect: &AzSvgRect, padding_top: f32, padding_bottom: f32, padding_left: f32, padding_right: f32) -> AzSvgRect { ffi_guard!(AzSvgRect, { svgrect.expand(padding_top, padding_bottom, padding_left, padding_right) }) }
 /// Equivalent to the Rust `SvgRect::tessellate_fill()` function.
-#[no_mangle] pub extern "C" fn AzSvgRect_tessellateFill(svgrect: &AzSvgRect, fill_style: AzSvgFillStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_rect_fill(svgrect, fill_style) }
+#[no_mangle] pub extern "C" fn AzSvgRect_tessellateFill(svgrect: &AzSvgRect, fill_style: AzSvgFillStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_rect_fill(svgrect, fill_style) }) }
 /// Equivalent to the Rust `SvgRect::tessellate_stroke()` function.
-#[no_mangle] pub extern "C" fn AzSvgRect_tessellateStroke(svgrect: &AzSvgRect, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { azul_impl::svg::tessellate_rect_stroke(svgrect, stroke_style) }
+#[no_mangle] pub extern "C" fn AzSvgRect_tessellateStroke(svgrect: &AzSvgRect, stroke_style: AzSvgStrokeStyle) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::tessellate_rect_stroke(svgrect, stroke_style) }) }
 
 /// Re-export of rust-allocated (stack based) `SvgColoredVertex` struct
 pub use azul_core::svg::SvgColoredVertex as AzSvgColoredVertexTT;
@@ -3699,26 +4367,30 @@ pub use AzSvgColoredVertexTT as AzSvgColoredVertex;
 pub use azul_core::svg::TessellatedColoredSvgNode as AzTessellatedColoredSvgNodeTT;
 pub use AzTessellatedColoredSvgNodeTT as AzTessellatedColoredSvgNode;
 /// Returns an empty buffer vertices / indices
-#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNode_empty() -> AzTessellatedColoredSvgNode { AzTessellatedColoredSvgNode::empty() }
+#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNode_empty() -> AzTessellatedColoredSvgNode { ffi_guard!(AzTessellatedColoredSvgNode, { AzTessellatedColoredSvgNode::empty() }) }
 /// Creates a new TessellatedColoredSvgNode by joining all the given nodes together into one array and inserting a `GL_RESTART_INDEX` (`u32::MAX`) into the indices (so that the resulting buffer can be drawn in one draw call).
-#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNode_fromNodes(nodes: AzTessellatedColoredSvgNodeVecRef) -> AzTessellatedColoredSvgNode { azul_impl::svg::join_tessellated_colored_nodes(nodes.as_slice()) }
+#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNode_fromNodes(nodes: AzTessellatedColoredSvgNodeVecRef) -> AzTessellatedColoredSvgNode { ffi_guard!(AzTessellatedColoredSvgNode, { azul_impl::svg::join_tessellated_colored_nodes(nodes.as_slice()) }) }
 /// Destructor: Takes ownership of the `TessellatedColoredSvgNode` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNode_delete(object: &mut AzTessellatedColoredSvgNode) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNode_delete(object: &mut AzTessellatedColoredSvgNode) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Rust wrapper over a `&[TessellatedColoredSvgNode]` or `&Vec<TessellatedColoredSvgNode>`
 pub use azul_core::svg::TessellatedColoredSvgNodeVecRef as AzTessellatedColoredSvgNodeVecRefTT;
 pub use AzTessellatedColoredSvgNodeVecRefTT as AzTessellatedColoredSvgNodeVecRef;
 /// Destructor: Takes ownership of the `TessellatedColoredSvgNodeVecRef` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNodeVecRef_delete(object: &mut AzTessellatedColoredSvgNodeVecRef) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNodeVecRef_delete(object: &mut AzTessellatedColoredSvgNodeVecRef) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TessellatedColoredGPUSvgNode` struct
 pub use azul_core::svg::TessellatedColoredGPUSvgNode as AzTessellatedColoredGPUSvgNodeTT;
 pub use AzTessellatedColoredGPUSvgNodeTT as AzTessellatedColoredGPUSvgNode;
 /// Creates a new `TessellatedColoredGPUSvgNode` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `TessellatedColoredGPUSvgNode::new()` constructor.
-#[no_mangle] pub extern "C" fn AzTessellatedColoredGPUSvgNode_new(tessellated_node: *const AzTessellatedColoredSvgNode, gl: AzGl) -> AzTessellatedColoredGPUSvgNode { AzTessellatedColoredGPUSvgNode::new(unsafe { &*tessellated_node }, gl) }
+///
+/// # Safety
+///
+/// `tessellated_node` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTessellatedColoredGPUSvgNode_new(tessellated_node: *const AzTessellatedColoredSvgNode, gl: AzGl) -> AzTessellatedColoredGPUSvgNode { ffi_guard!(AzTessellatedColoredGPUSvgNode, { AzTessellatedColoredGPUSvgNode::new(unsafe { &*tessellated_node }, gl) }) }
 /// Destructor: Takes ownership of the `TessellatedColoredGPUSvgNode` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTessellatedColoredGPUSvgNode_delete(object: &mut AzTessellatedColoredGPUSvgNode) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTessellatedColoredGPUSvgNode_delete(object: &mut AzTessellatedColoredGPUSvgNode) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `SvgVertex` struct
 pub use azul_impl::svg::SvgVertex as AzSvgVertexTT;
@@ -3728,35 +4400,39 @@ pub use AzSvgVertexTT as AzSvgVertex;
 pub use azul_impl::svg::TessellatedSvgNode as AzTessellatedSvgNodeTT;
 pub use AzTessellatedSvgNodeTT as AzTessellatedSvgNode;
 /// Returns an empty buffer vertices / indices
-#[no_mangle] pub extern "C" fn AzTessellatedSvgNode_empty() -> AzTessellatedSvgNode { AzTessellatedSvgNode::empty() }
+#[no_mangle] pub extern "C" fn AzTessellatedSvgNode_empty() -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { AzTessellatedSvgNode::empty() }) }
 /// Creates a new TessellatedSvgNode by joining all the given nodes together into one array and inserting a `GL_RESTART_INDEX` (`u32::MAX`) into the indices (so that the resulting buffer can be drawn in one draw call).
-#[no_mangle] pub extern "C" fn AzTessellatedSvgNode_fromNodes(nodes: AzTessellatedSvgNodeVecRef) -> AzTessellatedSvgNode { azul_impl::svg::join_tessellated_nodes(nodes.as_slice()) }
+#[no_mangle] pub extern "C" fn AzTessellatedSvgNode_fromNodes(nodes: AzTessellatedSvgNodeVecRef) -> AzTessellatedSvgNode { ffi_guard!(AzTessellatedSvgNode, { azul_impl::svg::join_tessellated_nodes(nodes.as_slice()) }) }
 /// Destructor: Takes ownership of the `TessellatedSvgNode` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTessellatedSvgNode_delete(object: &mut AzTessellatedSvgNode) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTessellatedSvgNode_delete(object: &mut AzTessellatedSvgNode) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Rust wrapper over a `&[TessellatedSvgNode]` or `&Vec<TessellatedSvgNode>`
 pub use azul_impl::svg::TessellatedSvgNodeVecRef as AzTessellatedSvgNodeVecRefTT;
 pub use AzTessellatedSvgNodeVecRefTT as AzTessellatedSvgNodeVecRef;
 /// Destructor: Takes ownership of the `TessellatedSvgNodeVecRef` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTessellatedSvgNodeVecRef_delete(object: &mut AzTessellatedSvgNodeVecRef) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTessellatedSvgNodeVecRef_delete(object: &mut AzTessellatedSvgNodeVecRef) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `TessellatedGPUSvgNode` struct
 pub use azul_impl::svg::TessellatedGPUSvgNode as AzTessellatedGPUSvgNodeTT;
 pub use AzTessellatedGPUSvgNodeTT as AzTessellatedGPUSvgNode;
 /// Creates a new `TessellatedGPUSvgNode` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `TessellatedGPUSvgNode::new()` constructor.
-#[no_mangle] pub extern "C" fn AzTessellatedGPUSvgNode_new(tessellated_node: *const AzTessellatedSvgNode, gl: AzGl) -> AzTessellatedGPUSvgNode { AzTessellatedGPUSvgNode::new(unsafe { &*tessellated_node }, gl) }
+///
+/// # Safety
+///
+/// `tessellated_node` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzTessellatedGPUSvgNode_new(tessellated_node: *const AzTessellatedSvgNode, gl: AzGl) -> AzTessellatedGPUSvgNode { ffi_guard!(AzTessellatedGPUSvgNode, { AzTessellatedGPUSvgNode::new(unsafe { &*tessellated_node }, gl) }) }
 /// Destructor: Takes ownership of the `TessellatedGPUSvgNode` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTessellatedGPUSvgNode_delete(object: &mut AzTessellatedGPUSvgNode) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTessellatedGPUSvgNode_delete(object: &mut AzTessellatedGPUSvgNode) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `SvgParseOptions` struct
 pub use azul_impl::svg::SvgParseOptions as AzSvgParseOptionsTT;
 pub use AzSvgParseOptionsTT as AzSvgParseOptions;
 /// Creates a new `SvgParseOptions` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `SvgParseOptions::default()` constructor.
-#[no_mangle] pub extern "C" fn AzSvgParseOptions_default() -> AzSvgParseOptions { AzSvgParseOptions::default() }
+#[no_mangle] pub extern "C" fn AzSvgParseOptions_default() -> AzSvgParseOptions { ffi_guard!(AzSvgParseOptions, { AzSvgParseOptions::default() }) }
 /// Destructor: Takes ownership of the `SvgParseOptions` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgParseOptions_delete(object: &mut AzSvgParseOptions) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgParseOptions_delete(object: &mut AzSvgParseOptions) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ShapeRendering` struct
 pub use azul_impl::svg::ShapeRendering as AzShapeRenderingTT;
@@ -3779,7 +4455,7 @@ pub use azul_impl::svg::SvgRenderOptions as AzSvgRenderOptionsTT;
 pub use AzSvgRenderOptionsTT as AzSvgRenderOptions;
 /// Creates a new `SvgRenderOptions` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `SvgRenderOptions::default()` constructor.
-#[no_mangle] pub extern "C" fn AzSvgRenderOptions_default() -> AzSvgRenderOptions { AzSvgRenderOptions::default() }
+#[no_mangle] pub extern "C" fn AzSvgRenderOptions_default() -> AzSvgRenderOptions { ffi_guard!(AzSvgRenderOptions, { AzSvgRenderOptions::default() }) }
 
 /// Re-export of rust-allocated (stack based) `SvgRenderTransform` struct
 pub use azul_impl::svg::SvgRenderTransform as AzSvgRenderTransformTT;
@@ -3814,14 +4490,14 @@ pub use azul_impl::svg::SvgFillStyle as AzSvgFillStyleTT;
 pub use AzSvgFillStyleTT as AzSvgFillStyle;
 /// Creates a new `SvgFillStyle` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `SvgFillStyle::default()` constructor.
-#[no_mangle] pub extern "C" fn AzSvgFillStyle_default() -> AzSvgFillStyle { AzSvgFillStyle::default() }
+#[no_mangle] pub extern "C" fn AzSvgFillStyle_default() -> AzSvgFillStyle { ffi_guard!(AzSvgFillStyle, { AzSvgFillStyle::default() }) }
 
 /// Re-export of rust-allocated (stack based) `SvgStrokeStyle` struct
 pub use azul_impl::svg::SvgStrokeStyle as AzSvgStrokeStyleTT;
 pub use AzSvgStrokeStyleTT as AzSvgStrokeStyle;
 /// Creates a new `SvgStrokeStyle` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `SvgStrokeStyle::default()` constructor.
-#[no_mangle] pub extern "C" fn AzSvgStrokeStyle_default() -> AzSvgStrokeStyle { AzSvgStrokeStyle::default() }
+#[no_mangle] pub extern "C" fn AzSvgStrokeStyle_default() -> AzSvgStrokeStyle { ffi_guard!(AzSvgStrokeStyle, { AzSvgStrokeStyle::default() }) }
 
 /// Re-export of rust-allocated (stack based) `SvgLineJoin` struct
 pub use azul_impl::svg::SvgLineJoin as AzSvgLineJoinTT;
@@ -3839,35 +4515,35 @@ pub use AzSvgDashPatternTT as AzSvgDashPattern;
 pub use azul_impl::xml::Xml as AzXmlTT;
 pub use AzXmlTT as AzXml;
 /// Parses an XML document with one or more root nodes
-#[no_mangle] pub extern "C" fn AzXml_fromStr(xml_string: AzRefstr) -> AzResultXmlXmlError { azul_impl::xml::parse_xml(xml_string.as_str()).into() }
+#[no_mangle] pub extern "C" fn AzXml_fromStr(xml_string: AzRefstr) -> AzResultXmlXmlError { ffi_guard!(AzResultXmlXmlError, { azul_impl::xml::parse_xml(xml_string.as_str()).into() }) }
 /// Destructor: Takes ownership of the `Xml` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzXml_delete(object: &mut AzXml) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzXml_delete(object: &mut AzXml) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `XmlNode` struct
 pub use azul_impl::xml::XmlNode as AzXmlNodeTT;
 pub use AzXmlNodeTT as AzXmlNode;
 /// Destructor: Takes ownership of the `XmlNode` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzXmlNode_delete(object: &mut AzXmlNode) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzXmlNode_delete(object: &mut AzXmlNode) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// **Reference-counted** file handle
 pub use azul_impl::file::File as AzFileTT;
 pub use AzFileTT as AzFile;
 /// Opens a file at the given path. If the file exists, replaces it with a new file
-#[no_mangle] pub extern "C" fn AzFile_open(path: AzString) -> AzOptionFile { AzFile::open(path.as_str()).into() }
+#[no_mangle] pub extern "C" fn AzFile_open(path: AzString) -> AzOptionFile { ffi_guard!(AzOptionFile, { AzFile::open(path.as_str()).into() }) }
 /// Creates a file at the given path. If the file exists, replaces it with a new file
-#[no_mangle] pub extern "C" fn AzFile_create(path: AzString) -> AzOptionFile { AzFile::create(path.as_str()).into() }
+#[no_mangle] pub extern "C" fn AzFile_create(path: AzString) -> AzOptionFile { ffi_guard!(AzOptionFile, { AzFile::create(path.as_str()).into() }) }
 /// Reads the file to a UTF8-encoded String, returns None if the file can't be decoded correctly
-#[no_mangle] pub extern "C" fn AzFile_readToString(file: &mut AzFile) -> AzOptionString { file.read_to_string().into() }
+#[no_mangle] pub extern "C" fn AzFile_readToString(file: &mut AzFile) -> AzOptionString { ffi_guard!(AzOptionString, { file.read_to_string().into() }) }
 /// Reads the file as bytes, returns None if the file can't be decoded correctly
-#[no_mangle] pub extern "C" fn AzFile_readToBytes(file: &mut AzFile) -> AzOptionU8Vec { file.read_to_bytes().into() }
+#[no_mangle] pub extern "C" fn AzFile_readToBytes(file: &mut AzFile) -> AzOptionU8Vec { ffi_guard!(AzOptionU8Vec, { file.read_to_bytes().into() }) }
 /// Writes a string to the file, synchronizes the results before returning
-#[no_mangle] pub extern "C" fn AzFile_writeString(file: &mut AzFile, bytes: AzRefstr) -> bool { file.write_string(bytes.as_str()).is_some() }
+#[no_mangle] pub extern "C" fn AzFile_writeString(file: &mut AzFile, bytes: AzRefstr) -> bool { ffi_guard!(bool, { file.write_string(bytes.as_str()).is_some() }) }
 /// Writes some bytes to the file, synchronizes the results before returning
-#[no_mangle] pub extern "C" fn AzFile_writeBytes(file: &mut AzFile, bytes: AzU8VecRef) -> bool { file.write_bytes(bytes.as_slice()).is_some() }
+#[no_mangle] pub extern "C" fn AzFile_writeBytes(file: &mut AzFile, bytes: AzU8VecRef) -> bool { ffi_guard!(bool, { file.write_bytes(bytes.as_slice()).is_some() }) }
 /// Destructor, closes the file handle
-#[no_mangle] pub extern "C" fn AzFile_close(file: &mut AzFile) { file.clone().close() }
+#[no_mangle] pub extern "C" fn AzFile_close(file: &mut AzFile) { ffi_guard!((), { file.clone().close() }) }
 /// Destructor: Takes ownership of the `File` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFile_delete(object: &mut AzFile) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzFile_delete(object: &mut AzFile) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzFile_deepCopy(object: &AzFile) -> AzFile { object.clone() }
 
@@ -3875,19 +4551,19 @@ pub use AzFileTT as AzFile;
 pub use azul_impl::dialogs::MsgBox as AzMsgBoxTT;
 pub use AzMsgBoxTT as AzMsgBox;
 /// Opens an informational message box with only an "OK" button
-#[no_mangle] pub extern "C" fn AzMsgBox_ok(icon: AzMsgBoxIcon, title: AzString, message: AzString) -> bool { azul_impl::dialogs::msg_box_ok(title.as_str(), message.as_str(), icon.into()); true }
+#[no_mangle] pub extern "C" fn AzMsgBox_ok(icon: AzMsgBoxIcon, title: AzString, message: AzString) -> bool { ffi_guard!(bool, { azul_impl::dialogs::msg_box_ok(title.as_str(), message.as_str(), icon.into()); true }) }
 /// Shorthand for `MsgBox::ok("Info", $message, Icon::Info)`
-#[no_mangle] pub extern "C" fn AzMsgBox_info(message: AzString) -> bool { azul_impl::dialogs::msg_box_ok("Info", message.as_str(), AzMsgBoxIcon::Info.into()); true }
+#[no_mangle] pub extern "C" fn AzMsgBox_info(message: AzString) -> bool { ffi_guard!(bool, { azul_impl::dialogs::msg_box_ok("Info", message.as_str(), AzMsgBoxIcon::Info.into()); true }) }
 /// Shorthand for `MsgBox::ok("Warning", $message, Icon::Warning)`
-#[no_mangle] pub extern "C" fn AzMsgBox_warning(message: AzString) -> bool { azul_impl::dialogs::msg_box_ok("Warning", message.as_str(), AzMsgBoxIcon::Warning.into()); true }
+#[no_mangle] pub extern "C" fn AzMsgBox_warning(message: AzString) -> bool { ffi_guard!(bool, { azul_impl::dialogs::msg_box_ok("Warning", message.as_str(), AzMsgBoxIcon::Warning.into()); true }) }
 /// Shorthand for `MsgBox::ok("Error", $message, Icon::Error)`
-#[no_mangle] pub extern "C" fn AzMsgBox_error(message: AzString) -> bool { azul_impl::dialogs::msg_box_ok("Error", message.as_str(), AzMsgBoxIcon::Error.into()); true }
+#[no_mangle] pub extern "C" fn AzMsgBox_error(message: AzString) -> bool { ffi_guard!(bool, { azul_impl::dialogs::msg_box_ok("Error", message.as_str(), AzMsgBoxIcon::Error.into()); true }) }
 /// Shorthand for `MsgBox::ok("Question", $message, Icon::Question)`
-#[no_mangle] pub extern "C" fn AzMsgBox_question(message: AzString) -> bool { azul_impl::dialogs::msg_box_ok("Question", message.as_str(), AzMsgBoxIcon::Question.into()); true }
+#[no_mangle] pub extern "C" fn AzMsgBox_question(message: AzString) -> bool { ffi_guard!(bool, { azul_impl::dialogs::msg_box_ok("Question", message.as_str(), AzMsgBoxIcon::Question.into()); true }) }
 /// Opens a ok / cancel message box. Blocks the current thread.
-#[no_mangle] pub extern "C" fn AzMsgBox_okCancel(icon: AzMsgBoxIcon, title: AzString, message: AzString, default_value: AzMsgBoxOkCancel) -> AzMsgBoxOkCancel { azul_impl::dialogs::msg_box_ok_cancel(title.as_str(), message.as_str(), icon.into(), default_value) }
+#[no_mangle] pub extern "C" fn AzMsgBox_okCancel(icon: AzMsgBoxIcon, title: AzString, message: AzString, default_value: AzMsgBoxOkCancel) -> AzMsgBoxOkCancel { ffi_guard!(AzMsgBoxOkCancel, { azul_impl::dialogs::msg_box_ok_cancel(title.as_str(), message.as_str(), icon.into(), default_value) }) }
 /// Opens a yes / no message box. Blocks the current thread.
-#[no_mangle] pub extern "C" fn AzMsgBox_yesNo(icon: AzMsgBoxIcon, title: AzString, message: AzString, default_value: AzMsgBoxYesNo) -> AzMsgBoxYesNo { azul_impl::dialogs::msg_box_yes_no(title.as_str(), message.as_str(), icon.into(), default_value) }
+#[no_mangle] pub extern "C" fn AzMsgBox_yesNo(icon: AzMsgBoxIcon, title: AzString, message: AzString, default_value: AzMsgBoxYesNo) -> AzMsgBoxYesNo { ffi_guard!(AzMsgBoxYesNo, { azul_impl::dialogs::msg_box_yes_no(title.as_str(), message.as_str(), icon.into(), default_value) }) }
 
 /// Type of message box icon
 pub use azul_impl::dialogs::MsgBoxIcon as AzMsgBoxIconTT;
@@ -3905,37 +4581,37 @@ pub use AzMsgBoxOkCancelTT as AzMsgBoxOkCancel;
 pub use azul_impl::dialogs::FileDialog as AzFileDialogTT;
 pub use AzFileDialogTT as AzFileDialog;
 /// Select a single file using the system-native file picker. Blocks the current thread.
-#[no_mangle] pub extern "C" fn AzFileDialog_selectFile(title: AzString, default_path: AzOptionString, filter_list: AzOptionFileTypeList) -> AzOptionString { azul_impl::dialogs::open_file_dialog(title.as_str(), default_path.as_ref().map(|s| s.as_str()), filter_list.into_option().map(|s| s.into())).into() }
+#[no_mangle] pub extern "C" fn AzFileDialog_selectFile(title: AzString, default_path: AzOptionString, filter_list: AzOptionFileTypeList) -> AzOptionString { ffi_guard!(AzOptionString, { azul_impl::dialogs::open_file_dialog(title.as_str(), default_path.as_ref().map(|s| s.as_str()), filter_list.into_option().map(|s| s.into())).into() }) }
 /// Select multiple files using the system-native file picker. Blocks the current thread.
-#[no_mangle] pub extern "C" fn AzFileDialog_selectMultipleFiles(title: AzString, default_path: AzOptionString, filter_list: AzOptionFileTypeList) -> AzOptionStringVec { azul_impl::dialogs::open_multiple_files_dialog(title.as_str(), default_path.as_ref().map(|s| s.as_str()), filter_list.into_option().map(|s| s.into())).into() }
+#[no_mangle] pub extern "C" fn AzFileDialog_selectMultipleFiles(title: AzString, default_path: AzOptionString, filter_list: AzOptionFileTypeList) -> AzOptionStringVec { ffi_guard!(AzOptionStringVec, { azul_impl::dialogs::open_multiple_files_dialog(title.as_str(), default_path.as_ref().map(|s| s.as_str()), filter_list.into_option().map(|s| s.into())).into() }) }
 /// Open a dialog prompting the user to select a directory to open. Blocks the current thread.
-#[no_mangle] pub extern "C" fn AzFileDialog_selectFolder(title: AzString, default_path: AzOptionString) -> AzOptionString { azul_impl::dialogs::open_directory_dialog(title.as_str(), default_path.as_ref().map(|s| s.as_str())).into() }
+#[no_mangle] pub extern "C" fn AzFileDialog_selectFolder(title: AzString, default_path: AzOptionString) -> AzOptionString { ffi_guard!(AzOptionString, { azul_impl::dialogs::open_directory_dialog(title.as_str(), default_path.as_ref().map(|s| s.as_str())).into() }) }
 /// Open a dialog prompting the user to save a file. Blocks the current thread.
-#[no_mangle] pub extern "C" fn AzFileDialog_saveFile(title: AzString, default_path: AzOptionString) -> AzOptionString { azul_impl::dialogs::save_file_dialog(title.as_str(), default_path.as_ref().map(|s| s.as_str())).into() }
+#[no_mangle] pub extern "C" fn AzFileDialog_saveFile(title: AzString, default_path: AzOptionString) -> AzOptionString { ffi_guard!(AzOptionString, { azul_impl::dialogs::save_file_dialog(title.as_str(), default_path.as_ref().map(|s| s.as_str())).into() }) }
 
 /// Re-export of rust-allocated (stack based) `FileTypeList` struct
 pub use azul_impl::dialogs::FileTypeList as AzFileTypeListTT;
 pub use AzFileTypeListTT as AzFileTypeList;
 /// Destructor: Takes ownership of the `FileTypeList` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFileTypeList_delete(object: &mut AzFileTypeList) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFileTypeList_delete(object: &mut AzFileTypeList) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ColorPickerDialog` struct
 pub use azul_impl::dialogs::ColorPickerDialog as AzColorPickerDialogTT;
 pub use AzColorPickerDialogTT as AzColorPickerDialog;
 /// Opens a system-native color picker dialog
-#[no_mangle] pub extern "C" fn AzColorPickerDialog_open(title: AzString, default_color: AzOptionColorU) -> AzOptionColorU { azul_impl::dialogs::color_picker_dialog(title.as_str(), default_color.into_option().map(|s| s.into())).into() }
+#[no_mangle] pub extern "C" fn AzColorPickerDialog_open(title: AzString, default_color: AzOptionColorU) -> AzOptionColorU { ffi_guard!(AzOptionColorU, { azul_impl::dialogs::color_picker_dialog(title.as_str(), default_color.into_option().map(|s| s.into())).into() }) }
 
 /// Connection to the system clipboard, on some systems this connection can be cached
 pub use azul_impl::app::Clipboard as AzSystemClipboardTT;
 pub use AzSystemClipboardTT as AzSystemClipboard;
 /// Creates a new connection to the system clipboard manager
-#[no_mangle] pub extern "C" fn AzSystemClipboard_new() -> AzOptionSystemClipboard { AzSystemClipboard::new().into() }
+#[no_mangle] pub extern "C" fn AzSystemClipboard_new() -> AzOptionSystemClipboard { ffi_guard!(AzOptionSystemClipboard, { AzSystemClipboard::new().into() }) }
 /// Returns the system clipboard contents or `None` if the clipboard is empty or there was an error
-#[no_mangle] pub extern "C" fn AzSystemClipboard_getStringContents(systemclipboard: &AzSystemClipboard) -> AzOptionString { systemclipboard.get_clipboard_string().into() }
+#[no_mangle] pub extern "C" fn AzSystemClipboard_getStringContents(systemclipboard: &AzSystemClipboard) -> AzOptionString { ffi_guard!(AzOptionString, { systemclipboard.get_clipboard_string().into() }) }
 /// Sets the system clipboard contents to the new string, returns true if the system clipboard was updated
-#[no_mangle] pub extern "C" fn AzSystemClipboard_setStringContents(systemclipboard: &mut AzSystemClipboard, contents: AzString) -> bool { systemclipboard.set_clipboard_string(contents).is_some() }
+#[no_mangle] pub extern "C" fn AzSystemClipboard_setStringContents(systemclipboard: &mut AzSystemClipboard, contents: AzString) -> bool { ffi_guard!(bool, { systemclipboard.set_clipboard_string(contents).is_some() }) }
 /// Destructor: Takes ownership of the `SystemClipboard` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSystemClipboard_delete(object: &mut AzSystemClipboard) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzSystemClipboard_delete(object: &mut AzSystemClipboard) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzSystemClipboard_deepCopy(object: &AzSystemClipboard) -> AzSystemClipboard { object.clone() }
 
@@ -3943,19 +4619,19 @@ pub use AzSystemClipboardTT as AzSystemClipboard;
 pub use azul_impl::task::Instant as AzInstantTT;
 pub use AzInstantTT as AzInstant;
 /// Returns the duration since and earlier instant or None if the earlier instant is later than self
-#[no_mangle] pub extern "C" fn AzInstant_durationSince(instant: &AzInstant, earlier: AzInstant) -> AzOptionDuration { if &earlier < instant { Some(instant.duration_since(&earlier)).into() } else { None.into() } }
+#[no_mangle] pub extern "C" fn AzInstant_durationSince(instant: &AzInstant, earlier: AzInstant) -> AzOptionDuration { ffi_guard!(AzOptionDuration, { if &earlier < instant { Some(instant.duration_since(&earlier)).into() } else { None.into() } }) }
 /// Adds a duration to the current time instant, returning the new `Instant`
-#[no_mangle] pub extern "C" fn AzInstant_addDuration(instant: &mut AzInstant, duration: AzDuration) -> AzInstant { instant.add_optional_duration(Some(&duration)) }
+#[no_mangle] pub extern "C" fn AzInstant_addDuration(instant: &mut AzInstant, duration: AzDuration) -> AzInstant { ffi_guard!(AzInstant, { instant.add_optional_duration(Some(&duration)) }) }
 /// Linearly interpolates between [start, end] if the `self` Instant lies between start and end. Returns values between 0.0 and 1.0
-#[no_mangle] pub extern "C" fn AzInstant_linearInterpolate(instant: &AzInstant, start: AzInstant, end: AzInstant) -> f32 { instant.linear_interpolate(start, end) }
+#[no_mangle] pub extern "C" fn AzInstant_linearInterpolate(instant: &AzInstant, start: AzInstant, end: AzInstant) -> f32 { ffi_guard!(f32, { instant.linear_interpolate(start, end) }) }
 /// Destructor: Takes ownership of the `Instant` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInstant_delete(object: &mut AzInstant) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInstant_delete(object: &mut AzInstant) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InstantPtr` struct
 pub use azul_impl::task::AzInstantPtr as AzInstantPtrTT;
 pub use AzInstantPtrTT as AzInstantPtr;
 /// Destructor: Takes ownership of the `InstantPtr` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInstantPtr_delete(object: &mut AzInstantPtr) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzInstantPtr_delete(object: &mut AzInstantPtr) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzInstantPtr_deepCopy(object: &AzInstantPtr) -> AzInstantPtr { object.clone() }
 
@@ -3994,15 +4670,15 @@ pub use azul_impl::task::Timer as AzTimerTT;
 pub use AzTimerTT as AzTimer;
 /// Creates a new `Timer` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `Timer::new()` constructor.
-#[no_mangle] pub extern "C" fn AzTimer_new(timer_data: AzRefAny, callback: AzTimerCallbackType, get_system_time_fn: AzGetSystemTimeFn) -> AzTimer { AzTimer::new(timer_data, callback, get_system_time_fn) }
+#[no_mangle] pub extern "C" fn AzTimer_new(timer_data: AzRefAny, callback: AzTimerCallbackType, get_system_time_fn: AzGetSystemTimeFn) -> AzTimer { ffi_guard!(AzTimer, { AzTimer::new(timer_data, callback, get_system_time_fn) }) }
 /// Equivalent to the Rust `Timer::with_delay()` function.
-#[no_mangle] pub extern "C" fn AzTimer_withDelay(timer: &AzTimer, delay: AzDuration) -> AzTimer { timer.clone().with_delay(delay) }
+#[no_mangle] pub extern "C" fn AzTimer_withDelay(timer: &AzTimer, delay: AzDuration) -> AzTimer { ffi_guard!(AzTimer, { timer.clone().with_delay(delay) }) }
 /// Equivalent to the Rust `Timer::with_interval()` function.
-#[no_mangle] pub extern "C" fn AzTimer_withInterval(timer: &AzTimer, interval: AzDuration) -> AzTimer { timer.clone().with_interval(interval) }
+#[no_mangle] pub extern "C" fn AzTimer_withInterval(timer: &AzTimer, interval: AzDuration) -> AzTimer { ffi_guard!(AzTimer, { timer.clone().with_interval(interval) }) }
 /// Equivalent to the Rust `Timer::with_timeout()` function.
-#[no_mangle] pub extern "C" fn AzTimer_withTimeout(timer: &AzTimer, timeout: AzDuration) -> AzTimer { timer.clone().with_timeout(timeout) }
+#[no_mangle] pub extern "C" fn AzTimer_withTimeout(timer: &AzTimer, timeout: AzDuration) -> AzTimer { ffi_guard!(AzTimer, { timer.clone().with_timeout(timeout) }) }
 /// Destructor: Takes ownership of the `Timer` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTimer_delete(object: &mut AzTimer) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTimer_delete(object: &mut AzTimer) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Should a timer terminate or not - used to remove active timers
 pub use azul_impl::task::TerminateTimer as AzTerminateTimerTT;
@@ -4012,31 +4688,39 @@ pub use AzTerminateTimerTT as AzTerminateTimer;
 pub use azul_impl::task::ThreadId as AzThreadIdTT;
 pub use AzThreadIdTT as AzThreadId;
 
-/// Re-export of rust-allocated (stack based) `Thread` struct
+/// Background thread, started via `CallbackInfo::start_thread`. The thread function receives the `thread_initialize_data`, a `ThreadSender` and a `ThreadReceiver` by value: in C, the thread function has to delete all three before it returns. Progress and results are sent back with `ThreadSender::send(ThreadReceiveMsg::WriteBack(..))`: the `WriteBackCallback` then runs on the main thread with the `writeback_data` and the sent data (which is deleted after the callback returns). Once the thread function has returned and all of its messages have been processed, the thread is removed and the `writeback_data` is deleted. `CallbackInfo::stop_thread` sends a `ThreadSendMsg::TerminateThread` and joins the thread, so the thread function should check `ThreadReceiver::receive` regularly and return early.
 pub use azul_impl::task::Thread as AzThreadTT;
 pub use AzThreadTT as AzThread;
 /// Destructor: Takes ownership of the `Thread` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzThread_delete(object: &mut AzThread) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzThread_delete(object: &mut AzThread) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzThread_deepCopy(object: &AzThread) -> AzThread { object.clone() }
 
+/// Background thread that has been created, but not started yet. Unlike `CallbackInfo::start_thread`, the data is moved back to the main thread once the thread function returns and passed to an `on_finish` callback. Start it with `CallbackInfo::start_pending_thread`, which consumes it - a thread that is never started has to be deleted.
+pub use crate::thread::PendingThread as AzPendingThreadTT;
+pub use AzPendingThreadTT as AzPendingThread;
+/// Creates a thread that runs `thread_fn` on `data` once it is started
+#[no_mangle] pub extern "C" fn AzPendingThread_new(data: AzRefAny, thread_fn: AzThreadFnType) -> AzPendingThread { ffi_guard!(AzPendingThread, { AzPendingThread { data, thread_fn } }) }
+/// Destructor: Takes ownership of the `PendingThread` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzPendingThread_delete(object: &mut AzPendingThread) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
 /// Re-export of rust-allocated (stack based) `ThreadSender` struct
 pub use azul_impl::task::ThreadSender as AzThreadSenderTT;
 pub use AzThreadSenderTT as AzThreadSender;
-/// Equivalent to the Rust `ThreadSender::send()` function.
-#[no_mangle] pub extern "C" fn AzThreadSender_send(threadsender: &mut AzThreadSender, msg: AzThreadReceiveMsg) -> bool { threadsender.send(msg) }
+/// Sends a message from the background thread to the main thread, i.e. a `ThreadReceiveMsg::WriteBack` to update the application data with the progress of the thread.
+#[no_mangle] pub extern "C" fn AzThreadSender_send(threadsender: &mut AzThreadSender, msg: AzThreadReceiveMsg) -> bool { ffi_guard!(bool, { threadsender.send(msg) }) }
 /// Destructor: Takes ownership of the `ThreadSender` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzThreadSender_delete(object: &mut AzThreadSender) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzThreadSender_delete(object: &mut AzThreadSender) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzThreadSender_deepCopy(object: &AzThreadSender) -> AzThreadSender { object.clone() }
 
 /// Re-export of rust-allocated (stack based) `ThreadReceiver` struct
 pub use azul_impl::task::ThreadReceiver as AzThreadReceiverTT;
 pub use AzThreadReceiverTT as AzThreadReceiver;
-/// Equivalent to the Rust `ThreadReceiver::receive()` function.
-#[no_mangle] pub extern "C" fn AzThreadReceiver_receive(threadreceiver: &mut AzThreadReceiver) -> AzOptionThreadSendMsg { threadreceiver.recv().into() }
+/// Receives the next message from the main thread without blocking, i.e. `ThreadSendMsg::TerminateThread` if the thread should stop.
+#[no_mangle] pub extern "C" fn AzThreadReceiver_receive(threadreceiver: &mut AzThreadReceiver) -> AzOptionThreadSendMsg { ffi_guard!(AzOptionThreadSendMsg, { threadreceiver.recv().into() }) }
 /// Destructor: Takes ownership of the `ThreadReceiver` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzThreadReceiver_delete(object: &mut AzThreadReceiver) {  if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }}
+#[no_mangle] pub extern "C" fn AzThreadReceiver_delete(object: &mut AzThreadReceiver) { ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } }) }
 /// Clones the object
 #[no_mangle] pub extern "C" fn AzThreadReceiver_deepCopy(object: &AzThreadReceiver) -> AzThreadReceiver { object.clone() }
 
@@ -4044,19 +4728,19 @@ pub use AzThreadReceiverTT as AzThreadReceiver;
 pub use azul_impl::task::ThreadSendMsg as AzThreadSendMsgTT;
 pub use AzThreadSendMsgTT as AzThreadSendMsg;
 /// Destructor: Takes ownership of the `ThreadSendMsg` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzThreadSendMsg_delete(object: &mut AzThreadSendMsg) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzThreadSendMsg_delete(object: &mut AzThreadSendMsg) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ThreadReceiveMsg` struct
 pub use azul_impl::task::ThreadReceiveMsg as AzThreadReceiveMsgTT;
 pub use AzThreadReceiveMsgTT as AzThreadReceiveMsg;
 /// Destructor: Takes ownership of the `ThreadReceiveMsg` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzThreadReceiveMsg_delete(object: &mut AzThreadReceiveMsg) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzThreadReceiveMsg_delete(object: &mut AzThreadReceiveMsg) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ThreadWriteBackMsg` struct
 pub use azul_impl::task::ThreadWriteBackMsg as AzThreadWriteBackMsgTT;
 pub use AzThreadWriteBackMsgTT as AzThreadWriteBackMsg;
 /// Destructor: Takes ownership of the `ThreadWriteBackMsg` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzThreadWriteBackMsg_delete(object: &mut AzThreadWriteBackMsg) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzThreadWriteBackMsg_delete(object: &mut AzThreadWriteBackMsg) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 pub type AzCreateThreadFnType = extern "C" fn(AzRefAny, AzRefAny, AzThreadCallback) -> AzThread;
 /// Re-export of rust-allocated (stack based) `CreateThreadFn` struct
@@ -4112,437 +4796,523 @@ pub use AzThreadSenderDestructorFnTT as AzThreadSenderDestructorFn;
 pub use azul_impl::str::FmtValue as AzFmtValueTT;
 pub use AzFmtValueTT as AzFmtValue;
 /// Destructor: Takes ownership of the `FmtValue` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFmtValue_delete(object: &mut AzFmtValue) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFmtValue_delete(object: &mut AzFmtValue) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `FmtArg` struct
 pub use azul_impl::str::FmtArg as AzFmtArgTT;
 pub use AzFmtArgTT as AzFmtArg;
 /// Destructor: Takes ownership of the `FmtArg` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFmtArg_delete(object: &mut AzFmtArg) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFmtArg_delete(object: &mut AzFmtArg) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `String` struct
 pub use azul_impl::css::AzString as AzStringTT;
 pub use AzStringTT as AzString;
 /// Creates a dynamically formatted String from a fomat string + named arguments
-#[no_mangle] pub extern "C" fn AzString_format(format: AzString, args: AzFmtArgVec) -> AzString { azul_impl::str::fmt_string(format, args).into() }
+#[no_mangle] pub extern "C" fn AzString_format(format: AzString, args: AzFmtArgVec) -> AzString { ffi_guard!(AzString, { azul_impl::str::fmt_string(format, args).into() }) }
 /// Creates a new String from an arbitary pointer, a start offset (bytes from the start pointer, usually 0) and a length (in bytes). The bytes are expected to point to a UTF-8 encoded string, no error checking is performed.
-#[no_mangle] pub extern "C" fn AzString_copyFromBytes(ptr: *const u8, start: usize, len: usize) -> AzString { unsafe { let start_ptr = ptr.offset(start.max(core::isize::MAX as usize) as isize); let s = core::str::from_utf8_unchecked(core::slice::from_raw_parts(start_ptr, len)); s.to_string().into() } }
+///
+/// # Safety
+///
+/// `ptr` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzString_copyFromBytes(ptr: *const u8, start: usize, len: usize) -> AzString { ffi_guard!(AzString, { unsafe { let start_ptr = ptr.offset(start.max(core::isize::MAX as usize) as isize); let s = core::str::from_utf8_unchecked(core::slice::from_raw_parts(start_ptr, len)); s.to_string().into() } }) }
 /// Trims whitespace from the start / end of the string
-#[no_mangle] pub extern "C" fn AzString_trim(string: &AzString) -> AzString { string.as_str().trim().to_string().into() }
+#[no_mangle] pub extern "C" fn AzString_trim(string: &AzString) -> AzString { ffi_guard!(AzString, { string.as_str().trim().to_string().into() }) }
 /// Returns a reference to the string - NOTE: the returned value is a reference to `self`, you MUST NOT drop the `String` object that the `Refstr` references
-#[no_mangle] pub extern "C" fn AzString_asRefstr(string: &AzString) -> AzRefstr { string.as_str().into() }
+#[no_mangle] pub extern "C" fn AzString_asRefstr(string: &AzString) -> AzRefstr { ffi_guard!(AzRefstr, { string.as_str().into() }) }
 /// Destructor: Takes ownership of the `String` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzString_delete(object: &mut AzString) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzString_delete(object: &mut AzString) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Position of the text cursor (caret) in a UTF-8 string. `byte_offset` is always on a grapheme boundary, `grapheme_offset` is the number of graphemes before the cursor.
+pub use azul_core::text_cursor::TextCursor as AzTextCursorTT;
+pub use AzTextCursorTT as AzTextCursor;
+/// Cursor before the first character
+#[no_mangle] pub extern "C" fn AzTextCursor_start() -> AzTextCursor { ffi_guard!(AzTextCursor, { AzTextCursor::start() }) }
+/// Cursor after the last character of `text`
+#[no_mangle] pub extern "C" fn AzTextCursor_end(text: AzString) -> AzTextCursor { ffi_guard!(AzTextCursor, { AzTextCursor::end(text.as_str()) }) }
+/// Creates a cursor at `byte_offset`, moved back to the start of the grapheme if the offset is inside of a grapheme
+#[no_mangle] pub extern "C" fn AzTextCursor_fromByteOffset(text: AzString, byte_offset: usize) -> AzTextCursor { ffi_guard!(AzTextCursor, { AzTextCursor::from_byte_offset(text.as_str(), byte_offset) }) }
+/// Moves the cursor one grapheme to the right
+#[no_mangle] pub extern "C" fn AzTextCursor_nextGrapheme(textcursor: &AzTextCursor, text: AzString) -> AzTextCursor { ffi_guard!(AzTextCursor, { textcursor.next_grapheme(text.as_str()) }) }
+/// Moves the cursor one grapheme to the left
+#[no_mangle] pub extern "C" fn AzTextCursor_prevGrapheme(textcursor: &AzTextCursor, text: AzString) -> AzTextCursor { ffi_guard!(AzTextCursor, { textcursor.prev_grapheme(text.as_str()) }) }
+/// Moves the cursor to the end of the current or next word
+#[no_mangle] pub extern "C" fn AzTextCursor_nextWord(textcursor: &AzTextCursor, text: AzString) -> AzTextCursor { ffi_guard!(AzTextCursor, { textcursor.next_word(text.as_str()) }) }
+/// Moves the cursor to the start of the current or previous word
+#[no_mangle] pub extern "C" fn AzTextCursor_prevWord(textcursor: &AzTextCursor, text: AzString) -> AzTextCursor { ffi_guard!(AzTextCursor, { textcursor.prev_word(text.as_str()) }) }
+
+/// Selected range of a text. `start` is where the selection was started, `end` is where the caret is (before `start` if the text was selected backwards)
+pub use azul_core::text_cursor::TextSelection as AzTextSelectionTT;
+pub use AzTextSelectionTT as AzTextSelection;
+/// Empty selection, only shows the caret
+#[no_mangle] pub extern "C" fn AzTextSelection_collapsed(cursor: AzTextCursor) -> AzTextSelection { ffi_guard!(AzTextSelection, { AzTextSelection::collapsed(cursor) }) }
+/// Equivalent to the Rust `TextSelection::is_collapsed()` function.
+#[no_mangle] pub extern "C" fn AzTextSelection_isCollapsed(textselection: &AzTextSelection) -> bool { ffi_guard!(bool, { textselection.is_collapsed() }) }
+/// Returns the selection with `start <= end`
+#[no_mangle] pub extern "C" fn AzTextSelection_normalized(textselection: &AzTextSelection) -> AzTextSelection { ffi_guard!(AzTextSelection, { textselection.normalized() }) }
 
 /// Wrapper over a Rust-allocated `Vec<ListViewRow>`
 pub use crate::widgets::list_view::ListViewRowVec as AzListViewRowVecTT;
 pub use AzListViewRowVecTT as AzListViewRowVec;
 /// Destructor: Takes ownership of the `ListViewRowVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzListViewRowVec_delete(object: &mut AzListViewRowVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzListViewRowVec_delete(object: &mut AzListViewRowVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<StyleFilter>`
 pub use azul_impl::css::StyleFilterVec as AzStyleFilterVecTT;
 pub use AzStyleFilterVecTT as AzStyleFilterVec;
 /// Destructor: Takes ownership of the `StyleFilterVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleFilterVec_delete(object: &mut AzStyleFilterVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleFilterVec_delete(object: &mut AzStyleFilterVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<LogicalRect>`
 pub use azul_core::window::LogicalRectVec as AzLogicalRectVecTT;
 pub use AzLogicalRectVecTT as AzLogicalRectVec;
 /// Destructor: Takes ownership of the `LogicalRectVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzLogicalRectVec_delete(object: &mut AzLogicalRectVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzLogicalRectVec_delete(object: &mut AzLogicalRectVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<NodeTypeIdInfoMap>`
 pub use crate::widgets::node_graph::NodeTypeIdInfoMapVec as AzNodeTypeIdInfoMapVecTT;
 pub use AzNodeTypeIdInfoMapVecTT as AzNodeTypeIdInfoMapVec;
 /// Destructor: Takes ownership of the `NodeTypeIdInfoMapVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeTypeIdInfoMapVec_delete(object: &mut AzNodeTypeIdInfoMapVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeTypeIdInfoMapVec_delete(object: &mut AzNodeTypeIdInfoMapVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<InputOutputTypeIdInfoMap>`
 pub use crate::widgets::node_graph::InputOutputTypeIdInfoMapVec as AzInputOutputTypeIdInfoMapVecTT;
 pub use AzInputOutputTypeIdInfoMapVecTT as AzInputOutputTypeIdInfoMapVec;
 /// Destructor: Takes ownership of the `InputOutputTypeIdInfoMapVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInputOutputTypeIdInfoMapVec_delete(object: &mut AzInputOutputTypeIdInfoMapVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInputOutputTypeIdInfoMapVec_delete(object: &mut AzInputOutputTypeIdInfoMapVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<NodeIdNodeMap>`
 pub use crate::widgets::node_graph::NodeIdNodeMapVec as AzNodeIdNodeMapVecTT;
 pub use AzNodeIdNodeMapVecTT as AzNodeIdNodeMapVec;
 /// Destructor: Takes ownership of the `NodeIdNodeMapVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeIdNodeMapVec_delete(object: &mut AzNodeIdNodeMapVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeIdNodeMapVec_delete(object: &mut AzNodeIdNodeMapVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<InputOutputTypeId>`
 pub use crate::widgets::node_graph::InputOutputTypeIdVec as AzInputOutputTypeIdVecTT;
 pub use AzInputOutputTypeIdVecTT as AzInputOutputTypeIdVec;
 /// Destructor: Takes ownership of the `InputOutputTypeIdVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInputOutputTypeIdVec_delete(object: &mut AzInputOutputTypeIdVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInputOutputTypeIdVec_delete(object: &mut AzInputOutputTypeIdVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<NodeTypeField>`
 pub use crate::widgets::node_graph::NodeTypeFieldVec as AzNodeTypeFieldVecTT;
 pub use AzNodeTypeFieldVecTT as AzNodeTypeFieldVec;
 /// Destructor: Takes ownership of the `NodeTypeFieldVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeTypeFieldVec_delete(object: &mut AzNodeTypeFieldVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeTypeFieldVec_delete(object: &mut AzNodeTypeFieldVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<InputConnection>`
 pub use crate::widgets::node_graph::InputConnectionVec as AzInputConnectionVecTT;
 pub use AzInputConnectionVecTT as AzInputConnectionVec;
 /// Destructor: Takes ownership of the `InputConnectionVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInputConnectionVec_delete(object: &mut AzInputConnectionVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInputConnectionVec_delete(object: &mut AzInputConnectionVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<OutputNodeAndIndex>`
 pub use crate::widgets::node_graph::OutputNodeAndIndexVec as AzOutputNodeAndIndexVecTT;
 pub use AzOutputNodeAndIndexVecTT as AzOutputNodeAndIndexVec;
 /// Destructor: Takes ownership of the `OutputNodeAndIndexVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOutputNodeAndIndexVec_delete(object: &mut AzOutputNodeAndIndexVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOutputNodeAndIndexVec_delete(object: &mut AzOutputNodeAndIndexVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<OutputConnection>`
 pub use crate::widgets::node_graph::OutputConnectionVec as AzOutputConnectionVecTT;
 pub use AzOutputConnectionVecTT as AzOutputConnectionVec;
 /// Destructor: Takes ownership of the `OutputConnectionVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOutputConnectionVec_delete(object: &mut AzOutputConnectionVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOutputConnectionVec_delete(object: &mut AzOutputConnectionVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<InputNodeAndIndex>`
 pub use crate::widgets::node_graph::InputNodeAndIndexVec as AzInputNodeAndIndexVecTT;
 pub use AzInputNodeAndIndexVecTT as AzInputNodeAndIndexVec;
 /// Destructor: Takes ownership of the `InputNodeAndIndexVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInputNodeAndIndexVec_delete(object: &mut AzInputNodeAndIndexVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInputNodeAndIndexVec_delete(object: &mut AzInputNodeAndIndexVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<AccessibilityState>`
 pub use azul_impl::dom::AccessibilityStateVec as AzAccessibilityStateVecTT;
 pub use AzAccessibilityStateVecTT as AzAccessibilityStateVec;
 /// Destructor: Takes ownership of the `AccessibilityStateVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzAccessibilityStateVec_delete(object: &mut AzAccessibilityStateVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzAccessibilityStateVec_delete(object: &mut AzAccessibilityStateVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<MenuItem>`
 pub use azul_core::window::MenuItemVec as AzMenuItemVecTT;
 pub use AzMenuItemVecTT as AzMenuItemVec;
 /// Destructor: Takes ownership of the `MenuItemVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzMenuItemVec_delete(object: &mut AzMenuItemVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzMenuItemVec_delete(object: &mut AzMenuItemVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<TessellatedSvgNode>`
 pub use azul_impl::svg::TessellatedSvgNodeVec as AzTessellatedSvgNodeVecTT;
 pub use AzTessellatedSvgNodeVecTT as AzTessellatedSvgNodeVec;
 /// Returns the `TessellatedSvgNodeVec` as a non-owning slice, NOTE: The `U8Vec` that this slice was borrowed from MUST NOT be deleted before the `U8VecRef`
-#[no_mangle] pub extern "C" fn AzTessellatedSvgNodeVec_asRefVec(tessellatedsvgnodevec: &AzTessellatedSvgNodeVec) -> AzTessellatedSvgNodeVecRef { tessellatedsvgnodevec.get_ref() }
+#[no_mangle] pub extern "C" fn AzTessellatedSvgNodeVec_asRefVec(tessellatedsvgnodevec: &AzTessellatedSvgNodeVec) -> AzTessellatedSvgNodeVecRef { ffi_guard!(AzTessellatedSvgNodeVecRef, { tessellatedsvgnodevec.get_ref() }) }
 /// Destructor: Takes ownership of the `TessellatedSvgNodeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTessellatedSvgNodeVec_delete(object: &mut AzTessellatedSvgNodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTessellatedSvgNodeVec_delete(object: &mut AzTessellatedSvgNodeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<TessellatedColoredSvgNode>`
 pub use azul_impl::svg::TessellatedColoredSvgNodeVec as AzTessellatedColoredSvgNodeVecTT;
 pub use AzTessellatedColoredSvgNodeVecTT as AzTessellatedColoredSvgNodeVec;
 /// Returns the `TessellatedColoredSvgNodeVec` as a non-owning slice, NOTE: The `TessellatedColoredSvgNodeVec` that this slice was borrowed from MUST NOT be deleted before the `U8VecRef`
-#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNodeVec_asRefVec(tessellatedcoloredsvgnodevec: &AzTessellatedColoredSvgNodeVec) -> AzTessellatedColoredSvgNodeVecRef { tessellatedcoloredsvgnodevec.get_ref() }
+#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNodeVec_asRefVec(tessellatedcoloredsvgnodevec: &AzTessellatedColoredSvgNodeVec) -> AzTessellatedColoredSvgNodeVecRef { ffi_guard!(AzTessellatedColoredSvgNodeVecRef, { tessellatedcoloredsvgnodevec.get_ref() }) }
 /// Destructor: Takes ownership of the `TessellatedColoredSvgNodeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNodeVec_delete(object: &mut AzTessellatedColoredSvgNodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTessellatedColoredSvgNodeVec_delete(object: &mut AzTessellatedColoredSvgNodeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<StyleFontFamily>`
 pub use azul_impl::css::StyleFontFamilyVec as AzStyleFontFamilyVecTT;
 pub use AzStyleFontFamilyVecTT as AzStyleFontFamilyVec;
 /// Destructor: Takes ownership of the `StyleFontFamilyVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleFontFamilyVec_delete(object: &mut AzStyleFontFamilyVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleFontFamilyVec_delete(object: &mut AzStyleFontFamilyVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<XmlNode>`
 pub use azul_impl::xml::XmlNodeVec as AzXmlNodeVecTT;
 pub use AzXmlNodeVecTT as AzXmlNodeVec;
 /// Destructor: Takes ownership of the `XmlNodeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzXmlNodeVec_delete(object: &mut AzXmlNodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzXmlNodeVec_delete(object: &mut AzXmlNodeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<FmtArg>`
 pub use azul_impl::str::FmtArgVec as AzFmtArgVecTT;
 pub use AzFmtArgVecTT as AzFmtArgVec;
 /// Destructor: Takes ownership of the `FmtArgVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzFmtArgVec_delete(object: &mut AzFmtArgVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzFmtArgVec_delete(object: &mut AzFmtArgVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<InlineLine>`
 pub use azul_impl::callbacks::InlineLineVec as AzInlineLineVecTT;
 pub use AzInlineLineVecTT as AzInlineLineVec;
 /// Destructor: Takes ownership of the `InlineLineVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInlineLineVec_delete(object: &mut AzInlineLineVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInlineLineVec_delete(object: &mut AzInlineLineVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<InlineWord>`
 pub use azul_impl::callbacks::InlineWordVec as AzInlineWordVecTT;
 pub use AzInlineWordVecTT as AzInlineWordVec;
 /// Destructor: Takes ownership of the `InlineWordVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInlineWordVec_delete(object: &mut AzInlineWordVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInlineWordVec_delete(object: &mut AzInlineWordVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<InlineGlyph>`
 pub use azul_impl::callbacks::InlineGlyphVec as AzInlineGlyphVecTT;
 pub use AzInlineGlyphVecTT as AzInlineGlyphVec;
 /// Destructor: Takes ownership of the `InlineGlyphVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInlineGlyphVec_delete(object: &mut AzInlineGlyphVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInlineGlyphVec_delete(object: &mut AzInlineGlyphVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<InlineTextHit>`
 pub use azul_impl::callbacks::InlineTextHitVec as AzInlineTextHitVecTT;
 pub use AzInlineTextHitVecTT as AzInlineTextHitVec;
 /// Destructor: Takes ownership of the `InlineTextHitVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInlineTextHitVec_delete(object: &mut AzInlineTextHitVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInlineTextHitVec_delete(object: &mut AzInlineTextHitVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<Monitor>`
 pub use azul_core::window::MonitorVec as AzMonitorVecTT;
 pub use AzMonitorVecTT as AzMonitorVec;
 /// Destructor: Takes ownership of the `MonitorVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzMonitorVec_delete(object: &mut AzMonitorVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzMonitorVec_delete(object: &mut AzMonitorVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<VideoMode>`
 pub use azul_core::window::VideoModeVec as AzVideoModeVecTT;
 pub use AzVideoModeVecTT as AzVideoModeVec;
 /// Destructor: Takes ownership of the `VideoModeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzVideoModeVec_delete(object: &mut AzVideoModeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzVideoModeVec_delete(object: &mut AzVideoModeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Wrapper over a Rust-allocated `Vec<AcceleratorCallback>`
+pub use azul_core::window::AcceleratorCallbackVec as AzAcceleratorCallbackVecTT;
+pub use AzAcceleratorCallbackVecTT as AzAcceleratorCallbackVec;
+/// Destructor: Takes ownership of the `AcceleratorCallbackVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzAcceleratorCallbackVec_delete(object: &mut AzAcceleratorCallbackVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<Dom>`
 pub use azul_impl::dom::DomVec as AzDomVecTT;
 pub use AzDomVecTT as AzDomVec;
 /// Destructor: Takes ownership of the `DomVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzDomVec_delete(object: &mut AzDomVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzDomVec_delete(object: &mut AzDomVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<IdOrClass>`
 pub use azul_impl::dom::IdOrClassVec as AzIdOrClassVecTT;
 pub use AzIdOrClassVecTT as AzIdOrClassVec;
 /// Destructor: Takes ownership of the `IdOrClassVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzIdOrClassVec_delete(object: &mut AzIdOrClassVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzIdOrClassVec_delete(object: &mut AzIdOrClassVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<NodeDataInlineCssProperty>`
 pub use azul_impl::dom::NodeDataInlineCssPropertyVec as AzNodeDataInlineCssPropertyVecTT;
 pub use AzNodeDataInlineCssPropertyVecTT as AzNodeDataInlineCssPropertyVec;
 /// Destructor: Takes ownership of the `NodeDataInlineCssPropertyVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeDataInlineCssPropertyVec_delete(object: &mut AzNodeDataInlineCssPropertyVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeDataInlineCssPropertyVec_delete(object: &mut AzNodeDataInlineCssPropertyVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<StyleBackgroundContent>`
 pub use azul_impl::css::StyleBackgroundContentVec as AzStyleBackgroundContentVecTT;
 pub use AzStyleBackgroundContentVecTT as AzStyleBackgroundContentVec;
 /// Destructor: Takes ownership of the `StyleBackgroundContentVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleBackgroundContentVec_delete(object: &mut AzStyleBackgroundContentVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleBackgroundContentVec_delete(object: &mut AzStyleBackgroundContentVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<StyleBackgroundPosition>`
 pub use azul_impl::css::StyleBackgroundPositionVec as AzStyleBackgroundPositionVecTT;
 pub use AzStyleBackgroundPositionVecTT as AzStyleBackgroundPositionVec;
 /// Destructor: Takes ownership of the `StyleBackgroundPositionVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleBackgroundPositionVec_delete(object: &mut AzStyleBackgroundPositionVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleBackgroundPositionVec_delete(object: &mut AzStyleBackgroundPositionVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<StyleBackgroundRepeat>`
 pub use azul_impl::css::StyleBackgroundRepeatVec as AzStyleBackgroundRepeatVecTT;
 pub use AzStyleBackgroundRepeatVecTT as AzStyleBackgroundRepeatVec;
 /// Destructor: Takes ownership of the `StyleBackgroundRepeatVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleBackgroundRepeatVec_delete(object: &mut AzStyleBackgroundRepeatVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleBackgroundRepeatVec_delete(object: &mut AzStyleBackgroundRepeatVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<StyleBackgroundSize>`
 pub use azul_impl::css::StyleBackgroundSizeVec as AzStyleBackgroundSizeVecTT;
 pub use AzStyleBackgroundSizeVecTT as AzStyleBackgroundSizeVec;
 /// Destructor: Takes ownership of the `StyleBackgroundSizeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleBackgroundSizeVec_delete(object: &mut AzStyleBackgroundSizeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleBackgroundSizeVec_delete(object: &mut AzStyleBackgroundSizeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
 pub use azul_impl::css::StyleTransformVec as AzStyleTransformVecTT;
 pub use AzStyleTransformVecTT as AzStyleTransformVec;
 /// Destructor: Takes ownership of the `StyleTransformVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyleTransformVec_delete(object: &mut AzStyleTransformVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyleTransformVec_delete(object: &mut AzStyleTransformVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Wrapper over a Rust-allocated `Vec<GridTrack>`
+pub use azul_impl::css::GridTrackVec as AzGridTrackVecTT;
+pub use AzGridTrackVecTT as AzGridTrackVec;
+/// Destructor: Takes ownership of the `GridTrackVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzGridTrackVec_delete(object: &mut AzGridTrackVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Wrapper over a Rust-allocated `Vec<CssPropertyType>`
+pub use azul_impl::css::CssPropertyTypeVec as AzCssPropertyTypeVecTT;
+pub use AzCssPropertyTypeVecTT as AzCssPropertyTypeVec;
+/// Destructor: Takes ownership of the `CssPropertyTypeVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssPropertyTypeVec_delete(object: &mut AzCssPropertyTypeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Wrapper over a Rust-allocated `Vec<CssVariable>`
+pub use azul_impl::css::CssVariableVec as AzCssVariableVecTT;
+pub use AzCssVariableVecTT as AzCssVariableVec;
+/// Destructor: Takes ownership of the `CssVariableVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssVariableVec_delete(object: &mut AzCssVariableVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Wrapper over a Rust-allocated `Vec<MediaFeature>`
+pub use azul_impl::css::MediaFeatureVec as AzMediaFeatureVecTT;
+pub use AzMediaFeatureVecTT as AzMediaFeatureVec;
+/// Destructor: Takes ownership of the `MediaFeatureVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzMediaFeatureVec_delete(object: &mut AzMediaFeatureVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Wrapper over a Rust-allocated `Vec<MediaCondition>`
+pub use azul_impl::css::MediaConditionVec as AzMediaConditionVecTT;
+pub use AzMediaConditionVecTT as AzMediaConditionVec;
+/// Destructor: Takes ownership of the `MediaConditionVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzMediaConditionVec_delete(object: &mut AzMediaConditionVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<CssProperty>`
 pub use azul_impl::css::CssPropertyVec as AzCssPropertyVecTT;
 pub use AzCssPropertyVecTT as AzCssPropertyVec;
+/// Returns the number of properties in the `CssPropertyVec`
+#[no_mangle] pub extern "C" fn AzCssPropertyVec_len(csspropertyvec: &AzCssPropertyVec) -> usize { ffi_guard!(usize, { csspropertyvec.len() }) }
+/// Returns a copy of the property at the given index or `None` if the index is out of bounds
+#[no_mangle] pub extern "C" fn AzCssPropertyVec_get(csspropertyvec: &AzCssPropertyVec, index: usize) -> AzOptionCssProperty { ffi_guard!(AzOptionCssProperty, { csspropertyvec.get(index).cloned().into() }) }
 /// Destructor: Takes ownership of the `CssPropertyVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssPropertyVec_delete(object: &mut AzCssPropertyVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCssPropertyVec_delete(object: &mut AzCssPropertyVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<SvgMultiPolygon>`
 pub use azul_impl::svg::SvgMultiPolygonVec as AzSvgMultiPolygonVecTT;
 pub use AzSvgMultiPolygonVecTT as AzSvgMultiPolygonVec;
 /// Destructor: Takes ownership of the `SvgMultiPolygonVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgMultiPolygonVec_delete(object: &mut AzSvgMultiPolygonVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgMultiPolygonVec_delete(object: &mut AzSvgMultiPolygonVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<SvgSimpleNode>`
 pub use azul_impl::svg::SvgSimpleNodeVec as AzSvgSimpleNodeVecTT;
 pub use AzSvgSimpleNodeVecTT as AzSvgSimpleNodeVec;
 /// Destructor: Takes ownership of the `SvgSimpleNodeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgSimpleNodeVec_delete(object: &mut AzSvgSimpleNodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgSimpleNodeVec_delete(object: &mut AzSvgSimpleNodeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<SvgPath>`
 pub use azul_impl::svg::SvgPathVec as AzSvgPathVecTT;
 pub use AzSvgPathVecTT as AzSvgPathVec;
 /// Destructor: Takes ownership of the `SvgPathVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgPathVec_delete(object: &mut AzSvgPathVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgPathVec_delete(object: &mut AzSvgPathVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<VertexAttribute>`
 pub use azul_impl::gl::VertexAttributeVec as AzVertexAttributeVecTT;
 pub use AzVertexAttributeVecTT as AzVertexAttributeVec;
 /// Destructor: Takes ownership of the `VertexAttributeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzVertexAttributeVec_delete(object: &mut AzVertexAttributeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzVertexAttributeVec_delete(object: &mut AzVertexAttributeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `VertexAttribute`
 pub use azul_impl::svg::SvgPathElementVec as AzSvgPathElementVecTT;
 pub use AzSvgPathElementVecTT as AzSvgPathElementVec;
 /// Destructor: Takes ownership of the `SvgPathElementVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgPathElementVec_delete(object: &mut AzSvgPathElementVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgPathElementVec_delete(object: &mut AzSvgPathElementVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `SvgVertex`
 pub use azul_impl::svg::SvgVertexVec as AzSvgVertexVecTT;
 pub use AzSvgVertexVecTT as AzSvgVertexVec;
 /// Destructor: Takes ownership of the `SvgVertexVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgVertexVec_delete(object: &mut AzSvgVertexVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgVertexVec_delete(object: &mut AzSvgVertexVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `SvgColoredVertex`
 pub use azul_core::svg::SvgColoredVertexVec as AzSvgColoredVertexVecTT;
 pub use AzSvgColoredVertexVecTT as AzSvgColoredVertexVec;
 /// Destructor: Takes ownership of the `SvgColoredVertexVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgColoredVertexVec_delete(object: &mut AzSvgColoredVertexVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgColoredVertexVec_delete(object: &mut AzSvgColoredVertexVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<u32>`
 pub use azul_impl::css::U32Vec as AzU32VecTT;
 pub use AzU32VecTT as AzU32Vec;
 /// Destructor: Takes ownership of the `U32Vec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzU32Vec_delete(object: &mut AzU32Vec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzU32Vec_delete(object: &mut AzU32Vec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `XWindowType`
 pub use azul_core::window::XWindowTypeVec as AzXWindowTypeVecTT;
 pub use AzXWindowTypeVecTT as AzXWindowTypeVec;
 /// Destructor: Takes ownership of the `XWindowTypeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzXWindowTypeVec_delete(object: &mut AzXWindowTypeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzXWindowTypeVec_delete(object: &mut AzXWindowTypeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `VirtualKeyCode`
 pub use azul_core::window::VirtualKeyCodeVec as AzVirtualKeyCodeVecTT;
 pub use AzVirtualKeyCodeVecTT as AzVirtualKeyCodeVec;
 /// Destructor: Takes ownership of the `VirtualKeyCodeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzVirtualKeyCodeVec_delete(object: &mut AzVirtualKeyCodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzVirtualKeyCodeVec_delete(object: &mut AzVirtualKeyCodeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `CascadeInfo`
 pub use azul_impl::style::CascadeInfoVec as AzCascadeInfoVecTT;
 pub use AzCascadeInfoVecTT as AzCascadeInfoVec;
 /// Destructor: Takes ownership of the `CascadeInfoVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCascadeInfoVec_delete(object: &mut AzCascadeInfoVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCascadeInfoVec_delete(object: &mut AzCascadeInfoVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `ScanCode`
 pub use azul_core::window::ScanCodeVec as AzScanCodeVecTT;
 pub use AzScanCodeVecTT as AzScanCodeVec;
 /// Destructor: Takes ownership of the `ScanCodeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzScanCodeVec_delete(object: &mut AzScanCodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzScanCodeVec_delete(object: &mut AzScanCodeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `CssDeclaration`
 pub use azul_impl::css::CssDeclarationVec as AzCssDeclarationVecTT;
 pub use AzCssDeclarationVecTT as AzCssDeclarationVec;
 /// Destructor: Takes ownership of the `CssDeclarationVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssDeclarationVec_delete(object: &mut AzCssDeclarationVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCssDeclarationVec_delete(object: &mut AzCssDeclarationVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `CssPathSelector`
 pub use azul_impl::css::CssPathSelectorVec as AzCssPathSelectorVecTT;
 pub use AzCssPathSelectorVecTT as AzCssPathSelectorVec;
 /// Destructor: Takes ownership of the `CssPathSelectorVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssPathSelectorVec_delete(object: &mut AzCssPathSelectorVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCssPathSelectorVec_delete(object: &mut AzCssPathSelectorVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Stylesheet`
 pub use azul_impl::css::StylesheetVec as AzStylesheetVecTT;
 pub use AzStylesheetVecTT as AzStylesheetVec;
 /// Destructor: Takes ownership of the `StylesheetVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStylesheetVec_delete(object: &mut AzStylesheetVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStylesheetVec_delete(object: &mut AzStylesheetVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `CssRuleBlock`
 pub use azul_impl::css::CssRuleBlockVec as AzCssRuleBlockVecTT;
 pub use AzCssRuleBlockVecTT as AzCssRuleBlockVec;
 /// Destructor: Takes ownership of the `CssRuleBlockVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCssRuleBlockVec_delete(object: &mut AzCssRuleBlockVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCssRuleBlockVec_delete(object: &mut AzCssRuleBlockVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<u16>`
 pub use azul_impl::css::U16Vec as AzU16VecTT;
 pub use AzU16VecTT as AzU16Vec;
 /// Destructor: Takes ownership of the `U16Vec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzU16Vec_delete(object: &mut AzU16Vec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzU16Vec_delete(object: &mut AzU16Vec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<f32>`
 pub use azul_impl::css::F32Vec as AzF32VecTT;
 pub use AzF32VecTT as AzF32Vec;
 /// Destructor: Takes ownership of the `F32Vec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzF32Vec_delete(object: &mut AzF32Vec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzF32Vec_delete(object: &mut AzF32Vec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `U8Vec`
 pub use azul_impl::css::U8Vec as AzU8VecTT;
 pub use AzU8VecTT as AzU8Vec;
 /// Creates a new, heap-allocated U8Vec by copying the memory into Rust (heap allocation)
-#[no_mangle] pub extern "C" fn AzU8Vec_copyFromBytes(ptr: *const u8, start: usize, len: usize) -> AzU8Vec {  unsafe { let start_ptr = ptr.offset(start.max(core::isize::MAX as usize) as isize); let s = core::slice::from_raw_parts(start_ptr, len); s.to_vec().into() } }
+///
+/// # Safety
+///
+/// `ptr` has to be valid for the duration of the call
+#[no_mangle] pub unsafe extern "C" fn AzU8Vec_copyFromBytes(ptr: *const u8, start: usize, len: usize) -> AzU8Vec { ffi_guard!(AzU8Vec, {  unsafe { let start_ptr = ptr.offset(start.max(core::isize::MAX as usize) as isize); let s = core::slice::from_raw_parts(start_ptr, len); s.to_vec().into() } }) }
 /// Returns the `U8Vec` as a non-owning slice, NOTE: The `U8Vec` that this slice was borrowed from MUST NOT be deleted before the `U8VecRef`
-#[no_mangle] pub extern "C" fn AzU8Vec_asRefVec(u8vec: &AzU8Vec) -> AzU8VecRef { u8vec.as_ref().into() }
+#[no_mangle] pub extern "C" fn AzU8Vec_asRefVec(u8vec: &AzU8Vec) -> AzU8VecRef { ffi_guard!(AzU8VecRef, { u8vec.as_ref().into() }) }
 /// Destructor: Takes ownership of the `U8Vec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzU8Vec_delete(object: &mut AzU8Vec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzU8Vec_delete(object: &mut AzU8Vec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `CallbackData`
 pub use azul_impl::dom::CallbackDataVec as AzCallbackDataVecTT;
 pub use AzCallbackDataVecTT as AzCallbackDataVec;
 /// Destructor: Takes ownership of the `CallbackDataVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzCallbackDataVec_delete(object: &mut AzCallbackDataVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzCallbackDataVec_delete(object: &mut AzCallbackDataVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<DebugMessage>`
 pub use azul_impl::gl::AzDebugMessageVec as AzDebugMessageVecTT;
 pub use AzDebugMessageVecTT as AzDebugMessageVec;
 /// Destructor: Takes ownership of the `DebugMessageVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzDebugMessageVec_delete(object: &mut AzDebugMessageVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzDebugMessageVec_delete(object: &mut AzDebugMessageVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `U32Vec`
 pub use azul_impl::gl::GLuintVec as AzGLuintVecTT;
 pub use AzGLuintVecTT as AzGLuintVec;
 /// Destructor: Takes ownership of the `GLuintVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzGLuintVec_delete(object: &mut AzGLuintVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzGLuintVec_delete(object: &mut AzGLuintVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `GLintVec`
 pub use azul_impl::gl::GLintVec as AzGLintVecTT;
 pub use AzGLintVecTT as AzGLintVec;
 /// Destructor: Takes ownership of the `GLintVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzGLintVec_delete(object: &mut AzGLintVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzGLintVec_delete(object: &mut AzGLintVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `StringVec`
 pub use azul_impl::css::StringVec as AzStringVecTT;
 pub use AzStringVecTT as AzStringVec;
+/// Creates a new, empty `StringVec`
+#[no_mangle] pub extern "C" fn AzStringVec_new() -> AzStringVec { ffi_guard!(AzStringVec, { azul_impl::css::StringVec::new() }) }
+/// Returns the number of strings in the `StringVec`
+#[no_mangle] pub extern "C" fn AzStringVec_len(stringvec: &AzStringVec) -> usize { ffi_guard!(usize, { stringvec.len() }) }
+/// Returns a copy of the string at the given index or `None` if the index is out of bounds
+#[no_mangle] pub extern "C" fn AzStringVec_get(stringvec: &AzStringVec, index: usize) -> AzOptionString { ffi_guard!(AzOptionString, { stringvec.get(index).cloned().into() }) }
 /// Destructor: Takes ownership of the `StringVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStringVec_delete(object: &mut AzStringVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStringVec_delete(object: &mut AzStringVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `StringPairVec`
 pub use azul_core::window::StringPairVec as AzStringPairVecTT;
 pub use AzStringPairVecTT as AzStringPairVec;
 /// Destructor: Takes ownership of the `StringPairVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStringPairVec_delete(object: &mut AzStringPairVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStringPairVec_delete(object: &mut AzStringPairVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `NormalizedLinearColorStopVec`
 pub use azul_impl::css::NormalizedLinearColorStopVec as AzNormalizedLinearColorStopVecTT;
 pub use AzNormalizedLinearColorStopVecTT as AzNormalizedLinearColorStopVec;
+/// Returns the number of color stops in the `NormalizedLinearColorStopVec`
+#[no_mangle] pub extern "C" fn AzNormalizedLinearColorStopVec_len(normalizedlinearcolorstopvec: &AzNormalizedLinearColorStopVec) -> usize { ffi_guard!(usize, { normalizedlinearcolorstopvec.len() }) }
+/// Returns a copy of the color stop at the given index or `None` if the index is out of bounds
+#[no_mangle] pub extern "C" fn AzNormalizedLinearColorStopVec_get(normalizedlinearcolorstopvec: &AzNormalizedLinearColorStopVec, index: usize) -> AzOptionNormalizedLinearColorStop { ffi_guard!(AzOptionNormalizedLinearColorStop, { normalizedlinearcolorstopvec.get(index).cloned().into() }) }
 /// Destructor: Takes ownership of the `NormalizedLinearColorStopVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNormalizedLinearColorStopVec_delete(object: &mut AzNormalizedLinearColorStopVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNormalizedLinearColorStopVec_delete(object: &mut AzNormalizedLinearColorStopVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `NormalizedRadialColorStopVec`
 pub use azul_impl::css::NormalizedRadialColorStopVec as AzNormalizedRadialColorStopVecTT;
 pub use AzNormalizedRadialColorStopVecTT as AzNormalizedRadialColorStopVec;
 /// Destructor: Takes ownership of the `NormalizedRadialColorStopVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNormalizedRadialColorStopVec_delete(object: &mut AzNormalizedRadialColorStopVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNormalizedRadialColorStopVec_delete(object: &mut AzNormalizedRadialColorStopVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `NodeIdVec`
 pub use azul_impl::styled_dom::NodeIdVec as AzNodeIdVecTT;
 pub use AzNodeIdVecTT as AzNodeIdVec;
 /// Destructor: Takes ownership of the `NodeIdVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeIdVec_delete(object: &mut AzNodeIdVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeIdVec_delete(object: &mut AzNodeIdVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `Vec<NodeHierarchyItem>`
 pub use azul_impl::styled_dom::NodeHierarchyItemVec as AzNodeHierarchyItemVecTT;
 pub use AzNodeHierarchyItemVecTT as AzNodeHierarchyItemVec;
 /// Destructor: Takes ownership of the `NodeHierarchyItemVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeHierarchyItemVec_delete(object: &mut AzNodeHierarchyItemVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeHierarchyItemVec_delete(object: &mut AzNodeHierarchyItemVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `StyledNodeVec`
 pub use azul_impl::styled_dom::StyledNodeVec as AzStyledNodeVecTT;
 pub use AzStyledNodeVecTT as AzStyledNodeVec;
 /// Destructor: Takes ownership of the `StyledNodeVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzStyledNodeVec_delete(object: &mut AzStyledNodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzStyledNodeVec_delete(object: &mut AzStyledNodeVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `TagIdToNodeIdMappingVec`
 pub use azul_impl::styled_dom::TagIdToNodeIdMappingVec as AzTagIdToNodeIdMappingVecTT;
 pub use AzTagIdToNodeIdMappingVecTT as AzTagIdToNodeIdMappingVec;
 /// Destructor: Takes ownership of the `TagIdToNodeIdMappingVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzTagIdToNodeIdMappingVec_delete(object: &mut AzTagIdToNodeIdMappingVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzTagIdToNodeIdMappingVec_delete(object: &mut AzTagIdToNodeIdMappingVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `ParentWithNodeDepthVec`
 pub use azul_impl::styled_dom::ParentWithNodeDepthVec as AzParentWithNodeDepthVecTT;
 pub use AzParentWithNodeDepthVecTT as AzParentWithNodeDepthVec;
 /// Destructor: Takes ownership of the `ParentWithNodeDepthVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzParentWithNodeDepthVec_delete(object: &mut AzParentWithNodeDepthVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzParentWithNodeDepthVec_delete(object: &mut AzParentWithNodeDepthVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Wrapper over a Rust-allocated `NodeDataVec`
 pub use azul_impl::dom::NodeDataVec as AzNodeDataVecTT;
 pub use AzNodeDataVecTT as AzNodeDataVec;
 /// Destructor: Takes ownership of the `NodeDataVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzNodeDataVec_delete(object: &mut AzNodeDataVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzNodeDataVec_delete(object: &mut AzNodeDataVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecDestructor` struct
 pub use azul_impl::css::StyleFontFamilyVecDestructor as AzStyleFontFamilyVecDestructorTT;
@@ -4669,6 +5439,11 @@ pub use azul_core::window::VideoModeVecDestructor as AzVideoModeVecDestructorTT;
 pub use AzVideoModeVecDestructorTT as AzVideoModeVecDestructor;
 
 pub type AzVideoModeVecDestructorType = extern "C" fn(&mut AzVideoModeVec);
+/// Re-export of rust-allocated (stack based) `AcceleratorCallbackVecDestructor` struct
+pub use azul_core::window::AcceleratorCallbackVecDestructor as AzAcceleratorCallbackVecDestructorTT;
+pub use AzAcceleratorCallbackVecDestructorTT as AzAcceleratorCallbackVecDestructor;
+
+pub type AzAcceleratorCallbackVecDestructorType = extern "C" fn(&mut AzAcceleratorCallbackVec);
 /// Re-export of rust-allocated (stack based) `DomVecDestructor` struct
 pub use azul_impl::dom::DomVecDestructor as AzDomVecDestructorTT;
 pub use AzDomVecDestructorTT as AzDomVecDestructor;
@@ -4709,6 +5484,31 @@ pub use azul_impl::css::StyleTransformVecDestructor as AzStyleTransformVecDestru
 pub use AzStyleTransformVecDestructorTT as AzStyleTransformVecDestructor;
 
 pub type AzStyleTransformVecDestructorType = extern "C" fn(&mut AzStyleTransformVec);
+/// Re-export of rust-allocated (stack based) `GridTrackVecDestructor` struct
+pub use azul_impl::css::GridTrackVecDestructor as AzGridTrackVecDestructorTT;
+pub use AzGridTrackVecDestructorTT as AzGridTrackVecDestructor;
+
+pub type AzGridTrackVecDestructorType = extern "C" fn(&mut AzGridTrackVec);
+/// Re-export of rust-allocated (stack based) `CssPropertyTypeVecDestructor` struct
+pub use azul_impl::css::CssPropertyTypeVecDestructor as AzCssPropertyTypeVecDestructorTT;
+pub use AzCssPropertyTypeVecDestructorTT as AzCssPropertyTypeVecDestructor;
+
+pub type AzCssPropertyTypeVecDestructorType = extern "C" fn(&mut AzCssPropertyTypeVec);
+/// Re-export of rust-allocated (stack based) `CssVariableVecDestructor` struct
+pub use azul_impl::css::CssVariableVecDestructor as AzCssVariableVecDestructorTT;
+pub use AzCssVariableVecDestructorTT as AzCssVariableVecDestructor;
+
+pub type AzCssVariableVecDestructorType = extern "C" fn(&mut AzCssVariableVec);
+/// Re-export of rust-allocated (stack based) `MediaFeatureVecDestructor` struct
+pub use azul_impl::css::MediaFeatureVecDestructor as AzMediaFeatureVecDestructorTT;
+pub use AzMediaFeatureVecDestructorTT as AzMediaFeatureVecDestructor;
+
+pub type AzMediaFeatureVecDestructorType = extern "C" fn(&mut AzMediaFeatureVec);
+/// Re-export of rust-allocated (stack based) `MediaConditionVecDestructor` struct
+pub use azul_impl::css::MediaConditionVecDestructor as AzMediaConditionVecDestructorTT;
+pub use AzMediaConditionVecDestructorTT as AzMediaConditionVecDestructor;
+
+pub type AzMediaConditionVecDestructorType = extern "C" fn(&mut AzMediaConditionVec);
 /// Re-export of rust-allocated (stack based) `CssPropertyVecDestructor` struct
 pub use azul_impl::css::CssPropertyVecDestructor as AzCssPropertyVecDestructorTT;
 pub use AzCssPropertyVecDestructorTT as AzCssPropertyVecDestructor;
@@ -4883,29 +5683,35 @@ pub type AzNodeDataVecDestructorType = extern "C" fn(&mut AzNodeDataVec);
 pub use azul_impl::css::OptionSvgPoint as AzOptionSvgPointTT;
 pub use AzOptionSvgPointTT as AzOptionSvgPoint;
 
+/// Re-export of rust-allocated (stack based) `OptionMediaQuery` struct
+pub use azul_impl::css::OptionMediaQuery as AzOptionMediaQueryTT;
+pub use AzOptionMediaQueryTT as AzOptionMediaQuery;
+/// Destructor: Takes ownership of the `OptionMediaQuery` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionMediaQuery_delete(object: &mut AzOptionMediaQuery) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
 /// Re-export of rust-allocated (stack based) `OptionListViewOnRowClick` struct
 pub use crate::widgets::list_view::OptionListViewOnRowClick as AzOptionListViewOnRowClickTT;
 pub use AzOptionListViewOnRowClickTT as AzOptionListViewOnRowClick;
 /// Destructor: Takes ownership of the `OptionListViewOnRowClick` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionListViewOnRowClick_delete(object: &mut AzOptionListViewOnRowClick) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionListViewOnRowClick_delete(object: &mut AzOptionListViewOnRowClick) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnClick` struct
 pub use crate::widgets::list_view::OptionListViewOnColumnClick as AzOptionListViewOnColumnClickTT;
 pub use AzOptionListViewOnColumnClickTT as AzOptionListViewOnColumnClick;
 /// Destructor: Takes ownership of the `OptionListViewOnColumnClick` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionListViewOnColumnClick_delete(object: &mut AzOptionListViewOnColumnClick) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionListViewOnColumnClick_delete(object: &mut AzOptionListViewOnColumnClick) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionListViewOnLazyLoadScroll` struct
 pub use crate::widgets::list_view::OptionListViewOnLazyLoadScroll as AzOptionListViewOnLazyLoadScrollTT;
 pub use AzOptionListViewOnLazyLoadScrollTT as AzOptionListViewOnLazyLoadScroll;
 /// Destructor: Takes ownership of the `OptionListViewOnLazyLoadScroll` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionListViewOnLazyLoadScroll_delete(object: &mut AzOptionListViewOnLazyLoadScroll) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionListViewOnLazyLoadScroll_delete(object: &mut AzOptionListViewOnLazyLoadScroll) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionMenu` struct
 pub use azul_core::window::OptionMenu as AzOptionMenuTT;
 pub use AzOptionMenuTT as AzOptionMenu;
 /// Destructor: Takes ownership of the `OptionMenu` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionMenu_delete(object: &mut AzOptionMenu) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionMenu_delete(object: &mut AzOptionMenu) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionPixelValueNoPercent` struct
 pub use azul_impl::css::OptionPixelValueNoPercent as AzOptionPixelValueNoPercentTT;
@@ -4915,109 +5721,109 @@ pub use AzOptionPixelValueNoPercentTT as AzOptionPixelValueNoPercent;
 pub use crate::widgets::drop_down::OptionDropDownOnChoiceChange as AzOptionDropDownOnChoiceChangeTT;
 pub use AzOptionDropDownOnChoiceChangeTT as AzOptionDropDownOnChoiceChange;
 /// Destructor: Takes ownership of the `OptionDropDownOnChoiceChange` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionDropDownOnChoiceChange_delete(object: &mut AzOptionDropDownOnChoiceChange) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionDropDownOnChoiceChange_delete(object: &mut AzOptionDropDownOnChoiceChange) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionResolvedTextLayoutOptions` struct
 pub use azul_impl::ui_solver::OptionResolvedTextLayoutOptions as AzOptionResolvedTextLayoutOptionsTT;
 pub use AzOptionResolvedTextLayoutOptionsTT as AzOptionResolvedTextLayoutOptions;
 /// Destructor: Takes ownership of the `OptionResolvedTextLayoutOptions` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionResolvedTextLayoutOptions_delete(object: &mut AzOptionResolvedTextLayoutOptions) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionResolvedTextLayoutOptions_delete(object: &mut AzOptionResolvedTextLayoutOptions) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeAdded` struct
 pub use crate::widgets::node_graph::OptionOnNodeAdded as AzOptionNodeGraphOnNodeAddedTT;
 pub use AzOptionNodeGraphOnNodeAddedTT as AzOptionNodeGraphOnNodeAdded;
 /// Destructor: Takes ownership of the `OptionNodeGraphOnNodeAdded` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeAdded_delete(object: &mut AzOptionNodeGraphOnNodeAdded) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeAdded_delete(object: &mut AzOptionNodeGraphOnNodeAdded) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeRemoved` struct
 pub use crate::widgets::node_graph::OptionOnNodeRemoved as AzOptionNodeGraphOnNodeRemovedTT;
 pub use AzOptionNodeGraphOnNodeRemovedTT as AzOptionNodeGraphOnNodeRemoved;
 /// Destructor: Takes ownership of the `OptionNodeGraphOnNodeRemoved` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeRemoved_delete(object: &mut AzOptionNodeGraphOnNodeRemoved) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeRemoved_delete(object: &mut AzOptionNodeGraphOnNodeRemoved) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeGraphDragged` struct
 pub use crate::widgets::node_graph::OptionOnNodeGraphDragged as AzOptionNodeGraphOnNodeGraphDraggedTT;
 pub use AzOptionNodeGraphOnNodeGraphDraggedTT as AzOptionNodeGraphOnNodeGraphDragged;
 /// Destructor: Takes ownership of the `OptionNodeGraphOnNodeGraphDragged` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeGraphDragged_delete(object: &mut AzOptionNodeGraphOnNodeGraphDragged) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeGraphDragged_delete(object: &mut AzOptionNodeGraphOnNodeGraphDragged) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeDragged` struct
 pub use crate::widgets::node_graph::OptionOnNodeDragged as AzOptionNodeGraphOnNodeDraggedTT;
 pub use AzOptionNodeGraphOnNodeDraggedTT as AzOptionNodeGraphOnNodeDragged;
 /// Destructor: Takes ownership of the `OptionNodeGraphOnNodeDragged` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeDragged_delete(object: &mut AzOptionNodeGraphOnNodeDragged) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeDragged_delete(object: &mut AzOptionNodeGraphOnNodeDragged) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeConnected` struct
 pub use crate::widgets::node_graph::OptionOnNodeConnected as AzOptionNodeGraphOnNodeConnectedTT;
 pub use AzOptionNodeGraphOnNodeConnectedTT as AzOptionNodeGraphOnNodeConnected;
 /// Destructor: Takes ownership of the `OptionNodeGraphOnNodeConnected` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeConnected_delete(object: &mut AzOptionNodeGraphOnNodeConnected) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeConnected_delete(object: &mut AzOptionNodeGraphOnNodeConnected) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeInputDisconnected` struct
 pub use crate::widgets::node_graph::OptionOnNodeInputDisconnected as AzOptionNodeGraphOnNodeInputDisconnectedTT;
 pub use AzOptionNodeGraphOnNodeInputDisconnectedTT as AzOptionNodeGraphOnNodeInputDisconnected;
 /// Destructor: Takes ownership of the `OptionNodeGraphOnNodeInputDisconnected` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeInputDisconnected_delete(object: &mut AzOptionNodeGraphOnNodeInputDisconnected) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeInputDisconnected_delete(object: &mut AzOptionNodeGraphOnNodeInputDisconnected) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeOutputDisconnected` struct
 pub use crate::widgets::node_graph::OptionOnNodeOutputDisconnected as AzOptionNodeGraphOnNodeOutputDisconnectedTT;
 pub use AzOptionNodeGraphOnNodeOutputDisconnectedTT as AzOptionNodeGraphOnNodeOutputDisconnected;
 /// Destructor: Takes ownership of the `OptionNodeGraphOnNodeOutputDisconnected` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeOutputDisconnected_delete(object: &mut AzOptionNodeGraphOnNodeOutputDisconnected) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeOutputDisconnected_delete(object: &mut AzOptionNodeGraphOnNodeOutputDisconnected) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionNodeGraphOnNodeFieldEdited` struct
 pub use crate::widgets::node_graph::OptionOnNodeFieldEdited as AzOptionNodeGraphOnNodeFieldEditedTT;
 pub use AzOptionNodeGraphOnNodeFieldEditedTT as AzOptionNodeGraphOnNodeFieldEdited;
 /// Destructor: Takes ownership of the `OptionNodeGraphOnNodeFieldEdited` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeFieldEdited_delete(object: &mut AzOptionNodeGraphOnNodeFieldEdited) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionNodeGraphOnNodeFieldEdited_delete(object: &mut AzOptionNodeGraphOnNodeFieldEdited) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionColorInputOnValueChange` struct
 pub use crate::widgets::color_input::OptionColorInputOnValueChange as AzOptionColorInputOnValueChangeTT;
 pub use AzOptionColorInputOnValueChangeTT as AzOptionColorInputOnValueChange;
 /// Destructor: Takes ownership of the `OptionColorInputOnValueChange` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionColorInputOnValueChange_delete(object: &mut AzOptionColorInputOnValueChange) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionColorInputOnValueChange_delete(object: &mut AzOptionColorInputOnValueChange) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionButtonOnClick` struct
 pub use crate::widgets::button::OptionButtonOnClick as AzOptionButtonOnClickTT;
 pub use AzOptionButtonOnClickTT as AzOptionButtonOnClick;
 /// Destructor: Takes ownership of the `OptionButtonOnClick` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionButtonOnClick_delete(object: &mut AzOptionButtonOnClick) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionButtonOnClick_delete(object: &mut AzOptionButtonOnClick) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionTabOnClick` struct
 pub use crate::widgets::tabs::OptionTabOnClick as AzOptionTabOnClickTT;
 pub use AzOptionTabOnClickTT as AzOptionTabOnClick;
 /// Destructor: Takes ownership of the `OptionTabOnClick` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionTabOnClick_delete(object: &mut AzOptionTabOnClick) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionTabOnClick_delete(object: &mut AzOptionTabOnClick) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionFileInputOnPathChange` struct
 pub use crate::widgets::file_input::OptionFileInputOnPathChange as AzOptionFileInputOnPathChangeTT;
 pub use AzOptionFileInputOnPathChangeTT as AzOptionFileInputOnPathChange;
 /// Destructor: Takes ownership of the `OptionFileInputOnPathChange` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionFileInputOnPathChange_delete(object: &mut AzOptionFileInputOnPathChange) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionFileInputOnPathChange_delete(object: &mut AzOptionFileInputOnPathChange) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionCheckBoxOnToggle` struct
 pub use crate::widgets::check_box::OptionCheckBoxOnToggle as AzOptionCheckBoxOnToggleTT;
 pub use AzOptionCheckBoxOnToggleTT as AzOptionCheckBoxOnToggle;
 /// Destructor: Takes ownership of the `OptionCheckBoxOnToggle` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionCheckBoxOnToggle_delete(object: &mut AzOptionCheckBoxOnToggle) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionCheckBoxOnToggle_delete(object: &mut AzOptionCheckBoxOnToggle) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionTextInputOnTextInput` struct
 pub use crate::widgets::text_input::OptionTextInputOnTextInput as AzOptionTextInputOnTextInputTT;
 pub use AzOptionTextInputOnTextInputTT as AzOptionTextInputOnTextInput;
 /// Destructor: Takes ownership of the `OptionTextInputOnTextInput` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionTextInputOnTextInput_delete(object: &mut AzOptionTextInputOnTextInput) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionTextInputOnTextInput_delete(object: &mut AzOptionTextInputOnTextInput) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionTextInputOnVirtualKeyDown` struct
 pub use crate::widgets::text_input::OptionTextInputOnVirtualKeyDown as AzOptionTextInputOnVirtualKeyDownTT;
 pub use AzOptionTextInputOnVirtualKeyDownTT as AzOptionTextInputOnVirtualKeyDown;
 /// Destructor: Takes ownership of the `OptionTextInputOnVirtualKeyDown` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionTextInputOnVirtualKeyDown_delete(object: &mut AzOptionTextInputOnVirtualKeyDown) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionTextInputOnVirtualKeyDown_delete(object: &mut AzOptionTextInputOnVirtualKeyDown) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionTextInputOnFocusLost` struct
 pub use crate::widgets::text_input::OptionTextInputOnFocusLost as AzOptionTextInputOnFocusLostTT;
 pub use AzOptionTextInputOnFocusLostTT as AzOptionTextInputOnFocusLost;
 /// Destructor: Takes ownership of the `OptionTextInputOnFocusLost` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionTextInputOnFocusLost_delete(object: &mut AzOptionTextInputOnFocusLost) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionTextInputOnFocusLost_delete(object: &mut AzOptionTextInputOnFocusLost) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionTextInputSelection` struct
 pub use crate::widgets::text_input::OptionTextInputSelection as AzOptionTextInputSelectionTT;
@@ -5027,37 +5833,41 @@ pub use AzOptionTextInputSelectionTT as AzOptionTextInputSelection;
 pub use crate::widgets::number_input::OptionNumberInputOnFocusLost as AzOptionNumberInputOnFocusLostTT;
 pub use AzOptionNumberInputOnFocusLostTT as AzOptionNumberInputOnFocusLost;
 /// Destructor: Takes ownership of the `OptionNumberInputOnFocusLost` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionNumberInputOnFocusLost_delete(object: &mut AzOptionNumberInputOnFocusLost) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionNumberInputOnFocusLost_delete(object: &mut AzOptionNumberInputOnFocusLost) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionNumberInputOnValueChange` struct
 pub use crate::widgets::number_input::OptionNumberInputOnValueChange as AzOptionNumberInputOnValueChangeTT;
 pub use AzOptionNumberInputOnValueChangeTT as AzOptionNumberInputOnValueChange;
 /// Destructor: Takes ownership of the `OptionNumberInputOnValueChange` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionNumberInputOnValueChange_delete(object: &mut AzOptionNumberInputOnValueChange) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionNumberInputOnValueChange_delete(object: &mut AzOptionNumberInputOnValueChange) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionMenuItemIcon` struct
 pub use azul_core::window::OptionMenuItemIcon as AzOptionMenuItemIconTT;
 pub use AzOptionMenuItemIconTT as AzOptionMenuItemIcon;
 /// Destructor: Takes ownership of the `OptionMenuItemIcon` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionMenuItemIcon_delete(object: &mut AzOptionMenuItemIcon) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionMenuItemIcon_delete(object: &mut AzOptionMenuItemIcon) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionMenuCallback` struct
 pub use azul_core::window::OptionMenuCallback as AzOptionMenuCallbackTT;
 pub use AzOptionMenuCallbackTT as AzOptionMenuCallback;
 /// Destructor: Takes ownership of the `OptionMenuCallback` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionMenuCallback_delete(object: &mut AzOptionMenuCallback) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionMenuCallback_delete(object: &mut AzOptionMenuCallback) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionVirtualKeyCodeCombo` struct
 pub use azul_core::window::OptionVirtualKeyCodeCombo as AzOptionVirtualKeyCodeComboTT;
 pub use AzOptionVirtualKeyCodeComboTT as AzOptionVirtualKeyCodeCombo;
 /// Destructor: Takes ownership of the `OptionVirtualKeyCodeCombo` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionVirtualKeyCodeCombo_delete(object: &mut AzOptionVirtualKeyCodeCombo) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionVirtualKeyCodeCombo_delete(object: &mut AzOptionVirtualKeyCodeCombo) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
 pub use azul_impl::css::OptionCssProperty as AzOptionCssPropertyTT;
 pub use AzOptionCssPropertyTT as AzOptionCssProperty;
 /// Destructor: Takes ownership of the `OptionCssProperty` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionCssProperty_delete(object: &mut AzOptionCssProperty) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionCssProperty_delete(object: &mut AzOptionCssProperty) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Re-export of rust-allocated (stack based) `OptionNormalizedLinearColorStop` struct
+pub use azul_impl::css::OptionNormalizedLinearColorStop as AzOptionNormalizedLinearColorStopTT;
+pub use AzOptionNormalizedLinearColorStopTT as AzOptionNormalizedLinearColorStop;
 
 /// Re-export of rust-allocated (stack based) `OptionPositionInfo` struct
 pub use azul_impl::ui_solver::OptionPositionInfo as AzOptionPositionInfoTT;
@@ -5087,31 +5897,31 @@ pub use AzOptionU32TT as AzOptionU32;
 pub use azul_impl::resources::OptionImageRef as AzOptionImageRefTT;
 pub use AzOptionImageRefTT as AzOptionImageRef;
 /// Destructor: Takes ownership of the `OptionImageRef` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionImageRef_delete(object: &mut AzOptionImageRef) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionImageRef_delete(object: &mut AzOptionImageRef) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionFontRef` struct
 pub use azul_impl::css::OptionFontRef as AzOptionFontRefTT;
 pub use AzOptionFontRefTT as AzOptionFontRef;
 /// Destructor: Takes ownership of the `OptionFontRef` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionFontRef_delete(object: &mut AzOptionFontRef) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionFontRef_delete(object: &mut AzOptionFontRef) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionSystemClipboard` struct
 pub use azul_impl::app::OptionClipboard as AzOptionSystemClipboardTT;
 pub use AzOptionSystemClipboardTT as AzOptionSystemClipboard;
 /// Destructor: Takes ownership of the `OptionSystemClipboard` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionSystemClipboard_delete(object: &mut AzOptionSystemClipboard) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionSystemClipboard_delete(object: &mut AzOptionSystemClipboard) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionFileTypeList` struct
 pub use azul_impl::dialogs::OptionFileTypeList as AzOptionFileTypeListTT;
 pub use AzOptionFileTypeListTT as AzOptionFileTypeList;
 /// Destructor: Takes ownership of the `OptionFileTypeList` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionFileTypeList_delete(object: &mut AzOptionFileTypeList) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionFileTypeList_delete(object: &mut AzOptionFileTypeList) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
 pub use azul_core::window::OptionWindowState as AzOptionWindowStateTT;
 pub use AzOptionWindowStateTT as AzOptionWindowState;
 /// Destructor: Takes ownership of the `OptionWindowState` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionWindowState_delete(object: &mut AzOptionWindowState) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionWindowState_delete(object: &mut AzOptionWindowState) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionMouseState` struct
 pub use azul_core::window::OptionMouseState as AzOptionMouseStateTT;
@@ -5121,31 +5931,31 @@ pub use AzOptionMouseStateTT as AzOptionMouseState;
 pub use azul_core::window::OptionKeyboardState as AzOptionKeyboardStateTT;
 pub use AzOptionKeyboardStateTT as AzOptionKeyboardState;
 /// Destructor: Takes ownership of the `OptionKeyboardState` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionKeyboardState_delete(object: &mut AzOptionKeyboardState) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionKeyboardState_delete(object: &mut AzOptionKeyboardState) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionStringVec` struct
 pub use azul_impl::css::OptionStringVec as AzOptionStringVecTT;
 pub use AzOptionStringVecTT as AzOptionStringVec;
 /// Destructor: Takes ownership of the `OptionStringVec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionStringVec_delete(object: &mut AzOptionStringVec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionStringVec_delete(object: &mut AzOptionStringVec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionFile` struct
 pub use azul_impl::file::OptionFile as AzOptionFileTT;
 pub use AzOptionFileTT as AzOptionFile;
 /// Destructor: Takes ownership of the `OptionFile` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionFile_delete(object: &mut AzOptionFile) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionFile_delete(object: &mut AzOptionFile) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionGl` struct
 pub use azul_impl::gl::OptionGlContextPtr as AzOptionGlTT;
 pub use AzOptionGlTT as AzOptionGl;
 /// Destructor: Takes ownership of the `OptionGl` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionGl_delete(object: &mut AzOptionGl) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionGl_delete(object: &mut AzOptionGl) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionThreadReceiveMsg` struct
 pub use azul_impl::task::OptionThreadReceiveMsg as AzOptionThreadReceiveMsgTT;
 pub use AzOptionThreadReceiveMsgTT as AzOptionThreadReceiveMsg;
 /// Destructor: Takes ownership of the `OptionThreadReceiveMsg` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionThreadReceiveMsg_delete(object: &mut AzOptionThreadReceiveMsg) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionThreadReceiveMsg_delete(object: &mut AzOptionThreadReceiveMsg) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionPercentageValue` struct
 pub use azul_impl::css::OptionPercentageValue as AzOptionPercentageValueTT;
@@ -5167,7 +5977,7 @@ pub use AzOptionCallbackTT as AzOptionCallback;
 pub use azul_impl::task::OptionThreadSendMsg as AzOptionThreadSendMsgTT;
 pub use AzOptionThreadSendMsgTT as AzOptionThreadSendMsg;
 /// Destructor: Takes ownership of the `OptionThreadSendMsg` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionThreadSendMsg_delete(object: &mut AzOptionThreadSendMsg) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionThreadSendMsg_delete(object: &mut AzOptionThreadSendMsg) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionLayoutRect` struct
 pub use azul_impl::css::OptionLayoutRect as AzOptionLayoutRectTT;
@@ -5177,13 +5987,13 @@ pub use AzOptionLayoutRectTT as AzOptionLayoutRect;
 pub use azul_impl::callbacks::OptionRefAny as AzOptionRefAnyTT;
 pub use AzOptionRefAnyTT as AzOptionRefAny;
 /// Destructor: Takes ownership of the `OptionRefAny` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionRefAny_delete(object: &mut AzOptionRefAny) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionRefAny_delete(object: &mut AzOptionRefAny) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionInlineText` struct
 pub use azul_impl::callbacks::OptionInlineText as AzOptionInlineTextTT;
 pub use AzOptionInlineTextTT as AzOptionInlineText;
 /// Destructor: Takes ownership of the `OptionInlineText` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionInlineText_delete(object: &mut AzOptionInlineText) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionInlineText_delete(object: &mut AzOptionInlineText) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionLayoutPoint` struct
 pub use azul_impl::css::OptionLayoutPoint as AzOptionLayoutPointTT;
@@ -5213,7 +6023,7 @@ pub use AzOptionColorUTT as AzOptionColorU;
 pub use azul_impl::resources::OptionRawImage as AzOptionRawImageTT;
 pub use AzOptionRawImageTT as AzOptionRawImage;
 /// Destructor: Takes ownership of the `OptionRawImage` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionRawImage_delete(object: &mut AzOptionRawImage) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionRawImage_delete(object: &mut AzOptionRawImage) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionSvgDashPattern` struct
 pub use azul_impl::svg::OptionSvgDashPattern as AzOptionSvgDashPatternTT;
@@ -5223,13 +6033,13 @@ pub use AzOptionSvgDashPatternTT as AzOptionSvgDashPattern;
 pub use azul_core::window::OptionWaylandTheme as AzOptionWaylandThemeTT;
 pub use AzOptionWaylandThemeTT as AzOptionWaylandTheme;
 /// Destructor: Takes ownership of the `OptionWaylandTheme` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionWaylandTheme_delete(object: &mut AzOptionWaylandTheme) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionWaylandTheme_delete(object: &mut AzOptionWaylandTheme) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionTaskBarIcon` struct
 pub use azul_core::window::OptionTaskBarIcon as AzOptionTaskBarIconTT;
 pub use AzOptionTaskBarIconTT as AzOptionTaskBarIcon;
 /// Destructor: Takes ownership of the `OptionTaskBarIcon` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionTaskBarIcon_delete(object: &mut AzOptionTaskBarIcon) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionTaskBarIcon_delete(object: &mut AzOptionTaskBarIcon) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionHwndHandle` struct
 pub use azul_core::window::OptionHwndHandle as AzOptionHwndHandleTT;
@@ -5239,6 +6049,10 @@ pub use AzOptionHwndHandleTT as AzOptionHwndHandle;
 pub use azul_core::window::OptionLogicalPosition as AzOptionLogicalPositionTT;
 pub use AzOptionLogicalPositionTT as AzOptionLogicalPosition;
 
+/// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+pub use azul_core::window::OptionLogicalRect as AzOptionLogicalRectTT;
+pub use AzOptionLogicalRectTT as AzOptionLogicalRect;
+
 /// Re-export of rust-allocated (stack based) `OptionPhysicalPositionI32` struct
 pub use azul_core::window::OptionPhysicalPositionI32 as AzOptionPhysicalPositionI32TT;
 pub use AzOptionPhysicalPositionI32TT as AzOptionPhysicalPositionI32;
@@ -5247,13 +6061,13 @@ pub use AzOptionPhysicalPositionI32TT as AzOptionPhysicalPositionI32;
 pub use azul_core::window::OptionWindowIcon as AzOptionWindowIconTT;
 pub use AzOptionWindowIconTT as AzOptionWindowIcon;
 /// Destructor: Takes ownership of the `OptionWindowIcon` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionWindowIcon_delete(object: &mut AzOptionWindowIcon) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionWindowIcon_delete(object: &mut AzOptionWindowIcon) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionString` struct
 pub use azul_impl::css::OptionAzString as AzOptionStringTT;
 pub use AzOptionStringTT as AzOptionString;
 /// Destructor: Takes ownership of the `OptionString` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionString_delete(object: &mut AzOptionString) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionString_delete(object: &mut AzOptionString) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionX11Visual` struct
 pub use azul_core::window::OptionX11Visual as AzOptionX11VisualTT;
@@ -5275,6 +6089,16 @@ pub use AzOptionMouseCursorTypeTT as AzOptionMouseCursorType;
 pub use azul_core::window::OptionLogicalSize as AzOptionLogicalSizeTT;
 pub use AzOptionLogicalSizeTT as AzOptionLogicalSize;
 
+/// Re-export of rust-allocated (stack based) `OptionImeEvent` struct
+pub use azul_core::window::OptionImeEvent as AzOptionImeEventTT;
+pub use AzOptionImeEventTT as AzOptionImeEvent;
+/// Destructor: Takes ownership of the `OptionImeEvent` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionImeEvent_delete(object: &mut AzOptionImeEvent) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
+
+/// Re-export of rust-allocated (stack based) `OptionImeCursorRange` struct
+pub use azul_core::window::OptionImeCursorRange as AzOptionImeCursorRangeTT;
+pub use AzOptionImeCursorRangeTT as AzOptionImeCursorRange;
+
 /// Option<char> but the char is a u32, for C FFI stability reasons
 pub use azul_core::window::OptionChar as AzOptionCharTT;
 pub use AzOptionCharTT as AzOptionChar;
@@ -5287,19 +6111,19 @@ pub use AzOptionVirtualKeyCodeTT as AzOptionVirtualKeyCode;
 pub use azul_impl::dom::OptionDom as AzOptionDomTT;
 pub use AzOptionDomTT as AzOptionDom;
 /// Destructor: Takes ownership of the `OptionDom` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionDom_delete(object: &mut AzOptionDom) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionDom_delete(object: &mut AzOptionDom) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionTexture` struct
 pub use azul_impl::gl::OptionTexture as AzOptionTextureTT;
 pub use AzOptionTextureTT as AzOptionTexture;
 /// Destructor: Takes ownership of the `OptionTexture` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionTexture_delete(object: &mut AzOptionTexture) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionTexture_delete(object: &mut AzOptionTexture) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionImageMask` struct
 pub use azul_impl::resources::OptionImageMask as AzOptionImageMaskTT;
 pub use AzOptionImageMaskTT as AzOptionImageMask;
 /// Destructor: Takes ownership of the `OptionImageMask` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionImageMask_delete(object: &mut AzOptionImageMask) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionImageMask_delete(object: &mut AzOptionImageMask) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionTabIndex` struct
 pub use azul_impl::dom::OptionTabIndex as AzOptionTabIndexTT;
@@ -5317,7 +6141,7 @@ pub use AzOptionDurationTT as AzOptionDuration;
 pub use azul_impl::task::OptionInstant as AzOptionInstantTT;
 pub use AzOptionInstantTT as AzOptionInstant;
 /// Destructor: Takes ownership of the `OptionInstant` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionInstant_delete(object: &mut AzOptionInstant) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionInstant_delete(object: &mut AzOptionInstant) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionUsize` struct
 pub use azul_impl::gl::OptionUsize as AzOptionUsizeTT;
@@ -5327,7 +6151,7 @@ pub use AzOptionUsizeTT as AzOptionUsize;
 pub use azul_impl::css::OptionU8Vec as AzOptionU8VecTT;
 pub use AzOptionU8VecTT as AzOptionU8Vec;
 /// Destructor: Takes ownership of the `OptionU8Vec` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzOptionU8Vec_delete(object: &mut AzOptionU8Vec) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzOptionU8Vec_delete(object: &mut AzOptionU8Vec) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `OptionU8VecRef` struct
 pub use azul_impl::gl::OptionU8VecRef as AzOptionU8VecRefTT;
@@ -5337,91 +6161,91 @@ pub use AzOptionU8VecRefTT as AzOptionU8VecRef;
 pub use azul_impl::xml::ResultXmlXmlError as AzResultXmlXmlErrorTT;
 pub use AzResultXmlXmlErrorTT as AzResultXmlXmlError;
 /// Destructor: Takes ownership of the `ResultXmlXmlError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzResultXmlXmlError_delete(object: &mut AzResultXmlXmlError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzResultXmlXmlError_delete(object: &mut AzResultXmlXmlError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ResultRawImageDecodeImageError` struct
 pub use azul_impl::resources::decode::ResultRawImageDecodeImageError as AzResultRawImageDecodeImageErrorTT;
 pub use AzResultRawImageDecodeImageErrorTT as AzResultRawImageDecodeImageError;
 /// Destructor: Takes ownership of the `ResultRawImageDecodeImageError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzResultRawImageDecodeImageError_delete(object: &mut AzResultRawImageDecodeImageError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzResultRawImageDecodeImageError_delete(object: &mut AzResultRawImageDecodeImageError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ResultU8VecEncodeImageError` struct
 pub use azul_impl::resources::encode::ResultU8VecEncodeImageError as AzResultU8VecEncodeImageErrorTT;
 pub use AzResultU8VecEncodeImageErrorTT as AzResultU8VecEncodeImageError;
 /// Destructor: Takes ownership of the `ResultU8VecEncodeImageError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzResultU8VecEncodeImageError_delete(object: &mut AzResultU8VecEncodeImageError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzResultU8VecEncodeImageError_delete(object: &mut AzResultU8VecEncodeImageError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ResultSvgXmlNodeSvgParseError` struct
 pub use azul_impl::svg::ResultSvgXmlNodeSvgParseError as AzResultSvgXmlNodeSvgParseErrorTT;
 pub use AzResultSvgXmlNodeSvgParseErrorTT as AzResultSvgXmlNodeSvgParseError;
 /// Destructor: Takes ownership of the `ResultSvgXmlNodeSvgParseError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzResultSvgXmlNodeSvgParseError_delete(object: &mut AzResultSvgXmlNodeSvgParseError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzResultSvgXmlNodeSvgParseError_delete(object: &mut AzResultSvgXmlNodeSvgParseError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `ResultSvgSvgParseError` struct
 pub use azul_impl::svg::ResultSvgSvgParseError as AzResultSvgSvgParseErrorTT;
 pub use AzResultSvgSvgParseErrorTT as AzResultSvgSvgParseError;
 /// Destructor: Takes ownership of the `ResultSvgSvgParseError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzResultSvgSvgParseError_delete(object: &mut AzResultSvgSvgParseError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzResultSvgSvgParseError_delete(object: &mut AzResultSvgSvgParseError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `SvgParseError` struct
 pub use azul_impl::svg::SvgParseError as AzSvgParseErrorTT;
 pub use AzSvgParseErrorTT as AzSvgParseError;
 /// Destructor: Takes ownership of the `SvgParseError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzSvgParseError_delete(object: &mut AzSvgParseError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzSvgParseError_delete(object: &mut AzSvgParseError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `XmlError` struct
 pub use azul_impl::xml::XmlError as AzXmlErrorTT;
 pub use AzXmlErrorTT as AzXmlError;
 /// Destructor: Takes ownership of the `XmlError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzXmlError_delete(object: &mut AzXmlError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzXmlError_delete(object: &mut AzXmlError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `DuplicatedNamespaceError` struct
 pub use azul_impl::xml::DuplicatedNamespaceError as AzDuplicatedNamespaceErrorTT;
 pub use AzDuplicatedNamespaceErrorTT as AzDuplicatedNamespaceError;
 /// Destructor: Takes ownership of the `DuplicatedNamespaceError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzDuplicatedNamespaceError_delete(object: &mut AzDuplicatedNamespaceError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzDuplicatedNamespaceError_delete(object: &mut AzDuplicatedNamespaceError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `UnknownNamespaceError` struct
 pub use azul_impl::xml::UnknownNamespaceError as AzUnknownNamespaceErrorTT;
 pub use AzUnknownNamespaceErrorTT as AzUnknownNamespaceError;
 /// Destructor: Takes ownership of the `UnknownNamespaceError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzUnknownNamespaceError_delete(object: &mut AzUnknownNamespaceError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzUnknownNamespaceError_delete(object: &mut AzUnknownNamespaceError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `UnexpectedCloseTagError` struct
 pub use azul_impl::xml::UnexpectedCloseTagError as AzUnexpectedCloseTagErrorTT;
 pub use AzUnexpectedCloseTagErrorTT as AzUnexpectedCloseTagError;
 /// Destructor: Takes ownership of the `UnexpectedCloseTagError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzUnexpectedCloseTagError_delete(object: &mut AzUnexpectedCloseTagError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzUnexpectedCloseTagError_delete(object: &mut AzUnexpectedCloseTagError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `UnknownEntityReferenceError` struct
 pub use azul_impl::xml::UnknownEntityReferenceError as AzUnknownEntityReferenceErrorTT;
 pub use AzUnknownEntityReferenceErrorTT as AzUnknownEntityReferenceError;
 /// Destructor: Takes ownership of the `UnknownEntityReferenceError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzUnknownEntityReferenceError_delete(object: &mut AzUnknownEntityReferenceError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzUnknownEntityReferenceError_delete(object: &mut AzUnknownEntityReferenceError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `DuplicatedAttributeError` struct
 pub use azul_impl::xml::DuplicatedAttributeError as AzDuplicatedAttributeErrorTT;
 pub use AzDuplicatedAttributeErrorTT as AzDuplicatedAttributeError;
 /// Destructor: Takes ownership of the `DuplicatedAttributeError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzDuplicatedAttributeError_delete(object: &mut AzDuplicatedAttributeError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzDuplicatedAttributeError_delete(object: &mut AzDuplicatedAttributeError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `XmlParseError` struct
 pub use azul_impl::xml::XmlParseError as AzXmlParseErrorTT;
 pub use AzXmlParseErrorTT as AzXmlParseError;
 /// Destructor: Takes ownership of the `XmlParseError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzXmlParseError_delete(object: &mut AzXmlParseError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzXmlParseError_delete(object: &mut AzXmlParseError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `XmlTextError` struct
 pub use azul_impl::xml::XmlTextError as AzXmlTextErrorTT;
 pub use AzXmlTextErrorTT as AzXmlTextError;
 /// Destructor: Takes ownership of the `XmlTextError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzXmlTextError_delete(object: &mut AzXmlTextError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzXmlTextError_delete(object: &mut AzXmlTextError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `XmlStreamError` struct
 pub use azul_impl::xml::XmlStreamError as AzXmlStreamErrorTT;
 pub use AzXmlStreamErrorTT as AzXmlStreamError;
 /// Destructor: Takes ownership of the `XmlStreamError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzXmlStreamError_delete(object: &mut AzXmlStreamError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzXmlStreamError_delete(object: &mut AzXmlStreamError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `NonXmlCharError` struct
 pub use azul_impl::xml::NonXmlCharError as AzNonXmlCharErrorTT;
@@ -5435,7 +6259,7 @@ pub use AzInvalidCharErrorTT as AzInvalidCharError;
 pub use azul_impl::xml::InvalidCharMultipleError as AzInvalidCharMultipleErrorTT;
 pub use AzInvalidCharMultipleErrorTT as AzInvalidCharMultipleError;
 /// Destructor: Takes ownership of the `InvalidCharMultipleError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInvalidCharMultipleError_delete(object: &mut AzInvalidCharMultipleError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInvalidCharMultipleError_delete(object: &mut AzInvalidCharMultipleError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `InvalidQuoteError` struct
 pub use azul_impl::xml::InvalidQuoteError as AzInvalidQuoteErrorTT;
@@ -5449,7 +6273,7 @@ pub use AzInvalidSpaceErrorTT as AzInvalidSpaceError;
 pub use azul_impl::xml::InvalidStringError as AzInvalidStringErrorTT;
 pub use AzInvalidStringErrorTT as AzInvalidStringError;
 /// Destructor: Takes ownership of the `InvalidStringError` pointer and deletes it.
-#[no_mangle] pub extern "C" fn AzInvalidStringError_delete(object: &mut AzInvalidStringError) {  unsafe { core::ptr::drop_in_place(object); } }
+#[no_mangle] pub extern "C" fn AzInvalidStringError_delete(object: &mut AzInvalidStringError) { ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } }) }
 
 /// Re-export of rust-allocated (stack based) `SvgParseErrorPosition` struct
 pub use azul_impl::xml::XmlTextPos as AzSvgParseErrorPositionTT;
@@ -5513,6 +6337,11 @@ mod test_sizes {
         impl ::core::fmt::Debug for AzStyleBackgroundRepeatVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzStyleBackgroundRepeatVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
         impl ::core::fmt::Debug for AzStyleBackgroundSizeVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzStyleBackgroundSizeVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
         impl ::core::fmt::Debug for AzStyleTransformVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzStyleTransformVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
+        impl ::core::fmt::Debug for AzGridTrackVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzGridTrackVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
+        impl ::core::fmt::Debug for AzCssPropertyTypeVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzCssPropertyTypeVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
+        impl ::core::fmt::Debug for AzCssVariableVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzCssVariableVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
+        impl ::core::fmt::Debug for AzMediaFeatureVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzMediaFeatureVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
+        impl ::core::fmt::Debug for AzMediaConditionVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzMediaConditionVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
         impl ::core::fmt::Debug for AzCssPropertyVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzCssPropertyVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
         impl ::core::fmt::Debug for AzSvgMultiPolygonVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzSvgMultiPolygonVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
         impl ::core::fmt::Debug for AzSvgPathVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzSvgPathVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
@@ -5536,6 +6365,7 @@ mod test_sizes {
         impl ::core::fmt::Debug for AzGLintVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzGLintVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
         impl ::core::fmt::Debug for AzStringVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzStringVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
         impl ::core::fmt::Debug for AzStringPairVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzStringPairVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
+        impl ::core::fmt::Debug for AzAcceleratorCallbackVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzAcceleratorCallbackVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
         impl ::core::fmt::Debug for AzNormalizedLinearColorStopVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzNormalizedLinearColorStopVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
         impl ::core::fmt::Debug for AzNormalizedRadialColorStopVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzNormalizedRadialColorStopVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
         impl ::core::fmt::Debug for AzNodeIdVecDestructor { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { use AzNodeIdVecDestructor::*; match self { DefaultRust => write!(f, "DefaultRust"), NoDestructor => write!(f, "NoDestructor"), External(_) => write!(f, "External"), }}}
@@ -5565,6 +6395,14 @@ mod test_sizes {
         Trace,
     }
 
+    /// Controls how often a window renders a new frame
+    #[repr(C, u8)]
+    pub enum AzRedrawBehavior {
+        OnEventOnly,
+        MaxFps(u16),
+        Unlimited,
+    }
+
     /// Version of the layout solver to use - future binary versions of azul may have more fields here, necessary so that old compiled applications don't break with newer releases of azul. Newer layout versions are opt-in only.
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5572,6 +6410,12 @@ mod test_sizes {
         Default,
     }
 
+    /// Version and feature detection of the loaded library. Bindings that load the library dynamically should call these functions before anything else and compare the results with the `AZ_API_VERSION_*` and `AZ_API_ABI_HASH` defines of the header they were generated from. The functions only use primitive types, so they can be called even if the rest of the API does not match.
+    #[repr(C)]
+    pub struct AzApi {
+        pub _reserved: usize,
+    }
+
     /// Whether the renderer has VSync enabled
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5897,6 +6741,21 @@ mod test_sizes {
         Cut,
     }
 
+    /// Which modifier keys are held down (left and right keys are not distinguished)
+    #[repr(C)]
+    pub struct AzModifiers {
+        pub shift: bool,
+        pub ctrl: bool,
+        pub alt: bool,
+        pub super_key: bool,
+    }
+
+    /// ID of a registered accelerator, used to replace or remove it
+    #[repr(C)]
+    pub struct AzAcceleratorId {
+        pub id: usize,
+    }
+
     /// State of the window frame (minimized, maximized, fullscreen or normal window)
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -5940,6 +6799,13 @@ mod test_sizes {
         pub force_picture_invalidation: bool,
     }
 
+    /// Byte range in the `ImePreedit` text
+    #[repr(C)]
+    pub struct AzImeCursorRange {
+        pub start: usize,
+        pub end: usize,
+    }
+
     /// Current icon of the mouse cursor
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -6151,6 +7017,9 @@ mod test_sizes {
     /// `AzThreadCallbackType` struct
     pub type AzThreadCallbackType = extern "C" fn(AzRefAny, AzThreadSender, AzThreadReceiver);
 
+    /// `AzThreadFnType` struct
+    pub type AzThreadFnType = extern "C" fn(&mut AzRefAny, &mut AzThreadSender, &mut AzThreadReceiver);
+
     /// `AzRefAnyDestructorType` struct
     pub type AzRefAnyDestructorType = extern "C" fn(&mut c_void);
 
@@ -6213,6 +7082,9 @@ mod test_sizes {
         TouchMove,
         TouchEnd,
         TouchCancel,
+        ImeStateChanged,
+        ImePreedit,
+        ImeCommit,
     }
 
     /// Re-export of rust-allocated (stack based) `FocusEventFilter` struct
@@ -6237,6 +7109,9 @@ mod test_sizes {
         VirtualKeyUp,
         FocusReceived,
         FocusLost,
+        ImeStateChanged,
+        ImePreedit,
+        ImeCommit,
     }
 
     /// Re-export of rust-allocated (stack based) `WindowEventFilter` struct
@@ -6272,6 +7147,11 @@ mod test_sizes {
         FocusLost,
         CloseRequested,
         ThemeChanged,
+        WindowFocusReceived,
+        WindowFocusLost,
+        ImeStateChanged,
+        ImePreedit,
+        ImeCommit,
     }
 
     /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -6438,6 +7318,20 @@ mod test_sizes {
         pub offset: u32,
     }
 
+    /// Value of the `orientation` media feature
+    #[repr(C)]
+    pub enum AzMediaOrientation {
+        Portrait,
+        Landscape,
+    }
+
+    /// Value of the `prefers-color-scheme` media feature
+    #[repr(C)]
+    pub enum AzMediaColorScheme {
+        Light,
+        Dark,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
     #[repr(C)]
     pub enum AzCssPropertyType {
@@ -6445,6 +7339,8 @@ mod test_sizes {
         FontSize,
         FontFamily,
         TextAlign,
+        VerticalAlign,
+        Direction,
         LetterSpacing,
         LineHeight,
         WordSpacing,
@@ -6471,6 +7367,10 @@ mod test_sizes {
         JustifyContent,
         AlignItems,
         AlignContent,
+        GridTemplateColumns,
+        GridTemplateRows,
+        GridColumn,
+        GridRow,
         BackgroundContent,
         BackgroundPosition,
         BackgroundSize,
@@ -6512,9 +7412,18 @@ mod test_sizes {
         PerspectiveOrigin,
         BackfaceVisibility,
         MixBlendMode,
+        Isolation,
+        ImageRendering,
         Filter,
         BackdropFilter,
         TextShadow,
+        TransitionProperty,
+        TransitionDuration,
+        TransitionTimingFunction,
+        TransitionDelay,
+        CaretColor,
+        SelectionColor,
+        SelectionBackground,
     }
 
     /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -6573,6 +7482,21 @@ mod test_sizes {
         Luminosity,
     }
 
+    /// Represents an `isolation` attribute: whether the element creates a new stacking context for `mix-blend-mode`
+    #[repr(C)]
+    pub enum AzStyleIsolation {
+        Auto,
+        Isolate,
+    }
+
+    /// Represents an `image-rendering` attribute: which algorithm is used to scale images and background images
+    #[repr(C)]
+    pub enum AzStyleImageRendering {
+        Auto,
+        CrispEdges,
+        Pixelated,
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutAlignContent` struct
     #[repr(C)]
     pub enum AzLayoutAlignContent {
@@ -6616,6 +7540,16 @@ mod test_sizes {
         Flex,
         Block,
         InlineBlock,
+        Grid,
+        Inline,
+    }
+
+    /// Start or end line of a `grid-column` or `grid-row` attribute
+    #[repr(C, u8)]
+    pub enum AzGridLine {
+        Auto,
+        Line(i16),
+        Span(u16),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -6623,6 +7557,7 @@ mod test_sizes {
     pub enum AzLayoutFloat {
         Left,
         Right,
+        None,
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutJustifyContent` struct
@@ -6650,6 +7585,7 @@ mod test_sizes {
     pub enum AzLayoutFlexWrap {
         Wrap,
         NoWrap,
+        WrapReverse,
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutOverflow` struct
@@ -6659,6 +7595,7 @@ mod test_sizes {
         Auto,
         Hidden,
         Visible,
+        Clip,
     }
 
     /// Re-export of rust-allocated (stack based) `AngleMetric` struct
@@ -6719,6 +7656,8 @@ mod test_sizes {
         Repeat,
         RepeatX,
         RepeatY,
+        Space,
+        Round,
     }
 
     /// Re-export of rust-allocated (stack based) `BorderStyle` struct
@@ -6785,6 +7724,24 @@ mod test_sizes {
         Left,
         Center,
         Right,
+        Start,
+        End,
+    }
+
+    /// Represents a `direction` attribute: the inline base direction of text and of `flex-direction: row` - default: `Ltr`
+    #[repr(C)]
+    pub enum AzStyleDirection {
+        Ltr,
+        Rtl,
+    }
+
+    /// Position of the jumps of a `steps()` timing function - default: `JumpEnd`
+    #[repr(C)]
+    pub enum AzStepPosition {
+        JumpStart,
+        JumpEnd,
+        JumpNone,
+        JumpBoth,
     }
 
     /// Re-export of rust-allocated (stack based) `Ribbon` struct
@@ -7601,7 +8558,7 @@ mod test_sizes {
         pub id: usize,
     }
 
-    /// Re-export of rust-allocated (stack based) `Thread` struct
+    /// Background thread, started via `CallbackInfo::start_thread`. The thread function receives the `thread_initialize_data`, a `ThreadSender` and a `ThreadReceiver` by value: in C, the thread function has to delete all three before it returns. Progress and results are sent back with `ThreadSender::send(ThreadReceiveMsg::WriteBack(..))`: the `WriteBackCallback` then runs on the main thread with the `writeback_data` and the sent data (which is deleted after the callback returns). Once the thread function has returned and all of its messages have been processed, the thread is removed and the `writeback_data` is deleted. `CallbackInfo::stop_thread` sends a `ThreadSendMsg::TerminateThread` and joins the thread, so the thread function should check `ThreadReceiver::receive` regularly and return early.
     #[repr(C)]
     pub struct AzThread {
         pub(crate) ptr: *const c_void,
@@ -7712,6 +8669,14 @@ mod test_sizes {
         pub cb: AzThreadSenderDestructorFnType,
     }
 
+    /// Position of the text cursor (caret) in a UTF-8 string. `byte_offset` is always on a grapheme boundary, `grapheme_offset` is the number of graphemes before the cursor.
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct AzTextCursor {
+        pub byte_offset: usize,
+        pub grapheme_offset: usize,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzStyleFontFamilyVecDestructor {
@@ -7987,6 +8952,17 @@ mod test_sizes {
     /// `AzVideoModeVecDestructorType` struct
     pub type AzVideoModeVecDestructorType = extern "C" fn(&mut AzVideoModeVec);
 
+    /// Re-export of rust-allocated (stack based) `AcceleratorCallbackVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzAcceleratorCallbackVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzAcceleratorCallbackVecDestructorType),
+    }
+
+    /// `AzAcceleratorCallbackVecDestructorType` struct
+    pub type AzAcceleratorCallbackVecDestructorType = extern "C" fn(&mut AzAcceleratorCallbackVec);
+
     /// Re-export of rust-allocated (stack based) `DomVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzDomVecDestructor {
@@ -8075,6 +9051,61 @@ mod test_sizes {
     /// `AzStyleTransformVecDestructorType` struct
     pub type AzStyleTransformVecDestructorType = extern "C" fn(&mut AzStyleTransformVec);
 
+    /// Re-export of rust-allocated (stack based) `GridTrackVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzGridTrackVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzGridTrackVecDestructorType),
+    }
+
+    /// `AzGridTrackVecDestructorType` struct
+    pub type AzGridTrackVecDestructorType = extern "C" fn(&mut AzGridTrackVec);
+
+    /// Re-export of rust-allocated (stack based) `CssPropertyTypeVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssPropertyTypeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssPropertyTypeVecDestructorType),
+    }
+
+    /// `AzCssPropertyTypeVecDestructorType` struct
+    pub type AzCssPropertyTypeVecDestructorType = extern "C" fn(&mut AzCssPropertyTypeVec);
+
+    /// Re-export of rust-allocated (stack based) `CssVariableVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssVariableVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssVariableVecDestructorType),
+    }
+
+    /// `AzCssVariableVecDestructorType` struct
+    pub type AzCssVariableVecDestructorType = extern "C" fn(&mut AzCssVariableVec);
+
+    /// Re-export of rust-allocated (stack based) `MediaFeatureVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzMediaFeatureVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzMediaFeatureVecDestructorType),
+    }
+
+    /// `AzMediaFeatureVecDestructorType` struct
+    pub type AzMediaFeatureVecDestructorType = extern "C" fn(&mut AzMediaFeatureVec);
+
+    /// Re-export of rust-allocated (stack based) `MediaConditionVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzMediaConditionVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzMediaConditionVecDestructorType),
+    }
+
+    /// `AzMediaConditionVecDestructorType` struct
+    pub type AzMediaConditionVecDestructorType = extern "C" fn(&mut AzMediaConditionVec);
+
     /// Re-export of rust-allocated (stack based) `CssPropertyVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzCssPropertyVecDestructor {
@@ -8575,6 +9606,13 @@ mod test_sizes {
         Key(AzVirtualKeyCode),
     }
 
+    /// Window-wide keyboard shortcut (i.e. `Ctrl+S`) that fires regardless of which node is focused
+    #[repr(C)]
+    pub struct AzAccelerator {
+        pub modifiers: AzModifiers,
+        pub key: AzVirtualKeyCode,
+    }
+
     /// Boolean flags relating to the current window state
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -8755,6 +9793,22 @@ mod test_sizes {
         pub number: AzFloatValue,
     }
 
+    /// Simplified `calc()` expression: sum of lengths with different metrics, i.e. `calc(100% - 32px)` is stored as `{ percent: 100, px: -32 }`
+    #[repr(C)]
+    pub struct AzCalcLength {
+        pub px: AzFloatValue,
+        pub pt: AzFloatValue,
+        pub em: AzFloatValue,
+        pub percent: AzFloatValue,
+    }
+
+    /// Value of a boxed `Calc` length: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzCalcLengthBoxed {
+        pub run_destructor: bool,
+        pub value: AzCalcLength,
+    }
+
     /// Re-export of rust-allocated (stack based) `PixelValueNoPercent` struct
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -8810,6 +9864,25 @@ mod test_sizes {
         pub inner: AzPixelValue,
     }
 
+    /// Start / end line pair of a `grid-column` or `grid-row` attribute
+    #[repr(C)]
+    pub struct AzGridPlacement {
+        pub start: AzGridLine,
+        pub end: AzGridLine,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridColumn` struct
+    #[repr(C)]
+    pub struct AzLayoutGridColumn {
+        pub inner: AzGridPlacement,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridRow` struct
+    #[repr(C)]
+    pub struct AzLayoutGridRow {
+        pub inner: AzGridPlacement,
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutFlexGrow` struct
     #[repr(C)]
     pub struct AzLayoutFlexGrow {
@@ -9229,18 +10302,97 @@ mod test_sizes {
         pub y: AzPercentageValue,
     }
 
+    /// Represents a `transition-duration` attribute, in milliseconds
+    #[repr(C)]
+    pub struct AzStyleTransitionDuration {
+        pub inner: AzFloatValue,
+    }
+
+    /// Represents a `transition-delay` attribute, in milliseconds (can be negative)
+    #[repr(C)]
+    pub struct AzStyleTransitionDelay {
+        pub inner: AzFloatValue,
+    }
+
+    /// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+    #[repr(C)]
+    pub struct AzStyleCubicBezier {
+        pub x1: AzFloatValue,
+        pub y1: AzFloatValue,
+        pub x2: AzFloatValue,
+        pub y2: AzFloatValue,
+    }
+
+    /// A `steps(count, position)` timing function
+    #[repr(C)]
+    pub struct AzStyleSteps {
+        pub count: u32,
+        pub position: AzStepPosition,
+    }
+
+    /// Represents a `transition-timing-function` attribute - default: `Ease`
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionTimingFunction {
+        Linear,
+        Ease,
+        EaseIn,
+        EaseOut,
+        EaseInOut,
+        CubicBezier(AzStyleCubicBezier),
+        Steps(AzStyleSteps),
+    }
+
+    /// Represents a `vertical-align` attribute, aligns an inline item (i.e. an icon next to text) inside of its line - default: `Baseline`
+    #[repr(C, u8)]
+    pub enum AzStyleVerticalAlign {
+        Baseline,
+        Top,
+        Middle,
+        Bottom,
+        TextTop,
+        TextBottom,
+        Sub,
+        Super,
+        Exact(AzPixelValue),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
     #[repr(C)]
     pub struct AzStyleTextColor {
         pub inner: AzColorU,
     }
 
+    /// Represents a `caret-color` attribute: the color of the text cursor in editable text
+    #[repr(C)]
+    pub struct AzStyleCaretColor {
+        pub inner: AzColorU,
+    }
+
+    /// Represents a `-azul-selection-color` attribute: the text color of selected text (same as `::selection { color }`)
+    #[repr(C)]
+    pub struct AzStyleSelectionColor {
+        pub inner: AzColorU,
+    }
+
+    /// Represents a `-azul-selection-background-color` attribute: the highlight color behind selected text
+    #[repr(C)]
+    pub struct AzStyleSelectionBackground {
+        pub inner: AzColorU,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleWordSpacing` struct
     #[repr(C)]
     pub struct AzStyleWordSpacing {
         pub inner: AzPixelValue,
     }
 
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleBoxShadowBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleBoxShadow,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBoxShadowValue` struct
     #[repr(C, u8)]
     pub enum AzStyleBoxShadowValue {
@@ -9248,7 +10400,7 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzStyleBoxShadow),
+        Exact(*const AzStyleBoxShadowBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutAlignContentValue` struct
@@ -9261,6 +10413,26 @@ mod test_sizes {
         Exact(AzLayoutAlignContent),
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutGridColumnValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutGridColumnValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridColumn),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridRowValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutGridRowValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridRow),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
     #[repr(C, u8)]
     pub enum AzLayoutAlignItemsValue {
@@ -9279,6 +10451,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutBottom),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutBoxSizingValue` struct
@@ -9349,6 +10522,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutHeight),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutJustifyContentValue` struct
@@ -9369,6 +10543,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutLeft),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutMarginBottomValue` struct
@@ -9379,6 +10554,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutMarginBottom),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutMarginLeftValue` struct
@@ -9389,6 +10565,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutMarginLeft),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutMarginRightValue` struct
@@ -9399,6 +10576,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutMarginRight),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutMarginTopValue` struct
@@ -9409,6 +10587,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutMarginTop),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutMaxHeightValue` struct
@@ -9419,6 +10598,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutMaxHeight),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutMaxWidthValue` struct
@@ -9429,6 +10609,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutMaxWidth),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutMinHeightValue` struct
@@ -9439,6 +10620,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutMinHeight),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutMinWidthValue` struct
@@ -9449,6 +10631,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutMinWidth),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutPaddingBottomValue` struct
@@ -9459,6 +10642,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutPaddingBottom),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutPaddingLeftValue` struct
@@ -9469,6 +10653,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutPaddingLeft),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutPaddingRightValue` struct
@@ -9479,6 +10664,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutPaddingRight),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutPaddingTopValue` struct
@@ -9489,6 +10675,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutPaddingTop),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutPositionValue` struct
@@ -9509,6 +10696,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutRight),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutTopValue` struct
@@ -9519,6 +10707,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutTop),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutWidthValue` struct
@@ -9529,6 +10718,7 @@ mod test_sizes {
         Inherit,
         Initial,
         Exact(AzLayoutWidth),
+        Calc(*const AzCalcLengthBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutFlexWrapValue` struct
@@ -9771,6 +10961,100 @@ mod test_sizes {
         Exact(AzStyleTextAlign),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleDirectionValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleDirectionValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleDirection),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDurationValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionDurationValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionDuration),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleTransitionTimingFunctionBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleTransitionTimingFunction,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionTimingFunctionValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(*const AzStyleTransitionTimingFunctionBoxed),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionDelayValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionDelayValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionDelay),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleCaretColorValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleCaretColorValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleCaretColor),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleSelectionColorValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleSelectionColorValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleSelectionColor),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleSelectionBackgroundValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleSelectionBackgroundValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleSelectionBackground),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleVerticalAlignBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleVerticalAlign,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleVerticalAlignValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleVerticalAlignValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(*const AzStyleVerticalAlignBoxed),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
     #[repr(C, u8)]
     pub enum AzStyleTextColorValue {
@@ -9801,6 +11085,13 @@ mod test_sizes {
         Exact(AzStyleOpacity),
     }
 
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleTransformOriginBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleTransformOrigin,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTransformOriginValue` struct
     #[repr(C, u8)]
     pub enum AzStyleTransformOriginValue {
@@ -9808,7 +11099,14 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzStyleTransformOrigin),
+        Exact(*const AzStyleTransformOriginBoxed),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStylePerspectiveOriginBoxed {
+        pub run_destructor: bool,
+        pub value: AzStylePerspectiveOrigin,
     }
 
     /// Re-export of rust-allocated (stack based) `StylePerspectiveOriginValue` struct
@@ -9818,7 +11116,7 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzStylePerspectiveOrigin),
+        Exact(*const AzStylePerspectiveOriginBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibilityValue` struct
@@ -9841,6 +11139,26 @@ mod test_sizes {
         Exact(AzStyleMixBlendMode),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleIsolationValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleIsolationValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleIsolation),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleImageRenderingValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleImageRenderingValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleImageRendering),
+    }
+
     /// Re-export of rust-allocated (stack based) `ButtonOnClick` struct
     #[repr(C)]
     pub struct AzButtonOnClick {
@@ -10182,6 +11500,13 @@ mod test_sizes {
         Tick(AzSystemTickDiff),
     }
 
+    /// Background thread that has been created, but not started yet. Unlike `CallbackInfo::start_thread`, the data is moved back to the main thread once the thread function returns and passed to an `on_finish` callback. Start it with `CallbackInfo::start_pending_thread`, which consumes it - a thread that is never started has to be deleted.
+    #[repr(C)]
+    pub struct AzPendingThread {
+        pub data: AzRefAny,
+        pub thread_fn: AzThreadFnType,
+    }
+
     /// Re-export of rust-allocated (stack based) `ThreadSendMsg` struct
     #[repr(C, u8)]
     pub enum AzThreadSendMsg {
@@ -10197,6 +11522,14 @@ mod test_sizes {
         pub callback: AzWriteBackCallback,
     }
 
+    /// Selected range of a text. `start` is where the selection was started, `end` is where the caret is (before `start` if the text was selected backwards)
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct AzTextSelection {
+        pub start: AzTextCursor,
+        pub end: AzTextCursor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<LogicalRect>`
     #[repr(C)]
     pub struct AzLogicalRectVec {
@@ -10323,6 +11656,24 @@ mod test_sizes {
         pub destructor: AzStyleBackgroundSizeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<GridTrack>`
+    #[repr(C)]
+    pub struct AzGridTrackVec {
+        pub(crate) ptr: *const AzGridTrack,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzGridTrackVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<CssPropertyType>`
+    #[repr(C)]
+    pub struct AzCssPropertyTypeVec {
+        pub(crate) ptr: *const AzCssPropertyType,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssPropertyTypeVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `SvgVertex`
     #[repr(C)]
     pub struct AzSvgVertexVec {
@@ -10665,6 +12016,13 @@ mod test_sizes {
         Some(AzMenuCallback),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionNormalizedLinearColorStop` struct
+    #[repr(C, u8)]
+    pub enum AzOptionNormalizedLinearColorStop {
+        None,
+        Some(AzNormalizedLinearColorStop),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionPositionInfo` struct
     #[repr(C, u8)]
     pub enum AzOptionPositionInfo {
@@ -10819,6 +12177,13 @@ mod test_sizes {
         Some(AzLogicalPosition),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+    #[repr(C, u8)]
+    pub enum AzOptionLogicalRect {
+        None,
+        Some(AzLogicalRect),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionPhysicalPositionI32` struct
     #[repr(C, u8)]
     pub enum AzOptionPhysicalPositionI32 {
@@ -10842,6 +12207,13 @@ mod test_sizes {
         Some(AzLogicalSize),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionImeCursorRange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionImeCursorRange {
+        None,
+        Some(AzImeCursorRange),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionVirtualKeyCode` struct
     #[repr(C, u8)]
     pub enum AzOptionVirtualKeyCode {
@@ -10944,6 +12316,7 @@ mod test_sizes {
         pub enable_logging_on_panic: bool,
         pub enable_tab_navigation: bool,
         pub system_callbacks: AzSystemCallbacks,
+        pub redraw_behavior: AzRedrawBehavior,
     }
 
     /// Small (16x16x4) window icon, usually shown in the window titlebar
@@ -10974,6 +12347,15 @@ mod test_sizes {
         pub rgba_bytes: AzU8Vec,
     }
 
+    /// Accelerator + the callback to invoke when it is pressed
+    #[repr(C)]
+    pub struct AzAcceleratorCallback {
+        pub id: AzAcceleratorId,
+        pub accelerator: AzAccelerator,
+        pub data: AzRefAny,
+        pub callback: AzCallback,
+    }
+
     /// Minimum / maximum / current size of the window in logical dimensions
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -10984,15 +12366,6 @@ mod test_sizes {
         pub max_dimensions: AzOptionLogicalSize,
     }
 
-    /// Current keyboard state, stores what keys / characters have been pressed
-    #[repr(C)]
-    pub struct AzKeyboardState {
-        pub current_char: AzOptionChar,
-        pub current_virtual_keycode: AzOptionVirtualKeyCode,
-        pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
-        pub pressed_scancodes: AzScanCodeVec,
-    }
-
     /// Current mouse / cursor state
     #[repr(C)]
     pub struct AzMouseState {
@@ -11110,6 +12483,26 @@ mod test_sizes {
         Focus,
     }
 
+    /// One `(feature: value)` or `(feature < value)` test of a `MediaCondition`
+    #[repr(C, u8)]
+    pub enum AzMediaFeature {
+        MinWidth(AzPixelValue),
+        MaxWidth(AzPixelValue),
+        MinHeight(AzPixelValue),
+        MaxHeight(AzPixelValue),
+        WidthLessThan(AzPixelValue),
+        WidthGreaterThan(AzPixelValue),
+        HeightLessThan(AzPixelValue),
+        HeightGreaterThan(AzPixelValue),
+        Orientation(AzMediaOrientation),
+        PrefersColorScheme(AzMediaColorScheme),
+        Resolution(AzFloatValue),
+        MinResolution(AzFloatValue),
+        MaxResolution(AzFloatValue),
+        ResolutionLessThan(AzFloatValue),
+        ResolutionGreaterThan(AzFloatValue),
+    }
+
     /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
     #[repr(C, u8)]
     pub enum AzAnimationInterpolationFunction {
@@ -11129,6 +12522,7 @@ mod test_sizes {
         pub parent_rect_height: f32,
         pub current_rect_width: f32,
         pub current_rect_height: f32,
+        pub em_size: f32,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleFilter` struct
@@ -11145,6 +12539,25 @@ mod test_sizes {
         Composite(AzStyleCompositeFilter),
     }
 
+    /// Argument of a `repeat()` track
+    #[repr(C)]
+    pub struct AzGridTrackRepeat {
+        pub count: u32,
+        pub tracks: AzGridTrackVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumns` struct
+    #[repr(C)]
+    pub struct AzLayoutGridTemplateColumns {
+        pub inner: AzGridTrackVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateRows` struct
+    #[repr(C)]
+    pub struct AzLayoutGridTemplateRows {
+        pub inner: AzGridTrackVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `LinearGradient` struct
     #[repr(C)]
     pub struct AzLinearGradient {
@@ -11198,6 +12611,55 @@ mod test_sizes {
         Perspective(AzPixelValue),
     }
 
+    /// Represents a `transition-property` attribute: which properties are animated when they change - default: `All`
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionProperty {
+        All,
+        None,
+        List(AzCssPropertyTypeVec),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzLayoutGridTemplateColumnsBoxed {
+        pub run_destructor: bool,
+        pub value: AzLayoutGridTemplateColumns,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumnsValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutGridTemplateColumnsValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(*const AzLayoutGridTemplateColumnsBoxed),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzLayoutGridTemplateRowsBoxed {
+        pub run_destructor: bool,
+        pub value: AzLayoutGridTemplateRows,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridTemplateRowsValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutGridTemplateRowsValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(*const AzLayoutGridTemplateRowsBoxed),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleBackgroundPositionVecBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleBackgroundPositionVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundPositionVecValue` struct
     #[repr(C, u8)]
     pub enum AzStyleBackgroundPositionVecValue {
@@ -11205,7 +12667,14 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzStyleBackgroundPositionVec),
+        Exact(*const AzStyleBackgroundPositionVecBoxed),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleBackgroundRepeatVecBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleBackgroundRepeatVec,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleBackgroundRepeatVecValue` struct
@@ -11215,7 +12684,14 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzStyleBackgroundRepeatVec),
+        Exact(*const AzStyleBackgroundRepeatVecBoxed),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleBackgroundSizeVecBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleBackgroundSizeVec,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleBackgroundSizeVecValue` struct
@@ -11225,7 +12701,24 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzStyleBackgroundSizeVec),
+        Exact(*const AzStyleBackgroundSizeVecBoxed),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleTransitionPropertyBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleTransitionProperty,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionPropertyValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionPropertyValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(*const AzStyleTransitionPropertyBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `CheckBoxStateWrapper` struct
@@ -11471,6 +12964,15 @@ mod test_sizes {
         pub destructor: AzTessellatedColoredSvgNodeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<AcceleratorCallback>`
+    #[repr(C)]
+    pub struct AzAcceleratorCallbackVec {
+        pub(crate) ptr: *const AzAcceleratorCallback,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzAcceleratorCallbackVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
     #[repr(C)]
     pub struct AzStyleTransformVec {
@@ -11480,6 +12982,15 @@ mod test_sizes {
         pub destructor: AzStyleTransformVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<MediaFeature>`
+    #[repr(C)]
+    pub struct AzMediaFeatureVec {
+        pub(crate) ptr: *const AzMediaFeature,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzMediaFeatureVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `VertexAttribute`
     #[repr(C)]
     pub struct AzSvgPathElementVec {
@@ -11544,13 +13055,6 @@ mod test_sizes {
         Some(AzMouseState),
     }
 
-    /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
-    #[repr(C, u8)]
-    pub enum AzOptionKeyboardState {
-        None,
-        Some(AzKeyboardState),
-    }
-
     /// Re-export of rust-allocated (stack based) `OptionStringVec` struct
     #[repr(C, u8)]
     pub enum AzOptionStringVec {
@@ -11644,6 +13148,13 @@ mod test_sizes {
         pub pos: AzSvgParseErrorPosition,
     }
 
+    /// Text that is currently being composed by the IME (usually rendered underlined)
+    #[repr(C)]
+    pub struct AzImePreedit {
+        pub text: AzString,
+        pub cursor_range: AzOptionImeCursorRange,
+    }
+
     /// Window configuration specific to Win32
     #[repr(C)]
     pub struct AzWindowsWindowOptions {
@@ -11799,6 +13310,23 @@ mod test_sizes {
         Children,
     }
 
+    /// Media features combined with `and`, i.e. `(min-width: 600px) and (orientation: portrait)`
+    #[repr(C)]
+    pub struct AzMediaCondition {
+        pub features: AzMediaFeatureVec,
+    }
+
+    /// Single track size of a `grid-template-columns` or `grid-template-rows` attribute
+    #[repr(C, u8)]
+    pub enum AzGridTrack {
+        Px(AzPixelValue),
+        Fraction(AzFloatValue),
+        MinContent,
+        MaxContent,
+        Auto,
+        Repeat(AzGridTrackRepeat),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
     #[repr(C, u8)]
     pub enum AzStyleBackgroundContent {
@@ -11837,6 +13365,13 @@ mod test_sizes {
         Ref(AzFontRef),
     }
 
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzScrollbarStyleBoxed {
+        pub run_destructor: bool,
+        pub value: AzScrollbarStyle,
+    }
+
     /// Re-export of rust-allocated (stack based) `ScrollbarStyleValue` struct
     #[repr(C, u8)]
     pub enum AzScrollbarStyleValue {
@@ -11844,7 +13379,14 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzScrollbarStyle),
+        Exact(*const AzScrollbarStyleBoxed),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleTransformVecBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleTransformVec,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleTransformVecValue` struct
@@ -11854,7 +13396,14 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzStyleTransformVec),
+        Exact(*const AzStyleTransformVecBoxed),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleFilterVecBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleFilterVec,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleFilterVecValue` struct
@@ -11864,7 +13413,7 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzStyleFilterVec),
+        Exact(*const AzStyleFilterVecBoxed),
     }
 
     /// Re-export of rust-allocated (stack based) `FileInputState` struct
@@ -12151,6 +13700,15 @@ mod test_sizes {
         pub destructor: AzStyleBackgroundContentVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<MediaCondition>`
+    #[repr(C)]
+    pub struct AzMediaConditionVec {
+        pub(crate) ptr: *const AzMediaCondition,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzMediaConditionVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<SvgPath>`
     #[repr(C)]
     pub struct AzSvgPathVec {
@@ -12258,6 +13816,15 @@ mod test_sizes {
         InvalidCharacterData,
     }
 
+    /// Event of the input method editor (IME), used to compose text that can't be typed directly (CJK characters, emojis, etc.)
+    #[repr(C, u8)]
+    pub enum AzImeEvent {
+        Enabled,
+        Disabled,
+        Preedit(AzImePreedit),
+        Commit(AzString),
+    }
+
     /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
     #[repr(C)]
     pub struct AzLinuxWindowOptions {
@@ -12296,6 +13863,19 @@ mod test_sizes {
         pub selectors: AzCssPathSelectorVec,
     }
 
+    /// Condition of an `@media` group, i.e. `(max-width: 600px), (orientation: portrait)`: the group applies if any of the conditions matches
+    #[repr(C)]
+    pub struct AzMediaQuery {
+        pub conditions: AzMediaConditionVec,
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleBackgroundContentVecBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleBackgroundContentVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
     #[repr(C, u8)]
     pub enum AzStyleBackgroundContentVecValue {
@@ -12303,7 +13883,14 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzStyleBackgroundContentVec),
+        Exact(*const AzStyleBackgroundContentVecBoxed),
+    }
+
+    /// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+    #[repr(C)]
+    pub struct AzStyleFontFamilyVecBoxed {
+        pub run_destructor: bool,
+        pub value: AzStyleFontFamilyVec,
     }
 
     /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
@@ -12313,7 +13900,7 @@ mod test_sizes {
         None,
         Inherit,
         Initial,
-        Exact(AzStyleFontFamilyVec),
+        Exact(*const AzStyleFontFamilyVecBoxed),
     }
 
     /// Parsed CSS key-value pair
@@ -12323,6 +13910,8 @@ mod test_sizes {
         FontSize(AzStyleFontSizeValue),
         FontFamily(AzStyleFontFamilyVecValue),
         TextAlign(AzStyleTextAlignValue),
+        VerticalAlign(AzStyleVerticalAlignValue),
+        Direction(AzStyleDirectionValue),
         LetterSpacing(AzStyleLetterSpacingValue),
         LineHeight(AzStyleLineHeightValue),
         WordSpacing(AzStyleWordSpacingValue),
@@ -12349,6 +13938,10 @@ mod test_sizes {
         JustifyContent(AzLayoutJustifyContentValue),
         AlignItems(AzLayoutAlignItemsValue),
         AlignContent(AzLayoutAlignContentValue),
+        GridTemplateColumns(AzLayoutGridTemplateColumnsValue),
+        GridTemplateRows(AzLayoutGridTemplateRowsValue),
+        GridColumn(AzLayoutGridColumnValue),
+        GridRow(AzLayoutGridRowValue),
         BackgroundContent(AzStyleBackgroundContentVecValue),
         BackgroundPosition(AzStyleBackgroundPositionVecValue),
         BackgroundSize(AzStyleBackgroundSizeVecValue),
@@ -12390,9 +13983,18 @@ mod test_sizes {
         PerspectiveOrigin(AzStylePerspectiveOriginValue),
         BackfaceVisibility(AzStyleBackfaceVisibilityValue),
         MixBlendMode(AzStyleMixBlendModeValue),
+        Isolation(AzStyleIsolationValue),
+        ImageRendering(AzStyleImageRenderingValue),
         Filter(AzStyleFilterVecValue),
         BackdropFilter(AzStyleFilterVecValue),
         TextShadow(AzStyleBoxShadowValue),
+        TransitionProperty(AzStyleTransitionPropertyValue),
+        TransitionDuration(AzStyleTransitionDurationValue),
+        TransitionTimingFunction(AzStyleTransitionTimingFunctionValue),
+        TransitionDelay(AzStyleTransitionDelayValue),
+        CaretColor(AzStyleCaretColorValue),
+        SelectionColor(AzStyleSelectionColorValue),
+        SelectionBackground(AzStyleSelectionBackgroundValue),
     }
 
     /// Re-export of rust-allocated (stack based) `FileInputStateWrapper` struct
@@ -12586,6 +14188,13 @@ mod test_sizes {
         pub destructor: AzSvgSimpleNodeVecDestructor,
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionMediaQuery` struct
+    #[repr(C, u8)]
+    pub enum AzOptionMediaQuery {
+        None,
+        Some(AzMediaQuery),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
     #[repr(C, u8)]
     pub enum AzOptionCssProperty {
@@ -12593,6 +14202,13 @@ mod test_sizes {
         Some(AzCssProperty),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionImeEvent` struct
+    #[repr(C, u8)]
+    pub enum AzOptionImeEvent {
+        None,
+        Some(AzImeEvent),
+    }
+
     /// Re-export of rust-allocated (stack based) `XmlTextError` struct
     #[repr(C)]
     pub struct AzXmlTextError {
@@ -12600,6 +14216,16 @@ mod test_sizes {
         pub pos: AzSvgParseErrorPosition,
     }
 
+    /// Current keyboard state, stores what keys / characters have been pressed
+    #[repr(C)]
+    pub struct AzKeyboardState {
+        pub current_char: AzOptionChar,
+        pub current_virtual_keycode: AzOptionVirtualKeyCode,
+        pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
+        pub pressed_scancodes: AzScanCodeVec,
+        pub current_ime_event: AzOptionImeEvent,
+    }
+
     /// Platform-specific window configuration, i.e. WM options that are not cross-platform
     #[repr(C)]
     pub struct AzPlatformSpecificOptions {
@@ -12628,6 +14254,7 @@ mod test_sizes {
         pub background_color: AzColorU,
         pub layout_callback: AzLayoutCallback,
         pub close_callback: AzOptionCallback,
+        pub accelerators: AzAcceleratorCallbackVec,
     }
 
     /// Re-export of rust-allocated (stack based) `CallbackInfo` struct
@@ -12646,6 +14273,7 @@ mod test_sizes {
         pub threads: *mut c_void,
         pub timers_removed: *mut c_void,
         pub threads_removed: *mut c_void,
+        pub thread_msgs_sent: *mut c_void,
         pub current_window_handle: *const AzRawWindowHandle,
         pub new_windows: *mut c_void,
         pub system_callbacks: *const AzSystemCallbacks,
@@ -12655,6 +14283,8 @@ mod test_sizes {
         pub images_changed_in_callbacks: *mut c_void,
         pub image_masks_changed_in_callbacks: *mut c_void,
         pub css_properties_changed_in_callbacks: *mut c_void,
+        pub stylesheet_changed_in_callbacks: *mut c_void,
+        pub context_menu_changed_in_callbacks: *mut c_void,
         pub current_scroll_states: *const c_void,
         pub nodes_scrolled_in_callback: *mut c_void,
         pub hit_dom_node: AzDomNodeId,
@@ -12721,6 +14351,13 @@ mod test_sizes {
         pub default_value: AzCssProperty,
     }
 
+    /// Value of a CSS variable (`--name`) that is set at runtime
+    #[repr(C)]
+    pub struct AzCssVariable {
+        pub name: AzString,
+        pub value: AzCssProperty,
+    }
+
     /// Re-export of rust-allocated (stack based) `Node` struct
     #[repr(C)]
     pub struct AzNode {
@@ -12758,6 +14395,15 @@ mod test_sizes {
         pub destructor: AzNodeDataInlineCssPropertyVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssVariable>`
+    #[repr(C)]
+    pub struct AzCssVariableVec {
+        pub(crate) ptr: *const AzCssVariable,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssVariableVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
     #[repr(C, u8)]
     pub enum AzOptionWindowState {
@@ -12765,6 +14411,13 @@ mod test_sizes {
         Some(AzWindowState),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+    #[repr(C, u8)]
+    pub enum AzOptionKeyboardState {
+        None,
+        Some(AzKeyboardState),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionInlineText` struct
     #[repr(C, u8)]
     pub enum AzOptionInlineText {
@@ -12958,6 +14611,7 @@ mod test_sizes {
     pub struct AzCssRuleBlock {
         pub path: AzCssPath,
         pub declarations: AzCssDeclarationVec,
+        pub media: AzOptionMediaQuery,
     }
 
     /// Re-export of rust-allocated (stack based) `TabContent` struct
@@ -13082,6 +14736,7 @@ mod test_sizes {
     #[repr(C)]
     pub struct AzCss {
         pub stylesheets: AzStylesheetVec,
+        pub variables: AzCssVariableVec,
     }
 
     use core::ffi::c_void;
@@ -13092,7 +14747,9 @@ mod test_sizes {
          use core::alloc::Layout;
         assert_eq!((Layout::new::<azul_impl::app::AzAppPtr>(), "AzApp"), (Layout::new::<AzApp>(), "AzApp"));
         assert_eq!((Layout::new::<azul_impl::resources::AppLogLevel>(), "AzAppLogLevel"), (Layout::new::<AzAppLogLevel>(), "AzAppLogLevel"));
+        assert_eq!((Layout::new::<azul_impl::resources::RedrawBehavior>(), "AzRedrawBehavior"), (Layout::new::<AzRedrawBehavior>(), "AzRedrawBehavior"));
         assert_eq!((Layout::new::<azul_impl::resources::LayoutSolverVersion>(), "AzLayoutSolver"), (Layout::new::<AzLayoutSolver>(), "AzLayoutSolver"));
+        assert_eq!((Layout::new::<crate::version::Api>(), "AzApi"), (Layout::new::<AzApi>(), "AzApi"));
         assert_eq!((Layout::new::<azul_core::window::Vsync>(), "AzVsync"), (Layout::new::<AzVsync>(), "AzVsync"));
         assert_eq!((Layout::new::<azul_core::window::Srgb>(), "AzSrgb"), (Layout::new::<AzSrgb>(), "AzSrgb"));
         assert_eq!((Layout::new::<azul_core::window::HwAcceleration>(), "AzHwAcceleration"), (Layout::new::<AzHwAcceleration>(), "AzHwAcceleration"));
@@ -13113,8 +14770,11 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::LogicalSize>(), "AzLogicalSize"), (Layout::new::<AzLogicalSize>(), "AzLogicalSize"));
         assert_eq!((Layout::new::<azul_core::window::IconKey>(), "AzIconKey"), (Layout::new::<AzIconKey>(), "AzIconKey"));
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCode>(), "AzVirtualKeyCode"), (Layout::new::<AzVirtualKeyCode>(), "AzVirtualKeyCode"));
+        assert_eq!((Layout::new::<azul_core::window::Modifiers>(), "AzModifiers"), (Layout::new::<AzModifiers>(), "AzModifiers"));
+        assert_eq!((Layout::new::<azul_core::window::AcceleratorId>(), "AzAcceleratorId"), (Layout::new::<AzAcceleratorId>(), "AzAcceleratorId"));
         assert_eq!((Layout::new::<azul_core::window::WindowFrame>(), "AzWindowFrame"), (Layout::new::<AzWindowFrame>(), "AzWindowFrame"));
         assert_eq!((Layout::new::<azul_core::window::DebugState>(), "AzDebugState"), (Layout::new::<AzDebugState>(), "AzDebugState"));
+        assert_eq!((Layout::new::<azul_core::window::ImeCursorRange>(), "AzImeCursorRange"), (Layout::new::<AzImeCursorRange>(), "AzImeCursorRange"));
         assert_eq!((Layout::new::<azul_core::window::MouseCursorType>(), "AzMouseCursorType"), (Layout::new::<AzMouseCursorType>(), "AzMouseCursorType"));
         assert_eq!((Layout::new::<azul_core::window::RendererType>(), "AzRendererType"), (Layout::new::<AzRendererType>(), "AzRendererType"));
         assert_eq!((Layout::new::<azul_core::window::MacWindowOptions>(), "AzMacWindowOptions"), (Layout::new::<AzMacWindowOptions>(), "AzMacWindowOptions"));
@@ -13152,17 +14812,22 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::MenuItemState>(), "AzMenuItemState"), (Layout::new::<AzMenuItemState>(), "AzMenuItemState"));
         assert_eq!((Layout::new::<azul_impl::css::NodeTypeTag>(), "AzNodeTypeKey"), (Layout::new::<AzNodeTypeKey>(), "AzNodeTypeKey"));
         assert_eq!((Layout::new::<azul_impl::css::CssNthChildPattern>(), "AzCssNthChildPattern"), (Layout::new::<AzCssNthChildPattern>(), "AzCssNthChildPattern"));
+        assert_eq!((Layout::new::<azul_impl::css::MediaOrientation>(), "AzMediaOrientation"), (Layout::new::<AzMediaOrientation>(), "AzMediaOrientation"));
+        assert_eq!((Layout::new::<azul_impl::css::MediaColorScheme>(), "AzMediaColorScheme"), (Layout::new::<AzMediaColorScheme>(), "AzMediaColorScheme"));
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyType>(), "AzCssPropertyType"), (Layout::new::<AzCssPropertyType>(), "AzCssPropertyType"));
         assert_eq!((Layout::new::<azul_impl::css::ColorU>(), "AzColorU"), (Layout::new::<AzColorU>(), "AzColorU"));
         assert_eq!((Layout::new::<azul_impl::css::SizeMetric>(), "AzSizeMetric"), (Layout::new::<AzSizeMetric>(), "AzSizeMetric"));
         assert_eq!((Layout::new::<azul_impl::css::FloatValue>(), "AzFloatValue"), (Layout::new::<AzFloatValue>(), "AzFloatValue"));
         assert_eq!((Layout::new::<azul_impl::css::BoxShadowClipMode>(), "AzBoxShadowClipMode"), (Layout::new::<AzBoxShadowClipMode>(), "AzBoxShadowClipMode"));
         assert_eq!((Layout::new::<azul_impl::css::StyleMixBlendMode>(), "AzStyleMixBlendMode"), (Layout::new::<AzStyleMixBlendMode>(), "AzStyleMixBlendMode"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleIsolation>(), "AzStyleIsolation"), (Layout::new::<AzStyleIsolation>(), "AzStyleIsolation"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleImageRendering>(), "AzStyleImageRendering"), (Layout::new::<AzStyleImageRendering>(), "AzStyleImageRendering"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignContent>(), "AzLayoutAlignContent"), (Layout::new::<AzLayoutAlignContent>(), "AzLayoutAlignContent"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignItems>(), "AzLayoutAlignItems"), (Layout::new::<AzLayoutAlignItems>(), "AzLayoutAlignItems"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBoxSizing>(), "AzLayoutBoxSizing"), (Layout::new::<AzLayoutBoxSizing>(), "AzLayoutBoxSizing"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexDirection>(), "AzLayoutFlexDirection"), (Layout::new::<AzLayoutFlexDirection>(), "AzLayoutFlexDirection"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutDisplay>(), "AzLayoutDisplay"), (Layout::new::<AzLayoutDisplay>(), "AzLayoutDisplay"));
+        assert_eq!((Layout::new::<azul_impl::css::GridLine>(), "AzGridLine"), (Layout::new::<AzGridLine>(), "AzGridLine"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFloat>(), "AzLayoutFloat"), (Layout::new::<AzLayoutFloat>(), "AzLayoutFloat"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutJustifyContent>(), "AzLayoutJustifyContent"), (Layout::new::<AzLayoutJustifyContent>(), "AzLayoutJustifyContent"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutPosition>(), "AzLayoutPosition"), (Layout::new::<AzLayoutPosition>(), "AzLayoutPosition"));
@@ -13178,6 +14843,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleCursor>(), "AzStyleCursor"), (Layout::new::<AzStyleCursor>(), "AzStyleCursor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"), (Layout::new::<AzStyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlign>(), "AzStyleTextAlign"), (Layout::new::<AzStyleTextAlign>(), "AzStyleTextAlign"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleDirection>(), "AzStyleDirection"), (Layout::new::<AzStyleDirection>(), "AzStyleDirection"));
+        assert_eq!((Layout::new::<azul_impl::css::StepPosition>(), "AzStepPosition"), (Layout::new::<AzStepPosition>(), "AzStepPosition"));
         assert_eq!((Layout::new::<crate::widgets::ribbon::Ribbon>(), "AzRibbon"), (Layout::new::<AzRibbon>(), "AzRibbon"));
         assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonOnTabClickedCallback>(), "AzRibbonOnTabClickedCallback"), (Layout::new::<AzRibbonOnTabClickedCallback>(), "AzRibbonOnTabClickedCallback"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputOnPathChangeCallback>(), "AzFileInputOnPathChangeCallback"), (Layout::new::<AzFileInputOnPathChangeCallback>(), "AzFileInputOnPathChangeCallback"));
@@ -13291,6 +14958,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::ThreadDestructorCallback>(), "AzThreadDestructorFn"), (Layout::new::<AzThreadDestructorFn>(), "AzThreadDestructorFn"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadReceiverDestructorCallback>(), "AzThreadReceiverDestructorFn"), (Layout::new::<AzThreadReceiverDestructorFn>(), "AzThreadReceiverDestructorFn"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadSenderDestructorCallback>(), "AzThreadSenderDestructorFn"), (Layout::new::<AzThreadSenderDestructorFn>(), "AzThreadSenderDestructorFn"));
+        assert_eq!((Layout::new::<azul_core::text_cursor::TextCursor>(), "AzTextCursor"), (Layout::new::<AzTextCursor>(), "AzTextCursor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecDestructor>(), "AzStyleFontFamilyVecDestructor"), (Layout::new::<AzStyleFontFamilyVecDestructor>(), "AzStyleFontFamilyVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRowVecDestructor>(), "AzListViewRowVecDestructor"), (Layout::new::<AzListViewRowVecDestructor>(), "AzListViewRowVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterVecDestructor>(), "AzStyleFilterVecDestructor"), (Layout::new::<AzStyleFilterVecDestructor>(), "AzStyleFilterVecDestructor"));
@@ -13316,6 +14984,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineTextHitVecDestructor>(), "AzInlineTextHitVecDestructor"), (Layout::new::<AzInlineTextHitVecDestructor>(), "AzInlineTextHitVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::MonitorVecDestructor>(), "AzMonitorVecDestructor"), (Layout::new::<AzMonitorVecDestructor>(), "AzMonitorVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::VideoModeVecDestructor>(), "AzVideoModeVecDestructor"), (Layout::new::<AzVideoModeVecDestructor>(), "AzVideoModeVecDestructor"));
+        assert_eq!((Layout::new::<azul_core::window::AcceleratorCallbackVecDestructor>(), "AzAcceleratorCallbackVecDestructor"), (Layout::new::<AzAcceleratorCallbackVecDestructor>(), "AzAcceleratorCallbackVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::dom::DomVecDestructor>(), "AzDomVecDestructor"), (Layout::new::<AzDomVecDestructor>(), "AzDomVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::dom::IdOrClassVecDestructor>(), "AzIdOrClassVecDestructor"), (Layout::new::<AzIdOrClassVecDestructor>(), "AzIdOrClassVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssPropertyVecDestructor>(), "AzNodeDataInlineCssPropertyVecDestructor"), (Layout::new::<AzNodeDataInlineCssPropertyVecDestructor>(), "AzNodeDataInlineCssPropertyVecDestructor"));
@@ -13324,6 +14993,11 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeatVecDestructor>(), "AzStyleBackgroundRepeatVecDestructor"), (Layout::new::<AzStyleBackgroundRepeatVecDestructor>(), "AzStyleBackgroundRepeatVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundSizeVecDestructor>(), "AzStyleBackgroundSizeVecDestructor"), (Layout::new::<AzStyleBackgroundSizeVecDestructor>(), "AzStyleBackgroundSizeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVecDestructor>(), "AzStyleTransformVecDestructor"), (Layout::new::<AzStyleTransformVecDestructor>(), "AzStyleTransformVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackVecDestructor>(), "AzGridTrackVecDestructor"), (Layout::new::<AzGridTrackVecDestructor>(), "AzGridTrackVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssPropertyTypeVecDestructor>(), "AzCssPropertyTypeVecDestructor"), (Layout::new::<AzCssPropertyTypeVecDestructor>(), "AzCssPropertyTypeVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssVariableVecDestructor>(), "AzCssVariableVecDestructor"), (Layout::new::<AzCssVariableVecDestructor>(), "AzCssVariableVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::MediaFeatureVecDestructor>(), "AzMediaFeatureVecDestructor"), (Layout::new::<AzMediaFeatureVecDestructor>(), "AzMediaFeatureVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::MediaConditionVecDestructor>(), "AzMediaConditionVecDestructor"), (Layout::new::<AzMediaConditionVecDestructor>(), "AzMediaConditionVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyVecDestructor>(), "AzCssPropertyVecDestructor"), (Layout::new::<AzCssPropertyVecDestructor>(), "AzCssPropertyVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgMultiPolygonVecDestructor>(), "AzSvgMultiPolygonVecDestructor"), (Layout::new::<AzSvgMultiPolygonVecDestructor>(), "AzSvgMultiPolygonVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgSimpleNodeVecDestructor>(), "AzSvgSimpleNodeVecDestructor"), (Layout::new::<AzSvgSimpleNodeVecDestructor>(), "AzSvgSimpleNodeVecDestructor"));
@@ -13374,6 +15048,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::RawWindowHandle>(), "AzRawWindowHandle"), (Layout::new::<AzRawWindowHandle>(), "AzRawWindowHandle"));
         assert_eq!((Layout::new::<azul_core::window::LogicalRect>(), "AzLogicalRect"), (Layout::new::<AzLogicalRect>(), "AzLogicalRect"));
         assert_eq!((Layout::new::<azul_core::window::AcceleratorKey>(), "AzAcceleratorKey"), (Layout::new::<AzAcceleratorKey>(), "AzAcceleratorKey"));
+        assert_eq!((Layout::new::<azul_core::window::Accelerator>(), "AzAccelerator"), (Layout::new::<AzAccelerator>(), "AzAccelerator"));
         assert_eq!((Layout::new::<azul_core::window::WindowFlags>(), "AzWindowFlags"), (Layout::new::<AzWindowFlags>(), "AzWindowFlags"));
         assert_eq!((Layout::new::<azul_core::window::CursorPosition>(), "AzCursorPosition"), (Layout::new::<AzCursorPosition>(), "AzCursorPosition"));
         assert_eq!((Layout::new::<azul_core::window::WindowPosition>(), "AzWindowPosition"), (Layout::new::<AzWindowPosition>(), "AzWindowPosition"));
@@ -13393,6 +15068,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::MenuItemIcon>(), "AzMenuItemIcon"), (Layout::new::<AzMenuItemIcon>(), "AzMenuItemIcon"));
         assert_eq!((Layout::new::<azul_impl::css::CssNthChildSelector>(), "AzCssNthChildSelector"), (Layout::new::<AzCssNthChildSelector>(), "AzCssNthChildSelector"));
         assert_eq!((Layout::new::<azul_impl::css::PixelValue>(), "AzPixelValue"), (Layout::new::<AzPixelValue>(), "AzPixelValue"));
+        assert_eq!((Layout::new::<azul_impl::css::CalcLength>(), "AzCalcLength"), (Layout::new::<AzCalcLength>(), "AzCalcLength"));
+        assert_eq!((Layout::new::<azul_impl::css::CalcLengthBoxed>(), "AzCalcLengthBoxed"), (Layout::new::<AzCalcLengthBoxed>(), "AzCalcLengthBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::PixelValueNoPercent>(), "AzPixelValueNoPercent"), (Layout::new::<AzPixelValueNoPercent>(), "AzPixelValueNoPercent"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBoxShadow>(), "AzStyleBoxShadow"), (Layout::new::<AzStyleBoxShadow>(), "AzStyleBoxShadow"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBlur>(), "AzStyleBlur"), (Layout::new::<AzStyleBlur>(), "AzStyleBlur"));
@@ -13400,6 +15077,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterOffset>(), "AzStyleFilterOffset"), (Layout::new::<AzStyleFilterOffset>(), "AzStyleFilterOffset"));
         assert_eq!((Layout::new::<azul_impl::css::StyleCompositeFilter>(), "AzStyleCompositeFilter"), (Layout::new::<AzStyleCompositeFilter>(), "AzStyleCompositeFilter"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBottom>(), "AzLayoutBottom"), (Layout::new::<AzLayoutBottom>(), "AzLayoutBottom"));
+        assert_eq!((Layout::new::<azul_impl::css::GridPlacement>(), "AzGridPlacement"), (Layout::new::<AzGridPlacement>(), "AzGridPlacement"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridColumn>(), "AzLayoutGridColumn"), (Layout::new::<AzLayoutGridColumn>(), "AzLayoutGridColumn"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridRow>(), "AzLayoutGridRow"), (Layout::new::<AzLayoutGridRow>(), "AzLayoutGridRow"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexGrow>(), "AzLayoutFlexGrow"), (Layout::new::<AzLayoutFlexGrow>(), "AzLayoutFlexGrow"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexShrink>(), "AzLayoutFlexShrink"), (Layout::new::<AzLayoutFlexShrink>(), "AzLayoutFlexShrink"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutHeight>(), "AzLayoutHeight"), (Layout::new::<AzLayoutHeight>(), "AzLayoutHeight"));
@@ -13451,7 +15131,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleTabWidth>(), "AzStyleTabWidth"), (Layout::new::<AzStyleTabWidth>(), "AzStyleTabWidth"));
         assert_eq!((Layout::new::<azul_impl::css::StyleOpacity>(), "AzStyleOpacity"), (Layout::new::<AzStyleOpacity>(), "AzStyleOpacity"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformOrigin>(), "AzStyleTransformOrigin"), (Layout::new::<AzStyleTransformOrigin>(), "AzStyleTransformOrigin"));
-        assert_eq!((Layout::new::<azul_impl::css::StyleTransformOrigin>(), "AzStylePerspectiveOrigin"), (Layout::new::<AzStylePerspectiveOrigin>(), "AzStylePerspectiveOrigin"));
+        assert_eq!((Layout::new::<azul_impl::css::StylePerspectiveOrigin>(), "AzStylePerspectiveOrigin"), (Layout::new::<AzStylePerspectiveOrigin>(), "AzStylePerspectiveOrigin"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformMatrix2D>(), "AzStyleTransformMatrix2D"), (Layout::new::<AzStyleTransformMatrix2D>(), "AzStyleTransformMatrix2D"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformMatrix3D>(), "AzStyleTransformMatrix3D"), (Layout::new::<AzStyleTransformMatrix3D>(), "AzStyleTransformMatrix3D"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformTranslate2D>(), "AzStyleTransformTranslate2D"), (Layout::new::<AzStyleTransformTranslate2D>(), "AzStyleTransformTranslate2D"));
@@ -13460,10 +15140,22 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformScale2D>(), "AzStyleTransformScale2D"), (Layout::new::<AzStyleTransformScale2D>(), "AzStyleTransformScale2D"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformScale3D>(), "AzStyleTransformScale3D"), (Layout::new::<AzStyleTransformScale3D>(), "AzStyleTransformScale3D"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformSkew2D>(), "AzStyleTransformSkew2D"), (Layout::new::<AzStyleTransformSkew2D>(), "AzStyleTransformSkew2D"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDuration>(), "AzStyleTransitionDuration"), (Layout::new::<AzStyleTransitionDuration>(), "AzStyleTransitionDuration"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDelay>(), "AzStyleTransitionDelay"), (Layout::new::<AzStyleTransitionDelay>(), "AzStyleTransitionDelay"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCubicBezier>(), "AzStyleCubicBezier"), (Layout::new::<AzStyleCubicBezier>(), "AzStyleCubicBezier"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleSteps>(), "AzStyleSteps"), (Layout::new::<AzStyleSteps>(), "AzStyleSteps"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionTimingFunction>(), "AzStyleTransitionTimingFunction"), (Layout::new::<AzStyleTransitionTimingFunction>(), "AzStyleTransitionTimingFunction"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleVerticalAlign>(), "AzStyleVerticalAlign"), (Layout::new::<AzStyleVerticalAlign>(), "AzStyleVerticalAlign"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextColor>(), "AzStyleTextColor"), (Layout::new::<AzStyleTextColor>(), "AzStyleTextColor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCaretColor>(), "AzStyleCaretColor"), (Layout::new::<AzStyleCaretColor>(), "AzStyleCaretColor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleSelectionColor>(), "AzStyleSelectionColor"), (Layout::new::<AzStyleSelectionColor>(), "AzStyleSelectionColor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleSelectionBackground>(), "AzStyleSelectionBackground"), (Layout::new::<AzStyleSelectionBackground>(), "AzStyleSelectionBackground"));
         assert_eq!((Layout::new::<azul_impl::css::StyleWordSpacing>(), "AzStyleWordSpacing"), (Layout::new::<AzStyleWordSpacing>(), "AzStyleWordSpacing"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleBoxShadowBoxed>(), "AzStyleBoxShadowBoxed"), (Layout::new::<AzStyleBoxShadowBoxed>(), "AzStyleBoxShadowBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBoxShadowValue>(), "AzStyleBoxShadowValue"), (Layout::new::<AzStyleBoxShadowValue>(), "AzStyleBoxShadowValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignContentValue>(), "AzLayoutAlignContentValue"), (Layout::new::<AzLayoutAlignContentValue>(), "AzLayoutAlignContentValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridColumnValue>(), "AzLayoutGridColumnValue"), (Layout::new::<AzLayoutGridColumnValue>(), "AzLayoutGridColumnValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridRowValue>(), "AzLayoutGridRowValue"), (Layout::new::<AzLayoutGridRowValue>(), "AzLayoutGridRowValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignItemsValue>(), "AzLayoutAlignItemsValue"), (Layout::new::<AzLayoutAlignItemsValue>(), "AzLayoutAlignItemsValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBottomValue>(), "AzLayoutBottomValue"), (Layout::new::<AzLayoutBottomValue>(), "AzLayoutBottomValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBoxSizingValue>(), "AzLayoutBoxSizingValue"), (Layout::new::<AzLayoutBoxSizingValue>(), "AzLayoutBoxSizingValue"));
@@ -13515,13 +15207,27 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleLineHeightValue>(), "AzStyleLineHeightValue"), (Layout::new::<AzStyleLineHeightValue>(), "AzStyleLineHeightValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTabWidthValue>(), "AzStyleTabWidthValue"), (Layout::new::<AzStyleTabWidthValue>(), "AzStyleTabWidthValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlignValue>(), "AzStyleTextAlignValue"), (Layout::new::<AzStyleTextAlignValue>(), "AzStyleTextAlignValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleDirectionValue>(), "AzStyleDirectionValue"), (Layout::new::<AzStyleDirectionValue>(), "AzStyleDirectionValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDurationValue>(), "AzStyleTransitionDurationValue"), (Layout::new::<AzStyleTransitionDurationValue>(), "AzStyleTransitionDurationValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionTimingFunctionBoxed>(), "AzStyleTransitionTimingFunctionBoxed"), (Layout::new::<AzStyleTransitionTimingFunctionBoxed>(), "AzStyleTransitionTimingFunctionBoxed"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionTimingFunctionValue>(), "AzStyleTransitionTimingFunctionValue"), (Layout::new::<AzStyleTransitionTimingFunctionValue>(), "AzStyleTransitionTimingFunctionValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionDelayValue>(), "AzStyleTransitionDelayValue"), (Layout::new::<AzStyleTransitionDelayValue>(), "AzStyleTransitionDelayValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCaretColorValue>(), "AzStyleCaretColorValue"), (Layout::new::<AzStyleCaretColorValue>(), "AzStyleCaretColorValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleSelectionColorValue>(), "AzStyleSelectionColorValue"), (Layout::new::<AzStyleSelectionColorValue>(), "AzStyleSelectionColorValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleSelectionBackgroundValue>(), "AzStyleSelectionBackgroundValue"), (Layout::new::<AzStyleSelectionBackgroundValue>(), "AzStyleSelectionBackgroundValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleVerticalAlignBoxed>(), "AzStyleVerticalAlignBoxed"), (Layout::new::<AzStyleVerticalAlignBoxed>(), "AzStyleVerticalAlignBoxed"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleVerticalAlignValue>(), "AzStyleVerticalAlignValue"), (Layout::new::<AzStyleVerticalAlignValue>(), "AzStyleVerticalAlignValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextColorValue>(), "AzStyleTextColorValue"), (Layout::new::<AzStyleTextColorValue>(), "AzStyleTextColorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleWordSpacingValue>(), "AzStyleWordSpacingValue"), (Layout::new::<AzStyleWordSpacingValue>(), "AzStyleWordSpacingValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleOpacityValue>(), "AzStyleOpacityValue"), (Layout::new::<AzStyleOpacityValue>(), "AzStyleOpacityValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransformOriginBoxed>(), "AzStyleTransformOriginBoxed"), (Layout::new::<AzStyleTransformOriginBoxed>(), "AzStyleTransformOriginBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformOriginValue>(), "AzStyleTransformOriginValue"), (Layout::new::<AzStyleTransformOriginValue>(), "AzStyleTransformOriginValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StylePerspectiveOriginBoxed>(), "AzStylePerspectiveOriginBoxed"), (Layout::new::<AzStylePerspectiveOriginBoxed>(), "AzStylePerspectiveOriginBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::StylePerspectiveOriginValue>(), "AzStylePerspectiveOriginValue"), (Layout::new::<AzStylePerspectiveOriginValue>(), "AzStylePerspectiveOriginValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackfaceVisibilityValue>(), "AzStyleBackfaceVisibilityValue"), (Layout::new::<AzStyleBackfaceVisibilityValue>(), "AzStyleBackfaceVisibilityValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleMixBlendModeValue>(), "AzStyleMixBlendModeValue"), (Layout::new::<AzStyleMixBlendModeValue>(), "AzStyleMixBlendModeValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleIsolationValue>(), "AzStyleIsolationValue"), (Layout::new::<AzStyleIsolationValue>(), "AzStyleIsolationValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleImageRenderingValue>(), "AzStyleImageRenderingValue"), (Layout::new::<AzStyleImageRenderingValue>(), "AzStyleImageRenderingValue"));
         assert_eq!((Layout::new::<crate::widgets::button::ButtonOnClick>(), "AzButtonOnClick"), (Layout::new::<AzButtonOnClick>(), "AzButtonOnClick"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputOnPathChange>(), "AzFileInputOnPathChange"), (Layout::new::<AzFileInputOnPathChange>(), "AzFileInputOnPathChange"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxOnToggle>(), "AzCheckBoxOnToggle"), (Layout::new::<AzCheckBoxOnToggle>(), "AzCheckBoxOnToggle"));
@@ -13561,8 +15267,10 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::svg::SvgFillStyle>(), "AzSvgFillStyle"), (Layout::new::<AzSvgFillStyle>(), "AzSvgFillStyle"));
         assert_eq!((Layout::new::<azul_impl::task::AzInstantPtr>(), "AzInstantPtr"), (Layout::new::<AzInstantPtr>(), "AzInstantPtr"));
         assert_eq!((Layout::new::<azul_impl::task::Duration>(), "AzDuration"), (Layout::new::<AzDuration>(), "AzDuration"));
+        assert_eq!((Layout::new::<crate::thread::PendingThread>(), "AzPendingThread"), (Layout::new::<AzPendingThread>(), "AzPendingThread"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadSendMsg>(), "AzThreadSendMsg"), (Layout::new::<AzThreadSendMsg>(), "AzThreadSendMsg"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadWriteBackMsg>(), "AzThreadWriteBackMsg"), (Layout::new::<AzThreadWriteBackMsg>(), "AzThreadWriteBackMsg"));
+        assert_eq!((Layout::new::<azul_core::text_cursor::TextSelection>(), "AzTextSelection"), (Layout::new::<AzTextSelection>(), "AzTextSelection"));
         assert_eq!((Layout::new::<azul_core::window::LogicalRectVec>(), "AzLogicalRectVec"), (Layout::new::<AzLogicalRectVec>(), "AzLogicalRectVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputOutputTypeIdVec>(), "AzInputOutputTypeIdVec"), (Layout::new::<AzInputOutputTypeIdVec>(), "AzInputOutputTypeIdVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputNodeAndIndexVec>(), "AzOutputNodeAndIndexVec"), (Layout::new::<AzOutputNodeAndIndexVec>(), "AzOutputNodeAndIndexVec"));
@@ -13577,6 +15285,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundPositionVec>(), "AzStyleBackgroundPositionVec"), (Layout::new::<AzStyleBackgroundPositionVec>(), "AzStyleBackgroundPositionVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeatVec>(), "AzStyleBackgroundRepeatVec"), (Layout::new::<AzStyleBackgroundRepeatVec>(), "AzStyleBackgroundRepeatVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundSizeVec>(), "AzStyleBackgroundSizeVec"), (Layout::new::<AzStyleBackgroundSizeVec>(), "AzStyleBackgroundSizeVec"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackVec>(), "AzGridTrackVec"), (Layout::new::<AzGridTrackVec>(), "AzGridTrackVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssPropertyTypeVec>(), "AzCssPropertyTypeVec"), (Layout::new::<AzCssPropertyTypeVec>(), "AzCssPropertyTypeVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgVertexVec>(), "AzSvgVertexVec"), (Layout::new::<AzSvgVertexVec>(), "AzSvgVertexVec"));
         assert_eq!((Layout::new::<azul_core::svg::SvgColoredVertexVec>(), "AzSvgColoredVertexVec"), (Layout::new::<AzSvgColoredVertexVec>(), "AzSvgColoredVertexVec"));
         assert_eq!((Layout::new::<azul_impl::css::U32Vec>(), "AzU32Vec"), (Layout::new::<AzU32Vec>(), "AzU32Vec"));
@@ -13621,6 +15331,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::number_input::OptionNumberInputOnValueChange>(), "AzOptionNumberInputOnValueChange"), (Layout::new::<AzOptionNumberInputOnValueChange>(), "AzOptionNumberInputOnValueChange"));
         assert_eq!((Layout::new::<azul_core::window::OptionMenuItemIcon>(), "AzOptionMenuItemIcon"), (Layout::new::<AzOptionMenuItemIcon>(), "AzOptionMenuItemIcon"));
         assert_eq!((Layout::new::<azul_core::window::OptionMenuCallback>(), "AzOptionMenuCallback"), (Layout::new::<AzOptionMenuCallback>(), "AzOptionMenuCallback"));
+        assert_eq!((Layout::new::<azul_impl::css::OptionNormalizedLinearColorStop>(), "AzOptionNormalizedLinearColorStop"), (Layout::new::<AzOptionNormalizedLinearColorStop>(), "AzOptionNormalizedLinearColorStop"));
         assert_eq!((Layout::new::<azul_impl::ui_solver::OptionPositionInfo>(), "AzOptionPositionInfo"), (Layout::new::<AzOptionPositionInfo>(), "AzOptionPositionInfo"));
         assert_eq!((Layout::new::<azul_impl::task::OptionTimerId>(), "AzOptionTimerId"), (Layout::new::<AzOptionTimerId>(), "AzOptionTimerId"));
         assert_eq!((Layout::new::<azul_impl::task::OptionThreadId>(), "AzOptionThreadId"), (Layout::new::<AzOptionThreadId>(), "AzOptionThreadId"));
@@ -13643,9 +15354,11 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::OptionColorU>(), "AzOptionColorU"), (Layout::new::<AzOptionColorU>(), "AzOptionColorU"));
         assert_eq!((Layout::new::<azul_impl::svg::OptionSvgDashPattern>(), "AzOptionSvgDashPattern"), (Layout::new::<AzOptionSvgDashPattern>(), "AzOptionSvgDashPattern"));
         assert_eq!((Layout::new::<azul_core::window::OptionLogicalPosition>(), "AzOptionLogicalPosition"), (Layout::new::<AzOptionLogicalPosition>(), "AzOptionLogicalPosition"));
+        assert_eq!((Layout::new::<azul_core::window::OptionLogicalRect>(), "AzOptionLogicalRect"), (Layout::new::<AzOptionLogicalRect>(), "AzOptionLogicalRect"));
         assert_eq!((Layout::new::<azul_core::window::OptionPhysicalPositionI32>(), "AzOptionPhysicalPositionI32"), (Layout::new::<AzOptionPhysicalPositionI32>(), "AzOptionPhysicalPositionI32"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseCursorType>(), "AzOptionMouseCursorType"), (Layout::new::<AzOptionMouseCursorType>(), "AzOptionMouseCursorType"));
         assert_eq!((Layout::new::<azul_core::window::OptionLogicalSize>(), "AzOptionLogicalSize"), (Layout::new::<AzOptionLogicalSize>(), "AzOptionLogicalSize"));
+        assert_eq!((Layout::new::<azul_core::window::OptionImeCursorRange>(), "AzOptionImeCursorRange"), (Layout::new::<AzOptionImeCursorRange>(), "AzOptionImeCursorRange"));
         assert_eq!((Layout::new::<azul_core::window::OptionVirtualKeyCode>(), "AzOptionVirtualKeyCode"), (Layout::new::<AzOptionVirtualKeyCode>(), "AzOptionVirtualKeyCode"));
         assert_eq!((Layout::new::<azul_impl::resources::OptionImageMask>(), "AzOptionImageMask"), (Layout::new::<AzOptionImageMask>(), "AzOptionImageMask"));
         assert_eq!((Layout::new::<azul_impl::dom::OptionTabIndex>(), "AzOptionTabIndex"), (Layout::new::<AzOptionTabIndex>(), "AzOptionTabIndex"));
@@ -13664,8 +15377,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::LargeWindowIconBytes>(), "AzLargeWindowIconBytes"), (Layout::new::<AzLargeWindowIconBytes>(), "AzLargeWindowIconBytes"));
         assert_eq!((Layout::new::<azul_core::window::WindowIcon>(), "AzWindowIcon"), (Layout::new::<AzWindowIcon>(), "AzWindowIcon"));
         assert_eq!((Layout::new::<azul_core::window::TaskBarIcon>(), "AzTaskBarIcon"), (Layout::new::<AzTaskBarIcon>(), "AzTaskBarIcon"));
+        assert_eq!((Layout::new::<azul_core::window::AcceleratorCallback>(), "AzAcceleratorCallback"), (Layout::new::<AzAcceleratorCallback>(), "AzAcceleratorCallback"));
         assert_eq!((Layout::new::<azul_core::window::WindowSize>(), "AzWindowSize"), (Layout::new::<AzWindowSize>(), "AzWindowSize"));
-        assert_eq!((Layout::new::<azul_core::window::KeyboardState>(), "AzKeyboardState"), (Layout::new::<AzKeyboardState>(), "AzKeyboardState"));
         assert_eq!((Layout::new::<azul_core::window::MouseState>(), "AzMouseState"), (Layout::new::<AzMouseState>(), "AzMouseState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::MarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"), (Layout::new::<AzMarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextContents>(), "AzInlineTextContents"), (Layout::new::<AzInlineTextContents>(), "AzInlineTextContents"));
@@ -13677,16 +15390,30 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::Menu>(), "AzMenu"), (Layout::new::<AzMenu>(), "AzMenu"));
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"), (Layout::new::<AzVirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathPseudoSelector>(), "AzCssPathPseudoSelector"), (Layout::new::<AzCssPathPseudoSelector>(), "AzCssPathPseudoSelector"));
+        assert_eq!((Layout::new::<azul_impl::css::MediaFeature>(), "AzMediaFeature"), (Layout::new::<AzMediaFeature>(), "AzMediaFeature"));
         assert_eq!((Layout::new::<azul_impl::css::AnimationInterpolationFunction>(), "AzAnimationInterpolationFunction"), (Layout::new::<AzAnimationInterpolationFunction>(), "AzAnimationInterpolationFunction"));
         assert_eq!((Layout::new::<azul_impl::css::InterpolateResolver>(), "AzInterpolateContext"), (Layout::new::<AzInterpolateContext>(), "AzInterpolateContext"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilter>(), "AzStyleFilter"), (Layout::new::<AzStyleFilter>(), "AzStyleFilter"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackRepeat>(), "AzGridTrackRepeat"), (Layout::new::<AzGridTrackRepeat>(), "AzGridTrackRepeat"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridTemplateColumns>(), "AzLayoutGridTemplateColumns"), (Layout::new::<AzLayoutGridTemplateColumns>(), "AzLayoutGridTemplateColumns"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridTemplateRows>(), "AzLayoutGridTemplateRows"), (Layout::new::<AzLayoutGridTemplateRows>(), "AzLayoutGridTemplateRows"));
         assert_eq!((Layout::new::<azul_impl::css::LinearGradient>(), "AzLinearGradient"), (Layout::new::<AzLinearGradient>(), "AzLinearGradient"));
         assert_eq!((Layout::new::<azul_impl::css::RadialGradient>(), "AzRadialGradient"), (Layout::new::<AzRadialGradient>(), "AzRadialGradient"));
         assert_eq!((Layout::new::<azul_impl::css::ConicGradient>(), "AzConicGradient"), (Layout::new::<AzConicGradient>(), "AzConicGradient"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransform>(), "AzStyleTransform"), (Layout::new::<AzStyleTransform>(), "AzStyleTransform"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionProperty>(), "AzStyleTransitionProperty"), (Layout::new::<AzStyleTransitionProperty>(), "AzStyleTransitionProperty"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridTemplateColumnsBoxed>(), "AzLayoutGridTemplateColumnsBoxed"), (Layout::new::<AzLayoutGridTemplateColumnsBoxed>(), "AzLayoutGridTemplateColumnsBoxed"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridTemplateColumnsValue>(), "AzLayoutGridTemplateColumnsValue"), (Layout::new::<AzLayoutGridTemplateColumnsValue>(), "AzLayoutGridTemplateColumnsValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridTemplateRowsBoxed>(), "AzLayoutGridTemplateRowsBoxed"), (Layout::new::<AzLayoutGridTemplateRowsBoxed>(), "AzLayoutGridTemplateRowsBoxed"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridTemplateRowsValue>(), "AzLayoutGridTemplateRowsValue"), (Layout::new::<AzLayoutGridTemplateRowsValue>(), "AzLayoutGridTemplateRowsValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundPositionVecBoxed>(), "AzStyleBackgroundPositionVecBoxed"), (Layout::new::<AzStyleBackgroundPositionVecBoxed>(), "AzStyleBackgroundPositionVecBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundPositionVecValue>(), "AzStyleBackgroundPositionVecValue"), (Layout::new::<AzStyleBackgroundPositionVecValue>(), "AzStyleBackgroundPositionVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeatVecBoxed>(), "AzStyleBackgroundRepeatVecBoxed"), (Layout::new::<AzStyleBackgroundRepeatVecBoxed>(), "AzStyleBackgroundRepeatVecBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeatVecValue>(), "AzStyleBackgroundRepeatVecValue"), (Layout::new::<AzStyleBackgroundRepeatVecValue>(), "AzStyleBackgroundRepeatVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundSizeVecBoxed>(), "AzStyleBackgroundSizeVecBoxed"), (Layout::new::<AzStyleBackgroundSizeVecBoxed>(), "AzStyleBackgroundSizeVecBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundSizeVecValue>(), "AzStyleBackgroundSizeVecValue"), (Layout::new::<AzStyleBackgroundSizeVecValue>(), "AzStyleBackgroundSizeVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionPropertyBoxed>(), "AzStyleTransitionPropertyBoxed"), (Layout::new::<AzStyleTransitionPropertyBoxed>(), "AzStyleTransitionPropertyBoxed"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionPropertyValue>(), "AzStyleTransitionPropertyValue"), (Layout::new::<AzStyleTransitionPropertyValue>(), "AzStyleTransitionPropertyValue"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxStateWrapper>(), "AzCheckBoxStateWrapper"), (Layout::new::<AzCheckBoxStateWrapper>(), "AzCheckBoxStateWrapper"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputStateWrapper>(), "AzNumberInputStateWrapper"), (Layout::new::<AzNumberInputStateWrapper>(), "AzNumberInputStateWrapper"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphCallbacks>(), "AzNodeGraphCallbacks"), (Layout::new::<AzNodeGraphCallbacks>(), "AzNodeGraphCallbacks"));
@@ -13716,7 +15443,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnectionVec>(), "AzOutputConnectionVec"), (Layout::new::<AzOutputConnectionVec>(), "AzOutputConnectionVec"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"), (Layout::new::<AzTessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedColoredSvgNodeVec>(), "AzTessellatedColoredSvgNodeVec"), (Layout::new::<AzTessellatedColoredSvgNodeVec>(), "AzTessellatedColoredSvgNodeVec"));
+        assert_eq!((Layout::new::<azul_core::window::AcceleratorCallbackVec>(), "AzAcceleratorCallbackVec"), (Layout::new::<AzAcceleratorCallbackVec>(), "AzAcceleratorCallbackVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVec>(), "AzStyleTransformVec"), (Layout::new::<AzStyleTransformVec>(), "AzStyleTransformVec"));
+        assert_eq!((Layout::new::<azul_impl::css::MediaFeatureVec>(), "AzMediaFeatureVec"), (Layout::new::<AzMediaFeatureVec>(), "AzMediaFeatureVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgPathElementVec>(), "AzSvgPathElementVec"), (Layout::new::<AzSvgPathElementVec>(), "AzSvgPathElementVec"));
         assert_eq!((Layout::new::<azul_impl::css::StringVec>(), "AzStringVec"), (Layout::new::<AzStringVec>(), "AzStringVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNodeVec>(), "AzStyledNodeVec"), (Layout::new::<AzStyledNodeVec>(), "AzStyledNodeVec"));
@@ -13725,7 +15454,6 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::ui_solver::OptionResolvedTextLayoutOptions>(), "AzOptionResolvedTextLayoutOptions"), (Layout::new::<AzOptionResolvedTextLayoutOptions>(), "AzOptionResolvedTextLayoutOptions"));
         assert_eq!((Layout::new::<azul_core::window::OptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"), (Layout::new::<AzOptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseState>(), "AzOptionMouseState"), (Layout::new::<AzOptionMouseState>(), "AzOptionMouseState"));
-        assert_eq!((Layout::new::<azul_core::window::OptionKeyboardState>(), "AzOptionKeyboardState"), (Layout::new::<AzOptionKeyboardState>(), "AzOptionKeyboardState"));
         assert_eq!((Layout::new::<azul_impl::css::OptionStringVec>(), "AzOptionStringVec"), (Layout::new::<AzOptionStringVec>(), "AzOptionStringVec"));
         assert_eq!((Layout::new::<azul_impl::task::OptionThreadReceiveMsg>(), "AzOptionThreadReceiveMsg"), (Layout::new::<AzOptionThreadReceiveMsg>(), "AzOptionThreadReceiveMsg"));
         assert_eq!((Layout::new::<azul_core::window::OptionTaskBarIcon>(), "AzOptionTaskBarIcon"), (Layout::new::<AzOptionTaskBarIcon>(), "AzOptionTaskBarIcon"));
@@ -13739,6 +15467,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::xml::UnknownEntityReferenceError>(), "AzUnknownEntityReferenceError"), (Layout::new::<AzUnknownEntityReferenceError>(), "AzUnknownEntityReferenceError"));
         assert_eq!((Layout::new::<azul_impl::xml::DuplicatedAttributeError>(), "AzDuplicatedAttributeError"), (Layout::new::<AzDuplicatedAttributeError>(), "AzDuplicatedAttributeError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidStringError>(), "AzInvalidStringError"), (Layout::new::<AzInvalidStringError>(), "AzInvalidStringError"));
+        assert_eq!((Layout::new::<azul_core::window::ImePreedit>(), "AzImePreedit"), (Layout::new::<AzImePreedit>(), "AzImePreedit"));
         assert_eq!((Layout::new::<azul_core::window::WindowsWindowOptions>(), "AzWindowsWindowOptions"), (Layout::new::<AzWindowsWindowOptions>(), "AzWindowsWindowOptions"));
         assert_eq!((Layout::new::<azul_core::window::WaylandTheme>(), "AzWaylandTheme"), (Layout::new::<AzWaylandTheme>(), "AzWaylandTheme"));
         assert_eq!((Layout::new::<azul_core::window::AzStringPair>(), "AzStringPair"), (Layout::new::<AzStringPair>(), "AzStringPair"));
@@ -13751,12 +15480,17 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::dom::IdOrClass>(), "AzIdOrClass"), (Layout::new::<AzIdOrClass>(), "AzIdOrClass"));
         assert_eq!((Layout::new::<azul_core::window::StringMenuItem>(), "AzStringMenuItem"), (Layout::new::<AzStringMenuItem>(), "AzStringMenuItem"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelector>(), "AzCssPathSelector"), (Layout::new::<AzCssPathSelector>(), "AzCssPathSelector"));
+        assert_eq!((Layout::new::<azul_impl::css::MediaCondition>(), "AzMediaCondition"), (Layout::new::<AzMediaCondition>(), "AzMediaCondition"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrack>(), "AzGridTrack"), (Layout::new::<AzGridTrack>(), "AzGridTrack"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContent>(), "AzStyleBackgroundContent"), (Layout::new::<AzStyleBackgroundContent>(), "AzStyleBackgroundContent"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarInfo>(), "AzScrollbarInfo"), (Layout::new::<AzScrollbarInfo>(), "AzScrollbarInfo"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyle>(), "AzScrollbarStyle"), (Layout::new::<AzScrollbarStyle>(), "AzScrollbarStyle"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamily>(), "AzStyleFontFamily"), (Layout::new::<AzStyleFontFamily>(), "AzStyleFontFamily"));
+        assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyleBoxed>(), "AzScrollbarStyleBoxed"), (Layout::new::<AzScrollbarStyleBoxed>(), "AzScrollbarStyleBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyleValue>(), "AzScrollbarStyleValue"), (Layout::new::<AzScrollbarStyleValue>(), "AzScrollbarStyleValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransformVecBoxed>(), "AzStyleTransformVecBoxed"), (Layout::new::<AzStyleTransformVecBoxed>(), "AzStyleTransformVecBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVecValue>(), "AzStyleTransformVecValue"), (Layout::new::<AzStyleTransformVecValue>(), "AzStyleTransformVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleFilterVecBoxed>(), "AzStyleFilterVecBoxed"), (Layout::new::<AzStyleFilterVecBoxed>(), "AzStyleFilterVecBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterVecValue>(), "AzStyleFilterVecValue"), (Layout::new::<AzStyleFilterVecValue>(), "AzStyleFilterVecValue"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputState>(), "AzFileInputState"), (Layout::new::<AzFileInputState>(), "AzFileInputState"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInputStateWrapper>(), "AzColorInputStateWrapper"), (Layout::new::<AzColorInputStateWrapper>(), "AzColorInputStateWrapper"));
@@ -13788,6 +15522,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::MonitorVec>(), "AzMonitorVec"), (Layout::new::<AzMonitorVec>(), "AzMonitorVec"));
         assert_eq!((Layout::new::<azul_impl::dom::IdOrClassVec>(), "AzIdOrClassVec"), (Layout::new::<AzIdOrClassVec>(), "AzIdOrClassVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVec>(), "AzStyleBackgroundContentVec"), (Layout::new::<AzStyleBackgroundContentVec>(), "AzStyleBackgroundContentVec"));
+        assert_eq!((Layout::new::<azul_impl::css::MediaConditionVec>(), "AzMediaConditionVec"), (Layout::new::<AzMediaConditionVec>(), "AzMediaConditionVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgPathVec>(), "AzSvgPathVec"), (Layout::new::<AzSvgPathVec>(), "AzSvgPathVec"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexAttributeVec>(), "AzVertexAttributeVec"), (Layout::new::<AzVertexAttributeVec>(), "AzVertexAttributeVec"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelectorVec>(), "AzCssPathSelectorVec"), (Layout::new::<AzCssPathSelectorVec>(), "AzCssPathSelectorVec"));
@@ -13800,11 +15535,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::OptionWaylandTheme>(), "AzOptionWaylandTheme"), (Layout::new::<AzOptionWaylandTheme>(), "AzOptionWaylandTheme"));
         assert_eq!((Layout::new::<azul_impl::resources::decode::ResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"), (Layout::new::<AzResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlStreamError>(), "AzXmlStreamError"), (Layout::new::<AzXmlStreamError>(), "AzXmlStreamError"));
+        assert_eq!((Layout::new::<azul_core::window::ImeEvent>(), "AzImeEvent"), (Layout::new::<AzImeEvent>(), "AzImeEvent"));
         assert_eq!((Layout::new::<azul_core::window::LinuxWindowOptions>(), "AzLinuxWindowOptions"), (Layout::new::<AzLinuxWindowOptions>(), "AzLinuxWindowOptions"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLine>(), "AzInlineLine"), (Layout::new::<AzInlineLine>(), "AzInlineLine"));
         assert_eq!((Layout::new::<azul_core::window::MenuItem>(), "AzMenuItem"), (Layout::new::<AzMenuItem>(), "AzMenuItem"));
         assert_eq!((Layout::new::<azul_impl::css::CssPath>(), "AzCssPath"), (Layout::new::<AzCssPath>(), "AzCssPath"));
+        assert_eq!((Layout::new::<azul_impl::css::MediaQuery>(), "AzMediaQuery"), (Layout::new::<AzMediaQuery>(), "AzMediaQuery"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVecBoxed>(), "AzStyleBackgroundContentVecBoxed"), (Layout::new::<AzStyleBackgroundContentVecBoxed>(), "AzStyleBackgroundContentVecBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"), (Layout::new::<AzStyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecBoxed>(), "AzStyleFontFamilyVecBoxed"), (Layout::new::<AzStyleFontFamilyVecBoxed>(), "AzStyleFontFamilyVecBoxed"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"), (Layout::new::<AzStyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::CssProperty>(), "AzCssProperty"), (Layout::new::<AzCssProperty>(), "AzCssProperty"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputStateWrapper>(), "AzFileInputStateWrapper"), (Layout::new::<AzFileInputStateWrapper>(), "AzFileInputStateWrapper"));
@@ -13829,8 +15568,11 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyVec>(), "AzCssPropertyVec"), (Layout::new::<AzCssPropertyVec>(), "AzCssPropertyVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"), (Layout::new::<AzSvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgSimpleNodeVec>(), "AzSvgSimpleNodeVec"), (Layout::new::<AzSvgSimpleNodeVec>(), "AzSvgSimpleNodeVec"));
+        assert_eq!((Layout::new::<azul_impl::css::OptionMediaQuery>(), "AzOptionMediaQuery"), (Layout::new::<AzOptionMediaQuery>(), "AzOptionMediaQuery"));
         assert_eq!((Layout::new::<azul_impl::css::OptionCssProperty>(), "AzOptionCssProperty"), (Layout::new::<AzOptionCssProperty>(), "AzOptionCssProperty"));
+        assert_eq!((Layout::new::<azul_core::window::OptionImeEvent>(), "AzOptionImeEvent"), (Layout::new::<AzOptionImeEvent>(), "AzOptionImeEvent"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlTextError>(), "AzXmlTextError"), (Layout::new::<AzXmlTextError>(), "AzXmlTextError"));
+        assert_eq!((Layout::new::<azul_core::window::KeyboardState>(), "AzKeyboardState"), (Layout::new::<AzKeyboardState>(), "AzKeyboardState"));
         assert_eq!((Layout::new::<azul_core::window::PlatformSpecificOptions>(), "AzPlatformSpecificOptions"), (Layout::new::<AzPlatformSpecificOptions>(), "AzPlatformSpecificOptions"));
         assert_eq!((Layout::new::<azul_core::window::WindowState>(), "AzWindowState"), (Layout::new::<AzWindowState>(), "AzWindowState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::CallbackInfo>(), "AzCallbackInfo"), (Layout::new::<AzCallbackInfo>(), "AzCallbackInfo"));
@@ -13840,11 +15582,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallbackInfo>(), "AzTimerCallbackInfo"), (Layout::new::<AzTimerCallbackInfo>(), "AzTimerCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"), (Layout::new::<AzNodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"));
         assert_eq!((Layout::new::<azul_impl::css::DynamicCssProperty>(), "AzDynamicCssProperty"), (Layout::new::<AzDynamicCssProperty>(), "AzDynamicCssProperty"));
+        assert_eq!((Layout::new::<azul_impl::css::CssVariable>(), "AzCssVariable"), (Layout::new::<AzCssVariable>(), "AzCssVariable"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::Node>(), "AzNode"), (Layout::new::<AzNode>(), "AzNode"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgNode>(), "AzSvgNode"), (Layout::new::<AzSvgNode>(), "AzSvgNode"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgStyledNode>(), "AzSvgStyledNode"), (Layout::new::<AzSvgStyledNode>(), "AzSvgStyledNode"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"), (Layout::new::<AzNodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssVariableVec>(), "AzCssVariableVec"), (Layout::new::<AzCssVariableVec>(), "AzCssVariableVec"));
         assert_eq!((Layout::new::<azul_core::window::OptionWindowState>(), "AzOptionWindowState"), (Layout::new::<AzOptionWindowState>(), "AzOptionWindowState"));
+        assert_eq!((Layout::new::<azul_core::window::OptionKeyboardState>(), "AzOptionKeyboardState"), (Layout::new::<AzOptionKeyboardState>(), "AzOptionKeyboardState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionInlineText>(), "AzOptionInlineText"), (Layout::new::<AzOptionInlineText>(), "AzOptionInlineText"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlParseError>(), "AzXmlParseError"), (Layout::new::<AzXmlParseError>(), "AzXmlParseError"));
         assert_eq!((Layout::new::<azul_core::window::WindowCreateOptions>(), "AzWindowCreateOptions"), (Layout::new::<AzWindowCreateOptions>(), "AzWindowCreateOptions"));
@@ -0,0 +1,165 @@
+//! Popup menus opened from callbacks with `AzCallbackInfo_openContextMenu`
+//!
+//! On Windows the menu is a native popup menu, on all other platforms it is
+//! drawn by azul on top of the DOM (see `azul_core::overlay_menu`). Clicking
+//! an item invokes its callback with the hit node of the callback that opened
+//! the menu.
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        az_test_harness, AzApp, AzApp_createTestHarness, AzCallbackInfo_openContextMenu,
+        AzContextMenuMouseButton, AzLogicalPosition, AzTestHarnessPtr, AzTestHarness_getData,
+        AzTestHarness_keyDown, AzTestHarness_keyUp, AzTestHarness_mouseDown,
+        AzTestHarness_mouseMove, AzTestHarness_mouseUp, AzVirtualKeyCode, AzWindowCreateOptions,
+    };
+    use azul_core::id_tree::NodeId;
+    use azul_core::window::{Menu, MenuItem, StringMenuItem, VirtualKeyCode, VirtualKeyCodeCombo};
+    use azul_impl::{
+        callbacks::{Callback, CallbackInfo, DomNodeId, LayoutCallbackInfo, RefAny, Update},
+        css::Css,
+        dom::{CallbackData, Dom, On},
+        resources::{AppConfig, LayoutSolverVersion},
+        styled_dom::{DomId, NodeHierarchyItemId, StyledDom},
+    };
+
+    #[derive(Default)]
+    struct DataModel {
+        opened: usize,
+        copied: Vec<DomNodeId>,
+    }
+
+    type CallbackFn = extern "C" fn(&mut RefAny, &mut CallbackInfo) -> Update;
+
+    fn menu(label: &str, data: &RefAny, callback: CallbackFn) -> Menu {
+        Menu::new(
+            vec![MenuItem::String(
+                StringMenuItem::new(label.to_string().into()).with_callback(data.clone(), callback),
+            )]
+            .into(),
+        )
+    }
+
+    extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+        let open = StringMenuItem::new("Open".into())
+            .with_callback(data.clone(), on_open)
+            .with_accelerator(VirtualKeyCodeCombo::new(
+                vec![VirtualKeyCode::LControl, VirtualKeyCode::O].into(),
+            ));
+        let file =
+            StringMenuItem::new("File".into()).with_children(vec![MenuItem::String(open)].into());
+
+        let mut body = Dom::body().with_children(
+            vec![
+                // right click: context menu of the node
+                Dom::div()
+                    .with_inline_style("height: 100px;")
+                    .with_context_menu(menu("Copy", data, on_copy)),
+                // left click: menu opened by the callback
+                Dom::div()
+                    .with_inline_style("height: 100px;")
+                    .with_callbacks(
+                        vec![CallbackData {
+                            event: On::LeftMouseUp.into(),
+                            callback: Callback { cb: on_button_clicked },
+                            data: data.clone(),
+                        }]
+                        .into(),
+                    ),
+            ]
+            .into(),
+        );
+        body.root.set_menu_bar(Menu::new(vec![MenuItem::String(file)].into()));
+        body.style(Css::empty())
+    }
+
+    extern "C" fn on_open(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        match data.downcast_mut::<DataModel>() {
+            Some(mut d) => d.opened += 1,
+            None => return Update::DoNothing,
+        }
+        Update::RefreshDom
+    }
+
+    extern "C" fn on_copy(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        match data.downcast_mut::<DataModel>() {
+            Some(mut d) => d.copied.push(info.get_hit_node()),
+            None => return Update::DoNothing,
+        }
+        Update::RefreshDom
+    }
+
+    extern "C" fn on_button_clicked(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+        // below both nodes, so that clicking the item doesn't click the button again
+        let menu = menu("Copy", data, on_copy);
+        AzCallbackInfo_openContextMenu(info, menu, AzLogicalPosition::new(50.0, 250.0));
+        Update::DoNothing
+    }
+
+    fn opened_and_copied(harness: AzTestHarnessPtr) -> (usize, Vec<DomNodeId>) {
+        unsafe { AzTestHarness_getData(harness) }
+            .downcast_ref::<DataModel>()
+            .map(|d| (d.opened, d.copied.clone()))
+            .unwrap()
+    }
+
+    fn node(id: usize) -> DomNodeId {
+        DomNodeId {
+            dom: DomId::ROOT_ID,
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(id))),
+        }
+    }
+
+    fn click(harness: AzTestHarnessPtr, x: f32, y: f32, button: AzContextMenuMouseButton) {
+        unsafe {
+            AzTestHarness_mouseMove(harness, x, y);
+            AzTestHarness_mouseDown(harness, button);
+            AzTestHarness_mouseUp(harness, button);
+        }
+    }
+
+    #[test]
+    fn test_menu_bar_accelerator() {
+        let data = RefAny::new(DataModel::default());
+        let app = AzApp::new(data, AppConfig::new(LayoutSolverVersion::Default));
+        let harness = AzApp_createTestHarness(&app, AzWindowCreateOptions::new(layout));
+        assert!(!harness.is_null());
+
+        unsafe {
+            AzTestHarness_keyDown(harness, AzVirtualKeyCode::LControl);
+            AzTestHarness_keyDown(harness, AzVirtualKeyCode::O);
+            AzTestHarness_keyUp(harness, AzVirtualKeyCode::O);
+            AzTestHarness_keyUp(harness, AzVirtualKeyCode::LControl);
+        }
+        assert_eq!(opened_and_copied(harness).0, 1);
+
+        unsafe { az_test_harness::delete(harness) };
+    }
+
+    #[test]
+    fn test_overlay_context_menu() {
+        let data = RefAny::new(DataModel::default());
+        let app = AzApp::new(data, AppConfig::new(LayoutSolverVersion::Default));
+        let harness = AzApp_createTestHarness(&app, AzWindowCreateOptions::new(layout));
+        assert!(!harness.is_null());
+
+        click(harness, 50.0, 50.0, AzContextMenuMouseButton::Right);
+        assert!(opened_and_copied(harness).1.is_empty());
+
+        // the menu opens at the cursor: the item starts 3px below (border + padding),
+        // its callback gets the node that was right-clicked
+        click(harness, 60.0, 55.0, AzContextMenuMouseButton::Left);
+        assert_eq!(opened_and_copied(harness).1, vec![node(1)]);
+
+        // the menu is closed: clicking the same spot again does nothing
+        click(harness, 60.0, 55.0, AzContextMenuMouseButton::Left);
+        assert_eq!(opened_and_copied(harness).1, vec![node(1)]);
+
+        // menu opened by the callback of the second div
+        click(harness, 50.0, 150.0, AzContextMenuMouseButton::Left);
+        click(harness, 60.0, 255.0, AzContextMenuMouseButton::Left);
+        assert_eq!(opened_and_copied(harness).1, vec![node(1), node(2)]);
+
+        unsafe { az_test_harness::delete(harness) };
+    }
+}
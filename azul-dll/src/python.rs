@@ -734,12 +734,26 @@ pub enum AzAppLogLevel {
     Trace,
 }
 
+/// Controls how often a window renders a new frame
+#[repr(C, u8)]
+pub enum AzRedrawBehavior {
+    OnEventOnly,
+    MaxFps(u16),
+    Unlimited,
+}
+
 /// Version of the layout solver to use - future binary versions of azul may have more fields here, necessary so that old compiled applications don't break with newer releases of azul. Newer layout versions are opt-in only.
 #[repr(C)]
 pub enum AzLayoutSolver {
     Default,
 }
 
+/// Version and feature detection of the loaded library. Bindings that load the library dynamically should call these functions before anything else and compare the results with the `AZ_API_VERSION_*` and `AZ_API_ABI_HASH` defines of the header they were generated from. The functions only use primitive types, so they can be called even if the rest of the API does not match.
+#[repr(C)]
+pub struct AzApi {
+    pub _reserved: usize,
+}
+
 /// Whether the renderer has VSync enabled
 #[repr(C)]
 pub enum AzVsync {
@@ -1054,6 +1068,21 @@ pub enum AzVirtualKeyCode {
     Cut,
 }
 
+/// Which modifier keys are held down (left and right keys are not distinguished)
+#[repr(C)]
+pub struct AzModifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub super_key: bool,
+}
+
+/// ID of a registered accelerator, used to replace or remove it
+#[repr(C)]
+pub struct AzAcceleratorId {
+    pub id: usize,
+}
+
 /// State of the window frame (minimized, maximized, fullscreen or normal window)
 #[repr(C)]
 pub enum AzWindowFrame {
@@ -1095,6 +1124,13 @@ pub struct AzDebugState {
     pub force_picture_invalidation: bool,
 }
 
+/// Byte range in the `ImePreedit` text
+#[repr(C)]
+pub struct AzImeCursorRange {
+    pub start: usize,
+    pub end: usize,
+}
+
 /// Current icon of the mouse cursor
 #[repr(C)]
 pub enum AzMouseCursorType {
@@ -1299,6 +1335,9 @@ pub struct AzThreadCallback {
 /// `AzThreadCallbackType` struct
 pub type AzThreadCallbackType = extern "C" fn(AzRefAny, AzThreadSender, AzThreadReceiver);
 
+/// `AzThreadFnType` struct
+pub type AzThreadFnType = extern "C" fn(&mut AzRefAny, &mut AzThreadSender, &mut AzThreadReceiver);
+
 /// `AzRefAnyDestructorType` struct
 pub type AzRefAnyDestructorType = extern "C" fn(&mut c_void);
 
@@ -1361,6 +1400,9 @@ pub enum AzHoverEventFilter {
     TouchMove,
     TouchEnd,
     TouchCancel,
+    ImeStateChanged,
+    ImePreedit,
+    ImeCommit,
 }
 
 /// Re-export of rust-allocated (stack based) `FocusEventFilter` struct
@@ -1385,6 +1427,9 @@ pub enum AzFocusEventFilter {
     VirtualKeyUp,
     FocusReceived,
     FocusLost,
+    ImeStateChanged,
+    ImePreedit,
+    ImeCommit,
 }
 
 /// Re-export of rust-allocated (stack based) `WindowEventFilter` struct
@@ -1420,6 +1465,11 @@ pub enum AzWindowEventFilter {
     FocusLost,
     CloseRequested,
     ThemeChanged,
+    WindowFocusReceived,
+    WindowFocusLost,
+    ImeStateChanged,
+    ImePreedit,
+    ImeCommit,
 }
 
 /// Re-export of rust-allocated (stack based) `ComponentEventFilter` struct
@@ -1586,6 +1636,20 @@ pub struct AzCssNthChildPattern {
     pub offset: u32,
 }
 
+/// Value of the `orientation` media feature
+#[repr(C)]
+pub enum AzMediaOrientation {
+    Portrait,
+    Landscape,
+}
+
+/// Value of the `prefers-color-scheme` media feature
+#[repr(C)]
+pub enum AzMediaColorScheme {
+    Light,
+    Dark,
+}
+
 /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
 #[repr(C)]
 pub enum AzCssPropertyType {
@@ -1593,6 +1657,8 @@ pub enum AzCssPropertyType {
     FontSize,
     FontFamily,
     TextAlign,
+    VerticalAlign,
+    Direction,
     LetterSpacing,
     LineHeight,
     WordSpacing,
@@ -1619,6 +1685,10 @@ pub enum AzCssPropertyType {
     JustifyContent,
     AlignItems,
     AlignContent,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridColumn,
+    GridRow,
     BackgroundContent,
     BackgroundPosition,
     BackgroundSize,
@@ -1660,9 +1730,18 @@ pub enum AzCssPropertyType {
     PerspectiveOrigin,
     BackfaceVisibility,
     MixBlendMode,
+    Isolation,
+    ImageRendering,
     Filter,
     BackdropFilter,
     TextShadow,
+    TransitionProperty,
+    TransitionDuration,
+    TransitionTimingFunction,
+    TransitionDelay,
+    CaretColor,
+    SelectionColor,
+    SelectionBackground,
 }
 
 /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -1717,6 +1796,21 @@ pub enum AzStyleMixBlendMode {
     Luminosity,
 }
 
+/// Represents an `isolation` attribute: whether the element creates a new stacking context for `mix-blend-mode`
+#[repr(C)]
+pub enum AzStyleIsolation {
+    Auto,
+    Isolate,
+}
+
+/// Represents an `image-rendering` attribute: which algorithm is used to scale images and background images
+#[repr(C)]
+pub enum AzStyleImageRendering {
+    Auto,
+    CrispEdges,
+    Pixelated,
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignContent` struct
 #[repr(C)]
 pub enum AzLayoutAlignContent {
@@ -1760,6 +1854,16 @@ pub enum AzLayoutDisplay {
     Flex,
     Block,
     InlineBlock,
+    Grid,
+    Inline,
+}
+
+/// Start or end line of a `grid-column` or `grid-row` attribute
+#[repr(C, u8)]
+pub enum AzGridLine {
+    Auto,
+    Line(i16),
+    Span(u16),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -1767,6 +1871,7 @@ pub enum AzLayoutDisplay {
 pub enum AzLayoutFloat {
     Left,
     Right,
+    None,
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutJustifyContent` struct
@@ -1794,6 +1899,7 @@ pub enum AzLayoutPosition {
 pub enum AzLayoutFlexWrap {
     Wrap,
     NoWrap,
+    WrapReverse,
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutOverflow` struct
@@ -1803,6 +1909,7 @@ pub enum AzLayoutOverflow {
     Auto,
     Hidden,
     Visible,
+    Clip,
 }
 
 /// Re-export of rust-allocated (stack based) `AngleMetric` struct
@@ -1858,6 +1965,8 @@ pub enum AzStyleBackgroundRepeat {
     Repeat,
     RepeatX,
     RepeatY,
+    Space,
+    Round,
 }
 
 /// Re-export of rust-allocated (stack based) `BorderStyle` struct
@@ -1923,6 +2032,24 @@ pub enum AzStyleTextAlign {
     Left,
     Center,
     Right,
+    Start,
+    End,
+}
+
+/// Represents a `direction` attribute: the inline base direction of text and of `flex-direction: row` - default: `Ltr`
+#[repr(C)]
+pub enum AzStyleDirection {
+    Ltr,
+    Rtl,
+}
+
+/// Position of the jumps of a `steps()` timing function - default: `JumpEnd`
+#[repr(C)]
+pub enum AzStepPosition {
+    JumpStart,
+    JumpEnd,
+    JumpNone,
+    JumpBoth,
 }
 
 /// Re-export of rust-allocated (stack based) `Ribbon` struct
@@ -2724,7 +2851,7 @@ pub struct AzThreadId {
     pub id: usize,
 }
 
-/// Re-export of rust-allocated (stack based) `Thread` struct
+/// Background thread, started via `CallbackInfo::start_thread`. The thread function receives the `thread_initialize_data`, a `ThreadSender` and a `ThreadReceiver` by value: in C, the thread function has to delete all three before it returns. Progress and results are sent back with `ThreadSender::send(ThreadReceiveMsg::WriteBack(..))`: the `WriteBackCallback` then runs on the main thread with the `writeback_data` and the sent data (which is deleted after the callback returns). Once the thread function has returned and all of its messages have been processed, the thread is removed and the `writeback_data` is deleted. `CallbackInfo::stop_thread` sends a `ThreadSendMsg::TerminateThread` and joins the thread, so the thread function should check `ThreadReceiver::receive` regularly and return early.
 #[repr(C)]
 pub struct AzThread {
     pub ptr: *const c_void,
@@ -2835,6 +2962,13 @@ pub struct AzThreadSenderDestructorFn {
     pub cb: AzThreadSenderDestructorFnType,
 }
 
+/// Position of the text cursor (caret) in a UTF-8 string. `byte_offset` is always on a grapheme boundary, `grapheme_offset` is the number of graphemes before the cursor.
+#[repr(C)]
+pub struct AzTextCursor {
+    pub byte_offset: usize,
+    pub grapheme_offset: usize,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzStyleFontFamilyVecDestructor {
@@ -3110,6 +3244,17 @@ pub enum AzVideoModeVecDestructor {
 /// `AzVideoModeVecDestructorType` struct
 pub type AzVideoModeVecDestructorType = extern "C" fn(&mut AzVideoModeVec);
 
+/// Re-export of rust-allocated (stack based) `AcceleratorCallbackVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzAcceleratorCallbackVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzAcceleratorCallbackVecDestructorType),
+}
+
+/// `AzAcceleratorCallbackVecDestructorType` struct
+pub type AzAcceleratorCallbackVecDestructorType = extern "C" fn(&mut AzAcceleratorCallbackVec);
+
 /// Re-export of rust-allocated (stack based) `DomVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzDomVecDestructor {
@@ -3198,6 +3343,61 @@ pub enum AzStyleTransformVecDestructor {
 /// `AzStyleTransformVecDestructorType` struct
 pub type AzStyleTransformVecDestructorType = extern "C" fn(&mut AzStyleTransformVec);
 
+/// Re-export of rust-allocated (stack based) `GridTrackVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzGridTrackVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzGridTrackVecDestructorType),
+}
+
+/// `AzGridTrackVecDestructorType` struct
+pub type AzGridTrackVecDestructorType = extern "C" fn(&mut AzGridTrackVec);
+
+/// Re-export of rust-allocated (stack based) `CssPropertyTypeVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssPropertyTypeVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssPropertyTypeVecDestructorType),
+}
+
+/// `AzCssPropertyTypeVecDestructorType` struct
+pub type AzCssPropertyTypeVecDestructorType = extern "C" fn(&mut AzCssPropertyTypeVec);
+
+/// Re-export of rust-allocated (stack based) `CssVariableVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssVariableVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssVariableVecDestructorType),
+}
+
+/// `AzCssVariableVecDestructorType` struct
+pub type AzCssVariableVecDestructorType = extern "C" fn(&mut AzCssVariableVec);
+
+/// Re-export of rust-allocated (stack based) `MediaFeatureVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzMediaFeatureVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzMediaFeatureVecDestructorType),
+}
+
+/// `AzMediaFeatureVecDestructorType` struct
+pub type AzMediaFeatureVecDestructorType = extern "C" fn(&mut AzMediaFeatureVec);
+
+/// Re-export of rust-allocated (stack based) `MediaConditionVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzMediaConditionVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzMediaConditionVecDestructorType),
+}
+
+/// `AzMediaConditionVecDestructorType` struct
+pub type AzMediaConditionVecDestructorType = extern "C" fn(&mut AzMediaConditionVec);
+
 /// Re-export of rust-allocated (stack based) `CssPropertyVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzCssPropertyVecDestructor {
@@ -3694,6 +3894,13 @@ pub enum AzAcceleratorKey {
     Key(AzVirtualKeyCode),
 }
 
+/// Window-wide keyboard shortcut (i.e. `Ctrl+S`) that fires regardless of which node is focused
+#[repr(C)]
+pub struct AzAccelerator {
+    pub modifiers: AzModifiers,
+    pub key: AzVirtualKeyCodeEnumWrapper,
+}
+
 /// Boolean flags relating to the current window state
 #[repr(C)]
 pub struct AzWindowFlags {
@@ -3867,6 +4074,22 @@ pub struct AzPixelValue {
     pub number: AzFloatValue,
 }
 
+/// Simplified `calc()` expression: sum of lengths with different metrics, i.e. `calc(100% - 32px)` is stored as `{ percent: 100, px: -32 }`
+#[repr(C)]
+pub struct AzCalcLength {
+    pub px: AzFloatValue,
+    pub pt: AzFloatValue,
+    pub em: AzFloatValue,
+    pub percent: AzFloatValue,
+}
+
+/// Value of a boxed `Calc` length: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzCalcLengthBoxed {
+    pub run_destructor: bool,
+    pub value: AzCalcLength,
+}
+
 /// Re-export of rust-allocated (stack based) `PixelValueNoPercent` struct
 #[repr(C)]
 pub struct AzPixelValueNoPercent {
@@ -3921,6 +4144,25 @@ pub struct AzLayoutBottom {
     pub inner: AzPixelValue,
 }
 
+/// Start / end line pair of a `grid-column` or `grid-row` attribute
+#[repr(C)]
+pub struct AzGridPlacement {
+    pub start: AzGridLineEnumWrapper,
+    pub end: AzGridLineEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridColumn` struct
+#[repr(C)]
+pub struct AzLayoutGridColumn {
+    pub inner: AzGridPlacement,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridRow` struct
+#[repr(C)]
+pub struct AzLayoutGridRow {
+    pub inner: AzGridPlacement,
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutFlexGrow` struct
 #[repr(C)]
 pub struct AzLayoutFlexGrow {
@@ -4327,18 +4569,97 @@ pub struct AzStyleTransformSkew2D {
     pub y: AzPercentageValue,
 }
 
+/// Represents a `transition-duration` attribute, in milliseconds
+#[repr(C)]
+pub struct AzStyleTransitionDuration {
+    pub inner: AzFloatValue,
+}
+
+/// Represents a `transition-delay` attribute, in milliseconds (can be negative)
+#[repr(C)]
+pub struct AzStyleTransitionDelay {
+    pub inner: AzFloatValue,
+}
+
+/// Control points of a `cubic-bezier(x1, y1, x2, y2)` timing function
+#[repr(C)]
+pub struct AzStyleCubicBezier {
+    pub x1: AzFloatValue,
+    pub y1: AzFloatValue,
+    pub x2: AzFloatValue,
+    pub y2: AzFloatValue,
+}
+
+/// A `steps(count, position)` timing function
+#[repr(C)]
+pub struct AzStyleSteps {
+    pub count: u32,
+    pub position: AzStepPositionEnumWrapper,
+}
+
+/// Represents a `transition-timing-function` attribute - default: `Ease`
+#[repr(C, u8)]
+pub enum AzStyleTransitionTimingFunction {
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(AzStyleCubicBezier),
+    Steps(AzStyleSteps),
+}
+
+/// Represents a `vertical-align` attribute, aligns an inline item (i.e. an icon next to text) inside of its line - default: `Baseline`
+#[repr(C, u8)]
+pub enum AzStyleVerticalAlign {
+    Baseline,
+    Top,
+    Middle,
+    Bottom,
+    TextTop,
+    TextBottom,
+    Sub,
+    Super,
+    Exact(AzPixelValue),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
 #[repr(C)]
 pub struct AzStyleTextColor {
     pub inner: AzColorU,
 }
 
+/// Represents a `caret-color` attribute: the color of the text cursor in editable text
+#[repr(C)]
+pub struct AzStyleCaretColor {
+    pub inner: AzColorU,
+}
+
+/// Represents a `-azul-selection-color` attribute: the text color of selected text (same as `::selection { color }`)
+#[repr(C)]
+pub struct AzStyleSelectionColor {
+    pub inner: AzColorU,
+}
+
+/// Represents a `-azul-selection-background-color` attribute: the highlight color behind selected text
+#[repr(C)]
+pub struct AzStyleSelectionBackground {
+    pub inner: AzColorU,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleWordSpacing` struct
 #[repr(C)]
 pub struct AzStyleWordSpacing {
     pub inner: AzPixelValue,
 }
 
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleBoxShadowBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleBoxShadow,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBoxShadowValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBoxShadowValue {
@@ -4346,7 +4667,7 @@ pub enum AzStyleBoxShadowValue {
     None,
     Inherit,
     Initial,
-    Exact(AzStyleBoxShadow),
+    Exact(*const AzStyleBoxShadowBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutAlignContentValue` struct
@@ -4359,6 +4680,26 @@ pub enum AzLayoutAlignContentValue {
     Exact(AzLayoutAlignContent),
 }
 
+/// Re-export of rust-allocated (stack based) `LayoutGridColumnValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutGridColumnValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutGridColumn),
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridRowValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutGridRowValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutGridRow),
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
 #[repr(C, u8)]
 pub enum AzLayoutAlignItemsValue {
@@ -4377,6 +4718,7 @@ pub enum AzLayoutBottomValue {
     Inherit,
     Initial,
     Exact(AzLayoutBottom),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutBoxSizingValue` struct
@@ -4447,6 +4789,7 @@ pub enum AzLayoutHeightValue {
     Inherit,
     Initial,
     Exact(AzLayoutHeight),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutJustifyContentValue` struct
@@ -4467,6 +4810,7 @@ pub enum AzLayoutLeftValue {
     Inherit,
     Initial,
     Exact(AzLayoutLeft),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutMarginBottomValue` struct
@@ -4477,6 +4821,7 @@ pub enum AzLayoutMarginBottomValue {
     Inherit,
     Initial,
     Exact(AzLayoutMarginBottom),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutMarginLeftValue` struct
@@ -4487,6 +4832,7 @@ pub enum AzLayoutMarginLeftValue {
     Inherit,
     Initial,
     Exact(AzLayoutMarginLeft),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutMarginRightValue` struct
@@ -4497,6 +4843,7 @@ pub enum AzLayoutMarginRightValue {
     Inherit,
     Initial,
     Exact(AzLayoutMarginRight),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutMarginTopValue` struct
@@ -4507,6 +4854,7 @@ pub enum AzLayoutMarginTopValue {
     Inherit,
     Initial,
     Exact(AzLayoutMarginTop),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutMaxHeightValue` struct
@@ -4517,6 +4865,7 @@ pub enum AzLayoutMaxHeightValue {
     Inherit,
     Initial,
     Exact(AzLayoutMaxHeight),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutMaxWidthValue` struct
@@ -4527,6 +4876,7 @@ pub enum AzLayoutMaxWidthValue {
     Inherit,
     Initial,
     Exact(AzLayoutMaxWidth),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutMinHeightValue` struct
@@ -4537,6 +4887,7 @@ pub enum AzLayoutMinHeightValue {
     Inherit,
     Initial,
     Exact(AzLayoutMinHeight),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutMinWidthValue` struct
@@ -4547,6 +4898,7 @@ pub enum AzLayoutMinWidthValue {
     Inherit,
     Initial,
     Exact(AzLayoutMinWidth),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutPaddingBottomValue` struct
@@ -4557,6 +4909,7 @@ pub enum AzLayoutPaddingBottomValue {
     Inherit,
     Initial,
     Exact(AzLayoutPaddingBottom),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutPaddingLeftValue` struct
@@ -4567,6 +4920,7 @@ pub enum AzLayoutPaddingLeftValue {
     Inherit,
     Initial,
     Exact(AzLayoutPaddingLeft),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutPaddingRightValue` struct
@@ -4577,6 +4931,7 @@ pub enum AzLayoutPaddingRightValue {
     Inherit,
     Initial,
     Exact(AzLayoutPaddingRight),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutPaddingTopValue` struct
@@ -4587,6 +4942,7 @@ pub enum AzLayoutPaddingTopValue {
     Inherit,
     Initial,
     Exact(AzLayoutPaddingTop),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutPositionValue` struct
@@ -4607,6 +4963,7 @@ pub enum AzLayoutRightValue {
     Inherit,
     Initial,
     Exact(AzLayoutRight),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutTopValue` struct
@@ -4617,6 +4974,7 @@ pub enum AzLayoutTopValue {
     Inherit,
     Initial,
     Exact(AzLayoutTop),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutWidthValue` struct
@@ -4627,6 +4985,7 @@ pub enum AzLayoutWidthValue {
     Inherit,
     Initial,
     Exact(AzLayoutWidth),
+    Calc(*const AzCalcLengthBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutFlexWrapValue` struct
@@ -4869,6 +5228,100 @@ pub enum AzStyleTextAlignValue {
     Exact(AzStyleTextAlign),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleDirectionValue` struct
+#[repr(C, u8)]
+pub enum AzStyleDirectionValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleDirection),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDurationValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionDurationValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTransitionDuration),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleTransitionTimingFunctionBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleTransitionTimingFunctionEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionTimingFunctionValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionTimingFunctionValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(*const AzStyleTransitionTimingFunctionBoxed),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionDelayValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionDelayValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTransitionDelay),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleCaretColorValue` struct
+#[repr(C, u8)]
+pub enum AzStyleCaretColorValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleCaretColor),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleSelectionColorValue` struct
+#[repr(C, u8)]
+pub enum AzStyleSelectionColorValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleSelectionColor),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleSelectionBackgroundValue` struct
+#[repr(C, u8)]
+pub enum AzStyleSelectionBackgroundValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleSelectionBackground),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleVerticalAlignBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleVerticalAlignEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleVerticalAlignValue` struct
+#[repr(C, u8)]
+pub enum AzStyleVerticalAlignValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(*const AzStyleVerticalAlignBoxed),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
 #[repr(C, u8)]
 pub enum AzStyleTextColorValue {
@@ -4899,6 +5352,13 @@ pub enum AzStyleOpacityValue {
     Exact(AzStyleOpacity),
 }
 
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleTransformOriginBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleTransformOrigin,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTransformOriginValue` struct
 #[repr(C, u8)]
 pub enum AzStyleTransformOriginValue {
@@ -4906,7 +5366,14 @@ pub enum AzStyleTransformOriginValue {
     None,
     Inherit,
     Initial,
-    Exact(AzStyleTransformOrigin),
+    Exact(*const AzStyleTransformOriginBoxed),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStylePerspectiveOriginBoxed {
+    pub run_destructor: bool,
+    pub value: AzStylePerspectiveOrigin,
 }
 
 /// Re-export of rust-allocated (stack based) `StylePerspectiveOriginValue` struct
@@ -4916,7 +5383,7 @@ pub enum AzStylePerspectiveOriginValue {
     None,
     Inherit,
     Initial,
-    Exact(AzStylePerspectiveOrigin),
+    Exact(*const AzStylePerspectiveOriginBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibilityValue` struct
@@ -4939,6 +5406,26 @@ pub enum AzStyleMixBlendModeValue {
     Exact(AzStyleMixBlendMode),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleIsolationValue` struct
+#[repr(C, u8)]
+pub enum AzStyleIsolationValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleIsolation),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleImageRenderingValue` struct
+#[repr(C, u8)]
+pub enum AzStyleImageRenderingValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleImageRendering),
+}
+
 /// Re-export of rust-allocated (stack based) `ButtonOnClick` struct
 #[repr(C)]
 pub struct AzButtonOnClick {
@@ -5277,6 +5764,13 @@ pub enum AzDuration {
     Tick(AzSystemTickDiff),
 }
 
+/// Background thread that has been created, but not started yet. Unlike `CallbackInfo::start_thread`, the data is moved back to the main thread once the thread function returns and passed to an `on_finish` callback. Start it with `CallbackInfo::start_pending_thread`, which consumes it - a thread that is never started has to be deleted.
+#[repr(C)]
+pub struct AzPendingThread {
+    pub data: AzRefAny,
+    pub thread_fn: AzThreadFnType,
+}
+
 /// Re-export of rust-allocated (stack based) `ThreadSendMsg` struct
 #[repr(C, u8)]
 pub enum AzThreadSendMsg {
@@ -5292,6 +5786,13 @@ pub struct AzThreadWriteBackMsg {
     pub callback: AzWriteBackCallback,
 }
 
+/// Selected range of a text. `start` is where the selection was started, `end` is where the caret is (before `start` if the text was selected backwards)
+#[repr(C)]
+pub struct AzTextSelection {
+    pub start: AzTextCursor,
+    pub end: AzTextCursor,
+}
+
 /// Wrapper over a Rust-allocated `Vec<LogicalRect>`
 #[repr(C)]
 pub struct AzLogicalRectVec {
@@ -5418,6 +5919,24 @@ pub struct AzStyleBackgroundSizeVec {
     pub destructor: AzStyleBackgroundSizeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<GridTrack>`
+#[repr(C)]
+pub struct AzGridTrackVec {
+    pub(crate) ptr: *const AzGridTrackEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzGridTrackVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<CssPropertyType>`
+#[repr(C)]
+pub struct AzCssPropertyTypeVec {
+    pub(crate) ptr: *const AzCssPropertyTypeEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzCssPropertyTypeVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `SvgVertex`
 #[repr(C)]
 pub struct AzSvgVertexVec {
@@ -5760,6 +6279,13 @@ pub enum AzOptionMenuCallback {
     Some(AzMenuCallback),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionNormalizedLinearColorStop` struct
+#[repr(C, u8)]
+pub enum AzOptionNormalizedLinearColorStop {
+    None,
+    Some(AzNormalizedLinearColorStop),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionPositionInfo` struct
 #[repr(C, u8)]
 pub enum AzOptionPositionInfo {
@@ -5914,6 +6440,13 @@ pub enum AzOptionLogicalPosition {
     Some(AzLogicalPosition),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+#[repr(C, u8)]
+pub enum AzOptionLogicalRect {
+    None,
+    Some(AzLogicalRect),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionPhysicalPositionI32` struct
 #[repr(C, u8)]
 pub enum AzOptionPhysicalPositionI32 {
@@ -5935,6 +6468,13 @@ pub enum AzOptionLogicalSize {
     Some(AzLogicalSize),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionImeCursorRange` struct
+#[repr(C, u8)]
+pub enum AzOptionImeCursorRange {
+    None,
+    Some(AzImeCursorRange),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionVirtualKeyCode` struct
 #[repr(C, u8)]
 pub enum AzOptionVirtualKeyCode {
@@ -6037,6 +6577,7 @@ pub struct AzAppConfig {
     pub enable_logging_on_panic: bool,
     pub enable_tab_navigation: bool,
     pub system_callbacks: AzSystemCallbacks,
+    pub redraw_behavior: AzRedrawBehaviorEnumWrapper,
 }
 
 /// Small (16x16x4) window icon, usually shown in the window titlebar
@@ -6067,6 +6608,15 @@ pub struct AzTaskBarIcon {
     pub rgba_bytes: AzU8Vec,
 }
 
+/// Accelerator + the callback to invoke when it is pressed
+#[repr(C)]
+pub struct AzAcceleratorCallback {
+    pub id: AzAcceleratorId,
+    pub accelerator: AzAccelerator,
+    pub data: AzRefAny,
+    pub callback: AzCallback,
+}
+
 /// Minimum / maximum / current size of the window in logical dimensions
 #[repr(C)]
 pub struct AzWindowSize {
@@ -6076,15 +6626,6 @@ pub struct AzWindowSize {
     pub max_dimensions: AzOptionLogicalSizeEnumWrapper,
 }
 
-/// Current keyboard state, stores what keys / characters have been pressed
-#[repr(C)]
-pub struct AzKeyboardState {
-    pub current_char: AzOptionCharEnumWrapper,
-    pub current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper,
-    pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
-    pub pressed_scancodes: AzScanCodeVec,
-}
-
 /// Current mouse / cursor state
 #[repr(C)]
 pub struct AzMouseState {
@@ -6201,6 +6742,26 @@ pub enum AzCssPathPseudoSelector {
     Focus,
 }
 
+/// One `(feature: value)` or `(feature < value)` test of a `MediaCondition`
+#[repr(C, u8)]
+pub enum AzMediaFeature {
+    MinWidth(AzPixelValue),
+    MaxWidth(AzPixelValue),
+    MinHeight(AzPixelValue),
+    MaxHeight(AzPixelValue),
+    WidthLessThan(AzPixelValue),
+    WidthGreaterThan(AzPixelValue),
+    HeightLessThan(AzPixelValue),
+    HeightGreaterThan(AzPixelValue),
+    Orientation(AzMediaOrientation),
+    PrefersColorScheme(AzMediaColorScheme),
+    Resolution(AzFloatValue),
+    MinResolution(AzFloatValue),
+    MaxResolution(AzFloatValue),
+    ResolutionLessThan(AzFloatValue),
+    ResolutionGreaterThan(AzFloatValue),
+}
+
 /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
 #[repr(C, u8)]
 pub enum AzAnimationInterpolationFunction {
@@ -6220,6 +6781,7 @@ pub struct AzInterpolateContext {
     pub parent_rect_height: f32,
     pub current_rect_width: f32,
     pub current_rect_height: f32,
+    pub em_size: f32,
 }
 
 /// Re-export of rust-allocated (stack based) `StyleFilter` struct
@@ -6236,6 +6798,25 @@ pub enum AzStyleFilter {
     Composite(AzStyleCompositeFilter),
 }
 
+/// Argument of a `repeat()` track
+#[repr(C)]
+pub struct AzGridTrackRepeat {
+    pub count: u32,
+    pub tracks: AzGridTrackVec,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumns` struct
+#[repr(C)]
+pub struct AzLayoutGridTemplateColumns {
+    pub inner: AzGridTrackVec,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateRows` struct
+#[repr(C)]
+pub struct AzLayoutGridTemplateRows {
+    pub inner: AzGridTrackVec,
+}
+
 /// Re-export of rust-allocated (stack based) `LinearGradient` struct
 #[repr(C)]
 pub struct AzLinearGradient {
@@ -6289,6 +6870,55 @@ pub enum AzStyleTransform {
     Perspective(AzPixelValue),
 }
 
+/// Represents a `transition-property` attribute: which properties are animated when they change - default: `All`
+#[repr(C, u8)]
+pub enum AzStyleTransitionProperty {
+    All,
+    None,
+    List(AzCssPropertyTypeVec),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzLayoutGridTemplateColumnsBoxed {
+    pub run_destructor: bool,
+    pub value: AzLayoutGridTemplateColumns,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateColumnsValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutGridTemplateColumnsValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(*const AzLayoutGridTemplateColumnsBoxed),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzLayoutGridTemplateRowsBoxed {
+    pub run_destructor: bool,
+    pub value: AzLayoutGridTemplateRows,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridTemplateRowsValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutGridTemplateRowsValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(*const AzLayoutGridTemplateRowsBoxed),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleBackgroundPositionVecBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleBackgroundPositionVec,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundPositionVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundPositionVecValue {
@@ -6296,7 +6926,14 @@ pub enum AzStyleBackgroundPositionVecValue {
     None,
     Inherit,
     Initial,
-    Exact(AzStyleBackgroundPositionVec),
+    Exact(*const AzStyleBackgroundPositionVecBoxed),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleBackgroundRepeatVecBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleBackgroundRepeatVec,
 }
 
 /// Re-export of rust-allocated (stack based) `StyleBackgroundRepeatVecValue` struct
@@ -6306,7 +6943,14 @@ pub enum AzStyleBackgroundRepeatVecValue {
     None,
     Inherit,
     Initial,
-    Exact(AzStyleBackgroundRepeatVec),
+    Exact(*const AzStyleBackgroundRepeatVecBoxed),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleBackgroundSizeVecBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleBackgroundSizeVec,
 }
 
 /// Re-export of rust-allocated (stack based) `StyleBackgroundSizeVecValue` struct
@@ -6316,7 +6960,24 @@ pub enum AzStyleBackgroundSizeVecValue {
     None,
     Inherit,
     Initial,
-    Exact(AzStyleBackgroundSizeVec),
+    Exact(*const AzStyleBackgroundSizeVecBoxed),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleTransitionPropertyBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleTransitionPropertyEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionPropertyValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionPropertyValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(*const AzStyleTransitionPropertyBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `CheckBoxStateWrapper` struct
@@ -6561,6 +7222,15 @@ pub struct AzTessellatedColoredSvgNodeVec {
     pub destructor: AzTessellatedColoredSvgNodeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<AcceleratorCallback>`
+#[repr(C)]
+pub struct AzAcceleratorCallbackVec {
+    pub(crate) ptr: *const AzAcceleratorCallback,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzAcceleratorCallbackVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
 #[repr(C)]
 pub struct AzStyleTransformVec {
@@ -6570,6 +7240,15 @@ pub struct AzStyleTransformVec {
     pub destructor: AzStyleTransformVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<MediaFeature>`
+#[repr(C)]
+pub struct AzMediaFeatureVec {
+    pub(crate) ptr: *const AzMediaFeatureEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzMediaFeatureVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `VertexAttribute`
 #[repr(C)]
 pub struct AzSvgPathElementVec {
@@ -6634,13 +7313,6 @@ pub enum AzOptionMouseState {
     Some(AzMouseState),
 }
 
-/// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
-#[repr(C, u8)]
-pub enum AzOptionKeyboardState {
-    None,
-    Some(AzKeyboardState),
-}
-
 /// Re-export of rust-allocated (stack based) `OptionStringVec` struct
 #[repr(C, u8)]
 pub enum AzOptionStringVec {
@@ -6733,6 +7405,13 @@ pub struct AzInvalidStringError {
     pub pos: AzSvgParseErrorPosition,
 }
 
+/// Text that is currently being composed by the IME (usually rendered underlined)
+#[repr(C)]
+pub struct AzImePreedit {
+    pub text: AzString,
+    pub cursor_range: AzOptionImeCursorRangeEnumWrapper,
+}
+
 /// Window configuration specific to Win32
 #[repr(C)]
 pub struct AzWindowsWindowOptions {
@@ -6887,6 +7566,23 @@ pub enum AzCssPathSelector {
     Children,
 }
 
+/// Media features combined with `and`, i.e. `(min-width: 600px) and (orientation: portrait)`
+#[repr(C)]
+pub struct AzMediaCondition {
+    pub features: AzMediaFeatureVec,
+}
+
+/// Single track size of a `grid-template-columns` or `grid-template-rows` attribute
+#[repr(C, u8)]
+pub enum AzGridTrack {
+    Px(AzPixelValue),
+    Fraction(AzFloatValue),
+    MinContent,
+    MaxContent,
+    Auto,
+    Repeat(AzGridTrackRepeat),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundContent {
@@ -6925,6 +7621,13 @@ pub enum AzStyleFontFamily {
     Ref(AzFontRef),
 }
 
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzScrollbarStyleBoxed {
+    pub run_destructor: bool,
+    pub value: AzScrollbarStyle,
+}
+
 /// Re-export of rust-allocated (stack based) `ScrollbarStyleValue` struct
 #[repr(C, u8)]
 pub enum AzScrollbarStyleValue {
@@ -6932,7 +7635,14 @@ pub enum AzScrollbarStyleValue {
     None,
     Inherit,
     Initial,
-    Exact(AzScrollbarStyle),
+    Exact(*const AzScrollbarStyleBoxed),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleTransformVecBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleTransformVec,
 }
 
 /// Re-export of rust-allocated (stack based) `StyleTransformVecValue` struct
@@ -6942,7 +7652,14 @@ pub enum AzStyleTransformVecValue {
     None,
     Inherit,
     Initial,
-    Exact(AzStyleTransformVec),
+    Exact(*const AzStyleTransformVecBoxed),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleFilterVecBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleFilterVec,
 }
 
 /// Re-export of rust-allocated (stack based) `StyleFilterVecValue` struct
@@ -6952,7 +7669,7 @@ pub enum AzStyleFilterVecValue {
     None,
     Inherit,
     Initial,
-    Exact(AzStyleFilterVec),
+    Exact(*const AzStyleFilterVecBoxed),
 }
 
 /// Re-export of rust-allocated (stack based) `FileInputState` struct
@@ -7235,6 +7952,15 @@ pub struct AzStyleBackgroundContentVec {
     pub destructor: AzStyleBackgroundContentVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<MediaCondition>`
+#[repr(C)]
+pub struct AzMediaConditionVec {
+    pub(crate) ptr: *const AzMediaCondition,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzMediaConditionVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<SvgPath>`
 #[repr(C)]
 pub struct AzSvgPathVec {
@@ -7342,6 +8068,15 @@ pub enum AzXmlStreamError {
     InvalidCharacterData,
 }
 
+/// Event of the input method editor (IME), used to compose text that can't be typed directly (CJK characters, emojis, etc.)
+#[repr(C, u8)]
+pub enum AzImeEvent {
+    Enabled,
+    Disabled,
+    Preedit(AzImePreedit),
+    Commit(AzString),
+}
+
 /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
 #[repr(C)]
 pub struct AzLinuxWindowOptions {
@@ -7380,6 +8115,19 @@ pub struct AzCssPath {
     pub selectors: AzCssPathSelectorVec,
 }
 
+/// Condition of an `@media` group, i.e. `(max-width: 600px), (orientation: portrait)`: the group applies if any of the conditions matches
+#[repr(C)]
+pub struct AzMediaQuery {
+    pub conditions: AzMediaConditionVec,
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleBackgroundContentVecBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleBackgroundContentVec,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundContentVecValue {
@@ -7387,7 +8135,14 @@ pub enum AzStyleBackgroundContentVecValue {
     None,
     Inherit,
     Initial,
-    Exact(AzStyleBackgroundContentVec),
+    Exact(*const AzStyleBackgroundContentVecBoxed),
+}
+
+/// Value of a boxed `Exact` property: `run_destructor` is set if the value was allocated by the library (and is freed together with the property), `false` for values in a `static`
+#[repr(C)]
+pub struct AzStyleFontFamilyVecBoxed {
+    pub run_destructor: bool,
+    pub value: AzStyleFontFamilyVec,
 }
 
 /// Re-export of rust-allocated (stack based) `StyleFontFamilyVecValue` struct
@@ -7397,7 +8152,7 @@ pub enum AzStyleFontFamilyVecValue {
     None,
     Inherit,
     Initial,
-    Exact(AzStyleFontFamilyVec),
+    Exact(*const AzStyleFontFamilyVecBoxed),
 }
 
 /// Parsed CSS key-value pair
@@ -7407,6 +8162,8 @@ pub enum AzCssProperty {
     FontSize(AzStyleFontSizeValue),
     FontFamily(AzStyleFontFamilyVecValue),
     TextAlign(AzStyleTextAlignValue),
+    VerticalAlign(AzStyleVerticalAlignValue),
+    Direction(AzStyleDirectionValue),
     LetterSpacing(AzStyleLetterSpacingValue),
     LineHeight(AzStyleLineHeightValue),
     WordSpacing(AzStyleWordSpacingValue),
@@ -7433,6 +8190,10 @@ pub enum AzCssProperty {
     JustifyContent(AzLayoutJustifyContentValue),
     AlignItems(AzLayoutAlignItemsValue),
     AlignContent(AzLayoutAlignContentValue),
+    GridTemplateColumns(AzLayoutGridTemplateColumnsValue),
+    GridTemplateRows(AzLayoutGridTemplateRowsValue),
+    GridColumn(AzLayoutGridColumnValue),
+    GridRow(AzLayoutGridRowValue),
     BackgroundContent(AzStyleBackgroundContentVecValue),
     BackgroundPosition(AzStyleBackgroundPositionVecValue),
     BackgroundSize(AzStyleBackgroundSizeVecValue),
@@ -7474,9 +8235,18 @@ pub enum AzCssProperty {
     PerspectiveOrigin(AzStylePerspectiveOriginValue),
     BackfaceVisibility(AzStyleBackfaceVisibilityValue),
     MixBlendMode(AzStyleMixBlendModeValue),
+    Isolation(AzStyleIsolationValue),
+    ImageRendering(AzStyleImageRenderingValue),
     Filter(AzStyleFilterVecValue),
     BackdropFilter(AzStyleFilterVecValue),
     TextShadow(AzStyleBoxShadowValue),
+    TransitionProperty(AzStyleTransitionPropertyValue),
+    TransitionDuration(AzStyleTransitionDurationValue),
+    TransitionTimingFunction(AzStyleTransitionTimingFunctionValue),
+    TransitionDelay(AzStyleTransitionDelayValue),
+    CaretColor(AzStyleCaretColorValue),
+    SelectionColor(AzStyleSelectionColorValue),
+    SelectionBackground(AzStyleSelectionBackgroundValue),
 }
 
 /// Re-export of rust-allocated (stack based) `FileInputStateWrapper` struct
@@ -7667,6 +8437,13 @@ pub struct AzSvgSimpleNodeVec {
     pub destructor: AzSvgSimpleNodeVecDestructorEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `OptionMediaQuery` struct
+#[repr(C, u8)]
+pub enum AzOptionMediaQuery {
+    None,
+    Some(AzMediaQuery),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionCssProperty` struct
 #[repr(C, u8)]
 pub enum AzOptionCssProperty {
@@ -7674,6 +8451,13 @@ pub enum AzOptionCssProperty {
     Some(AzCssProperty),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionImeEvent` struct
+#[repr(C, u8)]
+pub enum AzOptionImeEvent {
+    None,
+    Some(AzImeEvent),
+}
+
 /// Re-export of rust-allocated (stack based) `XmlTextError` struct
 #[repr(C)]
 pub struct AzXmlTextError {
@@ -7681,6 +8465,16 @@ pub struct AzXmlTextError {
     pub pos: AzSvgParseErrorPosition,
 }
 
+/// Current keyboard state, stores what keys / characters have been pressed
+#[repr(C)]
+pub struct AzKeyboardState {
+    pub current_char: AzOptionCharEnumWrapper,
+    pub current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper,
+    pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
+    pub pressed_scancodes: AzScanCodeVec,
+    pub current_ime_event: AzOptionImeEventEnumWrapper,
+}
+
 /// Platform-specific window configuration, i.e. WM options that are not cross-platform
 #[repr(C)]
 pub struct AzPlatformSpecificOptions {
@@ -7709,6 +8503,7 @@ pub struct AzWindowState {
     pub background_color: AzColorU,
     pub layout_callback: AzLayoutCallbackEnumWrapper,
     pub close_callback: AzOptionCallbackEnumWrapper,
+    pub accelerators: AzAcceleratorCallbackVec,
 }
 
 /// Re-export of rust-allocated (stack based) `CallbackInfo` struct
@@ -7727,6 +8522,7 @@ pub struct AzCallbackInfo {
     pub threads: *mut c_void,
     pub timers_removed: *mut c_void,
     pub threads_removed: *mut c_void,
+    pub thread_msgs_sent: *mut c_void,
     pub current_window_handle: *const AzRawWindowHandleEnumWrapper,
     pub new_windows: *mut c_void,
     pub system_callbacks: *const AzSystemCallbacks,
@@ -7736,6 +8532,8 @@ pub struct AzCallbackInfo {
     pub images_changed_in_callbacks: *mut c_void,
     pub image_masks_changed_in_callbacks: *mut c_void,
     pub css_properties_changed_in_callbacks: *mut c_void,
+    pub stylesheet_changed_in_callbacks: *mut c_void,
+    pub context_menu_changed_in_callbacks: *mut c_void,
     pub current_scroll_states: *const c_void,
     pub nodes_scrolled_in_callback: *mut c_void,
     pub hit_dom_node: AzDomNodeId,
@@ -7802,6 +8600,13 @@ pub struct AzDynamicCssProperty {
     pub default_value: AzCssPropertyEnumWrapper,
 }
 
+/// Value of a CSS variable (`--name`) that is set at runtime
+#[repr(C)]
+pub struct AzCssVariable {
+    pub name: AzString,
+    pub value: AzCssPropertyEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `Node` struct
 #[repr(C)]
 pub struct AzNode {
@@ -7839,6 +8644,15 @@ pub struct AzNodeDataInlineCssPropertyVec {
     pub destructor: AzNodeDataInlineCssPropertyVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<CssVariable>`
+#[repr(C)]
+pub struct AzCssVariableVec {
+    pub(crate) ptr: *const AzCssVariable,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzCssVariableVecDestructorEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
 #[repr(C, u8)]
 pub enum AzOptionWindowState {
@@ -7846,6 +8660,13 @@ pub enum AzOptionWindowState {
     Some(AzWindowState),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+#[repr(C, u8)]
+pub enum AzOptionKeyboardState {
+    None,
+    Some(AzKeyboardState),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionInlineText` struct
 #[repr(C, u8)]
 pub enum AzOptionInlineText {
@@ -8039,6 +8860,7 @@ pub struct AzDom {
 pub struct AzCssRuleBlock {
     pub path: AzCssPath,
     pub declarations: AzCssDeclarationVec,
+    pub media: AzOptionMediaQueryEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `TabContent` struct
@@ -8163,6 +8985,7 @@ pub enum AzResultSvgSvgParseError {
 #[repr(C)]
 pub struct AzCss {
     pub stylesheets: AzStylesheetVec,
+    pub variables: AzCssVariableVec,
 }
 
 /// `AzAppLogLevelEnumWrapper` struct
@@ -8171,6 +8994,12 @@ pub struct AzAppLogLevelEnumWrapper {
     pub inner: AzAppLogLevel,
 }
 
+/// `AzRedrawBehaviorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzRedrawBehaviorEnumWrapper {
+    pub inner: AzRedrawBehavior,
+}
+
 /// `AzLayoutSolverEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzLayoutSolverEnumWrapper {
@@ -8339,6 +9168,18 @@ pub struct AzNodeTypeKeyEnumWrapper {
     pub inner: AzNodeTypeKey,
 }
 
+/// `AzMediaOrientationEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzMediaOrientationEnumWrapper {
+    pub inner: AzMediaOrientation,
+}
+
+/// `AzMediaColorSchemeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzMediaColorSchemeEnumWrapper {
+    pub inner: AzMediaColorScheme,
+}
+
 /// `AzCssPropertyTypeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzCssPropertyTypeEnumWrapper {
@@ -8363,6 +9204,18 @@ pub struct AzStyleMixBlendModeEnumWrapper {
     pub inner: AzStyleMixBlendMode,
 }
 
+/// `AzStyleIsolationEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleIsolationEnumWrapper {
+    pub inner: AzStyleIsolation,
+}
+
+/// `AzStyleImageRenderingEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleImageRenderingEnumWrapper {
+    pub inner: AzStyleImageRendering,
+}
+
 /// `AzLayoutAlignContentEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzLayoutAlignContentEnumWrapper {
@@ -8393,6 +9246,12 @@ pub struct AzLayoutDisplayEnumWrapper {
     pub inner: AzLayoutDisplay,
 }
 
+/// `AzGridLineEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGridLineEnumWrapper {
+    pub inner: AzGridLine,
+}
+
 /// `AzLayoutFloatEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzLayoutFloatEnumWrapper {
@@ -8483,6 +9342,18 @@ pub struct AzStyleTextAlignEnumWrapper {
     pub inner: AzStyleTextAlign,
 }
 
+/// `AzStyleDirectionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleDirectionEnumWrapper {
+    pub inner: AzStyleDirection,
+}
+
+/// `AzStepPositionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStepPositionEnumWrapper {
+    pub inner: AzStepPosition,
+}
+
 /// `AzTextInputValidEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzTextInputValidEnumWrapper {
@@ -8759,6 +9630,12 @@ pub struct AzVideoModeVecDestructorEnumWrapper {
     pub inner: AzVideoModeVecDestructor,
 }
 
+/// `AzAcceleratorCallbackVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzAcceleratorCallbackVecDestructorEnumWrapper {
+    pub inner: AzAcceleratorCallbackVecDestructor,
+}
+
 /// `AzDomVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzDomVecDestructorEnumWrapper {
@@ -8807,6 +9684,36 @@ pub struct AzStyleTransformVecDestructorEnumWrapper {
     pub inner: AzStyleTransformVecDestructor,
 }
 
+/// `AzGridTrackVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGridTrackVecDestructorEnumWrapper {
+    pub inner: AzGridTrackVecDestructor,
+}
+
+/// `AzCssPropertyTypeVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssPropertyTypeVecDestructorEnumWrapper {
+    pub inner: AzCssPropertyTypeVecDestructor,
+}
+
+/// `AzCssVariableVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssVariableVecDestructorEnumWrapper {
+    pub inner: AzCssVariableVecDestructor,
+}
+
+/// `AzMediaFeatureVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzMediaFeatureVecDestructorEnumWrapper {
+    pub inner: AzMediaFeatureVecDestructor,
+}
+
+/// `AzMediaConditionVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzMediaConditionVecDestructorEnumWrapper {
+    pub inner: AzMediaConditionVecDestructor,
+}
+
 /// `AzCssPropertyVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzCssPropertyVecDestructorEnumWrapper {
@@ -9149,6 +10056,18 @@ pub struct AzStyleBackgroundSizeEnumWrapper {
     pub inner: AzStyleBackgroundSize,
 }
 
+/// `AzStyleTransitionTimingFunctionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransitionTimingFunctionEnumWrapper {
+    pub inner: AzStyleTransitionTimingFunction,
+}
+
+/// `AzStyleVerticalAlignEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleVerticalAlignEnumWrapper {
+    pub inner: AzStyleVerticalAlign,
+}
+
 /// `AzStyleBoxShadowValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBoxShadowValueEnumWrapper {
@@ -9161,6 +10080,18 @@ pub struct AzLayoutAlignContentValueEnumWrapper {
     pub inner: AzLayoutAlignContentValue,
 }
 
+/// `AzLayoutGridColumnValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLayoutGridColumnValueEnumWrapper {
+    pub inner: AzLayoutGridColumnValue,
+}
+
+/// `AzLayoutGridRowValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLayoutGridRowValueEnumWrapper {
+    pub inner: AzLayoutGridRowValue,
+}
+
 /// `AzLayoutAlignItemsValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzLayoutAlignItemsValueEnumWrapper {
@@ -9467,6 +10398,54 @@ pub struct AzStyleTextAlignValueEnumWrapper {
     pub inner: AzStyleTextAlignValue,
 }
 
+/// `AzStyleDirectionValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleDirectionValueEnumWrapper {
+    pub inner: AzStyleDirectionValue,
+}
+
+/// `AzStyleTransitionDurationValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransitionDurationValueEnumWrapper {
+    pub inner: AzStyleTransitionDurationValue,
+}
+
+/// `AzStyleTransitionTimingFunctionValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransitionTimingFunctionValueEnumWrapper {
+    pub inner: AzStyleTransitionTimingFunctionValue,
+}
+
+/// `AzStyleTransitionDelayValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransitionDelayValueEnumWrapper {
+    pub inner: AzStyleTransitionDelayValue,
+}
+
+/// `AzStyleCaretColorValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleCaretColorValueEnumWrapper {
+    pub inner: AzStyleCaretColorValue,
+}
+
+/// `AzStyleSelectionColorValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleSelectionColorValueEnumWrapper {
+    pub inner: AzStyleSelectionColorValue,
+}
+
+/// `AzStyleSelectionBackgroundValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleSelectionBackgroundValueEnumWrapper {
+    pub inner: AzStyleSelectionBackgroundValue,
+}
+
+/// `AzStyleVerticalAlignValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleVerticalAlignValueEnumWrapper {
+    pub inner: AzStyleVerticalAlignValue,
+}
+
 /// `AzStyleTextColorValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleTextColorValueEnumWrapper {
@@ -9509,6 +10488,18 @@ pub struct AzStyleMixBlendModeValueEnumWrapper {
     pub inner: AzStyleMixBlendModeValue,
 }
 
+/// `AzStyleIsolationValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleIsolationValueEnumWrapper {
+    pub inner: AzStyleIsolationValue,
+}
+
+/// `AzStyleImageRenderingValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleImageRenderingValueEnumWrapper {
+    pub inner: AzStyleImageRenderingValue,
+}
+
 /// `AzTextInputSelectionEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzTextInputSelectionEnumWrapper {
@@ -9689,6 +10680,12 @@ pub struct AzOptionMenuCallbackEnumWrapper {
     pub inner: AzOptionMenuCallback,
 }
 
+/// `AzOptionNormalizedLinearColorStopEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionNormalizedLinearColorStopEnumWrapper {
+    pub inner: AzOptionNormalizedLinearColorStop,
+}
+
 /// `AzOptionPositionInfoEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionPositionInfoEnumWrapper {
@@ -9821,6 +10818,12 @@ pub struct AzOptionLogicalPositionEnumWrapper {
     pub inner: AzOptionLogicalPosition,
 }
 
+/// `AzOptionLogicalRectEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionLogicalRectEnumWrapper {
+    pub inner: AzOptionLogicalRect,
+}
+
 /// `AzOptionPhysicalPositionI32EnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionPhysicalPositionI32EnumWrapper {
@@ -9839,6 +10842,12 @@ pub struct AzOptionLogicalSizeEnumWrapper {
     pub inner: AzOptionLogicalSize,
 }
 
+/// `AzOptionImeCursorRangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionImeCursorRangeEnumWrapper {
+    pub inner: AzOptionImeCursorRange,
+}
+
 /// `AzOptionVirtualKeyCodeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionVirtualKeyCodeEnumWrapper {
@@ -9911,6 +10920,12 @@ pub struct AzCssPathPseudoSelectorEnumWrapper {
     pub inner: AzCssPathPseudoSelector,
 }
 
+/// `AzMediaFeatureEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzMediaFeatureEnumWrapper {
+    pub inner: AzMediaFeature,
+}
+
 /// `AzAnimationInterpolationFunctionEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzAnimationInterpolationFunctionEnumWrapper {
@@ -9929,6 +10944,24 @@ pub struct AzStyleTransformEnumWrapper {
     pub inner: AzStyleTransform,
 }
 
+/// `AzStyleTransitionPropertyEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransitionPropertyEnumWrapper {
+    pub inner: AzStyleTransitionProperty,
+}
+
+/// `AzLayoutGridTemplateColumnsValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLayoutGridTemplateColumnsValueEnumWrapper {
+    pub inner: AzLayoutGridTemplateColumnsValue,
+}
+
+/// `AzLayoutGridTemplateRowsValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLayoutGridTemplateRowsValueEnumWrapper {
+    pub inner: AzLayoutGridTemplateRowsValue,
+}
+
 /// `AzStyleBackgroundPositionVecValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBackgroundPositionVecValueEnumWrapper {
@@ -9947,6 +10980,12 @@ pub struct AzStyleBackgroundSizeVecValueEnumWrapper {
     pub inner: AzStyleBackgroundSizeVecValue,
 }
 
+/// `AzStyleTransitionPropertyValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransitionPropertyValueEnumWrapper {
+    pub inner: AzStyleTransitionPropertyValue,
+}
+
 /// `AzRawImageDataEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzRawImageDataEnumWrapper {
@@ -9995,12 +11034,6 @@ pub struct AzOptionMouseStateEnumWrapper {
     pub inner: AzOptionMouseState,
 }
 
-/// `AzOptionKeyboardStateEnumWrapper` struct
-#[repr(transparent)]
-pub struct AzOptionKeyboardStateEnumWrapper {
-    pub inner: AzOptionKeyboardState,
-}
-
 /// `AzOptionStringVecEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionStringVecEnumWrapper {
@@ -10073,6 +11106,12 @@ pub struct AzCssPathSelectorEnumWrapper {
     pub inner: AzCssPathSelector,
 }
 
+/// `AzGridTrackEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGridTrackEnumWrapper {
+    pub inner: AzGridTrack,
+}
+
 /// `AzStyleBackgroundContentEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBackgroundContentEnumWrapper {
@@ -10157,6 +11196,12 @@ pub struct AzXmlStreamErrorEnumWrapper {
     pub inner: AzXmlStreamError,
 }
 
+/// `AzImeEventEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzImeEventEnumWrapper {
+    pub inner: AzImeEvent,
+}
+
 /// `AzMenuItemEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzMenuItemEnumWrapper {
@@ -10193,12 +11238,24 @@ pub struct AzSvgSimpleNodeEnumWrapper {
     pub inner: AzSvgSimpleNode,
 }
 
+/// `AzOptionMediaQueryEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionMediaQueryEnumWrapper {
+    pub inner: AzOptionMediaQuery,
+}
+
 /// `AzOptionCssPropertyEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionCssPropertyEnumWrapper {
     pub inner: AzOptionCssProperty,
 }
 
+/// `AzOptionImeEventEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionImeEventEnumWrapper {
+    pub inner: AzOptionImeEvent,
+}
+
 /// `AzNodeDataInlineCssPropertyEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzNodeDataInlineCssPropertyEnumWrapper {
@@ -10217,6 +11274,12 @@ pub struct AzOptionWindowStateEnumWrapper {
     pub inner: AzOptionWindowState,
 }
 
+/// `AzOptionKeyboardStateEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionKeyboardStateEnumWrapper {
+    pub inner: AzOptionKeyboardState,
+}
+
 /// `AzOptionInlineTextEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionInlineTextEnumWrapper {
@@ -10319,6 +11382,29 @@ unsafe impl Send for AzStyleBoxShadow { }
 unsafe impl Send for AzStyleColorMatrix { }
 unsafe impl Send for AzStyleCompositeFilter { }
 unsafe impl Send for AzStyleBackgroundSize { }
+unsafe impl Send for AzStyleBoxShadowValue { }
+unsafe impl Send for AzLayoutBottomValue { }
+unsafe impl Send for AzLayoutHeightValue { }
+unsafe impl Send for AzLayoutLeftValue { }
+unsafe impl Send for AzLayoutMarginBottomValue { }
+unsafe impl Send for AzLayoutMarginLeftValue { }
+unsafe impl Send for AzLayoutMarginRightValue { }
+unsafe impl Send for AzLayoutMarginTopValue { }
+unsafe impl Send for AzLayoutMaxHeightValue { }
+unsafe impl Send for AzLayoutMaxWidthValue { }
+unsafe impl Send for AzLayoutMinHeightValue { }
+unsafe impl Send for AzLayoutMinWidthValue { }
+unsafe impl Send for AzLayoutPaddingBottomValue { }
+unsafe impl Send for AzLayoutPaddingLeftValue { }
+unsafe impl Send for AzLayoutPaddingRightValue { }
+unsafe impl Send for AzLayoutPaddingTopValue { }
+unsafe impl Send for AzLayoutRightValue { }
+unsafe impl Send for AzLayoutTopValue { }
+unsafe impl Send for AzLayoutWidthValue { }
+unsafe impl Send for AzStyleTransitionTimingFunctionValue { }
+unsafe impl Send for AzStyleVerticalAlignValue { }
+unsafe impl Send for AzStyleTransformOriginValue { }
+unsafe impl Send for AzStylePerspectiveOriginValue { }
 unsafe impl Send for AzGl { }
 unsafe impl Send for AzRefstrVecRef { }
 unsafe impl Send for AzFontMetrics { }
@@ -10337,6 +11423,8 @@ unsafe impl Send for AzDomVec { }
 unsafe impl Send for AzStyleBackgroundPositionVec { }
 unsafe impl Send for AzStyleBackgroundRepeatVec { }
 unsafe impl Send for AzStyleBackgroundSizeVec { }
+unsafe impl Send for AzGridTrackVec { }
+unsafe impl Send for AzCssPropertyTypeVec { }
 unsafe impl Send for AzSvgVertexVec { }
 unsafe impl Send for AzSvgColoredVertexVec { }
 unsafe impl Send for AzU32Vec { }
@@ -10356,6 +11444,12 @@ unsafe impl Send for AzNodeHierarchyItemVec { }
 unsafe impl Send for AzParentWithNodeDepthVec { }
 unsafe impl Send for AzRenderImageCallbackInfo { }
 unsafe impl Send for AzLayoutCallbackInfo { }
+unsafe impl Send for AzLayoutGridTemplateColumnsValue { }
+unsafe impl Send for AzLayoutGridTemplateRowsValue { }
+unsafe impl Send for AzStyleBackgroundPositionVecValue { }
+unsafe impl Send for AzStyleBackgroundRepeatVecValue { }
+unsafe impl Send for AzStyleBackgroundSizeVecValue { }
+unsafe impl Send for AzStyleTransitionPropertyValue { }
 unsafe impl Send for AzTexture { }
 unsafe impl Send for AzTessellatedColoredSvgNodeVecRef { }
 unsafe impl Send for AzTessellatedSvgNodeVecRef { }
@@ -10365,12 +11459,17 @@ unsafe impl Send for AzInputConnectionVec { }
 unsafe impl Send for AzOutputConnectionVec { }
 unsafe impl Send for AzTessellatedSvgNodeVec { }
 unsafe impl Send for AzTessellatedColoredSvgNodeVec { }
+unsafe impl Send for AzAcceleratorCallbackVec { }
 unsafe impl Send for AzStyleTransformVec { }
+unsafe impl Send for AzMediaFeatureVec { }
 unsafe impl Send for AzSvgPathElementVec { }
 unsafe impl Send for AzStringVec { }
 unsafe impl Send for AzStyledNodeVec { }
 unsafe impl Send for AzTagIdToNodeIdMappingVec { }
 unsafe impl Send for AzWaylandTheme { }
+unsafe impl Send for AzScrollbarStyleValue { }
+unsafe impl Send for AzStyleTransformVecValue { }
+unsafe impl Send for AzStyleFilterVecValue { }
 unsafe impl Send for AzFile { }
 unsafe impl Send for AzStyleFontFamilyVec { }
 unsafe impl Send for AzFmtArgVec { }
@@ -10378,12 +11477,15 @@ unsafe impl Send for AzInlineWordVec { }
 unsafe impl Send for AzMonitorVec { }
 unsafe impl Send for AzIdOrClassVec { }
 unsafe impl Send for AzStyleBackgroundContentVec { }
+unsafe impl Send for AzMediaConditionVec { }
 unsafe impl Send for AzSvgPathVec { }
 unsafe impl Send for AzVertexAttributeVec { }
 unsafe impl Send for AzCssPathSelectorVec { }
 unsafe impl Send for AzCallbackDataVec { }
 unsafe impl Send for AzDebugMessageVec { }
 unsafe impl Send for AzStringPairVec { }
+unsafe impl Send for AzStyleBackgroundContentVecValue { }
+unsafe impl Send for AzStyleFontFamilyVecValue { }
 unsafe impl Send for AzVertexArrayObject { }
 unsafe impl Send for AzVertexBuffer { }
 unsafe impl Send for AzNodeTypeIdInfoMapVec { }
@@ -10396,6 +11498,7 @@ unsafe impl Send for AzSvgSimpleNodeVec { }
 unsafe impl Send for AzCallbackInfo { }
 unsafe impl Send for AzTimerCallbackInfo { }
 unsafe impl Send for AzNodeDataInlineCssPropertyVec { }
+unsafe impl Send for AzCssVariableVec { }
 unsafe impl Send for AzNodeData { }
 unsafe impl Send for AzNodeIdNodeMapVec { }
 unsafe impl Send for AzCssDeclarationVec { }
@@ -10407,7 +11510,9 @@ unsafe impl Send for AzStylesheetVec { }
 // Python objects must implement Clone at minimum
 impl Clone for AzApp { fn clone(&self) -> Self { let r: &azul_impl::app::AzAppPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAppLogLevelEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::AppLogLevel = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRedrawBehaviorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::RedrawBehavior = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutSolverEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::LayoutSolverVersion = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzApi { fn clone(&self) -> Self { let r: &crate::version::Api = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVsyncEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::Vsync = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSrgbEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::Srgb = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzHwAccelerationEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::HwAcceleration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10428,8 +11533,11 @@ impl Clone for AzLogicalPosition { fn clone(&self) -> Self { let r: &azul_core::
 impl Clone for AzLogicalSize { fn clone(&self) -> Self { let r: &azul_core::window::LogicalSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIconKey { fn clone(&self) -> Self { let r: &azul_core::window::IconKey = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVirtualKeyCodeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzModifiers { fn clone(&self) -> Self { let r: &azul_core::window::Modifiers = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAcceleratorId { fn clone(&self) -> Self { let r: &azul_core::window::AcceleratorId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowFrameEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowFrame = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugState { fn clone(&self) -> Self { let r: &azul_core::window::DebugState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzImeCursorRange { fn clone(&self) -> Self { let r: &azul_core::window::ImeCursorRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMouseCursorTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MouseCursorType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRendererTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::RendererType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMacWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::MacWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10467,17 +11575,22 @@ impl Clone for AzMenuPopupPositionEnumWrapper { fn clone(&self) -> Self { let r:
 impl Clone for AzMenuItemStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeKeyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::NodeTypeTag = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssNthChildPattern { fn clone(&self) -> Self { let r: &azul_impl::css::CssNthChildPattern = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMediaOrientationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::MediaOrientation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMediaColorSchemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::MediaColorScheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorU { fn clone(&self) -> Self { let r: &azul_impl::css::ColorU = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSizeMetricEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::SizeMetric = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFloatValue { fn clone(&self) -> Self { let r: &azul_impl::css::FloatValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzBoxShadowClipModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::BoxShadowClipMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleMixBlendModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleMixBlendMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleIsolationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleIsolation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleImageRenderingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleImageRendering = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignItemsEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignItems = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBoxSizingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBoxSizing = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexDirectionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexDirection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutDisplayEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutDisplay = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridLineEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFloatEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFloat = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutJustifyContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutJustifyContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10493,6 +11606,8 @@ impl Clone for AzBorderStyleEnumWrapper { fn clone(&self) -> Self { let r: &azul
 impl Clone for AzStyleCursorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackfaceVisibilityEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackfaceVisibility = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleDirectionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleDirection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStepPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StepPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRibbon { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::Ribbon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRibbonOnTabClickedCallback { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonOnTabClickedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputOnPathChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputOnPathChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10606,6 +11721,7 @@ impl Clone for AzThreadSendFn { fn clone(&self) -> Self { let r: &azul_impl::tas
 impl Clone for AzThreadDestructorFn { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadDestructorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadReceiverDestructorFn { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadReceiverDestructorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadSenderDestructorFn { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSenderDestructorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextCursor { fn clone(&self) -> Self { let r: &azul_core::text_cursor::TextCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewRowVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRowVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10631,6 +11747,7 @@ impl Clone for AzInlineGlyphVecDestructorEnumWrapper { fn clone(&self) -> Self {
 impl Clone for AzInlineTextHitVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineTextHitVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMonitorVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MonitorVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVideoModeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::VideoModeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAcceleratorCallbackVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::AcceleratorCallbackVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDomVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::DomVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIdOrClassVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::IdOrClassVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssPropertyVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10639,6 +11756,11 @@ impl Clone for AzStyleBackgroundPositionVecDestructorEnumWrapper { fn clone(&sel
 impl Clone for AzStyleBackgroundRepeatVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeatVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundSizeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundSizeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssPropertyTypeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyTypeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssVariableVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssVariableVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMediaFeatureVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::MediaFeatureVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMediaConditionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::MediaConditionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgMultiPolygonVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgMultiPolygonVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgSimpleNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgSimpleNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10689,6 +11811,7 @@ impl Clone for AzLayoutRect { fn clone(&self) -> Self { let r: &azul_impl::css::
 impl Clone for AzRawWindowHandleEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::RawWindowHandle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLogicalRect { fn clone(&self) -> Self { let r: &azul_core::window::LogicalRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAcceleratorKeyEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::AcceleratorKey = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAccelerator { fn clone(&self) -> Self { let r: &azul_core::window::Accelerator = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowFlags { fn clone(&self) -> Self { let r: &azul_core::window::WindowFlags = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCursorPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::CursorPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10708,6 +11831,8 @@ impl Clone for AzMenuCallback { fn clone(&self) -> Self { let r: &azul_core::win
 impl Clone for AzMenuItemIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssNthChildSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssNthChildSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPixelValue { fn clone(&self) -> Self { let r: &azul_impl::css::PixelValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCalcLength { fn clone(&self) -> Self { let r: &azul_impl::css::CalcLength = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCalcLengthBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::CalcLengthBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPixelValueNoPercent { fn clone(&self) -> Self { let r: &azul_impl::css::PixelValueNoPercent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBoxShadow { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBoxShadow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBlur { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBlur = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10715,6 +11840,9 @@ impl Clone for AzStyleColorMatrix { fn clone(&self) -> Self { let r: &azul_impl:
 impl Clone for AzStyleFilterOffset { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterOffset = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleCompositeFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCompositeFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBottom { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBottom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridPlacement { fn clone(&self) -> Self { let r: &azul_impl::css::GridPlacement = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridColumn { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridColumn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridRow { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridRow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexGrow { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexGrow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexShrink { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexShrink = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutHeight { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutHeight = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10766,7 +11894,7 @@ impl Clone for AzStyleLineHeight { fn clone(&self) -> Self { let r: &azul_impl::
 impl Clone for AzStyleTabWidth { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTabWidth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleOpacity { fn clone(&self) -> Self { let r: &azul_impl::css::StyleOpacity = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformOrigin { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformOrigin = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzStylePerspectiveOrigin { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformOrigin = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStylePerspectiveOrigin { fn clone(&self) -> Self { let r: &azul_impl::css::StylePerspectiveOrigin = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformMatrix2D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformMatrix2D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformMatrix3D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformMatrix3D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformTranslate2D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformTranslate2D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10775,10 +11903,22 @@ impl Clone for AzStyleTransformRotate3D { fn clone(&self) -> Self { let r: &azul
 impl Clone for AzStyleTransformScale2D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformScale2D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformScale3D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformScale3D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformSkew2D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformSkew2D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDuration { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDuration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDelay { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDelay = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCubicBezier { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCubicBezier = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleSteps { fn clone(&self) -> Self { let r: &azul_impl::css::StyleSteps = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionTimingFunctionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionTimingFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleVerticalAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleVerticalAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextColor { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextColor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCaretColor { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCaretColor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleSelectionColor { fn clone(&self) -> Self { let r: &azul_impl::css::StyleSelectionColor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleSelectionBackground { fn clone(&self) -> Self { let r: &azul_impl::css::StyleSelectionBackground = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleWordSpacing { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWordSpacing = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleBoxShadowBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBoxShadowBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBoxShadowValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBoxShadowValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignContentValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignContentValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridColumnValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridColumnValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridRowValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridRowValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignItemsValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignItemsValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBottomValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBottomValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBoxSizingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBoxSizingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10830,13 +11970,27 @@ impl Clone for AzStyleLetterSpacingValueEnumWrapper { fn clone(&self) -> Self {
 impl Clone for AzStyleLineHeightValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLineHeightValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTabWidthValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTabWidthValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextAlignValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlignValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleDirectionValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleDirectionValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDurationValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDurationValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionTimingFunctionBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionTimingFunctionBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionTimingFunctionValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionTimingFunctionValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionDelayValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionDelayValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCaretColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCaretColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleSelectionColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleSelectionColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleSelectionBackgroundValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleSelectionBackgroundValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleVerticalAlignBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleVerticalAlignBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleVerticalAlignValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleVerticalAlignValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleWordSpacingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWordSpacingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleOpacityValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleOpacityValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransformOriginBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformOriginBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformOriginValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformOriginValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStylePerspectiveOriginBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StylePerspectiveOriginBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStylePerspectiveOriginValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StylePerspectiveOriginValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackfaceVisibilityValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackfaceVisibilityValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleMixBlendModeValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleMixBlendModeValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleIsolationValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleIsolationValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleImageRenderingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleImageRenderingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzButtonOnClick { fn clone(&self) -> Self { let r: &crate::widgets::button::ButtonOnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputOnPathChange { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputOnPathChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxOnToggle { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxOnToggle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10876,8 +12030,10 @@ impl Clone for AzSvgStringFormatOptions { fn clone(&self) -> Self { let r: &azul
 impl Clone for AzSvgFillStyle { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgFillStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInstantPtr { fn clone(&self) -> Self { let r: &azul_impl::task::AzInstantPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDurationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::Duration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzPendingThread { fn clone(&self) -> Self { let r: &crate::thread::PendingThread = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadSendMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSendMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadWriteBackMsg { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadWriteBackMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextSelection { fn clone(&self) -> Self { let r: &azul_core::text_cursor::TextSelection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLogicalRectVec { fn clone(&self) -> Self { let r: &azul_core::window::LogicalRectVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputOutputTypeIdVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputOutputTypeIdVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOutputNodeAndIndexVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputNodeAndIndexVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10892,6 +12048,8 @@ impl Clone for AzDomVec { fn clone(&self) -> Self { let r: &azul_impl::dom::DomV
 impl Clone for AzStyleBackgroundPositionVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundPositionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundRepeatVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeatVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundSizeVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundSizeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackVec { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssPropertyTypeVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyTypeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgVertexVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgVertexVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgColoredVertexVec { fn clone(&self) -> Self { let r: &azul_core::svg::SvgColoredVertexVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU32Vec { fn clone(&self) -> Self { let r: &azul_impl::css::U32Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10936,6 +12094,7 @@ impl Clone for AzOptionNumberInputOnFocusLostEnumWrapper { fn clone(&self) -> Se
 impl Clone for AzOptionNumberInputOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::OptionNumberInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMenuItemIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenuItemIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMenuCallbackEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenuCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionNormalizedLinearColorStopEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionNormalizedLinearColorStop = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPositionInfoEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::OptionPositionInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTimerIdEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionTimerId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionThreadIdEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionThreadId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10958,9 +12117,11 @@ impl Clone for AzOptionDomNodeIdEnumWrapper { fn clone(&self) -> Self { let r: &
 impl Clone for AzOptionColorUEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionColorU = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionSvgDashPatternEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::OptionSvgDashPattern = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLogicalPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionLogicalPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionLogicalRectEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionLogicalRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPhysicalPositionI32EnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionPhysicalPositionI32 = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseCursorTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseCursorType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLogicalSizeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionLogicalSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionImeCursorRangeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionImeCursorRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionVirtualKeyCodeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionVirtualKeyCode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionImageMaskEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionImageMask = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTabIndexEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::OptionTabIndex = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10979,8 +12140,8 @@ impl Clone for AzSmallWindowIconBytes { fn clone(&self) -> Self { let r: &azul_c
 impl Clone for AzLargeWindowIconBytes { fn clone(&self) -> Self { let r: &azul_core::window::LargeWindowIconBytes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTaskBarIcon { fn clone(&self) -> Self { let r: &azul_core::window::TaskBarIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAcceleratorCallback { fn clone(&self) -> Self { let r: &azul_core::window::AcceleratorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowSize { fn clone(&self) -> Self { let r: &azul_core::window::WindowSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzKeyboardState { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMouseState { fn clone(&self) -> Self { let r: &azul_core::window::MouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMarshaledLayoutCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MarshaledLayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextContents { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextContents = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10992,16 +12153,30 @@ impl Clone for AzEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul
 impl Clone for AzMenu { fn clone(&self) -> Self { let r: &azul_core::window::Menu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVirtualKeyCodeCombo { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathPseudoSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathPseudoSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMediaFeatureEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::MediaFeature = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationInterpolationFunctionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::AnimationInterpolationFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInterpolateContext { fn clone(&self) -> Self { let r: &azul_impl::css::InterpolateResolver = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackRepeat { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackRepeat = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridTemplateColumns { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridTemplateColumns = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridTemplateRows { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridTemplateRows = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLinearGradient { fn clone(&self) -> Self { let r: &azul_impl::css::LinearGradient = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRadialGradient { fn clone(&self) -> Self { let r: &azul_impl::css::RadialGradient = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzConicGradient { fn clone(&self) -> Self { let r: &azul_impl::css::ConicGradient = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransform = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridTemplateColumnsBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridTemplateColumnsBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridTemplateColumnsValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridTemplateColumnsValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridTemplateRowsBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridTemplateRowsBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridTemplateRowsValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridTemplateRowsValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleBackgroundPositionVecBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundPositionVecBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundPositionVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundPositionVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleBackgroundRepeatVecBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeatVecBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundRepeatVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeatVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleBackgroundSizeVecBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundSizeVecBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundSizeVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundSizeVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionPropertyBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionPropertyValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphCallbacks { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphCallbacks = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11031,7 +12206,9 @@ impl Clone for AzInputConnectionVec { fn clone(&self) -> Self { let r: &crate::w
 impl Clone for AzOutputConnectionVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedColoredSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedColoredSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAcceleratorCallbackVec { fn clone(&self) -> Self { let r: &azul_core::window::AcceleratorCallbackVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMediaFeatureVec { fn clone(&self) -> Self { let r: &azul_impl::css::MediaFeatureVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgPathElementVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgPathElementVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringVec { fn clone(&self) -> Self { let r: &azul_impl::css::StringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNodeVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11040,7 +12217,6 @@ impl Clone for AzOptionMenuEnumWrapper { fn clone(&self) -> Self { let r: &azul_
 impl Clone for AzOptionResolvedTextLayoutOptionsEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::OptionResolvedTextLayoutOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionVirtualKeyCodeComboEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionVirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzOptionKeyboardStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionKeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionStringVecEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionStringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionThreadReceiveMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionThreadReceiveMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTaskBarIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionTaskBarIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11054,6 +12230,7 @@ impl Clone for AzUnexpectedCloseTagError { fn clone(&self) -> Self { let r: &azu
 impl Clone for AzUnknownEntityReferenceError { fn clone(&self) -> Self { let r: &azul_impl::xml::UnknownEntityReferenceError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDuplicatedAttributeError { fn clone(&self) -> Self { let r: &azul_impl::xml::DuplicatedAttributeError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidStringError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidStringError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzImePreedit { fn clone(&self) -> Self { let r: &azul_core::window::ImePreedit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowsWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::WindowsWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWaylandTheme { fn clone(&self) -> Self { let r: &azul_core::window::WaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringPair { fn clone(&self) -> Self { let r: &azul_core::window::AzStringPair = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11066,12 +12243,17 @@ impl Clone for AzAccessibilityInfo { fn clone(&self) -> Self { let r: &azul_impl
 impl Clone for AzIdOrClassEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::IdOrClass = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringMenuItem { fn clone(&self) -> Self { let r: &azul_core::window::StringMenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMediaCondition { fn clone(&self) -> Self { let r: &azul_impl::css::MediaCondition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrack = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarInfo { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyle { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamily = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzScrollbarStyleBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyleBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyleValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyleValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransformVecBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVecBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleFilterVecBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVecBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputState { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11103,6 +12285,7 @@ impl Clone for AzInlineWordVec { fn clone(&self) -> Self { let r: &azul_impl::ca
 impl Clone for AzMonitorVec { fn clone(&self) -> Self { let r: &azul_core::window::MonitorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIdOrClassVec { fn clone(&self) -> Self { let r: &azul_impl::dom::IdOrClassVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMediaConditionVec { fn clone(&self) -> Self { let r: &azul_impl::css::MediaConditionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgPathVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgPathVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexAttributeVec { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexAttributeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelectorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11115,11 +12298,15 @@ impl Clone for AzOptionRawImageEnumWrapper { fn clone(&self) -> Self { let r: &a
 impl Clone for AzOptionWaylandThemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultRawImageDecodeImageErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::decode::ResultRawImageDecodeImageError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlStreamErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlStreamError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzImeEventEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ImeEvent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLinuxWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::LinuxWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineLine { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPath { fn clone(&self) -> Self { let r: &azul_impl::css::CssPath = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzMediaQuery { fn clone(&self) -> Self { let r: &azul_impl::css::MediaQuery = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleBackgroundContentVecBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVecBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleFontFamilyVecBoxed { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecBoxed = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11144,8 +12331,11 @@ impl Clone for AzInlineLineVec { fn clone(&self) -> Self { let r: &azul_impl::ca
 impl Clone for AzCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgMultiPolygonVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgMultiPolygonVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgSimpleNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgSimpleNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionMediaQueryEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionMediaQuery = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionImeEventEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionImeEvent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlTextError { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlTextError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeyboardState { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPlatformSpecificOptions { fn clone(&self) -> Self { let r: &azul_core::window::PlatformSpecificOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowState { fn clone(&self) -> Self { let r: &azul_core::window::WindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::CallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11155,11 +12345,14 @@ impl Clone for AzAnimation { fn clone(&self) -> Self { let r: &azul_impl::callba
 impl Clone for AzTimerCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDynamicCssProperty { fn clone(&self) -> Self { let r: &azul_impl::css::DynamicCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssVariable { fn clone(&self) -> Self { let r: &azul_impl::css::CssVariable = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNode { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::Node = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgNodeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgStyledNode { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgStyledNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssVariableVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssVariableVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWindowStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionKeyboardStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionKeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionInlineTextEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionInlineText = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowCreateOptions { fn clone(&self) -> Self { let r: &azul_core::window::WindowCreateOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11226,6 +12419,8 @@ impl Drop for AzDomVec { fn drop(&mut self) { crate::AzDomVec_delete(unsafe { me
 impl Drop for AzStyleBackgroundPositionVec { fn drop(&mut self) { crate::AzStyleBackgroundPositionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleBackgroundRepeatVec { fn drop(&mut self) { crate::AzStyleBackgroundRepeatVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleBackgroundSizeVec { fn drop(&mut self) { crate::AzStyleBackgroundSizeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzGridTrackVec { fn drop(&mut self) { crate::AzGridTrackVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssPropertyTypeVec { fn drop(&mut self) { crate::AzCssPropertyTypeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgVertexVec { fn drop(&mut self) { crate::AzSvgVertexVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgColoredVertexVec { fn drop(&mut self) { crate::AzSvgColoredVertexVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzU32Vec { fn drop(&mut self) { crate::AzU32Vec_delete(unsafe { mem::transmute(self) }); } }
@@ -11250,7 +12445,9 @@ impl Drop for AzInputConnectionVec { fn drop(&mut self) { crate::AzInputConnecti
 impl Drop for AzOutputConnectionVec { fn drop(&mut self) { crate::AzOutputConnectionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTessellatedSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTessellatedColoredSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedColoredSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzAcceleratorCallbackVec { fn drop(&mut self) { crate::AzAcceleratorCallbackVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleTransformVec { fn drop(&mut self) { crate::AzStyleTransformVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzMediaFeatureVec { fn drop(&mut self) { crate::AzMediaFeatureVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgPathElementVec { fn drop(&mut self) { crate::AzSvgPathElementVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStringVec { fn drop(&mut self) { crate::AzStringVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyledNodeVec { fn drop(&mut self) { crate::AzStyledNodeVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11262,6 +12459,7 @@ impl Drop for AzInlineWordVec { fn drop(&mut self) { crate::AzInlineWordVec_dele
 impl Drop for AzMonitorVec { fn drop(&mut self) { crate::AzMonitorVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzIdOrClassVec { fn drop(&mut self) { crate::AzIdOrClassVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleBackgroundContentVec { fn drop(&mut self) { crate::AzStyleBackgroundContentVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzMediaConditionVec { fn drop(&mut self) { crate::AzMediaConditionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgPathVec { fn drop(&mut self) { crate::AzSvgPathVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzVertexAttributeVec { fn drop(&mut self) { crate::AzVertexAttributeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssPathSelectorVec { fn drop(&mut self) { crate::AzCssPathSelectorVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11278,6 +12476,7 @@ impl Drop for AzCssPropertyVec { fn drop(&mut self) { crate::AzCssPropertyVec_de
 impl Drop for AzSvgMultiPolygonVec { fn drop(&mut self) { crate::AzSvgMultiPolygonVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgSimpleNodeVec { fn drop(&mut self) { crate::AzSvgSimpleNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataInlineCssPropertyVec { fn drop(&mut self) { crate::AzNodeDataInlineCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssVariableVec { fn drop(&mut self) { crate::AzCssVariableVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeIdNodeMapVec { fn drop(&mut self) { crate::AzNodeIdNodeMapVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssDeclarationVec { fn drop(&mut self) { crate::AzCssDeclarationVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataVec { fn drop(&mut self) { crate::AzNodeDataVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11301,17 +12500,46 @@ impl AzApp {
             mem::transmute(image),
         )) }
     }
+    fn delete_image(&mut self, id: String) -> () {
+        let id = pystring_to_azstring(&id);
+        unsafe { mem::transmute(crate::AzApp_deleteImage(
+            mem::transmute(self),
+            mem::transmute(id),
+        )) }
+    }
     fn get_monitors(&self) -> AzMonitorVec {
         unsafe { mem::transmute(crate::AzApp_getMonitors(
             mem::transmute(self),
         )) }
     }
+    fn get_system_theme(&self) -> Option<AzWindowThemeEnumWrapper> {
+        let m: AzOptionWindowTheme = unsafe { mem::transmute(crate::AzApp_getSystemTheme(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionWindowTheme::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionWindowTheme::None => None,
+        }
+
+    }
     fn run(&self, window: AzWindowCreateOptions) -> () {
         unsafe { mem::transmute(crate::AzApp_run(
             mem::transmute(self),
             mem::transmute(window),
         )) }
     }
+    fn run_return_code(&self, window: AzWindowCreateOptions) -> i32 {
+        unsafe { mem::transmute(crate::AzApp_runReturnCode(
+            mem::transmute(self),
+            mem::transmute(window),
+        )) }
+    }
+    fn create_test_harness(&self, window: AzWindowCreateOptions) -> AzTestHarness {
+        unsafe { mem::transmute(crate::AzApp_createTestHarness(
+            mem::transmute(self),
+            mem::transmute(window),
+        )) }
+    }
     // impl App {
 
     #[new]
@@ -11344,6 +12572,12 @@ impl AzAppConfig {
             mem::transmute(layout_solver),
         )) }
     }
+    fn set_redraw_behavior(&mut self, behavior: AzRedrawBehaviorEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzAppConfig_setRedrawBehavior(
+            mem::transmute(self),
+            mem::transmute(behavior),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -11392,6 +12626,38 @@ impl PyObjectProtocol for AzAppLogLevelEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzRedrawBehaviorEnumWrapper {
+    #[classattr]
+    fn OnEventOnly() -> AzRedrawBehaviorEnumWrapper { AzRedrawBehaviorEnumWrapper { inner: AzRedrawBehavior::OnEventOnly } }
+    #[staticmethod]
+    fn MaxFps(v: u16) -> AzRedrawBehaviorEnumWrapper { AzRedrawBehaviorEnumWrapper { inner: AzRedrawBehavior::MaxFps(v) } }
+    #[classattr]
+    fn Unlimited() -> AzRedrawBehaviorEnumWrapper { AzRedrawBehaviorEnumWrapper { inner: AzRedrawBehavior::Unlimited } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzRedrawBehavior;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzRedrawBehavior::OnEventOnly => Ok(vec!["OnEventOnly".into_py(py), ().into_py(py)]),
+            AzRedrawBehavior::MaxFps(v) => Ok(vec!["MaxFps".into_py(py), v.into_py(py)]),
+            AzRedrawBehavior::Unlimited => Ok(vec!["Unlimited".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRedrawBehaviorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::RedrawBehavior = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::resources::RedrawBehavior = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutSolverEnumWrapper {
     #[classattr]
@@ -11436,6 +12702,49 @@ impl PyObjectProtocol for AzSystemCallbacks {
     }
 }
 
+#[pymethods]
+impl AzApi {
+    #[staticmethod]
+    fn version_major() -> u32 {
+        unsafe { mem::transmute(crate::AzApi_versionMajor()) }
+    }
+    #[staticmethod]
+    fn version_minor() -> u32 {
+        unsafe { mem::transmute(crate::AzApi_versionMinor()) }
+    }
+    #[staticmethod]
+    fn version_patch() -> u32 {
+        unsafe { mem::transmute(crate::AzApi_versionPatch()) }
+    }
+    #[staticmethod]
+    fn abi_hash() -> u64 {
+        unsafe { mem::transmute(crate::AzApi_abiHash()) }
+    }
+    #[staticmethod]
+    fn last_error() -> Option<String> {
+        let m: AzOptionString = unsafe { mem::transmute(crate::AzApi_lastError()) };
+        match m {
+            AzOptionString::Some(s) => Some({ let s: AzString = unsafe { mem::transmute(s) }; s.into() }),
+            AzOptionString::None => None,
+        }
+
+    }
+    #[staticmethod]
+    fn clear_last_error() -> bool {
+        unsafe { mem::transmute(crate::AzApi_clearLastError()) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzApi {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::version::Api = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::version::Api = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzWindowCreateOptions {
     // impl WindowCreateOptions {
@@ -12479,6 +13788,96 @@ impl PyObjectProtocol for AzAcceleratorKeyEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzModifiers {
+    #[new]
+    fn __new__(shift: bool, ctrl: bool, alt: bool, super_key: bool) -> Self {
+        Self {
+            shift,
+            ctrl,
+            alt,
+            super_key,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzModifiers {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Modifiers = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Modifiers = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzAccelerator {
+    #[new]
+    fn new(modifiers: AzModifiers, key: AzVirtualKeyCodeEnumWrapper) -> AzAccelerator {
+        unsafe { mem::transmute(crate::AzAccelerator_new(
+            mem::transmute(modifiers),
+            mem::transmute(key),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAccelerator {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Accelerator = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::Accelerator = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzAcceleratorId {
+    #[new]
+    fn __new__(id: usize) -> Self {
+        Self {
+            id,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAcceleratorId {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::AcceleratorId = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::AcceleratorId = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzAcceleratorCallback {
+    #[new]
+    fn __new__(id: AzAcceleratorId, accelerator: AzAccelerator, data: AzRefAny, callback: AzCallback) -> Self {
+        Self {
+            id,
+            accelerator,
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAcceleratorCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::AcceleratorCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::AcceleratorCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzWindowSize {
     #[new]
@@ -12621,12 +14020,13 @@ impl PyObjectProtocol for AzDebugState {
 #[pymethods]
 impl AzKeyboardState {
     #[new]
-    fn __new__(current_char: AzOptionCharEnumWrapper, current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper, pressed_virtual_keycodes: AzVirtualKeyCodeVec, pressed_scancodes: AzScanCodeVec) -> Self {
+    fn __new__(current_char: AzOptionCharEnumWrapper, current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper, pressed_virtual_keycodes: AzVirtualKeyCodeVec, pressed_scancodes: AzScanCodeVec, current_ime_event: AzOptionImeEventEnumWrapper) -> Self {
         Self {
             current_char,
             current_virtual_keycode,
             pressed_virtual_keycodes,
             pressed_scancodes,
+            current_ime_event,
         }
     }
 
@@ -12668,6 +14068,85 @@ impl PyObjectProtocol for AzKeyboardState {
     }
 }
 
+#[pymethods]
+impl AzImeEventEnumWrapper {
+    #[classattr]
+    fn Enabled() -> AzImeEventEnumWrapper { AzImeEventEnumWrapper { inner: AzImeEvent::Enabled } }
+    #[classattr]
+    fn Disabled() -> AzImeEventEnumWrapper { AzImeEventEnumWrapper { inner: AzImeEvent::Disabled } }
+    #[staticmethod]
+    fn Preedit(v: AzImePreedit) -> AzImeEventEnumWrapper { AzImeEventEnumWrapper { inner: AzImeEvent::Preedit(v) } }
+    #[staticmethod]
+    fn Commit(v: AzString) -> AzImeEventEnumWrapper { AzImeEventEnumWrapper { inner: AzImeEvent::Commit(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzImeEvent;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzImeEvent::Enabled => Ok(vec!["Enabled".into_py(py), ().into_py(py)]),
+            AzImeEvent::Disabled => Ok(vec!["Disabled".into_py(py), ().into_py(py)]),
+            AzImeEvent::Preedit(v) => Ok(vec!["Preedit".into_py(py), v.clone().into_py(py)]),
+            AzImeEvent::Commit(v) => Ok(vec!["Commit".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzImeEventEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ImeEvent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ImeEvent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzImePreedit {
+    #[new]
+    fn __new__(text: AzString, cursor_range: AzOptionImeCursorRangeEnumWrapper) -> Self {
+        Self {
+            text,
+            cursor_range,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzImePreedit {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ImePreedit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ImePreedit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzImeCursorRange {
+    #[new]
+    fn __new__(start: usize, end: usize) -> Self {
+        Self {
+            start,
+            end,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzImeCursorRange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ImeCursorRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ImeCursorRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMouseCursorTypeEnumWrapper {
     #[classattr]
@@ -13388,6 +14867,46 @@ impl AzCallbackInfo {
             mem::transmute(self),
         )) }
     }
+    fn get_ime_event(&self) -> Option<AzImeEventEnumWrapper> {
+        let m: AzOptionImeEvent = unsafe { mem::transmute(crate::AzCallbackInfo_getImeEvent(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionImeEvent::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionImeEvent::None => None,
+        }
+
+    }
+    fn get_hovered_files(&self) -> AzStringVec {
+        unsafe { mem::transmute(crate::AzCallbackInfo_getHoveredFiles(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_dropped_files(&self) -> AzStringVec {
+        unsafe { mem::transmute(crate::AzCallbackInfo_getDroppedFiles(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_drop_position(&self) -> Option<AzLogicalPosition> {
+        let m: AzOptionLogicalPosition = unsafe { mem::transmute(crate::AzCallbackInfo_getDropPosition(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionLogicalPosition::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionLogicalPosition::None => None,
+        }
+
+    }
+    fn get_drop_target(&self) -> Option<AzDomNodeId> {
+        let m: AzOptionDomNodeId = unsafe { mem::transmute(crate::AzCallbackInfo_getDropTarget(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionDomNodeId::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionDomNodeId::None => None,
+        }
+
+    }
     fn get_previous_window_state(&self) -> Option<AzWindowState> {
         let m: AzOptionWindowState = unsafe { mem::transmute(crate::AzCallbackInfo_getPreviousWindowState(
             mem::transmute(self),
@@ -13443,6 +14962,17 @@ impl AzCallbackInfo {
             AzOptionLogicalPosition::None => None,
         }
 
+    }
+    fn get_scroll_extent(&self, node_id: AzDomNodeId) -> Option<AzLogicalSize> {
+        let m: AzOptionLogicalSize = unsafe { mem::transmute(crate::AzCallbackInfo_getScrollExtent(
+            mem::transmute(self),
+            mem::transmute(node_id),
+        )) };
+        match m {
+            AzOptionLogicalSize::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionLogicalSize::None => None,
+        }
+
     }
     fn get_dataset(&mut self, node_id: AzDomNodeId) -> Option<AzRefAny> {
         let m: AzOptionRefAny = unsafe { mem::transmute(crate::AzCallbackInfo_getDataset(
@@ -13607,12 +15137,24 @@ impl AzCallbackInfo {
         }
 
     }
+    fn get_computed_css_properties(&self, node_id: AzDomNodeId) -> AzCssPropertyVec {
+        unsafe { mem::transmute(crate::AzCallbackInfo_getComputedCssProperties(
+            mem::transmute(self),
+            mem::transmute(node_id),
+        )) }
+    }
     fn set_window_state(&mut self, new_state: AzWindowState) -> () {
         unsafe { mem::transmute(crate::AzCallbackInfo_setWindowState(
             mem::transmute(self),
             mem::transmute(new_state),
         )) }
     }
+    fn set_ime_position(&mut self, position: AzLogicalPosition) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_setImePosition(
+            mem::transmute(self),
+            mem::transmute(position),
+        )) }
+    }
     fn set_focus(&mut self, target: AzFocusTargetEnumWrapper) -> () {
         unsafe { mem::transmute(crate::AzCallbackInfo_setFocus(
             mem::transmute(self),
@@ -13626,7 +15168,13 @@ impl AzCallbackInfo {
             mem::transmute(new_property),
         )) }
     }
-    fn set_scroll_position(&mut self, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) -> () {
+    fn set_css(&mut self, css: AzCss) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_setCss(
+            mem::transmute(self),
+            mem::transmute(css),
+        )) }
+    }
+    fn set_scroll_position(&mut self, node_id: AzDomNodeId, scroll_position: AzLogicalPosition) -> bool {
         unsafe { mem::transmute(crate::AzCallbackInfo_setScrollPosition(
             mem::transmute(self),
             mem::transmute(node_id),
@@ -13683,6 +15231,24 @@ impl AzCallbackInfo {
             mem::transmute(id),
         )) }
     }
+    fn remove_accelerator(&mut self, id: AzAcceleratorId) -> bool {
+        unsafe { mem::transmute(crate::AzCallbackInfo_removeAccelerator(
+            mem::transmute(self),
+            mem::transmute(id),
+        )) }
+    }
+    fn open_context_menu(&mut self, menu: AzMenu, position: AzLogicalPosition) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_openContextMenu(
+            mem::transmute(self),
+            mem::transmute(menu),
+            mem::transmute(position),
+        )) }
+    }
+    fn close_context_menu(&mut self) -> () {
+        unsafe { mem::transmute(crate::AzCallbackInfo_closeContextMenu(
+            mem::transmute(self),
+        )) }
+    }
     fn update_image_mask(&mut self, node_id: AzDomNodeId, new_mask: AzImageMask) -> () {
         unsafe { mem::transmute(crate::AzCallbackInfo_updateImageMask(
             mem::transmute(self),
@@ -13725,6 +15291,12 @@ impl AzCallbackInfo {
             mem::transmute(timer_id),
         )) }
     }
+    fn request_thread_stop(&mut self, thread_id: AzThreadId) -> bool {
+        unsafe { mem::transmute(crate::AzCallbackInfo_requestThreadStop(
+            mem::transmute(self),
+            mem::transmute(thread_id),
+        )) }
+    }
     fn send_thread_msg(&mut self, thread_id: AzThreadId, msg: AzThreadSendMsgEnumWrapper) -> bool {
         unsafe { mem::transmute(crate::AzCallbackInfo_sendThreadMsg(
             mem::transmute(self),
@@ -14655,6 +16227,11 @@ impl AzRefAny {
             mem::transmute(self),
         )) })
     }
+    fn strong_count(&self) -> usize {
+        unsafe { mem::transmute(crate::AzRefAny_strongCount(
+            mem::transmute(self),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -14981,6 +16558,32 @@ impl AzDom {
             mem::transmute(accessibility_info),
         )) }
     }
+    fn set_accessibility_role(&mut self, role: AzAccessibilityRoleEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzDom_setAccessibilityRole(
+            mem::transmute(self),
+            mem::transmute(role),
+        )) }
+    }
+    fn with_accessibility_role(&mut self, role: AzAccessibilityRoleEnumWrapper) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withAccessibilityRole(
+            mem::transmute(self),
+            mem::transmute(role),
+        )) }
+    }
+    fn set_accessibility_label(&mut self, label: String) -> () {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzDom_setAccessibilityLabel(
+            mem::transmute(self),
+            mem::transmute(label),
+        )) }
+    }
+    fn with_accessibility_label(&mut self, label: String) -> AzDom {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzDom_withAccessibilityLabel(
+            mem::transmute(self),
+            mem::transmute(label),
+        )) }
+    }
     fn set_menu_bar(&mut self, menu_bar: AzMenu) -> () {
         unsafe { mem::transmute(crate::AzDom_setMenuBar(
             mem::transmute(self),
@@ -15015,6 +16618,35 @@ impl AzDom {
             mem::transmute(self),
         )) }
     }
+    fn get_node_type(&self) -> AzNodeTypeEnumWrapper {
+        unsafe { mem::transmute(crate::AzDom_getNodeType(
+            mem::transmute(self),
+        )) }
+    }
+    fn children_count(&self) -> usize {
+        unsafe { mem::transmute(crate::AzDom_childrenCount(
+            mem::transmute(self),
+        )) }
+    }
+    fn find_first_by_id(&self, id: String) -> Option<AzNodeId> {
+        let id = pystring_to_azstring(&id);
+        let m: AzOptionNodeId = unsafe { mem::transmute(crate::AzDom_findFirstById(
+            mem::transmute(self),
+            mem::transmute(id),
+        )) };
+        match m {
+            AzOptionNodeId::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionNodeId::None => None,
+        }
+
+    }
+    fn find_all_by_class(&self, class_name: String) -> AzNodeIdVec {
+        let class_name = pystring_to_azstring(&class_name);
+        unsafe { mem::transmute(crate::AzDom_findAllByClass(
+            mem::transmute(self),
+            mem::transmute(class_name),
+        )) }
+    }
     fn get_html_string(&mut self) -> String {
         az_string_to_py_string(unsafe { mem::transmute(crate::AzDom_getHtmlString(
             mem::transmute(self),
@@ -15325,6 +16957,19 @@ impl AzNodeData {
             mem::transmute(accessibility_info),
         )) }
     }
+    fn set_accessibility_role(&mut self, role: AzAccessibilityRoleEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setAccessibilityRole(
+            mem::transmute(self),
+            mem::transmute(role),
+        )) }
+    }
+    fn set_accessibility_label(&mut self, label: String) -> () {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzNodeData_setAccessibilityLabel(
+            mem::transmute(self),
+            mem::transmute(label),
+        )) }
+    }
     fn set_menu_bar(&mut self, menu_bar: AzMenu) -> () {
         unsafe { mem::transmute(crate::AzNodeData_setMenuBar(
             mem::transmute(self),
@@ -15631,6 +17276,12 @@ impl AzHoverEventFilterEnumWrapper {
     fn TouchEnd() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TouchEnd } }
     #[classattr]
     fn TouchCancel() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::TouchCancel } }
+    #[classattr]
+    fn ImeStateChanged() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::ImeStateChanged } }
+    #[classattr]
+    fn ImePreedit() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::ImePreedit } }
+    #[classattr]
+    fn ImeCommit() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::ImeCommit } }
 }
 
 #[pyproto]
@@ -15693,6 +17344,12 @@ impl AzFocusEventFilterEnumWrapper {
     fn FocusReceived() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::FocusReceived } }
     #[classattr]
     fn FocusLost() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::FocusLost } }
+    #[classattr]
+    fn ImeStateChanged() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::ImeStateChanged } }
+    #[classattr]
+    fn ImePreedit() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::ImePreedit } }
+    #[classattr]
+    fn ImeCommit() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::ImeCommit } }
 }
 
 #[pyproto]
@@ -15806,6 +17463,16 @@ impl AzWindowEventFilterEnumWrapper {
     fn CloseRequested() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::CloseRequested } }
     #[classattr]
     fn ThemeChanged() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::ThemeChanged } }
+    #[classattr]
+    fn WindowFocusReceived() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::WindowFocusReceived } }
+    #[classattr]
+    fn WindowFocusLost() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::WindowFocusLost } }
+    #[classattr]
+    fn ImeStateChanged() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::ImeStateChanged } }
+    #[classattr]
+    fn ImePreedit() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::ImePreedit } }
+    #[classattr]
+    fn ImeCommit() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::ImeCommit } }
 }
 
 #[pyproto]
@@ -16367,6 +18034,30 @@ impl AzStringMenuItem {
             mem::transmute(label),
         )) }
     }
+    fn set_accelerator(&mut self, accelerator: AzVirtualKeyCodeCombo) -> () {
+        unsafe { mem::transmute(crate::AzStringMenuItem_setAccelerator(
+            mem::transmute(self),
+            mem::transmute(accelerator),
+        )) }
+    }
+    fn with_accelerator(&mut self, accelerator: AzVirtualKeyCodeCombo) -> AzStringMenuItem {
+        unsafe { mem::transmute(crate::AzStringMenuItem_withAccelerator(
+            mem::transmute(self),
+            mem::transmute(accelerator),
+        )) }
+    }
+    fn set_state(&mut self, state: AzMenuItemStateEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzStringMenuItem_setState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn with_state(&mut self, state: AzMenuItemStateEnumWrapper) -> AzStringMenuItem {
+        unsafe { mem::transmute(crate::AzStringMenuItem_withState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
     fn add_child(&mut self, child: AzMenuItemEnumWrapper) -> () {
         unsafe { mem::transmute(crate::AzStringMenuItem_addChild(
             mem::transmute(self),
@@ -16500,10 +18191,11 @@ impl PyObjectProtocol for AzMenuItemStateEnumWrapper {
 #[pymethods]
 impl AzCssRuleBlock {
     #[new]
-    fn __new__(path: AzCssPath, declarations: AzCssDeclarationVec) -> Self {
+    fn __new__(path: AzCssPath, declarations: AzCssDeclarationVec, media: AzOptionMediaQueryEnumWrapper) -> Self {
         Self {
             path,
             declarations,
+            media,
         }
     }
 
@@ -16803,6 +18495,19 @@ impl AzCss {
             mem::transmute(s),
         )) }
     }
+    fn set_variable(&mut self, name: String, value: AzCssPropertyEnumWrapper) -> () {
+        let name = pystring_to_azstring(&name);
+        unsafe { mem::transmute(crate::AzCss_setVariable(
+            mem::transmute(self),
+            mem::transmute(name),
+            mem::transmute(value),
+        )) }
+    }
+    fn resolve_variables(&mut self) -> () {
+        unsafe { mem::transmute(crate::AzCss_resolveVariables(
+            mem::transmute(self),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -16815,6 +18520,194 @@ impl PyObjectProtocol for AzCss {
     }
 }
 
+#[pymethods]
+impl AzCssVariable {
+    #[new]
+    fn __new__(name: AzString, value: AzCssPropertyEnumWrapper) -> Self {
+        Self {
+            name,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssVariable {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssVariable = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssVariable = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzMediaQuery {
+    #[new]
+    fn __new__(conditions: AzMediaConditionVec) -> Self {
+        Self {
+            conditions,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMediaQuery {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaQuery = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaQuery = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzMediaCondition {
+    #[new]
+    fn __new__(features: AzMediaFeatureVec) -> Self {
+        Self {
+            features,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMediaCondition {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaCondition = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaCondition = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzMediaFeatureEnumWrapper {
+    #[staticmethod]
+    fn MinWidth(v: AzPixelValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::MinWidth(v) } }
+    #[staticmethod]
+    fn MaxWidth(v: AzPixelValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::MaxWidth(v) } }
+    #[staticmethod]
+    fn MinHeight(v: AzPixelValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::MinHeight(v) } }
+    #[staticmethod]
+    fn MaxHeight(v: AzPixelValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::MaxHeight(v) } }
+    #[staticmethod]
+    fn WidthLessThan(v: AzPixelValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::WidthLessThan(v) } }
+    #[staticmethod]
+    fn WidthGreaterThan(v: AzPixelValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::WidthGreaterThan(v) } }
+    #[staticmethod]
+    fn HeightLessThan(v: AzPixelValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::HeightLessThan(v) } }
+    #[staticmethod]
+    fn HeightGreaterThan(v: AzPixelValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::HeightGreaterThan(v) } }
+    #[staticmethod]
+    fn Orientation(v: AzMediaOrientationEnumWrapper) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::Orientation(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn PrefersColorScheme(v: AzMediaColorSchemeEnumWrapper) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::PrefersColorScheme(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn Resolution(v: AzFloatValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::Resolution(v) } }
+    #[staticmethod]
+    fn MinResolution(v: AzFloatValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::MinResolution(v) } }
+    #[staticmethod]
+    fn MaxResolution(v: AzFloatValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::MaxResolution(v) } }
+    #[staticmethod]
+    fn ResolutionLessThan(v: AzFloatValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::ResolutionLessThan(v) } }
+    #[staticmethod]
+    fn ResolutionGreaterThan(v: AzFloatValue) -> AzMediaFeatureEnumWrapper { AzMediaFeatureEnumWrapper { inner: AzMediaFeature::ResolutionGreaterThan(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzMediaFeature;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzMediaFeature::MinWidth(v) => Ok(vec!["MinWidth".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::MaxWidth(v) => Ok(vec!["MaxWidth".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::MinHeight(v) => Ok(vec!["MinHeight".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::MaxHeight(v) => Ok(vec!["MaxHeight".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::WidthLessThan(v) => Ok(vec!["WidthLessThan".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::WidthGreaterThan(v) => Ok(vec!["WidthGreaterThan".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::HeightLessThan(v) => Ok(vec!["HeightLessThan".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::HeightGreaterThan(v) => Ok(vec!["HeightGreaterThan".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::Orientation(v) => Ok(vec!["Orientation".into_py(py), { let m: &AzMediaOrientationEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzMediaFeature::PrefersColorScheme(v) => Ok(vec!["PrefersColorScheme".into_py(py), { let m: &AzMediaColorSchemeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzMediaFeature::Resolution(v) => Ok(vec!["Resolution".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::MinResolution(v) => Ok(vec!["MinResolution".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::MaxResolution(v) => Ok(vec!["MaxResolution".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::ResolutionLessThan(v) => Ok(vec!["ResolutionLessThan".into_py(py), v.clone().into_py(py)]),
+            AzMediaFeature::ResolutionGreaterThan(v) => Ok(vec!["ResolutionGreaterThan".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMediaFeatureEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaFeature = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaFeature = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzMediaOrientationEnumWrapper {
+    #[classattr]
+    fn Portrait() -> AzMediaOrientationEnumWrapper { AzMediaOrientationEnumWrapper { inner: AzMediaOrientation::Portrait } }
+    #[classattr]
+    fn Landscape() -> AzMediaOrientationEnumWrapper { AzMediaOrientationEnumWrapper { inner: AzMediaOrientation::Landscape } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMediaOrientationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzMediaOrientationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzMediaColorSchemeEnumWrapper {
+    #[classattr]
+    fn Light() -> AzMediaColorSchemeEnumWrapper { AzMediaColorSchemeEnumWrapper { inner: AzMediaColorScheme::Light } }
+    #[classattr]
+    fn Dark() -> AzMediaColorSchemeEnumWrapper { AzMediaColorSchemeEnumWrapper { inner: AzMediaColorScheme::Dark } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMediaColorSchemeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaColorScheme = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaColorScheme = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzMediaColorSchemeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
@@ -16826,6 +18719,10 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn TextAlign() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TextAlign } }
     #[classattr]
+    fn VerticalAlign() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::VerticalAlign } }
+    #[classattr]
+    fn Direction() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Direction } }
+    #[classattr]
     fn LetterSpacing() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::LetterSpacing } }
     #[classattr]
     fn LineHeight() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::LineHeight } }
@@ -16878,6 +18775,14 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn AlignContent() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AlignContent } }
     #[classattr]
+    fn GridTemplateColumns() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridTemplateColumns } }
+    #[classattr]
+    fn GridTemplateRows() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridTemplateRows } }
+    #[classattr]
+    fn GridColumn() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridColumn } }
+    #[classattr]
+    fn GridRow() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridRow } }
+    #[classattr]
     fn BackgroundContent() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackgroundContent } }
     #[classattr]
     fn BackgroundPosition() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackgroundPosition } }
@@ -16960,11 +18865,29 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn MixBlendMode() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::MixBlendMode } }
     #[classattr]
+    fn Isolation() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Isolation } }
+    #[classattr]
+    fn ImageRendering() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ImageRendering } }
+    #[classattr]
     fn Filter() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Filter } }
     #[classattr]
     fn BackdropFilter() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackdropFilter } }
     #[classattr]
     fn TextShadow() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TextShadow } }
+    #[classattr]
+    fn TransitionProperty() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionProperty } }
+    #[classattr]
+    fn TransitionDuration() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionDuration } }
+    #[classattr]
+    fn TransitionTimingFunction() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionTimingFunction } }
+    #[classattr]
+    fn TransitionDelay() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionDelay } }
+    #[classattr]
+    fn CaretColor() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::CaretColor } }
+    #[classattr]
+    fn SelectionColor() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::SelectionColor } }
+    #[classattr]
+    fn SelectionBackground() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::SelectionBackground } }
 }
 
 #[pyproto]
@@ -17031,13 +18954,14 @@ impl PyObjectProtocol for AzAnimationInterpolationFunctionEnumWrapper {
 #[pymethods]
 impl AzInterpolateContext {
     #[new]
-    fn __new__(animation_func: AzAnimationInterpolationFunctionEnumWrapper, parent_rect_width: f32, parent_rect_height: f32, current_rect_width: f32, current_rect_height: f32) -> Self {
+    fn __new__(animation_func: AzAnimationInterpolationFunctionEnumWrapper, parent_rect_width: f32, parent_rect_height: f32, current_rect_width: f32, current_rect_height: f32, em_size: f32) -> Self {
         Self {
             animation_func,
             parent_rect_width,
             parent_rect_height,
             current_rect_width,
             current_rect_height,
+            em_size,
         }
     }
 
@@ -17079,6 +19003,54 @@ impl AzColorU {
             mem::transmute(self),
         )) })
     }
+    fn multiply(&self, other: AzColorU) -> AzColorU {
+        unsafe { mem::transmute(crate::AzColorU_multiply(
+            mem::transmute(self),
+            mem::transmute(other),
+        )) }
+    }
+    fn add_saturating(&self, other: AzColorU) -> AzColorU {
+        unsafe { mem::transmute(crate::AzColorU_addSaturating(
+            mem::transmute(self),
+            mem::transmute(other),
+        )) }
+    }
+    fn hue_rotate(&self, degrees: f32) -> AzColorU {
+        unsafe { mem::transmute(crate::AzColorU_hueRotate(
+            mem::transmute(self),
+            mem::transmute(degrees),
+        )) }
+    }
+    fn with_r(&self, r: u8) -> AzColorU {
+        unsafe { mem::transmute(crate::AzColorU_withR(
+            mem::transmute(self),
+            mem::transmute(r),
+        )) }
+    }
+    fn with_g(&self, g: u8) -> AzColorU {
+        unsafe { mem::transmute(crate::AzColorU_withG(
+            mem::transmute(self),
+            mem::transmute(g),
+        )) }
+    }
+    fn with_b(&self, b: u8) -> AzColorU {
+        unsafe { mem::transmute(crate::AzColorU_withB(
+            mem::transmute(self),
+            mem::transmute(b),
+        )) }
+    }
+    fn with_a(&self, a: u8) -> AzColorU {
+        unsafe { mem::transmute(crate::AzColorU_withA(
+            mem::transmute(self),
+            mem::transmute(a),
+        )) }
+    }
+    fn with_alpha_f32(&self, a: f32) -> AzColorU {
+        unsafe { mem::transmute(crate::AzColorU_withAlphaF32(
+            mem::transmute(self),
+            mem::transmute(a),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -17166,6 +19138,52 @@ impl PyObjectProtocol for AzPixelValue {
     }
 }
 
+#[pymethods]
+impl AzCalcLength {
+    #[new]
+    fn __new__(px: AzFloatValue, pt: AzFloatValue, em: AzFloatValue, percent: AzFloatValue) -> Self {
+        Self {
+            px,
+            pt,
+            em,
+            percent,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCalcLength {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CalcLength = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CalcLength = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCalcLengthBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzCalcLength) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCalcLengthBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CalcLengthBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CalcLengthBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzPixelValueNoPercent {
     #[new]
@@ -17285,6 +19303,64 @@ impl PyObjectProtocol for AzStyleMixBlendModeEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleIsolationEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleIsolationEnumWrapper { AzStyleIsolationEnumWrapper { inner: AzStyleIsolation::Auto } }
+    #[classattr]
+    fn Isolate() -> AzStyleIsolationEnumWrapper { AzStyleIsolationEnumWrapper { inner: AzStyleIsolation::Isolate } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleIsolationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleIsolation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleIsolation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleIsolationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleImageRenderingEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleImageRenderingEnumWrapper { AzStyleImageRenderingEnumWrapper { inner: AzStyleImageRendering::Auto } }
+    #[classattr]
+    fn CrispEdges() -> AzStyleImageRenderingEnumWrapper { AzStyleImageRenderingEnumWrapper { inner: AzStyleImageRendering::CrispEdges } }
+    #[classattr]
+    fn Pixelated() -> AzStyleImageRenderingEnumWrapper { AzStyleImageRenderingEnumWrapper { inner: AzStyleImageRendering::Pixelated } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleImageRenderingEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleImageRendering = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleImageRendering = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleImageRenderingEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzStyleFilterEnumWrapper {
     #[staticmethod]
@@ -17600,6 +19676,10 @@ impl AzLayoutDisplayEnumWrapper {
     fn Block() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::Block } }
     #[classattr]
     fn InlineBlock() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::InlineBlock } }
+    #[classattr]
+    fn Grid() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::Grid } }
+    #[classattr]
+    fn Inline() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::Inline } }
 }
 
 #[pyproto]
@@ -17622,6 +19702,207 @@ impl PyObjectProtocol for AzLayoutDisplayEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzGridTrackEnumWrapper {
+    #[staticmethod]
+    fn Px(v: AzPixelValue) -> AzGridTrackEnumWrapper { AzGridTrackEnumWrapper { inner: AzGridTrack::Px(v) } }
+    #[staticmethod]
+    fn Fraction(v: AzFloatValue) -> AzGridTrackEnumWrapper { AzGridTrackEnumWrapper { inner: AzGridTrack::Fraction(v) } }
+    #[classattr]
+    fn MinContent() -> AzGridTrackEnumWrapper { AzGridTrackEnumWrapper { inner: AzGridTrack::MinContent } }
+    #[classattr]
+    fn MaxContent() -> AzGridTrackEnumWrapper { AzGridTrackEnumWrapper { inner: AzGridTrack::MaxContent } }
+    #[classattr]
+    fn Auto() -> AzGridTrackEnumWrapper { AzGridTrackEnumWrapper { inner: AzGridTrack::Auto } }
+    #[staticmethod]
+    fn Repeat(v: AzGridTrackRepeat) -> AzGridTrackEnumWrapper { AzGridTrackEnumWrapper { inner: AzGridTrack::Repeat(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridTrack;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridTrack::Px(v) => Ok(vec!["Px".into_py(py), v.clone().into_py(py)]),
+            AzGridTrack::Fraction(v) => Ok(vec!["Fraction".into_py(py), v.clone().into_py(py)]),
+            AzGridTrack::MinContent => Ok(vec!["MinContent".into_py(py), ().into_py(py)]),
+            AzGridTrack::MaxContent => Ok(vec!["MaxContent".into_py(py), ().into_py(py)]),
+            AzGridTrack::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzGridTrack::Repeat(v) => Ok(vec!["Repeat".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrack = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrack = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridTrackRepeat {
+    #[new]
+    fn __new__(count: u32, tracks: AzGridTrackVec) -> Self {
+        Self {
+            count,
+            tracks,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackRepeat {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackRepeat = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackRepeat = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridLineEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzGridLineEnumWrapper { AzGridLineEnumWrapper { inner: AzGridLine::Auto } }
+    #[staticmethod]
+    fn Line(v: i16) -> AzGridLineEnumWrapper { AzGridLineEnumWrapper { inner: AzGridLine::Line(v) } }
+    #[staticmethod]
+    fn Span(v: u16) -> AzGridLineEnumWrapper { AzGridLineEnumWrapper { inner: AzGridLine::Span(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridLine;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridLine::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzGridLine::Line(v) => Ok(vec!["Line".into_py(py), v.into_py(py)]),
+            AzGridLine::Span(v) => Ok(vec!["Span".into_py(py), v.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridLineEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridLine = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridLine = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridPlacement {
+    #[new]
+    fn __new__(start: AzGridLineEnumWrapper, end: AzGridLineEnumWrapper) -> Self {
+        Self {
+            start,
+            end,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridPlacement {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridPlacement = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridPlacement = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridTemplateColumns {
+    #[new]
+    fn __new__(inner: AzGridTrackVec) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridTemplateColumns {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateColumns = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateColumns = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridTemplateRows {
+    #[new]
+    fn __new__(inner: AzGridTrackVec) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridTemplateRows {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateRows = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateRows = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridColumn {
+    #[new]
+    fn __new__(inner: AzGridPlacement) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridColumn {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridColumn = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridColumn = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridRow {
+    #[new]
+    fn __new__(inner: AzGridPlacement) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridRow {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridRow = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutFlexGrow {
     #[new]
@@ -17670,6 +19951,8 @@ impl AzLayoutFloatEnumWrapper {
     fn Left() -> AzLayoutFloatEnumWrapper { AzLayoutFloatEnumWrapper { inner: AzLayoutFloat::Left } }
     #[classattr]
     fn Right() -> AzLayoutFloatEnumWrapper { AzLayoutFloatEnumWrapper { inner: AzLayoutFloat::Right } }
+    #[classattr]
+    fn None() -> AzLayoutFloatEnumWrapper { AzLayoutFloatEnumWrapper { inner: AzLayoutFloat::None } }
 }
 
 #[pyproto]
@@ -18123,6 +20406,8 @@ impl AzLayoutFlexWrapEnumWrapper {
     fn Wrap() -> AzLayoutFlexWrapEnumWrapper { AzLayoutFlexWrapEnumWrapper { inner: AzLayoutFlexWrap::Wrap } }
     #[classattr]
     fn NoWrap() -> AzLayoutFlexWrapEnumWrapper { AzLayoutFlexWrapEnumWrapper { inner: AzLayoutFlexWrap::NoWrap } }
+    #[classattr]
+    fn WrapReverse() -> AzLayoutFlexWrapEnumWrapper { AzLayoutFlexWrapEnumWrapper { inner: AzLayoutFlexWrap::WrapReverse } }
 }
 
 #[pyproto]
@@ -18155,6 +20440,8 @@ impl AzLayoutOverflowEnumWrapper {
     fn Hidden() -> AzLayoutOverflowEnumWrapper { AzLayoutOverflowEnumWrapper { inner: AzLayoutOverflow::Hidden } }
     #[classattr]
     fn Visible() -> AzLayoutOverflowEnumWrapper { AzLayoutOverflowEnumWrapper { inner: AzLayoutOverflow::Visible } }
+    #[classattr]
+    fn Clip() -> AzLayoutOverflowEnumWrapper { AzLayoutOverflowEnumWrapper { inner: AzLayoutOverflow::Clip } }
 }
 
 #[pyproto]
@@ -18694,6 +20981,10 @@ impl AzStyleBackgroundRepeatEnumWrapper {
     fn RepeatX() -> AzStyleBackgroundRepeatEnumWrapper { AzStyleBackgroundRepeatEnumWrapper { inner: AzStyleBackgroundRepeat::RepeatX } }
     #[classattr]
     fn RepeatY() -> AzStyleBackgroundRepeatEnumWrapper { AzStyleBackgroundRepeatEnumWrapper { inner: AzStyleBackgroundRepeat::RepeatY } }
+    #[classattr]
+    fn Space() -> AzStyleBackgroundRepeatEnumWrapper { AzStyleBackgroundRepeatEnumWrapper { inner: AzStyleBackgroundRepeat::Space } }
+    #[classattr]
+    fn Round() -> AzStyleBackgroundRepeatEnumWrapper { AzStyleBackgroundRepeatEnumWrapper { inner: AzStyleBackgroundRepeat::Round } }
 }
 
 #[pyproto]
@@ -19434,10 +21725,10 @@ impl AzStylePerspectiveOrigin {
 #[pyproto]
 impl PyObjectProtocol for AzStylePerspectiveOrigin {
     fn __str__(&self) -> Result<String, PyErr> { 
-        let m: &azul_impl::css::StyleTransformOrigin = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &azul_impl::css::StylePerspectiveOrigin = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
     fn __repr__(&self) -> Result<String, PyErr> { 
-        let m: &azul_impl::css::StyleTransformOrigin = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+        let m: &azul_impl::css::StylePerspectiveOrigin = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
     }
 }
 
@@ -19761,6 +22052,10 @@ impl AzStyleTextAlignEnumWrapper {
     fn Center() -> AzStyleTextAlignEnumWrapper { AzStyleTextAlignEnumWrapper { inner: AzStyleTextAlign::Center } }
     #[classattr]
     fn Right() -> AzStyleTextAlignEnumWrapper { AzStyleTextAlignEnumWrapper { inner: AzStyleTextAlign::Right } }
+    #[classattr]
+    fn Start() -> AzStyleTextAlignEnumWrapper { AzStyleTextAlignEnumWrapper { inner: AzStyleTextAlign::Start } }
+    #[classattr]
+    fn End() -> AzStyleTextAlignEnumWrapper { AzStyleTextAlignEnumWrapper { inner: AzStyleTextAlign::End } }
 }
 
 #[pyproto]
@@ -19783,6 +22078,280 @@ impl PyObjectProtocol for AzStyleTextAlignEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleDirectionEnumWrapper {
+    #[classattr]
+    fn Ltr() -> AzStyleDirectionEnumWrapper { AzStyleDirectionEnumWrapper { inner: AzStyleDirection::Ltr } }
+    #[classattr]
+    fn Rtl() -> AzStyleDirectionEnumWrapper { AzStyleDirectionEnumWrapper { inner: AzStyleDirection::Rtl } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleDirectionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleDirectionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionPropertyEnumWrapper {
+    #[classattr]
+    fn All() -> AzStyleTransitionPropertyEnumWrapper { AzStyleTransitionPropertyEnumWrapper { inner: AzStyleTransitionProperty::All } }
+    #[classattr]
+    fn None() -> AzStyleTransitionPropertyEnumWrapper { AzStyleTransitionPropertyEnumWrapper { inner: AzStyleTransitionProperty::None } }
+    #[staticmethod]
+    fn List(v: AzCssPropertyTypeVec) -> AzStyleTransitionPropertyEnumWrapper { AzStyleTransitionPropertyEnumWrapper { inner: AzStyleTransitionProperty::List(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionProperty;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionProperty::All => Ok(vec!["All".into_py(py), ().into_py(py)]),
+            AzStyleTransitionProperty::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransitionProperty::List(v) => Ok(vec!["List".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionProperty = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionProperty = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDuration {
+    #[new]
+    fn __new__(inner: AzFloatValue) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDuration {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDuration = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDuration = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDelay {
+    #[new]
+    fn __new__(inner: AzFloatValue) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDelay {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelay = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelay = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStepPositionEnumWrapper {
+    #[classattr]
+    fn JumpStart() -> AzStepPositionEnumWrapper { AzStepPositionEnumWrapper { inner: AzStepPosition::JumpStart } }
+    #[classattr]
+    fn JumpEnd() -> AzStepPositionEnumWrapper { AzStepPositionEnumWrapper { inner: AzStepPosition::JumpEnd } }
+    #[classattr]
+    fn JumpNone() -> AzStepPositionEnumWrapper { AzStepPositionEnumWrapper { inner: AzStepPosition::JumpNone } }
+    #[classattr]
+    fn JumpBoth() -> AzStepPositionEnumWrapper { AzStepPositionEnumWrapper { inner: AzStepPosition::JumpBoth } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStepPositionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StepPosition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StepPosition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStepPositionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleCubicBezier {
+    #[new]
+    fn __new__(x1: AzFloatValue, y1: AzFloatValue, x2: AzFloatValue, y2: AzFloatValue) -> Self {
+        Self {
+            x1,
+            y1,
+            x2,
+            y2,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCubicBezier {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCubicBezier = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCubicBezier = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleSteps {
+    #[new]
+    fn __new__(count: u32, position: AzStepPositionEnumWrapper) -> Self {
+        Self {
+            count,
+            position,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleSteps {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSteps = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSteps = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionTimingFunctionEnumWrapper {
+    #[classattr]
+    fn Linear() -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::Linear } }
+    #[classattr]
+    fn Ease() -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::Ease } }
+    #[classattr]
+    fn EaseIn() -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::EaseIn } }
+    #[classattr]
+    fn EaseOut() -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::EaseOut } }
+    #[classattr]
+    fn EaseInOut() -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::EaseInOut } }
+    #[staticmethod]
+    fn CubicBezier(v: AzStyleCubicBezier) -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::CubicBezier(v) } }
+    #[staticmethod]
+    fn Steps(v: AzStyleSteps) -> AzStyleTransitionTimingFunctionEnumWrapper { AzStyleTransitionTimingFunctionEnumWrapper { inner: AzStyleTransitionTimingFunction::Steps(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionTimingFunction;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionTimingFunction::Linear => Ok(vec!["Linear".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunction::Ease => Ok(vec!["Ease".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunction::EaseIn => Ok(vec!["EaseIn".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunction::EaseOut => Ok(vec!["EaseOut".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunction::EaseInOut => Ok(vec!["EaseInOut".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunction::CubicBezier(v) => Ok(vec!["CubicBezier".into_py(py), v.clone().into_py(py)]),
+            AzStyleTransitionTimingFunction::Steps(v) => Ok(vec!["Steps".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionTimingFunctionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunction = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunction = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleVerticalAlignEnumWrapper {
+    #[classattr]
+    fn Baseline() -> AzStyleVerticalAlignEnumWrapper { AzStyleVerticalAlignEnumWrapper { inner: AzStyleVerticalAlign::Baseline } }
+    #[classattr]
+    fn Top() -> AzStyleVerticalAlignEnumWrapper { AzStyleVerticalAlignEnumWrapper { inner: AzStyleVerticalAlign::Top } }
+    #[classattr]
+    fn Middle() -> AzStyleVerticalAlignEnumWrapper { AzStyleVerticalAlignEnumWrapper { inner: AzStyleVerticalAlign::Middle } }
+    #[classattr]
+    fn Bottom() -> AzStyleVerticalAlignEnumWrapper { AzStyleVerticalAlignEnumWrapper { inner: AzStyleVerticalAlign::Bottom } }
+    #[classattr]
+    fn TextTop() -> AzStyleVerticalAlignEnumWrapper { AzStyleVerticalAlignEnumWrapper { inner: AzStyleVerticalAlign::TextTop } }
+    #[classattr]
+    fn TextBottom() -> AzStyleVerticalAlignEnumWrapper { AzStyleVerticalAlignEnumWrapper { inner: AzStyleVerticalAlign::TextBottom } }
+    #[classattr]
+    fn Sub() -> AzStyleVerticalAlignEnumWrapper { AzStyleVerticalAlignEnumWrapper { inner: AzStyleVerticalAlign::Sub } }
+    #[classattr]
+    fn Super() -> AzStyleVerticalAlignEnumWrapper { AzStyleVerticalAlignEnumWrapper { inner: AzStyleVerticalAlign::Super } }
+    #[staticmethod]
+    fn Exact(v: AzPixelValue) -> AzStyleVerticalAlignEnumWrapper { AzStyleVerticalAlignEnumWrapper { inner: AzStyleVerticalAlign::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleVerticalAlign;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleVerticalAlign::Baseline => Ok(vec!["Baseline".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlign::Top => Ok(vec!["Top".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlign::Middle => Ok(vec!["Middle".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlign::Bottom => Ok(vec!["Bottom".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlign::TextTop => Ok(vec!["TextTop".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlign::TextBottom => Ok(vec!["TextBottom".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlign::Sub => Ok(vec!["Sub".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlign::Super => Ok(vec!["Super".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlign::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleVerticalAlignEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleVerticalAlign = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleVerticalAlign = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleTextColor {
     #[new]
@@ -19804,6 +22373,69 @@ impl PyObjectProtocol for AzStyleTextColor {
     }
 }
 
+#[pymethods]
+impl AzStyleCaretColor {
+    #[new]
+    fn __new__(inner: AzColorU) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCaretColor {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCaretColor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCaretColor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleSelectionColor {
+    #[new]
+    fn __new__(inner: AzColorU) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleSelectionColor {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSelectionColor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSelectionColor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleSelectionBackground {
+    #[new]
+    fn __new__(inner: AzColorU) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleSelectionBackground {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSelectionBackground = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSelectionBackground = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleWordSpacing {
     #[new]
@@ -19825,6 +22457,28 @@ impl PyObjectProtocol for AzStyleWordSpacing {
     }
 }
 
+#[pymethods]
+impl AzStyleBoxShadowBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleBoxShadow) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleBoxShadowBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleBoxShadowBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleBoxShadowBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleBoxShadowValueEnumWrapper {
     #[classattr]
@@ -19835,8 +22489,6 @@ impl AzStyleBoxShadowValueEnumWrapper {
     fn Inherit() -> AzStyleBoxShadowValueEnumWrapper { AzStyleBoxShadowValueEnumWrapper { inner: AzStyleBoxShadowValue::Inherit } }
     #[classattr]
     fn Initial() -> AzStyleBoxShadowValueEnumWrapper { AzStyleBoxShadowValueEnumWrapper { inner: AzStyleBoxShadowValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzStyleBoxShadow) -> AzStyleBoxShadowValueEnumWrapper { AzStyleBoxShadowValueEnumWrapper { inner: AzStyleBoxShadowValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBoxShadowValue;
@@ -19901,6 +22553,198 @@ impl PyObjectProtocol for AzLayoutAlignContentValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzLayoutGridTemplateColumnsBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzLayoutGridTemplateColumns) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridTemplateColumnsBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateColumnsBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateColumnsBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridTemplateColumnsValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutGridTemplateColumnsValueEnumWrapper { AzLayoutGridTemplateColumnsValueEnumWrapper { inner: AzLayoutGridTemplateColumnsValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutGridTemplateColumnsValueEnumWrapper { AzLayoutGridTemplateColumnsValueEnumWrapper { inner: AzLayoutGridTemplateColumnsValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutGridTemplateColumnsValueEnumWrapper { AzLayoutGridTemplateColumnsValueEnumWrapper { inner: AzLayoutGridTemplateColumnsValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutGridTemplateColumnsValueEnumWrapper { AzLayoutGridTemplateColumnsValueEnumWrapper { inner: AzLayoutGridTemplateColumnsValue::Initial } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutGridTemplateColumnsValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutGridTemplateColumnsValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateColumnsValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateColumnsValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateColumnsValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateColumnsValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridTemplateColumnsValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateColumnsValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateColumnsValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridTemplateRowsBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzLayoutGridTemplateRows) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridTemplateRowsBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateRowsBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateRowsBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridTemplateRowsValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutGridTemplateRowsValueEnumWrapper { AzLayoutGridTemplateRowsValueEnumWrapper { inner: AzLayoutGridTemplateRowsValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutGridTemplateRowsValueEnumWrapper { AzLayoutGridTemplateRowsValueEnumWrapper { inner: AzLayoutGridTemplateRowsValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutGridTemplateRowsValueEnumWrapper { AzLayoutGridTemplateRowsValueEnumWrapper { inner: AzLayoutGridTemplateRowsValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutGridTemplateRowsValueEnumWrapper { AzLayoutGridTemplateRowsValueEnumWrapper { inner: AzLayoutGridTemplateRowsValue::Initial } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutGridTemplateRowsValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutGridTemplateRowsValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateRowsValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateRowsValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateRowsValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutGridTemplateRowsValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridTemplateRowsValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateRowsValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridTemplateRowsValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridColumnValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutGridColumnValueEnumWrapper { AzLayoutGridColumnValueEnumWrapper { inner: AzLayoutGridColumnValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutGridColumnValueEnumWrapper { AzLayoutGridColumnValueEnumWrapper { inner: AzLayoutGridColumnValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutGridColumnValueEnumWrapper { AzLayoutGridColumnValueEnumWrapper { inner: AzLayoutGridColumnValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutGridColumnValueEnumWrapper { AzLayoutGridColumnValueEnumWrapper { inner: AzLayoutGridColumnValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutGridColumn) -> AzLayoutGridColumnValueEnumWrapper { AzLayoutGridColumnValueEnumWrapper { inner: AzLayoutGridColumnValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutGridColumnValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutGridColumnValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutGridColumnValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutGridColumnValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutGridColumnValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutGridColumnValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridColumnValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridColumnValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridColumnValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridRowValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutGridRowValueEnumWrapper { AzLayoutGridRowValueEnumWrapper { inner: AzLayoutGridRowValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutGridRowValueEnumWrapper { AzLayoutGridRowValueEnumWrapper { inner: AzLayoutGridRowValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutGridRowValueEnumWrapper { AzLayoutGridRowValueEnumWrapper { inner: AzLayoutGridRowValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutGridRowValueEnumWrapper { AzLayoutGridRowValueEnumWrapper { inner: AzLayoutGridRowValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutGridRow) -> AzLayoutGridRowValueEnumWrapper { AzLayoutGridRowValueEnumWrapper { inner: AzLayoutGridRowValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutGridRowValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutGridRowValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutGridRowValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutGridRowValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutGridRowValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutGridRowValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridRowValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridRowValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridRowValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutAlignItemsValueEnumWrapper {
     #[classattr]
@@ -19963,6 +22807,7 @@ impl AzLayoutBottomValueEnumWrapper {
             AzLayoutBottomValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutBottomValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutBottomValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutBottomValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20229,6 +23074,7 @@ impl AzLayoutHeightValueEnumWrapper {
             AzLayoutHeightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutHeightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutHeightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutHeightValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20305,6 +23151,7 @@ impl AzLayoutLeftValueEnumWrapper {
             AzLayoutLeftValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutLeftValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutLeftValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutLeftValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20343,6 +23190,7 @@ impl AzLayoutMarginBottomValueEnumWrapper {
             AzLayoutMarginBottomValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMarginBottomValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMarginBottomValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutMarginBottomValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20381,6 +23229,7 @@ impl AzLayoutMarginLeftValueEnumWrapper {
             AzLayoutMarginLeftValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMarginLeftValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMarginLeftValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutMarginLeftValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20419,6 +23268,7 @@ impl AzLayoutMarginRightValueEnumWrapper {
             AzLayoutMarginRightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMarginRightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMarginRightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutMarginRightValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20457,6 +23307,7 @@ impl AzLayoutMarginTopValueEnumWrapper {
             AzLayoutMarginTopValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMarginTopValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMarginTopValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutMarginTopValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20495,6 +23346,7 @@ impl AzLayoutMaxHeightValueEnumWrapper {
             AzLayoutMaxHeightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMaxHeightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMaxHeightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutMaxHeightValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20533,6 +23385,7 @@ impl AzLayoutMaxWidthValueEnumWrapper {
             AzLayoutMaxWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMaxWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMaxWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutMaxWidthValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20571,6 +23424,7 @@ impl AzLayoutMinHeightValueEnumWrapper {
             AzLayoutMinHeightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMinHeightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMinHeightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutMinHeightValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20609,6 +23463,7 @@ impl AzLayoutMinWidthValueEnumWrapper {
             AzLayoutMinWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutMinWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutMinWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutMinWidthValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20647,6 +23502,7 @@ impl AzLayoutPaddingBottomValueEnumWrapper {
             AzLayoutPaddingBottomValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutPaddingBottomValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutPaddingBottomValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutPaddingBottomValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20685,6 +23541,7 @@ impl AzLayoutPaddingLeftValueEnumWrapper {
             AzLayoutPaddingLeftValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutPaddingLeftValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutPaddingLeftValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutPaddingLeftValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20723,6 +23580,7 @@ impl AzLayoutPaddingRightValueEnumWrapper {
             AzLayoutPaddingRightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutPaddingRightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutPaddingRightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutPaddingRightValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20761,6 +23619,7 @@ impl AzLayoutPaddingTopValueEnumWrapper {
             AzLayoutPaddingTopValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutPaddingTopValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutPaddingTopValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutPaddingTopValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20837,6 +23696,7 @@ impl AzLayoutRightValueEnumWrapper {
             AzLayoutRightValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutRightValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutRightValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutRightValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20875,6 +23735,7 @@ impl AzLayoutTopValueEnumWrapper {
             AzLayoutTopValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutTopValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutTopValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutTopValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -20913,6 +23774,7 @@ impl AzLayoutWidthValueEnumWrapper {
             AzLayoutWidthValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
             AzLayoutWidthValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
             AzLayoutWidthValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+            AzLayoutWidthValue::Calc(v) => Ok(vec!["Calc".into_py(py), v.clone().into_py(py)]),
         }
     }
 }
@@ -21003,6 +23865,28 @@ impl PyObjectProtocol for AzLayoutOverflowValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzScrollbarStyleBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzScrollbarStyle) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzScrollbarStyleBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::ScrollbarStyleBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::ScrollbarStyleBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzScrollbarStyleValueEnumWrapper {
     #[classattr]
@@ -21013,8 +23897,6 @@ impl AzScrollbarStyleValueEnumWrapper {
     fn Inherit() -> AzScrollbarStyleValueEnumWrapper { AzScrollbarStyleValueEnumWrapper { inner: AzScrollbarStyleValue::Inherit } }
     #[classattr]
     fn Initial() -> AzScrollbarStyleValueEnumWrapper { AzScrollbarStyleValueEnumWrapper { inner: AzScrollbarStyleValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzScrollbarStyle) -> AzScrollbarStyleValueEnumWrapper { AzScrollbarStyleValueEnumWrapper { inner: AzScrollbarStyleValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzScrollbarStyleValue;
@@ -21041,6 +23923,28 @@ impl PyObjectProtocol for AzScrollbarStyleValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleBackgroundContentVecBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleBackgroundContentVec) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleBackgroundContentVecBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleBackgroundContentVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleBackgroundContentVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleBackgroundContentVecValueEnumWrapper {
     #[classattr]
@@ -21051,8 +23955,6 @@ impl AzStyleBackgroundContentVecValueEnumWrapper {
     fn Inherit() -> AzStyleBackgroundContentVecValueEnumWrapper { AzStyleBackgroundContentVecValueEnumWrapper { inner: AzStyleBackgroundContentVecValue::Inherit } }
     #[classattr]
     fn Initial() -> AzStyleBackgroundContentVecValueEnumWrapper { AzStyleBackgroundContentVecValueEnumWrapper { inner: AzStyleBackgroundContentVecValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzStyleBackgroundContentVec) -> AzStyleBackgroundContentVecValueEnumWrapper { AzStyleBackgroundContentVecValueEnumWrapper { inner: AzStyleBackgroundContentVecValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundContentVecValue;
@@ -21079,6 +23981,28 @@ impl PyObjectProtocol for AzStyleBackgroundContentVecValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleBackgroundPositionVecBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleBackgroundPositionVec) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleBackgroundPositionVecBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleBackgroundPositionVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleBackgroundPositionVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleBackgroundPositionVecValueEnumWrapper {
     #[classattr]
@@ -21089,8 +24013,6 @@ impl AzStyleBackgroundPositionVecValueEnumWrapper {
     fn Inherit() -> AzStyleBackgroundPositionVecValueEnumWrapper { AzStyleBackgroundPositionVecValueEnumWrapper { inner: AzStyleBackgroundPositionVecValue::Inherit } }
     #[classattr]
     fn Initial() -> AzStyleBackgroundPositionVecValueEnumWrapper { AzStyleBackgroundPositionVecValueEnumWrapper { inner: AzStyleBackgroundPositionVecValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzStyleBackgroundPositionVec) -> AzStyleBackgroundPositionVecValueEnumWrapper { AzStyleBackgroundPositionVecValueEnumWrapper { inner: AzStyleBackgroundPositionVecValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundPositionVecValue;
@@ -21117,6 +24039,28 @@ impl PyObjectProtocol for AzStyleBackgroundPositionVecValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleBackgroundRepeatVecBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleBackgroundRepeatVec) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleBackgroundRepeatVecBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleBackgroundRepeatVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleBackgroundRepeatVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleBackgroundRepeatVecValueEnumWrapper {
     #[classattr]
@@ -21127,8 +24071,6 @@ impl AzStyleBackgroundRepeatVecValueEnumWrapper {
     fn Inherit() -> AzStyleBackgroundRepeatVecValueEnumWrapper { AzStyleBackgroundRepeatVecValueEnumWrapper { inner: AzStyleBackgroundRepeatVecValue::Inherit } }
     #[classattr]
     fn Initial() -> AzStyleBackgroundRepeatVecValueEnumWrapper { AzStyleBackgroundRepeatVecValueEnumWrapper { inner: AzStyleBackgroundRepeatVecValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzStyleBackgroundRepeatVec) -> AzStyleBackgroundRepeatVecValueEnumWrapper { AzStyleBackgroundRepeatVecValueEnumWrapper { inner: AzStyleBackgroundRepeatVecValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundRepeatVecValue;
@@ -21155,6 +24097,28 @@ impl PyObjectProtocol for AzStyleBackgroundRepeatVecValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleBackgroundSizeVecBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleBackgroundSizeVec) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleBackgroundSizeVecBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleBackgroundSizeVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleBackgroundSizeVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleBackgroundSizeVecValueEnumWrapper {
     #[classattr]
@@ -21165,8 +24129,6 @@ impl AzStyleBackgroundSizeVecValueEnumWrapper {
     fn Inherit() -> AzStyleBackgroundSizeVecValueEnumWrapper { AzStyleBackgroundSizeVecValueEnumWrapper { inner: AzStyleBackgroundSizeVecValue::Inherit } }
     #[classattr]
     fn Initial() -> AzStyleBackgroundSizeVecValueEnumWrapper { AzStyleBackgroundSizeVecValueEnumWrapper { inner: AzStyleBackgroundSizeVecValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzStyleBackgroundSizeVec) -> AzStyleBackgroundSizeVecValueEnumWrapper { AzStyleBackgroundSizeVecValueEnumWrapper { inner: AzStyleBackgroundSizeVecValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleBackgroundSizeVecValue;
@@ -21839,6 +24801,28 @@ impl PyObjectProtocol for AzStyleCursorValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleFontFamilyVecBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleFontFamilyVec) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleFontFamilyVecBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleFontFamilyVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleFontFamilyVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleFontFamilyVecValueEnumWrapper {
     #[classattr]
@@ -21849,8 +24833,6 @@ impl AzStyleFontFamilyVecValueEnumWrapper {
     fn Inherit() -> AzStyleFontFamilyVecValueEnumWrapper { AzStyleFontFamilyVecValueEnumWrapper { inner: AzStyleFontFamilyVecValue::Inherit } }
     #[classattr]
     fn Initial() -> AzStyleFontFamilyVecValueEnumWrapper { AzStyleFontFamilyVecValueEnumWrapper { inner: AzStyleFontFamilyVecValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzStyleFontFamilyVec) -> AzStyleFontFamilyVecValueEnumWrapper { AzStyleFontFamilyVecValueEnumWrapper { inner: AzStyleFontFamilyVecValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleFontFamilyVecValue;
@@ -22067,6 +25049,408 @@ impl PyObjectProtocol for AzStyleTextAlignValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleDirectionValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleDirectionValueEnumWrapper { AzStyleDirectionValueEnumWrapper { inner: AzStyleDirectionValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleDirectionValueEnumWrapper { AzStyleDirectionValueEnumWrapper { inner: AzStyleDirectionValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleDirectionValueEnumWrapper { AzStyleDirectionValueEnumWrapper { inner: AzStyleDirectionValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleDirectionValueEnumWrapper { AzStyleDirectionValueEnumWrapper { inner: AzStyleDirectionValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleDirectionEnumWrapper) -> AzStyleDirectionValueEnumWrapper { AzStyleDirectionValueEnumWrapper { inner: AzStyleDirectionValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleDirectionValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleDirectionValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleDirectionValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleDirectionValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleDirectionValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleDirectionValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleDirectionEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleDirectionValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleDirectionValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleDirectionValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionPropertyBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleTransitionPropertyEnumWrapper) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionPropertyValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTransitionPropertyValueEnumWrapper { AzStyleTransitionPropertyValueEnumWrapper { inner: AzStyleTransitionPropertyValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTransitionPropertyValueEnumWrapper { AzStyleTransitionPropertyValueEnumWrapper { inner: AzStyleTransitionPropertyValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTransitionPropertyValueEnumWrapper { AzStyleTransitionPropertyValueEnumWrapper { inner: AzStyleTransitionPropertyValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTransitionPropertyValueEnumWrapper { AzStyleTransitionPropertyValueEnumWrapper { inner: AzStyleTransitionPropertyValue::Initial } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionPropertyValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionPropertyValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDurationValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTransitionDurationValueEnumWrapper { AzStyleTransitionDurationValueEnumWrapper { inner: AzStyleTransitionDurationValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTransitionDurationValueEnumWrapper { AzStyleTransitionDurationValueEnumWrapper { inner: AzStyleTransitionDurationValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTransitionDurationValueEnumWrapper { AzStyleTransitionDurationValueEnumWrapper { inner: AzStyleTransitionDurationValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTransitionDurationValueEnumWrapper { AzStyleTransitionDurationValueEnumWrapper { inner: AzStyleTransitionDurationValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTransitionDuration) -> AzStyleTransitionDurationValueEnumWrapper { AzStyleTransitionDurationValueEnumWrapper { inner: AzStyleTransitionDurationValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionDurationValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionDurationValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDurationValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDurationValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDurationValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDurationValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDurationValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDurationValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDurationValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionTimingFunctionBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleTransitionTimingFunctionEnumWrapper) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionTimingFunctionBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunctionBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunctionBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionTimingFunctionValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTransitionTimingFunctionValueEnumWrapper { AzStyleTransitionTimingFunctionValueEnumWrapper { inner: AzStyleTransitionTimingFunctionValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTransitionTimingFunctionValueEnumWrapper { AzStyleTransitionTimingFunctionValueEnumWrapper { inner: AzStyleTransitionTimingFunctionValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTransitionTimingFunctionValueEnumWrapper { AzStyleTransitionTimingFunctionValueEnumWrapper { inner: AzStyleTransitionTimingFunctionValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTransitionTimingFunctionValueEnumWrapper { AzStyleTransitionTimingFunctionValueEnumWrapper { inner: AzStyleTransitionTimingFunctionValue::Initial } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionTimingFunctionValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionTimingFunctionValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunctionValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunctionValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunctionValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTransitionTimingFunctionValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionTimingFunctionValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunctionValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionTimingFunctionValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionDelayValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTransitionDelayValueEnumWrapper { AzStyleTransitionDelayValueEnumWrapper { inner: AzStyleTransitionDelayValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTransitionDelayValueEnumWrapper { AzStyleTransitionDelayValueEnumWrapper { inner: AzStyleTransitionDelayValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTransitionDelayValueEnumWrapper { AzStyleTransitionDelayValueEnumWrapper { inner: AzStyleTransitionDelayValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTransitionDelayValueEnumWrapper { AzStyleTransitionDelayValueEnumWrapper { inner: AzStyleTransitionDelayValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTransitionDelay) -> AzStyleTransitionDelayValueEnumWrapper { AzStyleTransitionDelayValueEnumWrapper { inner: AzStyleTransitionDelayValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionDelayValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionDelayValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDelayValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDelayValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDelayValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTransitionDelayValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionDelayValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelayValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionDelayValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleCaretColorValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleCaretColorValueEnumWrapper { AzStyleCaretColorValueEnumWrapper { inner: AzStyleCaretColorValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleCaretColorValueEnumWrapper { AzStyleCaretColorValueEnumWrapper { inner: AzStyleCaretColorValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleCaretColorValueEnumWrapper { AzStyleCaretColorValueEnumWrapper { inner: AzStyleCaretColorValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleCaretColorValueEnumWrapper { AzStyleCaretColorValueEnumWrapper { inner: AzStyleCaretColorValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleCaretColor) -> AzStyleCaretColorValueEnumWrapper { AzStyleCaretColorValueEnumWrapper { inner: AzStyleCaretColorValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleCaretColorValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleCaretColorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleCaretColorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleCaretColorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleCaretColorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleCaretColorValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCaretColorValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCaretColorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCaretColorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleSelectionColorValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleSelectionColorValueEnumWrapper { AzStyleSelectionColorValueEnumWrapper { inner: AzStyleSelectionColorValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleSelectionColorValueEnumWrapper { AzStyleSelectionColorValueEnumWrapper { inner: AzStyleSelectionColorValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleSelectionColorValueEnumWrapper { AzStyleSelectionColorValueEnumWrapper { inner: AzStyleSelectionColorValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleSelectionColorValueEnumWrapper { AzStyleSelectionColorValueEnumWrapper { inner: AzStyleSelectionColorValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleSelectionColor) -> AzStyleSelectionColorValueEnumWrapper { AzStyleSelectionColorValueEnumWrapper { inner: AzStyleSelectionColorValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleSelectionColorValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleSelectionColorValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleSelectionColorValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleSelectionColorValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleSelectionColorValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleSelectionColorValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleSelectionColorValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSelectionColorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSelectionColorValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleSelectionBackgroundValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleSelectionBackgroundValueEnumWrapper { AzStyleSelectionBackgroundValueEnumWrapper { inner: AzStyleSelectionBackgroundValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleSelectionBackgroundValueEnumWrapper { AzStyleSelectionBackgroundValueEnumWrapper { inner: AzStyleSelectionBackgroundValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleSelectionBackgroundValueEnumWrapper { AzStyleSelectionBackgroundValueEnumWrapper { inner: AzStyleSelectionBackgroundValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleSelectionBackgroundValueEnumWrapper { AzStyleSelectionBackgroundValueEnumWrapper { inner: AzStyleSelectionBackgroundValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleSelectionBackground) -> AzStyleSelectionBackgroundValueEnumWrapper { AzStyleSelectionBackgroundValueEnumWrapper { inner: AzStyleSelectionBackgroundValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleSelectionBackgroundValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleSelectionBackgroundValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleSelectionBackgroundValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleSelectionBackgroundValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleSelectionBackgroundValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleSelectionBackgroundValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleSelectionBackgroundValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSelectionBackgroundValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSelectionBackgroundValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleVerticalAlignBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleVerticalAlignEnumWrapper) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleVerticalAlignBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleVerticalAlignBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleVerticalAlignBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleVerticalAlignValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleVerticalAlignValueEnumWrapper { AzStyleVerticalAlignValueEnumWrapper { inner: AzStyleVerticalAlignValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleVerticalAlignValueEnumWrapper { AzStyleVerticalAlignValueEnumWrapper { inner: AzStyleVerticalAlignValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleVerticalAlignValueEnumWrapper { AzStyleVerticalAlignValueEnumWrapper { inner: AzStyleVerticalAlignValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleVerticalAlignValueEnumWrapper { AzStyleVerticalAlignValueEnumWrapper { inner: AzStyleVerticalAlignValue::Initial } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleVerticalAlignValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleVerticalAlignValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlignValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlignValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlignValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleVerticalAlignValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleVerticalAlignValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleVerticalAlignValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleVerticalAlignValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleTextColorValueEnumWrapper {
     #[classattr]
@@ -22181,6 +25565,28 @@ impl PyObjectProtocol for AzStyleOpacityValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleTransformVecBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleTransformVec) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransformVecBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransformVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransformVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleTransformVecValueEnumWrapper {
     #[classattr]
@@ -22191,8 +25597,6 @@ impl AzStyleTransformVecValueEnumWrapper {
     fn Inherit() -> AzStyleTransformVecValueEnumWrapper { AzStyleTransformVecValueEnumWrapper { inner: AzStyleTransformVecValue::Inherit } }
     #[classattr]
     fn Initial() -> AzStyleTransformVecValueEnumWrapper { AzStyleTransformVecValueEnumWrapper { inner: AzStyleTransformVecValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzStyleTransformVec) -> AzStyleTransformVecValueEnumWrapper { AzStyleTransformVecValueEnumWrapper { inner: AzStyleTransformVecValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleTransformVecValue;
@@ -22219,6 +25623,28 @@ impl PyObjectProtocol for AzStyleTransformVecValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleTransformOriginBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleTransformOrigin) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransformOriginBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransformOriginBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransformOriginBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleTransformOriginValueEnumWrapper {
     #[classattr]
@@ -22229,8 +25655,6 @@ impl AzStyleTransformOriginValueEnumWrapper {
     fn Inherit() -> AzStyleTransformOriginValueEnumWrapper { AzStyleTransformOriginValueEnumWrapper { inner: AzStyleTransformOriginValue::Inherit } }
     #[classattr]
     fn Initial() -> AzStyleTransformOriginValueEnumWrapper { AzStyleTransformOriginValueEnumWrapper { inner: AzStyleTransformOriginValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzStyleTransformOrigin) -> AzStyleTransformOriginValueEnumWrapper { AzStyleTransformOriginValueEnumWrapper { inner: AzStyleTransformOriginValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleTransformOriginValue;
@@ -22257,6 +25681,28 @@ impl PyObjectProtocol for AzStyleTransformOriginValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStylePerspectiveOriginBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStylePerspectiveOrigin) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStylePerspectiveOriginBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StylePerspectiveOriginBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StylePerspectiveOriginBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStylePerspectiveOriginValueEnumWrapper {
     #[classattr]
@@ -22267,8 +25713,6 @@ impl AzStylePerspectiveOriginValueEnumWrapper {
     fn Inherit() -> AzStylePerspectiveOriginValueEnumWrapper { AzStylePerspectiveOriginValueEnumWrapper { inner: AzStylePerspectiveOriginValue::Inherit } }
     #[classattr]
     fn Initial() -> AzStylePerspectiveOriginValueEnumWrapper { AzStylePerspectiveOriginValueEnumWrapper { inner: AzStylePerspectiveOriginValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzStylePerspectiveOrigin) -> AzStylePerspectiveOriginValueEnumWrapper { AzStylePerspectiveOriginValueEnumWrapper { inner: AzStylePerspectiveOriginValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStylePerspectiveOriginValue;
@@ -22371,6 +25815,104 @@ impl PyObjectProtocol for AzStyleMixBlendModeValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleIsolationValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleIsolationValueEnumWrapper { AzStyleIsolationValueEnumWrapper { inner: AzStyleIsolationValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleIsolationValueEnumWrapper { AzStyleIsolationValueEnumWrapper { inner: AzStyleIsolationValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleIsolationValueEnumWrapper { AzStyleIsolationValueEnumWrapper { inner: AzStyleIsolationValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleIsolationValueEnumWrapper { AzStyleIsolationValueEnumWrapper { inner: AzStyleIsolationValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleIsolationEnumWrapper) -> AzStyleIsolationValueEnumWrapper { AzStyleIsolationValueEnumWrapper { inner: AzStyleIsolationValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleIsolationValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleIsolationValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleIsolationValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleIsolationValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleIsolationValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleIsolationValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleIsolationEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleIsolationValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleIsolationValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleIsolationValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleImageRenderingValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleImageRenderingValueEnumWrapper { AzStyleImageRenderingValueEnumWrapper { inner: AzStyleImageRenderingValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleImageRenderingValueEnumWrapper { AzStyleImageRenderingValueEnumWrapper { inner: AzStyleImageRenderingValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleImageRenderingValueEnumWrapper { AzStyleImageRenderingValueEnumWrapper { inner: AzStyleImageRenderingValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleImageRenderingValueEnumWrapper { AzStyleImageRenderingValueEnumWrapper { inner: AzStyleImageRenderingValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleImageRenderingEnumWrapper) -> AzStyleImageRenderingValueEnumWrapper { AzStyleImageRenderingValueEnumWrapper { inner: AzStyleImageRenderingValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleImageRenderingValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleImageRenderingValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleImageRenderingValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleImageRenderingValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleImageRenderingValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleImageRenderingValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleImageRenderingEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleImageRenderingValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleImageRenderingValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleImageRenderingValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleFilterVecBoxed {
+    #[new]
+    fn __new__(run_destructor: bool, value: AzStyleFilterVec) -> Self {
+        Self {
+            run_destructor,
+            value,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleFilterVecBoxed {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleFilterVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleFilterVecBoxed = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleFilterVecValueEnumWrapper {
     #[classattr]
@@ -22381,8 +25923,6 @@ impl AzStyleFilterVecValueEnumWrapper {
     fn Inherit() -> AzStyleFilterVecValueEnumWrapper { AzStyleFilterVecValueEnumWrapper { inner: AzStyleFilterVecValue::Inherit } }
     #[classattr]
     fn Initial() -> AzStyleFilterVecValueEnumWrapper { AzStyleFilterVecValueEnumWrapper { inner: AzStyleFilterVecValue::Initial } }
-    #[staticmethod]
-    fn Exact(v: AzStyleFilterVec) -> AzStyleFilterVecValueEnumWrapper { AzStyleFilterVecValueEnumWrapper { inner: AzStyleFilterVecValue::Exact(v) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzStyleFilterVecValue;
@@ -22420,6 +25960,10 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn TextAlign(v: AzStyleTextAlignValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TextAlign(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn VerticalAlign(v: AzStyleVerticalAlignValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::VerticalAlign(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn Direction(v: AzStyleDirectionValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Direction(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn LetterSpacing(v: AzStyleLetterSpacingValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::LetterSpacing(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn LineHeight(v: AzStyleLineHeightValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::LineHeight(unsafe { mem::transmute(v) }) } }
@@ -22472,6 +26016,14 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn AlignContent(v: AzLayoutAlignContentValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AlignContent(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn GridTemplateColumns(v: AzLayoutGridTemplateColumnsValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridTemplateColumns(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridTemplateRows(v: AzLayoutGridTemplateRowsValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridTemplateRows(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridColumn(v: AzLayoutGridColumnValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridColumn(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridRow(v: AzLayoutGridRowValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridRow(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn BackgroundContent(v: AzStyleBackgroundContentVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackgroundContent(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn BackgroundPosition(v: AzStyleBackgroundPositionVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackgroundPosition(unsafe { mem::transmute(v) }) } }
@@ -22554,11 +26106,29 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn MixBlendMode(v: AzStyleMixBlendModeValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::MixBlendMode(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn Isolation(v: AzStyleIsolationValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Isolation(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn ImageRendering(v: AzStyleImageRenderingValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ImageRendering(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn Filter(v: AzStyleFilterVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Filter(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn BackdropFilter(v: AzStyleFilterVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackdropFilter(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn TextShadow(v: AzStyleBoxShadowValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TextShadow(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionProperty(v: AzStyleTransitionPropertyValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionProperty(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionDuration(v: AzStyleTransitionDurationValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionDuration(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionTimingFunction(v: AzStyleTransitionTimingFunctionValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionTimingFunction(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionDelay(v: AzStyleTransitionDelayValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionDelay(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn CaretColor(v: AzStyleCaretColorValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::CaretColor(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn SelectionColor(v: AzStyleSelectionColorValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::SelectionColor(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn SelectionBackground(v: AzStyleSelectionBackgroundValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::SelectionBackground(unsafe { mem::transmute(v) }) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssProperty;
@@ -22570,6 +26140,8 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::FontSize(v) => Ok(vec!["FontSize".into_py(py), { let m: &AzStyleFontSizeValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::FontFamily(v) => Ok(vec!["FontFamily".into_py(py), { let m: &AzStyleFontFamilyVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::TextAlign(v) => Ok(vec!["TextAlign".into_py(py), { let m: &AzStyleTextAlignValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::VerticalAlign(v) => Ok(vec!["VerticalAlign".into_py(py), { let m: &AzStyleVerticalAlignValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::Direction(v) => Ok(vec!["Direction".into_py(py), { let m: &AzStyleDirectionValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::LetterSpacing(v) => Ok(vec!["LetterSpacing".into_py(py), { let m: &AzStyleLetterSpacingValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::LineHeight(v) => Ok(vec!["LineHeight".into_py(py), { let m: &AzStyleLineHeightValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::WordSpacing(v) => Ok(vec!["WordSpacing".into_py(py), { let m: &AzStyleWordSpacingValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
@@ -22596,6 +26168,10 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::JustifyContent(v) => Ok(vec!["JustifyContent".into_py(py), { let m: &AzLayoutJustifyContentValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::AlignItems(v) => Ok(vec!["AlignItems".into_py(py), { let m: &AzLayoutAlignItemsValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::AlignContent(v) => Ok(vec!["AlignContent".into_py(py), { let m: &AzLayoutAlignContentValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridTemplateColumns(v) => Ok(vec!["GridTemplateColumns".into_py(py), { let m: &AzLayoutGridTemplateColumnsValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridTemplateRows(v) => Ok(vec!["GridTemplateRows".into_py(py), { let m: &AzLayoutGridTemplateRowsValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridColumn(v) => Ok(vec!["GridColumn".into_py(py), { let m: &AzLayoutGridColumnValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridRow(v) => Ok(vec!["GridRow".into_py(py), { let m: &AzLayoutGridRowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackgroundContent(v) => Ok(vec!["BackgroundContent".into_py(py), { let m: &AzStyleBackgroundContentVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackgroundPosition(v) => Ok(vec!["BackgroundPosition".into_py(py), { let m: &AzStyleBackgroundPositionVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackgroundSize(v) => Ok(vec!["BackgroundSize".into_py(py), { let m: &AzStyleBackgroundSizeVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
@@ -22637,9 +26213,18 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::PerspectiveOrigin(v) => Ok(vec!["PerspectiveOrigin".into_py(py), { let m: &AzStylePerspectiveOriginValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackfaceVisibility(v) => Ok(vec!["BackfaceVisibility".into_py(py), { let m: &AzStyleBackfaceVisibilityValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::MixBlendMode(v) => Ok(vec!["MixBlendMode".into_py(py), { let m: &AzStyleMixBlendModeValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::Isolation(v) => Ok(vec!["Isolation".into_py(py), { let m: &AzStyleIsolationValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ImageRendering(v) => Ok(vec!["ImageRendering".into_py(py), { let m: &AzStyleImageRenderingValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::Filter(v) => Ok(vec!["Filter".into_py(py), { let m: &AzStyleFilterVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackdropFilter(v) => Ok(vec!["BackdropFilter".into_py(py), { let m: &AzStyleFilterVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::TextShadow(v) => Ok(vec!["TextShadow".into_py(py), { let m: &AzStyleBoxShadowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionProperty(v) => Ok(vec!["TransitionProperty".into_py(py), { let m: &AzStyleTransitionPropertyValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionDuration(v) => Ok(vec!["TransitionDuration".into_py(py), { let m: &AzStyleTransitionDurationValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionTimingFunction(v) => Ok(vec!["TransitionTimingFunction".into_py(py), { let m: &AzStyleTransitionTimingFunctionValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionDelay(v) => Ok(vec!["TransitionDelay".into_py(py), { let m: &AzStyleTransitionDelayValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::CaretColor(v) => Ok(vec!["CaretColor".into_py(py), { let m: &AzStyleCaretColorValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::SelectionColor(v) => Ok(vec!["SelectionColor".into_py(py), { let m: &AzStyleSelectionColorValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::SelectionBackground(v) => Ok(vec!["SelectionBackground".into_py(py), { let m: &AzStyleSelectionBackgroundValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
         }
     }
 }
@@ -33373,6 +36958,20 @@ impl PyObjectProtocol for AzThread {
     }
 }
 
+#[pymethods]
+impl AzPendingThread {
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzPendingThread {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::thread::PendingThread = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::thread::PendingThread = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzThreadSender {
     fn send(&mut self, msg: AzThreadReceiveMsgEnumWrapper) -> bool {
@@ -33757,6 +37356,97 @@ impl PyObjectProtocol for AzString {
     }
 }
 
+#[pymethods]
+impl AzTextCursor {
+    #[staticmethod]
+    fn start() -> AzTextCursor {
+        unsafe { mem::transmute(crate::AzTextCursor_start()) }
+    }
+    #[staticmethod]
+    fn end(text: String) -> AzTextCursor {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTextCursor_end(
+            mem::transmute(text),
+        )) }
+    }
+    #[staticmethod]
+    fn from_byte_offset(text: String, byte_offset: usize) -> AzTextCursor {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTextCursor_fromByteOffset(
+            mem::transmute(text),
+            mem::transmute(byte_offset),
+        )) }
+    }
+    fn next_grapheme(&self, text: String) -> AzTextCursor {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTextCursor_nextGrapheme(
+            mem::transmute(self),
+            mem::transmute(text),
+        )) }
+    }
+    fn prev_grapheme(&self, text: String) -> AzTextCursor {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTextCursor_prevGrapheme(
+            mem::transmute(self),
+            mem::transmute(text),
+        )) }
+    }
+    fn next_word(&self, text: String) -> AzTextCursor {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTextCursor_nextWord(
+            mem::transmute(self),
+            mem::transmute(text),
+        )) }
+    }
+    fn prev_word(&self, text: String) -> AzTextCursor {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTextCursor_prevWord(
+            mem::transmute(self),
+            mem::transmute(text),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextCursor {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::text_cursor::TextCursor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::text_cursor::TextCursor = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextSelection {
+    #[staticmethod]
+    fn collapsed(cursor: AzTextCursor) -> AzTextSelection {
+        unsafe { mem::transmute(crate::AzTextSelection_collapsed(
+            mem::transmute(cursor),
+        )) }
+    }
+    fn is_collapsed(&self) -> bool {
+        unsafe { mem::transmute(crate::AzTextSelection_isCollapsed(
+            mem::transmute(self),
+        )) }
+    }
+    fn normalized(&self) -> AzTextSelection {
+        unsafe { mem::transmute(crate::AzTextSelection_normalized(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextSelection {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::text_cursor::TextSelection = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::text_cursor::TextSelection = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzListViewRowVec {
     /// Creates a new `ListViewRowVec` from a Python array
@@ -34392,6 +38082,31 @@ impl PyObjectProtocol for AzVideoModeVec {
     }
 }
 
+#[pymethods]
+impl AzAcceleratorCallbackVec {
+    /// Creates a new `AcceleratorCallbackVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzAcceleratorCallback>) -> Self {
+        let m: azul_core::window::AcceleratorCallbackVec = azul_core::window::AcceleratorCallbackVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the AcceleratorCallback as a Python array
+    fn array(&self) -> Vec<AzAcceleratorCallback> {
+        let m: &azul_core::window::AcceleratorCallbackVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAcceleratorCallbackVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::AcceleratorCallbackVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::AcceleratorCallbackVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzDomVec {
     /// Creates a new `DomVec` from a Python array
@@ -34592,6 +38307,131 @@ impl PyObjectProtocol for AzStyleTransformVec {
     }
 }
 
+#[pymethods]
+impl AzGridTrackVec {
+    /// Creates a new `GridTrackEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzGridTrackEnumWrapper>) -> Self {
+        let m: azul_impl::css::GridTrackVec = azul_impl::css::GridTrackVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the GridTrackEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzGridTrackEnumWrapper> {
+        let m: &azul_impl::css::GridTrackVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssPropertyTypeVec {
+    /// Creates a new `CssPropertyTypeEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssPropertyTypeEnumWrapper>) -> Self {
+        let m: azul_impl::css::CssPropertyTypeVec = azul_impl::css::CssPropertyTypeVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssPropertyTypeEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzCssPropertyTypeEnumWrapper> {
+        let m: &azul_impl::css::CssPropertyTypeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssPropertyTypeVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssPropertyTypeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssPropertyTypeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssVariableVec {
+    /// Creates a new `CssVariableVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssVariable>) -> Self {
+        let m: azul_impl::css::CssVariableVec = azul_impl::css::CssVariableVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssVariable as a Python array
+    fn array(&self) -> Vec<AzCssVariable> {
+        let m: &azul_impl::css::CssVariableVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssVariableVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssVariableVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssVariableVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzMediaFeatureVec {
+    /// Creates a new `MediaFeatureEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzMediaFeatureEnumWrapper>) -> Self {
+        let m: azul_impl::css::MediaFeatureVec = azul_impl::css::MediaFeatureVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the MediaFeatureEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzMediaFeatureEnumWrapper> {
+        let m: &azul_impl::css::MediaFeatureVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMediaFeatureVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaFeatureVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaFeatureVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzMediaConditionVec {
+    /// Creates a new `MediaConditionVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzMediaCondition>) -> Self {
+        let m: azul_impl::css::MediaConditionVec = azul_impl::css::MediaConditionVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the MediaCondition as a Python array
+    fn array(&self) -> Vec<AzMediaCondition> {
+        let m: &azul_impl::css::MediaConditionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMediaConditionVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaConditionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaConditionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssPropertyVec {
     /// Creates a new `CssPropertyEnumWrapperVec` from a Python array
@@ -34605,6 +38445,22 @@ impl AzCssPropertyVec {
         let m: &azul_impl::css::CssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
     }
 
+    fn len(&self) -> usize {
+        unsafe { mem::transmute(crate::AzCssPropertyVec_len(
+            mem::transmute(self),
+        )) }
+    }
+    fn get(&self, index: usize) -> Option<AzCssPropertyEnumWrapper> {
+        let m: AzOptionCssProperty = unsafe { mem::transmute(crate::AzCssPropertyVec_get(
+            mem::transmute(self),
+            mem::transmute(index),
+        )) };
+        match m {
+            AzOptionCssProperty::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionCssProperty::None => None,
+        }
+
+    }
 }
 
 #[pyproto]
@@ -35194,6 +39050,22 @@ impl AzStringVec {
         let m: &azul_impl::css::StringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
     }
 
+    fn len(&self) -> usize {
+        unsafe { mem::transmute(crate::AzStringVec_len(
+            mem::transmute(self),
+        )) }
+    }
+    fn get(&self, index: usize) -> Option<String> {
+        let m: AzOptionString = unsafe { mem::transmute(crate::AzStringVec_get(
+            mem::transmute(self),
+            mem::transmute(index),
+        )) };
+        match m {
+            AzOptionString::Some(s) => Some({ let s: AzString = unsafe { mem::transmute(s) }; s.into() }),
+            AzOptionString::None => None,
+        }
+
+    }
 }
 
 #[pyproto]
@@ -35244,6 +39116,22 @@ impl AzNormalizedLinearColorStopVec {
         let m: &azul_impl::css::NormalizedLinearColorStopVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
     }
 
+    fn len(&self) -> usize {
+        unsafe { mem::transmute(crate::AzNormalizedLinearColorStopVec_len(
+            mem::transmute(self),
+        )) }
+    }
+    fn get(&self, index: usize) -> Option<AzNormalizedLinearColorStop> {
+        let m: AzOptionNormalizedLinearColorStop = unsafe { mem::transmute(crate::AzNormalizedLinearColorStopVec_get(
+            mem::transmute(self),
+            mem::transmute(index),
+        )) };
+        match m {
+            AzOptionNormalizedLinearColorStop::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionNormalizedLinearColorStop::None => None,
+        }
+
+    }
 }
 
 #[pyproto]
@@ -36181,6 +40069,36 @@ impl PyObjectProtocol for AzVideoModeVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzAcceleratorCallbackVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzAcceleratorCallbackVecDestructorEnumWrapper { AzAcceleratorCallbackVecDestructorEnumWrapper { inner: AzAcceleratorCallbackVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzAcceleratorCallbackVecDestructorEnumWrapper { AzAcceleratorCallbackVecDestructorEnumWrapper { inner: AzAcceleratorCallbackVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzAcceleratorCallbackVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzAcceleratorCallbackVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzAcceleratorCallbackVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzAcceleratorCallbackVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAcceleratorCallbackVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::AcceleratorCallbackVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::AcceleratorCallbackVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzDomVecDestructorEnumWrapper {
     #[classattr]
@@ -36421,6 +40339,156 @@ impl PyObjectProtocol for AzStyleTransformVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzGridTrackVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzGridTrackVecDestructorEnumWrapper { AzGridTrackVecDestructorEnumWrapper { inner: AzGridTrackVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzGridTrackVecDestructorEnumWrapper { AzGridTrackVecDestructorEnumWrapper { inner: AzGridTrackVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridTrackVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridTrackVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzGridTrackVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzGridTrackVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssPropertyTypeVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssPropertyTypeVecDestructorEnumWrapper { AzCssPropertyTypeVecDestructorEnumWrapper { inner: AzCssPropertyTypeVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssPropertyTypeVecDestructorEnumWrapper { AzCssPropertyTypeVecDestructorEnumWrapper { inner: AzCssPropertyTypeVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssPropertyTypeVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssPropertyTypeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssPropertyTypeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssPropertyTypeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssPropertyTypeVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssPropertyTypeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssPropertyTypeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssVariableVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssVariableVecDestructorEnumWrapper { AzCssVariableVecDestructorEnumWrapper { inner: AzCssVariableVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssVariableVecDestructorEnumWrapper { AzCssVariableVecDestructorEnumWrapper { inner: AzCssVariableVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssVariableVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssVariableVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssVariableVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssVariableVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssVariableVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssVariableVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssVariableVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzMediaFeatureVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzMediaFeatureVecDestructorEnumWrapper { AzMediaFeatureVecDestructorEnumWrapper { inner: AzMediaFeatureVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzMediaFeatureVecDestructorEnumWrapper { AzMediaFeatureVecDestructorEnumWrapper { inner: AzMediaFeatureVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzMediaFeatureVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzMediaFeatureVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzMediaFeatureVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzMediaFeatureVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMediaFeatureVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaFeatureVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaFeatureVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzMediaConditionVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzMediaConditionVecDestructorEnumWrapper { AzMediaConditionVecDestructorEnumWrapper { inner: AzMediaConditionVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzMediaConditionVecDestructorEnumWrapper { AzMediaConditionVecDestructorEnumWrapper { inner: AzMediaConditionVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzMediaConditionVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzMediaConditionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzMediaConditionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzMediaConditionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzMediaConditionVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaConditionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::MediaConditionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssPropertyVecDestructorEnumWrapper {
     #[classattr]
@@ -37470,6 +41538,35 @@ impl PyObjectProtocol for AzOptionSvgPointEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionMediaQueryEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionMediaQueryEnumWrapper { AzOptionMediaQueryEnumWrapper { inner: AzOptionMediaQuery::None } }
+    #[staticmethod]
+    fn Some(v: AzMediaQuery) -> AzOptionMediaQueryEnumWrapper { AzOptionMediaQueryEnumWrapper { inner: AzOptionMediaQuery::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionMediaQuery;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionMediaQuery::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionMediaQuery::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionMediaQueryEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::OptionMediaQuery = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::OptionMediaQuery = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionListViewOnRowClickEnumWrapper {
     #[classattr]
@@ -38340,6 +42437,35 @@ impl PyObjectProtocol for AzOptionCssPropertyEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionNormalizedLinearColorStopEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionNormalizedLinearColorStopEnumWrapper { AzOptionNormalizedLinearColorStopEnumWrapper { inner: AzOptionNormalizedLinearColorStop::None } }
+    #[staticmethod]
+    fn Some(v: AzNormalizedLinearColorStop) -> AzOptionNormalizedLinearColorStopEnumWrapper { AzOptionNormalizedLinearColorStopEnumWrapper { inner: AzOptionNormalizedLinearColorStop::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionNormalizedLinearColorStop;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionNormalizedLinearColorStop::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionNormalizedLinearColorStop::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionNormalizedLinearColorStopEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::OptionNormalizedLinearColorStop = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::OptionNormalizedLinearColorStop = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionPositionInfoEnumWrapper {
     #[classattr]
@@ -39411,6 +43537,35 @@ impl PyObjectProtocol for AzOptionLogicalPositionEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionLogicalRectEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionLogicalRectEnumWrapper { AzOptionLogicalRectEnumWrapper { inner: AzOptionLogicalRect::None } }
+    #[staticmethod]
+    fn Some(v: AzLogicalRect) -> AzOptionLogicalRectEnumWrapper { AzOptionLogicalRectEnumWrapper { inner: AzOptionLogicalRect::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionLogicalRect;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionLogicalRect::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionLogicalRect::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionLogicalRectEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionLogicalRect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionLogicalRect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionPhysicalPositionI32EnumWrapper {
     #[classattr]
@@ -39641,6 +43796,64 @@ impl PyObjectProtocol for AzOptionLogicalSizeEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionImeEventEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionImeEventEnumWrapper { AzOptionImeEventEnumWrapper { inner: AzOptionImeEvent::None } }
+    #[staticmethod]
+    fn Some(v: AzImeEventEnumWrapper) -> AzOptionImeEventEnumWrapper { AzOptionImeEventEnumWrapper { inner: AzOptionImeEvent::Some(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionImeEvent;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionImeEvent::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionImeEvent::Some(v) => Ok(vec!["Some".into_py(py), { let m: &AzImeEventEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionImeEventEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionImeEvent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionImeEvent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionImeCursorRangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionImeCursorRangeEnumWrapper { AzOptionImeCursorRangeEnumWrapper { inner: AzOptionImeCursorRange::None } }
+    #[staticmethod]
+    fn Some(v: AzImeCursorRange) -> AzOptionImeCursorRangeEnumWrapper { AzOptionImeCursorRangeEnumWrapper { inner: AzOptionImeCursorRange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionImeCursorRange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionImeCursorRange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionImeCursorRange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionImeCursorRangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionImeCursorRange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionImeCursorRange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionCharEnumWrapper {
     #[classattr]
@@ -40700,8 +44913,10 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzApp>()?;
     m.add_class::<AzAppConfig>()?;
     m.add_class::<AzAppLogLevelEnumWrapper>()?;
+    m.add_class::<AzRedrawBehaviorEnumWrapper>()?;
     m.add_class::<AzLayoutSolverEnumWrapper>()?;
     m.add_class::<AzSystemCallbacks>()?;
+    m.add_class::<AzApi>()?;
 
     m.add_class::<AzWindowCreateOptions>()?;
     m.add_class::<AzRendererOptions>()?;
@@ -40733,11 +44948,18 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTaskBarIcon>()?;
     m.add_class::<AzVirtualKeyCodeEnumWrapper>()?;
     m.add_class::<AzAcceleratorKeyEnumWrapper>()?;
+    m.add_class::<AzModifiers>()?;
+    m.add_class::<AzAccelerator>()?;
+    m.add_class::<AzAcceleratorId>()?;
+    m.add_class::<AzAcceleratorCallback>()?;
     m.add_class::<AzWindowSize>()?;
     m.add_class::<AzWindowFlags>()?;
     m.add_class::<AzWindowFrameEnumWrapper>()?;
     m.add_class::<AzDebugState>()?;
     m.add_class::<AzKeyboardState>()?;
+    m.add_class::<AzImeEventEnumWrapper>()?;
+    m.add_class::<AzImePreedit>()?;
+    m.add_class::<AzImeCursorRange>()?;
     m.add_class::<AzMouseCursorTypeEnumWrapper>()?;
     m.add_class::<AzCursorPositionEnumWrapper>()?;
     m.add_class::<AzMouseState>()?;
@@ -40840,6 +45062,12 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzCssNthChildPattern>()?;
     m.add_class::<AzStylesheet>()?;
     m.add_class::<AzCss>()?;
+    m.add_class::<AzCssVariable>()?;
+    m.add_class::<AzMediaQuery>()?;
+    m.add_class::<AzMediaCondition>()?;
+    m.add_class::<AzMediaFeatureEnumWrapper>()?;
+    m.add_class::<AzMediaOrientationEnumWrapper>()?;
+    m.add_class::<AzMediaColorSchemeEnumWrapper>()?;
     m.add_class::<AzCssPropertyTypeEnumWrapper>()?;
     m.add_class::<AzAnimationInterpolationFunctionEnumWrapper>()?;
     m.add_class::<AzInterpolateContext>()?;
@@ -40847,10 +45075,14 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzSizeMetricEnumWrapper>()?;
     m.add_class::<AzFloatValue>()?;
     m.add_class::<AzPixelValue>()?;
+    m.add_class::<AzCalcLength>()?;
+    m.add_class::<AzCalcLengthBoxed>()?;
     m.add_class::<AzPixelValueNoPercent>()?;
     m.add_class::<AzBoxShadowClipModeEnumWrapper>()?;
     m.add_class::<AzStyleBoxShadow>()?;
     m.add_class::<AzStyleMixBlendModeEnumWrapper>()?;
+    m.add_class::<AzStyleIsolationEnumWrapper>()?;
+    m.add_class::<AzStyleImageRenderingEnumWrapper>()?;
     m.add_class::<AzStyleFilterEnumWrapper>()?;
     m.add_class::<AzStyleBlur>()?;
     m.add_class::<AzStyleColorMatrix>()?;
@@ -40862,6 +45094,14 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzLayoutBoxSizingEnumWrapper>()?;
     m.add_class::<AzLayoutFlexDirectionEnumWrapper>()?;
     m.add_class::<AzLayoutDisplayEnumWrapper>()?;
+    m.add_class::<AzGridTrackEnumWrapper>()?;
+    m.add_class::<AzGridTrackRepeat>()?;
+    m.add_class::<AzGridLineEnumWrapper>()?;
+    m.add_class::<AzGridPlacement>()?;
+    m.add_class::<AzLayoutGridTemplateColumns>()?;
+    m.add_class::<AzLayoutGridTemplateRows>()?;
+    m.add_class::<AzLayoutGridColumn>()?;
+    m.add_class::<AzLayoutGridRow>()?;
     m.add_class::<AzLayoutFlexGrow>()?;
     m.add_class::<AzLayoutFlexShrink>()?;
     m.add_class::<AzLayoutFloatEnumWrapper>()?;
@@ -40945,10 +45185,29 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleTransformScale3D>()?;
     m.add_class::<AzStyleTransformSkew2D>()?;
     m.add_class::<AzStyleTextAlignEnumWrapper>()?;
+    m.add_class::<AzStyleDirectionEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionPropertyEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionDuration>()?;
+    m.add_class::<AzStyleTransitionDelay>()?;
+    m.add_class::<AzStepPositionEnumWrapper>()?;
+    m.add_class::<AzStyleCubicBezier>()?;
+    m.add_class::<AzStyleSteps>()?;
+    m.add_class::<AzStyleTransitionTimingFunctionEnumWrapper>()?;
+    m.add_class::<AzStyleVerticalAlignEnumWrapper>()?;
     m.add_class::<AzStyleTextColor>()?;
+    m.add_class::<AzStyleCaretColor>()?;
+    m.add_class::<AzStyleSelectionColor>()?;
+    m.add_class::<AzStyleSelectionBackground>()?;
     m.add_class::<AzStyleWordSpacing>()?;
+    m.add_class::<AzStyleBoxShadowBoxed>()?;
     m.add_class::<AzStyleBoxShadowValueEnumWrapper>()?;
     m.add_class::<AzLayoutAlignContentValueEnumWrapper>()?;
+    m.add_class::<AzLayoutGridTemplateColumnsBoxed>()?;
+    m.add_class::<AzLayoutGridTemplateColumnsValueEnumWrapper>()?;
+    m.add_class::<AzLayoutGridTemplateRowsBoxed>()?;
+    m.add_class::<AzLayoutGridTemplateRowsValueEnumWrapper>()?;
+    m.add_class::<AzLayoutGridColumnValueEnumWrapper>()?;
+    m.add_class::<AzLayoutGridRowValueEnumWrapper>()?;
     m.add_class::<AzLayoutAlignItemsValueEnumWrapper>()?;
     m.add_class::<AzLayoutBottomValueEnumWrapper>()?;
     m.add_class::<AzLayoutBoxSizingValueEnumWrapper>()?;
@@ -40978,10 +45237,15 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzLayoutWidthValueEnumWrapper>()?;
     m.add_class::<AzLayoutFlexWrapValueEnumWrapper>()?;
     m.add_class::<AzLayoutOverflowValueEnumWrapper>()?;
+    m.add_class::<AzScrollbarStyleBoxed>()?;
     m.add_class::<AzScrollbarStyleValueEnumWrapper>()?;
+    m.add_class::<AzStyleBackgroundContentVecBoxed>()?;
     m.add_class::<AzStyleBackgroundContentVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleBackgroundPositionVecBoxed>()?;
     m.add_class::<AzStyleBackgroundPositionVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleBackgroundRepeatVecBoxed>()?;
     m.add_class::<AzStyleBackgroundRepeatVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleBackgroundSizeVecBoxed>()?;
     m.add_class::<AzStyleBackgroundSizeVecValueEnumWrapper>()?;
     m.add_class::<AzStyleBorderBottomColorValueEnumWrapper>()?;
     m.add_class::<AzStyleBorderBottomLeftRadiusValueEnumWrapper>()?;
@@ -41000,20 +45264,39 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleBorderTopStyleValueEnumWrapper>()?;
     m.add_class::<AzLayoutBorderTopWidthValueEnumWrapper>()?;
     m.add_class::<AzStyleCursorValueEnumWrapper>()?;
+    m.add_class::<AzStyleFontFamilyVecBoxed>()?;
     m.add_class::<AzStyleFontFamilyVecValueEnumWrapper>()?;
     m.add_class::<AzStyleFontSizeValueEnumWrapper>()?;
     m.add_class::<AzStyleLetterSpacingValueEnumWrapper>()?;
     m.add_class::<AzStyleLineHeightValueEnumWrapper>()?;
     m.add_class::<AzStyleTabWidthValueEnumWrapper>()?;
     m.add_class::<AzStyleTextAlignValueEnumWrapper>()?;
+    m.add_class::<AzStyleDirectionValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionPropertyBoxed>()?;
+    m.add_class::<AzStyleTransitionPropertyValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionDurationValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionTimingFunctionBoxed>()?;
+    m.add_class::<AzStyleTransitionTimingFunctionValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionDelayValueEnumWrapper>()?;
+    m.add_class::<AzStyleCaretColorValueEnumWrapper>()?;
+    m.add_class::<AzStyleSelectionColorValueEnumWrapper>()?;
+    m.add_class::<AzStyleSelectionBackgroundValueEnumWrapper>()?;
+    m.add_class::<AzStyleVerticalAlignBoxed>()?;
+    m.add_class::<AzStyleVerticalAlignValueEnumWrapper>()?;
     m.add_class::<AzStyleTextColorValueEnumWrapper>()?;
     m.add_class::<AzStyleWordSpacingValueEnumWrapper>()?;
     m.add_class::<AzStyleOpacityValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransformVecBoxed>()?;
     m.add_class::<AzStyleTransformVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransformOriginBoxed>()?;
     m.add_class::<AzStyleTransformOriginValueEnumWrapper>()?;
+    m.add_class::<AzStylePerspectiveOriginBoxed>()?;
     m.add_class::<AzStylePerspectiveOriginValueEnumWrapper>()?;
     m.add_class::<AzStyleBackfaceVisibilityValueEnumWrapper>()?;
     m.add_class::<AzStyleMixBlendModeValueEnumWrapper>()?;
+    m.add_class::<AzStyleIsolationValueEnumWrapper>()?;
+    m.add_class::<AzStyleImageRenderingValueEnumWrapper>()?;
+    m.add_class::<AzStyleFilterVecBoxed>()?;
     m.add_class::<AzStyleFilterVecValueEnumWrapper>()?;
     m.add_class::<AzCssPropertyEnumWrapper>()?;
 
@@ -41241,6 +45524,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTerminateTimerEnumWrapper>()?;
     m.add_class::<AzThreadId>()?;
     m.add_class::<AzThread>()?;
+    m.add_class::<AzPendingThread>()?;
     m.add_class::<AzThreadSender>()?;
     m.add_class::<AzThreadReceiver>()?;
     m.add_class::<AzThreadSendMsgEnumWrapper>()?;
@@ -41260,6 +45544,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzFmtValueEnumWrapper>()?;
     m.add_class::<AzFmtArg>()?;
     m.add_class::<AzString>()?;
+    m.add_class::<AzTextCursor>()?;
+    m.add_class::<AzTextSelection>()?;
 
     m.add_class::<AzListViewRowVec>()?;
     m.add_class::<AzStyleFilterVec>()?;
@@ -41286,6 +45572,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzInlineTextHitVec>()?;
     m.add_class::<AzMonitorVec>()?;
     m.add_class::<AzVideoModeVec>()?;
+    m.add_class::<AzAcceleratorCallbackVec>()?;
     m.add_class::<AzDomVec>()?;
     m.add_class::<AzIdOrClassVec>()?;
     m.add_class::<AzNodeDataInlineCssPropertyVec>()?;
@@ -41294,6 +45581,11 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleBackgroundRepeatVec>()?;
     m.add_class::<AzStyleBackgroundSizeVec>()?;
     m.add_class::<AzStyleTransformVec>()?;
+    m.add_class::<AzGridTrackVec>()?;
+    m.add_class::<AzCssPropertyTypeVec>()?;
+    m.add_class::<AzCssVariableVec>()?;
+    m.add_class::<AzMediaFeatureVec>()?;
+    m.add_class::<AzMediaConditionVec>()?;
     m.add_class::<AzCssPropertyVec>()?;
     m.add_class::<AzSvgMultiPolygonVec>()?;
     m.add_class::<AzSvgSimpleNodeVec>()?;
@@ -41353,6 +45645,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzInlineTextHitVecDestructorEnumWrapper>()?;
     m.add_class::<AzMonitorVecDestructorEnumWrapper>()?;
     m.add_class::<AzVideoModeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzAcceleratorCallbackVecDestructorEnumWrapper>()?;
     m.add_class::<AzDomVecDestructorEnumWrapper>()?;
     m.add_class::<AzIdOrClassVecDestructorEnumWrapper>()?;
     m.add_class::<AzNodeDataInlineCssPropertyVecDestructorEnumWrapper>()?;
@@ -41361,6 +45654,11 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleBackgroundRepeatVecDestructorEnumWrapper>()?;
     m.add_class::<AzStyleBackgroundSizeVecDestructorEnumWrapper>()?;
     m.add_class::<AzStyleTransformVecDestructorEnumWrapper>()?;
+    m.add_class::<AzGridTrackVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssPropertyTypeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssVariableVecDestructorEnumWrapper>()?;
+    m.add_class::<AzMediaFeatureVecDestructorEnumWrapper>()?;
+    m.add_class::<AzMediaConditionVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssPropertyVecDestructorEnumWrapper>()?;
     m.add_class::<AzSvgMultiPolygonVecDestructorEnumWrapper>()?;
     m.add_class::<AzSvgSimpleNodeVecDestructorEnumWrapper>()?;
@@ -41397,6 +45695,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzNodeDataVecDestructorEnumWrapper>()?;
 
     m.add_class::<AzOptionSvgPointEnumWrapper>()?;
+    m.add_class::<AzOptionMediaQueryEnumWrapper>()?;
     m.add_class::<AzOptionListViewOnRowClickEnumWrapper>()?;
     m.add_class::<AzOptionListViewOnColumnClickEnumWrapper>()?;
     m.add_class::<AzOptionListViewOnLazyLoadScrollEnumWrapper>()?;
@@ -41427,6 +45726,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionMenuCallbackEnumWrapper>()?;
     m.add_class::<AzOptionVirtualKeyCodeComboEnumWrapper>()?;
     m.add_class::<AzOptionCssPropertyEnumWrapper>()?;
+    m.add_class::<AzOptionNormalizedLinearColorStopEnumWrapper>()?;
     m.add_class::<AzOptionPositionInfoEnumWrapper>()?;
     m.add_class::<AzOptionTimerIdEnumWrapper>()?;
     m.add_class::<AzOptionThreadIdEnumWrapper>()?;
@@ -41464,6 +45764,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionTaskBarIconEnumWrapper>()?;
     m.add_class::<AzOptionHwndHandleEnumWrapper>()?;
     m.add_class::<AzOptionLogicalPositionEnumWrapper>()?;
+    m.add_class::<AzOptionLogicalRectEnumWrapper>()?;
     m.add_class::<AzOptionPhysicalPositionI32EnumWrapper>()?;
     m.add_class::<AzOptionWindowIconEnumWrapper>()?;
     m.add_class::<AzOptionStringEnumWrapper>()?;
@@ -41472,6 +45773,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionF32EnumWrapper>()?;
     m.add_class::<AzOptionMouseCursorTypeEnumWrapper>()?;
     m.add_class::<AzOptionLogicalSizeEnumWrapper>()?;
+    m.add_class::<AzOptionImeEventEnumWrapper>()?;
+    m.add_class::<AzOptionImeCursorRangeEnumWrapper>()?;
     m.add_class::<AzOptionCharEnumWrapper>()?;
     m.add_class::<AzOptionVirtualKeyCodeEnumWrapper>()?;
     m.add_class::<AzOptionDomEnumWrapper>()?;
@@ -0,0 +1,59 @@
+//! Type information of `RefAny` for language bindings
+//!
+//! Helps debugging callbacks that receive a `RefAny` of the wrong type:
+//! the type ID and the type name are the ones passed to `AzRefAny_newC`
+//! (or generated by the `AZ_REFLECT` macros). `AzRefAny_strongCount`
+//! helps to find leaked copies.
+
+#[cfg(test)]
+mod tests {
+    use crate::{AzRefAny, AzRefAny_getTypeId, AzRefAny_getTypeName, AzRefAny_strongCount, AzString};
+    use core::ffi::c_void;
+
+    struct MyDataModel {
+        counter: u32,
+    }
+
+    extern "C" fn destruct_my_data_model(_: &mut c_void) {}
+
+    #[test]
+    fn test_get_type_name_and_id() {
+        let data = MyDataModel { counter: 5 };
+        let refany = AzRefAny::new_c(
+            &data as *const MyDataModel as *const c_void,
+            core::mem::size_of::<MyDataModel>(),
+            1234,
+            AzString::from_const_str("MyDataModel"),
+            destruct_my_data_model,
+        );
+
+        assert_eq!(AzRefAny_getTypeName(&refany).as_str(), "MyDataModel");
+        assert_eq!(AzRefAny_getTypeId(&refany), 1234);
+
+        // copies share the same type information
+        let copy = refany.clone();
+        assert_eq!(AzRefAny_getTypeName(&copy).as_str(), "MyDataModel");
+        assert_eq!(data.counter, 5);
+    }
+
+    #[test]
+    fn test_strong_count() {
+        let data = MyDataModel { counter: 0 };
+        let refany = AzRefAny::new_c(
+            &data as *const MyDataModel as *const c_void,
+            core::mem::size_of::<MyDataModel>(),
+            1234,
+            AzString::from_const_str("MyDataModel"),
+            destruct_my_data_model,
+        );
+        assert_eq!(AzRefAny_strongCount(&refany), 1);
+
+        let mut copy = crate::AzRefAny_deepCopy(&refany);
+        assert_eq!(AzRefAny_strongCount(&refany), 2);
+        assert_eq!(AzRefAny_strongCount(&copy), 2);
+
+        crate::AzRefAny_delete(&mut copy);
+        core::mem::forget(copy); // already destroyed, same as in C
+        assert_eq!(AzRefAny_strongCount(&refany), 1);
+    }
+}
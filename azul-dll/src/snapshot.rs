@@ -0,0 +1,122 @@
+//! Layout snapshots for UI regression tests in language bindings
+//!
+//! `AzTestHarness_serializeLayout` prints the layouted rectangles and
+//! computed styles of a DOM as deterministic text (see
+//! `azul_core::snapshot`), so that a test can lay out a window with the
+//! test harness and compare the text against a committed golden file
+//! instead of comparing rendered pixels. `AzTestHarness_diffSnapshots`
+//! formats the differing lines for the error message of a failed comparison.
+
+use crate::{AzCssPropertyTypeVec, AzDomId, AzOptionString, AzTestHarness};
+use azul_impl::snapshot;
+
+/// Serializes the layout result of `dom`, an empty `properties` list
+/// prints `snapshot::DEFAULT_SNAPSHOT_PROPERTIES`
+pub(crate) fn serialize_layout(harness: &AzTestHarness, dom: AzDomId, properties: AzCssPropertyTypeVec) -> AzOptionString {
+    let properties = properties.as_ref();
+    let properties = if properties.is_empty() {
+        snapshot::DEFAULT_SNAPSHOT_PROPERTIES
+    } else {
+        properties
+    };
+    harness
+        .get_layout_result(dom)
+        .map(|r| r.serialize_debug(properties).into())
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        az_test_harness, widgets::button::Button, AzApp, AzApp_createTestHarness,
+        AzContextMenuMouseButton, AzString, AzTestHarnessPtr, AzTestHarness_diffSnapshots,
+        AzTestHarness_getNodeRect, AzTestHarness_mouseDown, AzTestHarness_mouseMove,
+        AzTestHarness_mouseUp, AzTestHarness_resize, AzTestHarness_serializeLayout,
+        AzWindowCreateOptions,
+    };
+    use azul_core::id_tree::NodeId;
+    use azul_impl::{
+        callbacks::{CallbackInfo, DomNodeId, LayoutCallbackInfo, RefAny, Update},
+        css::Css,
+        dom::Dom,
+        resources::{AppConfig, LayoutSolverVersion},
+        styled_dom::{DomId, NodeHierarchyItemId, StyledDom},
+    };
+
+    // same as examples/rust/hello-world.rs
+    struct DataModel {
+        counter: usize,
+    }
+
+    extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+        let counter = match data.downcast_ref::<DataModel>() {
+            Some(d) => format!("{}", d.counter),
+            None => return StyledDom::default(),
+        };
+
+        let mut label = Dom::text(counter);
+        label.set_inline_style("font-size: 50px");
+
+        let mut button = Button::new("Update counter".into());
+        button.set_on_click(data.clone(), on_click);
+        let mut button = button.dom();
+        button.set_inline_style("flex-grow: 1");
+
+        Dom::body()
+            .with_child(label)
+            .with_child(button)
+            .style(Css::empty())
+    }
+
+    extern "C" fn on_click(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        match data.downcast_mut::<DataModel>() {
+            Some(mut d) => d.counter += 1,
+            None => return Update::DoNothing,
+        }
+        Update::RefreshDom
+    }
+
+    fn serialize(harness: AzTestHarnessPtr) -> AzString {
+        unsafe { AzTestHarness_serializeLayout(harness, DomId::ROOT_ID, Vec::new().into()) }
+            .into_option()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_counter_snapshot() {
+        let app = AzApp::new(RefAny::new(DataModel { counter: 0 }), AppConfig::new(LayoutSolverVersion::Default));
+        let harness = AzApp_createTestHarness(&app, AzWindowCreateOptions::new(layout));
+        assert!(!harness.is_null());
+        unsafe { AzTestHarness_resize(harness, 400.0, 300.0) };
+
+        // the output is stable: laying out the same DOM again prints the same text
+        let before = serialize(harness);
+        assert!(before.as_str().starts_with("body [0.00 0.00 400.00x300.00]"));
+        assert!(before.as_str().contains("body > p[0] ["));
+        assert!(before.as_str().contains("\"0\" | color: #000000ff; font-size: 50px;"));
+        unsafe { AzTestHarness_resize(harness, 400.0, 300.0) };
+        assert_eq!(AzTestHarness_diffSnapshots(before.clone(), serialize(harness)).into_option(), None);
+
+        // click the button, then move the cursor away from it (:hover styles)
+        let button = DomNodeId {
+            dom: DomId::ROOT_ID,
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(2))),
+        };
+        let rect = unsafe { AzTestHarness_getNodeRect(harness, button) }.into_option().unwrap();
+        unsafe {
+            AzTestHarness_mouseMove(harness, rect.origin.x + 1.0, rect.origin.y + 1.0);
+            AzTestHarness_mouseDown(harness, AzContextMenuMouseButton::Left);
+            AzTestHarness_mouseUp(harness, AzContextMenuMouseButton::Left);
+            AzTestHarness_mouseMove(harness, -1.0, -1.0);
+        }
+
+        // incrementing the counter only changes the line of the label
+        let diff = AzTestHarness_diffSnapshots(before, serialize(harness)).into_option().unwrap();
+        let changed = diff.as_str().lines().filter(|l| !l.starts_with("  ")).collect::<Vec<_>>();
+        assert!(changed.iter().all(|l| l.starts_with("- body > p[0] ") || l.starts_with("+ body > p[0] ")));
+        assert!(changed.iter().any(|l| l.starts_with("- ") && l.contains("\"0\"")));
+        assert!(changed.iter().any(|l| l.starts_with("+ ") && l.contains("\"1\"")));
+
+        unsafe { az_test_harness::delete(harness) };
+    }
+}
@@ -0,0 +1,154 @@
+//! Window-less event simulation for integration tests in language bindings
+//!
+//! `AzApp_createTestHarness` lays out the root window of an app without
+//! opening it. The injected mouse / keyboard events invoke the callbacks
+//! and restyle / relayout the window like a real event loop would, so
+//! that tests can check the hit nodes, the layouted rectangles and the
+//! application data afterwards. The `AzTestHarness_*` functions are
+//! generated from `api.json`, see `azul_desktop::test_harness`.
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        az_test_harness, widgets::button::Button, AzApp, AzAppConfig_setRedrawBehavior,
+        AzApp_createTestHarness, AzContextMenuMouseButton, AzRedrawBehavior,
+        AzTestHarness_advanceTime, AzTestHarness_getData, AzTestHarness_getFramesRendered,
+        AzTestHarness_getHitNode, AzTestHarness_getHitNodeCount, AzTestHarness_getNodeRect,
+        AzTestHarness_getNodeText, AzTestHarness_mouseDown, AzTestHarness_mouseMove,
+        AzTestHarness_mouseUp, AzTestHarness_resize, AzWindowCreateOptions,
+    };
+    use azul_core::id_tree::NodeId;
+    use azul_impl::{
+        callbacks::{CallbackInfo, DomNodeId, LayoutCallbackInfo, RefAny, Update},
+        css::Css,
+        dom::Dom,
+        resources::{AppConfig, LayoutSolverVersion},
+        styled_dom::{DomId, NodeHierarchyItemId, StyledDom},
+    };
+
+    // same as examples/rust/hello-world.rs
+    struct DataModel {
+        counter: usize,
+    }
+
+    extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+        let counter = match data.downcast_ref::<DataModel>() {
+            Some(d) => format!("{}", d.counter),
+            None => return StyledDom::default(),
+        };
+
+        let mut label = Dom::text(counter);
+        label.set_inline_style("font-size: 50px");
+
+        let mut button = Button::new("Update counter".into());
+        button.set_on_click(data.clone(), on_click);
+        let mut button = button.dom();
+        button.set_inline_style("flex-grow: 1");
+
+        Dom::body()
+            .with_child(label)
+            .with_child(button)
+            .style(Css::empty())
+    }
+
+    extern "C" fn on_click(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        let mut data = match data.downcast_mut::<DataModel>() {
+            Some(s) => s,
+            None => return Update::DoNothing,
+        };
+        data.counter += 1;
+        Update::RefreshDom
+    }
+
+    fn node(index: usize) -> DomNodeId {
+        DomNodeId {
+            dom: DomId::ROOT_ID,
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(index))),
+        }
+    }
+
+    #[test]
+    fn test_click_counter_button() {
+        let app = AzApp::new(RefAny::new(DataModel { counter: 0 }), AppConfig::new(LayoutSolverVersion::Default));
+        let harness = AzApp_createTestHarness(&app, AzWindowCreateOptions::new(layout));
+        assert!(!harness.is_null());
+
+        unsafe { AzTestHarness_resize(harness, 400.0, 300.0) };
+
+        // body > [label text, button > text]
+        let (label, button) = (node(1), node(2));
+        assert_eq!(unsafe { AzTestHarness_getNodeText(harness, label) }.into_option().unwrap().as_str(), "0");
+
+        let rect = unsafe { AzTestHarness_getNodeRect(harness, button) }.into_option().unwrap();
+        let center_x = rect.origin.x + rect.size.width / 2.0;
+        let center_y = rect.origin.y + rect.size.height / 2.0;
+
+        for _ in 0..3 {
+            unsafe { AzTestHarness_mouseMove(harness, center_x, center_y) };
+            let hit_nodes = (0..unsafe { AzTestHarness_getHitNodeCount(harness) })
+                .filter_map(|i| unsafe { AzTestHarness_getHitNode(harness, i) }.into_option())
+                .collect::<Vec<_>>();
+            assert!(hit_nodes.contains(&button));
+            unsafe {
+                AzTestHarness_mouseDown(harness, AzContextMenuMouseButton::Left);
+                AzTestHarness_mouseUp(harness, AzContextMenuMouseButton::Left);
+            }
+        }
+
+        assert_eq!(unsafe { AzTestHarness_getNodeText(harness, label) }.into_option().unwrap().as_str(), "3");
+        let counter = unsafe { AzTestHarness_getData(harness) }
+            .downcast_ref::<DataModel>()
+            .map(|d| d.counter);
+        assert_eq!(counter, Some(3));
+
+        unsafe { az_test_harness::delete(harness) };
+    }
+
+    #[test]
+    fn test_redraw_behavior() {
+        let frames_per_second = |behavior| {
+            let mut config = AppConfig::new(LayoutSolverVersion::Default);
+            AzAppConfig_setRedrawBehavior(&mut config, behavior);
+            let app = AzApp::new(RefAny::new(DataModel { counter: 0 }), config);
+            let harness = AzApp_createTestHarness(&app, AzWindowCreateOptions::new(layout));
+            assert!(!harness.is_null());
+
+            let first_frame = unsafe { AzTestHarness_getFramesRendered(harness) };
+            unsafe { AzTestHarness_advanceTime(harness, 1000) };
+            let frames = unsafe { AzTestHarness_getFramesRendered(harness) } - first_frame;
+
+            unsafe { az_test_harness::delete(harness) };
+            frames
+        };
+
+        // an idle app doesn't render unless the behavior is Unlimited
+        assert_eq!(frames_per_second(AzRedrawBehavior::OnEventOnly), 0);
+        assert_eq!(frames_per_second(AzRedrawBehavior::MaxFps(60)), 0);
+        assert_eq!(frames_per_second(AzRedrawBehavior::Unlimited), 1000);
+    }
+
+    #[test]
+    fn test_max_fps_merges_redraws() {
+        let mut config = AppConfig::new(LayoutSolverVersion::Default);
+        AzAppConfig_setRedrawBehavior(&mut config, AzRedrawBehavior::MaxFps(10));
+        let app = AzApp::new(RefAny::new(DataModel { counter: 0 }), config);
+        let harness = AzApp_createTestHarness(&app, AzWindowCreateOptions::new(layout));
+        assert!(!harness.is_null());
+        let frames = unsafe { AzTestHarness_getFramesRendered(harness) };
+
+        // both resizes happen within 100ms of the first frame: one frame, 100ms later
+        unsafe {
+            AzTestHarness_resize(harness, 500.0, 300.0);
+            AzTestHarness_resize(harness, 600.0, 300.0);
+        }
+        assert_eq!(unsafe { AzTestHarness_getFramesRendered(harness) }, frames);
+        unsafe { AzTestHarness_advanceTime(harness, 99) };
+        assert_eq!(unsafe { AzTestHarness_getFramesRendered(harness) }, frames);
+        unsafe { AzTestHarness_advanceTime(harness, 1) };
+        assert_eq!(unsafe { AzTestHarness_getFramesRendered(harness) }, frames + 1);
+        unsafe { AzTestHarness_advanceTime(harness, 1000) };
+        assert_eq!(unsafe { AzTestHarness_getFramesRendered(harness) }, frames + 1);
+
+        unsafe { az_test_harness::delete(harness) };
+    }
+}
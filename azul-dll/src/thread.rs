@@ -0,0 +1,223 @@
+//! Background threads for language bindings
+//!
+//! `AzPendingThread_new` pairs the data of a thread with the function that
+//! runs on the background thread, `AzCallbackInfo_startPendingThread` starts
+//! it from a callback. While it runs, the thread function can send progress
+//! messages to the main thread (`AzThreadSender_send`) and poll for messages
+//! from the main thread (`AzThreadReceiver_receive`). Once it returns, the
+//! `on_finish` callback runs on the main thread like any other callback.
+//!
+//! Ownership of the `AzRefAny`s:
+//!
+//! - `AzPendingThread_new` takes ownership of `data`. The returned
+//!   `AzPendingThread` belongs to the caller until it is passed to
+//!   `AzCallbackInfo_startPendingThread`, which consumes it (even if the
+//!   thread can't be started). A thread that is never started has to be
+//!   freed with `AzPendingThread_delete`.
+//! - `data` must not have any other copies: it is moved to the background
+//!   thread and only the thread function may access it while it runs.
+//! - After the thread function returns, `data` is moved back to the main
+//!   thread and passed to `on_finish`. It is dropped after `on_finish`
+//!   returns, unless `on_finish` keeps a copy (`AzRefAny_deepCopy`).
+//! - The sender and receiver are owned by azul and are only valid until the
+//!   thread function returns.
+//! - The first argument of the `WriteBack` callbacks sent by the thread
+//!   function is internal to azul, so the message data has to carry
+//!   everything the callback needs (e.g. a copy of the application data).
+//!
+//! `AzCallbackInfo_requestThreadStop` only asks the thread to stop: the
+//! thread function sees a `TerminateThread` message in
+//! `AzThreadReceiver_receive` and should return early. `on_finish` runs in
+//! either case.
+
+use crate::{
+    AzCallbackInfo, AzCallbackType, AzOptionThreadId, AzRefAny, AzThreadFnType, AzThreadReceiver,
+    AzThreadSender,
+};
+use azul_impl::{
+    callbacks::{CallbackInfo, RefAny, Update},
+    task::{ThreadReceiveMsg, ThreadWriteBackMsg},
+};
+
+/// Thread that has been created with `AzPendingThread_new`, but not started yet
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct PendingThread {
+    pub data: AzRefAny,
+    pub thread_fn: AzThreadFnType,
+}
+
+/// Data of the writeback callback that invokes `on_finish` on the main thread
+struct OnFinish {
+    callback: AzCallbackType,
+}
+
+/// Implementation of `AzCallbackInfo_startPendingThread`: returns `None`
+/// if the data of the thread has other copies
+pub(crate) fn start_pending_thread(
+    info: &mut AzCallbackInfo,
+    thread: PendingThread,
+    on_finish: AzCallbackType,
+) -> AzOptionThreadId {
+    if !thread.data.has_no_copies() {
+        return None.into();
+    }
+
+    let on_finish = RefAny::new(OnFinish { callback: on_finish });
+    info.start_thread(RefAny::new(thread), on_finish, run_pending_thread).into()
+}
+
+// runs on the background thread
+extern "C" fn run_pending_thread(
+    mut thread: RefAny,
+    mut sender: AzThreadSender,
+    mut receiver: AzThreadReceiver,
+) {
+    let (mut data, thread_fn) = match thread.downcast_mut::<PendingThread>() {
+        Some(mut t) => (core::mem::replace(&mut t.data, RefAny::new(())), t.thread_fn),
+        None => return,
+    };
+
+    (thread_fn)(&mut data, &mut sender, &mut receiver);
+
+    // the last message of the thread: hands the data back to the main thread
+    let msg = ThreadWriteBackMsg::new(finish_thread, data);
+    let _ = sender.send(ThreadReceiveMsg::WriteBack(msg));
+}
+
+// runs on the main thread, after all progress messages of the thread
+extern "C" fn finish_thread(
+    on_finish: &mut RefAny,
+    data: &mut RefAny,
+    info: &mut CallbackInfo,
+) -> Update {
+    let callback = match on_finish.downcast_ref::<OnFinish>() {
+        Some(s) => s.callback,
+        None => return Update::DoNothing,
+    };
+    (callback)(data, info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AzPendingThread_new, AzThreadReceiver_receive, AzThreadSender_send};
+    use azul_impl::{
+        callbacks::ThreadCallback,
+        task::{create_thread_libstd, ThreadSendMsg},
+    };
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct Download {
+        chunks: usize,
+    }
+
+    struct Progress {
+        chunks: usize,
+    }
+
+    extern "C" fn on_progress(_: &mut RefAny, _: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        Update::RefreshDom
+    }
+
+    extern "C" fn on_finish(_: &mut RefAny, _: &mut CallbackInfo) -> Update {
+        Update::RefreshDom
+    }
+
+    // downloads 3 chunks
+    extern "C" fn download(
+        data: &mut AzRefAny,
+        sender: &mut AzThreadSender,
+        _: &mut AzThreadReceiver,
+    ) {
+        for _ in 0..3 {
+            let chunks = match data.downcast_mut::<Download>() {
+                Some(mut d) => {
+                    d.chunks += 1;
+                    d.chunks
+                }
+                None => return,
+            };
+            let msg = ThreadWriteBackMsg::new(on_progress, RefAny::new(Progress { chunks }));
+            AzThreadSender_send(sender, ThreadReceiveMsg::WriteBack(msg));
+        }
+    }
+
+    // runs until the main thread asks it to stop
+    extern "C" fn wait_for_stop(
+        data: &mut AzRefAny,
+        _: &mut AzThreadSender,
+        receiver: &mut AzThreadReceiver,
+    ) {
+        loop {
+            let msg = AzThreadReceiver_receive(receiver).into_option();
+            if let Some(ThreadSendMsg::TerminateThread) = msg {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        if let Some(mut d) = data.downcast_mut::<Download>() {
+            d.chunks = 42;
+        }
+    }
+
+    /// Runs the thread like `CallbackInfo::start_thread` would, returns the
+    /// chunks of the progress messages and of the data passed to `on_finish`
+    fn run(thread_fn: AzThreadFnType, terminate: bool) -> (Vec<usize>, usize) {
+        let pending = AzPendingThread_new(RefAny::new(Download::default()), thread_fn);
+        let (progress, mut data) = run_pending(pending, terminate);
+        let chunks = data.downcast_ref::<Download>().unwrap().chunks;
+        (progress, chunks)
+    }
+
+    /// Returns the chunks of the progress messages and the data passed to `on_finish`
+    fn run_pending(pending: PendingThread, terminate: bool) -> (Vec<usize>, RefAny) {
+        let on_finish = RefAny::new(OnFinish { callback: on_finish });
+        let thread = create_thread_libstd(
+            RefAny::new(pending),
+            on_finish,
+            ThreadCallback { cb: run_pending_thread },
+        );
+
+        let thread = thread.ptr.lock().unwrap();
+        if terminate {
+            thread.sender.send(ThreadSendMsg::TerminateThread).unwrap();
+        }
+
+        let mut progress = Vec::new();
+        loop {
+            let mut msg = match thread.receiver.recv_timeout(Duration::from_secs(5)).unwrap() {
+                ThreadReceiveMsg::WriteBack(msg) => msg,
+                ThreadReceiveMsg::Update(_) => continue,
+            };
+            if msg.callback.cb as usize == finish_thread as usize {
+                return (progress, msg.data);
+            }
+            progress.push(msg.data.downcast_ref::<Progress>().unwrap().chunks);
+        }
+    }
+
+    #[test]
+    fn test_thread_finish() {
+        assert_eq!(run(download, false), (vec![1, 2, 3], 3));
+    }
+
+    #[test]
+    fn test_thread_request_stop() {
+        // on_finish also runs for a stopped thread
+        assert_eq!(run(wait_for_stop, true), (Vec::new(), 42));
+    }
+
+    #[test]
+    fn test_panic_sentinel_thread() {
+        use crate::error::PanicSentinel;
+
+        // returned by the exported functions after a panic: the thread
+        // function returns immediately, on_finish gets the empty data back
+        let (progress, data) = run_pending(PendingThread::panic_sentinel(), false);
+        assert!(progress.is_empty());
+        // zero-sized data can't be downcast, only compare the type
+        assert_eq!(data.get_type_name().as_str(), "()");
+    }
+}
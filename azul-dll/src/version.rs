@@ -0,0 +1,116 @@
+//! Version and feature detection for language bindings
+//!
+//! Bindings that load the library dynamically should call these functions
+//! before anything else and compare the result with the `AZ_API_VERSION_*`
+//! and `AZ_API_ABI_HASH` defines of the header they were generated from.
+//! The functions only use primitive types, so they can be called safely
+//! even if the rest of the API does not match.
+
+use core::ffi::c_void;
+
+/// Marker type for the `AzApi_*` functions, never constructed
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct Api {
+    /// reserved pointer (currently nullptr) for potential C extension
+    pub _reserved: *mut c_void,
+}
+
+/// Optional capabilities that can be queried with `AzApi_hasFeature`
+const FEATURES: &[(&str, bool)] = &[
+    ("accessibility", cfg!(feature = "accessibility")),
+    ("css-parser", cfg!(feature = "css_parser")),
+    ("font-loading", cfg!(feature = "font_loading")),
+    // the window has to be regenerated periodically, only implemented on Windows
+    ("hot-reload", cfg!(target_os = "windows")),
+    ("image-loading", cfg!(feature = "image_loading")),
+    ("logging", cfg!(feature = "logging")),
+    ("python-extension", cfg!(feature = "python-extension")),
+    // rendering always requires OpenGL
+    ("software-renderer", false),
+    ("svg", cfg!(feature = "svg")),
+    ("text-layout", cfg!(feature = "text_layout")),
+    ("wasm", cfg!(target_arch = "wasm32")),
+    ("xml", cfg!(feature = "xml")),
+];
+
+const VERSION_STRING: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+pub(crate) fn version_major() -> u32 {
+    env!("CARGO_PKG_VERSION_MAJOR").parse().unwrap_or(0)
+}
+
+pub(crate) fn version_minor() -> u32 {
+    env!("CARGO_PKG_VERSION_MINOR").parse().unwrap_or(0)
+}
+
+pub(crate) fn version_patch() -> u32 {
+    env!("CARGO_PKG_VERSION_PATCH").parse().unwrap_or(0)
+}
+
+pub(crate) fn version_string() -> *const u8 {
+    VERSION_STRING.as_ptr()
+}
+
+/// # Safety
+///
+/// `name_ptr` has to be NULL or point to `name_len` readable bytes
+pub(crate) unsafe fn has_feature(name_ptr: *const u8, name_len: usize) -> bool {
+    if name_ptr.is_null() {
+        return false;
+    }
+
+    let name = core::slice::from_raw_parts(name_ptr, name_len);
+    FEATURES
+        .iter()
+        .any(|(feature, enabled)| *enabled && feature.as_bytes() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AzApi_abiHash, AzApi_hasFeature, AzApi_versionMajor, AzApi_versionMinor,
+        AzApi_versionPatch, AzApi_versionString,
+    };
+
+    fn cargo_toml_version() -> &'static str {
+        include_str!("../Cargo.toml")
+            .lines()
+            .find_map(|line| line.strip_prefix("version = \""))
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_api_version_matches_cargo_toml() {
+        let version = cargo_toml_version();
+        let mut parts = version.split('.').map(|p| p.parse::<u32>().unwrap());
+
+        assert_eq!(AzApi_versionMajor(), parts.next().unwrap());
+        assert_eq!(AzApi_versionMinor(), parts.next().unwrap());
+        assert_eq!(AzApi_versionPatch(), parts.next().unwrap());
+
+        let version_string = unsafe {
+            core::ffi::CStr::from_ptr(AzApi_versionString() as *const core::ffi::c_char)
+        };
+        assert_eq!(version_string.to_str().unwrap(), version);
+    }
+
+    #[test]
+    fn test_api_abi_hash_matches_header() {
+        let define = format!("#define AZ_API_ABI_HASH 0x{:016x}ULL", AzApi_abiHash());
+        assert!(include_str!("../../api/c/azul.h").contains(&define));
+    }
+
+    #[test]
+    fn test_api_has_feature() {
+        let has_feature = |name: &str| unsafe { AzApi_hasFeature(name.as_ptr(), name.len()) };
+
+        assert_eq!(has_feature("svg"), cfg!(feature = "svg"));
+        assert_eq!(has_feature("wasm"), cfg!(target_arch = "wasm32"));
+        assert!(!has_feature("software-renderer"));
+        assert!(!has_feature("does-not-exist"));
+        assert!(!has_feature("sv"));
+        assert!(!unsafe { AzApi_hasFeature(core::ptr::null(), 3) });
+    }
+}
@@ -116,7 +116,7 @@ StyleBackgroundContent::LinearGradient(LinearGradient {
 
 static BUTTON_CONTAINER_WINDOWS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_display(LayoutDisplay::Block)),
-    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BUTTON_NORMAL_BACKGROUND))),
+    Normal(CssProperty::const_background_content(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(BUTTON_NORMAL_BACKGROUND)))),
     Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
     Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
     Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
@@ -146,13 +146,13 @@ static BUTTON_CONTAINER_WINDOWS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(3))),
     Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(3))),
 
-    Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BUTTON_HOVER_BACKGROUND_WINDOWS))),
+    Hover(CssProperty::const_background_content(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(BUTTON_HOVER_BACKGROUND_WINDOWS)))),
     Hover(CssProperty::const_border_top_color(StyleBorderTopColor { inner: WINDOWS_HOVER_BORDER })),
     Hover(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: WINDOWS_HOVER_BORDER })),
     Hover(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: WINDOWS_HOVER_BORDER })),
     Hover(CssProperty::const_border_right_color(StyleBorderRightColor { inner: WINDOWS_HOVER_BORDER })),
 
-    Active(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(BUTTON_ACTIVE_BACKGROUND_WINDOWS))),
+    Active(CssProperty::const_background_content(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(BUTTON_ACTIVE_BACKGROUND_WINDOWS)))),
     Active(CssProperty::const_border_top_color(StyleBorderTopColor { inner: WINDOWS_ACTIVE_BORDER })),
     Active(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: WINDOWS_ACTIVE_BORDER })),
     Active(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: WINDOWS_ACTIVE_BORDER })),
@@ -217,7 +217,7 @@ static BUTTON_LABEL_WINDOWS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
     Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
     Normal(CssProperty::const_text_color(StyleTextColor { inner: ColorU::BLACK })),
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
 ];
 
 static BUTTON_LABEL_LINUX: &[NodeDataInlineCssProperty] = &[
@@ -333,9 +333,17 @@ impl Button {
 #[cfg(test)]
 mod ui_test {
 
+    #[cfg(target_os = "windows")]
     static EXPECTED_1: &str = "
-<div data-az-node-id=\"0\"  class=\"__azul-native-button-container\"  tabindex=\"0\"  style=\"background: linear-gradient(from top to bottom, 0%#efefefff, 100%#e5e5e5ff);cursor: pointer;border-top-color: #acacacff;border-left-color: #acacacff;border-right-color: #acacacff;border-bottom-color: #acacacff;border-top-style: solid;border-left-style: solid;border-right-style: solid;border-bottom-style: solid;display: flex;padding-top: 5px;padding-bottom: 5px;padding-left: 5px;padding-right: 5px;border-top-width: 1px;border-left-width: 1px;border-right-width: 1px;border-bottom-width: 1px;flex-direction: column;flex-grow: 1;justify-content: center;\">
-    <p data-az-node-id=\"1\"  class=\"__azul-native-button-content\"  style=\"font-size: 13px;font-family: sans-serif;text-align: center;\">Hello</p>
+<div data-az-node-id=\"0\"  class=\"__azul-native-button-container\"  tabindex=\"0\"  style=\"overflow:visible; box-sizing:border-box; margin: 0px; padding: 0px; display: flex; flex-direction: column;background: linear-gradient(to bottom, #efefefff 0%, #e5e5e5ff 100%);cursor: pointer;border-top-color: #acacacff;border-left-color: #acacacff;border-right-color: #acacacff;border-bottom-color: #acacacff;border-top-style: solid;border-left-style: solid;border-right-style: solid;border-bottom-style: solid;display: block;padding-top: 3px;padding-bottom: 3px;padding-left: 5px;padding-right: 5px;border-top-width: 1px;border-left-width: 1px;border-right-width: 1px;border-bottom-width: 1px;flex-direction: column;flex-grow: 0;justify-content: center;\">
+    <p data-az-node-id=\"1\"  class=\"__azul-native-button-content\"  style=\"overflow:visible; box-sizing:border-box; margin: 0px; padding: 0px; display: flex; flex-direction: column;font-size: 11px;font-family: sans-serif;color: #000000ff;text-align: center;\">Hello</p>
+</div>";
+
+    // the button has no inline style on the other platforms yet
+    #[cfg(not(target_os = "windows"))]
+    static EXPECTED_1: &str = "
+<div data-az-node-id=\"0\"  class=\"__azul-native-button-container\"  tabindex=\"0\"  style=\"overflow:visible; box-sizing:border-box; margin: 0px; padding: 0px; display: flex; flex-direction: column;\">
+    <p data-az-node-id=\"1\"  class=\"__azul-native-button-content\"  style=\"overflow:visible; box-sizing:border-box; margin: 0px; padding: 0px; display: flex; flex-direction: column;\">Hello</p>
 </div>";
 
     #[test]
@@ -352,6 +360,7 @@ mod ui_test {
 
     // assert that two strings are the same, independent of line ending format
     fn assert_lines(a: &str, b: &str) {
+        assert_eq!(a.lines().count(), b.lines().count());
         for (line_a, line_b) in a.lines().zip(b.lines()) {
             assert_eq!(line_a, line_b);
         }
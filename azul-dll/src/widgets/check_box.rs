@@ -50,7 +50,7 @@ const FILL_THEME: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(FI
 const FILL_COLOR_BACKGROUND: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(FILL_THEME);
 
 static DEFAULT_CHECKBOX_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
-    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+    Normal(CssProperty::const_background_content(css_boxed_static!(BACKGROUND_COLOR_LIGHT))),
 
     Normal(CssProperty::const_display(LayoutDisplay::Block)),
     Normal(CssProperty::const_width(LayoutWidth::const_px(14))),
@@ -86,7 +86,7 @@ static DEFAULT_CHECKBOX_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
 static DEFAULT_CHECKBOX_CONTENT_STYLE_CHECKED: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_width(LayoutWidth::const_px(8))),
     Normal(CssProperty::const_height(LayoutHeight::const_px(8))),
-    Normal(CssProperty::const_background_content(FILL_COLOR_BACKGROUND)),
+    Normal(CssProperty::const_background_content(css_boxed_static!(FILL_COLOR_BACKGROUND))),
     Normal(CssProperty::const_opacity(StyleOpacity::const_new(100))),
 
     // padding: 2px
@@ -95,7 +95,7 @@ static DEFAULT_CHECKBOX_CONTENT_STYLE_CHECKED: &[NodeDataInlineCssProperty] = &[
 static DEFAULT_CHECKBOX_CONTENT_STYLE_UNCHECKED: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_width(LayoutWidth::const_px(8))),
     Normal(CssProperty::const_height(LayoutHeight::const_px(8))),
-    Normal(CssProperty::const_background_content(FILL_COLOR_BACKGROUND)),
+    Normal(CssProperty::const_background_content(css_boxed_static!(FILL_COLOR_BACKGROUND))),
     Normal(CssProperty::const_opacity(StyleOpacity::const_new(0))),
 
     // padding: 2px
@@ -111,7 +111,7 @@ impl ColorInput {
         };
 
         let mut style = self.style.into_library_owned_vec();
-        style.push(Normal(CssProperty::const_background_content(vec![
+        style.push(Normal(CssProperty::background_content(vec![
             StyleBackgroundContent::Color(self.state.inner.color)
         ].into())));
 
@@ -148,7 +148,7 @@ extern "C" fn on_color_input_clicked(data: &mut RefAny, info: &mut CallbackInfo)
 
     // Update the color in the data and the screen
     color_input.inner.color = new_color;
-    info.set_css_property(info.get_hit_node(), CssProperty::const_background_content(
+    info.set_css_property(info.get_hit_node(), CssProperty::background_content(
         vec![StyleBackgroundContent::Color(new_color)].into(),
     ));
 
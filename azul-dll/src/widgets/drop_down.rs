@@ -49,6 +49,7 @@ const STYLE_BACKGROUND_CONTENT_16125239329823337131_ITEMS: &[StyleBackgroundCont
         stops: NormalizedLinearColorStopVec::from_const_slice(LINEAR_COLOR_STOP_8010235203234495977_ITEMS),
     })
 ];
+const STYLE_BACKGROUND_CONTENT_16125239329823337131: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16125239329823337131_ITEMS);
 const STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 255, g: 255, b: 255, a: 255 })
 ];
@@ -86,7 +87,7 @@ const CSS_MATCH_10188117026223137249_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Focus(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 86, g: 157, b: 229, a: 255 } }))),
     NodeDataInlineCssProperty::Focus(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 86, g: 157, b: 229, a: 255 } }))),
     NodeDataInlineCssProperty::Focus(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 86, g: 157, b: 229, a: 255 } }))),
-    NodeDataInlineCssProperty::Focus(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16125239329823337131_ITEMS)))),
+    NodeDataInlineCssProperty::Focus(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(STYLE_BACKGROUND_CONTENT_16125239329823337131)))),
     // .__azul-native-dropdown-wrapper:active
     NodeDataInlineCssProperty::Active(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Active(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
@@ -100,7 +101,7 @@ const CSS_MATCH_10188117026223137249_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Active(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 86, g: 157, b: 229, a: 255 } }))),
     NodeDataInlineCssProperty::Active(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 86, g: 157, b: 229, a: 255 } }))),
     NodeDataInlineCssProperty::Active(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 86, g: 157, b: 229, a: 255 } }))),
-    NodeDataInlineCssProperty::Active(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16125239329823337131_ITEMS)))),
+    NodeDataInlineCssProperty::Active(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(STYLE_BACKGROUND_CONTENT_16125239329823337131)))),
     // .__azul-native-dropdown-wrapper:hover
     NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
@@ -114,7 +115,7 @@ const CSS_MATCH_10188117026223137249_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 126, g: 180, b: 234, a: 255 } }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 126, g: 180, b: 234, a: 255 } }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 126, g: 180, b: 234, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4857374953508308215_ITEMS)))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4857374953508308215_ITEMS))))),
     // .__azul-native-dropdown-wrapper
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
@@ -122,7 +123,7 @@ const CSS_MATCH_10188117026223137249_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(2) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::MinWidth(LayoutMinWidthValue::Exact(LayoutMinWidth { inner: PixelValue::const_px(120) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_18001933966972968559_ITEMS)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact_static(css_boxed_static!(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_18001933966972968559_ITEMS))))),
     NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(0) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row))),
     NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block))),
@@ -138,7 +139,7 @@ const CSS_MATCH_10188117026223137249_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 172, g: 172, b: 172, a: 255 } }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 172, g: 172, b: 172, a: 255 } }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 172, g: 172, b: 172, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_8560341490937422656_ITEMS))))
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_8560341490937422656_ITEMS)))))
 ];
 const CSS_MATCH_10188117026223137249: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_10188117026223137249_PROPERTIES);    
 
@@ -151,7 +152,7 @@ const CSS_MATCH_16432538576103237591: NodeDataInlineCssPropertyVec = NodeDataInl
 
 const CSS_MATCH_2883986488332352590_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // body
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS))))
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS)))))
 ];
 const CSS_MATCH_2883986488332352590: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_2883986488332352590_PROPERTIES);    
 
@@ -176,7 +177,7 @@ const CSS_MATCH_4687758758634879229: NodeDataInlineCssPropertyVec = NodeDataInli
 const CSS_MATCH_5369484915686807864_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-dropdown-arrow-content
     NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(6) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::Exact(StyleTransformVec::from_const_slice(STYLE_TRANSFORM_9499236770162623295_ITEMS)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::exact_static(css_boxed_static!(StyleTransformVec::from_const_slice(STYLE_TRANSFORM_9499236770162623295_ITEMS))))),
     NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(6) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(2) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
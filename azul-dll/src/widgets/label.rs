@@ -32,7 +32,7 @@ static LABEL_STYLE_WINDOWS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
     Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
 ];
 
 static LABEL_STYLE_LINUX: &[NodeDataInlineCssProperty] = &[
@@ -45,7 +45,7 @@ static LABEL_STYLE_LINUX: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(13))),
     Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
 ];
 
 static LABEL_STYLE_MAC: &[NodeDataInlineCssProperty] = &[
@@ -58,7 +58,7 @@ static LABEL_STYLE_MAC: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(12))),
     Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
 ];
 
 static LABEL_STYLE_OTHER: &[NodeDataInlineCssProperty] = &[
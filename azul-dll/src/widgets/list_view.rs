@@ -26,6 +26,7 @@ const STYLE_BACKGROUND_CONTENT_2444935983575427872_ITEMS: &[StyleBackgroundConte
 const STYLE_BACKGROUND_CONTENT_3010057533077499049_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 229, g: 243, b: 251, a: 255 })
 ];
+const STYLE_BACKGROUND_CONTENT_3010057533077499049: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_3010057533077499049_ITEMS);
 const STYLE_BACKGROUND_CONTENT_3839348353894170136_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 249, g: 250, b: 251, a: 255 })
 ];
@@ -62,6 +63,7 @@ const STYLE_TRANSFORM_17732691695785266054_ITEMS: &[StyleTransform] = &[
 const STYLE_FONT_FAMILY_8122988506401935406_ITEMS: &[StyleFontFamily] = &[
     StyleFontFamily::System(STRING_16146701490593874959)
 ];
+const STYLE_FONT_FAMILY_8122988506401935406: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS);
 const LINEAR_COLOR_STOP_513857305091467054_ITEMS: &[NormalizedLinearColorStop] = &[
     NormalizedLinearColorStop { offset: PercentageValue::const_new(0), color: ColorU { r: 255, g: 255, b: 255, a: 255 } },
 NormalizedLinearColorStop { offset: PercentageValue::const_new(50), color: ColorU { r: 255, g: 255, b: 255, a: 255 } },
@@ -77,7 +79,7 @@ NormalizedLinearColorStop { offset: PercentageValue::const_new(100), color: Colo
 
 const CSS_MATCH_1085706216385961159_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-list-header-arrow-down
-    NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::Exact(StyleTransformVec::from_const_slice(STYLE_TRANSFORM_6162542744002865382_ITEMS)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::exact_static(css_boxed_static!(StyleTransformVec::from_const_slice(STYLE_TRANSFORM_6162542744002865382_ITEMS))))),
     NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(3) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(3) }))),
@@ -88,41 +90,24 @@ const CSS_MATCH_1085706216385961159_PROPERTIES: &[NodeDataInlineCssProperty] = &
 ];
 const CSS_MATCH_1085706216385961159: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_1085706216385961159_PROPERTIES);
 
+const STYLE_BOX_SHADOW_3880033253539547789: StyleBoxShadow = StyleBoxShadow {
+    offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
+    color: ColorU { r: 206, g: 231, b: 244, a: 255 },
+    blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(5) },
+    spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
+    clip_mode: BoxShadowClipMode::Inset,
+};
 const CSS_MATCH_12498280255863106397_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-list-header-item:hover
     NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 154, g: 223, b: 254, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_6112684430356720596_ITEMS)))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_6112684430356720596_ITEMS))))),
     // .__azul_native-list-header-item:active
-    NodeDataInlineCssProperty::Active(CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(StyleBoxShadow {
-            offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
-            color: ColorU { r: 206, g: 231, b: 244, a: 255 },
-            blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(5) },
-            spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
-            clip_mode: BoxShadowClipMode::Inset,
-        }))),
-    NodeDataInlineCssProperty::Active(CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(StyleBoxShadow {
-            offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
-            color: ColorU { r: 206, g: 231, b: 244, a: 255 },
-            blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(5) },
-            spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
-            clip_mode: BoxShadowClipMode::Inset,
-        }))),
-    NodeDataInlineCssProperty::Active(CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(StyleBoxShadow {
-            offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
-            color: ColorU { r: 206, g: 231, b: 244, a: 255 },
-            blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(5) },
-            spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
-            clip_mode: BoxShadowClipMode::Inset,
-        }))),
-    NodeDataInlineCssProperty::Active(CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(StyleBoxShadow {
-            offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
-            color: ColorU { r: 206, g: 231, b: 244, a: 255 },
-            blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(5) },
-            spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
-            clip_mode: BoxShadowClipMode::Inset,
-        }))),
+    NodeDataInlineCssProperty::Active(CssProperty::BoxShadowBottom(StyleBoxShadowValue::exact_static(css_boxed_static!(STYLE_BOX_SHADOW_3880033253539547789)))),
+    NodeDataInlineCssProperty::Active(CssProperty::BoxShadowTop(StyleBoxShadowValue::exact_static(css_boxed_static!(STYLE_BOX_SHADOW_3880033253539547789)))),
+    NodeDataInlineCssProperty::Active(CssProperty::BoxShadowRight(StyleBoxShadowValue::exact_static(css_boxed_static!(STYLE_BOX_SHADOW_3880033253539547789)))),
+    NodeDataInlineCssProperty::Active(CssProperty::BoxShadowLeft(StyleBoxShadowValue::exact_static(css_boxed_static!(STYLE_BOX_SHADOW_3880033253539547789)))),
     NodeDataInlineCssProperty::Active(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Active(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Active(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
@@ -135,7 +120,7 @@ const CSS_MATCH_12498280255863106397_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Active(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 194, g: 205, b: 219, a: 255 } }))),
     NodeDataInlineCssProperty::Active(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 194, g: 205, b: 219, a: 255 } }))),
     NodeDataInlineCssProperty::Active(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 194, g: 205, b: 219, a: 255 } }))),
-    NodeDataInlineCssProperty::Active(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_3839348353894170136_ITEMS)))),
+    NodeDataInlineCssProperty::Active(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_3839348353894170136_ITEMS))))),
     // .__azul_native-list-header-item
     NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(7) }))),
@@ -152,14 +137,14 @@ const CSS_MATCH_12980082330151137475_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(7) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::MinWidth(LayoutMinWidthValue::Exact(LayoutMinWidth { inner: PixelValue::const_px(100) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS))))
+    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact_static(css_boxed_static!(STYLE_FONT_FAMILY_8122988506401935406))))
 ];
 const CSS_MATCH_12980082330151137475: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_12980082330151137475_PROPERTIES);
 
 const CSS_MATCH_13758717721055992976_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-list-header-arrow-down-inner
     NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(6) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::Exact(StyleTransformVec::from_const_slice(STYLE_TRANSFORM_16978981723642914576_ITEMS)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::exact_static(css_boxed_static!(StyleTransformVec::from_const_slice(STYLE_TRANSFORM_16978981723642914576_ITEMS))))),
     NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Hidden))),
     NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Hidden))),
     NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(6) })))
@@ -177,14 +162,14 @@ const CSS_MATCH_15315949193378715186_PROPERTIES: &[NodeDataInlineCssProperty] =
     // .__azul_native-list-header
     NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(25) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_7422581697888665934_ITEMS))))
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_7422581697888665934_ITEMS)))))
 ];
 const CSS_MATCH_15315949193378715186: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_15315949193378715186_PROPERTIES);
 
 const CSS_MATCH_15673486787900743642_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-list-header .__azul_native-list-header-item p
     NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact_static(css_boxed_static!(STYLE_FONT_FAMILY_8122988506401935406)))),
     NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Column))),
     NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
@@ -192,46 +177,29 @@ const CSS_MATCH_15673486787900743642_PROPERTIES: &[NodeDataInlineCssProperty] =
 ];
 const CSS_MATCH_15673486787900743642: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_15673486787900743642_PROPERTIES);
 
+const STYLE_BOX_SHADOW_4097981284048471024: StyleBoxShadow = StyleBoxShadow {
+    offset: [PixelValueNoPercent { inner: PixelValue::const_px(3) }, PixelValueNoPercent { inner: PixelValue::const_px(3) }],
+    color: ColorU { r: 60, g: 94, b: 114, a: 255 },
+    blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(10) },
+    spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
+    clip_mode: BoxShadowClipMode::Inset,
+};
 const CSS_MATCH_1574792189506859253_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-list-header-arrow-down-inner-deco
     NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(12) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::Exact(StyleTransformVec::from_const_slice(STYLE_TRANSFORM_17732691695785266054_ITEMS)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::exact_static(css_boxed_static!(StyleTransformVec::from_const_slice(STYLE_TRANSFORM_17732691695785266054_ITEMS))))),
     NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(12) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(StyleBoxShadow {
-            offset: [PixelValueNoPercent { inner: PixelValue::const_px(3) }, PixelValueNoPercent { inner: PixelValue::const_px(3) }],
-            color: ColorU { r: 60, g: 94, b: 114, a: 255 },
-            blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(10) },
-            spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
-            clip_mode: BoxShadowClipMode::Inset,
-        }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(StyleBoxShadow {
-            offset: [PixelValueNoPercent { inner: PixelValue::const_px(3) }, PixelValueNoPercent { inner: PixelValue::const_px(3) }],
-            color: ColorU { r: 60, g: 94, b: 114, a: 255 },
-            blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(10) },
-            spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
-            clip_mode: BoxShadowClipMode::Inset,
-        }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(StyleBoxShadow {
-            offset: [PixelValueNoPercent { inner: PixelValue::const_px(3) }, PixelValueNoPercent { inner: PixelValue::const_px(3) }],
-            color: ColorU { r: 60, g: 94, b: 114, a: 255 },
-            blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(10) },
-            spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
-            clip_mode: BoxShadowClipMode::Inset,
-        }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(StyleBoxShadow {
-            offset: [PixelValueNoPercent { inner: PixelValue::const_px(3) }, PixelValueNoPercent { inner: PixelValue::const_px(3) }],
-            color: ColorU { r: 60, g: 94, b: 114, a: 255 },
-            blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(10) },
-            spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(0) },
-            clip_mode: BoxShadowClipMode::Inset,
-        })))
+    NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(StyleBoxShadowValue::exact_static(css_boxed_static!(STYLE_BOX_SHADOW_4097981284048471024)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::exact_static(css_boxed_static!(STYLE_BOX_SHADOW_4097981284048471024)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::exact_static(css_boxed_static!(STYLE_BOX_SHADOW_4097981284048471024)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::exact_static(css_boxed_static!(STYLE_BOX_SHADOW_4097981284048471024))))
 ];
 const CSS_MATCH_1574792189506859253: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_1574792189506859253_PROPERTIES);
 
 const CSS_MATCH_17553577885456905601_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native_list-container
     NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-     NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_2444935983575427872_ITEMS))))
+     NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_2444935983575427872_ITEMS)))))
 ];
 const CSS_MATCH_17553577885456905601: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17553577885456905601_PROPERTIES);
 
@@ -241,7 +209,7 @@ const CSS_MATCH_2883986488332352590_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(5) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_11062356617965867290_ITEMS))))
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_11062356617965867290_ITEMS)))))
 ];
 const CSS_MATCH_2883986488332352590: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_2883986488332352590_PROPERTIES);
 
@@ -273,7 +241,7 @@ const CSS_MATCH_6827198030119836081_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 102, g: 167, b: 232, a: 255 } }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 102, g: 167, b: 232, a: 255 } }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 102, g: 167, b: 232, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_661302523448178568_ITEMS)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_661302523448178568_ITEMS))))),
     // .__azul_native-list-rows-row:hover
     NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
@@ -287,7 +255,7 @@ const CSS_MATCH_6827198030119836081_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 101, g: 181, b: 220, a: 255 } }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 101, g: 181, b: 220, a: 255 } }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 101, g: 181, b: 220, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_3010057533077499049_ITEMS)))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(STYLE_BACKGROUND_CONTENT_3010057533077499049)))),
     // .__azul_native-list-rows-row
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(0) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(0) }))),
@@ -324,7 +292,7 @@ const CSS_MATCH_7894335449545988724_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Focus(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 38, g: 160, b: 218, a: 255 } }))),
     NodeDataInlineCssProperty::Focus(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 38, g: 160, b: 218, a: 255 } }))),
     NodeDataInlineCssProperty::Focus(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 38, g: 160, b: 218, a: 255 } }))),
-    NodeDataInlineCssProperty::Focus(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_11098930083828139815_ITEMS)))),
+    NodeDataInlineCssProperty::Focus(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_11098930083828139815_ITEMS))))),
     // .__azul_native-list-rows-row:hover
     NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
@@ -338,7 +306,7 @@ const CSS_MATCH_7894335449545988724_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 101, g: 181, b: 220, a: 255 } }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 101, g: 181, b: 220, a: 255 } }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 101, g: 181, b: 220, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_3010057533077499049_ITEMS)))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(css_boxed_static!(STYLE_BACKGROUND_CONTENT_3010057533077499049)))),
     // .__azul_native-list-rows-row
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(0) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(0) }))),
@@ -366,7 +334,7 @@ const CSS_MATCH_7937682281721781688_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(7) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::MinWidth(LayoutMinWidthValue::Exact(LayoutMinWidth { inner: PixelValue::const_px(100) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS))))
+    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact_static(css_boxed_static!(STYLE_FONT_FAMILY_8122988506401935406))))
 ];
 const CSS_MATCH_7937682281721781688: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_7937682281721781688_PROPERTIES);
 
@@ -375,7 +343,7 @@ const CSS_MATCH_8793836789597026811_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(7) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::MinWidth(LayoutMinWidthValue::Exact(LayoutMinWidth { inner: PixelValue::const_px(100) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS))))
+    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact_static(css_boxed_static!(STYLE_FONT_FAMILY_8122988506401935406))))
 ];
 const CSS_MATCH_8793836789597026811: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_8793836789597026811_PROPERTIES);
 
@@ -794,6 +794,7 @@ fn render_node(
             b: 34,
             a: 255,
         })];
+    static STYLE_BACKGROUND_CONTENT_524016094839686509: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_524016094839686509_ITEMS));
     const STYLE_BACKGROUND_CONTENT_10430246856047584562_ITEMS: &[StyleBackgroundContent] =
         &[StyleBackgroundContent::LinearGradient(LinearGradient {
             direction: Direction::FromTo(DirectionCorners {
@@ -805,6 +806,7 @@ fn render_node(
                 LINEAR_COLOR_STOP_4373556077110009258_ITEMS,
             ),
         })];
+    static STYLE_BACKGROUND_CONTENT_10430246856047584562: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_10430246856047584562_ITEMS));
     const STYLE_BACKGROUND_CONTENT_11535310356736632656_ITEMS: &[StyleBackgroundContent] =
         &[StyleBackgroundContent::RadialGradient(RadialGradient {
             shape: Shape::Ellipse,
@@ -829,6 +831,7 @@ fn render_node(
                 LINEAR_COLOR_STOP_4373556077110009258_ITEMS,
             ),
         })];
+    static STYLE_BACKGROUND_CONTENT_11936041127084538304: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_11936041127084538304_ITEMS));
     const STYLE_BACKGROUND_CONTENT_15813232491335471489_ITEMS: &[StyleBackgroundContent] =
         &[StyleBackgroundContent::Color(ColorU {
             r: 0,
@@ -836,6 +839,7 @@ fn render_node(
             b: 0,
             a: 85,
         })];
+    static STYLE_BACKGROUND_CONTENT_15813232491335471489: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_15813232491335471489_ITEMS));
     const STYLE_BACKGROUND_CONTENT_17648039690071193942_ITEMS: &[StyleBackgroundContent] =
         &[StyleBackgroundContent::LinearGradient(LinearGradient {
             direction: Direction::FromTo(DirectionCorners {
@@ -847,6 +851,7 @@ fn render_node(
                 LINEAR_COLOR_STOP_7397113864565941600_ITEMS,
             ),
         })];
+    static STYLE_BACKGROUND_CONTENT_17648039690071193942: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_17648039690071193942_ITEMS));
     const STYLE_TRANSFORM_347117342922946953_ITEMS: &[StyleTransform] =
         &[StyleTransform::Translate(StyleTransformTranslate2D {
             x: PixelValue::const_px(200),
@@ -881,6 +886,9 @@ fn render_node(
             },
         },
     ];
+    static STYLE_FONT_FAMILY_8122988506401935406: StyleFontFamilyVecBoxed = StyleFontFamilyVecBoxed::new_static(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS));
+    static STYLE_TRANSFORM_14683950870521466298: StyleTransformVecBoxed = StyleTransformVecBoxed::new_static(StyleTransformVec::from_const_slice(STYLE_TRANSFORM_14683950870521466298_ITEMS));
+    static STYLE_FONT_FAMILY_11383897783350685780: StyleFontFamilyVecBoxed = StyleFontFamilyVecBoxed::new_static(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_11383897783350685780_ITEMS));
     const LINEAR_COLOR_STOP_7397113864565941600_ITEMS: &[NormalizedLinearColorStop] = &[
         NormalizedLinearColorStop {
             offset: PercentageValue::const_new(0),
@@ -957,9 +965,7 @@ fn render_node(
 
     const CSS_MATCH_11452431279102104133_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .node_input_connection_label
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(
-            StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS),
-        ))),
+        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact_static(&STYLE_FONT_FAMILY_8122988506401935406))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(
             StyleFontSize {
                 inner: PixelValue::const_px(12),
@@ -1069,9 +1075,7 @@ fn render_node(
             LayoutAlignItems::Center,
         ))),
         NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(
-            StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(
-                STYLE_BACKGROUND_CONTENT_524016094839686509_ITEMS,
-            )),
+            StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_524016094839686509),
         )),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(
             StyleBorderTopColorValue::Exact(StyleBorderTopColor {
@@ -1195,9 +1199,7 @@ fn render_node(
     const CSS_MATCH_12038890904436132038_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .node_output_connection_label_wrapper
         NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(
-            StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(
-                STYLE_BACKGROUND_CONTENT_10430246856047584562_ITEMS,
-            )),
+            StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_10430246856047584562),
         )),
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(
             LayoutPaddingLeft {
@@ -1304,9 +1306,7 @@ fn render_node(
 
     const CSS_MATCH_2008162367868363199_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .node_output_connection_label
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(
-            StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS),
-        ))),
+        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact_static(&STYLE_FONT_FAMILY_8122988506401935406))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(
             StyleFontSize {
                 inner: PixelValue::const_px(12),
@@ -1375,9 +1375,7 @@ fn render_node(
     const CSS_MATCH_4700400755767504372_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .node_input_connection_label_wrapper
         NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(
-            StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(
-                STYLE_BACKGROUND_CONTENT_11936041127084538304_ITEMS,
-            )),
+            StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_11936041127084538304),
         )),
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(
             LayoutPaddingRightValue::Exact(LayoutPaddingRight {
@@ -1414,15 +1412,36 @@ fn render_node(
     const CSS_MATCH_705881630351954657: NodeDataInlineCssPropertyVec =
         NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_705881630351954657_PROPERTIES);
 
+    static STYLE_BOX_SHADOW_11600529372361802990: StyleBoxShadowBoxed = StyleBoxShadowBoxed::new_static(StyleBoxShadow {
+        offset: [
+            PixelValueNoPercent {
+                inner: PixelValue::const_px(0),
+            },
+            PixelValueNoPercent {
+                inner: PixelValue::const_px(0),
+            },
+        ],
+        color: ColorU {
+            r: 229,
+            g: 57,
+            b: 53,
+            a: 255,
+        },
+        blur_radius: PixelValueNoPercent {
+            inner: PixelValue::const_px(2),
+        },
+        spread_radius: PixelValueNoPercent {
+            inner: PixelValue::const_px(0),
+        },
+        clip_mode: BoxShadowClipMode::Outset,
+    });
     const CSS_MATCH_7395766480280098891_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .node_close_button
         NodeDataInlineCssProperty::Normal(CssProperty::AlignItems(LayoutAlignItemsValue::Exact(
             LayoutAlignItems::Center,
         ))),
         NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(
-            StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(
-                STYLE_BACKGROUND_CONTENT_17648039690071193942_ITEMS,
-            )),
+            StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_17648039690071193942),
         )),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(
             StyleBorderTopColorValue::Exact(StyleBorderTopColor {
@@ -1504,112 +1523,19 @@ fn render_node(
                 inner: PixelValue::const_px(1),
             }),
         )),
-        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(
-            StyleBoxShadow {
-                offset: [
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                ],
-                color: ColorU {
-                    r: 229,
-                    g: 57,
-                    b: 53,
-                    a: 255,
-                },
-                blur_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(2),
-                },
-                spread_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(0),
-                },
-                clip_mode: BoxShadowClipMode::Outset,
-            },
+        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::exact_static(&STYLE_BOX_SHADOW_11600529372361802990,
         ))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(
-            StyleBoxShadow {
-                offset: [
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                ],
-                color: ColorU {
-                    r: 229,
-                    g: 57,
-                    b: 53,
-                    a: 255,
-                },
-                blur_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(2),
-                },
-                spread_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(0),
-                },
-                clip_mode: BoxShadowClipMode::Outset,
-            },
+        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::exact_static(&STYLE_BOX_SHADOW_11600529372361802990,
         ))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(
-            StyleBoxShadow {
-                offset: [
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                ],
-                color: ColorU {
-                    r: 229,
-                    g: 57,
-                    b: 53,
-                    a: 255,
-                },
-                blur_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(2),
-                },
-                spread_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(0),
-                },
-                clip_mode: BoxShadowClipMode::Outset,
-            },
+        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::exact_static(&STYLE_BOX_SHADOW_11600529372361802990,
         ))),
         NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(
-            StyleBoxShadowValue::Exact(StyleBoxShadow {
-                offset: [
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                ],
-                color: ColorU {
-                    r: 229,
-                    g: 57,
-                    b: 53,
-                    a: 255,
-                },
-                blur_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(2),
-                },
-                spread_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(0),
-                },
-                clip_mode: BoxShadowClipMode::Outset,
-            }),
+            StyleBoxShadowValue::exact_static(&STYLE_BOX_SHADOW_11600529372361802990),
         )),
         NodeDataInlineCssProperty::Normal(CssProperty::Cursor(StyleCursorValue::Exact(
             StyleCursor::Pointer,
         ))),
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(
-            StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_11383897783350685780_ITEMS),
-        ))),
+        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact_static(&STYLE_FONT_FAMILY_11383897783350685780))),
         NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(
             LayoutHeight {
                 inner: PixelValue::const_px(20),
@@ -1621,9 +1547,7 @@ fn render_node(
         NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(
             StyleTextAlign::Center,
         ))),
-        NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::Exact(
-            StyleTransformVec::from_const_slice(STYLE_TRANSFORM_14683950870521466298_ITEMS),
-        ))),
+        NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::exact_static(&STYLE_TRANSFORM_14683950870521466298))),
         NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(
             LayoutWidth {
                 inner: PixelValue::const_px(20),
@@ -1633,112 +1557,42 @@ fn render_node(
     const CSS_MATCH_7395766480280098891: NodeDataInlineCssPropertyVec =
         NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_7395766480280098891_PROPERTIES);
 
+    static STYLE_BOX_SHADOW_7889575549614143188: StyleBoxShadowBoxed = StyleBoxShadowBoxed::new_static(StyleBoxShadow {
+        offset: [
+            PixelValueNoPercent {
+                inner: PixelValue::const_px(0),
+            },
+            PixelValueNoPercent {
+                inner: PixelValue::const_px(0),
+            },
+        ],
+        color: ColorU {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 255,
+        },
+        blur_radius: PixelValueNoPercent {
+            inner: PixelValue::const_px(4),
+        },
+        spread_radius: PixelValueNoPercent {
+            inner: PixelValue::const_px(0),
+        },
+        clip_mode: BoxShadowClipMode::Inset,
+    });
     const CSS_MATCH_7432473243011547380_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .node_content_wrapper
         NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(
-            StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(
-                STYLE_BACKGROUND_CONTENT_15813232491335471489_ITEMS,
-            )),
+            StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_15813232491335471489),
         )),
-        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(
-            StyleBoxShadow {
-                offset: [
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                ],
-                color: ColorU {
-                    r: 0,
-                    g: 0,
-                    b: 0,
-                    a: 255,
-                },
-                blur_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(4),
-                },
-                spread_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(0),
-                },
-                clip_mode: BoxShadowClipMode::Inset,
-            },
+        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::exact_static(&STYLE_BOX_SHADOW_7889575549614143188,
         ))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(
-            StyleBoxShadow {
-                offset: [
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                ],
-                color: ColorU {
-                    r: 0,
-                    g: 0,
-                    b: 0,
-                    a: 255,
-                },
-                blur_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(4),
-                },
-                spread_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(0),
-                },
-                clip_mode: BoxShadowClipMode::Inset,
-            },
+        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::exact_static(&STYLE_BOX_SHADOW_7889575549614143188,
         ))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(
-            StyleBoxShadow {
-                offset: [
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                ],
-                color: ColorU {
-                    r: 0,
-                    g: 0,
-                    b: 0,
-                    a: 255,
-                },
-                blur_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(4),
-                },
-                spread_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(0),
-                },
-                clip_mode: BoxShadowClipMode::Inset,
-            },
+        NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::exact_static(&STYLE_BOX_SHADOW_7889575549614143188,
         ))),
         NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(
-            StyleBoxShadowValue::Exact(StyleBoxShadow {
-                offset: [
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                    PixelValueNoPercent {
-                        inner: PixelValue::const_px(0),
-                    },
-                ],
-                color: ColorU {
-                    r: 0,
-                    g: 0,
-                    b: 0,
-                    a: 255,
-                },
-                blur_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(4),
-                },
-                spread_radius: PixelValueNoPercent {
-                    inner: PixelValue::const_px(0),
-                },
-                clip_mode: BoxShadowClipMode::Inset,
-            }),
+            StyleBoxShadowValue::exact_static(&STYLE_BOX_SHADOW_7889575549614143188),
         )),
         NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(
             LayoutFlexGrow {
@@ -1815,7 +1669,7 @@ fn render_node(
                LayoutOverflowValue::Exact(LayoutOverflow::Visible)
            )),
            NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(
-               StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(
+               StyleBackgroundContentVecValue::exact(StyleBackgroundContentVec::from_const_slice(
                    STYLE_BACKGROUND_CONTENT_11535310356736632656_ITEMS,
                )),
            )),
@@ -1899,7 +1753,7 @@ fn render_node(
                    inner: PixelValue::const_px(1),
                }),
            )),
-           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(
+           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::exact(
                StyleBoxShadow {
                    offset: [
                        PixelValueNoPercent {
@@ -1924,7 +1778,7 @@ fn render_node(
                    clip_mode: BoxShadowClipMode::Outset,
                },
            ))),
-           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(
+           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::exact(
                StyleBoxShadow {
                    offset: [
                        PixelValueNoPercent {
@@ -1949,7 +1803,7 @@ fn render_node(
                    clip_mode: BoxShadowClipMode::Outset,
                },
            ))),
-           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(
+           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::exact(
                StyleBoxShadow {
                    offset: [
                        PixelValueNoPercent {
@@ -1975,7 +1829,7 @@ fn render_node(
                },
            ))),
            NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(
-               StyleBoxShadowValue::Exact(StyleBoxShadow {
+               StyleBoxShadowValue::exact(StyleBoxShadow {
                    offset: [
                        PixelValueNoPercent {
                            inner: PixelValue::const_px(0),
@@ -2013,7 +1867,7 @@ fn render_node(
            NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(
                LayoutDisplay::Block
            ))),
-           NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(
+           NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact(
                StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS),
            ))),
            NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(
@@ -2036,7 +1890,7 @@ fn render_node(
                    inner: PixelValue::const_px(10),
                }),
            )),
-           NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::Exact(
+           NodeDataInlineCssProperty::Normal(CssProperty::Transform(StyleTransformVecValue::exact(
                if scale_factor != 1.0 {
                     vec![
                          StyleTransform::Translate(node_transform),
@@ -2177,7 +2031,7 @@ fn render_node(
                                                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_vec(vec![
                                                        // .node_input
                                                        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(
-                                                           StyleBackgroundContentVecValue::Exact(vec![StyleBackgroundContent::Color(input_color)].into()),
+                                                           StyleBackgroundContentVecValue::exact(vec![StyleBackgroundContent::Color(input_color)].into()),
                                                        )),
                                                        NodeDataInlineCssProperty::Normal(CssProperty::Cursor(StyleCursorValue::Exact(
                                                            StyleCursor::Pointer,
@@ -2342,7 +2196,7 @@ fn render_node(
                                                    NodeDataInlineCssPropertyVec::from_vec(vec![
                                                        // .node_output
                                                        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(
-                                                           StyleBackgroundContentVecValue::Exact(vec![
+                                                           StyleBackgroundContentVecValue::exact(vec![
                                                                StyleBackgroundContent::Color(output_color)
                                                            ].into()),
                                                        )),
@@ -2479,7 +2333,7 @@ fn render_connections(node_graph: &NodeGraph, root_marker_nodedata: RefAny) -> D
                         .with_dataset(Some(cld_refany).into())
                         .with_inline_css_props(vec![
                             NodeDataInlineCssProperty::Normal(CssProperty::Transform(
-                                StyleTransformVecValue::Exact(vec![
+                                StyleTransformVecValue::exact(vec![
                                     StyleTransform::Translate(StyleTransformTranslate2D {
                                         x: PixelValue::px(node_graph.offset.x + rect.origin.x),
                                         y: PixelValue::px(node_graph.offset.y + rect.origin.y),
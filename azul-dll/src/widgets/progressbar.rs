@@ -113,28 +113,28 @@ impl ProgressBar {
            // .__azul-native-progress-bar-container
            NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: self.height.clone() }))),
            NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row))),
-           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(StyleBoxShadow {
+           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(StyleBoxShadowValue::exact(StyleBoxShadow {
                offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
                color: ColorU { r: 0, g: 0, b: 0, a: 9 },
                blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
                spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(2) },
                clip_mode: BoxShadowClipMode::Inset,
            }))),
-           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(StyleBoxShadow {
+           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::exact(StyleBoxShadow {
                offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
                color: ColorU { r: 0, g: 0, b: 0, a: 9 },
                blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
                spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(2) },
                clip_mode: BoxShadowClipMode::Inset,
            }))),
-           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(StyleBoxShadow {
+           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::exact(StyleBoxShadow {
                offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
                color: ColorU { r: 0, g: 0, b: 0, a: 9 },
                blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
                spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(2) },
                clip_mode: BoxShadowClipMode::Inset,
            }))),
-           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(StyleBoxShadow {
+           NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::exact(StyleBoxShadow {
                offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
                color: ColorU { r: 0, g: 0, b: 0, a: 9 },
                blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
@@ -157,7 +157,7 @@ impl ProgressBar {
            NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 178, g: 178, b: 178, a: 255 } }))),
            NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 178, g: 178, b: 178, a: 255 } }))),
            NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 178, g: 178, b: 178, a: 255 } }))),
-           NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(self.container_background.clone())))
+           NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact(self.container_background.clone())))
         ]))
         .with_ids_and_classes({
             const IDS_AND_CLASSES_10874511710181900075: &[IdOrClass] = &[
@@ -170,28 +170,28 @@ impl ProgressBar {
             .with_inline_css_props(NodeDataInlineCssPropertyVec::from_vec(vec![
                 // .__azul-native-progress-bar-bar
                 NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::new(flex_grow_bar) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(StyleBoxShadow {
+                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(StyleBoxShadowValue::exact(StyleBoxShadow {
                         offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
                         color: ColorU { r: 0, g: 51, b: 0, a: 51 },
                         blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
                         spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
                         clip_mode: BoxShadowClipMode::Inset,
                     }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(StyleBoxShadow {
+                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::exact(StyleBoxShadow {
                         offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
                         color: ColorU { r: 0, g: 51, b: 0, a: 51 },
                         blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
                         spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
                         clip_mode: BoxShadowClipMode::Inset,
                     }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(StyleBoxShadow {
+                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::exact(StyleBoxShadow {
                         offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
                         color: ColorU { r: 0, g: 51, b: 0, a: 51 },
                         blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
                         spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
                         clip_mode: BoxShadowClipMode::Inset,
                     }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(StyleBoxShadow {
+                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::exact(StyleBoxShadow {
                         offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
                         color: ColorU { r: 0, g: 51, b: 0, a: 51 },
                         blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
@@ -202,7 +202,7 @@ impl ProgressBar {
                 NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomLeftRadius(StyleBorderBottomLeftRadiusValue::Exact(StyleBorderBottomLeftRadius { inner: PixelValue::const_px(1) }))),
                 NodeDataInlineCssProperty::Normal(CssProperty::BorderTopRightRadius(StyleBorderTopRightRadiusValue::Exact(StyleBorderTopRightRadius { inner: PixelValue::const_px(1) }))),
                 NodeDataInlineCssProperty::Normal(CssProperty::BorderTopLeftRadius(StyleBorderTopLeftRadiusValue::Exact(StyleBorderTopLeftRadius { inner: PixelValue::const_px(1) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(self.bar_background.clone())))
+                NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact(self.bar_background.clone())))
             ]))
             .with_ids_and_classes({
                 const IDS_AND_CLASSES_16512648314570682783: &[IdOrClass] = &[
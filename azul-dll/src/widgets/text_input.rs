@@ -15,7 +15,7 @@ use azul_desktop::{
 use azul_core::{
     callbacks::{Animation, AnimationRepeatCount, InlineText, DomNodeId},
     task::SystemTimeDiff,
-    window::{KeyboardState, LogicalPosition, VirtualKeyCode},
+    window::{ImeEvent, KeyboardState, LogicalPosition, VirtualKeyCode},
 };
 use alloc::vec::Vec;
 use alloc::string::String;
@@ -52,9 +52,9 @@ static TEXT_CURSOR_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_position(LayoutPosition::Absolute)),
     Normal(CssProperty::const_width(LayoutWidth::const_px(1))),
     Normal(CssProperty::const_height(LayoutHeight::const_px(11))),
-    Normal(CssProperty::const_background_content(CURSOR_COLOR)),
+    Normal(CssProperty::const_background_content(css_boxed_static!(CURSOR_COLOR))),
     Normal(CssProperty::const_opacity(StyleOpacity::const_new(0))),
-    Normal(CssProperty::const_transform(StyleTransformVec::from_const_slice(TEXT_CURSOR_TRANSFORM))),
+    Normal(CssProperty::const_transform(css_boxed_static!(StyleTransformVec::from_const_slice(TEXT_CURSOR_TRANSFORM)))),
 ];
 
 // -- container style
@@ -66,7 +66,7 @@ static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_cursor(StyleCursor::Text)),
     Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
     Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
-    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+    Normal(CssProperty::const_background_content(css_boxed_static!(BACKGROUND_COLOR_LIGHT))),
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
 
     Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
@@ -118,7 +118,7 @@ static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
     Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
-    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+    Normal(CssProperty::const_background_content(css_boxed_static!(BACKGROUND_COLOR_LIGHT))),
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
 
     Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
@@ -149,7 +149,7 @@ static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
     Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
 
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
 
     // Hover(border-color: #4286f4;)
 
@@ -173,7 +173,7 @@ static TEXT_INPUT_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_cursor(StyleCursor::Text)),
     Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
     Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
-    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+    Normal(CssProperty::const_background_content(css_boxed_static!(BACKGROUND_COLOR_LIGHT))),
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
 
     Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
@@ -227,7 +227,7 @@ static TEXT_INPUT_LABEL_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_position(LayoutPosition::Relative)),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
 ];
 
 #[cfg(target_os = "linux")]
@@ -237,7 +237,7 @@ static TEXT_INPUT_LABEL_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_position(LayoutPosition::Relative)),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
 ];
 
 #[cfg(target_os = "macos")]
@@ -247,7 +247,7 @@ static TEXT_INPUT_LABEL_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_position(LayoutPosition::Relative)),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
 ];
 
 // --- placeholder
@@ -261,7 +261,7 @@ static TEXT_INPUT_PLACEHOLDER_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_left(LayoutLeft::const_px(2))),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
     Normal(CssProperty::const_opacity(StyleOpacity::const_new(100))),
 ];
 
@@ -274,7 +274,7 @@ static TEXT_INPUT_PLACEHOLDER_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_left(LayoutLeft::const_px(2))),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
     Normal(CssProperty::const_opacity(StyleOpacity::const_new(100))),
 ];
 
@@ -287,7 +287,7 @@ static TEXT_INPUT_PLACEHOLDER_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_left(LayoutLeft::const_px(2))),
     Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
     Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
-    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+    Normal(CssProperty::const_font_family(css_boxed_static!(SANS_SERIF_FAMILY))),
     Normal(CssProperty::const_opacity(StyleOpacity::const_new(100))),
 ];
 
@@ -529,6 +529,11 @@ impl TextInput {
                 data: state_ref.clone(),
                 callback: Callback { cb: default_on_text_input }
             },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::ImeCommit),
+                data: state_ref.clone(),
+                callback: Callback { cb: default_on_ime_commit }
+            },
             CallbackData {
                 event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
                 data: state_ref.clone(),
@@ -641,11 +646,41 @@ fn default_on_text_input_inner(
     text_input: &mut RefAny,
     info: &mut CallbackInfo
 ) -> Option<Update> {
-
-    let mut text_input = text_input.downcast_mut::<TextInputStateWrapper>()?;
     let keyboard_state = info.get_current_keyboard_state();
-
     let c = keyboard_state.current_char.into_option()?;
+    insert_text(text_input, info, &[c])
+}
+
+// committed text of the input method editor (IME), this text is
+// not delivered as On::TextInput
+extern "C"
+fn default_on_ime_commit(
+    text_input: &mut RefAny,
+    info: &mut CallbackInfo
+) -> Update {
+    default_on_ime_commit_inner(text_input, info)
+    .unwrap_or(Update::DoNothing)
+}
+
+fn default_on_ime_commit_inner(
+    text_input: &mut RefAny,
+    info: &mut CallbackInfo
+) -> Option<Update> {
+    let text = match info.get_ime_event().into_option()? {
+        ImeEvent::Commit(text) => text,
+        _ => return None,
+    };
+    let chars = text.as_str().chars().map(|c| c as u32).collect::<Vec<_>>();
+    insert_text(text_input, info, &chars)
+}
+
+fn insert_text(
+    text_input: &mut RefAny,
+    info: &mut CallbackInfo,
+    chars: &[u32],
+) -> Option<Update> {
+
+    let mut text_input = text_input.downcast_mut::<TextInputStateWrapper>()?;
     let placeholder_node_id = info.get_first_child(info.get_hit_node())?;
     let label_node_id = info.get_next_sibling(placeholder_node_id)?;
     let cursor_node_id = info.get_first_child(label_node_id)?;
@@ -658,10 +693,10 @@ fn default_on_text_input_inner(
 
         // inner_clone has the new text
         let mut inner_clone = text_input.inner.clone();
-        inner_clone.cursor_pos = inner_clone.cursor_pos.saturating_add(1);
+        inner_clone.cursor_pos = inner_clone.cursor_pos.saturating_add(chars.len());
         inner_clone.text = {
             let mut internal = inner_clone.text.clone().into_library_owned_vec();
-            internal.push(c);
+            internal.extend_from_slice(chars);
             internal.into()
         };
 
@@ -684,10 +719,10 @@ fn default_on_text_input_inner(
         // append to the text
         text_input.inner.text = {
             let mut internal = text_input.inner.text.clone().into_library_owned_vec();
-            internal.push(c);
+            internal.extend_from_slice(chars);
             internal.into()
         };
-        text_input.inner.cursor_pos = text_input.inner.cursor_pos.saturating_add(1);
+        text_input.inner.cursor_pos = text_input.inner.cursor_pos.saturating_add(chars.len());
 
         info.set_string_contents(label_node_id, text_input.inner.get_text().into());
     }
@@ -18,6 +18,7 @@ const STRING_16146701490593874959: AzString = AzString::from_const_str("sans-ser
 const STYLE_BACKGROUND_CONTENT_2444935983575427872_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 252, g: 252, b: 252, a: 255 })
 ];
+static STYLE_BACKGROUND_CONTENT_2444935983575427872: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_2444935983575427872_ITEMS));
 const STYLE_BACKGROUND_CONTENT_3386545019168565479_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::LinearGradient(LinearGradient {
         direction: Direction::FromTo(DirectionCorners { from: DirectionCorner::Top, to: DirectionCorner::Bottom }),
@@ -25,18 +26,23 @@ const STYLE_BACKGROUND_CONTENT_3386545019168565479_ITEMS: &[StyleBackgroundConte
         stops: NormalizedLinearColorStopVec::from_const_slice(LINEAR_COLOR_STOP_8524009933333352376_ITEMS),
     })
 ];
+static STYLE_BACKGROUND_CONTENT_3386545019168565479: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_3386545019168565479_ITEMS));
 const STYLE_BACKGROUND_CONTENT_11062356617965867290_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 240, g: 240, b: 240, a: 255 })
 ];
+static STYLE_BACKGROUND_CONTENT_11062356617965867290: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_11062356617965867290_ITEMS));
 const STYLE_BACKGROUND_CONTENT_15987977139837592998_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 0, g: 0, b: 0, a: 255 })
 ];
+static STYLE_BACKGROUND_CONTENT_15987977139837592998: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_15987977139837592998_ITEMS));
 const STYLE_BACKGROUND_CONTENT_16215943235627030128_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 0, g: 206, b: 209, a: 255 })
 ];
+static STYLE_BACKGROUND_CONTENT_16215943235627030128: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16215943235627030128_ITEMS));
 const STYLE_FONT_FAMILY_18001933966972968559_ITEMS: &[StyleFontFamily] = &[
     StyleFontFamily::System(STRING_16146701490593874959)
 ];
+static STYLE_FONT_FAMILY_18001933966972968559: StyleFontFamilyVecBoxed = StyleFontFamilyVecBoxed::new_static(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_18001933966972968559_ITEMS));
 const LINEAR_COLOR_STOP_8524009933333352376_ITEMS: &[NormalizedLinearColorStop] = &[
     NormalizedLinearColorStop { offset: PercentageValue::const_new(0), color: ColorU { r: 250, g: 251, b: 251, a: 255 } },
 NormalizedLinearColorStop { offset: PercentageValue::const_new(100), color: ColorU { r: 227, g: 227, b: 227, a: 255 } }
@@ -64,7 +70,7 @@ const CSS_MATCH_11045010670475678001_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
     NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(2) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_15987977139837592998_ITEMS))))
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_15987977139837592998)))
 ];
 const CSS_MATCH_11045010670475678001: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_11045010670475678001_PROPERTIES);    
 
@@ -94,7 +100,7 @@ const CSS_MATCH_13401060217940352039_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_18001933966972968559_ITEMS)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact_static(&STYLE_FONT_FAMILY_18001933966972968559))),
     NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
@@ -108,7 +114,7 @@ const CSS_MATCH_13401060217940352039_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 221, g: 221, b: 221, a: 170 } }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 221, g: 221, b: 221, a: 170 } }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 221, g: 221, b: 221, a: 170 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_2444935983575427872_ITEMS))))
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_2444935983575427872)))
 ];
 const CSS_MATCH_13401060217940352039: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_13401060217940352039_PROPERTIES);    
 
@@ -136,7 +142,7 @@ const CSS_MATCH_14249021884908901216_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16215943235627030128_ITEMS)))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_16215943235627030128))),
     // .__azul-native-tree-view-row-label-focusable-wrapper
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
@@ -197,7 +203,7 @@ const CSS_MATCH_15054086665198995512_PROPERTIES: &[NodeDataInlineCssProperty] =
     NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
     NodeDataInlineCssProperty::Hover(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
-    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16215943235627030128_ITEMS)))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_16215943235627030128))),
     // .__azul-native-tree-view-row-label-focusable-wrapper
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
@@ -260,7 +266,7 @@ const CSS_MATCH_2883986488332352590_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(5) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_11062356617965867290_ITEMS))))
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_11062356617965867290)))
 ];
 const CSS_MATCH_2883986488332352590: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_2883986488332352590_PROPERTIES);    
 
@@ -332,7 +338,7 @@ const CSS_MATCH_6438488809014395635_PROPERTIES: &[NodeDataInlineCssProperty] = &
     NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 145, g: 145, b: 145, a: 170 } }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 145, g: 145, b: 145, a: 170 } }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 145, g: 145, b: 145, a: 170 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_3386545019168565479_ITEMS)))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact_static(&STYLE_BACKGROUND_CONTENT_3386545019168565479))),
     NodeDataInlineCssProperty::Normal(CssProperty::AlignItems(LayoutAlignItemsValue::Exact(LayoutAlignItems::Center)))
 ];
 const CSS_MATCH_6438488809014395635: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6438488809014395635_PROPERTIES);    
@@ -0,0 +1,46 @@
+//! Window configuration from language bindings
+//!
+//! Every window has its own layout callback, stored in the
+//! `WindowCreateOptions`. Windows that are created with
+//! `AzWindowCreateOptions_default()` render an empty DOM until a
+//! callback is set with `AzWindowCreateOptions_setLayoutCallback`.
+
+#[cfg(test)]
+mod tests {
+    use crate::{AzWindowCreateOptions, AzWindowCreateOptions_setLayoutCallback};
+    use azul_impl::{
+        callbacks::{LayoutCallback, LayoutCallbackInfo, RefAny},
+        css::Css,
+        dom::Dom,
+        styled_dom::StyledDom,
+    };
+
+    extern "C" fn main_window(_: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+        StyledDom::default()
+    }
+
+    // different body than main_window, otherwise the linker may merge both functions
+    extern "C" fn settings_window(_: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+        Dom::div().style(Css::empty())
+    }
+
+    fn callback_ptr(cb: &LayoutCallback) -> usize {
+        match cb {
+            LayoutCallback::Raw(inner) => inner.cb as usize,
+            LayoutCallback::Marshaled(_) => 0,
+        }
+    }
+
+    #[test]
+    fn test_set_layout_callback_per_window() {
+        let mut main = AzWindowCreateOptions::default();
+        let mut settings = AzWindowCreateOptions::new(main_window);
+
+        AzWindowCreateOptions_setLayoutCallback(&mut main, main_window);
+        AzWindowCreateOptions_setLayoutCallback(&mut settings, settings_window);
+
+        assert_eq!(callback_ptr(&main.state.layout_callback), main_window as usize);
+        assert_eq!(callback_ptr(&settings.state.layout_callback), settings_window as usize);
+        assert_ne!(main.state.layout_callback, settings.state.layout_callback);
+    }
+}
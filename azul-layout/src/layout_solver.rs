@@ -17,7 +17,7 @@ use azul_core::{
         ParentWithNodeDepth, ChangedCssProperty, CssPropertyCache,
     },
     ui_solver::{
-        DEFAULT_FONT_SIZE_PX, ScrolledNodes, ResolvedOffsets,
+        ScrolledNodes, ResolvedOffsets,
         LayoutResult, PositionedRectangle, WhConstraint,
         WidthCalculatedRect, HeightCalculatedRect,
         HorizontalSolvedPosition, VerticalSolvedPosition,
@@ -46,25 +46,27 @@ const DEFAULT_FLEX_GROW_FACTOR: f32 = 0.0;
 pub struct WhConfig {
     pub width: WidthConfig,
     pub height: HeightConfig,
+    /// Computed font size in pixels, `em` lengths are resolved against it
+    pub em_size: f32,
 }
 
 #[derive(Debug, Default)]
 pub struct WidthConfig {
-    pub exact: Option<LayoutWidth>,
-    pub max: Option<LayoutMaxWidth>,
-    pub min: Option<LayoutMinWidth>,
+    pub exact: Option<LayoutWidthValue>,
+    pub max: Option<LayoutMaxWidthValue>,
+    pub min: Option<LayoutMinWidthValue>,
     pub overflow: Option<LayoutOverflow>,
 }
 
 #[derive(Debug, Default)]
 pub struct HeightConfig {
-    pub exact: Option<LayoutHeight>,
-    pub max: Option<LayoutMaxHeight>,
-    pub min: Option<LayoutMinHeight>,
+    pub exact: Option<LayoutHeightValue>,
+    pub max: Option<LayoutMaxHeightValue>,
+    pub min: Option<LayoutMinHeightValue>,
     pub overflow: Option<LayoutOverflow>,
 }
 
-fn precalculate_wh_config(styled_dom: &StyledDom) -> NodeDataContainer<WhConfig> {
+pub(crate) fn precalculate_wh_config(styled_dom: &StyledDom) -> NodeDataContainer<WhConfig> {
 
     use rayon::prelude::*;
 
@@ -84,17 +86,17 @@ fn precalculate_wh_config(styled_dom: &StyledDom) -> NodeDataContainer<WhConfig>
                         &node_data_container[node_id],
                         &node_id,
                         &styled_node.state
-                    ).and_then(|p| p.get_property().copied()),
+                    ).cloned(),
                     max: css_property_cache.get_max_width(
                         &node_data_container[node_id],
                         &node_id,
                         &styled_node.state
-                    ).and_then(|p| p.get_property().copied()),
+                    ).cloned(),
                     min: css_property_cache.get_min_width(
                         &node_data_container[node_id],
                         &node_id,
                         &styled_node.state
-                    ).and_then(|p| p.get_property().copied()),
+                    ).cloned(),
                     overflow: css_property_cache.get_overflow_x(
                         &node_data_container[node_id],
                         &node_id,
@@ -106,23 +108,24 @@ fn precalculate_wh_config(styled_dom: &StyledDom) -> NodeDataContainer<WhConfig>
                         &node_data_container[node_id],
                         &node_id,
                         &styled_node.state
-                    ).and_then(|p| p.get_property().copied()),
+                    ).cloned(),
                     max: css_property_cache.get_max_height(
                         &node_data_container[node_id],
                         &node_id,
                         &styled_node.state
-                    ).and_then(|p| p.get_property().copied()),
+                    ).cloned(),
                     min: css_property_cache.get_min_height(
                         &node_data_container[node_id],
                         &node_id,
                         &styled_node.state
-                    ).and_then(|p| p.get_property().copied()),
+                    ).cloned(),
                     overflow: css_property_cache.get_overflow_y(
                         &node_data_container[node_id],
                         &node_id,
                         &styled_node.state
                     ).and_then(|p| p.get_property().copied()),
                 },
+                em_size: styled_dom.get_em_size(node_id),
             }
         })
         .collect(),
@@ -137,16 +140,17 @@ macro_rules! determine_preferred {
     ///
     /// For example, if you have an image, the `preferred_inner_width` is the images width,
     /// if the node type is an text, the `preferred_inner_width` is the text height.
-    fn $fn_name(
+    pub(crate) fn $fn_name(
         config: &WhConfig,
         preferred_width: Option<f32>,
         parent_width: f32,
         parent_overflow: LayoutOverflow,
     ) -> WhConstraint {
 
-        let width     = config.$width.exact.as_ref().map(|x| x.inner.to_pixels(parent_width).max(0.0));
-        let min_width = config.$width.min.as_ref().map(|x| x.inner.to_pixels(parent_width).max(0.0));
-        let max_width = config.$width.max.as_ref().map(|x| x.inner.to_pixels(parent_width).max(0.0));
+        let ctx = UnitResolutionContext::with_em_size(parent_width, config.em_size);
+        let width     = config.$width.exact.as_ref().and_then(|x| x.resolve_length(&ctx, |v| v.inner)).map(|x| x.max(0.0));
+        let min_width = config.$width.min.as_ref().and_then(|x| x.resolve_length(&ctx, |v| v.inner)).map(|x| x.max(0.0));
+        let max_width = config.$width.max.as_ref().and_then(|x| x.resolve_length(&ctx, |v| v.inner)).map(|x| x.max(0.0));
 
         if let Some(width) = width {
             // ignore preferred_width if the width is set manually
@@ -178,7 +182,7 @@ macro_rules! determine_preferred {
                         let preferred_max = preferred_width.max(0.0);
                         if preferred_max > parent_width {
                             match parent_overflow {
-                                LayoutOverflow::Hidden | LayoutOverflow::Visible => WhConstraint::Between(preferred_max, core::f32::MAX),
+                                LayoutOverflow::Hidden | LayoutOverflow::Visible | LayoutOverflow::Clip => WhConstraint::Between(preferred_max, core::f32::MAX),
                                 LayoutOverflow::Auto | LayoutOverflow::Scroll => WhConstraint::EqualTo(parent_width),
                             }
                         } else {
@@ -186,7 +190,7 @@ macro_rules! determine_preferred {
                         }
                     } else {
                         match parent_overflow {
-                            LayoutOverflow::Hidden | LayoutOverflow::Visible => WhConstraint::Between(0.0, core::f32::MAX),
+                            LayoutOverflow::Hidden | LayoutOverflow::Visible | LayoutOverflow::Clip => WhConstraint::Between(0.0, core::f32::MAX),
                             LayoutOverflow::Auto | LayoutOverflow::Scroll => WhConstraint::Between(0.0, parent_width),
                         }
                     }
@@ -208,7 +212,7 @@ determine_preferred!(determine_preferred_width, width);
 // fn determine_preferred_height(layout: &RectLayout) -> Option<f32>
 determine_preferred!(determine_preferred_height, height);
 
-/// ```rust
+/// ```rust,ignore
 /// typed_arena!(
 ///     WidthCalculatedRect,
 ///     preferred_width,
@@ -256,7 +260,7 @@ macro_rules! typed_arena {(
     ///
     /// NOTE: Later on, this could maybe be a NodeDataContainer<&'a RectLayout>.
     #[must_use]
-    fn $from_rect_layout_arena_fn_name<'a>(
+    pub(crate) fn $from_rect_layout_arena_fn_name<'a>(
         wh_configs: &NodeDataContainerRef<'a, WhConfig>,
         offsets: &NodeDataContainerRef<'a, AllOffsets>,
         widths: &NodeDataContainerRef<'a, Option<f32>>,
@@ -305,21 +309,22 @@ macro_rules! typed_arena {(
                 // TODO: get the initial width of the rect content
                 $preferred_field: parent_width,
 
-                $margin_left: parent_offsets.margin.$left.as_ref().copied(),
-                $margin_right: parent_offsets.margin.$right.as_ref().copied(),
+                $margin_left: parent_offsets.margin.$left.as_ref().cloned(),
+                $margin_right: parent_offsets.margin.$right.as_ref().cloned(),
 
-                $padding_left: parent_offsets.padding.$left.as_ref().copied(),
-                $padding_right: parent_offsets.padding.$right.as_ref().copied(),
+                $padding_left: parent_offsets.padding.$left.as_ref().cloned(),
+                $padding_right: parent_offsets.padding.$right.as_ref().cloned(),
 
                 $border_left: parent_offsets.border_widths.$left.as_ref().copied(),
                 $border_right: parent_offsets.border_widths.$right.as_ref().copied(),
 
-                $left: parent_offsets.position.$left.as_ref().copied(),
-                $right: parent_offsets.position.$right.as_ref().copied(),
+                $left: parent_offsets.position.$left.as_ref().cloned(),
+                $right: parent_offsets.position.$right.as_ref().cloned(),
 
                 box_sizing: parent_offsets.box_sizing,
                 flex_grow_px: 0.0,
                 min_inner_size_px: parent_width.min_needed_space().unwrap_or(0.0),
+                em_size: nd.em_size,
             };
 
             let parent_overflow = wh_configs[parent_id].$width_or_height.overflow.unwrap_or_default();
@@ -334,21 +339,22 @@ macro_rules! typed_arena {(
                     // TODO: get the initial width of the rect content
                     $preferred_field: child_width,
 
-                    $margin_left: child_offsets.margin.$left.as_ref().copied(),
-                    $margin_right: child_offsets.margin.$right.as_ref().copied(),
+                    $margin_left: child_offsets.margin.$left.as_ref().cloned(),
+                    $margin_right: child_offsets.margin.$right.as_ref().cloned(),
 
-                    $padding_left: child_offsets.padding.$left.as_ref().copied(),
-                    $padding_right: child_offsets.padding.$right.as_ref().copied(),
+                    $padding_left: child_offsets.padding.$left.as_ref().cloned(),
+                    $padding_right: child_offsets.padding.$right.as_ref().cloned(),
 
                     $border_left: child_offsets.border_widths.$left.as_ref().copied(),
                     $border_right: child_offsets.border_widths.$right.as_ref().copied(),
 
-                    $left: child_offsets.position.$left.as_ref().copied(),
-                    $right: child_offsets.position.$right.as_ref().copied(),
+                    $left: child_offsets.position.$left.as_ref().cloned(),
+                    $right: child_offsets.position.$right.as_ref().cloned(),
 
                     box_sizing: child_offsets.box_sizing,
                     flex_grow_px: 0.0,
                     min_inner_size_px: child_width.min_needed_space().unwrap_or(0.0),
+                    em_size: nd.em_size,
                 };
                 let child_flex_basis = child.$get_flex_basis(parent_available_space).min(child_width.max_available_space().unwrap_or(core::f32::MAX));
                 child.min_inner_size_px = child.min_inner_size_px.max(child_flex_basis);
@@ -362,7 +368,7 @@ macro_rules! typed_arena {(
     /// Bubble the inner sizes to their parents -  on any parent nodes, fill out
     /// the width so that the `preferred_width` can contain the child nodes (if
     /// that doesn't violate the constraints of the parent)
-    fn $bubble_fn_name<'a, 'b>(
+    pub(crate) fn $bubble_fn_name<'a, 'b>(
         node_data: &mut NodeDataContainerRefMut<'b, $struct_name>,
         node_hierarchy: &NodeDataContainerRef<'a, NodeHierarchyItem>,
         layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
@@ -429,7 +435,7 @@ macro_rules! typed_arena {(
     ///
     /// The layout step doesn't account for the min_width
     /// and max_width constraints, so we have to adjust them manually
-    fn $apply_flex_grow_fn_name<'a, 'b>(
+    pub(crate) fn $apply_flex_grow_fn_name<'a, 'b>(
         node_data: &mut NodeDataContainer<$struct_name>,
         node_hierarchy: &NodeDataContainerRef<'a, NodeHierarchyItem>,
         layout_displays: &NodeDataContainerRef<'a, CssPropertyValue<LayoutDisplay>>,
@@ -904,10 +910,11 @@ macro_rules! get_position {(
         arena: &mut NodeDataContainer<$height_solved_position>,
         node_hierarchy: &NodeDataContainerRef<'a, NodeHierarchyItem>,
         layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
-        layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+        layout_directions: &NodeDataContainerRef<'a, PhysicalFlexDirection>,
         layout_justify_contents: &NodeDataContainerRef<'a, LayoutJustifyContent>,
         node_depths: &[ParentWithNodeDepth],
         solved_widths: &NodeDataContainerRef<'a, $width_layout>,
+        vertical_aligns: Option<&NodeDataContainerRef<'a, Option<StyleVerticalAlign>>>,
         parents_to_solve: &BTreeSet<NodeId>
     ) {
 
@@ -928,14 +935,14 @@ macro_rules! get_position {(
             let child_node_parent_width = node_hierarchy[child_id].parent_id()
             .map(|p| solved_widths[p].total()).unwrap_or(0.0) as f32;
 
-            let child_right = child_node.$right.and_then(|s| {
-                Some(s.get_property()?.inner.to_pixels(child_node_parent_width))
+            let child_right = child_node.$right.as_ref().and_then(|s| {
+                s.resolve_length(&UnitResolutionContext::with_em_size(child_node_parent_width, child_node.em_size), |v| v.inner)
             });
 
             if let Some(child_right) = child_right {
                 // align right / bottom of last relative parent
-                let child_margin_right = child_node.$margin_right.and_then(|x| {
-                    Some(x.get_property()?.inner.to_pixels(child_node_parent_width))
+                let child_margin_right = child_node.$margin_right.as_ref().and_then(|x| {
+                    x.resolve_length(&UnitResolutionContext::with_em_size(child_node_parent_width, child_node.em_size), |v| v.inner)
                 }).unwrap_or(0.0);
 
                 let last_relative_node_id = child_id
@@ -950,12 +957,12 @@ macro_rules! get_position {(
                 - child_right
             } else {
                 // align left / top of last relative parent
-                let child_left = child_node.$left.and_then(|s| {
-                    Some(s.get_property()?.inner.to_pixels(child_node_parent_width))
+                let child_left = child_node.$left.as_ref().and_then(|s| {
+                    s.resolve_length(&UnitResolutionContext::with_em_size(child_node_parent_width, child_node.em_size), |v| v.inner)
                 });
 
-                let child_margin_left = child_node.$margin_left.and_then(|x| {
-                    Some(x.get_property()?.inner.to_pixels(child_node_parent_width))
+                let child_margin_left = child_node.$margin_left.as_ref().and_then(|x| {
+                    x.resolve_length(&UnitResolutionContext::with_em_size(child_node_parent_width, child_node.em_size), |v| v.inner)
                 }).unwrap_or(0.0);
 
                 child_margin_left
@@ -982,11 +989,11 @@ macro_rules! get_position {(
 
             // width: increase X according to the main axis, Y according to the cross_axis
             let child_node = &solved_widths[child_id];
-            let child_margin_left = child_node.$margin_left.and_then(|x| {
-                Some(x.get_property()?.inner.to_pixels(parent_inner_width))
+            let child_margin_left = child_node.$margin_left.as_ref().and_then(|x| {
+                x.resolve_length(&UnitResolutionContext::with_em_size(parent_inner_width, child_node.em_size), |v| v.inner)
             }).unwrap_or(0.0);
-            let child_margin_right = child_node.$margin_right.and_then(|x| {
-                Some(x.get_property()?.inner.to_pixels(parent_inner_width))
+            let child_margin_right = child_node.$margin_right.as_ref().and_then(|x| {
+                x.resolve_length(&UnitResolutionContext::with_em_size(parent_inner_width, child_node.em_size), |v| v.inner)
             }).unwrap_or(0.0);
 
             if layout_positions[child_id] == LayoutPosition::Absolute {
@@ -1033,13 +1040,14 @@ macro_rules! get_position {(
             child_id: NodeId,
             parent_x_position: f32,
             parent_inner_width: f32,
+            vertical_align: Option<StyleVerticalAlign>,
             node_hierarchy: &NodeDataContainerRef<'a, NodeHierarchyItem>
         ) -> f32 {
 
             let child_node = &solved_widths[child_id];
 
-            let child_margin_left = child_node.$margin_left.and_then(|x| {
-                Some(x.get_property()?.inner.to_pixels(parent_inner_width))
+            let child_margin_left = child_node.$margin_left.as_ref().and_then(|x| {
+                x.resolve_length(&UnitResolutionContext::with_em_size(parent_inner_width, child_node.em_size), |v| v.inner)
             }).unwrap_or(0.0);
 
             if layout_positions[child_id] == LayoutPosition::Absolute {
@@ -1049,6 +1057,16 @@ macro_rules! get_position {(
                     layout_positions,
                     node_hierarchy,
                 )
+            } else if let Some(vertical_align) = vertical_align {
+                // the parent is one line: there are no font metrics here, so the
+                // baseline is the bottom edge of the line (like a line of images)
+                let child_margin_right = child_node.$margin_right.as_ref().and_then(|x| {
+                    x.resolve_length(&UnitResolutionContext::with_em_size(parent_inner_width, child_node.em_size), |v| v.inner)
+                }).unwrap_or(0.0);
+                let child_outer_width = child_margin_left + child_node.total() + child_margin_right;
+                parent_x_position
+                + vertical_align.resolve_offset(parent_inner_width, child_outer_width, parent_inner_width, child_node.em_size)
+                + child_margin_left
             } else {
                 parent_x_position + child_margin_left
             }
@@ -1146,6 +1164,7 @@ macro_rules! get_position {(
                             child_id,
                             parent_x_position,
                             parent_inner_width,
+                            vertical_aligns.and_then(|v| v[child_id]),
                             node_hierarchy,
                         );
                     }
@@ -1157,6 +1176,7 @@ macro_rules! get_position {(
                             child_id,
                             parent_x_position,
                             parent_inner_width,
+                            vertical_aligns.and_then(|v| v[child_id]),
                             node_hierarchy,
                         );
                     }
@@ -1171,7 +1191,7 @@ fn get_x_positions<'a>(
     solved_widths: &NodeDataContainerRef<'a, WidthCalculatedRect>,
     node_hierarchy: &NodeDataContainerRef<'a, NodeHierarchyItem>,
     layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
-    layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+    layout_directions: &NodeDataContainerRef<'a, PhysicalFlexDirection>,
     layout_justify_contents: &NodeDataContainerRef<'a, LayoutJustifyContent>,
     node_depths: &[ParentWithNodeDepth],
     origin: LogicalPosition,
@@ -1199,6 +1219,7 @@ fn get_x_positions<'a>(
         layout_justify_contents,
         node_depths,
         solved_widths,
+        None,
         &parents_to_solve
     );
 
@@ -1211,9 +1232,10 @@ fn get_y_positions<'a>(
     solved_heights: &NodeDataContainerRef<'a, HeightCalculatedRect>,
     node_hierarchy: &NodeDataContainerRef<'a, NodeHierarchyItem>,
     layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
-    layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+    layout_directions: &NodeDataContainerRef<'a, PhysicalFlexDirection>,
     layout_justify_contents: &NodeDataContainerRef<'a, LayoutJustifyContent>,
     node_depths: &[ParentWithNodeDepth],
+    vertical_aligns: &NodeDataContainerRef<'a, Option<StyleVerticalAlign>>,
     origin: LogicalPosition,
     parents_to_solve: &BTreeSet<NodeId>,
 ) {
@@ -1231,6 +1253,7 @@ fn get_y_positions<'a>(
         Vertical
     );
 
+    // vertical-align only applies along the y axis
     get_pos_y(
         arena,
         node_hierarchy,
@@ -1239,6 +1262,7 @@ fn get_y_positions<'a>(
         layout_justify_contents,
         node_depths,
         solved_heights,
+        Some(vertical_aligns),
         &parents_to_solve
     );
 
@@ -1317,6 +1341,59 @@ pub fn get_layout_flex_directions<'a>(styled_dom: &StyledDom) -> NodeDataContain
     }
 }
 
+/// Resolves `row` / `row-reverse` against the `direction` of each node:
+/// in a `direction: rtl` container, `row` places the items from right to left
+#[inline]
+pub fn get_layout_physical_flex_directions<'a>(
+    styled_dom: &StyledDom,
+    layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+) -> NodeDataContainer<PhysicalFlexDirection> {
+    let cache = styled_dom.get_css_property_cache();
+    let node_data_container = styled_dom.node_data.as_container();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+    assert!(node_data_container.internal.len() == styled_nodes.internal.len()); // elide bounds checking
+
+    NodeDataContainer {
+        internal: styled_nodes.internal
+        .par_iter()
+        .enumerate()
+        .map(|(node_id, styled_node)| {
+            let direction = cache.get_direction(
+                &node_data_container.internal[node_id],
+                &NodeId::new(node_id),
+                &styled_node.state
+            )
+            .cloned()
+            .and_then(|p| p.get_property_or_default())
+            .unwrap_or_default();
+            layout_directions[NodeId::new(node_id)].resolve_physical(direction)
+        }).collect()
+    }
+}
+
+#[inline]
+pub fn get_layout_vertical_aligns<'a>(styled_dom: &StyledDom) -> NodeDataContainer<Option<StyleVerticalAlign>> {
+    let cache = styled_dom.get_css_property_cache();
+    let node_data_container = styled_dom.node_data.as_container();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+    assert!(node_data_container.internal.len() == styled_nodes.internal.len()); // elide bounds checking
+
+    NodeDataContainer {
+        internal: styled_nodes.internal
+        .par_iter()
+        .enumerate()
+        .map(|(node_id, styled_node)| {
+            // only nodes with an explicit vertical-align are moved,
+            // the others keep their position at the top of the parent
+            cache.get_vertical_align(
+                &node_data_container.internal[node_id],
+                &NodeId::new(node_id),
+                &styled_node.state
+            ).and_then(|v| v.get_property().map(|v| **v))
+        }).collect()
+    }
+}
+
 #[inline]
 pub fn get_layout_flex_grows<'a>(styled_dom: &StyledDom) -> NodeDataContainer<f32> {
     // Prevent flex-grow and flex-shrink to be less than 0
@@ -1365,7 +1442,7 @@ pub fn get_layout_displays<'a>(styled_dom: &StyledDom) -> NodeDataContainer<CssP
     }
 }
 
-fn precalculate_all_offsets(styled_dom: &StyledDom) -> NodeDataContainer<AllOffsets> {
+pub(crate) fn precalculate_all_offsets(styled_dom: &StyledDom) -> NodeDataContainer<AllOffsets> {
 
     use rayon::prelude::*;
 
@@ -1392,7 +1469,7 @@ fn precalculate_all_offsets(styled_dom: &StyledDom) -> NodeDataContainer<AllOffs
     }
 }
 
-struct AllOffsets {
+pub(crate) struct AllOffsets {
     position: LayoutAbsolutePositions,
     border_widths: LayoutBorderOffsets,
     padding: LayoutPaddingOffsets,
@@ -1450,10 +1527,10 @@ fn precalculate_offset(
 }
 
 struct LayoutAbsolutePositions {
-    left: Option<CssPropertyValue<LayoutLeft>>,
-    right: Option<CssPropertyValue<LayoutRight>>,
-    top: Option<CssPropertyValue<LayoutTop>>,
-    bottom: Option<CssPropertyValue<LayoutBottom>>,
+    left: Option<CssLengthValue<LayoutLeft>>,
+    right: Option<CssLengthValue<LayoutRight>>,
+    top: Option<CssLengthValue<LayoutTop>>,
+    bottom: Option<CssLengthValue<LayoutBottom>>,
 }
 
 struct LayoutBorderOffsets {
@@ -1464,48 +1541,48 @@ struct LayoutBorderOffsets {
 }
 
 impl LayoutBorderOffsets {
-    fn resolve(&self, parent_scale_x: f32, parent_scale_y: f32) -> ResolvedOffsets {
+    fn resolve(&self, parent_scale_x: f32, parent_scale_y: f32, em_size: f32) -> ResolvedOffsets {
         ResolvedOffsets {
-            left: self.left.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_x))).unwrap_or_default(),
-            top: self.top.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_y))).unwrap_or_default(),
-            bottom: self.bottom.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_y))).unwrap_or_default(),
-            right: self.right.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_x))).unwrap_or_default(),
+            left: self.left.and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_x, em_size), |v| v.inner)).unwrap_or_default(),
+            top: self.top.and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_y, em_size), |v| v.inner)).unwrap_or_default(),
+            bottom: self.bottom.and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_y, em_size), |v| v.inner)).unwrap_or_default(),
+            right: self.right.and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_x, em_size), |v| v.inner)).unwrap_or_default(),
         }
     }
 }
 
 struct LayoutPaddingOffsets {
-    left: Option<CssPropertyValue<LayoutPaddingLeft>>,
-    right: Option<CssPropertyValue<LayoutPaddingRight>>,
-    top: Option<CssPropertyValue<LayoutPaddingTop>>,
-    bottom: Option<CssPropertyValue<LayoutPaddingBottom>>,
+    left: Option<CssLengthValue<LayoutPaddingLeft>>,
+    right: Option<CssLengthValue<LayoutPaddingRight>>,
+    top: Option<CssLengthValue<LayoutPaddingTop>>,
+    bottom: Option<CssLengthValue<LayoutPaddingBottom>>,
 }
 
 impl LayoutPaddingOffsets {
-    fn resolve(&self, parent_scale_x: f32, parent_scale_y: f32) -> ResolvedOffsets {
+    fn resolve(&self, parent_scale_x: f32, parent_scale_y: f32, em_size: f32) -> ResolvedOffsets {
         ResolvedOffsets {
-            left: self.left.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_x))).unwrap_or_default(),
-            top: self.top.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_y))).unwrap_or_default(),
-            bottom: self.bottom.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_y))).unwrap_or_default(),
-            right: self.right.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_x))).unwrap_or_default(),
+            left: self.left.as_ref().and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_x, em_size), |v| v.inner)).unwrap_or_default(),
+            top: self.top.as_ref().and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_y, em_size), |v| v.inner)).unwrap_or_default(),
+            bottom: self.bottom.as_ref().and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_y, em_size), |v| v.inner)).unwrap_or_default(),
+            right: self.right.as_ref().and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_x, em_size), |v| v.inner)).unwrap_or_default(),
         }
     }
 }
 
 struct LayoutMarginOffsets {
-    left: Option<CssPropertyValue<LayoutMarginLeft>>,
-    right: Option<CssPropertyValue<LayoutMarginRight>>,
-    top: Option<CssPropertyValue<LayoutMarginTop>>,
-    bottom: Option<CssPropertyValue<LayoutMarginBottom>>,
+    left: Option<CssLengthValue<LayoutMarginLeft>>,
+    right: Option<CssLengthValue<LayoutMarginRight>>,
+    top: Option<CssLengthValue<LayoutMarginTop>>,
+    bottom: Option<CssLengthValue<LayoutMarginBottom>>,
 }
 
 impl LayoutMarginOffsets {
-    fn resolve(&self, parent_scale_x: f32, parent_scale_y: f32) -> ResolvedOffsets {
+    fn resolve(&self, parent_scale_x: f32, parent_scale_y: f32, em_size: f32) -> ResolvedOffsets {
         ResolvedOffsets {
-            left: self.left.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_x))).unwrap_or_default(),
-            top: self.top.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_y))).unwrap_or_default(),
-            bottom: self.bottom.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_y))).unwrap_or_default(),
-            right: self.right.and_then(|p| Some(p.get_property()?.inner.to_pixels(parent_scale_x))).unwrap_or_default(),
+            left: self.left.as_ref().and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_x, em_size), |v| v.inner)).unwrap_or_default(),
+            top: self.top.as_ref().and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_y, em_size), |v| v.inner)).unwrap_or_default(),
+            bottom: self.bottom.as_ref().and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_y, em_size), |v| v.inner)).unwrap_or_default(),
+            right: self.right.as_ref().and_then(|p| p.resolve_length(&UnitResolutionContext::with_em_size(parent_scale_x, em_size), |v| v.inner)).unwrap_or_default(),
         }
     }
 }
@@ -1621,7 +1698,7 @@ pub fn do_the_layout(
                     let mut node_data_mut = layout_result.styled_dom.node_data.as_container_mut();
                     match &mut node_data_mut[iframe_node_id].get_iframe_node() {
                         Some(iframe_node) => {
-                            (iframe_node.callback.cb)(&mut iframe_node.data, &mut iframe_callback_info)
+                            iframe_node.callback.invoke(&mut iframe_node.data, &mut iframe_callback_info)
                         },
                         None => IFrameCallbackReturn::default(),
                     }
@@ -1909,6 +1986,8 @@ pub fn do_the_layout_internal(
         &all_parents_btreeset,
     );
 
+    let physical_directions = get_layout_physical_flex_directions(&styled_dom, &layout_directions_info.as_ref());
+
     let mut x_positions = NodeDataContainer {
         internal: vec![HorizontalSolvedPosition(0.0); styled_dom.node_data.len()].into(),
     };
@@ -1918,7 +1997,7 @@ pub fn do_the_layout_internal(
         &width_calculated_arena.as_ref(),
         &styled_dom.node_hierarchy.as_container(),
         &layout_position_info.as_ref(),
-        &layout_directions_info.as_ref(),
+        &physical_directions.as_ref(),
         &layout_justify_contents.as_ref(),
         &styled_dom.non_leaf_nodes.as_ref(),
         rect_offset.clone(),
@@ -1934,9 +2013,10 @@ pub fn do_the_layout_internal(
         &height_calculated_arena.as_ref(),
         &styled_dom.node_hierarchy.as_container(),
         &layout_position_info.as_ref(),
-        &layout_directions_info.as_ref(),
+        &physical_directions.as_ref(),
         &layout_justify_contents.as_ref(),
         &styled_dom.non_leaf_nodes.as_ref(),
+        &get_layout_vertical_aligns(&styled_dom).as_ref(),
         rect_offset,
         &all_parents_btreeset,
     );
@@ -2096,16 +2176,16 @@ fn position_nodes<'a>(
         if !nodes_that_updated_positions.contains(&parent_node_id) { continue; };
 
         let parent_position = position_info[parent_node_id];
-        let width = solved_widths[parent_node_id];
-        let height = solved_heights[parent_node_id];
+        let width = solved_widths[parent_node_id].clone();
+        let height = solved_heights[parent_node_id].clone();
         let x_pos = x_positions[parent_node_id].0;
         let y_pos = y_positions[parent_node_id].0;
 
         let parent_parent_node_id = node_hierarchy[parent_node_id].parent_id().unwrap_or(NodeId::new(0));
         let parent_x_pos = x_positions[parent_parent_node_id].0;
         let parent_y_pos = y_positions[parent_parent_node_id].0;
-        let parent_parent_width = solved_widths[parent_parent_node_id];
-        let parent_parent_height = solved_heights[parent_parent_node_id];
+        let parent_parent_width = solved_widths[parent_parent_node_id].clone();
+        let parent_parent_height = solved_heights[parent_parent_node_id].clone();
 
         let last_positioned_item_node_id = positioned_node_stack.last().map(|l| *l).unwrap_or(NodeId::new(0));
         let last_positioned_item_x_pos = x_positions[last_positioned_item_node_id].0;
@@ -2147,9 +2227,9 @@ fn position_nodes<'a>(
             None => continue,
         };
 
-        let parent_padding = parent_offsets.padding.resolve(parent_parent_width.total(), parent_parent_height.total());
-        let parent_margin = parent_offsets.margin.resolve(parent_parent_width.total(), parent_parent_height.total());
-        let parent_border_widths = parent_offsets.border_widths.resolve(parent_parent_width.total(), parent_parent_height.total());
+        let parent_padding = parent_offsets.padding.resolve(parent_parent_width.total(), parent_parent_height.total(), width.em_size);
+        let parent_margin = parent_offsets.margin.resolve(parent_parent_width.total(), parent_parent_height.total(), width.em_size);
+        let parent_border_widths = parent_offsets.border_widths.resolve(parent_parent_width.total(), parent_parent_height.total(), width.em_size);
 
         // push positioned item and layout children
         if parent_position != LayoutPosition::Static {
@@ -2159,13 +2239,13 @@ fn position_nodes<'a>(
         for child_node_id in parent_node_id.az_children(node_hierarchy) {
 
             // copy the width and height from the parent node
-            let parent_width = width;
-            let parent_height = height;
+            let parent_width = &width;
+            let parent_height = &height;
             let parent_x_pos = x_pos;
             let parent_y_pos = y_pos;
 
-            let width = solved_widths[child_node_id];
-            let height = solved_heights[child_node_id];
+            let width = solved_widths[child_node_id].clone();
+            let height = solved_heights[child_node_id].clone();
             let x_pos = x_positions[child_node_id].0;
             let y_pos = y_positions[child_node_id].0;
             let child_position = position_info[child_node_id];
@@ -2209,9 +2289,9 @@ fn position_nodes<'a>(
                 None => continue,
             };
 
-            let child_padding = child_offsets.padding.resolve(parent_width.total(), parent_height.total());
-            let child_margin = child_offsets.margin.resolve(parent_width.total(), parent_height.total());
-            let child_border_widths = child_offsets.border_widths.resolve(parent_width.total(), parent_height.total());
+            let child_padding = child_offsets.padding.resolve(parent_width.total(), parent_height.total(), width.em_size);
+            let child_margin = child_offsets.margin.resolve(parent_width.total(), parent_height.total(), width.em_size);
+            let child_border_widths = child_offsets.border_widths.resolve(parent_width.total(), parent_height.total(), width.em_size);
 
             // set text, if any
             let child_text = if let (
@@ -2236,6 +2316,8 @@ fn position_nodes<'a>(
                             css_property_cache.get_justify_content(child_node_data, &child_node_id, child_styled_node_state)
                             .cloned().and_then(|p| p.get_property_or_default()).unwrap_or_default(),
                             css_property_cache.get_text_align(child_node_data, &child_node_id, child_styled_node_state).cloned(),
+                            css_property_cache.get_direction(child_node_data, &child_node_id, child_styled_node_state)
+                            .cloned().and_then(|p| p.get_property_or_default()).unwrap_or_default(),
                         );
 
                         inline_text_layout.align_children_horizontal(&child_size_logical, horz_alignment);
@@ -2258,7 +2340,7 @@ fn position_nodes<'a>(
                 position: child_position,
                 padding: child_padding,
                 margin: child_margin,
-                box_shadow: child_offsets.box_shadow,
+                box_shadow: child_offsets.box_shadow.clone(),
                 box_sizing: child_offsets.box_sizing,
                 border_widths: child_border_widths,
                 resolved_text_layout_options: child_text,
@@ -2280,7 +2362,7 @@ fn position_nodes<'a>(
         parent_rect.padding = parent_padding;
         parent_rect.margin = parent_margin;
         parent_rect.border_widths = parent_border_widths;
-        parent_rect.box_shadow = parent_offsets.box_shadow;
+        parent_rect.box_shadow = parent_offsets.box_shadow.clone();
         parent_rect.box_sizing = parent_offsets.box_sizing;
         parent_rect.overflow_x = parent_offsets.overflow_x;
         parent_rect.overflow_y = parent_offsets.overflow_y;
@@ -2399,10 +2481,8 @@ fn create_word_positions<'a>(
         let node_data = &node_data_container[*node_id];
 
         let styled_node_state = &styled_dom.styled_nodes.as_container()[*node_id].state;
-        let font_size = css_property_cache
-            .get_font_size_or_default(node_data, node_id, &styled_node_state);
-        let font_size_au = font_size_to_au(font_size);
-        let font_size_px = font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32);
+        let font_size_px = styled_dom.get_em_size(*node_id);
+        let font_size_au = font_size_to_au(font_size_px);
 
 
         let css_font_families = css_property_cache.get_font_id_or_default(node_data, node_id, styled_node_state);
@@ -2427,6 +2507,7 @@ fn create_word_positions<'a>(
 
             match overflow_x {
                 Some(CssPropertyValue::Exact(LayoutOverflow::Hidden)) |
+                Some(CssPropertyValue::Exact(LayoutOverflow::Clip)) |
                 Some(CssPropertyValue::Exact(LayoutOverflow::Visible)) => {
                     max_text_width = None;
                     break;
@@ -2448,11 +2529,11 @@ fn create_word_positions<'a>(
 
         let letter_spacing = css_property_cache
         .get_letter_spacing(node_data, node_id, &styled_node_state)
-        .and_then(|ls| Some(ls.get_property()?.inner.to_pixels(DEFAULT_LETTER_SPACING)));
+        .and_then(|ls| Some(ls.get_property()?.inner.to_pixels_em_aware(DEFAULT_LETTER_SPACING, font_size_px)));
 
         let word_spacing = css_property_cache
         .get_word_spacing(node_data, node_id, &styled_node_state)
-        .and_then(|ws| Some(ws.get_property()?.inner.to_pixels(DEFAULT_WORD_SPACING)));
+        .and_then(|ws| Some(ws.get_property()?.inner.to_pixels_em_aware(DEFAULT_WORD_SPACING, font_size_px)));
 
         let line_height = css_property_cache
         .get_line_height(node_data, node_id, &styled_node_state)
@@ -2490,6 +2571,7 @@ fn determine_text_alignment(
     align_items: LayoutAlignItems,
     justify_content: LayoutJustifyContent,
     text_align: Option<CssPropertyValue<StyleTextAlign>>,
+    direction: StyleDirection,
 )
     -> (StyleTextAlign, StyleVerticalAlign)
 {
@@ -2498,13 +2580,13 @@ fn determine_text_alignment(
         LayoutAlignItems::FlexStart => StyleVerticalAlign::Top,
         LayoutAlignItems::FlexEnd => StyleVerticalAlign::Bottom,
         // technically stretch = blocktext, but we don't have that yet
-        _ => StyleVerticalAlign::Center,
+        _ => StyleVerticalAlign::Middle,
     };
 
     // Horizontal text alignment
     let mut horz_alignment = match justify_content {
-        LayoutJustifyContent::Start => StyleTextAlign::Left,
-        LayoutJustifyContent::End => StyleTextAlign::Right,
+        LayoutJustifyContent::Start => StyleTextAlign::Start,
+        LayoutJustifyContent::End => StyleTextAlign::End,
         _ => StyleTextAlign::Center,
     };
 
@@ -2513,7 +2595,7 @@ fn determine_text_alignment(
         horz_alignment = text_align;
     }
 
-    (horz_alignment, vert_alignment)
+    (horz_alignment.resolve(direction), vert_alignment)
 }
 
 
@@ -2608,11 +2690,12 @@ fn get_nodes_that_need_scroll_clip(
         let overflow_y = positioned_rect.overflow_y;
 
         match (overflow_x, overflow_y) {
-            (Hidden, Hidden) => {
+            (Hidden, Hidden) | (Clip, Clip) | (Hidden, Clip) | (Clip, Hidden) => {
                 clip_nodes.insert(parent_id, positioned_rect.size);
                 all_direct_overflows.remove(&parent_id);
             },
-            (Visible, Visible) => {
+            (Visible, Visible) | (Clip, Visible) | (Visible, Clip) => {
+                // overflow:clip never creates a scroll container
                 all_direct_overflows.remove(&parent_id);
             },
             _ => {
@@ -2862,16 +2945,15 @@ pub fn do_the_relayout(
             let parsed_font_downcasted = unsafe { &*(font_data.parsed as *const ParsedFont) };
             let new_shaped_words = shape_words(&new_words, parsed_font_downcasted);
 
-            let font_size = css_property_cache.get_font_size_or_default(node_data, node_id, &styled_node_state);
-            let font_size_px = font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32);
+            let font_size_px = layout_result.styled_dom.get_em_size(*node_id);
 
             let letter_spacing = css_property_cache
             .get_letter_spacing(node_data, node_id, &styled_node_state)
-            .and_then(|ls| Some(ls.get_property()?.inner.to_pixels(DEFAULT_LETTER_SPACING)));
+            .and_then(|ls| Some(ls.get_property()?.inner.to_pixels_em_aware(DEFAULT_LETTER_SPACING, font_size_px)));
 
             let word_spacing = css_property_cache
             .get_word_spacing(node_data, node_id, &styled_node_state)
-            .and_then(|ws| Some(ws.get_property()?.inner.to_pixels(DEFAULT_WORD_SPACING)));
+            .and_then(|ws| Some(ws.get_property()?.inner.to_pixels_em_aware(DEFAULT_WORD_SPACING, font_size_px)));
 
             let line_height = css_property_cache
             .get_line_height(node_data, node_id, &styled_node_state)
@@ -2933,11 +3015,14 @@ pub fn do_the_relayout(
                     let solved_height_layout = &mut layout_result.height_calculated_rects.as_ref_mut()[$node_id];
                     let css_property_cache = layout_result.styled_dom.get_css_property_cache();
                     let parent_parent = layout_result.styled_dom.node_hierarchy.as_container()[$parent_id].parent_id().unwrap_or(NodeId::ZERO);
+                    // em lengths (width, padding, etc.) are relative to the font size of the node
+                    let font_size_changed = changes_for_this_node.contains_key(&CssPropertyType::FontSize);
 
                     // recalculate min / max / preferred width constraint if needed
                     if changes_for_this_node.contains_key(&CssPropertyType::Width) ||
                        changes_for_this_node.contains_key(&CssPropertyType::MinWidth) ||
                        changes_for_this_node.contains_key(&CssPropertyType::MaxWidth) ||
+                       font_size_changed ||
                        has_word_positions ||
                        text_content_has_changed {
 
@@ -2946,15 +3031,16 @@ pub fn do_the_relayout(
                         let wh_config = WhConfig {
                             width: WidthConfig {
                                 exact: css_property_cache.get_width(node_data, &$node_id, styled_node_state)
-                                .and_then(|p| p.get_property().copied()),
+                                .cloned(),
                                 max: css_property_cache.get_max_width(node_data, &$node_id, styled_node_state)
-                                .and_then(|p| p.get_property().copied()),
+                                .cloned(),
                                 min: css_property_cache.get_min_width(node_data, &$node_id, styled_node_state)
-                                .and_then(|p| p.get_property().copied()),
+                                .cloned(),
                                 overflow: css_property_cache.get_overflow_x(node_data, &$node_id, styled_node_state)
                                 .and_then(|p| p.get_property().copied()),
                             },
                             height: HeightConfig::default(),
+                            em_size: layout_result.styled_dom.get_em_size($node_id),
                         };
                         let parent_width = layout_result.preferred_widths.as_ref()[$parent_id].clone().unwrap_or(root_size.width as f32);
                         let parent_parent_overflow_x = css_property_cache
@@ -2982,6 +3068,7 @@ pub fn do_the_relayout(
                     if changes_for_this_node.contains_key(&CssPropertyType::MinHeight) ||
                        changes_for_this_node.contains_key(&CssPropertyType::MaxHeight) ||
                        changes_for_this_node.contains_key(&CssPropertyType::Height) ||
+                       font_size_changed ||
                        has_word_positions ||
                        text_content_has_changed {
                         let styled_node_state = &layout_result.styled_dom.styled_nodes.as_container()[$node_id].state;
@@ -2989,14 +3076,15 @@ pub fn do_the_relayout(
                             width: WidthConfig::default(),
                             height: HeightConfig {
                                 exact: css_property_cache.get_height(node_data, &$node_id, &styled_node_state)
-                                .and_then(|p| p.get_property().copied()),
+                                .cloned(),
                                 max: css_property_cache.get_max_height(node_data, &$node_id, &styled_node_state)
-                                .and_then(|p| p.get_property().copied()),
+                                .cloned(),
                                 min: css_property_cache.get_min_height(node_data, &$node_id, &styled_node_state)
-                                .and_then(|p| p.get_property().copied()),
+                                .cloned(),
                                 overflow: css_property_cache.get_overflow_y(node_data, &$node_id, &styled_node_state)
                                 .and_then(|p| p.get_property().copied()),
                             },
+                            em_size: layout_result.styled_dom.get_em_size($node_id),
                         };
                         let parent_height = layout_result.preferred_heights.as_ref()[$parent_id].clone().unwrap_or(root_size.height as f32);
                         let parent_parent_overflow_y = css_property_cache
@@ -3023,53 +3111,65 @@ pub fn do_the_relayout(
                     // padding / margin horizontal change
                     if let Some(CssProperty::PaddingLeft(prop)) = changes_for_this_node
                     .get(&CssPropertyType::PaddingLeft).map(|p| &p.current_prop) {
-                        solved_width_layout.padding_left = Some(*prop);
+                        solved_width_layout.padding_left = Some(prop.clone());
                         padding_x_changed = true;
                     }
 
                     if let Some(CssProperty::PaddingRight(prop)) = changes_for_this_node
                     .get(&CssPropertyType::PaddingRight).map(|p| &p.current_prop) {
-                        solved_width_layout.padding_right = Some(*prop);
+                        solved_width_layout.padding_right = Some(prop.clone());
                         padding_x_changed = true;
                     }
 
                     if let Some(CssProperty::MarginLeft(prop)) = changes_for_this_node
                     .get(&CssPropertyType::MarginLeft).map(|p| &p.current_prop) {
-                        solved_width_layout.margin_left = Some(*prop);
+                        solved_width_layout.margin_left = Some(prop.clone());
                         margin_x_changed = true;
                     }
 
                     if let Some(CssProperty::MarginRight(prop)) = changes_for_this_node
                     .get(&CssPropertyType::MarginRight).map(|p| &p.current_prop) {
-                        solved_width_layout.margin_right = Some(*prop);
+                        solved_width_layout.margin_right = Some(prop.clone());
                         margin_x_changed = true;
                     }
 
                     // padding / margin vertical change
                     if let Some(CssProperty::PaddingTop(prop)) = changes_for_this_node
                     .get(&CssPropertyType::PaddingTop).map(|p| &p.current_prop) {
-                        solved_height_layout.padding_top = Some(*prop);
+                        solved_height_layout.padding_top = Some(prop.clone());
                         padding_y_changed = true;
                     }
 
                     if let Some(CssProperty::PaddingBottom(prop)) = changes_for_this_node
                     .get(&CssPropertyType::PaddingBottom).map(|p| &p.current_prop) {
-                        solved_height_layout.padding_bottom = Some(*prop);
+                        solved_height_layout.padding_bottom = Some(prop.clone());
                         padding_y_changed = true;
                     }
 
                     if let Some(CssProperty::MarginTop(prop)) = changes_for_this_node
                     .get(&CssPropertyType::MarginTop).map(|p| &p.current_prop) {
-                        solved_height_layout.margin_top = Some(*prop);
+                        solved_height_layout.margin_top = Some(prop.clone());
                         margin_y_changed = true;
                     }
 
                     if let Some(CssProperty::MarginBottom(prop)) = changes_for_this_node
                     .get(&CssPropertyType::MarginBottom).map(|p| &p.current_prop) {
-                        solved_height_layout.margin_bottom = Some(*prop);
+                        solved_height_layout.margin_bottom = Some(prop.clone());
                         margin_y_changed = true;
                     }
 
+                    if font_size_changed {
+                        let em_size = layout_result.styled_dom.get_em_size($node_id);
+                        if em_size != solved_width_layout.em_size {
+                            solved_width_layout.em_size = em_size;
+                            solved_height_layout.em_size = em_size;
+                            padding_x_changed = true;
+                            padding_y_changed = true;
+                            margin_x_changed = true;
+                            margin_y_changed = true;
+                        }
+                    }
+
                     if let Some((previous_preferred_width, current_preferred_width)) = preferred_width_changed {
                         // need to recalc the width of the node
                         // need to bubble the width to the parent width
@@ -3192,7 +3292,7 @@ pub fn do_the_relayout(
 
         let previous_widths = parents_that_need_to_recalc_width_of_children.iter()
         .filter_map(|node_id| {
-            layout_result.width_calculated_rects.as_ref().get(*node_id).map(|s| (node_id, *s))
+            layout_result.width_calculated_rects.as_ref().get(*node_id).map(|s| (node_id, s.clone()))
         }).collect::<BTreeMap<_, _>>();
 
         subtree_needs_relayout_width.extend(parents_that_need_to_recalc_width_of_children.iter().cloned());
@@ -3213,8 +3313,8 @@ pub fn do_the_relayout(
         // if the parent width is not the same, bubble
         let parents_that_changed_width = parents_that_need_to_recalc_width_of_children.iter().filter_map(|p| {
             // get the current width after relayout
-            let current_width = layout_result.width_calculated_rects.as_ref().get(*p).copied()?;
-            let previous_width = previous_widths.get(p).copied()?;
+            let current_width = layout_result.width_calculated_rects.as_ref().get(*p).cloned()?;
+            let previous_width = previous_widths.get(p).cloned()?;
             if current_width == previous_width { return None; }
             let parent_id = layout_result.styled_dom.node_hierarchy.as_container()[*p].parent_id()?;
             Some(parent_id)
@@ -3238,7 +3338,7 @@ pub fn do_the_relayout(
 
         let previous_heights = parents_that_need_to_recalc_height_of_children.iter()
         .filter_map(|node_id| {
-            layout_result.height_calculated_rects.as_ref().get(*node_id).map(|s| (node_id, *s))
+            layout_result.height_calculated_rects.as_ref().get(*node_id).map(|s| (node_id, s.clone()))
         }).collect::<BTreeMap<_, _>>();
 
         subtree_needs_relayout_height.extend(parents_that_need_to_recalc_height_of_children.iter().cloned());
@@ -3259,8 +3359,8 @@ pub fn do_the_relayout(
         // if the parent height is not the same, bubble
         let mut parents_that_changed_height = parents_that_need_to_recalc_height_of_children.iter().filter_map(|p| {
             // get the current height after relayout
-            let current_height = layout_result.height_calculated_rects.as_ref().get(*p).copied()?;
-            let previous_height = previous_heights.get(p).copied()?;
+            let current_height = layout_result.height_calculated_rects.as_ref().get(*p).cloned()?;
+            let previous_height = previous_heights.get(p).cloned()?;
             if current_height == previous_height { return None; }
             let parent_id = layout_result.styled_dom.node_hierarchy.as_container()[*p].parent_id()?;
             Some(parent_id)
@@ -3333,12 +3433,17 @@ pub fn do_the_relayout(
 
     // -- step 2: recalc position for those parents that need it
 
+    let physical_directions = get_layout_physical_flex_directions(
+        &layout_result.styled_dom,
+        &layout_result.layout_flex_directions.as_ref(),
+    );
+
     get_x_positions(
         &mut layout_result.solved_pos_x,
         &layout_result.width_calculated_rects.as_ref(),
         &layout_result.styled_dom.node_hierarchy.as_container(),
         &layout_result.layout_positions.as_ref(),
-        &layout_result.layout_flex_directions.as_ref(),
+        &physical_directions.as_ref(),
         &layout_result.layout_justify_contents.as_ref(),
         &layout_result.styled_dom.non_leaf_nodes.as_ref(),
         LogicalPosition::new(root_bounds.origin.x as f32, root_bounds.origin.y as f32),
@@ -3350,9 +3455,10 @@ pub fn do_the_relayout(
         &layout_result.height_calculated_rects.as_ref(),
         &layout_result.styled_dom.node_hierarchy.as_container(),
         &layout_result.layout_positions.as_ref(),
-        &layout_result.layout_flex_directions.as_ref(),
+        &physical_directions.as_ref(),
         &layout_result.layout_justify_contents.as_ref(),
         &layout_result.styled_dom.non_leaf_nodes.as_ref(),
+        &get_layout_vertical_aligns(&layout_result.styled_dom).as_ref(),
         LogicalPosition::new(root_bounds.origin.x as f32, root_bounds.origin.y as f32),
         &parents_that_need_to_reposition_children_y, // <- important
     );
@@ -3474,4 +3580,132 @@ fn test_hash() {
     let a = NodeData::new(NodeType::Div);
     let b = NodeData::new(NodeType::Div);
     assert_eq!(a.calculate_node_data_hash(), b.calculate_node_data_hash())
+}
+
+#[cfg(feature = "text_layout")]
+#[test]
+fn test_calc_width() {
+    use azul_core::{app_resources::IdNamespace, dom::Dom};
+    use azul_css_parser::CssApiWrapper;
+
+    let mut dom = Dom::body()
+        .with_inline_style("flex-direction: row;")
+        .with_children(vec![
+            Dom::div().with_inline_style("width: calc(50% - 20px); height: 10px;"),
+            Dom::div().with_inline_style("width: 10px; height: 10px; margin-left: calc(1em + 4px);"),
+        ].into());
+
+    let layout_result = do_the_layout_internal(
+        DomId::ROOT_ID,
+        None,
+        StyledDom::new(&mut dom, CssApiWrapper::empty()),
+        &mut RendererResources::default(),
+        &DocumentId { namespace_id: IdNamespace(0), id: 0 },
+        LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(400.0, 300.0)),
+    );
+
+    let rects = layout_result.rects.as_ref();
+    assert_eq!(rects[NodeId::new(1)].size.width, 180.0);
+    // 180px + 16px (1em) + 4px
+    assert_eq!(layout_result.solved_pos_x.as_ref()[NodeId::new(2)].0, 200.0);
+}
+
+#[cfg(feature = "text_layout")]
+#[test]
+fn test_vertical_align_in_row() {
+    use azul_core::{app_resources::IdNamespace, dom::Dom};
+    use azul_css_parser::CssApiWrapper;
+
+    let mut dom = Dom::body()
+        .with_inline_style("flex-direction: row; height: 100px;")
+        .with_children(vec![
+            Dom::div().with_inline_style("width: 10px; height: 20px;"),
+            Dom::div().with_inline_style("width: 10px; height: 20px; vertical-align: middle;"),
+            Dom::div().with_inline_style("width: 10px; height: 20px; vertical-align: bottom;"),
+            Dom::div().with_inline_style("width: 10px; height: 20px; margin-top: 5px; vertical-align: top;"),
+        ].into());
+
+    let layout_result = do_the_layout_internal(
+        DomId::ROOT_ID,
+        None,
+        StyledDom::new(&mut dom, CssApiWrapper::empty()),
+        &mut RendererResources::default(),
+        &DocumentId { namespace_id: IdNamespace(0), id: 0 },
+        LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(400.0, 300.0)),
+    );
+
+    let y = |node_id: usize| layout_result.solved_pos_y.as_ref()[NodeId::new(node_id)].0;
+    assert_eq!(y(1), 0.0); // no vertical-align: top of the parent
+    assert_eq!(y(2), 40.0);
+    assert_eq!(y(3), 80.0);
+    assert_eq!(y(4), 5.0);
+}
+
+#[cfg(feature = "text_layout")]
+#[test]
+fn test_row_in_rtl_direction() {
+    use azul_core::{app_resources::IdNamespace, dom::Dom};
+    use azul_css_parser::CssApiWrapper;
+
+    let x_positions = |style: &str| {
+        let mut dom = Dom::body()
+            .with_inline_style(style)
+            .with_children(vec![
+                Dom::div().with_inline_style("width: 10px; height: 10px;"),
+                Dom::div().with_inline_style("width: 20px; height: 10px;"),
+            ].into());
+
+        let layout_result = do_the_layout_internal(
+            DomId::ROOT_ID,
+            None,
+            StyledDom::new(&mut dom, CssApiWrapper::empty()),
+            &mut RendererResources::default(),
+            &DocumentId { namespace_id: IdNamespace(0), id: 0 },
+            LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(400.0, 300.0)),
+        );
+
+        let x = layout_result.solved_pos_x.as_ref();
+        (x[NodeId::new(1)].0, x[NodeId::new(2)].0)
+    };
+
+    assert_eq!(x_positions("flex-direction: row;"), (0.0, 10.0));
+    // the first item starts at the right edge
+    assert_eq!(x_positions("flex-direction: row; direction: rtl;"), (390.0, 370.0));
+    assert_eq!(x_positions("flex-direction: row-reverse; direction: rtl;"), (0.0, 10.0));
+}
+
+#[cfg(feature = "text_layout")]
+#[test]
+fn test_layout_snapshot() {
+    use azul_core::{app_resources::IdNamespace, dom::Dom, snapshot};
+    use azul_css_parser::CssApiWrapper;
+
+    // no text nodes: the snapshot must not depend on the installed fonts
+    let mut dom = Dom::body()
+        .with_inline_style("flex-direction: column; background: #eeeeee;")
+        .with_children(vec![
+            Dom::div().with_inline_style("height: 50px; background: #ff0000;"),
+            Dom::div()
+                .with_inline_style("flex-direction: row; flex-grow: 1;")
+                .with_children(vec![
+                    Dom::div().with_inline_style("width: 100px; background: #00ff00;"),
+                    Dom::div().with_inline_style("flex-grow: 1; background: #0000ff;"),
+                ].into()),
+            Dom::div().with_inline_style("height: 20%;"),
+        ].into());
+
+    let layout_result = do_the_layout_internal(
+        DomId::ROOT_ID,
+        None,
+        StyledDom::new(&mut dom, CssApiWrapper::empty()),
+        &mut RendererResources::default(),
+        &DocumentId { namespace_id: IdNamespace(0), id: 0 },
+        LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(400.0, 300.0)),
+    );
+
+    let actual = layout_result.serialize_debug(snapshot::DEFAULT_SNAPSHOT_PROPERTIES);
+    let expected = include_str!("../tests/snapshots/flex_boxes.txt");
+    if let Some(diff) = snapshot::diff_snapshots(expected, &actual) {
+        panic!("layout snapshot does not match (- expected, + actual):\n{}", diff);
+    }
 }
\ No newline at end of file
@@ -1,137 +1,99 @@
-use azul_css::RectLayout;
+use azul_css::*;
 use azul_core::{
-    id_tree::{Node, NodeId, NodeHierarchy, NodeDataContainer},
-    styled_dom::{NodeHierarchyItem, NodeHierarchyItemId, StyledNode, ParentWithNodeDepth},
+    dom::Dom,
+    id_tree::NodeId,
+    styled_dom::{NodeHierarchyItemId, StyledDom, ParentWithNodeDepth},
     ui_solver::{WhConstraint, WidthSolvedResult},
 };
+use azul_css_parser::CssApiWrapper;
 use crate::layout_solver::{
     determine_preferred_width,
+    precalculate_wh_config,
+    precalculate_all_offsets,
     width_calculated_rect_arena_from_rect_layout_arena,
-    width_calculated_rect_arena_sum_children_flex_basis,
     bubble_preferred_widths_to_parents,
     width_calculated_rect_arena_apply_flex_grow,
+    WhConfig, WidthConfig,
 };
 
 /// Returns a DOM for testing so we don't have to construct it every time.
-/// The DOM structure looks like this:
+/// Every node `(NodeId, inline style)` gets the inline style. The DOM
+/// structure looks like this:
 ///
 /// ```no_run
 /// 0
 /// '- 1
-///    '-- 2
-///    '   '-- 3
-///    '   '--- 4
-///    '-- 5
+/// '  '-- 2
+/// '      '-- 3
+/// '      '-- 4
+/// '- 5
 /// ```
-fn get_testing_hierarchy() -> NodeHierarchy {
-    NodeHierarchy {
-        internal: vec![
-            // 0
-            Node {
-                parent: None,
-                previous_sibling: None,
-                next_sibling: None,
-                first_child: Some(NodeId::new(1)),
-                last_child: Some(NodeId::new(1)),
-            },
-            // 1
-            Node {
-                parent: Some(NodeId::new(0)),
-                previous_sibling: None,
-                next_sibling: Some(NodeId::new(5)),
-                first_child: Some(NodeId::new(2)),
-                last_child: Some(NodeId::new(2)),
-            },
-            // 2
-            Node {
-                parent: Some(NodeId::new(1)),
-                previous_sibling: None,
-                next_sibling: None,
-                first_child: Some(NodeId::new(3)),
-                last_child: Some(NodeId::new(4)),
-            },
-            // 3
-            Node {
-                parent: Some(NodeId::new(2)),
-                previous_sibling: None,
-                next_sibling: Some(NodeId::new(4)),
-                first_child: None,
-                last_child: None,
-            },
-            // 4
-            Node {
-                parent: Some(NodeId::new(2)),
-                previous_sibling: Some(NodeId::new(3)),
-                next_sibling: None,
-                first_child: None,
-                last_child: None,
-            },
-            // 5
-            Node {
-                parent: Some(NodeId::new(1)),
-                previous_sibling: Some(NodeId::new(2)),
-                next_sibling: None,
-                first_child: None,
-                last_child: None,
-            },
-        ]
-    }
-}
+fn get_testing_dom(styles: &[(usize, &str)]) -> StyledDom {
 
-/// Returns the same arena, but pre-fills nodes at [(NodeId, RectLayout)]
-/// with the layout rect
-fn get_display_rectangle_arena(constraints: &[(usize, RectLayout)]) -> (NodeDataContainer<NodeHierarchyItem>, Vec<ParentWithNodeDepth>, NodeDataContainer<StyledNode>) {
-    let node_hierarchy = get_testing_hierarchy();
-    let node_depths = node_hierarchy.as_ref().get_parents_sorted_by_depth();
-    let mut node_data = vec![StyledNode::default(); node_hierarchy.as_ref().len()];
-    for (id, rect) in constraints {
-        node_data[*id].layout = rect.clone();
-    }
+    let node = |id: usize| {
+        let style = styles.iter().find(|(i, _)| *i == id).map(|(_, s)| *s).unwrap_or("");
+        if id == 0 { Dom::body() } else { Dom::div() }.with_inline_style(style)
+    };
 
-    (
-     NodeDataContainer { internal: node_hierarchy.internal.into_iter().map(|i| NodeHierarchyItem::from(i)).collect() },
-     node_depths.into_iter().map(|(depth, node_id)| ParentWithNodeDepth { depth, node_id: NodeHierarchyItemId::from_crate_internal(Some(node_id)) }).collect(),
-     NodeDataContainer { internal: node_data }
-    )
+    let mut dom = node(0).with_children(vec![
+        node(1).with_children(vec![
+            node(2).with_children(vec![
+                node(3),
+                node(4),
+            ].into()),
+        ].into()),
+        node(5),
+    ].into());
+
+    StyledDom::new(&mut dom, CssApiWrapper::empty())
 }
 
+fn width_config(width: Option<f32>, min_width: Option<f32>, max_width: Option<f32>) -> WhConfig {
+    WhConfig {
+        width: WidthConfig {
+            exact: width.map(|w| LayoutWidth { inner: PixelValue::px(w) }.into()),
+            min: min_width.map(|w| LayoutMinWidth { inner: PixelValue::px(w) }.into()),
+            max: max_width.map(|w| LayoutMaxWidth { inner: PixelValue::px(w) }.into()),
+            overflow: None,
+        },
+        height: Default::default(),
+        em_size: 16.0,
+    }
+}
 
 #[cfg(test)]
 mod dom_tests {
 
     use azul_core::{
-        dom::Dom,
-        callbacks::{RefAny, PipelineId, IFrameCallbackInfo, IFrameCallbackReturn},
-        app_resources::AppResources,
+        dom::{Dom, NodeDataInlineCssProperty::Normal},
+        callbacks::{RefAny, DocumentId, IFrameCallbackInfo, IFrameCallbackReturn},
+        app_resources::{IdNamespace, RendererResources},
         styled_dom::{DomId, StyledDom},
         id_tree::NodeId,
         window::{LogicalRect, LogicalPosition, LogicalSize},
     };
     use azul_css::*;
+    use azul_css_parser::CssApiWrapper;
 
     struct A { }
 
-    extern "C" fn render_iframe(_: &RefAny, _: IFrameCallbackInfo) -> IFrameCallbackReturn {
-        IFrameCallbackReturn {
-            styled_dom: StyledDom::default(),
-            size: LayoutRect::zero(),
-            virtual_size: None.into(),
-        }
+    extern "C" fn render_iframe(_: &mut RefAny, _: &mut IFrameCallbackInfo) -> IFrameCallbackReturn {
+        IFrameCallbackReturn::default()
     }
 
     #[test]
     fn test_full_dom() {
 
-        let mut app_resources = AppResources::default();
+        let mut renderer_resources = RendererResources::default();
 
-        let styled_dom = StyledDom::new(Dom::body(), Css::empty());
+        let styled_dom = StyledDom::new(&mut Dom::body(), CssApiWrapper::empty());
 
         let layout_result = crate::layout_solver::do_the_layout_internal(
             DomId::ROOT_ID,
             None,
             styled_dom,
-            &mut app_resources,
-            PipelineId::DUMMY,
+            &mut renderer_resources,
+            &DocumentId { namespace_id: IdNamespace(0), id: 0 },
             LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(800.0, 600.0))
         );
 
@@ -141,174 +103,97 @@ mod dom_tests {
     #[test]
     fn test_full_dom_2() {
 
-        let mut app_resources = AppResources::default();
-
-        // tag_ids_to_node_ids gets generated?
+        let mut renderer_resources = RendererResources::default();
 
         let styled_dom = Dom::iframe(RefAny::new(A { }), render_iframe)
-            .with_inline_css(CssProperty::display(LayoutDisplay::Flex))
-            .with_inline_css(CssProperty::flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) }))
-            .with_inline_css(CssProperty::width(LayoutWidth { inner: PixelValue::const_percent(100) }))
-            .with_inline_css(CssProperty::height(LayoutHeight { inner: PixelValue::const_percent(100) }))
-            .with_inline_css(CssProperty::box_sizing(LayoutBoxSizing::BorderBox))
-            .style(Css::empty());
+            .with_inline_css_props(vec![
+                Normal(CssProperty::display(LayoutDisplay::Flex)),
+                Normal(CssProperty::flex_grow(LayoutFlexGrow { inner: FloatValue::const_new(1) })),
+                Normal(CssProperty::width(LayoutWidth { inner: PixelValue::const_percent(100) })),
+                Normal(CssProperty::height(LayoutHeight { inner: PixelValue::const_percent(100) })),
+                Normal(CssProperty::box_sizing(LayoutBoxSizing::BorderBox)),
+            ].into())
+            .style(CssApiWrapper::empty());
 
         let layout_result = crate::layout_solver::do_the_layout_internal(
             DomId::ROOT_ID,
             None,
             styled_dom,
-            &mut app_resources,
-            PipelineId::DUMMY,
+            &mut renderer_resources,
+            &DocumentId { namespace_id: IdNamespace(0), id: 0 },
             LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(800.0, 600.0))
         );
 
-        println!("layout result: {:#?}", layout_result);
+        assert_eq!(layout_result.rects.as_ref()[NodeId::new(0)].size, LogicalSize::new(800.0, 600.0));
     }
 }
 
 #[test]
 fn test_determine_preferred_width() {
-    use azul_css::{LayoutMinWidth, LayoutMaxWidth, PixelValue, LayoutWidth};
-    use azul_core::styled_dom::StyledNode;
-
-    let layout = StyledNode {
-        layout: RectLayout {
-            width: None.into(),
-            min_width: None.into(),
-            max_width: None.into(),
-            .. Default::default()
-        },
-        .. Default::default()
-    };
-    assert_eq!(determine_preferred_width(&layout, None, 800.0), WhConstraint::Unconstrained);
-
-    let layout = StyledNode {
-        layout: RectLayout {
-            width: Some(LayoutWidth { inner: PixelValue::px(500.0) }.into()).into(),
-            min_width: None.into(),
-            max_width: None.into(),
-            .. Default::default()
-        },
-        .. Default::default()
-    };
-    assert_eq!(determine_preferred_width(&layout, None, 800.0), WhConstraint::EqualTo(500.0));
-
-    let layout = StyledNode {
-        layout: RectLayout {
-            width: Some(LayoutWidth { inner: PixelValue::px(500.0) }.into()).into(),
-            min_width: Some(LayoutMinWidth { inner: PixelValue::px(600.0) }.into()).into(),
-            max_width: None.into(),
-            .. Default::default()
-        },
-        .. Default::default()
-    };
-    assert_eq!(determine_preferred_width(&layout, None, 800.0), WhConstraint::EqualTo(600.0));
-
-    let layout = StyledNode {
-        layout: RectLayout {
-            width: Some(LayoutWidth { inner: PixelValue::px(10000.0) }.into()).into(),
-            min_width: Some(LayoutMinWidth { inner: PixelValue::px(600.0) }.into()).into(),
-            max_width: Some(LayoutMaxWidth { inner: PixelValue::px(800.0) }.into()).into(),
-            .. Default::default()
-        },
-        .. Default::default()
-    };
-    assert_eq!(determine_preferred_width(&layout, None, 800.0), WhConstraint::EqualTo(800.0));
-
-    let layout = StyledNode {
-        layout: RectLayout {
-            width: None.into(),
-            min_width: Some(LayoutMinWidth { inner: PixelValue::px(600.0) }.into()).into(),
-            max_width: Some(LayoutMaxWidth { inner: PixelValue::px(800.0) }.into()).into(),
-            .. Default::default()
-        },
-        .. Default::default()
-    };
-    assert_eq!(determine_preferred_width(&layout, None, 800.0), WhConstraint::Between(600.0, 800.0));
-
-    let layout = StyledNode {
-        layout: RectLayout {
-            width: None.into(),
-            min_width: None.into(),
-            max_width: Some(LayoutMaxWidth { inner: PixelValue::px(800.0) }.into()).into(),
-            .. Default::default()
-        },
-        .. Default::default()
-    };
-    assert_eq!(determine_preferred_width(&layout, None, 800.0), WhConstraint::Between(0.0, 800.0));
-
-    let layout = StyledNode {
-        layout: RectLayout {
-            width: Some(LayoutWidth { inner: PixelValue::px(1000.0) }.into()).into(),
-            min_width: None.into(),
-            max_width: Some(LayoutMaxWidth { inner: PixelValue::px(800.0) }.into()).into(),
-            .. Default::default()
-        },
-        .. Default::default()
-    };
-    assert_eq!(determine_preferred_width(&layout, None, 800.0), WhConstraint::EqualTo(800.0));
-
-    let layout = StyledNode {
-        layout: RectLayout {
-            width: Some(LayoutWidth { inner: PixelValue::px(1200.0) }.into()).into(),
-            min_width: Some(LayoutMinWidth { inner: PixelValue::px(1000.0) }.into()).into(),
-            max_width: Some(LayoutMaxWidth { inner: PixelValue::px(800.0) }.into()).into(),
-            .. Default::default()
-        },
-        .. Default::default()
-    };
-    assert_eq!(determine_preferred_width(&layout, None, 800.0), WhConstraint::EqualTo(800.0));
-
-    let layout = StyledNode {
-        layout: RectLayout {
-            width: Some(LayoutWidth { inner: PixelValue::px(1200.0)}.into()).into(),
-            min_width: Some(LayoutMinWidth { inner: PixelValue::px(1000.0)}.into()).into(),
-            max_width: Some(LayoutMaxWidth { inner: PixelValue::px(400.0)}.into()).into(),
-            .. Default::default()
-        },
-        .. Default::default()
-    };
-    assert_eq!(determine_preferred_width(&layout, None, 800.0), WhConstraint::EqualTo(400.0));
+
+    let config = width_config(None, None, None);
+    assert_eq!(determine_preferred_width(&config, None, 800.0, LayoutOverflow::Visible), WhConstraint::Between(0.0, f32::MAX));
+
+    let config = width_config(Some(500.0), None, None);
+    assert_eq!(determine_preferred_width(&config, None, 800.0, LayoutOverflow::Visible), WhConstraint::EqualTo(500.0));
+
+    let config = width_config(Some(500.0), Some(600.0), None);
+    assert_eq!(determine_preferred_width(&config, None, 800.0, LayoutOverflow::Visible), WhConstraint::EqualTo(600.0));
+
+    let config = width_config(Some(10000.0), Some(600.0), Some(800.0));
+    assert_eq!(determine_preferred_width(&config, None, 800.0, LayoutOverflow::Visible), WhConstraint::EqualTo(800.0));
+
+    let config = width_config(None, Some(600.0), Some(800.0));
+    assert_eq!(determine_preferred_width(&config, None, 800.0, LayoutOverflow::Visible), WhConstraint::Between(600.0, 800.0));
+
+    let config = width_config(None, None, Some(800.0));
+    assert_eq!(determine_preferred_width(&config, None, 800.0, LayoutOverflow::Visible), WhConstraint::Between(0.0, 800.0));
+
+    let config = width_config(Some(1000.0), None, Some(800.0));
+    assert_eq!(determine_preferred_width(&config, None, 800.0, LayoutOverflow::Visible), WhConstraint::EqualTo(800.0));
+
+    let config = width_config(Some(1200.0), Some(1000.0), Some(800.0));
+    assert_eq!(determine_preferred_width(&config, None, 800.0, LayoutOverflow::Visible), WhConstraint::EqualTo(1000.0));
+
+    let config = width_config(Some(1200.0), Some(1000.0), Some(400.0));
+    assert_eq!(determine_preferred_width(&config, None, 800.0, LayoutOverflow::Visible), WhConstraint::EqualTo(1000.0));
 }
 
 /// Tests that the nodes get filled correctly
 #[test]
 fn test_fill_out_preferred_width() {
 
-    use azul_css::*;
     use std::collections::BTreeSet;
     use crate::layout_solver::{
         get_layout_positions,
         get_layout_flex_grows,
         get_layout_flex_directions,
+        get_layout_displays,
     };
 
     let window_width = 754.0; // pixel
 
-    let (node_hierarchy, node_depths, node_data) = get_display_rectangle_arena(&[
-        (0, RectLayout {
-            direction: Some(LayoutFlexDirection::Row.into()).into(),
-            .. Default::default()
-        }),
-        (1, RectLayout {
-            max_width: Some(LayoutMaxWidth { inner: PixelValue::px(200.0) }.into()).into(),
-            padding_left: Some(LayoutPaddingLeft { inner: PixelValue::px(20.0) }.into()).into(),
-            padding_right: Some(LayoutPaddingRight { inner: PixelValue::px(20.0) }.into()).into(),
-            direction: Some(LayoutFlexDirection::Row.into()).into(),
-            .. Default::default()
-        }),
-        (2, RectLayout {
-            direction: Some(LayoutFlexDirection::Row.into()).into(),
-            .. Default::default()
-        })
+    let styled_dom = get_testing_dom(&[
+        (0, "flex-direction: row;"),
+        (1, "flex-direction: row; flex-grow: 1; max-width: 200px; padding-left: 20px; padding-right: 20px;"),
+        (2, "flex-direction: row; flex-grow: 1;"),
+        (3, "flex-grow: 1;"),
+        (4, "flex-grow: 1;"),
+        (5, "flex-grow: 1;"),
     ]);
 
-    let preferred_widths = node_data.as_ref().transform(|_, _| None);
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+    let node_depths = styled_dom.non_leaf_nodes.as_ref();
+    let wh_configs = precalculate_wh_config(&styled_dom);
+    let offsets = precalculate_all_offsets(&styled_dom);
+    let preferred_widths = wh_configs.as_ref().transform_singlethread(|_, _| None);
+
     let mut width_filled_out_data = width_calculated_rect_arena_from_rect_layout_arena(
-        &node_data.as_ref(),
+        &wh_configs.as_ref(),
+        &offsets.as_ref(),
         &preferred_widths.as_ref(),
-        &node_hierarchy.as_ref(),
-        &node_depths,
+        &node_hierarchy,
+        node_depths,
         window_width,
     );
 
@@ -326,34 +211,39 @@ fn test_fill_out_preferred_width() {
     assert_eq!(width_filled_out_data.as_ref()[NodeId::new(4)].get_flex_basis_horizontal(800.0), 0.0);
     assert_eq!(width_filled_out_data.as_ref()[NodeId::new(5)].get_flex_basis_horizontal(800.0), 0.0);
 
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(0)].preferred_width, WhConstraint::Unconstrained);
+    // the default overflow is `auto`, so unconstrained nodes can't get larger than their parent
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(0)].preferred_width, WhConstraint::Between(0.0, window_width));
     assert_eq!(width_filled_out_data.as_ref()[NodeId::new(1)].preferred_width, WhConstraint::Between(0.0, 200.0));
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(2)].preferred_width, WhConstraint::Unconstrained);
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(3)].preferred_width, WhConstraint::Unconstrained);
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(4)].preferred_width, WhConstraint::Unconstrained);
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(5)].preferred_width, WhConstraint::Unconstrained);
-
-    let layout_positions = get_layout_positions(&node_data.as_ref());
-    let layout_flex_grows = get_layout_flex_grows(&node_data.as_ref());
-    let layout_directions = get_layout_flex_directions(&node_data.as_ref());
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(2)].preferred_width, WhConstraint::Between(0.0, 200.0));
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(3)].preferred_width, WhConstraint::Between(0.0, 200.0));
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(4)].preferred_width, WhConstraint::Between(0.0, 200.0));
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(5)].preferred_width, WhConstraint::Between(0.0, window_width));
 
-    // Test the flex-basis sum
-    assert_eq!(width_calculated_rect_arena_sum_children_flex_basis(&mut width_filled_out_data.as_ref_mut(), NodeId::new(2), &node_hierarchy.as_ref(), &layout_positions.as_ref()), 0.0);
-    assert_eq!(width_calculated_rect_arena_sum_children_flex_basis(&mut width_filled_out_data.as_ref_mut(), NodeId::new(1), &node_hierarchy.as_ref(), &layout_positions.as_ref()), 0.0);
-    assert_eq!(width_calculated_rect_arena_sum_children_flex_basis(&mut width_filled_out_data.as_ref_mut(), NodeId::new(0), &node_hierarchy.as_ref(), &layout_positions.as_ref()), 40.0);
+    let layout_positions = get_layout_positions(&styled_dom);
+    let layout_flex_grows = get_layout_flex_grows(&styled_dom);
+    let layout_directions = get_layout_flex_directions(&styled_dom);
+    let layout_displays = get_layout_displays(&styled_dom);
 
     // -- Section 2: Test that size-bubbling works:
     //
     // Size-bubbling should take the 40px padding and "bubble" it towards the
 
     // ID 5 has no child, so it's not returned, same as 3 and 4
-    assert_eq!(node_depths, vec![
+    assert_eq!(node_depths, &[
         ParentWithNodeDepth { depth: 0, node_id: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(0))) },
         ParentWithNodeDepth { depth: 1, node_id: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(1))) },
         ParentWithNodeDepth { depth: 2, node_id: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(2))) },
     ]);
 
-    bubble_preferred_widths_to_parents(&mut width_filled_out_data.as_ref_mut(), &node_hierarchy.as_ref(), &layout_positions.as_ref(), &node_depths);
+    bubble_preferred_widths_to_parents(
+        &mut width_filled_out_data.as_ref_mut(),
+        &node_hierarchy,
+        &layout_positions.as_ref(),
+        &layout_directions.as_ref(),
+        &wh_configs.as_ref(),
+        node_depths,
+        window_width,
+    );
 
     // This step shouldn't have touched the flex_grow_px
     for node in &width_filled_out_data.internal {
@@ -361,17 +251,18 @@ fn test_fill_out_preferred_width() {
     }
 
     // This step should not modify the `preferred_width`
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(0)].preferred_width, WhConstraint::Unconstrained);
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(0)].preferred_width, WhConstraint::Between(0.0, window_width));
     assert_eq!(width_filled_out_data.as_ref()[NodeId::new(1)].preferred_width, WhConstraint::Between(0.0, 200.0));
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(2)].preferred_width, WhConstraint::Unconstrained);
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(3)].preferred_width, WhConstraint::Unconstrained);
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(4)].preferred_width, WhConstraint::Unconstrained);
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(5)].preferred_width, WhConstraint::Unconstrained);
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(2)].preferred_width, WhConstraint::Between(0.0, 200.0));
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(3)].preferred_width, WhConstraint::Between(0.0, 200.0));
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(4)].preferred_width, WhConstraint::Between(0.0, 200.0));
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(5)].preferred_width, WhConstraint::Between(0.0, window_width));
 
     // The padding of the Node 1 should have bubbled up to be the minimum width of Node 0
     assert_eq!(width_filled_out_data.as_ref()[NodeId::new(0)].min_inner_size_px, 40.0);
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(1)].get_flex_basis_horizontal(800.0), 40.0);
-    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(1)].min_inner_size_px, 0.0);
+    // The minimum width of Node 1 now contains its own padding
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(1)].get_flex_basis_horizontal(800.0), 80.0);
+    assert_eq!(width_filled_out_data.as_ref()[NodeId::new(1)].min_inner_size_px, 40.0);
     assert_eq!(width_filled_out_data.as_ref()[NodeId::new(2)].get_flex_basis_horizontal(800.0), 0.0);
     assert_eq!(width_filled_out_data.as_ref()[NodeId::new(2)].min_inner_size_px, 0.0);
     assert_eq!(width_filled_out_data.as_ref()[NodeId::new(3)].get_flex_basis_horizontal(800.0), 0.0);
@@ -386,19 +277,20 @@ fn test_fill_out_preferred_width() {
     // - window_width: 754px
     // 0                -- [] - expecting width to stretch to 754 px
     // '- 1             -- [max-width: 200px; padding: 20px] - expecting width to stretch to 200 px
-    //    '-- 2         -- [] - expecting width to stretch to 160px
-    //    '   '-- 3     -- [] - expecting width to stretch to 80px (half of 160)
-    //    '   '-- 4     -- [] - expecting width to stretch to 80px (half of 160)
-    //    '-- 5         -- [] - expecting width to stretch to 554px (754 - 200px max-width of earlier sibling)
+    // '  '-- 2         -- [] - expecting width to stretch to 160px
+    // '      '-- 3     -- [] - expecting width to stretch to 80px (half of 160)
+    // '      '-- 4     -- [] - expecting width to stretch to 80px (half of 160)
+    // '- 5             -- [] - expecting width to stretch to 554px (754 - 200px max-width of earlier sibling)
 
     let parents_to_recalc = node_depths.iter().filter_map(|n| n.node_id.into_crate_internal()).collect::<BTreeSet<_>>();
     width_calculated_rect_arena_apply_flex_grow(
-        &mut width_filled_out_data.as_ref_mut(),
-        &node_hierarchy.as_ref(),
+        &mut width_filled_out_data,
+        &node_hierarchy,
+        &layout_displays.as_ref(),
         &layout_flex_grows.as_ref(),
         &layout_positions.as_ref(),
         &layout_directions.as_ref(),
-        &node_depths,
+        node_depths,
         window_width,
         &parents_to_recalc
     );
@@ -408,8 +300,8 @@ fn test_fill_out_preferred_width() {
         space_added: window_width - 40.0,
     });
     assert_eq!(width_filled_out_data.as_ref()[NodeId::new(1)].solved_result(), WidthSolvedResult {
-        min_width: 0.0,
-        space_added: 200.0,
+        min_width: 40.0,
+        space_added: 200.0 - 40.0,
     });
     assert_eq!(width_filled_out_data.as_ref()[NodeId::new(2)].solved_result(), WidthSolvedResult {
         min_width: 0.0,
@@ -452,13 +452,13 @@ fn test_split_words() {
 
     fn print_words(w: &Words) {
         println!("-- string: {:?}", w.get_str());
-        for item in &w.items {
+        for item in w.items.iter() {
             println!("{:?} - ({}..{}) = {:?}", w.get_substr(item), item.start, item.end, item.word_type);
         }
     }
 
-    fn string_to_vec(s: String) -> Vec<char> {
-        s.chars().collect()
+    fn string_to_vec(s: String) -> azul_css::U32Vec {
+        s.chars().map(|c| c as u32).collect()
     }
 
     fn assert_words(expected: &Words, got_words: &Words) {
@@ -477,7 +477,7 @@ fn test_split_words() {
     let ascii_str = String::from("abc\tdef  \nghi\r\njkl");
     let words_ascii = split_text_into_words(&ascii_str);
     let words_ascii_expected = Words {
-        internal_str: ascii_str.clone(),
+        internal_str: ascii_str.clone().into(),
         internal_chars: string_to_vec(ascii_str),
         items: vec![
             Word { start: 0,    end: 3,     word_type: WordType::Word     }, // "abc" - (0..3) = Word
@@ -489,7 +489,7 @@ fn test_split_words() {
             Word { start: 10,   end: 13,    word_type: WordType::Word     }, // "ghi" - (10..13) = Word
             Word { start: 13,   end: 15,    word_type: WordType::Return   }, // "\r\n" - (13..15) = Return
             Word { start: 15,   end: 18,    word_type: WordType::Word     }, // "jkl" - (15..18) = Word
-        ],
+        ].into(),
     };
 
     assert_words(&words_ascii_expected, &words_ascii);
@@ -497,13 +497,13 @@ fn test_split_words() {
     let unicode_str = String::from("㌊㌋㌌㌍㌎㌏㌐㌑ ㌒㌓㌔㌕㌖㌗");
     let words_unicode = split_text_into_words(&unicode_str);
     let words_unicode_expected = Words {
-        internal_str: unicode_str.clone(),
+        internal_str: unicode_str.clone().into(),
         internal_chars: string_to_vec(unicode_str),
         items: vec![
             Word { start: 0,        end: 8,         word_type: WordType::Word   }, // "㌊㌋㌌㌍㌎㌏㌐㌑"
             Word { start: 8,        end: 9,         word_type: WordType::Space  }, // " "
             Word { start: 9,        end: 15,        word_type: WordType::Word   }, // "㌒㌓㌔㌕㌖㌗"
-        ],
+        ].into(),
     };
 
     assert_words(&words_unicode_expected, &words_unicode);
@@ -511,11 +511,11 @@ fn test_split_words() {
     let single_str = String::from("A");
     let words_single_str = split_text_into_words(&single_str);
     let words_single_str_expected = Words {
-        internal_str: single_str.clone(),
+        internal_str: single_str.clone().into(),
         internal_chars: string_to_vec(single_str),
         items: vec![
             Word { start: 0,        end: 1,         word_type: WordType::Word   }, // "A"
-        ],
+        ].into(),
     };
 
     assert_words(&words_single_str_expected, &words_single_str);
@@ -527,133 +527,118 @@ fn test_split_words() {
 // |+ ------>|+
 // |         |
 // +---------+
-// rectangle: 100x200
+// word: 200px wide
 // max-width: none, line-height 1.0, font-size: 20
 // cursor is at: 0x, 20y
-// expect cursor to advance to 100x, 20y
+// expect cursor to advance to 200x, 20y
 //
 #[test]
-fn test_caret_intersects_with_holes_1() {
+fn test_line_caret_intersection_no_max_width() {
     let line_caret_x = 0.0;
-    let line_number = 0;
-    let font_size_px = 20.0;
-    let line_height_px = 0.0;
+    let line_caret_y = 20.0;
+    let word_width = 200.0;
+    let line_height_px = 20.0;
     let max_width = None;
-    let holes = vec![LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(200.0, 100.0))];
 
-    let result = caret_intersects_with_holes(
+    let result = LineCaretIntersection::new(
         line_caret_x,
-        line_number,
-        font_size_px,
+        word_width,
+        line_caret_y,
         line_height_px,
-        &holes,
         max_width,
     );
 
-    assert_eq!(result, LineCaretIntersection::AdvanceCaretTo(200.0));
+    assert_eq!(result, LineCaretIntersection::NoLineBreak { new_x: 200.0, new_y: 20.0 });
 }
 
 // Scenario 2:
 //
 // +---------+
-// |+ -----> |
-// |-------> |
-// |---------|
+// |    + ---|--->
 // |+        |
 // |         |
 // +---------+
-// rectangle: 100x200
+// word: 200px wide
 // max-width: 200px, line-height 1.0, font-size: 20
-// cursor is at: 0x, 20y
-// expect cursor to advance to 0x, 100y (+= 4 lines)
+// cursor is at: 100x, 20y
+// expect the word to break onto the next line: 0x, 40y
 //
 #[test]
-fn test_caret_intersects_with_holes_2() {
-    let line_caret_x = 0.0;
-    let line_number = 0;
-    let font_size_px = 20.0;
-    let line_height_px = 0.0;
+fn test_line_caret_intersection_breaks_at_max_width() {
+    let line_caret_x = 100.0;
+    let line_caret_y = 20.0;
+    let word_width = 200.0;
+    let line_height_px = 20.0;
     let max_width = Some(200.0);
-    let holes = vec![LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(200.0, 100.0))];
 
-    let result = caret_intersects_with_holes(
+    let result = LineCaretIntersection::new(
         line_caret_x,
-        line_number,
-        font_size_px,
+        word_width,
+        line_caret_y,
         line_height_px,
-        &holes,
         max_width,
     );
 
-    assert_eq!(result, LineCaretIntersection::PushCaretOntoNextLine(4, 0.0));
+    assert_eq!(result, LineCaretIntersection::LineBreak { new_x: 0.0, new_y: 40.0 });
 }
 
 // Scenario 3:
 //
 // +----------------+
-// |      |         |  +----->
-// |------->+       |
-// |------+         |
-// |                |
+// |                |  +----->
+// |+               |
 // |                |
 // +----------------+
-// rectangle: 100x200
+// word: 200px wide
 // max-width: 400px, line-height 1.0, font-size: 20
 // cursor is at: 450x, 20y
-// expect cursor to advance to 200x, 40y (+= 1 lines, leading of 200px)
+// expect the word to break onto the next line: 0x, 40y
 //
 #[test]
-fn test_caret_intersects_with_holes_3() {
+fn test_line_caret_intersection_breaks_past_max_width() {
     let line_caret_x = 450.0;
-    let line_number = 0;
-    let font_size_px = 20.0;
-    let line_height_px = 0.0;
+    let line_caret_y = 20.0;
+    let word_width = 200.0;
+    let line_height_px = 20.0;
     let max_width = Some(400.0);
-    let holes = vec![LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(200.0, 100.0))];
 
-    let result = caret_intersects_with_holes(
+    let result = LineCaretIntersection::new(
         line_caret_x,
-        line_number,
-        font_size_px,
+        word_width,
+        line_caret_y,
         line_height_px,
-        &holes,
         max_width,
     );
 
-    assert_eq!(result, LineCaretIntersection::PushCaretOntoNextLine(1, 200.0));
+    assert_eq!(result, LineCaretIntersection::LineBreak { new_x: 0.0, new_y: 40.0 });
 }
 
 // Scenario 4:
 //
 // +----------------+
-// | +   +------+   |
-// |     |      |   |
-// |     |      |   |
-// |     +------+   |
+// | +---->         |
 // |                |
 // +----------------+
-// rectangle: 100x200 @ 80.0x, 20.0y
+// word: 200px wide
 // max-width: 400px, line-height 1.0, font-size: 20
 // cursor is at: 40x, 20y
-// expect cursor to not advance at all
+// expect the cursor to advance on the same line: 240x, 20y
 //
 #[test]
-fn test_caret_intersects_with_holes_4() {
+fn test_line_caret_intersection_same_line() {
     let line_caret_x = 40.0;
-    let line_number = 0;
-    let font_size_px = 20.0;
-    let line_height_px = 0.0;
+    let line_caret_y = 20.0;
+    let word_width = 200.0;
+    let line_height_px = 20.0;
     let max_width = Some(400.0);
-    let holes = vec![LogicalRect::new(LogicalPosition::new(80.0, 20.0), LogicalSize::new(200.0, 100.0))];
 
-    let result = caret_intersects_with_holes(
+    let result = LineCaretIntersection::new(
         line_caret_x,
-        line_number,
-        font_size_px,
+        word_width,
+        line_caret_y,
         line_height_px,
-        &holes,
         max_width,
     );
 
-    assert_eq!(result, LineCaretIntersection::NoIntersection);
+    assert_eq!(result, LineCaretIntersection::NoLineBreak { new_x: 240.0, new_y: 20.0 });
 }
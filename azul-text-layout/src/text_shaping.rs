@@ -468,7 +468,7 @@ impl ShapedTextBufferUnsized {
 ///
 /// Example:
 ///
-/// ```
+/// ```rust,ignore
 /// assert_eq!(tag!(b"glyf"), 0x676C7966);
 /// ```
 macro_rules! tag {
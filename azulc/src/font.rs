@@ -295,166 +295,129 @@ fn parse_fc_match_font(input: &str) -> Option<&str> {
 }
 
 // Test that the font gets garbage collected correctly
+#[cfg(feature = "text_layout")]
 #[test]
 fn test_font_gc() {
 
-    use core::{
-        collections::BTreeMap,
-        hash::Hash,
-    };
-    use alloc::sync::Arc;
     use azul_core::{
-        FastHashMap, FastHashSet,
-        ui_description::UiDescription,
-        ui_state::UiState,
         app_resources::{
-            AppResources, Au, FakeRenderApi,
-            scan_ui_description_for_image_keys,
-            scan_ui_description_for_font_keys,
-            garbage_collect_fonts_and_images,
-            add_fonts_and_images, FontMetrics,
+            DpiScaleFactor, Epoch, GlTextureCache, IdNamespace,
+            ImageCache, LoadedFontSource, RendererResources, ResourceUpdate,
         },
-        display_list::DisplayList,
+        callbacks::DocumentId,
+        display_list::{RenderCallbacks, SolvedLayout},
+        dom::{Dom, IdOrClass},
+        window::FullWindowState,
     };
-    use crate::xml::DomXml;
+    use azul_css::{FloatValue, StyleFontFamily};
+    use azul_css_parser::CssApiWrapper;
 
-    fn build_map<T: Hash + Eq, U>(i: Vec<(T, U)>) -> FastHashMap<T, U> {
-        let mut map = FastHashMap::default();
-        for (k, v) in i { map.insert(k, v); }
-        map
+    // Every font family resolves to the same font file
+    fn fake_load_font_fn(_: &StyleFontFamily, _: &FcFontCache) -> Option<LoadedFontSource> {
+        Some(LoadedFontSource {
+            data: include_bytes!("../../examples/assets/fonts/KoHo-Light.ttf").to_vec().into(),
+            index: 0,
+            load_outlines: false,
+        })
     }
 
-    fn build_set<T: Hash + Eq>(i: Vec<T>) -> FastHashSet<T> {
-        let mut set = FastHashSet::default();
-        for x in i { set.insert(x); }
-        set
+    fn text(id: Option<&str>) -> Dom {
+        let dom = Dom::text("Hello");
+        match id {
+            Some(id) => dom.with_ids_and_classes(vec![IdOrClass::Id(id.into())].into()),
+            None => dom,
+        }
     }
 
-    fn build_ui(xml: &str, css: &str) -> (UiState<Mock>, UiDescription, DisplayList) {
-
-        use azul_css::from_str as css_from_str;
-
-        let is_mouse_down = false;
-        let focused_node = None;
-        let hovered_nodes = BTreeMap::new();
-        let css = css_from_str(css).unwrap();
-
-        let mut ui_state = UiState::new(DomXml::mock(xml).into_dom(), None);
-        let ui_description = UiDescription::new(&mut ui_state, &css, &focused_node, &hovered_nodes, is_mouse_down);
-        let display_list = DisplayList::new(&ui_description, &ui_state);
-
-        (ui_state, ui_description, display_list)
+    struct Renderer {
+        renderer_resources: RendererResources,
+        registered_fonts: usize,
+        epoch: Epoch,
     }
 
-    #[cfg(feature = "font_loading")]
-    fn fake_load_font_fn(_f: &FontSource) -> Option<LoadedFontSource> {
-        Some(LoadedFontSource {
-            font_bytes: Vec::new(),
-            font_index: 0,
-            font_metrics: FontMetrics::zero(),
-        })
-    }
+    impl Renderer {
+        /// Lays out one frame, runs the GC and returns the
+        /// number of fonts that are registered in the RenderApi
+        fn frame(&mut self, children: Vec<Dom>) -> usize {
+
+            let css = r#"
+                #one { font-family: Helvetica; }
+                #two { font-family: Arial; }
+                #three { font-family: Times New Roman; }
+            "#;
+
+            let document_id = DocumentId { namespace_id: IdNamespace(0), id: 0 };
+            let full_window_state = FullWindowState::default();
+            let image_cache = ImageCache::default();
+            let fc_cache = FcFontCache::default();
+            let callbacks = RenderCallbacks {
+                insert_into_active_gl_textures_fn: azul_core::gl::insert_into_active_gl_textures,
+                layout_fn: azul_layout::do_the_layout,
+                load_font_fn: fake_load_font_fn,
+                parse_font_fn: azul_layout::parse_font_fn,
+            };
+
+            let styled_dom = Dom::body()
+                .with_children(children.into())
+                .style(CssApiWrapper::from_string(css.into()));
+
+            let mut resource_updates = Vec::new();
+            let solved_layout = SolvedLayout::new(
+                styled_dom,
+                self.epoch,
+                &document_id,
+                &full_window_state,
+                &mut resource_updates,
+                IdNamespace(0),
+                &image_cache,
+                &fc_cache,
+                &callbacks,
+                &mut self.renderer_resources,
+                DpiScaleFactor { inner: FloatValue::new(1.0) },
+            );
+
+            self.renderer_resources.do_gc(
+                &mut resource_updates,
+                &image_cache,
+                &solved_layout.layout_results,
+                &GlTextureCache::empty(),
+            );
+            self.epoch.increment();
+
+            for update in resource_updates.iter() {
+                match update {
+                    ResourceUpdate::AddFont(_) => self.registered_fonts += 1,
+                    ResourceUpdate::DeleteFont(_) => self.registered_fonts -= 1,
+                    _ => { },
+                }
+            }
 
-    #[cfg(feature = "image_loading")]
-    fn fake_load_image_font_fn(_i: &ImageSource) -> Option<LoadedImageSource> {
-        Some(LoadedImageSource {
-            image_bytes_decoded: ImageData::Raw(Arc::new(Vec::new())),
-            image_descriptor: ImageDescriptor {
-                format: RawImageFormat::R8,
-                dimensions: (0, 0),
-                stride: None,
-                offset: 0,
-                is_opaque: true,
-                allow_mipmaps: false,
-            },
-        })
+            self.registered_fonts
+        }
     }
 
-    struct Mock;
-
-    use azul_core::ui_solver::DEFAULT_FONT_SIZE_PX;
-
-    const DEFAULT_FONT_SIZE: f32 = DEFAULT_FONT_SIZE_PX as f32;
-
-    let pipeline_id = PipelineId::new();
-    let mut app_resources = AppResources::new();
-    app_resources.add_pipeline(pipeline_id);
-
-    let css = r#"
-        #one { font-family: Helvetica; }
-        #two { font-family: Arial; }
-        #three { font-family: Times New Roman; }
-    "#;
-
-    let (ui_state_frame_1, _, display_list_frame_1) = build_ui(r#"
-        <p id="one">Hello</p>
-        <p id="two">Hello</p>
-        <p id="three">Hello</p>
-    "#, css);
-
-    let (ui_state_frame_2, _, display_list_frame_2) = build_ui(r#"
-        <p>Hello</p>
-    "#, css);
-
-    let (ui_state_frame_3, _, display_list_frame_3) = build_ui(r#"
-        <p id="one">Hello</p>
-        <p id="two">Hello</p>
-        <p id="three">Hello</p>
-    "#, css);
-
-    let node_data_1 = &ui_state_frame_1.get_dom().arena.node_data;
-    let node_data_2 = &ui_state_frame_2.get_dom().arena.node_data;
-    let node_data_3 = &ui_state_frame_3.get_dom().arena.node_data;
-
-    // Assert that the UI doesn't contain any images
-    assert_eq!(scan_ui_description_for_image_keys(&app_resources, &display_list_frame_1, &node_data_1), FastHashSet::default());
-    assert_eq!(scan_ui_description_for_image_keys(&app_resources, &display_list_frame_2, &node_data_2), FastHashSet::default());
-    assert_eq!(scan_ui_description_for_image_keys(&app_resources, &display_list_frame_3, &node_data_3), FastHashSet::default());
-
-    assert_eq!(scan_ui_description_for_font_keys(&app_resources, &display_list_frame_1, &node_data_1), build_map(vec![
-        (ImmediateFontId::Unresolved("Arial".to_string()), build_set(vec![Au::from_px(DEFAULT_FONT_SIZE)])),
-        (ImmediateFontId::Unresolved("Helvetica".to_string()), build_set(vec![Au::from_px(DEFAULT_FONT_SIZE)])),
-        (ImmediateFontId::Unresolved("Times New Roman".to_string()), build_set(vec![Au::from_px(DEFAULT_FONT_SIZE)])),
-    ]));
-    assert_eq!(scan_ui_description_for_font_keys(&app_resources, &display_list_frame_2, &node_data_2), build_map(vec![
-        (ImmediateFontId::Unresolved("serif".to_string()), build_set(vec![Au::from_px(DEFAULT_FONT_SIZE)])),
-    ]));
-    assert_eq!(scan_ui_description_for_font_keys(&app_resources, &display_list_frame_3, &node_data_3), build_map(vec![
-        (ImmediateFontId::Unresolved("Arial".to_string()), build_set(vec![Au::from_px(DEFAULT_FONT_SIZE)])),
-        (ImmediateFontId::Unresolved("Helvetica".to_string()), build_set(vec![Au::from_px(DEFAULT_FONT_SIZE)])),
-        (ImmediateFontId::Unresolved("Times New Roman".to_string()), build_set(vec![Au::from_px(DEFAULT_FONT_SIZE)])),
-    ]));
-
-    let mut fake_render_api = FakeRenderApi::new();
-
-    add_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id, &display_list_frame_1, &node_data_1, fake_load_font_fn, fake_load_image_font_fn);
-    assert_eq!(app_resources.currently_registered_fonts[&pipeline_id].len(), 3);
-    assert_eq!(app_resources.last_frame_font_keys[&pipeline_id].len(), 3);
+    let frame_1 = || vec![text(Some("one")), text(Some("two")), text(Some("three"))];
+    let frame_2 = || vec![text(None)];
+
+    let mut renderer = Renderer {
+        renderer_resources: RendererResources::default(),
+        registered_fonts: 0,
+        epoch: Epoch::new(),
+    };
 
     // Assert that the first frame doesn't delete the fonts again
-    garbage_collect_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id);
-    assert_eq!(app_resources.currently_registered_fonts[&pipeline_id].len(), 3);
+    assert_eq!(renderer.frame(frame_1()), 3);
 
     // Assert that fonts don't get double-inserted, still the same font sources as previously
-    add_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id, &display_list_frame_3, &node_data_3, fake_load_font_fn, fake_load_image_font_fn);
-    garbage_collect_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id);
-    assert_eq!(app_resources.currently_registered_fonts[&pipeline_id].len(), 3);
+    assert_eq!(renderer.frame(frame_1()), 3);
 
     // Assert that no new fonts get added on subsequent frames
-    add_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id, &display_list_frame_3, &node_data_3, fake_load_font_fn, fake_load_image_font_fn);
-    add_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id, &display_list_frame_3, &node_data_3, fake_load_font_fn, fake_load_image_font_fn);
-    add_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id, &display_list_frame_3, &node_data_3, fake_load_font_fn, fake_load_image_font_fn);
-    add_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id, &display_list_frame_3, &node_data_3, fake_load_font_fn, fake_load_image_font_fn);
-    add_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id, &display_list_frame_3, &node_data_3, fake_load_font_fn, fake_load_image_font_fn);
-    garbage_collect_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id);
-    assert_eq!(app_resources.currently_registered_fonts[&pipeline_id].len(), 3);
-
-    // If the DOM changes, the fonts should get deleted, the only font still present is "sans-serif"
-    add_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id, &display_list_frame_2, &node_data_2, fake_load_font_fn, fake_load_image_font_fn);
-    garbage_collect_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id);
-    assert_eq!(app_resources.currently_registered_fonts[&pipeline_id].len(), 1);
-
-    add_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id, &display_list_frame_1, &node_data_1, fake_load_font_fn, fake_load_image_font_fn);
-    garbage_collect_fonts_and_images(&mut app_resources, &mut fake_render_api, &pipeline_id);
-    assert_eq!(app_resources.currently_registered_fonts[&pipeline_id].len(), 3);
-}
\ No newline at end of file
+    for _ in 0..5 {
+        assert_eq!(renderer.frame(frame_1()), 3);
+    }
+
+    // If the DOM changes, the fonts should get deleted, the only font still present is the default font
+    assert_eq!(renderer.frame(frame_2()), 1);
+
+    assert_eq!(renderer.frame(frame_1()), 3);
+}
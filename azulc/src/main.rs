@@ -6,6 +6,7 @@ use std::fs;
 use std::path::Path;
 use std::process::exit;
 
+use azul_css::FloatValue;
 use azul_core::{
     gl::OptionGlContextPtr,
     window::FullWindowState,
@@ -15,7 +16,7 @@ use azul_core::{
     callbacks::{PipelineId, DocumentId},
     ui_solver::LayoutResult,
     app_resources::{
-        IdNamespace, LoadFontFn,
+        IdNamespace, LoadFontFn, DpiScaleFactor,
         Epoch, RendererResources,
         ImageCache, GlTextureCache,
     },
@@ -287,6 +288,7 @@ fn solve_layout(
         &fc_cache,
         &callbacks,
         renderer_resources,
+        DpiScaleFactor { inner: FloatValue::new(fake_window_state.size.get_hidpi_factor()) },
     );
 
     solved_layout.layout_results.remove(0)
@@ -302,8 +304,8 @@ fn layout_result_print_layout(result: &LayoutResult) -> String {
 
         let parent_node_id = match node_id.into_crate_internal() { Some(s) => s, None => continue, };
         let tabs = "    ".repeat(*depth);
-        let width = result.width_calculated_rects.as_ref()[parent_node_id];
-        let height = result.height_calculated_rects.as_ref()[parent_node_id];
+        let width = &result.width_calculated_rects.as_ref()[parent_node_id];
+        let height = &result.height_calculated_rects.as_ref()[parent_node_id];
         let x_pos = result.solved_pos_x.as_ref()[parent_node_id].0;
         let y_pos = result.solved_pos_y.as_ref()[parent_node_id].0;
 
@@ -315,8 +317,8 @@ fn layout_result_print_layout(result: &LayoutResult) -> String {
         for child_id in parent_node_id.az_children(&result.styled_dom.node_hierarchy.as_container()) {
 
             let tabs = "    ".repeat(*depth + 1);
-            let width = result.width_calculated_rects.as_ref()[child_id];
-            let height = result.height_calculated_rects.as_ref()[child_id];
+            let width = &result.width_calculated_rects.as_ref()[child_id];
+            let height = &result.height_calculated_rects.as_ref()[child_id];
             let x_pos = result.solved_pos_x.as_ref()[child_id].0;
             let y_pos = result.solved_pos_y.as_ref()[child_id].0;
 
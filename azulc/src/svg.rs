@@ -1909,6 +1909,17 @@ pub fn svg_root(s: &Svg) -> SvgXmlNode {
     SvgXmlNode { node: core::ptr::null_mut(), run_destructor: false }
 }
 
+/// Node without children or attributes (an empty group)
+#[cfg(feature = "svg")]
+pub fn svgxmlnode_empty() -> SvgXmlNode {
+    svgxmlnode_new(usvg::Node::new(usvg::NodeKind::Group(usvg::Group::default())))
+}
+
+#[cfg(not(feature = "svg"))]
+pub fn svgxmlnode_empty() -> SvgXmlNode {
+    SvgXmlNode { node: core::ptr::null_mut(), run_destructor: false }
+}
+
 #[cfg(feature = "svg")]
 pub fn svg_render(s: &Svg, options: SvgRenderOptions) -> Option<RawImage> {
     use tiny_skia::Pixmap;
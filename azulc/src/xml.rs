@@ -77,16 +77,13 @@ pub fn domxml_from_file<I: AsRef<Path>>(file_path: I, component_map: &mut XmlCom
 /// # Example
 ///
 /// ```rust
-/// # use azulc::xml::{XmlNode, parse_xml_string};
-/// assert_eq!(
-///     parse_xml_string("<app><p /><div id='thing' /></app>").unwrap(),
-///     vec![
-///          XmlNode::new("app").with_children(vec![
-///             XmlNode::new("p"),
-///             XmlNode::new("div").with_attribute("id", "thing"),
-///         ])
-///     ]
-/// )
+/// # use azulc_lib::xml::parse_xml_string;
+/// let nodes = parse_xml_string("<app><p /><div id='thing' /></app>").unwrap();
+/// let app = &nodes.as_ref()[0];
+/// assert_eq!(nodes.len(), 1);
+/// assert_eq!(app.node_type.as_str(), "app");
+/// assert_eq!(app.children.as_ref()[0].node_type.as_str(), "p");
+/// assert_eq!(app.children.as_ref()[1].attributes.get_key("id").map(|id| id.as_str()), Some("thing"));
 /// ```
 #[cfg(feature = "xml")]
 pub fn parse_xml_string(xml: &str) -> Result<XmlNodeVec, XmlError> {
@@ -1,5 +1,6 @@
 import json
 import re
+import hashlib
 import pprint
 import os
 import subprocess
@@ -59,10 +60,43 @@ rust_api_patches = {
     tuple(['css']): read_file(root_folder + "/api/_patches/azul.rs/css.rs"),
     tuple(['window']): read_file(root_folder + "/api/_patches/azul.rs/window.rs"),
     tuple(['callbacks']): read_file(root_folder + "/api/_patches/azul.rs/callbacks.rs"),
+    # already implemented by impl_vec! in vec.rs
+    tuple(['vec', 'StringVec', 'len']): "",
+    tuple(['vec', 'StringVec', 'get']): "",
+    tuple(['vec', 'CssPropertyVec', 'len']): "",
+    tuple(['vec', 'CssPropertyVec', 'get']): "",
+    tuple(['vec', 'NormalizedLinearColorStopVec', 'len']): "",
+    tuple(['vec', 'NormalizedLinearColorStopVec', 'get']): "",
 }
 
 # ---------------------------------------------------------------------------------------------
 
+# Returns the version of the azul-dll crate, as [major, minor, patch]
+def read_dll_version():
+    cargo_toml = read_file(root_folder + "/azul-dll/Cargo.toml")
+    version = re.search(r'^version = "(\d+)\.(\d+)\.(\d+)', cargo_toml, re.MULTILINE)
+    return [int(version.group(1)), int(version.group(2)), int(version.group(3))]
+
+# Hash over the binary interface of the API (struct fields, enum variants,
+# function names, arguments and return types), so that bindings can detect
+# if they were generated from a different API than the loaded library.
+#
+# Doc comments, function bodies and the paths of the external Rust types
+# don't change the ABI and are ignored
+def generate_abi_hash(api_data):
+
+    version = list(api_data.keys())[-1]
+
+    def strip_non_abi_keys(value):
+        if isinstance(value, dict):
+            return OrderedDict([(k, strip_non_abi_keys(v)) for k, v in value.items() if k not in ["doc", "fn_body", "external"]])
+        if isinstance(value, list):
+            return [strip_non_abi_keys(v) for v in value]
+        return value
+
+    abi = json.dumps(strip_non_abi_keys(api_data[version]), separators=(",", ":"))
+    return int(hashlib.sha256(abi.encode("utf-8")).hexdigest()[:16], 16)
+
 def snake_case_to_lower_camel(snake_str):
     first, *others = snake_str.split('_')
     return ''.join([first.lower(), *map(str.title, others)])
@@ -181,12 +215,14 @@ def get_all_imports(apiData, module, module_name):
 
     return imports_str
 
-def fn_args_c_api(f, class_name, class_ptr_name, self_as_first_arg, apiData):
+def fn_args_c_api(f, class_name, class_ptr_name, self_as_first_arg, apiData, self_is_ffi_ptr=False):
     fn_args = ""
 
     if self_as_first_arg:
         self_val = list(f["fn_args"][0].values())[0]
-        if (self_val == "value"):
+        if self_is_ffi_ptr:
+            fn_args += class_name.lower() + ": " + class_ptr_name + "Ptr, "
+        elif (self_val == "value"):
             fn_args += class_name.lower() + ": " + class_ptr_name + ", "
         elif (self_val == "mut value"):
             fn_args += "mut " + class_name.lower() + ": " + class_ptr_name + ", "
@@ -221,12 +257,14 @@ def fn_args_c_api(f, class_name, class_ptr_name, self_as_first_arg, apiData):
 
     return fn_args
 
-def c_fn_args_c_api(f, class_name, class_ptr_name, self_as_first_arg):
+def c_fn_args_c_api(f, class_name, class_ptr_name, self_as_first_arg, self_is_ffi_ptr=False):
     fn_args = ""
 
     if self_as_first_arg:
         self_val = list(f["fn_args"][0].values())[0]
-        if (self_val == "value"):
+        if self_is_ffi_ptr:
+            fn_args += class_ptr_name + "Ptr " + class_name.lower() + ", "
+        elif (self_val == "value"):
             fn_args += "const " + class_ptr_name + " " + class_name.lower() + ", "
         elif (self_val == "mut value"):
             fn_args += "restrict " + class_ptr_name + ": " + class_name.lower() + ", "
@@ -249,12 +287,16 @@ def c_fn_args_c_api(f, class_name, class_ptr_name, self_as_first_arg):
             arg_type = analyzed_arg_type[1]
 
             if is_primitive_arg(arg_type):
-                if ptr_type == "*const":
-                    fn_args += "const" + replace_primitive_ctype(arg_type) + "* " + arg_name + ", " # no pre, no postfix
-                elif ptr_type == "*mut":
-                    fn_args += replace_primitive_ctype(arg_type) + "* restrict" + " " + arg_name + ", " # no pre, no postfix
+                if ptr_type == "*const ":
+                    fn_args += "const " + replace_primitive_ctype(arg_type).strip() + "* " + arg_name + ", " # no pre, no postfix
+                elif ptr_type == "*mut ":
+                    fn_args += replace_primitive_ctype(arg_type).strip() + "* restrict" + " " + arg_name + ", " # no pre, no postfix
                 else:
                     fn_args += replace_primitive_ctype(arg_type) + " " + arg_name + ", " # no pre, no postfix
+            elif ptr_type == "&":
+                fn_args += "const " + prefix + arg_type + "* " + arg_name + ", "
+            elif ptr_type == "&mut ":
+                fn_args += prefix + arg_type + "* restrict " + arg_name + ", "
             else:
                 fn_args += prefix + replace_primitive_ctype(arg_type) + replace_primitive_ctype(ptr_type).strip() + " " + arg_name + ", " # no postfix
 
@@ -308,6 +350,17 @@ def class_is_stack_allocated(c):
     class_is_boxed_object = not("external" in c.keys() and ("struct_fields" in c.keys() or "enum_fields" in c.keys() or "callback_typedef" in c.keys() or "const" in c.keys()))
     return not(class_is_boxed_object)
 
+# Classes with an "external" type, but without any fields are opaque handles
+# (`AzFooPtr` = `*mut AzFoo`) managed by `impl_ffi_ptr!` in the DLL. Constructors
+# return the handle (the "fn_body" has to create it with `az_foo::new`), functions
+# take the handle instead of `self` and `AzFoo_delete(AzFooPtr)` frees it.
+def class_is_ffi_ptr(c):
+    return "external" in c.keys() and not(class_is_stack_allocated(c)) and not("is_boxed_object" in c.keys() and c["is_boxed_object"])
+
+# "TestHarness" => "az_test_harness", name of the module generated by `impl_ffi_ptr!`
+def ffi_ptr_module_name(class_name):
+    return "az" + re.sub(r"([A-Z])", r"_\1", class_name).lower()
+
 # Same as calling get_class(search_class_by_name())
 def quick_get_class(api_data, searched_class_name):
     field_type_class_path = search_for_class_by_class_name(api_data, searched_class_name)
@@ -358,6 +411,28 @@ def class_is_virtual(api_data, className, api):
 
     return False
 
+# Returns the names of the arguments that are raw pointers: functions
+# taking raw pointers are "unsafe extern", the caller has to keep them valid
+def raw_pointer_fn_args(f):
+    raw_pointer_args = []
+    if "fn_args" in f.keys():
+        for arg_object in f["fn_args"]:
+            arg_name = list(arg_object.keys())[0]
+            if arg_name == "self":
+                continue
+            if analyze_type(arg_object[arg_name])[0] in ["*const ", "*mut "]:
+                raw_pointer_args.append(arg_name)
+    return raw_pointer_args
+
+# Returns the "# Safety" section of the doc comment and the "unsafe " prefix
+# of a function taking raw pointers, empty strings otherwise
+def rust_dll_fn_safety(raw_pointer_args):
+    if len(raw_pointer_args) == 0:
+        return ["", ""]
+    arg_names = ", ".join(["`" + a + "`" for a in raw_pointer_args])
+    doc = "///\r\n/// # Safety\r\n///\r\n/// " + arg_names + (" has" if len(raw_pointer_args) == 1 else " have") + " to be valid for the duration of the call\r\n"
+    return [doc, "unsafe "]
+
 # Generate the string for TAKING rust-api function arguments
 def rust_bindings_fn_args(f, class_name, class_ptr_name, self_as_first_arg, api_data):
     fn_args = ""
@@ -495,6 +570,24 @@ def generate_rust_dll(api_data):
     code += "pub mod widgets;\r\n"
     code += "#[cfg(all(feature = \"python-extension\", feature = \"link_dynamic\", not(feature = \"link-static\")))]\r\n"
     code += "pub mod python;\r\n"
+    code += "pub mod version;\r\n"
+    code += "pub mod error;\r\n"
+    code += "pub mod image;\r\n"
+    code += "pub mod app;\r\n"
+    code += "pub mod window;\r\n"
+    code += "pub mod ref_any;\r\n"
+    code += "pub mod ffi_ptr;\r\n"
+    code += "pub mod test_harness;\r\n"
+    code += "pub mod css;\r\n"
+    code += "pub mod accelerator;\r\n"
+    code += "pub mod menu;\r\n"
+    code += "pub mod thread;\r\n"
+    code += "pub mod snapshot;\r\n"
+    code += "\r\n"
+    code += "use error::ffi_guard;\r\n"
+    code += "\r\n"
+    code += "/// Hash over the binary interface of the API, see `AzApi_abiHash`\r\n"
+    code += "pub(crate) const AZ_API_ABI_HASH: u64 = 0x" + format(generate_abi_hash(api_data), "016x") + ";\r\n"
     code += "\r\n"
 
     myapi_data = api_data[version]
@@ -565,6 +658,11 @@ def generate_rust_dll(api_data):
                 external_path = c["external"]
                 if class_is_const:
                     code += "pub static " + class_ptr_name + ": " + prefix + c["const"] + " = " + external_path + ";\r\n"
+                elif class_is_ffi_ptr(c):
+                    code += "pub use " + external_path + " as " + class_ptr_name + ";\r\n"
+                    code += "ffi_ptr::impl_ffi_ptr!(" + class_ptr_name + ", " + class_ptr_name + "Ptr, " + ffi_ptr_module_name(class_name) + ");\r\n"
+                    code += "pub use " + ffi_ptr_module_name(class_name) + "::delete as " + class_ptr_name + "_delete;\r\n"
+                    rust_functions_map[str(class_ptr_name + "_delete")] = ["ptr: " + class_ptr_name + "Ptr", ""];
                 elif class_is_boxed_object:
                     structs_map[class_ptr_name] = {
                         "external": external_path,
@@ -623,7 +721,7 @@ def generate_rust_dll(api_data):
 
                     fn_body = ""
 
-                    if c_is_stack_allocated:
+                    if c_is_stack_allocated or class_is_ffi_ptr(c):
                         fn_body += const["fn_body"]
                     else:
                         fn_body += "let object: " + class_name + " = " + const["fn_body"] + "; " # note: security check, that the returned object is of the correct type
@@ -636,7 +734,7 @@ def generate_rust_dll(api_data):
                         code += "/// Creates a new `" + class_name + "` instance whose memory is owned by the rust allocator\r\n"
                         code += "/// Equivalent to the Rust `" + class_name  + "::" + fn_name + "()` constructor.\r\n"
 
-                    returns = class_ptr_name
+                    returns = class_ptr_name + ("Ptr" if class_is_ffi_ptr(c) else "")
                     if "returns" in const.keys():
                         return_type = const["returns"]["type"]
                         analyzed_return_type = analyze_type(return_type)
@@ -648,13 +746,17 @@ def generate_rust_dll(api_data):
                                 print("rust-dll: (line 549): no return_type_class found for " + return_type)
 
                             returns = analyzed_return_type[0] + prefix + return_type_class[1] + analyzed_return_type[2] # no postfix
+                            if class_is_ffi_ptr(get_class(myapi_data, return_type_class[0], return_type_class[1])):
+                                returns = prefix + return_type_class[1] + "Ptr"
 
 
                     fn_args = fn_args_c_api(const, class_name, class_ptr_name, False, myapi_data)
+                    fn_safety = rust_dll_fn_safety(raw_pointer_fn_args(const))
+                    code += fn_safety[0]
 
                     rust_functions_map[str(class_ptr_name + "_" + snake_case_to_lower_camel(fn_name))] = [fn_args, returns];
-                    code += "#[no_mangle] pub extern \"C\" fn " + class_ptr_name + "_" + snake_case_to_lower_camel(fn_name) + "(" + fn_args + ") -> " + returns + " { "
-                    code += fn_body
+                    code += "#[no_mangle] pub " + fn_safety[1] + "extern \"C\" fn " + class_ptr_name + "_" + snake_case_to_lower_camel(fn_name) + "(" + fn_args + ") -> " + returns + " { "
+                    code += "ffi_guard!(" + returns + ", { " + fn_body + " })"
                     code += " }\r\n"
 
             if "functions" in c.keys():
@@ -669,7 +771,10 @@ def generate_rust_dll(api_data):
                     else:
                         code += "/// Equivalent to the Rust `" + class_name  + "::" + fn_name + "()` function.\r\n"
 
-                    fn_args = fn_args_c_api(f, class_name, class_ptr_name, True, myapi_data)
+                    self_is_ffi_ptr = class_is_ffi_ptr(c)
+                    fn_args = fn_args_c_api(f, class_name, class_ptr_name, True, myapi_data, self_is_ffi_ptr)
+                    if self_is_ffi_ptr:
+                        fn_body = "let " + class_name.lower() + " = unsafe { " + ffi_ptr_module_name(class_name) + "::downcast(" + class_name.lower() + ", \"" + class_ptr_name + "_" + snake_case_to_lower_camel(fn_name) + "\") }; " + fn_body
 
                     returns = ""
                     if "returns" in f.keys():
@@ -683,11 +788,16 @@ def generate_rust_dll(api_data):
                                 print("rust-dll: (line 549): no return_type_class found for " + return_type)
 
                             returns = analyzed_return_type[0] + prefix + return_type_class[1] + analyzed_return_type[2] # no postfix
+                            if class_is_ffi_ptr(get_class(myapi_data, return_type_class[0], return_type_class[1])):
+                                returns = prefix + return_type_class[1] + "Ptr"
+
+                    fn_safety = rust_dll_fn_safety(([class_name.lower()] if self_is_ffi_ptr else []) + raw_pointer_fn_args(f))
+                    code += fn_safety[0]
 
                     rust_functions_map[str(class_ptr_name + "_" + snake_case_to_lower_camel(fn_name))] = [fn_args, returns];
                     return_arrow = "" if returns == "" else " -> "
-                    code += "#[no_mangle] pub extern \"C\" fn " + class_ptr_name + "_" + snake_case_to_lower_camel(fn_name) + "(" + fn_args + ")" + return_arrow + returns + " { "
-                    code += fn_body
+                    code += "#[no_mangle] pub " + fn_safety[1] + "extern \"C\" fn " + class_ptr_name + "_" + snake_case_to_lower_camel(fn_name) + "(" + fn_args + ")" + return_arrow + returns + " { "
+                    code += "ffi_guard!(" + ("()" if returns == "" else returns) + ", { " + fn_body + " })"
                     code += " }\r\n"
 
             if c_is_stack_allocated:
@@ -701,19 +811,21 @@ def generate_rust_dll(api_data):
                         rust_functions_map[str(class_ptr_name + "_delete")] = ["object: &mut " + class_ptr_name, ""];
                     code += "#[no_mangle] pub extern \"C\" fn " + class_ptr_name + "_delete(object: &mut " + class_ptr_name + ") { "
                     if is_boxed_object:
-                        code += " if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } }"
+                        code += "ffi_guard!((), { if object.run_destructor { unsafe { core::ptr::drop_in_place(object); } } })"
                     else:
-                        code += " unsafe { core::ptr::drop_in_place(object); } "
-                    code += "}\r\n"
+                        code += "ffi_guard!((), { unsafe { core::ptr::drop_in_place(object); } })"
+                    code += " }\r\n"
 
                 if treat_external_as_ptr and class_can_be_cloned:
                     # az_item_deepCopy()
                     code += "/// Clones the object\r\n"
                     rust_functions_map[str(class_ptr_name + "_deepCopy")] = ["object: &" + class_ptr_name, class_ptr_name];
                     code += "#[no_mangle] pub extern \"C\" fn " + class_ptr_name + "_deepCopy(object: &" + class_ptr_name + ") -> " + class_ptr_name + " { "
+                    # not guarded: clone() only copies or increments a refcount, it can't
+                    # panic - and handles such as AzGl have no fallback value to return
                     code += "object.clone()"
                     code += " }\r\n"
-            else:
+            elif not(class_is_ffi_ptr(c)):
                 raise Exception("type " + class_name + "is not stack allocated!")
 
     sort_structs_result = sort_structs_map(myapi_data, structs_map)
@@ -804,11 +916,13 @@ def sort_structs_map(api_data, structs_map):
         "AzDomVec": {"type": "struct", "name": "AzDom"},
         "AzMenuItemVec": {"type": "struct", "name": "AzMenuItem"},
         "AzXmlNodeVec": {"type": "struct", "name": "AzXmlNode"},
+        "AzGridTrackVec": {"type": "union", "name": "AzGridTrack"},
     }
     forward_delcarations = OrderedDict([
         ("AzDomVec", "Dom"),
         ("AzXmlNodeVec", "XmlNode"),
-        ("AzMenuItemVec", "MenuItem")
+        ("AzMenuItemVec", "MenuItem"),
+        ("AzGridTrackVec", "GridTrack")
     ])
 
     classes_not_found = OrderedDict([])
@@ -1205,6 +1319,13 @@ def generate_rust_dll_bindings(api_data, structs_map, functions_map):
     code += "    mod types {\r\n"
     code += "        use core::ffi::c_void;\r\n\r\n"
     code += generate_structs(api_data, structs_map, True, indent=8)
+    for module_name in api_data.keys():
+        module = api_data[module_name]["classes"]
+        for class_name in module.keys():
+            if class_is_ffi_ptr(module[class_name]):
+                code += "        /// Rust-allocated `" + class_name + "`, only accessible via `" + prefix + class_name + "Ptr`\r\n"
+                code += "        #[repr(C)] pub struct " + prefix + class_name + " { _opaque: [u8; 0] }\r\n"
+                code += "        pub type " + prefix + class_name + "Ptr = *mut " + prefix + class_name + ";\r\n"
     code += "    }\r\n\r\n"
 
     code += "    #[cfg(feature = \"link-static\")]\r\n"
@@ -1315,6 +1436,19 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("svg", "TessellatedColoredGPUSvgNode", "new"),
 
         ("task", "Timer", "new"),
+        ("vec", "StringVec", "new"), # ok: replaced by the Python array constructor
+        ("app", "Api", "version_string"),
+        ("app", "Api", "has_feature"),
+        ("window", "WindowCreateOptions", "set_layout_callback"),
+        ("window", "WindowCreateOptions", "add_accelerator"),
+        ("callbacks", "CallbackInfo", "add_accelerator"),
+        ("callbacks", "CallbackInfo", "replace_accelerator"),
+        ("task", "PendingThread", "new"),
+        ("callbacks", "CallbackInfo", "start_pending_thread"),
+        ("app", "App", "add_image_raw"),
+        ("app", "App", "update_image_raw"),
+        ("callbacks", "CallbackInfo", "add_image_raw"),
+        ("callbacks", "CallbackInfo", "update_image_raw"),
         ("callbacks", "CallbackInfo", "start_thread"),
         ("callbacks", "CallbackInfo", "get_node_id_of_root_dataset"),
         ("image", "ImageRef", "callback"),
@@ -1629,8 +1763,9 @@ def generate_python_api(api_data, structs_map, functions_map):
 
                 # Generate constructors
                 class_is_vec = class_name.endswith("Vec")
+                has_py_constructors = "constructors" in struct.keys() and any(not((module_name, class_name, c) in manual_implementations) for c in struct["constructors"])
                 while True:
-                    if (not("constructors") in struct.keys() or len(struct["constructors"]) == 0) and not(class_name in not_default_constructable.keys()):
+                    if (not("constructors") in struct.keys() or len(struct["constructors"]) == 0 or (class_is_vec and not(has_py_constructors))) and not(class_name in not_default_constructable.keys()):
                         py_new_constructor = ""
                         py_func_args = ""
 
@@ -2151,6 +2286,8 @@ def generate_rust_api(api_data, structs_map, functions_map):
                 code += "    /// `" + class_name + "` struct\r\n    "
 
             code += "\r\n    #[doc(inline)] pub use crate::dll::" + class_ptr_name + " as " + class_name + ";\r\n"
+            if class_is_ffi_ptr(c):
+                code += "    #[doc(inline)] pub use crate::dll::" + class_ptr_name + "Ptr as " + class_name + "Ptr;\r\n"
 
             has_constructors = ("constructors" in c.keys() and len(c["constructors"]) > 0)
             has_functions = ("functions" in c.keys() and len(c["functions"]) > 0)
@@ -2193,7 +2330,7 @@ def generate_rust_api(api_data, structs_map, functions_map):
                         else:
                             class_impl_block += "        /// Creates a new `" + class_name + "` instance.\r\n"
 
-                        returns = "Self"
+                        returns = class_name + "Ptr" if class_is_ffi_ptr(c) else "Self"
                         if "returns" in const.keys():
                             return_type = const["returns"]["type"]
                             returns = return_type
@@ -2205,6 +2342,8 @@ def generate_rust_api(api_data, structs_map, functions_map):
                                 if return_type_class is None:
                                     print("no return type found for return type: " + return_type)
                                 returns = analyzed_return_type[0] + " crate::" + return_type_class[0] + "::" + return_type_class[1] + analyzed_return_type[2]
+                                if class_is_ffi_ptr(get_class(myapi_data, return_type_class[0], return_type_class[1])):
+                                    returns = "crate::" + return_type_class[0] + "::" + return_type_class[1] + "Ptr"
                                 fn_body = fn_body
 
                         class_impl_block += "        pub fn " + fn_name + "" + fn_args[0] + "(" + fn_args[1] + ") -> " + returns + " { " + fn_body + " }\r\n"
@@ -2215,6 +2354,12 @@ def generate_rust_api(api_data, structs_map, functions_map):
 
                         fn_args = rust_bindings_fn_args(f, class_name, class_ptr_name, True, myapi_data)
                         fn_args_call = rust_bindings_call_fn_args(f, class_name, class_ptr_name, True, myapi_data, class_is_boxed_object)
+                        if class_is_ffi_ptr(c):
+                            self_val = list(f["fn_args"][0].values())[0]
+                            if self_val == "ref":
+                                fn_args_call = "self as *const Self as *mut Self" + fn_args_call[len("self"):]
+                            elif self_val != "refmut":
+                                raise Exception("functions of " + class_name + " can only take the handle by reference: " + fn_name)
 
                         c_fn_name = class_ptr_name + "_" + snake_case_to_lower_camel(fn_name)
 
@@ -2250,10 +2395,17 @@ def generate_rust_api(api_data, structs_map, functions_map):
                                 if return_type_class is None:
                                     print("no return type found for return type: " + return_type)
                                 returns = " ->" + analyzed_return_type[0] + " crate::" + return_type_class[0] + "::" + return_type_class[1] + analyzed_return_type[2]
+                                if class_is_ffi_ptr(get_class(myapi_data, return_type_class[0], return_type_class[1])):
+                                    returns = " -> crate::" + return_type_class[0] + "::" + return_type_class[1] + "Ptr"
                                 fn_body = fn_body
 
                         class_impl_block += "        pub fn " + fn_name + fn_args[0] + "(" + fn_args[1] + ") " +  returns + " { " + fn_body + " }\r\n"
 
+                if class_is_ffi_ptr(c):
+                    class_impl_block += "        /// Frees the `" + class_name + "`, `NULL` is ignored\r\n"
+                    class_impl_block += "        ///\r\n        /// # Safety\r\n        ///\r\n        /// `ptr` has to be `NULL` or a live handle, it must not be used afterwards\r\n"
+                    class_impl_block += "        pub unsafe fn delete(ptr: " + class_name + "Ptr) { crate::dll::" + class_ptr_name + "_delete(ptr) }\r\n"
+
                 code += "    impl " + class_name + " {\r\n"
                 code += class_impl_block
                 code += "    }\r\n\r\n" # end of class
@@ -2795,6 +2947,23 @@ def replace_primitive_ctype(input):
     }
     return switcher.get(input, input + " ")
 
+# Returns the C type of a "returns" type, i.e. "*const u8" => "const uint8_t*"
+def c_return_type(return_type, pfx, api_data):
+    analyzed_return_type = analyze_type(return_type)
+    if is_primitive_arg(analyzed_return_type[1]):
+        return_val = replace_primitive_ctype(analyzed_return_type[1])
+    elif class_is_ffi_ptr(quick_get_class(api_data, analyzed_return_type[1])):
+        return_val = pfx + analyzed_return_type[1] + "Ptr"
+    else:
+        return_val = pfx + analyzed_return_type[1]
+
+    if analyzed_return_type[0] == "*const ":
+        return "const " + return_val.strip() + "*"
+    elif analyzed_return_type[0] == "*mut ":
+        return return_val.strip() + "*"
+    else:
+        return return_val
+
 # Generates the functions to put in the C header file
 # assumes that all structs / data types have already been declared previously
 def generate_c_functions(api_data,use_prefix=True,typedef_style="c"):
@@ -2812,6 +2981,15 @@ def generate_c_functions(api_data,use_prefix=True,typedef_style="c"):
     version = list(api_data.keys())[-1]
     myapi_data = api_data[version]
 
+    code += "\r\n"
+    code += "\r\n/* HANDLES to rust-allocated objects, only accessible via the functions */"
+    for module_name in myapi_data.keys():
+        module = myapi_data[module_name]["classes"]
+        for class_name in module.keys():
+            if class_is_ffi_ptr(module[class_name]):
+                code += "\r\ntypedef struct " + pfx + class_name + " " + pfx + class_name + ";"
+                code += "\r\ntypedef " + pfx + class_name + "* " + pfx + class_name + "Ptr;"
+
     code += "\r\n"
     code += "\r\n/* FUNCTIONS from azul.dll / libazul.so */"
 
@@ -2838,21 +3016,20 @@ def generate_c_functions(api_data,use_prefix=True,typedef_style="c"):
                 for constructor_name in c["constructors"].keys():
                     const = c["constructors"][constructor_name]
                     fn_args = c_fn_args_c_api(const, class_name, class_ptr_name, False)
-                    code += "\r\n" + function_prefix + class_ptr_name + " " + class_ptr_name + "_" + snake_case_to_lower_camel(constructor_name) + "(" + fn_args + ");"
+                    return_val = class_ptr_name + ("Ptr" if class_is_ffi_ptr(c) else "")
+                    if "returns" in const.keys():
+                        return_val = c_return_type(const["returns"]["type"], pfx, myapi_data)
+                    code += "\r\n" + function_prefix + return_val + " " + class_ptr_name + "_" + snake_case_to_lower_camel(constructor_name) + "(" + fn_args + ");"
 
             if "functions" in c.keys():
                 print_separator = True
                 for function_name in c["functions"].keys():
                     function = c["functions"][function_name]
-                    fn_args = c_fn_args_c_api(function, class_name, class_ptr_name, True)
+                    fn_args = c_fn_args_c_api(function, class_name, class_ptr_name, True, class_is_ffi_ptr(c))
 
                     return_val = "void"
                     if "returns" in function.keys():
-                        analyzed_return_type = analyze_type(function["returns"]["type"])
-                        if is_primitive_arg(analyzed_return_type[1]):
-                            return_val = replace_primitive_ctype(analyzed_return_type[1])
-                        else:
-                            return_val = pfx + analyzed_return_type[1]
+                        return_val = c_return_type(function["returns"]["type"], pfx, myapi_data)
 
                     code += "\r\n" + function_prefix + return_val + " "+ class_ptr_name + "_" + snake_case_to_lower_camel(function_name) + "(" + fn_args + ");"
 
@@ -2867,12 +3044,32 @@ def generate_c_functions(api_data,use_prefix=True,typedef_style="c"):
                 if treat_external_as_ptr and class_can_be_cloned:
                     print_separator = True
                     code += "\r\n" + function_prefix + class_ptr_name + " " + class_ptr_name + "_deepCopy(" + class_ptr_name + "* const instance);"
+            elif class_is_ffi_ptr(c):
+                print_separator = True
+                code += "\r\n" + function_prefix + "void " + class_ptr_name + "_delete(" + class_ptr_name + "Ptr ptr);"
 
             # if print_separator:
             #   code += "\r\n"
 
     return code
 
+# Generates the version defines, the version / ABI of the loaded
+# library is checked at runtime with the AzApi_* functions
+def generate_c_version_functions(api_data):
+
+    dll_version = read_dll_version()
+
+    code = ""
+    code += "\r\n"
+    code += "\r\n/* VERSION of the API this header was generated for, compare with AzApi_versionMajor() etc. */\r\n"
+    code += "\r\n#define AZ_API_VERSION_MAJOR " + str(dll_version[0])
+    code += "\r\n#define AZ_API_VERSION_MINOR " + str(dll_version[1])
+    code += "\r\n#define AZ_API_VERSION_PATCH " + str(dll_version[2])
+    code += "\r\n#define AZ_API_ABI_HASH 0x" + format(generate_abi_hash(api_data), "016x") + "ULL"
+    code += "\r\n"
+
+    return code
+
 # Generates all constants
 def generate_c_constants(api_data):
 
@@ -2923,16 +3120,27 @@ def generate_c_extra_functions(api_data):
             for variant in c["enum_fields"]:
                 variant_name = list(variant.keys())[0]
                 if "type" in variant[variant_name]:
-                    type_name = variant[variant_name]["type"]
-
-                    code += "bool " + prefix + class_name + "_matchRef" + variant_name + "(const " + prefix + class_name + "* value, const " + prefix + type_name + "** restrict out) {\r\n"
+                    # same payload type as in the variant struct: "u32" => "uint32_t", "*mut Foo" => "AzFoo* restrict"
+                    analyzed_variant_type = analyze_type(variant[variant_name]["type"])
+                    type_prefix = "" if is_primitive_arg(analyzed_variant_type[1]) else prefix
+                    type_name = type_prefix + replace_primitive_ctype(analyzed_variant_type[1]).strip()
+                    ref_out = "const " + type_name + replace_primitive_ctype(analyzed_variant_type[0]).strip() + "** restrict out"
+                    mut_out = type_name + replace_primitive_ctype(analyzed_variant_type[0]).strip() + "* restrict * restrict out"
+
+                    # arrays: [FloatValue;4] => "const AzFloatValue (** restrict out)[4]"
+                    if analyzed_variant_type[2].startswith(";"):
+                        array_len = analyzed_variant_type[2][1:].rstrip("]")
+                        ref_out = "const " + type_name + " (** restrict out)[" + array_len + "]"
+                        mut_out = type_name + " (* restrict * restrict out)[" + array_len + "]"
+
+                    code += "bool " + prefix + class_name + "_matchRef" + variant_name + "(const " + prefix + class_name + "* value, " + ref_out + ") {\r\n"
                     code += "    const " + prefix + class_name + "Variant_" + variant_name + "* casted = (const " + prefix + class_name +"Variant_" + variant_name + "*)value;\r\n"
                     code += "    bool valid = casted->tag == " + prefix + class_name + "Tag_" + variant_name + ";\r\n"
                     code += "    if (valid) { *out = &casted->payload; } else { *out = 0; }\r\n"
                     code += "    return valid;\r\n"
                     code += "}\r\n\r\n"
 
-                    code += "bool " + prefix + class_name + "_matchMut" + variant_name + "(" + prefix + class_name + "* restrict value, " + prefix + type_name + "* restrict * restrict out) {\r\n"
+                    code += "bool " + prefix + class_name + "_matchMut" + variant_name + "(" + prefix + class_name + "* restrict value, " + mut_out + ") {\r\n"
                     code += "    " + prefix + class_name + "Variant_" + variant_name + "* restrict casted = (" + prefix + class_name +"Variant_" + variant_name + "* restrict)value;\r\n"
                     code += "    bool valid = casted->tag == " + prefix + class_name + "Tag_" + variant_name + ";\r\n"
                     code += "    if (valid) { *out = &casted->payload; } else { *out = 0; }\r\n"
@@ -2993,6 +3201,7 @@ def generate_c_api(api_data, structs_map):
     code += generate_c_structs(myapi_data, structs_map, forward_delcarations, extra_forward_delcarations)
     code += generate_c_union_macros_and_vec_constructors(api_data, structs_map)
     code += generate_c_functions(api_data)
+    code += generate_c_version_functions(api_data)
     code += generate_c_constants(api_data)
     code += generate_c_extra_functions(api_data)
 
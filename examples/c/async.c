@@ -0,0 +1,228 @@
+#include <azul.h>
+#include <stdio.h>
+#include <stdbool.h>
+
+#ifdef _WIN32
+    #include <windows.h>
+    #define sleep_ms(ms) Sleep(ms)
+#else
+    #include <unistd.h>
+    #define sleep_ms(ms) usleep((ms) * 1000)
+#endif
+
+#define DOWNLOAD_CHUNKS 100
+
+// application data, lives on the main thread
+typedef struct {
+    float progress;
+    bool is_running;
+    AzOptionThreadId thread_id;
+} MyDataModel;
+
+void MyDataModel_delete(MyDataModel* restrict A) { }
+AZ_REFLECT(MyDataModel, MyDataModel_delete);
+
+// data of the background thread: moved into the thread while it runs,
+// handed to myOnDownloadFinished once it returns
+typedef struct {
+    size_t total_chunks;
+    size_t downloaded_chunks;
+    AzRefAny model; // only copied (never accessed) on the background thread
+} DownloadJob;
+
+void DownloadJob_delete(DownloadJob* restrict A) { AzRefAny_delete(&A->model); }
+AZ_REFLECT(DownloadJob, DownloadJob_delete);
+
+// message sent from the background thread to the main thread
+typedef struct {
+    float progress;
+    AzRefAny model;
+} DownloadProgress;
+
+void DownloadProgress_delete(DownloadProgress* restrict A) { AzRefAny_delete(&A->model); }
+AZ_REFLECT(DownloadProgress, DownloadProgress_delete);
+
+AzUpdate myOnStartClick(AzRefAny* restrict data, AzCallbackInfo* restrict info);
+AzUpdate myOnStopClick(AzRefAny* restrict data, AzCallbackInfo* restrict info);
+
+// model -> view
+AzStyledDom myLayoutFunc(AzRefAny* restrict data, AzLayoutCallbackInfo* restrict info) {
+    MyDataModelRef d = MyDataModelRef_create(data);
+    if (!MyDataModel_downcastRef(data, &d)) {
+        return AzStyledDom_default(); // error
+    }
+
+    char buffer[64];
+    int const written = d.ptr->is_running
+        ? snprintf(buffer, sizeof(buffer), "Downloading: %.0f%%", d.ptr->progress * 100.0f)
+        : snprintf(buffer, sizeof(buffer), "Progress: %.0f%%", d.ptr->progress * 100.0f);
+    MyDataModelRef_delete(&d);
+
+    AzString const labelstring = AzString_copyFromBytes((uint8_t const*)buffer, 0, (size_t)written);
+    AzString const label_style = AzString_fromConstStr("font-size: 20px");
+    AzDom label = AzDom_text(labelstring);
+    AzDom_setInlineStyle(&label, label_style);
+
+    AzEventFilter const mouse_up = AzEventFilter_Hover(AzHoverEventFilter_MouseUp);
+
+    AzString const start_text = AzString_fromConstStr("Start download");
+    AzString const start_style = AzString_fromConstStr("padding: 5px; background: #efefef");
+    AzDom start_button = AzDom_text(start_text);
+    AzDom_setInlineStyle(&start_button, start_style);
+    AzDom_addCallback(&start_button, mouse_up, AzRefAny_deepCopy(data), myOnStartClick);
+
+    AzString const stop_text = AzString_fromConstStr("Cancel");
+    AzString const stop_style = AzString_fromConstStr("padding: 5px; background: #efefef");
+    AzDom stop_button = AzDom_text(stop_text);
+    AzDom_setInlineStyle(&stop_button, stop_style);
+    AzDom_addCallback(&stop_button, mouse_up, AzRefAny_deepCopy(data), myOnStopClick);
+
+    AzDom body = AzDom_body();
+    AzDom_addChild(&body, label);
+    AzDom_addChild(&body, start_button);
+    AzDom_addChild(&body, stop_button);
+
+    return AzStyledDom_new(body, AzCss_empty());
+}
+
+// runs on the main thread: copies the progress of the background thread into the model
+// (the first argument is internal to azul, the message carries a copy of the model)
+AzUpdate myOnDownloadProgress(AzRefAny* restrict _, AzRefAny* restrict msg, AzCallbackInfo* restrict info) {
+    DownloadProgressRefMut p = DownloadProgressRefMut_create(msg);
+    if (!DownloadProgress_downcastMut(msg, &p)) {
+        return AzUpdate_DoNothing; // error
+    }
+
+    MyDataModelRefMut d = MyDataModelRefMut_create(&p.ptr->model);
+    if (!MyDataModel_downcastMut(&p.ptr->model, &d)) {
+        DownloadProgressRefMut_delete(&p);
+        return AzUpdate_DoNothing; // error
+    }
+
+    d.ptr->progress = p.ptr->progress;
+
+    MyDataModelRefMut_delete(&d);
+    DownloadProgressRefMut_delete(&p);
+
+    return AzUpdate_RefreshDom;
+}
+
+// runs on the main thread after the thread function returned (finished or stopped),
+// data is the DownloadJob, which is deleted after this callback returns
+AzUpdate myOnDownloadFinished(AzRefAny* restrict data, AzCallbackInfo* restrict info) {
+    DownloadJobRefMut j = DownloadJobRefMut_create(data);
+    if (!DownloadJob_downcastMut(data, &j)) {
+        return AzUpdate_DoNothing; // error
+    }
+
+    MyDataModelRefMut d = MyDataModelRefMut_create(&j.ptr->model);
+    if (!MyDataModel_downcastMut(&j.ptr->model, &d)) {
+        DownloadJobRefMut_delete(&j);
+        return AzUpdate_DoNothing; // error
+    }
+
+    d.ptr->progress = (float)j.ptr->downloaded_chunks / (float)j.ptr->total_chunks;
+    d.ptr->is_running = false;
+    AzOptionThreadId const none = AzOptionThreadId_None;
+    d.ptr->thread_id = none;
+
+    MyDataModelRefMut_delete(&d);
+    DownloadJobRefMut_delete(&j);
+
+    return AzUpdate_RefreshDom;
+}
+
+// returns true if the main thread asked the thread to stop (AzCallbackInfo_requestThreadStop)
+bool shouldTerminate(AzThreadReceiver* restrict receiver) {
+    bool terminate = false;
+    AzOptionThreadSendMsg msg = AzThreadReceiver_receive(receiver);
+    AzThreadSendMsg const* m;
+    if (AzOptionThreadSendMsg_matchRefSome(&msg, &m)) {
+        terminate = m->TerminateThread.tag == AzThreadSendMsgTag_TerminateThread;
+    }
+    AzOptionThreadSendMsg_delete(&msg);
+    return terminate;
+}
+
+// runs on the background thread: data, sender and receiver are owned by azul
+void myDownloadThread(AzRefAny* data, AzThreadSender* sender, AzThreadReceiver* receiver) {
+    DownloadJobRefMut j = DownloadJobRefMut_create(data);
+    if (!DownloadJob_downcastMut(data, &j)) {
+        return; // error
+    }
+
+    while (j.ptr->downloaded_chunks < j.ptr->total_chunks) {
+        if (shouldTerminate(receiver)) {
+            break;
+        }
+        sleep_ms(50); // simulate downloading one chunk
+        j.ptr->downloaded_chunks += 1;
+
+        DownloadProgress const progress = {
+            .progress = (float)j.ptr->downloaded_chunks / (float)j.ptr->total_chunks,
+            .model = AzRefAny_deepCopy(&j.ptr->model),
+        };
+        AzThreadWriteBackMsg const writeback = {
+            .data = DownloadProgress_upcast(progress),
+            .callback = { .cb = myOnDownloadProgress },
+        };
+        if (!AzThreadSender_send(sender, AzThreadReceiveMsg_WriteBack(writeback))) {
+            break; // main thread is gone
+        }
+    }
+
+    DownloadJobRefMut_delete(&j);
+}
+
+// model <- view: starts the download, unless it is already running
+AzUpdate myOnStartClick(AzRefAny* restrict data, AzCallbackInfo* restrict info) {
+    MyDataModelRefMut d = MyDataModelRefMut_create(data);
+    if (!MyDataModel_downcastMut(data, &d)) {
+        return AzUpdate_DoNothing; // error
+    }
+
+    if (d.ptr->is_running) {
+        MyDataModelRefMut_delete(&d);
+        return AzUpdate_DoNothing;
+    }
+
+    DownloadJob const job = {
+        .total_chunks = DOWNLOAD_CHUNKS,
+        .downloaded_chunks = 0,
+        .model = AzRefAny_deepCopy(data),
+    };
+    AzPendingThread const thread = AzPendingThread_new(DownloadJob_upcast(job), myDownloadThread);
+    d.ptr->thread_id = AzCallbackInfo_startPendingThread(info, thread, myOnDownloadFinished);
+    d.ptr->is_running = true;
+    d.ptr->progress = 0.0f;
+    MyDataModelRefMut_delete(&d);
+
+    return AzUpdate_RefreshDom;
+}
+
+// model <- view: asks the download to stop, myOnDownloadFinished resets the model
+AzUpdate myOnStopClick(AzRefAny* restrict data, AzCallbackInfo* restrict info) {
+    MyDataModelRef d = MyDataModelRef_create(data);
+    if (!MyDataModel_downcastRef(data, &d)) {
+        return AzUpdate_DoNothing; // error
+    }
+
+    AzThreadId const* thread_id;
+    if (AzOptionThreadId_matchRefSome(&d.ptr->thread_id, &thread_id)) {
+        AzCallbackInfo_requestThreadStop(info, *thread_id);
+    }
+    MyDataModelRef_delete(&d);
+
+    return AzUpdate_DoNothing;
+}
+
+int main() {
+    AzOptionThreadId const no_thread = AzOptionThreadId_None;
+    MyDataModel model = { .progress = 0.0f, .is_running = false, .thread_id = no_thread };
+    AzRefAny upcasted = MyDataModel_upcast(model);
+    AzAppConfig const config = AzAppConfig_default();
+    AzApp app = AzApp_new(upcasted, config);
+    AzApp_run(&app, AzWindowCreateOptions_new(myLayoutFunc));
+    AzApp_delete(&app);
+    return 0;
+}
@@ -0,0 +1,89 @@
+#include <azul.h>
+#include <stdio.h>
+#include <string.h>
+
+#define MAX_LABEL_LEN 4096
+
+typedef struct {
+    char label[MAX_LABEL_LEN];
+    size_t label_len;
+} MyDataModel;
+
+void MyDataModel_delete(MyDataModel* restrict A) { }
+AZ_REFLECT(MyDataModel, MyDataModel_delete);
+
+AzUpdate myOnFileDropped(AzRefAny* restrict data, AzCallbackInfo* restrict info);
+
+// model -> view
+AzStyledDom myLayoutFunc(AzRefAny* restrict data, AzLayoutCallbackInfo* restrict info) {
+    MyDataModelRef d = MyDataModelRef_create(data);
+    if (!MyDataModel_downcastRef(data, &d)) {
+        return AzStyledDom_default(); // error
+    }
+
+    AzString const labelstring = AzString_copyFromBytes(&d.ptr->label, 0, d.ptr->label_len);
+    MyDataModelRef_delete(&d);
+
+    AzString const label_style = AzString_fromConstStr("font-size: 20px");
+    AzDom label = AzDom_text(labelstring);
+    AzDom_setInlineStyle(&label, label_style);
+
+    AzString const body_style = AzString_fromConstStr("flex-grow: 1");
+    AzDom body = AzDom_body();
+    AzDom_setInlineStyle(&body, body_style);
+    AzEventFilter const dropped_file = AzEventFilter_Window(AzWindowEventFilter_DroppedFile);
+    AzDom_addCallback(&body, dropped_file, AzRefAny_deepCopy(data), myOnFileDropped);
+    AzDom_addChild(&body, label);
+
+    return AzStyledDom_new(body, AzCss_empty());
+}
+
+// model <- view: prints the paths of the dropped files to the label
+AzUpdate myOnFileDropped(AzRefAny* restrict data, AzCallbackInfo* restrict info) {
+    MyDataModelRefMut d = MyDataModelRefMut_create(data);
+    if (!MyDataModel_downcastMut(data, &d)) {
+        return AzUpdate_DoNothing; // error
+    }
+
+    AzStringVec files = AzCallbackInfo_getDroppedFiles(info);
+    size_t const file_count = AzStringVec_len(&files);
+
+    AzOptionLogicalPosition position = AzCallbackInfo_getDropPosition(info);
+    AzLogicalPosition const* drop_position;
+    if (AzOptionLogicalPosition_matchRefSome(&position, &drop_position)) {
+        printf("dropped %zu file(s) at (%f, %f):\n", file_count, drop_position->x, drop_position->y);
+    }
+
+    d.ptr->label_len = 0;
+    for (size_t i = 0; i < file_count; i++) {
+        AzOptionString file = AzStringVec_get(&files, i);
+        AzString const* path;
+        if (AzOptionString_matchRefSome(&file, &path)) {
+            size_t const remaining = MAX_LABEL_LEN - d.ptr->label_len;
+            size_t const len = path->vec.len + 1 < remaining ? path->vec.len : 0;
+            memcpy(&d.ptr->label[d.ptr->label_len], path->vec.ptr, len);
+            d.ptr->label_len += len;
+            if (len != 0) {
+                d.ptr->label[d.ptr->label_len] = '\n';
+                d.ptr->label_len += 1;
+            }
+            printf("%.*s\n", (int)path->vec.len, (const char*)path->vec.ptr);
+        }
+        AzOptionString_delete(&file);
+    }
+
+    AzStringVec_delete(&files);
+    MyDataModelRefMut_delete(&d);
+
+    return AzUpdate_RefreshDom;
+}
+
+int main() {
+    MyDataModel model = { .label = "Drop files onto this window", .label_len = 27 };
+    AzRefAny upcasted = MyDataModel_upcast(model);
+    AzAppConfig const config = AzAppConfig_default();
+    AzApp app = AzApp_new(upcasted, config);
+    AzApp_run(&app, AzWindowCreateOptions_new(myLayoutFunc));
+    AzApp_delete(&app);
+    return 0;
+}
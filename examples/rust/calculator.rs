@@ -92,6 +92,7 @@ pub mod ui {
         const STYLE_BACKGROUND_CONTENT_4154864923475193136_ITEMS: &[StyleBackgroundContent] = &[
             StyleBackgroundContent::Color(ColorU { r: 214, g: 214, b: 214, a: 255 })
         ];
+        static STYLE_BACKGROUND_CONTENT_4154864923475193136: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4154864923475193136_ITEMS));
         const STYLE_BACKGROUND_CONTENT_7327435497123668670_ITEMS: &[StyleBackgroundContent] = &[
             StyleBackgroundContent::Color(ColorU { r: 68, g: 68, b: 68, a: 255 })
         ];
@@ -131,7 +132,7 @@ pub mod ui {
             // .numpad-button
             NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
             NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(27) }))),
-            NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(STYLE_FONT_FAMILY_12348921234331816595_ITEMS.clone()))),
+            NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact(STYLE_FONT_FAMILY_12348921234331816595_ITEMS.clone()))),
             NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
             NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Column))),
             NodeDataInlineCssProperty::Normal(CssProperty::BoxSizing(LayoutBoxSizingValue::Exact(LayoutBoxSizing::BorderBox))),
@@ -148,11 +149,11 @@ pub mod ui {
             NodeDataInlineCssProperty::Normal(CssProperty::MaxHeight(LayoutMaxHeightValue::Exact(LayoutMaxHeight { inner: PixelValue::const_pt(81) }))),
             NodeDataInlineCssProperty::Normal(CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(LayoutJustifyContent::End))),
             NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(60) }))),
-            NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(STYLE_FONT_FAMILY_12348921234331816595_ITEMS.clone()))),
+            NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact(STYLE_FONT_FAMILY_12348921234331816595_ITEMS.clone()))),
             NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
             NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row))),
             NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
-            NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_13274507731280044099_ITEMS))))
+            NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_13274507731280044099_ITEMS))))
         ]);
 
         const CSS_MATCH_15575492078751046510_PROPERTIES: &[NodeDataInlineCssProperty] = &[
@@ -184,14 +185,14 @@ pub mod ui {
             NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(98) }))),
             NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
             NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(27) }))),
-            NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(STYLE_FONT_FAMILY_12348921234331816595_ITEMS.clone()))),
+            NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact(STYLE_FONT_FAMILY_12348921234331816595_ITEMS.clone()))),
             NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
             NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Column))),
             NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
             NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
             NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
             NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 141, g: 141, b: 141, a: 255 } }))),
-            NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_9344791489195694459_ITEMS)))),
+            NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_9344791489195694459_ITEMS)))),
             NodeDataInlineCssProperty::Normal(CssProperty::AlignItems(LayoutAlignItemsValue::Exact(LayoutAlignItems::Center)))
         ]);
 
@@ -202,17 +203,17 @@ pub mod ui {
             NodeDataInlineCssProperty::Normal(CssProperty::MaxHeight(LayoutMaxHeightValue::Exact(LayoutMaxHeight { inner: PixelValue::const_pt(50) }))),
             NodeDataInlineCssProperty::Normal(CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(LayoutJustifyContent::End))),
             NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(27) }))),
-            NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(STYLE_FONT_FAMILY_12348921234331816595_ITEMS.clone()))),
+            NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact(STYLE_FONT_FAMILY_12348921234331816595_ITEMS.clone()))),
             NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
             NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row))),
             NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
-            NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_7327435497123668670_ITEMS))))
+            NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_7327435497123668670_ITEMS))))
         ]);
 
         const CSS_MATCH_3485639429117624417_PROPERTIES: &[NodeDataInlineCssProperty] = &[
             // .numpad-container
             NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-            NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4154864923475193136_ITEMS))))
+            NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_4154864923475193136))
         ];
         const CSS_MATCH_3485639429117624417: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_3485639429117624417_PROPERTIES);
 
@@ -220,7 +221,7 @@ pub mod ui {
             // .numpad-button
             NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
             NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(27) }))),
-            NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(STYLE_FONT_FAMILY_12348921234331816595_ITEMS.clone()))),
+            NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::exact(STYLE_FONT_FAMILY_12348921234331816595_ITEMS.clone()))),
             NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
             NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Column))),
             NodeDataInlineCssProperty::Normal(CssProperty::BoxSizing(LayoutBoxSizingValue::Exact(LayoutBoxSizing::BorderBox))),
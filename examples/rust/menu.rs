@@ -0,0 +1,127 @@
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+use azul::prelude::*;
+
+#[derive(Default)]
+struct MenuExample {
+    last_action: &'static str,
+    has_selection: bool,
+}
+
+extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+
+    let (last_action, has_selection) = match data.downcast_ref::<MenuExample>() {
+        Some(s) => (s.last_action, s.has_selection),
+        None => return StyledDom::default(),
+    };
+
+    // "Cut" and "Copy" are greyed out (and won't invoke their callback)
+    // until "Select All" has been clicked
+    let selection_state = if has_selection {
+        MenuItemState::Normal
+    } else {
+        MenuItemState::Greyed
+    };
+
+    let file_menu = StringMenuItem::new("File").with_children(vec![
+        MenuItem::String(
+            StringMenuItem::new("New")
+            .with_accelerator(ctrl(VirtualKeyCode::N))
+            .with_callback(data.clone(), on_new)
+        ),
+        MenuItem::String(
+            StringMenuItem::new("Open...")
+            .with_accelerator(ctrl(VirtualKeyCode::O))
+            .with_callback(data.clone(), on_open)
+        ),
+        MenuItem::Separator,
+        MenuItem::String(
+            StringMenuItem::new("Quit")
+            .with_callback(data.clone(), on_quit)
+        ),
+    ]);
+
+    let edit_menu = StringMenuItem::new("Edit").with_children(vec![
+        MenuItem::String(
+            StringMenuItem::new("Cut")
+            .with_accelerator(ctrl(VirtualKeyCode::X))
+            .with_state(selection_state)
+            .with_callback(data.clone(), on_cut)
+        ),
+        MenuItem::String(
+            StringMenuItem::new("Copy")
+            .with_accelerator(ctrl(VirtualKeyCode::C))
+            .with_state(selection_state)
+            .with_callback(data.clone(), on_copy)
+        ),
+        MenuItem::Separator,
+        MenuItem::String(
+            StringMenuItem::new("Select All")
+            .with_accelerator(ctrl(VirtualKeyCode::A))
+            .with_callback(data.clone(), on_select_all)
+        ),
+    ]);
+
+    Dom::body()
+    .with_menu_bar(Menu::new(vec![
+        MenuItem::String(file_menu),
+        MenuItem::String(edit_menu),
+    ]))
+    .with_child(Dom::text(format!("Last action: {}", last_action)))
+    .style(Css::empty())
+}
+
+fn ctrl(key: VirtualKeyCode) -> VirtualKeyCodeCombo {
+    VirtualKeyCodeCombo { keys: vec![VirtualKeyCode::LControl, key].into() }
+}
+
+fn set_last_action(data: &mut RefAny, action: &'static str) -> Update {
+    match data.downcast_mut::<MenuExample>() {
+        Some(mut s) => {
+            s.last_action = action;
+            Update::RefreshDom
+        },
+        None => Update::DoNothing,
+    }
+}
+
+extern "C" fn on_new(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    set_last_action(data, "New")
+}
+
+extern "C" fn on_open(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    set_last_action(data, "Open")
+}
+
+extern "C" fn on_cut(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    set_last_action(data, "Cut")
+}
+
+extern "C" fn on_copy(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    set_last_action(data, "Copy")
+}
+
+extern "C" fn on_select_all(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    match data.downcast_mut::<MenuExample>() {
+        Some(mut s) => {
+            s.last_action = "Select All";
+            s.has_selection = true;
+            Update::RefreshDom
+        },
+        None => Update::DoNothing,
+    }
+}
+
+extern "C" fn on_quit(_: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    let mut window_state = info.get_current_window_state();
+    window_state.flags.is_about_to_close = true;
+    info.set_window_state(window_state);
+    Update::DoNothing
+}
+
+fn main() {
+    let app = App::new(RefAny::new(MenuExample::default()), AppConfig::new(LayoutSolver::Default));
+    let mut window = WindowCreateOptions::new(layout);
+    window.state.title = "Menu example".into();
+    app.run(window);
+}
@@ -58,30 +58,37 @@ pub mod ui {
     const STYLE_BACKGROUND_CONTENT_4878363956973295354_ITEMS: &[StyleBackgroundContent] = &[
         StyleBackgroundContent::Color(ColorU { r: 173, g: 216, b: 230, a: 255 })
     ];
+    static STYLE_BACKGROUND_CONTENT_4878363956973295354: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4878363956973295354_ITEMS));
     const STYLE_BACKGROUND_CONTENT_4967804087795204988_ITEMS: &[StyleBackgroundContent] = &[
         StyleBackgroundContent::Color(ColorU { r: 250, g: 128, b: 114, a: 255 })
     ];
+    static STYLE_BACKGROUND_CONTENT_4967804087795204988: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4967804087795204988_ITEMS));
     const STYLE_BACKGROUND_CONTENT_8568982142085024634_ITEMS: &[StyleBackgroundContent] = &[
         StyleBackgroundContent::Color(ColorU { r: 250, g: 235, b: 215, a: 255 })
     ];
+    static STYLE_BACKGROUND_CONTENT_8568982142085024634: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_8568982142085024634_ITEMS));
     const STYLE_BACKGROUND_CONTENT_12869309920691526943_ITEMS: &[StyleBackgroundContent] = &[
         StyleBackgroundContent::Color(ColorU { r: 240, g: 248, b: 255, a: 255 })
     ];
+    static STYLE_BACKGROUND_CONTENT_12869309920691526943: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_12869309920691526943_ITEMS));
     const STYLE_BACKGROUND_CONTENT_14573424550548235545_ITEMS: &[StyleBackgroundContent] = &[
         StyleBackgroundContent::Color(ColorU { r: 33, g: 114, b: 69, a: 255 })
     ];
+    static STYLE_BACKGROUND_CONTENT_14573424550548235545: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_14573424550548235545_ITEMS));
     const STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS: &[StyleBackgroundContent] = &[
         StyleBackgroundContent::Color(ColorU { r: 255, g: 255, b: 255, a: 255 })
     ];
+    static STYLE_BACKGROUND_CONTENT_16746671892555275291: StyleBackgroundContentVecBoxed = StyleBackgroundContentVecBoxed::new_static(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS));
     const STYLE_FONT_FAMILY_8122988506401935406_ITEMS: &[StyleFontFamily] = &[
         StyleFontFamily::System(STRING_16146701490593874959)
     ];
+    static STYLE_FONT_FAMILY_8122988506401935406: StyleFontFamilyVecBoxed = StyleFontFamilyVecBoxed::new_static(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS));
 
     const CSS_MATCH_10111026547520801912_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .minixel-table-container .column-wrapper .line-numbers
         NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(25) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(14) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(&STYLE_FONT_FAMILY_8122988506401935406)),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } })))
@@ -91,7 +98,7 @@ pub mod ui {
     const CSS_MATCH_10537637882082253178_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .minixel-formula-container .formula-commit .btn-2
         NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_12869309920691526943_ITEMS))))
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_12869309920691526943))
     ];
     const CSS_MATCH_10537637882082253178: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_10537637882082253178_PROPERTIES);    
 
@@ -168,7 +175,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(1) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(1) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(13) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(&STYLE_FONT_FAMILY_8122988506401935406)),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 229, g: 229, b: 229, a: 255 } }))),
@@ -209,7 +216,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 33, g: 114, b: 69, a: 255 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 33, g: 114, b: 69, a: 255 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 33, g: 114, b: 69, a: 255 } }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_14573424550548235545_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_14573424550548235545)),
         // .__azul_native-ribbon-tabs p
         NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(14) }))),
@@ -217,7 +224,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(12) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(&STYLE_FONT_FAMILY_8122988506401935406)),
         NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 101, g: 101, b: 101, a: 255 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
@@ -234,7 +241,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_16746671892555275291)),
         NodeDataInlineCssProperty::Normal(CssProperty::AlignItems(LayoutAlignItemsValue::Exact(LayoutAlignItems::Center)))
     ];
     const CSS_MATCH_14371786645818370801: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14371786645818370801_PROPERTIES);    
@@ -242,7 +249,7 @@ pub mod ui {
     const CSS_MATCH_14675068197785310311_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .minixel-table-container
         NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS))))
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_16746671892555275291))
     ];
     const CSS_MATCH_14675068197785310311: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14675068197785310311_PROPERTIES);    
 
@@ -271,14 +278,14 @@ pub mod ui {
         // .__azul_native-ribbon-action-vertical-large .icon-wrapper .icon
         NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(32) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(32) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4878363956973295354_ITEMS))))
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_4878363956973295354))
     ];
     const CSS_MATCH_15716718910432952660: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_15716718910432952660_PROPERTIES);    
 
     const CSS_MATCH_15943161397910029460_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .minixel-formula-container .formula-commit .btn-1
         NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4967804087795204988_ITEMS))))
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_4967804087795204988))
     ];
     const CSS_MATCH_15943161397910029460: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_15943161397910029460_PROPERTIES);    
 
@@ -308,7 +315,7 @@ pub mod ui {
         // .minixel-formula-container .formula-commit .btn-3
         NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(30) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_8568982142085024634_ITEMS))))
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_8568982142085024634))
     ];
     const CSS_MATCH_17283019665138187991: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17283019665138187991_PROPERTIES);    
 
@@ -337,7 +344,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(12) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(&STYLE_FONT_FAMILY_8122988506401935406)),
         NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 101, g: 101, b: 101, a: 255 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
@@ -354,7 +361,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_16746671892555275291)),
         NodeDataInlineCssProperty::Normal(CssProperty::AlignItems(LayoutAlignItemsValue::Exact(LayoutAlignItems::Center)))
     ];
     const CSS_MATCH_17524132644355033702: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17524132644355033702_PROPERTIES);    
@@ -371,7 +378,7 @@ pub mod ui {
     const CSS_MATCH_2161661208916302443_PROPERTIES: &[NodeDataInlineCssProperty] = &[
         // .minixel-formula-container .formula-entry .dropdown-sm
         NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(10) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_12869309920691526943_ITEMS))))
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_12869309920691526943))
     ];
     const CSS_MATCH_2161661208916302443: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_2161661208916302443_PROPERTIES);    
 
@@ -398,7 +405,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(12) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(&STYLE_FONT_FAMILY_8122988506401935406)),
         NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 101, g: 101, b: 101, a: 255 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
@@ -415,7 +422,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_16746671892555275291)),
         NodeDataInlineCssProperty::Normal(CssProperty::AlignItems(LayoutAlignItemsValue::Exact(LayoutAlignItems::Center)))
     ];
     const CSS_MATCH_2310038472753606232: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_2310038472753606232_PROPERTIES);    
@@ -428,7 +435,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(2) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(90) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(12) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(&STYLE_FONT_FAMILY_8122988506401935406)),
         NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row))),
         NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Flex))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
@@ -495,7 +502,7 @@ pub mod ui {
         // .__azul_native-ribbon-action-vertical-large .dropdown .icon
         NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(5) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(5) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4967804087795204988_ITEMS))))
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_4967804087795204988))
     ];
     const CSS_MATCH_491594124841839797: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_491594124841839797_PROPERTIES);    
 
@@ -505,7 +512,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
         NodeDataInlineCssProperty::Normal(CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(LayoutJustifyContent::Center))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(14) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(&STYLE_FONT_FAMILY_8122988506401935406)),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 229, g: 229, b: 229, a: 255 } }))),
@@ -552,9 +559,9 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(LayoutJustifyContent::Center))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(13) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(&STYLE_FONT_FAMILY_8122988506401935406)),
         NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS))))
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_16746671892555275291))
     ];
     const CSS_MATCH_6737656294326280219: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6737656294326280219_PROPERTIES);    
 
@@ -579,7 +586,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::MarginRight(LayoutMarginRightValue::Exact(LayoutMarginRight { inner: PixelValue::const_px(30) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(LayoutJustifyContent::Center))),
         NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(13) }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_font_family(&STYLE_FONT_FAMILY_8122988506401935406)),
         NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 34, g: 34, b: 34, a: 255 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
@@ -593,7 +600,7 @@ pub mod ui {
         NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
         NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-        NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS))))
+        NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(&STYLE_BACKGROUND_CONTENT_16746671892555275291))
     ];
     const CSS_MATCH_681808671153488983: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_681808671153488983_PROPERTIES);    
 